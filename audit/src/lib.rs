@@ -0,0 +1,276 @@
+//! PRMX Audit
+//!
+//! Settlement dossiers for external auditors. `PrmxAuditApi::settlement_dossier`
+//! returns everything the chain holds about how one policy settled, V1/V2 or V3,
+//! as a single SCALE-encoded [`SettlementDossier`] that can be archived as is.
+//!
+//! The types here are defined independently of the pallets' storage types, so a
+//! storage change does not silently change the archived format. Any change to the
+//! encoding bumps [`DOSSIER_VERSION`]; decoders should check it first.
+//!
+//! ## Contents
+//!
+//! - Terms: the insured event (V1/V2 event type and strike, or the V3 event spec
+//!   and location) and the coverage window
+//! - Oracle data: V1/V2 hourly rainfall buckets for the coverage window still on
+//!   chain, or the latest V3 snapshot (aggregation state and commitment)
+//! - Final report: the V3 report the policy settled on, with the hash oracle
+//!   members attested
+//! - Trigger log: the V1 threshold trigger row, with its observation root
+//! - Payout: outcome, amounts paid to the holder and returned to LPs and, for V3,
+//!   each underwriter's premium and payout obligation
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::{Decode, Encode};
+use prmx_primitives::{
+    AggStateV3, AreaSpecV3, EventSpecV3, EventType, HourlyRainfall, MarketId, Millimeters,
+    OracleReportKindV3, PolicyId, PolicyStatusV3, PolicyVersion,
+};
+use scale_info::TypeInfo;
+use sp_io::hashing::blake2_256;
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Encoding version of [`SettlementDossier`]
+pub const DOSSIER_VERSION: u16 = 1;
+
+// ============================================================================
+// Dossier
+// ============================================================================
+
+/// Insured event of a policy
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo)]
+pub enum PolicyTerms {
+    /// V1/V2 policy on a market
+    Market {
+        market_id: MarketId,
+        policy_version: PolicyVersion,
+        event_type: EventType,
+        /// Strike in tenths of mm (V2 only)
+        strike_mm: Option<Millimeters>,
+        early_trigger: bool,
+        /// Insured location (scaled by 1e6)
+        latitude: i32,
+        longitude: i32,
+    },
+    /// V3 policy on a registry location
+    V3 {
+        location_id: u64,
+        event_spec: EventSpecV3,
+        /// Locations and aggregation of an area policy
+        area: Option<AreaSpecV3>,
+    },
+}
+
+/// Latest V3 oracle snapshot of a policy
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo)]
+pub struct OracleSnapshot {
+    /// Latest observation timestamp processed
+    pub observed_until: u64,
+    pub agg_state: AggStateV3,
+    /// Commitment chain head (see `prmx-commitment`)
+    pub commitment: [u8; 32],
+    /// Block of the last snapshot
+    pub snapshot_block: u32,
+    pub status: PolicyStatusV3,
+}
+
+/// V3 final report a policy settled on
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo)]
+pub struct FinalReport {
+    /// Hash oracle members attested (`prmx_commitment::final_report_hash`)
+    pub report_hash: [u8; 32],
+    pub kind: OracleReportKindV3,
+    pub observed_until: u64,
+    pub agg_state: AggStateV3,
+    pub commitment: [u8; 32],
+}
+
+/// V1 threshold trigger log row
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo)]
+pub struct TriggerLogRow<AccountId> {
+    pub trigger_id: u64,
+    /// Unix timestamp of the trigger
+    pub triggered_at: u64,
+    pub block_number: u32,
+    /// 24h rolling sum at the trigger in tenths of mm
+    pub rolling_sum_mm: Millimeters,
+    /// Strike that was reached in tenths of mm
+    pub strike_threshold: Millimeters,
+    pub holder: AccountId,
+    pub payout_amount: u128,
+    /// Merkle root of the hourly observations in the rolling window
+    pub observation_root: [u8; 32],
+    pub observation_count: u32,
+}
+
+/// Why a policy settled
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Encode, Decode, TypeInfo)]
+pub enum SettlementPath {
+    /// V1 rolling sum reached the trigger threshold during coverage
+    ThresholdTriggered,
+    /// V1 coverage ended; outcome taken from oracle data for the window
+    CoverageExpired,
+    /// Governance settled the policy
+    GovernanceForced,
+    /// V2 off-chain oracle report
+    OracleReport,
+    /// Outcome supplied after coverage ended
+    Manual,
+    /// V3 final report of kind `Trigger`
+    V3Trigger,
+    /// V3 final report of kind `Maturity`
+    V3Maturity,
+}
+
+/// One underwriter's part of a settled V3 policy
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo)]
+pub struct UnderwriterPayout<AccountId, Balance> {
+    pub underwriter: AccountId,
+    pub shares: u128,
+    /// Premium earned on its shares
+    pub premium: Balance,
+    /// Pro-rata share of the payout (zero when the policy matured)
+    pub payout_obligation: Balance,
+}
+
+/// How a settled policy paid out
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo)]
+pub struct PayoutBreakdown<AccountId, Balance> {
+    pub path: SettlementPath,
+    pub event_occurred: bool,
+    /// Paid to the holder, including secondary-market positions and deferred installments
+    pub payout_to_holder: Balance,
+    /// Distributed to LP holders
+    pub returned_to_lps: Balance,
+    /// Metric the outcome was based on in tenths of mm (V1/V2)
+    pub observed_mm: Option<Millimeters>,
+    /// Commitment to the observations behind the outcome (V1/V2, zero without data)
+    pub evidence_hash: [u8; 32],
+    /// Unix timestamp of settlement (V1/V2)
+    pub settled_at: Option<u64>,
+    /// Block of settlement (V3)
+    pub settled_at_block: Option<u32>,
+    /// Per-underwriter breakdown (V3)
+    pub underwriters: Vec<UnderwriterPayout<AccountId, Balance>>,
+}
+
+/// Everything the chain holds about how one policy settled
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo)]
+pub struct SettlementDossier<AccountId, Balance> {
+    /// [`DOSSIER_VERSION`] the dossier was encoded with
+    pub version: u16,
+    pub policy_id: PolicyId,
+    pub holder: AccountId,
+    pub terms: PolicyTerms,
+    /// Coverage window (unix seconds)
+    pub coverage_start: u64,
+    pub coverage_end: u64,
+    pub shares: u128,
+    pub premium_paid: Balance,
+    pub max_payout: Balance,
+    /// V1/V2 hourly rainfall buckets in the coverage window not yet pruned
+    pub rainfall: Vec<HourlyRainfall>,
+    pub oracle_snapshot: Option<OracleSnapshot>,
+    pub final_report: Option<FinalReport>,
+    pub trigger_log: Option<TriggerLogRow<AccountId>>,
+    /// `None` until the policy settles
+    pub payout: Option<PayoutBreakdown<AccountId, Balance>>,
+}
+
+/// BLAKE2b-256 of a dossier's SCALE encoding, for auditors to fingerprint archives
+pub fn dossier_hash<AccountId: Encode, Balance: Encode>(
+    dossier: &SettlementDossier<AccountId, Balance>,
+) -> [u8; 32] {
+    blake2_256(&dossier.encode())
+}
+
+// ============================================================================
+// Runtime API
+// ============================================================================
+
+sp_api::decl_runtime_apis! {
+    /// Settlement dossiers for external auditors.
+    pub trait PrmxAuditApi<AccountId, Balance>
+    where
+        AccountId: codec::Codec,
+        Balance: codec::Codec,
+    {
+        /// Dossier of a V1/V2 or V3 policy (None if the policy does not exist)
+        fn settlement_dossier(policy_id: PolicyId) -> Option<SettlementDossier<AccountId, Balance>>;
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dossier() -> SettlementDossier<u64, u128> {
+        SettlementDossier {
+            version: DOSSIER_VERSION,
+            policy_id: PolicyId::from([7u8; 16]),
+            holder: 1,
+            terms: PolicyTerms::V3 {
+                location_id: 3,
+                event_spec: EventSpecV3 {
+                    event_type: prmx_primitives::EventTypeV3::PrecipSumGte,
+                    threshold: prmx_primitives::ThresholdV3 {
+                        value: 50_000,
+                        unit: prmx_primitives::UnitV3::MmX1000,
+                    },
+                    early_trigger: true,
+                },
+                area: None,
+            },
+            coverage_start: 1_700_000_000,
+            coverage_end: 1_700_086_400,
+            shares: 10,
+            premium_paid: 5_000_000,
+            max_payout: 1_000_000_000,
+            rainfall: Vec::new(),
+            oracle_snapshot: None,
+            final_report: None,
+            trigger_log: None,
+            payout: None,
+        }
+    }
+
+    #[test]
+    fn test_version_leads_encoding() {
+        let encoded = dossier().encode();
+        assert_eq!(encoded[..2].to_vec(), DOSSIER_VERSION.to_le_bytes().to_vec());
+        assert_eq!(
+            SettlementDossier::<u64, u128>::decode(&mut &encoded[..]).unwrap(),
+            dossier()
+        );
+    }
+
+    #[test]
+    fn test_dossier_hash_binds_contents() {
+        let mut settled = dossier();
+        settled.payout = Some(PayoutBreakdown {
+            path: SettlementPath::V3Maturity,
+            event_occurred: false,
+            payout_to_holder: 0,
+            returned_to_lps: 1_005_000_000,
+            observed_mm: None,
+            evidence_hash: [0u8; 32],
+            settled_at: None,
+            settled_at_block: Some(42),
+            underwriters: Vec::new(),
+        });
+
+        assert_eq!(dossier_hash(&dossier()), dossier_hash(&dossier()));
+        assert_ne!(dossier_hash(&dossier()), dossier_hash(&settled));
+    }
+}
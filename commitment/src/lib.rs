@@ -0,0 +1,318 @@
+//! PRMX Commitment
+//!
+//! Canonical construction of the V3 observation commitment chain. The oracle-v3
+//! pallet and its OCW build chains with this crate (no_std), and anyone holding the
+//! evidence exported to the Ingest API can replay them off-chain (std) with
+//! [`verify_chain`], without depending on the pallet.
+//!
+//! ## Encoding spec
+//!
+//! All integers are little-endian, all hashes are BLAKE2b-256.
+//!
+//! - Seed: `H(b"prmx_v3:" || policy_id (16 bytes) || SCALE(event_spec) || location_id (u64)
+//!   || coverage_start (u64) || coverage_end (u64) [|| SCALE(area)])`. The area spec is
+//!   only appended for area policies.
+//! - Sample (33 bytes): `epoch_time (u64) || precip_1h_mm_x1000 (i64) || temp_c_x1000 (i64)
+//!   || wind_gust_mps_x1000 (i64) || precip_type_mask (u8)`
+//! - Sample hash: `H(sample)`
+//! - Storm fix (24 bytes, cyclone policies): `epoch_time (u64) || latitude (i32)
+//!   || longitude (i32) || max_wind_mps_x1000 (i64)`, coordinates scaled by 1e6
+//! - Storm fix hash: `H(b"prmx_v3_storm:" || storm_fix)`
+//! - Chain step: `commitment_n = H(commitment_{n-1} || sample_hash_n)`, starting from
+//!   the seed, over samples (or storm fix hashes) in the order the OCW committed them
+//! - Final report hash: `H(b"prmx_v3_final:" || policy_id (16 bytes) || SCALE(kind)
+//!   || observed_until (u64) || SCALE(agg_state) || commitment)`
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::Encode;
+use prmx_primitives::{AggStateV3, AreaSpecV3, EventSpecV3, OracleReportKindV3, PolicyId};
+use sp_io::hashing::blake2_256;
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Domain prefix of the chain seed
+pub const SEED_DOMAIN: &[u8] = b"prmx_v3:";
+
+/// Domain prefix of the final report hash
+pub const FINAL_REPORT_DOMAIN: &[u8] = b"prmx_v3_final:";
+
+/// Length of a canonically encoded sample
+pub const SAMPLE_ENCODED_LEN: usize = 33;
+
+/// Domain prefix of a storm fix hash
+pub const STORM_FIX_DOMAIN: &[u8] = b"prmx_v3_storm:";
+
+/// Length of a canonically encoded storm fix
+pub const STORM_FIX_ENCODED_LEN: usize = 24;
+
+// ============================================================================
+// Samples
+// ============================================================================
+
+/// Observation fields bound by the commitment chain
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Sample {
+    /// Unix epoch time of observation
+    pub epoch_time: u64,
+    /// Precipitation in past hour (mm * 1000)
+    pub precip_1h_mm_x1000: i64,
+    /// Temperature (celsius * 1000)
+    pub temp_c_x1000: i64,
+    /// Wind gust speed (m/s * 1000)
+    pub wind_gust_mps_x1000: i64,
+    /// Precipitation type bitmask
+    pub precip_type_mask: u8,
+}
+
+impl Sample {
+    /// Canonical byte encoding (see the crate docs)
+    pub fn to_canonical_bytes(&self) -> [u8; SAMPLE_ENCODED_LEN] {
+        let mut bytes = [0u8; SAMPLE_ENCODED_LEN];
+        bytes[0..8].copy_from_slice(&self.epoch_time.to_le_bytes());
+        bytes[8..16].copy_from_slice(&self.precip_1h_mm_x1000.to_le_bytes());
+        bytes[16..24].copy_from_slice(&self.temp_c_x1000.to_le_bytes());
+        bytes[24..32].copy_from_slice(&self.wind_gust_mps_x1000.to_le_bytes());
+        bytes[32] = self.precip_type_mask;
+        bytes
+    }
+
+    /// Decode a canonically encoded sample, `None` unless exactly 33 bytes
+    pub fn from_canonical_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != SAMPLE_ENCODED_LEN {
+            return None;
+        }
+        let word = |at: usize| {
+            let mut buf = [0u8; 8];
+            buf.copy_from_slice(&bytes[at..at + 8]);
+            buf
+        };
+        Some(Self {
+            epoch_time: u64::from_le_bytes(word(0)),
+            precip_1h_mm_x1000: i64::from_le_bytes(word(8)),
+            temp_c_x1000: i64::from_le_bytes(word(16)),
+            wind_gust_mps_x1000: i64::from_le_bytes(word(24)),
+            precip_type_mask: bytes[32],
+        })
+    }
+}
+
+/// Storm advisory position bound by the chain of a cyclone policy
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct StormFix {
+    /// Unix epoch time of the advisory
+    pub epoch_time: u64,
+    /// Storm center latitude (scaled by 1e6)
+    pub latitude: i32,
+    /// Storm center longitude (scaled by 1e6)
+    pub longitude: i32,
+    /// Maximum sustained wind (m/s * 1000)
+    pub max_wind_mps_x1000: i64,
+}
+
+impl StormFix {
+    /// Canonical byte encoding (see the crate docs)
+    pub fn to_canonical_bytes(&self) -> [u8; STORM_FIX_ENCODED_LEN] {
+        let mut bytes = [0u8; STORM_FIX_ENCODED_LEN];
+        bytes[0..8].copy_from_slice(&self.epoch_time.to_le_bytes());
+        bytes[8..12].copy_from_slice(&self.latitude.to_le_bytes());
+        bytes[12..16].copy_from_slice(&self.longitude.to_le_bytes());
+        bytes[16..24].copy_from_slice(&self.max_wind_mps_x1000.to_le_bytes());
+        bytes
+    }
+
+    /// Decode a canonically encoded storm fix, `None` unless exactly 24 bytes
+    pub fn from_canonical_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != STORM_FIX_ENCODED_LEN {
+            return None;
+        }
+        let mut epoch = [0u8; 8];
+        let mut latitude = [0u8; 4];
+        let mut longitude = [0u8; 4];
+        let mut wind = [0u8; 8];
+        epoch.copy_from_slice(&bytes[0..8]);
+        latitude.copy_from_slice(&bytes[8..12]);
+        longitude.copy_from_slice(&bytes[12..16]);
+        wind.copy_from_slice(&bytes[16..24]);
+        Some(Self {
+            epoch_time: u64::from_le_bytes(epoch),
+            latitude: i32::from_le_bytes(latitude),
+            longitude: i32::from_le_bytes(longitude),
+            max_wind_mps_x1000: i64::from_le_bytes(wind),
+        })
+    }
+}
+
+// ============================================================================
+// Chain
+// ============================================================================
+
+/// Seed of a policy's chain, binding it to the policy parameters
+pub fn seed(
+    policy_id: PolicyId,
+    event_spec: &EventSpecV3,
+    location_id: u64,
+    coverage_start: u64,
+    coverage_end: u64,
+    area: Option<&AreaSpecV3>,
+) -> [u8; 32] {
+    let mut data = Vec::new();
+    data.extend_from_slice(SEED_DOMAIN);
+    data.extend_from_slice(&policy_id.to_le_bytes());
+    event_spec.encode_to(&mut data);
+    data.extend_from_slice(&location_id.to_le_bytes());
+    data.extend_from_slice(&coverage_start.to_le_bytes());
+    data.extend_from_slice(&coverage_end.to_le_bytes());
+    if let Some(area) = area {
+        area.encode_to(&mut data);
+    }
+    blake2_256(&data)
+}
+
+/// Hash of one sample
+pub fn sample_hash(sample: &Sample) -> [u8; 32] {
+    blake2_256(&sample.to_canonical_bytes())
+}
+
+/// Extend the chain by one sample
+pub fn extend(current: [u8; 32], sample: &Sample) -> [u8; 32] {
+    let mut data = [0u8; 64];
+    data[..32].copy_from_slice(&current);
+    data[32..].copy_from_slice(&sample_hash(sample));
+    blake2_256(&data)
+}
+
+/// Hash of one storm fix
+pub fn storm_fix_hash(fix: &StormFix) -> [u8; 32] {
+    let mut data = Vec::with_capacity(STORM_FIX_DOMAIN.len() + STORM_FIX_ENCODED_LEN);
+    data.extend_from_slice(STORM_FIX_DOMAIN);
+    data.extend_from_slice(&fix.to_canonical_bytes());
+    blake2_256(&data)
+}
+
+/// Extend the chain by one storm fix
+pub fn extend_storm(current: [u8; 32], fix: &StormFix) -> [u8; 32] {
+    let mut data = [0u8; 64];
+    data[..32].copy_from_slice(&current);
+    data[32..].copy_from_slice(&storm_fix_hash(fix));
+    blake2_256(&data)
+}
+
+/// Replay the chain from `seed` over `samples` and return where it ends
+pub fn replay(seed: [u8; 32], samples: impl IntoIterator<Item = Sample>) -> [u8; 32] {
+    samples.into_iter().fold(seed, |current, sample| extend(current, &sample))
+}
+
+/// Whether replaying `samples` from `seed` ends at `expected`. A changed value, a
+/// dropped or extra sample, or a reordering all break the chain.
+pub fn verify_chain(
+    seed: [u8; 32],
+    samples: impl IntoIterator<Item = Sample>,
+    expected: [u8; 32],
+) -> bool {
+    replay(seed, samples) == expected
+}
+
+/// Hash oracle members attest to for a final report
+pub fn final_report_hash(
+    policy_id: PolicyId,
+    kind: OracleReportKindV3,
+    observed_until: u64,
+    agg_state: &AggStateV3,
+    commitment: &[u8; 32],
+) -> [u8; 32] {
+    let mut data = Vec::new();
+    data.extend_from_slice(FINAL_REPORT_DOMAIN);
+    data.extend_from_slice(&policy_id.to_le_bytes());
+    kind.encode_to(&mut data);
+    data.extend_from_slice(&observed_until.to_le_bytes());
+    agg_state.encode_to(&mut data);
+    data.extend_from_slice(commitment);
+    blake2_256(&data)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Sample {
+        Sample {
+            epoch_time: 1_700_000_000,
+            precip_1h_mm_x1000: 5_000,
+            temp_c_x1000: 25_000,
+            wind_gust_mps_x1000: 5_000,
+            precip_type_mask: 1,
+        }
+    }
+
+    fn hex(bytes: &[u8]) -> alloc::string::String {
+        bytes.iter().map(|b| alloc::format!("{:02x}", b)).collect()
+    }
+
+    #[test]
+    fn test_canonical_encoding_vector() {
+        let bytes = sample().to_canonical_bytes();
+        assert_eq!(
+            hex(&bytes),
+            "00f15365000000008813000000000000a861000000000000881300000000000001"
+        );
+        assert_eq!(Sample::from_canonical_bytes(&bytes), Some(sample()));
+        assert_eq!(Sample::from_canonical_bytes(&bytes[..32]), None);
+    }
+
+    #[test]
+    fn test_chain_vector() {
+        assert_eq!(
+            hex(&sample_hash(&sample())),
+            "8dbf9ca1b650ed6bca3cf242192beb781bb2db20d87dcc158a31d29e0f7dfa8c"
+        );
+        assert_eq!(
+            hex(&extend([0u8; 32], &sample())),
+            "52bdff529a6dcee37ae6d9f3046383dcef749fa7317693d7d09f6eb2d4d11e32"
+        );
+    }
+
+    #[test]
+    fn test_storm_fix_encoding_vector() {
+        let fix = StormFix {
+            epoch_time: 1_700_000_000,
+            latitude: 14_599_500,
+            longitude: 120_984_200,
+            max_wind_mps_x1000: 52_000,
+        };
+        let bytes = fix.to_canonical_bytes();
+        assert_eq!(hex(&bytes), "00f15365000000004cc5de008812360720cb000000000000");
+        assert_eq!(StormFix::from_canonical_bytes(&bytes), Some(fix));
+        assert_eq!(StormFix::from_canonical_bytes(&bytes[..23]), None);
+        assert_ne!(extend_storm([0u8; 32], &fix), extend([0u8; 32], &sample()));
+    }
+
+    #[test]
+    fn test_verify_chain() {
+        let seed = [3u8; 32];
+        let samples: Vec<Sample> = (0..3)
+            .map(|i| Sample {
+                epoch_time: 1_000 + i * 3_600,
+                precip_1h_mm_x1000: 2_000 * i as i64,
+                ..sample()
+            })
+            .collect();
+        let expected = replay(seed, samples.iter().copied());
+
+        assert!(verify_chain(seed, samples.iter().copied(), expected));
+        assert!(!verify_chain(seed, samples[..2].iter().copied(), expected));
+
+        let mut reordered = samples.clone();
+        reordered.swap(0, 2);
+        assert!(!verify_chain(seed, reordered, expected));
+    }
+}
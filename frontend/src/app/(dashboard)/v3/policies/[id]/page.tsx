@@ -156,6 +156,8 @@ function formatAggStateValue(aggState: V3AggState): string {
       return `${(aggState.maxMpsX1000 / 1000).toFixed(1)} m/s`;
     case 'PrecipTypeOccurred':
       return aggState.mask === 0 ? 'None' : `Mask: ${aggState.mask}`;
+    case 'ConsecutiveDryHours':
+      return `${aggState.longestHours} h (current run ${aggState.currentHours} h)`;
     default:
       return 'N/A';
   }
@@ -169,6 +171,7 @@ function getAggStateLabel(aggState: V3AggState): string {
     case 'TempMin': return 'Min Temperature';
     case 'WindGustMax': return 'Max Wind Gust';
     case 'PrecipTypeOccurred': return 'Precipitation Types';
+    case 'ConsecutiveDryHours': return 'Longest Dry Spell';
     default: return 'Current Value';
   }
 }
@@ -1436,6 +1439,9 @@ function getThresholdProximity(aggState: V3AggState, threshold: number): number
     case 'PrecipTypeOccurred':
       // Binary check - if any bit matches threshold mask, it's 100%
       return (aggState.mask & threshold) ? 100 : 0;
+    case 'ConsecutiveDryHours':
+      currentValue = aggState.longestHours;
+      break;
     default:
       return 0;
   }
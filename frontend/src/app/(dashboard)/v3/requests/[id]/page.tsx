@@ -1,6 +1,6 @@
 'use client';
 
-import { useState, useMemo } from 'react';
+import { useState, useMemo, useEffect } from 'react';
 import { useParams, useRouter } from 'next/navigation';
 import { 
   ArrowLeft,
@@ -31,7 +31,14 @@ import { Input } from '@/components/ui/Input';
 import { useWalletStore, useIsDao } from '@/stores/walletStore';
 import { useV3Request } from '@/hooks/useV3ChainData';
 import { WalletConnectionModal } from '@/components/features/WalletConnectionModal';
-import { acceptV3Request, cancelV3Request, expireV3Request, formatId } from '@/lib/api-v3';
+import {
+  acceptV3Request,
+  cancelV3Request,
+  expireV3Request,
+  formatId,
+  getV3CollateralAccount,
+  V3CollateralAccount
+} from '@/lib/api-v3';
 import { 
   V3RequestStatus,
   getEventTypeInfo, 
@@ -122,6 +129,7 @@ export default function V3RequestDetailPage() {
   const [isExpiring, setIsExpiring] = useState(false);
   const [isRefreshing, setIsRefreshing] = useState(false);
   const [copiedAddress, setCopiedAddress] = useState(false);
+  const [collateralAccount, setCollateralAccount] = useState<V3CollateralAccount | null>(null);
   
   const eventInfo = useMemo(() => 
     request ? getEventTypeInfo(request.eventSpec.eventType) : null, 
@@ -160,6 +168,25 @@ export default function V3RequestDetailPage() {
     [sharesToAccept]
   );
   
+  // Load the underwriter's collateral account; accepting draws on it first
+  useEffect(() => {
+    if (!selectedAccount) {
+      setCollateralAccount(null);
+      return;
+    }
+    getV3CollateralAccount(selectedAccount.address)
+      .then(setCollateralAccount)
+      .catch(() => setCollateralAccount(null));
+  }, [selectedAccount, request?.filledShares]);
+  
+  // Collateral the chain moves from the wallet when the account cannot cover the fill
+  const collateralFromWallet = useMemo(() => {
+    const deposited = collateralAccount?.deposited ?? BigInt(0);
+    const obligations = collateralAccount?.obligations ?? BigInt(0);
+    const required = obligations + collateralNeeded;
+    return required > deposited ? required - deposited : BigInt(0);
+  }, [collateralAccount, collateralNeeded]);
+  
   const premiumToEarn = useMemo(() =>
     request ? BigInt(sharesToAccept) * request.premiumPerShare : BigInt(0),
     [request, sharesToAccept]
@@ -859,6 +886,10 @@ export default function V3RequestDetailPage() {
                           <span className="text-sm text-text-secondary">Collateral</span>
                           <span className="text-sm font-medium">{formatUSDT(collateralNeeded, false)}</span>
                         </div>
+                        <div className="flex items-center justify-between py-2 px-3 rounded-lg bg-background-tertiary/20">
+                          <span className="text-sm text-text-secondary">From Wallet</span>
+                          <span className="text-sm font-medium">{formatUSDT(collateralFromWallet, false)}</span>
+                        </div>
                         <div className="flex items-center justify-between py-2 px-3 rounded-lg bg-background-tertiary/20">
                           <span className="text-sm text-text-secondary">Premium</span>
                           <span className="text-sm font-medium text-emerald-500">−{formatUSDT(premiumToEarn, false)}</span>
@@ -29,6 +29,7 @@ import {
   V3EventType,
   V3ThresholdUnit,
   formatThresholdValue,
+  thresholdScale,
   getEventTypeInfo 
 } from '@/types/v3';
 import { formatUSDT, cn } from '@/lib/utils';
@@ -350,12 +351,12 @@ export default function NewV3RequestPage() {
                     <div className="flex items-center gap-4">
                       <Input
                         type="number"
-                        value={thresholdValue / 1000}
-                        onChange={(e) => setThresholdValue(Math.round(parseFloat(e.target.value) * 1000))}
+                        value={thresholdValue / thresholdScale(eventInfo.unit)}
+                        onChange={(e) => setThresholdValue(Math.round(parseFloat(e.target.value) * thresholdScale(eventInfo.unit)))}
                         className="max-w-xs"
-                        min={eventInfo.minThreshold / 1000}
-                        max={eventInfo.maxThreshold / 1000}
-                        step={0.1}
+                        min={eventInfo.minThreshold / thresholdScale(eventInfo.unit)}
+                        max={eventInfo.maxThreshold / thresholdScale(eventInfo.unit)}
+                        step={thresholdScale(eventInfo.unit) === 1 ? 1 : 0.1}
                       />
                       <span className="text-text-secondary">{eventInfo.unitLabel}</span>
                     </div>
@@ -13,10 +13,10 @@ import {
   Filler,
 } from 'chart.js';
 import { Line } from 'react-chartjs-2';
-import { CloudRain, Thermometer, Wind, Droplets } from 'lucide-react';
+import { CloudRain, Thermometer, Wind, Droplets, Sun } from 'lucide-react';
 import { Card, CardContent, CardHeader } from '@/components/ui/Card';
 import { useThemeStore } from '@/stores/themeStore';
-import type { V3EventType } from '@/types/v3';
+import { thresholdScale, type V3EventType } from '@/types/v3';
 import type { V3Observation } from '@/lib/api-v3';
 
 ChartJS.register(
@@ -57,6 +57,8 @@ function getEventIcon(eventType: V3EventType) {
       return Thermometer;
     case 'WindGustMaxGte':
       return Wind;
+    case 'DryStreakGte':
+      return Sun;
     default:
       return CloudRain;
   }
@@ -75,7 +77,7 @@ interface WeatherHistoryChartProps {
 
 /**
  * Extract value from observation based on event type
- * For cumulative types, we show the running sum
+ * For cumulative types, we show the running sum (or the current dry run)
  */
 function getObservationValue(
   obs: V3Observation, 
@@ -97,6 +99,9 @@ function getObservationValue(
       return fields.temp_c_x1000 ? fields.temp_c_x1000 / 1000 : null;
     case 'WindGustMaxGte':
       return fields.wind_gust_mps_x1000 ? fields.wind_gust_mps_x1000 / 1000 : null;
+    case 'DryStreakGte':
+      // Hours under 0.1mm extend the dry run, anything wetter resets it
+      return (fields.precip_1h_mm_x1000 ?? 0) < 100 ? previousSum + 1 : 0;
     default:
       return null;
   }
@@ -115,6 +120,8 @@ function getUnitLabel(eventType: V3EventType): string {
       return '°C';
     case 'WindGustMaxGte':
       return 'm/s';
+    case 'DryStreakGte':
+      return 'h';
     default:
       return '';
   }
@@ -135,6 +142,8 @@ function getDataLabel(eventType: V3EventType): string {
       return 'Min Temperature';
     case 'WindGustMaxGte':
       return 'Wind Gust';
+    case 'DryStreakGte':
+      return 'Dry Hours in a Row';
     default:
       return 'Value';
   }
@@ -183,7 +192,7 @@ export function WeatherHistoryChart({
       );
       
       const value = getObservationValue(obs, eventType, runningSum);
-      if (value !== null && eventType === 'PrecipSumGte') {
+      if (value !== null && (eventType === 'PrecipSumGte' || eventType === 'DryStreakGte')) {
         runningSum = value; // Update running sum for cumulative
       }
       values.push(value);
@@ -206,11 +215,11 @@ export function WeatherHistoryChart({
           pointBorderWidth: 2,
           borderWidth: 2.5,
         },
-        // Threshold line - divide by 1000 to match observation values
-        // (threshold is stored as value * 1000 to avoid decimals on-chain)
+        // Threshold line - scale to match observation values
+        // (most thresholds are stored as value * 1000 to avoid decimals on-chain)
         {
           label: 'Threshold',
-          data: filteredObservations.map(() => threshold / 1000),
+          data: filteredObservations.map(() => threshold / thresholdScale(thresholdUnit)),
           borderColor: colors.threshold,
           backgroundColor: colors.thresholdBg,
           borderWidth: 2,
@@ -220,7 +229,7 @@ export function WeatherHistoryChart({
         },
       ],
     };
-  }, [filteredObservations, eventType, threshold, colors, theme]);
+  }, [filteredObservations, eventType, threshold, thresholdUnit, colors, theme]);
 
   const chartOptions = useMemo(() => ({
     responsive: true,
@@ -314,7 +323,7 @@ export function WeatherHistoryChart({
 
   const EventIcon = getEventIcon(eventType);
   const unit = getUnitLabel(eventType);
-  const thresholdDisplay = threshold / 1000;
+  const thresholdDisplay = threshold / thresholdScale(thresholdUnit);
 
   if (loading) {
     return (
@@ -302,6 +302,9 @@ export async function createV3Request(
 
 /**
  * Accept shares from an underwrite request
+ *
+ * The fill is backed by the caller's collateral account; whatever the account
+ * lacks is moved into it from the caller's wallet, so no prior deposit is needed.
  */
 export async function acceptV3Request(
   keypair: KeyringPair,
@@ -318,6 +321,60 @@ export async function acceptV3Request(
   await signAndWaitV3(tx, keypair);
 }
 
+/**
+ * Underwriter collateral account (fills on open requests are backed by it)
+ */
+export interface V3CollateralAccount {
+  deposited: bigint;
+  obligations: bigint;
+  positions: number;
+}
+
+/**
+ * Get an underwriter's collateral account (null if it has none)
+ */
+export async function getV3CollateralAccount(address: string): Promise<V3CollateralAccount | null> {
+  const api = await getApi();
+  const result = await api.query.prmxMarketV3.underwriterCollateral(address);
+
+  if (result.isNone) return null;
+
+  const account = result.unwrap();
+  return {
+    deposited: BigInt(account.deposited.toString()),
+    obligations: BigInt(account.obligations.toString()),
+    positions: account.positions.toNumber(),
+  };
+}
+
+/**
+ * Add collateral to the caller's collateral account
+ */
+export async function depositV3Collateral(
+  keypair: KeyringPair,
+  amount: bigint
+): Promise<void> {
+  const api = await getApi();
+
+  const tx = api.tx.prmxMarketV3.depositCollateral(amount.toString());
+
+  await signAndWaitV3(tx, keypair);
+}
+
+/**
+ * Withdraw free collateral from the caller's collateral account
+ */
+export async function withdrawV3Collateral(
+  keypair: KeyringPair,
+  amount: bigint
+): Promise<void> {
+  const api = await getApi();
+
+  const tx = api.tx.prmxMarketV3.withdrawCollateral(amount.toString());
+
+  await signAndWaitV3(tx, keypair);
+}
+
 /**
  * Cancel an underwrite request (requester only)
  */
@@ -769,6 +826,8 @@ function normalizeEventType(raw: string): string {
     'temp_min_lte': 'TempMinLte',
     'wind_gust_max_gte': 'WindGustMaxGte',
     'precip_type_occurred': 'PrecipTypeOccurred',
+    'dry_streak_gte': 'DryStreakGte',
+    'cyclone_within_gte': 'CycloneWithinGte',
     // Already PascalCase
     'PrecipSumGte': 'PrecipSumGte',
     'Precip1hGte': 'Precip1hGte',
@@ -776,6 +835,8 @@ function normalizeEventType(raw: string): string {
     'TempMinLte': 'TempMinLte',
     'WindGustMaxGte': 'WindGustMaxGte',
     'PrecipTypeOccurred': 'PrecipTypeOccurred',
+    'DryStreakGte': 'DryStreakGte',
+    'CycloneWithinGte': 'CycloneWithinGte',
   };
   
   return mapping[raw] || raw;
@@ -789,11 +850,15 @@ function normalizeThresholdUnit(raw: string): string {
     'celsius_x1000': 'CelsiusX1000',
     'mps_x1000': 'MpsX1000',
     'precip_type_mask': 'PrecipTypeMask',
+    'hours': 'Hours',
+    'cyclone_category_within_km': 'CycloneCategoryWithinKm',
     // Already PascalCase
     'MmX1000': 'MmX1000',
     'CelsiusX1000': 'CelsiusX1000',
     'MpsX1000': 'MpsX1000',
     'PrecipTypeMask': 'PrecipTypeMask',
+    'Hours': 'Hours',
+    'CycloneCategoryWithinKm': 'CycloneCategoryWithinKm',
   };
   
   return mapping[raw] || raw;
@@ -846,6 +911,18 @@ function parseAggState(aggState: any): V3AggState {
         type: 'PrecipTypeOccurred', 
         mask: parseNum(stateValue.mask ?? stateValue)
       };
+    case 'ConsecutiveDryHours':
+      return {
+        type: 'ConsecutiveDryHours',
+        currentHours: parseNum(stateValue.current_hours ?? stateValue.currentHours),
+        longestHours: parseNum(stateValue.longest_hours ?? stateValue.longestHours),
+      };
+    case 'CycloneApproach':
+      return {
+        type: 'CycloneApproach',
+        closestKm: parseNum(stateValue.closest_km ?? stateValue.closestKm),
+        peakCategory: parseNum(stateValue.peak_category ?? stateValue.peakCategory),
+      };
     default:
       console.warn('Unknown aggState type:', stateType, aggState);
       return { type: 'PrecipSum', sumMmX1000: 0 };
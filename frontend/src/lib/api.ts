@@ -7,7 +7,7 @@
 
 import { ApiPromise, WsProvider, Keyring } from '@polkadot/api';
 import { KeyringPair } from '@polkadot/keyring/types';
-import type { Market, Policy, QuoteRequest, QuoteResult, LpHolding, LpAskOrder, RainfallData, V2Monitor, V2MonitorStats } from '@/types';
+import type { Market, Policy, QuoteRequest, QuoteResult, LpHolding, LpAskOrder, RainfallData, V2Monitor, V2MonitorStats, PayoutTier, EventType } from '@/types';
 
 // Constants
 export const WS_ENDPOINT = process.env.NEXT_PUBLIC_WS_ENDPOINT || 'ws://localhost:9944';
@@ -436,7 +436,7 @@ export async function getQuoteRequests(): Promise<QuoteRequest[]> {
       result,
       // V2 fields (default to V1 for backwards compatibility)
       policyVersion: (data.policyVersion?.toString() || 'V1') as 'V1' | 'V2',
-      eventType: (data.eventType?.toString() || 'Rainfall24hRolling') as 'Rainfall24hRolling' | 'CumulativeRainfallWindow',
+      eventType: (data.eventType?.toString() || 'Rainfall24hRolling') as EventType,
       earlyTrigger: data.earlyTrigger ?? false,
       durationDays: data.durationDays ?? 0,
     };
@@ -548,7 +548,7 @@ export async function requestQuote(
 }
 
 /**
- * Request a V2 policy quote (cumulative rainfall, early trigger)
+ * Request a V2 policy quote (cumulative rainfall, 24h snowfall or rainfall at river flood stage, early trigger)
  * V2 policies are only available for Manila market with 2-7 day duration.
  */
 export async function requestQuoteV2(
@@ -561,7 +561,14 @@ export async function requestQuoteV2(
     longitude: number;
     shares: number;
     durationDays: number;    // 2-7 days
+    eventType?: 'CumulativeRainfallWindow' | 'Snowfall24h' | 'FloodComposite';  // Defaults to rainfall
     strikeMm: number;        // Custom strike threshold in mm (1-300)
+    perils?: {               // Additional perils (wind gust, temperature) beside the strike
+      eventType: string;     // e.g. 'WindGustMaxGte', 'TempMaxGte'
+      threshold: { value: number; unit: string };  // value scaled by 1000
+      earlyTrigger: boolean;
+    }[];
+    perilAggregation?: 'Any' | 'All';  // Trigger on any peril (default) or only all
   }
 ): Promise<string> {
   const api = await getApi();
@@ -598,7 +605,10 @@ export async function requestQuoteV2(
       params.longitude,
       params.shares,
       params.durationDays,
-      scaledStrike
+      params.eventType ?? 'CumulativeRainfallWindow',
+      scaledStrike,
+      params.perils ?? [],
+      params.perilAggregation ?? 'Any'
     ).signAndSend(signer, async ({ status, events, dispatchError }) => {
       console.log('V2 Quote status update:', status.type);
       
@@ -661,7 +671,8 @@ export async function requestQuoteV2(
 /**
  * Submit quote result (simulates off-chain worker)
  * In production, the off-chain worker would calculate this from rainfall data.
- * For testing, we submit a mock probability.
+ * For testing, we submit a mock probability through the governance override,
+ * so `signer` must be the sudo account (Alice on dev chains).
  */
 export async function submitQuote(
   signer: KeyringPair,
@@ -671,7 +682,7 @@ export async function submitQuote(
   const api = await getApi();
   
   return new Promise((resolve, reject) => {
-    api.tx.prmxQuote.submitQuote(quoteId, probabilityPpm)
+    api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(quoteId, probabilityPpm))
       .signAndSend(signer, ({ status, dispatchError }) => {
         if (dispatchError) {
           if (dispatchError.isModule) {
@@ -733,10 +744,14 @@ export async function getPolicies(): Promise<Policy[]> {
       createdAt: data.createdAt || data.coverageStart,
       // V2 fields (default to V1 for backwards compatibility)
       policyVersion: (data.policyVersion?.toString() || 'V1') as 'V1' | 'V2',
-      eventType: (data.eventType?.toString() || 'Rainfall24hRolling') as 'Rainfall24hRolling' | 'CumulativeRainfallWindow',
+      eventType: (data.eventType?.toString() || 'Rainfall24hRolling') as EventType,
       earlyTrigger: data.earlyTrigger ?? false,
       oracleStatusV2: data.oracleStatusV2?.toString() as 'PendingMonitoring' | 'Monitoring' | 'TriggeredReported' | 'MaturedReported' | 'Settled' | undefined,
       strikeMm: data.strikeMm,
+      payoutTiers: (data.payoutTiers || []).map((tier: any) => ({
+        thresholdMm: Number(tier.thresholdMm),
+        payoutBp: Number(tier.payoutBp),
+      })),
     };
   });
 }
@@ -808,16 +823,23 @@ export async function getPolicyPoolInfo(policyId: string): Promise<{
 }
 
 /**
- * Apply coverage using a quote
+ * Apply coverage using a quote, optionally with a tiered payout table
  */
 export async function applyCoverage(
   signer: KeyringPair,
-  quoteId: string
+  quoteId: string,
+  payoutTiers: PayoutTier[] = []
 ): Promise<string> {
   const api = await getApi();
+  const tx = payoutTiers.length > 0
+    ? api.tx.prmxPolicy.applyCoverageWithTiers(
+        quoteId,
+        payoutTiers.map((tier) => ({ thresholdMm: tier.thresholdMm, payoutBp: tier.payoutBp }))
+      )
+    : api.tx.prmxPolicy.applyCoverageWithQuote(quoteId);
   
   return new Promise((resolve, reject) => {
-    api.tx.prmxPolicy.applyCoverageWithQuote(quoteId)
+    tx
       .signAndSend(signer, ({ status, events, dispatchError }) => {
         if (dispatchError) {
           if (dispatchError.isModule) {
@@ -873,6 +895,64 @@ export async function settlePolicy(
   });
 }
 
+/**
+ * Transfer a policy to another account (new holder receives any payout)
+ */
+export async function transferPolicy(
+  signer: KeyringPair,
+  policyId: string,
+  newHolder: string
+): Promise<string> {
+  const api = await getApi();
+  
+  return new Promise((resolve, reject) => {
+    api.tx.prmxPolicy.transferPolicy(policyId, newHolder)
+      .signAndSend(signer, ({ status, dispatchError }) => {
+        if (dispatchError) {
+          if (dispatchError.isModule) {
+            const decoded = api.registry.findMetaError(dispatchError.asModule);
+            reject(new Error(`${decoded.section}.${decoded.name}: ${decoded.docs.join(' ')}`));
+          } else {
+            reject(new Error(dispatchError.toString()));
+          }
+          return;
+        }
+        if (status.isFinalized) {
+          resolve(status.asFinalized.toHex());
+        }
+      });
+  });
+}
+
+/**
+ * Cancel a policy for a partial premium refund (before coverage starts, or
+ * prorated during coverage if governance allows)
+ */
+export async function cancelPolicy(
+  signer: KeyringPair,
+  policyId: string
+): Promise<string> {
+  const api = await getApi();
+  
+  return new Promise((resolve, reject) => {
+    api.tx.prmxPolicy.cancelPolicy(policyId)
+      .signAndSend(signer, ({ status, dispatchError }) => {
+        if (dispatchError) {
+          if (dispatchError.isModule) {
+            const decoded = api.registry.findMetaError(dispatchError.asModule);
+            reject(new Error(`${decoded.section}.${decoded.name}: ${decoded.docs.join(' ')}`));
+          } else {
+            reject(new Error(dispatchError.toString()));
+          }
+          return;
+        }
+        if (status.isFinalized) {
+          resolve(status.asFinalized.toHex());
+        }
+      });
+  });
+}
+
 // ============================================================================
 // Holdings Pallet (LP Tokens)
 // ============================================================================
@@ -1332,12 +1412,11 @@ export async function getHourlyBuckets(marketId: number): Promise<HourlyBucket[]
     // This is more reliable than predicting hour indices based on browser time
     const entries = await api.query.prmxOracle.hourlyBuckets.entries(marketId);
     
-    for (const [key, value] of entries) {
-      // Extract hour index from the key (it's the second key in the StorageDoubleMap)
-      const hourIdx = Number((key.args[1] as any).toString());
-      
+    for (const [, value] of entries) {
       if (!(value as any).isNone) {
         const data = (value as any).toJSON();
+        // Buckets are keyed by ring slot (hour_index % slots); the hour is in the value
+        const hourIdx = Number(data.hourIndex ?? data.hour_index ?? 0);
         // Handle both camelCase and snake_case
         const mm = data.mm ?? 0;
         const fetchedAt = data.fetchedAt ?? data.fetched_at ?? 0;
@@ -1411,6 +1490,8 @@ export interface ThresholdTriggerLog {
   payoutAmount: string; // u128 as string
   centerLatitude: number;
   centerLongitude: number;
+  observationRoot: string; // Merkle root of the hourly observations used (hex)
+  observationCount: number;
 }
 
 /**
@@ -1446,6 +1527,8 @@ export async function getThresholdTriggerLogs(): Promise<ThresholdTriggerLog[]>
               payoutAmount: String(data.payoutAmount ?? data.payout_amount ?? '0'),
               centerLatitude: data.centerLatitude ?? data.center_latitude ?? 0,
               centerLongitude: data.centerLongitude ?? data.center_longitude ?? 0,
+              observationRoot: data.observationRoot ?? data.observation_root ?? '',
+              observationCount: data.observationCount ?? data.observation_count ?? 0,
             });
           }
         }
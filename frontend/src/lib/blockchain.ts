@@ -127,12 +127,16 @@ export function calculateRequiredCapital(
  */
 export function formatMarketStatus(status: string): { label: string; color: string } {
   switch (status.toLowerCase()) {
+    case 'draft':
+      return { label: 'Draft', color: 'default' };
     case 'open':
       return { label: 'Open', color: 'success' };
-    case 'closed':
-      return { label: 'Closed', color: 'warning' };
-    case 'settled':
-      return { label: 'Settled', color: 'info' };
+    case 'paused':
+      return { label: 'Paused', color: 'warning' };
+    case 'closing':
+      return { label: 'Closing', color: 'warning' };
+    case 'archived':
+      return { label: 'Archived', color: 'info' };
     default:
       return { label: status, color: 'default' };
   }
@@ -13,7 +13,7 @@ export interface Market {
   accuWeatherLocationKey?: string;
 }
 
-export type MarketStatus = 'Open' | 'Closed' | 'Settled';
+export type MarketStatus = 'Draft' | 'Open' | 'Paused' | 'Closing' | 'Archived';
 
 export interface RiskParameters {
   daoMarginBp: number; // basis points, e.g., 2000 = 20%
@@ -45,11 +45,18 @@ export interface Policy {
   earlyTrigger: boolean;
   oracleStatusV2?: V2OracleStatus;
   strikeMm?: number;
+  payoutTiers?: PayoutTier[]; // empty/absent = all-or-nothing payout at strike
+}
+
+// Tiered payout: share of max payout once the observed metric reaches the threshold
+export interface PayoutTier {
+  thresholdMm: number; // tenths of mm
+  payoutBp: number;    // basis points of max payout (10000 = 100%)
 }
 
 // V2 Policy Types
 export type PolicyVersion = 'V1' | 'V2';
-export type EventType = 'Rainfall24hRolling' | 'CumulativeRainfallWindow';
+export type EventType = 'Rainfall24hRolling' | 'CumulativeRainfallWindow' | 'Snowfall24h' | 'FloodComposite';
 export type V2OracleStatus = 'PendingMonitoring' | 'Monitoring' | 'TriggeredReported' | 'MaturedReported' | 'Settled';
 export type V2Outcome = 'Triggered' | 'MaturedNoEvent';
 
@@ -233,8 +240,9 @@ export interface V2Monitor {
   strike_mm: number;        // Strike threshold in mm (scaled by 10)
   lat: number;
   lon: number;
+  event_type?: 'CumulativeRainfallWindow' | 'Snowfall24h' | 'FloodComposite';  // Absent on older rainfall monitors
   state: V2MonitorState;
-  cumulative_mm: number;    // Current cumulative rainfall (scaled by 10)
+  cumulative_mm: number;    // Cumulative rainfall, highest 24h snowfall (Snowfall24h) or 24h rainfall at flood stage (FloodComposite), scaled by 10
   trigger_time?: number;    // Unix timestamp when triggered
   last_fetch_at: number;    // Unix timestamp of last AccuWeather fetch
   location_key: string;     // AccuWeather location key
@@ -12,13 +12,17 @@ export type V3EventType =
   | 'TempMaxGte'        // Max temperature >= threshold
   | 'TempMinLte'        // Min temperature <= threshold
   | 'WindGustMaxGte'    // Max wind gust >= threshold
-  | 'PrecipTypeOccurred'; // Specific precipitation type occurred
+  | 'PrecipTypeOccurred' // Specific precipitation type occurred
+  | 'DryStreakGte'      // Longest run of dry hours >= threshold
+  | 'CycloneWithinGte'; // Cyclone of >= category passed within radius
 
 export type V3ThresholdUnit = 
   | 'MmX1000'      // Precipitation in mm * 1000
   | 'CelsiusX1000' // Temperature in Celsius * 1000
   | 'MpsX1000'     // Wind speed in m/s * 1000
-  | 'PrecipTypeMask'; // Bitmask for precipitation types
+  | 'PrecipTypeMask' // Bitmask for precipitation types
+  | 'Hours'        // Whole hours
+  | 'CycloneCategoryWithinKm'; // radius_km * 10 + Saffir-Simpson category
 
 export interface V3Threshold {
   value: number;
@@ -111,6 +115,17 @@ export const V3_EVENT_TYPES: V3EventTypeInfo[] = [
     maxThreshold: 255,
     icon: '🌨️',
   },
+  {
+    type: 'DryStreakGte',
+    label: 'Dry Spell',
+    description: 'Triggers when consecutive hours with under 0.1mm of rain reach threshold',
+    unit: 'Hours',
+    unitLabel: 'hours',
+    defaultThreshold: 240, // 10 days
+    minThreshold: 24,
+    maxThreshold: 720,
+    icon: '☀️',
+  },
 ];
 
 // =============================================================================
@@ -209,7 +224,9 @@ export type V3AggState =
   | { type: 'TempMax'; maxCX1000: number }
   | { type: 'TempMin'; minCX1000: number }
   | { type: 'WindGustMax'; maxMpsX1000: number }
-  | { type: 'PrecipTypeOccurred'; mask: number };
+  | { type: 'PrecipTypeOccurred'; mask: number }
+  | { type: 'ConsecutiveDryHours'; currentHours: number; longestHours: number }
+  | { type: 'CycloneApproach'; closestKm: number; peakCategory: number };
 
 export interface V3OracleState {
   policyId: string; // H128 hash ID as hex string
@@ -250,6 +267,11 @@ export interface V3Acceptance {
 // Utility Functions
 // =============================================================================
 
+// Scale between on-chain threshold values and the units shown in forms
+export function thresholdScale(unit: V3ThresholdUnit | string): number {
+  return /x1000$/i.test(unit) ? 1000 : 1;
+}
+
 export function formatThresholdValue(value: number, unit: V3ThresholdUnit | string): string {
   // Normalize unit to handle different formats from chain
   const normalizedUnit = normalizeUnit(unit);
@@ -263,6 +285,8 @@ export function formatThresholdValue(value: number, unit: V3ThresholdUnit | stri
       return `${(value / 1000).toFixed(1)} m/s`;
     case 'PrecipTypeMask':
       return `mask ${value}`;
+    case 'Hours':
+      return `${value} h`;
     default:
       // Fallback: if unit contains mm, celsius, mps, format accordingly
       if (unit.toLowerCase().includes('mm')) {
@@ -285,10 +309,12 @@ function normalizeUnit(unit: string): string {
     'celsius_x1000': 'CelsiusX1000',
     'mps_x1000': 'MpsX1000',
     'precip_type_mask': 'PrecipTypeMask',
+    'hours': 'Hours',
     'MmX1000': 'MmX1000',
     'CelsiusX1000': 'CelsiusX1000',
     'MpsX1000': 'MpsX1000',
     'PrecipTypeMask': 'PrecipTypeMask',
+    'Hours': 'Hours',
   };
   return mapping[unit] || unit;
 }
@@ -0,0 +1,433 @@
+//! PRMX JSON
+//!
+//! Minimal no_std JSON parser shared by the offchain workers. Responses are parsed
+//! into a [`Value`] tree and then read into the typed structs in [`responses`], so
+//! nested payloads can no longer be mis-read by substring matching.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod responses;
+
+use alloc::{string::String, vec::Vec};
+
+pub use responses::{
+    GeopositionResponse, HistoricalObservation, RPricingAttestation, RPricingResponse,
+};
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Maximum nesting of arrays/objects accepted by the parser
+pub const MAX_DEPTH: usize = 32;
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+/// Error returned when a response cannot be parsed or is missing a field
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum JsonError {
+    /// Input is not valid UTF-8
+    InvalidEncoding,
+    /// Input ended in the middle of a value
+    UnexpectedEnd,
+    /// Unexpected character at the given byte offset
+    UnexpectedChar(usize),
+    /// Malformed number literal
+    InvalidNumber,
+    /// Malformed string escape sequence
+    InvalidEscape,
+    /// Nesting deeper than `MAX_DEPTH`
+    TooDeep,
+    /// Non-whitespace input after the top-level value
+    TrailingCharacters,
+    /// Required field is absent
+    MissingField(&'static str),
+    /// Field is present but has the wrong type or an out-of-range value
+    InvalidField(&'static str),
+}
+
+impl JsonError {
+    /// Static description, for callers that report errors as `&'static str`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            JsonError::InvalidEncoding => "Invalid JSON encoding",
+            JsonError::UnexpectedEnd => "Unexpected end of JSON input",
+            JsonError::UnexpectedChar(_) => "Unexpected character in JSON input",
+            JsonError::InvalidNumber => "Invalid JSON number",
+            JsonError::InvalidEscape => "Invalid JSON string escape",
+            JsonError::TooDeep => "JSON nesting too deep",
+            JsonError::TrailingCharacters => "Trailing characters after JSON value",
+            JsonError::MissingField(field) | JsonError::InvalidField(field) => field,
+        }
+    }
+}
+
+impl From<JsonError> for &'static str {
+    fn from(error: JsonError) -> Self {
+        error.as_str()
+    }
+}
+
+// ============================================================================
+// Value
+// ============================================================================
+
+/// Parsed JSON value. Object members keep their document order.
+#[derive(Clone, PartialEq, Debug)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+impl Value {
+    /// Member of an object by key (first match), `None` for other values
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Object(members) => members.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    /// Follow a path of object keys, e.g. `["Temperature", "Metric", "Value"]`
+    pub fn pointer(&self, path: &[&str]) -> Option<&Value> {
+        path.iter().try_fold(self, |value, key| value.get(key))
+    }
+
+    /// Follow a dotted path where numeric segments index arrays, e.g.
+    /// `items.0.latestReading.value`. An empty path selects the value itself.
+    pub fn select(&self, path: &str) -> Option<&Value> {
+        if path.is_empty() {
+            return Some(self);
+        }
+        path.split('.').try_fold(self, |value, segment| match value {
+            Value::Array(items) => segment.parse::<usize>().ok().and_then(|i| items.get(i)),
+            _ => value.get(segment),
+        })
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Value::Number(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    /// Non-negative integral number
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            Value::Number(n) if *n >= 0.0 && *n <= u64::MAX as f64 && *n == (*n as u64) as f64 => {
+                Some(*n as u64)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[Value]> {
+        match self {
+            Value::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    pub fn is_null(&self) -> bool {
+        matches!(self, Value::Null)
+    }
+}
+
+// ============================================================================
+// Parser
+// ============================================================================
+
+/// Parse a complete JSON document
+pub fn parse(json: &[u8]) -> Result<Value, JsonError> {
+    let text = core::str::from_utf8(json).map_err(|_| JsonError::InvalidEncoding)?;
+    parse_str(text)
+}
+
+/// Parse a complete JSON document from a string
+pub fn parse_str(text: &str) -> Result<Value, JsonError> {
+    let mut parser = Parser { bytes: text.as_bytes(), pos: 0 };
+    let value = parser.value(0)?;
+    parser.skip_whitespace();
+    if parser.pos != parser.bytes.len() {
+        return Err(JsonError::TrailingCharacters);
+    }
+    Ok(value)
+}
+
+struct Parser<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn value(&mut self, depth: usize) -> Result<Value, JsonError> {
+        if depth > MAX_DEPTH {
+            return Err(JsonError::TooDeep);
+        }
+
+        self.skip_whitespace();
+        match self.peek()? {
+            b'{' => self.object(depth),
+            b'[' => self.array(depth),
+            b'"' => self.string().map(Value::String),
+            b't' => self.literal("true", Value::Bool(true)),
+            b'f' => self.literal("false", Value::Bool(false)),
+            b'n' => self.literal("null", Value::Null),
+            b'-' | b'0'..=b'9' => self.number(),
+            _ => Err(JsonError::UnexpectedChar(self.pos)),
+        }
+    }
+
+    fn object(&mut self, depth: usize) -> Result<Value, JsonError> {
+        self.expect(b'{')?;
+        let mut members = Vec::new();
+
+        self.skip_whitespace();
+        if self.peek()? == b'}' {
+            self.pos += 1;
+            return Ok(Value::Object(members));
+        }
+
+        loop {
+            self.skip_whitespace();
+            if self.peek()? != b'"' {
+                return Err(JsonError::UnexpectedChar(self.pos));
+            }
+            let key = self.string()?;
+            self.skip_whitespace();
+            self.expect(b':')?;
+            let value = self.value(depth + 1)?;
+            members.push((key, value));
+
+            self.skip_whitespace();
+            match self.next()? {
+                b',' => continue,
+                b'}' => return Ok(Value::Object(members)),
+                _ => return Err(JsonError::UnexpectedChar(self.pos - 1)),
+            }
+        }
+    }
+
+    fn array(&mut self, depth: usize) -> Result<Value, JsonError> {
+        self.expect(b'[')?;
+        let mut items = Vec::new();
+
+        self.skip_whitespace();
+        if self.peek()? == b']' {
+            self.pos += 1;
+            return Ok(Value::Array(items));
+        }
+
+        loop {
+            items.push(self.value(depth + 1)?);
+
+            self.skip_whitespace();
+            match self.next()? {
+                b',' => continue,
+                b']' => return Ok(Value::Array(items)),
+                _ => return Err(JsonError::UnexpectedChar(self.pos - 1)),
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, JsonError> {
+        self.expect(b'"')?;
+        let mut out = String::new();
+
+        loop {
+            // Copy the run of plain characters up to the next quote or escape in one go
+            let start = self.pos;
+            while let Some(&b) = self.bytes.get(self.pos) {
+                if b == b'"' || b == b'\\' || b < 0x20 {
+                    break;
+                }
+                self.pos += 1;
+            }
+            // Input came from a &str and the run stops on ASCII bytes, so it is valid UTF-8
+            out.push_str(
+                core::str::from_utf8(&self.bytes[start..self.pos])
+                    .map_err(|_| JsonError::InvalidEncoding)?,
+            );
+
+            match self.next()? {
+                b'"' => return Ok(out),
+                b'\\' => {
+                    let c = match self.next()? {
+                        b'"' => '"',
+                        b'\\' => '\\',
+                        b'/' => '/',
+                        b'b' => '\u{8}',
+                        b'f' => '\u{c}',
+                        b'n' => '\n',
+                        b'r' => '\r',
+                        b't' => '\t',
+                        b'u' => self.unicode_escape()?,
+                        _ => return Err(JsonError::InvalidEscape),
+                    };
+                    out.push(c);
+                }
+                _ => return Err(JsonError::UnexpectedChar(self.pos - 1)),
+            }
+        }
+    }
+
+    /// Decode the `XXXX` of a `\uXXXX` escape, joining UTF-16 surrogate pairs
+    fn unicode_escape(&mut self) -> Result<char, JsonError> {
+        let high = self.hex4()?;
+        if !(0xD800..0xDC00).contains(&high) {
+            return char::from_u32(high).ok_or(JsonError::InvalidEscape);
+        }
+
+        if self.next()? != b'\\' || self.next()? != b'u' {
+            return Err(JsonError::InvalidEscape);
+        }
+        let low = self.hex4()?;
+        if !(0xDC00..0xE000).contains(&low) {
+            return Err(JsonError::InvalidEscape);
+        }
+        char::from_u32(0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00))
+            .ok_or(JsonError::InvalidEscape)
+    }
+
+    fn hex4(&mut self) -> Result<u32, JsonError> {
+        let digits = self
+            .bytes
+            .get(self.pos..self.pos + 4)
+            .ok_or(JsonError::UnexpectedEnd)?;
+        let digits = core::str::from_utf8(digits).map_err(|_| JsonError::InvalidEscape)?;
+        let value = u32::from_str_radix(digits, 16).map_err(|_| JsonError::InvalidEscape)?;
+        self.pos += 4;
+        Ok(value)
+    }
+
+    fn number(&mut self) -> Result<Value, JsonError> {
+        let start = self.pos;
+        while let Some(&b) = self.bytes.get(self.pos) {
+            if !matches!(b, b'0'..=b'9' | b'-' | b'+' | b'.' | b'e' | b'E') {
+                break;
+            }
+            self.pos += 1;
+        }
+
+        let literal = core::str::from_utf8(&self.bytes[start..self.pos])
+            .map_err(|_| JsonError::InvalidNumber)?;
+        // Rust's float parser also accepts forms JSON forbids ("1.", ".5", "+1")
+        let digits = literal.strip_prefix('-').unwrap_or(literal);
+        let leading_ok = digits.starts_with(|c: char| c.is_ascii_digit())
+            && !(digits.len() > 1 && digits.starts_with('0') && digits.as_bytes()[1].is_ascii_digit());
+        if !leading_ok || digits.contains(".e") || digits.contains(".E") || digits.ends_with('.') {
+            return Err(JsonError::InvalidNumber);
+        }
+
+        literal
+            .parse::<f64>()
+            .map(Value::Number)
+            .map_err(|_| JsonError::InvalidNumber)
+    }
+
+    fn literal(&mut self, word: &str, value: Value) -> Result<Value, JsonError> {
+        let end = self.pos + word.len();
+        match self.bytes.get(self.pos..end) {
+            Some(bytes) if bytes == word.as_bytes() => {
+                self.pos = end;
+                Ok(value)
+            }
+            Some(_) => Err(JsonError::UnexpectedChar(self.pos)),
+            None => Err(JsonError::UnexpectedEnd),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.bytes.get(self.pos) {
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&self) -> Result<u8, JsonError> {
+        self.bytes.get(self.pos).copied().ok_or(JsonError::UnexpectedEnd)
+    }
+
+    fn next(&mut self) -> Result<u8, JsonError> {
+        let b = self.peek()?;
+        self.pos += 1;
+        Ok(b)
+    }
+
+    fn expect(&mut self, expected: u8) -> Result<(), JsonError> {
+        match self.next()? {
+            b if b == expected => Ok(()),
+            _ => Err(JsonError::UnexpectedChar(self.pos - 1)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_parse_nested_document() {
+        let value =
+            parse_str(r#" {"a": [1, -2.5e1, true, null], "b": {"c": "x\"y\u00e9é\ud83c\udf27"}} "#)
+                .unwrap();
+
+        assert_eq!(
+            value.get("a"),
+            Some(&Value::Array(vec![
+                Value::Number(1.0),
+                Value::Number(-25.0),
+                Value::Bool(true),
+                Value::Null,
+            ]))
+        );
+        assert_eq!(value.pointer(&["b", "c"]).and_then(Value::as_str), Some("x\"yéé🌧"));
+        assert_eq!(value.pointer(&["b", "missing"]), None);
+    }
+
+    #[test]
+    fn test_parse_rejects_malformed_input() {
+        assert_eq!(parse(br#"{"a":1"#), Err(JsonError::UnexpectedEnd));
+        assert_eq!(parse(br#"{"a":1}}"#), Err(JsonError::TrailingCharacters));
+        assert_eq!(parse(br#"{"a":01}"#), Err(JsonError::InvalidNumber));
+        assert_eq!(parse(br#"{"a":1.}"#), Err(JsonError::InvalidNumber));
+        assert_eq!(parse(br#"["\q"]"#), Err(JsonError::InvalidEscape));
+        assert_eq!(parse(br#"{a:1}"#), Err(JsonError::UnexpectedChar(1)));
+        assert_eq!(parse(&[b'['; MAX_DEPTH + 2]), Err(JsonError::TooDeep));
+    }
+
+    #[test]
+    fn test_select_dotted_path() {
+        let value = parse_str(r#"{"items": [{"reading": {"value": 1.25}}, {"1": "x"}]}"#).unwrap();
+
+        assert_eq!(value.select("items.0.reading.value"), Some(&Value::Number(1.25)));
+        // Numeric keys still address object members
+        assert_eq!(value.select("items.1.1").and_then(Value::as_str), Some("x"));
+        assert_eq!(value.select("items.2"), None);
+        assert_eq!(value.select("items.first"), None);
+        assert_eq!(value.select(""), Some(&value));
+    }
+
+    #[test]
+    fn test_as_u64_rejects_fractions_and_negatives() {
+        assert_eq!(Value::Number(1_704_074_400.0).as_u64(), Some(1_704_074_400));
+        assert_eq!(Value::Number(1.5).as_u64(), None);
+        assert_eq!(Value::Number(-1.0).as_u64(), None);
+    }
+}
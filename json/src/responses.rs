@@ -0,0 +1,313 @@
+//! # Typed API Responses
+//!
+//! Responses consumed by the offchain workers: AccuWeather geoposition search,
+//! AccuWeather `historical/24` current conditions and the R pricing model.
+
+use alloc::{string::String, vec::Vec};
+
+use crate::{parse, parse_str, JsonError, Value};
+
+/// AccuWeather `locations/v1/cities/geoposition/search` response
+#[derive(Clone, PartialEq, Debug)]
+pub struct GeopositionResponse {
+    /// AccuWeather location key (e.g. "264885")
+    pub key: String,
+}
+
+impl GeopositionResponse {
+    pub fn from_json(json: &[u8]) -> Result<Self, JsonError> {
+        let value = parse(json)?;
+        let key = value
+            .get("Key")
+            .ok_or(JsonError::MissingField("Geoposition response missing Key"))?
+            .as_str()
+            .filter(|key| !key.is_empty())
+            .ok_or(JsonError::InvalidField("Geoposition Key must be a non-empty string"))?;
+
+        Ok(Self { key: key.into() })
+    }
+}
+
+/// One hourly observation from AccuWeather `currentconditions/v1/{key}/historical/24`.
+/// Metric fields that are absent or null are `None`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct HistoricalObservation {
+    /// Observation time (unix seconds)
+    pub epoch_time: u64,
+    /// `PrecipitationSummary.PastHour.Metric.Value` in mm
+    pub past_hour_precip_mm: Option<f64>,
+    /// `Temperature.Metric.Value` in °C
+    pub temperature_c: Option<f64>,
+    /// `WindGust.Speed.Metric.Value` in km/h
+    pub wind_gust_kmh: Option<f64>,
+    /// `PrecipitationSummary.Snow.Metric.Value` (snowfall depth over the past hour) in cm
+    pub past_hour_snow_cm: Option<f64>,
+}
+
+impl HistoricalObservation {
+    /// Parse the array of observations in a historical/24 response
+    pub fn list_from_json(json: &[u8]) -> Result<Vec<Self>, JsonError> {
+        parse(json)?
+            .as_array()
+            .ok_or(JsonError::InvalidField("Historical response must be an array"))?
+            .iter()
+            .map(Self::from_value)
+            .collect()
+    }
+
+    fn from_value(value: &Value) -> Result<Self, JsonError> {
+        let epoch_time = value
+            .get("EpochTime")
+            .ok_or(JsonError::MissingField("Observation missing EpochTime"))?
+            .as_u64()
+            .filter(|epoch| *epoch > 0)
+            .ok_or(JsonError::InvalidField("Observation EpochTime must be a positive integer"))?;
+
+        Ok(Self {
+            epoch_time,
+            past_hour_precip_mm: optional_number(
+                value,
+                &["PrecipitationSummary", "PastHour", "Metric", "Value"],
+                "Observation PastHour precipitation must be a number",
+            )?,
+            temperature_c: optional_number(
+                value,
+                &["Temperature", "Metric", "Value"],
+                "Observation Temperature must be a number",
+            )?,
+            wind_gust_kmh: optional_number(
+                value,
+                &["WindGust", "Speed", "Metric", "Value"],
+                "Observation WindGust must be a number",
+            )?,
+            past_hour_snow_cm: optional_number(
+                value,
+                &["PrecipitationSummary", "Snow", "Metric", "Value"],
+                "Observation Snow must be a number",
+            )?,
+        })
+    }
+}
+
+/// R pricing model response. The API returns either a plain object or the object
+/// JSON-encoded inside a one-element array (`["{\"avg_cost\":0.902,...}"]`), and
+/// R serialises scalars as one-element arrays (`{"avg_cost":[0.902]}`).
+#[derive(Clone, PartialEq, Debug)]
+pub struct RPricingResponse {
+    /// Expected payout cost, in the same units as the requested coverage
+    pub avg_cost: f64,
+    pub recommended_premium: Option<f64>,
+    pub dist_closest_point_km: Option<f64>,
+    /// Discount on the summed member premiums of a group quote for the
+    /// correlation between its locations, as a fraction (0.15 = 15%)
+    pub correlation_discount: Option<f64>,
+    /// Pricing service signature over the request hash and `avg_cost`, if the
+    /// service signs its responses
+    pub attestation: Option<RPricingAttestation>,
+}
+
+/// `attestation` object of an R pricing response. Integers are exact, so the
+/// signed values can be reproduced on-chain.
+#[derive(Clone, PartialEq, Debug)]
+pub struct RPricingAttestation {
+    /// Coverage the request was priced for, in whole units
+    pub coverage: u64,
+    /// `avg_cost` in millionths of a unit
+    pub avg_cost_micros: u64,
+    /// Hex-encoded ed25519 signature, with or without `0x`
+    pub signature: String,
+}
+
+impl RPricingAttestation {
+    fn from_value(value: &Value) -> Result<Self, JsonError> {
+        let integer = |key: &'static str| -> Result<u64, JsonError> {
+            let n = r_number(value, key)?
+                .ok_or(JsonError::MissingField("R pricing attestation missing a field"))?;
+            if n < 0.0 || n > u64::MAX as f64 || n as u64 as f64 != n {
+                return Err(JsonError::InvalidField("R attestation values must be integers"));
+            }
+            Ok(n as u64)
+        };
+        let signature = match value.get("signature") {
+            Some(Value::Array(items)) if items.len() == 1 => items[0].as_str(),
+            Some(field) => field.as_str(),
+            None => None,
+        }
+        .ok_or(JsonError::MissingField("R pricing attestation missing signature"))?;
+
+        Ok(Self {
+            coverage: integer("coverage")?,
+            avg_cost_micros: integer("avg_cost_micros")?,
+            signature: signature.into(),
+        })
+    }
+}
+
+impl RPricingResponse {
+    pub fn from_json(json: &[u8]) -> Result<Self, JsonError> {
+        let mut value = parse(json)?;
+
+        // Unwrap the double-encoded form
+        let encoded = match &value {
+            Value::String(inner) => Some(inner.clone()),
+            Value::Array(items) => match items.as_slice() {
+                [Value::String(inner)] => Some(inner.clone()),
+                _ => None,
+            },
+            _ => None,
+        };
+        if let Some(inner) = encoded {
+            value = parse_str(&inner)?;
+        }
+
+        let avg_cost = r_number(&value, "avg_cost")?
+            .ok_or(JsonError::MissingField("R pricing response missing avg_cost"))?;
+        if avg_cost < 0.0 {
+            return Err(JsonError::InvalidField("R pricing avg_cost must not be negative"));
+        }
+
+        Ok(Self {
+            avg_cost,
+            recommended_premium: r_number(&value, "recommended_premium")?,
+            dist_closest_point_km: r_number(&value, "dist_closest_point_km")?,
+            correlation_discount: r_number(&value, "correlation_discount")?,
+            attestation: match value.get("attestation") {
+                None | Some(Value::Null) => None,
+                Some(attestation) => Some(RPricingAttestation::from_value(attestation)?),
+            },
+        })
+    }
+}
+
+/// Number at `path`; absent or null is `None`, any other type is an error
+fn optional_number(
+    value: &Value,
+    path: &[&str],
+    invalid: &'static str,
+) -> Result<Option<f64>, JsonError> {
+    match value.pointer(path) {
+        None | Some(Value::Null) => Ok(None),
+        Some(v) => v.as_f64().map(Some).ok_or(JsonError::InvalidField(invalid)),
+    }
+}
+
+/// Number or one-element number array under `key`
+fn r_number(value: &Value, key: &'static str) -> Result<Option<f64>, JsonError> {
+    let field = match value.get(key) {
+        None | Some(Value::Null) => return Ok(None),
+        Some(Value::Array(items)) if items.len() == 1 => &items[0],
+        Some(field) => field,
+    };
+    field
+        .as_f64()
+        .map(Some)
+        .ok_or(JsonError::InvalidField("R pricing field must be a number"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_geoposition_key() {
+        let json = br#"{"Version":1,"Key":"264885","Type":"City",
+            "ParentCity":{"Key":"999999"},"GeoPosition":{"Latitude":14.6}}"#;
+        assert_eq!(GeopositionResponse::from_json(json).unwrap().key, "264885");
+
+        // A nested Key must not be picked up when the top-level one is missing
+        let nested_only = br#"{"ParentCity":{"Key":"999999"}}"#;
+        assert_eq!(
+            GeopositionResponse::from_json(nested_only),
+            Err(JsonError::MissingField("Geoposition response missing Key"))
+        );
+    }
+
+    #[test]
+    fn test_historical_observations() {
+        let json = br#"[
+            {"EpochTime":1704074400,"Temperature":{"Metric":{"Value":31.7}},
+             "Wind":{"Speed":{"Metric":{"Value":18.5}}},
+             "WindGust":{"Speed":{"Metric":{"Value":42.6}}},
+             "PrecipitationSummary":{"Precipitation":{"Metric":{"Value":9.9}},
+                                     "PastHour":{"Metric":{"Value":2.4}},
+                                     "Snow":{"Metric":{"Value":1.5,"Unit":"cm"}}}},
+            {"EpochTime":1704070800,"WindGust":{"Speed":{"Metric":{"Value":null}}}}
+        ]"#;
+
+        let observations = HistoricalObservation::list_from_json(json).unwrap();
+        assert_eq!(
+            observations,
+            alloc::vec![
+                HistoricalObservation {
+                    epoch_time: 1_704_074_400,
+                    past_hour_precip_mm: Some(2.4),
+                    temperature_c: Some(31.7),
+                    wind_gust_kmh: Some(42.6),
+                    past_hour_snow_cm: Some(1.5),
+                },
+                HistoricalObservation {
+                    epoch_time: 1_704_070_800,
+                    past_hour_precip_mm: None,
+                    temperature_c: None,
+                    wind_gust_kmh: None,
+                    past_hour_snow_cm: None,
+                },
+            ]
+        );
+
+        let bad = br#"[{"EpochTime":"soon"}]"#;
+        assert!(matches!(
+            HistoricalObservation::list_from_json(bad),
+            Err(JsonError::InvalidField(_))
+        ));
+    }
+
+    #[test]
+    fn test_r_pricing_response_formats() {
+        let plain = br#"{"avg_cost":5.25,"recommended_premium":6.3,"dist_closest_point_km":12.5}"#;
+        let encoded = br#"["{\"avg_cost\":0.902,\"closest_point\":{\"avg_cost\":7}}"]"#;
+        let r_arrays = br#"{"avg_cost":[1.5],"recommended_premium":[2]}"#;
+
+        let plain = RPricingResponse::from_json(plain).unwrap();
+        assert_eq!(plain.avg_cost, 5.25);
+        assert_eq!(plain.dist_closest_point_km, Some(12.5));
+        assert_eq!(RPricingResponse::from_json(encoded).unwrap().avg_cost, 0.902);
+        assert_eq!(RPricingResponse::from_json(r_arrays).unwrap().recommended_premium, Some(2.0));
+        assert_eq!(plain.correlation_discount, None);
+
+        let group = br#"{"avg_cost":[3.1],"correlation_discount":[0.18]}"#;
+        assert_eq!(RPricingResponse::from_json(group).unwrap().correlation_discount, Some(0.18));
+
+        assert_eq!(
+            RPricingResponse::from_json(br#"{"closest_point":{"avg_cost":7}}"#),
+            Err(JsonError::MissingField("R pricing response missing avg_cost"))
+        );
+    }
+
+    #[test]
+    fn test_r_pricing_attestation() {
+        let signed = br#"{"avg_cost":[0.902],"attestation":{"coverage":[100],
+            "avg_cost_micros":[902000],"signature":["0xab01"]}}"#;
+        assert_eq!(
+            RPricingResponse::from_json(signed).unwrap().attestation,
+            Some(RPricingAttestation {
+                coverage: 100,
+                avg_cost_micros: 902_000,
+                signature: "0xab01".into(),
+            })
+        );
+
+        let fractional = br#"{"avg_cost":0.9,"attestation":{"coverage":100,
+            "avg_cost_micros":900000.5,"signature":"ab"}}"#;
+        assert!(matches!(
+            RPricingResponse::from_json(fractional),
+            Err(JsonError::InvalidField(_))
+        ));
+
+        let unsigned = br#"{"avg_cost":0.9,"attestation":{"coverage":100,"avg_cost_micros":1}}"#;
+        assert_eq!(
+            RPricingResponse::from_json(unsigned),
+            Err(JsonError::MissingField("R pricing attestation missing signature"))
+        );
+    }
+}
@@ -20,6 +20,9 @@ const ORACLE_KEY_TYPE: sp_runtime::KeyTypeId = sp_runtime::KeyTypeId(*b"orcl");
 /// Quote authority key type (must match pallet_prmx_quote::KEY_TYPE)
 const QUOTE_KEY_TYPE: sp_runtime::KeyTypeId = sp_runtime::KeyTypeId(*b"quot");
 
+/// V3 oracle OCW key type (must match pallet_oracle_v3::KEY_TYPE)
+const ORACLE_V3_KEY_TYPE: sp_runtime::KeyTypeId = sp_runtime::KeyTypeId(*b"orv3");
+
 /// Insert oracle authority key into keystore for offchain worker transaction signing.
 /// Uses dedicated Oracle account to avoid conflicts with admin operations.
 fn insert_oracle_authority_key(keystore: &KeystorePtr) -> Result<(), ServiceError> {
@@ -66,6 +69,28 @@ fn insert_quote_authority_key(keystore: &KeystorePtr) -> Result<(), ServiceError
     Ok(())
 }
 
+/// Insert V3 oracle OCW key into keystore for signing unsigned transaction payloads.
+/// The key's account must be added as a V3 oracle member for its submissions to be accepted.
+fn insert_oracle_v3_key(keystore: &KeystorePtr) -> Result<(), ServiceError> {
+    use sp_keystore::Keystore;
+    
+    // Same dedicated Oracle account the setup scripts add as V3 oracle member
+    let seed = "//Oracle";
+    
+    // Generate key from seed and insert into keystore
+    keystore.sr25519_generate_new(
+        ORACLE_V3_KEY_TYPE,
+        Some(seed),
+    ).map_err(|e| ServiceError::Other(format!("Failed to insert V3 oracle key: {:?}", e)))?;
+    
+    log::info!(
+        "🔐 V3 oracle OCW key inserted into keystore (seed: {})",
+        seed
+    );
+    
+    Ok(())
+}
+
 /// The full client type definition.
 pub type FullClient = sc_service::TFullClient<
     Block,
@@ -197,6 +222,7 @@ pub fn new_full(config: Configuration) -> Result<TaskManager, ServiceError> {
     if config.role.is_authority() {
         insert_oracle_authority_key(&keystore_container.keystore())?;
         insert_quote_authority_key(&keystore_container.keystore())?;
+        insert_oracle_v3_key(&keystore_container.keystore())?;
     }
 
     let grandpa_protocol_name = sc_consensus_grandpa::protocol_standard_name(
@@ -14,6 +14,7 @@ import { config } from '../config.js';
 export interface PrecipitationRecord {
   dateTime: string;     // ISO datetime
   precipitationMm: number;
+  snowfallCm: number;   // PrecipitationSummary.Snow over the past hour
   rawData: object;      // Raw AccuWeather API response
 }
 
@@ -71,6 +72,7 @@ export async function fetchPrecipitation(
       
       if (precipSummary) {
         const pastHourMm = precipSummary.PastHour?.Metric?.Value || 0;
+        const pastHourSnowCm = precipSummary.Snow?.Metric?.Value || 0;
         const past3HoursMm = precipSummary.Past3Hours?.Metric?.Value || 0;
         const past6HoursMm = precipSummary.Past6Hours?.Metric?.Value || 0;
         const past12HoursMm = precipSummary.Past12Hours?.Metric?.Value || 0;
@@ -89,6 +91,7 @@ export async function fetchPrecipitation(
         records.push({
           dateTime: current.LocalObservationDateTime,
           precipitationMm: pastHourMm,
+          snowfallCm: pastHourSnowCm,
           rawData: {
             // Full current conditions response
             LocalObservationDateTime: current.LocalObservationDateTime,
@@ -107,6 +110,7 @@ export async function fetchPrecipitation(
             // Extracted values for easy reference
             _extracted: {
               pastHourMm,
+              pastHourSnowCm,
               past3HoursMm,
               past6HoursMm,
               past12HoursMm,
@@ -219,10 +223,12 @@ export async function fetchHistorical24Hours(locationKey: string): Promise<Preci
       for (const observation of response.data) {
         const precipSummary = observation.PrecipitationSummary;
         const pastHourMm = precipSummary?.PastHour?.Metric?.Value || 0;
+        const pastHourSnowCm = precipSummary?.Snow?.Metric?.Value || 0;
         
         records.push({
           dateTime: observation.LocalObservationDateTime,
           precipitationMm: pastHourMm,
+          snowfallCm: pastHourSnowCm,
           rawData: {
             LocalObservationDateTime: observation.LocalObservationDateTime,
             EpochTime: observation.EpochTime,
@@ -239,6 +245,7 @@ export async function fetchHistorical24Hours(locationKey: string): Promise<Preci
             PrecipitationSummary: observation.PrecipitationSummary,
             _extracted: {
               pastHourMm,
+              pastHourSnowCm,
               fetchedAt: new Date().toISOString(),
               locationKey,
               source: 'historical/24',
@@ -8,7 +8,7 @@
  */
 
 import { Application, Request, Response } from 'express';
-import { getMonitors, getBuckets, getEvidence, clearAllData, getObservationsV3, getSnapshotsV3, checkDatabaseHealth } from '../db/mongo.js';
+import { getMonitors, getBuckets, getEvidence, clearAllData, getObservationsV3, getSnapshotsV3, getEvidenceChunksV3, checkDatabaseHealth } from '../db/mongo.js';
 import { runEvaluationCycle } from '../scheduler/monitor.js';
 import { evaluateMonitor } from '../evaluator/cumulative.js';
 import { fetchPrecipitation, fetchCurrentConditions, fetchHistorical24Hours } from '../accuweather/fetcher.js';
@@ -180,7 +180,7 @@ export function setupRoutes(app: Application): void {
       const existingHours = new Set(existingBuckets.map(b => b.hour_utc));
       
       // Fetch 24h historical data from AccuWeather
-      let historicalRecords: Array<{ dateTime: string; precipitationMm: number; rawData: object }> = [];
+      let historicalRecords: Array<{ dateTime: string; precipitationMm: number; snowfallCm: number; rawData: object }> = [];
       let historicalError: string | null = null;
       
       try {
@@ -192,11 +192,12 @@ export function setupRoutes(app: Application): void {
       }
       
       // Build a map of historical data by hour
-      const historicalByHour = new Map<string, { mm: number; rawData: object }>();
+      const historicalByHour = new Map<string, { mm: number; snowMm: number; rawData: object }>();
       for (const record of historicalRecords) {
         const hourUtc = normalizeToHour(record.dateTime);
         historicalByHour.set(hourUtc, {
           mm: Math.round(record.precipitationMm * 10), // Convert to tenths
+          snowMm: Math.round(record.snowfallCm * 100), // cm to tenths of mm
           rawData: record.rawData,
         });
       }
@@ -234,6 +235,7 @@ export function setupRoutes(app: Application): void {
                 monitor_id: id,
                 hour_utc: hourUtc,
                 mm: historical.mm,
+                snow_mm: historical.snowMm,
                 backfilled: false, // Real data, not a placeholder
                 fetched_at: new Date(),
                 raw_data: historical.rawData,
@@ -251,6 +253,7 @@ export function setupRoutes(app: Application): void {
                 monitor_id: id,
                 hour_utc: hourUtc,
                 mm: 0,
+                snow_mm: 0,
                 backfilled: true, // Placeholder, no real data
                 fetched_at: new Date(),
                 raw_data: { note: 'Backfilled - outside 24h historical window' },
@@ -898,6 +901,150 @@ export function setupRoutes(app: Application): void {
     }
   });
 
+  /**
+   * POST /ingest/evidence/chunks
+   * Receive one chunk of a finalized policy's observation chain from OCW
+   *
+   * OCW sends:
+   * - policy_id: string (H128 hex)
+   * - chunk_index: number (0-based)
+   * - chunk_count: number
+   * - commitment_before: string (hex) - chain value before the first sample
+   * - commitment_after: string (hex) - chain value after the last sample
+   * - samples: same format as /ingest/observations/batch
+   *
+   * The first copy of a chunk wins; re-sent chunks are acknowledged unchanged.
+   */
+  app.post('/ingest/evidence/chunks', async (req: Request, res: Response) => {
+    try {
+      // Rate limiting
+      const rateLimitResult = checkRateLimit(req);
+      if (!rateLimitResult.allowed) {
+        logV3Request(req, '/ingest/evidence/chunks', false);
+        return res.status(429).json({
+          success: false,
+          error: rateLimitResult.error,
+        });
+      }
+
+      // Validate HMAC signature
+      const authResult = validateV3Signature(req);
+      if (!authResult.valid) {
+        logV3Request(req, '/ingest/evidence/chunks', false);
+        return res.status(401).json({
+          success: false,
+          error: authResult.error,
+        });
+      }
+
+      const { policy_id, chunk_index, chunk_count, commitment_before, commitment_after, samples } = req.body;
+
+      if (
+        policy_id === undefined ||
+        !Number.isInteger(chunk_index) ||
+        !Number.isInteger(chunk_count) ||
+        chunk_index < 0 ||
+        chunk_index >= chunk_count ||
+        !commitment_before ||
+        !commitment_after ||
+        !Array.isArray(samples)
+      ) {
+        logV3Request(req, '/ingest/evidence/chunks', false);
+        return res.status(400).json({
+          success: false,
+          error: 'Missing or invalid fields: policy_id, chunk_index, chunk_count, commitment_before, commitment_after, samples',
+        });
+      }
+
+      const evidenceChunks = getEvidenceChunksV3();
+      const result = await evidenceChunks.updateOne(
+        { _id: `${policy_id}:${chunk_index}` },
+        {
+          $setOnInsert: {
+            policy_id,
+            chunk_index,
+            chunk_count,
+            commitment_before,
+            commitment_after,
+            samples,
+            inserted_at: new Date(),
+          }
+        },
+        { upsert: true }
+      );
+
+      const isNew = result.upsertedCount > 0;
+
+      console.log(`🧾 V3 Evidence chunk: policy=${policy_id}, chunk=${chunk_index + 1}/${chunk_count}, samples=${samples.length}, new=${isNew}`);
+      logV3Request(req, '/ingest/evidence/chunks', true);
+
+      res.json({
+        success: true,
+        is_new: isNew,
+        policy_id,
+        chunk_index,
+      });
+    } catch (error) {
+      console.error('Error processing evidence chunk:', error);
+      logV3Request(req, '/ingest/evidence/chunks', false);
+      res.status(500).json({
+        success: false,
+        error: 'Failed to process evidence chunk',
+      });
+    }
+  });
+
+  /**
+   * GET /ingest/evidence/:policyId
+   * Reassemble the evidence chain for a policy
+   *
+   * `complete` is true once every chunk has arrived and each chunk starts at
+   * the commitment the previous one ended on. The samples can then be passed
+   * to the oracle-v3 `verify_commitment_chain` extrinsic.
+   */
+  app.get('/ingest/evidence/:policyId', async (req: Request, res: Response) => {
+    try {
+      const policyId = req.params.policyId;  // H128 as hex string
+      if (!policyId) {
+        return res.status(400).json({
+          success: false,
+          error: 'Invalid policy ID',
+        });
+      }
+
+      const evidenceChunks = getEvidenceChunksV3();
+      const chunks = await evidenceChunks
+        .find({ policy_id: policyId })
+        .sort({ chunk_index: 1 })
+        .toArray();
+
+      const chunkCount = chunks.length > 0 ? chunks[0].chunk_count : 0;
+      const linked = chunks.every((chunk, i) =>
+        chunk.chunk_index === i &&
+        (i === 0 || chunk.commitment_before === chunks[i - 1].commitment_after)
+      );
+
+      res.json({
+        success: true,
+        data: {
+          policy_id: policyId,
+          chunk_count: chunkCount,
+          chunks_received: chunks.length,
+          complete: chunkCount > 0 && chunks.length === chunkCount && linked,
+          commitment_before: chunks.length > 0 ? chunks[0].commitment_before : null,
+          commitment_after: chunks.length > 0 ? chunks[chunks.length - 1].commitment_after : null,
+          samples: chunks.flatMap((chunk) => chunk.samples),
+        },
+      });
+    } catch (error) {
+      console.error('Error fetching evidence chain:', error);
+      res.status(500).json({
+        success: false,
+        error: 'Failed to fetch evidence chain',
+      });
+    }
+  });
+
   /**
    * GET /v1/stats
    * Get V3 ingest statistics
@@ -907,9 +1054,12 @@ export function setupRoutes(app: Application): void {
       const observations = getObservationsV3();
       const snapshots = getSnapshotsV3();
 
-      const [obsCount, snapCount] = await Promise.all([
+      const evidenceChunks = getEvidenceChunksV3();
+
+      const [obsCount, snapCount, evidenceCount] = await Promise.all([
         observations.countDocuments({}),
         snapshots.countDocuments({}),
+        evidenceChunks.countDocuments({}),
       ]);
 
       res.json({
@@ -917,6 +1067,7 @@ export function setupRoutes(app: Application): void {
         data: {
           observations_count: obsCount,
           snapshots_count: snapCount,
+          evidence_chunks_count: evidenceCount,
           nonces_cached: usedNonces.size,
         },
       });
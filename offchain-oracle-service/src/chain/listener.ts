@@ -6,8 +6,10 @@ import { ApiPromise, WsProvider } from '@polkadot/api';
 import type { Vec } from '@polkadot/types';
 import type { EventRecord } from '@polkadot/types/interfaces';
 import { config } from '../config.js';
-import { getMonitors, getBuckets, makeMonitorId, Monitor, checkChainRestart } from '../db/mongo.js';
+import { getMonitors, getBuckets, makeMonitorId, Monitor, V2EventType, checkChainRestart } from '../db/mongo.js';
 import { fetchHistorical24Hours } from '../accuweather/fetcher.js';
+import { measuredMm } from '../evaluator/snowfall.js';
+import { isFloodMonitor, floodMeasuredMm } from '../evaluator/flood.js';
 
 let api: ApiPromise | null = null;
 
@@ -50,6 +52,7 @@ export async function subscribeToV2PolicyCreated(
     strike_mm: number;
     lat: number;
     lon: number;
+    event_type: V2EventType;
   }) => Promise<void>
 ): Promise<void> {
   const chainApi = await connectToChain();
@@ -58,7 +61,7 @@ export async function subscribeToV2PolicyCreated(
     events.forEach(({ event }: EventRecord) => {
       // Handle V2PolicyCreated
       if (event.section === 'prmxPolicy' && event.method === 'V2PolicyCreated') {
-        const [policyId, marketId, coverageStart, coverageEnd, strikeMm, lat, lon] = event.data;
+        const [policyId, marketId, coverageStart, coverageEnd, strikeMm, lat, lon, eventType] = event.data;
         
         console.log(`📋 V2PolicyCreated event detected: policy_id=${policyId.toHex()}`);
         
@@ -70,6 +73,7 @@ export async function subscribeToV2PolicyCreated(
           strike_mm: Number(strikeMm.toString()),
           lat: Number(lat.toString()),
           lon: Number(lon.toString()),
+          event_type: toV2EventType(eventType?.toString()),
         }).catch(err => console.error('Error handling V2PolicyCreated:', err));
       }
       
@@ -167,6 +171,7 @@ export async function handleV2PolicyCreated(policy: {
   strike_mm: number;
   lat: number;
   lon: number;
+  event_type: V2EventType;
 }): Promise<void> {
   const monitors = getMonitors();
   const buckets = getBuckets();
@@ -191,6 +196,7 @@ export async function handleV2PolicyCreated(policy: {
     strike_mm: policy.strike_mm,
     lat: policy.lat,
     lon: policy.lon,
+    event_type: policy.event_type,
     state: 'monitoring',
     cumulative_mm: 0,
     last_fetch_at: 0,
@@ -209,8 +215,7 @@ export async function handleV2PolicyCreated(policy: {
     const records = await fetchHistorical24Hours(locationKey);
     console.log(`   ✅ Fetched ${records.length} hourly records`);
     
-    let bucketsCreated = 0;
-    let cumulativeMm = 0;
+    const populated: { hour_utc: string; mm: number; snow_mm: number }[] = [];
     
     for (const record of records) {
       const recordTime = new Date(record.dateTime).getTime() / 1000;
@@ -220,6 +225,7 @@ export async function handleV2PolicyCreated(policy: {
         const hourUtc = normalizeToHour(record.dateTime);
         const bucketId = `${monitorId}:${hourUtc.replace(/[-:TZ]/g, '').slice(0, 10)}`;
         const mmScaled = Math.round(record.precipitationMm * 10);
+        const snowScaled = Math.round(record.snowfallCm * 100);
         
         await buckets.updateOne(
           { _id: bucketId },
@@ -228,6 +234,7 @@ export async function handleV2PolicyCreated(policy: {
               monitor_id: monitorId,
               hour_utc: hourUtc,
               mm: mmScaled,
+              snow_mm: snowScaled,
               raw_data: record.rawData,
               fetched_at: new Date(),
               backfilled: false,
@@ -236,12 +243,16 @@ export async function handleV2PolicyCreated(policy: {
           { upsert: true }
         );
         
-        bucketsCreated++;
-        cumulativeMm += mmScaled;
+        populated.push({ hour_utc: hourUtc, mm: mmScaled, snow_mm: snowScaled });
       }
     }
     
-    // Update monitor with initial cumulative rainfall
+    const bucketsCreated = populated.length;
+    const cumulativeMm = isFloodMonitor(monitor)
+      ? await floodMeasuredMm(monitor)
+      : measuredMm(monitor, populated);
+    
+    // Update monitor with initial cumulative rainfall (or highest 24h snowfall)
     if (bucketsCreated > 0) {
       await monitors.updateOne(
         { _id: monitorId },
@@ -264,6 +275,15 @@ export async function handleV2PolicyCreated(policy: {
   }
 }
 
+/**
+ * Event type of a V2PolicyCreated event (events from before snowfall support carry none)
+ */
+function toV2EventType(eventType: string | undefined): V2EventType {
+  return eventType === 'Snowfall24h' || eventType === 'FloodComposite'
+    ? eventType
+    : 'CumulativeRainfallWindow';
+}
+
 /**
  * Normalize datetime to hour start (ISO format)
  */
@@ -11,6 +11,9 @@ let db: Db | null = null;
 /**
  * Monitor document structure (policy tracking)
  */
+/** What a V2 policy's strike is compared against */
+export type V2EventType = 'CumulativeRainfallWindow' | 'Snowfall24h' | 'FloodComposite';
+
 export interface Monitor {
   _id: string;             // Composite UID: "0:0x1234..." (market_id:policy_id_hex)
   market_id: number;
@@ -20,8 +23,10 @@ export interface Monitor {
   strike_mm: number;
   lat: number;
   lon: number;
+  event_type?: V2EventType;  // Absent on rainfall monitors created before snowfall support
   state: 'monitoring' | 'triggered' | 'matured' | 'reported';
-  cumulative_mm: number;
+  cumulative_mm: number;   // Cumulative rainfall, highest 24h snowfall for Snowfall24h, or highest
+                           // 24h rainfall at river flood stage for FloodComposite (tenths of mm)
   trigger_time?: number;
   last_fetch_at: number;
   location_key: string;
@@ -39,6 +44,7 @@ export interface Bucket {
   monitor_id: string;
   hour_utc: string;        // ISO hour: "2025-12-21T00:00:00Z"
   mm: number;
+  snow_mm?: number;        // Snowfall depth over the hour (tenths of mm)
   raw_data?: object;       // Raw AccuWeather API response for this reading
   fetched_at?: Date;       // When this bucket was last updated
   backfilled?: boolean;    // True if this bucket was backfilled (no actual data)
@@ -261,6 +267,21 @@ export interface SnapshotV3 {
   inserted_at: Date;       // TTL index: 90 days
 }
 
+/**
+ * V3 evidence chunk document structure
+ * One upload of a finalized policy's full observation chain from the OCW
+ */
+export interface EvidenceChunkV3 {
+  _id: string;             // policy_id:chunk_index
+  policy_id: string;       // H128 as hex string
+  chunk_index: number;
+  chunk_count: number;
+  commitment_before: string;
+  commitment_after: string;
+  samples: Array<Record<string, number | string>>;
+  inserted_at: Date;       // No TTL: kept for dispute resolution
+}
+
 /**
  * Get V3 observations collection
  */
@@ -277,6 +298,14 @@ export function getSnapshotsV3(): Collection<SnapshotV3> {
   return db.collection<SnapshotV3>('snapshots_v3');
 }
 
+/**
+ * Get V3 evidence chunks collection
+ */
+export function getEvidenceChunksV3(): Collection<EvidenceChunkV3> {
+  if (!db) throw new Error('Database not connected');
+  return db.collection<EvidenceChunkV3>('evidence_chunks_v3');
+}
+
 /**
  * Ensure V3 indexes with TTL
  */
@@ -285,6 +314,7 @@ export async function ensureV3Indexes(): Promise<void> {
   
   const observations = db.collection<ObservationV3>('observations_v3');
   const snapshots = db.collection<SnapshotV3>('snapshots_v3');
+  const evidenceChunks = db.collection<EvidenceChunkV3>('evidence_chunks_v3');
   
   // Observations: unique compound index + TTL (30 days)
   await observations.createIndex({ policy_id: 1, epoch_time: 1 });
@@ -300,6 +330,9 @@ export async function ensureV3Indexes(): Promise<void> {
     { expireAfterSeconds: 90 * 24 * 3600 } // 90 days
   );
   
+  // Evidence chunks: no TTL, they outlive the raw observations for disputes
+  await evidenceChunks.createIndex({ policy_id: 1, chunk_index: 1 });
+  
   console.log('✅ V3 indexes created with TTL');
 }
 
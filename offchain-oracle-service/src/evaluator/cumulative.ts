@@ -1,10 +1,17 @@
 /**
  * Cumulative rainfall evaluator for V2 policies
+ *
+ * Snowfall24h policies use the same buckets and reporting flow, but compare the
+ * highest 24h snowfall against the strike (see snowfall.ts). FloodComposite
+ * policies compare the 24h rainfall recorded on-chain at river flood stage (see
+ * flood.ts).
  */
 
 import { getBuckets, getMonitors, Bucket, Monitor } from '../db/mongo.js';
 import { fetchHistorical24Hours } from '../accuweather/fetcher.js';
 import { submitV2Report, checkV2ReportExists, V2Outcome } from '../chain/reporter.js';
+import { isSnowfallMonitor, measuredMm } from './snowfall.js';
+import { isFloodMonitor, floodMeasuredMm } from './flood.js';
 
 /**
  * Evaluate a single monitor and trigger/mature if conditions are met
@@ -68,6 +75,7 @@ export async function evaluateMonitor(monitor: Monitor): Promise<void> {
             monitor_id: monitor._id,
             hour_utc: hourUtc,
             mm: Math.round(record.precipitationMm * 10), // Convert to tenths of mm
+            snow_mm: Math.round(record.snowfallCm * 100), // cm to tenths of mm
             raw_data: record.rawData,  // Store raw AccuWeather response
             fetched_at: new Date(),    // When this bucket was last updated
             backfilled: false,         // Real data, not backfilled
@@ -80,10 +88,17 @@ export async function evaluateMonitor(monitor: Monitor): Promise<void> {
     
     console.log(`   📊 Created/updated ${bucketsCreated} buckets (${bucketsSkipped} outside coverage period)`);
     
-    // Recompute cumulative rainfall
+    // Recompute cumulative rainfall (or highest 24h snowfall / rainfall at flood stage)
     const allBuckets = await buckets.find({ monitor_id: monitor._id }).toArray();
-    const cumulativeMm = allBuckets.reduce((sum, b) => sum + b.mm, 0);
-    console.log(`   💧 Total cumulative: ${cumulativeMm / 10}mm (from ${allBuckets.length} buckets)`);
+    const cumulativeMm = isFloodMonitor(monitor)
+      ? await floodMeasuredMm(monitor)
+      : measuredMm(monitor, allBuckets);
+    const measure = isSnowfallMonitor(monitor)
+      ? 'Max 24h snowfall'
+      : isFloodMonitor(monitor)
+        ? 'Max 24h rainfall at flood stage'
+        : 'Total cumulative';
+    console.log(`   💧 ${measure}: ${cumulativeMm / 10}mm (from ${allBuckets.length} buckets)`);
     
     // Update monitor
     const monitors = getMonitors();
@@ -161,6 +176,7 @@ function buildEvidence(
     policy_id: monitor.policy_id,
     market_id: monitor.market_id,
     outcome,
+    event_type: monitor.event_type ?? 'CumulativeRainfallWindow',
     observed_at: observedAt,
     observed_at_iso: new Date(observedAt * 1000).toISOString(),
     cumulative_mm: cumulativeMm,
@@ -171,6 +187,7 @@ function buildEvidence(
     buckets: buckets.map(b => ({
       hour: b.hour_utc,
       mm: b.mm,
+      snow_mm: b.snow_mm ?? 0,
     })),
     generated_at: new Date().toISOString(),
   };
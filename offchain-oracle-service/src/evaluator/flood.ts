@@ -0,0 +1,89 @@
+/**
+ * Flood composite evaluation for V2 FloodComposite policies
+ *
+ * Mirrors `FloodOracle::max_rainfall_at_flood_stage_in_window` in the prmx-oracle
+ * pallet. The pallet's OCW stores each hourly river gauge level in `GaugeBuckets`
+ * together with the market's 24h rainfall at that time; a policy triggers when
+ * that rainfall reached the strike during an hour the gauge was at or above its
+ * flood stage. Gauge data is read from chain rather than fetched here, so the
+ * service and the pallet agree on every reading.
+ */
+
+import { getApi } from '../chain/listener.js';
+import type { Monitor } from '../db/mongo.js';
+
+/** One `GaugeBuckets` entry */
+export interface GaugeReading {
+  hour: number;             // Hour index (unix seconds / 3600)
+  level_cm: number;         // Gauge level above the datum
+  rainfall_24h_mm: number;  // Market's 24h rainfall when stored (tenths of mm)
+}
+
+/**
+ * Whether a monitor measures rainfall at river flood stage
+ */
+export function isFloodMonitor(monitor: Pick<Monitor, 'event_type'>): boolean {
+  return monitor.event_type === 'FloodComposite';
+}
+
+/**
+ * Highest 24h rainfall (tenths of mm) in [startHour, endHour] while the gauge was
+ * at or above the flood stage, 0 if it never got there
+ */
+export function maxRainfallAtFloodStage(
+  readings: GaugeReading[],
+  floodLevelCm: number,
+  startHour: number,
+  endHour: number
+): number {
+  return readings
+    .filter(r => r.hour >= startHour && r.hour <= endHour && r.level_cm >= floodLevelCm)
+    .reduce((max, r) => Math.max(max, r.rainfall_24h_mm), 0);
+}
+
+/**
+ * Read a market's flood stage and gauge readings from chain (null flood stage if
+ * governance has not configured a gauge)
+ */
+export async function fetchGaugeReadings(
+  marketId: number
+): Promise<{ floodLevelCm: number | null; readings: GaugeReading[] }> {
+  const api = getApi();
+
+  const source = (await api.query.prmxOracle.gaugeSources(marketId)) as any;
+  if (source.isNone) {
+    return { floodLevelCm: null, readings: [] };
+  }
+  const floodLevelCm = Number(source.unwrap().floodLevelCm.toString());
+
+  const entries = await api.query.prmxOracle.gaugeBuckets.entries(marketId);
+  const readings = entries.map(([key, value]) => {
+    const bucket = (value as any).unwrap();
+    return {
+      hour: Number(key.args[1].toString()),
+      level_cm: Number(bucket.levelCm.toString()),
+      rainfall_24h_mm: Number(bucket.rainfall24hMm.toString()),
+    };
+  });
+
+  return { floodLevelCm, readings };
+}
+
+/**
+ * Value compared against the strike for a flood monitor (tenths of mm)
+ */
+export async function floodMeasuredMm(
+  monitor: Pick<Monitor, 'market_id' | 'coverage_start' | 'coverage_end'>
+): Promise<number> {
+  const { floodLevelCm, readings } = await fetchGaugeReadings(monitor.market_id);
+  if (floodLevelCm === null) {
+    console.warn(`⚠️ No river gauge configured for market ${monitor.market_id}`);
+    return 0;
+  }
+  return maxRainfallAtFloodStage(
+    readings,
+    floodLevelCm,
+    Math.floor(monitor.coverage_start / 3600),
+    Math.floor(monitor.coverage_end / 3600)
+  );
+}
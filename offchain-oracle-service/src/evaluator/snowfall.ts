@@ -0,0 +1,59 @@
+/**
+ * 24-hour snowfall evaluation for V2 Snowfall24h policies
+ *
+ * Mirrors `max_rolling_sum` in the prmx-oracle pallet: a policy triggers when the
+ * snowfall over any 24 consecutive hours of its coverage window reaches the strike.
+ * Hours without a bucket count as no snowfall.
+ */
+
+import type { Bucket, Monitor } from '../db/mongo.js';
+
+/** Length of the snowfall accumulation window in hours */
+export const SNOWFALL_WINDOW_HOURS = 24;
+
+/**
+ * Whether a monitor measures 24h snowfall (monitors created before snowfall
+ * support have no event type and measure cumulative rainfall)
+ */
+export function isSnowfallMonitor(monitor: Pick<Monitor, 'event_type'>): boolean {
+  return monitor.event_type === 'Snowfall24h';
+}
+
+/**
+ * Highest snowfall (tenths of mm) over any 24 consecutive hours of the buckets
+ */
+export function maxSnowfall24h(buckets: Pick<Bucket, 'hour_utc' | 'snow_mm'>[]): number {
+  const readings = buckets
+    .map(b => ({ hour: Math.floor(new Date(b.hour_utc).getTime() / 3_600_000), mm: b.snow_mm ?? 0 }))
+    .sort((a, b) => a.hour - b.hour);
+
+  let max = 0;
+  let sum = 0;
+  let oldest = 0;
+
+  for (const reading of readings) {
+    sum += reading.mm;
+    // Drop readings that fell out of the window ending at this hour
+    while (readings[oldest].hour + SNOWFALL_WINDOW_HOURS <= reading.hour) {
+      sum -= readings[oldest].mm;
+      oldest++;
+    }
+    max = Math.max(max, sum);
+  }
+
+  return max;
+}
+
+/**
+ * Value compared against the strike: highest 24h snowfall for snowfall monitors,
+ * cumulative rainfall otherwise (tenths of mm)
+ */
+export function measuredMm(
+  monitor: Pick<Monitor, 'event_type'>,
+  buckets: Pick<Bucket, 'hour_utc' | 'mm' | 'snow_mm'>[]
+): number {
+  if (isSnowfallMonitor(monitor)) {
+    return maxSnowfall24h(buckets);
+  }
+  return buckets.reduce((sum, b) => sum + b.mm, 0);
+}
@@ -5,9 +5,30 @@
 //! ## Overview
 //!
 //! - UnderwriteRequest: Anyone can create a request for coverage
-//! - Partial acceptance: Multiple underwriters can accept portions
+//! - Partial acceptance: Multiple underwriters can accept portions; each fill is
+//!   tracked as (underwriter, shares) and the premium moves pro-rata per fill
 //! - Expiry: OCW triggers cleanup when requests expire
-//! - Premium escrow: Single global escrow holds premium until acceptance
+//! - Premium escrow: Single global escrow holds premium until acceptance, with a
+//!   per-request ledger (`PremiumEscrows`); requesters reclaim the unfilled part
+//!   themselves after expiry through `withdraw_unfilled_premium`
+//! - Area requests: Coverage over several registry locations (`AreaSpecV3`),
+//!   passed to the policy on first acceptance
+//! - Negotiated (RFQ) requests: Underwriters offer a premium with their collateral
+//!   locked until the offer deadline; the requester accepts one offer and the policy
+//!   and its oracle state are created in the same call. OCW expires unaccepted RFQs
+//! - Collateral accounts: Underwriters deposit collateral once and fill open
+//!   requests against it. A fill's maximum loss becomes an obligation of the
+//!   account and must be covered to `InitialMargin`, topped up from the underwriter
+//!   at fill time where the account falls short; the premium goes to the policy
+//!   pool, and obligations are drawn into the pool on a trigger or released at
+//!   maturity. RFQ offers stay fully collateralized in escrow
+//! - Margin calls: `on_initialize` sweeps collateral accounts; one below
+//!   `MaintenanceMargin` gets `MarginCallPeriod` blocks to top up, after which its
+//!   positions are reduced (shares removed from the policy, their premium refunded
+//!   to the holder from the collateral) until it is back at maintenance margin.
+//!   Each sweep visits at most `V3_MAX_FORCED_REDUCTIONS_PER_CHECK` positions of an
+//!   account, and the call stays open, blocking fills and withdrawals, until the
+//!   shortfall is gone
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -19,17 +40,29 @@ use alloc::vec::Vec;
 use frame_support::pallet_prelude::*;
 use frame_support::traits::fungibles::{Inspect, Mutate};
 use frame_support::traits::tokens::Preservation;
-use frame_support::traits::{Get, Time};
+use frame_support::traits::Get;
 use frame_system::pallet_prelude::*;
 use prmx_primitives::{
-    EventSpecV3, PolicyId, RequestStatusV3, V3_MIN_SHARES_PER_ACCEPT, V3_PAYOUT_PER_SHARE,
-    generate_unique_id, RequestId,
+    AreaSpecV3, EventSpecV3, PolicyId, RequestStatusV3, V3_MIN_SHARES_PER_ACCEPT,
+    V3_PAYOUT_PER_SHARE, generate_unique_id, RequestId,
 };
-use sp_runtime::traits::{AccountIdConversion, Saturating, Zero};
+use prmx_time::ChainTime;
+use sp_runtime::traits::{AccountIdConversion, Saturating, UniqueSaturatedInto, Zero};
+use sp_runtime::Permill;
 
 /// V3 Request expiry check interval (5 minutes in seconds)
 pub const V3_EXPIRY_CHECK_INTERVAL_SECS: u64 = 300;
 
+/// Blocks between margin sweeps over collateral accounts
+pub const V3_MARGIN_CHECK_INTERVAL_BLOCKS: u32 = 10;
+
+/// Positions of one account a margin sweep tries to reduce once its call is due
+pub const V3_MAX_FORCED_REDUCTIONS_PER_CHECK: u32 = 8;
+
+/// Time after an RFQ's offer deadline during which the requester can still accept
+/// an offer (1 hour in seconds)
+pub const V3_RFQ_ACCEPTANCE_WINDOW_SECS: u64 = 3600;
+
 // ============================================================================
 // Constants
 // ============================================================================
@@ -47,6 +80,9 @@ pub type LocationId = u64;
 /// Trait for accessing location registry
 pub trait LocationRegistryApiV3 {
     fn is_location_active(location_id: LocationId) -> bool;
+
+    /// Nearest active location to coordinates scaled by 1e6, if one is registered nearby
+    fn find_nearest_location(latitude: i32, longitude: i32) -> Option<LocationId>;
 }
 
 /// Trait for accessing request expiry information (used by OCW)
@@ -56,19 +92,26 @@ pub trait RequestExpiryApi {
     
     /// Check if a specific request is expired
     fn is_request_expired(request_id: RequestId, current_time: u64) -> bool;
+
+    /// Get all open RFQs past their acceptance window
+    fn get_expired_rfqs(current_time: u64) -> Vec<RequestId>;
 }
 
 /// Trait for creating and managing policies
 pub trait PolicyApiV3<AccountId, Balance> {
+    /// Create a policy with its first fill by `underwriter`; `area` is set for area
+    /// requests, with `location_id` as its primary location
     fn create_policy(
         policy_id: PolicyId,
         holder: AccountId,
+        underwriter: AccountId,
         location_id: LocationId,
         event_spec: EventSpecV3,
         initial_shares: u128,
         premium_per_share: Balance,
         coverage_start: u64,
         coverage_end: u64,
+        area: Option<AreaSpecV3>,
     ) -> DispatchResult;
 
     fn add_shares_to_policy(
@@ -77,6 +120,13 @@ pub trait PolicyApiV3<AccountId, Balance> {
         shares: u128,
     ) -> DispatchResult;
 
+    /// Remove shares of an underwriter's fill from an active policy; returns the holder
+    fn remove_shares_from_policy(
+        policy_id: PolicyId,
+        underwriter: AccountId,
+        shares: u128,
+    ) -> Result<AccountId, DispatchError>;
+
     /// Allocate a specific amount to DeFi strategy (called after each acceptance)
     fn allocate_to_defi(policy_id: PolicyId, amount: Balance) -> DispatchResult;
 
@@ -105,11 +155,16 @@ impl LocationRegistryApiV3 for () {
     fn is_location_active(_: LocationId) -> bool {
         true
     }
+
+    fn find_nearest_location(_: i32, _: i32) -> Option<LocationId> {
+        None
+    }
 }
 
 impl<AccountId: Clone, Balance: Default> PolicyApiV3<AccountId, Balance> for () {
-    fn create_policy(_: PolicyId, _: AccountId, _: LocationId, _: EventSpecV3, _: u128, _: Balance, _: u64, _: u64) -> DispatchResult { Ok(()) }
+    fn create_policy(_: PolicyId, _: AccountId, _: AccountId, _: LocationId, _: EventSpecV3, _: u128, _: Balance, _: u64, _: u64, _: Option<AreaSpecV3>) -> DispatchResult { Ok(()) }
     fn add_shares_to_policy(_: PolicyId, _: AccountId, _: u128) -> DispatchResult { Ok(()) }
+    fn remove_shares_from_policy(_: PolicyId, _: AccountId, _: u128) -> Result<AccountId, DispatchError> { Err(DispatchError::Other("no policy api")) }
     fn allocate_to_defi(_: PolicyId, _: Balance) -> DispatchResult { Ok(()) }
     fn trigger_defi_allocation(_: PolicyId) -> DispatchResult { Ok(()) }
     fn policy_pool_account(_: PolicyId) -> AccountId { unimplemented!() }
@@ -160,12 +215,113 @@ pub mod pallet {
         pub created_at: u64,
     }
 
+    /// RFQ (negotiated request) status
+    #[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    pub enum RfqStatusV3 {
+        /// Collecting offers
+        Open,
+        /// An offer was accepted and the policy created
+        Accepted,
+        /// Cancelled by the requester
+        Cancelled,
+        /// Acceptance window passed without an accepted offer
+        Expired,
+    }
+
+    /// Request for quote: coverage terms without a premium, priced by underwriter offers
+    #[derive(Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct RfqRequest<T: Config> {
+        /// RFQ ID (also serves as policy_id once an offer is accepted)
+        pub rfq_id: RequestId,
+        /// Requester/policyholder
+        pub requester: T::AccountId,
+        /// Location ID from LocationRegistry
+        pub location_id: LocationId,
+        /// Event specification
+        pub event_spec: EventSpecV3,
+        /// Shares to be underwritten by the accepted offer
+        pub total_shares: u128,
+        /// Coverage start timestamp (must be in future at creation and acceptance)
+        pub coverage_start: u64,
+        /// Coverage end timestamp
+        pub coverage_end: u64,
+        /// Last timestamp at which offers are taken
+        pub offer_deadline: u64,
+        /// RFQ status
+        pub status: RfqStatusV3,
+        /// Created at timestamp
+        pub created_at: u64,
+    }
+
+    /// Underwriter offer on an RFQ; the collateral for all shares sits in escrow
+    #[derive(Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct RfqOffer<T: Config> {
+        /// Underwriter making the offer
+        pub underwriter: T::AccountId,
+        /// Offered premium per share
+        pub premium_per_share: T::Balance,
+        /// Collateral locked in escrow ((payout - premium) per share)
+        pub collateral: T::Balance,
+        /// Submitted at timestamp
+        pub submitted_at: u64,
+    }
+
+    /// Premium escrow ledger of an underwrite request. The premium still held is
+    /// `deposited - released - refunded` (see `EscrowBalance`)
+    #[derive(Clone, PartialEq, Eq, RuntimeDebug, Default, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    pub struct PremiumEscrowV3<Balance> {
+        /// Premium locked when the request was created
+        pub deposited: Balance,
+        /// Premium moved to the policy pool by acceptances
+        pub released: Balance,
+        /// Unfilled premium returned to the requester
+        pub refunded: Balance,
+    }
+
+    /// Underwriter collateral account. The funds sit in the pallet's collateral
+    /// account; `obligations` is the maximum loss of the account's open positions
+    #[derive(Clone, PartialEq, Eq, RuntimeDebug, Default, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    pub struct CollateralAccountV3<Balance> {
+        /// Collateral held for the underwriter
+        pub deposited: Balance,
+        /// Sum of the open positions' maximum loss
+        pub obligations: Balance,
+        /// Number of open positions
+        pub positions: u32,
+    }
+
+    /// Underwriter's fills of one request, backed by its collateral account
+    #[derive(Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    pub struct CollateralPositionV3<Balance> {
+        /// Shares still underwritten
+        pub shares: u128,
+        /// Premium per share of the request
+        pub premium_per_share: Balance,
+        /// Maximum loss ((payout - premium) per share)
+        pub obligation: Balance,
+    }
+
+    /// Open margin call on a collateral account below maintenance margin
+    #[derive(Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    pub struct MarginCallV3<BlockNumber, Balance> {
+        /// Collateral missing when the call was opened or last enforced
+        pub shortfall: Balance,
+        /// Block the call was opened
+        pub opened_at: BlockNumber,
+        /// Positions are reduced from this block on unless topped up
+        pub deadline: BlockNumber,
+        /// Last position a sweep tried to reduce; the next sweep continues after it
+        pub reduction_cursor: Option<PolicyId>,
+    }
+
     // =========================================================================
     //                                  Config
     // =========================================================================
 
     #[pallet::config]
-    pub trait Config: frame_system::Config + pallet_timestamp::Config {
+    pub trait Config: frame_system::Config {
         /// Runtime event type
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
@@ -204,6 +360,37 @@ pub mod pallet {
         /// Origin that can trigger request expiry (OCW)
         type ExpiryOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+        /// Maximum number of open offers per RFQ
+        #[pallet::constant]
+        type MaxOffersPerRfq: Get<u32>;
+
+        /// Maximum distinct underwriters filling one request
+        #[pallet::constant]
+        type MaxUnderwritersPerRequest: Get<u32>;
+
+        /// Collateral required at fill time, as a share of the account's obligations
+        #[pallet::constant]
+        type InitialMargin: Get<Permill>;
+
+        /// Collateral below which a margin call is opened, as a share of obligations
+        #[pallet::constant]
+        type MaintenanceMargin: Get<Permill>;
+
+        /// Blocks an underwriter has to top up before its positions are reduced
+        #[pallet::constant]
+        type MarginCallPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Collateral accounts checked per margin sweep
+        #[pallet::constant]
+        type MaxMarginChecksPerBlock: Get<u32>;
+
+        /// Maximum open positions per collateral account
+        #[pallet::constant]
+        type MaxPositionsPerUnderwriter: Get<u32>;
+
+        /// Source of chain time for request expiry, RFQ deadlines and coverage windows
+        type TimeProvider: ChainTime;
+
         /// Weight info for this pallet
         type WeightInfo: WeightInfo;
     }
@@ -211,15 +398,30 @@ pub mod pallet {
     /// Weight info trait
     pub trait WeightInfo {
         fn create_underwrite_request() -> Weight;
+        fn create_area_underwrite_request() -> Weight;
         fn cancel_underwrite_request() -> Weight;
         fn accept_underwrite_request() -> Weight;
         fn expire_request() -> Weight;
+        fn withdraw_unfilled_premium() -> Weight;
+        fn create_rfq() -> Weight;
+        fn submit_rfq_offer() -> Weight;
+        fn withdraw_rfq_offer() -> Weight;
+        fn accept_rfq_offer(o: u32) -> Weight;
+        fn cancel_rfq(o: u32) -> Weight;
+        fn expire_rfq(o: u32) -> Weight;
+        fn deposit_collateral() -> Weight;
+        fn withdraw_collateral() -> Weight;
+        fn check_margins(a: u32) -> Weight;
+        fn force_reduce_position() -> Weight;
     }
 
     impl WeightInfo for () {
         fn create_underwrite_request() -> Weight {
             Weight::from_parts(50_000, 0)
         }
+        fn create_area_underwrite_request() -> Weight {
+            Weight::from_parts(60_000, 0)
+        }
         fn cancel_underwrite_request() -> Weight {
             Weight::from_parts(30_000, 0)
         }
@@ -229,6 +431,43 @@ pub mod pallet {
         fn expire_request() -> Weight {
             Weight::from_parts(30_000, 0)
         }
+        fn withdraw_unfilled_premium() -> Weight {
+            Weight::from_parts(30_000, 0)
+        }
+        fn create_rfq() -> Weight {
+            Weight::from_parts(40_000, 0)
+        }
+        fn submit_rfq_offer() -> Weight {
+            Weight::from_parts(50_000, 0)
+        }
+        fn withdraw_rfq_offer() -> Weight {
+            Weight::from_parts(40_000, 0)
+        }
+        fn accept_rfq_offer(o: u32) -> Weight {
+            Weight::from_parts(120_000, 0)
+                .saturating_add(Weight::from_parts(20_000, 0).saturating_mul(o.into()))
+        }
+        fn cancel_rfq(o: u32) -> Weight {
+            Weight::from_parts(30_000, 0)
+                .saturating_add(Weight::from_parts(20_000, 0).saturating_mul(o.into()))
+        }
+        fn expire_rfq(o: u32) -> Weight {
+            Weight::from_parts(30_000, 0)
+                .saturating_add(Weight::from_parts(20_000, 0).saturating_mul(o.into()))
+        }
+        fn deposit_collateral() -> Weight {
+            Weight::from_parts(40_000, 0)
+        }
+        fn withdraw_collateral() -> Weight {
+            Weight::from_parts(40_000, 0)
+        }
+        fn check_margins(a: u32) -> Weight {
+            Weight::from_parts(10_000, 0)
+                .saturating_add(Weight::from_parts(15_000, 0).saturating_mul(a.into()))
+        }
+        fn force_reduce_position() -> Weight {
+            Weight::from_parts(80_000, 0)
+        }
     }
 
     // =========================================================================
@@ -294,12 +533,99 @@ pub mod pallet {
     pub type EscrowBalance<T: Config> =
         StorageMap<_, Blake2_128Concat, RequestId, T::Balance, ValueQuery>;
 
+    /// Premium deposited, released to the policy pool and refunded per request
+    #[pallet::storage]
+    #[pallet::getter(fn premium_escrows)]
+    pub type PremiumEscrows<T: Config> =
+        StorageMap<_, Blake2_128Concat, RequestId, PremiumEscrowV3<T::Balance>, ValueQuery>;
+
+    /// Shares filled per underwriter, in fill order
+    #[pallet::storage]
+    #[pallet::getter(fn request_fills)]
+    pub type RequestFills<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        RequestId,
+        BoundedVec<(T::AccountId, u128), T::MaxUnderwritersPerRequest>,
+        ValueQuery,
+    >;
+
+    /// Locations and aggregation of area requests; the request's `location_id`
+    /// is the area's primary location
+    #[pallet::storage]
+    #[pallet::getter(fn request_areas)]
+    pub type RequestAreas<T: Config> =
+        StorageMap<_, Blake2_128Concat, RequestId, AreaSpecV3, OptionQuery>;
+
+    /// Negotiated requests (RFQs) by ID
+    #[pallet::storage]
+    #[pallet::getter(fn rfq_requests)]
+    pub type RfqRequests<T: Config> =
+        StorageMap<_, Blake2_128Concat, RequestId, RfqRequest<T>, OptionQuery>;
+
+    /// Open offers per RFQ, at most one per underwriter
+    #[pallet::storage]
+    #[pallet::getter(fn rfq_offers)]
+    pub type RfqOffers<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        RequestId,
+        BoundedVec<RfqOffer<T>, T::MaxOffersPerRfq>,
+        ValueQuery,
+    >;
+
+    /// Collateral accounts of underwriters
+    #[pallet::storage]
+    #[pallet::getter(fn underwriter_collateral)]
+    pub type UnderwriterCollateral<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, CollateralAccountV3<T::Balance>, OptionQuery>;
+
+    /// Open positions backed by collateral accounts, per underwriter and policy
+    #[pallet::storage]
+    #[pallet::getter(fn collateral_positions)]
+    pub type CollateralPositions<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        PolicyId,
+        CollateralPositionV3<T::Balance>,
+        OptionQuery,
+    >;
+
+    /// Open margin calls per underwriter
+    #[pallet::storage]
+    #[pallet::getter(fn margin_calls)]
+    pub type MarginCalls<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        MarginCallV3<BlockNumberFor<T>, T::Balance>,
+        OptionQuery,
+    >;
+
+    /// Last collateral account checked by the margin sweep (None: start over)
+    #[pallet::storage]
+    pub type MarginCheckCursor<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
+
     // =========================================================================
     //                                  Hooks
     // =========================================================================
 
     #[pallet::hooks]
-    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// Sweep collateral accounts for margin calls (every
+        /// V3_MARGIN_CHECK_INTERVAL_BLOCKS blocks)
+        fn on_initialize(block_number: BlockNumberFor<T>) -> Weight {
+            let block_num: u32 = block_number.unique_saturated_into();
+
+            if block_num % V3_MARGIN_CHECK_INTERVAL_BLOCKS != 0 {
+                return Weight::zero();
+            }
+
+            Self::check_margins(block_number)
+        }
+    }
 
     // =========================================================================
     //                                  Events
@@ -335,12 +661,107 @@ pub mod pallet {
             request_id: RequestId,
             total_shares: u128,
         },
-        /// Request expired
+        /// Request expired by the OCW or `ExpiryOrigin`; unfilled premium returned
         RequestExpired {
             request_id: RequestId,
             unfilled_shares: u128,
             premium_returned: T::Balance,
         },
+        /// Requester reclaimed the unfilled premium of an expired request
+        UnfilledPremiumWithdrawn {
+            request_id: RequestId,
+            requester: T::AccountId,
+            unfilled_shares: u128,
+            premium_returned: T::Balance,
+        },
+        /// RFQ created
+        RfqCreated {
+            rfq_id: RequestId,
+            requester: T::AccountId,
+            total_shares: u128,
+            offer_deadline: u64,
+        },
+        /// Underwriter offered (or re-priced) a premium on an RFQ
+        RfqOfferSubmitted {
+            rfq_id: RequestId,
+            underwriter: T::AccountId,
+            premium_per_share: T::Balance,
+            collateral_locked: T::Balance,
+        },
+        /// Underwriter withdrew an offer; collateral returned
+        RfqOfferWithdrawn {
+            rfq_id: RequestId,
+            underwriter: T::AccountId,
+            collateral_returned: T::Balance,
+        },
+        /// Requester accepted an offer; policy created with the offered premium
+        RfqAccepted {
+            rfq_id: RequestId,
+            underwriter: T::AccountId,
+            premium_per_share: T::Balance,
+            total_premium: T::Balance,
+            offers_refunded: u32,
+        },
+        /// RFQ cancelled by requester; all offers refunded
+        RfqCancelled {
+            rfq_id: RequestId,
+            offers_refunded: u32,
+        },
+        /// RFQ expired without an accepted offer; all offers refunded
+        RfqExpired {
+            rfq_id: RequestId,
+            offers_refunded: u32,
+        },
+        /// Underwriter added collateral to its account
+        CollateralDeposited {
+            underwriter: T::AccountId,
+            amount: T::Balance,
+            deposited: T::Balance,
+        },
+        /// Underwriter withdrew free collateral from its account
+        CollateralWithdrawn {
+            underwriter: T::AccountId,
+            amount: T::Balance,
+            deposited: T::Balance,
+        },
+        /// Triggered policy drew a position's obligation from the collateral account;
+        /// `shortfall` is the part the collateral could not cover
+        ObligationDrawn {
+            policy_id: PolicyId,
+            underwriter: T::AccountId,
+            amount: T::Balance,
+            shortfall: T::Balance,
+        },
+        /// Matured policy released a position's obligation
+        ObligationReleased {
+            policy_id: PolicyId,
+            underwriter: T::AccountId,
+            obligation: T::Balance,
+        },
+        /// Collateral account fell below maintenance margin
+        MarginCallOpened {
+            underwriter: T::AccountId,
+            shortfall: T::Balance,
+            deadline: BlockNumberFor<T>,
+        },
+        /// Collateral account is back at maintenance margin
+        MarginCallCleared {
+            underwriter: T::AccountId,
+        },
+        /// Positions were reduced after a margin call's deadline but the account is
+        /// still short; the call stays open and the next sweep reduces further
+        MarginCallUnresolved {
+            underwriter: T::AccountId,
+            shortfall: T::Balance,
+        },
+        /// Position reduced after an unanswered margin call; the premium of the
+        /// removed shares was refunded to the holder from the collateral
+        PositionForceReduced {
+            underwriter: T::AccountId,
+            policy_id: PolicyId,
+            shares: u128,
+            premium_refunded: T::Balance,
+        },
     }
 
     // =========================================================================
@@ -383,6 +804,41 @@ pub mod pallet {
         RequestExpired,
         /// Request has active policy
         RequestHasActivePolicy,
+        /// Area needs at least two distinct locations and an aggregation the
+        /// event type supports
+        InvalidAreaSpec,
+        /// RFQ not found
+        RfqNotFound,
+        /// RFQ is not open
+        RfqNotOpen,
+        /// Offer deadline must be in the future and before coverage start
+        InvalidOfferDeadline,
+        /// Offers are no longer taken for this RFQ
+        OfferDeadlinePassed,
+        /// The RFQ's acceptance window has closed
+        AcceptanceWindowClosed,
+        /// Premium per share must be below the payout per share
+        PremiumTooHigh,
+        /// RFQ already holds `MaxOffersPerRfq` offers
+        TooManyOffers,
+        /// No offer from this underwriter
+        OfferNotFound,
+        /// RFQ acceptance window has not closed yet
+        RfqNotExpired,
+        /// Request already has `MaxUnderwritersPerRequest` underwriters
+        TooManyUnderwriters,
+        /// Collateral amount must be > 0
+        ZeroCollateralAmount,
+        /// Collateral account would fall below `InitialMargin` of its obligations, or
+        /// the underwriter cannot fund the missing collateral
+        InsufficientCollateral,
+        /// Collateral account already has `MaxPositionsPerUnderwriter` positions
+        TooManyPositions,
+        /// Collateral account has an open margin call
+        MarginCallOpen,
+        /// Threshold is malformed for the event type (e.g. a cyclone category
+        /// outside 1-5 or radius above `V3_MAX_CYCLONE_RADIUS_KM`)
+        InvalidEventThreshold,
     }
 
     // =========================================================================
@@ -407,77 +863,16 @@ pub mod pallet {
         ) -> DispatchResult {
             let requester = ensure_signed(origin)?;
 
-            // Validate premium > 0
-            ensure!(
-                premium_per_share > T::Balance::zero(),
-                Error::<T>::PremiumMustBePositive
-            );
-
-            // Validate shares
-            ensure!(total_shares >= 1, Error::<T>::InvalidSharesAmount);
-
-            // Validate coverage window
-            let now = Self::current_timestamp();
-            ensure!(coverage_start > now, Error::<T>::CoverageStartMustBeFuture);
-            ensure!(coverage_end > coverage_start, Error::<T>::InvalidCoverageWindow);
-
-            // Validate location
-            ensure!(
-                T::LocationRegistry::is_location_active(location_id),
-                Error::<T>::LocationNotValid
-            );
-
-            // Calculate total premium
-            let premium_per_share_u128: u128 = premium_per_share.into();
-            let total_premium_u128 = total_shares
-                .checked_mul(premium_per_share_u128)
-                .ok_or(Error::<T>::ArithmeticOverflow)?;
-            let total_premium: T::Balance = total_premium_u128.into();
-
-            // Transfer premium to escrow
-            let escrow_account = Self::escrow_account();
-            T::Assets::transfer(
-                T::UsdtAssetId::get(),
-                &requester,
-                &escrow_account,
-                total_premium,
-                Preservation::Expendable,
-            )
-            .map_err(|_| Error::<T>::InsufficientFunds)?;
-
-            // Generate unique request ID using hash-based approach
-            let nonce = AccountNonce::<T>::get(&requester);
-            let request_id = generate_unique_id(b"V3", &requester, now, nonce);
-            AccountNonce::<T>::insert(&requester, nonce + 1);
-            
-            let payout_per_share: T::Balance = V3_PAYOUT_PER_SHARE.into();
-
-            let request = UnderwriteRequest {
-                request_id,
-                requester: requester.clone(),
+            Self::do_create_request(
+                requester,
                 location_id,
                 event_spec,
                 total_shares,
-                filled_shares: 0,
                 premium_per_share,
-                payout_per_share,
                 coverage_start,
                 coverage_end,
                 expires_at,
-                status: RequestStatusV3::Pending,
-                created_at: now,
-            };
-
-            UnderwriteRequests::<T>::insert(request_id, request);
-            EscrowBalance::<T>::insert(request_id, total_premium);
-
-            Self::deposit_event(Event::RequestCreated {
-                request_id,
-                requester,
-                total_shares,
-                premium_per_share,
-                expires_at,
-            });
+            )?;
 
             Ok(())
         }
@@ -502,34 +897,11 @@ pub mod pallet {
                 Error::<T>::RequestNotAcceptable
             );
 
-            let unfilled_shares = request
-                .total_shares
-                .saturating_sub(request.filled_shares);
-
-            // Calculate unfilled premium to return
-            let premium_per_share_u128: u128 = request.premium_per_share.into();
-            let unfilled_premium_u128 = unfilled_shares
-                .checked_mul(premium_per_share_u128)
-                .ok_or(Error::<T>::ArithmeticOverflow)?;
-            let unfilled_premium: T::Balance = unfilled_premium_u128.into();
-
-            // Return unfilled premium
-            if unfilled_premium > T::Balance::zero() {
-                let escrow_account = Self::escrow_account();
-                T::Assets::transfer(
-                    T::UsdtAssetId::get(),
-                    &escrow_account,
-                    &request.requester,
-                    unfilled_premium,
-                    Preservation::Expendable,
-                )
-                .map_err(|_| Error::<T>::TransferFailed)?;
-            }
+            let (unfilled_shares, unfilled_premium) = Self::refund_unfilled_premium(&request)?;
 
             // Update request status
             request.status = RequestStatusV3::Cancelled;
             UnderwriteRequests::<T>::insert(request_id, request);
-            EscrowBalance::<T>::insert(request_id, T::Balance::zero());
 
             Self::deposit_event(Event::RequestCancelled {
                 request_id,
@@ -541,6 +913,9 @@ pub mod pallet {
         }
 
         /// Accept shares from a request.
+        /// The shares' maximum loss is added to the underwriter's collateral account
+        /// obligations, which must stay covered to `InitialMargin`; collateral the
+        /// account lacks is taken from the underwriter.
         /// Creates policy on first acceptance, adds LP holder on subsequent.
         #[pallet::call_index(2)]
         #[pallet::weight(<T as Config>::WeightInfo::accept_underwrite_request())]
@@ -570,7 +945,7 @@ pub mod pallet {
             );
 
             // Check request has not expired
-            let now = Self::current_timestamp();
+            let now = T::TimeProvider::now_secs();
             ensure!(now < request.expires_at, Error::<T>::RequestExpired);
 
             let remaining_shares = request
@@ -598,19 +973,31 @@ pub mod pallet {
 
             let is_first_acceptance = request.filled_shares == 0;
 
+            RequestFills::<T>::try_mutate(request_id, |fills| -> DispatchResult {
+                if let Some((_, filled)) = fills.iter_mut().find(|(who, _)| *who == underwriter) {
+                    *filled = filled
+                        .checked_add(shares_to_accept)
+                        .ok_or(Error::<T>::ArithmeticOverflow)?;
+                    return Ok(());
+                }
+                fills
+                    .try_push((underwriter.clone(), shares_to_accept))
+                    .map_err(|_| Error::<T>::TooManyUnderwriters)?;
+                Ok(())
+            })?;
+
             // Get policy pool account
             let policy_id = request_id; // 1:1 mapping
             let policy_pool = T::PolicyApi::policy_pool_account(policy_id);
 
-            // Transfer collateral from underwriter to policy pool
-            T::Assets::transfer(
-                T::UsdtAssetId::get(),
+            // Back the shares' maximum loss with the underwriter's collateral account
+            Self::lock_obligation(
                 &underwriter,
-                &policy_pool,
+                policy_id,
+                shares_to_accept,
+                request.premium_per_share,
                 total_collateral,
-                Preservation::Expendable,
-            )
-            .map_err(|_| Error::<T>::InsufficientFunds)?;
+            )?;
 
             // Transfer premium from escrow to policy pool
             let escrow_account = Self::escrow_account();
@@ -627,18 +1014,23 @@ pub mod pallet {
             EscrowBalance::<T>::mutate(request_id, |balance| {
                 *balance = balance.saturating_sub(premium_for_shares);
             });
+            PremiumEscrows::<T>::mutate(request_id, |escrow| {
+                escrow.released = escrow.released.saturating_add(premium_for_shares);
+            });
 
             if is_first_acceptance {
                 // Create policy
                 T::PolicyApi::create_policy(
                     policy_id,
                     request.requester.clone(),
+                    underwriter.clone(),
                     request.location_id,
                     request.event_spec.clone(),
                     shares_to_accept,
                     request.premium_per_share,
                     request.coverage_start,
                     request.coverage_end,
+                    RequestAreas::<T>::get(request_id),
                 )?;
             } else {
                 // Add shares to existing policy
@@ -676,10 +1068,9 @@ pub mod pallet {
                 is_first_acceptance,
             });
 
-            // Allocate collateral + premium to DeFi incrementally (after each acceptance)
-            // This ensures 100% of pool funds are allocated to maximize yield
-            let total_to_allocate = total_collateral.saturating_add(premium_for_shares);
-            if let Err(e) = T::PolicyApi::allocate_to_defi(policy_id, total_to_allocate) {
+            // Allocate the premium to DeFi incrementally (after each acceptance); the
+            // collateral stays in the underwriter's collateral account
+            if let Err(e) = T::PolicyApi::allocate_to_defi(policy_id, premium_for_shares) {
                 log::warn!(
                     target: "pallet-market-v3",
                     "⚠️ Incremental DeFi allocation failed for policy {}: {:?}",
@@ -719,61 +1110,491 @@ pub mod pallet {
             ensure_none(origin)?;
             Self::do_expire_request(request_id)
         }
-    }
-
-    // =========================================================================
-    //                           Helper Functions
-    // =========================================================================
-
-    impl<T: Config> Pallet<T> {
-        /// Get the global escrow account for premium deposits
-        pub fn escrow_account() -> T::AccountId {
-            PALLET_ID.into_sub_account_truncating(("escrow",))
-        }
 
-        /// Get current timestamp from pallet_timestamp
-        /// Returns Unix timestamp in seconds
-        fn current_timestamp() -> u64 {
-            // pallet_timestamp returns milliseconds, convert to seconds
-            let moment = <pallet_timestamp::Pallet<T>>::now();
-            // Convert Moment to u64 (Moment is typically u64 in milliseconds)
-            let millis: u64 = moment.try_into().unwrap_or(0);
-            millis / 1000
-        }
-
-        /// Internal implementation of request expiry
-        pub fn do_expire_request(request_id: RequestId) -> DispatchResult {
-            let mut request =
-                UnderwriteRequests::<T>::get(request_id).ok_or(Error::<T>::RequestNotFound)?;
+        /// Create an underwrite request covering an area of locations.
+        /// The first location is the primary one; the oracle combines the
+        /// locations' aggregated values by `area.aggregation`.
+        #[pallet::call_index(5)]
+        #[pallet::weight(<T as Config>::WeightInfo::create_area_underwrite_request())]
+        pub fn create_area_underwrite_request(
+            origin: OriginFor<T>,
+            area: AreaSpecV3,
+            event_spec: EventSpecV3,
+            total_shares: u128,
+            premium_per_share: T::Balance,
+            coverage_start: u64,
+            coverage_end: u64,
+            expires_at: u64,
+        ) -> DispatchResult {
+            let requester = ensure_signed(origin)?;
 
-            // Validate request can be expired
+            ensure!(area.is_valid_for(event_spec.event_type), Error::<T>::InvalidAreaSpec);
+            let primary = area.primary().ok_or(Error::<T>::InvalidAreaSpec)?;
             ensure!(
-                request.status == RequestStatusV3::Pending
-                    || request.status == RequestStatusV3::PartiallyFilled,
-                Error::<T>::RequestNotAcceptable
+                area.locations
+                    .iter()
+                    .all(|location_id| T::LocationRegistry::is_location_active(*location_id)),
+                Error::<T>::LocationNotValid
             );
 
-            // Note: For unsigned transactions, we trust the OCW has validated expiry
-            // For signed transactions, we check the timestamp
-            // In production, use pallet_timestamp for reliable time
-
-            let unfilled_shares = request
+            let request_id = Self::do_create_request(
+                requester,
+                primary,
+                event_spec,
+                total_shares,
+                premium_per_share,
+                coverage_start,
+                coverage_end,
+                expires_at,
+            )?;
+            RequestAreas::<T>::insert(request_id, area);
+
+            Ok(())
+        }
+
+        /// Reclaim the unfilled premium of a request past its expiry, without waiting
+        /// for the OCW. Filled shares stay with the policy; the request is marked
+        /// expired.
+        #[pallet::call_index(12)]
+        #[pallet::weight(<T as Config>::WeightInfo::withdraw_unfilled_premium())]
+        pub fn withdraw_unfilled_premium(
+            origin: OriginFor<T>,
+            request_id: RequestId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let mut request =
+                UnderwriteRequests::<T>::get(request_id).ok_or(Error::<T>::RequestNotFound)?;
+
+            ensure!(request.requester == who, Error::<T>::NotRequester);
+            ensure!(
+                request.status == RequestStatusV3::Pending
+                    || request.status == RequestStatusV3::PartiallyFilled,
+                Error::<T>::RequestNotAcceptable
+            );
+            ensure!(
+                T::TimeProvider::now_secs() >= request.expires_at,
+                Error::<T>::RequestNotExpired
+            );
+
+            let (unfilled_shares, unfilled_premium) = Self::refund_unfilled_premium(&request)?;
+
+            request.status = RequestStatusV3::Expired;
+            UnderwriteRequests::<T>::insert(request_id, request);
+
+            Self::deposit_event(Event::UnfilledPremiumWithdrawn {
+                request_id,
+                requester: who,
+                unfilled_shares,
+                premium_returned: unfilled_premium,
+            });
+
+            Ok(())
+        }
+
+        /// Create a request for quote: coverage terms without a premium.
+        /// Underwriters respond with offers until `offer_deadline`; no funds are
+        /// locked from the requester until an offer is accepted.
+        #[pallet::call_index(6)]
+        #[pallet::weight(<T as Config>::WeightInfo::create_rfq())]
+        pub fn create_rfq(
+            origin: OriginFor<T>,
+            location_id: LocationId,
+            event_spec: EventSpecV3,
+            total_shares: u128,
+            coverage_start: u64,
+            coverage_end: u64,
+            offer_deadline: u64,
+        ) -> DispatchResult {
+            let requester = ensure_signed(origin)?;
+
+            ensure!(total_shares >= 1, Error::<T>::InvalidSharesAmount);
+            ensure!(event_spec.has_valid_threshold(), Error::<T>::InvalidEventThreshold);
+
+            let now = T::TimeProvider::now_secs();
+            ensure!(coverage_start > now, Error::<T>::CoverageStartMustBeFuture);
+            ensure!(coverage_end > coverage_start, Error::<T>::InvalidCoverageWindow);
+            ensure!(
+                offer_deadline > now && offer_deadline < coverage_start,
+                Error::<T>::InvalidOfferDeadline
+            );
+            ensure!(
+                T::LocationRegistry::is_location_active(location_id),
+                Error::<T>::LocationNotValid
+            );
+
+            let nonce = AccountNonce::<T>::get(&requester);
+            let rfq_id = generate_unique_id(b"V3RFQ", &requester, now, nonce);
+            AccountNonce::<T>::insert(&requester, nonce + 1);
+
+            RfqRequests::<T>::insert(
+                rfq_id,
+                RfqRequest {
+                    rfq_id,
+                    requester: requester.clone(),
+                    location_id,
+                    event_spec,
+                    total_shares,
+                    coverage_start,
+                    coverage_end,
+                    offer_deadline,
+                    status: RfqStatusV3::Open,
+                    created_at: now,
+                },
+            );
+
+            Self::deposit_event(Event::RfqCreated {
+                rfq_id,
+                requester,
+                total_shares,
+                offer_deadline,
+            });
+
+            Ok(())
+        }
+
+        /// Offer a premium per share on an open RFQ.
+        /// Collateral for all shares is transferred to the RFQ escrow. Submitting
+        /// again re-prices the offer and replaces its collateral.
+        #[pallet::call_index(7)]
+        #[pallet::weight(<T as Config>::WeightInfo::submit_rfq_offer())]
+        pub fn submit_rfq_offer(
+            origin: OriginFor<T>,
+            rfq_id: RequestId,
+            premium_per_share: T::Balance,
+        ) -> DispatchResult {
+            let underwriter = ensure_signed(origin)?;
+
+            let rfq = RfqRequests::<T>::get(rfq_id).ok_or(Error::<T>::RfqNotFound)?;
+
+            ensure!(rfq.status == RfqStatusV3::Open, Error::<T>::RfqNotOpen);
+            ensure!(underwriter != rfq.requester, Error::<T>::CannotSelfUnderwrite);
+            ensure!(
+                premium_per_share > T::Balance::zero(),
+                Error::<T>::PremiumMustBePositive
+            );
+            let premium_per_share_u128: u128 = premium_per_share.into();
+            ensure!(
+                premium_per_share_u128 < V3_PAYOUT_PER_SHARE,
+                Error::<T>::PremiumTooHigh
+            );
+
+            let now = T::TimeProvider::now_secs();
+            ensure!(now <= rfq.offer_deadline, Error::<T>::OfferDeadlinePassed);
+
+            let collateral_u128 = rfq
                 .total_shares
-                .saturating_sub(request.filled_shares);
+                .checked_mul(V3_PAYOUT_PER_SHARE - premium_per_share_u128)
+                .ok_or(Error::<T>::ArithmeticOverflow)?;
+            let collateral: T::Balance = collateral_u128.into();
 
-            // Calculate unfilled premium to return
-            let premium_per_share_u128: u128 = request.premium_per_share.into();
-            let unfilled_premium_u128 = unfilled_shares
+            RfqOffers::<T>::try_mutate(rfq_id, |offers| -> DispatchResult {
+                // Re-pricing returns the previous collateral first
+                if let Some(pos) = offers.iter().position(|o| o.underwriter == underwriter) {
+                    let previous = offers.remove(pos);
+                    Self::refund_rfq_offer(&previous)?;
+                }
+
+                T::Assets::transfer(
+                    T::UsdtAssetId::get(),
+                    &underwriter,
+                    &Self::rfq_escrow_account(),
+                    collateral,
+                    Preservation::Expendable,
+                )
+                .map_err(|_| Error::<T>::InsufficientFunds)?;
+
+                offers
+                    .try_push(RfqOffer {
+                        underwriter: underwriter.clone(),
+                        premium_per_share,
+                        collateral,
+                        submitted_at: now,
+                    })
+                    .map_err(|_| Error::<T>::TooManyOffers)?;
+
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::RfqOfferSubmitted {
+                rfq_id,
+                underwriter,
+                premium_per_share,
+                collateral_locked: collateral,
+            });
+
+            Ok(())
+        }
+
+        /// Withdraw an offer from an RFQ and return its collateral.
+        #[pallet::call_index(8)]
+        #[pallet::weight(<T as Config>::WeightInfo::withdraw_rfq_offer())]
+        pub fn withdraw_rfq_offer(origin: OriginFor<T>, rfq_id: RequestId) -> DispatchResult {
+            let underwriter = ensure_signed(origin)?;
+
+            let offer = RfqOffers::<T>::try_mutate(rfq_id, |offers| {
+                let pos = offers
+                    .iter()
+                    .position(|o| o.underwriter == underwriter)
+                    .ok_or(Error::<T>::OfferNotFound)?;
+                Ok::<_, DispatchError>(offers.remove(pos))
+            })?;
+            Self::refund_rfq_offer(&offer)?;
+
+            Self::deposit_event(Event::RfqOfferWithdrawn {
+                rfq_id,
+                underwriter,
+                collateral_returned: offer.collateral,
+            });
+
+            Ok(())
+        }
+
+        /// Accept an underwriter's offer on an RFQ.
+        /// Transfers the premium from the requester and the offer's collateral from
+        /// escrow to the policy pool, creates the policy (initializing its oracle
+        /// state) and refunds all other offers, in one call.
+        #[pallet::call_index(9)]
+        #[pallet::weight(<T as Config>::WeightInfo::accept_rfq_offer(T::MaxOffersPerRfq::get()))]
+        pub fn accept_rfq_offer(
+            origin: OriginFor<T>,
+            rfq_id: RequestId,
+            underwriter: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let mut rfq = RfqRequests::<T>::get(rfq_id).ok_or(Error::<T>::RfqNotFound)?;
+
+            ensure!(rfq.requester == who, Error::<T>::NotRequester);
+            ensure!(rfq.status == RfqStatusV3::Open, Error::<T>::RfqNotOpen);
+
+            let now = T::TimeProvider::now_secs();
+            ensure!(
+                now < Self::rfq_acceptance_deadline(&rfq),
+                Error::<T>::AcceptanceWindowClosed
+            );
+            ensure!(rfq.coverage_start > now, Error::<T>::CoverageStartMustBeFuture);
+
+            let offers = RfqOffers::<T>::take(rfq_id);
+            let offer = offers
+                .iter()
+                .find(|o| o.underwriter == underwriter)
+                .cloned()
+                .ok_or(Error::<T>::OfferNotFound)?;
+
+            let premium_per_share_u128: u128 = offer.premium_per_share.into();
+            let total_premium_u128 = rfq
+                .total_shares
                 .checked_mul(premium_per_share_u128)
                 .ok_or(Error::<T>::ArithmeticOverflow)?;
-            let unfilled_premium: T::Balance = unfilled_premium_u128.into();
+            let total_premium: T::Balance = total_premium_u128.into();
+
+            let policy_id = rfq_id; // 1:1 mapping
+            let policy_pool = T::PolicyApi::policy_pool_account(policy_id);
+
+            // Premium from requester to policy pool
+            T::Assets::transfer(
+                T::UsdtAssetId::get(),
+                &rfq.requester,
+                &policy_pool,
+                total_premium,
+                Preservation::Expendable,
+            )
+            .map_err(|_| Error::<T>::InsufficientFunds)?;
+
+            // Accepted offer's collateral from escrow to policy pool
+            T::Assets::transfer(
+                T::UsdtAssetId::get(),
+                &Self::rfq_escrow_account(),
+                &policy_pool,
+                offer.collateral,
+                Preservation::Expendable,
+            )
+            .map_err(|_| Error::<T>::TransferFailed)?;
+
+            let mut offers_refunded = 0u32;
+            for other in offers.iter().filter(|o| o.underwriter != underwriter) {
+                Self::refund_rfq_offer(other)?;
+                offers_refunded += 1;
+            }
+
+            T::PolicyApi::create_policy(
+                policy_id,
+                rfq.requester.clone(),
+                underwriter.clone(),
+                rfq.location_id,
+                rfq.event_spec.clone(),
+                rfq.total_shares,
+                offer.premium_per_share,
+                rfq.coverage_start,
+                rfq.coverage_end,
+                None,
+            )?;
+
+            T::HoldingsApi::mint_lp_tokens(policy_id, &underwriter, rfq.total_shares)?;
+            T::HoldingsApi::register_lp_holder(policy_id, &underwriter)?;
+
+            rfq.status = RfqStatusV3::Accepted;
+            RfqRequests::<T>::insert(rfq_id, rfq);
+
+            Self::deposit_event(Event::RfqAccepted {
+                rfq_id,
+                underwriter,
+                premium_per_share: offer.premium_per_share,
+                total_premium,
+                offers_refunded,
+            });
+
+            let total_to_allocate = offer.collateral.saturating_add(total_premium);
+            if let Err(e) = T::PolicyApi::allocate_to_defi(policy_id, total_to_allocate) {
+                log::warn!(
+                    target: "pallet-market-v3",
+                    "⚠️ DeFi allocation failed for RFQ policy {}: {:?}",
+                    policy_id,
+                    e
+                );
+                // Don't fail - DeFi allocation is optional
+            }
+
+            Ok(())
+        }
+
+        /// Cancel an open RFQ. All offers are refunded.
+        #[pallet::call_index(10)]
+        #[pallet::weight(<T as Config>::WeightInfo::cancel_rfq(T::MaxOffersPerRfq::get()))]
+        pub fn cancel_rfq(origin: OriginFor<T>, rfq_id: RequestId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let mut rfq = RfqRequests::<T>::get(rfq_id).ok_or(Error::<T>::RfqNotFound)?;
+
+            ensure!(rfq.requester == who, Error::<T>::NotRequester);
+            ensure!(rfq.status == RfqStatusV3::Open, Error::<T>::RfqNotOpen);
+
+            let offers_refunded = Self::refund_rfq_offers(rfq_id)?;
+
+            rfq.status = RfqStatusV3::Cancelled;
+            RfqRequests::<T>::insert(rfq_id, rfq);
+
+            Self::deposit_event(Event::RfqCancelled {
+                rfq_id,
+                offers_refunded,
+            });
+
+            Ok(())
+        }
+
+        /// Expire an RFQ whose acceptance window has closed.
+        /// Called by governance/sudo; the OCW expires RFQs through oracle-v3.
+        #[pallet::call_index(11)]
+        #[pallet::weight(<T as Config>::WeightInfo::expire_rfq(T::MaxOffersPerRfq::get()))]
+        pub fn expire_rfq(origin: OriginFor<T>, rfq_id: RequestId) -> DispatchResult {
+            T::ExpiryOrigin::ensure_origin(origin)?;
+            Self::do_expire_rfq(rfq_id)
+        }
+
+        /// Add collateral to the caller's collateral account.
+        /// Clears an open margin call once the account is back at maintenance margin.
+        #[pallet::call_index(13)]
+        #[pallet::weight(<T as Config>::WeightInfo::deposit_collateral())]
+        pub fn deposit_collateral(origin: OriginFor<T>, amount: T::Balance) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(amount > T::Balance::zero(), Error::<T>::ZeroCollateralAmount);
+
+            T::Assets::transfer(
+                T::UsdtAssetId::get(),
+                &who,
+                &Self::collateral_account(),
+                amount,
+                Preservation::Expendable,
+            )
+            .map_err(|_| Error::<T>::InsufficientFunds)?;
+
+            let mut account = UnderwriterCollateral::<T>::get(&who).unwrap_or_default();
+            account.deposited = account.deposited.saturating_add(amount);
+
+            if MarginCalls::<T>::contains_key(&who)
+                && Self::margin_shortfall(&account, T::MaintenanceMargin::get()).is_zero()
+            {
+                MarginCalls::<T>::remove(&who);
+                Self::deposit_event(Event::MarginCallCleared {
+                    underwriter: who.clone(),
+                });
+            }
+
+            Self::deposit_event(Event::CollateralDeposited {
+                underwriter: who.clone(),
+                amount,
+                deposited: account.deposited,
+            });
+            Self::store_collateral_account(&who, account);
+
+            Ok(())
+        }
+
+        /// Withdraw collateral not needed to cover the account's obligations to
+        /// `InitialMargin`.
+        #[pallet::call_index(14)]
+        #[pallet::weight(<T as Config>::WeightInfo::withdraw_collateral())]
+        pub fn withdraw_collateral(origin: OriginFor<T>, amount: T::Balance) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(amount > T::Balance::zero(), Error::<T>::ZeroCollateralAmount);
+
+            let mut account =
+                UnderwriterCollateral::<T>::get(&who).ok_or(Error::<T>::InsufficientCollateral)?;
+            ensure!(amount <= account.deposited, Error::<T>::InsufficientCollateral);
+
+            account.deposited = account.deposited.saturating_sub(amount);
+            ensure!(
+                Self::margin_shortfall(&account, T::InitialMargin::get()).is_zero(),
+                Error::<T>::InsufficientCollateral
+            );
+
+            T::Assets::transfer(
+                T::UsdtAssetId::get(),
+                &Self::collateral_account(),
+                &who,
+                amount,
+                Preservation::Expendable,
+            )
+            .map_err(|_| Error::<T>::TransferFailed)?;
+
+            Self::deposit_event(Event::CollateralWithdrawn {
+                underwriter: who.clone(),
+                amount,
+                deposited: account.deposited,
+            });
+            Self::store_collateral_account(&who, account);
+
+            Ok(())
+        }
+    }
+
+    // =========================================================================
+    //                           Helper Functions
+    // =========================================================================
+
+    impl<T: Config> Pallet<T> {
+        /// Get the global escrow account for premium deposits
+        pub fn escrow_account() -> T::AccountId {
+            PALLET_ID.into_sub_account_truncating(("escrow",))
+        }
+
+        /// Return the premium still held for a request's unfilled shares to the
+        /// requester and record the refund. Returns (unfilled_shares, premium_returned).
+        fn refund_unfilled_premium(
+            request: &UnderwriteRequest<T>,
+        ) -> Result<(u128, T::Balance), DispatchError> {
+            let unfilled_shares = request
+                .total_shares
+                .saturating_sub(request.filled_shares);
+            let unfilled_premium = EscrowBalance::<T>::take(request.request_id);
 
-            // Return unfilled premium
             if unfilled_premium > T::Balance::zero() {
-                let escrow_account = Self::escrow_account();
                 T::Assets::transfer(
                     T::UsdtAssetId::get(),
-                    &escrow_account,
+                    &Self::escrow_account(),
                     &request.requester,
                     unfilled_premium,
                     Preservation::Expendable,
@@ -781,10 +1602,195 @@ pub mod pallet {
                 .map_err(|_| Error::<T>::TransferFailed)?;
             }
 
+            PremiumEscrows::<T>::mutate(request.request_id, |escrow| {
+                escrow.refunded = escrow.refunded.saturating_add(unfilled_premium);
+            });
+
+            Ok((unfilled_shares, unfilled_premium))
+        }
+
+        /// Get the escrow account holding RFQ offer collateral
+        pub fn rfq_escrow_account() -> T::AccountId {
+            PALLET_ID.into_sub_account_truncating(("rfq",))
+        }
+
+        /// Timestamp from which offers on an RFQ can no longer be accepted
+        pub fn rfq_acceptance_deadline(rfq: &RfqRequest<T>) -> u64 {
+            rfq.offer_deadline.saturating_add(V3_RFQ_ACCEPTANCE_WINDOW_SECS)
+        }
+
+        /// Return an offer's collateral from the RFQ escrow to its underwriter
+        fn refund_rfq_offer(offer: &RfqOffer<T>) -> DispatchResult {
+            if offer.collateral > T::Balance::zero() {
+                T::Assets::transfer(
+                    T::UsdtAssetId::get(),
+                    &Self::rfq_escrow_account(),
+                    &offer.underwriter,
+                    offer.collateral,
+                    Preservation::Expendable,
+                )
+                .map_err(|_| Error::<T>::TransferFailed)?;
+            }
+            Ok(())
+        }
+
+        /// Remove and refund all offers of an RFQ, returning how many were refunded
+        fn refund_rfq_offers(rfq_id: RequestId) -> Result<u32, DispatchError> {
+            let offers = RfqOffers::<T>::take(rfq_id);
+            for offer in offers.iter() {
+                Self::refund_rfq_offer(offer)?;
+            }
+            Ok(offers.len() as u32)
+        }
+
+        /// Internal implementation of RFQ expiry
+        pub fn do_expire_rfq(rfq_id: RequestId) -> DispatchResult {
+            let mut rfq = RfqRequests::<T>::get(rfq_id).ok_or(Error::<T>::RfqNotFound)?;
+
+            ensure!(rfq.status == RfqStatusV3::Open, Error::<T>::RfqNotOpen);
+            ensure!(
+                T::TimeProvider::now_secs() >= Self::rfq_acceptance_deadline(&rfq),
+                Error::<T>::RfqNotExpired
+            );
+
+            let offers_refunded = Self::refund_rfq_offers(rfq_id)?;
+
+            rfq.status = RfqStatusV3::Expired;
+            RfqRequests::<T>::insert(rfq_id, rfq);
+
+            Self::deposit_event(Event::RfqExpired {
+                rfq_id,
+                offers_refunded,
+            });
+
+            Ok(())
+        }
+
+        /// Get open RFQs whose acceptance window has closed
+        pub fn get_expired_rfqs_internal(current_time: u64) -> Vec<RequestId> {
+            RfqRequests::<T>::iter()
+                .filter(|(_, rfq)| {
+                    rfq.status == RfqStatusV3::Open
+                        && current_time >= Self::rfq_acceptance_deadline(rfq)
+                })
+                .map(|(id, _)| id)
+                .collect()
+        }
+
+        /// Validate a request, escrow its premium and store it
+        fn do_create_request(
+            requester: T::AccountId,
+            location_id: LocationId,
+            event_spec: EventSpecV3,
+            total_shares: u128,
+            premium_per_share: T::Balance,
+            coverage_start: u64,
+            coverage_end: u64,
+            expires_at: u64,
+        ) -> Result<RequestId, DispatchError> {
+            // Validate premium > 0
+            ensure!(
+                premium_per_share > T::Balance::zero(),
+                Error::<T>::PremiumMustBePositive
+            );
+
+            // Validate shares
+            ensure!(total_shares >= 1, Error::<T>::InvalidSharesAmount);
+            ensure!(event_spec.has_valid_threshold(), Error::<T>::InvalidEventThreshold);
+
+            // Validate coverage window
+            let now = T::TimeProvider::now_secs();
+            ensure!(coverage_start > now, Error::<T>::CoverageStartMustBeFuture);
+            ensure!(coverage_end > coverage_start, Error::<T>::InvalidCoverageWindow);
+
+            // Validate location
+            ensure!(
+                T::LocationRegistry::is_location_active(location_id),
+                Error::<T>::LocationNotValid
+            );
+
+            // Calculate total premium
+            let premium_per_share_u128: u128 = premium_per_share.into();
+            let total_premium_u128 = total_shares
+                .checked_mul(premium_per_share_u128)
+                .ok_or(Error::<T>::ArithmeticOverflow)?;
+            let total_premium: T::Balance = total_premium_u128.into();
+
+            // Transfer premium to escrow
+            let escrow_account = Self::escrow_account();
+            T::Assets::transfer(
+                T::UsdtAssetId::get(),
+                &requester,
+                &escrow_account,
+                total_premium,
+                Preservation::Expendable,
+            )
+            .map_err(|_| Error::<T>::InsufficientFunds)?;
+
+            // Generate unique request ID using hash-based approach
+            let nonce = AccountNonce::<T>::get(&requester);
+            let request_id = generate_unique_id(b"V3", &requester, now, nonce);
+            AccountNonce::<T>::insert(&requester, nonce + 1);
+            
+            let payout_per_share: T::Balance = V3_PAYOUT_PER_SHARE.into();
+
+            let request = UnderwriteRequest {
+                request_id,
+                requester: requester.clone(),
+                location_id,
+                event_spec,
+                total_shares,
+                filled_shares: 0,
+                premium_per_share,
+                payout_per_share,
+                coverage_start,
+                coverage_end,
+                expires_at,
+                status: RequestStatusV3::Pending,
+                created_at: now,
+            };
+
+            UnderwriteRequests::<T>::insert(request_id, request);
+            EscrowBalance::<T>::insert(request_id, total_premium);
+            PremiumEscrows::<T>::insert(
+                request_id,
+                PremiumEscrowV3 {
+                    deposited: total_premium,
+                    ..Default::default()
+                },
+            );
+
+            Self::deposit_event(Event::RequestCreated {
+                request_id,
+                requester,
+                total_shares,
+                premium_per_share,
+                expires_at,
+            });
+
+            Ok(request_id)
+        }
+
+        /// Internal implementation of request expiry
+        pub fn do_expire_request(request_id: RequestId) -> DispatchResult {
+            let mut request =
+                UnderwriteRequests::<T>::get(request_id).ok_or(Error::<T>::RequestNotFound)?;
+
+            // Validate request can be expired
+            ensure!(
+                request.status == RequestStatusV3::Pending
+                    || request.status == RequestStatusV3::PartiallyFilled,
+                Error::<T>::RequestNotAcceptable
+            );
+
+            // Note: For unsigned transactions, we trust the OCW has validated expiry
+            // For signed transactions, we check the timestamp
+
+            let (unfilled_shares, unfilled_premium) = Self::refund_unfilled_premium(&request)?;
+
             // Update request status
             request.status = RequestStatusV3::Expired;
             UnderwriteRequests::<T>::insert(request_id, request);
-            EscrowBalance::<T>::insert(request_id, T::Balance::zero());
 
             Self::deposit_event(Event::RequestExpired {
                 request_id,
@@ -833,6 +1839,383 @@ pub mod pallet {
                 false
             }
         }
+
+        /// Get the account holding underwriter collateral
+        pub fn collateral_account() -> T::AccountId {
+            PALLET_ID.into_sub_account_truncating(("collateral",))
+        }
+
+        /// Collateral an account is short of `margin` of its obligations
+        pub fn margin_shortfall(
+            account: &CollateralAccountV3<T::Balance>,
+            margin: Permill,
+        ) -> T::Balance {
+            let required: u128 = margin.mul_ceil(account.obligations.into());
+            required.saturating_sub(account.deposited.into()).into()
+        }
+
+        /// Store a collateral account, removing it (and any margin call) once empty
+        fn store_collateral_account(who: &T::AccountId, account: CollateralAccountV3<T::Balance>) {
+            if account == CollateralAccountV3::default() {
+                UnderwriterCollateral::<T>::remove(who);
+                MarginCalls::<T>::remove(who);
+            } else {
+                UnderwriterCollateral::<T>::insert(who, account);
+            }
+        }
+
+        /// Add a fill's maximum loss to the underwriter's obligations, first moving
+        /// whatever the account lacks to stay covered to `InitialMargin` from the
+        /// underwriter into it
+        fn lock_obligation(
+            underwriter: &T::AccountId,
+            policy_id: PolicyId,
+            shares: u128,
+            premium_per_share: T::Balance,
+            obligation: T::Balance,
+        ) -> DispatchResult {
+            ensure!(
+                !MarginCalls::<T>::contains_key(underwriter),
+                Error::<T>::MarginCallOpen
+            );
+
+            let mut account = UnderwriterCollateral::<T>::get(underwriter).unwrap_or_default();
+            account.obligations = account.obligations.saturating_add(obligation);
+
+            let top_up = Self::margin_shortfall(&account, T::InitialMargin::get());
+            if top_up > T::Balance::zero() {
+                T::Assets::transfer(
+                    T::UsdtAssetId::get(),
+                    underwriter,
+                    &Self::collateral_account(),
+                    top_up,
+                    Preservation::Expendable,
+                )
+                .map_err(|_| Error::<T>::InsufficientCollateral)?;
+                account.deposited = account.deposited.saturating_add(top_up);
+
+                Self::deposit_event(Event::CollateralDeposited {
+                    underwriter: underwriter.clone(),
+                    amount: top_up,
+                    deposited: account.deposited,
+                });
+            }
+
+            let position = match CollateralPositions::<T>::get(underwriter, policy_id) {
+                Some(mut position) => {
+                    position.shares = position
+                        .shares
+                        .checked_add(shares)
+                        .ok_or(Error::<T>::ArithmeticOverflow)?;
+                    position.obligation = position.obligation.saturating_add(obligation);
+                    position
+                }
+                None => {
+                    ensure!(
+                        account.positions < T::MaxPositionsPerUnderwriter::get(),
+                        Error::<T>::TooManyPositions
+                    );
+                    account.positions += 1;
+                    CollateralPositionV3 {
+                        shares,
+                        premium_per_share,
+                        obligation,
+                    }
+                }
+            };
+
+            CollateralPositions::<T>::insert(underwriter, policy_id, position);
+            UnderwriterCollateral::<T>::insert(underwriter, account);
+
+            Ok(())
+        }
+
+        /// Remove a position, taking its obligation off the underwriter's account.
+        /// The caller stores the returned account.
+        fn take_position(
+            underwriter: &T::AccountId,
+            policy_id: PolicyId,
+        ) -> Option<(CollateralPositionV3<T::Balance>, CollateralAccountV3<T::Balance>)> {
+            let position = CollateralPositions::<T>::take(underwriter, policy_id)?;
+            let mut account = UnderwriterCollateral::<T>::get(underwriter).unwrap_or_default();
+            account.obligations = account.obligations.saturating_sub(position.obligation);
+            account.positions = account.positions.saturating_sub(1);
+            Some((position, account))
+        }
+
+        /// Move the obligations of a triggered policy's positions from the
+        /// collateral account into `to`, as far as each underwriter's collateral
+        /// covers them. Returns the amount drawn.
+        pub fn draw_obligations(
+            policy_id: PolicyId,
+            to: &T::AccountId,
+        ) -> Result<T::Balance, DispatchError> {
+            let mut drawn = T::Balance::zero();
+
+            for (underwriter, _) in RequestFills::<T>::get(policy_id) {
+                let Some((position, mut account)) = Self::take_position(&underwriter, policy_id)
+                else {
+                    continue;
+                };
+
+                let amount = position.obligation.min(account.deposited);
+                if amount > T::Balance::zero() {
+                    T::Assets::transfer(
+                        T::UsdtAssetId::get(),
+                        &Self::collateral_account(),
+                        to,
+                        amount,
+                        Preservation::Expendable,
+                    )
+                    .map_err(|_| Error::<T>::TransferFailed)?;
+                }
+                account.deposited = account.deposited.saturating_sub(amount);
+                Self::store_collateral_account(&underwriter, account);
+                drawn = drawn.saturating_add(amount);
+
+                Self::deposit_event(Event::ObligationDrawn {
+                    policy_id,
+                    underwriter,
+                    amount,
+                    shortfall: position.obligation.saturating_sub(amount),
+                });
+            }
+
+            Ok(drawn)
+        }
+
+        /// Release the obligations of a matured policy's positions
+        pub fn release_obligations(policy_id: PolicyId) -> DispatchResult {
+            for (underwriter, _) in RequestFills::<T>::get(policy_id) {
+                let Some((position, account)) = Self::take_position(&underwriter, policy_id)
+                else {
+                    continue;
+                };
+                Self::store_collateral_account(&underwriter, account);
+
+                Self::deposit_event(Event::ObligationReleased {
+                    policy_id,
+                    underwriter,
+                    obligation: position.obligation,
+                });
+            }
+
+            Ok(())
+        }
+
+        /// Walk `MarginCheckCursor` over up to `MaxMarginChecksPerBlock` collateral
+        /// accounts, wrapping around at the end
+        pub fn check_margins(now: BlockNumberFor<T>) -> Weight {
+            let max_checks = T::MaxMarginChecksPerBlock::get() as usize;
+            let iter = match MarginCheckCursor::<T>::get() {
+                Some(last) => UnderwriterCollateral::<T>::iter_from(
+                    UnderwriterCollateral::<T>::hashed_key_for(&last),
+                ),
+                None => UnderwriterCollateral::<T>::iter(),
+            };
+            let batch: Vec<_> = iter.take(max_checks).collect();
+
+            match batch.last() {
+                Some((last, _)) if batch.len() == max_checks => {
+                    MarginCheckCursor::<T>::put(last)
+                }
+                _ => MarginCheckCursor::<T>::kill(),
+            }
+
+            let mut scanned = 0u32;
+            for (underwriter, account) in batch.iter() {
+                scanned = scanned.saturating_add(Self::check_margin(underwriter, account, now));
+            }
+
+            T::WeightInfo::check_margins(batch.len() as u32).saturating_add(
+                T::WeightInfo::force_reduce_position().saturating_mul(scanned.into()),
+            )
+        }
+
+        /// Open, clear or enforce an account's margin call. Returns the number of
+        /// positions scanned for reduction.
+        fn check_margin(
+            underwriter: &T::AccountId,
+            account: &CollateralAccountV3<T::Balance>,
+            now: BlockNumberFor<T>,
+        ) -> u32 {
+            let shortfall = Self::margin_shortfall(account, T::MaintenanceMargin::get());
+
+            match MarginCalls::<T>::get(underwriter) {
+                None if shortfall.is_zero() => 0,
+                None => {
+                    let deadline = now.saturating_add(T::MarginCallPeriod::get());
+                    MarginCalls::<T>::insert(
+                        underwriter,
+                        MarginCallV3 {
+                            shortfall,
+                            opened_at: now,
+                            deadline,
+                            reduction_cursor: None,
+                        },
+                    );
+                    Self::deposit_event(Event::MarginCallOpened {
+                        underwriter: underwriter.clone(),
+                        shortfall,
+                        deadline,
+                    });
+                    0
+                }
+                Some(_) if shortfall.is_zero() => {
+                    MarginCalls::<T>::remove(underwriter);
+                    Self::deposit_event(Event::MarginCallCleared {
+                        underwriter: underwriter.clone(),
+                    });
+                    0
+                }
+                Some(call) if now < call.deadline => 0,
+                Some(call) => {
+                    let (scanned, cursor) =
+                        Self::force_reduce_positions(underwriter, call.reduction_cursor);
+                    let shortfall = UnderwriterCollateral::<T>::get(underwriter)
+                        .map(|account| {
+                            Self::margin_shortfall(&account, T::MaintenanceMargin::get())
+                        })
+                        .unwrap_or_default();
+
+                    if shortfall.is_zero() {
+                        MarginCalls::<T>::remove(underwriter);
+                        Self::deposit_event(Event::MarginCallCleared {
+                            underwriter: underwriter.clone(),
+                        });
+                    } else {
+                        // Reductions can fail (e.g. the LP tokens were sold), so the
+                        // call stays open until the shortfall is gone
+                        MarginCalls::<T>::insert(
+                            underwriter,
+                            MarginCallV3 {
+                                shortfall,
+                                reduction_cursor: cursor,
+                                ..call
+                            },
+                        );
+                        Self::deposit_event(Event::MarginCallUnresolved {
+                            underwriter: underwriter.clone(),
+                            shortfall,
+                        });
+                    }
+                    scanned
+                }
+            }
+        }
+
+        /// Reduce up to `V3_MAX_FORCED_REDUCTIONS_PER_CHECK` of an underwriter's
+        /// positions, starting after `cursor`, until its account is back at
+        /// maintenance margin. Returns the number of positions scanned and
+        /// where the next pass continues (`None` to start over).
+        fn force_reduce_positions(
+            underwriter: &T::AccountId,
+            cursor: Option<PolicyId>,
+        ) -> (u32, Option<PolicyId>) {
+            let margin = T::MaintenanceMargin::get();
+            let max_positions = V3_MAX_FORCED_REDUCTIONS_PER_CHECK as usize;
+            let iter = match cursor {
+                Some(last) => CollateralPositions::<T>::iter_prefix_from(
+                    underwriter,
+                    CollateralPositions::<T>::hashed_key_for(underwriter, last),
+                ),
+                None => CollateralPositions::<T>::iter_prefix(underwriter),
+            };
+            let positions: Vec<_> = iter.take(max_positions).collect();
+            let next_cursor = match positions.last() {
+                Some((last, _)) if positions.len() == max_positions => Some(*last),
+                _ => None,
+            };
+            let scanned = positions.len() as u32;
+
+            for (policy_id, position) in positions {
+                let Some(account) = UnderwriterCollateral::<T>::get(underwriter) else {
+                    break;
+                };
+                let shortfall: u128 = Self::margin_shortfall(&account, margin).into();
+                if shortfall == 0 {
+                    break;
+                }
+
+                // Requirement freed per removed share, net of the premium refunded for it
+                let premium_per_share: u128 = position.premium_per_share.into();
+                let relief = margin
+                    .mul_floor(V3_PAYOUT_PER_SHARE.saturating_sub(premium_per_share))
+                    .saturating_sub(premium_per_share);
+                let shares = if relief == 0 {
+                    position.shares
+                } else {
+                    shortfall.div_ceil(relief).min(position.shares)
+                };
+
+                let result = frame_support::storage::with_storage_layer(|| {
+                    Self::reduce_position(underwriter, policy_id, position, account, shares)
+                });
+                if let Err(e) = result {
+                    log::warn!(
+                        target: "pallet-market-v3",
+                        "⚠️ Could not reduce position of policy {}: {:?}",
+                        policy_id,
+                        e
+                    );
+                }
+            }
+
+            (scanned, next_cursor)
+        }
+
+        /// Remove `shares` of a position from its policy and refund their premium to
+        /// the holder from the underwriter's collateral
+        fn reduce_position(
+            underwriter: &T::AccountId,
+            policy_id: PolicyId,
+            mut position: CollateralPositionV3<T::Balance>,
+            mut account: CollateralAccountV3<T::Balance>,
+            shares: u128,
+        ) -> DispatchResult {
+            let holder =
+                T::PolicyApi::remove_shares_from_policy(policy_id, underwriter.clone(), shares)?;
+
+            let premium_per_share: u128 = position.premium_per_share.into();
+            let released: T::Balance = shares
+                .saturating_mul(V3_PAYOUT_PER_SHARE.saturating_sub(premium_per_share))
+                .into();
+            let released = released.min(position.obligation);
+            let refund: T::Balance = shares.saturating_mul(premium_per_share).into();
+            let refund = refund.min(account.deposited);
+
+            if refund > T::Balance::zero() {
+                T::Assets::transfer(
+                    T::UsdtAssetId::get(),
+                    &Self::collateral_account(),
+                    &holder,
+                    refund,
+                    Preservation::Expendable,
+                )
+                .map_err(|_| Error::<T>::TransferFailed)?;
+            }
+
+            account.deposited = account.deposited.saturating_sub(refund);
+            account.obligations = account.obligations.saturating_sub(released);
+            if shares >= position.shares {
+                CollateralPositions::<T>::remove(underwriter, policy_id);
+                account.positions = account.positions.saturating_sub(1);
+            } else {
+                position.shares -= shares;
+                position.obligation = position.obligation.saturating_sub(released);
+                CollateralPositions::<T>::insert(underwriter, policy_id, position);
+            }
+            Self::store_collateral_account(underwriter, account);
+
+            Self::deposit_event(Event::PositionForceReduced {
+                underwriter: underwriter.clone(),
+                policy_id,
+                shares,
+                premium_refunded: refund,
+            });
+
+            Ok(())
+        }
     }
 }
 
@@ -848,4 +2231,8 @@ impl<T: Config> RequestExpiryApi for Pallet<T> {
     fn is_request_expired(request_id: RequestId, current_time: u64) -> bool {
         pallet::Pallet::<T>::is_request_expired_internal(request_id, current_time)
     }
+
+    fn get_expired_rfqs(current_time: u64) -> Vec<RequestId> {
+        pallet::Pallet::<T>::get_expired_rfqs_internal(current_time)
+    }
 }
@@ -1,14 +1,17 @@
 //! # Aggregator for OCW
 //!
 //! Provides incremental aggregation of weather observations.
-//! Re-exports fetcher aggregation functions and adds batch processing.
+//! Re-exports fetcher aggregation functions and adds batch processing, plus the
+//! combination of per-location states for area policies. Batches can be passed
+//! through a policy's outlier rules first (see `outlier`).
 
 use alloc::vec::Vec;
-use prmx_primitives::{AggStateV3, EventTypeV3};
+use prmx_primitives::{AggStateV3, AreaAggregationV3, CycloneThresholdV3, EventTypeV3};
 
 pub use crate::fetcher::{
     filter_observations_for_window, sort_observations, update_agg_state, WeatherObservation,
 };
+use crate::outlier::{self, OutlierFilter, OutlierRulesV3, RejectedObservationV3};
 
 /// Process a batch of observations and return updated aggregation state
 pub fn process_observation_batch(
@@ -28,6 +31,29 @@ pub fn process_observation_batch(
     (current_state, last_epoch)
 }
 
+/// Process a batch of observations, dropping the outliers `rules` reject, and
+/// return the updated aggregation state, the last observation epoch in the batch
+/// and the rejected samples. Samples `filter` holds for confirmation are not
+/// aggregated until a later batch confirms them.
+pub fn process_filtered_batch(
+    event_type: EventTypeV3,
+    initial_state: AggStateV3,
+    observations: Vec<WeatherObservation>,
+    rules: Option<&OutlierRulesV3>,
+    filter: &mut OutlierFilter,
+) -> (AggStateV3, u64, Vec<RejectedObservationV3>) {
+    let sorted = sort_observations(observations);
+    let last_epoch = sorted.last().map(|obs| obs.epoch_time).unwrap_or_default();
+
+    let (accepted, rejected) = match rules {
+        Some(rules) if outlier::applies_to(event_type) => filter.apply(rules, sorted),
+        _ => (sorted, Vec::new()),
+    };
+    let (state, _) = process_observation_batch(event_type, initial_state, accepted);
+
+    (state, last_epoch, rejected)
+}
+
 /// Represents the result of processing observations
 #[derive(Clone, Debug)]
 pub struct AggregationResult {
@@ -77,6 +103,101 @@ pub fn process_with_threshold_check(
     }
 }
 
+/// Combine the aggregation states of an area policy's locations into the single
+/// state that is snapshotted, reported and checked against the threshold.
+/// Temperature states with no observation yet are left out; if no location has
+/// data the initial state is returned.
+pub fn combine_area_states(
+    event_type: EventTypeV3,
+    aggregation: AreaAggregationV3,
+    states: &[AggStateV3],
+) -> AggStateV3 {
+    let initial = AggStateV3::initial_for_event_type(event_type);
+    // Precipitation types that occurred at any location
+    if let AggStateV3::PrecipTypeOccurred { .. } = initial {
+        let mask = states.iter().fold(0u8, |mask, state| match state {
+            AggStateV3::PrecipTypeOccurred { mask: m } => mask | m,
+            _ => mask,
+        });
+        return AggStateV3::PrecipTypeOccurred { mask };
+    }
+
+    if let AggStateV3::ConsecutiveDryHours { .. } = initial {
+        let runs: Vec<(i64, i64)> = states
+            .iter()
+            .filter_map(|state| match state {
+                AggStateV3::ConsecutiveDryHours { current_hours, longest_hours } => {
+                    Some((*current_hours as i64, *longest_hours as i64))
+                }
+                _ => None,
+            })
+            .collect();
+        if runs.is_empty() {
+            return initial;
+        }
+        let current: Vec<i64> = runs.iter().map(|(current, _)| *current).collect();
+        let longest: Vec<i64> = runs.iter().map(|(_, longest)| *longest).collect();
+        return AggStateV3::ConsecutiveDryHours {
+            current_hours: combine_values(aggregation, false, &current) as u32,
+            longest_hours: combine_values(aggregation, false, &longest) as u32,
+        };
+    }
+
+    let values: Vec<i64> = states
+        .iter()
+        .filter(|state| has_value(state))
+        .filter_map(scalar_value)
+        .collect();
+    if values.is_empty() {
+        return initial;
+    }
+    let lowest_wins = event_type == EventTypeV3::TempMinLte;
+    let value = combine_values(aggregation, lowest_wins, &values);
+
+    match initial {
+        AggStateV3::PrecipSum { .. } => AggStateV3::PrecipSum { sum_mm_x1000: value },
+        AggStateV3::Precip1hMax { .. } => AggStateV3::Precip1hMax { max_1h_mm_x1000: value },
+        AggStateV3::TempMax { .. } => AggStateV3::TempMax { max_c_x1000: value },
+        AggStateV3::TempMin { .. } => AggStateV3::TempMin { min_c_x1000: value },
+        AggStateV3::WindGustMax { .. } => AggStateV3::WindGustMax { max_mps_x1000: value },
+        other => other,
+    }
+}
+
+/// Whether a state equal to the initial one still carries an observation
+/// (a zero precipitation sum is data, a temperature sentinel is not)
+fn has_value(state: &AggStateV3) -> bool {
+    !matches!(
+        state,
+        AggStateV3::TempMax { max_c_x1000: i64::MIN }
+            | AggStateV3::TempMin { min_c_x1000: i64::MAX }
+    )
+}
+
+/// Single value of a scalar aggregation state
+fn scalar_value(state: &AggStateV3) -> Option<i64> {
+    match state {
+        AggStateV3::PrecipSum { sum_mm_x1000 } => Some(*sum_mm_x1000),
+        AggStateV3::Precip1hMax { max_1h_mm_x1000 } => Some(*max_1h_mm_x1000),
+        AggStateV3::TempMax { max_c_x1000 } => Some(*max_c_x1000),
+        AggStateV3::TempMin { min_c_x1000 } => Some(*min_c_x1000),
+        AggStateV3::WindGustMax { max_mps_x1000 } => Some(*max_mps_x1000),
+        _ => None,
+    }
+}
+
+/// Mean (rounded towards zero) or most extreme of non-empty `values`
+fn combine_values(aggregation: AreaAggregationV3, lowest_wins: bool, values: &[i64]) -> i64 {
+    match aggregation {
+        AreaAggregationV3::Average => {
+            let sum: i128 = values.iter().map(|value| *value as i128).sum();
+            (sum / values.len() as i128) as i64
+        }
+        AreaAggregationV3::Max if lowest_wins => values.iter().copied().min().unwrap_or_default(),
+        AreaAggregationV3::Max => values.iter().copied().max().unwrap_or_default(),
+    }
+}
+
 /// Check if threshold is met for the current state
 fn check_threshold(state: &AggStateV3, threshold: i64) -> bool {
     match state {
@@ -86,6 +207,11 @@ fn check_threshold(state: &AggStateV3, threshold: i64) -> bool {
         AggStateV3::TempMin { min_c_x1000 } => *min_c_x1000 <= threshold,
         AggStateV3::WindGustMax { max_mps_x1000 } => *max_mps_x1000 >= threshold,
         AggStateV3::PrecipTypeOccurred { mask } => (*mask as i64) & threshold != 0,
+        AggStateV3::ConsecutiveDryHours { longest_hours, .. } => *longest_hours as i64 >= threshold,
+        AggStateV3::CycloneApproach { peak_category, .. } => {
+            CycloneThresholdV3::from_value(threshold)
+                .is_some_and(|cyclone| *peak_category >= cyclone.category)
+        }
     }
 }
 
@@ -124,5 +250,164 @@ mod tests {
             _ => panic!("Wrong state type"),
         }
     }
+
+    #[test]
+    fn test_filtered_batch_skips_outliers() {
+        let observation = |hour: u64, precip_1h_mm_x1000: i64| WeatherObservation {
+            epoch_time: 1_000 + hour * 3600,
+            precip_1h_mm_x1000,
+            temp_c_x1000: 25_000,
+            wind_gust_mps_x1000: 5_000,
+            precip_type_mask: 1,
+            has_precipitation: true,
+        };
+        // A bogus 900mm hour between ordinary readings
+        let observations =
+            vec![observation(0, 4_000), observation(1, 900_000), observation(2, 6_000)];
+        let rules = OutlierRulesV3 {
+            max_hourly_delta_mm_x1000: 100_000,
+            max_median_ratio_pct: 0,
+            median_window: 0,
+            spike_confirmations: 2,
+        };
+        let initial = AggStateV3::PrecipSum { sum_mm_x1000: 0 };
+
+        let (state, last_epoch, rejected) = process_filtered_batch(
+            EventTypeV3::PrecipSumGte,
+            initial,
+            observations.clone(),
+            Some(&rules),
+            &mut OutlierFilter::default(),
+        );
+        assert_eq!(state, AggStateV3::PrecipSum { sum_mm_x1000: 10_000 });
+        assert_eq!(last_epoch, 1_000 + 2 * 3600);
+        assert_eq!(rejected.len(), 1);
+        assert_eq!(rejected[0].precip_1h_mm_x1000, 900_000);
+
+        // Without rules, or for an event type the rules don't cover, nothing is dropped
+        let (state, _, rejected) = process_filtered_batch(
+            EventTypeV3::PrecipSumGte,
+            initial,
+            observations.clone(),
+            None,
+            &mut OutlierFilter::default(),
+        );
+        assert_eq!(state, AggStateV3::PrecipSum { sum_mm_x1000: 910_000 });
+        assert!(rejected.is_empty());
+        let (_, _, rejected) = process_filtered_batch(
+            EventTypeV3::TempMaxGte,
+            AggStateV3::initial_for_event_type(EventTypeV3::TempMaxGte),
+            observations,
+            Some(&rules),
+            &mut OutlierFilter::default(),
+        );
+        assert!(rejected.is_empty());
+    }
+
+    #[test]
+    fn test_dry_streak_aggregation() {
+        // Hourly precipitation (mm * 1000): 3 dry hours, rain, 4 dry hours, rain, 1 dry hour.
+        // 0.05mm is still dry; 0.1mm is not.
+        let precip = [0, 50, 0, 2_000, 0, 0, 0, 0, 100, 0];
+        let observations = precip
+            .iter()
+            .enumerate()
+            .map(|(i, mm)| WeatherObservation {
+                epoch_time: 1_000 + i as u64 * 3600,
+                precip_1h_mm_x1000: *mm,
+                temp_c_x1000: 30_000,
+                wind_gust_mps_x1000: 2_000,
+                precip_type_mask: if *mm > 0 { 1 } else { 0 },
+                has_precipitation: *mm > 0,
+            })
+            .collect::<Vec<_>>();
+
+        let initial = AggStateV3::initial_for_event_type(EventTypeV3::DryStreakGte);
+        let result = process_with_threshold_check(
+            EventTypeV3::DryStreakGte,
+            4,
+            initial,
+            observations.clone(),
+            true,
+        );
+        assert_eq!(
+            result.agg_state,
+            AggStateV3::ConsecutiveDryHours { current_hours: 1, longest_hours: 4 }
+        );
+        assert!(result.threshold_met);
+
+        let result =
+            process_with_threshold_check(EventTypeV3::DryStreakGte, 5, initial, observations, true);
+        assert!(!result.threshold_met);
+    }
+
+    #[test]
+    fn test_cyclone_threshold_uses_peak_category() {
+        // Category 3 within 100 km
+        let threshold = CycloneThresholdV3 { category: 3, radius_km: 100 }.to_value();
+        assert_eq!(threshold, 1_003);
+
+        let state = |peak_category| AggStateV3::CycloneApproach { closest_km: 40, peak_category };
+        assert!(!check_threshold(&state(2), threshold));
+        assert!(check_threshold(&state(3), threshold));
+        assert!(check_threshold(&state(5), threshold));
+        // A malformed threshold never triggers
+        assert!(!check_threshold(&state(5), 1_009));
+    }
+
+    #[test]
+    fn test_combine_area_states_average_and_max() {
+        let states = [
+            AggStateV3::PrecipSum { sum_mm_x1000: 40_000 },
+            AggStateV3::PrecipSum { sum_mm_x1000: 0 },
+            AggStateV3::PrecipSum { sum_mm_x1000: 21_000 },
+        ];
+        assert_eq!(
+            combine_area_states(EventTypeV3::PrecipSumGte, AreaAggregationV3::Average, &states),
+            AggStateV3::PrecipSum { sum_mm_x1000: 20_333 }
+        );
+        assert_eq!(
+            combine_area_states(EventTypeV3::PrecipSumGte, AreaAggregationV3::Max, &states),
+            AggStateV3::PrecipSum { sum_mm_x1000: 40_000 }
+        );
+
+        // For a cold snap the location closest to triggering is the coldest one
+        let states = [
+            AggStateV3::TempMin { min_c_x1000: -2_000 },
+            AggStateV3::TempMin { min_c_x1000: -6_000 },
+        ];
+        assert_eq!(
+            combine_area_states(EventTypeV3::TempMinLte, AreaAggregationV3::Max, &states),
+            AggStateV3::TempMin { min_c_x1000: -6_000 }
+        );
+    }
+
+    #[test]
+    fn test_combine_area_states_skips_locations_without_data() {
+        let states = [
+            AggStateV3::TempMax { max_c_x1000: 38_000 },
+            AggStateV3::initial_for_event_type(EventTypeV3::TempMaxGte),
+        ];
+        assert_eq!(
+            combine_area_states(EventTypeV3::TempMaxGte, AreaAggregationV3::Average, &states),
+            AggStateV3::TempMax { max_c_x1000: 38_000 }
+        );
+
+        let initial = AggStateV3::initial_for_event_type(EventTypeV3::TempMinLte);
+        let states = [initial, initial];
+        assert_eq!(
+            combine_area_states(EventTypeV3::TempMinLte, AreaAggregationV3::Average, &states),
+            initial
+        );
+
+        let states = [
+            AggStateV3::PrecipTypeOccurred { mask: 0b001 },
+            AggStateV3::PrecipTypeOccurred { mask: 0b100 },
+        ];
+        assert_eq!(
+            combine_area_states(EventTypeV3::PrecipTypeOccurred, AreaAggregationV3::Max, &states),
+            AggStateV3::PrecipTypeOccurred { mask: 0b101 }
+        );
+    }
 }
 
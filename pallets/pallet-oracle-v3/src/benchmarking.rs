@@ -0,0 +1,590 @@
+//! Benchmarks for the V3 oracle pallet
+
+use super::*;
+use alloc::vec;
+use frame_benchmarking::v2::*;
+use frame_system::RawOrigin;
+use prmx_primitives::{ThresholdV3, UnitV3};
+use sp_core::sr25519;
+
+const COVERAGE_START: u64 = 1_700_000_000;
+
+fn rain_spec() -> EventSpecV3 {
+    EventSpecV3 {
+        event_type: EventTypeV3::PrecipSumGte,
+        threshold: ThresholdV3 { value: 50_000, unit: UnitV3::MmX1000 },
+        early_trigger: true,
+    }
+}
+
+fn triggered_state() -> AggStateV3 {
+    AggStateV3::PrecipSum { sum_mm_x1000: 60_000 }
+}
+
+/// Register an active location and return its id
+fn location<T: Config>() -> LocationId {
+    let location_id = NextLocationId::<T>::get();
+    let _ = LocationsByGeohash::<T>::try_append(
+        geohash::encode(14_599_500, 120_984_200),
+        (location_id, 14_599_500, 120_984_200),
+    );
+    LocationRegistry::<T>::insert(
+        location_id,
+        LocationInfo {
+            location_id,
+            accuweather_key: BoundedVec::truncate_from(b"264885".to_vec()),
+            latitude: 14_599_500,
+            longitude: 120_984_200,
+            name: BoundedVec::truncate_from(b"Manila".to_vec()),
+            active: true,
+            timezone_offset_mins: None,
+            elevation_m: None,
+            station_id: None,
+        },
+    );
+    NextLocationId::<T>::put(location_id + 1);
+    location_id
+}
+
+/// Create an active policy with oracle state on a fresh location
+fn active_policy<T: Config>() -> PolicyId {
+    T::BenchmarkHelper::active_policy(location::<T>(), rain_spec())
+}
+
+fn oracle_member<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+    let member: T::AccountId = account(name, index, 0);
+    OracleMembership::<T>::insert(&member, true);
+    member
+}
+
+/// OCW payload from an arbitrary key. Signatures and nonces are checked in
+/// `validate_unsigned`/`pre_dispatch`, outside the benchmarked dispatch.
+fn ocw_payload<T: Config, Data>(data: Data) -> OcwPayloadV3<T::Public, Data>
+where
+    T::Public: From<sr25519::Public>,
+{
+    OcwPayloadV3 { data, nonce: 0, public: sr25519::Public::from_raw([1u8; 32]).into() }
+}
+
+fn ocw_signature<T: Config>() -> T::Signature
+where
+    T::Signature: From<sr25519::Signature>,
+{
+    sr25519::Signature::from_raw([0u8; 64]).into()
+}
+
+/// `n` hourly observations from coverage start
+fn observations(n: u32) -> Vec<WeatherObservation> {
+    (0..n as u64)
+        .map(|i| WeatherObservation {
+            epoch_time: COVERAGE_START + i * 3600,
+            precip_1h_mm_x1000: 1_000,
+            temp_c_x1000: 25_000,
+            wind_gust_mps_x1000: 5_000,
+            precip_type_mask: 1,
+            has_precipitation: true,
+        })
+        .collect()
+}
+
+/// Record one attestation short of quorum for `report_hash` plus up to `a`
+/// attestations for a competing report, all from current members
+fn pending_attestations<T: Config>(policy_id: PolicyId, report_hash: H256, a: u32) {
+    let quorum = T::FinalReportQuorum::get().clamp(1, MAX_FINAL_REPORT_ATTESTATIONS);
+    let matching = quorum - 1;
+    let competing = a.min(MAX_FINAL_REPORT_ATTESTATIONS - 1 - matching);
+    let competing_hash = H256::repeat_byte(0xee);
+    let attestations: Vec<(T::AccountId, H256)> = (0..matching)
+        .map(|i| (oracle_member::<T>("attester", i), report_hash))
+        .chain(
+            (0..competing)
+                .map(|i| (oracle_member::<T>("attester", matching + i), competing_hash)),
+        )
+        .collect();
+    PendingAttestations::<T>::insert(policy_id, BoundedVec::truncate_from(attestations));
+}
+
+#[benchmarks(
+    where
+        T::Public: From<sr25519::Public>,
+        T::Signature: From<sr25519::Signature>,
+)]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn add_location() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let key = vec![b'1'; T::MaxLocationKeyLength::get() as usize];
+
+        // Worst case: every neighbouring cell holds distant entries to compare against,
+        // and the target cell has one free slot
+        for (i, cell) in geohash::neighbourhood(14_599_500, 120_984_200).into_iter().enumerate() {
+            let entries = if i == 0 { MAX_LOCATIONS_PER_GEOHASH - 1 } else { MAX_LOCATIONS_PER_GEOHASH };
+            let far_away: Vec<(LocationId, i32, i32)> =
+                (0..entries).map(|j| (1_000 + j as LocationId, -14_599_500, -59_015_800)).collect();
+            LocationsByGeohash::<T>::insert(cell, BoundedVec::truncate_from(far_away));
+        }
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, key, 14_599_500, 120_984_200, vec![b'n'; 64]);
+
+        assert_eq!(NextLocationId::<T>::get(), 1);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn remove_location() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let location_id = location::<T>();
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, location_id);
+
+        assert!(!Pallet::<T>::is_location_active(location_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn add_oracle_member() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let member: T::AccountId = account("member", 0, 0);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, member.clone());
+
+        assert!(OracleMembership::<T>::get(&member));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn remove_oracle_member() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let member = oracle_member::<T>("member", 0);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, member.clone());
+
+        assert!(!OracleMembership::<T>::get(&member));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn rotate_oracle_member() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let old = oracle_member::<T>("member", 0);
+        let new: T::AccountId = account("member", 1, 0);
+        let overlap: BlockNumberFor<T> = MAX_ROTATION_OVERLAP_BLOCKS.into();
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, old.clone(), new.clone(), overlap);
+
+        assert!(OracleMembership::<T>::get(&old));
+        assert!(OracleMembership::<T>::get(&new));
+        assert!(RetiringMembers::<T>::contains_key(&old));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn retire_oracle_members(n: Linear<0, MAX_RETIREMENTS_PER_BLOCK>) {
+        let now: BlockNumberFor<T> = 10u32.into();
+        for i in 0..n {
+            let old = oracle_member::<T>("member", i);
+            RetiringMembers::<T>::insert(&old, i);
+            RotationHistory::<T>::insert(
+                i,
+                MemberRotation {
+                    old: old.clone(),
+                    new: account("successor", i, 0),
+                    started_at: Zero::zero(),
+                    retires_at: now,
+                    retired_at: None,
+                },
+            );
+            let _ = RetirementSchedule::<T>::try_append(now, old);
+        }
+
+        #[block]
+        {
+            Pallet::<T>::retire_due_members(now);
+        }
+
+        assert!(RetirementSchedule::<T>::get(now).is_empty());
+    }
+
+    #[benchmark]
+    fn check_snapshot_gaps(n: Linear<0, 64>) {
+        for i in 0..n {
+            let mut raw = [0u8; 16];
+            raw[..4].copy_from_slice(&i.to_le_bytes());
+            let policy_id = PolicyId::from(raw);
+            OracleStates::<T>::insert(
+                policy_id,
+                PolicyOracleStateV3 {
+                    policy_id,
+                    observed_until: COVERAGE_START,
+                    agg_state: AggStateV3::PrecipSum { sum_mm_x1000: 0 },
+                    commitment: [0u8; 32],
+                    last_snapshot_block: 1,
+                    status: PolicyStatusV3::Active,
+                },
+            );
+        }
+        // Every state is overdue
+        let now = T::SnapshotOverdueBlocks::get().saturating_add(2u32.into());
+
+        #[block]
+        {
+            Pallet::<T>::check_snapshot_gaps(now);
+        }
+
+        let checked = n.min(T::MaxSnapshotGapChecks::get());
+        assert_eq!(OverdueSnapshots::<T>::iter().count() as u32, checked);
+    }
+
+    #[benchmark]
+    fn submit_snapshot() {
+        let policy_id = active_policy::<T>();
+        let member = oracle_member::<T>("member", 0);
+        frame_system::Pallet::<T>::set_block_number(V3_MIN_SNAPSHOT_BLOCKS.into());
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(member),
+            policy_id,
+            COVERAGE_START + 3600,
+            AggStateV3::PrecipSum { sum_mm_x1000: 10_000 },
+            [1u8; 32],
+        );
+
+        assert_eq!(
+            OracleStates::<T>::get(policy_id).expect("state exists").observed_until,
+            COVERAGE_START + 3600
+        );
+    }
+
+    #[benchmark]
+    fn submit_snapshot_unsigned() {
+        let policy_id = active_policy::<T>();
+        frame_system::Pallet::<T>::set_block_number(V3_MIN_SNAPSHOT_BLOCKS.into());
+
+        let payload = ocw_payload::<T, _>(SnapshotDataV3 {
+            policy_id,
+            observed_until: COVERAGE_START + 3600,
+            agg_state: AggStateV3::PrecipSum { sum_mm_x1000: 10_000 },
+            commitment: [1u8; 32],
+        });
+
+        #[extrinsic_call]
+        _(RawOrigin::None, payload, ocw_signature::<T>());
+
+        assert_eq!(
+            OracleStates::<T>::get(policy_id).expect("state exists").observed_until,
+            COVERAGE_START + 3600
+        );
+    }
+
+    // The caller's attestation completes the quorum, so the policy settles
+    #[benchmark]
+    fn submit_final_report(a: Linear<0, { MAX_FINAL_REPORT_ATTESTATIONS - 1 }>) {
+        let policy_id = active_policy::<T>();
+        let observed_until = COVERAGE_START + 3600;
+        let commitment = [2u8; 32];
+        let report_hash = H256::from(crate::commitment::compute_final_report_hash(
+            policy_id,
+            OracleReportKindV3::Trigger,
+            observed_until,
+            &triggered_state(),
+            &commitment,
+        ));
+        pending_attestations::<T>(policy_id, report_hash, a);
+        let member = oracle_member::<T>("member", 0);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(member),
+            policy_id,
+            OracleReportKindV3::Trigger,
+            observed_until,
+            triggered_state(),
+            commitment,
+        );
+
+        assert_ne!(
+            OracleStates::<T>::get(policy_id).expect("state exists").status,
+            PolicyStatusV3::Active
+        );
+    }
+
+    #[benchmark]
+    fn submit_final_report_unsigned() {
+        let policy_id = active_policy::<T>();
+
+        let payload = ocw_payload::<T, _>(FinalReportDataV3 {
+            policy_id,
+            kind: OracleReportKindV3::Trigger,
+            observed_until: COVERAGE_START + 3600,
+            agg_state: triggered_state(),
+            commitment: [2u8; 32],
+        });
+
+        #[extrinsic_call]
+        _(RawOrigin::None, payload, ocw_signature::<T>());
+
+        assert_ne!(
+            OracleStates::<T>::get(policy_id).expect("state exists").status,
+            PolicyStatusV3::Active
+        );
+    }
+
+    #[benchmark]
+    fn expire_request_unsigned() {
+        let request_id = T::BenchmarkHelper::expirable_request(location::<T>());
+
+        #[extrinsic_call]
+        _(RawOrigin::None, ocw_payload::<T, _>(request_id), ocw_signature::<T>());
+    }
+
+    #[benchmark]
+    fn expire_rfq_unsigned() {
+        let rfq_id = T::BenchmarkHelper::expirable_rfq(location::<T>());
+
+        #[extrinsic_call]
+        _(RawOrigin::None, ocw_payload::<T, _>(rfq_id), ocw_signature::<T>());
+    }
+
+    #[benchmark]
+    fn report_ingest_backlog_unsigned() {
+        let policy_id = active_policy::<T>();
+        let payload = ocw_payload::<T, _>(IngestBacklogDataV3 {
+            policy_id,
+            pending_batches: crate::outbox::INGEST_BACKLOG_HIGH_BATCHES,
+            pending_samples: 24 * crate::outbox::INGEST_BACKLOG_HIGH_BATCHES,
+            oldest_queued_at: COVERAGE_START,
+        });
+
+        #[extrinsic_call]
+        _(RawOrigin::None, payload, ocw_signature::<T>());
+    }
+
+    #[benchmark]
+    fn report_api_budget_exhausted_unsigned() {
+        let budget = crate::fetch_quota::DEFAULT_DAILY_FETCH_BUDGET;
+        let payload = ocw_payload::<T, _>(ApiBudgetDataV3 { day: 20_000, calls: budget, budget });
+
+        #[extrinsic_call]
+        _(RawOrigin::None, payload, ocw_signature::<T>());
+    }
+
+    #[benchmark]
+    fn set_outlier_rules() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let rules = crate::outlier::OutlierRulesV3 {
+            max_hourly_delta_mm_x1000: 100_000,
+            max_median_ratio_pct: 1_000,
+            median_window: crate::outlier::MAX_OUTLIER_MEDIAN_WINDOW,
+            spike_confirmations: 2,
+        };
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, Some(rules));
+
+        assert_eq!(OutlierRules::<T>::get(), Some(rules));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn report_rejected_observations_unsigned(
+        n: Linear<1, { crate::outlier::MAX_REJECTED_PER_REPORT }>,
+    ) {
+        let policy_id = active_policy::<T>();
+        let rejected: Vec<_> = (0..n as u64)
+            .map(|i| crate::outlier::RejectedObservationV3 {
+                epoch_time: COVERAGE_START + i * 3600,
+                precip_1h_mm_x1000: 900_000,
+                reason: crate::outlier::OutlierReasonV3::HourlyDelta,
+            })
+            .collect();
+        let payload = ocw_payload::<T, _>(RejectedObservationsDataV3 {
+            policy_id,
+            location_id: 0,
+            rejected: BoundedVec::truncate_from(rejected),
+        });
+
+        #[extrinsic_call]
+        _(RawOrigin::None, payload, ocw_signature::<T>());
+    }
+
+    #[benchmark]
+    fn set_location_metadata() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let location_id = location::<T>();
+        let station_id = vec![b'7'; T::MaxLocationKeyLength::get() as usize];
+
+        #[extrinsic_call]
+        _(
+            origin as T::RuntimeOrigin,
+            location_id,
+            Some(480),
+            Some(13),
+            Some(station_id),
+        );
+
+        let location = LocationRegistry::<T>::get(location_id).ok_or(BenchmarkError::Weightless)?;
+        assert_eq!(location.timezone_offset_mins, Some(480));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn submit_location_metadata_unsigned() {
+        let location_id = location::<T>();
+        let payload = ocw_payload::<T, _>(LocationMetadataDataV3 {
+            location_id,
+            timezone_offset_mins: Some(480),
+            elevation_m: Some(13),
+        });
+
+        #[extrinsic_call]
+        _(RawOrigin::None, payload, ocw_signature::<T>());
+
+        assert!(LocationRegistry::<T>::get(location_id)
+            .is_some_and(|location| location.elevation_m == Some(13)));
+    }
+
+    #[benchmark]
+    fn purge_settled() {
+        let policy_id = active_policy::<T>();
+        OracleStates::<T>::mutate(policy_id, |maybe_state| {
+            if let Some(state) = maybe_state {
+                state.status = PolicyStatusV3::Settled;
+            }
+        });
+        FinalReports::<T>::insert(
+            policy_id,
+            FinalReportProposal {
+                kind: OracleReportKindV3::Maturity,
+                observed_until: COVERAGE_START + 86_400,
+                agg_state: AggStateV3::PrecipSum { sum_mm_x1000: 0 },
+                commitment: [0u8; 32],
+            },
+        );
+        let settled_at: BlockNumberFor<T> = 1u32.into();
+        SettledAt::<T>::insert(policy_id, settled_at);
+        frame_system::Pallet::<T>::set_block_number(
+            settled_at.saturating_add(T::SettledRetentionBlocks::get()),
+        );
+        let caller: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), policy_id);
+
+        assert!(SettledRecordHashes::<T>::contains_key(policy_id));
+        assert!(!OracleStates::<T>::contains_key(policy_id));
+    }
+
+    // The caller's attestation completes the quorum, so the policy settles
+    #[benchmark]
+    fn attest_final_report(a: Linear<0, { MAX_FINAL_REPORT_ATTESTATIONS - 1 }>) {
+        let policy_id = active_policy::<T>();
+        let proposal = FinalReportProposal {
+            kind: OracleReportKindV3::Trigger,
+            observed_until: COVERAGE_START + 3600,
+            agg_state: triggered_state(),
+            commitment: [2u8; 32],
+        };
+        let report_hash = crate::commitment::compute_final_report_hash(
+            policy_id,
+            proposal.kind,
+            proposal.observed_until,
+            &proposal.agg_state,
+            &proposal.commitment,
+        );
+        ProposedFinalReports::<T>::insert(policy_id, H256::from(report_hash), proposal);
+        pending_attestations::<T>(policy_id, H256::from(report_hash), a);
+        let member = oracle_member::<T>("member", 0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(member), policy_id, report_hash);
+
+        assert_ne!(
+            OracleStates::<T>::get(policy_id).expect("state exists").status,
+            PolicyStatusV3::Active
+        );
+    }
+
+    // A valid chain is the worst case: every sample is replayed and compared
+    #[benchmark]
+    fn verify_commitment_chain(n: Linear<0, MAX_EVIDENCE_SAMPLES>) {
+        let policy_id = active_policy::<T>();
+        let meta = PolicyMetadata::<T>::get(policy_id).expect("metadata exists");
+        let initial = crate::commitment::compute_initial_commitment(
+            policy_id,
+            &meta.event_spec,
+            meta.location_id,
+            meta.coverage_start,
+            meta.coverage_end,
+            meta.area.as_ref(),
+        );
+        let samples = observations(n);
+        let (commitment, _) = crate::commitment::process_commitment_batch(initial, &samples);
+        OracleStates::<T>::mutate(policy_id, |state| {
+            if let Some(state) = state {
+                state.commitment = commitment;
+            }
+        });
+        let caller: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), policy_id, BoundedVec::truncate_from(samples));
+
+        assert_eq!(
+            Pallet::<T>::check_commitment_chain(policy_id, &observations(n)),
+            Ok((true, commitment))
+        );
+    }
+
+    // The samples replay to the report's commitment but aggregate to another state
+    #[benchmark]
+    fn dispute_final_report(n: Linear<0, MAX_EVIDENCE_SAMPLES>) {
+        let policy_id = active_policy::<T>();
+        let meta = PolicyMetadata::<T>::get(policy_id).expect("metadata exists");
+        let initial = crate::commitment::compute_initial_commitment(
+            policy_id,
+            &meta.event_spec,
+            meta.location_id,
+            meta.coverage_start,
+            meta.coverage_end,
+            None,
+        );
+        let samples = observations(n);
+        let (commitment, _) = crate::commitment::process_commitment_batch(initial, &samples);
+        let proposal = FinalReportProposal {
+            kind: OracleReportKindV3::Trigger,
+            observed_until: COVERAGE_START + 3600 * n as u64,
+            agg_state: AggStateV3::PrecipSum { sum_mm_x1000: 1 },
+            commitment,
+        };
+        let report_hash = crate::commitment::compute_final_report_hash(
+            policy_id,
+            proposal.kind,
+            proposal.observed_until,
+            &proposal.agg_state,
+            &proposal.commitment,
+        );
+        ProposedFinalReports::<T>::insert(policy_id, H256::from(report_hash), proposal);
+        pending_attestations::<T>(policy_id, H256::from(report_hash), 0);
+        let caller: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), policy_id, report_hash, BoundedVec::truncate_from(samples));
+
+        assert!(ProposedFinalReports::<T>::get(policy_id, H256::from(report_hash)).is_none());
+    }
+}
@@ -1,15 +1,31 @@
 //! # Commitment Hash Chain for OCW
 //!
 //! Implements the commitment hash chain that binds observations to policies.
-//! Provides tamper evidence and recovery verification.
+//! Provides tamper evidence and recovery verification. The chain itself is built
+//! by the `prmx-commitment` crate, which documents the canonical encoding and can
+//! verify exported evidence off-chain.
 
 use alloc::vec::Vec;
 use codec::Encode;
-use prmx_primitives::{EventSpecV3, PolicyId};
+use prmx_commitment::Sample;
+use prmx_primitives::{AggStateV3, AreaSpecV3, EventSpecV3, OracleReportKindV3, PolicyId};
 use sp_core::Hasher;
 use sp_runtime::traits::BlakeTwo256;
 
 use crate::fetcher::WeatherObservation;
+use crate::storm::StormAdvisory;
+
+impl From<&WeatherObservation> for Sample {
+    fn from(observation: &WeatherObservation) -> Self {
+        Sample {
+            epoch_time: observation.epoch_time,
+            precip_1h_mm_x1000: observation.precip_1h_mm_x1000,
+            temp_c_x1000: observation.temp_c_x1000,
+            wind_gust_mps_x1000: observation.wind_gust_mps_x1000,
+            precip_type_mask: observation.precip_type_mask,
+        }
+    }
+}
 
 // ============================================================================
 // Commitment Chain Functions
@@ -18,51 +34,40 @@ use crate::fetcher::WeatherObservation;
 /// Compute the initial commitment seed from policy parameters.
 /// This binds the commitment chain to the specific policy.
 ///
-/// Format: blake2_256(b"prmx_v3:" || policy_id || event_spec || location_id || start_at || end_at)
+/// Format: blake2_256(b"prmx_v3:" || policy_id || event_spec || location_id || start_at || end_at [|| area])
+///
+/// The area spec is only appended for area policies, so single-location
+/// commitments are unchanged.
 pub fn compute_initial_commitment(
     policy_id: PolicyId,
     event_spec: &EventSpecV3,
     location_id: u64,
     coverage_start: u64,
     coverage_end: u64,
+    area: Option<&AreaSpecV3>,
 ) -> [u8; 32] {
-    let mut data = Vec::new();
-    data.extend_from_slice(b"prmx_v3:");
-    data.extend_from_slice(&policy_id.to_le_bytes());
-    data.extend_from_slice(&event_spec.encode());
-    data.extend_from_slice(&location_id.to_le_bytes());
-    data.extend_from_slice(&coverage_start.to_le_bytes());
-    data.extend_from_slice(&coverage_end.to_le_bytes());
-
-    BlakeTwo256::hash(&data).into()
+    prmx_commitment::seed(policy_id, event_spec, location_id, coverage_start, coverage_end, area)
 }
 
 /// Compute sample hash from an observation.
 ///
-/// Format: blake2_256(epoch_time || normalized_value)
+/// Format: blake2_256(canonical sample bytes), see `prmx_commitment::Sample`
 pub fn compute_sample_hash(observation: &WeatherObservation) -> [u8; 32] {
-    let mut data = Vec::new();
-    data.extend_from_slice(&observation.epoch_time.to_le_bytes());
-    // Use precip as the primary value for commitment
-    data.extend_from_slice(&observation.precip_1h_mm_x1000.to_le_bytes());
-    data.extend_from_slice(&observation.temp_c_x1000.to_le_bytes());
-    data.extend_from_slice(&observation.wind_gust_mps_x1000.to_le_bytes());
-    data.push(observation.precip_type_mask);
-
-    BlakeTwo256::hash(&data).into()
+    prmx_commitment::sample_hash(&observation.into())
 }
 
 /// Extend the commitment chain with a new observation.
 ///
 /// Format: commitment_n = blake2_256(commitment_{n-1} || sample_hash)
 pub fn extend_commitment(current_commitment: [u8; 32], observation: &WeatherObservation) -> [u8; 32] {
-    let sample_hash = compute_sample_hash(observation);
-
-    let mut data = Vec::new();
-    data.extend_from_slice(&current_commitment);
-    data.extend_from_slice(&sample_hash);
+    prmx_commitment::extend(current_commitment, &observation.into())
+}
 
-    BlakeTwo256::hash(&data).into()
+/// Extend the commitment chain with a storm fix of a cyclone policy.
+///
+/// Format: commitment_n = blake2_256(commitment_{n-1} || storm_fix_hash)
+pub fn extend_storm_commitment(current_commitment: [u8; 32], advisory: &StormAdvisory) -> [u8; 32] {
+    prmx_commitment::extend_storm(current_commitment, &advisory.into())
 }
 
 /// Process a batch of observations and return the final commitment.
@@ -82,6 +87,34 @@ pub fn process_commitment_batch(
     (current, sample_hashes)
 }
 
+/// Replay the chain from `initial_commitment` over `observations` and check it ends at
+/// `expected_commitment`. Observations must be in the order the OCW committed them.
+pub fn verify_commitment_chain(
+    initial_commitment: [u8; 32],
+    observations: &[WeatherObservation],
+    expected_commitment: [u8; 32],
+) -> bool {
+    prmx_commitment::verify_chain(
+        initial_commitment,
+        observations.iter().map(Sample::from),
+        expected_commitment,
+    )
+}
+
+/// Compute the hash oracle members attest to for a final report.
+/// Members attesting the same hash agree on every settlement-relevant field.
+///
+/// Format: blake2_256(b"prmx_v3_final:" || policy_id || kind || observed_until || agg_state || commitment)
+pub fn compute_final_report_hash(
+    policy_id: PolicyId,
+    kind: OracleReportKindV3,
+    observed_until: u64,
+    agg_state: &AggStateV3,
+    commitment: &[u8; 32],
+) -> [u8; 32] {
+    prmx_commitment::final_report_hash(policy_id, kind, observed_until, agg_state, commitment)
+}
+
 // ============================================================================
 // Ingest API Client Types
 // ============================================================================
@@ -172,7 +205,7 @@ pub fn generate_nonce() -> [u8; 16] {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use prmx_primitives::{EventTypeV3, ThresholdV3, UnitV3};
+    use prmx_primitives::{AreaAggregationV3, EventTypeV3, ThresholdV3, UnitV3};
 
     #[test]
     fn test_initial_commitment_deterministic() {
@@ -185,12 +218,36 @@ mod tests {
             early_trigger: true,
         };
 
-        let c1 = compute_initial_commitment(1, &event_spec, 100, 1000, 2000);
-        let c2 = compute_initial_commitment(1, &event_spec, 100, 1000, 2000);
+        let c1 = compute_initial_commitment(1, &event_spec, 100, 1000, 2000, None);
+        let c2 = compute_initial_commitment(1, &event_spec, 100, 1000, 2000, None);
 
         assert_eq!(c1, c2);
     }
 
+    #[test]
+    fn test_initial_commitment_binds_area() {
+        let event_spec = EventSpecV3 {
+            event_type: EventTypeV3::PrecipSumGte,
+            threshold: ThresholdV3 {
+                value: 50_000,
+                unit: UnitV3::MmX1000,
+            },
+            early_trigger: true,
+        };
+        let area = AreaSpecV3 {
+            locations: vec![100, 101].try_into().unwrap(),
+            aggregation: AreaAggregationV3::Average,
+        };
+        let max_area = AreaSpecV3 { aggregation: AreaAggregationV3::Max, ..area.clone() };
+
+        let single = compute_initial_commitment(1, &event_spec, 100, 1000, 2000, None);
+        let average = compute_initial_commitment(1, &event_spec, 100, 1000, 2000, Some(&area));
+        let max = compute_initial_commitment(1, &event_spec, 100, 1000, 2000, Some(&max_area));
+
+        assert_ne!(single, average);
+        assert_ne!(average, max);
+    }
+
     #[test]
     fn test_commitment_chain_extension() {
         let initial = [0u8; 32];
@@ -210,5 +267,57 @@ mod tests {
         let extended2 = extend_commitment(initial, &obs);
         assert_eq!(extended, extended2);
     }
+
+    #[test]
+    fn test_verify_commitment_chain() {
+        let initial = [3u8; 32];
+        let observations: Vec<WeatherObservation> = (0..3)
+            .map(|i| WeatherObservation {
+                epoch_time: 1000 + i * 3600,
+                precip_1h_mm_x1000: 2000 * i as i64,
+                temp_c_x1000: 25000,
+                wind_gust_mps_x1000: 5000,
+                precip_type_mask: 1,
+                has_precipitation: i > 0,
+            })
+            .collect();
+        let (expected, _) = process_commitment_batch(initial, &observations);
+
+        assert!(verify_commitment_chain(initial, &observations, expected));
+
+        // A tampered value, a dropped sample or a reordering breaks the chain
+        let mut tampered = observations.clone();
+        tampered[1].precip_1h_mm_x1000 += 1;
+        assert!(!verify_commitment_chain(initial, &tampered, expected));
+        assert!(!verify_commitment_chain(initial, &observations[..2], expected));
+        let mut reordered = observations.clone();
+        reordered.swap(0, 2);
+        assert!(!verify_commitment_chain(initial, &reordered, expected));
+    }
+
+    #[test]
+    fn test_final_report_hash_binds_fields() {
+        let agg_state = AggStateV3::PrecipSum { sum_mm_x1000: 52_000 };
+        let commitment = [7u8; 32];
+
+        let h1 =
+            compute_final_report_hash(1, OracleReportKindV3::Trigger, 5000, &agg_state, &commitment);
+        let h2 =
+            compute_final_report_hash(1, OracleReportKindV3::Trigger, 5000, &agg_state, &commitment);
+        assert_eq!(h1, h2);
+
+        // Any differing field yields a different hash
+        let other_kind =
+            compute_final_report_hash(1, OracleReportKindV3::Maturity, 5000, &agg_state, &commitment);
+        let other_state = compute_final_report_hash(
+            1,
+            OracleReportKindV3::Trigger,
+            5000,
+            &AggStateV3::PrecipSum { sum_mm_x1000: 51_000 },
+            &commitment,
+        );
+        assert_ne!(h1, other_kind);
+        assert_ne!(h1, other_state);
+    }
 }
 
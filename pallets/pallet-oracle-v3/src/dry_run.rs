@@ -0,0 +1,237 @@
+//! # OCW Dry Run (std only)
+//!
+//! The offchain worker's observation pipeline for one policy as plain functions:
+//! parse an AccuWeather historical/24 response, extend the commitment chain,
+//! aggregate, and decide the on-chain action. Nothing here touches offchain
+//! storage, HTTP or the keystore; the caller GETs `historical_24_url` with its
+//! own client and passes the body in. A companion binary or integration test can
+//! then run full observation cycles against live AccuWeather data without a node,
+//! through the same parsing and aggregation code the OCW runs.
+//!
+//! Covers single-location policies; area policies combine per-location states
+//! with `aggregator::combine_area_states`.
+
+use alloc::vec::Vec;
+use prmx_primitives::{
+    AggStateV3, EventSpecV3, OcwErrorKind, OracleReportKindV3, PolicyId, PolicyOracleStateV3,
+    PolicyStatusV3,
+};
+
+use crate::fetcher::WeatherObservation;
+use crate::ocw::{OcwPolicyState, SnapshotDecision};
+use crate::{aggregator, commitment, fetcher, http_client, ocw, LocationId};
+
+/// Terms of the policy being observed
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct DryRunPolicy {
+    pub policy_id: PolicyId,
+    pub location_id: LocationId,
+    pub event_spec: EventSpecV3,
+    /// Coverage window start (unix seconds)
+    pub coverage_start: u64,
+    /// Coverage window end (unix seconds)
+    pub coverage_end: u64,
+}
+
+impl DryRunPolicy {
+    /// OCW state of the policy as created on-chain: the commitment seed and the
+    /// initial aggregate for its event type
+    pub fn initial_state(&self) -> OcwPolicyState {
+        OcwPolicyState::from_on_chain_state(&PolicyOracleStateV3 {
+            policy_id: self.policy_id,
+            observed_until: 0,
+            agg_state: AggStateV3::initial_for_event_type(self.event_spec.event_type),
+            commitment: commitment::compute_initial_commitment(
+                self.policy_id,
+                &self.event_spec,
+                self.location_id,
+                self.coverage_start,
+                self.coverage_end,
+                None,
+            ),
+            last_snapshot_block: 0,
+            status: PolicyStatusV3::Active,
+        })
+    }
+}
+
+/// Outcome of one observation cycle
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct CycleResult {
+    /// Observations not seen in earlier cycles, in committed order
+    pub new_observations: Vec<WeatherObservation>,
+    /// Sample hash of each new observation, as sent to the Ingest API
+    pub sample_hashes: Vec<[u8; 32]>,
+    /// On-chain action the OCW would take
+    pub decision: SnapshotDecision,
+    /// Hash oracle members would attest to, for a final report decision
+    pub final_report_hash: Option<[u8; 32]>,
+}
+
+/// AccuWeather historical/24 URL for a location
+pub fn historical_24_url(location_key: &[u8], api_key: &[u8]) -> Vec<u8> {
+    fetcher::build_historical_24_url(location_key, api_key)
+}
+
+/// Parse an AccuWeather historical/24 response body as the OCW does
+pub fn parse_historical_24(body: &[u8]) -> Result<Vec<WeatherObservation>, OcwErrorKind> {
+    http_client::parse_accuweather_historical_response(body)
+}
+
+/// Run one OCW cycle for `policy` over fetched `observations` at `now` (unix
+/// seconds), updating `state` as the OCW would before submitting anything.
+/// A final report decision marks `state` finalized.
+pub fn run_cycle(
+    policy: &DryRunPolicy,
+    state: &mut OcwPolicyState,
+    observations: Vec<WeatherObservation>,
+    now: u64,
+) -> CycleResult {
+    let new_observations: Vec<_> = observations
+        .into_iter()
+        .filter(|obs| obs.epoch_time > state.last_seen_epoch)
+        .collect();
+
+    let mut sample_hashes = Vec::new();
+    let decision = if new_observations.is_empty() {
+        // With nothing new, the OCW only reports maturity once coverage ended
+        if now > policy.coverage_end && !state.finalized {
+            SnapshotDecision::SendFinalMaturity
+        } else {
+            SnapshotDecision::None
+        }
+    } else {
+        let (new_commitment, hashes) =
+            commitment::process_commitment_batch(state.commitment, &new_observations);
+        let (agg_state, last_epoch) = aggregator::process_observation_batch(
+            policy.event_spec.event_type,
+            state.agg_state.clone(),
+            new_observations.clone(),
+        );
+        state.commitment = new_commitment;
+        state.agg_state = agg_state;
+        state.last_seen_epoch = last_epoch;
+        sample_hashes = hashes;
+
+        ocw::decide_snapshot_action(
+            state,
+            &policy.event_spec,
+            now,
+            policy.coverage_start,
+            policy.coverage_end,
+        )
+    };
+
+    let kind = match decision {
+        SnapshotDecision::SendFinalTrigger => Some(OracleReportKindV3::Trigger),
+        SnapshotDecision::SendFinalMaturity => Some(OracleReportKindV3::Maturity),
+        _ => None,
+    };
+    let final_report_hash = kind.map(|kind| {
+        state.finalized = true;
+        commitment::compute_final_report_hash(
+            policy.policy_id,
+            kind,
+            state.last_seen_epoch,
+            &state.agg_state,
+            &state.commitment,
+        )
+    });
+    if decision == SnapshotDecision::SendSnapshot {
+        state.last_snapshot_epoch = state.last_seen_epoch;
+    }
+
+    CycleResult { new_observations, sample_hashes, decision, final_report_hash }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prmx_primitives::{EventTypeV3, ThresholdV3, UnitV3};
+
+    const START: u64 = 1_700_000_000;
+
+    fn policy() -> DryRunPolicy {
+        DryRunPolicy {
+            policy_id: PolicyId::from([9u8; 16]),
+            location_id: 1,
+            event_spec: EventSpecV3 {
+                event_type: EventTypeV3::PrecipSumGte,
+                threshold: ThresholdV3 { value: 10_000, unit: UnitV3::MmX1000 },
+                early_trigger: true,
+            },
+            coverage_start: START,
+            coverage_end: START + 86_400,
+        }
+    }
+
+    fn response(hourly_mm: &[f64]) -> Vec<u8> {
+        let observations: Vec<_> = hourly_mm
+            .iter()
+            .enumerate()
+            .map(|(i, mm)| {
+                format!(
+                    "{{\"EpochTime\":{},\"HasPrecipitation\":true,\"PrecipitationType\":\"Rain\",\
+                     \"Temperature\":{{\"Metric\":{{\"Value\":21.5}}}},\
+                     \"Precip1hr\":{{\"Metric\":{{\"Value\":{}}}}}}}",
+                    START + 3600 * (i as u64 + 1),
+                    mm
+                )
+            })
+            .collect();
+        format!("[{}]", observations.join(",")).into_bytes()
+    }
+
+    #[test]
+    fn test_cycle_snapshots_then_triggers() {
+        let policy = policy();
+        let mut state = policy.initial_state();
+        let seed = state.commitment;
+
+        let observations = parse_historical_24(&response(&[2.0, 3.0])).unwrap();
+        assert_eq!(observations.len(), 2);
+        assert_eq!(observations[1].precip_1h_mm_x1000, 3_000);
+
+        let result = run_cycle(&policy, &mut state, observations.clone(), START + 3 * 3600);
+        assert_eq!(result.decision, SnapshotDecision::SendSnapshot);
+        assert_eq!(result.sample_hashes.len(), 2);
+        assert_eq!(state.agg_state, AggStateV3::PrecipSum { sum_mm_x1000: 5_000 });
+        assert!(commitment::verify_commitment_chain(seed, &observations, state.commitment));
+
+        // The same response again adds nothing
+        let result = run_cycle(&policy, &mut state, observations, START + 3 * 3600);
+        assert_eq!(result.decision, SnapshotDecision::None);
+        assert!(result.new_observations.is_empty());
+
+        // 2 + 3 + 6 mm crosses the 10 mm threshold
+        let observations = parse_historical_24(&response(&[2.0, 3.0, 6.0])).unwrap();
+        let result = run_cycle(&policy, &mut state, observations, START + 4 * 3600);
+        assert_eq!(result.new_observations.len(), 1);
+        assert_eq!(result.decision, SnapshotDecision::SendFinalTrigger);
+        assert_eq!(
+            result.final_report_hash,
+            Some(commitment::compute_final_report_hash(
+                policy.policy_id,
+                OracleReportKindV3::Trigger,
+                START + 3 * 3600,
+                &AggStateV3::PrecipSum { sum_mm_x1000: 11_000 },
+                &state.commitment,
+            ))
+        );
+        assert!(state.finalized);
+    }
+
+    #[test]
+    fn test_cycle_reports_maturity_after_coverage() {
+        let policy = policy();
+        let mut state = policy.initial_state();
+
+        let result = run_cycle(&policy, &mut state, Vec::new(), policy.coverage_end + 1);
+        assert_eq!(result.decision, SnapshotDecision::SendFinalMaturity);
+        assert!(result.final_report_hash.is_some());
+
+        // Already finalized
+        let result = run_cycle(&policy, &mut state, Vec::new(), policy.coverage_end + 2);
+        assert_eq!(result.decision, SnapshotDecision::None);
+    }
+}
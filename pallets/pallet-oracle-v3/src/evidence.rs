@@ -0,0 +1,274 @@
+//! # Evidence Export for OCW
+//!
+//! Keeps the full observation chain of each policy in offchain storage and uploads
+//! it to the Ingest API in chunks once the policy is finalized locally.
+//!
+//! The Ingest API expires raw observations after 30 days; the exported chain is kept
+//! for dispute resolution, where its samples can be replayed on-chain with
+//! `verify_commitment_chain`.
+
+use alloc::vec::Vec;
+use codec::{Decode, Encode};
+use frame_support::sp_runtime::offchain::storage::StorageValueRef;
+use prmx_primitives::PolicyId;
+
+use crate::commitment;
+use crate::fetcher::WeatherObservation;
+use crate::http_client;
+use crate::ocw::OCW_V3_PREFIX;
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Samples per evidence upload (two days of hourly observations)
+pub const EVIDENCE_CHUNK_SIZE: usize = 48;
+
+/// Maximum chunks uploaded per policy in one OCW run
+pub const MAX_EVIDENCE_CHUNKS_PER_RUN: u32 = 4;
+
+/// Key for the list of policies with evidence awaiting export
+pub const EVIDENCE_EXPORT_QUEUE_KEY: &[u8] = b"ocw:v3:evidence:export_queue";
+
+// ============================================================================
+// Evidence Log
+// ============================================================================
+
+/// Observation chain recorded for a policy in offchain storage
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, Default)]
+pub struct EvidenceLog {
+    /// Commitment the recorded chain starts from. This is the policy's initial
+    /// commitment unless the OCW resumed from an on-chain snapshot.
+    pub initial_commitment: [u8; 32],
+    /// Observations in the order they were committed
+    pub samples: Vec<WeatherObservation>,
+    /// Number of chunks already accepted by the Ingest API
+    pub exported_chunks: u32,
+}
+
+impl EvidenceLog {
+    /// Generate storage key for a policy
+    pub fn storage_key(policy_id: PolicyId) -> Vec<u8> {
+        let mut key = OCW_V3_PREFIX.to_vec();
+        key.extend_from_slice(b"policy:");
+        key.extend_from_slice(&policy_id.to_le_bytes());
+        key.extend_from_slice(b":evidence");
+        key
+    }
+
+    /// Load log from offchain storage
+    pub fn load(policy_id: PolicyId) -> Option<Self> {
+        let key = Self::storage_key(policy_id);
+        let storage = StorageValueRef::persistent(&key);
+        storage.get::<Self>().ok().flatten()
+    }
+
+    /// Save log to offchain storage
+    pub fn save(&self, policy_id: PolicyId) {
+        let key = Self::storage_key(policy_id);
+        let storage = StorageValueRef::persistent(&key);
+        storage.set(self);
+    }
+
+    /// Remove log from offchain storage
+    pub fn clear(policy_id: PolicyId) {
+        let key = Self::storage_key(policy_id);
+        let mut storage = StorageValueRef::persistent(&key);
+        storage.clear();
+    }
+
+    /// Append a committed batch. A new log starts at `commitment_before`.
+    pub fn record_batch(
+        policy_id: PolicyId,
+        commitment_before: [u8; 32],
+        observations: &[WeatherObservation],
+    ) {
+        let mut log = Self::load(policy_id).unwrap_or_else(|| Self {
+            initial_commitment: commitment_before,
+            ..Default::default()
+        });
+        log.samples.extend_from_slice(observations);
+        log.save(policy_id);
+    }
+
+    /// Split the log into upload chunks
+    pub fn chunks(&self) -> Vec<EvidenceChunk> {
+        plan_chunks(self.initial_commitment, &self.samples, EVIDENCE_CHUNK_SIZE)
+    }
+}
+
+/// One upload of a policy's evidence chain
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct EvidenceChunk {
+    /// Chunk position (0-based)
+    pub index: u32,
+    /// Total chunks in the chain
+    pub total: u32,
+    /// Index of the first sample in the chunk
+    pub start: usize,
+    /// Index after the last sample in the chunk
+    pub end: usize,
+    /// Commitment before the first sample
+    pub commitment_before: [u8; 32],
+    /// Commitment after the last sample
+    pub commitment_after: [u8; 32],
+    /// Sample hashes for the chunk's samples
+    pub sample_hashes: Vec<[u8; 32]>,
+}
+
+/// Split `samples` into chunks of at most `chunk_size`, carrying the commitment
+/// across chunk boundaries so each chunk can be checked on its own
+pub fn plan_chunks(
+    initial_commitment: [u8; 32],
+    samples: &[WeatherObservation],
+    chunk_size: usize,
+) -> Vec<EvidenceChunk> {
+    let chunk_size = chunk_size.max(1);
+    let total = samples.len().div_ceil(chunk_size) as u32;
+    let mut commitment_before = initial_commitment;
+
+    samples
+        .chunks(chunk_size)
+        .enumerate()
+        .map(|(index, chunk)| {
+            let (commitment_after, sample_hashes) =
+                commitment::process_commitment_batch(commitment_before, chunk);
+            let start = index * chunk_size;
+            let planned = EvidenceChunk {
+                index: index as u32,
+                total,
+                start,
+                end: start + chunk.len(),
+                commitment_before,
+                commitment_after,
+                sample_hashes,
+            };
+            commitment_before = commitment_after;
+            planned
+        })
+        .collect()
+}
+
+// ============================================================================
+// Export Queue
+// ============================================================================
+
+/// Get policies with evidence awaiting export
+pub fn pending_exports() -> Vec<PolicyId> {
+    let storage = StorageValueRef::persistent(EVIDENCE_EXPORT_QUEUE_KEY);
+    storage.get::<Vec<PolicyId>>().ok().flatten().unwrap_or_default()
+}
+
+/// Queue a policy's evidence for export (called when the policy is finalized locally)
+pub fn queue_export(policy_id: PolicyId) {
+    let mut queue = pending_exports();
+    if !queue.contains(&policy_id) {
+        queue.push(policy_id);
+        StorageValueRef::persistent(EVIDENCE_EXPORT_QUEUE_KEY).set(&queue);
+    }
+}
+
+/// Remove a policy from the export queue
+fn dequeue_export(policy_id: PolicyId) {
+    let mut queue = pending_exports();
+    queue.retain(|queued| *queued != policy_id);
+    StorageValueRef::persistent(EVIDENCE_EXPORT_QUEUE_KEY).set(&queue);
+}
+
+/// Upload pending evidence chunks for every queued policy.
+/// A failed upload stops that policy's export until the next run, where it
+/// resumes from the first chunk not yet accepted.
+pub fn export_pending(ingest_url: &[u8], hmac_secret: &[u8]) {
+    for policy_id in pending_exports() {
+        let Some(mut evidence) = EvidenceLog::load(policy_id) else {
+            dequeue_export(policy_id);
+            continue;
+        };
+
+        let chunks = evidence.chunks();
+        for chunk in chunks
+            .iter()
+            .skip(evidence.exported_chunks as usize)
+            .take(MAX_EVIDENCE_CHUNKS_PER_RUN as usize)
+        {
+            if let Err(e) = http_client::send_evidence_chunk(
+                ingest_url,
+                hmac_secret,
+                policy_id,
+                chunk,
+                &evidence.samples[chunk.start..chunk.end],
+            ) {
+                log::warn!(
+                    target: "prmx-oracle-v3",
+                    "Failed to export evidence chunk {} for policy {}: {:?}",
+                    chunk.index,
+                    policy_id,
+                    e
+                );
+                crate::ocw::record_error_kind(e);
+                break;
+            }
+            evidence.exported_chunks = chunk.index + 1;
+        }
+
+        if evidence.exported_chunks as usize >= chunks.len() {
+            log::info!(
+                target: "prmx-oracle-v3",
+                "🧾 Exported evidence chain for policy {} ({} samples, {} chunks)",
+                policy_id,
+                evidence.samples.len(),
+                chunks.len()
+            );
+            EvidenceLog::clear(policy_id);
+            dequeue_export(policy_id);
+        } else {
+            evidence.save(policy_id);
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn observations(count: u64) -> Vec<WeatherObservation> {
+        (0..count)
+            .map(|i| WeatherObservation {
+                epoch_time: 1_700_000_000 + i * 3600,
+                precip_1h_mm_x1000: (i as i64) * 500,
+                temp_c_x1000: 25_000,
+                wind_gust_mps_x1000: 4_000,
+                precip_type_mask: 1,
+                has_precipitation: i % 2 == 0,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_plan_chunks_links_commitments() {
+        let initial = [9u8; 32];
+        let samples = observations(10);
+        let chunks = plan_chunks(initial, &samples, 4);
+
+        assert_eq!(chunks.len(), 3);
+        assert!(chunks.iter().all(|c| c.total == 3));
+        assert_eq!((chunks[2].start, chunks[2].end), (8, 10));
+        assert_eq!(chunks[0].commitment_before, initial);
+        assert_eq!(chunks[1].commitment_before, chunks[0].commitment_after);
+        assert_eq!(chunks[2].commitment_before, chunks[1].commitment_after);
+
+        // The last chunk ends where the whole chain ends
+        let (expected, sample_hashes) = commitment::process_commitment_batch(initial, &samples);
+        assert_eq!(chunks[2].commitment_after, expected);
+        assert_eq!(chunks[1].sample_hashes, sample_hashes[4..8].to_vec());
+    }
+
+    #[test]
+    fn test_plan_chunks_empty_chain() {
+        assert!(plan_chunks([0u8; 32], &[], EVIDENCE_CHUNK_SIZE).is_empty());
+    }
+}
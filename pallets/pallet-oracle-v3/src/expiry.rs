@@ -1,6 +1,6 @@
 //! # Request Expiry Detection for OCW
 //!
-//! Functions for detecting and triggering expired underwrite requests.
+//! Functions for detecting and triggering expired underwrite requests and RFQs.
 //! This module is used by the OCW to periodically check for expired requests.
 
 use alloc::vec::Vec;
@@ -58,5 +58,8 @@ pub struct ExpiredRequest {
 pub trait ExpirySource {
     /// Get all expired requests that need cleanup
     fn get_expired_requests(current_time: u64) -> Vec<PolicyId>;
+
+    /// Get all open RFQs whose acceptance window has closed
+    fn get_expired_rfqs(current_time: u64) -> Vec<PolicyId>;
 }
 
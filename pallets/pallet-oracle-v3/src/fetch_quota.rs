@@ -0,0 +1,478 @@
+//! # AccuWeather Fetch Quota for OCW
+//!
+//! Policies are processed one by one, but their weather is fetched per location.
+//! `RunFetches` holds the outcome of each location's fetch for the current OCW
+//! run, so every policy on a location is fed by one fetch, and a failure is not
+//! retried once per policy. A failed location backs off for all of its policies.
+//!
+//! Each run may spend `fetch_budget_per_run()` AccuWeather calls plus the budget
+//! left unspent in earlier runs (up to `MAX_FETCH_CARRY_OVER`). Locations already
+//! fetched in the current hour are served from the `http_client` cache and cost
+//! nothing. Policies are visited round-robin from a persisted cursor: when the
+//! budget runs out, the next run starts with the first policy that was skipped.
+//!
+//! On top of the per-run budget, calls are counted per UTC day (`DailyUsage`)
+//! against `daily_fetch_budget()`, since AccuWeather keys have daily call limits.
+//! The last `PRIORITY_RESERVE_PERCENT` of the day's budget is kept for policies
+//! near their trigger or maturity (`is_priority`); once the budget is spent the
+//! OCW only serves cached locations until the next day, and reports
+//! `ApiBudgetExhausted` on-chain once for the day.
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use codec::{Decode, Encode};
+use frame_support::sp_runtime::offchain::storage::StorageValueRef;
+use prmx_primitives::{AggStateV3, EventSpecV3, EventTypeV3, OcwErrorKind, PolicyId};
+
+use crate::fetcher::WeatherObservation;
+use crate::ocw::OCW_V3_PREFIX;
+use crate::LocationId;
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// AccuWeather calls per OCW run unless overridden in offchain storage
+pub const DEFAULT_FETCH_BUDGET_PER_RUN: u32 = 20;
+
+/// Most unspent budget carried into later runs
+pub const MAX_FETCH_CARRY_OVER: u32 = 40;
+
+/// Key overriding the per-run budget (SCALE `u32`)
+pub const FETCH_BUDGET_PER_RUN_KEY: &[u8] = b"ocw:v3:fetch_budget_per_run";
+
+/// Key for the carry-over and round-robin cursor
+pub const FETCH_BUDGET_STATE_KEY: &[u8] = b"ocw:v3:fetch_budget:state";
+
+/// Delay before retrying a failed location; doubles per consecutive failure
+pub const LOCATION_BACKOFF_BASE_SECS: u64 = 30;
+
+/// Upper bound on a location's retry delay
+pub const LOCATION_BACKOFF_MAX_SECS: u64 = 600;
+
+// ============================================================================
+// Budget
+// ============================================================================
+
+/// Budget carried between OCW runs
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, Default)]
+pub struct FetchBudgetState {
+    /// Calls left unspent by earlier runs
+    pub carry_over: u32,
+    /// First policy to visit in the next run
+    pub next_policy: Option<PolicyId>,
+}
+
+impl FetchBudgetState {
+    /// Load state from offchain storage
+    pub fn load() -> Self {
+        StorageValueRef::persistent(FETCH_BUDGET_STATE_KEY)
+            .get::<Self>()
+            .ok()
+            .flatten()
+            .unwrap_or_default()
+    }
+
+    /// Save state to offchain storage
+    pub fn save(&self) {
+        StorageValueRef::persistent(FETCH_BUDGET_STATE_KEY).set(self);
+    }
+}
+
+/// Calls each run may spend before carry-over
+pub fn fetch_budget_per_run() -> u32 {
+    StorageValueRef::persistent(FETCH_BUDGET_PER_RUN_KEY)
+        .get::<u32>()
+        .ok()
+        .flatten()
+        .unwrap_or(DEFAULT_FETCH_BUDGET_PER_RUN)
+}
+
+/// Set the per-run budget
+pub fn set_fetch_budget_per_run(budget: u32) {
+    StorageValueRef::persistent(FETCH_BUDGET_PER_RUN_KEY).set(&budget);
+}
+
+/// Order `items` by policy id, starting at the first policy at or after `cursor`
+/// and wrapping around
+pub fn round_robin<V>(
+    mut items: Vec<(PolicyId, V)>,
+    cursor: Option<PolicyId>,
+) -> Vec<(PolicyId, V)> {
+    items.sort_by_key(|(policy_id, _)| *policy_id);
+    if let Some(cursor) = cursor {
+        let start = items.partition_point(|(policy_id, _)| *policy_id < cursor);
+        items.rotate_left(start);
+    }
+    items
+}
+
+/// State carried into the next run: unspent calls (capped) and the first
+/// policy skipped for lack of budget, if any
+pub fn next_state(remaining: u32, first_skipped: Option<PolicyId>) -> FetchBudgetState {
+    FetchBudgetState {
+        carry_over: remaining.min(MAX_FETCH_CARRY_OVER),
+        next_policy: first_skipped,
+    }
+}
+
+// ============================================================================
+// Daily Budget
+// ============================================================================
+
+/// AccuWeather calls per UTC day unless overridden in offchain storage
+pub const DEFAULT_DAILY_FETCH_BUDGET: u32 = 2_000;
+
+/// Key overriding the daily budget (SCALE `u32`)
+pub const DAILY_FETCH_BUDGET_KEY: &[u8] = b"ocw:v3:daily_fetch_budget";
+
+/// Key for the current day's call count
+pub const DAILY_FETCH_USAGE_KEY: &[u8] = b"ocw:v3:fetch_budget:daily_usage";
+
+/// Share of the daily budget only spent on priority policies
+pub const PRIORITY_RESERVE_PERCENT: u32 = 20;
+
+/// A policy this close to its coverage end (or past it) is a priority
+pub const NEAR_MATURITY_SECS: u64 = 6 * 3600;
+
+/// A policy whose aggregate has reached this share of its threshold is a priority
+pub const NEAR_TRIGGER_PERCENT: i64 = 80;
+
+/// Temperature policies within this margin of their threshold are a priority
+/// (celsius * 1000)
+pub const NEAR_TRIGGER_TEMP_MARGIN_X1000: i64 = 2_000;
+
+const SECS_PER_DAY: u64 = 86_400;
+
+/// AccuWeather calls made on one UTC day
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, Default)]
+pub struct DailyUsage {
+    /// Days since the unix epoch
+    pub day: u64,
+    /// Calls made on `day`
+    pub calls: u32,
+    /// Whether `ApiBudgetExhausted` was reported for `day`
+    pub warned: bool,
+}
+
+impl DailyUsage {
+    /// Usage for the day of `now`; a new day starts from zero
+    pub fn load(now: u64) -> Self {
+        let day = now / SECS_PER_DAY;
+        StorageValueRef::persistent(DAILY_FETCH_USAGE_KEY)
+            .get::<Self>()
+            .ok()
+            .flatten()
+            .filter(|usage| usage.day == day)
+            .unwrap_or(Self { day, ..Default::default() })
+    }
+
+    /// Save usage to offchain storage
+    pub fn save(&self) {
+        StorageValueRef::persistent(DAILY_FETCH_USAGE_KEY).set(self);
+    }
+
+    /// Count `calls` made today
+    pub fn record(&mut self, calls: u32) {
+        self.calls = self.calls.saturating_add(calls);
+    }
+
+    /// Calls left today under `budget`
+    pub fn remaining(&self, budget: u32) -> u32 {
+        budget.saturating_sub(self.calls)
+    }
+
+    /// Whether today's `budget` is spent
+    pub fn is_exhausted(&self, budget: u32) -> bool {
+        self.calls >= budget
+    }
+
+    /// Whether only the priority reserve of `budget` is left today
+    pub fn in_priority_reserve(&self, budget: u32) -> bool {
+        self.remaining(budget) <= priority_reserve(budget)
+    }
+}
+
+/// Calls each day may spend
+pub fn daily_fetch_budget() -> u32 {
+    StorageValueRef::persistent(DAILY_FETCH_BUDGET_KEY)
+        .get::<u32>()
+        .ok()
+        .flatten()
+        .unwrap_or(DEFAULT_DAILY_FETCH_BUDGET)
+}
+
+/// Set the daily budget
+pub fn set_daily_fetch_budget(budget: u32) {
+    StorageValueRef::persistent(DAILY_FETCH_BUDGET_KEY).set(&budget);
+}
+
+/// Calls of `budget` kept for priority policies
+pub fn priority_reserve(budget: u32) -> u32 {
+    (budget as u64 * PRIORITY_RESERVE_PERCENT as u64 / 100) as u32
+}
+
+/// Whether a policy still gets fetches from the priority reserve: it is near (or
+/// past) its coverage end, or its aggregate is near its threshold
+pub fn is_priority(
+    event_spec: &EventSpecV3,
+    agg_state: &AggStateV3,
+    coverage_end: u64,
+    now: u64,
+) -> bool {
+    now.saturating_add(NEAR_MATURITY_SECS) >= coverage_end || near_trigger(event_spec, agg_state)
+}
+
+/// Whether an aggregate is within `NEAR_TRIGGER_PERCENT` of its threshold, or
+/// `NEAR_TRIGGER_TEMP_MARGIN_X1000` for temperatures. Precipitation types have
+/// no distance to their threshold and are never near it.
+pub fn near_trigger(event_spec: &EventSpecV3, agg_state: &AggStateV3) -> bool {
+    let threshold = event_spec.threshold.value;
+    let near =
+        |value: i64| value.saturating_mul(100) >= threshold.saturating_mul(NEAR_TRIGGER_PERCENT);
+
+    match (event_spec.event_type, agg_state) {
+        (EventTypeV3::PrecipSumGte, AggStateV3::PrecipSum { sum_mm_x1000 }) => near(*sum_mm_x1000),
+        (EventTypeV3::Precip1hGte, AggStateV3::Precip1hMax { max_1h_mm_x1000 }) => {
+            near(*max_1h_mm_x1000)
+        }
+        (EventTypeV3::WindGustMaxGte, AggStateV3::WindGustMax { max_mps_x1000 }) => {
+            near(*max_mps_x1000)
+        }
+        (EventTypeV3::DryStreakGte, AggStateV3::ConsecutiveDryHours { longest_hours, .. }) => {
+            near(*longest_hours as i64)
+        }
+        (EventTypeV3::TempMaxGte, AggStateV3::TempMax { max_c_x1000 }) => {
+            max_c_x1000.saturating_add(NEAR_TRIGGER_TEMP_MARGIN_X1000) >= threshold
+        }
+        (EventTypeV3::TempMinLte, AggStateV3::TempMin { min_c_x1000 }) => {
+            min_c_x1000.saturating_sub(NEAR_TRIGGER_TEMP_MARGIN_X1000) <= threshold
+        }
+        _ => false,
+    }
+}
+
+// ============================================================================
+// Location Backoff
+// ============================================================================
+
+/// Consecutive failures of one location, shared by every policy on it
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, Default)]
+pub struct LocationBackoff {
+    /// Number of consecutive failed fetches
+    pub error_count: u32,
+    /// No fetch before this timestamp
+    pub retry_after: u64,
+}
+
+impl LocationBackoff {
+    fn storage_key(location_id: LocationId) -> Vec<u8> {
+        let mut key = OCW_V3_PREFIX.to_vec();
+        key.extend_from_slice(b"location:");
+        key.extend_from_slice(&location_id.to_le_bytes());
+        key.extend_from_slice(b":backoff");
+        key
+    }
+
+    /// Load a location's backoff from offchain storage
+    pub fn load(location_id: LocationId) -> Self {
+        StorageValueRef::persistent(&Self::storage_key(location_id))
+            .get::<Self>()
+            .ok()
+            .flatten()
+            .unwrap_or_default()
+    }
+
+    /// Record a failed fetch at `now` and save it
+    pub fn record_failure(location_id: LocationId, now: u64) {
+        let mut backoff = Self::load(location_id);
+        backoff.error_count = backoff.error_count.saturating_add(1);
+        backoff.retry_after = now.saturating_add(location_backoff_secs(backoff.error_count));
+        StorageValueRef::persistent(&Self::storage_key(location_id)).set(&backoff);
+    }
+
+    /// Forget a location's failures after a successful fetch
+    pub fn clear(location_id: LocationId) {
+        StorageValueRef::persistent(&Self::storage_key(location_id)).clear();
+    }
+}
+
+/// Retry delay after `error_count` consecutive failures
+pub fn location_backoff_secs(error_count: u32) -> u64 {
+    LOCATION_BACKOFF_BASE_SECS
+        .saturating_mul(1u64 << error_count.saturating_sub(1).min(16))
+        .min(LOCATION_BACKOFF_MAX_SECS)
+}
+
+// ============================================================================
+// Per-Run Fetches
+// ============================================================================
+
+/// Outcome of asking for a location's observations in this run
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum FetchOutcome {
+    /// Observations fetched (or cached) for the location
+    Fetched(Vec<WeatherObservation>),
+    /// The fetch failed in this run
+    Failed,
+    /// The location is backing off after earlier failures
+    BackingOff,
+    /// No budget left in this run
+    OverBudget,
+}
+
+/// Location fetches of one OCW run
+pub struct RunFetches {
+    remaining: u32,
+    outcomes: BTreeMap<LocationId, FetchOutcome>,
+}
+
+impl RunFetches {
+    /// Start a run with `budget` calls to spend
+    pub fn new(budget: u32) -> Self {
+        Self { remaining: budget, outcomes: BTreeMap::new() }
+    }
+
+    /// Calls left in this run
+    pub fn remaining(&self) -> u32 {
+        self.remaining
+    }
+
+    /// Observations for `location_id`. The first request in a run resolves the
+    /// outcome; later requests for the same location reuse it. `cached` says
+    /// whether `fetch` would be served without an API call.
+    pub fn get(
+        &mut self,
+        location_id: LocationId,
+        now: u64,
+        cached: bool,
+        fetch: impl FnOnce() -> Result<Vec<WeatherObservation>, OcwErrorKind>,
+    ) -> FetchOutcome {
+        if let Some(outcome) = self.outcomes.get(&location_id) {
+            return outcome.clone();
+        }
+        if !cached && now < LocationBackoff::load(location_id).retry_after {
+            return FetchOutcome::BackingOff;
+        }
+        if !cached && self.remaining == 0 {
+            // Not remembered: a later run with budget should fetch it
+            return FetchOutcome::OverBudget;
+        }
+        if !cached {
+            self.remaining -= 1;
+        }
+
+        let outcome = match fetch() {
+            Ok(observations) => {
+                LocationBackoff::clear(location_id);
+                FetchOutcome::Fetched(observations)
+            }
+            Err(e) => {
+                log::warn!(
+                    target: "prmx-oracle-v3",
+                    "AccuWeather fetch for location {} failed: {:?}",
+                    location_id,
+                    e
+                );
+                crate::ocw::record_error_kind(e);
+                LocationBackoff::record_failure(location_id, now);
+                FetchOutcome::Failed
+            }
+        };
+        self.outcomes.insert(location_id, outcome.clone());
+        outcome
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(n: u8) -> PolicyId {
+        PolicyId::from([n; 16])
+    }
+
+    fn order(items: Vec<(PolicyId, char)>, cursor: Option<PolicyId>) -> Vec<char> {
+        round_robin(items, cursor).into_iter().map(|(_, v)| v).collect()
+    }
+
+    #[test]
+    fn test_round_robin_starts_at_cursor_and_wraps() {
+        let items = [(policy(3), 'c'), (policy(1), 'a'), (policy(2), 'b')].to_vec();
+
+        assert_eq!(order(items.clone(), None), ['a', 'b', 'c'].to_vec());
+        assert_eq!(order(items, Some(policy(2))), ['b', 'c', 'a'].to_vec());
+
+        // A cursor policy that has since settled resumes at the next one
+        let gone = [(policy(1), 'a'), (policy(3), 'c')].to_vec();
+        assert_eq!(order(gone, Some(policy(2))), ['c', 'a'].to_vec());
+    }
+
+    #[test]
+    fn test_next_state_caps_carry_over() {
+        assert_eq!(next_state(5, None), FetchBudgetState { carry_over: 5, next_policy: None });
+        assert_eq!(
+            next_state(MAX_FETCH_CARRY_OVER + 10, Some(policy(4))),
+            FetchBudgetState { carry_over: MAX_FETCH_CARRY_OVER, next_policy: Some(policy(4)) }
+        );
+    }
+
+    fn spec(event_type: EventTypeV3, value: i64) -> EventSpecV3 {
+        EventSpecV3 {
+            event_type,
+            threshold: prmx_primitives::ThresholdV3 { value, ..Default::default() },
+            early_trigger: true,
+        }
+    }
+
+    #[test]
+    fn test_daily_usage_reserve_and_exhaustion() {
+        let mut usage = DailyUsage { day: 1, ..Default::default() };
+        assert_eq!(priority_reserve(100), 20);
+        assert!(!usage.in_priority_reserve(100));
+
+        usage.record(79);
+        assert!(!usage.in_priority_reserve(100));
+        usage.record(1);
+        assert!(usage.in_priority_reserve(100));
+        assert_eq!(usage.remaining(100), 20);
+
+        usage.record(20);
+        assert!(usage.is_exhausted(100));
+        assert_eq!(usage.remaining(100), 0);
+    }
+
+    #[test]
+    fn test_is_priority_near_maturity_or_trigger() {
+        let rain = spec(EventTypeV3::PrecipSumGte, 50_000);
+        let dry = AggStateV3::PrecipSum { sum_mm_x1000: 10_000 };
+        let wet = AggStateV3::PrecipSum { sum_mm_x1000: 40_000 };
+
+        assert!(!is_priority(&rain, &dry, 100_000, 100_000 - NEAR_MATURITY_SECS - 1));
+        assert!(is_priority(&rain, &dry, 100_000, 100_000 - NEAR_MATURITY_SECS));
+        assert!(is_priority(&rain, &dry, 100_000, 200_000));
+        assert!(is_priority(&rain, &wet, 100_000, 0));
+
+        // Temperatures use an absolute margin in either direction
+        let frost = spec(EventTypeV3::TempMinLte, -2_000);
+        assert!(is_priority(&frost, &AggStateV3::TempMin { min_c_x1000: 0 }, 100_000, 0));
+        assert!(!is_priority(&frost, &AggStateV3::TempMin { min_c_x1000: 500 }, 100_000, 0));
+
+        let types = spec(EventTypeV3::PrecipTypeOccurred, 0b10);
+        let snow = AggStateV3::PrecipTypeOccurred { mask: 0b10 };
+        assert!(!is_priority(&types, &snow, 100_000, 0));
+    }
+
+    #[test]
+    fn test_location_backoff_doubles_up_to_max() {
+        assert_eq!(location_backoff_secs(1), 30);
+        assert_eq!(location_backoff_secs(2), 60);
+        assert_eq!(location_backoff_secs(5), 480);
+        assert_eq!(location_backoff_secs(6), LOCATION_BACKOFF_MAX_SECS);
+        assert_eq!(location_backoff_secs(40), LOCATION_BACKOFF_MAX_SECS);
+    }
+}
@@ -4,15 +4,16 @@
 //! Uses the historical/24 endpoint for hourly observations.
 
 use alloc::vec::Vec;
-use codec::{Decode, Encode};
-use prmx_primitives::{AggStateV3, EventTypeV3};
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use prmx_primitives::{AggStateV3, EventTypeV3, V3_DRY_HOUR_MAX_PRECIP_MM_X1000};
+use scale_info::TypeInfo;
 
 // ============================================================================
 // AccuWeather Response Types
 // ============================================================================
 
 /// Parsed observation from AccuWeather
-#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode)]
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, DecodeWithMemTracking, TypeInfo, MaxEncodedLen)]
 pub struct WeatherObservation {
     /// Unix epoch time of observation
     pub epoch_time: u64,
@@ -117,6 +118,22 @@ pub fn update_agg_state(
             }
         }
 
+        // Dry streak: extend the current run on a dry hour, reset it otherwise
+        (
+            EventTypeV3::DryStreakGte,
+            AggStateV3::ConsecutiveDryHours { current_hours, longest_hours },
+        ) => {
+            let current_hours = if observation.precip_1h_mm_x1000 < V3_DRY_HOUR_MAX_PRECIP_MM_X1000 {
+                current_hours.saturating_add(1)
+            } else {
+                0
+            };
+            AggStateV3::ConsecutiveDryHours {
+                current_hours,
+                longest_hours: (*longest_hours).max(current_hours),
+            }
+        }
+
         // Type mismatch - return current unchanged
         _ => current.clone(),
     }
@@ -0,0 +1,167 @@
+//! # Geohash Location Index
+//!
+//! Registered locations are indexed by the geohash cell their coordinates fall in.
+//! At `GEOHASH_PRECISION` characters a cell spans 0.044° in both directions, about
+//! 4.9 km at the equator (east-west it shrinks with the cosine of the latitude).
+//! A point's cell and its eight neighbours therefore contain every location closer
+//! than one cell width, which bounds the lookups done when adding a location and
+//! when searching for the nearest one.
+//!
+//! Distances use an equirectangular approximation, which is accurate to well
+//! under 1% at the few-kilometre scale the index is used for.
+
+use alloc::vec::Vec;
+
+/// Geohash characters used for the location index
+pub const GEOHASH_PRECISION: usize = 5;
+
+/// Geohash cell at `GEOHASH_PRECISION`, e.g. `wdw51` for Manila
+pub type Geohash = [u8; GEOHASH_PRECISION];
+
+const BASE32: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+/// Bits per character, interleaved starting with longitude
+const BITS: u32 = GEOHASH_PRECISION as u32 * 5;
+const LON_BITS: u32 = BITS.div_ceil(2);
+const LAT_BITS: u32 = BITS / 2;
+
+/// Coordinates are scaled by 1e6
+const SCALE: i64 = 1_000_000;
+
+/// Metres per 1e-6 degree of latitude (mean Earth radius 6,371 km), scaled by 1e6
+const METRES_PER_MICRODEGREE_X1E6: u128 = 111_195;
+
+/// Cell indices of coordinates scaled by 1e6, as (latitude cell, longitude cell)
+fn cell(latitude: i32, longitude: i32) -> (i64, i64) {
+    let lat = (latitude as i64 + 90 * SCALE).clamp(0, 180 * SCALE - 1);
+    let lon = (longitude as i64 + 180 * SCALE).rem_euclid(360 * SCALE);
+    ((lat << LAT_BITS) / (180 * SCALE), (lon << LON_BITS) / (360 * SCALE))
+}
+
+fn encode_cell(lat_cell: i64, lon_cell: i64) -> Geohash {
+    let mut bits: u32 = 0;
+    for k in 0..BITS {
+        let bit = if k % 2 == 0 {
+            (lon_cell >> (LON_BITS - 1 - k / 2)) & 1
+        } else {
+            (lat_cell >> (LAT_BITS - 1 - k / 2)) & 1
+        };
+        bits = (bits << 1) | bit as u32;
+    }
+
+    let mut hash = [0u8; GEOHASH_PRECISION];
+    for (i, c) in hash.iter_mut().enumerate() {
+        let shift = 5 * (GEOHASH_PRECISION - 1 - i);
+        *c = BASE32[((bits >> shift) & 31) as usize];
+    }
+    hash
+}
+
+/// Geohash cell of coordinates scaled by 1e6
+pub fn encode(latitude: i32, longitude: i32) -> Geohash {
+    let (lat_cell, lon_cell) = cell(latitude, longitude);
+    encode_cell(lat_cell, lon_cell)
+}
+
+/// Cell of the coordinates followed by its neighbours. Longitude wraps around
+/// the antimeridian; cells past the poles are skipped.
+pub fn neighbourhood(latitude: i32, longitude: i32) -> Vec<Geohash> {
+    let (lat_cell, lon_cell) = cell(latitude, longitude);
+    let lat_cells = 1i64 << LAT_BITS;
+    let lon_cells = 1i64 << LON_BITS;
+
+    let mut cells = Vec::with_capacity(9);
+    for (d_lat, d_lon) in [(0, 0), (-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)] {
+        let lat = lat_cell + d_lat;
+        if !(0..lat_cells).contains(&lat) {
+            continue;
+        }
+        let hash = encode_cell(lat, (lon_cell + d_lon).rem_euclid(lon_cells));
+        if !cells.contains(&hash) {
+            cells.push(hash);
+        }
+    }
+    cells
+}
+
+/// Cosine of a latitude in 1e-6 degrees, scaled by 1e9 (Taylor series to x^8,
+/// error below 3e-5 up to the poles)
+fn cos_x1e9(latitude: i64) -> i128 {
+    const ONE: i128 = 1_000_000_000;
+    const PI_X1E9: i128 = 3_141_592_654;
+
+    let x = (latitude.unsigned_abs().min(90 * SCALE as u64) as i128) * PI_X1E9 / (180 * SCALE as i128);
+    let x2 = x * x / ONE;
+
+    // 1 - x²/2! + x⁴/4! - x⁶/6! + x⁸/8!, evaluated from the innermost term
+    let mut cos = ONE;
+    for n in [8i128, 6, 4, 2] {
+        cos = ONE - x2 * cos / ONE / (n * (n - 1));
+    }
+    cos.max(0)
+}
+
+/// Approximate distance in metres between two coordinates scaled by 1e6
+pub fn distance_m(lat_a: i32, lon_a: i32, lat_b: i32, lon_b: i32) -> u64 {
+    let d_lat = (lat_b as i64 - lat_a as i64) as i128;
+    let mut d_lon = lon_b as i64 - lon_a as i64;
+    if d_lon > 180 * SCALE {
+        d_lon -= 360 * SCALE;
+    } else if d_lon < -180 * SCALE {
+        d_lon += 360 * SCALE;
+    }
+    let mean_lat = (lat_a as i64 + lat_b as i64) / 2;
+    let d_east = d_lon as i128 * cos_x1e9(mean_lat) / 1_000_000_000;
+
+    let microdegrees = ((d_lat * d_lat + d_east * d_east) as u128).isqrt();
+    (microdegrees * METRES_PER_MICRODEGREE_X1E6 / 1_000_000) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode() {
+        assert_eq!(&encode(14_599_500, 120_984_200), b"wdw51");
+        assert_eq!(&encode(-33_868_800, 151_209_300), b"r3gx2");
+        assert_eq!(&encode(0, 0), b"s0000");
+        assert_eq!(&encode(-90_000_000, -180_000_000), b"00000");
+        // The poles and the antimeridian stay in range
+        assert_eq!(&encode(90_000_000, 179_999_999), b"zzzzz");
+        assert_eq!(encode(0, 180_000_000), encode(0, -180_000_000));
+    }
+
+    #[test]
+    fn test_neighbourhood() {
+        let cells = neighbourhood(14_599_500, 120_984_200);
+        assert_eq!(cells.len(), 9);
+        assert_eq!(cells[0], encode(14_599_500, 120_984_200));
+        // A point 0.03° away falls in a neighbouring cell
+        assert!(cells.contains(&encode(14_629_500, 121_014_200)));
+
+        // Neighbours wrap around the antimeridian
+        assert!(neighbourhood(0, 179_999_999).contains(&encode(0, -179_999_999)));
+
+        // No cells past the pole
+        assert_eq!(neighbourhood(90_000_000, 0).len(), 6);
+    }
+
+    #[test]
+    fn test_distance_m() {
+        assert_eq!(distance_m(14_599_500, 120_984_200, 14_599_500, 120_984_200), 0);
+
+        // 0.01° of latitude is about 1,112 m anywhere
+        assert_eq!(distance_m(14_599_500, 120_984_200, 14_609_500, 120_984_200), 1_111);
+        // 0.01° of longitude is about 1,112 m at the equator and half that at 60°
+        assert_eq!(distance_m(0, 0, 0, 10_000), 1_111);
+        assert_eq!(distance_m(60_000_000, 0, 60_000_000, 10_000), 555);
+
+        // Symmetric, and short across the antimeridian
+        assert_eq!(distance_m(0, 179_995_000, 0, -179_995_000), 1_111);
+        assert_eq!(
+            distance_m(14_599_500, 120_984_200, 14_676_000, 121_043_700),
+            distance_m(14_676_000, 121_043_700, 14_599_500, 120_984_200),
+        );
+    }
+}
@@ -2,15 +2,24 @@
 //!
 //! Provides HTTP client functions for AccuWeather API and Ingest API.
 //! Uses sp_runtime::offchain::http for making requests.
+//!
+//! AccuWeather fetches try each configured endpoint in order (the public host
+//! first, then any mirrors), retrying transient failures with jittered backoff
+//! within `HTTP_FETCH_BUDGET_MS`. Parsed observations are cached in offchain
+//! storage per location for the current hour, so later OCW runs in the same
+//! hour skip the network.
 
 use alloc::string::String;
 use alloc::vec::Vec;
 use alloc::format;
-use sp_runtime::offchain::{http, Duration};
+use codec::{Decode, Encode};
+use frame_support::sp_runtime::offchain::storage::StorageValueRef;
+use sp_runtime::offchain::{http, Duration, Timestamp};
 
 use crate::fetcher::WeatherObservation;
 use crate::commitment;
-use prmx_primitives::PolicyId;
+use crate::evidence::EvidenceChunk;
+use prmx_primitives::{OcwErrorKind, PolicyId};
 
 // ============================================================================
 // Constants
@@ -22,24 +31,94 @@ pub const ACCUWEATHER_BASE_URL: &str = "https://dataservice.accuweather.com";
 /// HTTP request timeout (30 seconds)
 pub const HTTP_TIMEOUT_MS: u64 = 30_000;
 
+/// Total time one AccuWeather fetch may spend across endpoints and retries
+pub const HTTP_FETCH_BUDGET_MS: u64 = 45_000;
+
+/// Attempts per AccuWeather endpoint before failing over to the next one
+pub const HTTP_ATTEMPTS_PER_ENDPOINT: u32 = 2;
+
+/// Backoff before the first retry; doubles on each further retry
+pub const HTTP_RETRY_BASE_DELAY_MS: u64 = 500;
+
+/// Prefix for cached AccuWeather observations (followed by the location key)
+pub const ACCUWEATHER_CACHE_PREFIX: &[u8] = b"ocw:v3:accuweather_cache:";
+
 // ============================================================================
 // AccuWeather Client
 // ============================================================================
 
-/// Fetch 24-hour historical weather data from AccuWeather
+/// Observations fetched for a location, valid for the hour they were fetched in
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode)]
+pub struct CachedObservations {
+    /// Hour index (unix_timestamp / 3600) of the fetch
+    pub hour: u64,
+    pub observations: Vec<WeatherObservation>,
+}
+
+impl CachedObservations {
+    fn storage_key(location_key: &[u8]) -> Vec<u8> {
+        let mut key = ACCUWEATHER_CACHE_PREFIX.to_vec();
+        key.extend_from_slice(location_key);
+        key
+    }
+
+    /// Cached observations for `location_key`, if fetched during `hour`
+    pub fn load(location_key: &[u8], hour: u64) -> Option<Vec<WeatherObservation>> {
+        let key = Self::storage_key(location_key);
+        StorageValueRef::persistent(&key)
+            .get::<Self>()
+            .ok()
+            .flatten()
+            .filter(|cached| cached.hour == hour)
+            .map(|cached| cached.observations)
+    }
+
+    /// Replace the cached observations for `location_key`
+    pub fn store(location_key: &[u8], hour: u64, observations: &[WeatherObservation]) {
+        let key = Self::storage_key(location_key);
+        StorageValueRef::persistent(&key).set(&Self { hour, observations: observations.to_vec() });
+    }
+}
+
+/// Whether `fetch_accuweather_historical` would be served from the cache this hour
+pub fn has_cached_historical(location_key: &[u8]) -> bool {
+    let hour = sp_io::offchain::timestamp().unix_millis() / 1000 / 3600;
+    CachedObservations::load(location_key, hour).is_some()
+}
+
+/// Delay before retry number `retry` (0-based): exponential backoff plus up to
+/// half of it again as jitter, so OCWs on different nodes spread out
+pub fn backoff_delay_ms(retry: u32, jitter: u8) -> u64 {
+    let base = HTTP_RETRY_BASE_DELAY_MS.saturating_mul(1u64 << retry.min(16));
+    base.saturating_add(base * jitter as u64 / (2 * u8::MAX as u64))
+}
+
+/// Fetch 24-hour historical weather data from AccuWeather, trying `endpoints`
+/// (base URLs) in order. Served from the offchain cache if this location was
+/// already fetched in the current hour.
 pub fn fetch_accuweather_historical(
     location_key: &[u8],
     api_key: &[u8],
-) -> Result<Vec<WeatherObservation>, &'static str> {
+    endpoints: &[Vec<u8>],
+) -> Result<Vec<WeatherObservation>, OcwErrorKind> {
     let location_key_str = core::str::from_utf8(location_key)
-        .map_err(|_| "Invalid location key encoding")?;
+        .map_err(|_| OcwErrorKind::Config)?;
     let api_key_str = core::str::from_utf8(api_key)
-        .map_err(|_| "Invalid API key encoding")?;
-    
-    // Build URL for historical/24 endpoint
-    let url = format!(
-        "{}/currentconditions/v1/{}/historical/24?apikey={}&details=true",
-        ACCUWEATHER_BASE_URL,
+        .map_err(|_| OcwErrorKind::Config)?;
+
+    let hour = sp_io::offchain::timestamp().unix_millis() / 1000 / 3600;
+    if let Some(observations) = CachedObservations::load(location_key, hour) {
+        log::debug!(
+            target: "prmx-oracle-v3",
+            "Using cached AccuWeather historical/24 for location {}",
+            location_key_str
+        );
+        return Ok(observations);
+    }
+
+    // Path and query for the historical/24 endpoint
+    let path = format!(
+        "/currentconditions/v1/{}/historical/24?apikey={}&details=true",
         location_key_str,
         api_key_str
     );
@@ -50,40 +129,139 @@ pub fn fetch_accuweather_historical(
         location_key_str
     );
     
-    // Make HTTP request
-    let request = http::Request::get(&url);
-    let timeout = sp_io::offchain::timestamp()
-        .add(Duration::from_millis(HTTP_TIMEOUT_MS));
+    let body = get_with_failover(endpoints, &path)?;
     
-    let pending = request
-        .deadline(timeout)
+    // Parse JSON response
+    let observations = parse_accuweather_historical_response(&body)?;
+    CachedObservations::store(location_key, hour, &observations);
+
+    Ok(observations)
+}
+
+/// Fetch a location's timezone offset (minutes) and elevation (meters) from the
+/// AccuWeather locations endpoint. Not cached: the OCW resolves each location once.
+pub fn fetch_location_details(
+    location_key: &[u8],
+    api_key: &[u8],
+    endpoints: &[Vec<u8>],
+) -> Result<(Option<i16>, Option<i16>), OcwErrorKind> {
+    let location_key_str = core::str::from_utf8(location_key)
+        .map_err(|_| OcwErrorKind::Config)?;
+    let api_key_str = core::str::from_utf8(api_key)
+        .map_err(|_| OcwErrorKind::Config)?;
+
+    let path = format!("/locations/v1/{}?apikey={}", location_key_str, api_key_str);
+
+    log::info!(
+        target: "prmx-oracle-v3",
+        "🌐 Fetching AccuWeather location details for {}",
+        location_key_str
+    );
+
+    let body = get_with_failover(endpoints, &path)?;
+    parse_location_details_response(&body)
+}
+
+/// Parse the timezone offset and elevation from AccuWeather location details.
+/// Format: {"TimeZone":{"GmtOffset":8.0,...},"GeoPosition":{"Elevation":{"Metric":{"Value":13.0}}}}
+pub(crate) fn parse_location_details_response(
+    json: &[u8],
+) -> Result<(Option<i16>, Option<i16>), OcwErrorKind> {
+    let json_str = core::str::from_utf8(json).map_err(|_| OcwErrorKind::Parse)?;
+
+    let offset_mins = extract_json_value(json_str, &["TimeZone", "GmtOffset"])
+        .and_then(crate::location_meta::offset_mins_from_hours);
+    let elevation_m = extract_json_value(json_str, &["GeoPosition", "Elevation", "Metric", "Value"])
+        .and_then(crate::location_meta::elevation_from_meters);
+
+    if offset_mins.is_none() && elevation_m.is_none() {
+        return Err(OcwErrorKind::Parse);
+    }
+    Ok((offset_mins, elevation_m))
+}
+
+/// GET `path` from each endpoint in order, retrying transient failures with
+/// jittered backoff until one succeeds or the fetch budget runs out. Fails
+/// with the last endpoint's error.
+pub(crate) fn get_with_failover(
+    endpoints: &[Vec<u8>],
+    path: &str,
+) -> Result<Vec<u8>, OcwErrorKind> {
+    let budget_end = sp_io::offchain::timestamp().unix_millis().saturating_add(HTTP_FETCH_BUDGET_MS);
+    let seed = sp_io::offchain::random_seed();
+    let mut retries = 0usize;
+    let mut last_error = OcwErrorKind::Config;
+
+    for endpoint in endpoints {
+        let Ok(base_url) = core::str::from_utf8(endpoint) else {
+            continue;
+        };
+        let url = format!("{}{}", base_url, path);
+
+        for attempt in 0..HTTP_ATTEMPTS_PER_ENDPOINT {
+            if attempt > 0 {
+                let delay = backoff_delay_ms(attempt - 1, seed[retries % seed.len()]);
+                retries += 1;
+                let wake_at = sp_io::offchain::timestamp().unix_millis().saturating_add(delay);
+                sp_io::offchain::sleep_until(Timestamp::from_unix_millis(wake_at.min(budget_end)));
+            }
+            if sp_io::offchain::timestamp().unix_millis() >= budget_end {
+                return Err(OcwErrorKind::Budget);
+            }
+
+            match get_once(&url, budget_end) {
+                Ok(body) => return Ok(body),
+                Err(e) => {
+                    log::warn!(
+                        target: "prmx-oracle-v3",
+                        "AccuWeather endpoint {} attempt {} failed: {:?}",
+                        base_url,
+                        attempt + 1,
+                        e
+                    );
+                    last_error = e;
+                    if !e.is_retryable() {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    Err(last_error)
+}
+
+/// Single GET, bounded by the request timeout and the fetch budget
+fn get_once(url: &str, budget_end: u64) -> Result<Vec<u8>, OcwErrorKind> {
+    let deadline = sp_io::offchain::timestamp()
+        .add(Duration::from_millis(HTTP_TIMEOUT_MS))
+        .unix_millis()
+        .min(budget_end);
+    let deadline = Timestamp::from_unix_millis(deadline);
+
+    let pending = http::Request::get(url)
+        .deadline(deadline)
         .send()
-        .map_err(|_| "Failed to send HTTP request")?;
-    
+        .map_err(|_| OcwErrorKind::Timeout)?;
+
     let response = pending
-        .try_wait(timeout)
-        .map_err(|_| "HTTP request timeout")?
-        .map_err(|_| "HTTP request failed")?;
-    
+        .try_wait(deadline)
+        .map_err(|_| OcwErrorKind::Timeout)?
+        .map_err(|_| OcwErrorKind::Timeout)?;
+
     if response.code != 200 {
-        log::warn!(
-            target: "prmx-oracle-v3",
-            "AccuWeather API returned status {}",
-            response.code
-        );
-        return Err("AccuWeather API error");
+        return Err(OcwErrorKind::Http(response.code));
     }
-    
-    let body = response.body().collect::<Vec<u8>>();
-    
-    // Parse JSON response
-    parse_accuweather_historical_response(&body)
+
+    Ok(response.body().collect::<Vec<u8>>())
 }
 
 /// Parse AccuWeather historical/24 JSON response
-fn parse_accuweather_historical_response(json: &[u8]) -> Result<Vec<WeatherObservation>, &'static str> {
+pub(crate) fn parse_accuweather_historical_response(
+    json: &[u8],
+) -> Result<Vec<WeatherObservation>, OcwErrorKind> {
     let json_str = core::str::from_utf8(json)
-        .map_err(|_| "Invalid JSON encoding")?;
+        .map_err(|_| OcwErrorKind::Parse)?;
     
     let mut observations = Vec::new();
     let mut search_start = 0;
@@ -167,7 +345,7 @@ fn parse_accuweather_historical_response(json: &[u8]) -> Result<Vec<WeatherObser
 
 /// Find the end position of a JSON object, properly handling nested braces
 /// Returns the position after the closing brace (including the comma if present)
-fn find_object_end(json: &str) -> Option<usize> {
+pub(crate) fn find_object_end(json: &str) -> Option<usize> {
     let mut depth = 0;
     let mut in_string = false;
     let mut escape_next = false;
@@ -201,7 +379,7 @@ fn find_object_end(json: &str) -> Option<usize> {
 
 /// Helper to extract a numeric value from JSON, handling whitespace variations
 /// Searches for the key sequence and extracts the numeric value that follows
-fn extract_json_value(json: &str, keys: &[&str]) -> Option<f64> {
+pub(crate) fn extract_json_value(json: &str, keys: &[&str]) -> Option<f64> {
     let mut search_pos = 0;
     
     // Find each key in sequence
@@ -306,9 +484,9 @@ pub fn send_observations_batch(
     observations: &[WeatherObservation],
     sample_hashes: &[[u8; 32]],
     commitment_after: [u8; 32],
-) -> Result<(), &'static str> {
+) -> Result<(), OcwErrorKind> {
     let url_str = core::str::from_utf8(ingest_url)
-        .map_err(|_| "Invalid Ingest URL encoding")?;
+        .map_err(|_| OcwErrorKind::Config)?;
     
     let full_url = format!("{}/ingest/observations/batch", url_str);
     
@@ -321,24 +499,6 @@ pub fn send_observations_batch(
         commitment_after,
     );
     
-    // Get current timestamp in milliseconds
-    let timestamp = sp_io::offchain::timestamp().unix_millis();
-    let timestamp_str = format!("{}", timestamp);
-    
-    // Generate nonce
-    let nonce = commitment::generate_nonce();
-    let nonce_hex = hex_encode(&nonce);
-    
-    // Compute signature: Blake2(secret || payload || timestamp || nonce)
-    let mut sign_data = Vec::new();
-    sign_data.extend_from_slice(hmac_secret);
-    sign_data.extend_from_slice(payload.as_bytes());
-    sign_data.extend_from_slice(timestamp_str.as_bytes());
-    sign_data.extend_from_slice(nonce_hex.as_bytes());
-    
-    let signature = commitment::compute_hmac_signature(&[], &sign_data);
-    let signature_hex = hex_encode(&signature);
-    
     log::info!(
         target: "prmx-oracle-v3",
         "📤 Sending {} observations to Ingest API for policy {}",
@@ -346,35 +506,7 @@ pub fn send_observations_batch(
         policy_id
     );
     
-    // Make HTTP POST request - use slice reference for body
-    let body_bytes = payload.as_bytes();
-    let request = http::Request::post(&full_url, alloc::vec![body_bytes])
-        .add_header("Content-Type", "application/json")
-        .add_header("X-HMAC-Signature", &signature_hex)
-        .add_header("X-Timestamp", &timestamp_str)
-        .add_header("X-Nonce", &nonce_hex);
-    
-    let timeout = sp_io::offchain::timestamp()
-        .add(Duration::from_millis(HTTP_TIMEOUT_MS));
-    
-    let pending = request
-        .deadline(timeout)
-        .send()
-        .map_err(|_| "Failed to send HTTP request")?;
-    
-    let response = pending
-        .try_wait(timeout)
-        .map_err(|_| "HTTP request timeout")?
-        .map_err(|_| "HTTP request failed")?;
-    
-    if response.code != 200 && response.code != 201 {
-        log::warn!(
-            target: "prmx-oracle-v3",
-            "Ingest API returned status {}",
-            response.code
-        );
-        return Err("Ingest API error");
-    }
+    post_signed_json(&full_url, hmac_secret, &payload)?;
     
     log::info!(
         target: "prmx-oracle-v3",
@@ -392,9 +524,9 @@ pub fn send_snapshot(
     observed_until: u64,
     agg_state_encoded: &[u8],
     commitment: [u8; 32],
-) -> Result<(), &'static str> {
+) -> Result<(), OcwErrorKind> {
     let url_str = core::str::from_utf8(ingest_url)
-        .map_err(|_| "Invalid Ingest URL encoding")?;
+        .map_err(|_| OcwErrorKind::Config)?;
     
     let full_url = format!("{}/ingest/snapshots", url_str);
     
@@ -406,6 +538,56 @@ pub fn send_snapshot(
         commitment,
     );
     
+    log::info!(
+        target: "prmx-oracle-v3",
+        "📤 Sending snapshot to Ingest API for policy {}",
+        policy_id
+    );
+    
+    post_signed_json(&full_url, hmac_secret, &payload)?;
+    
+    log::info!(
+        target: "prmx-oracle-v3",
+        "✅ Successfully sent snapshot to Ingest API"
+    );
+    
+    Ok(())
+}
+
+/// Send one chunk of a policy's evidence chain to the Ingest API.
+/// The API reassembles chunks by (policy_id, chunk_index); re-sending a chunk is idempotent.
+pub fn send_evidence_chunk(
+    ingest_url: &[u8],
+    hmac_secret: &[u8],
+    policy_id: PolicyId,
+    chunk: &EvidenceChunk,
+    observations: &[WeatherObservation],
+) -> Result<(), OcwErrorKind> {
+    let url_str = core::str::from_utf8(ingest_url)
+        .map_err(|_| OcwErrorKind::Config)?;
+    
+    let full_url = format!("{}/ingest/evidence/chunks", url_str);
+    
+    let payload = build_evidence_chunk_json(policy_id, chunk, observations);
+    
+    log::info!(
+        target: "prmx-oracle-v3",
+        "📤 Sending evidence chunk {}/{} ({} samples) to Ingest API for policy {}",
+        chunk.index + 1,
+        chunk.total,
+        observations.len(),
+        policy_id
+    );
+    
+    post_signed_json(&full_url, hmac_secret, &payload)
+}
+
+/// POST a JSON payload signed with Blake2(secret || payload || timestamp || nonce)
+pub(crate) fn post_signed_json(
+    full_url: &str,
+    hmac_secret: &[u8],
+    payload: &str,
+) -> Result<(), OcwErrorKind> {
     // Get current timestamp in milliseconds
     let timestamp = sp_io::offchain::timestamp().unix_millis();
     let timestamp_str = format!("{}", timestamp);
@@ -424,15 +606,9 @@ pub fn send_snapshot(
     let signature = commitment::compute_hmac_signature(&[], &sign_data);
     let signature_hex = hex_encode(&signature);
     
-    log::info!(
-        target: "prmx-oracle-v3",
-        "📤 Sending snapshot to Ingest API for policy {}",
-        policy_id
-    );
-    
     // Make HTTP POST request - use slice reference for body
     let body_bytes = payload.as_bytes();
-    let request = http::Request::post(&full_url, alloc::vec![body_bytes])
+    let request = http::Request::post(full_url, alloc::vec![body_bytes])
         .add_header("Content-Type", "application/json")
         .add_header("X-HMAC-Signature", &signature_hex)
         .add_header("X-Timestamp", &timestamp_str)
@@ -444,27 +620,23 @@ pub fn send_snapshot(
     let pending = request
         .deadline(timeout)
         .send()
-        .map_err(|_| "Failed to send HTTP request")?;
+        .map_err(|_| OcwErrorKind::Timeout)?;
     
     let response = pending
         .try_wait(timeout)
-        .map_err(|_| "HTTP request timeout")?
-        .map_err(|_| "HTTP request failed")?;
+        .map_err(|_| OcwErrorKind::Timeout)?
+        .map_err(|_| OcwErrorKind::Timeout)?;
     
     if response.code != 200 && response.code != 201 {
         log::warn!(
             target: "prmx-oracle-v3",
-            "Ingest API returned status {}",
+            "{} returned status {}",
+            full_url,
             response.code
         );
-        return Err("Ingest API error");
+        return Err(OcwErrorKind::Http(response.code));
     }
     
-    log::info!(
-        target: "prmx-oracle-v3",
-        "✅ Successfully sent snapshot to Ingest API"
-    );
-    
     Ok(())
 }
 
@@ -483,6 +655,19 @@ fn build_observations_json(
     let location_key_str = core::str::from_utf8(location_key).unwrap_or("");
     let commitment_hex = hex_encode(&commitment_after);
     
+    let samples_json = build_samples_json(observations, sample_hashes);
+    
+    format!(
+        r#"{{"policy_id":"{}","location_key":"{}","samples":{},"commitment_after":"{}"}}"#,
+        policy_id,
+        location_key_str,
+        samples_json,
+        commitment_hex
+    )
+}
+
+/// Build the JSON array of samples shared by observation batches and evidence chunks
+fn build_samples_json(observations: &[WeatherObservation], sample_hashes: &[[u8; 32]]) -> String {
     let mut samples_json = String::from("[");
     for (i, obs) in observations.iter().enumerate() {
         if i > 0 {
@@ -500,13 +685,23 @@ fn build_observations_json(
         ));
     }
     samples_json.push_str("]");
-    
+    samples_json
+}
+
+/// Build JSON payload for an evidence chunk
+fn build_evidence_chunk_json(
+    policy_id: PolicyId,
+    chunk: &EvidenceChunk,
+    observations: &[WeatherObservation],
+) -> String {
     format!(
-        r#"{{"policy_id":"{}","location_key":"{}","samples":{},"commitment_after":"{}"}}"#,
+        r#"{{"policy_id":"{}","chunk_index":{},"chunk_count":{},"commitment_before":"{}","commitment_after":"{}","samples":{}}}"#,
         policy_id,
-        location_key_str,
-        samples_json,
-        commitment_hex
+        chunk.index,
+        chunk.total,
+        hex_encode(&chunk.commitment_before),
+        hex_encode(&chunk.commitment_after),
+        build_samples_json(observations, &chunk.sample_hashes)
     )
 }
 
@@ -534,7 +729,7 @@ fn build_snapshot_json(
 // ============================================================================
 
 /// Encode bytes to hex string
-fn hex_encode(bytes: &[u8]) -> String {
+pub(crate) fn hex_encode(bytes: &[u8]) -> String {
     const HEX_CHARS: &[u8; 16] = b"0123456789abcdef";
     let mut result = String::with_capacity(bytes.len() * 2);
     for byte in bytes {
@@ -548,6 +743,43 @@ fn hex_encode(bytes: &[u8]) -> String {
 mod tests {
     use super::*;
     
+    #[test]
+    fn test_fetch_error_is_retryable() {
+        assert!(OcwErrorKind::Timeout.is_retryable());
+        assert!(OcwErrorKind::Http(429).is_retryable());
+        assert!(OcwErrorKind::Http(503).is_retryable());
+        // Bad key or unknown location will not change on retry
+        assert!(!OcwErrorKind::Http(401).is_retryable());
+        assert!(!OcwErrorKind::Http(404).is_retryable());
+        assert!(!OcwErrorKind::Budget.is_retryable());
+    }
+
+    #[test]
+    fn test_backoff_delay_ms() {
+        assert_eq!(backoff_delay_ms(0, 0), 500);
+        assert_eq!(backoff_delay_ms(1, 0), 1_000);
+        assert_eq!(backoff_delay_ms(2, 0), 2_000);
+        // Jitter adds up to half the base delay
+        assert_eq!(backoff_delay_ms(0, u8::MAX), 750);
+        assert_eq!(backoff_delay_ms(1, 128), 1_250);
+        assert_eq!(backoff_delay_ms(u32::MAX, 0), 500 << 16);
+    }
+
+    #[test]
+    fn test_parse_location_details_response() {
+        let json = br#"{"Key":"264885","TimeZone":{"Code":"PHT","Name":"Asia/Manila",
+            "GmtOffset":8.0,"IsDaylightSaving":false},"GeoPosition":{"Latitude":14.6,
+            "Longitude":120.98,"Elevation":{"Metric":{"Value":13.0,"Unit":"m"},
+            "Imperial":{"Value":42.0,"Unit":"ft"}}}}"#;
+        assert_eq!(parse_location_details_response(json), Ok((Some(480), Some(13))));
+
+        // Elevation missing: the timezone alone is still usable
+        let json = br#"{"TimeZone":{"GmtOffset":-3.5}}"#;
+        assert_eq!(parse_location_details_response(json), Ok((Some(-210), None)));
+
+        assert_eq!(parse_location_details_response(b"{}"), Err(OcwErrorKind::Parse));
+    }
+
     #[test]
     fn test_hex_encode() {
         assert_eq!(hex_encode(&[0xde, 0xad, 0xbe, 0xef]), "deadbeef");
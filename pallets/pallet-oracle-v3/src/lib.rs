@@ -4,36 +4,109 @@
 //!
 //! ## Overview
 //!
-//! - LocationRegistry: Curated list of supported locations with AccuWeather keys
-//! - OracleMembership: Authorized accounts that can submit oracle reports
+//! - LocationRegistry: Curated list of supported locations with AccuWeather keys.
+//!   Active locations are indexed by geohash cell, so a new location close to an
+//!   existing one is rejected and `find_nearest_location` can search by proximity
+//! - OracleMembership: Authorized accounts that can submit oracle reports.
+//!   `rotate_oracle_member` swaps a member's key with an overlap window in which
+//!   both keys are valid; the old key retires in `on_initialize` and every rotation
+//!   is kept in `RotationHistory` for audit
 //! - OracleStates: Per-policy aggregation state and commitment tracking
 //! - Snapshots: Periodic recovery checkpoints
-//! - Final Reports: Trigger or maturity settlement reports
-//! - Offchain Worker: Polls policies, fetches AccuWeather data, sends to Ingest API
+//! - Snapshot gaps: `on_initialize` sweeps active policies and emits `SnapshotOverdue`
+//!   when the last snapshot is older than `SnapshotOverdueBlocks` (e.g. the OCW node
+//!   was down), and `SnapshotCaughtUp` once snapshots resume. With an
+//!   `OverdueGraceExtension` configured, maturity reports are held back for that many
+//!   blocks so the OCW can backfill the missed window first
+//! - Final Reports: Trigger or maturity settlement reports, settled once a
+//!   quorum of oracle members attest the same report hash. The report a policy
+//!   settled on is kept in `FinalReports` for audit
+//! - Offchain Worker: Polls policies, fetches AccuWeather data, sends to Ingest API.
+//!   Each location is fetched once per run for all of its policies, within a
+//!   per-run call budget shared round-robin across policies (see `fetch_quota`).
+//!   Calls are also counted per day against a daily budget; the last of it is kept
+//!   for policies near trigger or maturity, and an exhausted budget is reported
+//!   once a day as `ApiBudgetExhausted`
+//!   Its unsigned transactions carry a payload signed by an oracle member's OCW key
+//!   (`orv3`) and a per-signer nonce, checked in `validate_unsigned`
+//! - Policy metadata: Location, event spec and coverage window per policy, stored as
+//!   a versioned `PolicyMetaV3` (see `migrations` for the upgrade from the tuple layout)
+//! - Evidence: Finalized observation chains are exported to the Ingest API and can
+//!   be replayed against the on-chain commitment with `verify_commitment_chain`.
+//!   The chain is built by the `prmx-commitment` crate, which also verifies it
+//!   off-chain. `dispute_final_report` strikes a proposed final report whose own
+//!   committed samples do not aggregate to the state it claims
+//! - Ingest outbox: Observation batches the Ingest API did not accept are queued
+//!   offchain and re-sent on later runs; a high backlog is reported as `IngestBacklogHigh`
+//! - Area policies: A policy can cover a corridor or region of registry locations
+//!   (`AreaSpecV3`). The OCW aggregates each location separately and combines them by
+//!   average or max (`aggregator::combine_area_states`) into the policy's `agg_state`
+//! - Settlement webhooks: After submitting a final report the OCW POSTs a signed
+//!   notice to the webhook URLs configured in offchain storage (see `notifier`)
+//! - Cyclone policies: `CycloneWithinGte` policies follow storm-track advisories near
+//!   their location instead of AccuWeather observations (see `storm`)
+//! - Dry run: With `std`, the OCW's parse, commitment, aggregation and decision steps
+//!   are exposed as plain functions (see `dry_run`), so a harness can run observation
+//!   cycles against live AccuWeather responses without a node
+//! - Outlier filtering: Governance-set rules (`set_outlier_rules`) reject implausible
+//!   hourly precipitation samples before they are aggregated; each policy keeps the
+//!   rules in force when it was created. The OCW reports rejected samples, which are
+//!   emitted as `ObservationRejected` (see `outlier`)
+//! - Location metadata: Locations carry an optional timezone offset, elevation and
+//!   preferred station, set by governance or resolved once by the OCW from
+//!   AccuWeather's location details (see `location_meta`)
+//! - Settled policy purge: `SettledRetentionBlocks` after its final report, a policy's
+//!   oracle storage can be purged by anyone (`purge_settled`) and is purged by
+//!   `on_idle`, leaving a settled record hash (see `purge`)
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 extern crate alloc;
 
 pub use pallet::*;
+pub use weights::WeightInfo;
 
 pub mod ocw;
 pub mod expiry;
 pub mod fetcher;
 pub mod aggregator;
 pub mod commitment;
+#[cfg(feature = "std")]
+pub mod dry_run;
+pub mod evidence;
+pub mod fetch_quota;
+pub mod geohash;
 pub mod http_client;
+pub mod location_meta;
+pub mod migrations;
+pub mod notifier;
+pub mod outbox;
+pub mod outlier;
+pub mod purge;
+pub mod storm;
+pub mod weights;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 
 use alloc::vec::Vec;
 use codec::Encode;
 use frame_support::pallet_prelude::*;
 use frame_system::pallet_prelude::*;
 use prmx_primitives::{
-    AggStateV3, EventSpecV3, EventTypeV3, OracleReportKindV3, PolicyId, PolicyOracleStateV3,
-    PolicyStatusV3, V3_MIN_SNAPSHOT_BLOCKS,
+    AggStateV3, AreaSpecV3, CycloneThresholdV3, EventSpecV3, EventTypeV3, OcwErrorKind,
+    OracleReportKindV3, PolicyId, PolicyOracleStateV3, PolicyStatusV3, V3_MAX_CYCLONE_RADIUS_KM,
+    V3_MIN_SNAPSHOT_BLOCKS,
 };
 use sp_core::H256;
-use sp_runtime::traits::UniqueSaturatedInto;
+use sp_runtime::{
+    traits::{Saturating, UniqueSaturatedInto, Zero},
+    transaction_validity::ValidTransactionBuilder,
+};
+
+use crate::fetcher::WeatherObservation;
+use crate::geohash::Geohash;
+use crate::outlier::{OutlierRulesV3, RejectedObservationV3, MAX_REJECTED_PER_REPORT};
 
 // ============================================================================
 // Type Aliases
@@ -42,6 +115,74 @@ use sp_runtime::traits::UniqueSaturatedInto;
 /// Location identifier
 pub type LocationId = u64;
 
+/// Maximum number of active locations indexed in one geohash cell
+pub const MAX_LOCATIONS_PER_GEOHASH: u32 = 32;
+
+/// Maximum number of final report attestations tracked per policy
+pub const MAX_FINAL_REPORT_ATTESTATIONS: u32 = 32;
+
+/// Maximum number of samples replayed by `verify_commitment_chain`
+/// (92 days of hourly observations)
+pub const MAX_EVIDENCE_SAMPLES: u32 = 2_208;
+
+/// Longest overlap window of a member key rotation (~7 days at 6s blocks)
+pub const MAX_ROTATION_OVERLAP_BLOCKS: u32 = 100_800;
+
+/// Maximum number of member keys scheduled to retire in the same block
+pub const MAX_RETIREMENTS_PER_BLOCK: u32 = 8;
+
+/// Blocks between OCW runs (after the first 5 startup blocks)
+pub const OCW_RUN_INTERVAL_BLOCKS: u32 = 10;
+
+/// Blocks an OCW unsigned transaction stays valid in the pool. Shorter than the
+/// OCW run interval, so anything not yet included has expired before the next run
+/// resubmits with fresh nonces.
+pub const OCW_UNSIGNED_LONGEVITY: u64 = 5;
+
+/// Blocks between snapshot gap sweeps (~10 minutes at 6s blocks)
+pub const SNAPSHOT_GAP_CHECK_INTERVAL_BLOCKS: u32 = 100;
+
+/// Priority of OCW final reports. Snapshots get half and request expiries a quarter,
+/// so settlement is never starved by routine traffic.
+pub const OCW_UNSIGNED_PRIORITY: TransactionPriority = TransactionPriority::MAX / 2;
+
+// ============================================================================
+// OCW Signing Crypto Types
+// ============================================================================
+
+/// Key type for the V3 OCW signing key
+pub const KEY_TYPE: sp_runtime::KeyTypeId = sp_runtime::KeyTypeId(*b"orv3");
+
+/// Crypto module for V3 OCW payload signatures
+pub mod crypto {
+    use super::KEY_TYPE;
+    use sp_core::sr25519::Signature as Sr25519Signature;
+    use sp_runtime::{
+        app_crypto::{app_crypto, sr25519},
+        traits::Verify,
+        MultiSignature, MultiSigner,
+    };
+
+    app_crypto!(sr25519, KEY_TYPE);
+
+    /// V3 OCW authority ID (public key)
+    pub struct OracleV3AuthId;
+
+    impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for OracleV3AuthId {
+        type RuntimeAppPublic = Public;
+        type GenericPublic = sp_core::sr25519::Public;
+        type GenericSignature = sp_core::sr25519::Signature;
+    }
+
+    impl frame_system::offchain::AppCrypto<<Sr25519Signature as Verify>::Signer, Sr25519Signature>
+        for OracleV3AuthId
+    {
+        type RuntimeAppPublic = Public;
+        type GenericPublic = sp_core::sr25519::Public;
+        type GenericSignature = sp_core::sr25519::Signature;
+    }
+}
+
 // ============================================================================
 // Traits
 // ============================================================================
@@ -82,6 +223,13 @@ pub trait RequestExpiryApiV3 {
     /// Execute request expiry - returns unfilled premium to requester
     /// Called by oracle-v3's unsigned transaction handler
     fn expire_request(request_id: PolicyId) -> DispatchResult;
+
+    /// Get all open RFQs whose acceptance window has closed
+    fn get_expired_rfqs(current_time: u64) -> Vec<PolicyId>;
+
+    /// Execute RFQ expiry - refunds all offers' collateral
+    /// Called by oracle-v3's unsigned transaction handler
+    fn expire_rfq(rfq_id: PolicyId) -> DispatchResult;
 }
 
 /// No-op implementation for testing
@@ -97,16 +245,29 @@ impl RequestExpiryApiV3 for () {
     fn expire_request(_request_id: PolicyId) -> DispatchResult {
         Ok(())
     }
+
+    fn get_expired_rfqs(_current_time: u64) -> Vec<PolicyId> {
+        Vec::new()
+    }
+
+    fn expire_rfq(_rfq_id: PolicyId) -> DispatchResult {
+        Ok(())
+    }
 }
 
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
+    use codec::DecodeWithMemTracking;
 
     // =========================================================================
     //                                  Types
     // =========================================================================
 
+    /// In-code storage version. Bump it and add a migration to `migrations.rs`
+    /// whenever a stored layout changes.
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
     /// Location information stored in the registry
     #[derive(Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
     #[scale_info(skip_type_params(T))]
@@ -123,6 +284,149 @@ pub mod pallet {
         pub name: BoundedVec<u8, ConstU32<64>>,
         /// Whether this location accepts new requests
         pub active: bool,
+        /// UTC offset of local time in minutes, if known
+        pub timezone_offset_mins: Option<i16>,
+        /// Elevation in meters, if known
+        pub elevation_m: Option<i16>,
+        /// AccuWeather key of a preferred observation station, fetched instead of
+        /// `accuweather_key`
+        pub station_id: Option<BoundedVec<u8, T::MaxLocationKeyLength>>,
+    }
+
+    impl<T: Config> LocationInfo<T> {
+        /// AccuWeather key the OCW fetches observations for
+        pub fn fetch_key(&self) -> &[u8] {
+            self.station_id.as_ref().unwrap_or(&self.accuweather_key).as_slice()
+        }
+
+        /// Start of the local day containing `timestamp`, or of the UTC day when the
+        /// timezone is unknown
+        pub fn local_day_start(&self, timestamp: u64) -> u64 {
+            location_meta::local_day_start(timestamp, self.timezone_offset_mins.unwrap_or(0))
+        }
+    }
+
+    /// Oracle metadata for a policy, read by the OCW and commitment checks.
+    /// Extend it (e.g. with a grace period, provider set or extra peril specs)
+    /// together with a storage version bump and a migration.
+    #[derive(Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    pub struct PolicyMetaV3 {
+        /// Location the policy is observed at
+        pub location_id: LocationId,
+        /// Insured event
+        pub event_spec: EventSpecV3,
+        /// Coverage window start (unix seconds)
+        pub coverage_start: u64,
+        /// Coverage window end (unix seconds)
+        pub coverage_end: u64,
+        /// Locations and aggregation of an area policy; `None` for a single location
+        pub area: Option<AreaSpecV3>,
+    }
+
+    /// Payload of an OCW unsigned transaction, signed by an oracle member's OCW key
+    #[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct OcwPayloadV3<Public, Data> {
+        /// Call arguments covered by the signature
+        pub data: Data,
+        /// Signer's next OCW nonce (see `OcwNonces`)
+        pub nonce: u64,
+        /// OCW key that signed the payload; its account must be an oracle member
+        pub public: Public,
+    }
+
+    impl<T: frame_system::offchain::SigningTypes, Data: Encode>
+        frame_system::offchain::SignedPayload<T> for OcwPayloadV3<T::Public, Data>
+    {
+        fn public(&self) -> T::Public {
+            self.public.clone()
+        }
+    }
+
+    /// Snapshot submitted by the OCW
+    #[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct SnapshotDataV3 {
+        pub policy_id: PolicyId,
+        pub observed_until: u64,
+        pub agg_state: AggStateV3,
+        pub commitment: [u8; 32],
+    }
+
+    /// Final report submitted by the OCW
+    #[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct FinalReportDataV3 {
+        pub policy_id: PolicyId,
+        pub kind: OracleReportKindV3,
+        pub observed_until: u64,
+        pub agg_state: AggStateV3,
+        pub commitment: [u8; 32],
+    }
+
+    /// Ingest API backlog reported by the OCW
+    #[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct IngestBacklogDataV3 {
+        pub policy_id: PolicyId,
+        /// Observation batches queued in the OCW outbox
+        pub pending_batches: u32,
+        /// Observations across the queued batches
+        pub pending_samples: u32,
+        /// Unix timestamp the oldest queued batch was queued at
+        pub oldest_queued_at: u64,
+    }
+
+    /// Exhausted AccuWeather daily budget reported by the OCW
+    #[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct ApiBudgetDataV3 {
+        /// Days since the unix epoch (UTC)
+        pub day: u64,
+        /// AccuWeather calls made on `day`
+        pub calls: u32,
+        /// The OCW's daily call budget
+        pub budget: u32,
+    }
+
+    /// Samples of one policy location rejected by its outlier rules, reported by the OCW
+    #[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct RejectedObservationsDataV3 {
+        pub policy_id: PolicyId,
+        pub location_id: LocationId,
+        pub rejected: BoundedVec<RejectedObservationV3, ConstU32<MAX_REJECTED_PER_REPORT>>,
+    }
+
+    /// Location metadata resolved by the OCW from AccuWeather's location details
+    #[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct LocationMetadataDataV3 {
+        pub location_id: LocationId,
+        pub timezone_offset_mins: Option<i16>,
+        pub elevation_m: Option<i16>,
+    }
+
+    /// Final report proposed by an oracle member, awaiting quorum attestation
+    #[derive(Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    pub struct FinalReportProposal {
+        /// Trigger or maturity
+        pub kind: OracleReportKindV3,
+        /// Observation window end covered by the report
+        pub observed_until: u64,
+        /// Final aggregation state
+        pub agg_state: AggStateV3,
+        /// Final commitment
+        pub commitment: [u8; 32],
+    }
+
+    /// Oracle member key rotation, kept for audit
+    #[derive(Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct MemberRotation<T: Config> {
+        /// Key being retired
+        pub old: T::AccountId,
+        /// Key replacing it
+        pub new: T::AccountId,
+        /// Block the rotation started (both keys valid from here)
+        pub started_at: BlockNumberFor<T>,
+        /// Block the old key is scheduled to retire
+        pub retires_at: BlockNumberFor<T>,
+        /// Block the old key actually lost membership, if it has
+        pub retired_at: Option<BlockNumberFor<T>>,
     }
 
     // =========================================================================
@@ -131,6 +435,7 @@ pub mod pallet {
 
     #[pallet::config]
     pub trait Config: frame_system::Config 
+        + frame_system::offchain::SigningTypes
         + frame_system::offchain::CreateTransactionBase<Call<Self>>
         + frame_system::offchain::CreateBare<Call<Self>>
     {
@@ -153,105 +458,236 @@ pub mod pallet {
         #[pallet::constant]
         type MaxLocationKeyLength: Get<u32>;
 
+        /// Number of matching member attestations required to settle a final report
+        #[pallet::constant]
+        type FinalReportQuorum: Get<u32>;
+
+        /// New locations within this distance (metres) of an active location are
+        /// rejected. Only neighbouring geohash cells are searched, so keep it below
+        /// the cell width (about 4.9 km × cos(latitude)).
+        #[pallet::constant]
+        type LocationDedupRadius: Get<u32>;
+
+        /// Active policies whose last snapshot is older than this many blocks are
+        /// reported as overdue. Keep it well above the 6h snapshot interval.
+        #[pallet::constant]
+        type SnapshotOverdueBlocks: Get<BlockNumberFor<Self>>;
+
+        /// Blocks a maturity report is held back after a policy's snapshots go
+        /// overdue, so the OCW can backfill the gap first (zero disables it)
+        #[pallet::constant]
+        type OverdueGraceExtension: Get<BlockNumberFor<Self>>;
+
+        /// Maximum oracle states checked per snapshot gap sweep
+        #[pallet::constant]
+        type MaxSnapshotGapChecks: Get<u32>;
+
+        /// Blocks a policy's oracle storage is kept after its final report before it
+        /// can be purged. Keep it well beyond any settlement review period.
+        #[pallet::constant]
+        type SettledRetentionBlocks: Get<BlockNumberFor<Self>>;
+
+        /// OCW signing key for unsigned transactions; its account must be an oracle member
+        type AuthorityId: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>;
+
         /// Weight info
         type WeightInfo: WeightInfo;
+
+        /// Sets up policies and underwrite requests for benchmarks
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper;
+    }
+
+    /// Benchmark setup that needs the market and policy pallets
+    #[cfg(feature = "runtime-benchmarks")]
+    pub trait BenchmarkHelper {
+        /// Create an active policy on `location_id` with an initialized oracle state
+        /// and return its id
+        fn active_policy(location_id: LocationId, event_spec: EventSpecV3) -> PolicyId;
+
+        /// Create an open underwrite request on `location_id` with unfilled premium
+        /// in escrow and return its id
+        fn expirable_request(location_id: LocationId) -> PolicyId;
+
+        /// Create an RFQ on `location_id` holding offers, past its acceptance window,
+        /// and return its id
+        fn expirable_rfq(location_id: LocationId) -> PolicyId;
     }
 
-    /// Validate unsigned transactions from OCW
+    /// Validate unsigned transactions from OCW.
+    /// Each carries a payload signed by an oracle member's OCW key and that member's
+    /// next nonce. Nonces are consumed in `pre_dispatch`, so a payload can never be
+    /// replayed and spoofed submissions are rejected before they reach a block.
     #[pallet::validate_unsigned]
     impl<T: Config> ValidateUnsigned for Pallet<T> {
         type Call = Call<T>;
 
         fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
             match call {
-                Call::submit_snapshot_unsigned {
-                    policy_id,
-                    observed_until,
-                    ..
-                } => {
+                Call::submit_snapshot_unsigned { payload, signature } => {
+                    let data = &payload.data;
+
                     // Basic validation - ensure policy exists and is active
-                    let state = OracleStates::<T>::get(policy_id)
+                    let state = OracleStates::<T>::get(data.policy_id)
                         .ok_or(InvalidTransaction::Custom(1))?;
                     
                     if state.status != PolicyStatusV3::Active {
                         return Err(InvalidTransaction::Custom(2).into());
                     }
+                    if data.observed_until <= state.observed_until {
+                        return InvalidTransaction::Stale.into();
+                    }
 
-                    ValidTransaction::with_tag_prefix("OracleV3Snapshot")
-                        .priority(100)
-                        .and_provides((policy_id, observed_until))
-                        .longevity(5)
-                        .propagate(true)
+                    Self::validate_ocw_payload(payload, signature)?
+                        .priority(OCW_UNSIGNED_PRIORITY / 2)
+                        .and_provides((b"snapshot", data.policy_id, data.observed_until))
                         .build()
                 }
-                Call::submit_final_report_unsigned {
-                    policy_id,
-                    ..
-                } => {
+                Call::submit_final_report_unsigned { payload, signature } => {
+                    let data = &payload.data;
+
                     // Basic validation - ensure policy exists and is active
-                    let state = OracleStates::<T>::get(policy_id)
+                    let state = OracleStates::<T>::get(data.policy_id)
                         .ok_or(InvalidTransaction::Custom(1))?;
                     
                     if state.status != PolicyStatusV3::Active {
                         return Err(InvalidTransaction::Custom(3).into());
                     }
+                    if data.observed_until < state.observed_until {
+                        return InvalidTransaction::Stale.into();
+                    }
+                    if Self::ensure_settlement_grace_elapsed(data.policy_id, data.kind).is_err() {
+                        return Err(InvalidTransaction::Custom(4).into());
+                    }
 
-                    ValidTransaction::with_tag_prefix("OracleV3FinalReport")
-                        .priority(200) // Higher priority for final reports
-                        .and_provides((policy_id, "final"))
-                        .longevity(5)
-                        .propagate(true)
+                    Self::validate_ocw_payload(payload, signature)?
+                        .priority(OCW_UNSIGNED_PRIORITY)
+                        .and_provides((b"final", data.policy_id))
                         .build()
                 }
-                Call::expire_request_unsigned {
-                    request_id,
-                } => {
+                Call::expire_request_unsigned { payload, signature } => {
                     // Note: We cannot use sp_io::offchain::timestamp() here because
                     // validate_unsigned runs in the transaction pool context, not OCW.
                     // The actual expiry check happens in the extrinsic itself.
-                    // Here we only do basic validation to allow the transaction through.
                     
-                    ValidTransaction::with_tag_prefix("OracleV3RequestExpiry")
-                        .priority(50) // Lower priority than final reports
-                        .and_provides((request_id, "expiry"))
-                        .longevity(10)
-                        .propagate(true)
+                    Self::validate_ocw_payload(payload, signature)?
+                        .priority(OCW_UNSIGNED_PRIORITY / 4)
+                        .and_provides((b"expiry", payload.data))
+                        .build()
+                }
+                Call::expire_rfq_unsigned { payload, signature } => {
+                    // As for requests, the acceptance window is checked in the extrinsic
+                    Self::validate_ocw_payload(payload, signature)?
+                        .priority(OCW_UNSIGNED_PRIORITY / 4)
+                        .and_provides((b"rfq-expiry", payload.data))
+                        .build()
+                }
+                Call::report_ingest_backlog_unsigned { payload, signature } => {
+                    let data = &payload.data;
+
+                    if !OracleStates::<T>::contains_key(data.policy_id) {
+                        return Err(InvalidTransaction::Custom(1).into());
+                    }
+
+                    Self::validate_ocw_payload(payload, signature)?
+                        .priority(OCW_UNSIGNED_PRIORITY / 8)
+                        .and_provides((b"backlog", data.policy_id, data.oldest_queued_at))
+                        .build()
+                }
+                Call::report_api_budget_exhausted_unsigned { payload, signature } => {
+                    let data = &payload.data;
+
+                    // One warning per reporter per day
+                    let reporter =
+                        sp_runtime::traits::IdentifyAccount::into_account(payload.public.clone());
+                    if ApiBudgetWarnings::<T>::get(&reporter).is_some_and(|day| day >= data.day) {
+                        return InvalidTransaction::Stale.into();
+                    }
+
+                    Self::validate_ocw_payload(payload, signature)?
+                        .priority(OCW_UNSIGNED_PRIORITY / 8)
+                        .and_provides((b"api-budget", reporter, data.day))
+                        .build()
+                }
+                Call::report_rejected_observations_unsigned { payload, signature } => {
+                    let data = &payload.data;
+
+                    if !OracleStates::<T>::contains_key(data.policy_id) {
+                        return Err(InvalidTransaction::Custom(1).into());
+                    }
+                    let first = data.rejected.first().ok_or(InvalidTransaction::Custom(5))?;
+
+                    Self::validate_ocw_payload(payload, signature)?
+                        .priority(OCW_UNSIGNED_PRIORITY / 8)
+                        .and_provides((
+                            b"outliers",
+                            data.policy_id,
+                            data.location_id,
+                            first.epoch_time,
+                        ))
+                        .build()
+                }
+                Call::submit_location_metadata_unsigned { payload, signature } => {
+                    let data = &payload.data;
+
+                    let location = LocationRegistry::<T>::get(data.location_id)
+                        .ok_or(InvalidTransaction::Custom(1))?;
+                    if data.timezone_offset_mins.is_none() && data.elevation_m.is_none() {
+                        return Err(InvalidTransaction::Custom(6).into());
+                    }
+                    // Resolution only fills fields that are still unset
+                    if location.timezone_offset_mins.is_some() && location.elevation_m.is_some() {
+                        return InvalidTransaction::Stale.into();
+                    }
+
+                    Self::validate_ocw_payload(payload, signature)?
+                        .priority(OCW_UNSIGNED_PRIORITY / 8)
+                        .and_provides((b"location-meta", data.location_id))
                         .build()
                 }
                 _ => InvalidTransaction::Call.into(),
             }
         }
-    }
 
-    /// Weight info trait
-    pub trait WeightInfo {
-        fn add_location() -> Weight;
-        fn remove_location() -> Weight;
-        fn submit_snapshot() -> Weight;
-        fn submit_final_report() -> Weight;
-        fn add_oracle_member() -> Weight;
-        fn remove_oracle_member() -> Weight;
-    }
+        fn pre_dispatch(call: &Self::Call) -> Result<(), TransactionValidityError> {
+            Self::validate_unsigned(TransactionSource::InBlock, call)?;
 
-    /// Default weights
-    impl WeightInfo for () {
-        fn add_location() -> Weight {
-            Weight::from_parts(10_000, 0)
-        }
-        fn remove_location() -> Weight {
-            Weight::from_parts(10_000, 0)
-        }
-        fn submit_snapshot() -> Weight {
-            Weight::from_parts(20_000, 0)
-        }
-        fn submit_final_report() -> Weight {
-            Weight::from_parts(50_000, 0)
-        }
-        fn add_oracle_member() -> Weight {
-            Weight::from_parts(10_000, 0)
-        }
-        fn remove_oracle_member() -> Weight {
-            Weight::from_parts(10_000, 0)
+            let (public, nonce) = match call {
+                Call::submit_snapshot_unsigned { payload, .. } => {
+                    (payload.public.clone(), payload.nonce)
+                }
+                Call::submit_final_report_unsigned { payload, .. } => {
+                    (payload.public.clone(), payload.nonce)
+                }
+                Call::expire_request_unsigned { payload, .. } => {
+                    (payload.public.clone(), payload.nonce)
+                }
+                Call::report_ingest_backlog_unsigned { payload, .. } => {
+                    (payload.public.clone(), payload.nonce)
+                }
+                Call::expire_rfq_unsigned { payload, .. } => {
+                    (payload.public.clone(), payload.nonce)
+                }
+                Call::report_api_budget_exhausted_unsigned { payload, .. } => {
+                    (payload.public.clone(), payload.nonce)
+                }
+                Call::report_rejected_observations_unsigned { payload, .. } => {
+                    (payload.public.clone(), payload.nonce)
+                }
+                Call::submit_location_metadata_unsigned { payload, .. } => {
+                    (payload.public.clone(), payload.nonce)
+                }
+                _ => return Err(InvalidTransaction::Call.into()),
+            };
+
+            // Unlike validation, inclusion needs the exact next nonce
+            let signer = sp_runtime::traits::IdentifyAccount::into_account(public);
+            if nonce != OcwNonces::<T>::get(&signer) {
+                return Err(InvalidTransaction::Future.into());
+            }
+            OcwNonces::<T>::insert(&signer, nonce.saturating_add(1));
+
+            Ok(())
         }
     }
 
@@ -260,6 +696,7 @@ pub mod pallet {
     // =========================================================================
 
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     /// Curated location registry
@@ -273,6 +710,17 @@ pub mod pallet {
     #[pallet::getter(fn next_location_id)]
     pub type NextLocationId<T: Config> = StorageValue<_, LocationId, ValueQuery>;
 
+    /// Active locations by geohash cell, with their coordinates
+    #[pallet::storage]
+    #[pallet::getter(fn locations_by_geohash)]
+    pub type LocationsByGeohash<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        Geohash,
+        BoundedVec<(LocationId, i32, i32), ConstU32<MAX_LOCATIONS_PER_GEOHASH>>,
+        ValueQuery,
+    >;
+
     /// Oracle membership (authorized accounts)
     #[pallet::storage]
     #[pallet::getter(fn oracle_membership)]
@@ -291,11 +739,134 @@ pub mod pallet {
     pub type SnapshotRateLimit<T: Config> =
         StorageMap<_, Blake2_128Concat, PolicyId, BlockNumberFor<T>, ValueQuery>;
 
-    /// Policy metadata for OCW lookup (policy_id -> (location_id, event_spec, coverage_start, coverage_end))
+    /// Policy metadata for OCW lookup
     #[pallet::storage]
     #[pallet::getter(fn policy_metadata)]
     pub type PolicyMetadata<T: Config> =
-        StorageMap<_, Blake2_128Concat, PolicyId, (LocationId, EventSpecV3, u64, u64), OptionQuery>;
+        StorageMap<_, Blake2_128Concat, PolicyId, PolicyMetaV3, OptionQuery>;
+
+    /// Final reports proposed by oracle members (policy_id, report_hash -> report)
+    #[pallet::storage]
+    #[pallet::getter(fn proposed_final_reports)]
+    pub type ProposedFinalReports<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        PolicyId,
+        Blake2_128Concat,
+        H256,
+        FinalReportProposal,
+        OptionQuery,
+    >;
+
+    /// Final report each policy settled on, kept for audit
+    #[pallet::storage]
+    #[pallet::getter(fn final_reports)]
+    pub type FinalReports<T: Config> =
+        StorageMap<_, Blake2_128Concat, PolicyId, FinalReportProposal, OptionQuery>;
+
+    /// Next nonce expected in OCW unsigned payloads signed by each oracle member
+    #[pallet::storage]
+    #[pallet::getter(fn ocw_nonces)]
+    pub type OcwNonces<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
+    /// Pending final report attestations (policy_id -> [(member, report_hash)])
+    #[pallet::storage]
+    #[pallet::getter(fn pending_attestations)]
+    pub type PendingAttestations<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        PolicyId,
+        BoundedVec<(T::AccountId, H256), ConstU32<MAX_FINAL_REPORT_ATTESTATIONS>>,
+        ValueQuery,
+    >;
+
+    /// Member keys in their rotation overlap window (old key -> rotation id)
+    #[pallet::storage]
+    #[pallet::getter(fn retiring_members)]
+    pub type RetiringMembers<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Member keys due to retire at a block
+    #[pallet::storage]
+    #[pallet::getter(fn retirement_schedule)]
+    pub type RetirementSchedule<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        BlockNumberFor<T>,
+        BoundedVec<T::AccountId, ConstU32<MAX_RETIREMENTS_PER_BLOCK>>,
+        ValueQuery,
+    >;
+
+    /// Member key rotations (rotation id -> rotation)
+    #[pallet::storage]
+    #[pallet::getter(fn rotation_history)]
+    pub type RotationHistory<T: Config> =
+        StorageMap<_, Blake2_128Concat, u32, MemberRotation<T>, OptionQuery>;
+
+    /// Next rotation ID
+    #[pallet::storage]
+    #[pallet::getter(fn next_rotation_id)]
+    pub type NextRotationId<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Policies whose snapshots are overdue (policy_id -> block the gap was detected)
+    #[pallet::storage]
+    #[pallet::getter(fn overdue_snapshots)]
+    pub type OverdueSnapshots<T: Config> =
+        StorageMap<_, Blake2_128Concat, PolicyId, BlockNumberFor<T>, OptionQuery>;
+
+    /// Maturity reports for a policy are rejected before this block
+    #[pallet::storage]
+    #[pallet::getter(fn settlement_grace_until)]
+    pub type SettlementGraceUntil<T: Config> =
+        StorageMap<_, Blake2_128Concat, PolicyId, BlockNumberFor<T>, OptionQuery>;
+
+    /// Last oracle state checked by the snapshot gap sweep (None: start over)
+    #[pallet::storage]
+    pub type SnapshotGapCursor<T: Config> = StorageValue<_, PolicyId, OptionQuery>;
+
+    /// Last day (since the unix epoch) each oracle member reported its AccuWeather
+    /// daily budget exhausted
+    #[pallet::storage]
+    #[pallet::getter(fn api_budget_warnings)]
+    pub type ApiBudgetWarnings<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u64, OptionQuery>;
+
+    /// Outlier rules pinned to policies created from now on (None: no filtering)
+    #[pallet::storage]
+    #[pallet::getter(fn outlier_rules)]
+    pub type OutlierRules<T: Config> = StorageValue<_, OutlierRulesV3, OptionQuery>;
+
+    /// Outlier rules a policy's observations are filtered with, pinned when its
+    /// oracle state was initialized
+    #[pallet::storage]
+    #[pallet::getter(fn policy_outlier_rules)]
+    pub type PolicyOutlierRules<T: Config> =
+        StorageMap<_, Blake2_128Concat, PolicyId, OutlierRulesV3, OptionQuery>;
+
+    /// Block of each unpurged policy's final report
+    #[pallet::storage]
+    #[pallet::getter(fn settled_at)]
+    pub type SettledAt<T: Config> =
+        StorageMap<_, Blake2_128Concat, PolicyId, BlockNumberFor<T>, OptionQuery>;
+
+    /// Settled policies in final report order, purged from `PurgeCursor` onwards
+    #[pallet::storage]
+    pub type PurgeQueue<T: Config> = StorageMap<_, Blake2_128Concat, u64, PolicyId, OptionQuery>;
+
+    /// Next `PurgeQueue` index
+    #[pallet::storage]
+    pub type NextPurgeIndex<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// Next `PurgeQueue` index `on_idle` considers
+    #[pallet::storage]
+    pub type PurgeCursor<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// Hash of each purged policy's oracle entries (see `purge::settled_record_hash`)
+    #[pallet::storage]
+    #[pallet::getter(fn settled_record_hash)]
+    pub type SettledRecordHashes<T: Config> =
+        StorageMap<_, Blake2_128Concat, PolicyId, H256, OptionQuery>;
 
     // =========================================================================
     //                                  Events
@@ -315,6 +886,18 @@ pub mod pallet {
         OracleMemberAdded { account: T::AccountId },
         /// Oracle member removed
         OracleMemberRemoved { account: T::AccountId },
+        /// Member key rotation started; both keys are valid until `retires_at`
+        OracleMemberRotationStarted {
+            rotation_id: u32,
+            old: T::AccountId,
+            new: T::AccountId,
+            retires_at: BlockNumberFor<T>,
+        },
+        /// Old key of a rotation reached the end of its overlap window and was removed
+        OracleMemberRetired {
+            rotation_id: u32,
+            account: T::AccountId,
+        },
         /// Snapshot submitted for a policy
         SnapshotSubmitted {
             policy_id: PolicyId,
@@ -339,6 +922,102 @@ pub mod pallet {
         RequestExpiredByOcw {
             request_id: PolicyId,
         },
+        /// RFQ expired by OCW
+        RfqExpiredByOcw {
+            rfq_id: PolicyId,
+        },
+        /// Final report proposed by an oracle member (awaiting quorum)
+        FinalReportProposed {
+            policy_id: PolicyId,
+            report_hash: H256,
+            proposer: T::AccountId,
+            kind: OracleReportKindV3,
+            observed_until: u64,
+        },
+        /// Final report attested by an oracle member
+        FinalReportAttested {
+            policy_id: PolicyId,
+            report_hash: H256,
+            attester: T::AccountId,
+            attestations: u32,
+            quorum: u32,
+        },
+        /// Observation chain replayed against the policy's on-chain commitment
+        CommitmentChainVerified {
+            policy_id: PolicyId,
+            who: T::AccountId,
+            samples: u32,
+            commitment: H256,
+            valid: bool,
+        },
+        /// Proposed final report struck because its committed samples aggregate to a
+        /// different state than the one it claims
+        FinalReportDisputed {
+            policy_id: PolicyId,
+            report_hash: H256,
+            disputer: T::AccountId,
+            samples: u32,
+            claimed: AggStateV3,
+            recomputed: AggStateV3,
+        },
+        /// An OCW could not deliver observations to the Ingest API and has a high
+        /// backlog queued for the policy
+        IngestBacklogHigh {
+            policy_id: PolicyId,
+            reporter: T::AccountId,
+            pending_batches: u32,
+            pending_samples: u32,
+            oldest_queued_at: u64,
+        },
+        /// No snapshot for an active policy in `SnapshotOverdueBlocks`; maturity
+        /// reports wait until `grace_until` if an extension is configured
+        SnapshotOverdue {
+            policy_id: PolicyId,
+            last_snapshot_block: BlockNumberFor<T>,
+            blocks_since: BlockNumberFor<T>,
+            grace_until: Option<BlockNumberFor<T>>,
+        },
+        /// An OCW spent its AccuWeather daily call budget; until the next day it only
+        /// serves cached locations. Reported once per reporter per day.
+        ApiBudgetExhausted {
+            reporter: T::AccountId,
+            day: u64,
+            calls: u32,
+            budget: u32,
+        },
+        /// Snapshots resumed for a policy previously reported as overdue
+        SnapshotCaughtUp {
+            policy_id: PolicyId,
+            overdue_since: BlockNumberFor<T>,
+            caught_up_at: BlockNumberFor<T>,
+        },
+        /// Outlier rules for new policies changed (None: no filtering)
+        OutlierRulesSet { rules: Option<OutlierRulesV3> },
+        /// An OCW left an hourly sample out of a policy's aggregation as an outlier
+        ObservationRejected {
+            policy_id: PolicyId,
+            location_id: LocationId,
+            reporter: T::AccountId,
+            epoch_time: u64,
+            precip_1h_mm_x1000: i64,
+            reason: outlier::OutlierReasonV3,
+        },
+        /// Governance set a location's metadata
+        LocationMetadataSet {
+            location_id: LocationId,
+            timezone_offset_mins: Option<i16>,
+            elevation_m: Option<i16>,
+            station_id: Option<BoundedVec<u8, T::MaxLocationKeyLength>>,
+        },
+        /// A settled policy's oracle storage was purged, leaving its record hash
+        SettledPolicyPurged { policy_id: PolicyId, record_hash: H256 },
+        /// An OCW resolved unset location metadata
+        LocationMetadataResolved {
+            location_id: LocationId,
+            reporter: T::AccountId,
+            timezone_offset_mins: Option<i16>,
+            elevation_m: Option<i16>,
+        },
     }
 
     // =========================================================================
@@ -377,6 +1056,50 @@ pub mod pallet {
         FinalReportAlreadySubmitted,
         /// Location not active
         LocationNotActive,
+        /// No final report has been proposed with this hash
+        FinalReportNotProposed,
+        /// Member has already attested a final report for this policy
+        AlreadyAttested,
+        /// Too many pending attestations for this policy
+        TooManyAttestations,
+        /// An active location is within `LocationDedupRadius` of these coordinates
+        LocationTooClose,
+        /// Too many active locations in this geohash cell
+        GeohashCellFull,
+        /// Area needs at least two distinct locations, starting with the policy's
+        /// location, and an aggregation the event type supports
+        InvalidAreaSpec,
+        /// Old and new key of a rotation are the same account
+        InvalidRotation,
+        /// Member key is already being rotated out
+        OracleMemberRetiring,
+        /// Overlap window exceeds `MAX_ROTATION_OVERLAP_BLOCKS`
+        RotationOverlapTooLong,
+        /// Too many member keys already retire at that block
+        TooManyRetirements,
+        /// Samples do not replay to the disputed report's commitment
+        DisputeEvidenceMismatch,
+        /// Disputed report's aggregation state matches its committed samples
+        FinalReportConsistent,
+        /// Area policies interleave locations in one chain and cannot be disputed
+        DisputeUnsupportedForArea,
+        /// Cyclone policies commit storm fixes, not weather samples, and cannot be
+        /// disputed
+        DisputeUnsupportedForCyclone,
+        /// Maturity report submitted inside the grace window opened by a snapshot gap
+        SettlementGraceActive,
+        /// Outlier rules exceed the supported median window or confirmations
+        InvalidOutlierRules,
+        /// Timezone offset outside UTC-12:00..=UTC+14:00
+        InvalidTimezoneOffset,
+        /// Elevation outside the plausible land range
+        InvalidElevation,
+        /// Station ID too long
+        StationIdTooLong,
+        /// Policy has no final report, or was already purged
+        PolicyNotSettled,
+        /// `SettledRetentionBlocks` have not passed since the policy's final report
+        SettledRetentionActive,
     }
 
     // =========================================================================
@@ -386,6 +1109,7 @@ pub mod pallet {
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Add a new location to the registry.
+        /// Rejected if an active location is within `LocationDedupRadius`.
         /// Only governance can call this.
         #[pallet::call_index(0)]
         #[pallet::weight(T::WeightInfo::add_location())]
@@ -405,8 +1129,21 @@ pub mod pallet {
             let bounded_name: BoundedVec<u8, ConstU32<64>> =
                 name.try_into().map_err(|_| Error::<T>::LocationNameTooLong)?;
 
+            if let Some((_, distance)) = Self::nearest_indexed_location(latitude, longitude) {
+                ensure!(
+                    distance > T::LocationDedupRadius::get() as u64,
+                    Error::<T>::LocationTooClose
+                );
+            }
+
             let location_id = NextLocationId::<T>::get();
 
+            LocationsByGeohash::<T>::try_append(
+                geohash::encode(latitude, longitude),
+                (location_id, latitude, longitude),
+            )
+            .map_err(|_| Error::<T>::GeohashCellFull)?;
+
             let location_info = LocationInfo {
                 location_id,
                 accuweather_key: bounded_key,
@@ -414,6 +1151,9 @@ pub mod pallet {
                 longitude,
                 name: bounded_name.clone(),
                 active: true,
+                timezone_offset_mins: None,
+                elevation_m: None,
+                station_id: None,
             };
 
             LocationRegistry::<T>::insert(location_id, location_info);
@@ -434,13 +1174,21 @@ pub mod pallet {
         pub fn remove_location(origin: OriginFor<T>, location_id: LocationId) -> DispatchResult {
             T::GovernanceOrigin::ensure_origin(origin)?;
 
-            LocationRegistry::<T>::try_mutate(location_id, |maybe_location| -> DispatchResult {
-                let location = maybe_location
-                    .as_mut()
-                    .ok_or(Error::<T>::LocationNotFound)?;
-                location.active = false;
-                Ok(())
-            })?;
+            let (latitude, longitude) = LocationRegistry::<T>::try_mutate(
+                location_id,
+                |maybe_location| -> Result<(i32, i32), DispatchError> {
+                    let location = maybe_location
+                        .as_mut()
+                        .ok_or(Error::<T>::LocationNotFound)?;
+                    location.active = false;
+                    Ok((location.latitude, location.longitude))
+                },
+            )?;
+
+            // Inactive locations no longer block nearby registrations
+            LocationsByGeohash::<T>::mutate(geohash::encode(latitude, longitude), |ids| {
+                ids.retain(|(id, _, _)| *id != location_id)
+            });
 
             Self::deposit_event(Event::LocationRemoved { location_id });
 
@@ -482,6 +1230,10 @@ pub mod pallet {
             );
 
             OracleMembership::<T>::remove(&account);
+            if RetiringMembers::<T>::contains_key(&account) {
+                let rotation_id = RetiringMembers::<T>::take(&account);
+                Self::mark_retired(rotation_id);
+            }
 
             Self::deposit_event(Event::OracleMemberRemoved { account });
 
@@ -548,6 +1300,7 @@ pub mod pallet {
 
             // Update rate limit
             SnapshotRateLimit::<T>::insert(policy_id, current_block);
+            Self::clear_snapshot_gap(policy_id, current_block);
 
             Self::deposit_event(Event::SnapshotSubmitted {
                 policy_id,
@@ -558,11 +1311,12 @@ pub mod pallet {
             Ok(())
         }
 
-        /// Submit a final report (trigger or maturity) for a policy.
-        /// This triggers settlement in the policy pallet.
+        /// Propose a final report (trigger or maturity) for a policy.
+        /// Counts as the caller's attestation; settlement in the policy pallet
+        /// happens once `FinalReportQuorum` members attest the same report.
         /// Only authorized oracle members can call this.
         #[pallet::call_index(5)]
-        #[pallet::weight(T::WeightInfo::submit_final_report())]
+        #[pallet::weight(T::WeightInfo::submit_final_report(MAX_FINAL_REPORT_ATTESTATIONS))]
         pub fn submit_final_report(
             origin: OriginFor<T>,
             policy_id: PolicyId,
@@ -577,22 +1331,68 @@ pub mod pallet {
                 Error::<T>::NotOracleMember
             );
 
-            Self::do_submit_final_report(policy_id, kind, observed_until, agg_state, commitment)
+            let state =
+                OracleStates::<T>::get(policy_id).ok_or(Error::<T>::PolicyStateNotFound)?;
+            ensure!(
+                state.status == PolicyStatusV3::Active,
+                Error::<T>::PolicyAlreadySettled
+            );
+            ensure!(
+                observed_until >= state.observed_until,
+                Error::<T>::ObservedUntilNotMonotonic
+            );
+            ensure!(
+                Self::validate_agg_state_type(&state.agg_state, &agg_state),
+                Error::<T>::AggStateMismatch
+            );
+            Self::ensure_settlement_grace_elapsed(policy_id, kind)?;
+
+            let report_hash = H256::from(crate::commitment::compute_final_report_hash(
+                policy_id,
+                kind,
+                observed_until,
+                &agg_state,
+                &commitment,
+            ));
+
+            if !ProposedFinalReports::<T>::contains_key(policy_id, report_hash) {
+                ProposedFinalReports::<T>::insert(
+                    policy_id,
+                    report_hash,
+                    FinalReportProposal {
+                        kind,
+                        observed_until,
+                        agg_state,
+                        commitment,
+                    },
+                );
+
+                Self::deposit_event(Event::FinalReportProposed {
+                    policy_id,
+                    report_hash,
+                    proposer: who.clone(),
+                    kind,
+                    observed_until,
+                });
+            }
+
+            Self::do_attest_final_report(who, policy_id, report_hash)
         }
 
         /// Submit a snapshot via unsigned transaction from OCW.
-        /// This allows the OCW to submit snapshots without a signed origin.
+        /// This allows the OCW to submit snapshots without a signed origin; the payload
+        /// signature and nonce are checked in `validate_unsigned`.
         #[pallet::call_index(6)]
-        #[pallet::weight(T::WeightInfo::submit_snapshot())]
+        #[pallet::weight(T::WeightInfo::submit_snapshot_unsigned())]
         pub fn submit_snapshot_unsigned(
             origin: OriginFor<T>,
-            policy_id: PolicyId,
-            observed_until: u64,
-            agg_state: AggStateV3,
-            commitment: [u8; 32],
+            payload: OcwPayloadV3<T::Public, SnapshotDataV3>,
+            _signature: T::Signature,
         ) -> DispatchResult {
             ensure_none(origin)?;
 
+            let SnapshotDataV3 { policy_id, observed_until, agg_state, commitment } = payload.data;
+
             let current_block = frame_system::Pallet::<T>::block_number();
 
             // Check rate limit
@@ -636,6 +1436,7 @@ pub mod pallet {
 
             // Update rate limit
             SnapshotRateLimit::<T>::insert(policy_id, current_block);
+            Self::clear_snapshot_gap(policy_id, current_block);
 
             Self::deposit_event(Event::SnapshotSubmitted {
                 policy_id,
@@ -647,33 +1448,38 @@ pub mod pallet {
         }
 
         /// Submit a final report via unsigned transaction from OCW.
-        /// This triggers settlement in the policy pallet.
+        /// This triggers settlement in the policy pallet. The payload signature and
+        /// nonce are checked in `validate_unsigned`.
         #[pallet::call_index(7)]
-        #[pallet::weight(T::WeightInfo::submit_final_report())]
+        #[pallet::weight(T::WeightInfo::submit_final_report_unsigned())]
         pub fn submit_final_report_unsigned(
             origin: OriginFor<T>,
-            policy_id: PolicyId,
-            kind: OracleReportKindV3,
-            observed_until: u64,
-            agg_state: AggStateV3,
-            commitment: [u8; 32],
+            payload: OcwPayloadV3<T::Public, FinalReportDataV3>,
+            _signature: T::Signature,
         ) -> DispatchResult {
             ensure_none(origin)?;
 
+            let FinalReportDataV3 { policy_id, kind, observed_until, agg_state, commitment } =
+                payload.data;
+
             Self::do_submit_final_report(policy_id, kind, observed_until, agg_state, commitment)
         }
 
         /// Expire an underwrite request via unsigned transaction from OCW.
         /// This allows the OCW to trigger request expiry without a signed origin.
         /// The actual expiry logic is delegated to the market-v3 pallet via trait.
+        /// The payload carries the request id.
         #[pallet::call_index(8)]
-        #[pallet::weight(Weight::from_parts(50_000, 0))]
+        #[pallet::weight(T::WeightInfo::expire_request_unsigned())]
         pub fn expire_request_unsigned(
             origin: OriginFor<T>,
-            request_id: PolicyId,
+            payload: OcwPayloadV3<T::Public, PolicyId>,
+            _signature: T::Signature,
         ) -> DispatchResult {
             ensure_none(origin)?;
 
+            let request_id = payload.data;
+
             // Delegate to market-v3 via trait
             T::RequestExpiryApi::expire_request(request_id)?;
 
@@ -681,6 +1487,372 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// Attest a final report previously proposed via `submit_final_report`.
+        /// Settlement is triggered once `FinalReportQuorum` members attest the same hash.
+        /// Only authorized oracle members can call this.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::attest_final_report(MAX_FINAL_REPORT_ATTESTATIONS))]
+        pub fn attest_final_report(
+            origin: OriginFor<T>,
+            policy_id: PolicyId,
+            report_hash: [u8; 32],
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                OracleMembership::<T>::get(&who),
+                Error::<T>::NotOracleMember
+            );
+
+            Self::do_attest_final_report(who, policy_id, H256::from(report_hash))
+        }
+
+        /// Replay an observation chain from the policy's initial commitment and check
+        /// it ends at the commitment currently recorded on-chain.
+        /// Any signed account can call this, e.g. to back a dispute with the evidence
+        /// exported by the OCW. The result is reported in `CommitmentChainVerified`.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::verify_commitment_chain(samples.len() as u32))]
+        pub fn verify_commitment_chain(
+            origin: OriginFor<T>,
+            policy_id: PolicyId,
+            samples: BoundedVec<WeatherObservation, ConstU32<MAX_EVIDENCE_SAMPLES>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let (valid, commitment) = Self::check_commitment_chain(policy_id, &samples)?;
+
+            Self::deposit_event(Event::CommitmentChainVerified {
+                policy_id,
+                who,
+                samples: samples.len() as u32,
+                commitment: H256::from(commitment),
+                valid,
+            });
+
+            Ok(())
+        }
+
+        /// Report a high Ingest API backlog via unsigned transaction from OCW.
+        /// Emits `IngestBacklogHigh` so operators notice observations piling up in
+        /// the OCW outbox. The payload signature and nonce are checked in
+        /// `validate_unsigned`.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::report_ingest_backlog_unsigned())]
+        pub fn report_ingest_backlog_unsigned(
+            origin: OriginFor<T>,
+            payload: OcwPayloadV3<T::Public, IngestBacklogDataV3>,
+            _signature: T::Signature,
+        ) -> DispatchResult {
+            ensure_none(origin)?;
+
+            let reporter = sp_runtime::traits::IdentifyAccount::into_account(payload.public);
+            let IngestBacklogDataV3 { policy_id, pending_batches, pending_samples, oldest_queued_at } =
+                payload.data;
+
+            Self::deposit_event(Event::IngestBacklogHigh {
+                policy_id,
+                reporter,
+                pending_batches,
+                pending_samples,
+                oldest_queued_at,
+            });
+
+            Ok(())
+        }
+
+        /// Expire an RFQ via unsigned transaction from OCW once its acceptance
+        /// window has closed. The expiry and refunds are delegated to the market-v3
+        /// pallet via trait. The payload carries the RFQ id.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::expire_rfq_unsigned())]
+        pub fn expire_rfq_unsigned(
+            origin: OriginFor<T>,
+            payload: OcwPayloadV3<T::Public, PolicyId>,
+            _signature: T::Signature,
+        ) -> DispatchResult {
+            ensure_none(origin)?;
+
+            let rfq_id = payload.data;
+
+            // Delegate to market-v3 via trait
+            T::RequestExpiryApi::expire_rfq(rfq_id)?;
+
+            Self::deposit_event(Event::RfqExpiredByOcw { rfq_id });
+
+            Ok(())
+        }
+
+        /// Replace an oracle member's key with a new one.
+        /// The new key is a member immediately; the old key stays valid for
+        /// `overlap_blocks` and retires in `on_initialize` (at once if zero).
+        /// Only governance can call this.
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::rotate_oracle_member())]
+        pub fn rotate_oracle_member(
+            origin: OriginFor<T>,
+            old: T::AccountId,
+            new: T::AccountId,
+            overlap_blocks: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            ensure!(old != new, Error::<T>::InvalidRotation);
+            ensure!(
+                OracleMembership::<T>::get(&old),
+                Error::<T>::OracleMemberNotFound
+            );
+            ensure!(
+                !RetiringMembers::<T>::contains_key(&old),
+                Error::<T>::OracleMemberRetiring
+            );
+            ensure!(
+                !OracleMembership::<T>::get(&new),
+                Error::<T>::OracleMemberAlreadyExists
+            );
+            ensure!(
+                overlap_blocks <= MAX_ROTATION_OVERLAP_BLOCKS.into(),
+                Error::<T>::RotationOverlapTooLong
+            );
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let retires_at = now.saturating_add(overlap_blocks);
+            let rotation_id = NextRotationId::<T>::get();
+
+            if !overlap_blocks.is_zero() {
+                RetirementSchedule::<T>::try_append(retires_at, old.clone())
+                    .map_err(|_| Error::<T>::TooManyRetirements)?;
+                RetiringMembers::<T>::insert(&old, rotation_id);
+            }
+
+            OracleMembership::<T>::insert(&new, true);
+            RotationHistory::<T>::insert(
+                rotation_id,
+                MemberRotation {
+                    old: old.clone(),
+                    new: new.clone(),
+                    started_at: now,
+                    retires_at,
+                    retired_at: None,
+                },
+            );
+            NextRotationId::<T>::put(rotation_id.saturating_add(1));
+
+            Self::deposit_event(Event::OracleMemberRotationStarted {
+                rotation_id,
+                old: old.clone(),
+                new,
+                retires_at,
+            });
+
+            if overlap_blocks.is_zero() {
+                Self::retire_member(rotation_id, old);
+            }
+
+            Ok(())
+        }
+
+        /// Dispute a proposed final report with the samples it commits to.
+        /// The samples must replay from the policy's seed to the report's commitment;
+        /// if they aggregate to a different state than the report claims (after the
+        /// policy's outlier rules), the proposal and its attestations are struck.
+        /// Any signed account can call this.
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::dispute_final_report(samples.len() as u32))]
+        pub fn dispute_final_report(
+            origin: OriginFor<T>,
+            policy_id: PolicyId,
+            report_hash: [u8; 32],
+            samples: BoundedVec<WeatherObservation, ConstU32<MAX_EVIDENCE_SAMPLES>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::do_dispute_final_report(
+                who,
+                policy_id,
+                H256::from(report_hash),
+                samples.into_inner(),
+            )
+        }
+
+        /// Report an exhausted AccuWeather daily budget via unsigned transaction from
+        /// OCW. Emits `ApiBudgetExhausted` so operators notice the node is only serving
+        /// cached locations. Accepted once per reporter per day; the payload signature
+        /// and nonce are checked in `validate_unsigned`.
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::report_api_budget_exhausted_unsigned())]
+        pub fn report_api_budget_exhausted_unsigned(
+            origin: OriginFor<T>,
+            payload: OcwPayloadV3<T::Public, ApiBudgetDataV3>,
+            _signature: T::Signature,
+        ) -> DispatchResult {
+            ensure_none(origin)?;
+
+            let reporter = sp_runtime::traits::IdentifyAccount::into_account(payload.public);
+            let ApiBudgetDataV3 { day, calls, budget } = payload.data;
+
+            ApiBudgetWarnings::<T>::insert(&reporter, day);
+
+            Self::deposit_event(Event::ApiBudgetExhausted { reporter, day, calls, budget });
+
+            Ok(())
+        }
+
+        /// Set the outlier rules pinned to policies created from now on, or clear them
+        /// with `None`. Policies already active keep the rules they started with.
+        /// Only governance can call this.
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::set_outlier_rules())]
+        pub fn set_outlier_rules(
+            origin: OriginFor<T>,
+            rules: Option<OutlierRulesV3>,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            if let Some(rules) = &rules {
+                ensure!(rules.is_valid(), Error::<T>::InvalidOutlierRules);
+            }
+            OutlierRules::<T>::set(rules);
+
+            Self::deposit_event(Event::OutlierRulesSet { rules });
+
+            Ok(())
+        }
+
+        /// Report samples a policy's outlier rules rejected via unsigned transaction
+        /// from OCW. Emits one `ObservationRejected` per sample; the payload signature
+        /// and nonce are checked in `validate_unsigned`.
+        #[pallet::call_index(17)]
+        #[pallet::weight(
+            T::WeightInfo::report_rejected_observations_unsigned(payload.data.rejected.len() as u32)
+        )]
+        pub fn report_rejected_observations_unsigned(
+            origin: OriginFor<T>,
+            payload: OcwPayloadV3<T::Public, RejectedObservationsDataV3>,
+            _signature: T::Signature,
+        ) -> DispatchResult {
+            ensure_none(origin)?;
+
+            let reporter: T::AccountId =
+                sp_runtime::traits::IdentifyAccount::into_account(payload.public);
+            let RejectedObservationsDataV3 { policy_id, location_id, rejected } = payload.data;
+
+            for sample in rejected {
+                Self::deposit_event(Event::ObservationRejected {
+                    policy_id,
+                    location_id,
+                    reporter: reporter.clone(),
+                    epoch_time: sample.epoch_time,
+                    precip_1h_mm_x1000: sample.precip_1h_mm_x1000,
+                    reason: sample.reason,
+                });
+            }
+
+            Ok(())
+        }
+
+        /// Set or clear a location's timezone offset, elevation and preferred station.
+        /// Replaces all three fields, including any the OCW resolved.
+        /// Only governance can call this.
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::set_location_metadata())]
+        pub fn set_location_metadata(
+            origin: OriginFor<T>,
+            location_id: LocationId,
+            timezone_offset_mins: Option<i16>,
+            elevation_m: Option<i16>,
+            station_id: Option<Vec<u8>>,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            if let Some(offset) = timezone_offset_mins {
+                ensure!(
+                    location_meta::is_valid_timezone_offset(offset),
+                    Error::<T>::InvalidTimezoneOffset
+                );
+            }
+            if let Some(elevation) = elevation_m {
+                ensure!(location_meta::is_valid_elevation(elevation), Error::<T>::InvalidElevation);
+            }
+            let station_id: Option<BoundedVec<u8, T::MaxLocationKeyLength>> = station_id
+                .map(|id| id.try_into().map_err(|_| Error::<T>::StationIdTooLong))
+                .transpose()?;
+
+            LocationRegistry::<T>::try_mutate(location_id, |maybe_location| -> DispatchResult {
+                let location = maybe_location.as_mut().ok_or(Error::<T>::LocationNotFound)?;
+                location.timezone_offset_mins = timezone_offset_mins;
+                location.elevation_m = elevation_m;
+                location.station_id = station_id.clone();
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::LocationMetadataSet {
+                location_id,
+                timezone_offset_mins,
+                elevation_m,
+                station_id,
+            });
+
+            Ok(())
+        }
+
+        /// Submit location metadata resolved by the OCW via unsigned transaction. Only
+        /// fields still unset are filled, so governance-set values are never overwritten;
+        /// the payload signature and nonce are checked in `validate_unsigned`.
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::WeightInfo::submit_location_metadata_unsigned())]
+        pub fn submit_location_metadata_unsigned(
+            origin: OriginFor<T>,
+            payload: OcwPayloadV3<T::Public, LocationMetadataDataV3>,
+            _signature: T::Signature,
+        ) -> DispatchResult {
+            ensure_none(origin)?;
+
+            let reporter: T::AccountId =
+                sp_runtime::traits::IdentifyAccount::into_account(payload.public);
+            let LocationMetadataDataV3 { location_id, timezone_offset_mins, elevation_m } =
+                payload.data;
+
+            let timezone_offset_mins =
+                timezone_offset_mins.filter(|m| location_meta::is_valid_timezone_offset(*m));
+            let elevation_m = elevation_m.filter(|m| location_meta::is_valid_elevation(*m));
+
+            let (timezone_offset_mins, elevation_m) = LocationRegistry::<T>::try_mutate(
+                location_id,
+                |maybe_location| -> Result<(Option<i16>, Option<i16>), DispatchError> {
+                    let location =
+                        maybe_location.as_mut().ok_or(Error::<T>::LocationNotFound)?;
+                    let offset =
+                        timezone_offset_mins.filter(|_| location.timezone_offset_mins.is_none());
+                    let elevation = elevation_m.filter(|_| location.elevation_m.is_none());
+                    location.timezone_offset_mins = location.timezone_offset_mins.or(offset);
+                    location.elevation_m = location.elevation_m.or(elevation);
+                    Ok((offset, elevation))
+                },
+            )?;
+
+            if timezone_offset_mins.is_some() || elevation_m.is_some() {
+                Self::deposit_event(Event::LocationMetadataResolved {
+                    location_id,
+                    reporter,
+                    timezone_offset_mins,
+                    elevation_m,
+                });
+            }
+
+            Ok(())
+        }
+
+        /// Purge a settled policy's oracle storage once `SettledRetentionBlocks` have
+        /// passed since its final report, leaving its settled record hash.
+        /// Any signed account can call this.
+        #[pallet::call_index(20)]
+        #[pallet::weight(T::WeightInfo::purge_settled())]
+        pub fn purge_settled(origin: OriginFor<T>, policy_id: PolicyId) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            Self::do_purge_settled(policy_id, frame_system::Pallet::<T>::block_number())
+        }
     }
 
     // =========================================================================
@@ -688,6 +1860,41 @@ pub mod pallet {
     // =========================================================================
 
     impl<T: Config> Pallet<T> {
+        /// Check an OCW payload's signature, signer membership and nonce, and start a
+        /// transaction tagged with the signer's nonce. A nonce ahead of the next expected
+        /// one waits in the pool for its predecessor.
+        fn validate_ocw_payload<Data: Encode>(
+            payload: &OcwPayloadV3<T::Public, Data>,
+            signature: &T::Signature,
+        ) -> Result<ValidTransactionBuilder, TransactionValidityError> {
+            use frame_system::offchain::SignedPayload;
+            use sp_runtime::traits::IdentifyAccount;
+
+            if !SignedPayload::<T>::verify::<T::AuthorityId>(payload, signature.clone()) {
+                return Err(InvalidTransaction::BadProof.into());
+            }
+
+            let signer = payload.public.clone().into_account();
+            if !OracleMembership::<T>::get(&signer) {
+                return Err(InvalidTransaction::BadSigner.into());
+            }
+
+            let next_nonce = OcwNonces::<T>::get(&signer);
+            if payload.nonce < next_nonce {
+                return Err(InvalidTransaction::Stale.into());
+            }
+
+            let mut builder = ValidTransaction::with_tag_prefix("OracleV3Ocw")
+                .and_provides((b"nonce", signer.clone(), payload.nonce))
+                .longevity(OCW_UNSIGNED_LONGEVITY)
+                .propagate(true);
+            if payload.nonce > next_nonce {
+                builder = builder.and_requires((b"nonce", signer, payload.nonce - 1));
+            }
+
+            Ok(builder)
+        }
+
         /// Validate that two AggState values are of the same variant type
         fn validate_agg_state_type(existing: &AggStateV3, new: &AggStateV3) -> bool {
             core::mem::discriminant(existing) == core::mem::discriminant(new)
@@ -723,6 +1930,8 @@ pub mod pallet {
                 Error::<T>::AggStateMismatch
             );
 
+            Self::ensure_settlement_grace_elapsed(policy_id, kind)?;
+
             // Determine if triggered based on kind
             let triggered = matches!(kind, OracleReportKindV3::Trigger);
 
@@ -737,6 +1946,15 @@ pub mod pallet {
             };
 
             OracleStates::<T>::insert(policy_id, state);
+            FinalReports::<T>::insert(
+                policy_id,
+                FinalReportProposal {
+                    kind,
+                    observed_until,
+                    agg_state: agg_state.clone(),
+                    commitment,
+                },
+            );
 
             // Notify policy pallet
             T::PolicySettlement::on_final_report(
@@ -747,6 +1965,18 @@ pub mod pallet {
                 H256::from(commitment),
             )?;
 
+            // Any outstanding proposals are moot once the policy has a final report
+            PendingAttestations::<T>::remove(policy_id);
+            let _ = ProposedFinalReports::<T>::clear_prefix(policy_id, u32::MAX, None);
+            OverdueSnapshots::<T>::remove(policy_id);
+            SettlementGraceUntil::<T>::remove(policy_id);
+
+            // Queue the policy's oracle storage for purging after retention
+            SettledAt::<T>::insert(policy_id, frame_system::Pallet::<T>::block_number());
+            let purge_index = NextPurgeIndex::<T>::get();
+            PurgeQueue::<T>::insert(purge_index, policy_id);
+            NextPurgeIndex::<T>::put(purge_index.saturating_add(1));
+
             Self::deposit_event(Event::FinalReportSubmitted {
                 policy_id,
                 kind,
@@ -758,27 +1988,270 @@ pub mod pallet {
             Ok(())
         }
 
+        /// Record a member's attestation and settle once quorum is reached.
+        /// Attestations from accounts no longer in the oracle membership are not counted.
+        fn do_attest_final_report(
+            who: T::AccountId,
+            policy_id: PolicyId,
+            report_hash: H256,
+        ) -> DispatchResult {
+            let state =
+                OracleStates::<T>::get(policy_id).ok_or(Error::<T>::PolicyStateNotFound)?;
+            ensure!(
+                state.status == PolicyStatusV3::Active,
+                Error::<T>::PolicyAlreadySettled
+            );
+
+            let proposal = ProposedFinalReports::<T>::get(policy_id, report_hash)
+                .ok_or(Error::<T>::FinalReportNotProposed)?;
+
+            let attestations = PendingAttestations::<T>::try_mutate(
+                policy_id,
+                |pending| -> Result<u32, DispatchError> {
+                    ensure!(
+                        !pending.iter().any(|(member, _)| member == &who),
+                        Error::<T>::AlreadyAttested
+                    );
+                    pending
+                        .try_push((who.clone(), report_hash))
+                        .map_err(|_| Error::<T>::TooManyAttestations)?;
+
+                    Ok(pending
+                        .iter()
+                        .filter(|(member, hash)| {
+                            *hash == report_hash
+                                && OracleMembership::<T>::get(member)
+                                && !Self::successor_attested(member, report_hash, pending.as_slice())
+                        })
+                        .count() as u32)
+                },
+            )?;
+
+            let quorum = T::FinalReportQuorum::get().max(1);
+
+            Self::deposit_event(Event::FinalReportAttested {
+                policy_id,
+                report_hash,
+                attester: who,
+                attestations,
+                quorum,
+            });
+
+            if attestations >= quorum {
+                Self::do_submit_final_report(
+                    policy_id,
+                    proposal.kind,
+                    proposal.observed_until,
+                    proposal.agg_state,
+                    proposal.commitment,
+                )?;
+            }
+
+            Ok(())
+        }
+
+        /// Whether `member` is a key in its rotation overlap window whose new key
+        /// attested the same report, so one operator is not counted twice
+        fn successor_attested(
+            member: &T::AccountId,
+            report_hash: H256,
+            pending: &[(T::AccountId, H256)],
+        ) -> bool {
+            if !RetiringMembers::<T>::contains_key(member) {
+                return false;
+            }
+            RotationHistory::<T>::get(RetiringMembers::<T>::get(member))
+                .map(|rotation| {
+                    pending
+                        .iter()
+                        .any(|(attester, hash)| *attester == rotation.new && *hash == report_hash)
+                })
+                .unwrap_or(false)
+        }
+
+        /// Retire the member keys whose overlap window ends at `now`
+        pub(crate) fn retire_due_members(now: BlockNumberFor<T>) -> u32 {
+            let due = RetirementSchedule::<T>::take(now);
+            let mut retired = 0u32;
+            for account in due {
+                // Removed by governance during the overlap
+                if !RetiringMembers::<T>::contains_key(&account) {
+                    continue;
+                }
+                let rotation_id = RetiringMembers::<T>::take(&account);
+                Self::retire_member(rotation_id, account);
+                retired = retired.saturating_add(1);
+            }
+            retired
+        }
+
+        /// Walk `SnapshotGapCursor` over up to `MaxSnapshotGapChecks` oracle states,
+        /// wrapping around at the end, and return how many were checked
+        pub(crate) fn check_snapshot_gaps(now: BlockNumberFor<T>) -> u32 {
+            let max_checks = T::MaxSnapshotGapChecks::get() as usize;
+            let iter = match SnapshotGapCursor::<T>::get() {
+                Some(last) => {
+                    OracleStates::<T>::iter_from(OracleStates::<T>::hashed_key_for(last))
+                }
+                None => OracleStates::<T>::iter(),
+            };
+            let batch: Vec<_> = iter.take(max_checks).collect();
+
+            match batch.last() {
+                Some((last, _)) if batch.len() == max_checks => SnapshotGapCursor::<T>::put(last),
+                _ => SnapshotGapCursor::<T>::kill(),
+            }
+
+            let checked = batch.len() as u32;
+            for (policy_id, state) in batch {
+                Self::check_snapshot_gap(now, policy_id, state);
+            }
+            checked
+        }
+
+        /// Report an active policy's snapshots as overdue once per gap
+        fn check_snapshot_gap(
+            now: BlockNumberFor<T>,
+            policy_id: PolicyId,
+            state: PolicyOracleStateV3,
+        ) {
+            // No snapshot yet means coverage may not have started
+            if state.status != PolicyStatusV3::Active
+                || state.last_snapshot_block == 0
+                || OverdueSnapshots::<T>::contains_key(policy_id)
+            {
+                return;
+            }
+
+            // Observations cover the whole window; only the final report is pending
+            let observed_to_end = PolicyMetadata::<T>::get(policy_id)
+                .is_some_and(|meta| state.observed_until >= meta.coverage_end);
+            if observed_to_end {
+                return;
+            }
+
+            let last_snapshot_block: BlockNumberFor<T> = state.last_snapshot_block.into();
+            let blocks_since = now.saturating_sub(last_snapshot_block);
+            if blocks_since < T::SnapshotOverdueBlocks::get() {
+                return;
+            }
+
+            OverdueSnapshots::<T>::insert(policy_id, now);
+
+            let extension = T::OverdueGraceExtension::get();
+            let grace_until = if extension.is_zero() {
+                None
+            } else {
+                let until = now.saturating_add(extension);
+                let until = SettlementGraceUntil::<T>::get(policy_id)
+                    .map_or(until, |existing| existing.max(until));
+                SettlementGraceUntil::<T>::insert(policy_id, until);
+                Some(until)
+            };
+
+            log::warn!(
+                target: "prmx-oracle-v3",
+                "⏰ Snapshots overdue for policy {:?} ({:?} blocks since last)",
+                policy_id,
+                blocks_since
+            );
+
+            Self::deposit_event(Event::SnapshotOverdue {
+                policy_id,
+                last_snapshot_block,
+                blocks_since,
+                grace_until,
+            });
+        }
+
+        /// Clear a policy's overdue flag when a snapshot arrives
+        fn clear_snapshot_gap(policy_id: PolicyId, now: BlockNumberFor<T>) {
+            if let Some(overdue_since) = OverdueSnapshots::<T>::take(policy_id) {
+                Self::deposit_event(Event::SnapshotCaughtUp {
+                    policy_id,
+                    overdue_since,
+                    caught_up_at: now,
+                });
+            }
+        }
+
+        /// Maturity reports wait out the grace window opened by a snapshot gap;
+        /// trigger reports are never held back
+        fn ensure_settlement_grace_elapsed(
+            policy_id: PolicyId,
+            kind: OracleReportKindV3,
+        ) -> DispatchResult {
+            if !matches!(kind, OracleReportKindV3::Maturity) {
+                return Ok(());
+            }
+            if let Some(grace_until) = SettlementGraceUntil::<T>::get(policy_id) {
+                ensure!(
+                    frame_system::Pallet::<T>::block_number() >= grace_until,
+                    Error::<T>::SettlementGraceActive
+                );
+            }
+            Ok(())
+        }
+
+        /// Remove the old key of a rotation and record its retirement
+        fn retire_member(rotation_id: u32, account: T::AccountId) {
+            OracleMembership::<T>::remove(&account);
+            Self::mark_retired(rotation_id);
+
+            log::info!(
+                target: "prmx-oracle-v3",
+                "🔑 Oracle member key retired (rotation {})",
+                rotation_id
+            );
+
+            Self::deposit_event(Event::OracleMemberRetired { rotation_id, account });
+        }
+
+        fn mark_retired(rotation_id: u32) {
+            let now = frame_system::Pallet::<T>::block_number();
+            RotationHistory::<T>::mutate(rotation_id, |rotation| {
+                if let Some(rotation) = rotation {
+                    rotation.retired_at = Some(now);
+                }
+            });
+        }
+
         /// Initialize oracle state for a new policy.
-        /// Called by market pallet when a policy is created.
+        /// Called by market pallet when a policy is created. For an area policy,
+        /// `location_id` must be the area's primary location.
         pub fn initialize_oracle_state(
             policy_id: PolicyId,
             event_spec: EventSpecV3,
             location_id: LocationId,
             coverage_start: u64,
             coverage_end: u64,
+            area: Option<AreaSpecV3>,
         ) -> DispatchResult {
             // Verify location exists and is active
             let location =
                 LocationRegistry::<T>::get(location_id).ok_or(Error::<T>::LocationNotFound)?;
             ensure!(location.active, Error::<T>::LocationNotActive);
 
+            if let Some(area) = &area {
+                ensure!(
+                    area.is_valid_for(event_spec.event_type) && area.primary() == Some(location_id),
+                    Error::<T>::InvalidAreaSpec
+                );
+                for area_location_id in area.locations.iter().skip(1) {
+                    let area_location = LocationRegistry::<T>::get(area_location_id)
+                        .ok_or(Error::<T>::LocationNotFound)?;
+                    ensure!(area_location.active, Error::<T>::LocationNotActive);
+                }
+            }
+
             // Compute initial commitment seed
-            let commitment = Self::compute_initial_commitment(
+            let commitment = commitment::compute_initial_commitment(
                 policy_id,
                 &event_spec,
                 location_id,
                 coverage_start,
                 coverage_end,
+                area.as_ref(),
             );
 
             // Create initial agg_state based on event type
@@ -794,11 +2267,20 @@ pub mod pallet {
             };
 
             OracleStates::<T>::insert(policy_id, oracle_state);
+            if let Some(rules) = OutlierRules::<T>::get() {
+                PolicyOutlierRules::<T>::insert(policy_id, rules);
+            }
             
             // Store policy metadata for OCW lookup
             PolicyMetadata::<T>::insert(
                 policy_id,
-                (location_id, event_spec.clone(), coverage_start, coverage_end)
+                PolicyMetaV3 {
+                    location_id,
+                    event_spec: event_spec.clone(),
+                    coverage_start,
+                    coverage_end,
+                    area,
+                },
             );
 
             Self::deposit_event(Event::OracleStateInitialized {
@@ -810,26 +2292,98 @@ pub mod pallet {
             Ok(())
         }
 
-        /// Compute initial commitment seed from policy parameters
-        fn compute_initial_commitment(
+        /// Check that `samples` extend the policy's initial commitment to its current
+        /// on-chain commitment. Returns the result and the on-chain commitment.
+        pub fn check_commitment_chain(
             policy_id: PolicyId,
-            event_spec: &EventSpecV3,
-            location_id: LocationId,
-            coverage_start: u64,
-            coverage_end: u64,
-        ) -> [u8; 32] {
-            use sp_core::Hasher;
-            use sp_runtime::traits::BlakeTwo256;
+            samples: &[WeatherObservation],
+        ) -> Result<(bool, [u8; 32]), DispatchError> {
+            let state =
+                OracleStates::<T>::get(policy_id).ok_or(Error::<T>::PolicyStateNotFound)?;
+            let meta =
+                PolicyMetadata::<T>::get(policy_id).ok_or(Error::<T>::PolicyStateNotFound)?;
 
-            let mut data = Vec::new();
-            data.extend_from_slice(b"prmx_v3:");
-            data.extend_from_slice(&policy_id.to_le_bytes());
-            data.extend_from_slice(&event_spec.encode());
-            data.extend_from_slice(&location_id.to_le_bytes());
-            data.extend_from_slice(&coverage_start.to_le_bytes());
-            data.extend_from_slice(&coverage_end.to_le_bytes());
+            let initial = commitment::compute_initial_commitment(
+                policy_id,
+                &meta.event_spec,
+                meta.location_id,
+                meta.coverage_start,
+                meta.coverage_end,
+                meta.area.as_ref(),
+            );
 
-            BlakeTwo256::hash(&data).into()
+            Ok((
+                commitment::verify_commitment_chain(initial, samples, state.commitment),
+                state.commitment,
+            ))
+        }
+
+        /// Strike a proposed final report whose committed samples disagree with it
+        fn do_dispute_final_report(
+            who: T::AccountId,
+            policy_id: PolicyId,
+            report_hash: H256,
+            samples: Vec<WeatherObservation>,
+        ) -> DispatchResult {
+            let state =
+                OracleStates::<T>::get(policy_id).ok_or(Error::<T>::PolicyStateNotFound)?;
+            ensure!(
+                state.status == PolicyStatusV3::Active,
+                Error::<T>::PolicyAlreadySettled
+            );
+            let meta =
+                PolicyMetadata::<T>::get(policy_id).ok_or(Error::<T>::PolicyStateNotFound)?;
+            ensure!(meta.area.is_none(), Error::<T>::DisputeUnsupportedForArea);
+            ensure!(
+                meta.event_spec.event_type != EventTypeV3::CycloneWithinGte,
+                Error::<T>::DisputeUnsupportedForCyclone
+            );
+
+            let proposal = ProposedFinalReports::<T>::get(policy_id, report_hash)
+                .ok_or(Error::<T>::FinalReportNotProposed)?;
+
+            let initial = commitment::compute_initial_commitment(
+                policy_id,
+                &meta.event_spec,
+                meta.location_id,
+                meta.coverage_start,
+                meta.coverage_end,
+                None,
+            );
+            ensure!(
+                commitment::verify_commitment_chain(initial, &samples, proposal.commitment),
+                Error::<T>::DisputeEvidenceMismatch
+            );
+
+            let sample_count = samples.len() as u32;
+            let event_type = meta.event_spec.event_type;
+            let (recomputed, _, _) = aggregator::process_filtered_batch(
+                event_type,
+                AggStateV3::initial_for_event_type(event_type),
+                samples,
+                PolicyOutlierRules::<T>::get(policy_id).as_ref(),
+                &mut outlier::OutlierFilter::default(),
+            );
+            ensure!(
+                recomputed != proposal.agg_state,
+                Error::<T>::FinalReportConsistent
+            );
+
+            ProposedFinalReports::<T>::remove(policy_id, report_hash);
+            PendingAttestations::<T>::mutate(policy_id, |pending| {
+                pending.retain(|(_, hash)| *hash != report_hash)
+            });
+
+            Self::deposit_event(Event::FinalReportDisputed {
+                policy_id,
+                report_hash,
+                disputer: who,
+                samples: sample_count,
+                claimed: proposal.agg_state,
+                recomputed,
+            });
+
+            Ok(())
         }
 
         /// Evaluate if threshold is met based on event type and agg_state
@@ -856,6 +2410,16 @@ pub mod pallet {
                     // Threshold value is used as a mask to check for specific precip types
                     (*mask as i64) & threshold != 0
                 }
+                (
+                    EventTypeV3::DryStreakGte,
+                    AggStateV3::ConsecutiveDryHours { longest_hours, .. },
+                ) => *longest_hours as i64 >= threshold,
+                // Peak category only counts fixes inside the cover radius
+                (
+                    EventTypeV3::CycloneWithinGte,
+                    AggStateV3::CycloneApproach { peak_category, .. },
+                ) => CycloneThresholdV3::from_value(threshold)
+                    .is_some_and(|cyclone| *peak_category >= cyclone.category),
                 // Type mismatch - should not happen if properly validated
                 _ => false,
             }
@@ -873,11 +2437,108 @@ pub mod pallet {
                 .unwrap_or(false)
         }
 
+        /// Nearest active location to `latitude`/`longitude` (scaled by 1e6) in
+        /// their geohash cell or a neighbouring one
+        pub fn find_nearest_location(latitude: i32, longitude: i32) -> Option<LocationId> {
+            Self::nearest_indexed_location(latitude, longitude).map(|(location_id, _)| location_id)
+        }
+
+        /// Nearest indexed location and its distance in metres
+        fn nearest_indexed_location(latitude: i32, longitude: i32) -> Option<(LocationId, u64)> {
+            geohash::neighbourhood(latitude, longitude)
+                .into_iter()
+                .flat_map(LocationsByGeohash::<T>::get)
+                .map(|(location_id, lat, lon)| {
+                    (location_id, geohash::distance_m(latitude, longitude, lat, lon))
+                })
+                .min_by_key(|(location_id, distance)| (*distance, *location_id))
+        }
+
         /// Get oracle state for a policy
         pub fn get_oracle_state(policy_id: PolicyId) -> Option<PolicyOracleStateV3> {
             OracleStates::<T>::get(policy_id)
         }
 
+        /// Replace a settled policy's oracle storage with its settled record hash
+        fn do_purge_settled(policy_id: PolicyId, now: BlockNumberFor<T>) -> DispatchResult {
+            let settled_at = SettledAt::<T>::get(policy_id).ok_or(Error::<T>::PolicyNotSettled)?;
+            ensure!(
+                purge::is_purgeable(settled_at, T::SettledRetentionBlocks::get(), now),
+                Error::<T>::SettledRetentionActive
+            );
+
+            let state = OracleStates::<T>::take(policy_id).ok_or(Error::<T>::PolicyNotSettled)?;
+            let meta = PolicyMetadata::<T>::take(policy_id);
+            let final_report = FinalReports::<T>::take(policy_id);
+            PolicyOutlierRules::<T>::remove(policy_id);
+            SnapshotRateLimit::<T>::remove(policy_id);
+            SettledAt::<T>::remove(policy_id);
+
+            let record_hash = H256::from(purge::settled_record_hash(
+                policy_id,
+                &state,
+                &meta,
+                &final_report,
+            ));
+            SettledRecordHashes::<T>::insert(policy_id, record_hash);
+
+            Self::deposit_event(Event::SettledPolicyPurged { policy_id, record_hash });
+
+            Ok(())
+        }
+
+        /// Walk `PurgeCursor` forward in final report order, purging settled policies
+        /// past retention within `limit`. Stops at the first policy still retained.
+        fn purge_due_settled(now: BlockNumberFor<T>, limit: Weight) -> Weight {
+            let db = T::DbWeight::get();
+            // Cursor and next index reads, cursor write
+            let overhead = db.reads_writes(2, 1);
+            // Queue and settlement block reads, queue removal, plus the purge itself
+            let step_cost = T::WeightInfo::purge_settled().saturating_add(db.reads_writes(1, 1));
+            if !limit.all_gte(overhead) {
+                return Weight::zero();
+            }
+            let mut used = overhead;
+
+            let start = PurgeCursor::<T>::get();
+            let next_index = NextPurgeIndex::<T>::get();
+            let retention = T::SettledRetentionBlocks::get();
+            let mut cursor = start;
+            let mut steps = 0u32;
+
+            while cursor < next_index
+                && steps < purge::MAX_PURGES_PER_BLOCK
+                && limit.all_gte(used.saturating_add(step_cost))
+            {
+                used = used.saturating_add(step_cost);
+                steps += 1;
+
+                // Policies purged through `purge_settled` leave their queue entry behind
+                let settled_at = PurgeQueue::<T>::get(cursor)
+                    .and_then(|policy_id| SettledAt::<T>::get(policy_id).map(|at| (policy_id, at)));
+                if let Some((policy_id, settled_at)) = settled_at {
+                    if !purge::is_purgeable(settled_at, retention, now) {
+                        break;
+                    }
+                    if let Err(e) = Self::do_purge_settled(policy_id, now) {
+                        log::warn!(
+                            target: "prmx-oracle-v3",
+                            "Failed to purge settled policy {}: {:?}",
+                            policy_id,
+                            e
+                        );
+                    }
+                }
+                PurgeQueue::<T>::remove(cursor);
+                cursor = cursor.saturating_add(1);
+            }
+
+            if cursor != start {
+                PurgeCursor::<T>::put(cursor);
+            }
+            used
+        }
+
         /// Mark policy as settled (called after settlement completes)
         pub fn mark_policy_settled(policy_id: PolicyId) -> DispatchResult {
             OracleStates::<T>::try_mutate(policy_id, |maybe_state| -> DispatchResult {
@@ -901,6 +2562,27 @@ pub mod pallet {
     
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// Retire rotated member keys whose overlap window ends this block
+        /// and, every `SNAPSHOT_GAP_CHECK_INTERVAL_BLOCKS`, sweep for snapshot gaps
+        fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+            let retired = Self::retire_due_members(now);
+            let weight = T::WeightInfo::retire_oracle_members(retired);
+
+            let block_num: u32 = now.unique_saturated_into();
+            if block_num % SNAPSHOT_GAP_CHECK_INTERVAL_BLOCKS != 0 {
+                return weight;
+            }
+
+            let checked = Self::check_snapshot_gaps(now);
+            weight.saturating_add(T::WeightInfo::check_snapshot_gaps(checked))
+        }
+
+        /// Purge settled policies past `SettledRetentionBlocks` with the block's leftover
+        /// weight (see `purge`)
+        fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::purge_due_settled(now, remaining_weight)
+        }
+
         /// Offchain worker runs after each block is imported
         fn offchain_worker(block_number: BlockNumberFor<T>) {
             let block_num: u32 = block_number.unique_saturated_into();
@@ -908,7 +2590,7 @@ pub mod pallet {
             // Run OCW logic every 10 blocks (~1 minute at 6s block time)
             // During startup (first 5 blocks), run every block
             let is_startup = block_num < 5;
-            let should_run = is_startup || block_num % 10 == 0;
+            let should_run = is_startup || block_num % OCW_RUN_INTERVAL_BLOCKS == 0;
             
             if !should_run {
                 return;
@@ -941,6 +2623,29 @@ pub mod pallet {
             // Get current timestamp
             let now = sp_io::offchain::timestamp().unix_millis() / 1000;
             
+            // Upload evidence chains of policies finalized in earlier runs
+            if let (Some(ingest_url), Some(hmac_secret)) =
+                (ocw::get_ingest_api_url(), ocw::get_hmac_secret())
+            {
+                evidence::export_pending(&ingest_url, &hmac_secret);
+
+                // Re-send observation batches the Ingest API did not accept earlier
+                for report in outbox::flush_pending(&ingest_url, &hmac_secret, now) {
+                    if let Err(e) = Self::submit_ingest_backlog_on_chain(report) {
+                        log::warn!(
+                            target: "prmx-oracle-v3",
+                            "Failed to report Ingest API backlog for policy {}: {:?}",
+                            report.policy_id,
+                            e
+                        );
+                        ocw::record_error_kind(e);
+                    }
+                }
+            }
+            
+            // Resolve timezone and elevation of locations that have none yet
+            Self::resolve_location_metadata(now);
+
             // Process all active policies
             let active_policies = Self::get_active_policies();
             
@@ -958,20 +2663,91 @@ pub mod pallet {
                 active_policies.len()
             );
             
-            for (policy_id, on_chain_state) in active_policies {
-                if let Err(e) = Self::process_policy_ocw(policy_id, &on_chain_state, now) {
-                    log::warn!(
-                        target: "prmx-oracle-v3",
-                        "❌ Failed to process policy {}: {:?}",
-                        policy_id,
-                        e
-                    );
+            let budget_state = fetch_quota::FetchBudgetState::load();
+            let daily_budget = fetch_quota::daily_fetch_budget();
+            let mut usage = fetch_quota::DailyUsage::load(now);
+            let run_budget = fetch_quota::fetch_budget_per_run()
+                .saturating_add(budget_state.carry_over)
+                .min(usage.remaining(daily_budget));
+            let mut fetches = fetch_quota::RunFetches::new(run_budget);
+            let mut first_skipped = None;
+
+            let mut policies = fetch_quota::round_robin(active_policies, budget_state.next_policy);
+            let priority_only = usage.in_priority_reserve(daily_budget);
+            if priority_only {
+                // Keep the rest of the day's budget for policies about to settle.
+                // Cyclone policies do not fetch from AccuWeather.
+                policies.retain(|(policy_id, state)| {
+                    Self::get_policy_metadata(*policy_id).is_some_and(|meta| {
+                        meta.event_spec.event_type == EventTypeV3::CycloneWithinGte
+                            || fetch_quota::is_priority(
+                                &meta.event_spec,
+                                &state.agg_state,
+                                meta.coverage_end,
+                                now,
+                            )
+                    })
+                });
+                log::info!(
+                    target: "prmx-oracle-v3",
+                    "⏳ AccuWeather daily budget low ({}/{} calls); {} priority policies",
+                    usage.calls,
+                    daily_budget,
+                    policies.len()
+                );
+            }
+
+            for (policy_id, on_chain_state) in policies {
+                match Self::process_policy_ocw(policy_id, &on_chain_state, now, &mut fetches) {
+                    Ok(true) => {}
+                    Ok(false) => {
+                        first_skipped.get_or_insert(policy_id);
+                    }
+                    Err(e) => {
+                        log::warn!(
+                            target: "prmx-oracle-v3",
+                            "❌ Failed to process policy {}: {:?}",
+                            policy_id,
+                            e
+                        );
+                        ocw::record_error_kind(e);
+                    }
+                }
+            }
+
+            if let Some(policy_id) = first_skipped {
+                log::info!(
+                    target: "prmx-oracle-v3",
+                    "⏳ AccuWeather fetch budget exhausted; next run resumes at policy {}",
+                    policy_id
+                );
+            }
+            // Skipped non-priority policies resume from the old cursor
+            if priority_only && first_skipped.is_none() {
+                first_skipped = budget_state.next_policy;
+            }
+            fetch_quota::next_state(fetches.remaining(), first_skipped).save();
+
+            usage.record(run_budget.saturating_sub(fetches.remaining()));
+            if usage.is_exhausted(daily_budget) && !usage.warned {
+                match Self::submit_api_budget_exhausted_on_chain(&usage, daily_budget) {
+                    Ok(()) => usage.warned = true,
+                    Err(e) => {
+                        log::warn!(
+                            target: "prmx-oracle-v3",
+                            "Failed to report exhausted AccuWeather daily budget: {:?}",
+                            e
+                        );
+                        ocw::record_error_kind(e);
+                    }
                 }
             }
+            usage.save();
             
             // Check for expired requests (every 5 minutes)
             if expiry::should_check_expiry(now) {
                 Self::process_expired_requests(now);
+                Self::process_expired_rfqs(now);
                 expiry::record_expiry_check(now);
             }
         }
@@ -982,16 +2758,18 @@ pub mod pallet {
     // =========================================================================
     
     impl<T: Config> Pallet<T> {
-        /// Process a single policy in the offchain worker
+        /// Process a single policy in the offchain worker.
+        /// Returns `false` if the policy was skipped for lack of fetch budget.
         fn process_policy_ocw(
             policy_id: PolicyId,
             on_chain_state: &PolicyOracleStateV3,
             now_epoch: u64,
-        ) -> Result<(), &'static str> {
+            fetches: &mut fetch_quota::RunFetches,
+        ) -> Result<bool, OcwErrorKind> {
             // Load or initialize local OCW state
             let mut local_state = ocw::OcwPolicyState::load(policy_id)
                 .unwrap_or_else(|| ocw::OcwPolicyState::from_on_chain_state(on_chain_state));
-            
+
             // Skip if in backoff
             if local_state.is_in_backoff(now_epoch) {
                 log::debug!(
@@ -1000,270 +2778,471 @@ pub mod pallet {
                     policy_id,
                     local_state.backoff.retry_after
                 );
-                return Ok(());
+                return Ok(true);
             }
-            
+
             // Skip if already finalized locally
             if local_state.finalized {
-                return Ok(());
+                return Ok(true);
             }
-            
-            // Get location info for this policy
-            // Note: We need to get the location_id from somewhere - for now use a lookup
-            // In production, this would be stored in the policy or oracle state
-            let location_id = Self::get_policy_location_id(policy_id)?;
-            let location = LocationRegistry::<T>::get(location_id)
-                .ok_or("Location not found")?;
-            
-            // Fetch new observations from AccuWeather
-            let api_key = ocw::get_accuweather_api_key().ok_or("No API key")?;
-            let location_key = &location.accuweather_key;
-            
-            log::info!(
-                target: "prmx-oracle-v3",
-                "🌐 Fetching weather for policy {} from AccuWeather",
-                policy_id
-            );
-            
-            // Fetch and process observations
-            match http_client::fetch_accuweather_historical(location_key.as_slice(), &api_key) {
-                Ok(observations) => {
-                    if observations.is_empty() {
-                        log::debug!(
+
+            let meta = Self::get_policy_metadata(policy_id).ok_or(OcwErrorKind::Parse)?;
+            let event_type = meta.event_spec.event_type;
+
+            if event_type == EventTypeV3::CycloneWithinGte {
+                return Self::process_cyclone_policy_ocw(policy_id, &meta, local_state, now_epoch);
+            }
+
+            // An area policy aggregates each of its locations separately; a
+            // single-location policy is the one-location case
+            let mut area_state = meta.area.as_ref().map(|area| {
+                ocw::OcwAreaState::load(policy_id)
+                    .unwrap_or_else(|| ocw::OcwAreaState::from_on_chain_state(area, on_chain_state))
+            });
+            let mut locations = match &area_state {
+                Some(area_state) => area_state.locations.clone(),
+                None => alloc::vec![ocw::OcwLocationState {
+                    location_id: meta.location_id,
+                    last_seen_epoch: local_state.last_seen_epoch,
+                    agg_state: local_state.agg_state.clone(),
+                }],
+            };
+
+            let api_key = ocw::get_accuweather_api_key().ok_or(OcwErrorKind::Config)?;
+            let endpoints = ocw::get_accuweather_endpoints();
+
+            // Fetch every location before committing anything, so a failed fetch
+            // leaves the commitment chain as it was for the retry
+            let mut fetched = Vec::with_capacity(locations.len());
+            for location_state in &locations {
+                let location = LocationRegistry::<T>::get(location_state.location_id)
+                    .ok_or(OcwErrorKind::Config)?;
+
+                log::info!(
+                    target: "prmx-oracle-v3",
+                    "🌐 Fetching weather for policy {} (location {}) from AccuWeather",
+                    policy_id,
+                    location_state.location_id
+                );
+
+                let location_key = location.fetch_key();
+                match fetches.get(
+                    location_state.location_id,
+                    now_epoch,
+                    http_client::has_cached_historical(location_key),
+                    || http_client::fetch_accuweather_historical(location_key, &api_key, &endpoints),
+                ) {
+                    fetch_quota::FetchOutcome::Fetched(observations) => {
+                        fetched.push((location_key.to_vec(), observations))
+                    }
+                    fetch_quota::FetchOutcome::Failed => {
+                        log::warn!(
                             target: "prmx-oracle-v3",
-                            "No new observations for policy {}",
-                            policy_id
+                            "Failed to fetch AccuWeather data for policy {} (location {})",
+                            policy_id,
+                            location_state.location_id
                         );
+                        local_state.record_error(ocw::OcwError::AccuWeatherFetch, now_epoch);
                         local_state.save(policy_id);
-                        return Ok(());
+                        return Ok(true);
                     }
-                    
-                    // Filter observations to those we haven't seen
-                    let new_obs: Vec<_> = observations
-                        .into_iter()
-                        .filter(|obs| obs.epoch_time > local_state.last_seen_epoch)
-                        .collect();
-                    
-                    if new_obs.is_empty() {
+                    fetch_quota::FetchOutcome::BackingOff => {
                         log::debug!(
                             target: "prmx-oracle-v3",
-                            "All observations already processed for policy {}",
+                            "Location {} in backoff, skipping policy {}",
+                            location_state.location_id,
                             policy_id
                         );
-                        
-                        // Even with no new observations, check if coverage ended and we need to submit maturity
-                        if let Some((_, _event_spec, _coverage_start, coverage_end)) = Self::get_policy_metadata(policy_id) {
-                            if now_epoch > coverage_end && !local_state.finalized {
-                                log::info!(
-                                    target: "prmx-oracle-v3",
-                                    "✅ Submitting final MATURITY report for policy {} (no new observations)",
-                                    policy_id
-                                );
-                                
-                                if let Err(e) = Self::submit_final_report_on_chain(
-                                    policy_id,
-                                    OracleReportKindV3::Maturity,
-                                    local_state.last_seen_epoch,
-                                    local_state.agg_state.clone(),
-                                    local_state.commitment,
-                                ) {
-                                    log::warn!(
-                                        target: "prmx-oracle-v3",
-                                        "Failed to submit maturity report: {:?}",
-                                        e
-                                    );
-                                    local_state.record_error(ocw::OcwError::ChainSubmission, now_epoch);
-                                } else {
-                                    local_state.finalized = true;
-                                }
-                            }
-                        }
-                        
-                        local_state.save(policy_id);
-                        return Ok(());
+                        return Ok(true);
                     }
-                    
+                    fetch_quota::FetchOutcome::OverBudget => return Ok(false),
+                }
+            }
+
+            // Commit each location's new observations to the policy's single chain,
+            // in area order, and aggregate the ones its outlier rules accept into that
+            // location's state
+            let outlier_rules = PolicyOutlierRules::<T>::get(policy_id);
+            let mut new_samples = 0usize;
+            for (location_state, (location_key, observations)) in locations.iter_mut().zip(fetched) {
+                // Filter observations to those we haven't seen
+                let new_obs: Vec<_> = observations
+                    .into_iter()
+                    .filter(|obs| obs.epoch_time > location_state.last_seen_epoch)
+                    .collect();
+
+                if new_obs.is_empty() {
+                    continue;
+                }
+
+                log::info!(
+                    target: "prmx-oracle-v3",
+                    "📊 Processing {} new observations for policy {} (location {})",
+                    new_obs.len(),
+                    policy_id,
+                    location_state.location_id
+                );
+
+                // Update commitment chain and aggregation
+                let (new_commitment, sample_hashes) =
+                    commitment::process_commitment_batch(local_state.commitment, &new_obs);
+
+                // Keep the committed samples for the evidence export
+                evidence::EvidenceLog::record_batch(policy_id, local_state.commitment, &new_obs);
+
+                let location_id = location_state.location_id;
+                let mut filter = outlier::OutlierFilter::load(policy_id, location_id);
+                let (new_agg_state, last_epoch, rejected) = aggregator::process_filtered_batch(
+                    event_type,
+                    location_state.agg_state.clone(),
+                    new_obs.clone(),
+                    outlier_rules.as_ref(),
+                    &mut filter,
+                );
+                if outlier_rules.is_some() {
+                    filter.save(policy_id, location_id);
+                }
+                if !rejected.is_empty() {
+                    Self::report_rejected_observations_ocw(policy_id, location_id, rejected);
+                }
+
+                location_state.agg_state = new_agg_state;
+                location_state.last_seen_epoch = last_epoch;
+                local_state.commitment = new_commitment;
+                new_samples += new_obs.len();
+
+                Self::send_ingest_batch_ocw(
+                    policy_id,
+                    &mut local_state,
+                    outbox::PendingBatch {
+                        location_key,
+                        observations: new_obs,
+                        sample_hashes,
+                        commitment_after: new_commitment,
+                        queued_at: now_epoch,
+                    },
+                    last_epoch,
+                    now_epoch,
+                );
+            }
+
+            if new_samples == 0 {
+                log::debug!(
+                    target: "prmx-oracle-v3",
+                    "No new observations for policy {}",
+                    policy_id
+                );
+
+                // Even with no new observations, check if coverage ended and we need to submit maturity
+                if now_epoch > meta.coverage_end {
                     log::info!(
                         target: "prmx-oracle-v3",
-                        "📊 Processing {} new observations for policy {}",
-                        new_obs.len(),
+                        "✅ Submitting final MATURITY report for policy {} (no new observations)",
                         policy_id
                     );
-                    
-                    // Get event type from on-chain state
-                    let event_type = Self::get_policy_event_type(policy_id)?;
-                    
-                    // Update commitment chain and aggregation
-                    let (new_commitment, sample_hashes) = 
-                        commitment::process_commitment_batch(local_state.commitment, &new_obs);
-                    
-                    // Aggregate observations
-                    let (new_agg_state, last_epoch) = aggregator::process_observation_batch(
-                        event_type,
-                        local_state.agg_state.clone(),
-                        new_obs.clone(),
+                    Self::finalize_policy_ocw(
+                        policy_id,
+                        &mut local_state,
+                        OracleReportKindV3::Maturity,
+                        now_epoch,
                     );
-                    
-                    // Update local state
-                    local_state.agg_state = new_agg_state.clone();
-                    local_state.commitment = new_commitment;
-                    local_state.last_seen_epoch = last_epoch;
-                    
-                    // Send observations to Ingest API
-                    if let Some(ingest_url) = ocw::get_ingest_api_url() {
-                        if let Some(hmac_secret) = ocw::get_hmac_secret() {
-                            if let Err(e) = http_client::send_observations_batch(
-                                &ingest_url,
-                                &hmac_secret,
-                                policy_id,
-                                location_key.as_slice(),
-                                &new_obs,
-                                &sample_hashes,
-                                new_commitment,
-                            ) {
-                                log::warn!(
-                                    target: "prmx-oracle-v3",
-                                    "Failed to send observations to Ingest API: {}",
-                                    e
-                                );
-                                local_state.record_error(ocw::OcwError::IngestApi, now_epoch);
-                            } else {
-                                local_state.last_observation_sent_epoch = last_epoch;
-                                local_state.clear_error();
-                            }
-                        }
-                    }
-                    
-                    // Get policy metadata for coverage times
-                    if let Some((_, event_spec, coverage_start, coverage_end)) = Self::get_policy_metadata(policy_id) {
-                        // Determine what on-chain action to take
-                        let decision = ocw::decide_snapshot_action(
-                            &local_state,
-                            &event_spec,
-                            now_epoch,
-                            coverage_start,
-                            coverage_end,
-                        );
-                        
-                        match decision {
-                            ocw::SnapshotDecision::SendFinalTrigger => {
-                                log::info!(
-                                    target: "prmx-oracle-v3",
-                                    "🎯 Submitting final TRIGGER report for policy {}",
-                                    policy_id
-                                );
-                                
-                                if let Err(e) = Self::submit_final_report_on_chain(
-                                    policy_id,
-                                    OracleReportKindV3::Trigger,
-                                    last_epoch,
-                                    new_agg_state.clone(),
-                                    new_commitment,
-                                ) {
-                                    log::warn!(
-                                        target: "prmx-oracle-v3",
-                                        "Failed to submit trigger report: {:?}",
-                                        e
-                                    );
-                                    local_state.record_error(ocw::OcwError::ChainSubmission, now_epoch);
-                                } else {
-                                    local_state.finalized = true;
-                                    local_state.last_snapshot_epoch = last_epoch;
-                                }
-                            }
-                            ocw::SnapshotDecision::SendFinalMaturity => {
-                                log::info!(
-                                    target: "prmx-oracle-v3",
-                                    "✅ Submitting final MATURITY report for policy {}",
-                                    policy_id
-                                );
-                                
-                                if let Err(e) = Self::submit_final_report_on_chain(
-                                    policy_id,
-                                    OracleReportKindV3::Maturity,
-                                    last_epoch,
-                                    new_agg_state.clone(),
-                                    new_commitment,
-                                ) {
-                                    log::warn!(
-                                        target: "prmx-oracle-v3",
-                                        "Failed to submit maturity report: {:?}",
-                                        e
-                                    );
-                                    local_state.record_error(ocw::OcwError::ChainSubmission, now_epoch);
-                                } else {
-                                    local_state.finalized = true;
-                                    local_state.last_snapshot_epoch = last_epoch;
-                                }
-                            }
-                            ocw::SnapshotDecision::SendSnapshot => {
-                                log::info!(
-                                    target: "prmx-oracle-v3",
-                                    "📸 Submitting snapshot for policy {} (observed_until: {})",
-                                    policy_id,
-                                    last_epoch
-                                );
-                                
-                                if let Err(e) = Self::submit_snapshot_on_chain(
-                                    policy_id,
-                                    last_epoch,
-                                    new_agg_state.clone(),
-                                    new_commitment,
-                                ) {
-                                    log::warn!(
-                                        target: "prmx-oracle-v3",
-                                        "Failed to submit snapshot: {:?}",
-                                        e
-                                    );
-                                    local_state.record_error(ocw::OcwError::ChainSubmission, now_epoch);
-                                } else {
-                                    local_state.last_snapshot_epoch = last_epoch;
-                                    local_state.last_snapshot_sent_at = now_epoch;
-                                }
-                            }
-                            ocw::SnapshotDecision::None => {
-                                // No on-chain action needed
-                            }
-                        }
-                    }
-                    
-                    local_state.save(policy_id);
                 }
+
+                local_state.save(policy_id);
+                return Ok(true);
+            }
+
+            // Update local state with the policy-level view of its locations
+            local_state.last_seen_epoch = locations
+                .iter()
+                .map(|location_state| location_state.last_seen_epoch)
+                .max()
+                .unwrap_or(local_state.last_seen_epoch);
+            local_state.agg_state = match &meta.area {
+                Some(area) => {
+                    let states: Vec<AggStateV3> = locations
+                        .iter()
+                        .map(|location_state| location_state.agg_state.clone())
+                        .collect();
+                    aggregator::combine_area_states(event_type, area.aggregation, &states)
+                }
+                None => locations[0].agg_state.clone(),
+            };
+            if let Some(area_state) = area_state.as_mut() {
+                area_state.locations = locations;
+                area_state.save(policy_id);
+            }
+
+            Self::submit_policy_report_ocw(policy_id, &meta, &mut local_state, now_epoch);
+            Ok(true)
+        }
+
+        /// Process a cyclone policy: fold the storm-track advisories issued since
+        /// the last run into its closest-approach state. Fixes are committed to the
+        /// policy's chain but not sent to the Ingest API or the evidence log, which
+        /// hold station observations.
+        fn process_cyclone_policy_ocw(
+            policy_id: PolicyId,
+            meta: &PolicyMetaV3,
+            mut local_state: ocw::OcwPolicyState,
+            now_epoch: u64,
+        ) -> Result<bool, OcwErrorKind> {
+            let threshold = CycloneThresholdV3::from_value(meta.event_spec.threshold.value)
+                .ok_or(OcwErrorKind::Parse)?;
+            let base_url = ocw::get_storm_track_api_url().ok_or(OcwErrorKind::Config)?;
+            let location =
+                LocationRegistry::<T>::get(meta.location_id).ok_or(OcwErrorKind::Config)?;
+
+            let advisories = match storm::fetch_storm_advisories(
+                &base_url,
+                location.latitude,
+                location.longitude,
+                V3_MAX_CYCLONE_RADIUS_KM,
+            ) {
+                Ok(advisories) => advisories,
                 Err(e) => {
                     log::warn!(
                         target: "prmx-oracle-v3",
-                        "Failed to fetch AccuWeather data for policy {}: {}",
+                        "Failed to fetch storm advisories for policy {}: {:?}",
                         policy_id,
                         e
                     );
-                    local_state.record_error(ocw::OcwError::AccuWeatherFetch, now_epoch);
+                    ocw::record_error_kind(e);
+                    local_state.record_error(ocw::OcwError::StormTrackFetch, now_epoch);
                     local_state.save(policy_id);
+                    return Ok(true);
+                }
+            };
+
+            // Tracks include fixes from before the coverage window
+            let new_fixes: Vec<_> = advisories
+                .into_iter()
+                .filter(|advisory| {
+                    advisory.epoch_time > local_state.last_seen_epoch
+                        && advisory.epoch_time >= meta.coverage_start
+                        && advisory.epoch_time <= meta.coverage_end
+                })
+                .collect();
+
+            if new_fixes.is_empty() {
+                if now_epoch > meta.coverage_end {
+                    log::info!(
+                        target: "prmx-oracle-v3",
+                        "✅ Submitting final MATURITY report for policy {} (no new storm fixes)",
+                        policy_id
+                    );
+                    Self::finalize_policy_ocw(
+                        policy_id,
+                        &mut local_state,
+                        OracleReportKindV3::Maturity,
+                        now_epoch,
+                    );
                 }
+                local_state.save(policy_id);
+                return Ok(true);
             }
-            
-            Ok(())
+
+            log::info!(
+                target: "prmx-oracle-v3",
+                "🌀 Processing {} new storm fixes for policy {}",
+                new_fixes.len(),
+                policy_id
+            );
+
+            for advisory in &new_fixes {
+                local_state.commitment =
+                    commitment::extend_storm_commitment(local_state.commitment, advisory);
+                local_state.agg_state = storm::update_cyclone_state(
+                    &local_state.agg_state,
+                    advisory,
+                    location.latitude,
+                    location.longitude,
+                    &threshold,
+                );
+                local_state.last_seen_epoch = advisory.epoch_time;
+            }
+            local_state.clear_error();
+
+            Self::submit_policy_report_ocw(policy_id, meta, &mut local_state, now_epoch);
+            Ok(true)
         }
-        
+
+        /// Decide on and submit the snapshot or final report for a policy whose
+        /// local state has just been updated, then save the state
+        fn submit_policy_report_ocw(
+            policy_id: PolicyId,
+            meta: &PolicyMetaV3,
+            local_state: &mut ocw::OcwPolicyState,
+            now_epoch: u64,
+        ) {
+            let observed_until = local_state.last_seen_epoch;
+
+            // Determine what on-chain action to take
+            let decision = ocw::decide_snapshot_action(
+                local_state,
+                &meta.event_spec,
+                now_epoch,
+                meta.coverage_start,
+                meta.coverage_end,
+            );
+
+            match decision {
+                ocw::SnapshotDecision::SendFinalTrigger => {
+                    log::info!(
+                        target: "prmx-oracle-v3",
+                        "🎯 Submitting final TRIGGER report for policy {}",
+                        policy_id
+                    );
+
+                    if Self::finalize_policy_ocw(
+                        policy_id,
+                        local_state,
+                        OracleReportKindV3::Trigger,
+                        now_epoch,
+                    ) {
+                        local_state.last_snapshot_epoch = observed_until;
+                    }
+                }
+                ocw::SnapshotDecision::SendFinalMaturity => {
+                    log::info!(
+                        target: "prmx-oracle-v3",
+                        "✅ Submitting final MATURITY report for policy {}",
+                        policy_id
+                    );
+
+                    if Self::finalize_policy_ocw(
+                        policy_id,
+                        local_state,
+                        OracleReportKindV3::Maturity,
+                        now_epoch,
+                    ) {
+                        local_state.last_snapshot_epoch = observed_until;
+                    }
+                }
+                ocw::SnapshotDecision::SendSnapshot => {
+                    log::info!(
+                        target: "prmx-oracle-v3",
+                        "📸 Submitting snapshot for policy {} (observed_until: {})",
+                        policy_id,
+                        observed_until
+                    );
+
+                    if let Err(e) = Self::submit_snapshot_on_chain(
+                        policy_id,
+                        observed_until,
+                        local_state.agg_state.clone(),
+                        local_state.commitment,
+                    ) {
+                        log::warn!(
+                            target: "prmx-oracle-v3",
+                            "Failed to submit snapshot: {:?}",
+                            e
+                        );
+                        ocw::record_error_kind(e);
+                        local_state.record_error(ocw::OcwError::ChainSubmission, now_epoch);
+                    } else {
+                        local_state.last_snapshot_epoch = observed_until;
+                        local_state.last_snapshot_sent_at = now_epoch;
+                    }
+                }
+                ocw::SnapshotDecision::None => {
+                    // No on-chain action needed
+                }
+            }
+
+            local_state.save(policy_id);
+        }
+
+        /// Send a committed observation batch to the Ingest API. Batches that fail, or
+        /// that would overtake batches still queued for this policy, go to the outbox.
+        fn send_ingest_batch_ocw(
+            policy_id: PolicyId,
+            local_state: &mut ocw::OcwPolicyState,
+            batch: outbox::PendingBatch,
+            last_epoch: u64,
+            now_epoch: u64,
+        ) {
+            let (Some(ingest_url), Some(hmac_secret)) =
+                (ocw::get_ingest_api_url(), ocw::get_hmac_secret())
+            else {
+                return;
+            };
+
+            if outbox::has_pending(policy_id) {
+                outbox::enqueue(policy_id, batch);
+            } else if let Err(e) = http_client::send_observations_batch(
+                &ingest_url,
+                &hmac_secret,
+                policy_id,
+                &batch.location_key,
+                &batch.observations,
+                &batch.sample_hashes,
+                batch.commitment_after,
+            ) {
+                log::warn!(
+                    target: "prmx-oracle-v3",
+                    "Failed to send observations to Ingest API, queued for retry: {:?}",
+                    e
+                );
+                ocw::record_error_kind(e);
+                outbox::enqueue(policy_id, batch);
+                local_state.record_error(ocw::OcwError::IngestApi, now_epoch);
+            } else {
+                local_state.last_observation_sent_epoch = last_epoch;
+                local_state.clear_error();
+            }
+        }
+
+        /// Submit a final report from the local OCW state. On success the policy is
+        /// finalized locally, its evidence queued for export and settlement webhooks
+        /// notified.
+        fn finalize_policy_ocw(
+            policy_id: PolicyId,
+            local_state: &mut ocw::OcwPolicyState,
+            kind: OracleReportKindV3,
+            now_epoch: u64,
+        ) -> bool {
+            if let Err(e) = Self::submit_final_report_on_chain(
+                policy_id,
+                kind,
+                local_state.last_seen_epoch,
+                local_state.agg_state.clone(),
+                local_state.commitment,
+            ) {
+                log::warn!(
+                    target: "prmx-oracle-v3",
+                    "Failed to submit {:?} report: {:?}",
+                    kind,
+                    e
+                );
+                ocw::record_error_kind(e);
+                local_state.record_error(ocw::OcwError::ChainSubmission, now_epoch);
+                false
+            } else {
+                local_state.finalized = true;
+                evidence::queue_export(policy_id);
+                notifier::notify_settlement(
+                    policy_id,
+                    kind,
+                    local_state.last_seen_epoch,
+                    &local_state.agg_state,
+                    local_state.commitment,
+                );
+                true
+            }
+        }
+
         /// Submit a snapshot to the chain via unsigned transaction
         fn submit_snapshot_on_chain(
             policy_id: PolicyId,
             observed_until: u64,
             agg_state: AggStateV3,
             commitment: [u8; 32],
-        ) -> Result<(), &'static str> {
-            use frame_system::offchain::SubmitTransaction;
-            
-            let call = Call::<T>::submit_snapshot_unsigned {
-                policy_id,
-                observed_until,
-                agg_state,
-                commitment,
-            };
-            
-            // Create a bare (unsigned) extrinsic and submit it
-            let xt = T::create_bare(call.into());
-            SubmitTransaction::<T, Call<T>>::submit_transaction(xt)
-                .map_err(|_| "Failed to submit unsigned snapshot transaction")
+        ) -> Result<(), OcwErrorKind> {
+            Self::submit_ocw_payload(
+                SnapshotDataV3 { policy_id, observed_until, agg_state, commitment },
+                |payload, signature| Call::submit_snapshot_unsigned { payload, signature },
+            )
         }
         
         /// Submit a final report to the chain via unsigned transaction
@@ -1273,21 +3252,184 @@ pub mod pallet {
             observed_until: u64,
             agg_state: AggStateV3,
             commitment: [u8; 32],
-        ) -> Result<(), &'static str> {
-            use frame_system::offchain::SubmitTransaction;
-            
-            let call = Call::<T>::submit_final_report_unsigned {
+        ) -> Result<(), OcwErrorKind> {
+            Self::submit_ocw_payload(
+                FinalReportDataV3 { policy_id, kind, observed_until, agg_state, commitment },
+                |payload, signature| Call::submit_final_report_unsigned { payload, signature },
+            )
+        }
+        
+        /// Report a high Ingest API backlog to the chain via unsigned transaction
+        fn submit_ingest_backlog_on_chain(
+            report: outbox::BacklogReport,
+        ) -> Result<(), OcwErrorKind> {
+            let outbox::BacklogReport { policy_id, pending_batches, pending_samples, oldest_queued_at } =
+                report;
+            Self::submit_ocw_payload(
+                IngestBacklogDataV3 { policy_id, pending_batches, pending_samples, oldest_queued_at },
+                |payload, signature| Call::report_ingest_backlog_unsigned { payload, signature },
+            )
+        }
+        
+        /// Submit an exhausted AccuWeather daily budget via unsigned transaction
+        fn submit_api_budget_exhausted_on_chain(
+            usage: &fetch_quota::DailyUsage,
+            budget: u32,
+        ) -> Result<(), OcwErrorKind> {
+            Self::submit_ocw_payload(
+                ApiBudgetDataV3 { day: usage.day, calls: usage.calls, budget },
+                |payload, signature| Call::report_api_budget_exhausted_unsigned {
+                    payload,
+                    signature,
+                },
+            )
+        }
+
+        /// Report samples rejected by a policy's outlier rules via unsigned transaction,
+        /// in chunks of `MAX_REJECTED_PER_REPORT`
+        fn report_rejected_observations_ocw(
+            policy_id: PolicyId,
+            location_id: LocationId,
+            rejected: Vec<RejectedObservationV3>,
+        ) {
+            log::warn!(
+                target: "prmx-oracle-v3",
+                "🚫 {} observation(s) for policy {} (location {}) rejected as outliers",
+                rejected.len(),
                 policy_id,
-                kind,
-                observed_until,
-                agg_state,
-                commitment,
+                location_id
+            );
+
+            for chunk in rejected.chunks(MAX_REJECTED_PER_REPORT as usize) {
+                let data = RejectedObservationsDataV3 {
+                    policy_id,
+                    location_id,
+                    rejected: BoundedVec::truncate_from(chunk.to_vec()),
+                };
+                if let Err(e) = Self::submit_ocw_payload(data, |payload, signature| {
+                    Call::report_rejected_observations_unsigned { payload, signature }
+                }) {
+                    log::warn!(
+                        target: "prmx-oracle-v3",
+                        "Failed to report rejected observations for policy {}: {:?}",
+                        policy_id,
+                        e
+                    );
+                    ocw::record_error_kind(e);
+                }
+            }
+        }
+
+        /// Fetch AccuWeather location details for up to `MAX_METADATA_RESOLUTIONS_PER_RUN`
+        /// active locations with unset metadata and submit what resolves. Each location is
+        /// fetched once per node; the calls count against the daily budget.
+        fn resolve_location_metadata(now: u64) {
+            let pending: Vec<LocationInfo<T>> = LocationRegistry::<T>::iter_values()
+                .filter(|location| {
+                    let unset =
+                        location.timezone_offset_mins.is_none() || location.elevation_m.is_none();
+                    location.active && unset && !location_meta::is_resolved(location.location_id)
+                })
+                .take(location_meta::MAX_METADATA_RESOLUTIONS_PER_RUN as usize)
+                .collect();
+            if pending.is_empty() {
+                return;
+            }
+
+            let Some(api_key) = ocw::get_accuweather_api_key() else {
+                return;
             };
+            let endpoints = ocw::get_accuweather_endpoints();
+            let daily_budget = fetch_quota::daily_fetch_budget();
+            let mut usage = fetch_quota::DailyUsage::load(now);
+
+            for location in pending {
+                // Observations take priority over metadata
+                if usage.in_priority_reserve(daily_budget) {
+                    break;
+                }
+                let location_id = location.location_id;
+                usage.record(1);
+                let details = http_client::fetch_location_details(
+                    location.accuweather_key.as_slice(),
+                    &api_key,
+                    &endpoints,
+                );
+                let result = details.and_then(|(timezone_offset_mins, elevation_m)| {
+                    Self::submit_ocw_payload(
+                        LocationMetadataDataV3 { location_id, timezone_offset_mins, elevation_m },
+                        |payload, signature| Call::submit_location_metadata_unsigned {
+                            payload,
+                            signature,
+                        },
+                    )
+                });
+                match result {
+                    Ok(()) => location_meta::mark_resolved(location_id),
+                    Err(e) => {
+                        log::warn!(
+                            target: "prmx-oracle-v3",
+                            "Failed to resolve metadata for location {}: {:?}",
+                            location_id,
+                            e
+                        );
+                        ocw::record_error_kind(e);
+                        // Details AccuWeather cannot parse or serve will not improve
+                        let permanent = match e {
+                            OcwErrorKind::Parse => true,
+                            OcwErrorKind::Http(_) => !e.is_retryable(),
+                            _ => false,
+                        };
+                        if permanent {
+                            location_meta::mark_resolved(location_id);
+                        }
+                    }
+                }
+            }
+            usage.save();
+        }
+
+        /// Sign `data` with the local OCW key and submit it as an unsigned transaction.
+        /// The key's account must be an oracle member for the pool to accept it.
+        fn submit_ocw_payload<Data: Encode + Clone>(
+            data: Data,
+            build_call: impl Fn(OcwPayloadV3<T::Public, Data>, T::Signature) -> Call<T>,
+        ) -> Result<(), OcwErrorKind> {
+            use frame_system::offchain::{SendUnsignedTransaction, Signer};
+            
+            let signer = Signer::<T, T::AuthorityId>::any_account();
+            if !signer.can_sign() {
+                return Err(OcwErrorKind::Keystore);
+            }
+            
+            let block: u64 = frame_system::Pallet::<T>::block_number().unique_saturated_into();
+            let allocated = core::cell::Cell::new(None);
+            
+            let (_, result) = signer
+                .send_unsigned_transaction(
+                    |account| {
+                        let account_key = account.id.encode();
+                        let nonce = ocw::OcwNonceCursor::allocate(
+                            &account_key,
+                            OcwNonces::<T>::get(&account.id),
+                            block,
+                            OCW_UNSIGNED_LONGEVITY,
+                        );
+                        allocated.set(Some((account_key, nonce)));
+                        OcwPayloadV3 { data: data.clone(), nonce, public: account.public.clone() }
+                    },
+                    build_call,
+                )
+                .ok_or(OcwErrorKind::Keystore)?;
             
-            // Create a bare (unsigned) extrinsic and submit it
-            let xt = T::create_bare(call.into());
-            SubmitTransaction::<T, Call<T>>::submit_transaction(xt)
-                .map_err(|_| "Failed to submit unsigned final report transaction")
+            if result.is_err() {
+                if let Some((account_key, nonce)) = allocated.take() {
+                    ocw::OcwNonceCursor::release(&account_key, nonce);
+                }
+                return Err(OcwErrorKind::Submission);
+            }
+            
+            Ok(())
         }
         
         /// Process expired requests and submit expiry transactions
@@ -1312,10 +3454,11 @@ pub mod pallet {
                 if let Err(e) = Self::submit_request_expiry_on_chain(request_id) {
                     log::warn!(
                         target: "prmx-oracle-v3",
-                        "❌ Failed to submit expiry for request {}: {}",
+                        "❌ Failed to submit expiry for request {}: {:?}",
                         request_id,
                         e
                     );
+                    ocw::record_error_kind(e);
                 } else {
                     log::info!(
                         target: "prmx-oracle-v3",
@@ -1325,13 +3468,40 @@ pub mod pallet {
                 }
             }
         }
+
+        /// Process RFQs past their acceptance window and submit expiry transactions
+        fn process_expired_rfqs(now_epoch: u64) {
+            let expired_rfqs = T::RequestExpiryApi::get_expired_rfqs(now_epoch);
+
+            if expired_rfqs.is_empty() {
+                return;
+            }
+
+            log::info!(
+                target: "prmx-oracle-v3",
+                "⏰ Processing {} expired V3 RFQs",
+                expired_rfqs.len()
+            );
+
+            for rfq_id in expired_rfqs {
+                if let Err(e) = Self::submit_ocw_payload(rfq_id, |payload, signature| {
+                    Call::expire_rfq_unsigned { payload, signature }
+                }) {
+                    log::warn!(
+                        target: "prmx-oracle-v3",
+                        "❌ Failed to submit expiry for RFQ {}: {:?}",
+                        rfq_id,
+                        e
+                    );
+                    ocw::record_error_kind(e);
+                }
+            }
+        }
         
         /// Submit a request expiry to the chain via unsigned transaction
         /// Note: This calls into the market-v3 pallet
         /// Submit a request expiry to the chain via unsigned transaction
-        fn submit_request_expiry_on_chain(request_id: PolicyId) -> Result<(), &'static str> {
-            use frame_system::offchain::SubmitTransaction;
-            
+        fn submit_request_expiry_on_chain(request_id: PolicyId) -> Result<(), OcwErrorKind> {
             log::info!(
                 target: "prmx-oracle-v3",
                 "📤 Submitting request {} expiry via unsigned transaction",
@@ -1340,30 +3510,13 @@ pub mod pallet {
             
             // Create the call to our own pallet's expire_request_unsigned
             // which will then delegate to market-v3 via trait
-            let call = Call::<T>::expire_request_unsigned { request_id };
-            
-            // Create a bare (unsigned) extrinsic and submit it
-            let xt = T::create_bare(call.into());
-            SubmitTransaction::<T, Call<T>>::submit_transaction(xt)
-                .map_err(|_| "Failed to submit unsigned expiry transaction")
-        }
-        
-        /// Get the location ID for a policy
-        fn get_policy_location_id(policy_id: PolicyId) -> Result<LocationId, &'static str> {
-            PolicyMetadata::<T>::get(policy_id)
-                .map(|(location_id, _, _, _)| location_id)
-                .ok_or("Policy metadata not found")
-        }
-        
-        /// Get the event type for a policy
-        fn get_policy_event_type(policy_id: PolicyId) -> Result<EventTypeV3, &'static str> {
-            PolicyMetadata::<T>::get(policy_id)
-                .map(|(_, event_spec, _, _)| event_spec.event_type)
-                .ok_or("Policy metadata not found")
+            Self::submit_ocw_payload(request_id, |payload, signature| {
+                Call::expire_request_unsigned { payload, signature }
+            })
         }
         
-        /// Get full policy metadata (location_id, event_spec, coverage_start, coverage_end)
-        pub fn get_policy_metadata(policy_id: PolicyId) -> Option<(LocationId, EventSpecV3, u64, u64)> {
+        /// Get full policy metadata
+        pub fn get_policy_metadata(policy_id: PolicyId) -> Option<PolicyMetaV3> {
             PolicyMetadata::<T>::get(policy_id)
         }
     }
@@ -1380,6 +3533,9 @@ pub trait LocationRegistryApi {
 
     /// Get location coordinates
     fn get_location_coordinates(location_id: LocationId) -> Option<(i32, i32)>;
+
+    /// Nearest active location to coordinates scaled by 1e6 (neighbouring geohash cells only)
+    fn find_nearest_location(latitude: i32, longitude: i32) -> Option<LocationId>;
 }
 
 impl<T: Config> LocationRegistryApi for Pallet<T> {
@@ -1390,5 +3546,9 @@ impl<T: Config> LocationRegistryApi for Pallet<T> {
     fn get_location_coordinates(location_id: LocationId) -> Option<(i32, i32)> {
         pallet::LocationRegistry::<T>::get(location_id).map(|l| (l.latitude, l.longitude))
     }
+
+    fn find_nearest_location(latitude: i32, longitude: i32) -> Option<LocationId> {
+        Pallet::<T>::find_nearest_location(latitude, longitude)
+    }
 }
 
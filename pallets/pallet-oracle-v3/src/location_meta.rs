@@ -0,0 +1,148 @@
+//! # Location Metadata
+//!
+//! Optional metadata on `LocationInfo`: UTC offset of local time, elevation and
+//! a preferred observation station. Governance sets it with
+//! `set_location_metadata`; otherwise the OCW resolves the timezone and
+//! elevation once per location from AccuWeather's location details and submits
+//! them with `submit_location_metadata_unsigned`, which only fills fields that
+//! are still unset.
+//!
+//! The timezone offset lets products align windows to local midnight
+//! (`local_day_start`). A preferred station's AccuWeather key is fetched instead
+//! of the location's own key.
+
+use alloc::vec::Vec;
+use codec::Encode;
+use frame_support::sp_runtime::offchain::storage::StorageValueRef;
+
+use crate::LocationId;
+
+/// Earliest UTC offset of local time in minutes (UTC-12:00)
+pub const MIN_TIMEZONE_OFFSET_MINS: i16 = -12 * 60;
+
+/// Latest UTC offset of local time in minutes (UTC+14:00)
+pub const MAX_TIMEZONE_OFFSET_MINS: i16 = 14 * 60;
+
+/// Lowest accepted elevation in meters (below the Dead Sea shore)
+pub const MIN_ELEVATION_M: i16 = -500;
+
+/// Highest accepted elevation in meters (above Everest)
+pub const MAX_ELEVATION_M: i16 = 9_000;
+
+/// Locations the OCW resolves metadata for per run
+pub const MAX_METADATA_RESOLUTIONS_PER_RUN: u32 = 2;
+
+/// Prefix for the OCW's resolved-location markers (followed by the location ID)
+pub const METADATA_RESOLVED_PREFIX: &[u8] = b"ocw:v3:location_meta_resolved:";
+
+/// Seconds in a day
+const DAY_SECS: i64 = 86_400;
+
+/// Whether `offset_mins` is a real-world UTC offset
+pub fn is_valid_timezone_offset(offset_mins: i16) -> bool {
+    (MIN_TIMEZONE_OFFSET_MINS..=MAX_TIMEZONE_OFFSET_MINS).contains(&offset_mins)
+}
+
+/// Whether `elevation_m` is a plausible land elevation
+pub fn is_valid_elevation(elevation_m: i16) -> bool {
+    (MIN_ELEVATION_M..=MAX_ELEVATION_M).contains(&elevation_m)
+}
+
+/// Start (unix seconds) of the local day containing `timestamp`, for a location
+/// `offset_mins` ahead of UTC
+pub fn local_day_start(timestamp: u64, offset_mins: i16) -> u64 {
+    let offset_secs = offset_mins as i64 * 60;
+    let local = timestamp as i64 + offset_secs;
+    let start = local - local.rem_euclid(DAY_SECS) - offset_secs;
+    start.max(0) as u64
+}
+
+/// UTC offset in minutes from AccuWeather's `GmtOffset` in hours (e.g. 5.5),
+/// if it is a real-world offset
+pub fn offset_mins_from_hours(hours: f64) -> Option<i16> {
+    if !hours.is_finite() {
+        return None;
+    }
+    // Manual rounding: f64::round is not available in no_std
+    let mins = hours * 60.0;
+    let mins = (if mins < 0.0 { mins - 0.5 } else { mins + 0.5 }) as i32;
+    i16::try_from(mins)
+        .ok()
+        .filter(|m| is_valid_timezone_offset(*m))
+}
+
+/// Elevation in whole meters from AccuWeather's metric value, if plausible
+pub fn elevation_from_meters(meters: f64) -> Option<i16> {
+    if !meters.is_finite() {
+        return None;
+    }
+    let rounded = if meters < 0.0 {
+        meters - 0.5
+    } else {
+        meters + 0.5
+    };
+    i16::try_from(rounded as i32)
+        .ok()
+        .filter(|m| is_valid_elevation(*m))
+}
+
+fn resolved_key(location_id: LocationId) -> Vec<u8> {
+    let mut key = METADATA_RESOLVED_PREFIX.to_vec();
+    key.extend_from_slice(&location_id.encode());
+    key
+}
+
+/// Whether this node's OCW already resolved `location_id`
+pub fn is_resolved(location_id: LocationId) -> bool {
+    StorageValueRef::persistent(&resolved_key(location_id))
+        .get::<bool>()
+        .ok()
+        .flatten()
+        .unwrap_or(false)
+}
+
+/// Mark `location_id` resolved, so the OCW does not fetch its details again
+pub fn mark_resolved(location_id: LocationId) {
+    StorageValueRef::persistent(&resolved_key(location_id)).set(&true);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timezone_and_elevation_bounds() {
+        assert!(is_valid_timezone_offset(480));
+        assert!(is_valid_timezone_offset(MIN_TIMEZONE_OFFSET_MINS));
+        assert!(!is_valid_timezone_offset(MAX_TIMEZONE_OFFSET_MINS + 1));
+        assert!(is_valid_elevation(-28));
+        assert!(!is_valid_elevation(MAX_ELEVATION_M + 1));
+    }
+
+    #[test]
+    fn test_local_day_start() {
+        // 2024-06-01 20:00 UTC is 2024-06-02 04:00 in Manila (UTC+8), whose day
+        // started at 2024-06-01 16:00 UTC
+        let ts = 1_717_272_000;
+        assert_eq!(local_day_start(ts, 480), 1_717_257_600);
+        assert_eq!(local_day_start(ts, 0), 1_717_200_000);
+
+        // Behind UTC: 2024-06-01 16:00 in New York (UTC-4), from 04:00 UTC
+        assert_eq!(local_day_start(ts, -240), 1_717_214_400);
+
+        // Local midnight itself
+        assert_eq!(local_day_start(1_717_257_600, 480), 1_717_257_600);
+    }
+
+    #[test]
+    fn test_conversions_from_accuweather_values() {
+        assert_eq!(offset_mins_from_hours(8.0), Some(480));
+        assert_eq!(offset_mins_from_hours(5.5), Some(330));
+        assert_eq!(offset_mins_from_hours(-3.5), Some(-210));
+        assert_eq!(offset_mins_from_hours(20.0), None);
+        assert_eq!(offset_mins_from_hours(f64::NAN), None);
+        assert_eq!(elevation_from_meters(13.4), Some(13));
+        assert_eq!(elevation_from_meters(-27.6), Some(-28));
+        assert_eq!(elevation_from_meters(40_000.0), None);
+    }
+}
@@ -0,0 +1,244 @@
+//! # Storage Migrations
+//!
+//! Versioned migrations for `pallet_oracle_v3`, run from the runtime's
+//! `SingleBlockMigrations`. Each one only runs when the on-chain storage version
+//! matches its source version, so it is a no-op on chains started from a
+//! genesis that already has the new layout.
+//!
+//! - `MigrateV0ToV1`: `PolicyMetadata` from the `(location_id, event_spec,
+//!   coverage_start, coverage_end)` tuple to `PolicyMetaV3`
+//! - `MigrateV1ToV2`: `PolicyMetaV3` gains `area`; existing policies are
+//!   single-location (`None`)
+//! - `MigrateV2ToV3`: `LocationInfo` gains `timezone_offset_mins`, `elevation_m`
+//!   and `station_id`; existing locations start without metadata (`None`)
+
+use crate::{
+    Config, LocationId, LocationInfo, LocationRegistry, Pallet, PolicyMetaV3, PolicyMetadata,
+};
+use frame_support::{
+    migrations::VersionedMigration,
+    traits::{Get, UncheckedOnRuntimeUpgrade},
+    weights::Weight,
+};
+use prmx_primitives::EventSpecV3;
+
+#[cfg(feature = "try-runtime")]
+use alloc::vec::Vec;
+#[cfg(feature = "try-runtime")]
+use codec::{Decode, Encode};
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+/// Storage layouts before version 1
+pub mod v0 {
+    use super::*;
+
+    /// `PolicyMetadata` value: (location_id, event_spec, coverage_start, coverage_end)
+    pub type PolicyMetadataV0 = (LocationId, EventSpecV3, u64, u64);
+}
+
+/// Storage layouts before version 2
+pub mod v1 {
+    use super::*;
+    use codec::{Decode, Encode};
+
+    /// `PolicyMetadata` value before area policies
+    #[derive(Encode, Decode)]
+    pub struct PolicyMetaV1 {
+        pub location_id: LocationId,
+        pub event_spec: EventSpecV3,
+        pub coverage_start: u64,
+        pub coverage_end: u64,
+    }
+}
+
+/// Storage layouts before version 3
+pub mod v2 {
+    use super::*;
+    use codec::{Decode, Encode};
+    use frame_support::{traits::ConstU32, BoundedVec};
+
+    /// `LocationRegistry` value before location metadata
+    #[derive(Encode, Decode)]
+    pub struct LocationInfoV2<T: Config> {
+        pub location_id: LocationId,
+        pub accuweather_key: BoundedVec<u8, T::MaxLocationKeyLength>,
+        pub latitude: i32,
+        pub longitude: i32,
+        pub name: BoundedVec<u8, ConstU32<64>>,
+        pub active: bool,
+    }
+}
+
+/// Translate every `PolicyMetadata` entry to `PolicyMetaV3`. Use through
+/// `MigrateV0ToV1`, which checks and bumps the storage version.
+pub struct UncheckedMigrateV0ToV1<T>(core::marker::PhantomData<T>);
+
+impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV0ToV1<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let mut translated = 0u64;
+
+        PolicyMetadata::<T>::translate::<v0::PolicyMetadataV0, _>(
+            |_, (location_id, event_spec, coverage_start, coverage_end)| {
+                translated += 1;
+                Some(PolicyMetaV3 {
+                    location_id,
+                    event_spec,
+                    coverage_start,
+                    coverage_end,
+                    area: None,
+                })
+            },
+        );
+
+        log::info!(
+            target: "prmx-oracle-v3",
+            "Migrated {} policy metadata entries to storage version 1",
+            translated
+        );
+
+        T::DbWeight::get().reads_writes(translated, translated)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+        Ok((PolicyMetadata::<T>::iter_keys().count() as u64).encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+        let before = u64::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+        // `iter` decodes every value, so this also checks the new layout
+        let after = PolicyMetadata::<T>::iter().count() as u64;
+        frame_support::ensure!(after == before, "policy metadata lost in migration");
+        Ok(())
+    }
+}
+
+/// `PolicyMetadata` tuple to `PolicyMetaV3`, storage version 0 to 1
+pub type MigrateV0ToV1<T> = VersionedMigration<
+    0,
+    1,
+    UncheckedMigrateV0ToV1<T>,
+    Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+>;
+
+/// Translate every `PolicyMetadata` entry to the layout with `area`. Use
+/// through `MigrateV1ToV2`, which checks and bumps the storage version.
+pub struct UncheckedMigrateV1ToV2<T>(core::marker::PhantomData<T>);
+
+impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV1ToV2<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let mut translated = 0u64;
+
+        PolicyMetadata::<T>::translate::<v1::PolicyMetaV1, _>(|_, meta| {
+            translated += 1;
+            Some(PolicyMetaV3 {
+                location_id: meta.location_id,
+                event_spec: meta.event_spec,
+                coverage_start: meta.coverage_start,
+                coverage_end: meta.coverage_end,
+                area: None,
+            })
+        });
+
+        log::info!(
+            target: "prmx-oracle-v3",
+            "Migrated {} policy metadata entries to storage version 2",
+            translated
+        );
+
+        T::DbWeight::get().reads_writes(translated, translated)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+        Ok((PolicyMetadata::<T>::iter_keys().count() as u64).encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+        let before = u64::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+        let mut after = 0u64;
+        for (_, meta) in PolicyMetadata::<T>::iter() {
+            frame_support::ensure!(meta.area.is_none(), "area set by migration");
+            after += 1;
+        }
+        frame_support::ensure!(after == before, "policy metadata lost in migration");
+        Ok(())
+    }
+}
+
+/// `PolicyMetaV3` with `area`, storage version 1 to 2
+pub type MigrateV1ToV2<T> = VersionedMigration<
+    1,
+    2,
+    UncheckedMigrateV1ToV2<T>,
+    Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+>;
+
+/// Translate every `LocationRegistry` entry to the layout with location metadata.
+/// Use through `MigrateV2ToV3`, which checks and bumps the storage version.
+pub struct UncheckedMigrateV2ToV3<T>(core::marker::PhantomData<T>);
+
+impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV2ToV3<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let mut translated = 0u64;
+
+        LocationRegistry::<T>::translate::<v2::LocationInfoV2<T>, _>(|_, location| {
+            translated += 1;
+            Some(LocationInfo {
+                location_id: location.location_id,
+                accuweather_key: location.accuweather_key,
+                latitude: location.latitude,
+                longitude: location.longitude,
+                name: location.name,
+                active: location.active,
+                timezone_offset_mins: None,
+                elevation_m: None,
+                station_id: None,
+            })
+        });
+
+        log::info!(
+            target: "prmx-oracle-v3",
+            "Migrated {} registry locations to storage version 3",
+            translated
+        );
+
+        T::DbWeight::get().reads_writes(translated, translated)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+        Ok((LocationRegistry::<T>::iter_keys().count() as u64).encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+        let before = u64::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+        let mut after = 0u64;
+        for (_, location) in LocationRegistry::<T>::iter() {
+            frame_support::ensure!(
+                location.timezone_offset_mins.is_none()
+                    && location.elevation_m.is_none()
+                    && location.station_id.is_none(),
+                "location metadata set by migration"
+            );
+            after += 1;
+        }
+        frame_support::ensure!(after == before, "registry locations lost in migration");
+        Ok(())
+    }
+}
+
+/// `LocationInfo` with timezone, elevation and station, storage version 2 to 3
+pub type MigrateV2ToV3<T> = VersionedMigration<
+    2,
+    3,
+    UncheckedMigrateV2ToV3<T>,
+    Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+>;
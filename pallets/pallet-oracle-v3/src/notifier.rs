@@ -0,0 +1,178 @@
+//! # Settlement Notifier for OCW
+//!
+//! After the OCW submits a trigger or maturity report, it POSTs a settlement
+//! notice to every webhook URL configured under `SETTLEMENT_WEBHOOKS_KEY`, so
+//! claim processing systems learn of settlements without following chain events.
+//!
+//! Notices are signed like Ingest API requests: the `X-HMAC-Signature`,
+//! `X-Timestamp` and `X-Nonce` headers use the Ingest HMAC secret. Delivery is
+//! best effort; a failed webhook is logged and not retried, and receivers should
+//! treat the on-chain report (matched by `report_hash`) as authoritative.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use codec::Encode;
+use prmx_primitives::{AggStateV3, OracleReportKindV3, PolicyId};
+
+use crate::commitment;
+use crate::http_client::{hex_encode, post_signed_json};
+use crate::ocw;
+
+/// Most webhooks notified per settlement; each POST can block the OCW for the
+/// HTTP timeout
+pub const MAX_SETTLEMENT_WEBHOOKS: usize = 4;
+
+/// Configured webhook URLs without empty or repeated entries, capped at
+/// `MAX_SETTLEMENT_WEBHOOKS`
+pub fn settlement_webhooks(configured: Vec<Vec<u8>>) -> Vec<Vec<u8>> {
+    let mut webhooks: Vec<Vec<u8>> = Vec::new();
+    for url in configured {
+        if webhooks.len() == MAX_SETTLEMENT_WEBHOOKS {
+            break;
+        }
+        if !url.is_empty() && !webhooks.contains(&url) {
+            webhooks.push(url);
+        }
+    }
+    webhooks
+}
+
+/// Notify every configured webhook of a submitted final report.
+/// Returns how many webhooks accepted the notice.
+pub fn notify_settlement(
+    policy_id: PolicyId,
+    kind: OracleReportKindV3,
+    observed_until: u64,
+    agg_state: &AggStateV3,
+    commitment: [u8; 32],
+) -> usize {
+    let webhooks = ocw::get_settlement_webhooks();
+    if webhooks.is_empty() {
+        return 0;
+    }
+
+    let Some(hmac_secret) = ocw::get_hmac_secret() else {
+        log::warn!(
+            target: "prmx-oracle-v3",
+            "Settlement webhooks configured without an HMAC secret; not notifying"
+        );
+        return 0;
+    };
+
+    let payload = build_settlement_json(policy_id, kind, observed_until, agg_state, commitment);
+    let mut delivered = 0;
+
+    for url in webhooks {
+        let Ok(url) = core::str::from_utf8(&url) else {
+            log::warn!(target: "prmx-oracle-v3", "Invalid settlement webhook URL encoding");
+            continue;
+        };
+
+        match post_signed_json(url, &hmac_secret, &payload) {
+            Ok(()) => delivered += 1,
+            Err(e) => {
+                log::warn!(
+                    target: "prmx-oracle-v3",
+                    "Failed to notify {} of policy {} settlement: {:?}",
+                    url,
+                    policy_id,
+                    e
+                );
+                crate::ocw::record_error_kind(e);
+            }
+        }
+    }
+
+    log::info!(
+        target: "prmx-oracle-v3",
+        "🔔 Notified {} settlement webhook(s) for policy {}",
+        delivered,
+        policy_id
+    );
+
+    delivered
+}
+
+/// Build JSON payload for a settlement notice
+pub fn build_settlement_json(
+    policy_id: PolicyId,
+    kind: OracleReportKindV3,
+    observed_until: u64,
+    agg_state: &AggStateV3,
+    commitment: [u8; 32],
+) -> String {
+    let report_hash = commitment::compute_final_report_hash(
+        policy_id,
+        kind,
+        observed_until,
+        agg_state,
+        &commitment,
+    );
+    let kind = match kind {
+        OracleReportKindV3::Trigger => "trigger",
+        OracleReportKindV3::Maturity => "maturity",
+    };
+
+    format!(
+        r#"{{"event":"settlement","policy_id":"{}","kind":"{}","observed_until":{},"agg_state":"{}","commitment":"{}","report_hash":"{}"}}"#,
+        policy_id,
+        kind,
+        observed_until,
+        hex_encode(&agg_state.encode()),
+        hex_encode(&commitment),
+        hex_encode(&report_hash)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_settlement_webhooks() {
+        let configured = alloc::vec![
+            b"https://a.example/hook".to_vec(),
+            Vec::new(),
+            b"https://b.example/hook".to_vec(),
+            b"https://a.example/hook".to_vec(),
+            b"https://c.example/hook".to_vec(),
+            b"https://d.example/hook".to_vec(),
+            b"https://e.example/hook".to_vec(),
+        ];
+        let webhooks = settlement_webhooks(configured);
+
+        assert_eq!(webhooks.len(), MAX_SETTLEMENT_WEBHOOKS);
+        assert_eq!(webhooks[0], b"https://a.example/hook".to_vec());
+        assert_eq!(webhooks[1], b"https://b.example/hook".to_vec());
+        assert_eq!(webhooks[3], b"https://d.example/hook".to_vec());
+    }
+
+    #[test]
+    fn test_build_settlement_json() {
+        let policy_id = PolicyId::from([0x07; 16]);
+        let agg_state = AggStateV3::PrecipSum { sum_mm_x1000: 60_000 };
+        let json = build_settlement_json(
+            policy_id,
+            OracleReportKindV3::Trigger,
+            1_000,
+            &agg_state,
+            [0xab; 32],
+        );
+        let report_hash = commitment::compute_final_report_hash(
+            policy_id,
+            OracleReportKindV3::Trigger,
+            1_000,
+            &agg_state,
+            &[0xab; 32],
+        );
+
+        assert!(json.starts_with(&format!(
+            r#"{{"event":"settlement","policy_id":"0x{}","kind":"trigger","#,
+            "07".repeat(16)
+        )));
+        assert!(json.contains(r#""observed_until":1000,"#));
+        assert!(json.contains(&format!(r#""commitment":"{}""#, "ab".repeat(32))));
+        assert!(json.contains(&format!(r#""report_hash":"{}""#, hex_encode(&report_hash))));
+    }
+}
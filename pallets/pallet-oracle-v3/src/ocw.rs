@@ -6,17 +6,21 @@
 //!
 //! - Poll active policies from on-chain storage
 //! - Fetch AccuWeather historical/24 data
-//! - Incrementally aggregate observations
+//! - Incrementally aggregate observations, per location for area policies
 //! - Maintain commitment hash chain
 //! - Send observations to Ingest API
 //! - Submit on-chain snapshots and final reports
+//! - Hand out nonces for signed unsigned-transaction payloads
+//! - Count failures per `OcwErrorKind` for diagnostics
 
 use alloc::vec::Vec;
 use codec::{Decode, Encode};
 use frame_support::sp_runtime::offchain::storage::StorageValueRef;
+use frame_support::sp_runtime::offchain::storage::StorageRetrievalError;
 use prmx_primitives::{
-    AggStateV3, EventSpecV3, PolicyId, PolicyOracleStateV3, PolicyStatusV3,
-    V3_SNAPSHOT_INTERVAL_FINAL_SECS, V3_SNAPSHOT_INTERVAL_SECS,
+    AggStateV3, AreaSpecV3, EventSpecV3, OcwErrorCounts, OcwErrorKind, PolicyId,
+    PolicyOracleStateV3, PolicyStatusV3, V3_SNAPSHOT_INTERVAL_FINAL_SECS,
+    V3_SNAPSHOT_INTERVAL_SECS,
 };
 
 // ============================================================================
@@ -35,6 +39,24 @@ pub const ACCUWEATHER_API_KEY: &[u8] = b"ocw:v3:accuweather_api_key";
 /// Key for Ingest API URL
 pub const INGEST_API_URL_KEY: &[u8] = b"ocw:v3:ingest_api_url";
 
+/// Key for AccuWeather mirror base URLs (SCALE `Vec<Vec<u8>>`), tried in order
+/// after the public host
+pub const ACCUWEATHER_MIRRORS_KEY: &[u8] = b"ocw:v3:accuweather_mirrors";
+
+/// Prefix for per-signer nonce cursors
+pub const OCW_NONCE_PREFIX: &[u8] = b"ocw:v3:nonce:";
+
+/// Key for settlement webhook URLs (SCALE `Vec<Vec<u8>>`), notified after each
+/// final report
+pub const SETTLEMENT_WEBHOOKS_KEY: &[u8] = b"ocw:v3:settlement_webhooks";
+
+/// Key for the storm-track API base URL that cyclone policies are fetched from
+pub const STORM_TRACK_API_URL_KEY: &[u8] = b"ocw:v3:storm_track_api_url";
+
+/// Key for the OCW failures counted per kind (SCALE `OcwErrorCounts`), read by
+/// operators through the offchain storage RPC
+pub const OCW_ERROR_COUNTS_KEY: &[u8] = b"ocw:v3:error_counts";
+
 // ============================================================================
 // OCW Policy State
 // ============================================================================
@@ -93,6 +115,7 @@ pub enum OcwError {
     IngestApi,
     ChainSubmission,
     ParseError,
+    StormTrackFetch,
 }
 
 impl OcwPolicyState {
@@ -179,6 +202,70 @@ impl OcwPolicyState {
     }
 }
 
+// ============================================================================
+// OCW Area State
+// ============================================================================
+
+/// Cursor and aggregation of one location of a policy
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode)]
+pub struct OcwLocationState {
+    /// Location ID
+    pub location_id: u64,
+    /// Last seen observation epoch time at this location
+    pub last_seen_epoch: u64,
+    /// Aggregation of this location's observations
+    pub agg_state: AggStateV3,
+}
+
+/// Local state of an area policy's locations, stored next to its `OcwPolicyState`,
+/// which keeps the combined aggregation and the shared commitment chain
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, Default)]
+pub struct OcwAreaState {
+    /// Locations in `AreaSpecV3` order
+    pub locations: Vec<OcwLocationState>,
+}
+
+impl OcwAreaState {
+    /// Generate storage key for a policy
+    pub fn storage_key(policy_id: PolicyId) -> Vec<u8> {
+        let mut key = OCW_V3_PREFIX.to_vec();
+        key.extend_from_slice(b"policy:");
+        key.extend_from_slice(&policy_id.to_le_bytes());
+        key.extend_from_slice(b":area");
+        key
+    }
+
+    /// Load state from offchain storage
+    pub fn load(policy_id: PolicyId) -> Option<Self> {
+        let key = Self::storage_key(policy_id);
+        let storage = StorageValueRef::persistent(&key);
+        storage.get::<Self>().ok().flatten()
+    }
+
+    /// Save state to offchain storage
+    pub fn save(&self, policy_id: PolicyId) {
+        let key = Self::storage_key(policy_id);
+        let storage = StorageValueRef::persistent(&key);
+        storage.set(self);
+    }
+
+    /// Initialize from on-chain oracle state. Per-location values are not kept
+    /// on chain, so every location resumes from the combined state.
+    pub fn from_on_chain_state(area: &AreaSpecV3, state: &PolicyOracleStateV3) -> Self {
+        Self {
+            locations: area
+                .locations
+                .iter()
+                .map(|location_id| OcwLocationState {
+                    location_id: *location_id,
+                    last_seen_epoch: state.observed_until,
+                    agg_state: state.agg_state.clone(),
+                })
+                .collect(),
+        }
+    }
+}
+
 // ============================================================================
 // Snapshot Scheduling
 // ============================================================================
@@ -258,10 +345,109 @@ pub fn evaluate_threshold_static(event_spec: &EventSpecV3, agg_state: &AggStateV
         (EventTypeV3::PrecipTypeOccurred, AggStateV3::PrecipTypeOccurred { mask }) => {
             (*mask as i64) & threshold != 0
         }
+        (EventTypeV3::DryStreakGte, AggStateV3::ConsecutiveDryHours { longest_hours, .. }) => {
+            *longest_hours as i64 >= threshold
+        }
+        (EventTypeV3::CycloneWithinGte, AggStateV3::CycloneApproach { peak_category, .. }) => {
+            prmx_primitives::CycloneThresholdV3::from_value(threshold)
+                .is_some_and(|cyclone| *peak_category >= cyclone.category)
+        }
         _ => false,
     }
 }
 
+// ============================================================================
+// Unsigned Transaction Nonces
+// ============================================================================
+
+/// Nonces handed out to one OCW signer that may still be in the transaction pool
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, Default)]
+pub struct OcwNonceCursor {
+    /// Next nonce to hand out
+    pub next: u64,
+    /// Block at which the last nonce was handed out
+    pub allocated_at: u64,
+}
+
+impl OcwNonceCursor {
+    /// Generate storage key for a signer (SCALE-encoded account id)
+    pub fn storage_key(account: &[u8]) -> Vec<u8> {
+        let mut key = OCW_NONCE_PREFIX.to_vec();
+        key.extend_from_slice(account);
+        key
+    }
+
+    /// Hand out the nonce for a new transaction from `account`
+    pub fn allocate(account: &[u8], on_chain_nonce: u64, block: u64, longevity: u64) -> u64 {
+        let key = Self::storage_key(account);
+        let storage = StorageValueRef::persistent(&key);
+        let cursor = storage.get::<Self>().ok().flatten();
+
+        let nonce = next_nonce(cursor.as_ref(), on_chain_nonce, block, longevity);
+        storage.set(&Self {
+            next: nonce.saturating_add(1),
+            allocated_at: block,
+        });
+        nonce
+    }
+
+    /// Give back a nonce whose transaction never reached the pool, so the next
+    /// transaction does not wait on it
+    pub fn release(account: &[u8], nonce: u64) {
+        let key = Self::storage_key(account);
+        let storage = StorageValueRef::persistent(&key);
+        if let Some(mut cursor) = storage.get::<Self>().ok().flatten() {
+            if cursor.next == nonce.saturating_add(1) {
+                cursor.next = nonce;
+                storage.set(&cursor);
+            }
+        }
+    }
+}
+
+/// Pick the nonce for a new transaction.
+/// Nonces handed out within the pool longevity may still be in flight, so keep
+/// counting from the cursor; older ones are included or expired, so restart from
+/// the on-chain nonce.
+pub fn next_nonce(
+    cursor: Option<&OcwNonceCursor>,
+    on_chain_nonce: u64,
+    block: u64,
+    longevity: u64,
+) -> u64 {
+    match cursor {
+        Some(cursor) if block <= cursor.allocated_at.saturating_add(longevity) => {
+            cursor.next.max(on_chain_nonce)
+        }
+        _ => on_chain_nonce,
+    }
+}
+
+// ============================================================================
+// Error Telemetry
+// ============================================================================
+
+/// OCW failures counted per kind since the node's offchain storage was created
+pub fn error_counts() -> OcwErrorCounts {
+    StorageValueRef::persistent(OCW_ERROR_COUNTS_KEY)
+        .get::<OcwErrorCounts>()
+        .ok()
+        .flatten()
+        .unwrap_or_default()
+}
+
+/// Count one OCW failure. A write that loses a race with a concurrent worker
+/// run is dropped; the counts are for diagnostics, not accounting.
+pub fn record_error_kind(kind: OcwErrorKind) {
+    let _ = StorageValueRef::persistent(OCW_ERROR_COUNTS_KEY).mutate(
+        |stored: Result<Option<OcwErrorCounts>, StorageRetrievalError>| -> Result<_, ()> {
+            let mut counts = stored.ok().flatten().unwrap_or_default();
+            counts.record(kind);
+            Ok(counts)
+        },
+    );
+}
+
 // ============================================================================
 // Secret Provisioning
 // ============================================================================
@@ -278,12 +464,49 @@ pub fn get_accuweather_api_key() -> Option<Vec<u8>> {
     storage.get::<Vec<u8>>().ok().flatten()
 }
 
+/// AccuWeather base URLs in failover order: the public host, then configured mirrors
+pub fn get_accuweather_endpoints() -> Vec<Vec<u8>> {
+    let mirrors = StorageValueRef::persistent(ACCUWEATHER_MIRRORS_KEY)
+        .get::<Vec<Vec<u8>>>()
+        .ok()
+        .flatten()
+        .unwrap_or_default();
+    accuweather_endpoints(mirrors)
+}
+
+/// Public host followed by `mirrors`, without empty or repeated entries
+pub fn accuweather_endpoints(mirrors: Vec<Vec<u8>>) -> Vec<Vec<u8>> {
+    let mut endpoints = alloc::vec![crate::http_client::ACCUWEATHER_BASE_URL.as_bytes().to_vec()];
+    for mirror in mirrors {
+        if !mirror.is_empty() && !endpoints.contains(&mirror) {
+            endpoints.push(mirror);
+        }
+    }
+    endpoints
+}
+
 /// Get Ingest API URL from offchain storage
 pub fn get_ingest_api_url() -> Option<Vec<u8>> {
     let storage = StorageValueRef::persistent(INGEST_API_URL_KEY);
     storage.get::<Vec<u8>>().ok().flatten()
 }
 
+/// Get storm-track API base URL from offchain storage
+pub fn get_storm_track_api_url() -> Option<Vec<u8>> {
+    let storage = StorageValueRef::persistent(STORM_TRACK_API_URL_KEY);
+    storage.get::<Vec<u8>>().ok().flatten()
+}
+
+/// Settlement webhook URLs from offchain storage, empty if none are configured
+pub fn get_settlement_webhooks() -> Vec<Vec<u8>> {
+    let webhooks = StorageValueRef::persistent(SETTLEMENT_WEBHOOKS_KEY)
+        .get::<Vec<Vec<u8>>>()
+        .ok()
+        .flatten()
+        .unwrap_or_default();
+    crate::notifier::settlement_webhooks(webhooks)
+}
+
 /// Set HMAC secret (called by setup script via RPC)
 pub fn set_hmac_secret(secret: Vec<u8>) {
     let storage = StorageValueRef::persistent(INGEST_HMAC_SECRET_KEY);
@@ -302,4 +525,57 @@ pub fn set_ingest_api_url(url: Vec<u8>) {
     storage.set(&url);
 }
 
+/// Set storm-track API base URL (called by setup script via RPC)
+pub fn set_storm_track_api_url(url: Vec<u8>) {
+    let storage = StorageValueRef::persistent(STORM_TRACK_API_URL_KEY);
+    storage.set(&url);
+}
+
+/// Set AccuWeather mirror base URLs (called by setup script via RPC)
+pub fn set_accuweather_mirrors(mirrors: Vec<Vec<u8>>) {
+    let storage = StorageValueRef::persistent(ACCUWEATHER_MIRRORS_KEY);
+    storage.set(&mirrors);
+}
 
+/// Set settlement webhook URLs (called by setup script via RPC)
+pub fn set_settlement_webhooks(webhooks: Vec<Vec<u8>>) {
+    let storage = StorageValueRef::persistent(SETTLEMENT_WEBHOOKS_KEY);
+    storage.set(&webhooks);
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_nonce() {
+        let cursor = OcwNonceCursor { next: 7, allocated_at: 100 };
+
+        // No cursor: start from the chain
+        assert_eq!(next_nonce(None, 4, 100, 5), 4);
+
+        // Within longevity: earlier nonces may still be pending
+        assert_eq!(next_nonce(Some(&cursor), 4, 105, 5), 7);
+
+        // Chain already past the cursor
+        assert_eq!(next_nonce(Some(&cursor), 9, 101, 5), 9);
+
+        // Past longevity: anything not included has expired
+        assert_eq!(next_nonce(Some(&cursor), 5, 106, 5), 5);
+    }
+
+    #[test]
+    fn test_accuweather_endpoints() {
+        let public = crate::http_client::ACCUWEATHER_BASE_URL.as_bytes().to_vec();
+        assert_eq!(accuweather_endpoints(Vec::new()), vec![public.clone()]);
+
+        let mirror = b"https://aw-mirror.example.org".to_vec();
+        let endpoints =
+            accuweather_endpoints(vec![mirror.clone(), Vec::new(), public.clone(), mirror.clone()]);
+        assert_eq!(endpoints, vec![public, mirror]);
+    }
+}
@@ -0,0 +1,392 @@
+//! # Ingest Outbox for OCW
+//!
+//! Observation batches the Ingest API did not accept are kept per policy in
+//! offchain storage and re-sent on later OCW runs, oldest first, so a temporary
+//! outage does not lose observations. While a policy has queued batches, new
+//! batches queue behind them to keep the API's view of the chain in order.
+//!
+//! Batches older than `OUTBOX_MAX_AGE_SECS` are dropped (the committed samples
+//! remain in the evidence log). When a policy's backlog reaches
+//! `INGEST_BACKLOG_HIGH_BATCHES`, the OCW reports it on-chain, which emits
+//! `IngestBacklogHigh`.
+
+use alloc::vec::Vec;
+use codec::{Decode, Encode};
+use frame_support::sp_runtime::offchain::storage::StorageValueRef;
+use prmx_primitives::PolicyId;
+
+use crate::fetcher::WeatherObservation;
+use crate::http_client;
+use crate::ocw::OCW_V3_PREFIX;
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Queued batches older than this are dropped (7 days)
+pub const OUTBOX_MAX_AGE_SECS: u64 = 7 * 24 * 3600;
+
+/// Batches kept per policy; the oldest is dropped when a new one would exceed it
+pub const MAX_OUTBOX_BATCHES: usize = 168;
+
+/// Maximum batches re-sent per policy in one OCW run
+pub const MAX_OUTBOX_FLUSH_PER_RUN: usize = 8;
+
+/// Delay before the first retry; doubles per consecutive failure
+pub const OUTBOX_RETRY_BASE_SECS: u64 = 60;
+
+/// Upper bound on the retry delay
+pub const OUTBOX_RETRY_MAX_SECS: u64 = 3600;
+
+/// Backlog size at which the OCW reports `IngestBacklogHigh` on-chain
+pub const INGEST_BACKLOG_HIGH_BATCHES: u32 = 24;
+
+/// Minimum time between backlog reports for one policy
+pub const INGEST_BACKLOG_REPORT_INTERVAL_SECS: u64 = 6 * 3600;
+
+/// Key for the list of policies with queued batches
+pub const INGEST_OUTBOX_QUEUE_KEY: &[u8] = b"ocw:v3:outbox:queue";
+
+// ============================================================================
+// Outbox
+// ============================================================================
+
+/// Observation batch awaiting delivery to the Ingest API
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode)]
+pub struct PendingBatch {
+    /// AccuWeather location key the observations were fetched for
+    pub location_key: Vec<u8>,
+    /// Observations in commitment order
+    pub observations: Vec<WeatherObservation>,
+    /// Sample hashes for the observations
+    pub sample_hashes: Vec<[u8; 32]>,
+    /// Commitment after the last observation
+    pub commitment_after: [u8; 32],
+    /// Unix timestamp the batch was queued at
+    pub queued_at: u64,
+}
+
+/// Queued batches of a policy with their retry state
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, Default)]
+pub struct IngestOutbox {
+    /// Batches in the order they were committed
+    pub batches: Vec<PendingBatch>,
+    /// Consecutive failed deliveries
+    pub failures: u32,
+    /// Unix timestamp before which no delivery is attempted
+    pub retry_after: u64,
+    /// Batches dropped for age or capacity
+    pub dropped: u32,
+    /// Unix timestamp of the last on-chain backlog report
+    pub last_backlog_report_at: u64,
+}
+
+/// Backlog of one policy, reported on-chain as `IngestBacklogHigh`
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct BacklogReport {
+    pub policy_id: PolicyId,
+    pub pending_batches: u32,
+    pub pending_samples: u32,
+    pub oldest_queued_at: u64,
+}
+
+impl IngestOutbox {
+    /// Generate storage key for a policy
+    pub fn storage_key(policy_id: PolicyId) -> Vec<u8> {
+        let mut key = OCW_V3_PREFIX.to_vec();
+        key.extend_from_slice(b"policy:");
+        key.extend_from_slice(&policy_id.to_le_bytes());
+        key.extend_from_slice(b":outbox");
+        key
+    }
+
+    /// Load outbox from offchain storage
+    pub fn load(policy_id: PolicyId) -> Option<Self> {
+        let key = Self::storage_key(policy_id);
+        let storage = StorageValueRef::persistent(&key);
+        storage.get::<Self>().ok().flatten()
+    }
+
+    /// Save outbox to offchain storage
+    pub fn save(&self, policy_id: PolicyId) {
+        let key = Self::storage_key(policy_id);
+        let storage = StorageValueRef::persistent(&key);
+        storage.set(self);
+    }
+
+    /// Remove outbox from offchain storage
+    pub fn clear(policy_id: PolicyId) {
+        let key = Self::storage_key(policy_id);
+        let mut storage = StorageValueRef::persistent(&key);
+        storage.clear();
+    }
+
+    /// Append a batch, dropping the oldest one when full
+    pub fn push(&mut self, batch: PendingBatch) {
+        if self.batches.len() >= MAX_OUTBOX_BATCHES {
+            self.batches.remove(0);
+            self.dropped = self.dropped.saturating_add(1);
+        }
+        self.batches.push(batch);
+    }
+
+    /// Drop batches queued more than `OUTBOX_MAX_AGE_SECS` before `now`.
+    /// Returns the number dropped.
+    pub fn trim_expired(&mut self, now: u64) -> u32 {
+        let before = self.batches.len();
+        self.batches
+            .retain(|batch| batch.queued_at.saturating_add(OUTBOX_MAX_AGE_SECS) > now);
+        let trimmed = (before - self.batches.len()) as u32;
+        self.dropped = self.dropped.saturating_add(trimmed);
+        trimmed
+    }
+
+    /// Whether a delivery may be attempted at `now`
+    pub fn is_due(&self, now: u64) -> bool {
+        now >= self.retry_after
+    }
+
+    /// Record a failed delivery and schedule the next attempt
+    pub fn record_failure(&mut self, now: u64) {
+        self.failures = self.failures.saturating_add(1);
+        self.retry_after = now.saturating_add(retry_delay_secs(self.failures));
+    }
+
+    /// Reset the retry state after a successful delivery
+    pub fn record_success(&mut self) {
+        self.failures = 0;
+        self.retry_after = 0;
+    }
+
+    /// Backlog to report on-chain at `now`, if it is high and was not reported
+    /// within `INGEST_BACKLOG_REPORT_INTERVAL_SECS`
+    pub fn backlog_report(&self, policy_id: PolicyId, now: u64) -> Option<BacklogReport> {
+        let pending_batches = self.batches.len() as u32;
+        if pending_batches < INGEST_BACKLOG_HIGH_BATCHES {
+            return None;
+        }
+        if self.last_backlog_report_at != 0
+            && now < self.last_backlog_report_at.saturating_add(INGEST_BACKLOG_REPORT_INTERVAL_SECS)
+        {
+            return None;
+        }
+
+        Some(BacklogReport {
+            policy_id,
+            pending_batches,
+            pending_samples: self.batches.iter().map(|b| b.observations.len() as u32).sum(),
+            oldest_queued_at: self.batches.first().map(|b| b.queued_at).unwrap_or(now),
+        })
+    }
+}
+
+/// Delay before the next delivery after `failures` consecutive failures:
+/// 60s, 120s, 240s, ... up to `OUTBOX_RETRY_MAX_SECS`
+pub fn retry_delay_secs(failures: u32) -> u64 {
+    let exponent = failures.saturating_sub(1).min(16);
+    OUTBOX_RETRY_BASE_SECS
+        .saturating_mul(1u64 << exponent)
+        .min(OUTBOX_RETRY_MAX_SECS)
+}
+
+// ============================================================================
+// Outbox Queue
+// ============================================================================
+
+/// Get policies with queued batches
+pub fn pending_policies() -> Vec<PolicyId> {
+    let storage = StorageValueRef::persistent(INGEST_OUTBOX_QUEUE_KEY);
+    storage.get::<Vec<PolicyId>>().ok().flatten().unwrap_or_default()
+}
+
+/// Whether a policy has batches waiting, in which case new batches must queue
+/// behind them
+pub fn has_pending(policy_id: PolicyId) -> bool {
+    pending_policies().contains(&policy_id)
+}
+
+/// Queue a batch the Ingest API did not accept (or that must wait its turn)
+pub fn enqueue(policy_id: PolicyId, batch: PendingBatch) {
+    let mut outbox = IngestOutbox::load(policy_id).unwrap_or_default();
+    outbox.push(batch);
+    outbox.save(policy_id);
+
+    let mut queue = pending_policies();
+    if !queue.contains(&policy_id) {
+        queue.push(policy_id);
+        StorageValueRef::persistent(INGEST_OUTBOX_QUEUE_KEY).set(&queue);
+    }
+}
+
+/// Remove a policy from the outbox queue
+fn dequeue(policy_id: PolicyId) {
+    let mut queue = pending_policies();
+    queue.retain(|queued| *queued != policy_id);
+    StorageValueRef::persistent(INGEST_OUTBOX_QUEUE_KEY).set(&queue);
+}
+
+/// Re-send queued batches for every policy that is due, oldest first.
+/// A failed delivery stops that policy's flush and backs off; expired batches
+/// are dropped first. Returns the backlogs to report on-chain, which are
+/// marked as reported.
+pub fn flush_pending(ingest_url: &[u8], hmac_secret: &[u8], now: u64) -> Vec<BacklogReport> {
+    let mut reports = Vec::new();
+
+    for policy_id in pending_policies() {
+        let Some(mut outbox) = IngestOutbox::load(policy_id) else {
+            dequeue(policy_id);
+            continue;
+        };
+
+        let trimmed = outbox.trim_expired(now);
+        if trimmed > 0 {
+            log::warn!(
+                target: "prmx-oracle-v3",
+                "Dropped {} expired Ingest API batches for policy {}",
+                trimmed,
+                policy_id
+            );
+        }
+
+        if outbox.is_due(now) {
+            let mut delivered = 0;
+            let mut failed = false;
+            for batch in outbox.batches.iter().take(MAX_OUTBOX_FLUSH_PER_RUN) {
+                if let Err(e) = http_client::send_observations_batch(
+                    ingest_url,
+                    hmac_secret,
+                    policy_id,
+                    &batch.location_key,
+                    &batch.observations,
+                    &batch.sample_hashes,
+                    batch.commitment_after,
+                ) {
+                    log::warn!(
+                        target: "prmx-oracle-v3",
+                        "Failed to re-send queued batch for policy {}: {:?}",
+                        policy_id,
+                        e
+                    );
+                    crate::ocw::record_error_kind(e);
+                    outbox.record_failure(now);
+                    failed = true;
+                    break;
+                }
+                delivered += 1;
+            }
+
+            outbox.batches.drain(..delivered);
+            if !failed {
+                outbox.record_success();
+            }
+        }
+
+        if outbox.batches.is_empty() {
+            log::info!(
+                target: "prmx-oracle-v3",
+                "📬 Ingest outbox drained for policy {}",
+                policy_id
+            );
+            IngestOutbox::clear(policy_id);
+            dequeue(policy_id);
+            continue;
+        }
+
+        if let Some(report) = outbox.backlog_report(policy_id, now) {
+            outbox.last_backlog_report_at = now;
+            reports.push(report);
+        }
+        outbox.save(policy_id);
+    }
+
+    reports
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn batch(queued_at: u64, samples: usize) -> PendingBatch {
+        PendingBatch {
+            location_key: b"264885".to_vec(),
+            observations: (0..samples as u64)
+                .map(|i| WeatherObservation {
+                    epoch_time: queued_at + i * 3600,
+                    precip_1h_mm_x1000: 1_000,
+                    temp_c_x1000: 25_000,
+                    wind_gust_mps_x1000: 4_000,
+                    precip_type_mask: 1,
+                    has_precipitation: true,
+                })
+                .collect(),
+            sample_hashes: alloc::vec![[0u8; 32]; samples],
+            commitment_after: [1u8; 32],
+            queued_at,
+        }
+    }
+
+    #[test]
+    fn test_retry_delay_secs() {
+        assert_eq!(retry_delay_secs(1), 60);
+        assert_eq!(retry_delay_secs(2), 120);
+        assert_eq!(retry_delay_secs(4), 480);
+        assert_eq!(retry_delay_secs(7), OUTBOX_RETRY_MAX_SECS);
+        assert_eq!(retry_delay_secs(u32::MAX), OUTBOX_RETRY_MAX_SECS);
+    }
+
+    #[test]
+    fn test_outbox_trim_and_capacity() {
+        let now = 1_700_000_000;
+        let mut outbox = IngestOutbox::default();
+        outbox.push(batch(now - OUTBOX_MAX_AGE_SECS, 1));
+        outbox.push(batch(now - OUTBOX_MAX_AGE_SECS + 1, 1));
+        assert_eq!(outbox.trim_expired(now), 1);
+        assert_eq!(outbox.batches[0].queued_at, now - OUTBOX_MAX_AGE_SECS + 1);
+
+        // A full outbox drops its oldest batch
+        let mut outbox = IngestOutbox::default();
+        for i in 0..=MAX_OUTBOX_BATCHES as u64 {
+            outbox.push(batch(now + i, 1));
+        }
+        assert_eq!(outbox.batches.len(), MAX_OUTBOX_BATCHES);
+        assert_eq!(outbox.batches[0].queued_at, now + 1);
+        assert_eq!(outbox.dropped, 1);
+    }
+
+    #[test]
+    fn test_backlog_report() {
+        let now = 1_700_000_000;
+        let mut outbox = IngestOutbox::default();
+        for i in 0..INGEST_BACKLOG_HIGH_BATCHES as u64 - 1 {
+            outbox.push(batch(now - 3600 * (30 - i), 2));
+        }
+        assert_eq!(outbox.backlog_report(7, now), None);
+
+        outbox.push(batch(now, 2));
+        let report = outbox.backlog_report(7, now).expect("backlog is high");
+        assert_eq!(report.pending_batches, INGEST_BACKLOG_HIGH_BATCHES);
+        assert_eq!(report.pending_samples, 2 * INGEST_BACKLOG_HIGH_BATCHES);
+        assert_eq!(report.oldest_queued_at, now - 3600 * 30);
+
+        // Reported again only after the interval
+        outbox.last_backlog_report_at = now;
+        assert_eq!(outbox.backlog_report(7, now + 3600), None);
+        assert!(outbox.backlog_report(7, now + INGEST_BACKLOG_REPORT_INTERVAL_SECS).is_some());
+    }
+
+    #[test]
+    fn test_record_failure_backs_off() {
+        let mut outbox = IngestOutbox::default();
+        assert!(outbox.is_due(0));
+        outbox.record_failure(1_000);
+        outbox.record_failure(1_000);
+        assert!(!outbox.is_due(1_119));
+        assert!(outbox.is_due(1_120));
+        outbox.record_success();
+        assert_eq!((outbox.failures, outbox.retry_after), (0, 0));
+    }
+}
@@ -0,0 +1,387 @@
+//! # Observation Outlier Filtering
+//!
+//! Rejects implausible hourly precipitation samples before they reach a
+//! policy's aggregation, so a single bogus reading (e.g. 900mm in one hour)
+//! cannot trigger a policy on its own.
+//!
+//! A sample is flagged when its precipitation rises more than
+//! `max_hourly_delta_mm_x1000` above the previous accepted sample, or exceeds
+//! `max_median_ratio_pct` percent of the median of the last `median_window`
+//! accepted samples. Flagged samples are held: once `spike_confirmations`
+//! further samples in a row are flagged as well the change is genuine and the
+//! whole run is accepted; a run that ends earlier is rejected as a spike.
+//!
+//! Rules are set by governance (`set_outlier_rules`) and pinned per policy when
+//! its oracle state is initialized. The OCW keeps an `OutlierFilter` per policy
+//! location across runs, and `dispute_final_report` replays a fresh filter over
+//! the committed samples, so both see the same accepted samples. Rejected
+//! samples stay in the commitment chain and evidence log.
+
+use alloc::vec::Vec;
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use frame_support::sp_runtime::offchain::storage::StorageValueRef;
+use prmx_primitives::{EventTypeV3, PolicyId};
+use scale_info::TypeInfo;
+
+use crate::fetcher::WeatherObservation;
+use crate::ocw::OCW_V3_PREFIX;
+use crate::LocationId;
+
+/// Longest trailing window the median rule can look back over
+pub const MAX_OUTLIER_MEDIAN_WINDOW: u8 = 24;
+
+/// Most confirmations a spike can be required to have
+pub const MAX_SPIKE_CONFIRMATIONS: u8 = 6;
+
+/// Most rejected samples reported in one unsigned transaction
+pub const MAX_REJECTED_PER_REPORT: u32 = 32;
+
+/// Rules for rejecting hourly precipitation outliers
+#[derive(
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Debug,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct OutlierRulesV3 {
+    /// Largest rise over the previous accepted sample (mm * 1000); zero disables
+    /// the rule
+    pub max_hourly_delta_mm_x1000: i64,
+    /// Largest sample as a percentage of the trailing median; zero disables the
+    /// rule. Skipped while the median is zero, where the delta rule applies alone.
+    pub max_median_ratio_pct: u32,
+    /// Accepted samples the trailing median is taken over; the rule applies once
+    /// the window is full
+    pub median_window: u8,
+    /// Further flagged samples in a row needed to accept a flagged sample; zero
+    /// rejects every flagged sample
+    pub spike_confirmations: u8,
+}
+
+impl OutlierRulesV3 {
+    /// Whether the rules are within the supported bounds
+    pub fn is_valid(&self) -> bool {
+        self.max_hourly_delta_mm_x1000 >= 0
+            && self.median_window <= MAX_OUTLIER_MEDIAN_WINDOW
+            && self.spike_confirmations <= MAX_SPIKE_CONFIRMATIONS
+    }
+
+    /// Rule a sample breaks given the previous accepted sample and the trailing
+    /// window, if any
+    fn check(
+        &self,
+        precip: i64,
+        previous: Option<i64>,
+        trailing: &[i64],
+    ) -> Option<OutlierReasonV3> {
+        if self.max_hourly_delta_mm_x1000 > 0 {
+            if let Some(previous) = previous {
+                if precip.saturating_sub(previous) > self.max_hourly_delta_mm_x1000 {
+                    return Some(OutlierReasonV3::HourlyDelta);
+                }
+            }
+        }
+
+        if self.max_median_ratio_pct > 0
+            && self.median_window > 0
+            && trailing.len() >= self.median_window as usize
+        {
+            let median = median(trailing);
+            let limit = (median as i128).saturating_mul(self.max_median_ratio_pct as i128) / 100;
+            if median > 0 && precip as i128 > limit {
+                return Some(OutlierReasonV3::TrailingMedian);
+            }
+        }
+
+        None
+    }
+}
+
+/// Rule that rejected a sample
+#[derive(
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Debug,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum OutlierReasonV3 {
+    /// Rose more than `max_hourly_delta_mm_x1000` over the previous accepted sample
+    HourlyDelta,
+    /// Exceeded `max_median_ratio_pct` of the trailing median
+    TrailingMedian,
+}
+
+/// Sample rejected by the outlier rules
+#[derive(
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Debug,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct RejectedObservationV3 {
+    /// Unix epoch time of the observation
+    pub epoch_time: u64,
+    /// Precipitation in the hour (mm * 1000)
+    pub precip_1h_mm_x1000: i64,
+    /// Rule it broke
+    pub reason: OutlierReasonV3,
+}
+
+/// Whether outlier rules apply to an event type. Only event types that read the
+/// hourly precipitation amount are filtered.
+pub fn applies_to(event_type: EventTypeV3) -> bool {
+    matches!(
+        event_type,
+        EventTypeV3::PrecipSumGte | EventTypeV3::Precip1hGte | EventTypeV3::DryStreakGte
+    )
+}
+
+/// Filter state carried across batches of one location's samples
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, Default)]
+pub struct OutlierFilter {
+    /// Precipitation of the last accepted sample
+    pub previous: Option<i64>,
+    /// Precipitation of the latest accepted samples, oldest first
+    pub trailing: Vec<i64>,
+    /// Flagged samples awaiting confirmation, with the rule each broke
+    pub held: Vec<(WeatherObservation, OutlierReasonV3)>,
+}
+
+impl OutlierFilter {
+    /// Generate storage key for a policy location
+    pub fn storage_key(policy_id: PolicyId, location_id: LocationId) -> Vec<u8> {
+        let mut key = OCW_V3_PREFIX.to_vec();
+        key.extend_from_slice(b"policy:");
+        key.extend_from_slice(&policy_id.to_le_bytes());
+        key.extend_from_slice(b":outliers:");
+        key.extend_from_slice(&location_id.to_le_bytes());
+        key
+    }
+
+    /// Load state from offchain storage
+    pub fn load(policy_id: PolicyId, location_id: LocationId) -> Self {
+        StorageValueRef::persistent(&Self::storage_key(policy_id, location_id))
+            .get::<Self>()
+            .ok()
+            .flatten()
+            .unwrap_or_default()
+    }
+
+    /// Save state to offchain storage
+    pub fn save(&self, policy_id: PolicyId, location_id: LocationId) {
+        StorageValueRef::persistent(&Self::storage_key(policy_id, location_id)).set(self);
+    }
+
+    /// Split time-ordered `observations` into the samples to aggregate and the
+    /// samples rejected. Flagged samples still awaiting confirmation at the end
+    /// are kept for the next batch and appear in neither.
+    pub fn apply(
+        &mut self,
+        rules: &OutlierRulesV3,
+        observations: Vec<WeatherObservation>,
+    ) -> (Vec<WeatherObservation>, Vec<RejectedObservationV3>) {
+        let mut accepted = Vec::with_capacity(observations.len());
+        let mut rejected = Vec::new();
+
+        for obs in observations {
+            match rules.check(obs.precip_1h_mm_x1000, self.previous, &self.trailing) {
+                None => {
+                    // The run of flagged samples ended unconfirmed
+                    rejected.extend(self.held.drain(..).map(|(held, reason)| {
+                        RejectedObservationV3 {
+                            epoch_time: held.epoch_time,
+                            precip_1h_mm_x1000: held.precip_1h_mm_x1000,
+                            reason,
+                        }
+                    }));
+                    self.accept(rules, obs, &mut accepted);
+                }
+                Some(reason) if rules.spike_confirmations == 0 => {
+                    rejected.push(RejectedObservationV3 {
+                        epoch_time: obs.epoch_time,
+                        precip_1h_mm_x1000: obs.precip_1h_mm_x1000,
+                        reason,
+                    });
+                }
+                Some(reason) => {
+                    self.held.push((obs, reason));
+                    if self.held.len() > rules.spike_confirmations as usize {
+                        let confirmed: Vec<_> = self.held.drain(..).collect();
+                        for (held, _) in confirmed {
+                            self.accept(rules, held, &mut accepted);
+                        }
+                    }
+                }
+            }
+        }
+
+        (accepted, rejected)
+    }
+
+    fn accept(
+        &mut self,
+        rules: &OutlierRulesV3,
+        obs: WeatherObservation,
+        accepted: &mut Vec<WeatherObservation>,
+    ) {
+        self.previous = Some(obs.precip_1h_mm_x1000);
+        if rules.median_window > 0 {
+            self.trailing.push(obs.precip_1h_mm_x1000);
+            let excess = self
+                .trailing
+                .len()
+                .saturating_sub(rules.median_window as usize);
+            self.trailing.drain(..excess);
+        }
+        accepted.push(obs);
+    }
+}
+
+/// Median of non-empty `values` (lower median for even counts)
+fn median(values: &[i64]) -> i64 {
+    let mut sorted = values.to_vec();
+    sorted.sort_unstable();
+    sorted[(sorted.len() - 1) / 2]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hourly(precip: &[i64]) -> Vec<WeatherObservation> {
+        precip
+            .iter()
+            .enumerate()
+            .map(|(i, mm)| WeatherObservation {
+                epoch_time: 1_000 + i as u64 * 3600,
+                precip_1h_mm_x1000: *mm,
+                temp_c_x1000: 25_000,
+                wind_gust_mps_x1000: 5_000,
+                precip_type_mask: if *mm > 0 { 1 } else { 0 },
+                has_precipitation: *mm > 0,
+            })
+            .collect()
+    }
+
+    fn precip_of(observations: &[WeatherObservation]) -> Vec<i64> {
+        observations
+            .iter()
+            .map(|obs| obs.precip_1h_mm_x1000)
+            .collect()
+    }
+
+    fn rules(spike_confirmations: u8) -> OutlierRulesV3 {
+        OutlierRulesV3 {
+            max_hourly_delta_mm_x1000: 50_000,
+            max_median_ratio_pct: 1_000,
+            median_window: 3,
+            spike_confirmations,
+        }
+    }
+
+    #[test]
+    fn test_single_spike_is_rejected() {
+        let mut filter = OutlierFilter::default();
+        let (accepted, rejected) =
+            filter.apply(&rules(2), hourly(&[2_000, 3_000, 900_000, 4_000, 2_000]));
+
+        assert_eq!(precip_of(&accepted), vec![2_000, 3_000, 4_000, 2_000]);
+        assert_eq!(
+            rejected,
+            vec![RejectedObservationV3 {
+                epoch_time: 1_000 + 2 * 3600,
+                precip_1h_mm_x1000: 900_000,
+                reason: OutlierReasonV3::HourlyDelta,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_confirmed_run_is_accepted() {
+        let mut filter = OutlierFilter::default();
+        let (accepted, rejected) =
+            filter.apply(&rules(2), hourly(&[1_000, 80_000, 85_000, 90_000, 70_000]));
+
+        assert_eq!(
+            precip_of(&accepted),
+            vec![1_000, 80_000, 85_000, 90_000, 70_000]
+        );
+        assert!(rejected.is_empty());
+    }
+
+    #[test]
+    fn test_held_samples_carry_across_batches() {
+        let samples = hourly(&[1_000, 2_000, 1_000, 95_000, 2_000]);
+
+        let mut whole = OutlierFilter::default();
+        let (accepted, rejected) = whole.apply(&rules(1), samples.clone());
+
+        // The flagged sample ends the first batch and is settled by the second
+        let mut split = OutlierFilter::default();
+        let (first, first_rejected) = split.apply(&rules(1), samples[..4].to_vec());
+        assert_eq!(split.held.len(), 1);
+        assert!(first_rejected.is_empty());
+        let (second, second_rejected) = split.apply(&rules(1), samples[4..].to_vec());
+
+        assert_eq!([first, second].concat(), accepted);
+        assert_eq!([first_rejected, second_rejected].concat(), rejected);
+        assert_eq!(rejected.len(), 1);
+    }
+
+    #[test]
+    fn test_trailing_median_rule() {
+        let rules = OutlierRulesV3 {
+            max_hourly_delta_mm_x1000: 0,
+            max_median_ratio_pct: 500,
+            median_window: 3,
+            spike_confirmations: 0,
+        };
+        let mut filter = OutlierFilter::default();
+        let (accepted, rejected) =
+            filter.apply(&rules, hourly(&[2_000, 4_000, 3_000, 20_000, 15_000]));
+
+        // Median of the last three accepted samples is 3mm, so 15mm is the limit
+        assert_eq!(precip_of(&accepted), vec![2_000, 4_000, 3_000, 15_000]);
+        assert_eq!(rejected.len(), 1);
+        assert_eq!(rejected[0].reason, OutlierReasonV3::TrailingMedian);
+
+        // A dry trailing window leaves the median rule out
+        let mut filter = OutlierFilter::default();
+        let (accepted, _) = filter.apply(&rules, hourly(&[0, 0, 0, 20_000]));
+        assert_eq!(accepted.len(), 4);
+    }
+
+    #[test]
+    fn test_rule_bounds() {
+        assert!(rules(2).is_valid());
+        assert!(!rules(MAX_SPIKE_CONFIRMATIONS + 1).is_valid());
+        assert!(!OutlierRulesV3 {
+            median_window: MAX_OUTLIER_MEDIAN_WINDOW + 1,
+            ..rules(2)
+        }
+        .is_valid());
+        assert!(!OutlierRulesV3 {
+            max_hourly_delta_mm_x1000: -1,
+            ..rules(2)
+        }
+        .is_valid());
+    }
+}
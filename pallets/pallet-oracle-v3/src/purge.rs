@@ -0,0 +1,100 @@
+//! # Settled Policy Purge
+//!
+//! A policy's oracle storage (`OracleStates`, `PolicyMetadata`, `FinalReports`,
+//! `PolicyOutlierRules`, `SnapshotRateLimit`) is only needed until settlement
+//! and for a review period after it. A final report queues the policy in
+//! `PurgeQueue`; once `SettledRetentionBlocks` have passed since the report,
+//! anyone can `purge_settled` it, and `on_idle` purges due policies in queue
+//! order with the block's leftover weight.
+//!
+//! Purging replaces the policy's entries with a single settled record hash
+//! (`SettledRecordHashes`), so anyone holding the purged entries, e.g. from an
+//! archive node, can still prove what the chain recorded.
+
+use alloc::vec::Vec;
+use codec::Encode;
+use prmx_primitives::{PolicyId, PolicyOracleStateV3};
+use sp_core::Hasher;
+use sp_runtime::traits::{BlakeTwo256, Saturating};
+
+use crate::{FinalReportProposal, PolicyMetaV3};
+
+/// Most `PurgeQueue` entries `on_idle` visits per block, purged or not
+pub const MAX_PURGES_PER_BLOCK: u32 = 16;
+
+/// Whether a policy settled at block `settled_at` is past `retention` at `now`
+pub fn is_purgeable<B: Saturating + PartialOrd + Copy>(
+    settled_at: B,
+    retention: B,
+    now: B,
+) -> bool {
+    settled_at.saturating_add(retention) <= now
+}
+
+/// Hash of a purged policy's oracle state, metadata and final report.
+///
+/// Format: blake2_256(b"prmx_v3_settled:" || SCALE(policy_id) || SCALE(state)
+/// || SCALE(meta) || SCALE(final_report))
+pub fn settled_record_hash(
+    policy_id: PolicyId,
+    state: &PolicyOracleStateV3,
+    meta: &Option<PolicyMetaV3>,
+    final_report: &Option<FinalReportProposal>,
+) -> [u8; 32] {
+    let mut data = Vec::new();
+    data.extend_from_slice(b"prmx_v3_settled:");
+    data.extend_from_slice(&policy_id.encode());
+    data.extend_from_slice(&state.encode());
+    data.extend_from_slice(&meta.encode());
+    data.extend_from_slice(&final_report.encode());
+
+    BlakeTwo256::hash(&data).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prmx_primitives::{AggStateV3, OracleReportKindV3, PolicyStatusV3};
+
+    fn policy(n: u8) -> PolicyId {
+        PolicyId::repeat_byte(n)
+    }
+
+    fn matured_state() -> PolicyOracleStateV3 {
+        PolicyOracleStateV3 {
+            policy_id: policy(7),
+            observed_until: 1_700_086_400,
+            agg_state: AggStateV3::PrecipSum { sum_mm_x1000: 12_000 },
+            commitment: [3u8; 32],
+            last_snapshot_block: 1_200,
+            status: PolicyStatusV3::Settled,
+        }
+    }
+
+    #[test]
+    fn test_is_purgeable_after_retention() {
+        assert!(!is_purgeable(100u32, 50, 149));
+        assert!(is_purgeable(100u32, 50, 150));
+        assert!(!is_purgeable(u32::MAX - 1, 50, u32::MAX - 1));
+    }
+
+    #[test]
+    fn test_settled_record_hash_covers_every_purged_entry() {
+        let state = matured_state();
+        let report = Some(FinalReportProposal {
+            kind: OracleReportKindV3::Maturity,
+            observed_until: state.observed_until,
+            agg_state: state.agg_state.clone(),
+            commitment: state.commitment,
+        });
+
+        let hash = settled_record_hash(policy(7), &state, &None, &report);
+        assert_eq!(hash, settled_record_hash(policy(7), &state, &None, &report));
+        assert_ne!(hash, settled_record_hash(policy(8), &state, &None, &report));
+        assert_ne!(hash, settled_record_hash(policy(7), &state, &None, &None));
+
+        let mut changed = state.clone();
+        changed.commitment = [4u8; 32];
+        assert_ne!(hash, settled_record_hash(policy(7), &changed, &None, &report));
+    }
+}
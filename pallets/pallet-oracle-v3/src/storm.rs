@@ -0,0 +1,193 @@
+//! # Storm Tracks for Cyclone Policies
+//!
+//! `CycloneWithinGte` policies are driven by storm-track advisories instead of
+//! AccuWeather station observations. The OCW fetches the advisories near a
+//! policy's location from the storm-track API configured in offchain storage,
+//! commits each new fix to the policy's chain as a `prmx_commitment::StormFix`,
+//! and folds it into a `CycloneApproach` state:
+//!
+//! - `closest_km`: closest approach of any storm center to the location
+//! - `peak_category`: highest Saffir-Simpson category of a fix inside the cover
+//!   radius
+//!
+//! The API answers `GET {base}/advisories?lat_e6=..&lon_e6=..&radius_km=..` with
+//! a JSON array of fixes:
+//! `[{"StormId":"WP22","EpochTime":123,"Latitude":14.6,"Longitude":121.0,"MaxWindKph":185}]`
+
+use alloc::format;
+use alloc::vec::Vec;
+use prmx_commitment::StormFix;
+use prmx_primitives::{saffir_simpson_category, AggStateV3, CycloneThresholdV3, OcwErrorKind};
+
+use crate::geohash;
+use crate::http_client::{extract_json_value, find_object_end, get_with_failover};
+
+/// One storm-track advisory position
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct StormAdvisory {
+    /// Unix epoch time of the advisory
+    pub epoch_time: u64,
+    /// Storm center latitude (scaled by 1e6)
+    pub latitude: i32,
+    /// Storm center longitude (scaled by 1e6)
+    pub longitude: i32,
+    /// Maximum sustained wind (m/s * 1000)
+    pub max_wind_mps_x1000: i64,
+}
+
+impl From<&StormAdvisory> for StormFix {
+    fn from(advisory: &StormAdvisory) -> Self {
+        StormFix {
+            epoch_time: advisory.epoch_time,
+            latitude: advisory.latitude,
+            longitude: advisory.longitude,
+            max_wind_mps_x1000: advisory.max_wind_mps_x1000,
+        }
+    }
+}
+
+/// Fetch the advisories of storms near `latitude`/`longitude` (scaled by 1e6)
+pub fn fetch_storm_advisories(
+    base_url: &[u8],
+    latitude: i32,
+    longitude: i32,
+    radius_km: u32,
+) -> Result<Vec<StormAdvisory>, OcwErrorKind> {
+    let path =
+        format!("/advisories?lat_e6={}&lon_e6={}&radius_km={}", latitude, longitude, radius_km);
+    let body = get_with_failover(&[base_url.to_vec()], &path)?;
+    parse_storm_track_response(&body)
+}
+
+/// Parse a storm-track API response. Fixes missing a position or time are
+/// skipped; the result is sorted by time.
+pub fn parse_storm_track_response(json: &[u8]) -> Result<Vec<StormAdvisory>, OcwErrorKind> {
+    let json_str = core::str::from_utf8(json).map_err(|_| OcwErrorKind::Parse)?;
+
+    let mut advisories = Vec::new();
+    let mut search_start = 0;
+    while let Some(pos) = json_str[search_start..].find('{') {
+        let obj_start = search_start + pos;
+        let Some(len) = find_object_end(&json_str[obj_start..]) else {
+            break;
+        };
+        let obj = &json_str[obj_start..obj_start + len];
+        search_start = obj_start + len;
+
+        let (Some(epoch), Some(lat), Some(lon)) = (
+            extract_json_value(obj, &["EpochTime"]),
+            extract_json_value(obj, &["Latitude"]),
+            extract_json_value(obj, &["Longitude"]),
+        ) else {
+            continue;
+        };
+        let wind_kph = extract_json_value(obj, &["MaxWindKph"]).unwrap_or(0.0);
+
+        advisories.push(StormAdvisory {
+            epoch_time: epoch as u64,
+            latitude: (lat * 1_000_000.0) as i32,
+            longitude: (lon * 1_000_000.0) as i32,
+            max_wind_mps_x1000: ((wind_kph / 3.6) * 1000.0) as i64,
+        });
+    }
+
+    advisories.sort_by_key(|advisory| advisory.epoch_time);
+    Ok(advisories)
+}
+
+/// Fold one advisory into a cyclone policy's state for a location at
+/// `latitude`/`longitude`. Other states are returned unchanged.
+pub fn update_cyclone_state(
+    current: &AggStateV3,
+    advisory: &StormAdvisory,
+    latitude: i32,
+    longitude: i32,
+    threshold: &CycloneThresholdV3,
+) -> AggStateV3 {
+    let AggStateV3::CycloneApproach { closest_km, peak_category } = *current else {
+        return *current;
+    };
+
+    let distance_km =
+        geohash::distance_m(latitude, longitude, advisory.latitude, advisory.longitude) / 1000;
+    let distance_km = u32::try_from(distance_km).unwrap_or(u32::MAX);
+    let peak_category = if distance_km <= threshold.radius_km {
+        peak_category.max(saffir_simpson_category(advisory.max_wind_mps_x1000))
+    } else {
+        peak_category
+    };
+
+    AggStateV3::CycloneApproach { closest_km: closest_km.min(distance_km), peak_category }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Manila
+    const LAT: i32 = 14_599_500;
+    const LON: i32 = 120_984_200;
+
+    fn advisory(epoch_time: u64, latitude: i32, max_wind_mps_x1000: i64) -> StormAdvisory {
+        StormAdvisory { epoch_time, latitude, longitude: LON, max_wind_mps_x1000 }
+    }
+
+    #[test]
+    fn test_parse_storm_track_response() {
+        let json = br#"[
+            {"EpochTime":1700003600,"Latitude":15.5,"Longitude":121.5,"MaxWindKph":185},
+            {"EpochTime":1700000000,"Latitude":14.25,"Longitude":122.75,"MaxWindKph":150},
+            {"EpochTime":1700007200,"MaxWindKph":120}
+        ]"#;
+
+        let advisories = parse_storm_track_response(json).unwrap();
+        assert_eq!(advisories.len(), 2);
+        assert_eq!(advisories[0].epoch_time, 1_700_000_000);
+        assert_eq!(advisories[0].latitude, 14_250_000);
+        assert_eq!(advisories[0].longitude, 122_750_000);
+        assert_eq!(advisories[1].max_wind_mps_x1000, 51_388);
+        assert_eq!(saffir_simpson_category(advisories[1].max_wind_mps_x1000), 3);
+    }
+
+    #[test]
+    fn test_peak_category_counts_only_inside_radius() {
+        let threshold = CycloneThresholdV3 { category: 3, radius_km: 100 };
+        let initial = AggStateV3::CycloneApproach { closest_km: u32::MAX, peak_category: 0 };
+
+        // Category 5 about 200 km away sets the closest approach but is outside the radius
+        let far = update_cyclone_state(
+            &initial,
+            &advisory(1, LAT + 1_800_000, 75_000),
+            LAT,
+            LON,
+            &threshold,
+        );
+        let AggStateV3::CycloneApproach { closest_km, peak_category } = far else {
+            panic!("unexpected state");
+        };
+        assert!((190..=210).contains(&closest_km));
+        assert_eq!(peak_category, 0);
+
+        // Category 3 about 50 km away
+        let near =
+            update_cyclone_state(&far, &advisory(2, LAT + 450_000, 52_000), LAT, LON, &threshold);
+        let AggStateV3::CycloneApproach { closest_km, peak_category } = near else {
+            panic!("unexpected state");
+        };
+        assert!((45..=55).contains(&closest_km));
+        assert_eq!(peak_category, 3);
+
+        // A weaker, more distant later fix keeps the closest approach and the peak
+        let after =
+            update_cyclone_state(&near, &advisory(3, LAT + 900_000, 35_000), LAT, LON, &threshold);
+        assert_eq!(after, near);
+    }
+
+    #[test]
+    fn test_other_states_unchanged() {
+        let threshold = CycloneThresholdV3 { category: 1, radius_km: 100 };
+        let state = AggStateV3::PrecipSum { sum_mm_x1000: 7 };
+        let updated = update_cyclone_state(&state, &advisory(1, LAT, 80_000), LAT, LON, &threshold);
+        assert_eq!(updated, state);
+    }
+}
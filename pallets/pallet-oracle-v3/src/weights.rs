@@ -0,0 +1,382 @@
+//! Placeholder weights for `pallet_oracle_v3`, written by hand: the benchmarks
+//! have not been run, so none of these values is measured.
+//!
+//! Storage reads/writes per extrinsic are counted from the dispatch paths in
+//! `lib.rs`; ref-time bases and per-item slopes are unmeasured guesses.
+//! Calls that can reach the final report quorum include the settlement in
+//! `pallet_policy_v3`, and `expire_request_unsigned` / `expire_rfq_unsigned` include
+//! the escrow refunds in `pallet_market_v3`. The unsigned calls also count the
+//! payload signature check and the `OcwNonces` update done in `pre_dispatch`.
+//! They must be replaced with weights generated by the benchmarks in
+//! `benchmarking.rs` (see `docs/benchmarking.md`) before production use.
+//!
+//! Components:
+//! - `a`: attestations pending for the policy (`MAX_FINAL_REPORT_ATTESTATIONS`)
+//! - `n`: samples replayed by `verify_commitment_chain` / `dispute_final_report`
+//!   (`MAX_EVIDENCE_SAMPLES`), or
+//!   member keys retired in `on_initialize` (`MAX_RETIREMENTS_PER_BLOCK`), or
+//!   oracle states checked by the snapshot gap sweep (`MaxSnapshotGapChecks`), or
+//!   rejected samples reported by `report_rejected_observations_unsigned`
+//!   (`MAX_REJECTED_PER_REPORT`)
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use core::marker::PhantomData;
+use frame_support::{
+    traits::Get,
+    weights::{constants::RocksDbWeight, Weight},
+};
+
+/// Weight functions needed for `pallet_oracle_v3`.
+pub trait WeightInfo {
+    fn add_location() -> Weight;
+    fn remove_location() -> Weight;
+    fn add_oracle_member() -> Weight;
+    fn remove_oracle_member() -> Weight;
+    fn submit_snapshot() -> Weight;
+    fn submit_snapshot_unsigned() -> Weight;
+    fn submit_final_report(a: u32) -> Weight;
+    fn submit_final_report_unsigned() -> Weight;
+    fn expire_request_unsigned() -> Weight;
+    fn attest_final_report(a: u32) -> Weight;
+    fn verify_commitment_chain(n: u32) -> Weight;
+    fn report_ingest_backlog_unsigned() -> Weight;
+    fn expire_rfq_unsigned() -> Weight;
+    fn rotate_oracle_member() -> Weight;
+    fn retire_oracle_members(n: u32) -> Weight;
+    fn dispute_final_report(n: u32) -> Weight;
+    fn check_snapshot_gaps(n: u32) -> Weight;
+    fn report_api_budget_exhausted_unsigned() -> Weight;
+    fn set_outlier_rules() -> Weight;
+    fn report_rejected_observations_unsigned(n: u32) -> Weight;
+    fn set_location_metadata() -> Weight;
+    fn submit_location_metadata_unsigned() -> Weight;
+    fn purge_settled() -> Weight;
+}
+
+/// Weights for `pallet_oracle_v3` using the runtime's database weights.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    /// Reads: LocationsByGeohash (9), NextLocationId
+    /// Writes: LocationsByGeohash, LocationRegistry, NextLocationId
+    fn add_location() -> Weight {
+        Weight::from_parts(40_000_000, 6_500)
+            .saturating_add(T::DbWeight::get().reads(10_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    /// Reads: LocationRegistry, LocationsByGeohash
+    /// Writes: LocationRegistry, LocationsByGeohash
+    fn remove_location() -> Weight {
+        Weight::from_parts(16_000_000, 4_200)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Reads: OracleMembership
+    /// Writes: OracleMembership
+    fn add_oracle_member() -> Weight {
+        Weight::from_parts(11_000_000, 3_500)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Reads: OracleMembership
+    /// Writes: OracleMembership
+    fn remove_oracle_member() -> Weight {
+        Weight::from_parts(11_000_000, 3_500)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Reads: OracleMembership, SnapshotRateLimit, OracleStates, OverdueSnapshots
+    /// Writes: OracleStates, SnapshotRateLimit, OverdueSnapshots
+    fn submit_snapshot() -> Weight {
+        Weight::from_parts(24_000_000, 3_800)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    /// Reads: OracleStates (2), OracleMembership, OcwNonces, SnapshotRateLimit,
+    /// OverdueSnapshots
+    /// Writes: OcwNonces, OracleStates, SnapshotRateLimit, OverdueSnapshots
+    fn submit_snapshot_unsigned() -> Weight {
+        Weight::from_parts(70_000_000, 3_800)
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    /// Reads: OracleMembership, OracleStates (3), ProposedFinalReports (2),
+    /// PendingAttestations, SettlementGraceUntil (2), NextPurgeIndex, policy settlement (14),
+    /// OracleMembership per attestation
+    /// Writes: ProposedFinalReports (2), PendingAttestations (2), OracleStates,
+    /// FinalReports, OverdueSnapshots, SettlementGraceUntil, SettledAt, PurgeQueue,
+    /// NextPurgeIndex, policy settlement (11)
+    fn submit_final_report(a: u32) -> Weight {
+        Weight::from_parts(150_000_000, 8_000)
+            .saturating_add(Weight::from_parts(1_200_000, 0).saturating_mul(a.into()))
+            .saturating_add(T::DbWeight::get().reads(24_u64))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(a.into())))
+            .saturating_add(T::DbWeight::get().writes(22_u64))
+            .saturating_add(Weight::from_parts(0, 2_500).saturating_mul(a.into()))
+    }
+    /// Reads: OracleStates (2), OracleMembership, OcwNonces, SettlementGraceUntil (2),
+    /// NextPurgeIndex, policy settlement (14)
+    /// Writes: OcwNonces, OracleStates, PendingAttestations, ProposedFinalReports,
+    /// FinalReports, OverdueSnapshots, SettlementGraceUntil, SettledAt, PurgeQueue,
+    /// NextPurgeIndex, policy settlement (11)
+    fn submit_final_report_unsigned() -> Weight {
+        Weight::from_parts(170_000_000, 8_000)
+            .saturating_add(T::DbWeight::get().reads(21_u64))
+            .saturating_add(T::DbWeight::get().writes(21_u64))
+    }
+    /// Reads: OracleMembership, OcwNonces, UnderwriteRequests, Assets (4)
+    /// Writes: OcwNonces, UnderwriteRequests, EscrowBalance, Assets (3)
+    fn expire_request_unsigned() -> Weight {
+        Weight::from_parts(110_000_000, 6_200)
+            .saturating_add(T::DbWeight::get().reads(7_u64))
+            .saturating_add(T::DbWeight::get().writes(6_u64))
+    }
+    /// Reads: OracleMembership, OracleStates (2), ProposedFinalReports,
+    /// PendingAttestations, SettlementGraceUntil, NextPurgeIndex, policy settlement (14),
+    /// OracleMembership per attestation
+    /// Writes: PendingAttestations (2), OracleStates, ProposedFinalReports,
+    /// FinalReports, OverdueSnapshots, SettlementGraceUntil, SettledAt, PurgeQueue,
+    /// NextPurgeIndex, policy settlement (11)
+    fn attest_final_report(a: u32) -> Weight {
+        Weight::from_parts(140_000_000, 8_000)
+            .saturating_add(Weight::from_parts(1_200_000, 0).saturating_mul(a.into()))
+            .saturating_add(T::DbWeight::get().reads(21_u64))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(a.into())))
+            .saturating_add(T::DbWeight::get().writes(21_u64))
+            .saturating_add(Weight::from_parts(0, 2_500).saturating_mul(a.into()))
+    }
+    /// Reads: OracleStates, PolicyMetadata
+    /// Writes: none
+    fn verify_commitment_chain(n: u32) -> Weight {
+        Weight::from_parts(18_000_000, 3_800)
+            .saturating_add(Weight::from_parts(4_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+    }
+    /// Reads: OracleStates (2), OracleMembership, OcwNonces
+    /// Writes: OcwNonces
+    fn report_ingest_backlog_unsigned() -> Weight {
+        Weight::from_parts(55_000_000, 3_800)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Reads: OracleMembership, OcwNonces, RfqRequests, RfqOffers, Timestamp,
+    /// Assets (2 per offer, `MaxOffersPerRfq` = 16)
+    /// Writes: OcwNonces, RfqRequests, RfqOffers, Assets (2 per offer)
+    fn expire_rfq_unsigned() -> Weight {
+        Weight::from_parts(240_000_000, 9_000)
+            .saturating_add(T::DbWeight::get().reads(37_u64))
+            .saturating_add(T::DbWeight::get().writes(35_u64))
+    }
+    /// Reads: OracleMembership (2), RetiringMembers, RetirementSchedule, NextRotationId
+    /// Writes: RetirementSchedule, RetiringMembers, OracleMembership, RotationHistory, NextRotationId
+    fn rotate_oracle_member() -> Weight {
+        Weight::from_parts(22_000_000, 4_500)
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(5_u64))
+    }
+    /// Reads: RetirementSchedule, RetiringMembers (2n), RotationHistory (n)
+    /// Writes: RetirementSchedule, RetiringMembers (n), OracleMembership (n), RotationHistory (n)
+    fn retire_oracle_members(n: u32) -> Weight {
+        Weight::from_parts(3_000_000, 1_500)
+            .saturating_add(Weight::from_parts(14_000_000, 3_500).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+            .saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+    }
+    /// Reads: OracleStates, PolicyMetadata, ProposedFinalReports, PolicyOutlierRules,
+    /// PendingAttestations
+    /// Writes: ProposedFinalReports, PendingAttestations
+    fn dispute_final_report(n: u32) -> Weight {
+        Weight::from_parts(30_000_000, 6_000)
+            .saturating_add(Weight::from_parts(4_500_000, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Reads: SnapshotGapCursor, OracleStates (n), PolicyMetadata (n),
+    /// OverdueSnapshots (n)
+    /// Writes: SnapshotGapCursor, OverdueSnapshots (n), SettlementGraceUntil (n)
+    fn check_snapshot_gaps(n: u32) -> Weight {
+        Weight::from_parts(3_000_000, 1_500)
+            .saturating_add(Weight::from_parts(12_000_000, 3_800).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+    }
+    /// Reads: ApiBudgetWarnings (2), OracleMembership, OcwNonces
+    /// Writes: OcwNonces, ApiBudgetWarnings
+    fn report_api_budget_exhausted_unsigned() -> Weight {
+        Weight::from_parts(50_000_000, 3_700)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Writes: OutlierRules
+    fn set_outlier_rules() -> Weight {
+        Weight::from_parts(9_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Reads: OracleStates (2), OracleMembership, OcwNonces
+    /// Writes: OcwNonces
+    fn report_rejected_observations_unsigned(n: u32) -> Weight {
+        Weight::from_parts(50_000_000, 3_800)
+            .saturating_add(Weight::from_parts(1_500_000, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Reads: LocationRegistry
+    /// Writes: LocationRegistry
+    fn set_location_metadata() -> Weight {
+        Weight::from_parts(14_000_000, 3_900)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Reads: LocationRegistry (2), OracleMembership, OcwNonces
+    /// Writes: OcwNonces, LocationRegistry
+    fn submit_location_metadata_unsigned() -> Weight {
+        Weight::from_parts(50_000_000, 3_900)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Reads: SettledAt, OracleStates, PolicyMetadata, FinalReports
+    /// Writes: OracleStates, PolicyMetadata, FinalReports, PolicyOutlierRules,
+    /// SnapshotRateLimit, SettledAt, SettledRecordHashes
+    fn purge_settled() -> Weight {
+        Weight::from_parts(30_000_000, 4_200)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(7_u64))
+    }
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+    fn add_location() -> Weight {
+        Weight::from_parts(40_000_000, 6_500)
+            .saturating_add(RocksDbWeight::get().reads(10_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    fn remove_location() -> Weight {
+        Weight::from_parts(16_000_000, 4_200)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    fn add_oracle_member() -> Weight {
+        Weight::from_parts(11_000_000, 3_500)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn remove_oracle_member() -> Weight {
+        Weight::from_parts(11_000_000, 3_500)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn submit_snapshot() -> Weight {
+        Weight::from_parts(24_000_000, 3_800)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    fn submit_snapshot_unsigned() -> Weight {
+        Weight::from_parts(70_000_000, 3_800)
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+    fn submit_final_report(a: u32) -> Weight {
+        Weight::from_parts(150_000_000, 8_000)
+            .saturating_add(Weight::from_parts(1_200_000, 0).saturating_mul(a.into()))
+            .saturating_add(RocksDbWeight::get().reads(24_u64))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(a.into())))
+            .saturating_add(RocksDbWeight::get().writes(22_u64))
+            .saturating_add(Weight::from_parts(0, 2_500).saturating_mul(a.into()))
+    }
+    fn submit_final_report_unsigned() -> Weight {
+        Weight::from_parts(170_000_000, 8_000)
+            .saturating_add(RocksDbWeight::get().reads(21_u64))
+            .saturating_add(RocksDbWeight::get().writes(21_u64))
+    }
+    fn expire_request_unsigned() -> Weight {
+        Weight::from_parts(110_000_000, 6_200)
+            .saturating_add(RocksDbWeight::get().reads(7_u64))
+            .saturating_add(RocksDbWeight::get().writes(6_u64))
+    }
+    fn attest_final_report(a: u32) -> Weight {
+        Weight::from_parts(140_000_000, 8_000)
+            .saturating_add(Weight::from_parts(1_200_000, 0).saturating_mul(a.into()))
+            .saturating_add(RocksDbWeight::get().reads(21_u64))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(a.into())))
+            .saturating_add(RocksDbWeight::get().writes(21_u64))
+            .saturating_add(Weight::from_parts(0, 2_500).saturating_mul(a.into()))
+    }
+    fn verify_commitment_chain(n: u32) -> Weight {
+        Weight::from_parts(18_000_000, 3_800)
+            .saturating_add(Weight::from_parts(4_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+    }
+    fn report_ingest_backlog_unsigned() -> Weight {
+        Weight::from_parts(55_000_000, 3_800)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn expire_rfq_unsigned() -> Weight {
+        Weight::from_parts(240_000_000, 9_000)
+            .saturating_add(RocksDbWeight::get().reads(37_u64))
+            .saturating_add(RocksDbWeight::get().writes(35_u64))
+    }
+    fn rotate_oracle_member() -> Weight {
+        Weight::from_parts(22_000_000, 4_500)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(5_u64))
+    }
+    fn retire_oracle_members(n: u32) -> Weight {
+        Weight::from_parts(3_000_000, 1_500)
+            .saturating_add(Weight::from_parts(14_000_000, 3_500).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+            .saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+    }
+    fn dispute_final_report(n: u32) -> Weight {
+        Weight::from_parts(30_000_000, 6_000)
+            .saturating_add(Weight::from_parts(4_500_000, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    fn check_snapshot_gaps(n: u32) -> Weight {
+        Weight::from_parts(3_000_000, 1_500)
+            .saturating_add(Weight::from_parts(12_000_000, 3_800).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+            .saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+    }
+    fn report_api_budget_exhausted_unsigned() -> Weight {
+        Weight::from_parts(50_000_000, 3_700)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    fn set_outlier_rules() -> Weight {
+        Weight::from_parts(9_000_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn report_rejected_observations_unsigned(n: u32) -> Weight {
+        Weight::from_parts(50_000_000, 3_800)
+            .saturating_add(Weight::from_parts(1_500_000, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn set_location_metadata() -> Weight {
+        Weight::from_parts(14_000_000, 3_900)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn submit_location_metadata_unsigned() -> Weight {
+        Weight::from_parts(50_000_000, 3_900)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    fn purge_settled() -> Weight {
+        Weight::from_parts(30_000_000, 4_200)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(7_u64))
+    }
+}
@@ -6,7 +6,14 @@
 //!
 //! - Policy: Represents an active insurance contract with coverage details
 //! - Settlement: Handles trigger (payout to holder) and maturity (distribute to LPs)
-//! - Per-policy pool account: Holds premium + collateral until settlement
+//! - Per-policy pool account: Holds premium (and RFQ collateral) until settlement
+//! - Underwriter collateral: Obligations of fills backed by market-v3 collateral
+//!   accounts are drawn into the pool on a trigger and released at maturity
+//! - Underwriter shares: Each fill is recorded as (underwriter, shares); settlement
+//!   reports every underwriter's premium and pro-rata payout obligation
+//! - Settlement records: Outcome and amounts of each settled policy, kept for audit
+//! - Pool account map: Each policy's derived pool account is recorded at creation
+//!   (`PolicyPoolAccounts`, reverse lookup in `PoolAccountPolicies`)
 //! - Integrates with holdings pallet for LP token management
 
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -23,8 +30,9 @@ use frame_support::traits::Get;
 use frame_system::pallet_prelude::*;
 use pallet_oracle_v3::LocationId;
 use prmx_primitives::{
-    AggStateV3, EventSpecV3, PolicyId, PolicyStatusV3, V3_PAYOUT_PER_SHARE,
+    AggStateV3, AreaSpecV3, EventSpecV3, PolicyId, PolicyStatusV3, V3_PAYOUT_PER_SHARE,
 };
+use prmx_time::ChainTime;
 use sp_core::H256;
 use sp_runtime::traits::{AccountIdConversion, Zero};
 
@@ -50,6 +58,13 @@ pub trait HoldingsApiV3<AccountId> {
         amount: u128,
     ) -> Result<(), DispatchError>;
 
+    /// Burn LP tokens from an account for a specific policy
+    fn burn_lp_tokens(
+        policy_id: PolicyId,
+        from: &AccountId,
+        amount: u128,
+    ) -> Result<(), DispatchError>;
+
     /// Register an LP holder for a policy
     fn register_lp_holder(policy_id: PolicyId, holder: &AccountId) -> Result<(), DispatchError>;
 
@@ -77,6 +92,9 @@ impl<AccountId> HoldingsApiV3<AccountId> for () {
     fn mint_lp_tokens(_: PolicyId, _: &AccountId, _: u128) -> Result<(), DispatchError> {
         Ok(())
     }
+    fn burn_lp_tokens(_: PolicyId, _: &AccountId, _: u128) -> Result<(), DispatchError> {
+        Ok(())
+    }
     fn register_lp_holder(_: PolicyId, _: &AccountId) -> Result<(), DispatchError> {
         Ok(())
     }
@@ -138,6 +156,32 @@ impl<AccountId> CapitalApiV3<AccountId> for () {
     }
 }
 
+/// Trait for underwriter collateral accounts (market pallet integration)
+pub trait CollateralApiV3<AccountId> {
+    type Balance;
+
+    /// Move the obligations backing a triggered policy into `to`; returns the amount drawn
+    fn draw_obligations(
+        policy_id: PolicyId,
+        to: &AccountId,
+    ) -> Result<Self::Balance, DispatchError>;
+
+    /// Release the obligations backing a matured policy
+    fn release_obligations(policy_id: PolicyId) -> Result<(), DispatchError>;
+}
+
+/// No-op implementation
+impl<AccountId> CollateralApiV3<AccountId> for () {
+    type Balance = u128;
+
+    fn draw_obligations(_: PolicyId, _: &AccountId) -> Result<u128, DispatchError> {
+        Ok(0)
+    }
+    fn release_obligations(_: PolicyId) -> Result<(), DispatchError> {
+        Ok(())
+    }
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -177,6 +221,20 @@ pub mod pallet {
         pub created_at: u64,
     }
 
+    /// Outcome of a settled policy
+    #[derive(Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct SettlementRecordV3<T: Config> {
+        /// Whether the policy settled on a trigger report
+        pub triggered: bool,
+        /// Amount paid to the holder
+        pub payout_to_holder: T::Balance,
+        /// Amount distributed to LP holders
+        pub distributed_to_lps: T::Balance,
+        /// Block of settlement
+        pub settled_at: BlockNumberFor<T>,
+    }
+
     // =========================================================================
     //                                  Config
     // =========================================================================
@@ -215,10 +273,20 @@ pub mod pallet {
         /// Capital API for DeFi integration
         type CapitalApi: CapitalApiV3<Self::AccountId, Balance = Self::Balance>;
 
+        /// Underwriter collateral accounts backing fills
+        type CollateralApi: CollateralApiV3<Self::AccountId, Balance = Self::Balance>;
+
         /// Maximum LP holders per policy
         #[pallet::constant]
         type MaxLpHoldersPerPolicy: Get<u32>;
 
+        /// Maximum distinct underwriters filling one policy
+        #[pallet::constant]
+        type MaxUnderwritersPerPolicy: Get<u32>;
+
+        /// Source of chain time for policy creation timestamps
+        type TimeProvider: ChainTime;
+
         /// Weight info
         type WeightInfo: WeightInfo;
     }
@@ -253,6 +321,35 @@ pub mod pallet {
     pub type PolicyPoolBalance<T: Config> =
         StorageMap<_, Blake2_128Concat, PolicyId, T::Balance, ValueQuery>;
 
+    /// Shares underwritten per underwriter, in fill order
+    #[pallet::storage]
+    #[pallet::getter(fn policy_underwriters)]
+    pub type PolicyUnderwriters<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        PolicyId,
+        BoundedVec<(T::AccountId, u128), T::MaxUnderwritersPerPolicy>,
+        ValueQuery,
+    >;
+
+    /// Outcome of each settled policy
+    #[pallet::storage]
+    #[pallet::getter(fn settlement_records)]
+    pub type SettlementRecords<T: Config> =
+        StorageMap<_, Blake2_128Concat, PolicyId, SettlementRecordV3<T>, OptionQuery>;
+
+    /// Pool account derived for each policy, recorded at creation
+    #[pallet::storage]
+    #[pallet::getter(fn policy_pool_accounts)]
+    pub type PolicyPoolAccounts<T: Config> =
+        StorageMap<_, Blake2_128Concat, PolicyId, T::AccountId, OptionQuery>;
+
+    /// Policy owning each recorded pool account (reverse of `PolicyPoolAccounts`)
+    #[pallet::storage]
+    #[pallet::getter(fn pool_account_policies)]
+    pub type PoolAccountPolicies<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, PolicyId, OptionQuery>;
+
     // =========================================================================
     //                                  Events
     // =========================================================================
@@ -295,6 +392,22 @@ pub mod pallet {
             shares: u128,
             new_total: u128,
         },
+        /// Shares removed from policy by a forced position reduction
+        SharesRemoved {
+            policy_id: PolicyId,
+            underwriter: T::AccountId,
+            shares: u128,
+            new_total: u128,
+        },
+        /// Underwriter's part of a settled policy: premium earned on its shares and
+        /// its pro-rata share of the payout (zero when the policy matured)
+        UnderwriterSettled {
+            policy_id: PolicyId,
+            underwriter: T::AccountId,
+            shares: u128,
+            premium: T::Balance,
+            payout_obligation: T::Balance,
+        },
     }
 
     // =========================================================================
@@ -319,6 +432,8 @@ pub mod pallet {
         PolicyAlreadyExists,
         /// Invalid shares amount
         InvalidSharesAmount,
+        /// Policy already has `MaxUnderwritersPerPolicy` underwriters
+        TooManyUnderwriters,
     }
 
     // =========================================================================
@@ -340,16 +455,20 @@ pub mod pallet {
             PALLET_ID.into_sub_account_truncating(("policy", policy_id))
         }
 
-        /// Create a new policy (called by market pallet)
+        /// Create a new policy (called by market pallet) with its first fill by
+        /// `underwriter`. `area` is set for area policies, whose primary location is
+        /// `location_id`.
         pub fn create_policy(
             policy_id: PolicyId,
             holder: T::AccountId,
+            underwriter: T::AccountId,
             location_id: LocationId,
             event_spec: EventSpecV3,
             initial_shares: u128,
             premium_per_share: T::Balance,
             coverage_start: u64,
             coverage_end: u64,
+            area: Option<AreaSpecV3>,
         ) -> DispatchResult {
             ensure!(
                 !Policies::<T>::contains_key(policy_id),
@@ -370,10 +489,15 @@ pub mod pallet {
                 coverage_end,
                 status: PolicyStatusV3::Active,
                 defi_allocated: false,
-                created_at: Self::current_timestamp(),
+                created_at: T::TimeProvider::now_secs(),
             };
 
             Policies::<T>::insert(policy_id, policy);
+            Self::record_underwriter_shares(policy_id, underwriter, initial_shares)?;
+
+            let pool_account = Self::policy_pool_account(policy_id);
+            PolicyPoolAccounts::<T>::insert(policy_id, &pool_account);
+            PoolAccountPolicies::<T>::insert(&pool_account, policy_id);
 
             // Initialize oracle state
             pallet_oracle_v3::Pallet::<T>::initialize_oracle_state(
@@ -382,6 +506,7 @@ pub mod pallet {
                 location_id,
                 coverage_start,
                 coverage_end,
+                area,
             )?;
 
             Self::deposit_event(Event::PolicyCreated {
@@ -412,6 +537,7 @@ pub mod pallet {
                     .total_shares
                     .checked_add(shares)
                     .ok_or(Error::<T>::ArithmeticOverflow)?;
+                Self::record_underwriter_shares(policy_id, underwriter.clone(), shares)?;
 
                 Self::deposit_event(Event::SharesAdded {
                     policy_id,
@@ -424,6 +550,114 @@ pub mod pallet {
             })
         }
 
+        /// Remove shares of an underwriter's fill from an active policy (called by the
+        /// market pallet when it reduces an undercollateralized position), burning
+        /// the LP tokens minted for them. Fails if the underwriter no longer holds
+        /// those tokens. Returns the policy holder.
+        pub fn remove_shares_from_policy(
+            policy_id: PolicyId,
+            underwriter: T::AccountId,
+            shares: u128,
+        ) -> Result<T::AccountId, DispatchError> {
+            Policies::<T>::try_mutate(policy_id, |maybe_policy| {
+                let policy = maybe_policy.as_mut().ok_or(Error::<T>::PolicyNotFound)?;
+
+                ensure!(
+                    policy.status == PolicyStatusV3::Active,
+                    Error::<T>::PolicyNotActive
+                );
+
+                PolicyUnderwriters::<T>::try_mutate(policy_id, |fills| -> DispatchResult {
+                    let (_, filled) = fills
+                        .iter_mut()
+                        .find(|(who, _)| *who == underwriter)
+                        .ok_or(Error::<T>::InvalidSharesAmount)?;
+                    *filled = filled
+                        .checked_sub(shares)
+                        .ok_or(Error::<T>::InvalidSharesAmount)?;
+                    Ok(())
+                })?;
+                policy.total_shares = policy
+                    .total_shares
+                    .checked_sub(shares)
+                    .ok_or(Error::<T>::InvalidSharesAmount)?;
+                T::HoldingsApi::burn_lp_tokens(policy_id, &underwriter, shares)?;
+
+                Self::deposit_event(Event::SharesRemoved {
+                    policy_id,
+                    underwriter,
+                    shares,
+                    new_total: policy.total_shares,
+                });
+
+                Ok(policy.holder.clone())
+            })
+        }
+
+        /// Add `shares` to an underwriter's fill, appending new underwriters
+        fn record_underwriter_shares(
+            policy_id: PolicyId,
+            underwriter: T::AccountId,
+            shares: u128,
+        ) -> DispatchResult {
+            PolicyUnderwriters::<T>::try_mutate(policy_id, |fills| -> DispatchResult {
+                if let Some((_, filled)) = fills.iter_mut().find(|(who, _)| *who == underwriter) {
+                    *filled = filled.checked_add(shares).ok_or(Error::<T>::ArithmeticOverflow)?;
+                    return Ok(());
+                }
+                fills
+                    .try_push((underwriter, shares))
+                    .map_err(|_| Error::<T>::TooManyUnderwriters)?;
+                Ok(())
+            })
+        }
+
+        /// `amount * shares / total_shares`, rounded down
+        fn pro_rata(amount: T::Balance, shares: u128, total_shares: u128) -> T::Balance {
+            sp_runtime::helpers_128bit::multiply_by_rational_with_rounding(
+                amount.into(),
+                shares,
+                total_shares.max(1),
+                sp_runtime::Rounding::Down,
+            )
+            .unwrap_or_default()
+            .into()
+        }
+
+        /// Each underwriter's shares, premium and share of `payout`, in fill order
+        pub fn underwriter_breakdown(
+            policy: &PolicyInfoV3<T>,
+            payout: T::Balance,
+        ) -> Vec<(T::AccountId, u128, T::Balance, T::Balance)> {
+            let premium_per_share: u128 = policy.premium_per_share.into();
+            PolicyUnderwriters::<T>::get(policy.policy_id)
+                .into_iter()
+                .map(|(underwriter, shares)| {
+                    (
+                        underwriter,
+                        shares,
+                        shares.saturating_mul(premium_per_share).into(),
+                        Self::pro_rata(payout, shares, policy.total_shares),
+                    )
+                })
+                .collect()
+        }
+
+        /// Emit each underwriter's premium and share of `payout`
+        fn report_underwriter_settlement(policy: &PolicyInfoV3<T>, payout: T::Balance) {
+            for (underwriter, shares, premium, payout_obligation) in
+                Self::underwriter_breakdown(policy, payout)
+            {
+                Self::deposit_event(Event::UnderwriterSettled {
+                    policy_id: policy.policy_id,
+                    underwriter,
+                    shares,
+                    premium,
+                    payout_obligation,
+                });
+            }
+        }
+
         /// Allocate a specific amount to DeFi (called incrementally per acceptance)
         pub fn allocate_to_defi(policy_id: PolicyId, amount: T::Balance) -> DispatchResult {
             // Ensure policy exists
@@ -519,12 +753,20 @@ pub mod pallet {
                 .ok_or(Error::<T>::ArithmeticOverflow)?;
             let max_payout: T::Balance = max_payout_u128.into();
 
+            // Underwriter collateral backs the payout on a trigger and is freed at maturity
+            if triggered {
+                T::CollateralApi::draw_obligations(policy_id, &pool_account)?;
+            } else {
+                T::CollateralApi::release_obligations(policy_id)?;
+            }
+
             T::CapitalApi::ensure_local_liquidity(policy_id, max_payout)?;
 
             // Get actual pool balance
             let pool_balance = T::Assets::balance(T::UsdtAssetId::get(), &pool_account);
 
             let payout: T::Balance;
+            let distributed: T::Balance;
 
             if triggered {
                 // Triggered: pay out to policyholder
@@ -552,6 +794,7 @@ pub mod pallet {
                 }
 
                 payout = actual_payout;
+                distributed = T::Balance::zero();
                 policy.status = PolicyStatusV3::Settled;
 
                 // Cleanup LP tokens
@@ -569,6 +812,7 @@ pub mod pallet {
                 T::HoldingsApi::cleanup_policy_lp_tokens(policy_id)?;
 
                 payout = T::Balance::zero();
+                distributed = pool_balance;
                 policy.status = PolicyStatusV3::Settled;
 
                 Self::deposit_event(Event::PolicyMatured {
@@ -577,6 +821,17 @@ pub mod pallet {
                 });
             }
 
+            Self::report_underwriter_settlement(&policy, payout);
+
+            SettlementRecords::<T>::insert(
+                policy_id,
+                SettlementRecordV3 {
+                    triggered,
+                    payout_to_holder: payout,
+                    distributed_to_lps: distributed,
+                    settled_at: frame_system::Pallet::<T>::block_number(),
+                },
+            );
             Policies::<T>::insert(policy_id, policy);
             PolicyPoolBalance::<T>::insert(policy_id, T::Balance::zero());
 
@@ -616,16 +871,18 @@ pub mod pallet {
             Policies::<T>::get(policy_id)
         }
 
+        /// Pool account of a policy (None if the policy does not exist)
+        pub fn pool_account_of(policy_id: PolicyId) -> Option<T::AccountId> {
+            PolicyPoolAccounts::<T>::get(policy_id).or_else(|| {
+                Policies::<T>::contains_key(policy_id)
+                    .then(|| Self::policy_pool_account(policy_id))
+            })
+        }
+
         /// Check if policy exists
         pub fn policy_exists(policy_id: PolicyId) -> bool {
             Policies::<T>::contains_key(policy_id)
         }
-
-        /// Get current timestamp (placeholder - should use pallet_timestamp)
-        fn current_timestamp() -> u64 {
-            // In production, use pallet_timestamp
-            0
-        }
     }
 }
 
@@ -657,7 +914,8 @@ pub trait PolicyPoolAccountApi<AccountId> {
 
 impl<T: Config> PolicyPoolAccountApi<T::AccountId> for Pallet<T> {
     fn policy_pool_account(policy_id: PolicyId) -> T::AccountId {
-        pallet::Pallet::<T>::policy_pool_account(policy_id)
+        pallet::PolicyPoolAccounts::<T>::get(policy_id)
+            .unwrap_or_else(|| pallet::Pallet::<T>::policy_pool_account(policy_id))
     }
 }
 
@@ -9,6 +9,11 @@
 //! - Each policy has its own isolated LP token pool.
 //! - Provides trait `HoldingsApi` for other pallets to mint/burn/transfer LP tokens.
 //! - **Automatic LP payout distribution** when policies settle.
+//! - **Redemption queue**: LP holders can queue LP shares for buy-back by the DAO,
+//!   paid from capital the DAO receives as policies settle (see `redemption`).
+//!   Redemptions are budgeted in USDT, so only LP shares of USDT-denominated
+//!   policies can be queued; other policies' shares trade on the orderbook.
+//! - LP payouts are made in each policy's denomination asset (`PolicyAssets`).
 //!
 //! ## Key Design Decision
 //!
@@ -21,7 +26,14 @@
 
 extern crate alloc;
 
+pub mod redemption;
+pub mod weights;
+
 pub use pallet::*;
+pub use weights::WeightInfo;
+
+use alloc::vec::Vec;
+use prmx_primitives::PolicyAssetApi;
 
 /// Policy ID type - re-exported from primitives
 pub use prmx_primitives::PolicyId;
@@ -103,11 +115,21 @@ pub trait HoldingsApi<AccountId> {
     ) -> Result<(), sp_runtime::DispatchError>;
 }
 
+/// Price at which the DAO buys back LP shares of a policy (implemented by the
+/// policy pallet)
+pub trait LpRedemptionPriceApi<Balance> {
+    /// Redemption price per LP share, or `None` if the policy's LP shares can no
+    /// longer be redeemed (settled, or coverage over)
+    fn redemption_price(policy_id: PolicyId) -> Option<Balance>;
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
     use frame_support::pallet_prelude::*;
     use frame_support::traits::fungibles::{Inspect, Mutate};
+    use frame_system::pallet_prelude::*;
+    use sp_runtime::traits::Saturating;
 
     // =========================================================================
     //                                  Types
@@ -122,6 +144,22 @@ pub mod pallet {
         pub locked_shares: u128,
     }
 
+    /// Identifier of a queued redemption
+    pub type RedemptionId = u64;
+
+    /// LP shares queued for buy-back by the DAO
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct RedemptionRequest<T: Config> {
+        pub policy_id: PolicyId,
+        pub who: T::AccountId,
+        /// Shares originally queued
+        pub shares: u128,
+        /// Shares still waiting to be redeemed (locked in holdings)
+        pub remaining: u128,
+        pub requested_at: BlockNumberFor<T>,
+    }
+
     /// Asset ID type alias
     pub type AssetIdOf<T> = <<T as Config>::Assets as Inspect<<T as frame_system::Config>::AccountId>>::AssetId;
     pub type AssetBalanceOf<T> = <<T as Config>::Assets as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
@@ -137,16 +175,38 @@ pub mod pallet {
         /// Balance type
         type Balance: Parameter + Member + From<u128> + Into<u128> + Copy + Default + MaxEncodedLen;
 
-        /// Assets pallet for USDT transfers
+        /// Assets pallet for LP payouts and redemptions
         type Assets: Inspect<Self::AccountId> + Mutate<Self::AccountId>;
 
-        /// USDT Asset ID
+        /// USDT Asset ID, the redemption currency and the denomination of
+        /// policies without a recorded asset
         #[pallet::constant]
         type UsdtAssetId: Get<AssetIdOf<Self>>;
 
+        /// Denomination asset of each policy
+        type PolicyAssets: PolicyAssetApi<AssetIdOf<Self>>;
+
         /// Maximum number of LP holders per policy (for bounded iteration)
         #[pallet::constant]
         type MaxLpHoldersPerPolicy: Get<u32>;
+
+        /// DAO account that receives LP payouts and buys back redeemed LP shares
+        #[pallet::constant]
+        type DaoAccountId: Get<Self::AccountId>;
+
+        /// Price at which the DAO redeems LP shares
+        type RedemptionPrice: LpRedemptionPriceApi<Self::Balance>;
+
+        /// Blocks between redemption epochs
+        #[pallet::constant]
+        type RedemptionEpochBlocks: Get<BlockNumberFor<Self>>;
+
+        /// Maximum queued redemption requests
+        #[pallet::constant]
+        type MaxRedemptionRequests: Get<u32>;
+
+        /// Weight info for extrinsics and redemption processing
+        type WeightInfo: WeightInfo;
     }
 
     // =========================================================================
@@ -204,6 +264,37 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// Queued redemption requests by ID
+    #[pallet::storage]
+    #[pallet::getter(fn redemption_requests)]
+    pub type RedemptionRequests<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        RedemptionId,
+        RedemptionRequest<T>,
+        OptionQuery,
+    >;
+
+    /// Redemption requests in queue order (oldest first)
+    #[pallet::storage]
+    #[pallet::getter(fn redemption_queue)]
+    pub type RedemptionQueue<T: Config> =
+        StorageValue<_, BoundedVec<RedemptionId, T::MaxRedemptionRequests>, ValueQuery>;
+
+    /// Next redemption ID
+    #[pallet::storage]
+    pub type NextRedemptionId<T: Config> = StorageValue<_, RedemptionId, ValueQuery>;
+
+    /// Capital paid to the DAO by settled policies and not yet spent on redemptions
+    #[pallet::storage]
+    #[pallet::getter(fn released_capital)]
+    pub type ReleasedCapital<T: Config> = StorageValue<_, T::Balance, ValueQuery>;
+
+    /// Block of the last processed redemption epoch
+    #[pallet::storage]
+    #[pallet::getter(fn last_redemption_epoch)]
+    pub type LastRedemptionEpoch<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
     // =========================================================================
     //                                  Events
     // =========================================================================
@@ -259,6 +350,37 @@ pub mod pallet {
             total_burned: u128,
             num_holders: u32,
         },
+        /// LP shares queued for redemption. [request_id, policy_id, who, shares]
+        RedemptionQueued {
+            request_id: RedemptionId,
+            policy_id: PolicyId,
+            who: T::AccountId,
+            shares: u128,
+        },
+        /// Part of a redemption filled this epoch. [request_id, shares, paid, remaining]
+        RedemptionPartiallyFilled {
+            request_id: RedemptionId,
+            shares: u128,
+            paid: T::Balance,
+            remaining: u128,
+        },
+        /// Redemption fully filled. [request_id, shares, paid]
+        RedemptionCompleted {
+            request_id: RedemptionId,
+            shares: u128,
+            paid: T::Balance,
+        },
+        /// Redemption removed from the queue; unfilled shares were returned, or
+        /// paid out by settlement if the policy closed. [request_id, remaining]
+        RedemptionCancelled {
+            request_id: RedemptionId,
+            remaining: u128,
+        },
+        /// Redemption epoch processed. [released_capital_spent, requests]
+        RedemptionEpochProcessed {
+            spent: T::Balance,
+            requests: u32,
+        },
     }
 
     // =========================================================================
@@ -279,6 +401,50 @@ pub mod pallet {
         TransferFailed,
         /// No LP shares in policy.
         NoLpShares,
+        /// Redemption request not found.
+        RedemptionNotFound,
+        /// Not the owner of the redemption request.
+        NotRedemptionOwner,
+        /// LP shares of this policy cannot be redeemed.
+        RedemptionUnavailable,
+        /// The DAO is the redemption counterparty and cannot redeem.
+        DaoCannotRedeem,
+        /// Redemption queue is full.
+        RedemptionQueueFull,
+        /// Invalid share amount.
+        InvalidAmount,
+        /// Only LP shares of USDT-denominated policies can be redeemed.
+        RedemptionAssetUnsupported,
+    }
+
+    // =========================================================================
+    //                                  Hooks
+    // =========================================================================
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// Process queued redemptions once per epoch with leftover block weight
+        fn on_idle(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let check = T::DbWeight::get().reads(2);
+            if remaining_weight.any_lt(check) {
+                return Weight::zero();
+            }
+
+            let next_epoch = LastRedemptionEpoch::<T>::get()
+                .saturating_add(T::RedemptionEpochBlocks::get());
+            let queued = RedemptionQueue::<T>::get().len() as u32;
+            if n < next_epoch || queued == 0 {
+                return check;
+            }
+
+            let needed = T::WeightInfo::process_redemptions(queued);
+            if remaining_weight.any_lt(needed) {
+                return check;
+            }
+
+            Self::process_redemption_epoch(n);
+            needed
+        }
     }
 
     // =========================================================================
@@ -287,7 +453,88 @@ pub mod pallet {
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
-        // No public extrinsics - all operations go through HoldingsApi
+        /// Queue LP shares of a policy for buy-back by the DAO.
+        ///
+        /// The shares are locked until redeemed or the request is cancelled. Requests
+        /// are filled at the policy's redemption price each epoch, as far as capital
+        /// released by settled policies allows.
+        ///
+        /// - `policy_id`: The policy whose LP shares to redeem.
+        /// - `shares`: Number of free LP shares to queue.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::request_redemption())]
+        pub fn request_redemption(
+            origin: OriginFor<T>,
+            policy_id: PolicyId,
+            shares: u128,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(shares > 0, Error::<T>::InvalidAmount);
+            ensure!(who != T::DaoAccountId::get(), Error::<T>::DaoCannotRedeem);
+            ensure!(
+                T::RedemptionPrice::redemption_price(policy_id).is_some(),
+                Error::<T>::RedemptionUnavailable
+            );
+            ensure!(
+                Self::policy_asset(policy_id) == T::UsdtAssetId::get(),
+                Error::<T>::RedemptionAssetUnsupported
+            );
+
+            Self::do_lock_lp_tokens(policy_id, &who, shares)?;
+
+            let request_id = NextRedemptionId::<T>::get();
+            RedemptionQueue::<T>::try_mutate(|queue| {
+                queue.try_push(request_id).map_err(|_| Error::<T>::RedemptionQueueFull)
+            })?;
+            NextRedemptionId::<T>::put(request_id.saturating_add(1));
+
+            RedemptionRequests::<T>::insert(request_id, RedemptionRequest::<T> {
+                policy_id,
+                who: who.clone(),
+                shares,
+                remaining: shares,
+                requested_at: frame_system::Pallet::<T>::block_number(),
+            });
+
+            Self::deposit_event(Event::RedemptionQueued {
+                request_id,
+                policy_id,
+                who,
+                shares,
+            });
+
+            Ok(())
+        }
+
+        /// Cancel a queued redemption and unlock the shares not yet redeemed.
+        ///
+        /// - `request_id`: The redemption to cancel.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::cancel_redemption())]
+        pub fn cancel_redemption(
+            origin: OriginFor<T>,
+            request_id: RedemptionId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let request = RedemptionRequests::<T>::get(request_id)
+                .ok_or(Error::<T>::RedemptionNotFound)?;
+            ensure!(request.who == who, Error::<T>::NotRedemptionOwner);
+
+            // Shares of a settled policy were already burned by cleanup
+            if TotalLpShares::<T>::get(request.policy_id) > 0 {
+                Self::do_unlock_lp_tokens(request.policy_id, &who, request.remaining)?;
+            }
+            Self::remove_redemption(request_id);
+
+            Self::deposit_event(Event::RedemptionCancelled {
+                request_id,
+                remaining: request.remaining,
+            });
+
+            Ok(())
+        }
     }
 
     // =========================================================================
@@ -295,6 +542,11 @@ pub mod pallet {
     // =========================================================================
 
     impl<T: Config> Pallet<T> {
+        /// Asset a policy's LP payouts are made in
+        pub fn policy_asset(policy_id: PolicyId) -> AssetIdOf<T> {
+            T::PolicyAssets::policy_asset(policy_id).unwrap_or_else(T::UsdtAssetId::get)
+        }
+
         /// Internal function to mint LP tokens for a policy
         pub fn do_mint_lp_tokens(
             policy_id: PolicyId,
@@ -508,7 +760,7 @@ pub mod pallet {
             Ok(())
         }
 
-        /// Distribute USDT to all LP holders pro-rata for a specific policy
+        /// Distribute the policy's asset to all LP holders pro-rata for a specific policy
         /// This is the automatic payout mechanism
         pub fn do_distribute_to_lp_holders(
             policy_id: PolicyId,
@@ -519,6 +771,8 @@ pub mod pallet {
             ensure!(total_lp_shares > 0, Error::<T>::NoLpShares);
 
             let holders = LpHolders::<T>::get(policy_id);
+            let asset = Self::policy_asset(policy_id);
+            let is_usdt = asset == T::UsdtAssetId::get();
             let total_amount_u128: u128 = total_amount.into();
             let mut distributed: u128 = 0;
             let num_holders = holders.len() as u32;
@@ -536,14 +790,22 @@ pub mod pallet {
                     if payout_u128 > 0 {
                         let payout: AssetBalanceOf<T> = payout_u128.try_into().unwrap_or_default();
                         
-                        // Transfer USDT from source to LP holder
+                        // Transfer the policy's asset from source to LP holder
                         T::Assets::transfer(
-                            T::UsdtAssetId::get(),
+                            asset,
                             from_account,
                             holder,
                             payout,
                             frame_support::traits::tokens::Preservation::Expendable,
                         ).map_err(|_| Error::<T>::TransferFailed)?;
+
+                        // The DAO's USDT share is released capital available for redemptions
+                        if is_usdt && *holder == T::DaoAccountId::get() {
+                            ReleasedCapital::<T>::mutate(|released| {
+                                let current: u128 = (*released).into();
+                                *released = current.saturating_add(payout_u128).into();
+                            });
+                        }
                         
                         distributed = distributed.saturating_add(payout_u128);
                     }
@@ -560,6 +822,114 @@ pub mod pallet {
             Ok(())
         }
 
+        /// Fill queued redemptions from released capital, pro-rata if it does not
+        /// cover every request. Requests for policies that can no longer be redeemed
+        /// are dropped; their holders were paid by the policy's settlement.
+        pub fn process_redemption_epoch(now: BlockNumberFor<T>) {
+            LastRedemptionEpoch::<T>::put(now);
+
+            let dao = T::DaoAccountId::get();
+            let dao_balance: u128 = T::Assets::balance(T::UsdtAssetId::get(), &dao)
+                .try_into()
+                .unwrap_or(0);
+            let released: u128 = ReleasedCapital::<T>::get().into();
+            let budget = released.min(dao_balance);
+
+            let mut open: Vec<(RedemptionId, RedemptionRequest<T>, u128)> = Vec::new();
+            for request_id in RedemptionQueue::<T>::get() {
+                let Some(request) = RedemptionRequests::<T>::get(request_id) else {
+                    continue;
+                };
+                match T::RedemptionPrice::redemption_price(request.policy_id) {
+                    Some(price) => open.push((request_id, request, price.into())),
+                    None => {
+                        Self::remove_redemption(request_id);
+                        Self::deposit_event(Event::RedemptionCancelled {
+                            request_id,
+                            remaining: request.remaining,
+                        });
+                    }
+                }
+            }
+
+            let terms: Vec<(u128, u128)> = open
+                .iter()
+                .map(|(_, request, price)| (request.remaining, *price))
+                .collect();
+            let fills = redemption::pro_rata_fills(budget, &terms);
+
+            let mut spent: u128 = 0;
+            for ((request_id, mut request, price), shares) in open.into_iter().zip(fills) {
+                if shares == 0 {
+                    continue;
+                }
+                let cost = price.saturating_mul(shares);
+                if Self::redeem(&request, &dao, shares, cost).is_err() {
+                    log::warn!(
+                        target: "prmx-holdings",
+                        "Redemption {} failed; it stays queued",
+                        request_id
+                    );
+                    continue;
+                }
+                spent = spent.saturating_add(cost);
+                request.remaining = request.remaining.saturating_sub(shares);
+
+                if request.remaining == 0 {
+                    Self::remove_redemption(request_id);
+                    Self::deposit_event(Event::RedemptionCompleted {
+                        request_id,
+                        shares: request.shares,
+                        paid: cost.into(),
+                    });
+                } else {
+                    Self::deposit_event(Event::RedemptionPartiallyFilled {
+                        request_id,
+                        shares,
+                        paid: cost.into(),
+                        remaining: request.remaining,
+                    });
+                    RedemptionRequests::<T>::insert(request_id, request);
+                }
+            }
+
+            ReleasedCapital::<T>::put(T::Balance::from(released.saturating_sub(spent)));
+
+            Self::deposit_event(Event::RedemptionEpochProcessed {
+                spent: spent.into(),
+                requests: terms.len() as u32,
+            });
+        }
+
+        /// Pay `cost` from the DAO and move `shares` locked LP shares to it
+        fn redeem(
+            request: &RedemptionRequest<T>,
+            dao: &T::AccountId,
+            shares: u128,
+            cost: u128,
+        ) -> DispatchResult {
+            frame_support::storage::with_storage_layer(|| {
+                let amount: AssetBalanceOf<T> = cost
+                    .try_into()
+                    .map_err(|_| Error::<T>::ArithmeticOverflow)?;
+                T::Assets::transfer(
+                    T::UsdtAssetId::get(),
+                    dao,
+                    &request.who,
+                    amount,
+                    frame_support::traits::tokens::Preservation::Expendable,
+                ).map_err(|_| Error::<T>::TransferFailed)?;
+                Self::do_transfer_locked_lp_tokens(request.policy_id, &request.who, dao, shares)?;
+                Self::do_register_lp_holder(request.policy_id, dao)
+            })
+        }
+
+        /// Remove a redemption request and its queue entry
+        fn remove_redemption(request_id: RedemptionId) {
+            RedemptionRequests::<T>::remove(request_id);
+            RedemptionQueue::<T>::mutate(|queue| queue.retain(|&id| id != request_id));
+        }
+
         /// Cleanup all LP tokens for a settled policy
         /// Burns all LP tokens and clears storage
         pub fn do_cleanup_policy_lp_tokens(
@@ -0,0 +1,62 @@
+//! # LP Redemptions
+//!
+//! LP holders who want to exit before a policy settles can queue a redemption
+//! instead of selling on the orderbook. The DAO buys the queued LP shares back
+//! at the policy's redemption price, paying from the capital it has received
+//! from settled policies (released capital).
+//!
+//! Redemptions are processed once per epoch. If released capital does not cover
+//! every request, each request is filled pro-rata to its value and the rest
+//! stays queued for the next epoch.
+
+use alloc::vec::Vec;
+
+/// Shares to fill for each `(remaining_shares, price_per_share)` request with
+/// `budget` of released capital. The total cost never exceeds the budget.
+pub fn pro_rata_fills(budget: u128, requests: &[(u128, u128)]) -> Vec<u128> {
+    let demand = requests
+        .iter()
+        .fold(0u128, |total, (shares, price)| total.saturating_add(shares.saturating_mul(*price)));
+
+    if demand <= budget {
+        return requests.iter().map(|(shares, _)| *shares).collect();
+    }
+
+    requests
+        .iter()
+        .map(|(shares, _)| shares.saturating_mul(budget) / demand)
+        .collect()
+}
+
+/// Total cost of `fills` at the requests' prices
+pub fn fill_cost(requests: &[(u128, u128)], fills: &[u128]) -> u128 {
+    requests
+        .iter()
+        .zip(fills)
+        .fold(0u128, |total, ((_, price), shares)| total.saturating_add(price.saturating_mul(*shares)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_full_fill_when_budget_covers_demand() {
+        let requests = [(10, 90), (5, 80)];
+        assert_eq!(pro_rata_fills(1_300, &requests), vec![10, 5]);
+        assert_eq!(pro_rata_fills(10_000, &requests), vec![10, 5]);
+        assert!(pro_rata_fills(0, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_pro_rata_fill_within_budget() {
+        // Demand is 1_300; half the budget fills half of every request (rounded down)
+        let requests = [(10, 90), (5, 80)];
+        let fills = pro_rata_fills(650, &requests);
+        assert_eq!(fills, vec![5, 2]);
+        assert!(fill_cost(&requests, &fills) <= 650);
+
+        // Nothing released, nothing filled
+        assert_eq!(pro_rata_fills(0, &requests), vec![0, 0]);
+    }
+}
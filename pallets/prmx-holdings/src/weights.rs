@@ -0,0 +1,81 @@
+//! Weights for `pallet_prmx_holdings`
+//!
+//! Storage reads/writes are counted from the redemption paths in `lib.rs`;
+//! ref-time bases are conservative estimates. The pallet has no benchmarks yet,
+//! so these are hand-counted.
+//!
+//! Components:
+//! - `n`: queued redemption requests processed in an epoch (`MaxRedemptionRequests`)
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use core::marker::PhantomData;
+use frame_support::{
+    traits::Get,
+    weights::{constants::RocksDbWeight, Weight},
+};
+
+/// Weight functions needed for `pallet_prmx_holdings`.
+pub trait WeightInfo {
+    fn request_redemption() -> Weight;
+    fn cancel_redemption() -> Weight;
+    fn process_redemptions(n: u32) -> Weight;
+}
+
+/// Weights for `pallet_prmx_holdings` using the runtime's database weights.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    /// Reads: HoldingsStorage, redemption price (2), NextRedemptionId, RedemptionQueue
+    /// Writes: HoldingsStorage, NextRedemptionId, RedemptionRequests, RedemptionQueue
+    fn request_redemption() -> Weight {
+        Weight::from_parts(30_000_000, 3_500)
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    /// Reads: RedemptionRequests, HoldingsStorage, RedemptionQueue
+    /// Writes: RedemptionRequests, HoldingsStorage, RedemptionQueue
+    fn cancel_redemption() -> Weight {
+        Weight::from_parts(25_000_000, 3_500)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    /// Reads: LastRedemptionEpoch, RedemptionQueue, ReleasedCapital, Assets (DAO),
+    /// per request: RedemptionRequests, redemption price (2), HoldingsStorage (2),
+    /// IsLpHolder, LpHolders, Assets (2)
+    /// Writes: LastRedemptionEpoch, RedemptionQueue, ReleasedCapital, Assets (DAO),
+    /// per request: RedemptionRequests, HoldingsStorage (2), IsLpHolder, LpHolders,
+    /// Assets
+    fn process_redemptions(n: u32) -> Weight {
+        Weight::from_parts(15_000_000, 3_000)
+            .saturating_add(Weight::from_parts(60_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(Weight::from_parts(0, 6_000).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().reads(8_u64).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+            .saturating_add(T::DbWeight::get().writes(6_u64).saturating_mul(n.into()))
+    }
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+    fn request_redemption() -> Weight {
+        Weight::from_parts(30_000_000, 3_500)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+    fn cancel_redemption() -> Weight {
+        Weight::from_parts(25_000_000, 3_500)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    fn process_redemptions(n: u32) -> Weight {
+        Weight::from_parts(15_000_000, 3_000)
+            .saturating_add(Weight::from_parts(60_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(Weight::from_parts(0, 6_000).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().reads(8_u64).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(6_u64).saturating_mul(n.into()))
+    }
+}
@@ -8,11 +8,30 @@
 //! - Each market has center coordinates used by oracle for AccuWeather binding
 //! - Customers choose a market (not coordinates) when requesting coverage
 //! - DAO creates and manages market parameters
+//! - Markets follow a Draft → Open ⇄ Paused → Closing → Archived lifecycle
+//!   (see `lifecycle`); transitions can be scheduled for a block or timestamp
+//! - Each market is denominated in its own base asset (USDT by default); premiums,
+//!   policy pools, LP trades and payouts of its policies all use that asset. The
+//!   base asset can only be changed while the market is in Draft, so quotes and
+//!   policies of a market always agree on it
+//! - Anyone can propose a market by bonding a deposit (see `listing`); the oracle
+//!   checks the location is resolvable with enough data history before the DAO
+//!   approves it into Draft or rejects it, refunding or slashing the deposit
+//! - Premiums and payouts are tracked per market as a loss ratio; a governance rule
+//!   can step each market's DAO margin towards a target loss ratio every
+//!   `MarginEpochBlocks` (see `margin`)
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 extern crate alloc;
 
+pub mod lifecycle;
+pub mod listing;
+pub mod margin;
+
+pub use lifecycle::{MarketStatus, ScheduledTransition, TransitionTrigger};
+pub use listing::{ListingFeasibility, ListingProposalId, MarketListingParams};
+pub use margin::{LossStats, MarginAdjustmentRule};
 pub use pallet::*;
 
 // =============================================================================
@@ -21,10 +40,25 @@ pub use pallet::*;
 
 /// Trait for notifying the oracle pallet when a new market is created.
 /// This allows the oracle to immediately queue a fetch request for the new market.
+/// Also called when an archived market is reopened, so fetching resumes at once.
 pub trait NewMarketNotifier {
     /// Called when a new market is created. Queues the market for immediate
     /// AccuWeather location resolution and rainfall fetch.
     fn notify_new_market(market_id: u64);
+
+    /// Called when a market listing is proposed. Queues the proposal's center
+    /// coordinates for an oracle feasibility check, reported back through
+    /// `MarketsAccess::record_listing_feasibility`.
+    fn notify_listing_proposed(
+        _proposal_id: ListingProposalId,
+        _center_latitude: i32,
+        _center_longitude: i32,
+    ) {
+    }
+
+    /// Called when a listing proposal is approved or rejected, so a feasibility
+    /// check still queued for it can be dropped.
+    fn notify_listing_closed(_proposal_id: ListingProposalId) {}
 }
 
 /// No-op implementation for when oracle notification is not needed
@@ -38,7 +72,10 @@ pub mod pallet {
     use alloc::vec::Vec;
     use codec::DecodeWithMemTracking;
     use frame_support::pallet_prelude::*;
+    use frame_support::traits::{Currency, ReservableCurrency};
     use frame_system::pallet_prelude::*;
+    use prmx_time::ChainTime;
+    use sp_runtime::traits::{Saturating, Zero};
 
     // =========================================================================
     //                                  Types
@@ -103,12 +140,25 @@ pub mod pallet {
         // future variants...
     }
 
-    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-    pub enum MarketStatus {
-        Open,
-        Closed,
-        Settled,
-    }
+    /// Balance of the currency listing deposits are reserved in
+    pub type DepositBalanceOf<T> = <<T as Config>::DepositCurrency as Currency<
+        <T as frame_system::Config>::AccountId,
+    >>::Balance;
+
+    /// A listing proposal as stored in `ListingProposals`
+    pub type ListingProposalOf<T> = listing::ListingProposal<
+        <T as frame_system::Config>::AccountId,
+        DepositBalanceOf<T>,
+        <T as Config>::Balance,
+        <T as Config>::AssetId,
+        BlockNumberFor<T>,
+    >;
+
+    /// Maximum pending scheduled transitions per market
+    pub type MaxScheduledTransitions = ConstU32<4>;
+
+    /// Maximum markets with pending scheduled transitions
+    pub type MaxScheduledMarkets = ConstU32<64>;
 
     /// Market information as defined in design.md section 5.4
     #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
@@ -127,7 +177,8 @@ pub mod pallet {
         pub strike_value: Millimeters,
         /// Payout per share = PAYOUT_PER_SHARE
         pub payout_per_share: Balance,
-        /// Base asset ID (USDT)
+        /// Denomination asset of premiums, pools and payouts (USDT by default).
+        /// Fixed once the market leaves Draft.
         pub base_asset: AssetId,
         pub status: MarketStatus,
         pub risk: RiskParameters,
@@ -155,6 +206,20 @@ pub mod pallet {
         /// Origin that can perform DAO operations (create/update/close markets).
         /// Typically set to Root or a DAO governance origin.
         type DaoOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Time provider for timestamp-scheduled lifecycle transitions
+        type TimeProvider: ChainTime;
+
+        /// Currency listing deposits are reserved in
+        type DepositCurrency: ReservableCurrency<Self::AccountId>;
+
+        /// Deposit reserved from a proposer for each market listing proposal
+        #[pallet::constant]
+        type ListingDeposit: Get<DepositBalanceOf<Self>>;
+
+        /// Blocks per margin adjustment epoch
+        #[pallet::constant]
+        type MarginEpochBlocks: Get<BlockNumberFor<Self>>;
     }
 
     // =========================================================================
@@ -196,6 +261,63 @@ pub mod pallet {
     #[pallet::getter(fn v2_duration_rules)]
     pub type V2DurationRules<T> = StorageMap<_, Blake2_128Concat, MarketId, (u8, u8), ValueQuery>;
 
+    // =========================================================================
+    //                           Lifecycle Storage
+    // =========================================================================
+
+    /// Pending lifecycle transitions per market, in the order they fire.
+    #[pallet::storage]
+    #[pallet::getter(fn market_schedule)]
+    pub type MarketSchedules<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        MarketId,
+        BoundedVec<ScheduledTransition<BlockNumberFor<T>>, MaxScheduledTransitions>,
+        ValueQuery,
+    >;
+
+    /// Markets with an entry in `MarketSchedules`, checked by `on_initialize`.
+    #[pallet::storage]
+    #[pallet::getter(fn scheduled_markets)]
+    pub type ScheduledMarkets<T> =
+        StorageValue<_, BoundedVec<MarketId, MaxScheduledMarkets>, ValueQuery>;
+
+    // =========================================================================
+    //                           Listing Storage
+    // =========================================================================
+
+    /// Market listing proposals awaiting a DAO decision.
+    #[pallet::storage]
+    #[pallet::getter(fn listing_proposals)]
+    pub type ListingProposals<T: Config> =
+        StorageMap<_, Blake2_128Concat, ListingProposalId, ListingProposalOf<T>, OptionQuery>;
+
+    /// Tracks the next available ListingProposalId.
+    #[pallet::storage]
+    #[pallet::getter(fn next_listing_proposal_id)]
+    pub type NextListingProposalId<T> = StorageValue<_, ListingProposalId, ValueQuery>;
+
+    // =========================================================================
+    //                           Loss Ratio Storage
+    // =========================================================================
+
+    /// Premiums and payouts of each market's policies since the market was created.
+    #[pallet::storage]
+    #[pallet::getter(fn market_loss_stats)]
+    pub type MarketLossStats<T> = StorageMap<_, Blake2_128Concat, MarketId, LossStats, ValueQuery>;
+
+    /// Premiums and payouts of each market's policies in the current margin epoch.
+    /// Drained at the end of every epoch.
+    #[pallet::storage]
+    #[pallet::getter(fn epoch_loss_stats)]
+    pub type EpochLossStats<T> = StorageMap<_, Blake2_128Concat, MarketId, LossStats, ValueQuery>;
+
+    /// Markets whose DAO margin follows their loss ratio, with the rule they follow.
+    #[pallet::storage]
+    #[pallet::getter(fn margin_adjustment_rule)]
+    pub type MarginAdjustmentRules<T> =
+        StorageMap<_, Blake2_128Concat, MarketId, MarginAdjustmentRule, OptionQuery>;
+
     // =========================================================================
     //                           Genesis Configuration
     // =========================================================================
@@ -296,10 +418,70 @@ pub mod pallet {
         WindowRulesUpdated { market_id: MarketId },
         /// Risk parameters updated for a market. [market_id]
         RiskParametersUpdated { market_id: MarketId },
-        /// Market closed (no new policies). [market_id]
-        MarketClosed { market_id: MarketId },
-        /// Market settled (all policies settled). [market_id]
-        MarketSettled { market_id: MarketId },
+        /// Market moved to a new lifecycle state. [market_id, from, to]
+        MarketStatusChanged {
+            market_id: MarketId,
+            from: MarketStatus,
+            to: MarketStatus,
+        },
+        /// Lifecycle transition scheduled. [market_id, to, at]
+        MarketTransitionScheduled {
+            market_id: MarketId,
+            to: MarketStatus,
+            at: TransitionTrigger<BlockNumberFor<T>>,
+        },
+        /// A due scheduled transition was dropped because the market's state no
+        /// longer allows it. [market_id, from, to]
+        MarketTransitionSkipped {
+            market_id: MarketId,
+            from: MarketStatus,
+            to: MarketStatus,
+        },
+        /// All pending scheduled transitions of a market cancelled. [market_id]
+        MarketScheduleCleared { market_id: MarketId },
+        /// Denomination asset of a Draft market changed. [market_id, base_asset]
+        BaseAssetUpdated {
+            market_id: MarketId,
+            base_asset: T::AssetId,
+        },
+        /// Market listing proposed and deposit reserved. [proposal_id, proposer, deposit]
+        MarketListingProposed {
+            proposal_id: ListingProposalId,
+            proposer: T::AccountId,
+            deposit: DepositBalanceOf<T>,
+        },
+        /// Oracle feasibility of a listing proposal recorded. [proposal_id, feasibility]
+        ListingFeasibilityRecorded {
+            proposal_id: ListingProposalId,
+            feasibility: ListingFeasibility,
+        },
+        /// Listing approved into a Draft market, deposit refunded. [proposal_id, market_id]
+        MarketListingApproved {
+            proposal_id: ListingProposalId,
+            market_id: MarketId,
+        },
+        /// Listing rejected, deposit refunded or slashed. [proposal_id, slashed]
+        MarketListingRejected {
+            proposal_id: ListingProposalId,
+            slashed: DepositBalanceOf<T>,
+        },
+        /// Loss ratio margin rule set, or removed if `None`. [market_id, rule]
+        MarginAdjustmentRuleSet {
+            market_id: MarketId,
+            rule: Option<MarginAdjustmentRule>,
+        },
+        /// A market's margin rule ran at the end of an epoch. `new_margin_bp` may
+        /// equal `old_margin_bp` when the loss ratio is within tolerance or the
+        /// margin is at a bound.
+        /// [market_id, old_margin_bp, new_margin_bp, premiums, payouts, loss_ratio_bp]
+        DaoMarginAdjusted {
+            market_id: MarketId,
+            old_margin_bp: BasisPoints,
+            new_margin_bp: BasisPoints,
+            premiums: u128,
+            payouts: u128,
+            loss_ratio_bp: BasisPoints,
+        },
     }
 
     // =========================================================================
@@ -330,6 +512,42 @@ pub mod pallet {
         V2InvalidDuration,
         /// Market does not support V2 policies.
         MarketNotV2Enabled,
+        /// The market's current state does not allow this transition.
+        InvalidStatusTransition,
+        /// Transition trigger is not in the future.
+        TransitionNotInFuture,
+        /// Too many transitions scheduled for the market, or too many markets
+        /// with scheduled transitions.
+        TooManyScheduledTransitions,
+        /// Market has no scheduled transitions.
+        NoScheduledTransitions,
+        /// The base asset can only be changed while the market is in Draft.
+        BaseAssetLocked,
+        /// Listing proposal not found.
+        ListingProposalNotFound,
+        /// The oracle has already reported on the listing proposal.
+        ListingFeasibilityAlreadyRecorded,
+        /// Only listing proposals the oracle found feasible can be approved.
+        ListingNotFeasible,
+        /// Margin rule needs a non-zero step and `min_margin_bp <= max_margin_bp`.
+        InvalidMarginRule,
+    }
+
+    // =========================================================================
+    //                                  Hooks
+    // =========================================================================
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(block_number: BlockNumberFor<T>) -> Weight {
+            let weight = Self::apply_due_transitions(block_number);
+
+            let epoch = T::MarginEpochBlocks::get();
+            if epoch.is_zero() || !(block_number % epoch).is_zero() {
+                return weight;
+            }
+            weight.saturating_add(Self::adjust_margins())
+        }
     }
 
     // =========================================================================
@@ -356,38 +574,18 @@ pub mod pallet {
         ) -> DispatchResult {
             T::DaoOrigin::ensure_origin(origin)?;
 
-            let bounded_name: BoundedVec<u8, ConstU32<64>> =
-                name.try_into().map_err(|_| Error::<T>::NameTooLong)?;
-
-            let market_id = NextMarketId::<T>::get();
-
-            let market_info = MarketInfo {
-                market_id,
-                name: bounded_name.clone(),
+            Self::do_create_market(
+                name,
                 center_latitude,
                 center_longitude,
                 timezone_offset_hours,
-                event_type: EventType::Rainfall24h,
                 strike_value,
-                payout_per_share,
                 base_asset,
-                status: MarketStatus::Open,
+                payout_per_share,
                 risk,
                 window_rules,
-            };
-
-            Markets::<T>::insert(market_id, market_info);
-            NextMarketId::<T>::put(market_id + 1);
-
-            Self::deposit_event(Event::MarketCreated {
-                market_id,
-                name: bounded_name,
-            });
-
-            // Notify the oracle pallet to queue immediate AccuWeather binding and fetch
-            T::NewMarketNotifier::notify_new_market(market_id);
-
-            Ok(())
+                MarketStatus::Open,
+            )
         }
 
         /// Update window rules for a market.
@@ -434,38 +632,295 @@ pub mod pallet {
             Ok(())
         }
 
-        /// Close a market (prevent new policies).
+        /// Close a market (no new policies; active policies run to expiry).
+        /// Moves an Open or Paused market to Closing.
         /// Only DAO origin can call this.
         #[pallet::call_index(3)]
         #[pallet::weight(10_000)]
         pub fn dao_close_market(origin: OriginFor<T>, market_id: MarketId) -> DispatchResult {
             T::DaoOrigin::ensure_origin(origin)?;
 
-            Markets::<T>::try_mutate(market_id, |maybe_market| -> DispatchResult {
-                let market = maybe_market.as_mut().ok_or(Error::<T>::MarketNotFound)?;
-                market.status = MarketStatus::Closed;
+            Self::do_transition(market_id, MarketStatus::Closing)
+        }
+
+        /// Archive a market (after all policies settled). Moves a Closing or Draft
+        /// market to Archived; the oracle stops fetching its data.
+        /// Only DAO origin can call this.
+        #[pallet::call_index(4)]
+        #[pallet::weight(10_000)]
+        pub fn dao_settle_market(origin: OriginFor<T>, market_id: MarketId) -> DispatchResult {
+            T::DaoOrigin::ensure_origin(origin)?;
+
+            Self::do_transition(market_id, MarketStatus::Archived)
+        }
+
+        /// Create a market in Draft. It takes no quotes or policies until opened,
+        /// directly or by a scheduled transition; the oracle starts collecting data.
+        /// Only DAO origin can call this.
+        #[pallet::call_index(5)]
+        #[pallet::weight(10_000)]
+        pub fn dao_create_draft_market(
+            origin: OriginFor<T>,
+            name: Vec<u8>,
+            center_latitude: i32,
+            center_longitude: i32,
+            timezone_offset_hours: i8,
+            strike_value: Millimeters,
+            base_asset: T::AssetId,
+            payout_per_share: T::Balance,
+            risk: RiskParameters,
+            window_rules: WindowRules,
+        ) -> DispatchResult {
+            T::DaoOrigin::ensure_origin(origin)?;
+
+            Self::do_create_market(
+                name,
+                center_latitude,
+                center_longitude,
+                timezone_offset_hours,
+                strike_value,
+                base_asset,
+                payout_per_share,
+                risk,
+                window_rules,
+                MarketStatus::Draft,
+            )
+        }
+
+        /// Move a market to another lifecycle state now (see `lifecycle`).
+        /// Only DAO origin can call this.
+        #[pallet::call_index(6)]
+        #[pallet::weight(10_000)]
+        pub fn dao_set_market_status(
+            origin: OriginFor<T>,
+            market_id: MarketId,
+            status: MarketStatus,
+        ) -> DispatchResult {
+            T::DaoOrigin::ensure_origin(origin)?;
+
+            Self::do_transition(market_id, status)
+        }
+
+        /// Schedule a lifecycle transition for a block number or unix timestamp.
+        /// A market's transitions fire in the order they were scheduled; a
+        /// transition the market's state no longer allows when it comes due is
+        /// skipped.
+        /// Only DAO origin can call this.
+        #[pallet::call_index(7)]
+        #[pallet::weight(10_000)]
+        pub fn dao_schedule_market_transition(
+            origin: OriginFor<T>,
+            market_id: MarketId,
+            to: MarketStatus,
+            at: TransitionTrigger<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            T::DaoOrigin::ensure_origin(origin)?;
+
+            ensure!(Markets::<T>::contains_key(market_id), Error::<T>::MarketNotFound);
+            ensure!(
+                !at.is_due(
+                    &frame_system::Pallet::<T>::block_number(),
+                    T::TimeProvider::now_secs()
+                ),
+                Error::<T>::TransitionNotInFuture
+            );
+
+            ScheduledMarkets::<T>::try_mutate(|markets| -> DispatchResult {
+                if !markets.contains(&market_id) {
+                    markets
+                        .try_push(market_id)
+                        .map_err(|_| Error::<T>::TooManyScheduledTransitions)?;
+                }
                 Ok(())
             })?;
+            MarketSchedules::<T>::try_mutate(market_id, |schedule| {
+                schedule
+                    .try_push(ScheduledTransition { to, at })
+                    .map_err(|_| Error::<T>::TooManyScheduledTransitions)
+            })?;
 
-            Self::deposit_event(Event::MarketClosed { market_id });
+            Self::deposit_event(Event::MarketTransitionScheduled { market_id, to, at });
 
             Ok(())
         }
 
-        /// Settle a market (after all policies settled).
+        /// Cancel every pending scheduled transition of a market.
         /// Only DAO origin can call this.
-        #[pallet::call_index(4)]
+        #[pallet::call_index(8)]
         #[pallet::weight(10_000)]
-        pub fn dao_settle_market(origin: OriginFor<T>, market_id: MarketId) -> DispatchResult {
+        pub fn dao_clear_market_schedule(
+            origin: OriginFor<T>,
+            market_id: MarketId,
+        ) -> DispatchResult {
+            T::DaoOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                MarketSchedules::<T>::contains_key(market_id),
+                Error::<T>::NoScheduledTransitions
+            );
+            MarketSchedules::<T>::remove(market_id);
+            ScheduledMarkets::<T>::mutate(|markets| markets.retain(|id| *id != market_id));
+
+            Self::deposit_event(Event::MarketScheduleCleared { market_id });
+
+            Ok(())
+        }
+
+        /// Change the denomination asset of a Draft market. Once the market
+        /// opens its quotes and policies rely on it, so it cannot change again.
+        /// Only DAO origin can call this.
+        #[pallet::call_index(9)]
+        #[pallet::weight(10_000)]
+        pub fn dao_set_base_asset(
+            origin: OriginFor<T>,
+            market_id: MarketId,
+            base_asset: T::AssetId,
+        ) -> DispatchResult {
             T::DaoOrigin::ensure_origin(origin)?;
 
             Markets::<T>::try_mutate(market_id, |maybe_market| -> DispatchResult {
                 let market = maybe_market.as_mut().ok_or(Error::<T>::MarketNotFound)?;
-                market.status = MarketStatus::Settled;
+                ensure!(market.status == MarketStatus::Draft, Error::<T>::BaseAssetLocked);
+                market.base_asset = base_asset;
                 Ok(())
             })?;
 
-            Self::deposit_event(Event::MarketSettled { market_id });
+            Self::deposit_event(Event::BaseAssetUpdated { market_id, base_asset });
+
+            Ok(())
+        }
+
+        /// Propose a new market. Reserves `ListingDeposit` from the proposer and
+        /// queues an oracle feasibility check of the center coordinates.
+        #[pallet::call_index(10)]
+        #[pallet::weight(10_000)]
+        pub fn propose_market_listing(
+            origin: OriginFor<T>,
+            params: MarketListingParams<T::Balance, T::AssetId>,
+        ) -> DispatchResult {
+            let proposer = ensure_signed(origin)?;
+
+            let deposit = T::ListingDeposit::get();
+            T::DepositCurrency::reserve(&proposer, deposit)?;
+
+            let proposal_id = NextListingProposalId::<T>::get();
+            NextListingProposalId::<T>::put(proposal_id + 1);
+
+            let (center_latitude, center_longitude) =
+                (params.center_latitude, params.center_longitude);
+            ListingProposals::<T>::insert(
+                proposal_id,
+                listing::ListingProposal {
+                    proposer: proposer.clone(),
+                    deposit,
+                    params,
+                    feasibility: ListingFeasibility::Pending,
+                    proposed_at: frame_system::Pallet::<T>::block_number(),
+                },
+            );
+
+            Self::deposit_event(Event::MarketListingProposed { proposal_id, proposer, deposit });
+
+            T::NewMarketNotifier::notify_listing_proposed(
+                proposal_id,
+                center_latitude,
+                center_longitude,
+            );
+
+            Ok(())
+        }
+
+        /// Approve a listing the oracle found feasible: the market is created in
+        /// Draft and the proposer's deposit refunded.
+        /// Only DAO origin can call this.
+        #[pallet::call_index(11)]
+        #[pallet::weight(10_000)]
+        pub fn dao_approve_listing(
+            origin: OriginFor<T>,
+            proposal_id: ListingProposalId,
+        ) -> DispatchResult {
+            T::DaoOrigin::ensure_origin(origin)?;
+
+            let proposal = ListingProposals::<T>::get(proposal_id)
+                .ok_or(Error::<T>::ListingProposalNotFound)?;
+            ensure!(proposal.feasibility.is_feasible(), Error::<T>::ListingNotFeasible);
+
+            let market_id = NextMarketId::<T>::get();
+            let params = proposal.params;
+            Self::do_create_market(
+                params.name.into_inner(),
+                params.center_latitude,
+                params.center_longitude,
+                params.timezone_offset_hours,
+                params.strike_value,
+                params.base_asset,
+                params.payout_per_share,
+                params.risk,
+                params.window_rules,
+                MarketStatus::Draft,
+            )?;
+
+            ListingProposals::<T>::remove(proposal_id);
+            T::DepositCurrency::unreserve(&proposal.proposer, proposal.deposit);
+            T::NewMarketNotifier::notify_listing_closed(proposal_id);
+
+            Self::deposit_event(Event::MarketListingApproved { proposal_id, market_id });
+
+            Ok(())
+        }
+
+        /// Reject a listing proposal, slashing the proposer's deposit if `slash`
+        /// is set and refunding it otherwise.
+        /// Only DAO origin can call this.
+        #[pallet::call_index(12)]
+        #[pallet::weight(10_000)]
+        pub fn dao_reject_listing(
+            origin: OriginFor<T>,
+            proposal_id: ListingProposalId,
+            slash: bool,
+        ) -> DispatchResult {
+            T::DaoOrigin::ensure_origin(origin)?;
+
+            let proposal = ListingProposals::<T>::take(proposal_id)
+                .ok_or(Error::<T>::ListingProposalNotFound)?;
+
+            let slashed = if slash {
+                let (_imbalance, unslashed) =
+                    T::DepositCurrency::slash_reserved(&proposal.proposer, proposal.deposit);
+                proposal.deposit.saturating_sub(unslashed)
+            } else {
+                T::DepositCurrency::unreserve(&proposal.proposer, proposal.deposit);
+                Zero::zero()
+            };
+            T::NewMarketNotifier::notify_listing_closed(proposal_id);
+
+            Self::deposit_event(Event::MarketListingRejected { proposal_id, slashed });
+
+            Ok(())
+        }
+
+        /// Set the rule stepping a market's DAO margin towards a target loss ratio
+        /// at the end of each epoch, or remove it with `None`.
+        /// Only DAO origin can call this.
+        #[pallet::call_index(13)]
+        #[pallet::weight(10_000)]
+        pub fn dao_set_margin_adjustment_rule(
+            origin: OriginFor<T>,
+            market_id: MarketId,
+            rule: Option<MarginAdjustmentRule>,
+        ) -> DispatchResult {
+            T::DaoOrigin::ensure_origin(origin)?;
+
+            ensure!(Markets::<T>::contains_key(market_id), Error::<T>::MarketNotFound);
+            match rule {
+                Some(rule) => {
+                    ensure!(rule.is_valid(), Error::<T>::InvalidMarginRule);
+                    MarginAdjustmentRules::<T>::insert(market_id, rule);
+                }
+                None => MarginAdjustmentRules::<T>::remove(market_id),
+            }
+
+            Self::deposit_event(Event::MarginAdjustmentRuleSet { market_id, rule });
 
             Ok(())
         }
@@ -476,6 +931,225 @@ pub mod pallet {
     // =========================================================================
 
     impl<T: Config> Pallet<T> {
+        /// Store a new market in `status` and queue its first oracle fetch
+        #[allow(clippy::too_many_arguments)]
+        fn do_create_market(
+            name: Vec<u8>,
+            center_latitude: i32,
+            center_longitude: i32,
+            timezone_offset_hours: i8,
+            strike_value: Millimeters,
+            base_asset: T::AssetId,
+            payout_per_share: T::Balance,
+            risk: RiskParameters,
+            window_rules: WindowRules,
+            status: MarketStatus,
+        ) -> DispatchResult {
+            let bounded_name: BoundedVec<u8, ConstU32<64>> =
+                name.try_into().map_err(|_| Error::<T>::NameTooLong)?;
+
+            let market_id = NextMarketId::<T>::get();
+
+            let market_info = MarketInfo {
+                market_id,
+                name: bounded_name.clone(),
+                center_latitude,
+                center_longitude,
+                timezone_offset_hours,
+                event_type: EventType::Rainfall24h,
+                strike_value,
+                payout_per_share,
+                base_asset,
+                status,
+                risk,
+                window_rules,
+            };
+
+            Markets::<T>::insert(market_id, market_info);
+            NextMarketId::<T>::put(market_id + 1);
+
+            Self::deposit_event(Event::MarketCreated {
+                market_id,
+                name: bounded_name,
+            });
+
+            // Notify the oracle pallet to queue immediate AccuWeather binding and fetch
+            T::NewMarketNotifier::notify_new_market(market_id);
+
+            Ok(())
+        }
+
+        /// Record the oracle's feasibility check of a listing proposal
+        pub fn do_record_listing_feasibility(
+            proposal_id: ListingProposalId,
+            feasibility: ListingFeasibility,
+        ) -> DispatchResult {
+            ListingProposals::<T>::try_mutate(proposal_id, |maybe_proposal| -> DispatchResult {
+                let proposal =
+                    maybe_proposal.as_mut().ok_or(Error::<T>::ListingProposalNotFound)?;
+                ensure!(
+                    !proposal.feasibility.is_checked(),
+                    Error::<T>::ListingFeasibilityAlreadyRecorded
+                );
+                proposal.feasibility = feasibility;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::ListingFeasibilityRecorded { proposal_id, feasibility });
+
+            Ok(())
+        }
+
+        /// Move a market to `to` if its current state allows it
+        fn do_transition(market_id: MarketId, to: MarketStatus) -> DispatchResult {
+            let from = Markets::<T>::try_mutate(market_id, |maybe_market| {
+                let market = maybe_market.as_mut().ok_or(Error::<T>::MarketNotFound)?;
+                let from = market.status;
+                ensure!(from.can_transition_to(to), Error::<T>::InvalidStatusTransition);
+                market.status = to;
+                Ok::<_, DispatchError>(from)
+            })?;
+
+            Self::deposit_event(Event::MarketStatusChanged { market_id, from, to });
+
+            // Resume oracle fetching at once for a reopened market
+            if !from.is_oracle_active() && to.is_oracle_active() {
+                T::NewMarketNotifier::notify_new_market(market_id);
+            }
+
+            Ok(())
+        }
+
+        /// Apply every scheduled transition that is due, in scheduled order
+        fn apply_due_transitions(block_number: BlockNumberFor<T>) -> Weight {
+            let markets = ScheduledMarkets::<T>::get();
+            let mut weight = T::DbWeight::get().reads(1);
+            if markets.is_empty() {
+                return weight;
+            }
+
+            let now = T::TimeProvider::now_secs();
+            let mut finished: Vec<MarketId> = Vec::new();
+
+            for market_id in markets.iter() {
+                let schedule = MarketSchedules::<T>::get(market_id);
+                weight = weight.saturating_add(T::DbWeight::get().reads(1));
+
+                let mut applied = 0usize;
+                for transition in schedule.iter() {
+                    if !transition.at.is_due(&block_number, now) {
+                        break;
+                    }
+                    applied += 1;
+
+                    // Markets, plus the oracle's fetch queue on reopen
+                    weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 2));
+                    if Self::do_transition(*market_id, transition.to).is_err() {
+                        let from = Markets::<T>::get(market_id).map(|m| m.status);
+                        log::warn!(
+                            target: "prmx-markets",
+                            "⚠️ Skipping scheduled transition of market {} to {:?} from {:?}",
+                            market_id,
+                            transition.to,
+                            from
+                        );
+                        if let Some(from) = from {
+                            Self::deposit_event(Event::MarketTransitionSkipped {
+                                market_id: *market_id,
+                                from,
+                                to: transition.to,
+                            });
+                        }
+                    }
+                }
+
+                if applied == 0 {
+                    continue;
+                }
+                let remaining = schedule.into_inner().split_off(applied);
+                weight = weight.saturating_add(T::DbWeight::get().writes(1));
+                if remaining.is_empty() {
+                    MarketSchedules::<T>::remove(market_id);
+                    finished.push(*market_id);
+                } else {
+                    MarketSchedules::<T>::insert(market_id, BoundedVec::truncate_from(remaining));
+                }
+            }
+
+            if !finished.is_empty() {
+                ScheduledMarkets::<T>::mutate(|markets| {
+                    markets.retain(|id| !finished.contains(id))
+                });
+                weight = weight.saturating_add(T::DbWeight::get().writes(1));
+            }
+
+            weight
+        }
+
+        /// Add to a market's lifetime and epoch loss statistics
+        fn record_loss_stats(market_id: MarketId, update: impl Fn(&mut LossStats)) {
+            MarketLossStats::<T>::mutate(market_id, &update);
+            EpochLossStats::<T>::mutate(market_id, &update);
+        }
+
+        /// Record premium paid in by a policy of `market_id`
+        pub fn do_record_premium(market_id: MarketId, amount: u128) {
+            Self::record_loss_stats(market_id, |stats| {
+                stats.premiums = stats.premiums.saturating_add(amount)
+            });
+        }
+
+        /// Record premium refunded to the holder of a cancelled policy
+        pub fn do_record_premium_refund(market_id: MarketId, amount: u128) {
+            Self::record_loss_stats(market_id, |stats| {
+                stats.premiums = stats.premiums.saturating_sub(amount)
+            });
+        }
+
+        /// Record a payout to the holder of a triggered policy
+        pub fn do_record_payout(market_id: MarketId, amount: u128) {
+            Self::record_loss_stats(market_id, |stats| {
+                stats.payouts = stats.payouts.saturating_add(amount)
+            });
+        }
+
+        /// End the margin epoch: run each market's margin rule on its epoch loss
+        /// ratio and start the next epoch from empty statistics
+        fn adjust_margins() -> Weight {
+            let mut weight = Weight::zero();
+
+            for (market_id, stats) in EpochLossStats::<T>::drain() {
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 1));
+                let (Some(rule), Some(loss_ratio_bp)) =
+                    (MarginAdjustmentRules::<T>::get(market_id), stats.loss_ratio_bp())
+                else {
+                    continue;
+                };
+
+                let Some(mut market) = Markets::<T>::get(market_id) else {
+                    continue;
+                };
+                let old_margin_bp = market.risk.dao_margin_bp;
+                let new_margin_bp = rule.adjust(old_margin_bp, loss_ratio_bp);
+                if new_margin_bp != old_margin_bp {
+                    market.risk.dao_margin_bp = new_margin_bp;
+                    Markets::<T>::insert(market_id, market);
+                    weight = weight.saturating_add(T::DbWeight::get().writes(1));
+                }
+
+                Self::deposit_event(Event::DaoMarginAdjusted {
+                    market_id,
+                    old_margin_bp,
+                    new_margin_bp,
+                    premiums: stats.premiums,
+                    payouts: stats.payouts,
+                    loss_ratio_bp,
+                });
+            }
+
+            weight
+        }
+
         /// Validate a coverage window against market rules.
         /// When compiled with `test-mode` feature, only validates that end > start
         /// and market exists, bypassing duration and lead-time requirements.
@@ -530,10 +1204,55 @@ pub mod pallet {
             Ok(())
         }
 
-        /// Check if a market is open
+        /// Validate extending coverage that started at `coverage_start` from
+        /// `current_end` to `new_end`. The extended window must respect the maximum
+        /// duration, and the extension must be agreed at least the market's lead
+        /// time before the current coverage ends.
+        /// When compiled with `test-mode` feature, only validates that the end moves
+        /// later and market exists.
+        pub fn validate_coverage_extension(
+            market_id: MarketId,
+            coverage_start: u64,
+            current_end: u64,
+            new_end: u64,
+            #[allow(unused_variables)]
+            now: u64,
+        ) -> DispatchResult {
+            let _market = Markets::<T>::get(market_id).ok_or(Error::<T>::MarketNotFound)?;
+
+            ensure!(new_end > current_end, Error::<T>::InvalidCoverageWindow);
+
+            #[cfg(not(feature = "test-mode"))]
+            {
+                let market = Markets::<T>::get(market_id).ok_or(Error::<T>::MarketNotFound)?;
+                let rules = &market.window_rules;
+                let duration = new_end.saturating_sub(coverage_start);
+                ensure!(
+                    duration <= rules.max_duration_secs as u64,
+                    Error::<T>::CoverageTooLong
+                );
+
+                let lead_time = current_end.saturating_sub(now);
+                ensure!(
+                    lead_time >= rules.min_lead_time_secs as u64,
+                    Error::<T>::TooLateToApply
+                );
+            }
+
+            Ok(())
+        }
+
+        /// Check if a market is open (takes new quotes and policies)
         pub fn is_market_open(market_id: MarketId) -> bool {
             Markets::<T>::get(market_id)
-                .map(|m| m.status == MarketStatus::Open)
+                .map(|m| m.status.accepts_new_business())
+                .unwrap_or(false)
+        }
+
+        /// Check if the oracle should keep fetching data for a market
+        pub fn is_oracle_active(market_id: MarketId) -> bool {
+            Markets::<T>::get(market_id)
+                .map(|m| m.status.is_oracle_active())
                 .unwrap_or(false)
         }
 
@@ -547,6 +1266,11 @@ pub mod pallet {
             Markets::<T>::get(market_id).map(|m| m.payout_per_share)
         }
 
+        /// Get the denomination asset of a market
+        pub fn get_base_asset(market_id: MarketId) -> Option<T::AssetId> {
+            Markets::<T>::get(market_id).map(|m| m.base_asset)
+        }
+
         /// Get center coordinates for a market (used by quote pallet for R model API)
         pub fn get_center_coordinates(market_id: MarketId) -> Option<(i32, i32)> {
             Markets::<T>::get(market_id).map(|m| (m.center_latitude, m.center_longitude))
@@ -623,6 +1347,7 @@ pub mod pallet {
 /// Trait for other pallets to access market data
 pub trait MarketsAccess {
     type Balance;
+    type AssetId;
 
     /// Get DAO margin in basis points for a market
     fn dao_margin_bp(market_id: u64) -> Result<u32, ()>;
@@ -630,12 +1355,18 @@ pub trait MarketsAccess {
     /// Get payout per share for a market
     fn payout_per_share(market_id: u64) -> Result<Self::Balance, ()>;
 
+    /// Get the asset a market's premiums, pools and payouts are denominated in
+    fn base_asset(market_id: u64) -> Result<Self::AssetId, ()>;
+
     /// Get center coordinates (lat, lon) for a market
     fn center_coordinates(market_id: u64) -> Result<(i32, i32), ()>;
 
     /// Check if market exists and is open
     fn is_market_open(market_id: u64) -> bool;
 
+    /// Check if market exists and is not archived (oracle keeps fetching its data)
+    fn is_oracle_active(market_id: u64) -> bool;
+
     /// Validate coverage window against market rules
     fn validate_coverage_window(
         market_id: u64,
@@ -644,6 +1375,15 @@ pub trait MarketsAccess {
         now: u64,
     ) -> Result<(), sp_runtime::DispatchError>;
 
+    /// Validate extending coverage from `current_end` to `new_end` against market rules
+    fn validate_coverage_extension(
+        market_id: u64,
+        coverage_start: u64,
+        current_end: u64,
+        new_end: u64,
+        now: u64,
+    ) -> Result<(), sp_runtime::DispatchError>;
+
     /// Get strike value for a market
     fn strike_value(market_id: u64) -> Result<u32, ()>;
 
@@ -658,10 +1398,26 @@ pub trait MarketsAccess {
 
     /// Get V2 duration rules for a market (min_days, max_days)
     fn v2_duration_rules(market_id: u64) -> (u8, u8);
+
+    /// Record the oracle's feasibility check of a pending listing proposal
+    fn record_listing_feasibility(
+        proposal_id: ListingProposalId,
+        feasibility: ListingFeasibility,
+    ) -> Result<(), sp_runtime::DispatchError>;
+
+    /// Record premium paid in by a policy of the market (loss ratio tracking)
+    fn record_premium(_market_id: u64, _amount: u128) {}
+
+    /// Record premium refunded to the holder of a cancelled policy of the market
+    fn record_premium_refund(_market_id: u64, _amount: u128) {}
+
+    /// Record a payout to the holder of a triggered policy of the market
+    fn record_payout(_market_id: u64, _amount: u128) {}
 }
 
 impl<T: Config> MarketsAccess for Pallet<T> {
     type Balance = T::Balance;
+    type AssetId = T::AssetId;
 
     fn dao_margin_bp(market_id: u64) -> Result<u32, ()> {
         Pallet::<T>::get_dao_margin_bp(market_id).ok_or(())
@@ -671,6 +1427,10 @@ impl<T: Config> MarketsAccess for Pallet<T> {
         Pallet::<T>::get_payout_per_share(market_id).ok_or(())
     }
 
+    fn base_asset(market_id: u64) -> Result<Self::AssetId, ()> {
+        Pallet::<T>::get_base_asset(market_id).ok_or(())
+    }
+
     fn center_coordinates(market_id: u64) -> Result<(i32, i32), ()> {
         Pallet::<T>::get_center_coordinates(market_id).ok_or(())
     }
@@ -679,6 +1439,10 @@ impl<T: Config> MarketsAccess for Pallet<T> {
         Pallet::<T>::is_market_open(market_id)
     }
 
+    fn is_oracle_active(market_id: u64) -> bool {
+        Pallet::<T>::is_oracle_active(market_id)
+    }
+
     fn validate_coverage_window(
         market_id: u64,
         coverage_start: u64,
@@ -688,6 +1452,16 @@ impl<T: Config> MarketsAccess for Pallet<T> {
         Pallet::<T>::validate_coverage_window(market_id, coverage_start, coverage_end, now)
     }
 
+    fn validate_coverage_extension(
+        market_id: u64,
+        coverage_start: u64,
+        current_end: u64,
+        new_end: u64,
+        now: u64,
+    ) -> Result<(), sp_runtime::DispatchError> {
+        Pallet::<T>::validate_coverage_extension(market_id, coverage_start, current_end, new_end, now)
+    }
+
     fn strike_value(market_id: u64) -> Result<u32, ()> {
         Pallet::<T>::get_strike_value(market_id).ok_or(())
     }
@@ -709,4 +1483,23 @@ impl<T: Config> MarketsAccess for Pallet<T> {
     fn v2_duration_rules(market_id: u64) -> (u8, u8) {
         Pallet::<T>::get_v2_duration_rules(market_id)
     }
+
+    fn record_listing_feasibility(
+        proposal_id: ListingProposalId,
+        feasibility: ListingFeasibility,
+    ) -> Result<(), sp_runtime::DispatchError> {
+        Pallet::<T>::do_record_listing_feasibility(proposal_id, feasibility)
+    }
+
+    fn record_premium(market_id: u64, amount: u128) {
+        Pallet::<T>::do_record_premium(market_id, amount)
+    }
+
+    fn record_premium_refund(market_id: u64, amount: u128) {
+        Pallet::<T>::do_record_premium_refund(market_id, amount)
+    }
+
+    fn record_payout(market_id: u64, amount: u128) {
+        Pallet::<T>::do_record_payout(market_id, amount)
+    }
 }
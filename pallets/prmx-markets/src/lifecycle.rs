@@ -0,0 +1,178 @@
+//! # Market Lifecycle
+//!
+//! Markets move through Draft → Open ⇄ Paused → Closing → Archived:
+//!
+//! - `Draft`: configured but not yet selling; the oracle already collects data.
+//! - `Open`: accepts new quotes and policies.
+//! - `Paused`: temporarily stops new quotes and policies.
+//! - `Closing`: no new business; active policies run to expiry and settle.
+//! - `Archived`: retired; the oracle stops fetching data until the market is
+//!   reopened.
+//!
+//! The DAO applies transitions directly or schedules them for a block number or
+//! unix timestamp. A market's scheduled transitions fire in the order they were
+//! scheduled, each once it is due.
+
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// Lifecycle state of a market.
+///
+/// `Closing` and `Archived` keep the encoding of the former `Closed` and
+/// `Settled` states, which they replace.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum MarketStatus {
+    #[codec(index = 0)]
+    Open,
+    #[codec(index = 1)]
+    Closing,
+    #[codec(index = 2)]
+    Archived,
+    #[codec(index = 3)]
+    Draft,
+    #[codec(index = 4)]
+    Paused,
+}
+
+impl MarketStatus {
+    /// Whether governance may move a market from `self` to `to`
+    pub fn can_transition_to(self, to: MarketStatus) -> bool {
+        use MarketStatus::*;
+        matches!(
+            (self, to),
+            (Draft, Open)
+                | (Draft, Archived)
+                | (Open, Paused)
+                | (Open, Closing)
+                | (Paused, Open)
+                | (Paused, Closing)
+                | (Closing, Open)
+                | (Closing, Archived)
+                | (Archived, Open)
+        )
+    }
+
+    /// Whether the market takes new quotes and policies
+    pub fn accepts_new_business(self) -> bool {
+        self == MarketStatus::Open
+    }
+
+    /// Whether the oracle keeps fetching weather data for the market
+    pub fn is_oracle_active(self) -> bool {
+        self != MarketStatus::Archived
+    }
+}
+
+/// When a scheduled transition becomes due
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum TransitionTrigger<BlockNumber> {
+    /// At the start of this block
+    AtBlock(BlockNumber),
+    /// At the first block whose previous block's timestamp is at or after this
+    /// unix time (seconds)
+    AtTimestamp(u64),
+}
+
+impl<BlockNumber: PartialOrd> TransitionTrigger<BlockNumber> {
+    /// Whether the trigger has fired at block `now_block` and time `now_secs`
+    pub fn is_due(&self, now_block: &BlockNumber, now_secs: u64) -> bool {
+        match self {
+            TransitionTrigger::AtBlock(block) => block <= now_block,
+            TransitionTrigger::AtTimestamp(timestamp) => *timestamp <= now_secs,
+        }
+    }
+}
+
+/// A transition waiting for its trigger
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct ScheduledTransition<BlockNumber> {
+    pub to: MarketStatus,
+    pub at: TransitionTrigger<BlockNumber>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use MarketStatus::*;
+
+    #[test]
+    fn test_lifecycle_transitions() {
+        assert!(Draft.can_transition_to(Open));
+        assert!(Open.can_transition_to(Paused));
+        assert!(Paused.can_transition_to(Open));
+        assert!(Open.can_transition_to(Closing));
+        assert!(Closing.can_transition_to(Archived));
+        assert!(Archived.can_transition_to(Open));
+
+        assert!(!Open.can_transition_to(Draft));
+        assert!(!Open.can_transition_to(Archived));
+        assert!(!Paused.can_transition_to(Archived));
+        assert!(!Archived.can_transition_to(Closing));
+        assert!(!Open.can_transition_to(Open));
+    }
+
+    #[test]
+    fn test_only_open_markets_sell_and_archived_markets_stop_oracle() {
+        assert!(Open.accepts_new_business());
+        for status in [Draft, Paused, Closing, Archived] {
+            assert!(!status.accepts_new_business());
+        }
+
+        assert!(!Archived.is_oracle_active());
+        for status in [Draft, Open, Paused, Closing] {
+            assert!(status.is_oracle_active());
+        }
+    }
+
+    #[test]
+    fn test_encoding_matches_former_statuses() {
+        // Former Open, Closed and Settled
+        assert_eq!(Open.encode(), vec![0]);
+        assert_eq!(Closing.encode(), vec![1]);
+        assert_eq!(Archived.encode(), vec![2]);
+    }
+
+    #[test]
+    fn test_trigger_is_due() {
+        let at_block: TransitionTrigger<u32> = TransitionTrigger::AtBlock(10);
+        assert!(!at_block.is_due(&9, u64::MAX));
+        assert!(at_block.is_due(&10, 0));
+
+        let at_time: TransitionTrigger<u32> = TransitionTrigger::AtTimestamp(1_000);
+        assert!(!at_time.is_due(&u32::MAX, 999));
+        assert!(at_time.is_due(&0, 1_000));
+    }
+}
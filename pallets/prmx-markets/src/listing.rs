@@ -0,0 +1,153 @@
+//! # Market Listing Proposals
+//!
+//! Anyone can propose a new market by bonding `ListingDeposit` with its
+//! parameters (`propose_market_listing`). The oracle's offchain worker then
+//! checks the proposal is feasible:
+//!
+//! - the center coordinates resolve to an AccuWeather location, and
+//! - that location has at least `MIN_LISTING_HISTORY_HOURS` of the last 24
+//!   hourly observations.
+//!
+//! Governance approves a feasible proposal, which creates the market in Draft
+//! and refunds the deposit, or rejects any proposal, refunding or slashing the
+//! deposit.
+
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use frame_support::{traits::ConstU32, BoundedVec};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+use crate::pallet::{Millimeters, RiskParameters, WindowRules};
+
+/// Identifier of a listing proposal
+pub type ListingProposalId = u64;
+
+/// Hourly observations out of the last 24 a location needs to be listable
+pub const MIN_LISTING_HISTORY_HOURS: u32 = 20;
+
+/// Oracle feasibility of a proposed market
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum ListingFeasibility {
+    /// Not yet checked by the oracle
+    Pending,
+    /// Location resolved with enough observation history
+    Feasible { history_hours: u32 },
+    /// No AccuWeather location at the proposed coordinates
+    LocationUnresolvable,
+    /// Location resolved but too few recent observations
+    InsufficientHistory { history_hours: u32 },
+}
+
+impl ListingFeasibility {
+    /// Feasibility of a resolved location with `history_hours` of the last 24
+    /// hourly observations
+    pub fn from_history_hours(history_hours: u32) -> Self {
+        if history_hours >= MIN_LISTING_HISTORY_HOURS {
+            ListingFeasibility::Feasible { history_hours }
+        } else {
+            ListingFeasibility::InsufficientHistory { history_hours }
+        }
+    }
+
+    /// Whether the oracle has reported on the proposal
+    pub fn is_checked(self) -> bool {
+        self != ListingFeasibility::Pending
+    }
+
+    /// Whether governance may approve the proposal
+    pub fn is_feasible(self) -> bool {
+        matches!(self, ListingFeasibility::Feasible { .. })
+    }
+}
+
+/// Parameters of a proposed market, as passed to `dao_create_market`
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct MarketListingParams<Balance, AssetId> {
+    pub name: BoundedVec<u8, ConstU32<64>>,
+    /// Center latitude (scaled by 1e6)
+    pub center_latitude: i32,
+    /// Center longitude (scaled by 1e6)
+    pub center_longitude: i32,
+    pub timezone_offset_hours: i8,
+    pub strike_value: Millimeters,
+    pub base_asset: AssetId,
+    pub payout_per_share: Balance,
+    pub risk: RiskParameters,
+    pub window_rules: WindowRules,
+}
+
+/// A market listing awaiting governance decision
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct ListingProposal<AccountId, DepositBalance, Balance, AssetId, BlockNumber> {
+    pub proposer: AccountId,
+    /// Reserved from the proposer until the proposal is decided
+    pub deposit: DepositBalance,
+    pub params: MarketListingParams<Balance, AssetId>,
+    pub feasibility: ListingFeasibility,
+    /// Block the proposal was made
+    pub proposed_at: BlockNumber,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_feasibility_from_history() {
+        assert_eq!(
+            ListingFeasibility::from_history_hours(24),
+            ListingFeasibility::Feasible { history_hours: 24 }
+        );
+        assert_eq!(
+            ListingFeasibility::from_history_hours(MIN_LISTING_HISTORY_HOURS),
+            ListingFeasibility::Feasible { history_hours: MIN_LISTING_HISTORY_HOURS }
+        );
+        assert_eq!(
+            ListingFeasibility::from_history_hours(MIN_LISTING_HISTORY_HOURS - 1),
+            ListingFeasibility::InsufficientHistory {
+                history_hours: MIN_LISTING_HISTORY_HOURS - 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_only_feasible_is_approvable() {
+        assert!(!ListingFeasibility::Pending.is_checked());
+        assert!(!ListingFeasibility::Pending.is_feasible());
+        assert!(ListingFeasibility::LocationUnresolvable.is_checked());
+        assert!(!ListingFeasibility::LocationUnresolvable.is_feasible());
+        assert!(!ListingFeasibility::InsufficientHistory { history_hours: 3 }.is_feasible());
+        assert!(ListingFeasibility::Feasible { history_hours: 24 }.is_feasible());
+    }
+}
@@ -0,0 +1,144 @@
+//! # Loss Ratio Margin Adjustment
+//!
+//! Each market tracks the premiums its policies collected and the payouts they
+//! made, over its lifetime and over the current epoch. A market's loss ratio is
+//! payouts over premiums, in basis points.
+//!
+//! Governance can give a market a `MarginAdjustmentRule`. At the end of every
+//! epoch with premiums, the rule moves `dao_margin_bp` one step towards its
+//! target loss ratio:
+//!
+//! - above `target + tolerance` (losing more than priced for) the margin rises,
+//! - below `target - tolerance` it falls,
+//! - otherwise it is unchanged,
+//!
+//! and the result is kept within `[min_margin_bp, max_margin_bp]`.
+
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+use crate::pallet::BasisPoints;
+
+/// Premiums collected and payouts made by a market's policies
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+    Default,
+)]
+pub struct LossStats {
+    /// Premiums paid in, less cancellation refunds
+    pub premiums: u128,
+    /// Payouts to policy holders
+    pub payouts: u128,
+}
+
+impl LossStats {
+    /// Payouts over premiums in basis points, `None` without premiums
+    pub fn loss_ratio_bp(&self) -> Option<BasisPoints> {
+        if self.premiums == 0 {
+            return None;
+        }
+        let ratio = self.payouts.saturating_mul(10_000) / self.premiums;
+        Some(ratio.min(BasisPoints::MAX as u128) as BasisPoints)
+    }
+}
+
+/// Governance rule steering a market's DAO margin by its loss ratio
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct MarginAdjustmentRule {
+    /// Loss ratio the margin steers towards
+    pub target_loss_ratio_bp: BasisPoints,
+    /// Distance from the target within which the margin is left unchanged
+    pub tolerance_bp: BasisPoints,
+    /// Margin change per epoch
+    pub step_bp: BasisPoints,
+    /// Lowest margin the rule sets
+    pub min_margin_bp: BasisPoints,
+    /// Highest margin the rule sets
+    pub max_margin_bp: BasisPoints,
+}
+
+impl MarginAdjustmentRule {
+    /// A non-zero step within non-empty bounds
+    pub fn is_valid(&self) -> bool {
+        self.step_bp > 0 && self.min_margin_bp <= self.max_margin_bp
+    }
+
+    /// Margin following `current` after an epoch with `loss_ratio_bp`
+    pub fn adjust(&self, current: BasisPoints, loss_ratio_bp: BasisPoints) -> BasisPoints {
+        let upper = self.target_loss_ratio_bp.saturating_add(self.tolerance_bp);
+        let lower = self.target_loss_ratio_bp.saturating_sub(self.tolerance_bp);
+        let next = if loss_ratio_bp > upper {
+            current.saturating_add(self.step_bp)
+        } else if loss_ratio_bp < lower {
+            current.saturating_sub(self.step_bp)
+        } else {
+            current
+        };
+        next.clamp(self.min_margin_bp, self.max_margin_bp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule() -> MarginAdjustmentRule {
+        MarginAdjustmentRule {
+            target_loss_ratio_bp: 6_000,
+            tolerance_bp: 500,
+            step_bp: 250,
+            min_margin_bp: 1_000,
+            max_margin_bp: 3_000,
+        }
+    }
+
+    #[test]
+    fn test_loss_ratio() {
+        assert_eq!(LossStats { premiums: 0, payouts: 10 }.loss_ratio_bp(), None);
+        assert_eq!(LossStats { premiums: 1_000, payouts: 0 }.loss_ratio_bp(), Some(0));
+        assert_eq!(LossStats { premiums: 1_000, payouts: 650 }.loss_ratio_bp(), Some(6_500));
+        assert_eq!(LossStats { premiums: 1, payouts: u128::MAX }.loss_ratio_bp(), Some(u32::MAX));
+    }
+
+    #[test]
+    fn test_adjust_steps_towards_target_within_bounds() {
+        let rule = rule();
+        // Losing more than priced for raises the margin
+        assert_eq!(rule.adjust(2_000, 9_000), 2_250);
+        // Inside the tolerance band nothing changes
+        assert_eq!(rule.adjust(2_000, 6_500), 2_000);
+        assert_eq!(rule.adjust(2_000, 5_500), 2_000);
+        // Low losses lower it
+        assert_eq!(rule.adjust(2_000, 1_000), 1_750);
+
+        // Bounds hold, and an out-of-bounds margin is pulled back in
+        assert_eq!(rule.adjust(2_900, 9_000), 3_000);
+        assert_eq!(rule.adjust(1_100, 0), 1_000);
+        assert_eq!(rule.adjust(5_000, 6_000), 3_000);
+
+        assert!(rule.is_valid());
+        assert!(!MarginAdjustmentRule { step_bp: 0, ..rule }.is_valid());
+        assert!(!MarginAdjustmentRule { min_margin_bp: 4_000, ..rule }.is_valid());
+    }
+}
@@ -0,0 +1,156 @@
+//! # AccuWeather Observation Parsing
+//!
+//! Pure helpers for extracting non-rainfall hourly observations from the
+//! AccuWeather `currentconditions/v1/{key}/historical/24?details=true` response.
+//! The OCW fetches the response once per market and feeds the same body to the
+//! rainfall parser in `lib.rs` and to the parsers below.
+
+use alloc::vec::Vec;
+
+use prmx_json::HistoricalObservation;
+use prmx_primitives::OcwErrorKind;
+
+use crate::{DeciCelsius, DeciKmh, Millimeters};
+
+/// Lowest accepted temperature reading (-90.0°C, scaled by 10)
+pub const MIN_TEMPERATURE_C: DeciCelsius = -900;
+
+/// Highest accepted temperature reading (60.0°C, scaled by 10)
+pub const MAX_TEMPERATURE_C: DeciCelsius = 600;
+
+/// Highest accepted wind gust reading (500 km/h, scaled by 10)
+pub const MAX_WIND_GUST_KMH: DeciKmh = 5000;
+
+/// Highest accepted hourly snowfall reading (30 cm, in tenths of mm)
+pub const MAX_SNOWFALL_MM: Millimeters = 3000;
+
+/// Maximum number of observations in a historical/24 response
+const MAX_OBSERVATIONS: usize = 24;
+
+/// Extract hourly temperature readings as (epoch_time, tenths of °C).
+/// Observations without a metric temperature or outside the plausible range are skipped.
+pub fn extract_hourly_temperature(json: &[u8]) -> Result<Vec<(u64, DeciCelsius)>, OcwErrorKind> {
+    let readings = HistoricalObservation::list_from_json(json)
+        .map_err(|_| OcwErrorKind::Parse)?
+        .into_iter()
+        .take(MAX_OBSERVATIONS)
+        .filter_map(|obs| {
+            let temp_c = to_tenths(obs.temperature_c?);
+            (MIN_TEMPERATURE_C..=MAX_TEMPERATURE_C)
+                .contains(&temp_c)
+                .then_some((obs.epoch_time, temp_c))
+        })
+        .collect();
+
+    Ok(readings)
+}
+
+/// Extract hourly wind gust readings as (epoch_time, tenths of km/h) from
+/// `WindGust.Speed.Metric`. Observations without a gust or above the plausible
+/// maximum are skipped.
+pub fn extract_hourly_wind_gust(json: &[u8]) -> Result<Vec<(u64, DeciKmh)>, OcwErrorKind> {
+    let readings = HistoricalObservation::list_from_json(json)
+        .map_err(|_| OcwErrorKind::Parse)?
+        .into_iter()
+        .take(MAX_OBSERVATIONS)
+        .filter_map(|obs| {
+            let kmh = obs.wind_gust_kmh?;
+            if kmh < 0.0 {
+                return None;
+            }
+            let gust = to_tenths(kmh) as DeciKmh;
+            (gust <= MAX_WIND_GUST_KMH).then_some((obs.epoch_time, gust))
+        })
+        .collect();
+
+    Ok(readings)
+}
+
+/// Extract hourly snowfall readings as (epoch_time, tenths of mm) from
+/// `PrecipitationSummary.Snow.Metric` (cm). Observations without a snow reading or
+/// above the plausible maximum are skipped.
+pub fn extract_hourly_snowfall(json: &[u8]) -> Result<Vec<(u64, Millimeters)>, OcwErrorKind> {
+    let readings = HistoricalObservation::list_from_json(json)
+        .map_err(|_| OcwErrorKind::Parse)?
+        .into_iter()
+        .take(MAX_OBSERVATIONS)
+        .filter_map(|obs| {
+            let cm = obs.past_hour_snow_cm?;
+            if cm < 0.0 {
+                return None;
+            }
+            // 1 cm = 10 mm = 100 tenths of mm
+            let snowfall = to_tenths(cm * 10.0) as Millimeters;
+            (snowfall <= MAX_SNOWFALL_MM).then_some((obs.epoch_time, snowfall))
+        })
+        .collect();
+
+    Ok(readings)
+}
+
+/// Scale a reading by 10 with rounding away from zero
+/// (f64::round not available in no_std)
+pub fn to_tenths(value: f64) -> i32 {
+    if value >= 0.0 {
+        (value * 10.0 + 0.5) as i32
+    } else {
+        (value * 10.0 - 0.5) as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HISTORICAL: &[u8] = br#"[
+        {"LocalObservationDateTime":"2024-01-01T10:00:00+08:00","EpochTime":1704074400,
+         "Temperature":{"Metric":{"Value":31.7,"Unit":"C","UnitType":17},"Imperial":{"Value":89.0,"Unit":"F","UnitType":18}},
+         "Wind":{"Direction":{"Degrees":90,"English":"E"},"Speed":{"Metric":{"Value":18.5,"Unit":"km/h","UnitType":7}}},
+         "WindGust":{"Speed":{"Metric":{"Value":42.6,"Unit":"km/h","UnitType":7}}},
+         "RealFeelTemperature":{"Metric":{"Value":38.2,"Unit":"C","UnitType":17}},
+         "PrecipitationSummary":{"PastHour":{"Metric":{"Value":0.0,"Unit":"mm","UnitType":3}}}},
+        {"LocalObservationDateTime":"2024-01-01T09:00:00+08:00","EpochTime":1704070800,
+         "RealFeelTemperature":{"Metric":{"Value":35.0,"Unit":"C","UnitType":17}},
+         "PrecipitationSummary":{"Snow":{"Metric":{"Value":2.35,"Unit":"cm","UnitType":4}}}},
+        {"LocalObservationDateTime":"2024-01-01T08:00:00+08:00","EpochTime":1704067200,
+         "Temperature":{"Metric":{"Value":-2.46,"Unit":"C","UnitType":17}},
+         "Wind":{"Direction":{"Degrees":0,"English":"N"},"Speed":{"Metric":{"Value":3.7,"Unit":"km/h","UnitType":7}}}}
+    ]"#;
+
+    #[test]
+    fn test_extract_hourly_temperature() {
+        let readings = extract_hourly_temperature(HISTORICAL).unwrap();
+
+        // The middle observation has no Temperature field and must not borrow
+        // the RealFeel value or the next hour's reading
+        assert_eq!(readings, vec![(1_704_074_400, 317), (1_704_067_200, -25)]);
+    }
+
+    #[test]
+    fn test_extract_hourly_wind_gust() {
+        let readings = extract_hourly_wind_gust(HISTORICAL).unwrap();
+
+        // Sustained wind speed must not be mistaken for a gust
+        assert_eq!(readings, vec![(1_704_074_400, 426)]);
+    }
+
+    #[test]
+    fn test_extract_hourly_snowfall() {
+        let readings = extract_hourly_snowfall(HISTORICAL).unwrap();
+
+        // 2.35 cm of snow is 23.5 mm
+        assert_eq!(readings, vec![(1_704_070_800, 235)]);
+
+        let json = br#"[{"EpochTime":1704067200,"PrecipitationSummary":{"Snow":{"Metric":{"Value":45.0,"Unit":"cm"}}}}]"#;
+        assert!(extract_hourly_snowfall(json).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_implausible_temperature_is_skipped() {
+        let json = br#"[{"EpochTime":1704067200,"Temperature":{"Metric":{"Value":75.0,"Unit":"C"}}}]"#;
+
+        assert!(extract_hourly_temperature(json).unwrap().is_empty());
+        assert_eq!(to_tenths(-0.04), 0);
+        assert_eq!(to_tenths(12.25), 123);
+    }
+}
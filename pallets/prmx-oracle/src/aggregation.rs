@@ -0,0 +1,251 @@
+//! # Multi-Provider Aggregation
+//!
+//! Pure helpers for combining hourly rainfall readings reported by several
+//! weather providers into a single bucket value.
+//!
+//! Agreement is counted per submitting account, not per weather provider: one
+//! account reporting several providers collapses to the median of its readings
+//! (see `per_submitter`), so it cannot reach a quorum on its own.
+//!
+//! A bucket is only accepted when at least `min_providers` readings lie within
+//! `tolerance_mm` of the median. The accepted value is the trimmed mean of the
+//! agreeing readings, so a single outlier cannot move the rolling sum. Readings
+//! can carry a weight (the submitter's data quality score, see `scoring`), in
+//! which case the kept readings are averaged by weight.
+//!
+//! A provider's reading for an hour is only replaced through the correction
+//! path; a plain resubmission must match the stored reading within
+//! `tolerance_mm` (see `classify_resubmission`).
+
+use alloc::vec::Vec;
+
+use crate::Millimeters;
+
+/// Outcome of aggregating provider readings for a single hour
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AggregatedReading {
+    /// Aggregated rainfall in tenths of mm
+    pub mm: Millimeters,
+    /// Median of all readings, used to judge outliers
+    pub median: Millimeters,
+    /// Number of providers whose readings agreed with the median
+    pub agreeing_providers: u32,
+}
+
+/// Median of a set of readings (lower median for even counts)
+pub fn median(values: &[Millimeters]) -> Option<Millimeters> {
+    if values.is_empty() {
+        return None;
+    }
+
+    let mut sorted = values.to_vec();
+    sorted.sort_unstable();
+
+    Some(sorted[(sorted.len() - 1) / 2])
+}
+
+/// Mean of a set of readings after dropping `trim` values from each end
+pub fn trimmed_mean(values: &[Millimeters], trim: usize) -> Option<Millimeters> {
+    if values.len() <= trim.saturating_mul(2) {
+        return None;
+    }
+
+    let mut sorted = values.to_vec();
+    sorted.sort_unstable();
+
+    let kept = &sorted[trim..sorted.len() - trim];
+    let sum: u64 = kept.iter().map(|v| *v as u64).sum();
+
+    Some((sum / kept.len() as u64) as Millimeters)
+}
+
+/// Mean of `(value, weight)` readings by weight after dropping `trim` values
+/// from each end. Falls back to the plain mean when every kept weight is zero.
+pub fn weighted_trimmed_mean(readings: &[(Millimeters, u32)], trim: usize) -> Option<Millimeters> {
+    if readings.len() <= trim.saturating_mul(2) {
+        return None;
+    }
+
+    let mut sorted = readings.to_vec();
+    sorted.sort_unstable();
+
+    let kept = &sorted[trim..sorted.len() - trim];
+    let total_weight: u128 = kept.iter().map(|(_, w)| *w as u128).sum();
+    if total_weight == 0 {
+        let values: Vec<Millimeters> = kept.iter().map(|(v, _)| *v).collect();
+        return trimmed_mean(&values, 0);
+    }
+
+    let weighted: u128 = kept.iter().map(|(v, w)| *v as u128 * *w as u128).sum();
+    Some((weighted / total_weight) as Millimeters)
+}
+
+/// Aggregate provider readings for one hour.
+///
+/// Returns `None` when fewer than `min_providers` readings agree with the
+/// median within `tolerance_mm`.
+pub fn aggregate_readings(
+    readings: &[Millimeters],
+    tolerance_mm: Millimeters,
+    min_providers: u32,
+) -> Option<AggregatedReading> {
+    let weighted: Vec<(Millimeters, u32)> = readings.iter().map(|v| (*v, 1)).collect();
+    aggregate_weighted_readings(&weighted, tolerance_mm, min_providers)
+}
+
+/// Aggregate `(value, weight)` provider readings for one hour. The median and
+/// agreement ignore weights; only the trimmed mean of agreeing readings uses them.
+///
+/// Returns `None` when fewer than `min_providers` readings agree with the
+/// median within `tolerance_mm`.
+pub fn aggregate_weighted_readings(
+    readings: &[(Millimeters, u32)],
+    tolerance_mm: Millimeters,
+    min_providers: u32,
+) -> Option<AggregatedReading> {
+    let values: Vec<Millimeters> = readings.iter().map(|(v, _)| *v).collect();
+    let mid = median(&values)?;
+
+    let agreeing: Vec<(Millimeters, u32)> = readings
+        .iter()
+        .copied()
+        .filter(|(v, _)| v.abs_diff(mid) <= tolerance_mm)
+        .collect();
+
+    let agreeing_providers = agreeing.len() as u32;
+    if agreeing_providers < min_providers.max(1) {
+        return None;
+    }
+
+    // Trim a quarter from each end once there are enough readings to spare
+    let mm = weighted_trimmed_mean(&agreeing, agreeing.len() / 4)?;
+
+    Some(AggregatedReading {
+        mm,
+        median: mid,
+        agreeing_providers,
+    })
+}
+
+/// Collapse `(submitter, value, weight)` readings into one `(value, weight)`
+/// reading per submitter, the median of that submitter's values
+pub fn per_submitter<K: PartialEq>(readings: &[(K, Millimeters, u32)]) -> Vec<(Millimeters, u32)> {
+    let mut grouped: Vec<(&K, Vec<Millimeters>, u32)> = Vec::new();
+    for (submitter, mm, weight) in readings {
+        match grouped.iter_mut().find(|(k, _, _)| *k == submitter) {
+            Some((_, values, _)) => values.push(*mm),
+            None => grouped.push((submitter, alloc::vec![*mm], *weight)),
+        }
+    }
+
+    grouped
+        .into_iter()
+        .filter_map(|(_, values, weight)| median(&values).map(|mm| (mm, weight)))
+        .collect()
+}
+
+/// How a provider's submitted reading for an hour relates to its stored one
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Resubmission {
+    /// Nothing stored yet for this provider and hour
+    New,
+    /// Within tolerance of the stored reading, which is kept
+    Matching,
+    /// Differs from the stored reading by more than the tolerance
+    Conflicting { stored_mm: Millimeters },
+}
+
+/// Classify a reading against the provider's `stored` reading for the same hour
+pub fn classify_resubmission(
+    stored: Option<Millimeters>,
+    submitted: Millimeters,
+    tolerance_mm: Millimeters,
+) -> Resubmission {
+    match stored {
+        None => Resubmission::New,
+        Some(stored_mm) if stored_mm.abs_diff(submitted) <= tolerance_mm => Resubmission::Matching,
+        Some(stored_mm) => Resubmission::Conflicting { stored_mm },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_median_and_trimmed_mean() {
+        assert_eq!(median(&[]), None);
+        assert_eq!(median(&[30, 10, 20]), Some(20));
+        assert_eq!(median(&[40, 10, 30, 20]), Some(20));
+
+        assert_eq!(trimmed_mean(&[10, 20, 30, 1000], 1), Some(25));
+        assert_eq!(trimmed_mean(&[10, 20], 1), None);
+    }
+
+    #[test]
+    fn test_outlier_is_ignored() {
+        // Three providers agree around 5mm, one reports an absurd 80mm
+        let result = aggregate_readings(&[50, 52, 48, 800], 10, 3).unwrap();
+
+        assert_eq!(result.agreeing_providers, 3);
+        assert_eq!(result.mm, 50);
+        assert_eq!(result.median, 50);
+    }
+
+    #[test]
+    fn test_agreement_counts_submitters() {
+        // Account 1 reports three weather providers, account 2 one
+        let readings = [(1u8, 50, 7), (1, 52, 7), (1, 90, 7), (2, 51, 3)];
+        let collapsed = per_submitter(&readings);
+        assert_eq!(collapsed, alloc::vec![(52, 7), (51, 3)]);
+
+        // A single account cannot reach a quorum of two on its own
+        let alone = per_submitter(&readings[..3]);
+        assert_eq!(aggregate_weighted_readings(&alone, 10, 2), None);
+        assert_eq!(aggregate_weighted_readings(&collapsed, 10, 2).unwrap().agreeing_providers, 2);
+    }
+
+    #[test]
+    fn test_insufficient_agreement() {
+        assert_eq!(aggregate_readings(&[50, 200, 400], 10, 2), None);
+        assert_eq!(aggregate_readings(&[], 10, 1), None);
+
+        // Single provider is accepted when only one is required
+        let single = aggregate_readings(&[123], 0, 1).unwrap();
+        assert_eq!(single.mm, 123);
+        assert_eq!(single.agreeing_providers, 1);
+    }
+
+    #[test]
+    fn test_weighted_readings() {
+        // A provider with a quarter of the weight pulls the mean less
+        let result = aggregate_weighted_readings(&[(40, 1_000_000), (60, 250_000)], 20, 2).unwrap();
+        assert_eq!(result.mm, 44);
+        assert_eq!(result.agreeing_providers, 2);
+
+        // Weights do not change which readings agree with the median
+        let result =
+            aggregate_weighted_readings(&[(50, 0), (52, 0), (48, 0), (800, 1_000_000)], 10, 3)
+                .unwrap();
+        assert_eq!(result.mm, 50);
+
+        assert_eq!(weighted_trimmed_mean(&[(10, 1), (20, 1), (30, 1), (1000, 1)], 1), Some(25));
+        assert_eq!(weighted_trimmed_mean(&[(10, 0), (20, 0)], 0), Some(15));
+    }
+
+    #[test]
+    fn test_classify_resubmission() {
+        assert_eq!(classify_resubmission(None, 120, 20), Resubmission::New);
+        assert_eq!(classify_resubmission(Some(120), 120, 0), Resubmission::Matching);
+        assert_eq!(classify_resubmission(Some(120), 140, 20), Resubmission::Matching);
+        assert_eq!(classify_resubmission(Some(120), 100, 20), Resubmission::Matching);
+        assert_eq!(
+            classify_resubmission(Some(120), 141, 20),
+            Resubmission::Conflicting { stored_mm: 120 }
+        );
+        assert_eq!(
+            classify_resubmission(Some(120), 0, 20),
+            Resubmission::Conflicting { stored_mm: 120 }
+        );
+    }
+}
@@ -0,0 +1,994 @@
+//! Benchmarks for the PRMX oracle pallet
+
+use super::*;
+use alloc::vec;
+use frame_benchmarking::v2::*;
+use frame_support::{
+    pallet_prelude::*,
+    traits::{Currency, ReservableCurrency},
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use pallet_prmx_markets::{MarketListingParams, RiskParameters, WindowRules};
+use sp_runtime::traits::{Bounded, IdentifyAccount, Saturating, Zero};
+
+/// Chain time set through `BenchmarkHelper::set_timestamp` (Jan 7, 2025 00:00 UTC)
+const NOW: u64 = 1_736_208_000;
+
+/// Create an open market through the markets pallet and return its id
+fn market<T: Config>() -> MarketId {
+    let origin = <T as pallet_prmx_markets::Config>::DaoOrigin::try_successful_origin()
+        .expect("DaoOrigin has a successful origin for benchmarks");
+    pallet_prmx_markets::Pallet::<T>::dao_create_market(
+        origin,
+        b"Benchmark".to_vec(),
+        14_599_500,
+        120_984_200,
+        8,
+        500,
+        Default::default(),
+        100_000_000u128.into(),
+        RiskParameters { dao_margin_bp: 2_000 },
+        WindowRules::default(),
+    )
+    .expect("market is valid");
+    pallet_prmx_markets::NextMarketId::<T>::get() - 1
+}
+
+/// Create a market with an AccuWeather location binding
+fn bound_market<T: Config>() -> MarketId {
+    let market_id = market::<T>();
+    MarketLocationConfig::<T>::insert(
+        market_id,
+        MarketLocationInfo {
+            accuweather_location_key: BoundedVec::truncate_from(b"264885".to_vec()),
+            center_latitude: 14_599_500,
+            center_longitude: 120_984_200,
+            bucket_interval_secs: BUCKET_INTERVAL_SECS,
+            rolling_window_secs: ROLLING_WINDOW_SECS,
+        },
+    );
+    market_id
+}
+
+/// Register and bond an oracle provider
+fn bonded_provider<T: Config>(index: u32) -> T::AccountId {
+    let provider: T::AccountId = account("provider", index, 0);
+    let bond = T::OracleProviderBond::get();
+    T::Currency::make_free_balance_be(&provider, bond.saturating_mul(10u32.into()));
+    T::Currency::reserve(&provider, bond).expect("provider is funded");
+    OracleProviders::<T>::insert(&provider, true);
+    ProviderBonds::<T>::insert(&provider, bond);
+    provider
+}
+
+/// Bind an OCW key account to `provider` and return it
+fn registered_key<T: Config>(provider: &T::AccountId) -> T::AccountId {
+    let key: T::AccountId = account("key", 0, 0);
+    ProviderKeys::<T>::insert(provider, &key);
+    KeyOwners::<T>::insert(&key, provider);
+    key
+}
+
+/// Bonded provider assigned to a market, last in a full assignment list
+fn assigned_provider<T: Config>(market_id: MarketId) -> T::AccountId {
+    let provider = bonded_provider::<T>(0);
+    let mut providers: Vec<T::AccountId> = (1..MAX_PROVIDERS_PER_MARKET)
+        .map(|i| account("assigned", i, 0))
+        .collect();
+    providers.push(provider.clone());
+    ProviderAssignments::<T>::insert(
+        market_id,
+        BoundedVec::<_, ConstU32<MAX_PROVIDERS_PER_MARKET>>::truncate_from(providers),
+    );
+    provider
+}
+
+/// Gauge source with a URL and JSON path of the maximum length
+fn gauge_source() -> gauge::GaugeSource {
+    let mut url = b"https://".to_vec();
+    url.resize(gauge::MAX_GAUGE_URL_LEN as usize, b'g');
+    gauge::GaugeSource {
+        url: BoundedVec::truncate_from(url),
+        json_path: BoundedVec::truncate_from(vec![b'v'; gauge::MAX_GAUGE_PATH_LEN as usize]),
+        cm_per_unit_milli: 100_000,
+        flood_level_cm: 350,
+    }
+}
+
+/// Record-mapped data source with a template and paths of the maximum length
+fn data_source_config() -> data_source::DataSourceConfig {
+    let mut template = b"https://".to_vec();
+    template.resize(data_source::MAX_ENDPOINT_TEMPLATE_LEN as usize, b'd');
+    let path = || BoundedVec::truncate_from(vec![b'p'; data_source::MAX_FIELD_PATH_LEN as usize]);
+    data_source::DataSourceConfig {
+        provider: WeatherProvider::Custom,
+        endpoint_template: BoundedVec::truncate_from(template),
+        field_mapping: data_source::FieldMapping::Records {
+            records: path(),
+            time: path(),
+            precipitation: path(),
+        },
+        time_format: data_source::TimeFormat::Iso8601,
+        unit: data_source::PrecipitationUnit::Millimeters,
+    }
+}
+
+/// `n` consecutive hourly readings ending at the current hour
+fn hourly<V: Copy>(n: u32, value: V) -> BoundedVec<(u64, V), ConstU32<24>> {
+    let current_hour = NOW / 3600;
+    let readings: Vec<(u64, V)> = (0..n as u64)
+        .map(|i| ((current_hour - n as u64 + 1 + i) * 3600, value))
+        .collect();
+    readings.try_into().expect("n is at most 24")
+}
+
+/// Hold a V2 report for `policy_id` as if `reporter` had submitted it
+fn pending_v2_report<T: Config>(
+    policy_id: PolicyId,
+    reporter: T::AccountId,
+    challenge_ends_at: BlockNumberFor<T>,
+) {
+    let coverage_start = T::PolicySettlement::get_policy_info(policy_id)
+        .map(|(_, _, coverage_start, _, _)| coverage_start)
+        .unwrap_or_default();
+    PendingV2Reports::<T>::insert(
+        policy_id,
+        PendingV2Report {
+            report: prmx_primitives::V2Report {
+                outcome: prmx_primitives::V2Outcome::Triggered,
+                observed_at: coverage_start,
+                cumulative_mm: MAX_RAINFALL_MM,
+                evidence_hash: [7u8; 32],
+                reporter,
+                submitted_at: NOW,
+            },
+            challenge_ends_at,
+        },
+    );
+}
+
+/// Open a dispute on `policy_id` with a reserved bond
+fn open_dispute<T: Config>(policy_id: PolicyId) -> T::AccountId {
+    let disputer: T::AccountId = account("disputer", 0, 0);
+    let bond = T::V2DisputeBond::get();
+    T::Currency::make_free_balance_be(&disputer, bond.saturating_mul(10u32.into()));
+    T::Currency::reserve(&disputer, bond).expect("disputer is funded");
+    V2Disputes::<T>::insert(
+        policy_id,
+        V2Dispute {
+            disputer: disputer.clone(),
+            counter_evidence_hash: [9u8; 32],
+            bond,
+            disputed_at: frame_system::Pallet::<T>::block_number(),
+        },
+    );
+    disputer
+}
+
+#[benchmarks(
+    where
+        T::Public: From<sp_core::sr25519::Public>,
+        T::Signature: From<sp_core::sr25519::Signature>,
+)]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn set_market_location_key() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let market_id = market::<T>();
+        let key = vec![b'1'; T::MaxLocationKeyLength::get() as usize];
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, market_id, key);
+
+        assert!(MarketLocationConfig::<T>::contains_key(market_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn submit_rainfall() {
+        let market_id = bound_market::<T>();
+        let provider = assigned_provider::<T>(market_id);
+        T::BenchmarkHelper::set_timestamp(NOW);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(provider), market_id, NOW, 125);
+
+        assert!(RollingState::<T>::contains_key(market_id));
+    }
+
+    #[benchmark]
+    fn add_oracle_provider() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let provider: T::AccountId = account("provider", 0, 0);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, provider.clone());
+
+        assert!(OracleProviders::<T>::get(&provider));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn remove_oracle_provider() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let provider = bonded_provider::<T>(0);
+        let key = registered_key::<T>(&provider);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, provider.clone());
+
+        assert!(!OracleProviders::<T>::get(&provider));
+        assert!(!KeyOwners::<T>::contains_key(&key));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_test_rainfall() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        // Unbound, so the call also binds a test location
+        let market_id = market::<T>();
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, market_id, 125);
+
+        assert!(RollingState::<T>::contains_key(market_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_accuweather_api_key() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, vec![b'k'; 256]);
+
+        assert!(PendingApiKey::<T>::get().is_some());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn request_rainfall_fetch() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let market_id = bound_market::<T>();
+        PendingFetchRequests::<T>::remove(market_id);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, market_id);
+
+        assert!(PendingFetchRequests::<T>::contains_key(market_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn complete_rainfall_fetch() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let market_id = bound_market::<T>();
+        PendingFetchRequests::<T>::insert(market_id, frame_system::Pallet::<T>::block_number());
+        T::BenchmarkHelper::set_timestamp(NOW);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, market_id, 125);
+
+        assert!(!PendingFetchRequests::<T>::contains_key(market_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn submit_rainfall_from_ocw() {
+        let market_id = bound_market::<T>();
+        let provider = assigned_provider::<T>(market_id);
+        PendingFetchRequests::<T>::insert(market_id, frame_system::Pallet::<T>::block_number());
+        T::BenchmarkHelper::set_timestamp(NOW);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(provider), market_id, 125);
+
+        assert!(!PendingFetchRequests::<T>::contains_key(market_id));
+    }
+
+    #[benchmark]
+    fn request_rainfall_fetch_all(m: Linear<1, 100>) -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        for _ in 0..m {
+            let market_id = market::<T>();
+            PendingFetchRequests::<T>::remove(market_id);
+        }
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin);
+
+        assert!(PendingFetchRequests::<T>::iter().count() as u32 >= m);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn submit_v2_report() {
+        let reporter: T::AccountId = account("reporter", 0, 0);
+        AuthorizedV2Reporters::<T>::insert(&reporter, true);
+        let policy_id = T::BenchmarkHelper::v2_policy();
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(reporter),
+            policy_id,
+            prmx_primitives::V2Outcome::Triggered,
+            NOW,
+            MAX_RAINFALL_MM,
+            [7u8; 32],
+        );
+
+        assert!(PendingV2Reports::<T>::contains_key(policy_id));
+    }
+
+    #[benchmark]
+    fn add_v2_reporter() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let reporter: T::AccountId = account("reporter", 0, 0);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, reporter.clone());
+
+        assert!(AuthorizedV2Reporters::<T>::get(&reporter));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn remove_v2_reporter() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let reporter: T::AccountId = account("reporter", 0, 0);
+        AuthorizedV2Reporters::<T>::insert(&reporter, true);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, reporter.clone());
+
+        assert!(!AuthorizedV2Reporters::<T>::get(&reporter));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn submit_hourly_rainfall_from_ocw(n: Linear<1, 24>) {
+        let market_id = bound_market::<T>();
+        let provider = assigned_provider::<T>(market_id);
+        PendingFetchRequests::<T>::insert(market_id, frame_system::Pallet::<T>::block_number());
+        T::BenchmarkHelper::set_timestamp(NOW);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(provider), market_id, hourly(n, 25u32));
+
+        assert_eq!(HourlyRing::<T>::get(market_id).map(|ring| ring.buckets), Some(n));
+    }
+
+    #[benchmark]
+    fn submit_provider_hourly_rainfall_from_ocw(n: Linear<1, 24>) {
+        let market_id = bound_market::<T>();
+        let provider = assigned_provider::<T>(market_id);
+        PendingFetchRequests::<T>::insert(market_id, frame_system::Pallet::<T>::block_number());
+        T::BenchmarkHelper::set_timestamp(NOW);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(provider),
+            market_id,
+            WeatherProvider::OpenMeteo,
+            hourly(n, 25u32),
+        );
+
+        assert_eq!(ProviderHourlyReadings::<T>::iter_prefix(market_id).count() as u32, n);
+    }
+
+    #[benchmark]
+    fn set_provider_agreement() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, 2, 30);
+
+        assert_eq!(ProviderAgreement::<T>::get().min_providers, 2);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn dispute_v2_report() {
+        let policy_id = T::BenchmarkHelper::v2_policy();
+        let reporter: T::AccountId = account("reporter", 0, 0);
+        let challenge_ends_at =
+            frame_system::Pallet::<T>::block_number().saturating_add(T::V2ChallengePeriod::get());
+        pending_v2_report::<T>(policy_id, reporter, challenge_ends_at);
+        let disputer: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&disputer, BalanceOf::<T>::max_value() / 2u32.into());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(disputer), policy_id, [9u8; 32]);
+
+        assert!(V2Disputes::<T>::contains_key(policy_id));
+    }
+
+    // Rejecting the dispute settles the policy, the more expensive branch
+    #[benchmark]
+    fn resolve_v2_dispute() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let policy_id = T::BenchmarkHelper::v2_policy();
+        let reporter = bonded_provider::<T>(0);
+        pending_v2_report::<T>(policy_id, reporter, frame_system::Pallet::<T>::block_number());
+        open_dispute::<T>(policy_id);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, policy_id, false);
+
+        assert!(V2FinalReportByPolicy::<T>::contains_key(policy_id));
+        Ok(())
+    }
+
+    // `v` votes against the outcome are already recorded; the benchmarked vote
+    // completes the quorum for rejecting the dispute, which settles the policy
+    #[benchmark]
+    fn vote_v2_dispute(v: Linear<0, { MAX_V2_DISPUTE_VOTES - 1 }>) {
+        let policy_id = T::BenchmarkHelper::v2_policy();
+        let reporter = bonded_provider::<T>(0);
+        pending_v2_report::<T>(policy_id, reporter, frame_system::Pallet::<T>::block_number());
+        open_dispute::<T>(policy_id);
+
+        let quorum = T::V2DisputeQuorum::get().clamp(1, MAX_V2_DISPUTE_VOTES);
+        let matching = quorum - 1;
+        let opposing = v.min(MAX_V2_DISPUTE_VOTES - 1 - matching);
+        let votes: Vec<(T::AccountId, bool)> = (0..matching)
+            .map(|i| (account("voter", i, 0), false))
+            .chain((0..opposing).map(|i| (account("voter", matching + i, 0), true)))
+            .collect();
+        V2DisputeVotes::<T>::insert(
+            policy_id,
+            BoundedVec::<_, ConstU32<MAX_V2_DISPUTE_VOTES>>::truncate_from(votes),
+        );
+        let voter: T::AccountId = whitelisted_caller();
+        AuthorizedV2Reporters::<T>::insert(&voter, true);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(voter), policy_id, false);
+
+        assert!(V2FinalReportByPolicy::<T>::contains_key(policy_id));
+    }
+
+    #[benchmark]
+    fn finalize_v2_report() {
+        let policy_id = T::BenchmarkHelper::v2_policy();
+        let reporter: T::AccountId = account("reporter", 0, 0);
+        let block = frame_system::Pallet::<T>::block_number();
+        pending_v2_report::<T>(policy_id, reporter, block);
+        frame_system::Pallet::<T>::set_block_number(block.saturating_add(1u32.into()));
+        let caller: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), policy_id);
+
+        assert!(V2FinalReportByPolicy::<T>::contains_key(policy_id));
+    }
+
+    #[benchmark]
+    fn submit_hourly_temperature_from_ocw(n: Linear<1, 24>) {
+        let market_id = bound_market::<T>();
+        let provider = assigned_provider::<T>(market_id);
+        T::BenchmarkHelper::set_timestamp(NOW);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(provider), market_id, hourly(n, 315i32));
+
+        assert_eq!(TempBuckets::<T>::iter_prefix(market_id).count() as u32, n);
+    }
+
+    #[benchmark]
+    fn submit_hourly_wind_gust_from_ocw(n: Linear<1, 24>) {
+        let market_id = bound_market::<T>();
+        let provider = assigned_provider::<T>(market_id);
+        T::BenchmarkHelper::set_timestamp(NOW);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(provider), market_id, hourly(n, 850u32));
+
+        assert_eq!(WindBuckets::<T>::iter_prefix(market_id).count() as u32, n);
+    }
+
+    #[benchmark]
+    fn bond_as_oracle_provider() {
+        let provider: T::AccountId = whitelisted_caller();
+        let bond = T::OracleProviderBond::get();
+        T::Currency::make_free_balance_be(&provider, bond.saturating_mul(10u32.into()));
+        OracleProviders::<T>::insert(&provider, true);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(provider.clone()), bond);
+
+        assert_eq!(ProviderBonds::<T>::get(&provider), bond);
+    }
+
+    #[benchmark]
+    fn unbond_oracle_provider() {
+        let provider = bonded_provider::<T>(0);
+        let bond = T::OracleProviderBond::get();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(provider.clone()), bond);
+
+        assert!(ProviderUnbondings::<T>::get(&provider).is_some());
+    }
+
+    #[benchmark]
+    fn withdraw_unbonded_provider_bond() {
+        let provider = bonded_provider::<T>(0);
+        let bond = ProviderBonds::<T>::take(&provider);
+        ProviderUnbondings::<T>::insert(
+            &provider,
+            ProviderUnbonding {
+                amount: bond,
+                unlock_at: frame_system::Pallet::<T>::block_number(),
+            },
+        );
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(provider.clone()));
+
+        assert!(ProviderUnbondings::<T>::get(&provider).is_none());
+    }
+
+    #[benchmark]
+    fn set_market_window(
+        b: Linear<0, { (MAX_ROLLING_WINDOW_SECS / MIN_BUCKET_INTERVAL_SECS) as u32 }>,
+    ) -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let market_id = bound_market::<T>();
+        for idx in 0..b as u64 {
+            RainBuckets::<T>::insert(
+                market_id,
+                idx,
+                RainBucket {
+                    timestamp: idx * BUCKET_INTERVAL_SECS,
+                    rainfall_mm: 10,
+                    block_number: 0,
+                },
+            );
+        }
+
+        // A new interval clears the legacy buckets
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, market_id, 1800, 6 * 3600);
+
+        assert_eq!(RainBuckets::<T>::iter_prefix(market_id).count(), 0);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn request_backfill() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let market_id = bound_market::<T>();
+        T::BenchmarkHelper::set_timestamp(NOW);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, market_id, NOW - 12 * 3600, NOW);
+
+        assert!(BackfillRequests::<T>::contains_key(market_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn submit_backfilled_rainfall_from_ocw(n: Linear<1, 24>) {
+        let market_id = bound_market::<T>();
+        let provider = assigned_provider::<T>(market_id);
+        T::BenchmarkHelper::set_timestamp(NOW);
+        let page = hourly(n, 25u32);
+        let next_hour = page[0].0 / 3600;
+        BackfillRequests::<T>::insert(
+            market_id,
+            BackfillRequest {
+                next_hour,
+                last_hour: next_hour + n as u64 - 1,
+                requested_at: frame_system::Pallet::<T>::block_number(),
+            },
+        );
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(provider), market_id, WeatherProvider::AccuWeather, page);
+
+        assert!(!BackfillRequests::<T>::contains_key(market_id));
+    }
+
+    #[benchmark]
+    fn set_settlement_grace_period() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let market_id = bound_market::<T>();
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, market_id, 3600);
+
+        assert_eq!(SettlementGracePeriods::<T>::get(market_id), Some(3600));
+        Ok(())
+    }
+
+    // Without `observed_mm` the call scans the whole coverage window
+    #[benchmark]
+    fn force_settle_expired_policy() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let market_id = bound_market::<T>();
+        let policy_id = T::BenchmarkHelper::expired_policy(market_id);
+        AwaitingSettlementData::<T>::insert(policy_id, 0);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, policy_id, false, None);
+
+        assert!(!AwaitingSettlementData::<T>::contains_key(policy_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn report_heartbeat() {
+        let key = sp_io::crypto::sr25519_generate(KEY_TYPE, None);
+        let public: T::Public = key.into();
+        let provider = public.clone().into_account();
+        OracleProviders::<T>::insert(&provider, true);
+        let heartbeat = HeartbeatPayload {
+            block_number: frame_system::Pallet::<T>::block_number(),
+            public,
+        };
+        let signature = sp_io::crypto::sr25519_sign(KEY_TYPE, &key, &heartbeat.encode())
+            .expect("key was just generated");
+
+        #[extrinsic_call]
+        _(RawOrigin::None, heartbeat, signature.into());
+
+        assert!(ProviderLastHeartbeat::<T>::contains_key(&provider));
+    }
+
+    #[benchmark]
+    fn report_ocw_health() {
+        let key = sp_io::crypto::sr25519_generate(KEY_TYPE, None);
+        let public: T::Public = key.into();
+        let provider = public.clone().into_account();
+        OracleProviders::<T>::insert(&provider, true);
+        let report = OcwHealthReport {
+            block_number: frame_system::Pallet::<T>::block_number(),
+            public,
+            counters: OcwCounters { api_calls: 24, api_failures: 1, ..Default::default() },
+        };
+        let signature = sp_io::crypto::sr25519_sign(KEY_TYPE, &key, &report.encode())
+            .expect("key was just generated");
+
+        #[extrinsic_call]
+        _(RawOrigin::None, report, signature.into());
+
+        assert!(OcwHealth::<T>::contains_key(&provider));
+    }
+
+    #[benchmark]
+    fn set_data_retention() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let market_id = bound_market::<T>();
+        let retention_secs = crate::retention::MIN_DATA_RETENTION_SECS;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, market_id, Some(retention_secs));
+
+        assert_eq!(DataRetention::<T>::get(market_id), Some(retention_secs));
+        Ok(())
+    }
+
+    // The attestation completes quorum, so the observations are ingested
+    #[benchmark]
+    fn mirror_observations(n: Linear<1, 24>) {
+        let market_id = bound_market::<T>();
+        let key = sp_io::crypto::sr25519_generate(KEY_TYPE, None);
+        let public: T::Public = key.into();
+        let attester = public.clone().into_account();
+        let bond = T::OracleProviderBond::get();
+        T::Currency::make_free_balance_be(&attester, bond.saturating_mul(10u32.into()));
+        T::Currency::reserve(&attester, bond).expect("attester is funded");
+        OracleProviders::<T>::insert(&attester, true);
+        ProviderBonds::<T>::insert(&attester, bond);
+        T::BenchmarkHelper::set_timestamp(NOW);
+
+        let observations = hourly(n, 25u32);
+        let digest = gossip::observations_digest(
+            market_id,
+            WeatherProvider::AccuWeather,
+            &gossip::normalize(&observations),
+        );
+        let mut candidates: Vec<MirrorCandidate<T>> = (1..gossip::MAX_MIRROR_CANDIDATES)
+            .map(|i| MirrorCandidate {
+                digest: [i as u8; 32],
+                first_seen: Zero::zero(),
+                attesters: BoundedVec::truncate_from(vec![account("attester", i, 0)]),
+            })
+            .collect();
+        candidates.push(MirrorCandidate {
+            digest,
+            first_seen: Zero::zero(),
+            attesters: BoundedVec::truncate_from(vec![bonded_provider::<T>(1)]),
+        });
+        MirrorVotes::<T>::insert(
+            market_id,
+            WeatherProvider::AccuWeather,
+            BoundedVec::<_, ConstU32<{ gossip::MAX_MIRROR_CANDIDATES }>>::truncate_from(candidates),
+        );
+
+        let mirror = ObservationMirror {
+            block_number: frame_system::Pallet::<T>::block_number(),
+            public,
+            market_id,
+            provider: WeatherProvider::AccuWeather,
+            observations,
+        };
+        let signature = sp_io::crypto::sr25519_sign(KEY_TYPE, &key, &mirror.encode())
+            .expect("key was just generated");
+
+        #[extrinsic_call]
+        _(RawOrigin::None, mirror, signature.into());
+
+        assert!(MirroredDigests::<T>::contains_key(market_id, WeatherProvider::AccuWeather));
+        assert_eq!(ProviderHourlyReadings::<T>::iter_prefix(market_id).count() as u32, n);
+    }
+
+    #[benchmark]
+    fn set_mirror_quorum() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, 3);
+
+        assert_eq!(MirrorQuorum::<T>::get(), Some(3));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn submit_listing_feasibility() {
+        let proposer: T::AccountId = account("proposer", 0, 0);
+        let deposit = <T as pallet_prmx_markets::Config>::ListingDeposit::get();
+        <T as pallet_prmx_markets::Config>::DepositCurrency::make_free_balance_be(
+            &proposer,
+            deposit.saturating_mul(10u32.into()),
+        );
+        let proposal_id = pallet_prmx_markets::NextListingProposalId::<T>::get();
+        pallet_prmx_markets::Pallet::<T>::propose_market_listing(
+            RawOrigin::Signed(proposer).into(),
+            MarketListingParams {
+                name: BoundedVec::truncate_from(b"Benchmark".to_vec()),
+                center_latitude: 14_599_500,
+                center_longitude: 120_984_200,
+                timezone_offset_hours: 8,
+                strike_value: 500,
+                base_asset: Default::default(),
+                payout_per_share: 100_000_000u128.into(),
+                risk: RiskParameters { dao_margin_bp: 2_000 },
+                window_rules: WindowRules::default(),
+            },
+        )
+        .expect("proposer is funded");
+        PendingListingChecks::<T>::insert(proposal_id, (14_599_500, 120_984_200));
+        let provider = bonded_provider::<T>(0);
+        let feasibility = ListingFeasibility::Feasible { history_hours: 24 };
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(provider), proposal_id, feasibility);
+
+        assert!(!PendingListingChecks::<T>::contains_key(proposal_id));
+        assert_eq!(
+            pallet_prmx_markets::ListingProposals::<T>::get(proposal_id).map(|p| p.feasibility),
+            Some(feasibility)
+        );
+    }
+
+    #[benchmark]
+    fn set_oracle_stale_after_hours() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, 6);
+
+        assert_eq!(OracleStaleAfterHours::<T>::get(), Some(6));
+        Ok(())
+    }
+    // Settles without an `observed_mm` override, so the whole coverage window is scanned
+    #[benchmark]
+    fn poke_settlement() {
+        let keeper: T::AccountId = whitelisted_caller();
+        let market_id = bound_market::<T>();
+        let policy_id = T::BenchmarkHelper::expired_policy(market_id);
+        let (_, _, _, coverage_end, _) =
+            T::PolicySettlement::get_policy_info(policy_id).expect("policy was created");
+        T::BenchmarkHelper::set_timestamp(coverage_end + DEFAULT_SETTLEMENT_GRACE_SECS);
+        RollingState::<T>::insert(
+            market_id,
+            RollingWindowState {
+                last_bucket_index: bucket_index_for_timestamp(coverage_end),
+                ..Default::default()
+            },
+        );
+        T::Currency::make_free_balance_be(
+            &T::KeeperRewardAccount::get(),
+            BalanceOf::<T>::max_value() / 2u32.into(),
+        );
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(keeper.clone()), policy_id);
+
+        assert!(!T::Currency::free_balance(&keeper).is_zero());
+    }
+
+    #[benchmark]
+    fn submit_hourly_snowfall_from_ocw(n: Linear<1, 24>) {
+        let market_id = bound_market::<T>();
+        let provider = assigned_provider::<T>(market_id);
+        T::BenchmarkHelper::set_timestamp(NOW);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(provider), market_id, hourly(n, 120u32));
+
+        assert_eq!(SnowBuckets::<T>::iter_prefix(market_id).count() as u32, n);
+    }
+
+    // A full retention window of gauge levels, one of them aged out, and a full day of
+    // rainfall for the rolling sum
+    #[benchmark]
+    fn submit_gauge_level_from_ocw() {
+        let market_id = bound_market::<T>();
+        let provider = assigned_provider::<T>(market_id);
+        T::BenchmarkHelper::set_timestamp(NOW);
+        GaugeSources::<T>::insert(market_id, gauge_source());
+
+        let current_hour = NOW / 3600;
+        for hour in (current_hour - WEATHER_RETENTION_SECS / 3600 - 1)..current_hour {
+            GaugeBuckets::<T>::insert(
+                market_id,
+                hour,
+                GaugeBucket { level_cm: 250, rainfall_24h_mm: 0, fetched_at: hour * 3600 },
+            );
+        }
+        for hour in (current_hour - ROLLING_WINDOW_SECS / 3600)..=current_hour {
+            Pallet::<T>::put_hourly_bucket(
+                market_id,
+                HourlyBucket { hour_index: hour, mm: 50, fetched_at: hour * 3600, source: 2 },
+                NOW,
+            );
+        }
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(provider), market_id, NOW, 420);
+
+        assert_eq!(GaugeBuckets::<T>::get(market_id, current_hour).map(|b| b.level_cm), Some(420));
+    }
+
+    #[benchmark]
+    fn set_gauge_source() -> Result<(), BenchmarkError> {
+        let market_id = market::<T>();
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, market_id, Some(gauge_source()));
+
+        assert!(GaugeSources::<T>::contains_key(market_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_market_data_source() -> Result<(), BenchmarkError> {
+        let market_id = market::<T>();
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, market_id, Some(data_source_config()));
+
+        assert!(MarketDataSources::<T>::contains_key(market_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_provider_assignments(
+        p: Linear<1, MAX_PROVIDERS_PER_MARKET>,
+    ) -> Result<(), BenchmarkError> {
+        let market_id = market::<T>();
+        let providers: Vec<T::AccountId> = (0..p).map(bonded_provider::<T>).collect();
+        let providers = BoundedVec::truncate_from(providers);
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, market_id, providers);
+
+        assert_eq!(
+            ProviderAssignments::<T>::get(market_id).map(|providers| providers.len()),
+            Some(p as usize)
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn rotate_provider() -> Result<(), BenchmarkError> {
+        let market_id = bound_market::<T>();
+        let outgoing = assigned_provider::<T>(market_id);
+        let incoming = bonded_provider::<T>(1);
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, market_id, outgoing, incoming.clone());
+
+        assert!(ProviderAssignments::<T>::get(market_id)
+            .is_some_and(|providers| providers.contains(&incoming)));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn correct_hourly_rainfall_from_ocw(n: Linear<1, 24>) -> Result<(), BenchmarkError> {
+        let market_id = bound_market::<T>();
+        let provider = assigned_provider::<T>(market_id);
+        T::BenchmarkHelper::set_timestamp(NOW);
+        Pallet::<T>::do_submit_provider_hourly_rainfall(
+            &provider,
+            market_id,
+            WeatherProvider::OpenMeteo,
+            &hourly(n, 25u32),
+        )?;
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(provider),
+            market_id,
+            WeatherProvider::OpenMeteo,
+            hourly(n, 250u32),
+        );
+
+        assert!(ProviderHourlyReadings::<T>::iter_prefix_values(market_id)
+            .all(|readings| readings.iter().all(|r| r.mm == 250)));
+        Ok(())
+    }
+
+    // Rotates out a previously registered key
+    #[benchmark]
+    fn register_oracle_keys() {
+        let provider = bonded_provider::<T>(0);
+        let old_key = registered_key::<T>(&provider);
+        let key = sp_io::crypto::sr25519_generate(KEY_TYPE, None);
+        let message = keys::ownership_message(&provider);
+        let proof =
+            sp_io::crypto::sr25519_sign(KEY_TYPE, &key, &message).expect("key was just generated");
+        let public: T::Public = key.into();
+        let key_account = public.clone().into_account();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(provider.clone()), public, proof.into());
+
+        assert_eq!(ProviderKeys::<T>::get(&provider), Some(key_account));
+        assert!(!KeyOwners::<T>::contains_key(&old_key));
+    }
+
+    #[benchmark]
+    fn purge_oracle_keys() {
+        let provider = bonded_provider::<T>(0);
+        let key = registered_key::<T>(&provider);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(provider.clone()));
+
+        assert!(!ProviderKeys::<T>::contains_key(&provider));
+        assert!(!KeyOwners::<T>::contains_key(&key));
+    }
+}
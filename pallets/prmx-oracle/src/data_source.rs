@@ -0,0 +1,395 @@
+//! # Per-Market Data Sources
+//!
+//! Governance can point a market's secondary rainfall fetch at any JSON weather
+//! API (`MarketDataSources`) instead of adding a fetcher per national weather
+//! service. A source names:
+//!
+//! - the `WeatherProvider` its readings are reported as. For Open-Meteo, NOAA or
+//!   Meteostat it replaces the built-in fetcher for that market; `Custom` adds a
+//!   provider with no built-in fetcher.
+//! - an HTTPS endpoint template. `{lat}` and `{lon}` expand to the market center
+//!   in degrees, `{start_date}` and `{end_date}` to yesterday and today
+//!   (`YYYY-MM-DD`, UTC), and `{api_key}` to the key in offchain storage under
+//!   `DATA_SOURCE_API_KEY_PREFIX` + market id, so secrets stay off-chain.
+//! - where the hourly readings are in the response: an array of observation
+//!   records, or parallel arrays of times and precipitation (dotted paths as in
+//!   `prmx_json::Value::select`)
+//! - the time format and precipitation unit, converted to Unix seconds and
+//!   tenths of mm.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use frame_support::{traits::ConstU32, BoundedVec};
+use prmx_json::Value;
+use prmx_primitives::OcwErrorKind;
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+use crate::{providers, Millimeters, WeatherProvider};
+
+/// Maximum length of an endpoint template
+pub const MAX_ENDPOINT_TEMPLATE_LEN: u32 = 256;
+
+/// Maximum length of a JSON path in a field mapping
+pub const MAX_FIELD_PATH_LEN: u32 = 64;
+
+/// Offchain storage key prefix for a market's data source API key
+pub const DATA_SOURCE_API_KEY_PREFIX: &[u8] = b"prmx-oracle::data-source-key::";
+
+/// Placeholders an endpoint template may contain
+const PLACEHOLDERS: [&str; 5] = ["{lat}", "{lon}", "{start_date}", "{end_date}", "{api_key}"];
+
+/// Dotted JSON path within a response
+pub type FieldPath = BoundedVec<u8, ConstU32<MAX_FIELD_PATH_LEN>>;
+
+/// Where the hourly readings are in a response
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum FieldMapping {
+    /// Array of observation records at `records` (empty for a top-level array);
+    /// `time` and `precipitation` are paths within each record
+    Records { records: FieldPath, time: FieldPath, precipitation: FieldPath },
+    /// Parallel arrays of observation times and precipitation
+    Columns { time: FieldPath, precipitation: FieldPath },
+}
+
+/// Format of observation times
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum TimeFormat {
+    UnixSeconds,
+    UnixMillis,
+    /// `YYYY-MM-DD[T ]HH:MM:SS[+HH:MM|Z]`
+    Iso8601,
+}
+
+/// Unit of precipitation readings
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum PrecipitationUnit {
+    Millimeters,
+    TenthsOfMillimeters,
+    Inches,
+}
+
+impl PrecipitationUnit {
+    /// Convert a reading to tenths of mm, clamping negatives to zero
+    pub fn to_tenths(self, value: f64) -> Millimeters {
+        let tenths = match self {
+            PrecipitationUnit::Millimeters => value * 10.0,
+            PrecipitationUnit::TenthsOfMillimeters => value,
+            PrecipitationUnit::Inches => value * 254.0,
+        };
+        if tenths <= 0.0 {
+            return 0;
+        }
+        // Manual rounding: add 0.5 and truncate (f64::round not available in no_std)
+        (tenths + 0.5) as Millimeters
+    }
+}
+
+/// Rainfall API configured for a market
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct DataSourceConfig {
+    /// Provider the readings are reported as
+    pub provider: WeatherProvider,
+    /// HTTPS URL with placeholders, e.g.
+    /// `https://api.example.gov/obs?lat={lat}&lon={lon}&key={api_key}`
+    pub endpoint_template: BoundedVec<u8, ConstU32<MAX_ENDPOINT_TEMPLATE_LEN>>,
+    pub field_mapping: FieldMapping,
+    pub time_format: TimeFormat,
+    pub unit: PrecipitationUnit,
+}
+
+impl DataSourceConfig {
+    /// Whether the source can be fetched and parsed by the offchain worker.
+    /// AccuWeather has its own pipeline and cannot be configured here.
+    pub fn is_valid(&self) -> bool {
+        let template_ok = core::str::from_utf8(&self.endpoint_template)
+            .is_ok_and(|url| url.starts_with("https://") && unknown_placeholder(url).is_none());
+
+        let path_ok = |path: &FieldPath, allow_empty: bool| {
+            core::str::from_utf8(path).is_ok_and(|path| {
+                (allow_empty && path.is_empty())
+                    || (!path.is_empty() && path.split('.').all(|segment| !segment.is_empty()))
+            })
+        };
+        let mapping_ok = match &self.field_mapping {
+            FieldMapping::Records { records, time, precipitation } => {
+                path_ok(records, true) && path_ok(time, false) && path_ok(precipitation, false)
+            }
+            FieldMapping::Columns { time, precipitation } => {
+                path_ok(time, false) && path_ok(precipitation, false)
+            }
+        };
+
+        self.provider != WeatherProvider::AccuWeather && template_ok && mapping_ok
+    }
+
+    /// Expand the endpoint template for a market at `now`
+    pub fn endpoint(
+        &self,
+        lat: f64,
+        lon: f64,
+        now: u64,
+        api_key: Option<&str>,
+    ) -> Result<String, OcwErrorKind> {
+        let template = core::str::from_utf8(&self.endpoint_template)
+            .map_err(|_| OcwErrorKind::Config)?;
+        if template.contains("{api_key}") && api_key.is_none() {
+            return Err(OcwErrorKind::Config);
+        }
+
+        Ok(template
+            .replace("{lat}", &alloc::format!("{}", lat))
+            .replace("{lon}", &alloc::format!("{}", lon))
+            .replace("{start_date}", &providers::format_date(now.saturating_sub(86_400)))
+            .replace("{end_date}", &providers::format_date(now))
+            .replace("{api_key}", api_key.unwrap_or_default()))
+    }
+
+    /// Read `(epoch_time, rainfall)` pairs from a response, rainfall in tenths
+    /// of mm. Observations with a missing or null time or reading are skipped.
+    pub fn parse_response(&self, json: &[u8]) -> Result<Vec<(u64, Millimeters)>, OcwErrorKind> {
+        let document = prmx_json::parse(json).map_err(|_| OcwErrorKind::Parse)?;
+
+        let readings = match &self.field_mapping {
+            FieldMapping::Records { records, time, precipitation } => {
+                let records = select_array(&document, records)?;
+                let time = path_str(time)?;
+                let precipitation = path_str(precipitation)?;
+                records
+                    .iter()
+                    .filter_map(|record| {
+                        Some((
+                            self.read_time(record.select(time)?)?,
+                            self.read_precipitation(record.select(precipitation)?)?,
+                        ))
+                    })
+                    .collect()
+            }
+            FieldMapping::Columns { time, precipitation } => {
+                let times = select_array(&document, time)?;
+                let values = select_array(&document, precipitation)?;
+                if times.len() != values.len() {
+                    return Err(OcwErrorKind::Parse);
+                }
+                times
+                    .iter()
+                    .zip(values)
+                    .filter_map(|(ts, value)| {
+                        Some((self.read_time(ts)?, self.read_precipitation(value)?))
+                    })
+                    .collect()
+            }
+        };
+
+        Ok(readings)
+    }
+
+    fn read_time(&self, value: &Value) -> Option<u64> {
+        match self.time_format {
+            TimeFormat::UnixSeconds => value.as_u64(),
+            TimeFormat::UnixMillis => value.as_u64().map(|ms| ms / 1000),
+            TimeFormat::Iso8601 => value.as_str().and_then(providers::parse_iso8601),
+        }
+    }
+
+    /// Numeric readings, or numeric strings as some national APIs quote them
+    fn read_precipitation(&self, value: &Value) -> Option<Millimeters> {
+        let value = match value {
+            Value::Number(n) => *n,
+            Value::String(s) => prmx_json::parse_str(s.trim()).ok()?.as_f64()?,
+            _ => return None,
+        };
+        Some(self.unit.to_tenths(value))
+    }
+}
+
+/// Fetch the last 24 hours of rainfall from a configured source
+pub fn fetch(
+    source: &DataSourceConfig,
+    lat: f64,
+    lon: f64,
+    now: u64,
+    api_key: Option<&str>,
+) -> Result<Vec<(u64, Millimeters)>, OcwErrorKind> {
+    let url = source.endpoint(lat, lon, now, api_key)?;
+    let body = providers::http_get(&url, &[("Accept", "application/json")])?;
+    let readings = source.parse_response(&body)?;
+
+    Ok(providers::last_24_hours(readings, now))
+}
+
+/// First `{...}` in `template` that is not a known placeholder
+fn unknown_placeholder(template: &str) -> Option<&str> {
+    let mut rest = template;
+    while let Some(open) = rest.find('{') {
+        let Some(close) = rest[open..].find('}') else {
+            return Some(&rest[open..]);
+        };
+        let placeholder = &rest[open..=open + close];
+        if !PLACEHOLDERS.contains(&placeholder) {
+            return Some(placeholder);
+        }
+        rest = &rest[open + close + 1..];
+    }
+    None
+}
+
+fn path_str(path: &FieldPath) -> Result<&str, OcwErrorKind> {
+    core::str::from_utf8(path).map_err(|_| OcwErrorKind::Config)
+}
+
+fn select_array<'a>(document: &'a Value, path: &FieldPath) -> Result<&'a [Value], OcwErrorKind> {
+    document
+        .select(path_str(path)?)
+        .and_then(Value::as_array)
+        .ok_or(OcwErrorKind::Parse)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn path(p: &str) -> FieldPath {
+        p.as_bytes().to_vec().try_into().unwrap()
+    }
+
+    fn config(template: &str, field_mapping: FieldMapping) -> DataSourceConfig {
+        DataSourceConfig {
+            provider: WeatherProvider::Custom,
+            endpoint_template: template.as_bytes().to_vec().try_into().unwrap(),
+            field_mapping,
+            time_format: TimeFormat::Iso8601,
+            unit: PrecipitationUnit::Millimeters,
+        }
+    }
+
+    fn records() -> FieldMapping {
+        FieldMapping::Records {
+            records: path("data"),
+            time: path("time"),
+            precipitation: path("prcp"),
+        }
+    }
+
+    #[test]
+    fn test_is_valid() {
+        assert!(config("https://api.example.gov/obs?lat={lat}&lon={lon}", records()).is_valid());
+        assert!(!config("http://api.example.gov/obs", records()).is_valid());
+        assert!(!config("https://api.example.gov/obs?q={city}", records()).is_valid());
+        assert!(!config("https://api.example.gov/obs?q={lat", records()).is_valid());
+
+        let empty_time = FieldMapping::Columns { time: path(""), precipitation: path("rain") };
+        assert!(!config("https://api.example.gov/obs", empty_time).is_valid());
+
+        let mut accuweather = config("https://api.example.gov/obs", records());
+        accuweather.provider = WeatherProvider::AccuWeather;
+        assert!(!accuweather.is_valid());
+    }
+
+    #[test]
+    fn test_endpoint() {
+        let source = config(
+            "https://api.example.gov/obs?lat={lat}&lon={lon}&from={start_date}&to={end_date}\
+             &key={api_key}",
+            records(),
+        );
+        assert_eq!(
+            source.endpoint(14.5995, 120.9842, 1_704_153_600, Some("secret")).unwrap(),
+            "https://api.example.gov/obs?lat=14.5995&lon=120.9842&from=2024-01-01&to=2024-01-02\
+             &key=secret"
+        );
+        assert_eq!(
+            source.endpoint(14.5995, 120.9842, 1_704_153_600, None),
+            Err(OcwErrorKind::Config)
+        );
+    }
+
+    #[test]
+    fn test_parse_records() {
+        // Meteostat-style records with ISO times in mm
+        let json = br#"{"data":[{"time":"2024-01-01 00:00:00","prcp":0.4},
+            {"time":"2024-01-01 01:00:00","prcp":null},{"time":"2024-01-01 02:00:00","prcp":"1.2"}]}"#;
+        assert_eq!(
+            config("https://x.example", records()).parse_response(json).unwrap(),
+            vec![(1_704_067_200, 4), (1_704_074_400, 12)]
+        );
+
+        // Top-level array of nested records in inches
+        let mut source = config(
+            "https://x.example",
+            FieldMapping::Records {
+                records: path(""),
+                time: path("obs.epoch"),
+                precipitation: path("obs.rain.in"),
+            },
+        );
+        source.time_format = TimeFormat::UnixSeconds;
+        source.unit = PrecipitationUnit::Inches;
+        let json = br#"[{"obs":{"epoch":1704067200,"rain":{"in":0.1}}}]"#;
+        assert_eq!(source.parse_response(json).unwrap(), vec![(1_704_067_200, 25)]);
+    }
+
+    #[test]
+    fn test_parse_columns() {
+        let mut source = config(
+            "https://x.example",
+            FieldMapping::Columns {
+                time: path("hourly.time"),
+                precipitation: path("hourly.precipitation"),
+            },
+        );
+        source.time_format = TimeFormat::UnixMillis;
+        let json = br#"{"hourly":{"time":[1704067200000,1704070800000],"precipitation":[1.25,-1]}}"#;
+        assert_eq!(
+            source.parse_response(json).unwrap(),
+            vec![(1_704_067_200, 13), (1_704_070_800, 0)]
+        );
+
+        let mismatched = br#"{"hourly":{"time":[1704067200000],"precipitation":[]}}"#;
+        assert!(source.parse_response(mismatched).is_err());
+        assert!(source.parse_response(br#"{"daily":{}}"#).is_err());
+    }
+}
@@ -0,0 +1,90 @@
+//! # Rainfall Fetch Schedule
+//!
+//! Pure helpers for `NextFetchDue`, the on-chain time at which each market's
+//! rainfall is next fetched by the offchain worker.
+//!
+//! `on_initialize` keeps the schedule: a market without an entry is due at once,
+//! and once rainfall data arrives at or after the due time the next fetch is due
+//! at the start of the following hour. The OCW only reads the schedule, so a
+//! node that restarts or skips blocks finds overdue markets still due and
+//! catches up on its next run. A single historical/24 fetch covers up to
+//! `CATCH_UP_LIMIT_SECS` of missed hours; longer gaps need `request_backfill`.
+
+/// Interval between scheduled fetches (1 hour)
+pub const FETCH_INTERVAL_SECS: u64 = 3600;
+
+/// Longest gap one catch-up fetch can fill (one historical/24 response)
+pub const CATCH_UP_LIMIT_SECS: u64 = 24 * 3600;
+
+/// New `NextFetchDue` for a market, or `None` if the stored value stays.
+///
+/// - `due`: the stored due time, if any
+/// - `last_data_at`: when rainfall data last arrived on-chain
+pub fn next_fetch_due(due: Option<u64>, last_data_at: Option<u64>, now: u64) -> Option<u64> {
+    match (due, last_data_at) {
+        (None, _) => Some(now),
+        (Some(due), Some(last_data_at)) if last_data_at >= due => {
+            Some((last_data_at / FETCH_INTERVAL_SECS + 1) * FETCH_INTERVAL_SECS)
+        }
+        (Some(_), _) => None,
+    }
+}
+
+/// Whether a fetch due at `due` is overdue at `now` by more than one catch-up
+/// fetch can recover
+pub fn exceeds_catch_up(due: u64, now: u64) -> bool {
+    now.saturating_sub(due) > CATCH_UP_LIMIT_SECS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: u64 = FETCH_INTERVAL_SECS;
+
+    #[test]
+    fn test_unscheduled_market_is_due_now() {
+        assert_eq!(
+            next_fetch_due(None, None, 10 * HOUR + 5),
+            Some(10 * HOUR + 5)
+        );
+        assert_eq!(
+            next_fetch_due(None, Some(3 * HOUR), 10 * HOUR),
+            Some(10 * HOUR)
+        );
+    }
+
+    #[test]
+    fn test_data_at_or_after_due_schedules_next_hour() {
+        assert_eq!(
+            next_fetch_due(Some(10 * HOUR), Some(10 * HOUR + 30), 10 * HOUR + 60),
+            Some(11 * HOUR)
+        );
+        assert_eq!(
+            next_fetch_due(Some(10 * HOUR), Some(10 * HOUR), 10 * HOUR),
+            Some(11 * HOUR)
+        );
+        // Catch-up after a long outage resumes the hourly cadence from the fetch
+        assert_eq!(
+            next_fetch_due(Some(10 * HOUR), Some(15 * HOUR + 1800), 15 * HOUR + 1900),
+            Some(16 * HOUR)
+        );
+    }
+
+    #[test]
+    fn test_due_stays_until_data_arrives() {
+        // No data yet, or only data from before the due time (e.g. a manual fetch)
+        assert_eq!(next_fetch_due(Some(11 * HOUR), None, 12 * HOUR), None);
+        assert_eq!(
+            next_fetch_due(Some(11 * HOUR), Some(10 * HOUR + 3000), 12 * HOUR),
+            None
+        );
+    }
+
+    #[test]
+    fn test_exceeds_catch_up() {
+        assert!(!exceeds_catch_up(10 * HOUR, 9 * HOUR));
+        assert!(!exceeds_catch_up(10 * HOUR, 34 * HOUR));
+        assert!(exceeds_catch_up(10 * HOUR, 34 * HOUR + 1));
+    }
+}
@@ -0,0 +1,205 @@
+//! # River Gauge Levels
+//!
+//! Second data source for urban flood products. Governance configures a gauge
+//! API per market (`GaugeSources`): a URL returning JSON, a dotted path to the
+//! water level in the response (numeric segments index arrays, e.g.
+//! `items.0.latestReading.value`), the unit conversion to centimetres and the
+//! flood stage of the gauge.
+//!
+//! The offchain worker fetches the level once per hour and submits it to
+//! `GaugeBuckets` together with the market's 24h rolling rainfall at that time.
+//! A `FloodComposite` policy triggers on an hour where the gauge is at or above
+//! the flood stage while the 24h rainfall reaches the strike, so neither a
+//! downpour that drains away nor a river swollen by rain elsewhere pays out alone.
+
+use alloc::vec::Vec;
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use frame_support::{traits::ConstU32, BoundedVec};
+use prmx_json::Value;
+use prmx_primitives::OcwErrorKind;
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+use crate::{providers, Centimeters, Millimeters};
+
+/// Maximum length of a gauge API URL
+pub const MAX_GAUGE_URL_LEN: u32 = 256;
+
+/// Maximum length of the JSON path to the gauge level
+pub const MAX_GAUGE_PATH_LEN: u32 = 128;
+
+/// `cm_per_unit_milli` for gauges reporting in centimetres
+pub const CM_PER_UNIT_SCALE: u32 = 1_000;
+
+/// Lowest plausible gauge level (-100 m below the datum)
+pub const MIN_GAUGE_LEVEL_CM: Centimeters = -10_000;
+
+/// Highest plausible gauge level (500 m above the datum)
+pub const MAX_GAUGE_LEVEL_CM: Centimeters = 50_000;
+
+/// River/flood gauge API configured for a market
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct GaugeSource {
+    /// HTTPS URL returning the latest gauge reading as JSON
+    pub url: BoundedVec<u8, ConstU32<MAX_GAUGE_URL_LEN>>,
+    /// Dotted path to the level in the response, e.g. `items.0.latestReading.value`
+    pub json_path: BoundedVec<u8, ConstU32<MAX_GAUGE_PATH_LEN>>,
+    /// Centimetres per API unit, scaled by 1000 (100_000 for metres, 30_480 for feet)
+    pub cm_per_unit_milli: u32,
+    /// Flood stage of the gauge in centimetres above the datum
+    pub flood_level_cm: Centimeters,
+}
+
+impl GaugeSource {
+    /// Whether the source can be fetched and parsed by the offchain worker
+    pub fn is_valid(&self) -> bool {
+        let url_ok = core::str::from_utf8(&self.url).is_ok_and(|url| url.starts_with("https://"));
+        let path_ok = core::str::from_utf8(&self.json_path)
+            .is_ok_and(|path| !path.is_empty() && path.split('.').all(|segment| !segment.is_empty()));
+
+        url_ok
+            && path_ok
+            && self.cm_per_unit_milli > 0
+            && (MIN_GAUGE_LEVEL_CM..=MAX_GAUGE_LEVEL_CM).contains(&self.flood_level_cm)
+    }
+}
+
+/// Fetch the current level of a configured gauge in centimetres
+pub fn fetch_gauge_level(source: &GaugeSource) -> Result<Centimeters, OcwErrorKind> {
+    let url = core::str::from_utf8(&source.url).map_err(|_| OcwErrorKind::Config)?;
+    let path = core::str::from_utf8(&source.json_path).map_err(|_| OcwErrorKind::Config)?;
+
+    let body = providers::http_get(url, &[("Accept", "application/json")])?;
+    parse_gauge_level(&body, path, source.cm_per_unit_milli)
+}
+
+/// Read the level at `path` and convert it to centimetres. Gauge APIs often
+/// quote numbers as strings, so numeric strings are accepted too.
+pub fn parse_gauge_level(
+    json: &[u8],
+    path: &str,
+    cm_per_unit_milli: u32,
+) -> Result<Centimeters, OcwErrorKind> {
+    let document = prmx_json::parse(json).map_err(|_| OcwErrorKind::Parse)?;
+    let value = match document.select(path).ok_or(OcwErrorKind::Parse)? {
+        Value::Number(n) => *n,
+        Value::String(s) => prmx_json::parse_str(s.trim())
+            .ok()
+            .and_then(|v| v.as_f64())
+            .ok_or(OcwErrorKind::Parse)?,
+        _ => return Err(OcwErrorKind::Parse),
+    };
+
+    let cm = value * cm_per_unit_milli as f64 / CM_PER_UNIT_SCALE as f64;
+    // Rounded away from zero (f64::round not available in no_std)
+    let cm = if cm >= 0.0 { cm + 0.5 } else { cm - 0.5 };
+    if !(MIN_GAUGE_LEVEL_CM as f64..=MAX_GAUGE_LEVEL_CM as f64).contains(&cm) {
+        return Err(OcwErrorKind::Parse);
+    }
+
+    Ok(cm as Centimeters)
+}
+
+/// Highest 24h rainfall (tenths of mm) recorded while the gauge was at or above
+/// `flood_level_cm`, for readings given as (level, 24h rainfall). `None` if the
+/// gauge never reached the flood stage.
+pub fn max_rainfall_at_flood_stage(
+    readings: &[(Centimeters, Millimeters)],
+    flood_level_cm: Centimeters,
+) -> Option<Millimeters> {
+    readings
+        .iter()
+        .filter(|(level, _)| *level >= flood_level_cm)
+        .map(|(_, rainfall)| *rainfall)
+        .max()
+}
+
+/// Readings from (hour_index, level, 24h rainfall) entries inside
+/// [start_hour, end_hour]
+pub fn readings_in_window(
+    entries: impl Iterator<Item = (u64, Centimeters, Millimeters)>,
+    start_hour: u64,
+    end_hour: u64,
+) -> Vec<(Centimeters, Millimeters)> {
+    entries
+        .filter(|(hour, _, _)| (start_hour..=end_hour).contains(hour))
+        .map(|(_, level, rainfall)| (level, rainfall))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn source(url: &str, path: &str) -> GaugeSource {
+        GaugeSource {
+            url: url.as_bytes().to_vec().try_into().unwrap(),
+            json_path: path.as_bytes().to_vec().try_into().unwrap(),
+            cm_per_unit_milli: 100_000,
+            flood_level_cm: 350,
+        }
+    }
+
+    #[test]
+    fn test_is_valid() {
+        assert!(source("https://gauges.example/api/1", "items.0.value").is_valid());
+        assert!(!source("http://gauges.example/api/1", "items.0.value").is_valid());
+        assert!(!source("https://gauges.example/api/1", "").is_valid());
+        assert!(!source("https://gauges.example/api/1", "items..value").is_valid());
+
+        let mut zero_scale = source("https://gauges.example/api/1", "value");
+        zero_scale.cm_per_unit_milli = 0;
+        assert!(!zero_scale.is_valid());
+    }
+
+    #[test]
+    fn test_parse_gauge_level() {
+        // Environment Agency style: metres as a number
+        let ea = br#"{"items": {"latestReading": {"dateTime": "2024-01-01T10:00:00Z", "value": 3.456}}}"#;
+        assert_eq!(parse_gauge_level(ea, "items.latestReading.value", 100_000), Ok(346));
+
+        // USGS style: feet as a string inside nested arrays
+        let usgs = br#"{"value": {"timeSeries": [{"values": [{"value": [{"value": "12.5"}]}]}]}}"#;
+        assert_eq!(
+            parse_gauge_level(usgs, "value.timeSeries.0.values.0.value.0.value", 30_480),
+            Ok(381)
+        );
+
+        // Below the datum, and already in centimetres
+        assert_eq!(parse_gauge_level(br#"{"level": -12.4}"#, "level", CM_PER_UNIT_SCALE), Ok(-12));
+
+        assert!(parse_gauge_level(br#"{"level": null}"#, "level", CM_PER_UNIT_SCALE).is_err());
+        assert!(parse_gauge_level(br#"{"level": "n/a"}"#, "level", CM_PER_UNIT_SCALE).is_err());
+        assert!(parse_gauge_level(br#"{"other": 1}"#, "level", CM_PER_UNIT_SCALE).is_err());
+        assert!(parse_gauge_level(br#"{"level": 9999}"#, "level", 100_000).is_err());
+    }
+
+    #[test]
+    fn test_max_rainfall_at_flood_stage() {
+        assert_eq!(max_rainfall_at_flood_stage(&[], 350), None);
+
+        // Heavy rain while the river is low does not count
+        let readings = [(200, 1_500), (360, 600), (410, 900), (300, 2_000)];
+        assert_eq!(max_rainfall_at_flood_stage(&readings, 350), Some(900));
+        assert_eq!(max_rainfall_at_flood_stage(&readings, 500), None);
+    }
+
+    #[test]
+    fn test_readings_in_window() {
+        let entries = [(99, 400, 10), (100, 410, 20), (105, 420, 30), (106, 430, 40)];
+        assert_eq!(
+            readings_in_window(entries.into_iter(), 100, 105),
+            vec![(410, 20), (420, 30)]
+        );
+    }
+}
@@ -0,0 +1,115 @@
+//! # Observation Gossip
+//!
+//! Each oracle authority fetches hourly rainfall on its own schedule, so the
+//! observations known to one node's OCW can drift from another's when a fetch
+//! fails or is skipped. After every successful fetch the OCW publishes the raw
+//! observations through an unsigned `mirror_observations` call, signed by its
+//! oracle authority key.
+//!
+//! Mirrors are grouped by market, weather provider and observation digest.
+//! Once `MirrorQuorum` distinct authorities attest the same digest, the
+//! observations are ingested as a provider submission and written through
+//! offchain indexing under `MIRRORED_OBSERVATIONS_PREFIX`, so every node's
+//! offchain storage holds the same buckets, fetched locally or not.
+
+use alloc::vec::Vec;
+use codec::Encode;
+use sp_io::hashing::blake2_256;
+
+use crate::{MarketId, Millimeters, WeatherProvider};
+
+/// Offchain storage key prefix for observations mirrored by a quorum of authorities
+/// (SCALE-encoded `Vec<(hour_index, Millimeters)>`, written via offchain indexing)
+pub const MIRRORED_OBSERVATIONS_PREFIX: &[u8] = b"prmx-oracle::mirrored-observations::";
+
+/// Authorities that must attest the same observations before they are applied,
+/// while governance has not set `MirrorQuorum`
+pub const DEFAULT_MIRROR_QUORUM: u32 = 2;
+
+/// Most authorities counted towards one mirror candidate
+pub const MAX_MIRROR_ATTESTERS: u32 = 16;
+
+/// Most competing observation sets kept per market and weather provider; the
+/// oldest candidate is dropped when a new one arrives
+pub const MAX_MIRROR_CANDIDATES: u32 = 4;
+
+/// Blocks a mirror stays valid after the OCW signed it (~1 hour)
+pub const MIRROR_LONGEVITY_BLOCKS: u32 = 600;
+
+/// Observations keyed by hour index, sorted, one per hour (the first one wins).
+/// Fetch epoch times differ between nodes within an hour, hour indices do not.
+pub fn normalize(observations: &[(u64, Millimeters)]) -> Vec<(u64, Millimeters)> {
+    let mut by_hour: Vec<(u64, Millimeters)> = Vec::with_capacity(observations.len());
+    for (epoch_time, mm) in observations {
+        let hour_index = epoch_time / 3600;
+        if let Err(pos) = by_hour.binary_search_by_key(&hour_index, |(hour, _)| *hour) {
+            by_hour.insert(pos, (hour_index, *mm));
+        }
+    }
+    by_hour
+}
+
+/// Digest identifying a normalized observation set.
+///
+/// Format: blake2_256(b"prmx_mirror:" || SCALE(market_id, provider, observations))
+pub fn observations_digest(
+    market_id: MarketId,
+    provider: WeatherProvider,
+    observations: &[(u64, Millimeters)],
+) -> [u8; 32] {
+    let mut data = b"prmx_mirror:".to_vec();
+    (market_id, provider, observations).encode_to(&mut data);
+    blake2_256(&data)
+}
+
+/// Offchain storage key of the latest mirrored observations for a market and provider
+pub fn mirror_key(market_id: MarketId, provider: WeatherProvider) -> Vec<u8> {
+    let mut key = MIRRORED_OBSERVATIONS_PREFIX.to_vec();
+    key.extend_from_slice(&market_id.to_le_bytes());
+    provider.encode_to(&mut key);
+    key
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_keys_by_hour_and_keeps_first_reading() {
+        let observations = [(7_260, 30), (3_600, 10), (3_700, 99), (10_800, 0)];
+        assert_eq!(normalize(&observations), [(1, 10), (2, 30), (3, 0)].to_vec());
+        assert!(normalize(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_digest_matches_across_fetch_times_within_the_hour() {
+        let node_a = normalize(&[(3_600, 10), (7_200, 25)]);
+        let node_b = normalize(&[(7_500, 25), (3_900, 10)]);
+        assert_eq!(
+            observations_digest(1, WeatherProvider::AccuWeather, &node_a),
+            observations_digest(1, WeatherProvider::AccuWeather, &node_b)
+        );
+
+        let changed = normalize(&[(3_600, 10), (7_200, 26)]);
+        assert_ne!(
+            observations_digest(1, WeatherProvider::AccuWeather, &node_a),
+            observations_digest(1, WeatherProvider::AccuWeather, &changed)
+        );
+        assert_ne!(
+            observations_digest(1, WeatherProvider::AccuWeather, &node_a),
+            observations_digest(2, WeatherProvider::AccuWeather, &node_a)
+        );
+        assert_ne!(
+            observations_digest(1, WeatherProvider::AccuWeather, &node_a),
+            observations_digest(1, WeatherProvider::OpenMeteo, &node_a)
+        );
+    }
+
+    #[test]
+    fn test_mirror_key_is_distinct_per_market_and_provider() {
+        let key = mirror_key(1, WeatherProvider::AccuWeather);
+        assert!(key.starts_with(MIRRORED_OBSERVATIONS_PREFIX));
+        assert_ne!(key, mirror_key(2, WeatherProvider::AccuWeather));
+        assert_ne!(key, mirror_key(1, WeatherProvider::Noaa));
+    }
+}
@@ -0,0 +1,121 @@
+//! # Hourly Ring Buffer
+//!
+//! `HourlyBuckets` keeps a market's agreed hourly rainfall in a fixed ring of
+//! slots keyed by `hour_index % slots`, with the running sum of the stored
+//! buckets in `HourlyRing`. Submissions update the sum as they write buckets and
+//! only evict the hours that left the acceptance window since the last refresh,
+//! so no extrinsic has to iterate a market's buckets.
+//!
+//! The acceptance window covers the rolling window, one buffer hour and the
+//! current hour, so a market needs `window_hours + 2` slots (26 for a 24h window)
+//! for every accepted hour to have a slot of its own.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use core::ops::Range;
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+use crate::Millimeters;
+
+/// Number of ring slots for a rolling window of `window_secs`
+pub fn ring_slots(window_secs: u64) -> u32 {
+    (window_secs / 3600 + 2) as u32
+}
+
+/// Slot holding `hour_index` in a ring of `slots`
+pub fn slot(hour_index: u64, slots: u32) -> u32 {
+    (hour_index % slots.max(1) as u64) as u32
+}
+
+/// Running totals of a market's `HourlyBuckets` ring
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+pub struct HourlyRingState {
+    /// Sum of all stored buckets in mm (scaled by 10)
+    pub sum_mm: u64,
+    /// Number of stored buckets
+    pub buckets: u32,
+    /// Every hour before this one has been evicted from the ring
+    pub evicted_before: u64,
+}
+
+impl HourlyRingState {
+    /// Empty ring whose first live hour is `oldest_hour`
+    pub fn starting_at(oldest_hour: u64) -> Self {
+        Self {
+            evicted_before: oldest_hour,
+            ..Default::default()
+        }
+    }
+
+    /// Account for a bucket written to an empty slot
+    pub fn add(&mut self, mm: Millimeters) {
+        self.sum_mm = self.sum_mm.saturating_add(mm as u64);
+        self.buckets = self.buckets.saturating_add(1);
+    }
+
+    /// Account for a bucket removed from its slot
+    pub fn remove(&mut self, mm: Millimeters) {
+        self.sum_mm = self.sum_mm.saturating_sub(mm as u64);
+        self.buckets = self.buckets.saturating_sub(1);
+    }
+
+    /// Sum of the stored buckets, capped at `Millimeters::MAX`
+    pub fn sum(&self) -> Millimeters {
+        self.sum_mm.min(Millimeters::MAX as u64) as Millimeters
+    }
+
+    /// Hours to evict so that nothing before `oldest_hour` is left. Stored hours
+    /// always lie within one ring length of `evicted_before`, so later hours can
+    /// hold no data even after a long gap between refreshes.
+    pub fn eviction_range(&self, oldest_hour: u64, slots: u32) -> Range<u64> {
+        let end = oldest_hour.min(self.evicted_before.saturating_add(slots as u64));
+        self.evicted_before..end.max(self.evicted_before)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ring_slots_cover_acceptance_window() {
+        assert_eq!(ring_slots(24 * 3600), 26);
+        assert_eq!(ring_slots(3600), 3);
+        assert_eq!(ring_slots(7 * 24 * 3600), 170);
+
+        // Every hour from the buffer hour to the current hour maps to its own slot
+        let slots = ring_slots(24 * 3600);
+        let current_hour = 480_000u64;
+        let mut seen: alloc::vec::Vec<u32> = (current_hour - 25..=current_hour)
+            .map(|hour| slot(hour, slots))
+            .collect();
+        seen.sort();
+        seen.dedup();
+        assert_eq!(seen.len(), 26);
+    }
+
+    #[test]
+    fn test_add_and_remove_keep_running_sum() {
+        let mut ring = HourlyRingState::starting_at(100);
+        ring.add(50);
+        ring.add(25);
+        ring.remove(50);
+        assert_eq!(ring.sum(), 25);
+        assert_eq!(ring.buckets, 1);
+
+        ring.remove(25);
+        ring.remove(10);
+        assert_eq!((ring.sum(), ring.buckets), (0, 0));
+    }
+
+    #[test]
+    fn test_eviction_range() {
+        let ring = HourlyRingState::starting_at(100);
+        assert_eq!(ring.eviction_range(103, 26), 100..103);
+        // Nothing to evict yet
+        assert_eq!(ring.eviction_range(100, 26), 100..100);
+        assert_eq!(ring.eviction_range(90, 26), 100..100);
+        // After a long gap only one ring length can still hold data
+        assert_eq!(ring.eviction_range(1_000, 26), 100..126);
+    }
+}
@@ -0,0 +1,46 @@
+//! # OCW Key Registration
+//!
+//! Oracle providers bind the `orcl` key their offchain worker signs with to their
+//! provider account, the way validators set session keys. `register_oracle_keys`
+//! takes the public key and a signature by it over
+//! `b"prmx_oracle_keys:" || SCALE(provider account)`, so a provider can only bind
+//! a key it holds and the proof cannot be replayed for another account.
+//!
+//! - Submissions signed by a registered key count as the provider's own: signed
+//!   OCW submissions, heartbeats, health reports and mirror attestations all
+//!   resolve to the provider account through `KeyOwners`, so bonds, market
+//!   assignments and scoring stay on that account.
+//! - Registering another key rotates it: the previous key is no longer accepted
+//!   from the same block on. `purge_oracle_keys` or removing the provider unbinds
+//!   the key.
+//! - A provider without a registered key signs with its own account's key, as
+//!   before keys could be registered.
+//!
+//! Quote providers (`quot` keys) are not covered and still sign with their own
+//! account's key.
+
+use alloc::vec::Vec;
+use codec::Encode;
+
+/// Domain separator of the key ownership proof
+pub const KEY_OWNERSHIP_CONTEXT: &[u8] = b"prmx_oracle_keys:";
+
+/// Message an OCW key signs to prove it belongs to `provider`
+pub fn ownership_message<AccountId: Encode>(provider: &AccountId) -> Vec<u8> {
+    let mut message = KEY_OWNERSHIP_CONTEXT.to_vec();
+    message.extend_from_slice(&provider.encode());
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ownership_message_binds_provider() {
+        let message = ownership_message(&[1u8; 32]);
+        assert!(message.starts_with(KEY_OWNERSHIP_CONTEXT));
+        assert_eq!(message.len(), KEY_OWNERSHIP_CONTEXT.len() + 32);
+        assert_ne!(message, ownership_message(&[2u8; 32]));
+    }
+}
@@ -14,8 +14,61 @@
 //!
 //! - `MarketLocationConfig`: Binds AccuWeather Location Key to market
 //! - `RainBuckets`: Hourly rainfall data per market
-//! - `RollingState`: 24h rolling sum state per market
+//! - `RollingState`: Rolling sum state per market (24h by default, configurable per market)
 //! - `RainfallOracle` trait for settlement queries
+//! - `ProviderHourlyReadings`: Per-provider hourly readings (AccuWeather, Open-Meteo,
+//!   NOAA, Meteostat) aggregated into `HourlyBuckets` only when enough providers agree
+//!   (a stored reading only changes through `correct_hourly_rainfall_from_ocw`, which
+//!   emits an auditable `BucketCorrected`)
+//! - `TempBuckets`: Hourly AccuWeather temperature per market, exposed through the
+//!   `TemperatureOracle` trait for heatwave (max) and frost (min) settlement
+//! - `WindBuckets`: Hourly AccuWeather wind gusts per market, exposed through the
+//!   `WindOracle` trait for typhoon/wind settlement
+//! - `SnowBuckets`: Hourly AccuWeather snowfall depth per market, exposed through the
+//!   `SnowfallOracle` trait for `Snowfall24h` winter-weather settlement
+//! - `GaugeSources` / `GaugeBuckets`: River/flood gauge APIs configured per market and
+//!   their hourly levels with the 24h rainfall at the time, exposed through the
+//!   `FloodOracle` trait for `FloodComposite` urban flood settlement
+//! - `MarketDataSources`: Governance-set secondary rainfall API per market (endpoint
+//!   template, JSON field mapping, time format and unit, see `data_source`), so a
+//!   national weather service can be added without a new fetcher
+//! - `ProviderBonds`: Oracle providers must bond before submitting data; bonds are
+//!   slashed for readings far from the quorum median or overturned V2 reports
+//! - `ProviderAssignments`: Governance can restrict a market's submissions to an
+//!   assigned subset of providers and rotate individual providers out
+//! - `ProviderKeys` / `KeyOwners`: Providers bind the `orcl` key their OCW signs
+//!   with to their account through `register_oracle_keys` with an ownership proof,
+//!   and rotate it by registering another (see `keys`)
+//! - `ProviderStats`: Per-account submission counts, late readings and deviation
+//!   from the final consensus, scored by `scoring` to weight aggregated buckets and
+//!   to inform governance slashing/removal decisions
+//! - `ProviderLastHeartbeat` / `StaleMarkets`: OCWs report liveness through unsigned
+//!   heartbeats; markets without rainfall data for `OracleStaleAfterHours` are marked
+//!   stale and new policy sales pause until data resumes
+//! - `OcwHealth`: OCWs count API calls, failures, submissions and backoffs in
+//!   offchain storage (see `ocw_metrics`) and report them hourly through unsigned
+//!   `report_ocw_health` calls, so oracle health can be alerted on from chain state
+//! - `MirrorVotes`: OCWs gossip their fetched hourly observations through unsigned
+//!   `mirror_observations` calls; once `MirrorQuorum` authorities attest the same set
+//!   it is ingested and written to offchain indexing for every node (see `gossip`)
+//! - `NextFetchDue`: On-chain rainfall fetch schedule kept by `on_initialize`; the OCW
+//!   fetches overdue markets, so restarts and skipped blocks are caught up
+//! - `ThresholdTriggerLogs`: Each automatic settlement commits a Merkle root of the
+//!   hourly observations it used (see `merkle`), so audits survive bucket pruning.
+//!   `TriggersByMarket` indexes them per market for paginated reads through
+//!   `PrmxTriggerLogApi` (see `trigger_index`)
+//! - `DataRetention` / `ArchiveChains`: `on_idle` prunes rain buckets and trigger logs
+//!   past a governance-set retention window per market, folding each removed entry
+//!   into a per-market archive hash chain first (see `retention`). V2 final reports
+//!   are pruned the same way, leaving their hash in `V2FinalReportHashes`
+//! - `poke_settlement`: Anyone can settle an expired policy once its grace period has
+//!   passed and be paid `KeeperReward` from `KeeperRewardAccount`
+//! - `PendingListingChecks`: Market listing proposals queued by the markets pallet;
+//!   the OCW resolves their coordinates and counts the location's recent hourly
+//!   observations, reporting feasibility back through `submit_listing_feasibility`
+//! - All on-chain time (drift checks, rolling windows, coverage and grace periods)
+//!   comes from the markets pallet's `TimeProvider`, the runtime's
+//!   `prmx_time::ChainTime`
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -23,6 +76,33 @@ extern crate alloc;
 
 pub use pallet::*;
 
+pub mod accuweather;
+pub mod aggregation;
+pub mod data_source;
+pub mod fetch_schedule;
+pub mod gauge;
+pub mod gossip;
+pub mod hourly_ring;
+pub mod keys;
+pub mod merkle;
+pub mod migrations;
+pub mod ocw_metrics;
+pub mod providers;
+pub mod retention;
+pub mod scoring;
+pub mod settlement_record;
+pub mod snowfall;
+pub mod trigger_index;
+pub mod weights;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub use providers::{WeatherProvider, MAX_WEATHER_PROVIDERS};
+pub use ocw_metrics::OcwCounters;
+pub use settlement_record::{SettlementReason, SettlementRecord};
+pub use weights::WeightInfo;
+
 // =============================================================================
 //                     Oracle Authority Crypto Types
 // =============================================================================
@@ -61,7 +141,7 @@ pub mod crypto {
 }
 
 use alloc::vec::Vec;
-use pallet_prmx_markets::{MarketId, NewMarketNotifier};
+use pallet_prmx_markets::{ListingFeasibility, ListingProposalId, MarketId, NewMarketNotifier};
 
 // =============================================================================
 //                             Type Aliases
@@ -73,6 +153,15 @@ pub type LocationId = MarketId;
 /// Millimeters type for rainfall (scaled by 10, so 12.5mm = 125)
 pub type Millimeters = u32;
 
+/// Temperature in degrees Celsius (scaled by 10, so -3.5°C = -35)
+pub type DeciCelsius = i32;
+
+/// Wind speed in km/h (scaled by 10, so 118.5 km/h = 1185)
+pub type DeciKmh = u32;
+
+/// River gauge level in centimetres above the gauge datum
+pub type Centimeters = i32;
+
 /// Bucket index (timestamp / BUCKET_INTERVAL_SECS)
 pub type BucketIndex = u64;
 
@@ -83,12 +172,18 @@ pub type AccuWeatherLocationKey = Vec<u8>;
 //                             Constants
 // =============================================================================
 
-/// Seconds per bucket (1 hour)
+/// Default seconds per bucket (1 hour)
 pub const BUCKET_INTERVAL_SECS: u64 = 3600;
 
-/// Seconds in rolling window (24 hours)
+/// Default seconds in rolling window (24 hours)
 pub const ROLLING_WINDOW_SECS: u64 = 24 * 3600;
 
+/// Shortest bucket interval a market can be configured with (15 minutes)
+pub const MIN_BUCKET_INTERVAL_SECS: u64 = 900;
+
+/// Longest rolling window a market can be configured with (7 days)
+pub const MAX_ROLLING_WINDOW_SECS: u64 = 7 * 24 * 3600;
+
 /// Maximum allowed past drift for submitted timestamps (7 days)
 pub const MAX_PAST_DRIFT_SECS: u64 = 7 * 24 * 3600;
 
@@ -98,12 +193,12 @@ pub const MAX_FUTURE_DRIFT_SECS: u64 = 2 * 3600;
 /// Maximum rainfall value sanity check (1000mm per hour is absurd)
 pub const MAX_RAINFALL_MM: u32 = 10000; // 1000mm scaled by 10
 
-/// Base timestamp for block-to-time conversion (Dec 8, 2025 00:00 UTC approximate)
-pub const BASE_TIMESTAMP_SECS: u64 = 1733616000;
+/// Retention for hourly non-rainfall buckets (temperature, wind, snow). Covers the longest
+/// coverage window (7 days) plus a day of slack for expiry settlement.
+pub const WEATHER_RETENTION_SECS: u64 = 8 * 24 * 3600;
 
-/// Blocks per hour (assuming ~6 second block time)
-/// 3600 seconds / 6 seconds = 600 blocks
-pub const BLOCKS_PER_HOUR: u32 = 600;
+/// Hours fetched per historic backfill page (one AccuWeather historical/24 response)
+pub const BACKFILL_PAGE_HOURS: u64 = 24;
 
 /// Blocks between location binding checks (~10 minutes)
 /// 600 seconds / 6 seconds = 100 blocks
@@ -113,18 +208,61 @@ pub const BLOCKS_PER_BINDING_CHECK: u32 = 100;
 /// 60 seconds / 6 seconds = 10 blocks
 pub const BLOCKS_PER_SETTLEMENT_CHECK: u32 = 10;
 
+/// Blocks between oracle OCW heartbeats (~10 minutes)
+pub const BLOCKS_PER_HEARTBEAT: u32 = 100;
+
+/// Blocks between OCW health reports (~1 hour); also the minimum spacing between
+/// two reports from the same provider
+pub const BLOCKS_PER_HEALTH_REPORT: u32 = 600;
+
+/// Default hours without rainfall data before a market's oracle is considered stale
+pub const DEFAULT_ORACLE_STALE_HOURS: u32 = 3;
+
+/// Default delay after coverage end before an expired policy is settled, so that
+/// late observations for the final hours can still arrive (2 hours)
+pub const DEFAULT_SETTLEMENT_GRACE_SECS: u64 = 2 * 3600;
+
+/// Longest settlement grace period a market can be configured with. Bounded by the
+/// day of slack in `WEATHER_RETENTION_SECS` (24 hours)
+pub const MAX_SETTLEMENT_GRACE_SECS: u64 = 24 * 3600;
+
 // =============================================================================
 //                          Helper Functions
 // =============================================================================
 
 /// Convert timestamp to bucket index
 pub fn bucket_index_for_timestamp(ts: u64) -> BucketIndex {
-    ts / BUCKET_INTERVAL_SECS
+    bucket_index_with_interval(ts, BUCKET_INTERVAL_SECS)
+}
+
+/// Compute bucket index for a timestamp using a market-specific interval
+pub fn bucket_index_with_interval(ts: u64, interval_secs: u64) -> BucketIndex {
+    ts / interval_secs.max(1)
+}
+
+/// Get bucket start time from index using a market-specific interval
+pub fn bucket_start_with_interval(idx: BucketIndex, interval_secs: u64) -> u64 {
+    idx.saturating_mul(interval_secs)
+}
+
+/// Whether a backfill page holds exactly one reading per hour from `first_hour`
+/// to `last_hour` (inclusive), in order
+pub fn backfill_page_is_continuous(
+    hourly_data: &[(u64, Millimeters)],
+    first_hour: u64,
+    last_hour: u64,
+) -> bool {
+    last_hour >= first_hour
+        && hourly_data.len() as u64 == last_hour - first_hour + 1
+        && hourly_data
+            .iter()
+            .zip(first_hour..)
+            .all(|((epoch_time, _), hour_index)| epoch_time / 3600 == hour_index)
 }
 
 /// Get bucket start time from index
 pub fn bucket_start_time(idx: BucketIndex) -> u64 {
-    idx * BUCKET_INTERVAL_SECS
+    bucket_start_with_interval(idx, BUCKET_INTERVAL_SECS)
 }
 
 // =============================================================================
@@ -145,6 +283,108 @@ pub trait RainfallOracle {
     ) -> Result<bool, sp_runtime::DispatchError>;
 }
 
+// =============================================================================
+//                          TemperatureOracle Trait
+// =============================================================================
+
+/// Trait for other pallets to access temperature data for heatwave/frost settlement
+pub trait TemperatureOracle {
+    /// Highest hourly temperature observed during the window (None if no readings)
+    fn max_temperature_in_window(
+        location_id: LocationId,
+        coverage_start: u64,
+        coverage_end: u64,
+    ) -> Option<DeciCelsius>;
+
+    /// Lowest hourly temperature observed during the window (None if no readings)
+    fn min_temperature_in_window(
+        location_id: LocationId,
+        coverage_start: u64,
+        coverage_end: u64,
+    ) -> Option<DeciCelsius>;
+}
+
+// =============================================================================
+//                             WindOracle Trait
+// =============================================================================
+
+/// Trait for other pallets to access wind gust data for typhoon/wind settlement
+pub trait WindOracle {
+    /// Strongest hourly wind gust observed during the window (None if no readings)
+    fn max_wind_gust_in_window(
+        location_id: LocationId,
+        coverage_start: u64,
+        coverage_end: u64,
+    ) -> Option<DeciKmh>;
+
+    /// Check if any hourly wind gust reached `threshold_kmh` during the window
+    fn wind_gust_exceeded_in_window(
+        location_id: LocationId,
+        threshold_kmh: DeciKmh,
+        coverage_start: u64,
+        coverage_end: u64,
+    ) -> bool {
+        Self::max_wind_gust_in_window(location_id, coverage_start, coverage_end)
+            .is_some_and(|gust| gust >= threshold_kmh)
+    }
+}
+
+// =============================================================================
+//                           SnowfallOracle Trait
+// =============================================================================
+
+/// Trait for other pallets to access snowfall data for `Snowfall24h` settlement
+pub trait SnowfallOracle {
+    /// Highest snowfall over any 24 consecutive hours of the window, in tenths of mm
+    /// (None if no readings)
+    fn max_snowfall_24h_in_window(
+        location_id: LocationId,
+        coverage_start: u64,
+        coverage_end: u64,
+    ) -> Option<Millimeters>;
+
+    /// Check if 24-hour snowfall reached `threshold_mm` during the window
+    fn snowfall_exceeded_in_window(
+        location_id: LocationId,
+        threshold_mm: Millimeters,
+        coverage_start: u64,
+        coverage_end: u64,
+    ) -> bool {
+        Self::max_snowfall_24h_in_window(location_id, coverage_start, coverage_end)
+            .is_some_and(|snowfall| snowfall >= threshold_mm)
+    }
+}
+
+// =============================================================================
+//                             FloodOracle Trait
+// =============================================================================
+
+/// Trait for other pallets to access river gauge data for `FloodComposite` settlement
+pub trait FloodOracle {
+    /// Flood stage of the market's gauge in centimetres (None if no gauge is configured)
+    fn flood_level_cm(location_id: LocationId) -> Option<Centimeters>;
+
+    /// Highest 24h rainfall, in tenths of mm, recorded during the window while the
+    /// gauge was at or above its flood stage (None if it never got there)
+    fn max_rainfall_at_flood_stage_in_window(
+        location_id: LocationId,
+        coverage_start: u64,
+        coverage_end: u64,
+    ) -> Option<Millimeters>;
+
+    /// Check if the gauge reached its flood stage while 24h rainfall was at least
+    /// `threshold_mm` during the window
+    fn flood_composite_exceeded_in_window(
+        location_id: LocationId,
+        threshold_mm: Millimeters,
+        coverage_start: u64,
+        coverage_end: u64,
+    ) -> bool {
+        Self::max_rainfall_at_flood_stage_in_window(location_id, coverage_start, coverage_end)
+            .is_some_and(|rainfall| rainfall >= threshold_mm)
+    }
+}
+
 // =============================================================================
 //                          PolicySettlement Trait
 // =============================================================================
@@ -154,26 +394,34 @@ pub use prmx_primitives::PolicyId;
 
 /// Trait for oracle to trigger automatic policy settlements
 pub trait PolicySettlement<AccountId> {
-    /// Get the current blockchain timestamp in seconds
-    fn current_time() -> u64;
-    
     /// Get all active policies for a market that are currently in their coverage window
     fn get_active_policies_in_window(market_id: MarketId, current_time: u64) -> Vec<PolicyId>;
     
     /// Get policy details: (holder, max_payout_u128, coverage_start, coverage_end, market_id)
     fn get_policy_info(policy_id: PolicyId) -> Option<(AccountId, u128, u64, u64, MarketId)>;
     
-    /// Trigger immediate settlement for a policy (called when threshold exceeded)
-    /// Returns Ok(payout_amount_u128) on success
-    fn trigger_immediate_settlement(policy_id: PolicyId) -> Result<u128, sp_runtime::DispatchError>;
+    /// Rolling sum at which a policy settles before coverage ends: the strike, or the
+    /// top tier for policies with a tiered payout table
+    fn early_trigger_threshold(policy_id: PolicyId, strike_mm: Millimeters) -> Millimeters;
+
+    /// Trigger immediate settlement for a policy (called when threshold exceeded).
+    /// `record.observed_mm` is the observed rolling sum. Returns Ok(payout_amount_u128) on success
+    fn trigger_immediate_settlement(
+        policy_id: PolicyId,
+        record: SettlementRecord,
+    ) -> Result<u128, sp_runtime::DispatchError>;
     
     /// Get all active policies that have expired (coverage_end < current_time)
     /// Used for automated expiration settlement
     fn get_expired_policies(current_time: u64) -> Vec<PolicyId>;
     
-    /// Settle an expired policy with the determined event outcome
-    /// Returns Ok(payout_amount_u128) on success
-    fn settle_expired_policy(policy_id: PolicyId, event_occurred: bool) -> Result<u128, sp_runtime::DispatchError>;
+    /// Settle an expired policy with the determined event outcome. `record.observed_mm`
+    /// is the maximum rolling sum observed during coverage. Returns Ok(payout_amount_u128) on success
+    fn settle_expired_policy(
+        policy_id: PolicyId,
+        event_occurred: bool,
+        record: SettlementRecord,
+    ) -> Result<u128, sp_runtime::DispatchError>;
 
     /// Settle a V2 policy based on off-chain oracle report.
     /// This is called by the oracle pallet after validating the report.
@@ -184,14 +432,49 @@ pub trait PolicySettlement<AccountId> {
         cumulative_mm: u32,
         evidence_hash: [u8; 32],
     ) -> Result<(), sp_runtime::DispatchError>;
+
+    /// Payout a settlement with `event_occurred` and `observed_mm` would make, without
+    /// changing state: (payout_u128, of which to secondary-market positions,
+    /// returned to LP holders). None unless the policy is active or expired.
+    fn preview_settlement_payout(
+        policy_id: PolicyId,
+        event_occurred: bool,
+        observed_mm: Option<Millimeters>,
+    ) -> Option<(u128, u128, u128)>;
 }
 
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
     use frame_support::pallet_prelude::*;
+    use frame_support::traits::{Currency, ReservableCurrency};
     use frame_system::pallet_prelude::*;
     use pallet_prmx_markets::MarketsAccess;
+    use prmx_primitives::{OcwErrorKind, PausableOperation, PauseApi};
+    use prmx_time::ChainTime;
+    use sp_runtime::{traits::Zero, Perbill, Permill, Saturating};
+    use crate::hourly_ring::{self, HourlyRingState};
+    use crate::gossip;
+    use crate::retention::{self, ArchiveChain, ArchiveKind};
+    use crate::scoring::ProviderSubmissionStats;
+
+    /// Balance type of the currency used for V2 dispute bonds and provider bonds
+    pub type BalanceOf<T> = <<T as Config>::Currency as Currency<
+        <T as frame_system::Config>::AccountId,
+    >>::Balance;
+
+    /// Maximum number of V2 reporter votes recorded per dispute
+    pub const MAX_V2_DISPUTE_VOTES: u32 = 16;
+
+    /// Maximum number of pending V2 reports finalized per block
+    pub const MAX_V2_FINALIZATIONS_PER_BLOCK: u32 = 10;
+
+    /// Maximum number of providers assigned to a market
+    pub const MAX_PROVIDERS_PER_MARKET: u32 = 16;
+
+    /// In-code storage version. Bump it and add a migration to `migrations.rs`
+    /// whenever a stored layout changes.
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(5);
 
     // =========================================================================
     //                                  Types
@@ -207,6 +490,10 @@ pub mod pallet {
         pub center_latitude: i32,
         /// Center longitude (copied from MarketInfo at bind time)
         pub center_longitude: i32,
+        /// Seconds per rainfall bucket (defaults to BUCKET_INTERVAL_SECS)
+        pub bucket_interval_secs: u64,
+        /// Seconds covered by the rolling sum (defaults to ROLLING_WINDOW_SECS)
+        pub rolling_window_secs: u64,
     }
 
     /// Rainfall bucket (hourly data) per oracle_design.md section 5.2
@@ -241,14 +528,224 @@ pub mod pallet {
         Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default,
     )]
     pub struct HourlyBucket {
+        /// Hour this bucket covers (unix_timestamp / 3600); the ring slot it is stored in
+        /// is reused once the hour leaves the acceptance window
+        pub hour_index: u64,
         /// Rainfall amount in mm (scaled by 10, so 12.5mm = 125)
         pub mm: Millimeters,
         /// Unix timestamp when this bucket was fetched
         pub fetched_at: u64,
-        /// Data source: 0 = current conditions, 1 = historical/24
+        /// Data source: 0 = current conditions, 1 = single provider, 2 = multi-provider aggregate
         pub source: u8,
     }
 
+    /// Hourly temperature bucket from the AccuWeather historical/24 endpoint
+    #[derive(
+        Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default,
+    )]
+    pub struct TempBucket {
+        /// Temperature in °C (scaled by 10, so 31.5°C = 315)
+        pub temp_c: DeciCelsius,
+        /// Unix timestamp when this bucket was fetched
+        pub fetched_at: u64,
+    }
+
+    /// Hourly wind gust bucket from the AccuWeather historical/24 endpoint
+    #[derive(
+        Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default,
+    )]
+    pub struct WindBucket {
+        /// Peak wind gust in km/h (scaled by 10, so 118.5 km/h = 1185)
+        pub gust_kmh: DeciKmh,
+        /// Unix timestamp when this bucket was fetched
+        pub fetched_at: u64,
+    }
+
+    /// Hourly snowfall bucket from the AccuWeather historical/24 endpoint
+    #[derive(
+        Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default,
+    )]
+    pub struct SnowBucket {
+        /// Snowfall depth over the hour in mm (scaled by 10, so 2.5 cm = 250)
+        pub snowfall_mm: Millimeters,
+        /// Unix timestamp when this bucket was fetched
+        pub fetched_at: u64,
+    }
+
+    /// Hourly river gauge level from the market's `GaugeSource`
+    #[derive(
+        Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default,
+    )]
+    pub struct GaugeBucket {
+        /// Gauge level in centimetres above the datum
+        pub level_cm: Centimeters,
+        /// Market's 24h rolling rainfall when the level was stored (tenths of mm)
+        pub rainfall_24h_mm: Millimeters,
+        /// Unix timestamp when this bucket was fetched
+        pub fetched_at: u64,
+    }
+
+    /// One provider's reading for an hour, with the account that submitted it
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct ProviderReading<T: Config> {
+        /// Weather provider the reading came from
+        pub provider: WeatherProvider,
+        /// Rainfall amount in mm (scaled by 10)
+        pub mm: Millimeters,
+        /// Oracle provider account that submitted the reading
+        pub submitter: T::AccountId,
+        /// Whether the submitter was already slashed for this reading
+        pub slashed: bool,
+    }
+
+    /// Bond in the process of being withdrawn by an oracle provider
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct ProviderUnbonding<T: Config> {
+        /// Amount still reserved until `unlock_at`
+        pub amount: BalanceOf<T>,
+        /// Block from which the amount can be withdrawn
+        pub unlock_at: BlockNumberFor<T>,
+    }
+
+    /// Liveness report signed by an oracle authority key and submitted unsigned
+    #[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct HeartbeatPayload<Public, BlockNumber> {
+        /// Block at which the OCW produced the heartbeat
+        pub block_number: BlockNumber,
+        /// Oracle authority key that signed the heartbeat
+        pub public: Public,
+    }
+
+    impl<T: frame_system::offchain::SigningTypes> frame_system::offchain::SignedPayload<T>
+        for HeartbeatPayload<T::Public, BlockNumberFor<T>>
+    {
+        fn public(&self) -> T::Public {
+            self.public.clone()
+        }
+    }
+
+    /// OCW health counters signed by an oracle authority key and submitted unsigned
+    #[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct OcwHealthReport<Public, BlockNumber> {
+        /// Block at which the OCW produced the report
+        pub block_number: BlockNumber,
+        /// Oracle authority key that signed the report
+        pub public: Public,
+        /// Activity since the provider's previous report
+        pub counters: OcwCounters,
+    }
+
+    impl<T: frame_system::offchain::SigningTypes> frame_system::offchain::SignedPayload<T>
+        for OcwHealthReport<T::Public, BlockNumberFor<T>>
+    {
+        fn public(&self) -> T::Public {
+            self.public.clone()
+        }
+    }
+
+    /// Hourly observations fetched by an OCW, signed by an oracle authority key and
+    /// gossiped unsigned to the other authorities (see `gossip`)
+    #[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct ObservationMirror<Public, BlockNumber> {
+        /// Block at which the OCW signed the mirror
+        pub block_number: BlockNumber,
+        /// Oracle authority key that signed the mirror
+        pub public: Public,
+        /// Market the observations belong to
+        pub market_id: MarketId,
+        /// Weather provider the observations were fetched from
+        pub provider: WeatherProvider,
+        /// (epoch_time, rainfall_mm_scaled) as returned by the provider
+        pub observations: BoundedVec<(u64, Millimeters), ConstU32<24>>,
+    }
+
+    impl<T: frame_system::offchain::SigningTypes> frame_system::offchain::SignedPayload<T>
+        for ObservationMirror<T::Public, BlockNumberFor<T>>
+    {
+        fn public(&self) -> T::Public {
+            self.public.clone()
+        }
+    }
+
+    /// Observation set attested by one or more authorities, waiting for `MirrorQuorum`
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct MirrorCandidate<T: Config> {
+        /// `gossip::observations_digest` of the normalized observations
+        pub digest: [u8; 32],
+        /// Block at which the first attestation arrived
+        pub first_seen: BlockNumberFor<T>,
+        /// Authorities that attested the observations, first one first
+        pub attesters: BoundedVec<T::AccountId, ConstU32<{ gossip::MAX_MIRROR_ATTESTERS }>>,
+    }
+
+    /// Latest OCW health report of an oracle provider
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct OcwHealthRecord<BlockNumber> {
+        /// Block at which the OCW produced the report
+        pub reported_at: BlockNumber,
+        /// Activity since the provider's previous report
+        pub counters: OcwCounters,
+    }
+
+    /// Historic backfill for a market, fetched by the OCW one page at a time
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct BackfillRequest<T: Config> {
+        /// First hour_index of the next page to fetch
+        pub next_hour: u64,
+        /// Last hour_index to backfill (inclusive)
+        pub last_hour: u64,
+        /// Block number when the backfill was requested
+        pub requested_at: BlockNumberFor<T>,
+    }
+
+    impl<T: Config> BackfillRequest<T> {
+        /// Last hour_index of the next page (inclusive)
+        pub fn page_end(&self) -> u64 {
+            self.next_hour
+                .saturating_add(BACKFILL_PAGE_HOURS - 1)
+                .min(self.last_hour)
+        }
+    }
+
+    /// Why an oracle provider bond was slashed
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum ProviderSlashReason {
+        /// Hourly reading deviated from the quorum median beyond `ProviderSlashTolerance`
+        Deviation {
+            market_id: MarketId,
+            hour_index: u64,
+            reading_mm: Millimeters,
+            median_mm: Millimeters,
+        },
+        /// V2 report was overturned by an upheld dispute
+        OverturnedV2Report { policy_id: PolicyId },
+    }
+
+    /// Governance parameters for multi-provider bucket acceptance
+    #[derive(
+        Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+    )]
+    pub struct ProviderAgreementParams {
+        /// Minimum number of distinct submitting accounts that must agree for a bucket to be used
+        pub min_providers: u32,
+        /// Maximum distance from the median for a reading to count as agreeing (tenths of mm)
+        pub tolerance_mm: Millimeters,
+    }
+
+    impl Default for ProviderAgreementParams {
+        fn default() -> Self {
+            // Single provider (AccuWeather) accepted until governance raises the quorum
+            Self {
+                min_providers: 1,
+                tolerance_mm: 20, // 2.0mm
+            }
+        }
+    }
+
     /// On-chain log of threshold trigger events
     /// Records comprehensive data when a policy is auto-settled due to threshold breach
     #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
@@ -276,6 +773,34 @@ pub mod pallet {
         pub center_latitude: i32,
         /// Market center longitude (scaled by 1e6)
         pub center_longitude: i32,
+        /// Merkle root of the hourly observations in the rolling window (see `merkle`)
+        pub observation_root: [u8; 32],
+        /// Number of hourly observations committed under `observation_root`
+        pub observation_count: u32,
+    }
+
+    /// V2 report held during its challenge period before settlement
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct PendingV2Report<T: Config> {
+        /// The submitted report
+        pub report: prmx_primitives::V2Report<T::AccountId>,
+        /// Last block in which the report can be disputed
+        pub challenge_ends_at: BlockNumberFor<T>,
+    }
+
+    /// Open dispute against a pending V2 report
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct V2Dispute<T: Config> {
+        /// Account that raised the dispute and posted the bond
+        pub disputer: T::AccountId,
+        /// Hash of the disputer's counter evidence
+        pub counter_evidence_hash: [u8; 32],
+        /// Bond reserved from the disputer
+        pub bond: BalanceOf<T>,
+        /// Block when the dispute was raised
+        pub disputed_at: BlockNumberFor<T>,
     }
 
     // =========================================================================
@@ -287,6 +812,7 @@ pub mod pallet {
         frame_system::Config
         + pallet_prmx_markets::Config
         + frame_system::offchain::CreateSignedTransaction<Call<Self>>
+        + frame_system::offchain::CreateBare<Call<Self>>
     {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
@@ -309,24 +835,67 @@ pub mod pallet {
         /// Oracle authority ID for signing offchain transactions
         type AuthorityId: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>;
 
+        /// Currency used to reserve V2 dispute bonds
+        type Currency: ReservableCurrency<Self::AccountId>;
+
+        /// Number of blocks a V2 report can be disputed before it is settled
+        #[pallet::constant]
+        type V2ChallengePeriod: Get<BlockNumberFor<Self>>;
+
+        /// Bond reserved from an account disputing a V2 report
+        #[pallet::constant]
+        type V2DisputeBond: Get<BalanceOf<Self>>;
+
+        /// Matching V2 reporter votes required to resolve a dispute without governance
+        #[pallet::constant]
+        type V2DisputeQuorum: Get<u32>;
+
+        /// Minimum bond an oracle provider must hold to submit weather data
+        #[pallet::constant]
+        type OracleProviderBond: Get<BalanceOf<Self>>;
+
+        /// Number of blocks an unbonded provider bond stays reserved (and slashable)
+        #[pallet::constant]
+        type ProviderUnbondingPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Share of a provider's total bond slashed per offence
+        #[pallet::constant]
+        type ProviderSlashFraction: Get<Perbill>;
+
+        /// Distance from the quorum median (tenths of mm) beyond which a reading is slashed
+        #[pallet::constant]
+        type ProviderSlashTolerance: Get<Millimeters>;
+
+        /// Emergency pause flags for oracle submissions and settlements
+        type Pause: PauseApi;
+
+        /// Reward paid to whoever settles an expired policy with `poke_settlement`
+        #[pallet::constant]
+        type KeeperReward: Get<BalanceOf<Self>>;
+
+        /// Account keeper rewards are paid from (typically the DAO treasury)
+        #[pallet::constant]
+        type KeeperRewardAccount: Get<Self::AccountId>;
+
         /// Weight info for extrinsics
         type WeightInfo: WeightInfo;
-    }
 
-    /// Weight info trait
-    pub trait WeightInfo {
-        fn set_market_location_key() -> Weight;
-        fn submit_rainfall() -> Weight;
+        /// Sets up policies and the chain clock for benchmarks
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper;
     }
 
-    /// Default weights
-    impl WeightInfo for () {
-        fn set_market_location_key() -> Weight {
-            Weight::from_parts(10_000, 0)
-        }
-        fn submit_rainfall() -> Weight {
-            Weight::from_parts(20_000, 0)
-        }
+    /// Benchmark setup that needs the policy and timestamp pallets
+    #[cfg(feature = "runtime-benchmarks")]
+    pub trait BenchmarkHelper {
+        /// Set the time returned by `TimeProvider`
+        fn set_timestamp(now_secs: u64);
+
+        /// Create an active V2 policy and return its id
+        fn v2_policy() -> PolicyId;
+
+        /// Create a policy on `market_id` and move the clock past its coverage end
+        fn expired_policy(market_id: MarketId) -> PolicyId;
     }
 
     // =========================================================================
@@ -334,6 +903,7 @@ pub mod pallet {
     // =========================================================================
 
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     /// Market location binding (AccuWeather key per market)
@@ -364,9 +934,9 @@ pub mod pallet {
     pub type RollingState<T: Config> =
         StorageMap<_, Blake2_128Concat, LocationId, RollingWindowState, OptionQuery>;
 
-    /// Hourly buckets for V1 oracle (per market_id and hour_index)
-    /// Stores individual hourly rainfall readings from AccuWeather historical/24 endpoint
-    /// hour_index = unix_timestamp / 3600 (hour since Unix epoch)
+    /// Hourly buckets for V1 oracle (per market_id and ring slot)
+    /// Stores agreed hourly rainfall in a ring of `hourly_ring::ring_slots` slots per market,
+    /// keyed by hour_index % slots, where hour_index = unix_timestamp / 3600
     #[pallet::storage]
     #[pallet::getter(fn hourly_buckets)]
     pub type HourlyBuckets<T: Config> = StorageDoubleMap<
@@ -374,59 +944,361 @@ pub mod pallet {
         Blake2_128Concat,
         MarketId,
         Blake2_128Concat,
-        u64, // hour_index
+        u32, // ring slot
         HourlyBucket,
         OptionQuery,
     >;
 
-    /// Authorized oracle providers (accounts that can submit data)
+    /// Running sum and eviction cursor of each market's `HourlyBuckets` ring
     #[pallet::storage]
-    #[pallet::getter(fn oracle_providers)]
-    pub type OracleProviders<T: Config> =
-        StorageMap<_, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
+    #[pallet::getter(fn hourly_ring)]
+    pub type HourlyRing<T: Config> =
+        StorageMap<_, Blake2_128Concat, MarketId, HourlyRingState, OptionQuery>;
 
-    /// On-chain threshold trigger logs
-    /// Records all automatic settlements triggered by threshold breaches
+    /// Hourly temperature per (market_id, hour_index), kept for `WEATHER_RETENTION_SECS`
+    /// so max/min can be evaluated over a full coverage window
     #[pallet::storage]
-    #[pallet::getter(fn threshold_trigger_logs)]
-    pub type ThresholdTriggerLogs<T: Config> = StorageMap<
+    #[pallet::getter(fn temp_buckets)]
+    pub type TempBuckets<T: Config> = StorageDoubleMap<
         _,
         Blake2_128Concat,
-        u64, // trigger_id
-        ThresholdTriggerLog<T>,
+        MarketId,
+        Blake2_128Concat,
+        u64, // hour_index
+        TempBucket,
         OptionQuery,
     >;
 
-    /// Next trigger log ID (auto-increment)
+    /// Hourly wind gust per (market_id, hour_index), kept for `WEATHER_RETENTION_SECS`
     #[pallet::storage]
-    #[pallet::getter(fn next_trigger_log_id)]
-    pub type NextTriggerLogId<T: Config> = StorageValue<_, u64, ValueQuery>;
+    #[pallet::getter(fn wind_buckets)]
+    pub type WindBuckets<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        MarketId,
+        Blake2_128Concat,
+        u64, // hour_index
+        WindBucket,
+        OptionQuery,
+    >;
 
-    /// Pending manual fetch requests by market ID
-    /// Stores the block number when the request was made
-    /// Used by offchain worker to trigger immediate AccuWeather API fetch
+    /// Hourly snowfall per (market_id, hour_index), kept for `WEATHER_RETENTION_SECS`
     #[pallet::storage]
-    #[pallet::getter(fn pending_fetch_requests)]
-    pub type PendingFetchRequests<T: Config> =
-        StorageMap<_, Blake2_128Concat, MarketId, BlockNumberFor<T>, OptionQuery>;
+    #[pallet::getter(fn snow_buckets)]
+    pub type SnowBuckets<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        MarketId,
+        Blake2_128Concat,
+        u64, // hour_index
+        SnowBucket,
+        OptionQuery,
+    >;
 
-    /// Flag indicating API key was just configured and immediate fetch should be triggered
+    /// River/flood gauge API per market, set by governance. Markets without one
+    /// cannot settle `FloodComposite` policies.
     #[pallet::storage]
-    #[pallet::getter(fn api_key_configured_at)]
-    pub type ApiKeyConfiguredAt<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+    #[pallet::getter(fn gauge_sources)]
+    pub type GaugeSources<T: Config> =
+        StorageMap<_, Blake2_128Concat, MarketId, gauge::GaugeSource, OptionQuery>;
 
-    /// Pending API key to be copied to offchain local storage by the OCW.
-    /// This is used to securely transfer the API key from on-chain to offchain.
-    /// The OCW reads this, copies to local storage, and clears it.
+    /// Secondary rainfall API per market, set by governance. Fetched instead of the
+    /// built-in fetcher of the same provider (see `data_source`).
     #[pallet::storage]
-    pub type PendingApiKey<T: Config> = StorageValue<_, BoundedVec<u8, ConstU32<256>>, OptionQuery>;
+    #[pallet::getter(fn market_data_sources)]
+    pub type MarketDataSources<T: Config> =
+        StorageMap<_, Blake2_128Concat, MarketId, data_source::DataSourceConfig, OptionQuery>;
 
-    // =========================================================================
-    //                          V2 Oracle Storage
-    // =========================================================================
+    /// Hourly gauge levels per (market_id, hour_index), kept for `WEATHER_RETENTION_SECS`
+    #[pallet::storage]
+    #[pallet::getter(fn gauge_buckets)]
+    pub type GaugeBuckets<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        MarketId,
+        Blake2_128Concat,
+        u64, // hour_index
+        GaugeBucket,
+        OptionQuery,
+    >;
 
-    /// Authorized V2 oracle reporters (accounts that can submit V2 reports).
-    /// These are off-chain oracle service accounts.
+    /// Raw hourly readings per (market_id, hour_index), one entry per weather provider.
+    /// Aggregated into `HourlyBuckets` once enough providers agree.
+    #[pallet::storage]
+    #[pallet::getter(fn provider_hourly_readings)]
+    pub type ProviderHourlyReadings<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        MarketId,
+        Blake2_128Concat,
+        u64, // hour_index
+        BoundedVec<ProviderReading<T>, ConstU32<MAX_WEATHER_PROVIDERS>>,
+        ValueQuery,
+    >;
+
+    /// Quorum and tolerance used when aggregating provider readings
+    #[pallet::storage]
+    #[pallet::getter(fn provider_agreement)]
+    pub type ProviderAgreement<T: Config> = StorageValue<_, ProviderAgreementParams, ValueQuery>;
+
+    /// Submission statistics per oracle provider account, see `scoring`
+    #[pallet::storage]
+    #[pallet::getter(fn provider_stats)]
+    pub type ProviderStats<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, ProviderSubmissionStats, ValueQuery>;
+
+    /// Active bond per oracle provider (reserved from the provider's balance)
+    #[pallet::storage]
+    #[pallet::getter(fn provider_bonds)]
+    pub type ProviderBonds<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+    /// Provider bond waiting out the unbonding period
+    #[pallet::storage]
+    #[pallet::getter(fn provider_unbonding)]
+    pub type ProviderUnbondings<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, ProviderUnbonding<T>, OptionQuery>;
+
+    /// Authorized oracle providers (accounts that can submit data)
+    #[pallet::storage]
+    #[pallet::getter(fn oracle_providers)]
+    pub type OracleProviders<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
+
+    /// OCW key registered by each provider, as the key's account (see `keys`)
+    #[pallet::storage]
+    #[pallet::getter(fn provider_keys)]
+    pub type ProviderKeys<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId, OptionQuery>;
+
+    /// Provider each registered OCW key account signs for
+    #[pallet::storage]
+    #[pallet::getter(fn key_owners)]
+    pub type KeyOwners<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId, OptionQuery>;
+
+    /// Providers allowed to submit data for a market. Markets without an entry
+    /// accept any bonded provider.
+    #[pallet::storage]
+    #[pallet::getter(fn provider_assignments)]
+    pub type ProviderAssignments<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        MarketId,
+        BoundedVec<T::AccountId, ConstU32<MAX_PROVIDERS_PER_MARKET>>,
+        OptionQuery,
+    >;
+
+    /// On-chain threshold trigger logs
+    /// Records all automatic settlements triggered by threshold breaches
+    #[pallet::storage]
+    #[pallet::getter(fn threshold_trigger_logs)]
+    pub type ThresholdTriggerLogs<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u64, // trigger_id
+        ThresholdTriggerLog<T>,
+        OptionQuery,
+    >;
+
+    /// Most recent retained trigger IDs per market, ascending (see `trigger_index`)
+    #[pallet::storage]
+    #[pallet::getter(fn triggers_by_market)]
+    pub type TriggersByMarket<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        MarketId,
+        BoundedVec<u64, ConstU32<{ trigger_index::MAX_TRIGGERS_PER_MARKET }>>,
+        ValueQuery,
+    >;
+
+    /// Next trigger log ID (auto-increment)
+    #[pallet::storage]
+    #[pallet::getter(fn next_trigger_log_id)]
+    pub type NextTriggerLogId<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// Pending manual fetch requests by market ID
+    /// Stores the block number when the request was made
+    /// Used by offchain worker to trigger immediate AccuWeather API fetch
+    #[pallet::storage]
+    #[pallet::getter(fn pending_fetch_requests)]
+    pub type PendingFetchRequests<T: Config> =
+        StorageMap<_, Blake2_128Concat, MarketId, BlockNumberFor<T>, OptionQuery>;
+
+    /// Market listing proposals awaiting an OCW feasibility check, with the
+    /// proposed center coordinates (lat, lon scaled by 1e6)
+    #[pallet::storage]
+    #[pallet::getter(fn pending_listing_checks)]
+    pub type PendingListingChecks<T: Config> =
+        StorageMap<_, Blake2_128Concat, ListingProposalId, (i32, i32), OptionQuery>;
+
+    /// Pending historic backfills by market ID, advanced page by page by the OCW
+    #[pallet::storage]
+    #[pallet::getter(fn backfill_requests)]
+    pub type BackfillRequests<T: Config> =
+        StorageMap<_, Blake2_128Concat, MarketId, BackfillRequest<T>, OptionQuery>;
+
+    /// Block of the last heartbeat received from each oracle provider
+    #[pallet::storage]
+    #[pallet::getter(fn provider_last_heartbeat)]
+    pub type ProviderLastHeartbeat<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
+    /// Latest OCW health counters reported by each oracle provider
+    #[pallet::storage]
+    #[pallet::getter(fn ocw_health)]
+    pub type OcwHealth<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        OcwHealthRecord<BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// Timestamp at which rainfall data last arrived on-chain for each market
+    #[pallet::storage]
+    #[pallet::getter(fn market_last_data_at)]
+    pub type MarketLastDataAt<T: Config> =
+        StorageMap<_, Blake2_128Concat, MarketId, u64, OptionQuery>;
+
+    /// Unix timestamp at which each market's next rainfall fetch is due. Kept by
+    /// `on_initialize` (see `fetch_schedule`) and read by the offchain worker.
+    #[pallet::storage]
+    #[pallet::getter(fn next_fetch_due)]
+    pub type NextFetchDue<T: Config> =
+        StorageMap<_, Blake2_128Concat, MarketId, u64, OptionQuery>;
+
+    /// Hours without rainfall data after which a market's oracle is stale.
+    /// Falls back to `DEFAULT_ORACLE_STALE_HOURS` when unset
+    #[pallet::storage]
+    #[pallet::getter(fn oracle_stale_after_hours)]
+    pub type OracleStaleAfterHours<T: Config> = StorageValue<_, u32, OptionQuery>;
+
+    /// Markets whose oracle is stale (value: last data timestamp). New policy
+    /// sales are paused for these markets until data arrives again
+    #[pallet::storage]
+    #[pallet::getter(fn stale_markets)]
+    pub type StaleMarkets<T: Config> =
+        StorageMap<_, Blake2_128Concat, MarketId, u64, OptionQuery>;
+
+    /// Settlement grace period per market in seconds. Markets without an entry use
+    /// `DEFAULT_SETTLEMENT_GRACE_SECS`
+    #[pallet::storage]
+    #[pallet::getter(fn settlement_grace_periods)]
+    pub type SettlementGracePeriods<T: Config> =
+        StorageMap<_, Blake2_128Concat, MarketId, u64, OptionQuery>;
+
+    /// Retention window in seconds for a market's legacy rain buckets and trigger
+    /// logs. Markets without an entry use `DEFAULT_DATA_RETENTION_SECS`
+    #[pallet::storage]
+    #[pallet::getter(fn data_retention)]
+    pub type DataRetention<T: Config> =
+        StorageMap<_, Blake2_128Concat, MarketId, u64, OptionQuery>;
+
+    /// Lowest `RainBuckets` index per market that `on_idle` has not pruned yet.
+    /// Lowered whenever an earlier bucket is written
+    #[pallet::storage]
+    #[pallet::getter(fn rain_bucket_prune_cursor)]
+    pub type RainBucketPruneCursor<T: Config> =
+        StorageMap<_, Blake2_128Concat, MarketId, BucketIndex, OptionQuery>;
+
+    /// Next `ThresholdTriggerLogs` ID that `on_idle` considers for pruning
+    #[pallet::storage]
+    #[pallet::getter(fn trigger_log_prune_cursor)]
+    pub type TriggerLogPruneCursor<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// Policies with a `V2FinalReportByPolicy` entry, in finalization order
+    #[pallet::storage]
+    pub type V2FinalReportPruneQueue<T: Config> =
+        StorageMap<_, Blake2_128Concat, u64, PolicyId, OptionQuery>;
+
+    /// Next `V2FinalReportPruneQueue` index
+    #[pallet::storage]
+    pub type NextV2FinalReportPruneIndex<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// Next `V2FinalReportPruneQueue` index that `on_idle` considers for pruning
+    #[pallet::storage]
+    #[pallet::getter(fn v2_final_report_prune_cursor)]
+    pub type V2FinalReportPruneCursor<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// blake2_256 of the SCALE encoding of each pruned V2 final report
+    #[pallet::storage]
+    #[pallet::getter(fn v2_final_report_hash)]
+    pub type V2FinalReportHashes<T: Config> =
+        StorageMap<_, Blake2_128Concat, PolicyId, [u8; 32], OptionQuery>;
+
+    /// Hash chain over the pruned entries of each market, per kind of data
+    /// (see `retention`)
+    #[pallet::storage]
+    #[pallet::getter(fn archive_chain)]
+    pub type ArchiveChains<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        MarketId,
+        Blake2_128Concat,
+        ArchiveKind,
+        ArchiveChain,
+        ValueQuery,
+    >;
+
+    /// Observation sets gossiped by OCWs that have not reached `MirrorQuorum` yet,
+    /// per market and weather provider, oldest first
+    #[pallet::storage]
+    #[pallet::getter(fn mirror_votes)]
+    pub type MirrorVotes<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        MarketId,
+        Blake2_128Concat,
+        WeatherProvider,
+        BoundedVec<MirrorCandidate<T>, ConstU32<{ gossip::MAX_MIRROR_CANDIDATES }>>,
+        ValueQuery,
+    >;
+
+    /// Digest of the latest observation set that reached `MirrorQuorum`, per market
+    /// and weather provider, with the block it was applied at
+    #[pallet::storage]
+    #[pallet::getter(fn mirrored_digest)]
+    pub type MirroredDigests<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        MarketId,
+        Blake2_128Concat,
+        WeatherProvider,
+        ([u8; 32], BlockNumberFor<T>),
+        OptionQuery,
+    >;
+
+    /// Authorities that must attest the same observations before they are applied.
+    /// `DEFAULT_MIRROR_QUORUM` when unset
+    #[pallet::storage]
+    #[pallet::getter(fn mirror_quorum_override)]
+    pub type MirrorQuorum<T: Config> = StorageValue<_, u32, OptionQuery>;
+
+    /// Expired policies whose grace period ended without data for the final hour,
+    /// by policy ID (value: coverage end). Cleared when the policy is settled
+    #[pallet::storage]
+    #[pallet::getter(fn awaiting_settlement_data)]
+    pub type AwaitingSettlementData<T: Config> =
+        StorageMap<_, Blake2_128Concat, PolicyId, u64, OptionQuery>;
+
+    /// Flag indicating API key was just configured and immediate fetch should be triggered
+    #[pallet::storage]
+    #[pallet::getter(fn api_key_configured_at)]
+    pub type ApiKeyConfiguredAt<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+    /// Pending API key to be copied to offchain local storage by the OCW.
+    /// This is used to securely transfer the API key from on-chain to offchain.
+    /// The OCW reads this, copies to local storage, and clears it.
+    #[pallet::storage]
+    pub type PendingApiKey<T: Config> = StorageValue<_, BoundedVec<u8, ConstU32<256>>, OptionQuery>;
+
+    // =========================================================================
+    //                          V2 Oracle Storage
+    // =========================================================================
+
+    /// Authorized V2 oracle reporters (accounts that can submit V2 reports).
+    /// These are off-chain oracle service accounts.
     #[pallet::storage]
     #[pallet::getter(fn authorized_v2_reporters)]
     pub type AuthorizedV2Reporters<T: Config> =
@@ -444,6 +1316,44 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// V2 reports waiting for their challenge period to end
+    #[pallet::storage]
+    #[pallet::getter(fn pending_v2_reports)]
+    pub type PendingV2Reports<T: Config> =
+        StorageMap<_, Blake2_128Concat, PolicyId, PendingV2Report<T>, OptionQuery>;
+
+    /// Pending V2 reports with their `challenge_ends_at`, in submission order. Every
+    /// report gets the same `V2ChallengePeriod`, so this is also maturity order
+    #[pallet::storage]
+    pub type V2FinalizationQueue<T: Config> =
+        StorageMap<_, Blake2_128Concat, u64, (PolicyId, BlockNumberFor<T>), OptionQuery>;
+
+    /// Next `V2FinalizationQueue` index
+    #[pallet::storage]
+    pub type NextV2FinalizationIndex<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// Next `V2FinalizationQueue` index that `on_initialize` considers for finalization
+    #[pallet::storage]
+    #[pallet::getter(fn v2_finalization_cursor)]
+    pub type V2FinalizationCursor<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// Open disputes against pending V2 reports
+    #[pallet::storage]
+    #[pallet::getter(fn v2_disputes)]
+    pub type V2Disputes<T: Config> =
+        StorageMap<_, Blake2_128Concat, PolicyId, V2Dispute<T>, OptionQuery>;
+
+    /// V2 reporter votes on an open dispute: (voter, uphold_dispute)
+    #[pallet::storage]
+    #[pallet::getter(fn v2_dispute_votes)]
+    pub type V2DisputeVotes<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        PolicyId,
+        BoundedVec<(T::AccountId, bool), ConstU32<MAX_V2_DISPUTE_VOTES>>,
+        ValueQuery,
+    >;
+
     // =========================================================================
     //                                  Events
     // =========================================================================
@@ -501,6 +1411,18 @@ pub mod pallet {
             event_occurred: bool,
             payout_amount: u128,
         },
+        /// Hourly readings from a weather provider stored and re-aggregated
+        ProviderRainfallSubmitted {
+            market_id: MarketId,
+            provider: WeatherProvider,
+            readings_stored: u32,
+            buckets_agreed: u32,
+        },
+        /// Multi-provider agreement parameters updated
+        ProviderAgreementUpdated {
+            min_providers: u32,
+            tolerance_mm: Millimeters,
+        },
         // ===== V2 Oracle Events =====
         /// V2 reporter added
         V2ReporterAdded { account: T::AccountId },
@@ -513,6 +1435,246 @@ pub mod pallet {
             cumulative_mm: u32,
             evidence_hash: [u8; 32],
         },
+        /// V2 report submitted and held until its challenge period ends
+        V2ReportPending {
+            policy_id: super::PolicyId,
+            outcome: prmx_primitives::V2Outcome,
+            cumulative_mm: u32,
+            evidence_hash: [u8; 32],
+            challenge_ends_at: BlockNumberFor<T>,
+        },
+        /// Automatic finalization of a matured V2 report failed. The report stays
+        /// pending and can be finalized again through `finalize_v2_report`
+        V2ReportFinalizationFailed {
+            policy_id: super::PolicyId,
+            error: DispatchError,
+        },
+        /// Pending V2 report disputed with counter evidence
+        V2ReportDisputed {
+            policy_id: super::PolicyId,
+            disputer: T::AccountId,
+            counter_evidence_hash: [u8; 32],
+            bond: BalanceOf<T>,
+        },
+        /// V2 reporter voted on an open dispute
+        V2DisputeVoteCast {
+            policy_id: super::PolicyId,
+            voter: T::AccountId,
+            uphold: bool,
+        },
+        /// Dispute resolved. If upheld, the report is discarded and the bond returned;
+        /// otherwise the bond is slashed and the report is settled.
+        V2DisputeResolved {
+            policy_id: super::PolicyId,
+            upheld: bool,
+        },
+        /// Hourly temperature readings stored for a market
+        HourlyTemperatureSubmitted {
+            market_id: MarketId,
+            readings_stored: u32,
+            latest_temp_c: Option<DeciCelsius>,
+        },
+        /// Hourly wind gust readings stored for a market
+        HourlyWindGustSubmitted {
+            market_id: MarketId,
+            readings_stored: u32,
+            max_gust_kmh: Option<DeciKmh>,
+        },
+        /// Hourly snowfall readings stored for a market
+        HourlySnowfallSubmitted {
+            market_id: MarketId,
+            readings_stored: u32,
+            max_snowfall_mm: Option<Millimeters>,
+        },
+        /// Hourly gauge level stored for a market
+        HourlyGaugeLevelSubmitted {
+            market_id: MarketId,
+            hour_index: u64,
+            level_cm: Centimeters,
+            rainfall_24h_mm: Millimeters,
+        },
+        /// Gauge source configured for a market
+        GaugeSourceSet {
+            market_id: MarketId,
+            flood_level_cm: Centimeters,
+        },
+        /// Gauge source removed from a market
+        GaugeSourceRemoved {
+            market_id: MarketId,
+        },
+        /// Secondary rainfall API configured for a market
+        MarketDataSourceSet {
+            market_id: MarketId,
+            provider: WeatherProvider,
+        },
+        /// Secondary rainfall API removed from a market
+        MarketDataSourceRemoved {
+            market_id: MarketId,
+        },
+        /// Providers assigned to a market; empty opens the market to any bonded provider
+        ProviderAssignmentsSet {
+            market_id: MarketId,
+            providers: BoundedVec<T::AccountId, ConstU32<MAX_PROVIDERS_PER_MARKET>>,
+        },
+        /// Assigned provider of a market replaced
+        ProviderRotated {
+            market_id: MarketId,
+            outgoing: T::AccountId,
+            incoming: T::AccountId,
+        },
+        /// A resubmitted reading differed from the provider's stored reading for the
+        /// hour by more than the agreement tolerance and was ignored
+        HourlyOverwriteRejected {
+            market_id: MarketId,
+            provider: WeatherProvider,
+            hour_index: u64,
+            stored_mm: Millimeters,
+            submitted_mm: Millimeters,
+            submitter: T::AccountId,
+        },
+        /// A provider's stored reading was replaced through the correction path
+        BucketCorrected {
+            market_id: MarketId,
+            provider: WeatherProvider,
+            hour_index: u64,
+            previous_mm: Millimeters,
+            corrected_mm: Millimeters,
+            submitter: T::AccountId,
+        },
+        /// Oracle provider bonded funds
+        OracleProviderBonded {
+            account: T::AccountId,
+            amount: BalanceOf<T>,
+            total_bond: BalanceOf<T>,
+        },
+        /// Oracle provider started unbonding
+        OracleProviderUnbonding {
+            account: T::AccountId,
+            amount: BalanceOf<T>,
+            unlock_at: BlockNumberFor<T>,
+        },
+        /// Unbonded provider funds released
+        OracleProviderBondWithdrawn {
+            account: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// Bucket interval / rolling window changed for a market
+        MarketWindowUpdated {
+            market_id: MarketId,
+            bucket_interval_secs: u64,
+            rolling_window_secs: u64,
+        },
+        /// Oracle provider bond slashed
+        OracleProviderSlashed {
+            account: T::AccountId,
+            amount: BalanceOf<T>,
+            reason: ProviderSlashReason,
+        },
+        /// Historic backfill scheduled for a market (hour indices, inclusive)
+        BackfillRequested {
+            market_id: MarketId,
+            from_hour: u64,
+            to_hour: u64,
+        },
+        /// One backfill page stored and the rolling state recomputed
+        BackfillPageSubmitted {
+            market_id: MarketId,
+            provider: WeatherProvider,
+            from_hour: u64,
+            to_hour: u64,
+            buckets_agreed: u32,
+        },
+        /// All pages of a backfill have been stored
+        BackfillCompleted { market_id: MarketId },
+        /// No rainfall data arrived for a market within the stale threshold;
+        /// new policy sales for the market are paused
+        OracleStale {
+            market_id: MarketId,
+            last_data_at: u64,
+        },
+        /// Rainfall data arrived again for a stale market; sales resume
+        OracleRecovered { market_id: MarketId },
+        /// Stale threshold changed
+        OracleStaleAfterHoursUpdated { hours: u32 },
+        /// An oracle provider's OCW reported its activity since its previous report
+        OcwHealthReported {
+            provider: T::AccountId,
+            counters: OcwCounters,
+        },
+        /// Settlement grace period changed for a market
+        SettlementGracePeriodUpdated {
+            market_id: MarketId,
+            grace_period_secs: u64,
+        },
+        /// Data retention window changed for a market
+        DataRetentionUpdated {
+            market_id: MarketId,
+            retention_secs: u64,
+        },
+        /// An authority attested gossiped observations that have not reached quorum yet
+        ObservationsAttested {
+            market_id: MarketId,
+            provider: WeatherProvider,
+            attester: T::AccountId,
+            digest: [u8; 32],
+            attestations: u32,
+        },
+        /// Gossiped observations reached `MirrorQuorum`, were ingested and written to
+        /// offchain indexing for every node
+        ObservationsMirrored {
+            market_id: MarketId,
+            provider: WeatherProvider,
+            digest: [u8; 32],
+            attestations: u32,
+        },
+        /// Mirror quorum changed
+        MirrorQuorumUpdated { quorum: u32 },
+        /// Expired oracle data pruned after being folded into the market's archive chain
+        OracleDataPruned {
+            market_id: MarketId,
+            kind: ArchiveKind,
+            entries: u32,
+            archive_hash: [u8; 32],
+        },
+        /// A V2 final report past retention was pruned, leaving its hash
+        V2FinalReportPruned { policy_id: PolicyId, report_hash: [u8; 32] },
+        /// Grace period ended without data for the final hour of coverage;
+        /// the policy waits for late data or a forced settlement
+        ExpirySettlementAwaitingData {
+            policy_id: super::PolicyId,
+            market_id: MarketId,
+            coverage_end: u64,
+        },
+        /// Expired policy settled by governance
+        PolicyExpirationForceSettled {
+            policy_id: super::PolicyId,
+            event_occurred: bool,
+            observed_mm: Millimeters,
+            payout_amount: u128,
+        },
+        /// Expired policy settled by a keeper through `poke_settlement`.
+        /// `reward` is zero if the reward account could not pay it.
+        PolicySettlementPoked {
+            policy_id: super::PolicyId,
+            keeper: T::AccountId,
+            event_occurred: bool,
+            payout_amount: u128,
+            reward: BalanceOf<T>,
+        },
+        /// OCW feasibility check of a market listing proposal submitted
+        ListingFeasibilitySubmitted {
+            proposal_id: ListingProposalId,
+            provider: T::AccountId,
+            feasibility: ListingFeasibility,
+        },
+        /// A provider bound an OCW key; `replaced` is the key it rotated out
+        OracleKeysRegistered {
+            provider: T::AccountId,
+            key: T::AccountId,
+            replaced: Option<T::AccountId>,
+        },
+        /// A provider's OCW key was unbound
+        OracleKeysPurged { provider: T::AccountId, key: T::AccountId },
     }
 
     // =========================================================================
@@ -559,6 +1721,106 @@ pub mod pallet {
         NotV2Policy,
         /// V2 policies only allowed for Manila market
         V2OnlyManilaAllowed,
+        /// Provider quorum must be between 1 and MAX_WEATHER_PROVIDERS
+        InvalidProviderAgreement,
+        /// No pending V2 report for this policy
+        V2ReportNotPending,
+        /// Challenge period for this V2 report has ended
+        ChallengePeriodEnded,
+        /// Challenge period for this V2 report has not ended yet
+        ChallengePeriodNotEnded,
+        /// V2 report is already disputed
+        V2ReportAlreadyDisputed,
+        /// V2 report has an open dispute that must be resolved first
+        V2ReportDisputeOpen,
+        /// No open dispute for this policy
+        V2DisputeNotFound,
+        /// Account already voted on this dispute
+        AlreadyVotedOnDispute,
+        /// Reporter and disputer cannot vote on their own dispute
+        DisputePartyCannotVote,
+        /// Too many votes recorded for this dispute
+        TooManyDisputeVotes,
+        /// Invalid temperature value (sanity check failed)
+        InvalidTemperatureValue,
+        /// Invalid wind gust value (sanity check failed)
+        InvalidWindGustValue,
+        /// Invalid snowfall value (sanity check failed)
+        InvalidSnowfallValue,
+        /// Gauge source URL, JSON path, unit scale or flood stage is invalid
+        InvalidGaugeSource,
+        /// Gauge level outside the plausible range
+        InvalidGaugeLevel,
+        /// No gauge source is configured for the market
+        GaugeSourceNotConfigured,
+        /// Data source provider, endpoint template or field mapping is invalid
+        InvalidDataSource,
+        /// No data source is configured for the market
+        DataSourceNotConfigured,
+        /// Provider is not assigned to submit data for this market
+        ProviderNotAssignedToMarket,
+        /// Provider is already assigned to this market
+        ProviderAlreadyAssigned,
+        /// Oracle provider bond is below `OracleProviderBond`
+        InsufficientProviderBond,
+        /// Bond amount must be greater than zero
+        InvalidBondAmount,
+        /// Unbond amount exceeds the active bond
+        UnbondExceedsBond,
+        /// No unbonded funds for this account
+        NothingToWithdraw,
+        /// Unbonding period has not ended yet
+        UnbondingPeriodNotEnded,
+        /// Rolling window must be whole hours up to 7 days and a multiple of the bucket interval
+        InvalidMarketWindow,
+        /// Backfill range must be non-empty, in the past and within the market's acceptance window
+        InvalidBackfillRange,
+        /// No pending backfill for this market
+        NoPendingBackfill,
+        /// Backfill page must cover every hour of the next page, in order
+        BackfillNotContinuous,
+        /// Stale threshold must be at least one hour
+        InvalidStaleThreshold,
+        /// Operation is paused by governance
+        OperationPaused,
+        /// Settlement grace period exceeds `MAX_SETTLEMENT_GRACE_SECS`
+        InvalidGracePeriod,
+        /// Policy not found
+        PolicyNotFound,
+        /// Policy coverage has not ended yet
+        CoverageNotEnded,
+        /// Settlement grace period has not ended yet
+        GracePeriodNotEnded,
+        /// Rainfall for the final hour of coverage has not been reported yet
+        FinalHourDataMissing,
+        /// Strike value for the policy's market is not available
+        StrikeNotAvailable,
+        /// The provider has no stored reading for the hour to correct
+        NoReadingToCorrect,
+        /// Corrections are only accepted inside the hourly acceptance window
+        CorrectionOutsideWindow,
+        /// Retention window is shorter than `MIN_DATA_RETENTION_SECS`
+        RetentionTooShort,
+        /// A mirror must carry at least one observation
+        EmptyMirror,
+        /// The authority already attested these observations
+        AlreadyAttested,
+        /// These observations already reached quorum
+        MirrorAlreadyApplied,
+        /// No room for further attestations of these observations
+        TooManyMirrorAttesters,
+        /// Mirror quorum must be between 1 and `MAX_MIRROR_ATTESTERS`
+        InvalidMirrorQuorum,
+        /// No feasibility check pending for this listing proposal
+        NoPendingListingCheck,
+        /// A feasibility check cannot report `Pending`
+        InvalidListingFeasibility,
+        /// The ownership proof is not the key's signature of the provider account
+        InvalidKeyOwnershipProof,
+        /// The key is bound to, or is the account of, another provider
+        OracleKeyInUse,
+        /// The provider has no registered OCW key
+        NoOracleKeys,
     }
 
     // =========================================================================
@@ -587,6 +1849,12 @@ pub mod pallet {
                     target: "prmx-oracle",
                     "🔐 Genesis: Registered oracle provider"
                 );
+
+                // Bond the minimum so genesis providers can submit from the first block
+                let bond = T::OracleProviderBond::get();
+                if !bond.is_zero() && T::Currency::reserve(account, bond).is_ok() {
+                    ProviderBonds::<T>::insert(account, bond);
+                }
             }
 
             // Register initial V2 reporters (for off-chain oracle service)
@@ -626,22 +1894,138 @@ pub mod pallet {
     }
 
     // =========================================================================
-    //                                Extrinsics
+    //                           Unsigned Validation
     // =========================================================================
 
-    #[pallet::call]
-    impl<T: Config> Pallet<T> {
-        /// Bind AccuWeather Location Key to a market.
-        /// Called by oracle offchain worker after resolving geoposition.
-        /// Per oracle_design.md section 7.1
-        #[pallet::call_index(0)]
-        #[pallet::weight(T::WeightInfo::set_market_location_key())]
-        pub fn set_market_location_key(
-            origin: OriginFor<T>,
-            market_id: MarketId,
-            accuweather_location_key: Vec<u8>,
-        ) -> DispatchResult {
-            // Either OracleOrigin or GovernanceOrigin can call this
+    /// Heartbeats, OCW health reports and observation mirrors are unsigned transactions
+    /// carrying a payload signed by an oracle authority key, so providers do not pay
+    /// fees just to report liveness or gossip what they fetched
+    #[pallet::validate_unsigned]
+    impl<T: Config> ValidateUnsigned for Pallet<T> {
+        type Call = Call<T>;
+
+        fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+            use frame_system::offchain::SignedPayload;
+            use sp_runtime::traits::IdentifyAccount;
+
+            match call {
+                Call::report_heartbeat { heartbeat, signature } => {
+                    if !SignedPayload::<T>::verify::<T::AuthorityId>(heartbeat, signature.clone()) {
+                        return InvalidTransaction::BadProof.into();
+                    }
+
+                    let provider = Self::provider_of_key(heartbeat.public.clone().into_account());
+                    if !OracleProviders::<T>::get(&provider) {
+                        return InvalidTransaction::BadSigner.into();
+                    }
+
+                    let current_block = frame_system::Pallet::<T>::block_number();
+                    if heartbeat.block_number > current_block {
+                        return InvalidTransaction::Future.into();
+                    }
+                    if ProviderLastHeartbeat::<T>::get(&provider)
+                        .map_or(false, |last| heartbeat.block_number <= last)
+                    {
+                        return InvalidTransaction::Stale.into();
+                    }
+
+                    ValidTransaction::with_tag_prefix("PrmxOracleHeartbeat")
+                        .priority(TransactionPriority::MAX / 2)
+                        .and_provides((provider, heartbeat.block_number))
+                        .longevity(BLOCKS_PER_HEARTBEAT as u64)
+                        .propagate(true)
+                        .build()
+                }
+                Call::report_ocw_health { report, signature } => {
+                    if !SignedPayload::<T>::verify::<T::AuthorityId>(report, signature.clone()) {
+                        return InvalidTransaction::BadProof.into();
+                    }
+
+                    let provider = Self::provider_of_key(report.public.clone().into_account());
+                    if !OracleProviders::<T>::get(&provider) {
+                        return InvalidTransaction::BadSigner.into();
+                    }
+
+                    let current_block = frame_system::Pallet::<T>::block_number();
+                    if report.block_number > current_block {
+                        return InvalidTransaction::Future.into();
+                    }
+                    // One report per provider every BLOCKS_PER_HEALTH_REPORT blocks
+                    if OcwHealth::<T>::get(&provider).map_or(false, |last| {
+                        report.block_number
+                            < last.reported_at.saturating_add(BLOCKS_PER_HEALTH_REPORT.into())
+                    }) {
+                        return InvalidTransaction::Stale.into();
+                    }
+
+                    ValidTransaction::with_tag_prefix("PrmxOracleOcwHealth")
+                        .priority(TransactionPriority::MAX / 4)
+                        .and_provides((provider, report.block_number))
+                        .longevity(BLOCKS_PER_HEARTBEAT as u64)
+                        .propagate(true)
+                        .build()
+                }
+                Call::mirror_observations { mirror, signature } => {
+                    if !SignedPayload::<T>::verify::<T::AuthorityId>(mirror, signature.clone()) {
+                        return InvalidTransaction::BadProof.into();
+                    }
+
+                    let attester = Self::provider_of_key(mirror.public.clone().into_account());
+                    if !OracleProviders::<T>::get(&attester) {
+                        return InvalidTransaction::BadSigner.into();
+                    }
+
+                    let current_block = frame_system::Pallet::<T>::block_number();
+                    if mirror.block_number > current_block {
+                        return InvalidTransaction::Future.into();
+                    }
+                    if mirror.block_number.saturating_add(gossip::MIRROR_LONGEVITY_BLOCKS.into())
+                        < current_block
+                    {
+                        return InvalidTransaction::Stale.into();
+                    }
+
+                    let observations = gossip::normalize(&mirror.observations);
+                    if observations.is_empty() {
+                        return InvalidTransaction::Call.into();
+                    }
+                    let digest =
+                        gossip::observations_digest(mirror.market_id, mirror.provider, &observations);
+                    if Self::is_mirror_applied(mirror.market_id, mirror.provider, &digest)
+                        || Self::has_attested(mirror.market_id, mirror.provider, &digest, &attester)
+                    {
+                        return InvalidTransaction::Stale.into();
+                    }
+
+                    ValidTransaction::with_tag_prefix("PrmxOracleMirror")
+                        .priority(TransactionPriority::MAX / 2)
+                        .and_provides((attester, mirror.market_id, mirror.provider, digest))
+                        .longevity(gossip::MIRROR_LONGEVITY_BLOCKS as u64)
+                        .propagate(true)
+                        .build()
+                }
+                _ => InvalidTransaction::Call.into(),
+            }
+        }
+    }
+
+    // =========================================================================
+    //                                Extrinsics
+    // =========================================================================
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Bind AccuWeather Location Key to a market.
+        /// Called by oracle offchain worker after resolving geoposition.
+        /// Per oracle_design.md section 7.1
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::set_market_location_key())]
+        pub fn set_market_location_key(
+            origin: OriginFor<T>,
+            market_id: MarketId,
+            accuweather_location_key: Vec<u8>,
+        ) -> DispatchResult {
+            // Either OracleOrigin or GovernanceOrigin can call this
             let is_oracle = T::OracleOrigin::try_origin(origin.clone()).is_ok();
             let is_governance = T::GovernanceOrigin::try_origin(origin).is_ok();
 
@@ -657,11 +2041,14 @@ pub mod pallet {
                 .try_into()
                 .map_err(|_| Error::<T>::LocationKeyTooLong)?;
 
-            // Store the binding
+            // Store the binding, keeping any window configuration from a previous binding
+            let (bucket_interval_secs, rolling_window_secs) = Self::market_window(market_id);
             let location_info = MarketLocationInfo {
                 accuweather_location_key: bounded_key,
                 center_latitude,
                 center_longitude,
+                bucket_interval_secs,
+                rolling_window_secs,
             };
 
             MarketLocationConfig::<T>::insert(market_id, location_info);
@@ -689,11 +2076,9 @@ pub mod pallet {
             let is_oracle_origin = T::OracleOrigin::try_origin(origin.clone()).is_ok();
 
             if !is_oracle_origin {
-                let who = ensure_signed(origin)?;
-                ensure!(
-                    OracleProviders::<T>::get(&who),
-                    Error::<T>::NotOracleProvider
-                );
+                let who = Self::provider_of_key(ensure_signed(origin)?);
+                Self::ensure_market_provider(&who, location_id)?;
+                Self::ensure_submissions_not_paused()?;
             }
 
             // Ensure market has location config
@@ -703,13 +2088,7 @@ pub mod pallet {
             );
 
             // Get current time for drift validation
-            // Use block number * 6 seconds + base timestamp for approximation
-            let now = {
-                use sp_runtime::traits::UniqueSaturatedInto;
-                let block_num: u64 = frame_system::Pallet::<T>::block_number().unique_saturated_into();
-                // Use consistent timestamp calculation: base + (block_num * 6 seconds)
-                BASE_TIMESTAMP_SECS + (block_num * 6)
-            };
+            let now = T::TimeProvider::now_secs();
 
             // Validate timestamp drift (allow any timestamp in dev mode if now is 0)
             if now > 0 {
@@ -730,8 +2109,9 @@ pub mod pallet {
             );
 
             // Compute bucket index and aligned timestamp
-            let idx = bucket_index_for_timestamp(timestamp);
-            let bucket_start = bucket_start_time(idx);
+            let (interval_secs, _) = Self::market_window(location_id);
+            let idx = bucket_index_with_interval(timestamp, interval_secs);
+            let bucket_start = bucket_start_with_interval(idx, interval_secs);
 
             // Get old bucket value for delta calculation
             let old_mm = RainBuckets::<T>::get(location_id, idx)
@@ -748,6 +2128,7 @@ pub mod pallet {
                 block_number: current_block,
             };
             RainBuckets::<T>::insert(location_id, idx, bucket);
+            Self::note_rain_bucket(location_id, idx);
 
             Self::deposit_event(Event::RainfallUpdated {
                 location_id,
@@ -764,7 +2145,7 @@ pub mod pallet {
         /// Add an oracle provider account.
         /// Only callable by GovernanceOrigin.
         #[pallet::call_index(2)]
-        #[pallet::weight(Weight::from_parts(10_000, 0))]
+        #[pallet::weight(T::WeightInfo::add_oracle_provider())]
         pub fn add_oracle_provider(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
             T::GovernanceOrigin::ensure_origin(origin)?;
 
@@ -778,7 +2159,7 @@ pub mod pallet {
         /// Remove an oracle provider account.
         /// Only callable by GovernanceOrigin.
         #[pallet::call_index(3)]
-        #[pallet::weight(Weight::from_parts(10_000, 0))]
+        #[pallet::weight(T::WeightInfo::remove_oracle_provider())]
         pub fn remove_oracle_provider(
             origin: OriginFor<T>,
             account: T::AccountId,
@@ -786,6 +2167,7 @@ pub mod pallet {
             T::GovernanceOrigin::ensure_origin(origin)?;
 
             OracleProviders::<T>::remove(&account);
+            Self::unbind_oracle_keys(&account);
 
             Self::deposit_event(Event::OracleProviderRemoved { account });
 
@@ -796,7 +2178,7 @@ pub mod pallet {
         /// This allows manual population of rainfall data without needing AccuWeather API.
         /// Only callable by GovernanceOrigin or OracleOrigin.
         #[pallet::call_index(4)]
-        #[pallet::weight(Weight::from_parts(50_000, 0))]
+        #[pallet::weight(T::WeightInfo::set_test_rainfall())]
         pub fn set_test_rainfall(
             origin: OriginFor<T>,
             market_id: MarketId,
@@ -822,25 +2204,28 @@ pub mod pallet {
                             accuweather_location_key: bounded_key,
                             center_latitude: lat,
                             center_longitude: lon,
+                            bucket_interval_secs: BUCKET_INTERVAL_SECS,
+                            rolling_window_secs: ROLLING_WINDOW_SECS,
                         };
                         MarketLocationConfig::<T>::insert(market_id, location_info);
                     }
                 }
             }
 
-            // Get current timestamp approximation
             use sp_runtime::traits::UniqueSaturatedInto;
             let block_num: u64 = frame_system::Pallet::<T>::block_number().unique_saturated_into();
-            let now_ts = BASE_TIMESTAMP_SECS + (block_num * 6);
-            let bucket_idx = bucket_index_for_timestamp(now_ts);
+            let now_ts = T::TimeProvider::now_secs();
+            let (interval_secs, _) = Self::market_window(market_id);
+            let bucket_idx = bucket_index_with_interval(now_ts, interval_secs);
 
             // Store rainfall bucket
             let bucket = RainBucket {
-                timestamp: bucket_start_time(bucket_idx),
+                timestamp: bucket_start_with_interval(bucket_idx, interval_secs),
                 rainfall_mm,
                 block_number: block_num as u32,
             };
             RainBuckets::<T>::insert(market_id, bucket_idx, bucket);
+            Self::note_rain_bucket(market_id, bucket_idx);
 
             // Update or create rolling state
             let state = RollingWindowState {
@@ -849,6 +2234,7 @@ pub mod pallet {
                 rolling_sum_mm: rainfall_mm,
             };
             RollingState::<T>::insert(market_id, state);
+            Self::note_market_data(market_id);
 
             Self::deposit_event(Event::RainfallUpdated {
                 location_id: market_id,
@@ -876,7 +2262,7 @@ pub mod pallet {
         /// Only callable by GovernanceOrigin.
         /// Note: The API key is stored in offchain local storage, not on-chain.
         #[pallet::call_index(5)]
-        #[pallet::weight(Weight::from_parts(10_000, 0))]
+        #[pallet::weight(T::WeightInfo::set_accuweather_api_key())]
         pub fn set_accuweather_api_key(
             origin: OriginFor<T>,
             api_key: Vec<u8>,
@@ -907,7 +2293,7 @@ pub mod pallet {
         /// Only callable by GovernanceOrigin (DAO).
         /// The offchain worker will process this request and fetch real data.
         #[pallet::call_index(6)]
-        #[pallet::weight(Weight::from_parts(10_000, 0))]
+        #[pallet::weight(T::WeightInfo::request_rainfall_fetch())]
         pub fn request_rainfall_fetch(
             origin: OriginFor<T>,
             market_id: MarketId,
@@ -952,7 +2338,7 @@ pub mod pallet {
         /// Called by DAO after offchain worker has fetched and stored data.
         /// This extrinsic allows the DAO to manually submit the AccuWeather data.
         #[pallet::call_index(7)]
-        #[pallet::weight(Weight::from_parts(50_000, 0))]
+        #[pallet::weight(T::WeightInfo::complete_rainfall_fetch())]
         pub fn complete_rainfall_fetch(
             origin: OriginFor<T>,
             market_id: MarketId,
@@ -966,9 +2352,10 @@ pub mod pallet {
             PendingFetchRequests::<T>::remove(market_id);
 
             // Get current time for rolling state updates
-            let now = Self::current_timestamp();
-            let bucket_idx = bucket_index_for_timestamp(now);
-            let bucket_start = bucket_start_time(bucket_idx);
+            let now = T::TimeProvider::now_secs();
+            let (interval_secs, window_secs) = Self::market_window(market_id);
+            let bucket_idx = bucket_index_with_interval(now, interval_secs);
+            let bucket_start = bucket_start_with_interval(bucket_idx, interval_secs);
 
             log::info!(
                 target: "prmx-oracle",
@@ -992,6 +2379,7 @@ pub mod pallet {
                 block_number: current_block,
             };
             RainBuckets::<T>::insert(market_id, bucket_idx, bucket);
+            Self::note_rain_bucket(market_id, bucket_idx);
 
             Self::deposit_event(Event::RainfallUpdated {
                 location_id: market_id,
@@ -1003,10 +2391,11 @@ pub mod pallet {
             // (AccuWeather Past24Hours already gives us the 24h sum)
             let state = RollingWindowState {
                 last_bucket_index: bucket_idx,
-                oldest_bucket_index: bucket_idx.saturating_sub(24), // ~24 hours of buckets
+                oldest_bucket_index: bucket_idx.saturating_sub(window_secs / interval_secs),
                 rolling_sum_mm: rainfall_mm,
             };
             RollingState::<T>::insert(market_id, state);
+            Self::note_market_data(market_id);
 
             Self::deposit_event(Event::RollingSumUpdated {
                 location_id: market_id,
@@ -1032,18 +2421,16 @@ pub mod pallet {
         /// This is called by the offchain worker after fetching real data from AccuWeather.
         /// The signer must be an authorized oracle provider.
         #[pallet::call_index(8)]
-        #[pallet::weight(Weight::from_parts(50_000, 0))]
+        #[pallet::weight(T::WeightInfo::submit_rainfall_from_ocw())]
         pub fn submit_rainfall_from_ocw(
             origin: OriginFor<T>,
             market_id: MarketId,
             rainfall_mm: Millimeters, // The 24h rolling sum from AccuWeather (in tenths of mm)
         ) -> DispatchResult {
-            // Verify signed by an oracle provider
-            let who = ensure_signed(origin)?;
-            ensure!(
-                OracleProviders::<T>::get(&who),
-                Error::<T>::NotOracleProvider
-            );
+            // Verify signed by a bonded oracle provider assigned to the market
+            let who = Self::provider_of_key(ensure_signed(origin)?);
+            Self::ensure_market_provider(&who, market_id)?;
+            Self::ensure_submissions_not_paused()?;
 
             // Validate market exists
             ensure!(
@@ -1058,9 +2445,10 @@ pub mod pallet {
             );
 
             // Get current time for rolling state updates
-            let now = Self::current_timestamp();
-            let bucket_idx = bucket_index_for_timestamp(now);
-            let bucket_start = bucket_start_time(bucket_idx);
+            let now = T::TimeProvider::now_secs();
+            let (interval_secs, window_secs) = Self::market_window(market_id);
+            let bucket_idx = bucket_index_with_interval(now, interval_secs);
+            let bucket_start = bucket_start_with_interval(bucket_idx, interval_secs);
 
             log::info!(
                 target: "prmx-oracle",
@@ -1084,6 +2472,7 @@ pub mod pallet {
                 block_number: current_block,
             };
             RainBuckets::<T>::insert(market_id, bucket_idx, bucket);
+            Self::note_rain_bucket(market_id, bucket_idx);
 
             Self::deposit_event(Event::RainfallUpdated {
                 location_id: market_id,
@@ -1095,10 +2484,11 @@ pub mod pallet {
             // (AccuWeather Past24Hours already gives us the 24h sum)
             let state = RollingWindowState {
                 last_bucket_index: bucket_idx,
-                oldest_bucket_index: bucket_idx.saturating_sub(24),
+                oldest_bucket_index: bucket_idx.saturating_sub(window_secs / interval_secs),
                 rolling_sum_mm: rainfall_mm,
             };
             RollingState::<T>::insert(market_id, state);
+            Self::note_market_data(market_id);
 
             Self::deposit_event(Event::RollingSumUpdated {
                 location_id: market_id,
@@ -1129,134 +2519,77 @@ pub mod pallet {
         /// Uses AccuWeather historical/24 endpoint data for more accurate rolling window
         /// Each entry is (epoch_time, rainfall_mm_scaled)
         #[pallet::call_index(13)]
-        #[pallet::weight(Weight::from_parts(100_000, 0))]
+        #[pallet::weight(T::WeightInfo::submit_hourly_rainfall_from_ocw(hourly_data.len() as u32))]
         pub fn submit_hourly_rainfall_from_ocw(
             origin: OriginFor<T>,
             market_id: MarketId,
             hourly_data: BoundedVec<(u64, Millimeters), ConstU32<24>>, // Max 24 hourly readings
         ) -> DispatchResult {
-            // Verify signed by an oracle provider
-            let who = ensure_signed(origin)?;
-            ensure!(
-                OracleProviders::<T>::get(&who),
-                Error::<T>::NotOracleProvider
-            );
-
-            // Validate market exists
-            ensure!(
-                pallet_prmx_markets::Markets::<T>::contains_key(market_id),
-                Error::<T>::MarketNotFound
-            );
+            // Verify signed by a bonded oracle provider assigned to the market
+            let who = Self::provider_of_key(ensure_signed(origin)?);
+            Self::ensure_market_provider(&who, market_id)?;
+            Self::ensure_submissions_not_paused()?;
 
-            let now = Self::current_timestamp();
-            let current_hour_index = now / 3600;
-            // Accept data up to 25 hours old to account for timing differences between
-            // AccuWeather's observation time and chain processing time
-            let oldest_acceptable_hour = current_hour_index.saturating_sub(25);
-            // But only keep 24 hours for display/calculation purposes
-            let oldest_display_hour = current_hour_index.saturating_sub(24);
-            
-            log::info!(
-                target: "prmx-oracle",
-                "🌧️ OCW hourly rainfall: {} readings for market {} (hours {} to {})",
-                hourly_data.len(),
+            Self::do_submit_provider_hourly_rainfall(
+                &who,
                 market_id,
-                oldest_acceptable_hour,
-                current_hour_index
-            );
+                WeatherProvider::AccuWeather,
+                &hourly_data,
+            )
+        }
 
-            // Store each hourly bucket
-            let mut rolling_sum: Millimeters = 0;
-            let mut buckets_stored = 0u32;
-            
-            for (epoch_time, rainfall_mm) in hourly_data.iter() {
-                let hour_index = *epoch_time / 3600;
-                
-                // Skip buckets older than 25 hours (gives 1 hour buffer for timing)
-                if hour_index < oldest_acceptable_hour {
-                    log::debug!(
-                        target: "prmx-oracle",
-                        "⏭️ Skipping bucket {} (too old, oldest acceptable: {})",
-                        hour_index,
-                        oldest_acceptable_hour
-                    );
-                    continue;
-                }
-                
-                // Sanity check
-                if *rainfall_mm > MAX_RAINFALL_MM {
-                    continue;
-                }
-                
-                let bucket = HourlyBucket {
-                    mm: *rainfall_mm,
-                    fetched_at: now,
-                    source: 1, // historical/24
-                };
-                
-                HourlyBuckets::<T>::insert(market_id, hour_index, bucket);
-                rolling_sum = rolling_sum.saturating_add(*rainfall_mm);
-                buckets_stored += 1;
-            }
+        /// Submit hourly rainfall readings from a secondary weather provider.
+        /// Readings are stored per provider and only feed `HourlyBuckets` once
+        /// `ProviderAgreement::min_providers` distinct oracle accounts agree within
+        /// tolerance; several providers reported by one account count once.
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::submit_provider_hourly_rainfall_from_ocw(hourly_data.len() as u32))]
+        pub fn submit_provider_hourly_rainfall_from_ocw(
+            origin: OriginFor<T>,
+            market_id: MarketId,
+            provider: WeatherProvider,
+            hourly_data: BoundedVec<(u64, Millimeters), ConstU32<24>>, // Max 24 hourly readings
+        ) -> DispatchResult {
+            // Verify signed by a bonded oracle provider assigned to the market
+            let who = Self::provider_of_key(ensure_signed(origin)?);
+            Self::ensure_market_provider(&who, market_id)?;
+            Self::ensure_submissions_not_paused()?;
 
-            // Cleanup old buckets (older than 25 hours from current hour)
-            // Use 25 hours to match the acceptance window and avoid race conditions
-            let mut removed = 0u32;
-            for (hour_idx, _) in HourlyBuckets::<T>::iter_prefix(market_id) {
-                if hour_idx < oldest_acceptable_hour {
-                    HourlyBuckets::<T>::remove(market_id, hour_idx);
-                    removed += 1;
-                }
-            }
+            Self::do_submit_provider_hourly_rainfall(&who, market_id, provider, &hourly_data)
+        }
 
-            // Recalculate rolling sum from buckets within the 24-hour display window
-            let mut actual_rolling_sum: Millimeters = 0;
-            let mut bucket_count = 0u32;
-            for (hour_idx, bucket) in HourlyBuckets::<T>::iter_prefix(market_id) {
-                // Only include buckets within the 24-hour display window for the rolling sum
-                if hour_idx >= oldest_display_hour {
-                    actual_rolling_sum = actual_rolling_sum.saturating_add(bucket.mm);
-                    bucket_count += 1;
-                }
-            }
-            
-            log::info!(
-                target: "prmx-oracle",
-                "📊 Market {} rolling sum: {:.1}mm from {} buckets (stored: {}, removed: {})",
-                market_id,
-                actual_rolling_sum as f64 / 10.0,
-                bucket_count,
-                buckets_stored,
-                removed
-            );
+        /// Set the multi-provider agreement parameters.
+        /// Only governance/root can call this.
+        ///
+        /// - `min_providers`: Oracle accounts that must agree before a bucket is used (1-4).
+        /// - `tolerance_mm`: Max distance from the median in tenths of mm.
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::set_provider_agreement())]
+        pub fn set_provider_agreement(
+            origin: OriginFor<T>,
+            min_providers: u32,
+            tolerance_mm: Millimeters,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
 
-            // Update the legacy RollingState for backwards compatibility
-            let bucket_idx = bucket_index_for_timestamp(now);
-            let state = RollingWindowState {
-                last_bucket_index: bucket_idx,
-                oldest_bucket_index: bucket_idx.saturating_sub(24),
-                rolling_sum_mm: actual_rolling_sum,
-            };
-            RollingState::<T>::insert(market_id, state);
+            ensure!(
+                min_providers >= 1 && min_providers <= MAX_WEATHER_PROVIDERS,
+                Error::<T>::InvalidProviderAgreement
+            );
+            ensure!(
+                tolerance_mm <= MAX_RAINFALL_MM,
+                Error::<T>::InvalidProviderAgreement
+            );
 
-            Self::deposit_event(Event::RollingSumUpdated {
-                location_id: market_id,
-                rolling_sum_mm: actual_rolling_sum,
+            ProviderAgreement::<T>::put(ProviderAgreementParams {
+                min_providers,
+                tolerance_mm,
             });
 
-            log::info!(
-                target: "prmx-oracle",
-                "✅ Stored {} hourly buckets for market {} (removed {} old), rolling sum = {:.1}mm",
-                buckets_stored,
-                market_id,
-                removed,
-                actual_rolling_sum as f64 / 10.0
-            );
-
-            // Clear any pending fetch request
-            if PendingFetchRequests::<T>::contains_key(market_id) {
-                PendingFetchRequests::<T>::remove(market_id);
-            }
+            Self::deposit_event(Event::ProviderAgreementUpdated {
+                min_providers,
+                tolerance_mm,
+            });
 
             Ok(())
         }
@@ -1265,7 +2598,7 @@ pub mod pallet {
         /// Useful when the node has been offline and missed regular polling.
         /// This queues fetch requests for all registered markets.
         #[pallet::call_index(9)]
-        #[pallet::weight(Weight::from_parts(50_000, 0))]
+        #[pallet::weight(T::WeightInfo::request_rainfall_fetch_all(pallet_prmx_markets::NextMarketId::<T>::get() as u32))]
         pub fn request_rainfall_fetch_all(
             origin: OriginFor<T>,
         ) -> DispatchResult {
@@ -1276,7 +2609,7 @@ pub mod pallet {
             
             // Get current block for pending request storage
             let current_block = frame_system::Pallet::<T>::block_number();
-            let now: u64 = Self::current_timestamp();
+            let now: u64 = T::TimeProvider::now_secs();
             
             let mut queued_count = 0u32;
             for market_id in 0..next_market_id {
@@ -1317,15 +2650,18 @@ pub mod pallet {
         /// Submit a V2 oracle report for a policy.
         /// 
         /// Only authorized V2 reporters can call this.
-        /// This forwards the report to the policy pallet for settlement.
+        /// The report is held for `V2ChallengePeriod` blocks, during which it can be
+        /// disputed. Undisputed reports are forwarded to the policy pallet afterwards.
         ///
         /// - `policy_id`: The V2 policy to report on.
         /// - `outcome`: Triggered or MaturedNoEvent.
         /// - `observed_at`: Timestamp when the outcome was determined.
-        /// - `cumulative_mm`: Cumulative rainfall in tenths of mm.
+        /// - `cumulative_mm`: Cumulative rainfall (for `Snowfall24h` policies the highest
+        ///   24-hour snowfall, for `FloodComposite` the highest 24-hour rainfall at river
+        ///   flood stage) in tenths of mm.
         /// - `evidence_hash`: SHA256 hash of off-chain evidence JSON.
         #[pallet::call_index(10)]
-        #[pallet::weight(Weight::from_parts(100_000, 0))]
+        #[pallet::weight(T::WeightInfo::submit_v2_report())]
         pub fn submit_v2_report(
             origin: OriginFor<T>,
             policy_id: PolicyId,
@@ -1341,17 +2677,22 @@ pub mod pallet {
                 AuthorizedV2Reporters::<T>::get(&who),
                 Error::<T>::NotAuthorizedV2Reporter
             );
+            Self::ensure_submissions_not_paused()?;
 
-            // Verify no report already submitted for this policy (idempotency)
+            // Verify no report already pending or finalized for this policy (idempotency)
             ensure!(
-                !V2FinalReportByPolicy::<T>::contains_key(policy_id),
+                !V2FinalReportByPolicy::<T>::contains_key(policy_id)
+                    && !V2FinalReportHashes::<T>::contains_key(policy_id)
+                    && !PendingV2Reports::<T>::contains_key(policy_id),
                 Error::<T>::V2ReportAlreadySubmitted
             );
 
             // Get current timestamp
-            let now = Self::current_timestamp();
+            let now = T::TimeProvider::now_secs();
+            let challenge_ends_at =
+                frame_system::Pallet::<T>::block_number() + T::V2ChallengePeriod::get();
 
-            // Store the report in oracle storage (immutable record)
+            // Hold the report until the challenge period ends
             let report = prmx_primitives::V2Report {
                 outcome: outcome.clone(),
                 observed_at,
@@ -1360,32 +2701,32 @@ pub mod pallet {
                 reporter: who.clone(),
                 submitted_at: now,
             };
-            V2FinalReportByPolicy::<T>::insert(policy_id, report);
-
-            // Forward to policy pallet for actual settlement
-            // The policy pallet will validate the report and perform settlement
-            T::PolicySettlement::settle_v2_policy(
+            PendingV2Reports::<T>::insert(
                 policy_id,
-                outcome.clone(),
-                observed_at,
-                cumulative_mm,
-                evidence_hash,
-            )?;
+                PendingV2Report {
+                    report,
+                    challenge_ends_at,
+                },
+            );
+            let queue_index = NextV2FinalizationIndex::<T>::get();
+            V2FinalizationQueue::<T>::insert(queue_index, (policy_id, challenge_ends_at));
+            NextV2FinalizationIndex::<T>::put(queue_index.saturating_add(1));
 
-            // Emit event
-            Self::deposit_event(Event::V2ReportAccepted {
+            Self::deposit_event(Event::V2ReportPending {
                 policy_id,
-                outcome,
+                outcome: outcome.clone(),
                 cumulative_mm,
                 evidence_hash,
+                challenge_ends_at,
             });
 
             log::info!(
                 target: "prmx-oracle",
-                "✅ V2 report accepted for policy {}: {:?}, cumulative_mm={}",
+                "⏳ V2 report pending for policy {}: {:?}, cumulative_mm={}, challenge ends at block {:?}",
                 policy_id,
                 outcome,
-                cumulative_mm
+                cumulative_mm,
+                challenge_ends_at
             );
 
             Ok(())
@@ -1394,7 +2735,7 @@ pub mod pallet {
         /// Add an authorized V2 reporter.
         /// Only governance/root can call this.
         #[pallet::call_index(11)]
-        #[pallet::weight(Weight::from_parts(10_000, 0))]
+        #[pallet::weight(T::WeightInfo::add_v2_reporter())]
         pub fn add_v2_reporter(
             origin: OriginFor<T>,
             account: T::AccountId,
@@ -1411,7 +2752,7 @@ pub mod pallet {
         /// Remove an authorized V2 reporter.
         /// Only governance/root can call this.
         #[pallet::call_index(12)]
-        #[pallet::weight(Weight::from_parts(10_000, 0))]
+        #[pallet::weight(T::WeightInfo::remove_v2_reporter())]
         pub fn remove_v2_reporter(
             origin: OriginFor<T>,
             account: T::AccountId,
@@ -1425,1174 +2766,5384 @@ pub mod pallet {
             Ok(())
         }
 
-    }
-
-    // =========================================================================
-    //                          Internal Functions
-    // =========================================================================
-
-    impl<T: Config> Pallet<T> {
-        /// Get current timestamp from PolicySettlement trait (uses pallet_timestamp)
-        pub fn current_timestamp() -> u64 {
-            // Use the PolicySettlement trait to get the real blockchain timestamp
-            T::PolicySettlement::current_time()
-        }
-
-        /// Update rolling state after rainfall submission
-        /// Per oracle_design.md section 8.3
-        fn update_rolling_state(
-            location_id: LocationId,
-            idx: BucketIndex,
-            old_mm: Millimeters,
-            new_mm: Millimeters,
-            now: u64,
+        /// Dispute a pending V2 report during its challenge period.
+        /// Reserves `V2DisputeBond` from the caller, which is returned if the
+        /// dispute is upheld and slashed otherwise.
+        ///
+        /// - `policy_id`: The policy whose pending report is disputed.
+        /// - `counter_evidence_hash`: SHA256 hash of the disputer's counter evidence.
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::dispute_v2_report())]
+        pub fn dispute_v2_report(
+            origin: OriginFor<T>,
+            policy_id: PolicyId,
+            counter_evidence_hash: [u8; 32],
         ) -> DispatchResult {
-            let window_start_ts = now.saturating_sub(ROLLING_WINDOW_SECS);
+            let who = ensure_signed(origin)?;
 
-            let mut state = RollingState::<T>::get(location_id).unwrap_or(RollingWindowState {
-                last_bucket_index: idx,
-                oldest_bucket_index: idx,
-                rolling_sum_mm: 0,
-            });
+            let pending = PendingV2Reports::<T>::get(policy_id)
+                .ok_or(Error::<T>::V2ReportNotPending)?;
 
-            // Adjust sum by delta if bucket is within window
-            let bucket_ts = bucket_start_time(idx);
-            if bucket_ts >= window_start_ts {
-                let delta = new_mm as i64 - old_mm as i64;
-                let new_sum = (state.rolling_sum_mm as i64 + delta).max(0) as u32;
-                state.rolling_sum_mm = new_sum;
-            }
+            let current_block = frame_system::Pallet::<T>::block_number();
+            ensure!(
+                current_block <= pending.challenge_ends_at,
+                Error::<T>::ChallengePeriodEnded
+            );
+            ensure!(
+                !V2Disputes::<T>::contains_key(policy_id),
+                Error::<T>::V2ReportAlreadyDisputed
+            );
 
-            // If this is a newer bucket, update last_bucket_index and prune old buckets
-            if idx > state.last_bucket_index {
-                state.last_bucket_index = idx;
-                Self::prune_old_buckets(location_id, &mut state, window_start_ts);
-            }
+            let bond = T::V2DisputeBond::get();
+            T::Currency::reserve(&who, bond)?;
 
-            RollingState::<T>::insert(location_id, state.clone());
+            V2Disputes::<T>::insert(
+                policy_id,
+                V2Dispute {
+                    disputer: who.clone(),
+                    counter_evidence_hash,
+                    bond,
+                    disputed_at: current_block,
+                },
+            );
 
-            Self::deposit_event(Event::RollingSumUpdated {
-                location_id,
-                rolling_sum_mm: state.rolling_sum_mm,
+            Self::deposit_event(Event::V2ReportDisputed {
+                policy_id,
+                disputer: who,
+                counter_evidence_hash,
+                bond,
             });
 
+            log::info!(
+                target: "prmx-oracle",
+                "⚖️ V2 report for policy {} disputed at block {:?}",
+                policy_id,
+                current_block
+            );
+
             Ok(())
         }
 
-        /// Prune old buckets that fall outside the rolling window
-        /// Per oracle_design.md section 8.4
-        fn prune_old_buckets(
-            location_id: LocationId,
-            state: &mut RollingWindowState,
-            window_start_ts: u64,
-        ) {
-            let mut candidate_idx = state.oldest_bucket_index;
-
-            while bucket_start_time(candidate_idx) < window_start_ts
-                && candidate_idx <= state.last_bucket_index
-            {
-                if let Some(bucket) = RainBuckets::<T>::get(location_id, candidate_idx) {
-                    // Subtract from rolling sum
-                    state.rolling_sum_mm = state.rolling_sum_mm.saturating_sub(bucket.rainfall_mm);
-                    // Remove bucket
-                    RainBuckets::<T>::remove(location_id, candidate_idx);
-                }
-                candidate_idx = candidate_idx.saturating_add(1);
-            }
+        /// Resolve an open V2 dispute.
+        /// Only governance/root can call this.
+        ///
+        /// - `uphold`: true discards the report and returns the bond; false slashes
+        ///   the bond and settles the policy with the original report.
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::resolve_v2_dispute())]
+        pub fn resolve_v2_dispute(
+            origin: OriginFor<T>,
+            policy_id: PolicyId,
+            uphold: bool,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
 
-            state.oldest_bucket_index = candidate_idx;
+            Self::do_resolve_v2_dispute(policy_id, uphold)
         }
 
-        /// Calculate 24h rolling sum at a specific timestamp
-        /// Per oracle_design.md section 9.2
-        pub fn calculate_rolling_sum_at(location_id: LocationId, timestamp: u64) -> Millimeters {
-            let window_start = timestamp.saturating_sub(ROLLING_WINDOW_SECS);
-            let start_idx = bucket_index_for_timestamp(window_start);
-            let end_idx = bucket_index_for_timestamp(timestamp);
+        /// Vote on an open V2 dispute as an authorized V2 reporter.
+        /// The dispute resolves once `V2DisputeQuorum` votes agree on the outcome.
+        /// The original reporter and the disputer cannot vote.
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::vote_v2_dispute(MAX_V2_DISPUTE_VOTES))]
+        pub fn vote_v2_dispute(
+            origin: OriginFor<T>,
+            policy_id: PolicyId,
+            uphold: bool,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
 
-            let mut sum: u64 = 0;
-            for idx in start_idx..=end_idx {
-                if let Some(bucket) = RainBuckets::<T>::get(location_id, idx) {
-                    sum = sum.saturating_add(bucket.rainfall_mm as u64);
-                }
-            }
+            ensure!(
+                AuthorizedV2Reporters::<T>::get(&who),
+                Error::<T>::NotAuthorizedV2Reporter
+            );
 
-            // Cap at u32::MAX
-            sum.min(u32::MAX as u64) as u32
+            let dispute = V2Disputes::<T>::get(policy_id)
+                .ok_or(Error::<T>::V2DisputeNotFound)?;
+            let pending = PendingV2Reports::<T>::get(policy_id)
+                .ok_or(Error::<T>::V2ReportNotPending)?;
+
+            ensure!(
+                who != dispute.disputer && who != pending.report.reporter,
+                Error::<T>::DisputePartyCannotVote
+            );
+
+            let matching_votes = V2DisputeVotes::<T>::try_mutate(policy_id, |votes| {
+                ensure!(
+                    !votes.iter().any(|(voter, _)| *voter == who),
+                    Error::<T>::AlreadyVotedOnDispute
+                );
+                votes
+                    .try_push((who.clone(), uphold))
+                    .map_err(|_| Error::<T>::TooManyDisputeVotes)?;
+                Ok::<u32, Error<T>>(votes.iter().filter(|(_, v)| *v == uphold).count() as u32)
+            })?;
+
+            Self::deposit_event(Event::V2DisputeVoteCast {
+                policy_id,
+                voter: who,
+                uphold,
+            });
+
+            if matching_votes >= T::V2DisputeQuorum::get() {
+                Self::do_resolve_v2_dispute(policy_id, uphold)?;
+            }
+
+            Ok(())
+        }
+
+        /// Finalize an undisputed V2 report after its challenge period.
+        /// Callable by anyone; reports are also finalized automatically in `on_initialize`,
+        /// and this retries a report whose automatic finalization failed.
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::WeightInfo::finalize_v2_report())]
+        pub fn finalize_v2_report(
+            origin: OriginFor<T>,
+            policy_id: PolicyId,
+        ) -> DispatchResult {
+            let _ = ensure_signed(origin)?;
+
+            let pending = PendingV2Reports::<T>::get(policy_id)
+                .ok_or(Error::<T>::V2ReportNotPending)?;
+
+            ensure!(
+                frame_system::Pallet::<T>::block_number() > pending.challenge_ends_at,
+                Error::<T>::ChallengePeriodNotEnded
+            );
+            ensure!(
+                !V2Disputes::<T>::contains_key(policy_id),
+                Error::<T>::V2ReportDisputeOpen
+            );
+
+            Self::do_finalize_v2_report(policy_id)
+        }
+
+        /// Submit up to 24 hourly temperature readings from OCW.
+        /// Each entry is (epoch_time, temp_c_scaled) from the AccuWeather historical/24 endpoint.
+        #[pallet::call_index(20)]
+        #[pallet::weight(T::WeightInfo::submit_hourly_temperature_from_ocw(hourly_data.len() as u32))]
+        pub fn submit_hourly_temperature_from_ocw(
+            origin: OriginFor<T>,
+            market_id: MarketId,
+            hourly_data: BoundedVec<(u64, DeciCelsius), ConstU32<24>>,
+        ) -> DispatchResult {
+            let who = Self::provider_of_key(ensure_signed(origin)?);
+            Self::ensure_market_provider(&who, market_id)?;
+            Self::ensure_submissions_not_paused()?;
+
+            Self::do_submit_hourly_temperature(market_id, &hourly_data)
+        }
+
+        /// Submit up to 24 hourly wind gust readings from OCW.
+        /// Each entry is (epoch_time, gust_kmh_scaled) from the AccuWeather historical/24 endpoint.
+        #[pallet::call_index(21)]
+        #[pallet::weight(T::WeightInfo::submit_hourly_wind_gust_from_ocw(hourly_data.len() as u32))]
+        pub fn submit_hourly_wind_gust_from_ocw(
+            origin: OriginFor<T>,
+            market_id: MarketId,
+            hourly_data: BoundedVec<(u64, DeciKmh), ConstU32<24>>,
+        ) -> DispatchResult {
+            let who = Self::provider_of_key(ensure_signed(origin)?);
+            Self::ensure_market_provider(&who, market_id)?;
+            Self::ensure_submissions_not_paused()?;
+
+            Self::do_submit_hourly_wind_gust(market_id, &hourly_data)
+        }
+
+        /// Bond funds as an oracle provider. The amount is reserved and added to the
+        /// active bond; at least `OracleProviderBond` is required to submit data.
+        #[pallet::call_index(22)]
+        #[pallet::weight(T::WeightInfo::bond_as_oracle_provider())]
+        pub fn bond_as_oracle_provider(
+            origin: OriginFor<T>,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                OracleProviders::<T>::get(&who),
+                Error::<T>::NotOracleProvider
+            );
+            ensure!(!amount.is_zero(), Error::<T>::InvalidBondAmount);
+
+            T::Currency::reserve(&who, amount)?;
+
+            let total_bond = ProviderBonds::<T>::mutate(&who, |bond| {
+                *bond = bond.saturating_add(amount);
+                *bond
+            });
+
+            Self::deposit_event(Event::OracleProviderBonded {
+                account: who,
+                amount,
+                total_bond,
+            });
+
+            Ok(())
+        }
+
+        /// Start unbonding part of the active provider bond. The amount stays reserved
+        /// and slashable for `ProviderUnbondingPeriod` blocks. Unbonding again resets the timer.
+        #[pallet::call_index(23)]
+        #[pallet::weight(T::WeightInfo::unbond_oracle_provider())]
+        pub fn unbond_oracle_provider(
+            origin: OriginFor<T>,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(!amount.is_zero(), Error::<T>::InvalidBondAmount);
+
+            ProviderBonds::<T>::try_mutate(&who, |bond| -> DispatchResult {
+                ensure!(*bond >= amount, Error::<T>::UnbondExceedsBond);
+                *bond = bond.saturating_sub(amount);
+                Ok(())
+            })?;
+
+            let unlock_at =
+                frame_system::Pallet::<T>::block_number().saturating_add(T::ProviderUnbondingPeriod::get());
+
+            ProviderUnbondings::<T>::mutate(&who, |maybe_unbonding| {
+                let total = maybe_unbonding
+                    .as_ref()
+                    .map(|u| u.amount)
+                    .unwrap_or_else(Zero::zero)
+                    .saturating_add(amount);
+                *maybe_unbonding = Some(ProviderUnbonding {
+                    amount: total,
+                    unlock_at,
+                });
+            });
+
+            Self::deposit_event(Event::OracleProviderUnbonding {
+                account: who,
+                amount,
+                unlock_at,
+            });
+
+            Ok(())
+        }
+
+        /// Set the bucket interval and rolling window for a market (e.g. 1h/6h/24h windows).
+        /// Only callable by GovernanceOrigin.
+        ///
+        /// Changing the bucket interval re-indexes `RainBuckets`, so the market's legacy
+        /// buckets and rolling state are cleared and rebuilt from the next submission.
+        /// Changing the window re-slots the market's `HourlyBuckets` ring.
+        #[pallet::call_index(25)]
+        #[pallet::weight(T::WeightInfo::set_market_window((MAX_ROLLING_WINDOW_SECS / MIN_BUCKET_INTERVAL_SECS) as u32))]
+        pub fn set_market_window(
+            origin: OriginFor<T>,
+            market_id: MarketId,
+            bucket_interval_secs: u64,
+            rolling_window_secs: u64,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                bucket_interval_secs >= MIN_BUCKET_INTERVAL_SECS
+                    && rolling_window_secs >= BUCKET_INTERVAL_SECS
+                    && rolling_window_secs <= MAX_ROLLING_WINDOW_SECS
+                    && rolling_window_secs % 3600 == 0
+                    && rolling_window_secs % bucket_interval_secs == 0,
+                Error::<T>::InvalidMarketWindow
+            );
+
+            let (previous_interval, previous_window) = MarketLocationConfig::<T>::try_mutate(
+                market_id,
+                |maybe_info| -> Result<(u64, u64), DispatchError> {
+                    let info = maybe_info
+                        .as_mut()
+                        .ok_or(Error::<T>::MarketLocationNotConfigured)?;
+                    let previous = (info.bucket_interval_secs, info.rolling_window_secs);
+                    info.bucket_interval_secs = bucket_interval_secs;
+                    info.rolling_window_secs = rolling_window_secs;
+                    Ok(previous)
+                },
+            )?;
+
+            if previous_interval != bucket_interval_secs {
+                let _ = RainBuckets::<T>::clear_prefix(market_id, u32::MAX, None);
+                RollingState::<T>::remove(market_id);
+                RainBucketPruneCursor::<T>::remove(market_id);
+            }
+
+            let previous_slots = hourly_ring::ring_slots(previous_window);
+            if previous_slots != hourly_ring::ring_slots(rolling_window_secs) {
+                Self::rebuild_hourly_ring(market_id, previous_slots, T::TimeProvider::now_secs());
+            }
+
+            Self::deposit_event(Event::MarketWindowUpdated {
+                market_id,
+                bucket_interval_secs,
+                rolling_window_secs,
+            });
+
+            Ok(())
+        }
+
+        /// Schedule a historic backfill of missed rainfall hours between `from_ts` and `to_ts`.
+        /// Only callable by GovernanceOrigin. Replaces any backfill already pending for the market.
+        ///
+        /// The OCW fetches the range in pages of `BACKFILL_PAGE_HOURS` and submits each via
+        /// `submit_backfilled_rainfall_from_ocw`. The range must lie within the market's
+        /// acceptance window (rolling window plus one hour), since older buckets are pruned.
+        #[pallet::call_index(26)]
+        #[pallet::weight(T::WeightInfo::request_backfill())]
+        pub fn request_backfill(
+            origin: OriginFor<T>,
+            market_id: MarketId,
+            from_ts: u64,
+            to_ts: u64,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                MarketLocationConfig::<T>::contains_key(market_id),
+                Error::<T>::MarketLocationNotConfigured
+            );
+
+            let now = T::TimeProvider::now_secs();
+            let from_hour = from_ts / 3600;
+            let to_hour = to_ts / 3600;
+            ensure!(
+                from_ts <= to_ts
+                    && to_ts <= now
+                    && from_hour >= Self::oldest_acceptable_hour(market_id, now),
+                Error::<T>::InvalidBackfillRange
+            );
+
+            BackfillRequests::<T>::insert(
+                market_id,
+                BackfillRequest {
+                    next_hour: from_hour,
+                    last_hour: to_hour,
+                    requested_at: frame_system::Pallet::<T>::block_number(),
+                },
+            );
+
+            Self::deposit_event(Event::BackfillRequested {
+                market_id,
+                from_hour,
+                to_hour,
+            });
+
+            Ok(())
+        }
+
+        /// Submit one page of a historic backfill from the OCW.
+        /// The readings must cover every hour of the request's next page, in order;
+        /// they are aggregated like regular provider readings and the rolling state recomputed.
+        #[pallet::call_index(27)]
+        #[pallet::weight(T::WeightInfo::submit_backfilled_rainfall_from_ocw(hourly_data.len() as u32))]
+        pub fn submit_backfilled_rainfall_from_ocw(
+            origin: OriginFor<T>,
+            market_id: MarketId,
+            provider: WeatherProvider,
+            hourly_data: BoundedVec<(u64, Millimeters), ConstU32<24>>, // One backfill page
+        ) -> DispatchResult {
+            let who = Self::provider_of_key(ensure_signed(origin)?);
+            Self::ensure_market_provider(&who, market_id)?;
+            Self::ensure_submissions_not_paused()?;
+
+            Self::do_submit_backfilled_rainfall(&who, market_id, provider, &hourly_data)
+        }
+
+        /// Release provider funds whose unbonding period has ended.
+        #[pallet::call_index(24)]
+        #[pallet::weight(T::WeightInfo::withdraw_unbonded_provider_bond())]
+        pub fn withdraw_unbonded_provider_bond(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let unbonding =
+                ProviderUnbondings::<T>::get(&who).ok_or(Error::<T>::NothingToWithdraw)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= unbonding.unlock_at,
+                Error::<T>::UnbondingPeriodNotEnded
+            );
+
+            ProviderUnbondings::<T>::remove(&who);
+            T::Currency::unreserve(&who, unbonding.amount);
+
+            Self::deposit_event(Event::OracleProviderBondWithdrawn {
+                account: who,
+                amount: unbonding.amount,
+            });
+
+            Ok(())
+        }
+
+        /// Set how long after coverage end expired policies of a market wait before
+        /// automatic settlement. Only callable by GovernanceOrigin.
+        #[pallet::call_index(28)]
+        #[pallet::weight(T::WeightInfo::set_settlement_grace_period())]
+        pub fn set_settlement_grace_period(
+            origin: OriginFor<T>,
+            market_id: MarketId,
+            grace_period_secs: u64,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                MarketLocationConfig::<T>::contains_key(market_id),
+                Error::<T>::MarketLocationNotConfigured
+            );
+            ensure!(
+                grace_period_secs <= MAX_SETTLEMENT_GRACE_SECS,
+                Error::<T>::InvalidGracePeriod
+            );
+
+            SettlementGracePeriods::<T>::insert(market_id, grace_period_secs);
+
+            Self::deposit_event(Event::SettlementGracePeriodUpdated {
+                market_id,
+                grace_period_secs,
+            });
+
+            Ok(())
+        }
+
+        /// Settle an expired policy without waiting for the grace period or final-hour data.
+        /// Only callable by GovernanceOrigin.
+        ///
+        /// `observed_mm` overrides the maximum rolling sum used for tiered payouts; when
+        /// omitted, whatever the oracle holds for the coverage window is used.
+        #[pallet::call_index(29)]
+        #[pallet::weight(T::WeightInfo::force_settle_expired_policy())]
+        pub fn force_settle_expired_policy(
+            origin: OriginFor<T>,
+            policy_id: PolicyId,
+            event_occurred: bool,
+            observed_mm: Option<Millimeters>,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            let (_holder, _max_payout, coverage_start, coverage_end, market_id) =
+                T::PolicySettlement::get_policy_info(policy_id)
+                    .ok_or(Error::<T>::PolicyNotFound)?;
+            ensure!(
+                T::TimeProvider::now_secs() >= coverage_end,
+                Error::<T>::CoverageNotEnded
+            );
+
+            let observed_mm = match observed_mm {
+                Some(observed) => observed,
+                None => Self::max_rolling_sum_in_window(market_id, coverage_start, coverage_end)
+                    .unwrap_or(0),
+            };
+
+            // Whatever the oracle holds for the window is recorded alongside the override
+            let record = Self::observation_settlement_record(
+                SettlementReason::GovernanceForced,
+                market_id,
+                observed_mm,
+                &Self::coverage_observations(market_id, coverage_start, coverage_end),
+            );
+
+            let payout_amount =
+                T::PolicySettlement::settle_expired_policy(policy_id, event_occurred, record)?;
+            AwaitingSettlementData::<T>::remove(policy_id);
+
+            Self::deposit_event(Event::PolicyExpirationForceSettled {
+                policy_id,
+                event_occurred,
+                observed_mm,
+                payout_amount,
+            });
+
+            Ok(())
+        }
+
+        /// Record an oracle provider's liveness. Submitted unsigned by the OCW every
+        /// `BLOCKS_PER_HEARTBEAT` blocks; the payload signature is checked in `validate_unsigned`.
+        #[pallet::call_index(30)]
+        #[pallet::weight(T::WeightInfo::report_heartbeat())]
+        pub fn report_heartbeat(
+            origin: OriginFor<T>,
+            heartbeat: HeartbeatPayload<T::Public, BlockNumberFor<T>>,
+            _signature: T::Signature,
+        ) -> DispatchResult {
+            use sp_runtime::traits::IdentifyAccount;
+
+            ensure_none(origin)?;
+
+            let provider = Self::provider_of_key(heartbeat.public.into_account());
+            ensure!(OracleProviders::<T>::get(&provider), Error::<T>::NotOracleProvider);
+
+            ProviderLastHeartbeat::<T>::insert(&provider, heartbeat.block_number);
+
+            Ok(())
+        }
+
+        /// Set how many hours a market may go without rainfall data before its oracle
+        /// is considered stale and new policy sales are paused. Only callable by GovernanceOrigin.
+        #[pallet::call_index(31)]
+        #[pallet::weight(T::WeightInfo::set_oracle_stale_after_hours())]
+        pub fn set_oracle_stale_after_hours(origin: OriginFor<T>, hours: u32) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            ensure!(hours > 0, Error::<T>::InvalidStaleThreshold);
+
+            OracleStaleAfterHours::<T>::put(hours);
+
+            Self::deposit_event(Event::OracleStaleAfterHoursUpdated { hours });
+
+            Ok(())
+        }
+
+        /// Settle an expired policy once its grace period has passed and the final hour
+        /// of coverage has data. Anyone can call this; the caller is paid `KeeperReward`
+        /// from `KeeperRewardAccount` so settlement does not depend on the periodic
+        /// on-chain sweep alone.
+        #[pallet::call_index(32)]
+        #[pallet::weight(T::WeightInfo::poke_settlement())]
+        pub fn poke_settlement(origin: OriginFor<T>, policy_id: PolicyId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(
+                !T::Pause::is_paused(PausableOperation::Settlements),
+                Error::<T>::OperationPaused
+            );
+
+            let (_holder, _max_payout, coverage_start, coverage_end, market_id) =
+                T::PolicySettlement::get_policy_info(policy_id)
+                    .ok_or(Error::<T>::PolicyNotFound)?;
+            let now = T::TimeProvider::now_secs();
+            ensure!(now >= coverage_end, Error::<T>::CoverageNotEnded);
+            ensure!(
+                now >= coverage_end.saturating_add(Self::settlement_grace_secs(market_id)),
+                Error::<T>::GracePeriodNotEnded
+            );
+            ensure!(
+                Self::final_bucket_reported(market_id, coverage_end),
+                Error::<T>::FinalHourDataMissing
+            );
+
+            let (event_occurred, max_observed_mm) =
+                Self::expiry_outcome(market_id, coverage_start, coverage_end)
+                    .ok_or(Error::<T>::StrikeNotAvailable)?;
+
+            let record = Self::observation_settlement_record(
+                SettlementReason::CoverageExpired,
+                market_id,
+                max_observed_mm,
+                &Self::coverage_observations(market_id, coverage_start, coverage_end),
+            );
+
+            let payout_amount =
+                T::PolicySettlement::settle_expired_policy(policy_id, event_occurred, record)?;
+            AwaitingSettlementData::<T>::remove(policy_id);
+
+            let reward = Self::pay_keeper_reward(&who);
+
+            Self::deposit_event(Event::PolicySettlementPoked {
+                policy_id,
+                keeper: who,
+                event_occurred,
+                payout_amount,
+                reward,
+            });
+
+            Ok(())
+        }
+
+        /// Submit up to 24 hourly snowfall readings from OCW.
+        /// Each entry is (epoch_time, snowfall_mm_scaled) from the AccuWeather historical/24 endpoint.
+        #[pallet::call_index(33)]
+        #[pallet::weight(T::WeightInfo::submit_hourly_snowfall_from_ocw(hourly_data.len() as u32))]
+        pub fn submit_hourly_snowfall_from_ocw(
+            origin: OriginFor<T>,
+            market_id: MarketId,
+            hourly_data: BoundedVec<(u64, Millimeters), ConstU32<24>>,
+        ) -> DispatchResult {
+            let who = Self::provider_of_key(ensure_signed(origin)?);
+            Self::ensure_market_provider(&who, market_id)?;
+            Self::ensure_submissions_not_paused()?;
+
+            Self::do_submit_hourly_snowfall(market_id, &hourly_data)
+        }
+
+        /// Submit the current river gauge level of a market from OCW.
+        /// `epoch_time` is when the gauge API was read; `level_cm` is the level in
+        /// centimetres above the gauge datum.
+        #[pallet::call_index(34)]
+        #[pallet::weight(T::WeightInfo::submit_gauge_level_from_ocw())]
+        pub fn submit_gauge_level_from_ocw(
+            origin: OriginFor<T>,
+            market_id: MarketId,
+            epoch_time: u64,
+            level_cm: Centimeters,
+        ) -> DispatchResult {
+            let who = Self::provider_of_key(ensure_signed(origin)?);
+            Self::ensure_market_provider(&who, market_id)?;
+            Self::ensure_submissions_not_paused()?;
+
+            Self::do_submit_gauge_level(market_id, epoch_time, level_cm)
+        }
+
+        /// Set or remove the river/flood gauge API of a market. Only callable by
+        /// GovernanceOrigin.
+        ///
+        /// - `source`: URL, JSON path to the level, unit scale and flood stage, or
+        ///   `None` to stop fetching. Stored levels are kept until they age out.
+        #[pallet::call_index(35)]
+        #[pallet::weight(T::WeightInfo::set_gauge_source())]
+        pub fn set_gauge_source(
+            origin: OriginFor<T>,
+            market_id: MarketId,
+            source: Option<gauge::GaugeSource>,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                pallet_prmx_markets::Markets::<T>::contains_key(market_id),
+                Error::<T>::MarketNotFound
+            );
+
+            match source {
+                Some(source) => {
+                    ensure!(source.is_valid(), Error::<T>::InvalidGaugeSource);
+                    let flood_level_cm = source.flood_level_cm;
+                    GaugeSources::<T>::insert(market_id, source);
+                    Self::deposit_event(Event::GaugeSourceSet { market_id, flood_level_cm });
+                }
+                None => {
+                    ensure!(
+                        GaugeSources::<T>::contains_key(market_id),
+                        Error::<T>::GaugeSourceNotConfigured
+                    );
+                    GaugeSources::<T>::remove(market_id);
+                    Self::deposit_event(Event::GaugeSourceRemoved { market_id });
+                }
+            }
+
+            Ok(())
+        }
+
+        /// Restrict a market's data submissions to a set of oracle providers.
+        /// Only callable by GovernanceOrigin.
+        ///
+        /// - `market_id`: The market to configure.
+        /// - `providers`: Registered providers allowed to submit for the market. An
+        ///   empty list removes the restriction, so any bonded provider may submit.
+        #[pallet::call_index(36)]
+        #[pallet::weight(T::WeightInfo::set_provider_assignments(providers.len() as u32))]
+        pub fn set_provider_assignments(
+            origin: OriginFor<T>,
+            market_id: MarketId,
+            providers: BoundedVec<T::AccountId, ConstU32<MAX_PROVIDERS_PER_MARKET>>,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                pallet_prmx_markets::Markets::<T>::contains_key(market_id),
+                Error::<T>::MarketNotFound
+            );
+
+            for (i, provider) in providers.iter().enumerate() {
+                ensure!(OracleProviders::<T>::get(provider), Error::<T>::NotOracleProvider);
+                ensure!(
+                    !providers[..i].contains(provider),
+                    Error::<T>::ProviderAlreadyAssigned
+                );
+            }
+
+            if providers.is_empty() {
+                ProviderAssignments::<T>::remove(market_id);
+            } else {
+                ProviderAssignments::<T>::insert(market_id, &providers);
+            }
+
+            Self::deposit_event(Event::ProviderAssignmentsSet { market_id, providers });
+
+            Ok(())
+        }
+
+        /// Replace one of a market's assigned providers, e.g. to rotate out a
+        /// compromised key. Only callable by GovernanceOrigin.
+        ///
+        /// - `market_id`: The market to update.
+        /// - `outgoing`: Currently assigned provider to remove.
+        /// - `incoming`: Registered provider taking its place.
+        #[pallet::call_index(37)]
+        #[pallet::weight(T::WeightInfo::rotate_provider())]
+        pub fn rotate_provider(
+            origin: OriginFor<T>,
+            market_id: MarketId,
+            outgoing: T::AccountId,
+            incoming: T::AccountId,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            ensure!(OracleProviders::<T>::get(&incoming), Error::<T>::NotOracleProvider);
+
+            ProviderAssignments::<T>::try_mutate(market_id, |assigned| -> DispatchResult {
+                let providers = assigned.as_mut().ok_or(Error::<T>::ProviderNotAssignedToMarket)?;
+                ensure!(!providers.contains(&incoming), Error::<T>::ProviderAlreadyAssigned);
+                let slot = providers
+                    .iter_mut()
+                    .find(|provider| **provider == outgoing)
+                    .ok_or(Error::<T>::ProviderNotAssignedToMarket)?;
+                *slot = incoming.clone();
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::ProviderRotated { market_id, outgoing, incoming });
+
+            Ok(())
+        }
+
+        /// Replace a weather provider's stored hourly readings, e.g. after the
+        /// upstream source revised them. Plain resubmissions cannot move a stored
+        /// reading by more than the agreement tolerance; every correction made here
+        /// is recorded in a `BucketCorrected` event.
+        /// Only callable by a bonded oracle provider assigned to the market.
+        ///
+        /// - `corrections`: (epoch_time, rainfall_mm) for hours the provider already
+        ///   reported, inside the acceptance window.
+        #[pallet::call_index(38)]
+        #[pallet::weight(T::WeightInfo::correct_hourly_rainfall_from_ocw(corrections.len() as u32))]
+        pub fn correct_hourly_rainfall_from_ocw(
+            origin: OriginFor<T>,
+            market_id: MarketId,
+            provider: WeatherProvider,
+            corrections: BoundedVec<(u64, Millimeters), ConstU32<24>>,
+        ) -> DispatchResult {
+            let who = Self::provider_of_key(ensure_signed(origin)?);
+            Self::ensure_market_provider(&who, market_id)?;
+            Self::ensure_submissions_not_paused()?;
+
+            Self::do_correct_hourly_rainfall(&who, market_id, provider, &corrections)
+        }
+
+        /// Record an oracle provider's OCW health counters. Submitted unsigned by the OCW
+        /// every `BLOCKS_PER_HEALTH_REPORT` blocks; the payload signature and rate limit
+        /// are checked in `validate_unsigned`.
+        #[pallet::call_index(39)]
+        #[pallet::weight(T::WeightInfo::report_ocw_health())]
+        pub fn report_ocw_health(
+            origin: OriginFor<T>,
+            report: OcwHealthReport<T::Public, BlockNumberFor<T>>,
+            _signature: T::Signature,
+        ) -> DispatchResult {
+            use sp_runtime::traits::IdentifyAccount;
+
+            ensure_none(origin)?;
+
+            let provider = Self::provider_of_key(report.public.into_account());
+            ensure!(OracleProviders::<T>::get(&provider), Error::<T>::NotOracleProvider);
+
+            OcwHealth::<T>::insert(
+                &provider,
+                OcwHealthRecord {
+                    reported_at: report.block_number,
+                    counters: report.counters,
+                },
+            );
+
+            Self::deposit_event(Event::OcwHealthReported {
+                provider,
+                counters: report.counters,
+            });
+
+            Ok(())
+        }
+
+        /// Set how long a market's legacy rain buckets and trigger logs are kept before
+        /// `on_idle` archives and prunes them. `None` restores
+        /// `DEFAULT_DATA_RETENTION_SECS`. Only callable by GovernanceOrigin.
+        #[pallet::call_index(40)]
+        #[pallet::weight(T::WeightInfo::set_data_retention())]
+        pub fn set_data_retention(
+            origin: OriginFor<T>,
+            market_id: MarketId,
+            retention_secs: Option<u64>,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                pallet_prmx_markets::Markets::<T>::contains_key(market_id),
+                Error::<T>::MarketNotFound
+            );
+            if let Some(secs) = retention_secs {
+                ensure!(
+                    secs >= retention::MIN_DATA_RETENTION_SECS,
+                    Error::<T>::RetentionTooShort
+                );
+            }
+
+            DataRetention::<T>::set(market_id, retention_secs);
+
+            Self::deposit_event(Event::DataRetentionUpdated {
+                market_id,
+                retention_secs: retention_secs
+                    .unwrap_or(retention::DEFAULT_DATA_RETENTION_SECS),
+            });
+
+            Ok(())
+        }
+
+        /// Attest hourly observations fetched by an oracle authority's OCW. Submitted
+        /// unsigned after each fetch; the payload signature is checked in
+        /// `validate_unsigned`. Once `MirrorQuorum` assigned providers attest the same
+        /// observations they are ingested and written to offchain indexing.
+        #[pallet::call_index(41)]
+        #[pallet::weight(T::WeightInfo::mirror_observations(mirror.observations.len() as u32))]
+        pub fn mirror_observations(
+            origin: OriginFor<T>,
+            mirror: ObservationMirror<T::Public, BlockNumberFor<T>>,
+            _signature: T::Signature,
+        ) -> DispatchResult {
+            use sp_runtime::traits::IdentifyAccount;
+
+            ensure_none(origin)?;
+
+            let attester = Self::provider_of_key(mirror.public.into_account());
+            Self::ensure_market_provider(&attester, mirror.market_id)?;
+            Self::ensure_submissions_not_paused()?;
+
+            Self::do_mirror_observations(
+                attester,
+                mirror.market_id,
+                mirror.provider,
+                &mirror.observations,
+            )
+        }
+
+        /// Set how many authorities must attest the same gossiped observations before
+        /// they are applied. Only callable by GovernanceOrigin.
+        #[pallet::call_index(42)]
+        #[pallet::weight(T::WeightInfo::set_mirror_quorum())]
+        pub fn set_mirror_quorum(origin: OriginFor<T>, quorum: u32) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                quorum >= 1 && quorum <= gossip::MAX_MIRROR_ATTESTERS,
+                Error::<T>::InvalidMirrorQuorum
+            );
+
+            MirrorQuorum::<T>::put(quorum);
+
+            Self::deposit_event(Event::MirrorQuorumUpdated { quorum });
+
+            Ok(())
+        }
+
+        /// Submit the OCW feasibility check of a market listing proposal: whether
+        /// its coordinates resolve to an AccuWeather location, and how many of the
+        /// last 24 hourly observations that location has.
+        #[pallet::call_index(43)]
+        #[pallet::weight(T::WeightInfo::submit_listing_feasibility())]
+        pub fn submit_listing_feasibility(
+            origin: OriginFor<T>,
+            proposal_id: ListingProposalId,
+            feasibility: ListingFeasibility,
+        ) -> DispatchResult {
+            let who = Self::provider_of_key(ensure_signed(origin)?);
+            Self::ensure_bonded_provider(&who)?;
+            Self::ensure_submissions_not_paused()?;
+
+            ensure!(feasibility.is_checked(), Error::<T>::InvalidListingFeasibility);
+            ensure!(
+                PendingListingChecks::<T>::contains_key(proposal_id),
+                Error::<T>::NoPendingListingCheck
+            );
+
+            T::MarketsApi::record_listing_feasibility(proposal_id, feasibility)?;
+            PendingListingChecks::<T>::remove(proposal_id);
+
+            Self::deposit_event(Event::ListingFeasibilitySubmitted {
+                proposal_id,
+                provider: who,
+                feasibility,
+            });
+
+            Ok(())
+        }
+
+        /// Configure or remove the secondary rainfall API of a market. Only callable
+        /// by GovernanceOrigin.
+        ///
+        /// - `source`: provider, endpoint template, field mapping, time format and
+        ///   unit, or `None` to fall back to the built-in fetchers. A source for
+        ///   Open-Meteo, NOAA or Meteostat replaces that fetcher for the market.
+        #[pallet::call_index(44)]
+        #[pallet::weight(T::WeightInfo::set_market_data_source())]
+        pub fn set_market_data_source(
+            origin: OriginFor<T>,
+            market_id: MarketId,
+            source: Option<data_source::DataSourceConfig>,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                pallet_prmx_markets::Markets::<T>::contains_key(market_id),
+                Error::<T>::MarketNotFound
+            );
+
+            match source {
+                Some(source) => {
+                    ensure!(source.is_valid(), Error::<T>::InvalidDataSource);
+                    let provider = source.provider;
+                    MarketDataSources::<T>::insert(market_id, source);
+                    Self::deposit_event(Event::MarketDataSourceSet { market_id, provider });
+                }
+                None => {
+                    ensure!(
+                        MarketDataSources::<T>::contains_key(market_id),
+                        Error::<T>::DataSourceNotConfigured
+                    );
+                    MarketDataSources::<T>::remove(market_id);
+                    Self::deposit_event(Event::MarketDataSourceRemoved { market_id });
+                }
+            }
+
+            Ok(())
+        }
+
+        /// Bind the `orcl` key the caller's OCW signs with to the caller's provider
+        /// account, replacing any key registered before (see `keys`).
+        ///
+        /// - `key`: Public key from the OCW node's keystore.
+        /// - `proof`: The key's signature of `keys::ownership_message(caller)`.
+        #[pallet::call_index(45)]
+        #[pallet::weight(T::WeightInfo::register_oracle_keys())]
+        pub fn register_oracle_keys(
+            origin: OriginFor<T>,
+            key: T::Public,
+            proof: T::Signature,
+        ) -> DispatchResult {
+            use frame_system::offchain::AppCrypto;
+            use sp_runtime::traits::IdentifyAccount;
+
+            let who = ensure_signed(origin)?;
+            ensure!(OracleProviders::<T>::get(&who), Error::<T>::NotOracleProvider);
+            ensure!(
+                <T::AuthorityId as AppCrypto<T::Public, T::Signature>>::verify(
+                    &keys::ownership_message(&who),
+                    key.clone(),
+                    proof,
+                ),
+                Error::<T>::InvalidKeyOwnershipProof
+            );
+
+            let key = key.into_account();
+            ensure!(
+                key == who || !OracleProviders::<T>::get(&key),
+                Error::<T>::OracleKeyInUse
+            );
+            ensure!(
+                KeyOwners::<T>::get(&key).map_or(true, |owner| owner == who),
+                Error::<T>::OracleKeyInUse
+            );
+
+            let replaced = ProviderKeys::<T>::get(&who).filter(|old| *old != key);
+            if let Some(old) = &replaced {
+                KeyOwners::<T>::remove(old);
+            }
+            ProviderKeys::<T>::insert(&who, &key);
+            KeyOwners::<T>::insert(&key, &who);
+
+            Self::deposit_event(Event::OracleKeysRegistered { provider: who, key, replaced });
+
+            Ok(())
+        }
+
+        /// Unbind the caller's registered OCW key. Its submissions are rejected from
+        /// then on unless the key's own account is a provider.
+        #[pallet::call_index(46)]
+        #[pallet::weight(T::WeightInfo::purge_oracle_keys())]
+        pub fn purge_oracle_keys(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(ProviderKeys::<T>::contains_key(&who), Error::<T>::NoOracleKeys);
+
+            Self::unbind_oracle_keys(&who);
+
+            Ok(())
+        }
+    }
+
+    // =========================================================================
+    //                          Internal Functions
+    // =========================================================================
+
+    impl<T: Config> Pallet<T> {
+        /// (bucket_interval_secs, rolling_window_secs) for a market.
+        /// Unbound markets use the global defaults.
+        pub fn market_window(location_id: LocationId) -> (u64, u64) {
+            MarketLocationConfig::<T>::get(location_id)
+                .map(|info| (info.bucket_interval_secs, info.rolling_window_secs))
+                .unwrap_or((BUCKET_INTERVAL_SECS, ROLLING_WINDOW_SECS))
+        }
+
+        /// Reject oracle data and report submissions while governance has paused them
+        fn ensure_submissions_not_paused() -> DispatchResult {
+            ensure!(
+                !T::Pause::is_paused(PausableOperation::OracleSubmissions),
+                Error::<T>::OperationPaused
+            );
+            Ok(())
+        }
+
+        /// Settlement grace period for a market, falling back to the default
+        pub fn settlement_grace_secs(market_id: MarketId) -> u64 {
+            SettlementGracePeriods::<T>::get(market_id).unwrap_or(DEFAULT_SETTLEMENT_GRACE_SECS)
+        }
+
+        /// Whether rainfall for the last bucket before `coverage_end` has been recorded
+        pub fn final_bucket_reported(market_id: MarketId, coverage_end: u64) -> bool {
+            let (interval_secs, _) = Self::market_window(market_id);
+            let final_idx = bucket_index_with_interval(coverage_end.saturating_sub(1), interval_secs);
+            RollingState::<T>::get(market_id)
+                .map(|state| state.last_bucket_index >= final_idx)
+                .unwrap_or(false)
+        }
+
+        /// Whether the strike was reached during coverage, and the maximum rolling sum
+        /// observed (used for tiered payouts). `None` if the market has no strike value.
+        pub fn expiry_outcome(
+            market_id: MarketId,
+            coverage_start: u64,
+            coverage_end: u64,
+        ) -> Option<(bool, Millimeters)> {
+            let strike_mm = T::MarketsApi::strike_value(market_id).ok()?;
+            let max_observed_mm =
+                Self::max_rolling_sum_in_window(market_id, coverage_start, coverage_end)
+                    .unwrap_or(0);
+            Some((max_observed_mm >= strike_mm, max_observed_mm))
+        }
+
+        /// Outcome the automatic settlement would reach for a policy at `at` (unix
+        /// seconds) from the oracle data currently on chain, for the
+        /// `PrmxSettlementApi` runtime API. Nothing is written.
+        ///
+        /// The trigger check samples the rolling sum from coverage start up to `at` at
+        /// the market's interval, as `check_exceeded_threshold_in_window` does; expiry
+        /// follows `check_and_settle_expired_policies`, including the grace period and
+        /// the final-hour data requirement.
+        pub fn simulate_settlement(
+            policy_id: PolicyId,
+            at: u64,
+        ) -> Option<prmx_primitives::SettlementSimulation<u128>> {
+            use prmx_primitives::SimulatedSettlementPath;
+
+            let (_holder, _max_payout, coverage_start, coverage_end, market_id) =
+                T::PolicySettlement::get_policy_info(policy_id)?;
+            let strike_mm = T::MarketsApi::strike_value(market_id).ok()?;
+            let trigger_threshold_mm =
+                T::PolicySettlement::early_trigger_threshold(policy_id, strike_mm);
+            let (interval_secs, _) = Self::market_window(market_id);
+
+            // First sample at which the periodic trigger check would settle the policy
+            let mut triggered_at = None;
+            let mut observed_mm: Millimeters = 0;
+            let mut t = coverage_start;
+            while t <= coverage_end.min(at) {
+                let sum = Self::calculate_rolling_sum_at(market_id, t);
+                observed_mm = observed_mm.max(sum);
+                if sum >= strike_mm && sum >= trigger_threshold_mm {
+                    observed_mm = sum;
+                    triggered_at = Some(t);
+                    break;
+                }
+                t = t.saturating_add(interval_secs);
+            }
+
+            let grace_ends = coverage_end.saturating_add(Self::settlement_grace_secs(market_id));
+            let (path, event_occurred) = if triggered_at.is_some() {
+                (SimulatedSettlementPath::ThresholdTriggered, true)
+            } else if at <= coverage_end {
+                (SimulatedSettlementPath::NotDue, false)
+            } else if at < grace_ends {
+                (SimulatedSettlementPath::GracePeriod, false)
+            } else if !Self::final_bucket_reported(market_id, coverage_end) {
+                (SimulatedSettlementPath::AwaitingData, false)
+            } else {
+                (SimulatedSettlementPath::CoverageExpired, observed_mm >= strike_mm)
+            };
+
+            let settles = matches!(
+                path,
+                SimulatedSettlementPath::ThresholdTriggered
+                    | SimulatedSettlementPath::CoverageExpired
+            );
+            let (path, (payout, paid_to_positions, returned_to_lps)) =
+                match T::PolicySettlement::preview_settlement_payout(
+                    policy_id,
+                    event_occurred,
+                    Some(observed_mm),
+                ) {
+                    None => (SimulatedSettlementPath::Closed, (0, 0, 0)),
+                    Some(preview) if settles => (path, preview),
+                    Some(_) => (path, (0, 0, 0)),
+                };
+
+            Some(prmx_primitives::SettlementSimulation {
+                path,
+                event_occurred: event_occurred && path != SimulatedSettlementPath::Closed,
+                observed_mm,
+                strike_mm,
+                trigger_threshold_mm,
+                triggered_at,
+                payout,
+                paid_to_positions,
+                returned_to_lps,
+            })
+        }
+
+        /// Pay the keeper reward to `keeper`. Settlement does not fail if the reward
+        /// account cannot pay; the reward is skipped and zero is returned.
+        fn pay_keeper_reward(keeper: &T::AccountId) -> BalanceOf<T> {
+            use frame_support::traits::ExistenceRequirement;
+
+            let reward = T::KeeperReward::get();
+            if reward.is_zero() {
+                return reward;
+            }
+
+            match T::Currency::transfer(
+                &T::KeeperRewardAccount::get(),
+                keeper,
+                reward,
+                ExistenceRequirement::KeepAlive,
+            ) {
+                Ok(()) => reward,
+                Err(e) => {
+                    log::warn!(
+                        target: "prmx-oracle",
+                        "⚠️ Keeper reward not paid: {:?}",
+                        e
+                    );
+                    Zero::zero()
+                }
+            }
+        }
+
+        /// Record that rainfall data arrived for a market, resuming sales if it was stale
+        fn note_market_data(market_id: MarketId) {
+            MarketLastDataAt::<T>::insert(market_id, T::TimeProvider::now_secs());
+            if StaleMarkets::<T>::take(market_id).is_some() {
+                log::info!(
+                    target: "prmx-oracle",
+                    "💚 Oracle data resumed for market {}",
+                    market_id
+                );
+                Self::deposit_event(Event::OracleRecovered { market_id });
+            }
+        }
+
+        /// Mark markets whose rainfall data is older than the stale threshold.
+        /// Markets that never received data are not tracked.
+        pub fn check_stale_markets() -> Weight {
+            let now = T::TimeProvider::now_secs();
+            let stale_after_secs = (OracleStaleAfterHours::<T>::get()
+                .unwrap_or(DEFAULT_ORACLE_STALE_HOURS) as u64)
+                .saturating_mul(3600);
+            let mut weight = Weight::from_parts(5_000, 0);
+
+            for (market_id, last_data_at) in MarketLastDataAt::<T>::iter() {
+                weight = weight.saturating_add(Weight::from_parts(10_000, 0));
+
+                // Archived markets receive no data by design
+                if now.saturating_sub(last_data_at) < stale_after_secs
+                    || StaleMarkets::<T>::contains_key(market_id)
+                    || !T::MarketsApi::is_oracle_active(market_id)
+                {
+                    continue;
+                }
+
+                log::warn!(
+                    target: "prmx-oracle",
+                    "⚠️ No oracle data for market {} since {}, pausing new policies",
+                    market_id,
+                    last_data_at
+                );
+                StaleMarkets::<T>::insert(market_id, last_data_at);
+                Self::deposit_event(Event::OracleStale { market_id, last_data_at });
+            }
+
+            weight
+        }
+
+        /// Schedule a fetch for markets without one and move each market's
+        /// `NextFetchDue` to the next hour once data for the due fetch arrived.
+        /// Archived markets are unscheduled; reopening one schedules it again.
+        pub fn schedule_rainfall_fetches() -> Weight {
+            let now = T::TimeProvider::now_secs();
+            let next_market_id = pallet_prmx_markets::NextMarketId::<T>::get();
+            let mut weight = T::DbWeight::get().reads(2);
+
+            for market_id in 0..next_market_id {
+                weight = weight.saturating_add(T::DbWeight::get().reads(1));
+                if !T::MarketsApi::is_oracle_active(market_id) {
+                    if NextFetchDue::<T>::take(market_id).is_some() {
+                        log::info!(
+                            target: "prmx-oracle",
+                            "🗄️ Market {} archived, rainfall fetches stopped",
+                            market_id
+                        );
+                        weight = weight.saturating_add(T::DbWeight::get().writes(1));
+                    }
+                    weight = weight.saturating_add(T::DbWeight::get().reads(1));
+                    continue;
+                }
+
+                weight = weight.saturating_add(T::DbWeight::get().reads(2));
+                let due = NextFetchDue::<T>::get(market_id);
+                let last_data_at = MarketLastDataAt::<T>::get(market_id);
+                if let Some(next_due) = fetch_schedule::next_fetch_due(due, last_data_at, now) {
+                    NextFetchDue::<T>::insert(market_id, next_due);
+                    weight = weight.saturating_add(T::DbWeight::get().writes(1));
+                }
+            }
+
+            weight
+        }
+
+        /// Retention window of a market's oracle data in seconds
+        pub fn retention_secs(market_id: MarketId) -> u64 {
+            DataRetention::<T>::get(market_id).unwrap_or(retention::DEFAULT_DATA_RETENTION_SECS)
+        }
+
+        /// Keep the market's prune cursor at or below a newly written rain bucket
+        fn note_rain_bucket(market_id: MarketId, idx: BucketIndex) {
+            RainBucketPruneCursor::<T>::mutate(market_id, |cursor| {
+                if cursor.map_or(true, |current| idx < current) {
+                    *cursor = Some(idx);
+                }
+            });
+        }
+
+        /// Archive and prune expired rain buckets, then expired trigger logs, within
+        /// `limit` and `MAX_PRUNE_STEPS_PER_BLOCK`. Returns the weight used.
+        pub fn prune_expired_data(limit: Weight) -> Weight {
+            let mut used = T::DbWeight::get().reads(1);
+            if !limit.all_gte(used) {
+                return Weight::zero();
+            }
+
+            let now = T::TimeProvider::now_secs();
+            let mut steps = retention::MAX_PRUNE_STEPS_PER_BLOCK;
+            Self::prune_rain_buckets(now, limit, &mut used, &mut steps);
+            Self::prune_trigger_logs(now, limit, &mut used, &mut steps);
+            Self::prune_v2_final_reports(now, limit, &mut used, &mut steps);
+
+            used
+        }
+
+        /// Walk each market's `RainBucketPruneCursor` forward over expired bucket
+        /// indices, archiving and removing the buckets found
+        fn prune_rain_buckets(now: u64, limit: Weight, used: &mut Weight, steps: &mut u32) {
+            let db = T::DbWeight::get();
+            // Retention, market window and archive chain reads; cursor and chain writes
+            let market_cost = db.reads_writes(3, 2);
+            let step_cost = db.reads_writes(1, 1);
+
+            let cursors: Vec<(MarketId, BucketIndex)> =
+                RainBucketPruneCursor::<T>::iter().take(*steps as usize).collect();
+
+            for (market_id, start) in cursors {
+                *used = used.saturating_add(db.reads(1));
+                *steps = steps.saturating_sub(1);
+                if *steps == 0
+                    || !limit.all_gte(used.saturating_add(market_cost).saturating_add(step_cost))
+                {
+                    break;
+                }
+                *used = used.saturating_add(market_cost);
+
+                let retention_secs = Self::retention_secs(market_id);
+                let (interval_secs, _) = Self::market_window(market_id);
+                let mut chain = ArchiveChains::<T>::get(market_id, ArchiveKind::RainBuckets);
+                let mut cursor = start;
+                let mut pruned: u32 = 0;
+
+                while *steps > 0
+                    && limit.all_gte(used.saturating_add(step_cost))
+                    && retention::is_expired(
+                        bucket_start_with_interval(cursor, interval_secs),
+                        retention_secs,
+                        now,
+                    )
+                {
+                    *used = used.saturating_add(step_cost);
+                    *steps -= 1;
+                    if let Some(bucket) = RainBuckets::<T>::take(market_id, cursor) {
+                        chain.append(&bucket.encode(), bucket.timestamp);
+                        pruned = pruned.saturating_add(1);
+                    }
+                    cursor = cursor.saturating_add(1);
+                }
+
+                if cursor != start {
+                    RainBucketPruneCursor::<T>::insert(market_id, cursor);
+                }
+                if pruned > 0 {
+                    let archive_hash = chain.hash;
+                    ArchiveChains::<T>::insert(market_id, ArchiveKind::RainBuckets, chain);
+                    Self::deposit_event(Event::OracleDataPruned {
+                        market_id,
+                        kind: ArchiveKind::RainBuckets,
+                        entries: pruned,
+                        archive_hash,
+                    });
+                }
+            }
+        }
+
+        /// Walk `TriggerLogPruneCursor` forward in trigger ID order, archiving and
+        /// removing expired logs. Stops at the first log still within retention.
+        fn prune_trigger_logs(now: u64, limit: Weight, used: &mut Weight, steps: &mut u32) {
+            let db = T::DbWeight::get();
+            // Cursor and next ID reads, cursor write
+            let overhead = db.reads_writes(2, 1);
+            // Log, retention, archive chain and index reads; log removal, chain and
+            // index writes
+            let step_cost = db.reads_writes(4, 3);
+            if *steps == 0 || !limit.all_gte(used.saturating_add(overhead)) {
+                return;
+            }
+            *used = used.saturating_add(overhead);
+
+            let start = TriggerLogPruneCursor::<T>::get();
+            let next_id = NextTriggerLogId::<T>::get();
+            let mut cursor = start;
+            let mut archived: Vec<(MarketId, ArchiveChain, u32)> = Vec::new();
+
+            while cursor < next_id && *steps > 0 && limit.all_gte(used.saturating_add(step_cost)) {
+                *used = used.saturating_add(step_cost);
+                *steps -= 1;
+
+                let Some(log) = ThresholdTriggerLogs::<T>::get(cursor) else {
+                    cursor = cursor.saturating_add(1);
+                    continue;
+                };
+                if !retention::is_expired(
+                    log.triggered_at,
+                    Self::retention_secs(log.market_id),
+                    now,
+                ) {
+                    break;
+                }
+
+                ThresholdTriggerLogs::<T>::remove(cursor);
+                TriggersByMarket::<T>::mutate(log.market_id, |ids| ids.retain(|id| *id != cursor));
+                let position = match archived.iter().position(|(id, _, _)| *id == log.market_id) {
+                    Some(position) => position,
+                    None => {
+                        let chain = ArchiveChains::<T>::get(
+                            log.market_id,
+                            ArchiveKind::ThresholdTriggerLogs,
+                        );
+                        archived.push((log.market_id, chain, 0));
+                        archived.len() - 1
+                    }
+                };
+                let (_, chain, entries) = &mut archived[position];
+                chain.append(&log.encode(), log.triggered_at);
+                *entries = entries.saturating_add(1);
+                cursor = cursor.saturating_add(1);
+            }
+
+            if cursor != start {
+                TriggerLogPruneCursor::<T>::put(cursor);
+            }
+            for (market_id, chain, entries) in archived {
+                let archive_hash = chain.hash;
+                ArchiveChains::<T>::insert(market_id, ArchiveKind::ThresholdTriggerLogs, chain);
+                Self::deposit_event(Event::OracleDataPruned {
+                    market_id,
+                    kind: ArchiveKind::ThresholdTriggerLogs,
+                    entries,
+                    archive_hash,
+                });
+            }
+        }
+
+        /// Walk `V2FinalReportPruneCursor` forward in finalization order, replacing
+        /// expired V2 final reports with their hash. Stops at the first report still
+        /// within retention.
+        fn prune_v2_final_reports(now: u64, limit: Weight, used: &mut Weight, steps: &mut u32) {
+            let db = T::DbWeight::get();
+            // Cursor, next index and retention reads, cursor write
+            let overhead = db.reads_writes(3, 1);
+            // Queue and report reads; queue and report removal, hash write
+            let step_cost = db.reads_writes(2, 3);
+            if *steps == 0 || !limit.all_gte(used.saturating_add(overhead)) {
+                return;
+            }
+            *used = used.saturating_add(overhead);
+
+            let start = V2FinalReportPruneCursor::<T>::get();
+            let next_index = NextV2FinalReportPruneIndex::<T>::get();
+            // V2 policies are only offered on the Manila market
+            let retention_secs = Self::retention_secs(prmx_primitives::MANILA_MARKET_ID);
+            let mut cursor = start;
+
+            while cursor < next_index && *steps > 0 && limit.all_gte(used.saturating_add(step_cost))
+            {
+                *used = used.saturating_add(step_cost);
+                *steps -= 1;
+
+                let report = V2FinalReportPruneQueue::<T>::get(cursor).and_then(|policy_id| {
+                    V2FinalReportByPolicy::<T>::get(policy_id).map(|report| (policy_id, report))
+                });
+                if let Some((policy_id, report)) = report {
+                    if !retention::is_expired(report.submitted_at, retention_secs, now) {
+                        break;
+                    }
+
+                    let report_hash = sp_io::hashing::blake2_256(&report.encode());
+                    V2FinalReportByPolicy::<T>::remove(policy_id);
+                    V2FinalReportHashes::<T>::insert(policy_id, report_hash);
+                    Self::deposit_event(Event::V2FinalReportPruned { policy_id, report_hash });
+                }
+                V2FinalReportPruneQueue::<T>::remove(cursor);
+                cursor = cursor.saturating_add(1);
+            }
+
+            if cursor != start {
+                V2FinalReportPruneCursor::<T>::put(cursor);
+            }
+        }
+
+        /// Store hourly readings from a weather provider, re-aggregate the affected
+        /// hours and recalculate the 24h rolling sum from agreed buckets only.
+        pub fn do_submit_provider_hourly_rainfall(
+            submitter: &T::AccountId,
+            market_id: MarketId,
+            provider: WeatherProvider,
+            hourly_data: &[(u64, Millimeters)],
+        ) -> DispatchResult {
+            // Validate market exists
+            ensure!(
+                pallet_prmx_markets::Markets::<T>::contains_key(market_id),
+                Error::<T>::MarketNotFound
+            );
+
+            let now = T::TimeProvider::now_secs();
+            let current_hour_index = now / 3600;
+            let oldest_acceptable_hour = Self::oldest_acceptable_hour(market_id, now);
+
+            log::info!(
+                target: "prmx-oracle",
+                "🌧️ OCW hourly rainfall: {} readings from {} for market {} (hours {} to {})",
+                hourly_data.len(),
+                provider.name(),
+                market_id,
+                oldest_acceptable_hour,
+                current_hour_index
+            );
+
+            let mut readings_stored = 0u32;
+            let mut buckets_agreed = 0u32;
+            let mut new_readings = 0u32;
+            let mut late_readings = 0u32;
+
+            for (epoch_time, rainfall_mm) in hourly_data.iter() {
+                let hour_index = *epoch_time / 3600;
+
+                // Skip buckets older than the window plus 1 hour buffer
+                if hour_index < oldest_acceptable_hour {
+                    log::debug!(
+                        target: "prmx-oracle",
+                        "⏭️ Skipping bucket {} (too old, oldest acceptable: {})",
+                        hour_index,
+                        oldest_acceptable_hour
+                    );
+                    continue;
+                }
+
+                // The hourly ring has no slot for hours that have not started yet
+                if hour_index > current_hour_index {
+                    continue;
+                }
+
+                // Sanity check
+                if *rainfall_mm > MAX_RAINFALL_MM {
+                    continue;
+                }
+
+                match Self::record_provider_reading(submitter, market_id, hour_index, provider, *rainfall_mm) {
+                    aggregation::Resubmission::New => {
+                        new_readings += 1;
+                        if scoring::is_late(hour_index, now) {
+                            late_readings += 1;
+                        }
+                    }
+                    aggregation::Resubmission::Matching => {}
+                    aggregation::Resubmission::Conflicting { .. } => continue,
+                }
+                readings_stored += 1;
+
+                if Self::aggregate_hourly_bucket(market_id, hour_index, now) {
+                    buckets_agreed += 1;
+                }
+            }
+
+            ProviderStats::<T>::mutate(submitter, |stats| {
+                stats.record_submissions(new_readings, late_readings, now)
+            });
+
+            let (actual_rolling_sum, bucket_count, removed) =
+                Self::refresh_hourly_rolling_state(market_id, now);
+
+            Self::deposit_event(Event::ProviderRainfallSubmitted {
+                market_id,
+                provider,
+                readings_stored,
+                buckets_agreed,
+            });
+
+            Self::deposit_event(Event::RollingSumUpdated {
+                location_id: market_id,
+                rolling_sum_mm: actual_rolling_sum,
+            });
+
+            log::info!(
+                target: "prmx-oracle",
+                "✅ Stored {} {} readings for market {} ({} agreed, removed {} old), rolling sum = {:.1}mm from {} buckets",
+                readings_stored,
+                provider.name(),
+                market_id,
+                buckets_agreed,
+                removed,
+                actual_rolling_sum as f64 / 10.0,
+                bucket_count
+            );
+
+            // Clear any pending fetch request
+            if PendingFetchRequests::<T>::contains_key(market_id) {
+                PendingFetchRequests::<T>::remove(market_id);
+            }
+
+            Ok(())
+        }
+
+        /// Oldest hour_index accepted for a market's hourly buckets. Data is accepted up to
+        /// one hour beyond the rolling window to account for timing differences between the
+        /// provider's observation time and chain processing time.
+        pub(crate) fn oldest_acceptable_hour(market_id: MarketId, now: u64) -> u64 {
+            let (_, window_secs) = Self::market_window(market_id);
+            (now / 3600).saturating_sub(window_secs / 3600 + 1)
+        }
+
+        /// Store this provider's first reading for the hour. A stored reading is
+        /// never overwritten here: a resubmission within the agreement tolerance
+        /// keeps it, a larger change is rejected with `HourlyOverwriteRejected`
+        /// and must go through `correct_hourly_rainfall_from_ocw`.
+        fn record_provider_reading(
+            submitter: &T::AccountId,
+            market_id: MarketId,
+            hour_index: u64,
+            provider: WeatherProvider,
+            mm: Millimeters,
+        ) -> aggregation::Resubmission {
+            let tolerance_mm = ProviderAgreement::<T>::get().tolerance_mm;
+            let outcome = ProviderHourlyReadings::<T>::mutate(market_id, hour_index, |readings| {
+                let stored = readings.iter().find(|r| r.provider == provider).map(|r| r.mm);
+                let outcome = aggregation::classify_resubmission(stored, mm, tolerance_mm);
+                if outcome == aggregation::Resubmission::New
+                    && readings
+                        .try_push(ProviderReading {
+                            provider,
+                            mm,
+                            submitter: submitter.clone(),
+                            slashed: false,
+                        })
+                        .is_err()
+                {
+                    // No room for another provider; nothing was stored
+                    return aggregation::Resubmission::Matching;
+                }
+                outcome
+            });
+
+            if let aggregation::Resubmission::Conflicting { stored_mm } = outcome {
+                log::warn!(
+                    target: "prmx-oracle",
+                    "🚫 Market {} hour {}: {} resubmitted {} over stored {} without a correction",
+                    market_id,
+                    hour_index,
+                    provider.name(),
+                    mm,
+                    stored_mm
+                );
+                Self::deposit_event(Event::HourlyOverwriteRejected {
+                    market_id,
+                    provider,
+                    hour_index,
+                    stored_mm,
+                    submitted_mm: mm,
+                    submitter: submitter.clone(),
+                });
+            }
+            outcome
+        }
+
+        /// Replace stored provider readings with corrected values, re-aggregate
+        /// their buckets and record each change in a `BucketCorrected` event.
+        /// Every hour must already hold a reading from `provider` and still be
+        /// inside the acceptance window.
+        pub fn do_correct_hourly_rainfall(
+            submitter: &T::AccountId,
+            market_id: MarketId,
+            provider: WeatherProvider,
+            corrections: &[(u64, Millimeters)],
+        ) -> DispatchResult {
+            ensure!(
+                pallet_prmx_markets::Markets::<T>::contains_key(market_id),
+                Error::<T>::MarketNotFound
+            );
+            ensure!(
+                corrections.iter().all(|(_, mm)| *mm <= MAX_RAINFALL_MM),
+                Error::<T>::InvalidRainfallValue
+            );
+
+            let now = T::TimeProvider::now_secs();
+            let oldest_acceptable_hour = Self::oldest_acceptable_hour(market_id, now);
+
+            for (epoch_time, corrected_mm) in corrections.iter() {
+                let hour_index = *epoch_time / 3600;
+                ensure!(
+                    hour_index >= oldest_acceptable_hour,
+                    Error::<T>::CorrectionOutsideWindow
+                );
+
+                let previous_mm = ProviderHourlyReadings::<T>::try_mutate(
+                    market_id,
+                    hour_index,
+                    |readings| -> Result<Millimeters, DispatchError> {
+                        let entry = readings
+                            .iter_mut()
+                            .find(|r| r.provider == provider)
+                            .ok_or(Error::<T>::NoReadingToCorrect)?;
+                        let previous_mm = entry.mm;
+                        entry.mm = *corrected_mm;
+                        entry.submitter = submitter.clone();
+                        Ok(previous_mm)
+                    },
+                )?;
+
+                Self::aggregate_hourly_bucket(market_id, hour_index, now);
+
+                Self::deposit_event(Event::BucketCorrected {
+                    market_id,
+                    provider,
+                    hour_index,
+                    previous_mm,
+                    corrected_mm: *corrected_mm,
+                    submitter: submitter.clone(),
+                });
+            }
+
+            let (rolling_sum_mm, _, _) = Self::refresh_hourly_rolling_state(market_id, now);
+            Self::deposit_event(Event::RollingSumUpdated {
+                location_id: market_id,
+                rolling_sum_mm,
+            });
+
+            Ok(())
+        }
+
+        /// Score the readings of an hour leaving the acceptance window against its
+        /// final consensus median. Hours without agreement between at least two
+        /// submitting accounts have no meaningful consensus and are not scored.
+        pub(crate) fn score_final_readings(
+            readings: &[ProviderReading<T>],
+            params: &ProviderAgreementParams,
+        ) {
+            let submitted: Vec<(T::AccountId, Millimeters, u32)> =
+                readings.iter().map(|r| (r.submitter.clone(), r.mm, 1)).collect();
+            let values: Vec<Millimeters> =
+                aggregation::per_submitter(&submitted).into_iter().map(|(mm, _)| mm).collect();
+            let Some(consensus) =
+                aggregation::aggregate_readings(&values, params.tolerance_mm, params.min_providers)
+            else {
+                return;
+            };
+            if consensus.agreeing_providers < 2 {
+                return;
+            }
+
+            for reading in readings {
+                ProviderStats::<T>::mutate(&reading.submitter, |stats| {
+                    stats.record_deviation(reading.mm, consensus.median, params.tolerance_mm)
+                });
+            }
+        }
+
+        /// Data quality score of an oracle provider account (see `scoring`)
+        pub fn provider_score(account: &T::AccountId) -> Permill {
+            ProviderStats::<T>::get(account).score(ProviderAgreement::<T>::get().tolerance_mm)
+        }
+
+        /// Submission statistics and score for the `PrmxOracleProviderApi` runtime API
+        pub fn provider_quality(account: &T::AccountId) -> Option<prmx_primitives::ProviderQuality> {
+            if !ProviderStats::<T>::contains_key(account) {
+                return None;
+            }
+            let stats = ProviderStats::<T>::get(account);
+
+            Some(prmx_primitives::ProviderQuality {
+                submissions: stats.submissions,
+                late_submissions: stats.late_submissions,
+                scored_readings: stats.scored_readings,
+                outlier_readings: stats.outlier_readings,
+                mean_deviation_mm: stats.mean_deviation_mm(),
+                last_submission_at: stats.last_submission_at,
+                score_ppm: Self::provider_score(account).deconstruct(),
+            })
+        }
+
+        /// Evict hourly data that left the acceptance window, take the rolling sum from
+        /// the `HourlyRing` running total and update the legacy `RollingState`.
+        /// Returns (rolling_sum_mm, bucket_count, buckets_removed).
+        fn refresh_hourly_rolling_state(market_id: MarketId, now: u64) -> (Millimeters, u32, u32) {
+            let (interval_secs, window_secs) = Self::market_window(market_id);
+            let slots = hourly_ring::ring_slots(window_secs);
+            // Use the same buffer as acceptance to avoid race conditions
+            let oldest_acceptable_hour = Self::oldest_acceptable_hour(market_id, now);
+
+            let mut ring = HourlyRing::<T>::get(market_id)
+                .unwrap_or_else(|| HourlyRingState::starting_at(oldest_acceptable_hour));
+            let removed = Self::evict_hourly_ring(market_id, &mut ring, oldest_acceptable_hour, slots);
+
+            // Only the rolling window itself counts towards the sum, not the buffer hour
+            let mut rolling_sum = ring.sum();
+            let mut bucket_count = ring.buckets;
+            if let Some(buffer) = Self::hourly_bucket(market_id, oldest_acceptable_hour) {
+                rolling_sum = rolling_sum.saturating_sub(buffer.mm);
+                bucket_count = bucket_count.saturating_sub(1);
+            }
+            HourlyRing::<T>::insert(market_id, ring);
+
+            // Update the legacy RollingState for backwards compatibility
+            let bucket_idx = bucket_index_with_interval(now, interval_secs);
+            let state = RollingWindowState {
+                last_bucket_index: bucket_idx,
+                oldest_bucket_index: bucket_idx.saturating_sub(window_secs / interval_secs),
+                rolling_sum_mm: rolling_sum,
+            };
+            RollingState::<T>::insert(market_id, state);
+            Self::note_market_data(market_id);
+
+            (rolling_sum, bucket_count, removed)
+        }
+
+        /// Evict every hour before `oldest_hour` from a market's ring: remove its bucket
+        /// and score and drop its provider readings, whose consensus is now final.
+        /// Returns the number of buckets removed.
+        fn evict_hourly_ring(
+            market_id: MarketId,
+            ring: &mut HourlyRingState,
+            oldest_hour: u64,
+            slots: u32,
+        ) -> u32 {
+            let agreement = ProviderAgreement::<T>::get();
+            let mut removed = 0u32;
+            for hour_idx in ring.eviction_range(oldest_hour, slots) {
+                let slot = hourly_ring::slot(hour_idx, slots);
+                if let Some(bucket) = HourlyBuckets::<T>::get(market_id, slot) {
+                    if bucket.hour_index == hour_idx {
+                        HourlyBuckets::<T>::remove(market_id, slot);
+                        ring.remove(bucket.mm);
+                        removed += 1;
+                    }
+                }
+
+                let readings = ProviderHourlyReadings::<T>::take(market_id, hour_idx);
+                if !readings.is_empty() {
+                    Self::score_final_readings(&readings, &agreement);
+                }
+            }
+            ring.evicted_before = ring.evicted_before.max(oldest_hour);
+            removed
+        }
+
+        /// Number of `HourlyBuckets` ring slots for a market's current window
+        fn hourly_ring_slots(market_id: MarketId) -> u32 {
+            let (_, window_secs) = Self::market_window(market_id);
+            hourly_ring::ring_slots(window_secs)
+        }
+
+        /// Agreed bucket for `hour_index`, if its ring slot still holds that hour
+        pub fn hourly_bucket(market_id: MarketId, hour_index: u64) -> Option<HourlyBucket> {
+            let slot = hourly_ring::slot(hour_index, Self::hourly_ring_slots(market_id));
+            HourlyBuckets::<T>::get(market_id, slot).filter(|bucket| bucket.hour_index == hour_index)
+        }
+
+        /// Store `bucket` in its ring slot and update the running sum. The slot's previous
+        /// bucket is either the same hour or one that already left the acceptance window.
+        pub(crate) fn put_hourly_bucket(market_id: MarketId, bucket: HourlyBucket, now: u64) {
+            let slot = hourly_ring::slot(bucket.hour_index, Self::hourly_ring_slots(market_id));
+            let mut ring = HourlyRing::<T>::get(market_id).unwrap_or_else(|| {
+                HourlyRingState::starting_at(Self::oldest_acceptable_hour(market_id, now))
+            });
+            if let Some(previous) = HourlyBuckets::<T>::get(market_id, slot) {
+                ring.remove(previous.mm);
+            }
+            ring.add(bucket.mm);
+            HourlyBuckets::<T>::insert(market_id, slot, bucket);
+            HourlyRing::<T>::insert(market_id, ring);
+        }
+
+        /// Remove the bucket for `hour_index` and take it out of the running sum
+        fn remove_hourly_bucket(market_id: MarketId, hour_index: u64) {
+            let slot = hourly_ring::slot(hour_index, Self::hourly_ring_slots(market_id));
+            let Some(bucket) = HourlyBuckets::<T>::get(market_id, slot) else {
+                return;
+            };
+            if bucket.hour_index != hour_index {
+                return;
+            }
+            HourlyBuckets::<T>::remove(market_id, slot);
+            HourlyRing::<T>::mutate(market_id, |ring| {
+                if let Some(ring) = ring {
+                    ring.remove(bucket.mm);
+                }
+            });
+        }
+
+        /// Re-slot a market's hourly buckets after its rolling window changed from one
+        /// with `previous_slots` ring slots. Hours outside the new acceptance window are
+        /// evicted first.
+        fn rebuild_hourly_ring(market_id: MarketId, previous_slots: u32, now: u64) {
+            let Some(mut ring) = HourlyRing::<T>::get(market_id) else {
+                return;
+            };
+            let oldest_hour = Self::oldest_acceptable_hour(market_id, now);
+            Self::evict_hourly_ring(market_id, &mut ring, oldest_hour, previous_slots);
+
+            let buckets: Vec<HourlyBucket> = HourlyBuckets::<T>::drain_prefix(market_id)
+                .map(|(_, bucket)| bucket)
+                .collect();
+            let slots = Self::hourly_ring_slots(market_id);
+            let mut rebuilt = HourlyRingState::starting_at(ring.evicted_before);
+            for bucket in buckets {
+                rebuilt.add(bucket.mm);
+                HourlyBuckets::<T>::insert(market_id, hourly_ring::slot(bucket.hour_index, slots), bucket);
+            }
+            HourlyRing::<T>::insert(market_id, rebuilt);
+        }
+
+        /// Hourly buckets inside the market's rolling window at `now`, in hour order.
+        /// These are the observations a threshold settlement is based on.
+        pub fn settlement_observations(market_id: MarketId, now: u64) -> Vec<(u64, Millimeters)> {
+            let (_, window_secs) = Self::market_window(market_id);
+            let oldest_hour = (now / 3600).saturating_sub(window_secs / 3600);
+
+            let mut observations: Vec<(u64, Millimeters)> = HourlyBuckets::<T>::iter_prefix_values(market_id)
+                .filter(|bucket| bucket.hour_index >= oldest_hour)
+                .map(|bucket| (bucket.hour_index, bucket.mm))
+                .collect();
+            observations.sort_by_key(|(hour_idx, _)| *hour_idx);
+            observations
+        }
+
+        /// Merkle root of a market's observations, folded in one hour at a time
+        pub fn observation_root(market_id: MarketId, observations: &[(u64, Millimeters)]) -> [u8; 32] {
+            let mut tree = merkle::ObservationTree::default();
+            for (hour_index, mm) in observations {
+                tree.push(merkle::observation_leaf(market_id, *hour_index, *mm));
+            }
+            tree.root()
+        }
+
+        /// Hourly observations of a market within a coverage window, oldest first
+        pub fn coverage_observations(
+            market_id: MarketId,
+            coverage_start: u64,
+            coverage_end: u64,
+        ) -> Vec<(u64, Millimeters)> {
+            let (first_hour, last_hour) = (coverage_start / 3600, coverage_end / 3600);
+
+            let mut observations: Vec<(u64, Millimeters)> = HourlyBuckets::<T>::iter_prefix_values(market_id)
+                .filter(|bucket| (first_hour..=last_hour).contains(&bucket.hour_index))
+                .map(|bucket| (bucket.hour_index, bucket.mm))
+                .collect();
+            observations.sort_by_key(|(hour_idx, _)| *hour_idx);
+            observations
+        }
+
+        /// Settlement record committing to a market's `observations`: their observation
+        /// root and the weather providers whose readings fed them
+        pub fn observation_settlement_record(
+            reason: SettlementReason,
+            market_id: MarketId,
+            observed_mm: Millimeters,
+            observations: &[(u64, Millimeters)],
+        ) -> SettlementRecord {
+            let providers = settlement_record::provider_set(observations.iter().flat_map(
+                |(hour_index, _)| {
+                    ProviderHourlyReadings::<T>::get(market_id, *hour_index)
+                        .into_iter()
+                        .map(|reading| reading.provider)
+                },
+            ));
+
+            SettlementRecord {
+                reason,
+                observed_mm: Some(observed_mm),
+                providers,
+                evidence_hash: Self::observation_root(market_id, observations),
+            }
+        }
+
+        /// Archive a trigger's observations in offchain storage so proofs can still be
+        /// built after the buckets are pruned
+        fn archive_trigger_observations(trigger_id: u64, observations: &[(u64, Millimeters)]) {
+            let mut key = OBSERVATION_ARCHIVE_PREFIX.to_vec();
+            key.extend_from_slice(&trigger_id.to_le_bytes());
+            sp_io::offchain_index::set(&key, &observations.encode());
+        }
+
+        /// Trigger log of a policy settled on a threshold trigger, if not yet pruned.
+        /// Scans the retained logs; meant for runtime API calls, not extrinsics.
+        pub fn trigger_log_of(policy_id: PolicyId) -> Option<ThresholdTriggerLog<T>> {
+            (TriggerLogPruneCursor::<T>::get()..NextTriggerLogId::<T>::get())
+                .filter_map(ThresholdTriggerLogs::<T>::get)
+                .find(|log| log.policy_id == policy_id)
+        }
+
+        /// Add a new trigger log to its market's `TriggersByMarket` index
+        pub(crate) fn index_trigger(market_id: MarketId, trigger_id: u64) {
+            TriggersByMarket::<T>::mutate(market_id, |ids| {
+                let mut list = core::mem::take(ids).into_inner();
+                trigger_index::push_bounded(
+                    &mut list,
+                    trigger_id,
+                    trigger_index::MAX_TRIGGERS_PER_MARKET as usize,
+                );
+                *ids = BoundedVec::truncate_from(list);
+            });
+        }
+
+        /// Page of a market's retained trigger logs, newest first, and the cursor of
+        /// the next page (see `trigger_index::page`)
+        pub fn trigger_logs_of_market(
+            market_id: MarketId,
+            cursor: Option<u64>,
+            limit: u32,
+        ) -> (Vec<ThresholdTriggerLog<T>>, Option<u64>) {
+            let ids = TriggersByMarket::<T>::get(market_id);
+            let (page, next) = trigger_index::page(&ids, cursor, limit);
+            let logs = page.into_iter().filter_map(ThresholdTriggerLogs::<T>::get).collect();
+            (logs, next)
+        }
+
+        /// Check that the hourly observation `(hour_index, mm)` was committed by a trigger log.
+        /// `proof` is built with `merkle::observation_proof` over the archived observations.
+        pub fn verify_observation_proof(
+            trigger_id: u64,
+            hour_index: u64,
+            mm: Millimeters,
+            proof: &[[u8; 32]],
+        ) -> bool {
+            ThresholdTriggerLogs::<T>::get(trigger_id).is_some_and(|log| {
+                merkle::verify_observation_proof(
+                    log.observation_root,
+                    log.market_id,
+                    hour_index,
+                    mm,
+                    proof,
+                )
+            })
+        }
+
+        /// Store one page of a historic backfill. The page must start at the request's
+        /// `next_hour` and cover every hour up to the end of the page without gaps.
+        pub fn do_submit_backfilled_rainfall(
+            submitter: &T::AccountId,
+            market_id: MarketId,
+            provider: WeatherProvider,
+            hourly_data: &[(u64, Millimeters)],
+        ) -> DispatchResult {
+            let mut request =
+                BackfillRequests::<T>::get(market_id).ok_or(Error::<T>::NoPendingBackfill)?;
+            let page_end = request.page_end();
+
+            ensure!(
+                backfill_page_is_continuous(hourly_data, request.next_hour, page_end),
+                Error::<T>::BackfillNotContinuous
+            );
+            ensure!(
+                hourly_data.iter().all(|(_, mm)| *mm <= MAX_RAINFALL_MM),
+                Error::<T>::InvalidRainfallValue
+            );
+
+            let now = T::TimeProvider::now_secs();
+            let oldest_acceptable_hour = Self::oldest_acceptable_hour(market_id, now);
+            let mut buckets_agreed = 0u32;
+            let mut new_readings = 0u32;
+            for (epoch_time, rainfall_mm) in hourly_data.iter() {
+                let hour_index = *epoch_time / 3600;
+                // Hours that left the acceptance window while the backfill was pending
+                // have already been evicted from the ring
+                if hour_index < oldest_acceptable_hour {
+                    continue;
+                }
+                match Self::record_provider_reading(submitter, market_id, hour_index, provider, *rainfall_mm) {
+                    aggregation::Resubmission::New => new_readings += 1,
+                    aggregation::Resubmission::Matching => {}
+                    aggregation::Resubmission::Conflicting { .. } => continue,
+                }
+                if Self::aggregate_hourly_bucket(market_id, hour_index, now) {
+                    buckets_agreed += 1;
+                }
+            }
+
+            // Backfill pages are historic by design, so none of them count as late
+            ProviderStats::<T>::mutate(submitter, |stats| {
+                stats.record_submissions(new_readings, 0, now)
+            });
+
+            let (rolling_sum_mm, bucket_count, _) =
+                Self::refresh_hourly_rolling_state(market_id, now);
+
+            Self::deposit_event(Event::BackfillPageSubmitted {
+                market_id,
+                provider,
+                from_hour: request.next_hour,
+                to_hour: page_end,
+                buckets_agreed,
+            });
+            Self::deposit_event(Event::RollingSumUpdated {
+                location_id: market_id,
+                rolling_sum_mm,
+            });
+
+            log::info!(
+                target: "prmx-oracle",
+                "⏪ Backfilled hours {}..={} for market {} from {} ({} agreed), rolling sum = {:.1}mm from {} buckets",
+                request.next_hour,
+                page_end,
+                market_id,
+                provider.name(),
+                buckets_agreed,
+                rolling_sum_mm as f64 / 10.0,
+                bucket_count
+            );
+
+            if page_end >= request.last_hour {
+                BackfillRequests::<T>::remove(market_id);
+                Self::deposit_event(Event::BackfillCompleted { market_id });
+            } else {
+                request.next_hour = page_end + 1;
+                BackfillRequests::<T>::insert(market_id, request);
+            }
+
+            Ok(())
+        }
+
+        /// Slash submitters whose reading for the hour lies further than
+        /// `ProviderSlashTolerance` from the quorum median. Each reading is slashed at most once.
+        fn slash_deviating_readings(market_id: MarketId, hour_index: u64, median_mm: Millimeters) {
+            let tolerance = T::ProviderSlashTolerance::get();
+            let mut offenders: Vec<(T::AccountId, Millimeters)> = Vec::new();
+
+            ProviderHourlyReadings::<T>::mutate(market_id, hour_index, |readings| {
+                for reading in readings.iter_mut() {
+                    if !reading.slashed && reading.mm.abs_diff(median_mm) > tolerance {
+                        reading.slashed = true;
+                        offenders.push((reading.submitter.clone(), reading.mm));
+                    }
+                }
+            });
+
+            for (account, reading_mm) in offenders {
+                Self::slash_provider(
+                    &account,
+                    ProviderSlashReason::Deviation {
+                        market_id,
+                        hour_index,
+                        reading_mm,
+                        median_mm,
+                    },
+                );
+            }
+        }
+
+        /// Ensure `who` is a registered oracle provider holding at least `OracleProviderBond`
+        pub fn ensure_bonded_provider(who: &T::AccountId) -> DispatchResult {
+            ensure!(
+                OracleProviders::<T>::get(who),
+                Error::<T>::NotOracleProvider
+            );
+            ensure!(
+                ProviderBonds::<T>::get(who) >= T::OracleProviderBond::get(),
+                Error::<T>::InsufficientProviderBond
+            );
+            Ok(())
+        }
+
+        /// Authorities that must attest the same gossiped observations
+        pub fn mirror_quorum() -> u32 {
+            MirrorQuorum::<T>::get().unwrap_or(gossip::DEFAULT_MIRROR_QUORUM)
+        }
+
+        /// Whether the observations with `digest` were the last ones to reach quorum
+        fn is_mirror_applied(market_id: MarketId, provider: WeatherProvider, digest: &[u8; 32]) -> bool {
+            MirroredDigests::<T>::get(market_id, provider).map_or(false, |(applied, _)| &applied == digest)
+        }
+
+        /// Whether `attester` already attested the pending observations with `digest`
+        fn has_attested(
+            market_id: MarketId,
+            provider: WeatherProvider,
+            digest: &[u8; 32],
+            attester: &T::AccountId,
+        ) -> bool {
+            MirrorVotes::<T>::get(market_id, provider)
+                .iter()
+                .any(|c| &c.digest == digest && c.attesters.contains(attester))
+        }
+
+        /// Count an authority's attestation of gossiped observations. At `MirrorQuorum`
+        /// the observations are ingested as a submission of the first attester, the
+        /// pending candidates are cleared and the set is written to offchain indexing.
+        pub fn do_mirror_observations(
+            attester: T::AccountId,
+            market_id: MarketId,
+            provider: WeatherProvider,
+            observations: &[(u64, Millimeters)],
+        ) -> DispatchResult {
+            let observations = gossip::normalize(observations);
+            ensure!(!observations.is_empty(), Error::<T>::EmptyMirror);
+
+            let digest = gossip::observations_digest(market_id, provider, &observations);
+            ensure!(
+                !Self::is_mirror_applied(market_id, provider, &digest),
+                Error::<T>::MirrorAlreadyApplied
+            );
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let mut candidates = MirrorVotes::<T>::get(market_id, provider);
+            let index = match candidates.iter().position(|c| c.digest == digest) {
+                Some(index) => index,
+                None => {
+                    if candidates.is_full() {
+                        candidates.remove(0);
+                    }
+                    candidates
+                        .try_push(MirrorCandidate {
+                            digest,
+                            first_seen: now,
+                            attesters: BoundedVec::default(),
+                        })
+                        .map_err(|_| Error::<T>::TooManyMirrorAttesters)?;
+                    candidates.len() - 1
+                }
+            };
+
+            let candidate = &mut candidates[index];
+            ensure!(!candidate.attesters.contains(&attester), Error::<T>::AlreadyAttested);
+            candidate
+                .attesters
+                .try_push(attester.clone())
+                .map_err(|_| Error::<T>::TooManyMirrorAttesters)?;
+            let attestations = candidate.attesters.len() as u32;
+
+            if attestations < Self::mirror_quorum() {
+                MirrorVotes::<T>::insert(market_id, provider, candidates);
+                Self::deposit_event(Event::ObservationsAttested {
+                    market_id,
+                    provider,
+                    attester,
+                    digest,
+                    attestations,
+                });
+                return Ok(());
+            }
+
+            let submitter = candidate.attesters[0].clone();
+            MirrorVotes::<T>::remove(market_id, provider);
+            MirroredDigests::<T>::insert(market_id, provider, (digest, now));
+
+            let hourly_data: Vec<(u64, Millimeters)> = observations
+                .iter()
+                .map(|(hour_index, mm)| (hour_index.saturating_mul(3600), *mm))
+                .collect();
+            Self::do_submit_provider_hourly_rainfall(&submitter, market_id, provider, &hourly_data)?;
+
+            sp_io::offchain_index::set(
+                &gossip::mirror_key(market_id, provider),
+                &observations.encode(),
+            );
+
+            Self::deposit_event(Event::ObservationsMirrored {
+                market_id,
+                provider,
+                digest,
+                attestations,
+            });
+
+            Ok(())
+        }
+
+        /// Ensure `who` is a bonded provider allowed to submit data for `market_id`
+        /// (see `ProviderAssignments`)
+        pub fn ensure_market_provider(who: &T::AccountId, market_id: MarketId) -> DispatchResult {
+            Self::ensure_bonded_provider(who)?;
+            if let Some(assigned) = ProviderAssignments::<T>::get(market_id) {
+                ensure!(assigned.contains(who), Error::<T>::ProviderNotAssignedToMarket);
+            }
+            Ok(())
+        }
+
+        /// Provider an OCW submission signed by `key` counts for: the owner of a
+        /// registered key, otherwise the key's own account
+        pub fn provider_of_key(key: T::AccountId) -> T::AccountId {
+            KeyOwners::<T>::get(&key).unwrap_or(key)
+        }
+
+        /// Remove a provider's registered OCW key, if any
+        pub(crate) fn unbind_oracle_keys(provider: &T::AccountId) {
+            if let Some(key) = ProviderKeys::<T>::take(provider) {
+                KeyOwners::<T>::remove(&key);
+                Self::deposit_event(Event::OracleKeysPurged { provider: provider.clone(), key });
+            }
+        }
+
+        /// Slash `ProviderSlashFraction` of a provider's active and unbonding stake.
+        /// The active bond is slashed first so unbonding cannot be used to dodge a penalty.
+        pub fn slash_provider(account: &T::AccountId, reason: ProviderSlashReason) {
+            let bonded = ProviderBonds::<T>::get(account);
+            let unbonding = ProviderUnbondings::<T>::get(account);
+            let unbonding_amount = unbonding.as_ref().map(|u| u.amount).unwrap_or_else(Zero::zero);
+
+            let amount = T::ProviderSlashFraction::get() * bonded.saturating_add(unbonding_amount);
+            if amount.is_zero() {
+                return;
+            }
+
+            let (_imbalance, unslashed) = T::Currency::slash_reserved(account, amount);
+            let slashed = amount.saturating_sub(unslashed);
+
+            let from_bond = slashed.min(bonded);
+            ProviderBonds::<T>::insert(account, bonded.saturating_sub(from_bond));
+
+            if let Some(mut unbonding) = unbonding {
+                unbonding.amount = unbonding.amount.saturating_sub(slashed.saturating_sub(from_bond));
+                if unbonding.amount.is_zero() {
+                    ProviderUnbondings::<T>::remove(account);
+                } else {
+                    ProviderUnbondings::<T>::insert(account, unbonding);
+                }
+            }
+
+            log::warn!(
+                target: "prmx-oracle",
+                "🔪 Slashed oracle provider {:?}: {:?} ({:?})",
+                account,
+                slashed,
+                reason
+            );
+
+            Self::deposit_event(Event::OracleProviderSlashed {
+                account: account.clone(),
+                amount: slashed,
+                reason,
+            });
+        }
+
+        /// Aggregate provider readings for one hour into `HourlyBuckets`, weighting
+        /// each reading by its submitter's data quality score.
+        /// Removes the bucket when providers do not reach the agreement quorum,
+        /// so settlement never uses an hour backed by a single outlier.
+        /// Returns true if the bucket was accepted.
+        fn aggregate_hourly_bucket(market_id: MarketId, hour_index: u64, now: u64) -> bool {
+            let params = ProviderAgreement::<T>::get();
+            let readings: Vec<(T::AccountId, Millimeters, u32)> =
+                ProviderHourlyReadings::<T>::get(market_id, hour_index)
+                    .iter()
+                    .map(|r| {
+                        let score = ProviderStats::<T>::get(&r.submitter).score(params.tolerance_mm);
+                        (r.submitter.clone(), r.mm, score.deconstruct())
+                    })
+                    .collect();
+            // Agreement is counted per submitting account, not per weather provider
+            let values = aggregation::per_submitter(&readings);
+
+            match aggregation::aggregate_weighted_readings(
+                &values,
+                params.tolerance_mm,
+                params.min_providers,
+            ) {
+                Some(result) => {
+                    // A median is only meaningful for slashing once several providers agree
+                    if result.agreeing_providers >= 2 {
+                        Self::slash_deviating_readings(market_id, hour_index, result.median);
+                    }
+
+                    let bucket = HourlyBucket {
+                        hour_index,
+                        mm: result.mm,
+                        fetched_at: now,
+                        source: if result.agreeing_providers > 1 { 2 } else { 1 },
+                    };
+                    Self::put_hourly_bucket(market_id, bucket, now);
+                    true
+                }
+                None => {
+                    log::debug!(
+                        target: "prmx-oracle",
+                        "⏳ Market {} hour {}: {} readings, below agreement quorum of {}",
+                        market_id,
+                        hour_index,
+                        values.len(),
+                        params.min_providers
+                    );
+                    Self::remove_hourly_bucket(market_id, hour_index);
+                    false
+                }
+            }
+        }
+
+        /// Update rolling state after rainfall submission
+        /// Per oracle_design.md section 8.3
+        fn update_rolling_state(
+            location_id: LocationId,
+            idx: BucketIndex,
+            old_mm: Millimeters,
+            new_mm: Millimeters,
+            now: u64,
+        ) -> DispatchResult {
+            let (interval_secs, window_secs) = Self::market_window(location_id);
+            let window_start_ts = now.saturating_sub(window_secs);
+
+            let mut state = RollingState::<T>::get(location_id).unwrap_or(RollingWindowState {
+                last_bucket_index: idx,
+                oldest_bucket_index: idx,
+                rolling_sum_mm: 0,
+            });
+
+            // Adjust sum by delta if bucket is within window
+            let bucket_ts = bucket_start_with_interval(idx, interval_secs);
+            if bucket_ts >= window_start_ts {
+                let delta = new_mm as i64 - old_mm as i64;
+                let new_sum = (state.rolling_sum_mm as i64 + delta).max(0) as u32;
+                state.rolling_sum_mm = new_sum;
+                // A late in-window bucket extends the window back to cover it
+                state.oldest_bucket_index = state.oldest_bucket_index.min(idx);
+            }
+
+            // If this is a newer bucket, update last_bucket_index and prune old buckets
+            if idx > state.last_bucket_index {
+                state.last_bucket_index = idx;
+                Self::prune_old_buckets(location_id, &mut state, window_start_ts, interval_secs);
+            }
+
+            RollingState::<T>::insert(location_id, state.clone());
+            Self::note_market_data(location_id);
+
+            Self::deposit_event(Event::RollingSumUpdated {
+                location_id,
+                rolling_sum_mm: state.rolling_sum_mm,
+            });
+
+            Ok(())
+        }
+
+        /// Prune old buckets that fall outside the rolling window
+        /// Per oracle_design.md section 8.4
+        fn prune_old_buckets(
+            location_id: LocationId,
+            state: &mut RollingWindowState,
+            window_start_ts: u64,
+            interval_secs: u64,
+        ) {
+            let mut candidate_idx = state.oldest_bucket_index;
+
+            while bucket_start_with_interval(candidate_idx, interval_secs) < window_start_ts
+                && candidate_idx <= state.last_bucket_index
+            {
+                if let Some(bucket) = RainBuckets::<T>::get(location_id, candidate_idx) {
+                    // Subtract from rolling sum
+                    state.rolling_sum_mm = state.rolling_sum_mm.saturating_sub(bucket.rainfall_mm);
+                    // Remove bucket
+                    RainBuckets::<T>::remove(location_id, candidate_idx);
+                }
+                candidate_idx = candidate_idx.saturating_add(1);
+            }
+
+            state.oldest_bucket_index = candidate_idx;
+        }
+
+        /// Calculate the market's rolling sum at a specific timestamp
+        /// Per oracle_design.md section 9.2
+        pub fn calculate_rolling_sum_at(location_id: LocationId, timestamp: u64) -> Millimeters {
+            let (interval_secs, window_secs) = Self::market_window(location_id);
+            let window_start = timestamp.saturating_sub(window_secs);
+            let start_idx = bucket_index_with_interval(window_start, interval_secs);
+            let end_idx = bucket_index_with_interval(timestamp, interval_secs);
+
+            let mut sum: u64 = 0;
+            for idx in start_idx..=end_idx {
+                if let Some(bucket) = RainBuckets::<T>::get(location_id, idx) {
+                    sum = sum.saturating_add(bucket.rainfall_mm as u64);
+                }
+            }
+
+            // Cap at u32::MAX
+            sum.min(u32::MAX as u64) as u32
+        }
+
+        /// Rolling sum over the market's window ending at `timestamp` for the `PrmxOracleApi`
+        /// runtime API. Uses the OCW `HourlyBuckets` when present, falling back to legacy `RainBuckets`.
+        pub fn rolling_sum_at(market_id: MarketId, timestamp: u64) -> Option<Millimeters> {
+            if !pallet_prmx_markets::Markets::<T>::contains_key(market_id) {
+                return None;
+            }
+
+            let (_, window_secs) = Self::market_window(market_id);
+            let end_hour = timestamp / 3600;
+            let start_hour = end_hour.saturating_sub(window_secs / 3600);
+
+            let mut found = false;
+            let mut sum: Millimeters = 0;
+            for bucket in HourlyBuckets::<T>::iter_prefix_values(market_id) {
+                if bucket.hour_index >= start_hour && bucket.hour_index <= end_hour {
+                    sum = sum.saturating_add(bucket.mm);
+                    found = true;
+                }
+            }
+
+            if found {
+                Some(sum)
+            } else {
+                Some(Self::calculate_rolling_sum_at(market_id, timestamp))
+            }
+        }
+
+        /// Hourly buckets between two unix timestamps for the `PrmxOracleApi` runtime API
+        pub fn hourly_buckets_in_range(
+            market_id: MarketId,
+            from: u64,
+            to: u64,
+        ) -> Vec<prmx_primitives::HourlyRainfall> {
+            let from_hour = from / 3600;
+            let to_hour = to / 3600;
+
+            let mut buckets: Vec<prmx_primitives::HourlyRainfall> =
+                HourlyBuckets::<T>::iter_prefix_values(market_id)
+                    .filter(|bucket| bucket.hour_index >= from_hour && bucket.hour_index <= to_hour)
+                    .map(|bucket| prmx_primitives::HourlyRainfall {
+                        hour_index: bucket.hour_index,
+                        mm: bucket.mm,
+                        fetched_at: bucket.fetched_at,
+                        source: bucket.source,
+                    })
+                    .collect();
+            buckets.sort_by_key(|b| b.hour_index);
+
+            buckets
+        }
+
+        /// Current rolling sum versus market strike for the `PrmxOracleApi` runtime API
+        pub fn threshold_status(market_id: MarketId) -> Option<prmx_primitives::ThresholdStatus> {
+            let strike_mm = T::MarketsApi::strike_value(market_id).ok()?;
+            let state = RollingState::<T>::get(market_id)?;
+
+            Some(prmx_primitives::ThresholdStatus {
+                market_id,
+                rolling_sum_mm: state.rolling_sum_mm,
+                strike_mm,
+                exceeded: state.rolling_sum_mm >= strike_mm,
+                last_bucket_index: state.last_bucket_index,
+            })
+        }
+
+        /// Store hourly temperature readings and prune buckets older than
+        /// `WEATHER_RETENTION_SECS`.
+        pub fn do_submit_hourly_temperature(
+            market_id: MarketId,
+            hourly_data: &[(u64, DeciCelsius)],
+        ) -> DispatchResult {
+            ensure!(
+                pallet_prmx_markets::Markets::<T>::contains_key(market_id),
+                Error::<T>::MarketNotFound
+            );
+            ensure!(
+                hourly_data.iter().all(|(_, temp_c)| {
+                    (accuweather::MIN_TEMPERATURE_C..=accuweather::MAX_TEMPERATURE_C)
+                        .contains(temp_c)
+                }),
+                Error::<T>::InvalidTemperatureValue
+            );
+
+            let now = T::TimeProvider::now_secs();
+            let current_hour_index = now / 3600;
+            let oldest_hour = now.saturating_sub(WEATHER_RETENTION_SECS) / 3600;
+
+            let mut readings_stored = 0u32;
+            let mut latest: Option<(u64, DeciCelsius)> = None;
+
+            for (epoch_time, temp_c) in hourly_data.iter() {
+                let hour_index = *epoch_time / 3600;
+                // Skip readings outside the retention window or from the future
+                if hour_index < oldest_hour || hour_index > current_hour_index + 1 {
+                    continue;
+                }
+
+                TempBuckets::<T>::insert(
+                    market_id,
+                    hour_index,
+                    TempBucket {
+                        temp_c: *temp_c,
+                        fetched_at: now,
+                    },
+                );
+                readings_stored += 1;
+
+                if !matches!(latest, Some((h, _)) if h >= hour_index) {
+                    latest = Some((hour_index, *temp_c));
+                }
+            }
+
+            for (hour_idx, _) in TempBuckets::<T>::iter_prefix(market_id) {
+                if hour_idx < oldest_hour {
+                    TempBuckets::<T>::remove(market_id, hour_idx);
+                }
+            }
+
+            log::info!(
+                target: "prmx-oracle",
+                "🌡️ OCW hourly temperature: stored {} of {} readings for market {}",
+                readings_stored,
+                hourly_data.len(),
+                market_id
+            );
+
+            Self::deposit_event(Event::HourlyTemperatureSubmitted {
+                market_id,
+                readings_stored,
+                latest_temp_c: latest.map(|(_, temp_c)| temp_c),
+            });
+
+            Ok(())
+        }
+
+        /// Temperature readings for hours overlapping [coverage_start, coverage_end]
+        pub fn temperatures_in_window(
+            location_id: LocationId,
+            coverage_start: u64,
+            coverage_end: u64,
+        ) -> impl Iterator<Item = DeciCelsius> {
+            let start_hour = coverage_start / 3600;
+            let end_hour = coverage_end / 3600;
+
+            TempBuckets::<T>::iter_prefix(location_id)
+                .filter(move |(hour_idx, _)| *hour_idx >= start_hour && *hour_idx <= end_hour)
+                .map(|(_, bucket)| bucket.temp_c)
+        }
+
+        /// Store hourly wind gust readings and prune buckets older than
+        /// `WEATHER_RETENTION_SECS`.
+        pub fn do_submit_hourly_wind_gust(
+            market_id: MarketId,
+            hourly_data: &[(u64, DeciKmh)],
+        ) -> DispatchResult {
+            ensure!(
+                pallet_prmx_markets::Markets::<T>::contains_key(market_id),
+                Error::<T>::MarketNotFound
+            );
+            ensure!(
+                hourly_data
+                    .iter()
+                    .all(|(_, gust)| *gust <= accuweather::MAX_WIND_GUST_KMH),
+                Error::<T>::InvalidWindGustValue
+            );
+
+            let now = T::TimeProvider::now_secs();
+            let current_hour_index = now / 3600;
+            let oldest_hour = now.saturating_sub(WEATHER_RETENTION_SECS) / 3600;
+
+            let mut readings_stored = 0u32;
+            let mut max_gust_kmh: Option<DeciKmh> = None;
+
+            for (epoch_time, gust_kmh) in hourly_data.iter() {
+                let hour_index = *epoch_time / 3600;
+                // Skip readings outside the retention window or from the future
+                if hour_index < oldest_hour || hour_index > current_hour_index + 1 {
+                    continue;
+                }
+
+                WindBuckets::<T>::insert(
+                    market_id,
+                    hour_index,
+                    WindBucket {
+                        gust_kmh: *gust_kmh,
+                        fetched_at: now,
+                    },
+                );
+                readings_stored += 1;
+                max_gust_kmh = max_gust_kmh.max(Some(*gust_kmh));
+            }
+
+            for (hour_idx, _) in WindBuckets::<T>::iter_prefix(market_id) {
+                if hour_idx < oldest_hour {
+                    WindBuckets::<T>::remove(market_id, hour_idx);
+                }
+            }
+
+            log::info!(
+                target: "prmx-oracle",
+                "💨 OCW hourly wind gust: stored {} of {} readings for market {}",
+                readings_stored,
+                hourly_data.len(),
+                market_id
+            );
+
+            Self::deposit_event(Event::HourlyWindGustSubmitted {
+                market_id,
+                readings_stored,
+                max_gust_kmh,
+            });
+
+            Ok(())
+        }
+
+        /// Wind gust readings for hours overlapping [coverage_start, coverage_end]
+        pub fn wind_gusts_in_window(
+            location_id: LocationId,
+            coverage_start: u64,
+            coverage_end: u64,
+        ) -> impl Iterator<Item = DeciKmh> {
+            let start_hour = coverage_start / 3600;
+            let end_hour = coverage_end / 3600;
+
+            WindBuckets::<T>::iter_prefix(location_id)
+                .filter(move |(hour_idx, _)| *hour_idx >= start_hour && *hour_idx <= end_hour)
+                .map(|(_, bucket)| bucket.gust_kmh)
+        }
+
+        /// Store hourly snowfall readings and prune buckets older than
+        /// `WEATHER_RETENTION_SECS`.
+        pub fn do_submit_hourly_snowfall(
+            market_id: MarketId,
+            hourly_data: &[(u64, Millimeters)],
+        ) -> DispatchResult {
+            ensure!(
+                pallet_prmx_markets::Markets::<T>::contains_key(market_id),
+                Error::<T>::MarketNotFound
+            );
+            ensure!(
+                hourly_data
+                    .iter()
+                    .all(|(_, snowfall)| *snowfall <= accuweather::MAX_SNOWFALL_MM),
+                Error::<T>::InvalidSnowfallValue
+            );
+
+            let now = T::TimeProvider::now_secs();
+            let current_hour_index = now / 3600;
+            let oldest_hour = now.saturating_sub(WEATHER_RETENTION_SECS) / 3600;
+
+            let mut readings_stored = 0u32;
+            let mut max_snowfall_mm: Option<Millimeters> = None;
+
+            for (epoch_time, snowfall_mm) in hourly_data.iter() {
+                let hour_index = *epoch_time / 3600;
+                // Skip readings outside the retention window or from the future
+                if hour_index < oldest_hour || hour_index > current_hour_index + 1 {
+                    continue;
+                }
+
+                SnowBuckets::<T>::insert(
+                    market_id,
+                    hour_index,
+                    SnowBucket {
+                        snowfall_mm: *snowfall_mm,
+                        fetched_at: now,
+                    },
+                );
+                readings_stored += 1;
+                max_snowfall_mm = max_snowfall_mm.max(Some(*snowfall_mm));
+            }
+
+            for (hour_idx, _) in SnowBuckets::<T>::iter_prefix(market_id) {
+                if hour_idx < oldest_hour {
+                    SnowBuckets::<T>::remove(market_id, hour_idx);
+                }
+            }
+
+            log::info!(
+                target: "prmx-oracle",
+                "❄️ OCW hourly snowfall: stored {} of {} readings for market {}",
+                readings_stored,
+                hourly_data.len(),
+                market_id
+            );
+
+            Self::deposit_event(Event::HourlySnowfallSubmitted {
+                market_id,
+                readings_stored,
+                max_snowfall_mm,
+            });
+
+            Ok(())
+        }
+
+        /// Snowfall readings as (hour_index, tenths of mm) for hours overlapping
+        /// [coverage_start, coverage_end], in ascending hour order
+        pub fn snowfall_in_window(
+            location_id: LocationId,
+            coverage_start: u64,
+            coverage_end: u64,
+        ) -> Vec<(u64, Millimeters)> {
+            let start_hour = coverage_start / 3600;
+            let end_hour = coverage_end / 3600;
+
+            let mut readings: Vec<(u64, Millimeters)> = SnowBuckets::<T>::iter_prefix(location_id)
+                .filter(|(hour_idx, _)| *hour_idx >= start_hour && *hour_idx <= end_hour)
+                .map(|(hour_idx, bucket)| (hour_idx, bucket.snowfall_mm))
+                .collect();
+            readings.sort_unstable_by_key(|(hour_idx, _)| *hour_idx);
+            readings
+        }
+
+        /// Store a gauge level for the hour of `epoch_time` with the market's current
+        /// 24h rainfall, and prune buckets older than `WEATHER_RETENTION_SECS`.
+        pub fn do_submit_gauge_level(
+            market_id: MarketId,
+            epoch_time: u64,
+            level_cm: Centimeters,
+        ) -> DispatchResult {
+            ensure!(
+                GaugeSources::<T>::contains_key(market_id),
+                Error::<T>::GaugeSourceNotConfigured
+            );
+            ensure!(
+                (gauge::MIN_GAUGE_LEVEL_CM..=gauge::MAX_GAUGE_LEVEL_CM).contains(&level_cm),
+                Error::<T>::InvalidGaugeLevel
+            );
+
+            let now = T::TimeProvider::now_secs();
+            // The level is paired with the rolling sum now, so only the current hour
+            // (or the previous one, for a read just before the hour turned) is accepted
+            let hour_index = epoch_time / 3600;
+            let current_hour_index = now / 3600;
+            ensure!(
+                epoch_time <= now.saturating_add(MAX_FUTURE_DRIFT_SECS)
+                    && hour_index.saturating_add(1) >= current_hour_index
+                    && hour_index <= current_hour_index,
+                Error::<T>::InvalidTimestamp
+            );
+
+            let rainfall_24h_mm = Self::rolling_sum_at(market_id, now).unwrap_or(0);
+
+            GaugeBuckets::<T>::insert(
+                market_id,
+                hour_index,
+                GaugeBucket {
+                    level_cm,
+                    rainfall_24h_mm,
+                    fetched_at: now,
+                },
+            );
+
+            let oldest_hour = now.saturating_sub(WEATHER_RETENTION_SECS) / 3600;
+            for (hour_idx, _) in GaugeBuckets::<T>::iter_prefix(market_id) {
+                if hour_idx < oldest_hour {
+                    GaugeBuckets::<T>::remove(market_id, hour_idx);
+                }
+            }
+
+            log::info!(
+                target: "prmx-oracle",
+                "🌊 OCW gauge level: market {} hour {} at {} cm (24h rainfall {})",
+                market_id,
+                hour_index,
+                level_cm,
+                rainfall_24h_mm
+            );
+
+            Self::deposit_event(Event::HourlyGaugeLevelSubmitted {
+                market_id,
+                hour_index,
+                level_cm,
+                rainfall_24h_mm,
+            });
+
+            Ok(())
+        }
+
+        /// Gauge readings as (level, 24h rainfall) for hours overlapping
+        /// [coverage_start, coverage_end]
+        pub fn gauge_readings_in_window(
+            location_id: LocationId,
+            coverage_start: u64,
+            coverage_end: u64,
+        ) -> Vec<(Centimeters, Millimeters)> {
+            gauge::readings_in_window(
+                GaugeBuckets::<T>::iter_prefix(location_id)
+                    .map(|(hour_idx, bucket)| (hour_idx, bucket.level_cm, bucket.rainfall_24h_mm)),
+                coverage_start / 3600,
+                coverage_end / 3600,
+            )
+        }
+
+        /// Check if rainfall exceeded threshold during coverage window
+        /// Per oracle_design.md section 9.3
+        pub fn check_exceeded_threshold_in_window(
+            location_id: LocationId,
+            strike_mm: Millimeters,
+            coverage_start: u64,
+            coverage_end: u64,
+        ) -> Result<bool, Error<T>> {
+            ensure!(coverage_start < coverage_end, Error::<T>::InvalidCoverageWindow);
+
+            let (interval_secs, _) = Self::market_window(location_id);
+            let mut t = coverage_start;
+            while t <= coverage_end {
+                let sum = Self::calculate_rolling_sum_at(location_id, t);
+                if sum >= strike_mm {
+                    return Ok(true);
+                }
+                t = t.saturating_add(interval_secs);
+            }
+
+            Ok(false)
+        }
+
+        /// Maximum rolling sum during the coverage window, sampled at the market's
+        /// rolling interval like `check_exceeded_threshold_in_window`
+        pub fn max_rolling_sum_in_window(
+            location_id: LocationId,
+            coverage_start: u64,
+            coverage_end: u64,
+        ) -> Result<Millimeters, Error<T>> {
+            ensure!(coverage_start < coverage_end, Error::<T>::InvalidCoverageWindow);
+
+            let (interval_secs, _) = Self::market_window(location_id);
+            let mut max_sum: Millimeters = 0;
+            let mut t = coverage_start;
+            while t <= coverage_end {
+                max_sum = max_sum.max(Self::calculate_rolling_sum_at(location_id, t));
+                t = t.saturating_add(interval_secs);
+            }
+
+            Ok(max_sum)
+        }
+
+        /// Check all active policies across all markets and trigger settlements if threshold exceeded
+        /// This is called from on_initialize every BLOCKS_PER_SETTLEMENT_CHECK blocks
+        pub fn check_and_settle_triggered_policies(block_number: BlockNumberFor<T>) -> Weight {
+            use sp_runtime::traits::UniqueSaturatedInto;
+            let block_num: u32 = block_number.unique_saturated_into();
+            
+            log::debug!(
+                target: "prmx-oracle",
+                "🔍 Settlement check running at block {}",
+                block_num
+            );
+            
+            let current_time = T::TimeProvider::now_secs();
+            
+            let mut weight = Weight::from_parts(5_000, 0);
+            let mut settlements_triggered = 0u32;
+            
+            // Iterate through all markets
+            let next_market_id = pallet_prmx_markets::NextMarketId::<T>::get();
+            
+            for market_id in 0..next_market_id {
+                // Get rolling state for this market
+                let rolling_state = match RollingState::<T>::get(market_id) {
+                    Some(state) => state,
+                    None => {
+                        log::debug!(
+                            target: "prmx-oracle",
+                            "  Market {}: No rolling state data",
+                            market_id
+                        );
+                        continue; // No rainfall data for this market
+                    }
+                };
+                
+                // Get strike threshold for this market
+                let strike_threshold = match T::MarketsApi::strike_value(market_id) {
+                    Ok(strike) => strike,
+                    Err(_) => {
+                        log::debug!(
+                            target: "prmx-oracle",
+                            "  Market {}: Market not found",
+                            market_id
+                        );
+                        continue; // Market not found
+                    }
+                };
+                
+                let current_rolling_sum = rolling_state.rolling_sum_mm;
+                
+                log::debug!(
+                    target: "prmx-oracle",
+                    "  Market {}: rainfall={:.1}mm, strike={:.1}mm, threshold_breached={}",
+                    market_id,
+                    current_rolling_sum as f64 / 10.0,
+                    strike_threshold as f64 / 10.0,
+                    current_rolling_sum >= strike_threshold
+                );
+                
+                // Check if current rainfall exceeds threshold
+                if current_rolling_sum >= strike_threshold {
+                    log::info!(
+                        target: "prmx-oracle",
+                        "⚠️ Threshold breach detected! Market {}: {} mm >= {} mm threshold",
+                        market_id,
+                        current_rolling_sum as f64 / 10.0,
+                        strike_threshold as f64 / 10.0
+                    );
+                    
+                    // Commit the observations behind this rolling sum before they can be pruned
+                    let observations = Self::settlement_observations(market_id, current_time);
+                    let record = Self::observation_settlement_record(
+                        SettlementReason::ThresholdTriggered,
+                        market_id,
+                        current_rolling_sum,
+                        &observations,
+                    );
+                    let observation_root = record.evidence_hash;
+
+                    // Get all active policies in their coverage window for this market
+                    let active_policies = T::PolicySettlement::get_active_policies_in_window(market_id, current_time);
+                    
+                    log::info!(
+                        target: "prmx-oracle",
+                        "🔍 Found {} active policies in coverage window for market {} (current_time={})",
+                        active_policies.len(),
+                        market_id,
+                        current_time
+                    );
+                    
+                    if active_policies.is_empty() {
+                        log::warn!(
+                            target: "prmx-oracle",
+                            "⚠️ No active policies to settle for market {} - check coverage windows",
+                            market_id
+                        );
+                    }
+                    
+                    let mut policies_settled_count = 0u32;
+                    
+                    for policy_id in active_policies {
+                        // Tiered policies keep accruing until their top tier is reached
+                        if current_rolling_sum < T::PolicySettlement::early_trigger_threshold(policy_id, strike_threshold) {
+                            continue;
+                        }
+
+                        // Get policy info for logging
+                        if let Some((holder, _max_payout, _coverage_start, _coverage_end, _market_id)) = 
+                            T::PolicySettlement::get_policy_info(policy_id) 
+                        {
+                            // Get market coordinates for logging
+                            let (center_lat, center_lon) = T::MarketsApi::center_coordinates(market_id)
+                                .unwrap_or((0, 0));
+                            
+                            // Trigger immediate settlement
+                            match T::PolicySettlement::trigger_immediate_settlement(policy_id, record.clone()) {
+                                Ok(payout_amount) => {
+                                    // Create and store trigger log
+                                    let trigger_id = NextTriggerLogId::<T>::get();
+                                    NextTriggerLogId::<T>::put(trigger_id + 1);
+                                    
+                                    let trigger_log = ThresholdTriggerLog {
+                                        trigger_id,
+                                        market_id,
+                                        policy_id,
+                                        triggered_at: current_time,
+                                        block_number,
+                                        rolling_sum_mm: current_rolling_sum,
+                                        strike_threshold,
+                                        holder: holder.clone(),
+                                        payout_amount,
+                                        center_latitude: center_lat,
+                                        center_longitude: center_lon,
+                                        observation_root,
+                                        observation_count: observations.len() as u32,
+                                    };
+                                    
+                                    ThresholdTriggerLogs::<T>::insert(trigger_id, trigger_log);
+                                    Self::index_trigger(market_id, trigger_id);
+                                    Self::archive_trigger_observations(trigger_id, &observations);
+                                    
+                                    // Emit event
+                                    Self::deposit_event(Event::ThresholdTriggered {
+                                        trigger_id,
+                                        market_id,
+                                        policy_id,
+                                        rolling_sum_mm: current_rolling_sum,
+                                        strike_threshold,
+                                        triggered_at: current_time,
+                                        payout_amount,
+                                    });
+                                    
+                                    settlements_triggered += 1;
+                                    policies_settled_count += 1;
+                                    
+                                    log::info!(
+                                        target: "prmx-oracle",
+                                        "✅ Auto-settled policy {} (trigger_id: {}) - Payout: {} to holder",
+                                        policy_id,
+                                        trigger_id,
+                                        payout_amount
+                                    );
+                                }
+                                Err(e) => {
+                                    log::warn!(
+                                        target: "prmx-oracle",
+                                        "❌ Failed to auto-settle policy {}: {:?}",
+                                        policy_id,
+                                        e
+                                    );
+                                }
+                            }
+                        }
+                        
+                        // Add weight for each policy processed
+                        weight = weight.saturating_add(Weight::from_parts(50_000, 0));
+                    }
+                    
+                    // Reset the rolling state after trigger to continue monitoring for future policies
+                    // This ensures the oracle starts fresh after a threshold event
+                    if policies_settled_count > 0 {
+                        // Reset rolling state to zero
+                        let reset_state = RollingWindowState {
+                            last_bucket_index: rolling_state.last_bucket_index,
+                            oldest_bucket_index: rolling_state.last_bucket_index, // Start fresh
+                            rolling_sum_mm: 0, // Reset to zero
+                        };
+                        RollingState::<T>::insert(market_id, reset_state);
+                        
+                        // Clear old rain buckets for this market
+                        // Keep only the current bucket index as reference point
+                        let _ = RainBuckets::<T>::clear_prefix(market_id, u32::MAX, None);
+                        RainBucketPruneCursor::<T>::remove(market_id);
+                        
+                        log::info!(
+                            target: "prmx-oracle",
+                            "🔄 Reset rainfall data for market {} after settling {} policies",
+                            market_id,
+                            policies_settled_count
+                        );
+                        
+                        Self::deposit_event(Event::RollingSumUpdated {
+                            location_id: market_id,
+                            rolling_sum_mm: 0,
+                        });
+                    }
+                }
+                
+                // Add weight for each market processed
+                weight = weight.saturating_add(Weight::from_parts(10_000, 0));
+            }
+            
+            if settlements_triggered > 0 {
+                log::info!(
+                    target: "prmx-oracle",
+                    "🏁 Settlement check complete: {} policies auto-settled",
+                    settlements_triggered
+                );
+            }
+            
+            weight
+        }
+        
+        /// Settle a pending V2 report: record it as final and forward it to the policy pallet
+        fn do_finalize_v2_report(policy_id: PolicyId) -> DispatchResult {
+            let pending = PendingV2Reports::<T>::take(policy_id)
+                .ok_or(Error::<T>::V2ReportNotPending)?;
+            let report = pending.report;
+
+            // Store the report in oracle storage (immutable until pruned after retention)
+            V2FinalReportByPolicy::<T>::insert(policy_id, report.clone());
+            let prune_index = NextV2FinalReportPruneIndex::<T>::get();
+            V2FinalReportPruneQueue::<T>::insert(prune_index, policy_id);
+            NextV2FinalReportPruneIndex::<T>::put(prune_index.saturating_add(1));
+
+            // Forward to policy pallet for actual settlement
+            // The policy pallet will validate the report and perform settlement
+            T::PolicySettlement::settle_v2_policy(
+                policy_id,
+                report.outcome.clone(),
+                report.observed_at,
+                report.cumulative_mm,
+                report.evidence_hash,
+            )?;
+
+            Self::deposit_event(Event::V2ReportAccepted {
+                policy_id,
+                outcome: report.outcome.clone(),
+                cumulative_mm: report.cumulative_mm,
+                evidence_hash: report.evidence_hash,
+            });
+
+            log::info!(
+                target: "prmx-oracle",
+                "✅ V2 report accepted for policy {}: {:?}, cumulative_mm={}",
+                policy_id,
+                report.outcome,
+                report.cumulative_mm
+            );
+
+            Ok(())
+        }
+
+        /// Close an open V2 dispute.
+        /// Upheld: the report is discarded and the bond returned, so a corrected report
+        /// can be submitted. Rejected: the bond is slashed and the report is settled.
+        fn do_resolve_v2_dispute(policy_id: PolicyId, uphold: bool) -> DispatchResult {
+            let dispute = V2Disputes::<T>::take(policy_id)
+                .ok_or(Error::<T>::V2DisputeNotFound)?;
+            V2DisputeVotes::<T>::remove(policy_id);
+
+            if uphold {
+                T::Currency::unreserve(&dispute.disputer, dispute.bond);
+                if let Some(pending) = PendingV2Reports::<T>::take(policy_id) {
+                    Self::slash_provider(
+                        &pending.report.reporter,
+                        ProviderSlashReason::OverturnedV2Report { policy_id },
+                    );
+                }
+            } else {
+                let _ = T::Currency::slash_reserved(&dispute.disputer, dispute.bond);
+                Self::do_finalize_v2_report(policy_id)?;
+            }
+
+            Self::deposit_event(Event::V2DisputeResolved {
+                policy_id,
+                upheld: uphold,
+            });
+
+            log::info!(
+                target: "prmx-oracle",
+                "⚖️ V2 dispute for policy {} resolved (upheld: {})",
+                policy_id,
+                uphold
+            );
+
+            Ok(())
+        }
+
+        /// Finalize undisputed V2 reports whose challenge period has ended, walking
+        /// `V2FinalizationQueue` from its cursor over at most
+        /// `MAX_V2_FINALIZATIONS_PER_BLOCK` matured entries.
+        /// This is called from on_initialize every BLOCKS_PER_SETTLEMENT_CHECK blocks
+        pub fn finalize_matured_v2_reports(block_number: BlockNumberFor<T>) -> Weight {
+            let mut weight = Weight::from_parts(5_000, 0);
+            let mut cursor = V2FinalizationCursor::<T>::get();
+            let end = NextV2FinalizationIndex::<T>::get();
+            let mut visited = 0u32;
+
+            while cursor < end && visited < MAX_V2_FINALIZATIONS_PER_BLOCK {
+                visited += 1;
+                weight = weight.saturating_add(Weight::from_parts(100_000, 0));
+
+                let Some((policy_id, challenge_ends_at)) = V2FinalizationQueue::<T>::get(cursor)
+                else {
+                    cursor += 1;
+                    continue;
+                };
+                // The queue is in maturity order, so nothing behind this entry is ready
+                if block_number <= challenge_ends_at {
+                    break;
+                }
+                V2FinalizationQueue::<T>::remove(cursor);
+                cursor += 1;
+
+                // Skip reports already finalized by hand or discarded after an upheld
+                // dispute (a resubmitted report has its own, later queue entry)
+                let matured = PendingV2Reports::<T>::get(policy_id)
+                    .is_some_and(|pending| pending.challenge_ends_at == challenge_ends_at);
+                // A disputed report is settled when its dispute is resolved
+                if !matured || V2Disputes::<T>::contains_key(policy_id) {
+                    continue;
+                }
+
+                // Roll back partial writes if settlement fails
+                let result = frame_support::storage::with_storage_layer(|| {
+                    Self::do_finalize_v2_report(policy_id)
+                });
+
+                if let Err(error) = result {
+                    log::warn!(
+                        target: "prmx-oracle",
+                        "❌ Failed to finalize V2 report for policy {}: {:?}",
+                        policy_id,
+                        error
+                    );
+                    // The report stays pending for a retry through `finalize_v2_report`
+                    Self::deposit_event(Event::V2ReportFinalizationFailed { policy_id, error });
+                }
+            }
+
+            V2FinalizationCursor::<T>::put(cursor);
+            weight
+        }
+
+        /// Maximum number of expired policies to settle per block
+        /// Limits block weight while ensuring backlog is cleared within reasonable time
+        const MAX_EXPIRATION_SETTLEMENTS_PER_BLOCK: u32 = 10;
+        
+        /// Check all expired policies and settle them automatically
+        /// This is called from on_initialize every BLOCKS_PER_SETTLEMENT_CHECK blocks
+        pub fn check_and_settle_expired_policies(block_number: BlockNumberFor<T>) -> Weight {
+            let current_time = T::TimeProvider::now_secs();
+            let mut weight = Weight::from_parts(5_000, 0);
+            let mut settlements_count = 0u32;
+            
+            // Get all expired policies (coverage ended, still active)
+            let expired_policies = T::PolicySettlement::get_expired_policies(current_time);
+            
+            if expired_policies.is_empty() {
+                return weight;
+            }
+            
+            log::info!(
+                target: "prmx-oracle",
+                "📋 Found {} expired policies to settle (current_time={})",
+                expired_policies.len(),
+                current_time
+            );
+            
+            for policy_id in expired_policies {
+                if settlements_count >= Self::MAX_EXPIRATION_SETTLEMENTS_PER_BLOCK {
+                    log::info!(
+                        target: "prmx-oracle",
+                        "⏸️ Reached max settlements per block ({}), deferring remaining to next block",
+                        Self::MAX_EXPIRATION_SETTLEMENTS_PER_BLOCK
+                    );
+                    break; // Defer remaining to next block
+                }
+                
+                // Get policy info to determine event outcome
+                if let Some((_holder, _max_payout, coverage_start, coverage_end, market_id)) = 
+                    T::PolicySettlement::get_policy_info(policy_id) 
+                {
+                    // Give late observations for the final hours time to arrive
+                    let grace_ends = coverage_end.saturating_add(Self::settlement_grace_secs(market_id));
+                    if current_time < grace_ends {
+                        log::debug!(
+                            target: "prmx-oracle",
+                            "⏳ Policy {} expired, settlement deferred until grace period ends at {}",
+                            policy_id,
+                            grace_ends
+                        );
+                        continue;
+                    }
+
+                    // Without data for the final hour, wait for late data or a forced settlement
+                    if !Self::final_bucket_reported(market_id, coverage_end) {
+                        if !AwaitingSettlementData::<T>::contains_key(policy_id) {
+                            log::warn!(
+                                target: "prmx-oracle",
+                                "⚠️ Policy {} grace period ended without final-hour data for market {}",
+                                policy_id,
+                                market_id
+                            );
+                            AwaitingSettlementData::<T>::insert(policy_id, coverage_end);
+                            Self::deposit_event(Event::ExpirySettlementAwaitingData {
+                                policy_id,
+                                market_id,
+                                coverage_end,
+                            });
+                        }
+                        continue;
+                    }
+
+                    // Check if event occurred during coverage window using oracle data.
+                    // The maximum is also passed on for tiered payouts.
+                    let Some((event_occurred, max_observed_mm)) =
+                        Self::expiry_outcome(market_id, coverage_start, coverage_end)
+                    else {
+                        log::warn!(
+                            target: "prmx-oracle",
+                            "❌ Could not get strike value for market {}, skipping policy {}",
+                            market_id,
+                            policy_id
+                        );
+                        continue;
+                    };
+                    
+                    log::info!(
+                        target: "prmx-oracle",
+                        "🔍 Policy {} expired: coverage [{}, {}], max {} mm, event_occurred: {}",
+                        policy_id,
+                        coverage_start,
+                        coverage_end,
+                        max_observed_mm as f64 / 10.0,
+                        event_occurred
+                    );
+                    
+                    let record = Self::observation_settlement_record(
+                        SettlementReason::CoverageExpired,
+                        market_id,
+                        max_observed_mm,
+                        &Self::coverage_observations(market_id, coverage_start, coverage_end),
+                    );
+
+                    // Settle the policy
+                    match T::PolicySettlement::settle_expired_policy(policy_id, event_occurred, record) {
+                        Ok(payout) => {
+                            log::info!(
+                                target: "prmx-oracle",
+                                "✅ Auto-settled expired policy {} (event: {}, payout: {})",
+                                policy_id,
+                                event_occurred,
+                                payout
+                            );
+                            
+                            AwaitingSettlementData::<T>::remove(policy_id);
+                            Self::deposit_event(Event::PolicyExpirationSettled {
+                                policy_id,
+                                event_occurred,
+                                payout_amount: payout,
+                            });
+                            
+                            settlements_count += 1;
+                        }
+                        Err(e) => {
+                            log::warn!(
+                                target: "prmx-oracle",
+                                "❌ Failed to auto-settle expired policy {}: {:?}",
+                                policy_id,
+                                e
+                            );
+                        }
+                    }
+                }
+                
+                // Add weight for each policy processed
+                weight = weight.saturating_add(Weight::from_parts(100_000, 0));
+            }
+            
+            if settlements_count > 0 {
+                log::info!(
+                    target: "prmx-oracle",
+                    "🏁 Expiration settlement complete: {} policies auto-settled",
+                    settlements_count
+                );
+            }
+            
+            weight
+        }
+    }
+
+    // =========================================================================
+    //                          Offchain Worker
+    // =========================================================================
+
+    /// Offchain storage key for AccuWeather API key
+    pub const ACCUWEATHER_API_KEY_STORAGE: &[u8] = b"prmx-oracle::accuweather-api-key";
+
+    /// Offchain storage key prefix for tracking in-flight pending fetch submissions
+    /// This prevents duplicate submissions while waiting for on-chain transaction to be processed
+    pub const PENDING_FETCH_INFLIGHT_PREFIX: &[u8] = b"prmx-oracle::pending-fetch-inflight::";
+
+    /// Offchain storage key prefix for tracking scheduled fetches already attempted
+    pub const SCHEDULED_FETCH_INFLIGHT_PREFIX: &[u8] = b"prmx-oracle::scheduled-fetch-inflight::";
+
+    /// Offchain storage key prefix for tracking in-flight backfill page submissions
+    pub const BACKFILL_INFLIGHT_PREFIX: &[u8] = b"prmx-oracle::backfill-inflight::";
+
+    /// Offchain storage key prefix for tracking in-flight listing feasibility submissions
+    pub const LISTING_CHECK_INFLIGHT_PREFIX: &[u8] = b"prmx-oracle::listing-check-inflight::";
+
+    /// Offchain storage key prefix for observations archived per threshold trigger
+    /// (SCALE-encoded `Vec<(hour_index, Millimeters)>`, written via offchain indexing)
+    pub const OBSERVATION_ARCHIVE_PREFIX: &[u8] = b"prmx-oracle::trigger-observations::";
+
+    /// AccuWeather API base URL
+    pub const ACCUWEATHER_BASE_URL: &str = "https://dataservice.accuweather.com";
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// On initialize hook:
+        /// 1. Clear API key configured flag after offchain worker has had time to fetch
+        /// 2. Check for threshold breaches and trigger automatic settlements (every BLOCKS_PER_SETTLEMENT_CHECK blocks)
+        /// 3. Check for expired policies and settle them automatically (every BLOCKS_PER_SETTLEMENT_CHECK blocks)
+        /// 4. Finalize undisputed V2 reports after their challenge period (every BLOCKS_PER_SETTLEMENT_CHECK blocks)
+        /// 5. Keep the `NextFetchDue` rainfall fetch schedule (every BLOCKS_PER_SETTLEMENT_CHECK blocks)
+        fn on_initialize(block_number: BlockNumberFor<T>) -> Weight {
+            use sp_runtime::traits::UniqueSaturatedInto;
+            let block_num: u32 = block_number.unique_saturated_into();
+            
+            let mut weight = Weight::zero();
+            
+            // =========================================================================
+            // Clear API key configured flag after enough blocks for OCW to pick it up
+            // The OCW runs during startup (blocks 0-9) and whenever a market's fetch is due.
+            // We keep the pending key for 100 blocks to ensure the OCW has a chance to copy it.
+            // =========================================================================
+            if let Some(configured_at) = ApiKeyConfiguredAt::<T>::get() {
+                // Clear the flag and pending key after 100 blocks (ensure OCW has time to copy)
+                if block_number > configured_at + 100u32.into() {
+                    ApiKeyConfiguredAt::<T>::kill();
+                    PendingApiKey::<T>::kill(); // Clear the temporary on-chain key
+                    weight = weight.saturating_add(Weight::from_parts(10_000, 0));
+                }
+            }
+            
+            // =========================================================================
+            // Automatic settlement check (every BLOCKS_PER_SETTLEMENT_CHECK blocks)
+            // =========================================================================
+            let should_check_settlements = block_num % BLOCKS_PER_SETTLEMENT_CHECK == 0
+                && !T::Pause::is_paused(PausableOperation::Settlements);
+            
+            if should_check_settlements {
+                // Check for threshold breaches during active coverage
+                let settlements_weight = Self::check_and_settle_triggered_policies(block_number);
+                weight = weight.saturating_add(settlements_weight);
+                
+                // Check for expired policies that need settlement
+                let expiration_weight = Self::check_and_settle_expired_policies(block_number);
+                weight = weight.saturating_add(expiration_weight);
+
+                // Settle V2 reports whose challenge period ended without dispute
+                let v2_weight = Self::finalize_matured_v2_reports(block_number);
+                weight = weight.saturating_add(v2_weight);
+            }
+
+            // =========================================================================
+            // Oracle liveness check (every BLOCKS_PER_BINDING_CHECK blocks)
+            // =========================================================================
+            if block_num % BLOCKS_PER_BINDING_CHECK == 0 {
+                weight = weight.saturating_add(Self::check_stale_markets());
+            }
+
+            // =========================================================================
+            // Rainfall fetch schedule (every BLOCKS_PER_SETTLEMENT_CHECK blocks)
+            // =========================================================================
+            if block_num % BLOCKS_PER_SETTLEMENT_CHECK == 0 {
+                weight = weight.saturating_add(Self::schedule_rainfall_fetches());
+            }
+
+            weight
+        }
+
+        /// Archive and prune oracle data past its market's retention window with
+        /// the block's leftover weight (see `retention`)
+        fn on_idle(_block_number: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::prune_expired_data(remaining_weight)
+        }
+
+        /// Offchain worker entry point
+        /// Per oracle_design.md section 7.2
+        fn offchain_worker(block_number: BlockNumberFor<T>) {
+            // Convert block number to u32 for modulo check
+            use sp_runtime::traits::UniqueSaturatedInto;
+            let block_num: u32 = block_number.unique_saturated_into();
+
+            // Report liveness independently of any data work
+            if block_num % BLOCKS_PER_HEARTBEAT == 0 {
+                if let Err(e) = Self::send_heartbeat(block_number) {
+                    log::warn!(target: "prmx-oracle", "❌ Heartbeat failed: {:?}", e);
+                    ocw_metrics::record_error(e);
+                }
+            }
+            if block_num % BLOCKS_PER_HEALTH_REPORT == 0 {
+                if let Err(e) = Self::send_ocw_health_report(block_number) {
+                    log::warn!(target: "prmx-oracle", "❌ OCW health report failed: {:?}", e);
+                    ocw_metrics::record_error(e);
+                }
+            }
+
+            // Submissions would be rejected on-chain, so don't fetch
+            if T::Pause::is_paused(PausableOperation::OracleSubmissions) {
+                return;
+            }
+
+            // =========================================================================
+            // Priority 1: Process pending manual fetch requests (every block)
+            // =========================================================================
+            let has_pending_requests = Self::process_pending_fetch_requests(block_number);
+            let has_pending_backfills = BackfillRequests::<T>::iter_keys().next().is_some();
+            let has_pending_listing_checks =
+                PendingListingChecks::<T>::iter_keys().next().is_some();
+
+            // Check if API key was just configured and immediate fetch is needed
+            let api_key_just_configured = ApiKeyConfiguredAt::<T>::get().is_some();
+            
+            // Check if there's a pending API key that needs to be copied to local storage
+            // We check this on every block to ensure quick pickup after extrinsic submission
+            let has_pending_api_key = PendingApiKey::<T>::get().map_or(false, |k| !k.is_empty());
+            
+            // Determine what operations to run
+            // - Rainfall ingestion: markets whose on-chain `NextFetchDue` has passed, or all
+            //   markets in the first 10 blocks for quick startup
+            // - Location binding: every ~10 minutes (every 100 blocks) for new markets
+            // - Immediate fetch: when API key is newly configured or pending
+            let is_startup_window = block_num < 10; // Run more frequently during startup
+            let force_fetch = is_startup_window || api_key_just_configured || has_pending_api_key;
+            let due_markets = Self::claim_due_fetches();
+            let should_fetch_rainfall = force_fetch || !due_markets.is_empty();
+            let should_check_bindings = is_startup_window || block_num % BLOCKS_PER_BINDING_CHECK == 0 || api_key_just_configured || has_pending_api_key;
+
+            // River gauges need no AccuWeather key and follow the rainfall schedule
+            if should_fetch_rainfall {
+                Self::fetch_and_store_gauge_levels();
+            }
+
+            // Early return if nothing to do this block (and no pending requests processed)
+            if !should_fetch_rainfall
+                && !should_check_bindings
+                && !has_pending_requests
+                && !has_pending_backfills
+                && !has_pending_listing_checks
+            {
+                return;
+            }
+            
+            // Log if this is an immediate fetch triggered by API key configuration
+            if api_key_just_configured || has_pending_api_key {
+                log::info!(
+                    target: "prmx-oracle",
+                    "🚀 API key pending - triggering immediate rainfall fetch for all markets"
+                );
+            }
+
+            // Log occasionally to show worker is alive
+            if should_check_bindings || is_startup_window || has_pending_requests || has_pending_api_key {
+                log::info!(
+                    target: "prmx-oracle",
+                    "Offchain worker at block {} (startup: {}, rainfall: {}, bindings: {}, pending: {}, api_key_pending: {})",
+                    block_num,
+                    is_startup_window,
+                    should_fetch_rainfall,
+                    should_check_bindings,
+                    has_pending_requests,
+                    has_pending_api_key
+                );
+            }
+
+            // Try to get API key from offchain local storage
+            let api_key = Self::get_accuweather_api_key();
+
+            match api_key {
+                Some(key) => {
+                    let key_preview = core::str::from_utf8(&key[..10.min(key.len())])
+                        .unwrap_or("invalid");
+                    log::debug!(
+                        target: "prmx-oracle",
+                        "AccuWeather API key configured ({}...)",
+                        key_preview
+                    );
+
+                    // Process markets: resolve bindings AND fetch rainfall
+                    // This combined approach handles both binding resolution and rainfall fetching
+                    // in the same offchain worker invocation to avoid storage persistence issues
+                    if should_check_bindings || should_fetch_rainfall {
+                        if let Err(e) = Self::process_markets_and_fetch_rainfall(
+                            &key,
+                            block_number,
+                            should_check_bindings,
+                            force_fetch,
+                            &due_markets,
+                        ) {
+                            log::warn!(
+                                target: "prmx-oracle",
+                                "Error processing markets: {:?}",
+                                e
+                            );
+                            ocw_metrics::record_error(e);
+                        }
+                    }
+
+                    if has_pending_backfills {
+                        Self::process_backfill_requests(&key);
+                    }
+
+                    if has_pending_listing_checks {
+                        Self::process_listing_checks(&key);
+                    }
+                }
+                None => {
+                    log::warn!(
+                        target: "prmx-oracle",
+                        "AccuWeather API key not configured. Set it via offchain storage or environment variable ACCUWEATHER_API_KEY"
+                    );
+                }
+            }
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+            Self::do_try_state()
+        }
+    }
+
+    // =========================================================================
+    //                          Try-State Invariants
+    // =========================================================================
+
+    #[cfg(feature = "try-runtime")]
+    impl<T: Config> Pallet<T> {
+        /// Check rolling state against the stored buckets. For every `RollingState`:
+        /// - `oldest_bucket_index <= last_bucket_index`, and no `RainBuckets` entry is
+        ///   newer than `last_bucket_index`
+        /// - `rolling_sum_mm` is either the 24h total stored as a single bucket at
+        ///   `last_bucket_index` (Past24Hours submissions), the sum of in-window
+        ///   `HourlyBuckets` for markets on the hourly pipeline, or the sum of
+        ///   `RainBuckets` from `oldest_bucket_index` to `last_bucket_index`
+        /// - no `HourlyBuckets` entry is older than the acceptance window of the refresh
+        ///   that wrote the state
+        ///
+        /// `RainBuckets` must also never exist without a `RollingState`, nor
+        /// `HourlyBuckets` without a `HourlyRing` whose running sum and count match them.
+        pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+            for (market_id, state) in RollingState::<T>::iter() {
+                ensure!(
+                    state.oldest_bucket_index <= state.last_bucket_index,
+                    "rolling state oldest bucket is after its last bucket"
+                );
+
+                let mut range_sum: Millimeters = 0;
+                for (idx, bucket) in RainBuckets::<T>::iter_prefix(market_id) {
+                    ensure!(
+                        idx <= state.last_bucket_index,
+                        "rain bucket newer than the rolling state"
+                    );
+                    if idx >= state.oldest_bucket_index {
+                        range_sum = range_sum.saturating_add(bucket.rainfall_mm);
+                    }
+                }
+
+                let snapshot = RainBuckets::<T>::get(market_id, state.last_bucket_index)
+                    .map(|bucket| bucket.rainfall_mm);
+                if snapshot == Some(state.rolling_sum_mm) {
+                    continue;
+                }
+
+                let hourly: Vec<(u64, Millimeters)> = HourlyBuckets::<T>::iter_prefix_values(market_id)
+                    .map(|bucket| (bucket.hour_index, bucket.mm))
+                    .collect();
+                if hourly.is_empty() {
+                    ensure!(
+                        state.rolling_sum_mm == range_sum,
+                        "rolling sum does not match in-window rain buckets"
+                    );
+                    continue;
+                }
+
+                // The hourly refresh that wrote this state ran during its last bucket,
+                // which can span more than one hour
+                let (interval_secs, window_secs) = Self::market_window(market_id);
+                let window_hours = window_secs / 3600;
+                let bucket_start = state.last_bucket_index.saturating_mul(interval_secs);
+                let first_hour = bucket_start / 3600;
+                let last_hour = bucket_start.saturating_add(interval_secs.saturating_sub(1)) / 3600;
+
+                let oldest_retained_hour = first_hour.saturating_sub(window_hours + 1);
+                ensure!(
+                    hourly.iter().all(|(hour_idx, _)| *hour_idx >= oldest_retained_hour),
+                    "hourly bucket outside the retention window"
+                );
+                let matches_refresh = (first_hour..=last_hour).any(|refresh_hour| {
+                    let oldest_hour = refresh_hour.saturating_sub(window_hours);
+                    let sum = hourly
+                        .iter()
+                        .filter(|(hour_idx, _)| *hour_idx >= oldest_hour)
+                        .fold(0 as Millimeters, |sum, (_, mm)| sum.saturating_add(*mm));
+                    sum == state.rolling_sum_mm
+                });
+                ensure!(matches_refresh, "rolling sum does not match in-window hourly buckets");
+            }
+
+            for (market_id, ring) in HourlyRing::<T>::iter() {
+                let slots = Self::hourly_ring_slots(market_id);
+                let mut sum = 0u64;
+                let mut buckets = 0u32;
+                for (slot, bucket) in HourlyBuckets::<T>::iter_prefix(market_id) {
+                    ensure!(
+                        slot == hourly_ring::slot(bucket.hour_index, slots),
+                        "hourly bucket stored in the wrong ring slot"
+                    );
+                    ensure!(
+                        bucket.hour_index >= ring.evicted_before,
+                        "hourly bucket older than the ring's eviction cursor"
+                    );
+                    sum = sum.saturating_add(bucket.mm as u64);
+                    buckets += 1;
+                }
+                ensure!(
+                    sum == ring.sum_mm && buckets == ring.buckets,
+                    "hourly ring totals do not match its buckets"
+                );
+            }
+            for (market_id, _) in HourlyBuckets::<T>::iter_keys() {
+                ensure!(
+                    HourlyRing::<T>::contains_key(market_id),
+                    "hourly buckets without a ring"
+                );
+            }
+
+            for (market_id, _) in RainBuckets::<T>::iter_keys() {
+                ensure!(
+                    RollingState::<T>::contains_key(market_id),
+                    "rain buckets without rolling state"
+                );
+            }
+
+            Ok(())
+        }
+    }
+
+    // NOTE: API keys should be configured via environment variable ACCUWEATHER_API_KEY
+    // or set at runtime using the set_accuweather_api_key extrinsic.
+    // See .env.example for configuration template.
+
+    impl<T: Config> Pallet<T> {
+        /// Get AccuWeather API key from offchain storage.
+        /// 
+        /// The key can be injected via:
+        /// 1. Genesis config with environment variable ACCUWEATHER_API_KEY
+        /// 2. CLI: `prmx-node inject-api-key --key "prmx-oracle::accuweather-api-key" --value "YOUR_KEY"`
+        /// 3. Extrinsic: `prmxOracle.setAccuweatherApiKey`
+        /// 
+        /// Based on the offchain-utils pattern from polkadot-confidential-offchain-worker.
+        fn get_accuweather_api_key() -> Option<Vec<u8>> {
+            // Priority 1: Check local storage (PERSISTENT kind) - fastest access
+            let local_storage = sp_io::offchain::local_storage_get(
+                sp_core::offchain::StorageKind::PERSISTENT,
+                ACCUWEATHER_API_KEY_STORAGE,
+            );
+
+            if let Some(ref key) = local_storage {
+                if !key.is_empty() {
+                    log::debug!(
+                        target: "prmx-oracle",
+                        "Using AccuWeather API key from local storage (length: {} bytes)",
+                        key.len()
+                    );
+                    return local_storage;
+                }
+            }
+            
+            // Priority 2: Check offchain index (set by genesis)
+            // This is populated during block import from genesis data
+            if let Some(key) = sp_io::offchain::local_storage_get(
+                sp_core::offchain::StorageKind::PERSISTENT,
+                // Try the indexed storage key that genesis might have populated
+                b"prmx-oracle::api-key-indexed",
+            ) {
+                if !key.is_empty() {
+                    // Copy to local storage for faster future access
+                    sp_io::offchain::local_storage_set(
+                        sp_core::offchain::StorageKind::PERSISTENT,
+                        ACCUWEATHER_API_KEY_STORAGE,
+                        &key,
+                    );
+                    
+                    log::info!(
+                        target: "prmx-oracle",
+                        "✅ Found AccuWeather API key from offchain index (length: {} bytes)",
+                        key.len()
+                    );
+                    return Some(key);
+                }
+            }
+            
+            // Priority 3: Check if there's a pending API key from on-chain storage
+            // This is set by the set_accuweather_api_key extrinsic
+            if let Some(pending_key) = PendingApiKey::<T>::get() {
+                if !pending_key.is_empty() {
+                    let key_vec: Vec<u8> = pending_key.into();
+                    
+                    // Copy to local storage for persistence
+                    sp_io::offchain::local_storage_set(
+                        sp_core::offchain::StorageKind::PERSISTENT,
+                        ACCUWEATHER_API_KEY_STORAGE,
+                        &key_vec,
+                    );
+                    
+                    log::info!(
+                        target: "prmx-oracle",
+                        "✅ Copied AccuWeather API key from on-chain to local storage (length: {} bytes)",
+                        key_vec.len()
+                    );
+                    
+                    // Note: We can't clear PendingApiKey here because we're in offchain context
+                    // It will be cleared in on_initialize after a few blocks
+                    
+                    return Some(key_vec);
+                }
+            }
+            
+            log::warn!(
+                target: "prmx-oracle",
+                "⚠️ AccuWeather API key not configured. Inject via CLI or extrinsic."
+            );
+            None
+        }
+
+        /// Process pending manual fetch requests
+        /// Returns true if any requests were processed
+        /// Submit an unsigned heartbeat signed by each local oracle authority key
+        fn send_heartbeat(block_number: BlockNumberFor<T>) -> Result<(), OcwErrorKind> {
+            use frame_system::offchain::{SendUnsignedTransaction, Signer};
+
+            let signer = Signer::<T, T::AuthorityId>::all_accounts();
+
+            if !signer.can_sign() {
+                return Err(OcwErrorKind::Keystore);
+            }
+
+            let results = signer.send_unsigned_transaction(
+                |account| HeartbeatPayload {
+                    block_number,
+                    public: account.public.clone(),
+                },
+                |heartbeat, signature| Call::report_heartbeat { heartbeat, signature },
+            );
+
+            for (acc, result) in &results {
+                if result.is_err() {
+                    log::warn!(
+                        target: "prmx-oracle",
+                        "❌ Heartbeat tx from account {:?} failed",
+                        acc.id
+                    );
+                }
+            }
+
+            Ok(())
+        }
+
+        /// Submit the OCW counters accumulated since the last report, unsigned and signed
+        /// by each local oracle authority key, and clear what was reported
+        fn send_ocw_health_report(block_number: BlockNumberFor<T>) -> Result<(), OcwErrorKind> {
+            use frame_system::offchain::{SendUnsignedTransaction, Signer};
+
+            let signer = Signer::<T, T::AuthorityId>::all_accounts();
+
+            if !signer.can_sign() {
+                return Err(OcwErrorKind::Keystore);
+            }
+
+            let counters = ocw_metrics::current();
+            let results = signer.send_unsigned_transaction(
+                |account| OcwHealthReport {
+                    block_number,
+                    public: account.public.clone(),
+                    counters,
+                },
+                |report, signature| Call::report_ocw_health { report, signature },
+            );
+
+            if results.iter().any(|(_, result)| result.is_ok()) {
+                ocw_metrics::clear_reported(&counters);
+            }
+            for (acc, result) in &results {
+                if result.is_err() {
+                    log::warn!(
+                        target: "prmx-oracle",
+                        "❌ OCW health report tx from account {:?} failed",
+                        acc.id
+                    );
+                }
+            }
+
+            Ok(())
         }
 
-        /// Check if rainfall exceeded threshold during coverage window
-        /// Per oracle_design.md section 9.3
-        pub fn check_exceeded_threshold_in_window(
-            location_id: LocationId,
-            strike_mm: Millimeters,
-            coverage_start: u64,
-            coverage_end: u64,
-        ) -> Result<bool, Error<T>> {
-            ensure!(coverage_start < coverage_end, Error::<T>::InvalidCoverageWindow);
+        /// Gossip freshly fetched observations to the other authorities, unsigned and
+        /// signed by each local oracle authority key. Skipped when the same set already
+        /// reached quorum and sits in offchain storage.
+        fn send_observation_mirror(
+            market_id: MarketId,
+            provider: WeatherProvider,
+            observations: &[(u64, Millimeters)],
+        ) -> Result<(), OcwErrorKind> {
+            use frame_system::offchain::{SendUnsignedTransaction, Signer};
+
+            let normalized = gossip::normalize(observations);
+            if normalized.is_empty() || Self::mirrored_observations(market_id, provider) == normalized {
+                return Ok(());
+            }
 
-            let mut t = coverage_start;
-            while t <= coverage_end {
-                let sum = Self::calculate_rolling_sum_at(location_id, t);
-                if sum >= strike_mm {
-                    return Ok(true);
+            let signer = Signer::<T, T::AuthorityId>::all_accounts();
+
+            if !signer.can_sign() {
+                return Err(OcwErrorKind::Keystore);
+            }
+
+            // Keep the most recent 24 observations
+            let skip = observations.len().saturating_sub(24);
+            let bounded: BoundedVec<(u64, Millimeters), ConstU32<24>> =
+                observations[skip..].to_vec().try_into()
+                    .map_err(|_| OcwErrorKind::Parse)?;
+
+            let block_number = frame_system::Pallet::<T>::block_number();
+            let results = signer.send_unsigned_transaction(
+                |account| ObservationMirror {
+                    block_number,
+                    public: account.public.clone(),
+                    market_id,
+                    provider,
+                    observations: bounded.clone(),
+                },
+                |mirror, signature| Call::mirror_observations { mirror, signature },
+            );
+
+            for (acc, result) in &results {
+                if result.is_err() {
+                    log::warn!(
+                        target: "prmx-oracle",
+                        "❌ {} observation mirror for market {} from account {:?} failed",
+                        provider.name(),
+                        market_id,
+                        acc.id
+                    );
                 }
-                t = t.saturating_add(BUCKET_INTERVAL_SECS);
             }
 
-            Ok(false)
+            Ok(())
         }
 
-        /// Check all active policies across all markets and trigger settlements if threshold exceeded
-        /// This is called from on_initialize every BLOCKS_PER_SETTLEMENT_CHECK blocks
-        pub fn check_and_settle_triggered_policies(block_number: BlockNumberFor<T>) -> Weight {
-            use sp_runtime::traits::UniqueSaturatedInto;
-            let block_num: u32 = block_number.unique_saturated_into();
-            
-            log::debug!(
+        /// Observations of a market and provider that last reached mirror quorum, as
+        /// written to this node's offchain storage through offchain indexing
+        pub fn mirrored_observations(
+            market_id: MarketId,
+            provider: WeatherProvider,
+        ) -> Vec<(u64, Millimeters)> {
+            Self::get_offchain_value(&gossip::mirror_key(market_id, provider))
+                .and_then(|value| Vec::<(u64, Millimeters)>::decode(&mut &value[..]).ok())
+                .unwrap_or_default()
+        }
+
+        fn process_pending_fetch_requests(_block_number: BlockNumberFor<T>) -> bool {
+            // Check for pending fetch requests
+            let pending_markets: Vec<_> = PendingFetchRequests::<T>::iter()
+                .map(|(market_id, _)| market_id)
+                .collect();
+
+            if pending_markets.is_empty() {
+                return false;
+            }
+
+            log::info!(
                 target: "prmx-oracle",
-                "🔍 Settlement check running at block {}",
-                block_num
+                "📥 Found {} pending fetch request(s) to process",
+                pending_markets.len()
             );
-            
-            // Get current timestamp from the policy pallet (which has access to pallet_timestamp)
-            let current_time = T::PolicySettlement::current_time();
-            
-            let mut weight = Weight::from_parts(5_000, 0);
-            let mut settlements_triggered = 0u32;
-            
-            // Iterate through all markets
-            let next_market_id = pallet_prmx_markets::NextMarketId::<T>::get();
-            
-            for market_id in 0..next_market_id {
-                // Get rolling state for this market
-                let rolling_state = match RollingState::<T>::get(market_id) {
-                    Some(state) => state,
-                    None => {
-                        log::debug!(
-                            target: "prmx-oracle",
-                            "  Market {}: No rolling state data",
-                            market_id
-                        );
-                        continue; // No rainfall data for this market
-                    }
-                };
-                
-                // Get strike threshold for this market
-                let strike_threshold = match T::MarketsApi::strike_value(market_id) {
-                    Ok(strike) => strike,
-                    Err(_) => {
-                        log::debug!(
-                            target: "prmx-oracle",
-                            "  Market {}: Market not found",
-                            market_id
-                        );
-                        continue; // Market not found
-                    }
-                };
-                
-                let current_rolling_sum = rolling_state.rolling_sum_mm;
-                
-                log::debug!(
-                    target: "prmx-oracle",
-                    "  Market {}: rainfall={:.1}mm, strike={:.1}mm, threshold_breached={}",
-                    market_id,
-                    current_rolling_sum as f64 / 10.0,
-                    strike_threshold as f64 / 10.0,
-                    current_rolling_sum >= strike_threshold
-                );
-                
-                // Check if current rainfall exceeds threshold
-                if current_rolling_sum >= strike_threshold {
-                    log::info!(
+
+            // Get API key
+            let api_key = match Self::get_accuweather_api_key() {
+                Some(key) => key,
+                None => {
+                    log::warn!(
                         target: "prmx-oracle",
-                        "⚠️ Threshold breach detected! Market {}: {} mm >= {} mm threshold",
-                        market_id,
-                        current_rolling_sum as f64 / 10.0,
-                        strike_threshold as f64 / 10.0
+                        "Cannot process pending fetch requests: AccuWeather API key not configured"
                     );
-                    
-                    // Get all active policies in their coverage window for this market
-                    let active_policies = T::PolicySettlement::get_active_policies_in_window(market_id, current_time);
-                    
+                    return false;
+                }
+            };
+
+            let mut processed_any = false;
+
+            for market_id in pending_markets {
+                // Skip if we've already submitted a transaction for this market that's still in-flight
+                // This prevents duplicate submissions while waiting for on-chain processing
+                if Self::is_pending_fetch_inflight(market_id) {
                     log::info!(
                         target: "prmx-oracle",
-                        "🔍 Found {} active policies in coverage window for market {} (current_time={})",
-                        active_policies.len(),
-                        market_id,
-                        current_time
+                        "⏳ Skipping market {} - submission already in-flight",
+                        market_id
                     );
-                    
-                    if active_policies.is_empty() {
-                        log::warn!(
+                    ocw_metrics::record(ocw_metrics::OcwEvent::Backoff);
+                    continue;
+                }
+
+                log::info!(
+                    target: "prmx-oracle",
+                    "🌧️ Processing manual fetch request for market {}",
+                    market_id
+                );
+
+                // First, try to get location key from offchain cache
+                let location_key: Vec<u8> = match Self::get_location_key_from_offchain_index(market_id) {
+                    Some(key) => {
+                        log::info!(
                             target: "prmx-oracle",
-                            "⚠️ No active policies to settle for market {} - check coverage windows",
+                            "📖 Found cached location key for market {}",
                             market_id
                         );
+                        key
                     }
-                    
-                    let mut policies_settled_count = 0u32;
-                    
-                    for policy_id in active_policies {
-                        // Get policy info for logging
-                        if let Some((holder, _max_payout, _coverage_start, _coverage_end, _market_id)) = 
-                            T::PolicySettlement::get_policy_info(policy_id) 
-                        {
-                            // Get market coordinates for logging
-                            let (center_lat, center_lon) = T::MarketsApi::center_coordinates(market_id)
-                                .unwrap_or((0, 0));
-                            
-                            // Trigger immediate settlement
-                            match T::PolicySettlement::trigger_immediate_settlement(policy_id) {
-                                Ok(payout_amount) => {
-                                    // Create and store trigger log
-                                    let trigger_id = NextTriggerLogId::<T>::get();
-                                    NextTriggerLogId::<T>::put(trigger_id + 1);
-                                    
-                                    let trigger_log = ThresholdTriggerLog {
-                                        trigger_id,
-                                        market_id,
-                                        policy_id,
-                                        triggered_at: current_time,
-                                        block_number,
-                                        rolling_sum_mm: current_rolling_sum,
-                                        strike_threshold,
-                                        holder: holder.clone(),
-                                        payout_amount,
-                                        center_latitude: center_lat,
-                                        center_longitude: center_lon,
-                                    };
-                                    
-                                    ThresholdTriggerLogs::<T>::insert(trigger_id, trigger_log);
-                                    
-                                    // Emit event
-                                    Self::deposit_event(Event::ThresholdTriggered {
-                                        trigger_id,
-                                        market_id,
-                                        policy_id,
-                                        rolling_sum_mm: current_rolling_sum,
-                                        strike_threshold,
-                                        triggered_at: current_time,
-                                        payout_amount,
-                                    });
-                                    
-                                    settlements_triggered += 1;
-                                    policies_settled_count += 1;
-                                    
-                                    log::info!(
+                    None => {
+                        // No cached key - need to resolve from AccuWeather
+                        // Get market coordinates from MarketsApi
+                        let (lat, lon) = match T::MarketsApi::center_coordinates(market_id) {
+                            Ok(coords) => coords,
+                            Err(_) => {
+                                log::warn!(
+                                    target: "prmx-oracle",
+                                    "Market {} not found in markets pallet, skipping",
+                                    market_id
+                                );
+                                continue;
+                            }
+                        };
+
+                        let lat_f = lat as f64 / 1_000_000.0;
+                        let lon_f = lon as f64 / 1_000_000.0;
+
+                        log::info!(
+                            target: "prmx-oracle",
+                            "🔍 Resolving AccuWeather location key for new market {} (lat: {}, lon: {})",
+                            market_id,
+                            lat_f,
+                            lon_f
+                        );
+
+                        match Self::fetch_accuweather_location_key(&api_key, lat_f, lon_f) {
+                            Ok(key) => {
+                                let key_str = core::str::from_utf8(&key).unwrap_or("invalid");
+                                log::info!(
+                                    target: "prmx-oracle",
+                                    "✅ Resolved AccuWeather location key for new market {}: {}",
+                                    market_id,
+                                    key_str
+                                );
+
+                                // Store in offchain cache for future use
+                                let storage_key = Self::location_binding_key(market_id);
+                                sp_io::offchain::local_storage_set(
+                                    sp_core::offchain::StorageKind::PERSISTENT,
+                                    &storage_key,
+                                    &key,
+                                );
+
+                                // Also submit on-chain binding via signed transaction
+                                if let Err(e) = Self::submit_location_binding_tx(market_id, key.clone()) {
+                                    log::warn!(
                                         target: "prmx-oracle",
-                                        "✅ Auto-settled policy {} (trigger_id: {}) - Payout: {} to holder",
-                                        policy_id,
-                                        trigger_id,
-                                        payout_amount
+                                        "Failed to submit on-chain location binding for market {}: {:?}",
+                                        market_id,
+                                        e
                                     );
+                                    ocw_metrics::record_error(e);
                                 }
-                                Err(e) => {
+
+                                key
+                            }
+                            Err(e) => {
+                                log::warn!(
+                                    target: "prmx-oracle",
+                                    "❌ Failed to resolve location key for new market {}: {:?}",
+                                    market_id,
+                                    e
+                                );
+                                ocw_metrics::record_error(e);
+                                continue;
+                            }
+                        }
+                    }
+                };
+
+                let location_key_str = match core::str::from_utf8(&location_key) {
+                    Ok(key) => key,
+                    Err(_) => {
+                        log::warn!(
+                            target: "prmx-oracle",
+                            "Invalid location key encoding for market {}",
+                            market_id
+                        );
+                        continue;
+                    }
+                };
+
+                // Fetch rainfall data from AccuWeather
+                match Self::fetch_accuweather_rainfall(&api_key, location_key_str) {
+                    Ok(rainfall_data) => {
+                        log::info!(
+                            target: "prmx-oracle",
+                            "✅ Fetched {} rainfall records for market {} from AccuWeather",
+                            rainfall_data.len(),
+                            market_id
+                        );
+
+                        if !rainfall_data.is_empty() {
+                            // Store the fetched data in offchain index
+                            Self::store_fetched_rainfall_data(market_id, rainfall_data.clone());
+                            
+                            // Get the 24h rainfall sum and submit on-chain
+                            // AccuWeather Past24Hours gives us the 24h sum in the first entry
+                            if let Some((_, rainfall_mm)) = rainfall_data.first() {
+                                log::info!(
+                                    target: "prmx-oracle",
+                                    "🌧️ AccuWeather 24h rainfall for market {}: {:.1} mm - submitting on-chain",
+                                    market_id,
+                                    *rainfall_mm as f64 / 10.0
+                                );
+                                
+                                // Submit rainfall on-chain via signed transaction
+                                if let Err(e) = Self::submit_rainfall_signed_tx(market_id, *rainfall_mm) {
                                     log::warn!(
                                         target: "prmx-oracle",
-                                        "❌ Failed to auto-settle policy {}: {:?}",
-                                        policy_id,
+                                        "Failed to submit on-chain rainfall for market {}: {:?}",
+                                        market_id,
                                         e
                                     );
+                                    ocw_metrics::record_error(e);
+                                } else {
+                                    // Mark as in-flight to prevent duplicate submissions
+                                    // The in-flight marker will be cleared when:
+                                    // 1. The on-chain transaction is processed (clears PendingFetchRequests)
+                                    // 2. The marker expires after 3 minutes (staleness check)
+                                    Self::mark_pending_fetch_inflight(market_id);
+                                    
+                                    log::info!(
+                                        target: "prmx-oracle",
+                                        "✅ Submitted on-chain rainfall update for market {}: {:.1} mm (marked in-flight)",
+                                        market_id,
+                                        *rainfall_mm as f64 / 10.0
+                                    );
                                 }
                             }
+                            
+                            processed_any = true;
                         }
-                        
-                        // Add weight for each policy processed
-                        weight = weight.saturating_add(Weight::from_parts(50_000, 0));
                     }
-                    
-                    // Reset the rolling state after trigger to continue monitoring for future policies
-                    // This ensures the oracle starts fresh after a threshold event
-                    if policies_settled_count > 0 {
-                        // Reset rolling state to zero
-                        let reset_state = RollingWindowState {
-                            last_bucket_index: rolling_state.last_bucket_index,
-                            oldest_bucket_index: rolling_state.last_bucket_index, // Start fresh
-                            rolling_sum_mm: 0, // Reset to zero
-                        };
-                        RollingState::<T>::insert(market_id, reset_state);
-                        
-                        // Clear old rain buckets for this market
-                        // Keep only the current bucket index as reference point
-                        let _ = RainBuckets::<T>::clear_prefix(market_id, u32::MAX, None);
-                        
-                        log::info!(
+                    Err(e) => {
+                        log::warn!(
                             target: "prmx-oracle",
-                            "🔄 Reset rainfall data for market {} after settling {} policies",
+                            "Failed to fetch rainfall for market {}: {:?}",
                             market_id,
-                            policies_settled_count
+                            e
                         );
-                        
-                        Self::deposit_event(Event::RollingSumUpdated {
-                            location_id: market_id,
-                            rolling_sum_mm: 0,
-                        });
+                        ocw_metrics::record_error(e);
                     }
                 }
-                
-                // Add weight for each market processed
-                weight = weight.saturating_add(Weight::from_parts(10_000, 0));
             }
+
+            processed_any
+        }
+
+        /// Store fetched rainfall data in offchain indexed storage for logging/reference
+        fn store_fetched_rainfall_data(
+            market_id: MarketId,
+            rainfall_data: Vec<(u64, Millimeters)>,
+        ) {
+            // Store data in offchain index for reference
+            let key = Self::pending_rainfall_data_key(market_id);
+            let encoded_data = rainfall_data.encode();
+            sp_io::offchain_index::set(&key, &encoded_data);
             
-            if settlements_triggered > 0 {
-                log::info!(
-                    target: "prmx-oracle",
-                    "🏁 Settlement check complete: {} policies auto-settled",
-                    settlements_triggered
-                );
+            log::info!(
+                target: "prmx-oracle",
+                "📝 Stored {} rainfall records in offchain index for market {}",
+                rainfall_data.len(),
+                market_id
+            );
+        }
+
+        /// Generate offchain index key for pending rainfall data
+        fn pending_rainfall_data_key(market_id: MarketId) -> Vec<u8> {
+            let mut key = b"prmx-oracle::pending-rainfall::".to_vec();
+            key.extend_from_slice(&market_id.to_le_bytes());
+            key
+        }
+
+        /// Combined function: resolve location bindings AND fetch rainfall data
+        /// This handles both in a single pass to avoid storage persistence issues with --tmp
+        fn process_markets_and_fetch_rainfall(
+            api_key: &[u8],
+            _block_number: BlockNumberFor<T>,
+            should_check_bindings: bool,
+            force_fetch: bool,
+            due_markets: &[MarketId],
+        ) -> Result<(), OcwErrorKind> {
+            use pallet_prmx_markets::Markets;
+
+            let mut processed = 0u32;
+            const MAX_MARKETS_PER_BLOCK: u32 = 10; // Support up to 10 markets
+
+            let next_id = pallet_prmx_markets::NextMarketId::<T>::get();
+            
+            log::info!(
+                target: "prmx-oracle",
+                "🔄 Processing {} markets (fetch_rainfall: {}, due: {})",
+                next_id,
+                force_fetch,
+                due_markets.len()
+            );
+
+            for market_id in 0..next_id {
+                if processed >= MAX_MARKETS_PER_BLOCK {
+                    break;
+                }
+
+                let should_fetch_rainfall = force_fetch || due_markets.contains(&market_id);
+                if !should_check_bindings && !should_fetch_rainfall {
+                    continue;
+                }
+
+                // Get market info
+                let market = match Markets::<T>::get(market_id) {
+                    Some(m) => m,
+                    None => continue,
+                };
+                if !market.status.is_oracle_active() {
+                    continue;
+                }
+
+                // Get center coordinates
+                let lat = market.center_latitude as f64 / 1_000_000.0;
+                let lon = market.center_longitude as f64 / 1_000_000.0;
+
+                // Secondary providers are queried by coordinates, independent of the
+                // AccuWeather location binding below
+                if should_fetch_rainfall {
+                    Self::fetch_and_store_secondary_providers(market_id, lat, lon);
+                }
+
+                // First, try to get location key from offchain local storage
+                let location_key = Self::get_location_key_from_offchain_index(market_id);
+                
+                let location_key: Vec<u8> = match location_key {
+                    Some(key) => {
+                        log::info!(
+                            target: "prmx-oracle",
+                            "📖 Found cached location key for market {}",
+                            market_id
+                        );
+                        key
+                    }
+                    None => {
+                        // Need to resolve location key from AccuWeather
+                        log::info!(
+                            target: "prmx-oracle",
+                            "🔍 Resolving AccuWeather location key for market {} (lat: {}, lon: {})",
+                            market_id,
+                            lat,
+                            lon
+                        );
+                        
+                        match Self::fetch_accuweather_location_key(api_key, lat, lon) {
+                            Ok(key) => {
+                                let key_str = core::str::from_utf8(&key).unwrap_or("invalid");
+                                log::info!(
+                                    target: "prmx-oracle",
+                                    "✅ Resolved AccuWeather location key for market {}: {}",
+                                    market_id,
+                                    key_str
+                                );
+
+                                // Store for future use
+                                let storage_key = Self::location_binding_key(market_id);
+                                sp_io::offchain::local_storage_set(
+                                    sp_core::offchain::StorageKind::PERSISTENT,
+                                    &storage_key,
+                                    &key,
+                                );
+                                
+                                key
+                            }
+                            Err(e) => {
+                                log::warn!(
+                                    target: "prmx-oracle",
+                                    "❌ Failed to resolve location key for market {}: {:?}",
+                                    market_id,
+                                    e
+                                );
+                                ocw_metrics::record_error(e);
+                                continue;
+                            }
+                        }
+                    }
+                };
+
+                // Now fetch rainfall if enabled
+                if should_fetch_rainfall {
+                    let key_str = core::str::from_utf8(&location_key).unwrap_or("invalid");
+                    log::info!(
+                        target: "prmx-oracle",
+                        "🌧️ Fetching 24h rainfall for market {} from AccuWeather (location: {})",
+                        market_id,
+                        key_str
+                    );
+
+                    if let Err(e) = Self::fetch_and_store_rainfall(api_key, key_str, market_id) {
+                        log::warn!(
+                            target: "prmx-oracle",
+                            "❌ Failed to fetch rainfall for market {}: {:?}",
+                            market_id,
+                            e
+                        );
+                        ocw_metrics::record_error(e);
+                    }
+                }
+
+                processed += 1;
             }
-            
-            weight
+
+            log::info!(
+                target: "prmx-oracle",
+                "🔄 Completed processing {} markets",
+                processed
+            );
+
+            Ok(())
         }
-        
-        /// Maximum number of expired policies to settle per block
-        /// Limits block weight while ensuring backlog is cleared within reasonable time
-        const MAX_EXPIRATION_SETTLEMENTS_PER_BLOCK: u32 = 10;
-        
-        /// Check all expired policies and settle them automatically
-        /// This is called from on_initialize every BLOCKS_PER_SETTLEMENT_CHECK blocks
-        pub fn check_and_settle_expired_policies(block_number: BlockNumberFor<T>) -> Weight {
-            let current_time = T::PolicySettlement::current_time();
-            let mut weight = Weight::from_parts(5_000, 0);
-            let mut settlements_count = 0u32;
-            
-            // Get all expired policies (coverage ended, still active)
-            let expired_policies = T::PolicySettlement::get_expired_policies(current_time);
+
+        /// Get location key from offchain indexed storage
+        fn get_location_key_from_offchain_index(market_id: MarketId) -> Option<Vec<u8>> {
+            let key = Self::location_binding_key(market_id);
             
-            if expired_policies.is_empty() {
-                return weight;
-            }
+            // Read from offchain local storage (where offchain_index::set stores data)
+            let value = sp_io::offchain::local_storage_get(
+                sp_core::offchain::StorageKind::PERSISTENT,
+                &key,
+            );
             
             log::info!(
                 target: "prmx-oracle",
-                "📋 Found {} expired policies to settle (current_time={})",
-                expired_policies.len(),
-                current_time
+                "📖 Reading offchain index for market {}: found = {}",
+                market_id,
+                value.is_some()
             );
             
-            for policy_id in expired_policies {
-                if settlements_count >= Self::MAX_EXPIRATION_SETTLEMENTS_PER_BLOCK {
+            match value {
+                Some(data) if !data.is_empty() => {
+                    let key_str = core::str::from_utf8(&data).unwrap_or("invalid");
                     log::info!(
                         target: "prmx-oracle",
-                        "⏸️ Reached max settlements per block ({}), deferring remaining to next block",
-                        Self::MAX_EXPIRATION_SETTLEMENTS_PER_BLOCK
+                        "📖 Found offchain location key for market {}: {}",
+                        market_id,
+                        key_str
                     );
-                    break; // Defer remaining to next block
+                    Some(data)
                 }
-                
-                // Get policy info to determine event outcome
-                if let Some((_holder, _max_payout, coverage_start, coverage_end, market_id)) = 
-                    T::PolicySettlement::get_policy_info(policy_id) 
-                {
-                    // Get strike threshold for this market
-                    let strike_mm = match T::MarketsApi::strike_value(market_id) {
-                        Ok(strike) => strike,
-                        Err(_) => {
-                            log::warn!(
-                                target: "prmx-oracle",
-                                "❌ Could not get strike value for market {}, skipping policy {}",
-                                market_id,
-                                policy_id
-                            );
-                            continue;
-                        }
-                    };
-                    
-                    // Check if event occurred during coverage window using oracle data
-                    let event_occurred = Self::check_exceeded_threshold_in_window(
+                _ => None,
+            }
+        }
+
+        /// Fetch rainfall data and submit signed transaction to update on-chain storage
+        /// Now uses historical/24 endpoint and stores individual hourly buckets
+        fn fetch_and_store_rainfall(
+            api_key: &[u8],
+            location_key: &str,
+            market_id: MarketId,
+        ) -> Result<(), OcwErrorKind> {
+            let body = match Self::fetch_accuweather_historical(api_key, location_key) {
+                Ok(body) => body,
+                Err(e) => {
+                    log::warn!(
+                        target: "prmx-oracle",
+                        "Failed to fetch rainfall for market {}: {:?}",
                         market_id,
-                        strike_mm,
-                        coverage_start,
-                        coverage_end,
-                    ).unwrap_or(false);
-                    
+                        e
+                    );
+                    ocw_metrics::record_error(e);
+                    return Ok(());
+                }
+            };
+
+            // Temperature, wind gusts and snowfall are read from the same hourly
+            // observations as rainfall
+            Self::store_hourly_temperature(market_id, &body);
+            Self::store_hourly_wind_gust(market_id, &body);
+            Self::store_hourly_snowfall(market_id, &body);
+
+            match Self::extract_hourly_rainfall_data(&body) {
+                Ok(rainfall_data) => {
+                    if let Err(e) = Self::send_observation_mirror(
+                        market_id,
+                        WeatherProvider::AccuWeather,
+                        &rainfall_data,
+                    ) {
+                        log::warn!(
+                            target: "prmx-oracle",
+                            "❌ Failed to mirror rainfall for market {}: {:?}",
+                            market_id,
+                            e
+                        );
+                        ocw_metrics::record_error(e);
+                    }
+                    let rainfall_data = Self::route_hourly_corrections(
+                        market_id,
+                        WeatherProvider::AccuWeather,
+                        rainfall_data,
+                    );
                     log::info!(
                         target: "prmx-oracle",
-                        "🔍 Policy {} expired: coverage [{}, {}], strike {} mm, event_occurred: {}",
-                        policy_id,
-                        coverage_start,
-                        coverage_end,
-                        strike_mm as f64 / 10.0,
-                        event_occurred
+                        "📊 Fetched {} hourly rainfall records for market {}",
+                        rainfall_data.len(),
+                        market_id
                     );
-                    
-                    // Settle the policy
-                    match T::PolicySettlement::settle_expired_policy(policy_id, event_occurred) {
-                        Ok(payout) => {
-                            log::info!(
-                                target: "prmx-oracle",
-                                "✅ Auto-settled expired policy {} (event: {}, payout: {})",
-                                policy_id,
-                                event_occurred,
-                                payout
-                            );
-                            
-                            Self::deposit_event(Event::PolicyExpirationSettled {
-                                policy_id,
-                                event_occurred,
-                                payout_amount: payout,
-                            });
-                            
-                            settlements_count += 1;
-                        }
-                        Err(e) => {
-                            log::warn!(
-                                target: "prmx-oracle",
-                                "❌ Failed to auto-settle expired policy {}: {:?}",
-                                policy_id,
-                                e
-                            );
+
+                    if !rainfall_data.is_empty() {
+                        // Calculate total for logging
+                        let total_mm: Millimeters = rainfall_data.iter().map(|(_, mm)| *mm).sum();
+                        log::info!(
+                            target: "prmx-oracle",
+                            "🌧️ Submitting {} hourly readings for market {} (total: {:.1} mm)",
+                            rainfall_data.len(),
+                            market_id,
+                            total_mm as f64 / 10.0
+                        );
+
+                        // Submit hourly data via signed transaction
+                        let result = Self::submit_hourly_rainfall_signed_tx(market_id, rainfall_data.clone());
+                        
+                        match result {
+                            Ok(()) => {
+                                log::info!(
+                                    target: "prmx-oracle",
+                                    "✅ Hourly rainfall submitted for market {} ({} readings)",
+                                    market_id,
+                                    rainfall_data.len()
+                                );
+                            }
+                            Err(e) => {
+                                log::warn!(
+                                    target: "prmx-oracle",
+                                    "❌ Failed to submit hourly rainfall for market {}: {:?}",
+                                    market_id,
+                                    e
+                                );
+                                ocw_metrics::record_error(e);
+                                // Fallback: try legacy single-value submission with total
+                                if let Some((timestamp, _)) = rainfall_data.first() {
+                                    let key = Self::rainfall_data_key(market_id, *timestamp);
+                                    let value = total_mm.to_le_bytes();
+                                    sp_io::offchain_index::set(&key, &value);
+                                }
+                            }
                         }
+                    } else {
+                        log::debug!(
+                            target: "prmx-oracle",
+                            "No rainfall data returned for market {}",
+                            market_id
+                        );
                     }
                 }
-                
-                // Add weight for each policy processed
-                weight = weight.saturating_add(Weight::from_parts(100_000, 0));
-            }
-            
-            if settlements_count > 0 {
-                log::info!(
-                    target: "prmx-oracle",
-                    "🏁 Expiration settlement complete: {} policies auto-settled",
-                    settlements_count
-                );
+                Err(e) => {
+                    log::warn!(
+                        target: "prmx-oracle",
+                        "Failed to parse rainfall for market {}: {:?}",
+                        market_id,
+                        e
+                    );
+                    ocw_metrics::record_error(e);
+                }
             }
-            
-            weight
-        }
-    }
-
-    // =========================================================================
-    //                          Offchain Worker
-    // =========================================================================
-
-    /// Offchain storage key for AccuWeather API key
-    pub const ACCUWEATHER_API_KEY_STORAGE: &[u8] = b"prmx-oracle::accuweather-api-key";
-
-    /// Offchain storage key prefix for tracking in-flight pending fetch submissions
-    /// This prevents duplicate submissions while waiting for on-chain transaction to be processed
-    pub const PENDING_FETCH_INFLIGHT_PREFIX: &[u8] = b"prmx-oracle::pending-fetch-inflight::";
 
-    /// AccuWeather API base URL
-    pub const ACCUWEATHER_BASE_URL: &str = "https://dataservice.accuweather.com";
+            Ok(())
+        }
 
-    #[pallet::hooks]
-    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-        /// On initialize hook:
-        /// 1. Clear API key configured flag after offchain worker has had time to fetch
-        /// 2. Check for threshold breaches and trigger automatic settlements (every BLOCKS_PER_SETTLEMENT_CHECK blocks)
-        /// 3. Check for expired policies and settle them automatically (every BLOCKS_PER_SETTLEMENT_CHECK blocks)
-        fn on_initialize(block_number: BlockNumberFor<T>) -> Weight {
-            use sp_runtime::traits::UniqueSaturatedInto;
-            let block_num: u32 = block_number.unique_saturated_into();
-            
-            let mut weight = Weight::zero();
-            
-            // =========================================================================
-            // Clear API key configured flag after enough blocks for OCW to pick it up
-            // The OCW runs during startup (blocks 0-9) and every BLOCKS_PER_HOUR (600).
-            // We keep the pending key for 100 blocks to ensure the OCW has a chance to copy it.
-            // =========================================================================
-            if let Some(configured_at) = ApiKeyConfiguredAt::<T>::get() {
-                // Clear the flag and pending key after 100 blocks (ensure OCW has time to copy)
-                if block_number > configured_at + 100u32.into() {
-                    ApiKeyConfiguredAt::<T>::kill();
-                    PendingApiKey::<T>::kill(); // Clear the temporary on-chain key
-                    weight = weight.saturating_add(Weight::from_parts(10_000, 0));
+        /// Parse hourly temperature from a historical/24 response and submit it on-chain
+        fn store_hourly_temperature(market_id: MarketId, body: &[u8]) {
+            let temperature_data = match accuweather::extract_hourly_temperature(body) {
+                Ok(data) if !data.is_empty() => data,
+                Ok(_) => {
+                    log::debug!(
+                        target: "prmx-oracle",
+                        "No temperature data returned for market {}",
+                        market_id
+                    );
+                    return;
+                }
+                Err(e) => {
+                    log::warn!(
+                        target: "prmx-oracle",
+                        "Failed to parse temperature for market {}: {:?}",
+                        market_id,
+                        e
+                    );
+                    ocw_metrics::record_error(e);
+                    return;
                 }
+            };
+
+            if let Err(e) = Self::submit_hourly_temperature_signed_tx(market_id, temperature_data) {
+                log::warn!(
+                    target: "prmx-oracle",
+                    "❌ Failed to submit hourly temperature for market {}: {:?}",
+                    market_id,
+                    e
+                );
+                ocw_metrics::record_error(e);
             }
+        }
+
+        /// Submit a signed transaction to update on-chain rainfall data
+        fn submit_rainfall_signed_tx(
+            market_id: MarketId,
+            rainfall_mm: Millimeters,
+        ) -> Result<(), OcwErrorKind> {
+            use frame_system::offchain::{Signer, SendSignedTransaction};
+
+            // Get signer from keystore
+            let signer = Signer::<T, T::AuthorityId>::all_accounts();
             
-            // =========================================================================
-            // Automatic settlement check (every BLOCKS_PER_SETTLEMENT_CHECK blocks)
-            // =========================================================================
-            let should_check_settlements = block_num % BLOCKS_PER_SETTLEMENT_CHECK == 0;
-            
-            if should_check_settlements {
-                // Check for threshold breaches during active coverage
-                let settlements_weight = Self::check_and_settle_triggered_policies(block_number);
-                weight = weight.saturating_add(settlements_weight);
-                
-                // Check for expired policies that need settlement
-                let expiration_weight = Self::check_and_settle_expired_policies(block_number);
-                weight = weight.saturating_add(expiration_weight);
+            if !signer.can_sign() {
+                log::warn!(
+                    target: "prmx-oracle",
+                    "⚠️ No oracle authority keys found in keystore. Cannot submit signed tx."
+                );
+                return Err(OcwErrorKind::Keystore);
             }
 
-            weight
-        }
+            // Create the call
+            let call = Call::<T>::submit_rainfall_from_ocw {
+                market_id,
+                rainfall_mm,
+            };
 
-        /// Offchain worker entry point
-        /// Per oracle_design.md section 7.2
-        fn offchain_worker(block_number: BlockNumberFor<T>) {
-            // Convert block number to u32 for modulo check
-            use sp_runtime::traits::UniqueSaturatedInto;
-            let block_num: u32 = block_number.unique_saturated_into();
+            // Send signed transaction
+            let results = signer.send_signed_transaction(|_account| call.clone());
+            ocw_metrics::record_submissions(&results);
 
-            // =========================================================================
-            // Priority 1: Process pending manual fetch requests (every block)
-            // =========================================================================
-            let has_pending_requests = Self::process_pending_fetch_requests(block_number);
+            for (acc, result) in &results {
+                match result {
+                    Ok(()) => {
+                        log::info!(
+                            target: "prmx-oracle",
+                            "✅ Signed tx sent from account {:?}",
+                            acc.id
+                        );
+                        return Ok(());
+                    }
+                    Err(e) => {
+                        log::warn!(
+                            target: "prmx-oracle",
+                            "❌ Signed tx from account {:?} failed: {:?}",
+                            acc.id,
+                            e
+                        );
+                    }
+                }
+            }
 
-            // Check if API key was just configured and immediate fetch is needed
-            let api_key_just_configured = ApiKeyConfiguredAt::<T>::get().is_some();
-            
-            // Check if there's a pending API key that needs to be copied to local storage
-            // We check this on every block to ensure quick pickup after extrinsic submission
-            let has_pending_api_key = PendingApiKey::<T>::get().map_or(false, |k| !k.is_empty());
-            
-            // Determine what operations to run based on block number
-            // - Rainfall ingestion: once per hour (every 600 blocks), or first 10 blocks for quick startup
-            // - Location binding: every ~10 minutes (every 100 blocks) for new markets
-            // - Immediate fetch: when API key is newly configured or pending
-            let is_startup_window = block_num < 10; // Run more frequently during startup
-            let should_fetch_rainfall = is_startup_window || block_num % BLOCKS_PER_HOUR == 0 || api_key_just_configured || has_pending_api_key;
-            let should_check_bindings = is_startup_window || block_num % BLOCKS_PER_BINDING_CHECK == 0 || api_key_just_configured || has_pending_api_key;
+            Err(OcwErrorKind::Submission)
+        }
 
-            // Early return if nothing to do this block (and no pending requests processed)
-            if !should_fetch_rainfall && !should_check_bindings && !has_pending_requests {
-                return;
-            }
+        /// Submit hourly rainfall data via signed transaction
+        /// Uses the new submit_hourly_rainfall_from_ocw extrinsic
+        fn submit_hourly_rainfall_signed_tx(
+            market_id: MarketId,
+            hourly_data: Vec<(u64, Millimeters)>,
+        ) -> Result<(), OcwErrorKind> {
+            use frame_system::offchain::{Signer, SendSignedTransaction};
+
+            // Get signer from keystore
+            let signer = Signer::<T, T::AuthorityId>::all_accounts();
             
-            // Log if this is an immediate fetch triggered by API key configuration
-            if api_key_just_configured || has_pending_api_key {
-                log::info!(
+            if !signer.can_sign() {
+                log::warn!(
                     target: "prmx-oracle",
-                    "🚀 API key pending - triggering immediate rainfall fetch for all markets"
+                    "⚠️ No oracle authority keys found in keystore. Cannot submit hourly rainfall tx."
                 );
+                return Err(OcwErrorKind::Keystore);
             }
 
-            // Log occasionally to show worker is alive
-            if should_check_bindings || is_startup_window || has_pending_requests || has_pending_api_key {
-                log::info!(
-                    target: "prmx-oracle",
-                    "Offchain worker at block {} (startup: {}, rainfall: {}, bindings: {}, pending: {}, api_key_pending: {})",
-                    block_num,
-                    is_startup_window,
-                    should_fetch_rainfall,
-                    should_check_bindings,
-                    has_pending_requests,
-                    has_pending_api_key
-                );
+            // Convert to BoundedVec (max 24 entries)
+            let bounded_data: BoundedVec<(u64, Millimeters), ConstU32<24>> = 
+                hourly_data.into_iter().take(24).collect::<Vec<_>>().try_into()
+                    .map_err(|_| OcwErrorKind::Parse)?;
+
+            // Create the call
+            let call = Call::<T>::submit_hourly_rainfall_from_ocw {
+                market_id,
+                hourly_data: bounded_data,
+            };
+
+            // Send signed transaction
+            let results = signer.send_signed_transaction(|_account| call.clone());
+            ocw_metrics::record_submissions(&results);
+
+            for (acc, result) in &results {
+                match result {
+                    Ok(()) => {
+                        log::info!(
+                            target: "prmx-oracle",
+                            "✅ Hourly rainfall tx sent from account {:?}",
+                            acc.id
+                        );
+                        return Ok(());
+                    }
+                    Err(e) => {
+                        log::warn!(
+                            target: "prmx-oracle",
+                            "❌ Hourly rainfall tx from account {:?} failed: {:?}",
+                            acc.id,
+                            e
+                        );
+                    }
+                }
             }
 
-            // Try to get API key from offchain local storage
-            let api_key = Self::get_accuweather_api_key();
+            Err(OcwErrorKind::Submission)
+        }
 
-            match api_key {
-                Some(key) => {
-                    let key_preview = core::str::from_utf8(&key[..10.min(key.len())])
-                        .unwrap_or("invalid");
+        /// Parse hourly wind gusts from a historical/24 response and submit them on-chain
+        fn store_hourly_wind_gust(market_id: MarketId, body: &[u8]) {
+            let wind_data = match accuweather::extract_hourly_wind_gust(body) {
+                Ok(data) if !data.is_empty() => data,
+                Ok(_) => {
                     log::debug!(
                         target: "prmx-oracle",
-                        "AccuWeather API key configured ({}...)",
-                        key_preview
+                        "No wind gust data returned for market {}",
+                        market_id
                     );
-
-                    // Process markets: resolve bindings AND fetch rainfall
-                    // This combined approach handles both binding resolution and rainfall fetching
-                    // in the same offchain worker invocation to avoid storage persistence issues
-                    if should_check_bindings || should_fetch_rainfall {
-                        if let Err(e) = Self::process_markets_and_fetch_rainfall(&key, block_number, should_fetch_rainfall) {
-                            log::warn!(
-                                target: "prmx-oracle",
-                                "Error processing markets: {:?}",
-                                e
-                            );
-                        }
-                    }
+                    return;
                 }
-                None => {
+                Err(e) => {
                     log::warn!(
                         target: "prmx-oracle",
-                        "AccuWeather API key not configured. Set it via offchain storage or environment variable ACCUWEATHER_API_KEY"
+                        "Failed to parse wind gusts for market {}: {:?}",
+                        market_id,
+                        e
                     );
+                    ocw_metrics::record_error(e);
+                    return;
                 }
+            };
+
+            if let Err(e) = Self::submit_hourly_wind_gust_signed_tx(market_id, wind_data) {
+                log::warn!(
+                    target: "prmx-oracle",
+                    "❌ Failed to submit hourly wind gusts for market {}: {:?}",
+                    market_id,
+                    e
+                );
+                ocw_metrics::record_error(e);
             }
         }
-    }
 
-    // NOTE: API keys should be configured via environment variable ACCUWEATHER_API_KEY
-    // or set at runtime using the set_accuweather_api_key extrinsic.
-    // See .env.example for configuration template.
+        /// Submit hourly wind gust data via signed transaction
+        fn submit_hourly_wind_gust_signed_tx(
+            market_id: MarketId,
+            hourly_data: Vec<(u64, DeciKmh)>,
+        ) -> Result<(), OcwErrorKind> {
+            use frame_system::offchain::{Signer, SendSignedTransaction};
 
-    impl<T: Config> Pallet<T> {
-        /// Get AccuWeather API key from offchain storage.
-        /// 
-        /// The key can be injected via:
-        /// 1. Genesis config with environment variable ACCUWEATHER_API_KEY
-        /// 2. CLI: `prmx-node inject-api-key --key "prmx-oracle::accuweather-api-key" --value "YOUR_KEY"`
-        /// 3. Extrinsic: `prmxOracle.setAccuweatherApiKey`
-        /// 
-        /// Based on the offchain-utils pattern from polkadot-confidential-offchain-worker.
-        fn get_accuweather_api_key() -> Option<Vec<u8>> {
-            // Priority 1: Check local storage (PERSISTENT kind) - fastest access
-            let local_storage = sp_io::offchain::local_storage_get(
-                sp_core::offchain::StorageKind::PERSISTENT,
-                ACCUWEATHER_API_KEY_STORAGE,
-            );
+            let signer = Signer::<T, T::AuthorityId>::all_accounts();
 
-            if let Some(ref key) = local_storage {
-                if !key.is_empty() {
-                    log::debug!(
-                        target: "prmx-oracle",
-                        "Using AccuWeather API key from local storage (length: {} bytes)",
-                        key.len()
-                    );
-                    return local_storage;
+            if !signer.can_sign() {
+                return Err(OcwErrorKind::Keystore);
+            }
+
+            let bounded_data: BoundedVec<(u64, DeciKmh), ConstU32<24>> =
+                hourly_data.into_iter().take(24).collect::<Vec<_>>().try_into()
+                    .map_err(|_| OcwErrorKind::Parse)?;
+
+            let call = Call::<T>::submit_hourly_wind_gust_from_ocw {
+                market_id,
+                hourly_data: bounded_data,
+            };
+
+            let results = signer.send_signed_transaction(|_account| call.clone());
+            ocw_metrics::record_submissions(&results);
+
+            for (acc, result) in &results {
+                match result {
+                    Ok(()) => {
+                        log::info!(
+                            target: "prmx-oracle",
+                            "✅ Hourly wind gust tx sent from account {:?}",
+                            acc.id
+                        );
+                        return Ok(());
+                    }
+                    Err(e) => {
+                        log::warn!(
+                            target: "prmx-oracle",
+                            "❌ Hourly wind gust tx from account {:?} failed: {:?}",
+                            acc.id,
+                            e
+                        );
+                    }
                 }
             }
-            
-            // Priority 2: Check offchain index (set by genesis)
-            // This is populated during block import from genesis data
-            if let Some(key) = sp_io::offchain::local_storage_get(
-                sp_core::offchain::StorageKind::PERSISTENT,
-                // Try the indexed storage key that genesis might have populated
-                b"prmx-oracle::api-key-indexed",
-            ) {
-                if !key.is_empty() {
-                    // Copy to local storage for faster future access
-                    sp_io::offchain::local_storage_set(
-                        sp_core::offchain::StorageKind::PERSISTENT,
-                        ACCUWEATHER_API_KEY_STORAGE,
-                        &key,
-                    );
-                    
-                    log::info!(
+
+            Err(OcwErrorKind::Submission)
+        }
+
+        /// Parse hourly snowfall from a historical/24 response and submit it on-chain.
+        /// Responses without any snow reading (most markets, most of the year) submit nothing.
+        fn store_hourly_snowfall(market_id: MarketId, body: &[u8]) {
+            let snow_data = match accuweather::extract_hourly_snowfall(body) {
+                Ok(data) if !data.is_empty() => data,
+                Ok(_) => {
+                    log::debug!(
                         target: "prmx-oracle",
-                        "✅ Found AccuWeather API key from offchain index (length: {} bytes)",
-                        key.len()
+                        "No snowfall data returned for market {}",
+                        market_id
                     );
-                    return Some(key);
+                    return;
                 }
-            }
-            
-            // Priority 3: Check if there's a pending API key from on-chain storage
-            // This is set by the set_accuweather_api_key extrinsic
-            if let Some(pending_key) = PendingApiKey::<T>::get() {
-                if !pending_key.is_empty() {
-                    let key_vec: Vec<u8> = pending_key.into();
-                    
-                    // Copy to local storage for persistence
-                    sp_io::offchain::local_storage_set(
-                        sp_core::offchain::StorageKind::PERSISTENT,
-                        ACCUWEATHER_API_KEY_STORAGE,
-                        &key_vec,
-                    );
-                    
-                    log::info!(
+                Err(e) => {
+                    log::warn!(
                         target: "prmx-oracle",
-                        "✅ Copied AccuWeather API key from on-chain to local storage (length: {} bytes)",
-                        key_vec.len()
+                        "Failed to parse snowfall for market {}: {:?}",
+                        market_id,
+                        e
                     );
-                    
-                    // Note: We can't clear PendingApiKey here because we're in offchain context
-                    // It will be cleared in on_initialize after a few blocks
-                    
-                    return Some(key_vec);
+                    ocw_metrics::record_error(e);
+                    return;
                 }
+            };
+
+            if let Err(e) = Self::submit_hourly_snowfall_signed_tx(market_id, snow_data) {
+                log::warn!(
+                    target: "prmx-oracle",
+                    "❌ Failed to submit hourly snowfall for market {}: {:?}",
+                    market_id,
+                    e
+                );
+                ocw_metrics::record_error(e);
             }
-            
-            log::warn!(
-                target: "prmx-oracle",
-                "⚠️ AccuWeather API key not configured. Inject via CLI or extrinsic."
-            );
-            None
         }
 
-        /// Process pending manual fetch requests
-        /// Returns true if any requests were processed
-        fn process_pending_fetch_requests(_block_number: BlockNumberFor<T>) -> bool {
-            // Check for pending fetch requests
-            let pending_markets: Vec<_> = PendingFetchRequests::<T>::iter()
-                .map(|(market_id, _)| market_id)
-                .collect();
-
-            if pending_markets.is_empty() {
-                return false;
-            }
+        /// Read every configured river gauge and submit its level on-chain
+        fn fetch_and_store_gauge_levels() {
+            let now = sp_io::offchain::timestamp().unix_millis() / 1000;
 
-            log::info!(
-                target: "prmx-oracle",
-                "📥 Found {} pending fetch request(s) to process",
-                pending_markets.len()
-            );
+            for (market_id, source) in GaugeSources::<T>::iter() {
+                let level_cm = match gauge::fetch_gauge_level(&source) {
+                    Ok(level_cm) => level_cm,
+                    Err(e) => {
+                        log::warn!(
+                            target: "prmx-oracle",
+                            "Failed to read river gauge for market {}: {:?}",
+                            market_id,
+                            e
+                        );
+                        ocw_metrics::record_error(e);
+                        continue;
+                    }
+                };
 
-            // Get API key
-            let api_key = match Self::get_accuweather_api_key() {
-                Some(key) => key,
-                None => {
+                if let Err(e) = Self::submit_gauge_level_signed_tx(market_id, now, level_cm) {
                     log::warn!(
                         target: "prmx-oracle",
-                        "Cannot process pending fetch requests: AccuWeather API key not configured"
+                        "❌ Failed to submit gauge level for market {}: {:?}",
+                        market_id,
+                        e
                     );
-                    return false;
+                    ocw_metrics::record_error(e);
                 }
+            }
+        }
+
+        /// Submit a river gauge level via signed transaction
+        fn submit_gauge_level_signed_tx(
+            market_id: MarketId,
+            epoch_time: u64,
+            level_cm: Centimeters,
+        ) -> Result<(), OcwErrorKind> {
+            use frame_system::offchain::{Signer, SendSignedTransaction};
+
+            let signer = Signer::<T, T::AuthorityId>::all_accounts();
+
+            if !signer.can_sign() {
+                return Err(OcwErrorKind::Keystore);
+            }
+
+            let call = Call::<T>::submit_gauge_level_from_ocw {
+                market_id,
+                epoch_time,
+                level_cm,
             };
 
-            let mut processed_any = false;
+            let results = signer.send_signed_transaction(|_account| call.clone());
+            ocw_metrics::record_submissions(&results);
 
-            for market_id in pending_markets {
-                // Skip if we've already submitted a transaction for this market that's still in-flight
-                // This prevents duplicate submissions while waiting for on-chain processing
-                if Self::is_pending_fetch_inflight(market_id) {
-                    log::info!(
-                        target: "prmx-oracle",
-                        "⏳ Skipping market {} - submission already in-flight",
-                        market_id
-                    );
-                    continue;
+            for (acc, result) in &results {
+                match result {
+                    Ok(()) => {
+                        log::info!(
+                            target: "prmx-oracle",
+                            "✅ Gauge level tx sent from account {:?}",
+                            acc.id
+                        );
+                        return Ok(());
+                    }
+                    Err(e) => {
+                        log::warn!(
+                            target: "prmx-oracle",
+                            "❌ Gauge level tx from account {:?} failed: {:?}",
+                            acc.id,
+                            e
+                        );
+                    }
                 }
+            }
 
-                log::info!(
-                    target: "prmx-oracle",
-                    "🌧️ Processing manual fetch request for market {}",
-                    market_id
-                );
+            Err(OcwErrorKind::Submission)
+        }
 
-                // First, try to get location key from offchain cache
-                let location_key: Vec<u8> = match Self::get_location_key_from_offchain_index(market_id) {
-                    Some(key) => {
+        /// Submit hourly snowfall data via signed transaction
+        fn submit_hourly_snowfall_signed_tx(
+            market_id: MarketId,
+            hourly_data: Vec<(u64, Millimeters)>,
+        ) -> Result<(), OcwErrorKind> {
+            use frame_system::offchain::{Signer, SendSignedTransaction};
+
+            let signer = Signer::<T, T::AuthorityId>::all_accounts();
+
+            if !signer.can_sign() {
+                return Err(OcwErrorKind::Keystore);
+            }
+
+            let bounded_data: BoundedVec<(u64, Millimeters), ConstU32<24>> =
+                hourly_data.into_iter().take(24).collect::<Vec<_>>().try_into()
+                    .map_err(|_| OcwErrorKind::Parse)?;
+
+            let call = Call::<T>::submit_hourly_snowfall_from_ocw {
+                market_id,
+                hourly_data: bounded_data,
+            };
+
+            let results = signer.send_signed_transaction(|_account| call.clone());
+            ocw_metrics::record_submissions(&results);
+
+            for (acc, result) in &results {
+                match result {
+                    Ok(()) => {
                         log::info!(
                             target: "prmx-oracle",
-                            "📖 Found cached location key for market {}",
-                            market_id
+                            "✅ Hourly snowfall tx sent from account {:?}",
+                            acc.id
                         );
-                        key
+                        return Ok(());
                     }
-                    None => {
-                        // No cached key - need to resolve from AccuWeather
-                        // Get market coordinates from MarketsApi
-                        let (lat, lon) = match T::MarketsApi::center_coordinates(market_id) {
-                            Ok(coords) => coords,
-                            Err(_) => {
-                                log::warn!(
-                                    target: "prmx-oracle",
-                                    "Market {} not found in markets pallet, skipping",
-                                    market_id
-                                );
-                                continue;
-                            }
-                        };
+                    Err(e) => {
+                        log::warn!(
+                            target: "prmx-oracle",
+                            "❌ Hourly snowfall tx from account {:?} failed: {:?}",
+                            acc.id,
+                            e
+                        );
+                    }
+                }
+            }
 
-                        let lat_f = lat as f64 / 1_000_000.0;
-                        let lon_f = lon as f64 / 1_000_000.0;
+            Err(OcwErrorKind::Submission)
+        }
+
+        /// Submit hourly temperature data via signed transaction
+        fn submit_hourly_temperature_signed_tx(
+            market_id: MarketId,
+            hourly_data: Vec<(u64, DeciCelsius)>,
+        ) -> Result<(), OcwErrorKind> {
+            use frame_system::offchain::{Signer, SendSignedTransaction};
+
+            let signer = Signer::<T, T::AuthorityId>::all_accounts();
+
+            if !signer.can_sign() {
+                return Err(OcwErrorKind::Keystore);
+            }
+
+            let bounded_data: BoundedVec<(u64, DeciCelsius), ConstU32<24>> =
+                hourly_data.into_iter().take(24).collect::<Vec<_>>().try_into()
+                    .map_err(|_| OcwErrorKind::Parse)?;
 
+            let call = Call::<T>::submit_hourly_temperature_from_ocw {
+                market_id,
+                hourly_data: bounded_data,
+            };
+
+            let results = signer.send_signed_transaction(|_account| call.clone());
+            ocw_metrics::record_submissions(&results);
+
+            for (acc, result) in &results {
+                match result {
+                    Ok(()) => {
                         log::info!(
                             target: "prmx-oracle",
-                            "🔍 Resolving AccuWeather location key for new market {} (lat: {}, lon: {})",
-                            market_id,
-                            lat_f,
-                            lon_f
+                            "✅ Hourly temperature tx sent from account {:?}",
+                            acc.id
                         );
+                        return Ok(());
+                    }
+                    Err(e) => {
+                        log::warn!(
+                            target: "prmx-oracle",
+                            "❌ Hourly temperature tx from account {:?} failed: {:?}",
+                            acc.id,
+                            e
+                        );
+                    }
+                }
+            }
 
-                        match Self::fetch_accuweather_location_key(&api_key, lat_f, lon_f) {
-                            Ok(key) => {
-                                let key_str = core::str::from_utf8(&key).unwrap_or("invalid");
-                                log::info!(
-                                    target: "prmx-oracle",
-                                    "✅ Resolved AccuWeather location key for new market {}: {}",
-                                    market_id,
-                                    key_str
-                                );
-
-                                // Store in offchain cache for future use
-                                let storage_key = Self::location_binding_key(market_id);
-                                sp_io::offchain::local_storage_set(
-                                    sp_core::offchain::StorageKind::PERSISTENT,
-                                    &storage_key,
-                                    &key,
-                                );
+            Err(OcwErrorKind::Submission)
+        }
 
-                                // Also submit on-chain binding via signed transaction
-                                if let Err(e) = Self::submit_location_binding_tx(market_id, key.clone()) {
-                                    log::warn!(
-                                        target: "prmx-oracle",
-                                        "Failed to submit on-chain location binding for market {}: {:?}",
-                                        market_id,
-                                        e
-                                    );
-                                }
+        /// Fetch hourly rainfall from Open-Meteo, NOAA and Meteostat and submit each
+        /// provider's readings on-chain. Providers without configuration are skipped.
+        /// A market's `MarketDataSources` entry is fetched in place of the built-in
+        /// fetcher of its provider.
+        fn fetch_and_store_secondary_providers(market_id: MarketId, lat: f64, lon: f64) {
+            let now = sp_io::offchain::timestamp().unix_millis() / 1000;
+            let configured = MarketDataSources::<T>::get(market_id);
+
+            let mut fetches: Vec<(WeatherProvider, Result<Vec<(u64, Millimeters)>, OcwErrorKind>)> =
+                Vec::new();
+            if let Some(source) = &configured {
+                let api_key = Self::get_data_source_api_key(market_id);
+                let api_key = api_key.as_deref().and_then(|key| core::str::from_utf8(key).ok());
+                fetches.push((source.provider, data_source::fetch(source, lat, lon, now, api_key)));
+            }
 
-                                key
-                            }
-                            Err(e) => {
-                                log::warn!(
-                                    target: "prmx-oracle",
-                                    "❌ Failed to resolve location key for new market {}: {}",
-                                    market_id,
-                                    e
-                                );
-                                continue;
-                            }
+            let built_in: [(WeatherProvider, Result<Vec<(u64, Millimeters)>, OcwErrorKind>); 3] = [
+                (
+                    WeatherProvider::OpenMeteo,
+                    providers::fetch_open_meteo(lat, lon, now),
+                ),
+                (
+                    WeatherProvider::Noaa,
+                    match Self::get_noaa_station(market_id) {
+                        Some(station) => core::str::from_utf8(&station)
+                            .map_err(|_| OcwErrorKind::Config)
+                            .and_then(|station| providers::fetch_noaa(station, now)),
+                        None => Err(OcwErrorKind::Config),
+                    },
+                ),
+                (
+                    WeatherProvider::Meteostat,
+                    match Self::get_offchain_value(providers::METEOSTAT_API_KEY_STORAGE) {
+                        Some(key) => providers::fetch_meteostat(&key, lat, lon, now),
+                        None => Err(OcwErrorKind::Config),
+                    },
+                ),
+            ];
+            fetches.extend(built_in.into_iter().filter(|(provider, _)| {
+                configured.as_ref().map_or(true, |source| source.provider != *provider)
+            }));
+
+            for (provider, result) in fetches {
+                match result {
+                    Ok(readings) if !readings.is_empty() => {
+                        if let Err(e) = Self::send_observation_mirror(market_id, provider, &readings) {
+                            log::warn!(
+                                target: "prmx-oracle",
+                                "❌ Failed to mirror {} rainfall for market {}: {:?}",
+                                provider.name(),
+                                market_id,
+                                e
+                            );
+                            ocw_metrics::record_error(e);
+                        }
+                        let readings = Self::route_hourly_corrections(market_id, provider, readings);
+                        if readings.is_empty() {
+                            continue;
+                        }
+                        if let Err(e) = Self::submit_provider_hourly_rainfall_signed_tx(market_id, provider, readings) {
+                            log::warn!(
+                                target: "prmx-oracle",
+                                "❌ Failed to submit {} rainfall for market {}: {:?}",
+                                provider.name(),
+                                market_id,
+                                e
+                            );
+                            ocw_metrics::record_error(e);
                         }
                     }
-                };
-
-                let location_key_str = match core::str::from_utf8(&location_key) {
-                    Ok(key) => key,
-                    Err(_) => {
-                        log::warn!(
+                    Ok(_) => {
+                        log::debug!(
                             target: "prmx-oracle",
-                            "Invalid location key encoding for market {}",
+                            "No {} rainfall data returned for market {}",
+                            provider.name(),
                             market_id
                         );
-                        continue;
                     }
-                };
+                    Err(e) => {
+                        log::debug!(
+                            target: "prmx-oracle",
+                            "Skipping {} for market {}: {:?}",
+                            provider.name(),
+                            market_id,
+                            e
+                        );
+                        ocw_metrics::record_error(e);
+                    }
+                }
+            }
+        }
 
-                // Fetch rainfall data from AccuWeather
-                match Self::fetch_accuweather_rainfall(&api_key, location_key_str) {
-                    Ok(rainfall_data) => {
+        /// Get NOAA station ID configured for a market in offchain storage
+        fn get_noaa_station(market_id: MarketId) -> Option<Vec<u8>> {
+            let mut key = providers::NOAA_STATION_PREFIX.to_vec();
+            key.extend_from_slice(&market_id.to_le_bytes());
+            Self::get_offchain_value(&key)
+        }
+
+        /// Get the API key of a market's configured data source from offchain storage
+        fn get_data_source_api_key(market_id: MarketId) -> Option<Vec<u8>> {
+            let mut key = data_source::DATA_SOURCE_API_KEY_PREFIX.to_vec();
+            key.extend_from_slice(&market_id.to_le_bytes());
+            Self::get_offchain_value(&key)
+        }
+
+        /// Read a non-empty value from persistent offchain local storage
+        fn get_offchain_value(key: &[u8]) -> Option<Vec<u8>> {
+            sp_io::offchain::local_storage_get(sp_core::offchain::StorageKind::PERSISTENT, key)
+                .filter(|value| !value.is_empty())
+        }
+
+        /// Submit a secondary provider's hourly rainfall via signed transaction
+        fn submit_provider_hourly_rainfall_signed_tx(
+            market_id: MarketId,
+            provider: WeatherProvider,
+            hourly_data: Vec<(u64, Millimeters)>,
+        ) -> Result<(), OcwErrorKind> {
+            use frame_system::offchain::{Signer, SendSignedTransaction};
+
+            // Get signer from keystore
+            let signer = Signer::<T, T::AuthorityId>::all_accounts();
+
+            if !signer.can_sign() {
+                return Err(OcwErrorKind::Keystore);
+            }
+
+            // Keep the most recent 24 readings
+            let skip = hourly_data.len().saturating_sub(24);
+            let bounded_data: BoundedVec<(u64, Millimeters), ConstU32<24>> =
+                hourly_data.into_iter().skip(skip).collect::<Vec<_>>().try_into()
+                    .map_err(|_| OcwErrorKind::Parse)?;
+
+            let call = Call::<T>::submit_provider_hourly_rainfall_from_ocw {
+                market_id,
+                provider,
+                hourly_data: bounded_data,
+            };
+
+            let results = signer.send_signed_transaction(|_account| call.clone());
+            ocw_metrics::record_submissions(&results);
+
+            for (acc, result) in &results {
+                match result {
+                    Ok(()) => {
                         log::info!(
                             target: "prmx-oracle",
-                            "✅ Fetched {} rainfall records for market {} from AccuWeather",
-                            rainfall_data.len(),
-                            market_id
+                            "✅ {} rainfall tx sent from account {:?}",
+                            provider.name(),
+                            acc.id
                         );
-
-                        if !rainfall_data.is_empty() {
-                            // Store the fetched data in offchain index
-                            Self::store_fetched_rainfall_data(market_id, rainfall_data.clone());
-                            
-                            // Get the 24h rainfall sum and submit on-chain
-                            // AccuWeather Past24Hours gives us the 24h sum in the first entry
-                            if let Some((_, rainfall_mm)) = rainfall_data.first() {
-                                log::info!(
-                                    target: "prmx-oracle",
-                                    "🌧️ AccuWeather 24h rainfall for market {}: {:.1} mm - submitting on-chain",
-                                    market_id,
-                                    *rainfall_mm as f64 / 10.0
-                                );
-                                
-                                // Submit rainfall on-chain via signed transaction
-                                if let Err(e) = Self::submit_rainfall_signed_tx(market_id, *rainfall_mm) {
-                                    log::warn!(
-                                        target: "prmx-oracle",
-                                        "Failed to submit on-chain rainfall for market {}: {:?}",
-                                        market_id,
-                                        e
-                                    );
-                                } else {
-                                    // Mark as in-flight to prevent duplicate submissions
-                                    // The in-flight marker will be cleared when:
-                                    // 1. The on-chain transaction is processed (clears PendingFetchRequests)
-                                    // 2. The marker expires after 3 minutes (staleness check)
-                                    Self::mark_pending_fetch_inflight(market_id);
-                                    
-                                    log::info!(
-                                        target: "prmx-oracle",
-                                        "✅ Submitted on-chain rainfall update for market {}: {:.1} mm (marked in-flight)",
-                                        market_id,
-                                        *rainfall_mm as f64 / 10.0
-                                    );
-                                }
-                            }
-                            
-                            processed_any = true;
-                        }
+                        return Ok(());
                     }
                     Err(e) => {
                         log::warn!(
                             target: "prmx-oracle",
-                            "Failed to fetch rainfall for market {}: {}",
-                            market_id,
+                            "❌ {} rainfall tx from account {:?} failed: {:?}",
+                            provider.name(),
+                            acc.id,
                             e
                         );
                     }
                 }
             }
 
-            processed_any
+            Err(OcwErrorKind::Submission)
         }
 
-        /// Store fetched rainfall data in offchain indexed storage for logging/reference
-        fn store_fetched_rainfall_data(
+        /// Send readings that differ from the provider's stored on-chain reading by
+        /// more than the agreement tolerance through `correct_hourly_rainfall_from_ocw`,
+        /// since a plain resubmission would be rejected. Returns the remaining
+        /// readings for the normal submission.
+        fn route_hourly_corrections(
             market_id: MarketId,
-            rainfall_data: Vec<(u64, Millimeters)>,
-        ) {
-            // Store data in offchain index for reference
-            let key = Self::pending_rainfall_data_key(market_id);
-            let encoded_data = rainfall_data.encode();
-            sp_io::offchain_index::set(&key, &encoded_data);
-            
-            log::info!(
-                target: "prmx-oracle",
-                "📝 Stored {} rainfall records in offchain index for market {}",
-                rainfall_data.len(),
-                market_id
-            );
-        }
+            provider: WeatherProvider,
+            hourly_data: Vec<(u64, Millimeters)>,
+        ) -> Vec<(u64, Millimeters)> {
+            let tolerance_mm = ProviderAgreement::<T>::get().tolerance_mm;
+            let oldest_acceptable_hour =
+                Self::oldest_acceptable_hour(market_id, T::TimeProvider::now_secs());
+
+            let (corrections, readings): (Vec<_>, Vec<_>) =
+                hourly_data.into_iter().partition(|(epoch_time, mm)| {
+                    let hour_index = *epoch_time / 3600;
+                    let stored = ProviderHourlyReadings::<T>::get(market_id, hour_index)
+                        .iter()
+                        .find(|r| r.provider == provider)
+                        .map(|r| r.mm);
+                    hour_index >= oldest_acceptable_hour
+                        && *mm <= MAX_RAINFALL_MM
+                        && matches!(
+                            aggregation::classify_resubmission(stored, *mm, tolerance_mm),
+                            aggregation::Resubmission::Conflicting { .. }
+                        )
+                });
+
+            if !corrections.is_empty() {
+                if let Err(e) = Self::submit_hourly_corrections_signed_tx(market_id, provider, corrections) {
+                    log::warn!(
+                        target: "prmx-oracle",
+                        "❌ Failed to submit {} corrections for market {}: {:?}",
+                        provider.name(),
+                        market_id,
+                        e
+                    );
+                    ocw_metrics::record_error(e);
+                }
+            }
 
-        /// Generate offchain index key for pending rainfall data
-        fn pending_rainfall_data_key(market_id: MarketId) -> Vec<u8> {
-            let mut key = b"prmx-oracle::pending-rainfall::".to_vec();
-            key.extend_from_slice(&market_id.to_le_bytes());
-            key
+            readings
         }
 
-        /// Combined function: resolve location bindings AND fetch rainfall data
-        /// This handles both in a single pass to avoid storage persistence issues with --tmp
-        fn process_markets_and_fetch_rainfall(
-            api_key: &[u8],
-            _block_number: BlockNumberFor<T>,
-            should_fetch_rainfall: bool,
-        ) -> Result<(), &'static str> {
-            use pallet_prmx_markets::Markets;
+        /// Submit corrected hourly readings via signed transaction
+        fn submit_hourly_corrections_signed_tx(
+            market_id: MarketId,
+            provider: WeatherProvider,
+            corrections: Vec<(u64, Millimeters)>,
+        ) -> Result<(), OcwErrorKind> {
+            use frame_system::offchain::{Signer, SendSignedTransaction};
 
-            let mut processed = 0u32;
-            const MAX_MARKETS_PER_BLOCK: u32 = 10; // Support up to 10 markets
+            let signer = Signer::<T, T::AuthorityId>::all_accounts();
 
-            let next_id = pallet_prmx_markets::NextMarketId::<T>::get();
-            
-            log::info!(
-                target: "prmx-oracle",
-                "🔄 Processing {} markets (fetch_rainfall: {})",
-                next_id,
-                should_fetch_rainfall
-            );
+            if !signer.can_sign() {
+                return Err(OcwErrorKind::Keystore);
+            }
 
-            for market_id in 0..next_id {
-                if processed >= MAX_MARKETS_PER_BLOCK {
-                    break;
-                }
+            // Keep the most recent 24 corrections
+            let skip = corrections.len().saturating_sub(24);
+            let bounded_corrections: BoundedVec<(u64, Millimeters), ConstU32<24>> =
+                corrections.into_iter().skip(skip).collect::<Vec<_>>().try_into()
+                    .map_err(|_| OcwErrorKind::Parse)?;
 
-                // Get market info
-                let market = match Markets::<T>::get(market_id) {
-                    Some(m) => m,
-                    None => continue,
-                };
+            let call = Call::<T>::correct_hourly_rainfall_from_ocw {
+                market_id,
+                provider,
+                corrections: bounded_corrections,
+            };
 
-                // Get center coordinates
-                let lat = market.center_latitude as f64 / 1_000_000.0;
-                let lon = market.center_longitude as f64 / 1_000_000.0;
+            let results = signer.send_signed_transaction(|_account| call.clone());
+            ocw_metrics::record_submissions(&results);
 
-                // First, try to get location key from offchain local storage
-                let location_key = Self::get_location_key_from_offchain_index(market_id);
-                
-                let location_key: Vec<u8> = match location_key {
-                    Some(key) => {
+            for (acc, result) in &results {
+                match result {
+                    Ok(()) => {
                         log::info!(
                             target: "prmx-oracle",
-                            "📖 Found cached location key for market {}",
-                            market_id
+                            "✏️ {} correction tx sent from account {:?}",
+                            provider.name(),
+                            acc.id
                         );
-                        key
+                        return Ok(());
                     }
-                    None => {
-                        // Need to resolve location key from AccuWeather
-                        log::info!(
+                    Err(e) => {
+                        log::warn!(
                             target: "prmx-oracle",
-                            "🔍 Resolving AccuWeather location key for market {} (lat: {}, lon: {})",
-                            market_id,
-                            lat,
-                            lon
+                            "❌ {} correction tx from account {:?} failed: {:?}",
+                            provider.name(),
+                            acc.id,
+                            e
                         );
-                        
-                        match Self::fetch_accuweather_location_key(api_key, lat, lon) {
-                            Ok(key) => {
-                                let key_str = core::str::from_utf8(&key).unwrap_or("invalid");
-                                log::info!(
-                                    target: "prmx-oracle",
-                                    "✅ Resolved AccuWeather location key for market {}: {}",
-                                    market_id,
-                                    key_str
-                                );
-
-                                // Store for future use
-                                let storage_key = Self::location_binding_key(market_id);
-                                sp_io::offchain::local_storage_set(
-                                    sp_core::offchain::StorageKind::PERSISTENT,
-                                    &storage_key,
-                                    &key,
-                                );
-                                
-                                key
-                            }
-                            Err(e) => {
-                                log::warn!(
-                                    target: "prmx-oracle",
-                                    "❌ Failed to resolve location key for market {}: {}",
-                                    market_id,
-                                    e
-                                );
-                                continue;
-                            }
-                        }
                     }
-                };
+                }
+            }
 
-                // Now fetch rainfall if enabled
-                if should_fetch_rainfall {
-                    let key_str = core::str::from_utf8(&location_key).unwrap_or("invalid");
-                    log::info!(
-                        target: "prmx-oracle",
-                        "🌧️ Fetching 24h rainfall for market {} from AccuWeather (location: {})",
-                        market_id,
-                        key_str
-                    );
+            Err(OcwErrorKind::Submission)
+        }
+
+        /// Submit a signed transaction to bind market location on-chain
+        /// This ensures the MarketLocationConfig storage is populated
+        fn submit_location_binding_tx(
+            market_id: MarketId,
+            location_key: Vec<u8>,
+        ) -> Result<(), OcwErrorKind> {
+            use frame_system::offchain::{Signer, SendSignedTransaction};
+
+            // Get signer from keystore
+            let signer = Signer::<T, T::AuthorityId>::all_accounts();
+            
+            if !signer.can_sign() {
+                log::warn!(
+                    target: "prmx-oracle",
+                    "⚠️ No oracle authority keys found in keystore. Cannot submit location binding tx."
+                );
+                return Err(OcwErrorKind::Keystore);
+            }
+
+            // Create the call to set_market_location_key
+            let call = Call::<T>::set_market_location_key {
+                market_id,
+                accuweather_location_key: location_key.clone(),
+            };
 
-                    if let Err(e) = Self::fetch_and_store_rainfall(api_key, key_str, market_id) {
-                        log::warn!(
+            // Send signed transaction
+            let results = signer.send_signed_transaction(|_account| call.clone());
+            ocw_metrics::record_submissions(&results);
+
+            for (acc, result) in &results {
+                match result {
+                    Ok(()) => {
+                        let key_str = core::str::from_utf8(&location_key).unwrap_or("invalid");
+                        log::info!(
                             target: "prmx-oracle",
-                            "❌ Failed to fetch rainfall for market {}: {}",
+                            "✅ Location binding tx sent for market {} with key {} from account {:?}",
                             market_id,
+                            key_str,
+                            acc.id
+                        );
+                        return Ok(());
+                    }
+                    Err(e) => {
+                        log::warn!(
+                            target: "prmx-oracle",
+                            "❌ Location binding tx from account {:?} failed: {:?}",
+                            acc.id,
                             e
                         );
                     }
                 }
-
-                processed += 1;
             }
 
-            log::info!(
-                target: "prmx-oracle",
-                "🔄 Completed processing {} markets",
-                processed
-            );
+            Err(OcwErrorKind::Submission)
+        }
 
-            Ok(())
+        /// Generate offchain index key for location binding
+        fn location_binding_key(market_id: MarketId) -> Vec<u8> {
+            let mut key = b"prmx-oracle::location::".to_vec();
+            key.extend_from_slice(&market_id.to_le_bytes());
+            key
         }
 
-        /// Get location key from offchain indexed storage
-        fn get_location_key_from_offchain_index(market_id: MarketId) -> Option<Vec<u8>> {
-            let key = Self::location_binding_key(market_id);
-            
-            // Read from offchain local storage (where offchain_index::set stores data)
-            let value = sp_io::offchain::local_storage_get(
-                sp_core::offchain::StorageKind::PERSISTENT,
-                &key,
-            );
-            
-            log::info!(
-                target: "prmx-oracle",
-                "📖 Reading offchain index for market {}: found = {}",
-                market_id,
-                value.is_some()
-            );
-            
-            match value {
-                Some(data) if !data.is_empty() => {
-                    let key_str = core::str::from_utf8(&data).unwrap_or("invalid");
-                    log::info!(
-                        target: "prmx-oracle",
-                        "📖 Found offchain location key for market {}: {}",
-                        market_id,
-                        key_str
-                    );
-                    Some(data)
-                }
-                _ => None,
-            }
+        /// Generate offchain index key for rainfall data
+        fn rainfall_data_key(market_id: MarketId, timestamp: u64) -> Vec<u8> {
+            let mut key = b"prmx-oracle::rainfall::".to_vec();
+            key.extend_from_slice(&market_id.to_le_bytes());
+            key.extend_from_slice(b"::");
+            key.extend_from_slice(&timestamp.to_le_bytes());
+            key
         }
 
-        /// Fetch rainfall data and submit signed transaction to update on-chain storage
-        /// Now uses historical/24 endpoint and stores individual hourly buckets
-        fn fetch_and_store_rainfall(
-            api_key: &[u8],
-            location_key: &str,
-            market_id: MarketId,
-        ) -> Result<(), &'static str> {
-            match Self::fetch_accuweather_rainfall(api_key, location_key) {
-                Ok(rainfall_data) => {
-                    log::info!(
-                        target: "prmx-oracle",
-                        "📊 Fetched {} hourly rainfall records for market {}",
-                        rainfall_data.len(),
-                        market_id
-                    );
+        /// Fetch and submit the next page of each pending backfill.
+        /// Pages within the last 24 hours come from AccuWeather historical/24;
+        /// older pages come from Open-Meteo's dated hourly API.
+        fn process_backfill_requests(api_key: &[u8]) {
+            let now = sp_io::offchain::timestamp().unix_millis() / 1000;
+            let accuweather_oldest_hour = now.saturating_sub(24 * 3600) / 3600 + 1;
 
-                    if !rainfall_data.is_empty() {
-                        // Calculate total for logging
-                        let total_mm: Millimeters = rainfall_data.iter().map(|(_, mm)| *mm).sum();
-                        log::info!(
+            for (market_id, request) in BackfillRequests::<T>::iter() {
+                let first_hour = request.next_hour;
+                let last_hour = request.page_end();
+
+                if Self::is_backfill_inflight(market_id, first_hour) {
+                    ocw_metrics::record(ocw_metrics::OcwEvent::Backoff);
+                    continue;
+                }
+
+                let location = match MarketLocationConfig::<T>::get(market_id) {
+                    Some(location) => location,
+                    None => continue,
+                };
+
+                let (provider, fetched) = if first_hour >= accuweather_oldest_hour {
+                    let key_str = core::str::from_utf8(&location.accuweather_location_key)
+                        .unwrap_or("invalid");
+                    (
+                        WeatherProvider::AccuWeather,
+                        Self::fetch_accuweather_rainfall(api_key, key_str),
+                    )
+                } else {
+                    let lat = location.center_latitude as f64 / 1_000_000.0;
+                    let lon = location.center_longitude as f64 / 1_000_000.0;
+                    (
+                        WeatherProvider::OpenMeteo,
+                        providers::fetch_open_meteo_range(
+                            lat,
+                            lon,
+                            first_hour * 3600,
+                            last_hour * 3600 + 3599,
+                        ),
+                    )
+                };
+
+                let readings = match fetched {
+                    Ok(readings) => readings,
+                    Err(e) => {
+                        log::warn!(
                             target: "prmx-oracle",
-                            "🌧️ Submitting {} hourly readings for market {} (total: {:.1} mm)",
-                            rainfall_data.len(),
+                            "❌ Backfill fetch from {} failed for market {}: {:?}",
+                            provider.name(),
                             market_id,
-                            total_mm as f64 / 10.0
+                            e
                         );
+                        ocw_metrics::record_error(e);
+                        continue;
+                    }
+                };
 
-                        // Submit hourly data via signed transaction
-                        let result = Self::submit_hourly_rainfall_signed_tx(market_id, rainfall_data.clone());
-                        
-                        match result {
-                            Ok(()) => {
-                                log::info!(
-                                    target: "prmx-oracle",
-                                    "✅ Hourly rainfall submitted for market {} ({} readings)",
-                                    market_id,
-                                    rainfall_data.len()
-                                );
-                            }
-                            Err(e) => {
-                                log::warn!(
-                                    target: "prmx-oracle",
-                                    "❌ Failed to submit hourly rainfall for market {}: {}",
-                                    market_id,
-                                    e
-                                );
-                                // Fallback: try legacy single-value submission with total
-                                if let Some((timestamp, _)) = rainfall_data.first() {
-                                    let key = Self::rainfall_data_key(market_id, *timestamp);
-                                    let value = total_mm.to_le_bytes();
-                                    sp_io::offchain_index::set(&key, &value);
-                                }
-                            }
-                        }
-                    } else {
-                        log::debug!(
-                            target: "prmx-oracle",
-                            "No rainfall data returned for market {}",
-                            market_id
-                        );
+                // One reading per hour of the page, first observation wins
+                let mut page: Vec<(u64, Millimeters)> = Vec::new();
+                for (epoch_time, mm) in readings {
+                    let hour_index = epoch_time / 3600;
+                    if hour_index >= first_hour
+                        && hour_index <= last_hour
+                        && !page.iter().any(|(ts, _)| ts / 3600 == hour_index)
+                    {
+                        page.push((epoch_time, mm));
                     }
                 }
-                Err(e) => {
+                page.sort_by_key(|(ts, _)| *ts);
+
+                if !backfill_page_is_continuous(&page, first_hour, last_hour) {
                     log::warn!(
                         target: "prmx-oracle",
-                        "Failed to fetch rainfall for market {}: {}",
+                        "⏳ {} returned {} of {} hours for market {} backfill page {}..={}, retrying later",
+                        provider.name(),
+                        page.len(),
+                        last_hour - first_hour + 1,
                         market_id,
-                        e
+                        first_hour,
+                        last_hour
                     );
+                    ocw_metrics::record(ocw_metrics::OcwEvent::Backoff);
+                    continue;
                 }
-            }
 
-            Ok(())
+                match Self::submit_backfilled_rainfall_signed_tx(market_id, provider, page) {
+                    Ok(()) => Self::mark_backfill_inflight(market_id, first_hour),
+                    Err(e) => {
+                        log::warn!(
+                            target: "prmx-oracle",
+                            "❌ Failed to submit backfill page for market {}: {:?}",
+                            market_id,
+                            e
+                        );
+                        ocw_metrics::record_error(e);
+                    }
+                }
+            }
         }
 
-        /// Submit a signed transaction to update on-chain rainfall data
-        fn submit_rainfall_signed_tx(
+        /// Submit one backfill page via signed transaction
+        fn submit_backfilled_rainfall_signed_tx(
             market_id: MarketId,
-            rainfall_mm: Millimeters,
-        ) -> Result<(), &'static str> {
+            provider: WeatherProvider,
+            hourly_data: Vec<(u64, Millimeters)>,
+        ) -> Result<(), OcwErrorKind> {
             use frame_system::offchain::{Signer, SendSignedTransaction};
 
-            // Get signer from keystore
             let signer = Signer::<T, T::AuthorityId>::all_accounts();
-            
+
             if !signer.can_sign() {
-                log::warn!(
-                    target: "prmx-oracle",
-                    "⚠️ No oracle authority keys found in keystore. Cannot submit signed tx."
-                );
-                return Err("No oracle authority keys in keystore");
+                return Err(OcwErrorKind::Keystore);
             }
 
-            // Create the call
-            let call = Call::<T>::submit_rainfall_from_ocw {
+            let bounded_data: BoundedVec<(u64, Millimeters), ConstU32<24>> =
+                hourly_data.try_into().map_err(|_| OcwErrorKind::Parse)?;
+
+            let call = Call::<T>::submit_backfilled_rainfall_from_ocw {
                 market_id,
-                rainfall_mm,
+                provider,
+                hourly_data: bounded_data,
             };
 
-            // Send signed transaction
             let results = signer.send_signed_transaction(|_account| call.clone());
+            ocw_metrics::record_submissions(&results);
 
             for (acc, result) in &results {
                 match result {
                     Ok(()) => {
                         log::info!(
                             target: "prmx-oracle",
-                            "✅ Signed tx sent from account {:?}",
+                            "✅ Backfill page for market {} sent from account {:?}",
+                            market_id,
                             acc.id
                         );
                         return Ok(());
@@ -2600,7 +8151,7 @@ pub mod pallet {
                     Err(e) => {
                         log::warn!(
                             target: "prmx-oracle",
-                            "❌ Signed tx from account {:?} failed: {:?}",
+                            "❌ Backfill tx from account {:?} failed: {:?}",
                             acc.id,
                             e
                         );
@@ -2608,135 +8159,270 @@ pub mod pallet {
                 }
             }
 
-            Err("All signed transactions failed")
+            Err(OcwErrorKind::Submission)
         }
 
-        /// Submit hourly rainfall data via signed transaction
-        /// Uses the new submit_hourly_rainfall_from_ocw extrinsic
-        fn submit_hourly_rainfall_signed_tx(
-            market_id: MarketId,
-            hourly_data: Vec<(u64, Millimeters)>,
-        ) -> Result<(), &'static str> {
-            use frame_system::offchain::{Signer, SendSignedTransaction};
+        /// Markets whose `NextFetchDue` has passed and whose fetch for that due time
+        /// was not attempted in the last 3 minutes. Marks each returned market as
+        /// attempted, so a failed fetch is retried every few minutes until data for
+        /// it lands on-chain and `on_initialize` schedules the next one.
+        fn claim_due_fetches() -> Vec<MarketId> {
+            let now = T::TimeProvider::now_secs();
+            let mut due_markets = Vec::new();
 
-            // Get signer from keystore
-            let signer = Signer::<T, T::AuthorityId>::all_accounts();
-            
-            if !signer.can_sign() {
-                log::warn!(
-                    target: "prmx-oracle",
-                    "⚠️ No oracle authority keys found in keystore. Cannot submit hourly rainfall tx."
-                );
-                return Err("No oracle authority keys in keystore");
+            for (market_id, due) in NextFetchDue::<T>::iter() {
+                if due > now {
+                    continue;
+                }
+                // Attempted recently without data landing; retry once the marker expires
+                if Self::is_scheduled_fetch_inflight(market_id, due) {
+                    ocw_metrics::record(ocw_metrics::OcwEvent::Backoff);
+                    continue;
+                }
+
+                if fetch_schedule::exceeds_catch_up(due, now) {
+                    log::warn!(
+                        target: "prmx-oracle",
+                        "⚠️ Market {} fetch overdue since {}, beyond what one fetch covers; request a backfill for the gap",
+                        market_id,
+                        due
+                    );
+                } else if now.saturating_sub(due) >= fetch_schedule::FETCH_INTERVAL_SECS {
+                    log::info!(
+                        target: "prmx-oracle",
+                        "⏩ Catching up market {} fetch overdue since {}",
+                        market_id,
+                        due
+                    );
+                }
+
+                Self::mark_scheduled_fetch_inflight(market_id, due);
+                due_markets.push(market_id);
             }
 
-            // Convert to BoundedVec (max 24 entries)
-            let bounded_data: BoundedVec<(u64, Millimeters), ConstU32<24>> = 
-                hourly_data.into_iter().take(24).collect::<Vec<_>>().try_into()
-                    .map_err(|_| "Failed to create bounded vec")?;
+            due_markets
+        }
 
-            // Create the call
-            let call = Call::<T>::submit_hourly_rainfall_from_ocw {
-                market_id,
-                hourly_data: bounded_data,
-            };
+        /// Generate offchain storage key for tracking attempted scheduled fetches
+        fn scheduled_fetch_inflight_key(market_id: MarketId) -> Vec<u8> {
+            let mut key = SCHEDULED_FETCH_INFLIGHT_PREFIX.to_vec();
+            key.extend_from_slice(&market_id.to_le_bytes());
+            key
+        }
+
+        /// Check if the fetch due at `due` was attempted within the last 3 minutes
+        fn is_scheduled_fetch_inflight(market_id: MarketId, due: u64) -> bool {
+            const MAX_INFLIGHT_AGE_SECS: u64 = 180;
+
+            let value = sp_io::offchain::local_storage_get(
+                sp_core::offchain::StorageKind::PERSISTENT,
+                &Self::scheduled_fetch_inflight_key(market_id),
+            );
+
+            match value {
+                Some(bytes) if bytes.len() >= 16 => {
+                    let mut attempted_due = [0u8; 8];
+                    let mut attempted_at = [0u8; 8];
+                    attempted_due.copy_from_slice(&bytes[..8]);
+                    attempted_at.copy_from_slice(&bytes[8..16]);
+                    u64::from_le_bytes(attempted_due) == due
+                        && T::TimeProvider::now_secs()
+                            .saturating_sub(u64::from_le_bytes(attempted_at))
+                            < MAX_INFLIGHT_AGE_SECS
+                }
+                _ => false,
+            }
+        }
+
+        /// Mark the fetch due at `due` as attempted
+        fn mark_scheduled_fetch_inflight(market_id: MarketId, due: u64) {
+            let mut value = due.to_le_bytes().to_vec();
+            value.extend_from_slice(&T::TimeProvider::now_secs().to_le_bytes());
+            sp_io::offchain::local_storage_set(
+                sp_core::offchain::StorageKind::PERSISTENT,
+                &Self::scheduled_fetch_inflight_key(market_id),
+                &value,
+            );
+        }
+
+        /// Check each pending market listing proposal: resolve its coordinates to an
+        /// AccuWeather location and count the distinct hours in the location's
+        /// historical/24 observations. Fetch failures are retried on a later block;
+        /// a geoposition response without a location key is reported as unresolvable.
+        fn process_listing_checks(api_key: &[u8]) {
+            for (proposal_id, (lat, lon)) in PendingListingChecks::<T>::iter() {
+                if Self::is_listing_check_inflight(proposal_id) {
+                    ocw_metrics::record(ocw_metrics::OcwEvent::Backoff);
+                    continue;
+                }
+
+                let lat_f = lat as f64 / 1_000_000.0;
+                let lon_f = lon as f64 / 1_000_000.0;
+                let geoposition = match Self::fetch_accuweather_geoposition(api_key, lat_f, lon_f)
+                {
+                    Ok(body) => body,
+                    Err(e) => {
+                        log::warn!(
+                            target: "prmx-oracle",
+                            "❌ Geoposition lookup failed for listing proposal {}: {:?}",
+                            proposal_id,
+                            e
+                        );
+                        ocw_metrics::record_error(e);
+                        continue;
+                    }
+                };
+
+                let feasibility = match Self::extract_json_key(&geoposition) {
+                    Err(_) => ListingFeasibility::LocationUnresolvable,
+                    Ok(location_key) => {
+                        let key_str = core::str::from_utf8(&location_key).unwrap_or("invalid");
+                        let readings = match Self::fetch_accuweather_rainfall(api_key, key_str) {
+                            Ok(readings) => readings,
+                            Err(e) => {
+                                log::warn!(
+                                    target: "prmx-oracle",
+                                    "❌ History fetch failed for listing proposal {}: {:?}",
+                                    proposal_id,
+                                    e
+                                );
+                                ocw_metrics::record_error(e);
+                                continue;
+                            }
+                        };
+                        let mut hours: Vec<u64> =
+                            readings.iter().map(|(epoch_time, _)| epoch_time / 3600).collect();
+                        hours.sort_unstable();
+                        hours.dedup();
+                        ListingFeasibility::from_history_hours(hours.len() as u32)
+                    }
+                };
 
-            // Send signed transaction
-            let results = signer.send_signed_transaction(|_account| call.clone());
+                log::info!(
+                    target: "prmx-oracle",
+                    "🔎 Listing proposal {} at {:.4},{:.4} checked: {:?}",
+                    proposal_id,
+                    lat_f,
+                    lon_f,
+                    feasibility
+                );
 
-            for (acc, result) in &results {
-                match result {
-                    Ok(()) => {
-                        log::info!(
-                            target: "prmx-oracle",
-                            "✅ Hourly rainfall tx sent from account {:?}",
-                            acc.id
-                        );
-                        return Ok(());
-                    }
+                match Self::submit_listing_feasibility_signed_tx(proposal_id, feasibility) {
+                    Ok(()) => Self::mark_listing_check_inflight(proposal_id),
                     Err(e) => {
                         log::warn!(
                             target: "prmx-oracle",
-                            "❌ Hourly rainfall tx from account {:?} failed: {:?}",
-                            acc.id,
+                            "❌ Failed to submit feasibility of listing proposal {}: {:?}",
+                            proposal_id,
                             e
                         );
+                        ocw_metrics::record_error(e);
                     }
                 }
             }
-
-            Err("All signed transactions failed for hourly rainfall")
         }
 
-        /// Submit a signed transaction to bind market location on-chain
-        /// This ensures the MarketLocationConfig storage is populated
-        fn submit_location_binding_tx(
-            market_id: MarketId,
-            location_key: Vec<u8>,
-        ) -> Result<(), &'static str> {
+        /// Submit a listing feasibility check via signed transaction
+        fn submit_listing_feasibility_signed_tx(
+            proposal_id: ListingProposalId,
+            feasibility: ListingFeasibility,
+        ) -> Result<(), OcwErrorKind> {
             use frame_system::offchain::{Signer, SendSignedTransaction};
 
-            // Get signer from keystore
             let signer = Signer::<T, T::AuthorityId>::all_accounts();
-            
+
             if !signer.can_sign() {
-                log::warn!(
-                    target: "prmx-oracle",
-                    "⚠️ No oracle authority keys found in keystore. Cannot submit location binding tx."
-                );
-                return Err("No oracle authority keys in keystore");
+                return Err(OcwErrorKind::Keystore);
             }
 
-            // Create the call to set_market_location_key
-            let call = Call::<T>::set_market_location_key {
-                market_id,
-                accuweather_location_key: location_key.clone(),
-            };
+            let call = Call::<T>::submit_listing_feasibility { proposal_id, feasibility };
 
-            // Send signed transaction
             let results = signer.send_signed_transaction(|_account| call.clone());
+            ocw_metrics::record_submissions(&results);
 
-            for (acc, result) in &results {
-                match result {
-                    Ok(()) => {
-                        let key_str = core::str::from_utf8(&location_key).unwrap_or("invalid");
-                        log::info!(
-                            target: "prmx-oracle",
-                            "✅ Location binding tx sent for market {} with key {} from account {:?}",
-                            market_id,
-                            key_str,
-                            acc.id
-                        );
-                        return Ok(());
-                    }
-                    Err(e) => {
-                        log::warn!(
-                            target: "prmx-oracle",
-                            "❌ Location binding tx from account {:?} failed: {:?}",
-                            acc.id,
-                            e
-                        );
-                    }
+            if results.iter().any(|(_, result)| result.is_ok()) {
+                Ok(())
+            } else {
+                Err(OcwErrorKind::Submission)
+            }
+        }
+
+        /// Generate offchain storage key for tracking in-flight listing checks
+        fn listing_check_inflight_key(proposal_id: ListingProposalId) -> Vec<u8> {
+            let mut key = LISTING_CHECK_INFLIGHT_PREFIX.to_vec();
+            key.extend_from_slice(&proposal_id.to_le_bytes());
+            key
+        }
+
+        /// Check if the proposal's feasibility was submitted within the last 3 minutes
+        fn is_listing_check_inflight(proposal_id: ListingProposalId) -> bool {
+            const MAX_INFLIGHT_AGE_SECS: u64 = 180;
+
+            let value = sp_io::offchain::local_storage_get(
+                sp_core::offchain::StorageKind::PERSISTENT,
+                &Self::listing_check_inflight_key(proposal_id),
+            );
+
+            match value {
+                Some(bytes) if bytes.len() >= 8 => {
+                    let mut submitted_at = [0u8; 8];
+                    submitted_at.copy_from_slice(&bytes[..8]);
+                    T::TimeProvider::now_secs().saturating_sub(u64::from_le_bytes(submitted_at))
+                        < MAX_INFLIGHT_AGE_SECS
                 }
+                _ => false,
             }
+        }
 
-            Err("All signed transactions failed for location binding")
+        /// Record that the proposal's feasibility was just submitted
+        fn mark_listing_check_inflight(proposal_id: ListingProposalId) {
+            sp_io::offchain::local_storage_set(
+                sp_core::offchain::StorageKind::PERSISTENT,
+                &Self::listing_check_inflight_key(proposal_id),
+                &T::TimeProvider::now_secs().to_le_bytes(),
+            );
         }
 
-        /// Generate offchain index key for location binding
-        fn location_binding_key(market_id: MarketId) -> Vec<u8> {
-            let mut key = b"prmx-oracle::location::".to_vec();
+        /// Generate offchain storage key for tracking in-flight backfill pages
+        fn backfill_inflight_key(market_id: MarketId) -> Vec<u8> {
+            let mut key = BACKFILL_INFLIGHT_PREFIX.to_vec();
             key.extend_from_slice(&market_id.to_le_bytes());
             key
         }
 
-        /// Generate offchain index key for rainfall data
-        fn rainfall_data_key(market_id: MarketId, timestamp: u64) -> Vec<u8> {
-            let mut key = b"prmx-oracle::rainfall::".to_vec();
-            key.extend_from_slice(&market_id.to_le_bytes());
-            key.extend_from_slice(b"::");
-            key.extend_from_slice(&timestamp.to_le_bytes());
-            key
+        /// Check if the page starting at `first_hour` was submitted within the last 3 minutes
+        fn is_backfill_inflight(market_id: MarketId, first_hour: u64) -> bool {
+            const MAX_INFLIGHT_AGE_SECS: u64 = 180;
+
+            let value = sp_io::offchain::local_storage_get(
+                sp_core::offchain::StorageKind::PERSISTENT,
+                &Self::backfill_inflight_key(market_id),
+            );
+
+            match value {
+                Some(bytes) if bytes.len() >= 16 => {
+                    let mut hour = [0u8; 8];
+                    let mut submitted_at = [0u8; 8];
+                    hour.copy_from_slice(&bytes[..8]);
+                    submitted_at.copy_from_slice(&bytes[8..16]);
+                    u64::from_le_bytes(hour) == first_hour
+                        && T::TimeProvider::now_secs()
+                            .saturating_sub(u64::from_le_bytes(submitted_at))
+                            < MAX_INFLIGHT_AGE_SECS
+                }
+                _ => false,
+            }
+        }
+
+        /// Mark the page starting at `first_hour` as submitted
+        fn mark_backfill_inflight(market_id: MarketId, first_hour: u64) {
+            let mut value = first_hour.to_le_bytes().to_vec();
+            value.extend_from_slice(&T::TimeProvider::now_secs().to_le_bytes());
+            sp_io::offchain::local_storage_set(
+                sp_core::offchain::StorageKind::PERSISTENT,
+                &Self::backfill_inflight_key(market_id),
+                &value,
+            );
         }
 
         /// Generate offchain storage key for tracking in-flight pending fetch requests
@@ -2759,14 +8445,14 @@ pub mod pallet {
                 // Check if the in-flight marker is stale (older than 30 blocks worth of time)
                 // Each block is ~6 seconds, so 30 blocks = ~180 seconds = 3 minutes
                 // This prevents permanent blocking if a transaction fails
-                // NOTE: current_timestamp() returns SECONDS (not milliseconds)
+                // NOTE: TimeProvider::now_secs() returns SECONDS (not milliseconds)
                 const MAX_INFLIGHT_AGE_SECS: u64 = 180; // 3 minutes in seconds
                 
                 if timestamp_bytes.len() >= 8 {
                     let mut bytes = [0u8; 8];
                     bytes.copy_from_slice(&timestamp_bytes[..8]);
                     let submitted_at = u64::from_le_bytes(bytes);
-                    let now = Self::current_timestamp();
+                    let now = T::TimeProvider::now_secs();
                     
                     if now.saturating_sub(submitted_at) < MAX_INFLIGHT_AGE_SECS {
                         return true;
@@ -2785,7 +8471,7 @@ pub mod pallet {
         /// Mark a pending fetch request as in-flight (transaction submitted, waiting for processing)
         fn mark_pending_fetch_inflight(market_id: MarketId) {
             let key = Self::pending_fetch_inflight_key(market_id);
-            let timestamp = Self::current_timestamp();
+            let timestamp = T::TimeProvider::now_secs();
             sp_io::offchain::local_storage_set(
                 sp_core::offchain::StorageKind::PERSISTENT,
                 &key,
@@ -2811,11 +8497,21 @@ pub mod pallet {
             api_key: &[u8],
             lat: f64,
             lon: f64,
-        ) -> Result<Vec<u8>, &'static str> {
-            use sp_runtime::offchain::http;
+        ) -> Result<Vec<u8>, OcwErrorKind> {
+            let body = Self::fetch_accuweather_geoposition(api_key, lat, lon)?;
+
+            // Parse JSON to extract the top-level "Key" field
+            Self::extract_json_key(&body)
+        }
 
+        /// Fetch the raw AccuWeather Geoposition Search response body
+        fn fetch_accuweather_geoposition(
+            api_key: &[u8],
+            lat: f64,
+            lon: f64,
+        ) -> Result<Vec<u8>, OcwErrorKind> {
             let api_key_str =
-                core::str::from_utf8(api_key).map_err(|_| "Invalid API key encoding")?;
+                core::str::from_utf8(api_key).map_err(|_| OcwErrorKind::Config)?;
 
             // Build URL: /locations/v1/cities/geoposition/search?apikey=XXX&q=lat,lon
             let url = alloc::format!(
@@ -2833,35 +8529,7 @@ pub mod pallet {
                 lon
             );
 
-            // Make HTTP request
-            let request = http::Request::get(&url);
-            let timeout = sp_io::offchain::timestamp()
-                .add(sp_runtime::offchain::Duration::from_millis(10_000));
-
-            let pending = request
-                .deadline(timeout)
-                .send()
-                .map_err(|_| "Failed to send HTTP request")?;
-
-            let response = pending
-                .try_wait(timeout)
-                .map_err(|_| "HTTP request timeout")?
-                .map_err(|_| "HTTP request failed")?;
-
-            if response.code != 200 {
-                log::warn!(
-                    target: "prmx-oracle",
-                    "AccuWeather API returned status {}",
-                    response.code
-                );
-                return Err("AccuWeather API error");
-            }
-
-            let body = response.body().collect::<Vec<u8>>();
-
-            // Parse JSON to extract "Key" field
-            // Simple JSON parsing without serde (look for "Key":"...")
-            Self::extract_json_key(&body)
+            Self::accuweather_get(&url, 10_000)
         }
 
         /// Fetch AccuWeather 24 hours historical current conditions with rainfall data
@@ -2870,11 +8538,21 @@ pub mod pallet {
         fn fetch_accuweather_rainfall(
             api_key: &[u8],
             location_key: &str,
-        ) -> Result<Vec<(u64, Millimeters)>, &'static str> {
-            use sp_runtime::offchain::http;
+        ) -> Result<Vec<(u64, Millimeters)>, OcwErrorKind> {
+            let body = Self::fetch_accuweather_historical(api_key, location_key)?;
+
+            // Parse JSON to extract 24 hourly rainfall records from historical/24 response
+            Self::extract_hourly_rainfall_data(&body)
+        }
 
+        /// Fetch the raw AccuWeather historical/24 response body.
+        /// Shared by the rainfall and temperature parsers so each market costs one request.
+        fn fetch_accuweather_historical(
+            api_key: &[u8],
+            location_key: &str,
+        ) -> Result<Vec<u8>, OcwErrorKind> {
             let api_key_str =
-                core::str::from_utf8(api_key).map_err(|_| "Invalid API key encoding")?;
+                core::str::from_utf8(api_key).map_err(|_| OcwErrorKind::Config)?;
 
             // Build URL: /currentconditions/v1/{locationKey}/historical/24?apikey=XXX&details=true
             // Returns 24 hourly observations with individual PastHour precipitation for each
@@ -2887,24 +8565,38 @@ pub mod pallet {
 
             log::info!(
                 target: "prmx-oracle",
-                "🌐 Fetching 24h historical rainfall from AccuWeather for location {}",
+                "🌐 Fetching 24h historical conditions from AccuWeather for location {}",
                 location_key
             );
 
-            // Make HTTP request
-            let request = http::Request::get(&url);
+            // Longer timeout for historical data
+            Self::accuweather_get(&url, 30_000)
+        }
+
+        /// GET an AccuWeather URL and return the body of a 200 response, counting the
+        /// call in the OCW health metrics
+        fn accuweather_get(url: &str, timeout_ms: u64) -> Result<Vec<u8>, OcwErrorKind> {
+            let result = Self::send_accuweather_get(url, timeout_ms);
+            ocw_metrics::record(ocw_metrics::OcwEvent::ApiCall { ok: result.is_ok() });
+            result
+        }
+
+        fn send_accuweather_get(url: &str, timeout_ms: u64) -> Result<Vec<u8>, OcwErrorKind> {
+            use sp_runtime::offchain::http;
+
+            let request = http::Request::get(url);
             let timeout = sp_io::offchain::timestamp()
-                .add(sp_runtime::offchain::Duration::from_millis(30_000)); // Longer timeout for historical data
+                .add(sp_runtime::offchain::Duration::from_millis(timeout_ms));
 
             let pending = request
                 .deadline(timeout)
                 .send()
-                .map_err(|_| "Failed to send HTTP request")?;
+                .map_err(|_| OcwErrorKind::Timeout)?;
 
             let response = pending
                 .try_wait(timeout)
-                .map_err(|_| "HTTP request timeout")?
-                .map_err(|_| "HTTP request failed")?;
+                .map_err(|_| OcwErrorKind::Timeout)?
+                .map_err(|_| OcwErrorKind::Timeout)?;
 
             if response.code != 200 {
                 log::warn!(
@@ -2912,85 +8604,62 @@ pub mod pallet {
                     "AccuWeather API returned status {}",
                     response.code
                 );
-                return Err("AccuWeather API error");
+                return Err(OcwErrorKind::Http(response.code));
             }
 
-            let body = response.body().collect::<Vec<u8>>();
-
-            // Parse JSON to extract 24 hourly rainfall records from historical/24 response
-            Self::extract_hourly_rainfall_data(&body)
+            Ok(response.body().collect::<Vec<u8>>())
         }
 
         /// Extract "Key" value from AccuWeather JSON response
-        fn extract_json_key(json: &[u8]) -> Result<Vec<u8>, &'static str> {
-            let json_str = core::str::from_utf8(json).map_err(|_| "Invalid JSON encoding")?;
-
-            // Look for "Key":"value" pattern
-            if let Some(key_start) = json_str.find("\"Key\":\"") {
-                let value_start = key_start + 7;
-                if let Some(value_end) = json_str[value_start..].find('"') {
-                    let key = &json_str[value_start..value_start + value_end];
-                    return Ok(key.as_bytes().to_vec());
-                }
-            }
-
-            Err("Could not find Key in JSON response")
+        fn extract_json_key(json: &[u8]) -> Result<Vec<u8>, OcwErrorKind> {
+            let response = prmx_json::GeopositionResponse::from_json(json)
+                .map_err(|_| OcwErrorKind::Parse)?;
+            Ok(response.key.into_bytes())
         }
 
         /// Extract rainfall data from AccuWeather current conditions response (legacy)
         /// The response contains PrecipitationSummary.Past24Hours with total 24h rainfall
         #[allow(dead_code)]
-        fn extract_rainfall_data(json: &[u8]) -> Result<Vec<(u64, Millimeters)>, &'static str> {
-            let json_str = core::str::from_utf8(json).map_err(|_| "Invalid JSON encoding")?;
+        fn extract_rainfall_data(json: &[u8]) -> Result<Vec<(u64, Millimeters)>, OcwErrorKind> {
+            let value = prmx_json::parse(json).map_err(|_| OcwErrorKind::Parse)?;
+            // currentconditions returns a one-element array
+            let observation = match value.as_array() {
+                Some(items) => items.first().ok_or(OcwErrorKind::Parse)?,
+                None => &value,
+            };
 
-            let mut results = Vec::new();
+            let epoch = observation
+                .get("EpochTime")
+                .and_then(prmx_json::Value::as_u64)
+                .ok_or(OcwErrorKind::Parse)?;
 
-            // Extract EpochTime (observation time)
-            let epoch = if let Some(epoch_start) = json_str.find("\"EpochTime\":") {
-                let epoch_pos = epoch_start + 12;
-                let epoch_end = json_str[epoch_pos..]
-                    .find(|c: char| !c.is_ascii_digit())
-                    .unwrap_or(0);
-                json_str[epoch_pos..epoch_pos + epoch_end]
-                    .parse::<u64>()
-                    .unwrap_or(0)
-            } else {
-                return Err("No EpochTime found in response");
-            };
+            let mut results = Vec::new();
 
-            // Look for Past24Hours rainfall in PrecipitationSummary
-            // Format: "Past24Hours":{"Metric":{"Value":23.1,...}}
-            if let Some(past24h_start) = json_str.find("\"Past24Hours\":{\"Metric\":{\"Value\":") {
-                let value_pos = past24h_start + 34; // Skip to the value
-                let value_end = json_str[value_pos..]
-                    .find(|c: char| !c.is_ascii_digit() && c != '.')
-                    .unwrap_or(0);
-                if let Ok(precip) = json_str[value_pos..value_pos + value_end].parse::<f64>() {
+            // Format: "PrecipitationSummary":{"Past24Hours":{"Metric":{"Value":23.1,...}}}
+            match observation
+                .pointer(&["PrecipitationSummary", "Past24Hours", "Metric", "Value"])
+                .and_then(prmx_json::Value::as_f64)
+            {
+                Some(precip) => {
                     // Convert to mm * 10 for storage (e.g., 23.1mm -> 231)
                     let rainfall_mm = (precip * 10.0) as Millimeters;
-                    
+
                     log::info!(
                         target: "prmx-oracle",
                         "📊 AccuWeather Past24Hours rainfall: {:.1}mm (stored as {})",
                         precip,
                         rainfall_mm
                     );
-                    
+
                     // Return as a single data point with the total 24h rainfall
                     results.push((epoch, rainfall_mm));
                 }
-            } else {
-                log::debug!(
-                    target: "prmx-oracle",
-                    "No Past24Hours rainfall found in response"
-                );
-            }
-
-            if results.is_empty() {
-                log::debug!(
-                    target: "prmx-oracle",
-                    "No rainfall data found in response"
-                );
+                None => {
+                    log::debug!(
+                        target: "prmx-oracle",
+                        "No Past24Hours rainfall found in response"
+                    );
+                }
             }
 
             Ok(results)
@@ -2998,70 +8667,21 @@ pub mod pallet {
 
         /// Extract 24 hourly rainfall readings from AccuWeather historical/24 response
         /// The response is an array of 24 hourly observations, each with PrecipitationSummary.PastHour
-        fn extract_hourly_rainfall_data(json: &[u8]) -> Result<Vec<(u64, Millimeters)>, &'static str> {
-            let json_str = core::str::from_utf8(json).map_err(|_| "Invalid JSON encoding")?;
-            
-            let mut results: Vec<(u64, Millimeters)> = Vec::new();
-            
-            // The response is an array of objects: [{"EpochTime":123,...,"PrecipitationSummary":{...}},...]
-            // Parse each observation
-            let mut search_start = 0;
-            let mut observations_parsed = 0u32;
-            
-            while let Some(epoch_pos) = json_str[search_start..].find("\"EpochTime\":") {
-                let abs_epoch_pos = search_start + epoch_pos + 12;
-                
-                // Extract EpochTime value
-                let epoch_end = json_str[abs_epoch_pos..]
-                    .find(|c: char| !c.is_ascii_digit())
-                    .unwrap_or(0);
-                
-                let epoch = json_str[abs_epoch_pos..abs_epoch_pos + epoch_end]
-                    .parse::<u64>()
-                    .unwrap_or(0);
-                
-                if epoch == 0 {
-                    search_start = abs_epoch_pos;
-                    continue;
-                }
-                
-                // Look for PastHour rainfall near this observation
-                // Search within the next ~3000 chars for the PastHour value
-                // (with details=true, PrecipitationSummary can be ~2500 chars after EpochTime)
-                let search_window_end = core::cmp::min(abs_epoch_pos + 3000, json_str.len());
-                let search_window = &json_str[abs_epoch_pos..search_window_end];
-                
-                let mut rainfall_mm: Millimeters = 0;
-                
-                // Look for "PastHour":{"Metric":{"Value":X.X
-                if let Some(past_hour_pos) = search_window.find("\"PastHour\":{\"Metric\":{\"Value\":") {
-                    let value_start = past_hour_pos + 31;
-                    if value_start < search_window.len() {
-                        let remaining = &search_window[value_start..];
-                        let value_end = remaining
-                            .find(|c: char| !c.is_ascii_digit() && c != '.' && c != '-')
-                            .unwrap_or(0);
-                        if value_end > 0 {
-                            if let Ok(precip) = remaining[..value_end].parse::<f64>() {
-                                // Convert to mm * 10 for storage
-                                rainfall_mm = (precip * 10.0) as Millimeters;
-                            }
-                        }
-                    }
-                }
-                
-                results.push((epoch, rainfall_mm));
-                observations_parsed += 1;
-                
-                // Move to next observation
-                search_start = abs_epoch_pos + 1;
-                
-                // Safety limit
-                if observations_parsed >= 24 {
-                    break;
-                }
-            }
-            
+        fn extract_hourly_rainfall_data(json: &[u8]) -> Result<Vec<(u64, Millimeters)>, OcwErrorKind> {
+            let observations = prmx_json::HistoricalObservation::list_from_json(json)
+                .map_err(|_| OcwErrorKind::Parse)?;
+
+            // Observations without a PastHour value count as no rainfall
+            let results: Vec<(u64, Millimeters)> = observations
+                .iter()
+                .take(24)
+                .map(|obs| {
+                    let precip = obs.past_hour_precip_mm.unwrap_or(0.0).max(0.0);
+                    // Convert to mm * 10 for storage
+                    (obs.epoch_time, (precip * 10.0) as Millimeters)
+                })
+                .collect();
+
             if !results.is_empty() {
                 let total_mm: Millimeters = results.iter().map(|(_, mm)| *mm).sum();
                 log::info!(
@@ -3076,7 +8696,7 @@ pub mod pallet {
                     "⚠️ No hourly observations found in historical/24 response"
                 );
             }
-            
+
             Ok(results)
         }
     }
@@ -3111,6 +8731,93 @@ impl<T: Config> RainfallOracle for Pallet<T> {
     }
 }
 
+// =============================================================================
+//                     TemperatureOracle Trait Implementation
+// =============================================================================
+
+impl<T: Config> TemperatureOracle for Pallet<T> {
+    fn max_temperature_in_window(
+        location_id: LocationId,
+        coverage_start: u64,
+        coverage_end: u64,
+    ) -> Option<DeciCelsius> {
+        Pallet::<T>::temperatures_in_window(location_id, coverage_start, coverage_end).max()
+    }
+
+    fn min_temperature_in_window(
+        location_id: LocationId,
+        coverage_start: u64,
+        coverage_end: u64,
+    ) -> Option<DeciCelsius> {
+        Pallet::<T>::temperatures_in_window(location_id, coverage_start, coverage_end).min()
+    }
+}
+
+// =============================================================================
+//                        WindOracle Trait Implementation
+// =============================================================================
+
+impl<T: Config> WindOracle for Pallet<T> {
+    fn max_wind_gust_in_window(
+        location_id: LocationId,
+        coverage_start: u64,
+        coverage_end: u64,
+    ) -> Option<DeciKmh> {
+        Pallet::<T>::wind_gusts_in_window(location_id, coverage_start, coverage_end).max()
+    }
+}
+
+// =============================================================================
+//                      SnowfallOracle Trait Implementation
+// =============================================================================
+
+impl<T: Config> SnowfallOracle for Pallet<T> {
+    fn max_snowfall_24h_in_window(
+        location_id: LocationId,
+        coverage_start: u64,
+        coverage_end: u64,
+    ) -> Option<Millimeters> {
+        let readings = Pallet::<T>::snowfall_in_window(location_id, coverage_start, coverage_end);
+        snowfall::max_rolling_sum(&readings, snowfall::SNOWFALL_WINDOW_HOURS)
+    }
+}
+
+// =============================================================================
+//                       FloodOracle Trait Implementation
+// =============================================================================
+
+impl<T: Config> FloodOracle for Pallet<T> {
+    fn flood_level_cm(location_id: LocationId) -> Option<Centimeters> {
+        pallet::GaugeSources::<T>::get(location_id).map(|source| source.flood_level_cm)
+    }
+
+    fn max_rainfall_at_flood_stage_in_window(
+        location_id: LocationId,
+        coverage_start: u64,
+        coverage_end: u64,
+    ) -> Option<Millimeters> {
+        let flood_level_cm = Self::flood_level_cm(location_id)?;
+        let readings = Pallet::<T>::gauge_readings_in_window(location_id, coverage_start, coverage_end);
+        gauge::max_rainfall_at_flood_stage(&readings, flood_level_cm)
+    }
+}
+
+// =============================================================================
+//                              Oracle Liveness
+// =============================================================================
+
+/// Oracle liveness, used by the policy pallet to pause sales on stale markets
+pub trait OracleStatusApi {
+    /// Whether no rainfall data has arrived for the market within the stale threshold
+    fn is_market_stale(market_id: MarketId) -> bool;
+}
+
+impl<T: Config> OracleStatusApi for Pallet<T> {
+    fn is_market_stale(market_id: MarketId) -> bool {
+        pallet::StaleMarkets::<T>::contains_key(market_id)
+    }
+}
+
 // =============================================================================
 //                       Legacy OracleAccess (for backwards compatibility)
 // =============================================================================
@@ -3162,6 +8869,7 @@ impl<T: Config> OracleAccess for Pallet<T> {
             return 0;
         }
 
+        let (interval_secs, _) = Pallet::<T>::market_window(location_id as u64);
         let mut max_sum: u32 = 0;
         let mut t = coverage_start;
         while t <= coverage_end {
@@ -3169,7 +8877,7 @@ impl<T: Config> OracleAccess for Pallet<T> {
             if sum > max_sum {
                 max_sum = sum;
             }
-            t = t.saturating_add(BUCKET_INTERVAL_SECS);
+            t = t.saturating_add(interval_secs);
         }
 
         max_sum
@@ -3203,4 +8911,23 @@ impl<T: Config> NewMarketNotifier for Pallet<T> {
             current_block
         );
     }
+
+    /// Called by the markets pallet when a market listing is proposed.
+    /// Queues the coordinates for the OCW feasibility check.
+    fn notify_listing_proposed(
+        proposal_id: ListingProposalId,
+        center_latitude: i32,
+        center_longitude: i32,
+    ) {
+        pallet::PendingListingChecks::<T>::insert(
+            proposal_id,
+            (center_latitude, center_longitude),
+        );
+    }
+
+    /// Called by the markets pallet once a listing proposal is decided.
+    /// Drops its feasibility check if the OCW never reported one.
+    fn notify_listing_closed(proposal_id: ListingProposalId) {
+        pallet::PendingListingChecks::<T>::remove(proposal_id);
+    }
 }
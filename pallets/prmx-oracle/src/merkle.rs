@@ -0,0 +1,182 @@
+//! # Observation Merkle Commitments
+//!
+//! Binary Merkle tree over the hourly observations a settlement was based on.
+//! The root is stored in the threshold trigger log, so an auditor holding the
+//! archived observations can prove any single hour was part of the settlement
+//! after the on-chain buckets have been pruned.
+//!
+//! Leaves and inner nodes use distinct hash prefixes, and sibling pairs are hashed
+//! in sorted order so a proof is just the list of sibling hashes. A level with an
+//! odd number of nodes promotes its last node unchanged.
+
+use alloc::vec::Vec;
+use codec::Encode;
+use sp_io::hashing::blake2_256;
+
+use crate::{MarketId, Millimeters};
+
+/// Root of a tree with no observations
+pub const EMPTY_ROOT: [u8; 32] = [0u8; 32];
+
+/// Leaf hash of one hourly observation.
+///
+/// Format: blake2_256(b"prmx_obs:" || market_id || hour_index || mm)
+pub fn observation_leaf(market_id: MarketId, hour_index: u64, mm: Millimeters) -> [u8; 32] {
+    let mut data = Vec::new();
+    data.extend_from_slice(b"prmx_obs:");
+    data.extend_from_slice(&(market_id, hour_index, mm).encode());
+    blake2_256(&data)
+}
+
+/// Inner node hash of two children, in sorted order.
+///
+/// Format: blake2_256(b"prmx_node:" || min(a, b) || max(a, b))
+fn node_hash(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    let mut data = Vec::with_capacity(10 + 64);
+    data.extend_from_slice(b"prmx_node:");
+    data.extend_from_slice(first);
+    data.extend_from_slice(second);
+    blake2_256(&data)
+}
+
+/// Incremental tree builder. Leaves are pushed one at a time and only the
+/// frontier of completed subtrees (one per set bit of the leaf count) is kept.
+#[derive(Default)]
+pub struct ObservationTree {
+    /// (height, root) of completed subtrees, heights strictly decreasing
+    frontier: Vec<(u32, [u8; 32])>,
+    leaf_count: u32,
+}
+
+impl ObservationTree {
+    pub fn push(&mut self, leaf: [u8; 32]) {
+        let mut node = (0u32, leaf);
+        while let Some(&(height, left)) = self.frontier.last() {
+            if height != node.0 {
+                break;
+            }
+            self.frontier.pop();
+            node = (height + 1, node_hash(&left, &node.1));
+        }
+        self.frontier.push(node);
+        self.leaf_count += 1;
+    }
+
+    pub fn leaf_count(&self) -> u32 {
+        self.leaf_count
+    }
+
+    /// Root of the leaves pushed so far; equals `merkle_root` over the same leaves
+    pub fn root(&self) -> [u8; 32] {
+        let mut nodes = self.frontier.iter().rev().map(|(_, hash)| *hash);
+        match nodes.next() {
+            Some(smallest) => nodes.fold(smallest, |acc, larger| node_hash(&larger, &acc)),
+            None => EMPTY_ROOT,
+        }
+    }
+}
+
+/// Root over `leaves`, built level by level
+pub fn merkle_root(leaves: &[[u8; 32]]) -> [u8; 32] {
+    if leaves.is_empty() {
+        return EMPTY_ROOT;
+    }
+
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        level = next_level(&level);
+    }
+    level[0]
+}
+
+/// Sibling hashes proving `leaves[index]` is included under `merkle_root(leaves)`
+pub fn observation_proof(leaves: &[[u8; 32]], mut index: usize) -> Option<Vec<[u8; 32]>> {
+    if index >= leaves.len() {
+        return None;
+    }
+
+    let mut proof = Vec::new();
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        // A promoted last node has no sibling at this level
+        if let Some(sibling) = level.get(index ^ 1) {
+            proof.push(*sibling);
+        }
+        level = next_level(&level);
+        index /= 2;
+    }
+    Some(proof)
+}
+
+/// Check that an observation is included under `root`
+pub fn verify_observation_proof(
+    root: [u8; 32],
+    market_id: MarketId,
+    hour_index: u64,
+    mm: Millimeters,
+    proof: &[[u8; 32]],
+) -> bool {
+    let leaf = observation_leaf(market_id, hour_index, mm);
+    proof.iter().fold(leaf, |acc, sibling| node_hash(&acc, sibling)) == root
+}
+
+fn next_level(level: &[[u8; 32]]) -> Vec<[u8; 32]> {
+    level
+        .chunks(2)
+        .map(|pair| match pair {
+            [left, right] => node_hash(left, right),
+            [single] => *single,
+            _ => unreachable!("chunks(2) yields one or two nodes"),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaves(n: u64) -> Vec<[u8; 32]> {
+        (0..n).map(|h| observation_leaf(7, 480_000 + h, (h * 13) as u32)).collect()
+    }
+
+    #[test]
+    fn test_incremental_root_matches_full_tree() {
+        assert_eq!(ObservationTree::default().root(), EMPTY_ROOT);
+
+        for n in 1..=25 {
+            let leaves = leaves(n);
+            let mut tree = ObservationTree::default();
+            leaves.iter().for_each(|leaf| tree.push(*leaf));
+
+            assert_eq!(tree.leaf_count(), n as u32);
+            assert_eq!(tree.root(), merkle_root(&leaves), "{} leaves", n);
+        }
+    }
+
+    #[test]
+    fn test_every_observation_has_a_valid_proof() {
+        for n in [1u64, 2, 5, 24, 168] {
+            let leaves = leaves(n);
+            let root = merkle_root(&leaves);
+
+            for h in 0..n {
+                let proof = observation_proof(&leaves, h as usize).unwrap();
+                assert!(verify_observation_proof(root, 7, 480_000 + h, (h * 13) as u32, &proof));
+            }
+        }
+    }
+
+    #[test]
+    fn test_tampered_observation_fails() {
+        let leaves = leaves(24);
+        let root = merkle_root(&leaves);
+        let proof = observation_proof(&leaves, 3).unwrap();
+
+        assert!(verify_observation_proof(root, 7, 480_003, 39, &proof));
+        assert!(!verify_observation_proof(root, 7, 480_003, 40, &proof));
+        assert!(!verify_observation_proof(root, 8, 480_003, 39, &proof));
+        assert!(!verify_observation_proof(root, 7, 480_004, 39, &proof));
+        assert!(observation_proof(&leaves, 24).is_none());
+    }
+}
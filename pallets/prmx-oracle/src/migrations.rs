@@ -0,0 +1,475 @@
+//! # Storage Migrations
+//!
+//! Versioned migrations for `pallet_prmx_oracle`, run from the runtime's
+//! `SingleBlockMigrations`. Each one only runs when the on-chain storage version
+//! matches its source version, so it is a no-op on chains started from a
+//! genesis that already has the new layout.
+//!
+//! - `MigrateV0ToV1`: rebuild `RollingState` on `TimeProvider` time. Before
+//!   version 1 the pallet derived "now" from the block number, which lagged the
+//!   bucket timestamps reported by the offchain worker, so rolling windows
+//!   started too early and stale `RainBuckets` were never pruned.
+//! - `MigrateV1ToV2`: move `HourlyBuckets` from hour-keyed entries into the
+//!   per-market ring of `hourly_ring::ring_slots` slots and build each market's
+//!   `HourlyRing` running sum. Buckets and provider readings that already left
+//!   the acceptance window are evicted on the way.
+//! - `MigrateV2ToV3`: seed `RainBucketPruneCursor` with each market's lowest
+//!   `RainBuckets` index so the `on_idle` retention pruner starts from the
+//!   oldest bucket written before version 3.
+//! - `MigrateV3ToV4`: build the `TriggersByMarket` index from the retained
+//!   `ThresholdTriggerLogs`, keeping each market's most recent
+//!   `MAX_TRIGGERS_PER_MARKET` trigger IDs.
+//! - `MigrateV4ToV5`: queue the V2 reports pending before version 5 in
+//!   `V2FinalizationQueue`, ordered by `challenge_ends_at`, so `on_initialize`
+//!   finalizes them without scanning `PendingV2Reports`.
+
+use crate::{
+    bucket_index_with_interval, hourly_ring, hourly_ring::HourlyRingState, trigger_index, Config,
+    HourlyBucket, HourlyBuckets, HourlyRing, LocationId, NextTriggerLogId,
+    NextV2FinalizationIndex, Pallet, PendingV2Reports, ProviderAgreement, ProviderHourlyReadings,
+    RainBucketPruneCursor, RainBuckets, RollingState, RollingWindowState, ThresholdTriggerLogs,
+    TriggerLogPruneCursor, TriggersByMarket, V2FinalizationCursor, V2FinalizationQueue,
+};
+use alloc::{collections::BTreeMap, vec::Vec};
+use frame_support::{
+    migrations::VersionedMigration,
+    BoundedVec,
+    traits::{Get, UncheckedOnRuntimeUpgrade},
+    weights::Weight,
+};
+use pallet_prmx_markets::MarketId;
+use prmx_time::ChainTime;
+
+#[cfg(feature = "try-runtime")]
+use codec::{Decode, Encode};
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+/// Storage layouts before version 2
+pub mod v1 {
+    use super::*;
+    use crate::Millimeters;
+    use codec::{Decode, Encode};
+    use frame_support::{storage_alias, Blake2_128Concat};
+
+    /// `HourlyBuckets` value before the ring buffer; the hour was the map key
+    #[derive(Encode, Decode)]
+    pub struct HourlyBucketV1 {
+        pub mm: Millimeters,
+        pub fetched_at: u64,
+        pub source: u8,
+    }
+
+    /// `HourlyBuckets` keyed by (market_id, hour_index)
+    #[storage_alias]
+    pub type HourlyBuckets<T: Config> = StorageDoubleMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        MarketId,
+        Blake2_128Concat,
+        u64,
+        HourlyBucketV1,
+    >;
+}
+
+/// Prune every market's rain buckets to the rolling window ending at the
+/// current `TimeProvider` time and recompute its `RollingState`. Use through
+/// `MigrateV0ToV1`, which checks and bumps the storage version.
+pub struct UncheckedMigrateV0ToV1<T>(core::marker::PhantomData<T>);
+
+impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV0ToV1<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let now = T::TimeProvider::now_secs();
+        if now == 0 {
+            // No timestamp yet (genesis); there is nothing to rebase
+            return T::DbWeight::get().reads(1);
+        }
+
+        let locations: Vec<(LocationId, RollingWindowState)> =
+            RollingState::<T>::iter().collect();
+        let mut reads = 1 + locations.len() as u64;
+        let mut writes = 0u64;
+        let mut pruned = 0u64;
+
+        for (location_id, state) in locations {
+            let (interval_secs, window_secs) = Pallet::<T>::market_window(location_id);
+            let window_start_idx =
+                bucket_index_with_interval(now.saturating_sub(window_secs), interval_secs);
+            reads += 1;
+
+            let mut rebuilt = RollingWindowState {
+                last_bucket_index: state.last_bucket_index,
+                oldest_bucket_index: window_start_idx,
+                rolling_sum_mm: 0,
+            };
+            let mut kept = 0u64;
+
+            let buckets: Vec<_> = RainBuckets::<T>::iter_prefix(location_id).collect();
+            reads += buckets.len() as u64;
+
+            for (idx, bucket) in buckets {
+                if idx < window_start_idx {
+                    RainBuckets::<T>::remove(location_id, idx);
+                    writes += 1;
+                    pruned += 1;
+                    continue;
+                }
+                kept += 1;
+                rebuilt.rolling_sum_mm = rebuilt.rolling_sum_mm.saturating_add(bucket.rainfall_mm);
+                rebuilt.last_bucket_index = rebuilt.last_bucket_index.max(idx);
+            }
+
+            if kept == 0 {
+                RollingState::<T>::remove(location_id);
+            } else {
+                RollingState::<T>::insert(location_id, rebuilt);
+            }
+            writes += 1;
+        }
+
+        log::info!(
+            target: "prmx-oracle",
+            "Rebased rolling state on chain time {}; pruned {} stale rain buckets",
+            now,
+            pruned
+        );
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+        Ok((RollingState::<T>::iter_keys().count() as u64).encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+        let before = u64::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+        let after = RollingState::<T>::iter_keys().count() as u64;
+        frame_support::ensure!(after <= before, "rolling state created by migration");
+
+        let now = T::TimeProvider::now_secs();
+        for (location_id, state) in RollingState::<T>::iter() {
+            let (interval_secs, window_secs) = Pallet::<T>::market_window(location_id);
+            let window_start_idx =
+                bucket_index_with_interval(now.saturating_sub(window_secs), interval_secs);
+            let mut sum = 0u32;
+            for (idx, bucket) in RainBuckets::<T>::iter_prefix(location_id) {
+                frame_support::ensure!(idx >= window_start_idx, "stale rain bucket kept");
+                sum = sum.saturating_add(bucket.rainfall_mm);
+            }
+            frame_support::ensure!(
+                state.rolling_sum_mm == sum,
+                "rolling sum does not match remaining buckets"
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Rolling state rebased from block-number time to `TimeProvider` time,
+/// storage version 0 to 1
+pub type MigrateV0ToV1<T> = VersionedMigration<
+    0,
+    1,
+    UncheckedMigrateV0ToV1<T>,
+    Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+>;
+
+/// Move every market's hourly buckets into its ring and build the running sum.
+/// Use through `MigrateV1ToV2`, which checks and bumps the storage version.
+pub struct UncheckedMigrateV1ToV2<T>(core::marker::PhantomData<T>);
+
+impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV1ToV2<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let now = T::TimeProvider::now_secs();
+        let current_hour = now / 3600;
+
+        // Decode with the old layout before any entry under the new one is written
+        let old_buckets: Vec<(MarketId, u64, v1::HourlyBucketV1)> =
+            v1::HourlyBuckets::<T>::drain().collect();
+        let mut reads = 1 + old_buckets.len() as u64;
+        let mut writes = old_buckets.len() as u64;
+
+        // Every market with hourly data gets a ring starting at its acceptance window,
+        // so readings without an agreed bucket are evicted on time as well
+        let mut rings: BTreeMap<MarketId, HourlyRingState> = BTreeMap::new();
+        let mut stale_readings: Vec<(MarketId, u64)> = Vec::new();
+        for (market_id, hour_index) in ProviderHourlyReadings::<T>::iter_keys() {
+            reads += 1;
+            let oldest_hour = Pallet::<T>::oldest_acceptable_hour(market_id, now);
+            rings
+                .entry(market_id)
+                .or_insert_with(|| HourlyRingState::starting_at(oldest_hour));
+            if hour_index < oldest_hour {
+                stale_readings.push((market_id, hour_index));
+            }
+        }
+
+        let agreement = ProviderAgreement::<T>::get();
+        for (market_id, hour_index) in stale_readings {
+            let readings = ProviderHourlyReadings::<T>::take(market_id, hour_index);
+            Pallet::<T>::score_final_readings(&readings, &agreement);
+            reads += 1 + readings.len() as u64;
+            writes += 1 + readings.len() as u64;
+        }
+
+        let (mut kept, mut pruned) = (0u64, 0u64);
+        for (market_id, hour_index, old) in old_buckets {
+            let oldest_hour = Pallet::<T>::oldest_acceptable_hour(market_id, now);
+            let ring = rings
+                .entry(market_id)
+                .or_insert_with(|| HourlyRingState::starting_at(oldest_hour));
+            if hour_index < oldest_hour || hour_index > current_hour {
+                pruned += 1;
+                continue;
+            }
+
+            let (_, window_secs) = Pallet::<T>::market_window(market_id);
+            let slot = hourly_ring::slot(hour_index, hourly_ring::ring_slots(window_secs));
+            ring.add(old.mm);
+            HourlyBuckets::<T>::insert(
+                market_id,
+                slot,
+                HourlyBucket {
+                    hour_index,
+                    mm: old.mm,
+                    fetched_at: old.fetched_at,
+                    source: old.source,
+                },
+            );
+            reads += 1;
+            writes += 1;
+            kept += 1;
+        }
+
+        writes += rings.len() as u64;
+        for (market_id, ring) in rings {
+            HourlyRing::<T>::insert(market_id, ring);
+        }
+
+        log::info!(
+            target: "prmx-oracle",
+            "Moved {} hourly buckets into market rings; pruned {} outside the acceptance window",
+            kept,
+            pruned
+        );
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+        let now = T::TimeProvider::now_secs();
+        let mut live: BTreeMap<MarketId, u64> = BTreeMap::new();
+        for (market_id, hour_index, bucket) in v1::HourlyBuckets::<T>::iter() {
+            let oldest_hour = Pallet::<T>::oldest_acceptable_hour(market_id, now);
+            if hour_index >= oldest_hour && hour_index <= now / 3600 {
+                *live.entry(market_id).or_default() += bucket.mm as u64;
+            }
+        }
+        Ok(live.encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+        let live = BTreeMap::<MarketId, u64>::decode(&mut &state[..])
+            .map_err(|_| "invalid pre-upgrade state")?;
+        for (market_id, sum_mm) in live {
+            let ring = HourlyRing::<T>::get(market_id).ok_or("market without a ring")?;
+            frame_support::ensure!(ring.sum_mm == sum_mm, "ring sum does not match old buckets");
+
+            let (_, window_secs) = Pallet::<T>::market_window(market_id);
+            let slots = hourly_ring::ring_slots(window_secs);
+            let mut stored = 0u64;
+            // `iter_prefix` decodes every value, so this also checks the new layout
+            for (slot, bucket) in HourlyBuckets::<T>::iter_prefix(market_id) {
+                frame_support::ensure!(
+                    slot == hourly_ring::slot(bucket.hour_index, slots),
+                    "bucket in the wrong ring slot"
+                );
+                stored += bucket.mm as u64;
+            }
+            frame_support::ensure!(stored == sum_mm, "buckets lost in migration");
+        }
+        Ok(())
+    }
+}
+
+/// Hourly buckets in a per-market ring with a running sum, storage version 1 to 2
+pub type MigrateV1ToV2<T> = VersionedMigration<
+    1,
+    2,
+    UncheckedMigrateV1ToV2<T>,
+    Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+>;
+
+/// Point every market's prune cursor at its lowest rain bucket index. Use
+/// through `MigrateV2ToV3`, which checks and bumps the storage version.
+pub struct UncheckedMigrateV2ToV3<T>(core::marker::PhantomData<T>);
+
+impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV2ToV3<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let mut lowest: BTreeMap<MarketId, u64> = BTreeMap::new();
+        let mut reads = 0u64;
+        for (market_id, idx) in RainBuckets::<T>::iter_keys() {
+            reads += 1;
+            lowest
+                .entry(market_id)
+                .and_modify(|current| *current = (*current).min(idx))
+                .or_insert(idx);
+        }
+
+        let writes = lowest.len() as u64;
+        for (market_id, idx) in lowest {
+            RainBucketPruneCursor::<T>::insert(market_id, idx);
+        }
+
+        log::info!(
+            target: "prmx-oracle",
+            "Seeded rain bucket prune cursors for {} markets",
+            writes
+        );
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+        for (market_id, idx) in RainBuckets::<T>::iter_keys() {
+            let cursor = RainBucketPruneCursor::<T>::get(market_id)
+                .ok_or("market with rain buckets but no prune cursor")?;
+            frame_support::ensure!(cursor <= idx, "prune cursor past a rain bucket");
+        }
+        Ok(())
+    }
+}
+
+/// Rain bucket prune cursors for data retention, storage version 2 to 3
+pub type MigrateV2ToV3<T> = VersionedMigration<
+    2,
+    3,
+    UncheckedMigrateV2ToV3<T>,
+    Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+>;
+
+/// Index every retained trigger log under its market. Use through
+/// `MigrateV3ToV4`, which checks and bumps the storage version.
+pub struct UncheckedMigrateV3ToV4<T>(core::marker::PhantomData<T>);
+
+impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV3ToV4<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let mut indexes: BTreeMap<MarketId, Vec<u64>> = BTreeMap::new();
+        let mut reads = 2u64;
+        // Trigger IDs are issued in order and pruned from the cursor on, so walking
+        // the retained range yields each market's IDs ascending
+        for trigger_id in TriggerLogPruneCursor::<T>::get()..NextTriggerLogId::<T>::get() {
+            reads += 1;
+            if let Some(log) = ThresholdTriggerLogs::<T>::get(trigger_id) {
+                trigger_index::push_bounded(
+                    indexes.entry(log.market_id).or_default(),
+                    trigger_id,
+                    trigger_index::MAX_TRIGGERS_PER_MARKET as usize,
+                );
+            }
+        }
+
+        let writes = indexes.len() as u64;
+        for (market_id, ids) in indexes {
+            TriggersByMarket::<T>::insert(market_id, BoundedVec::truncate_from(ids));
+        }
+
+        log::info!(
+            target: "prmx-oracle",
+            "Indexed threshold trigger logs for {} markets",
+            writes
+        );
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+        for (market_id, ids) in TriggersByMarket::<T>::iter() {
+            frame_support::ensure!(
+                ids.windows(2).all(|pair| pair[0] < pair[1]),
+                "trigger index not ascending"
+            );
+            for trigger_id in ids {
+                let log = ThresholdTriggerLogs::<T>::get(trigger_id)
+                    .ok_or("indexed trigger log missing")?;
+                frame_support::ensure!(
+                    log.market_id == market_id,
+                    "trigger indexed under wrong market"
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Per-market threshold trigger index, storage version 3 to 4
+pub type MigrateV3ToV4<T> = VersionedMigration<
+    3,
+    4,
+    UncheckedMigrateV3ToV4<T>,
+    Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+>;
+
+/// Queue every pending V2 report for finalization. Use through `MigrateV4ToV5`,
+/// which checks and bumps the storage version.
+pub struct UncheckedMigrateV4ToV5<T>(core::marker::PhantomData<T>);
+
+impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV4ToV5<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let mut pending: Vec<_> = PendingV2Reports::<T>::iter()
+            .map(|(policy_id, report)| (report.challenge_ends_at, policy_id))
+            .collect();
+        pending.sort();
+
+        let reads = pending.len() as u64;
+        let queued = pending.len() as u64;
+        for (index, (challenge_ends_at, policy_id)) in pending.into_iter().enumerate() {
+            V2FinalizationQueue::<T>::insert(index as u64, (policy_id, challenge_ends_at));
+        }
+        V2FinalizationCursor::<T>::put(0);
+        NextV2FinalizationIndex::<T>::put(queued);
+
+        log::info!(
+            target: "prmx-oracle",
+            "Queued {} pending V2 reports for finalization",
+            queued
+        );
+
+        T::DbWeight::get().reads_writes(reads, queued + 2)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+        let queued: Vec<_> = (0..NextV2FinalizationIndex::<T>::get())
+            .filter_map(V2FinalizationQueue::<T>::get)
+            .collect();
+        frame_support::ensure!(
+            queued.windows(2).all(|pair| pair[0].1 <= pair[1].1),
+            "finalization queue not in maturity order"
+        );
+        frame_support::ensure!(
+            queued.len() == PendingV2Reports::<T>::iter_keys().count(),
+            "pending V2 report missing from the finalization queue"
+        );
+        Ok(())
+    }
+}
+
+/// Ordered V2 report finalization queue, storage version 4 to 5
+pub type MigrateV4ToV5<T> = VersionedMigration<
+    4,
+    5,
+    UncheckedMigrateV4ToV5<T>,
+    Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+>;
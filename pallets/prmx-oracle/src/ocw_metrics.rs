@@ -0,0 +1,214 @@
+//! # OCW Health Metrics
+//!
+//! Counters the offchain worker keeps in PERSISTENT offchain storage: weather
+//! API calls and failures, signed transaction submissions and failures, and
+//! backoffs (fetches deferred because an earlier attempt is still in flight or
+//! returned incomplete data). Every `BLOCKS_PER_HEALTH_REPORT` blocks the OCW
+//! reports them on-chain with `report_ocw_health` and subtracts what it
+//! reported, so each `OcwHealth` entry covers one reporting period.
+//!
+//! Failed OCW operations are also counted per `OcwErrorKind` under a separate
+//! key. Those counts are never reported or reset; they are read locally when
+//! diagnosing a node.
+//!
+//! Updates go through `StorageValueRef::mutate`. A write that loses a race with
+//! a concurrent worker run is dropped; the counters are for alerting, not
+//! accounting.
+
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use prmx_primitives::{OcwErrorCounts, OcwErrorKind};
+use scale_info::TypeInfo;
+use sp_runtime::{
+    offchain::storage::{StorageRetrievalError, StorageValueRef},
+    Permill, RuntimeDebug,
+};
+
+/// Offchain storage key of the counters accumulated since the last report
+pub const OCW_METRICS_KEY: &[u8] = b"prmx-oracle::ocw-metrics";
+
+/// Offchain storage key of the per-kind OCW error counts
+pub const OCW_ERROR_COUNTS_KEY: &[u8] = b"prmx-oracle::ocw-error-counts";
+
+/// Something the offchain worker did that the health counters track
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum OcwEvent {
+    /// An HTTP request to a weather or gauge API
+    ApiCall { ok: bool },
+    /// A signed oracle transaction handed to the transaction pool
+    Submission { ok: bool },
+    /// Work deferred to a later run
+    Backoff,
+}
+
+/// OCW activity counters for one reporting period
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+    Default,
+)]
+pub struct OcwCounters {
+    /// HTTP requests sent to weather and gauge APIs
+    pub api_calls: u32,
+    /// Requests that failed, timed out or returned a non-200 status
+    pub api_failures: u32,
+    /// Signed transactions submitted to the pool
+    pub submissions: u32,
+    /// Signed transactions the pool rejected
+    pub submission_failures: u32,
+    /// Worker runs that deferred a fetch because an earlier attempt is still in
+    /// flight or returned incomplete data
+    pub backoffs: u32,
+}
+
+impl OcwCounters {
+    /// Count one event
+    pub fn record(&mut self, event: OcwEvent) {
+        match event {
+            OcwEvent::ApiCall { ok } => {
+                self.api_calls = self.api_calls.saturating_add(1);
+                if !ok {
+                    self.api_failures = self.api_failures.saturating_add(1);
+                }
+            }
+            OcwEvent::Submission { ok } => {
+                self.submissions = self.submissions.saturating_add(1);
+                if !ok {
+                    self.submission_failures = self.submission_failures.saturating_add(1);
+                }
+            }
+            OcwEvent::Backoff => self.backoffs = self.backoffs.saturating_add(1),
+        }
+    }
+
+    /// Counters left after removing those already reported in `reported`
+    pub fn saturating_sub(&self, reported: &OcwCounters) -> OcwCounters {
+        OcwCounters {
+            api_calls: self.api_calls.saturating_sub(reported.api_calls),
+            api_failures: self.api_failures.saturating_sub(reported.api_failures),
+            submissions: self.submissions.saturating_sub(reported.submissions),
+            submission_failures: self
+                .submission_failures
+                .saturating_sub(reported.submission_failures),
+            backoffs: self.backoffs.saturating_sub(reported.backoffs),
+        }
+    }
+
+    /// Share of API calls that failed
+    pub fn api_failure_rate(&self) -> Permill {
+        Permill::from_rational(self.api_failures, self.api_calls.max(1))
+    }
+}
+
+/// Counters accumulated since the last report
+pub fn current() -> OcwCounters {
+    StorageValueRef::persistent(OCW_METRICS_KEY)
+        .get::<OcwCounters>()
+        .ok()
+        .flatten()
+        .unwrap_or_default()
+}
+
+/// Count one event in offchain storage
+pub fn record(event: OcwEvent) {
+    update(|counters| counters.record(event));
+}
+
+/// Count each result of a `send_signed_transaction` as a submission
+pub fn record_submissions<A, E>(results: &[(A, Result<(), E>)]) {
+    update(|counters| {
+        for (_, result) in results {
+            counters.record(OcwEvent::Submission { ok: result.is_ok() });
+        }
+    });
+}
+
+/// Remove counters that made it into an on-chain report
+pub fn clear_reported(reported: &OcwCounters) {
+    update(|counters| *counters = counters.saturating_sub(reported));
+}
+
+/// OCW failures counted per error kind since the node's offchain DB was created
+pub fn error_counts() -> OcwErrorCounts {
+    StorageValueRef::persistent(OCW_ERROR_COUNTS_KEY)
+        .get::<OcwErrorCounts>()
+        .ok()
+        .flatten()
+        .unwrap_or_default()
+}
+
+/// Count one failed OCW operation by kind
+pub fn record_error(kind: OcwErrorKind) {
+    let _ = StorageValueRef::persistent(OCW_ERROR_COUNTS_KEY).mutate(
+        |stored: Result<Option<OcwErrorCounts>, StorageRetrievalError>| -> Result<_, ()> {
+            let mut counts = stored.ok().flatten().unwrap_or_default();
+            counts.record(kind);
+            Ok(counts)
+        },
+    );
+}
+
+fn update(f: impl FnOnce(&mut OcwCounters)) {
+    let _ = StorageValueRef::persistent(OCW_METRICS_KEY).mutate(
+        |stored: Result<Option<OcwCounters>, StorageRetrievalError>| -> Result<_, ()> {
+            let mut counters = stored.ok().flatten().unwrap_or_default();
+            f(&mut counters);
+            Ok(counters)
+        },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_counts_totals_and_failures() {
+        let mut counters = OcwCounters::default();
+        counters.record(OcwEvent::ApiCall { ok: true });
+        counters.record(OcwEvent::ApiCall { ok: false });
+        counters.record(OcwEvent::Submission { ok: false });
+        counters.record(OcwEvent::Backoff);
+
+        assert_eq!(
+            counters,
+            OcwCounters {
+                api_calls: 2,
+                api_failures: 1,
+                submissions: 1,
+                submission_failures: 1,
+                backoffs: 1,
+            }
+        );
+        assert_eq!(counters.api_failure_rate(), Permill::from_percent(50));
+        assert_eq!(OcwCounters::default().api_failure_rate(), Permill::zero());
+    }
+
+    #[test]
+    fn test_saturating_sub_keeps_counts_since_report() {
+        let reported = OcwCounters {
+            api_calls: 3,
+            backoffs: 1,
+            ..Default::default()
+        };
+        let mut current = reported;
+        current.record(OcwEvent::ApiCall { ok: false });
+
+        let left = current.saturating_sub(&reported);
+        assert_eq!(
+            (left.api_calls, left.api_failures, left.backoffs),
+            (1, 1, 0)
+        );
+        assert_eq!(
+            OcwCounters::default().saturating_sub(&reported),
+            OcwCounters::default()
+        );
+    }
+}
@@ -0,0 +1,474 @@
+//! # Weather Providers
+//!
+//! Provider abstraction and HTTP fetchers used by the offchain worker to
+//! collect hourly rainfall from sources other than AccuWeather.
+//!
+//! Every fetcher returns `(epoch_time, rainfall_mm)` pairs with rainfall in
+//! tenths of mm, matching the format of `submit_hourly_rainfall_from_ocw`.
+
+use alloc::format;
+use alloc::vec::Vec;
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::offchain::{http, Duration};
+use sp_runtime::RuntimeDebug;
+
+use crate::ocw_metrics::{self, OcwEvent};
+use crate::Millimeters;
+use prmx_primitives::OcwErrorKind;
+
+// ============================================================================
+// Provider Types
+// ============================================================================
+
+/// Maximum number of weather providers reporting for a single hour
+pub const MAX_WEATHER_PROVIDERS: u32 = 5;
+
+/// Weather data provider that produced a rainfall reading
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum WeatherProvider {
+    /// AccuWeather historical/24 endpoint
+    AccuWeather,
+    /// Open-Meteo forecast API (past_days)
+    OpenMeteo,
+    /// NOAA api.weather.gov station observations
+    Noaa,
+    /// Meteostat point/hourly API (via RapidAPI)
+    Meteostat,
+    /// Market-specific API configured through `MarketDataSources`
+    Custom,
+}
+
+impl WeatherProvider {
+    /// Human-readable provider name for logs
+    pub fn name(&self) -> &'static str {
+        match self {
+            WeatherProvider::AccuWeather => "AccuWeather",
+            WeatherProvider::OpenMeteo => "Open-Meteo",
+            WeatherProvider::Noaa => "NOAA",
+            WeatherProvider::Meteostat => "Meteostat",
+            WeatherProvider::Custom => "Custom",
+        }
+    }
+}
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Open-Meteo forecast API base URL (no API key required)
+pub const OPEN_METEO_BASE_URL: &str = "https://api.open-meteo.com/v1/forecast";
+
+/// NOAA weather.gov API base URL (US stations only)
+pub const NOAA_BASE_URL: &str = "https://api.weather.gov";
+
+/// Meteostat API host on RapidAPI
+pub const METEOSTAT_HOST: &str = "meteostat.p.rapidapi.com";
+
+/// User agent sent to NOAA (required by api.weather.gov)
+pub const NOAA_USER_AGENT: &str = "prmx-oracle (https://prmx.io)";
+
+/// Offchain storage key for Meteostat (RapidAPI) key
+pub const METEOSTAT_API_KEY_STORAGE: &[u8] = b"prmx-oracle::meteostat-api-key";
+
+/// Offchain storage key prefix for NOAA station ID per market (e.g. b"KJFK")
+pub const NOAA_STATION_PREFIX: &[u8] = b"prmx-oracle::noaa-station::";
+
+/// HTTP request timeout (30 seconds)
+pub const HTTP_TIMEOUT_MS: u64 = 30_000;
+
+/// Seconds per day
+const SECS_PER_DAY: u64 = 86_400;
+
+// ============================================================================
+// Fetchers
+// ============================================================================
+
+/// Fetch the last 24 hours of hourly precipitation from Open-Meteo
+pub fn fetch_open_meteo(
+    lat: f64,
+    lon: f64,
+    now: u64,
+) -> Result<Vec<(u64, Millimeters)>, OcwErrorKind> {
+    let url = format!(
+        "{}?latitude={}&longitude={}&hourly=precipitation&past_days=1&forecast_days=1&timeformat=unixtime&timezone=GMT",
+        OPEN_METEO_BASE_URL, lat, lon
+    );
+
+    let body = http_get(&url, &[])?;
+    let readings = parse_open_meteo_response(&body)?;
+
+    Ok(last_24_hours(readings, now))
+}
+
+/// Fetch hourly precipitation between two timestamps (inclusive) from Open-Meteo.
+/// Used for historic backfill pages older than AccuWeather's historical/24 range.
+pub fn fetch_open_meteo_range(
+    lat: f64,
+    lon: f64,
+    from_ts: u64,
+    to_ts: u64,
+) -> Result<Vec<(u64, Millimeters)>, OcwErrorKind> {
+    let (sy, sm, sd) = civil_from_days(from_ts / SECS_PER_DAY);
+    let (ey, em, ed) = civil_from_days(to_ts / SECS_PER_DAY);
+
+    let url = format!(
+        "{}?latitude={}&longitude={}&hourly=precipitation&start_date={:04}-{:02}-{:02}&end_date={:04}-{:02}-{:02}&timeformat=unixtime&timezone=GMT",
+        OPEN_METEO_BASE_URL, lat, lon, sy, sm, sd, ey, em, ed
+    );
+
+    let body = http_get(&url, &[])?;
+    let mut readings = parse_open_meteo_response(&body)?;
+    readings.retain(|(ts, _)| *ts >= from_ts && *ts <= to_ts);
+    readings.sort_by_key(|(ts, _)| *ts);
+
+    Ok(readings)
+}
+
+/// Fetch the latest 24 hourly observations from a NOAA station
+pub fn fetch_noaa(station: &str, now: u64) -> Result<Vec<(u64, Millimeters)>, OcwErrorKind> {
+    let url = format!("{}/stations/{}/observations?limit=24", NOAA_BASE_URL, station);
+
+    let body = http_get(
+        &url,
+        &[("User-Agent", NOAA_USER_AGENT), ("Accept", "application/geo+json")],
+    )?;
+    let readings = parse_noaa_response(&body)?;
+
+    Ok(last_24_hours(readings, now))
+}
+
+/// Fetch yesterday's and today's hourly precipitation from Meteostat
+pub fn fetch_meteostat(
+    api_key: &[u8],
+    lat: f64,
+    lon: f64,
+    now: u64,
+) -> Result<Vec<(u64, Millimeters)>, OcwErrorKind> {
+    let api_key_str = core::str::from_utf8(api_key).map_err(|_| OcwErrorKind::Config)?;
+
+    let (sy, sm, sd) = civil_from_days(now.saturating_sub(SECS_PER_DAY) / SECS_PER_DAY);
+    let (ey, em, ed) = civil_from_days(now / SECS_PER_DAY);
+
+    let url = format!(
+        "https://{}/point/hourly?lat={}&lon={}&start={:04}-{:02}-{:02}&end={:04}-{:02}-{:02}&tz=UTC",
+        METEOSTAT_HOST, lat, lon, sy, sm, sd, ey, em, ed
+    );
+
+    let body = http_get(
+        &url,
+        &[("x-rapidapi-key", api_key_str), ("x-rapidapi-host", METEOSTAT_HOST)],
+    )?;
+    let readings = parse_meteostat_response(&body)?;
+
+    Ok(last_24_hours(readings, now))
+}
+
+/// Perform a GET request and return the body of a 200 response, counting the call
+/// in the OCW health metrics
+pub(crate) fn http_get(url: &str, headers: &[(&str, &str)]) -> Result<Vec<u8>, OcwErrorKind> {
+    let result = send_get(url, headers);
+    ocw_metrics::record(OcwEvent::ApiCall { ok: result.is_ok() });
+    result
+}
+
+fn send_get(url: &str, headers: &[(&str, &str)]) -> Result<Vec<u8>, OcwErrorKind> {
+    let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(HTTP_TIMEOUT_MS));
+
+    let mut request = http::Request::get(url);
+    for (name, value) in headers {
+        request = request.add_header(name, value);
+    }
+
+    let pending = request
+        .deadline(deadline)
+        .send()
+        .map_err(|_| OcwErrorKind::Timeout)?;
+
+    let response = pending
+        .try_wait(deadline)
+        .map_err(|_| OcwErrorKind::Timeout)?
+        .map_err(|_| OcwErrorKind::Timeout)?;
+
+    if response.code != 200 {
+        log::warn!(
+            target: "prmx-oracle",
+            "Weather provider returned status {} for {}",
+            response.code,
+            url
+        );
+        return Err(OcwErrorKind::Http(response.code));
+    }
+
+    Ok(response.body().collect::<Vec<u8>>())
+}
+
+/// Keep only readings from the 24 hours up to `now`, sorted by time
+pub(crate) fn last_24_hours(
+    mut readings: Vec<(u64, Millimeters)>,
+    now: u64,
+) -> Vec<(u64, Millimeters)> {
+    let oldest = now.saturating_sub(SECS_PER_DAY);
+    readings.retain(|(ts, _)| *ts <= now && *ts > oldest);
+    readings.sort_by_key(|(ts, _)| *ts);
+    readings
+}
+
+// ============================================================================
+// Response Parsers
+// ============================================================================
+
+/// Parse Open-Meteo `hourly.time` / `hourly.precipitation` arrays
+pub fn parse_open_meteo_response(json: &[u8]) -> Result<Vec<(u64, Millimeters)>, OcwErrorKind> {
+    let json_str = core::str::from_utf8(json).map_err(|_| OcwErrorKind::Parse)?;
+
+    // Skip "hourly_units", which also contains "time" and "precipitation" keys
+    let hourly_start = json_str.find("\"hourly\":").ok_or(OcwErrorKind::Parse)?;
+    let hourly = &json_str[hourly_start..];
+
+    let times = extract_number_array(hourly, "time").ok_or(OcwErrorKind::Parse)?;
+    let precip = extract_number_array(hourly, "precipitation").ok_or(OcwErrorKind::Parse)?;
+
+    if times.len() != precip.len() {
+        return Err(OcwErrorKind::Parse);
+    }
+
+    Ok(times
+        .into_iter()
+        .zip(precip)
+        .filter_map(|(ts, mm)| Some((ts? as u64, mm_to_tenths(mm?))))
+        .collect())
+}
+
+/// Parse NOAA observation features (`timestamp` + `precipitationLastHour.value`)
+pub fn parse_noaa_response(json: &[u8]) -> Result<Vec<(u64, Millimeters)>, OcwErrorKind> {
+    let json_str = core::str::from_utf8(json).map_err(|_| OcwErrorKind::Parse)?;
+
+    let mut results = Vec::new();
+    let mut search_start = 0;
+
+    while let Some(pos) = json_str[search_start..].find("\"timestamp\"") {
+        let abs_pos = search_start + pos;
+        let after = &json_str[abs_pos + 11..];
+
+        // Observation ends where the next one begins
+        let obs_end = after.find("\"timestamp\"").unwrap_or(after.len());
+        let observation = &after[..obs_end];
+        search_start = abs_pos + 11 + obs_end;
+
+        let Some(ts) = extract_string_value(observation, "").and_then(parse_iso8601) else {
+            continue;
+        };
+
+        // Missing or null precipitation is treated as no reading for that hour
+        let precip = observation
+            .find("\"precipitationLastHour\"")
+            .and_then(|p| extract_number_value(&observation[p..], "value"));
+
+        if let Some(mm) = precip {
+            results.push((ts, mm_to_tenths(mm)));
+        }
+    }
+
+    Ok(results)
+}
+
+/// Parse Meteostat `data` entries (`time` + `prcp`)
+pub fn parse_meteostat_response(json: &[u8]) -> Result<Vec<(u64, Millimeters)>, OcwErrorKind> {
+    let json_str = core::str::from_utf8(json).map_err(|_| OcwErrorKind::Parse)?;
+
+    let data_start = json_str.find("\"data\":").ok_or(OcwErrorKind::Parse)?;
+
+    let mut results = Vec::new();
+    let mut rest = &json_str[data_start..];
+
+    while let Some(obj_start) = rest.find('{') {
+        let obj_end = match rest[obj_start..].find('}') {
+            Some(end) => obj_start + end,
+            None => break,
+        };
+        let object = &rest[obj_start..=obj_end];
+        rest = &rest[obj_end + 1..];
+
+        let ts = extract_string_value(object, "time").and_then(parse_iso8601);
+        let mm = extract_number_value(object, "prcp");
+
+        if let (Some(ts), Some(mm)) = (ts, mm) {
+            results.push((ts, mm_to_tenths(mm)));
+        }
+    }
+
+    Ok(results)
+}
+
+/// Convert a millimetre reading to tenths of mm, clamping negatives to zero
+fn mm_to_tenths(mm: f64) -> Millimeters {
+    if mm <= 0.0 {
+        return 0;
+    }
+    // Manual rounding: add 0.5 and truncate (f64::round not available in no_std)
+    (mm * 10.0 + 0.5) as Millimeters
+}
+
+/// Extract a JSON array of numbers (`null` entries become `None`)
+fn extract_number_array(json: &str, key: &str) -> Option<Vec<Option<f64>>> {
+    let pattern = format!("\"{}\"", key);
+    let key_pos = json.find(&pattern)?;
+    let after_key = &json[key_pos + pattern.len()..];
+
+    let open = after_key.find('[')?;
+    let close = after_key[open..].find(']')? + open;
+    let body = &after_key[open + 1..close];
+
+    if body.trim().is_empty() {
+        return Some(Vec::new());
+    }
+
+    Some(body.split(',').map(|v| v.trim().parse::<f64>().ok()).collect())
+}
+
+/// Extract a numeric value for `key` (tolerates whitespace and `null`)
+fn extract_number_value(json: &str, key: &str) -> Option<f64> {
+    let value = value_after_key(json, key)?;
+    let end = value
+        .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-' || c == 'e' || c == 'E'))
+        .unwrap_or(value.len());
+
+    value[..end].parse::<f64>().ok()
+}
+
+/// Extract a string value for `key`; an empty key reads the value at the start of `json`
+fn extract_string_value<'a>(json: &'a str, key: &str) -> Option<&'a str> {
+    let value = if key.is_empty() {
+        json.trim_start().strip_prefix(':')?.trim_start()
+    } else {
+        value_after_key(json, key)?
+    };
+
+    let inner = value.strip_prefix('"')?;
+    let end = inner.find('"')?;
+    Some(&inner[..end])
+}
+
+/// Return the text following `"key":` with leading whitespace removed
+fn value_after_key<'a>(json: &'a str, key: &str) -> Option<&'a str> {
+    let pattern = format!("\"{}\"", key);
+    let key_pos = json.find(&pattern)?;
+    let after_key = json[key_pos + pattern.len()..].trim_start();
+    Some(after_key.strip_prefix(':')?.trim_start())
+}
+
+// ============================================================================
+// Date Helpers
+// ============================================================================
+
+/// Parse `YYYY-MM-DD[T ]HH:MM:SS[+HH:MM|Z]` into a Unix timestamp
+pub fn parse_iso8601(value: &str) -> Option<u64> {
+    let bytes = value.as_bytes();
+    if bytes.len() < 19 {
+        return None;
+    }
+
+    let num = |range: core::ops::Range<usize>| -> Option<i64> { value.get(range)?.parse().ok() };
+
+    let year = num(0..4)?;
+    let month = num(5..7)?;
+    let day = num(8..10)?;
+    let hour = num(11..13)?;
+    let minute = num(14..16)?;
+    let second = num(17..19)?;
+
+    // Optional numeric offset, e.g. "+00:00" or "-05:00"
+    let offset_secs = match bytes.get(19) {
+        Some(b'+') | Some(b'-') => {
+            let sign = if bytes[19] == b'-' { -1 } else { 1 };
+            let oh = num(20..22)?;
+            let om = num(23..25)?;
+            sign * (oh * 3600 + om * 60)
+        }
+        _ => 0,
+    };
+
+    let days = days_from_civil(year, month, day);
+    let ts = days * SECS_PER_DAY as i64 + hour * 3600 + minute * 60 + second - offset_secs;
+
+    u64::try_from(ts).ok()
+}
+
+/// UTC date of a Unix timestamp as `YYYY-MM-DD`
+pub fn format_date(ts: u64) -> alloc::string::String {
+    let (year, month, day) = civil_from_days(ts / SECS_PER_DAY);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Days since 1970-01-01 for a proleptic Gregorian date
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = (if y >= 0 { y } else { y - 399 }) / 400;
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// (year, month, day) for a count of days since 1970-01-01
+fn civil_from_days(days: u64) -> (i64, i64, i64) {
+    let z = days as i64 + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_iso8601() {
+        assert_eq!(parse_iso8601("1970-01-01T00:00:00+00:00"), Some(0));
+        assert_eq!(parse_iso8601("2024-01-01 00:00:00"), Some(1_704_067_200));
+        assert_eq!(parse_iso8601("2024-01-01T07:00:00+07:00"), Some(1_704_067_200));
+        assert_eq!(parse_iso8601("2024-01-01"), None);
+
+        assert_eq!(civil_from_days(1_704_067_200 / SECS_PER_DAY), (2024, 1, 1));
+        assert_eq!(format_date(1_704_153_599), "2024-01-01");
+    }
+
+    #[test]
+    fn test_parse_open_meteo_response() {
+        let json = br#"{"hourly_units":{"time":"unixtime","precipitation":"mm"},"hourly":{"time":[1704067200,1704070800,1704074400],"precipitation":[0.0,1.25,null]}}"#;
+
+        let readings = parse_open_meteo_response(json).unwrap();
+        assert_eq!(readings, vec![(1_704_067_200, 0), (1_704_070_800, 13)]);
+    }
+
+    #[test]
+    fn test_parse_noaa_and_meteostat_responses() {
+        let noaa = br#"{"features": [
+            {"properties": {"timestamp": "2024-01-01T01:00:00+00:00", "precipitationLastHour": {"unitCode": "wmoUnit:mm", "value": 2.5}}},
+            {"properties": {"timestamp": "2024-01-01T00:00:00+00:00", "precipitationLastHour": {"unitCode": "wmoUnit:mm", "value": null}}}
+        ]}"#;
+        assert_eq!(parse_noaa_response(noaa).unwrap(), vec![(1_704_070_800, 25)]);
+
+        let meteostat = br#"{"meta":{"generated":"2024-01-02 00:00:00"},"data":[{"time":"2024-01-01 00:00:00","temp":25.1,"prcp":0.4},{"time":"2024-01-01 01:00:00","temp":24.8,"prcp":null}]}"#;
+        assert_eq!(parse_meteostat_response(meteostat).unwrap(), vec![(1_704_067_200, 4)]);
+    }
+}
@@ -0,0 +1,133 @@
+//! # Oracle Data Retention
+//!
+//! Legacy `RainBuckets` and `ThresholdTriggerLogs` are kept for a retention
+//! window per market (`DataRetention`, `DEFAULT_DATA_RETENTION_SECS` when unset)
+//! and pruned afterwards by `on_idle`, a bounded number of entries per block.
+//! `HourlyBuckets` is a fixed ring per market (see `hourly_ring`) and needs no
+//! pruning.
+//!
+//! Before an entry is removed, its SCALE encoding is appended to the market's
+//! archive chain for that kind of data (`ArchiveChains`). Anyone holding the
+//! pruned entries, e.g. from an archive node or an indexer, can replay them in
+//! pruning order and compare the result with the on-chain chain head:
+//!
+//! - rain buckets are pruned in bucket index order, per market;
+//! - trigger logs are pruned in trigger ID order. A log still inside its
+//!   market's retention window holds back pruning of all later logs.
+//!
+//! V2 final reports (`V2FinalReportByPolicy`) are pruned in finalization order
+//! once past the Manila market's retention window, the only market offering V2
+//! policies. Each leaves the hash of its encoding in `V2FinalReportHashes`
+//! rather than joining an archive chain, so a policy's report can be checked on
+//! its own and is never reported twice.
+
+use alloc::vec::Vec;
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_io::hashing::blake2_256;
+use sp_runtime::RuntimeDebug;
+
+/// Retention window of markets without a governance-set one (365 days)
+pub const DEFAULT_DATA_RETENTION_SECS: u64 = 365 * 24 * 3600;
+
+/// Shortest retention window governance can set (30 days), well beyond the
+/// longest coverage window plus settlement grace
+pub const MIN_DATA_RETENTION_SECS: u64 = 30 * 24 * 3600;
+
+/// Most storage entries `on_idle` visits per block, pruned or not
+pub const MAX_PRUNE_STEPS_PER_BLOCK: u32 = 256;
+
+/// Kind of pruned oracle data, one archive chain per market and kind
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum ArchiveKind {
+    RainBuckets,
+    ThresholdTriggerLogs,
+}
+
+/// Hash chain over every entry of one kind pruned for a market
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+    Default,
+)]
+pub struct ArchiveChain {
+    /// Chain head; all zeroes before anything was pruned
+    pub hash: [u8; 32],
+    /// Number of entries folded into `hash`
+    pub entries: u64,
+    /// Latest timestamp among the pruned entries
+    pub pruned_through: u64,
+}
+
+impl ArchiveChain {
+    /// Fold one pruned entry into the chain.
+    ///
+    /// Format: hash' = blake2_256(b"prmx_archive:" || hash || SCALE(entry))
+    pub fn append(&mut self, encoded_entry: &[u8], timestamp: u64) {
+        let mut data = Vec::with_capacity(13 + 32 + encoded_entry.len());
+        data.extend_from_slice(b"prmx_archive:");
+        data.extend_from_slice(&self.hash);
+        data.extend_from_slice(encoded_entry);
+        self.hash = blake2_256(&data);
+        self.entries = self.entries.saturating_add(1);
+        self.pruned_through = self.pruned_through.max(timestamp);
+    }
+}
+
+/// Whether data recorded at `timestamp` is past a `retention_secs` window at `now`
+pub fn is_expired(timestamp: u64, retention_secs: u64, now: u64) -> bool {
+    timestamp.saturating_add(retention_secs) <= now
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_append_is_order_sensitive_and_reproducible() {
+        let mut chain = ArchiveChain::default();
+        chain.append(b"first", 100);
+        chain.append(b"second", 200);
+        assert_eq!(chain.entries, 2);
+        assert_eq!(chain.pruned_through, 200);
+        assert_ne!(chain.hash, [0u8; 32]);
+
+        let mut replayed = ArchiveChain::default();
+        for (entry, timestamp) in [(&b"first"[..], 100), (&b"second"[..], 200)] {
+            replayed.append(entry, timestamp);
+        }
+        assert_eq!(replayed, chain);
+
+        let mut reordered = ArchiveChain::default();
+        reordered.append(b"second", 200);
+        reordered.append(b"first", 100);
+        assert_ne!(reordered.hash, chain.hash);
+        assert_eq!(reordered.pruned_through, 200);
+    }
+
+    #[test]
+    fn test_is_expired() {
+        let retention = MIN_DATA_RETENTION_SECS;
+        assert!(!is_expired(1_000, retention, 1_000 + retention - 1));
+        assert!(is_expired(1_000, retention, 1_000 + retention));
+        assert!(!is_expired(u64::MAX, retention, u64::MAX - 1));
+    }
+}
@@ -0,0 +1,169 @@
+//! # Provider Data Quality Scoring
+//!
+//! Pure helpers for the per-account statistics kept in `ProviderStats`.
+//!
+//! Every new hourly reading counts as a submission, and a reading arriving more
+//! than `LATE_READING_SECS` after its hour ended counts as late. Once an hour
+//! leaves the acceptance window its readings are compared with the final
+//! consensus median: the distance is added to the provider's deviation total,
+//! and readings beyond the agreement tolerance count as outliers.
+//!
+//! The score starts at 100% and loses the outlier rate, a quarter of the mean
+//! deviation relative to the tolerance (capped at one tolerance) and a quarter of
+//! the late rate. Providers with fewer than `MIN_SCORED_READINGS` scored readings
+//! keep the full score. Scores weight the trimmed mean in `aggregation` and are
+//! exposed to governance for slashing and removal decisions.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{Permill, RuntimeDebug};
+
+use crate::Millimeters;
+
+/// A first reading arriving this long after its hour ended is late (3 hours)
+pub const LATE_READING_SECS: u64 = 3 * 3600;
+
+/// Scored readings needed before the score departs from 100%
+pub const MIN_SCORED_READINGS: u32 = 24;
+
+/// Submission statistics of one oracle provider account
+#[derive(
+    Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default,
+)]
+pub struct ProviderSubmissionStats {
+    /// Hourly readings submitted (first reading per market and hour)
+    pub submissions: u32,
+    /// Submissions that arrived later than `LATE_READING_SECS`
+    pub late_submissions: u32,
+    /// Readings compared with a final consensus median
+    pub scored_readings: u32,
+    /// Scored readings further than the agreement tolerance from the median
+    pub outlier_readings: u32,
+    /// Sum of distances from the consensus median (tenths of mm)
+    pub total_deviation_mm: u64,
+    /// Unix timestamp of the latest submission
+    pub last_submission_at: u64,
+}
+
+impl ProviderSubmissionStats {
+    /// Count `new_readings` submitted at `now`, `late` of which were late
+    pub fn record_submissions(&mut self, new_readings: u32, late: u32, now: u64) {
+        self.submissions = self.submissions.saturating_add(new_readings);
+        self.late_submissions = self.late_submissions.saturating_add(late);
+        self.last_submission_at = now;
+    }
+
+    /// Compare a reading with the final consensus median
+    pub fn record_deviation(
+        &mut self,
+        reading_mm: Millimeters,
+        median_mm: Millimeters,
+        tolerance_mm: Millimeters,
+    ) {
+        let deviation = reading_mm.abs_diff(median_mm);
+        self.scored_readings = self.scored_readings.saturating_add(1);
+        self.total_deviation_mm = self.total_deviation_mm.saturating_add(deviation as u64);
+        if deviation > tolerance_mm {
+            self.outlier_readings = self.outlier_readings.saturating_add(1);
+        }
+    }
+
+    /// Mean distance from the consensus median (tenths of mm)
+    pub fn mean_deviation_mm(&self) -> Millimeters {
+        if self.scored_readings == 0 {
+            return 0;
+        }
+        let mean = self.total_deviation_mm / self.scored_readings as u64;
+        mean.min(Millimeters::MAX as u64) as Millimeters
+    }
+
+    /// Data quality score given the agreement tolerance
+    pub fn score(&self, tolerance_mm: Millimeters) -> Permill {
+        if self.scored_readings < MIN_SCORED_READINGS {
+            return Permill::one();
+        }
+
+        let one = 1_000_000u64;
+        let outlier_rate = ratio_ppm(self.outlier_readings as u64, self.scored_readings as u64);
+        let deviation_ratio = if tolerance_mm == 0 {
+            if self.total_deviation_mm > 0 { one } else { 0 }
+        } else {
+            ratio_ppm(self.mean_deviation_mm() as u64, tolerance_mm as u64).min(one)
+        };
+        let late_rate = ratio_ppm(self.late_submissions as u64, self.submissions as u64);
+
+        let penalty = outlier_rate + deviation_ratio / 4 + late_rate / 4;
+        Permill::from_parts(one.saturating_sub(penalty) as u32)
+    }
+}
+
+/// Whether a first reading for `hour_index` submitted at `now` is late
+pub fn is_late(hour_index: u64, now: u64) -> bool {
+    let hour_end = hour_index.saturating_add(1).saturating_mul(3600);
+    now > hour_end.saturating_add(LATE_READING_SECS)
+}
+
+/// `part / whole` in parts per million, capped at one
+fn ratio_ppm(part: u64, whole: u64) -> u64 {
+    if whole == 0 {
+        return 0;
+    }
+    (part.min(whole) as u128 * 1_000_000 / whole as u128) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scored(readings: u32, outliers: u32, mean_deviation: u64) -> ProviderSubmissionStats {
+        ProviderSubmissionStats {
+            submissions: readings,
+            scored_readings: readings,
+            outlier_readings: outliers,
+            total_deviation_mm: mean_deviation * readings as u64,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_is_late() {
+        // Hour 100 ends at 101 * 3600
+        assert!(!is_late(100, 101 * 3600));
+        assert!(!is_late(100, 101 * 3600 + LATE_READING_SECS));
+        assert!(is_late(100, 101 * 3600 + LATE_READING_SECS + 1));
+    }
+
+    #[test]
+    fn test_record_deviation() {
+        let mut stats = ProviderSubmissionStats::default();
+        stats.record_deviation(50, 52, 20);
+        stats.record_deviation(100, 52, 20);
+        assert_eq!(stats.scored_readings, 2);
+        assert_eq!(stats.outlier_readings, 1);
+        assert_eq!(stats.total_deviation_mm, 50);
+        assert_eq!(stats.mean_deviation_mm(), 25);
+    }
+
+    #[test]
+    fn test_score() {
+        // Too few scored readings to judge
+        assert_eq!(scored(MIN_SCORED_READINGS - 1, 10, 100).score(20), Permill::one());
+
+        // Exact readings, always on time
+        assert_eq!(scored(100, 0, 0).score(20), Permill::one());
+
+        // 10% outliers and a mean deviation of half the tolerance
+        assert_eq!(scored(100, 10, 10).score(20), Permill::from_parts(775_000));
+
+        // Late submissions cost a quarter of the late rate
+        let mut stats = scored(100, 0, 0);
+        stats.late_submissions = 40;
+        assert_eq!(stats.score(20), Permill::from_percent(90));
+
+        // Every reading an outlier far from the median
+        assert_eq!(scored(100, 100, 500).score(20), Permill::zero());
+
+        // Zero tolerance only forgives exact readings
+        assert_eq!(scored(100, 0, 1).score(0), Permill::from_percent(75));
+    }
+}
@@ -0,0 +1,128 @@
+//! # Settlement Records
+//!
+//! Why a policy settled and the data it settled on. The oracle builds a
+//! `SettlementRecord` for every settlement it drives and hands it to
+//! `PolicySettlement`; the policy pallet stores it per policy and emits it with
+//! `PolicySettledV2`, so indexers can follow a policy to its outcome without
+//! replaying oracle state.
+//!
+//! `evidence_hash` is the `merkle` observation root of the hourly buckets the
+//! outcome was computed from (the trigger log's root for threshold triggers), or
+//! the SHA256 of the off-chain evidence for V2 reports. Settlements made without
+//! oracle data carry a zero hash and no providers.
+
+use alloc::vec::Vec;
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use frame_support::{traits::ConstU32, BoundedVec};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+use crate::{Millimeters, WeatherProvider, MAX_WEATHER_PROVIDERS};
+
+/// Why a policy settled
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum SettlementReason {
+    /// The rolling sum reached the policy's trigger threshold during coverage
+    ThresholdTriggered,
+    /// Coverage ended and the outcome was taken from oracle data for the window
+    CoverageExpired,
+    /// Governance settled the policy, with or without waiting for oracle data
+    GovernanceForced,
+    /// The V2 off-chain oracle's final report
+    OracleReport,
+    /// A caller supplied the outcome after coverage ended (`settle_policy`)
+    Manual,
+}
+
+/// Reason for a policy's settlement and the data behind it
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct SettlementRecord {
+    pub reason: SettlementReason,
+    /// Observed metric the outcome and any tiered payout were based on, in tenths of mm
+    pub observed_mm: Option<Millimeters>,
+    /// Weather providers whose readings fed the observations
+    pub providers: BoundedVec<WeatherProvider, ConstU32<MAX_WEATHER_PROVIDERS>>,
+    /// Commitment to the observations (see module docs)
+    pub evidence_hash: [u8; 32],
+}
+
+impl SettlementRecord {
+    /// Record for a settlement made without oracle observations
+    pub fn without_data(reason: SettlementReason, observed_mm: Option<Millimeters>) -> Self {
+        Self {
+            reason,
+            observed_mm,
+            providers: BoundedVec::new(),
+            evidence_hash: [0u8; 32],
+        }
+    }
+}
+
+/// Distinct providers in `readings`, in order of first appearance
+pub fn provider_set(
+    readings: impl IntoIterator<Item = WeatherProvider>,
+) -> BoundedVec<WeatherProvider, ConstU32<MAX_WEATHER_PROVIDERS>> {
+    let mut providers: Vec<WeatherProvider> = Vec::new();
+    for provider in readings {
+        if !providers.contains(&provider) {
+            providers.push(provider);
+        }
+    }
+    // At most one entry per provider variant, so this never truncates
+    BoundedVec::truncate_from(providers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_provider_set_dedups_in_order() {
+        let providers = provider_set([
+            WeatherProvider::OpenMeteo,
+            WeatherProvider::AccuWeather,
+            WeatherProvider::OpenMeteo,
+            WeatherProvider::Noaa,
+            WeatherProvider::AccuWeather,
+        ]);
+        assert_eq!(
+            providers.into_inner(),
+            alloc::vec![
+                WeatherProvider::OpenMeteo,
+                WeatherProvider::AccuWeather,
+                WeatherProvider::Noaa,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_provider_set_empty() {
+        assert!(provider_set([]).is_empty());
+        assert!(
+            SettlementRecord::without_data(SettlementReason::Manual, None)
+                .providers
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,55 @@
+//! # Snowfall Evaluation
+//!
+//! Pure helpers for `EventType::Snowfall24h` policies. Hourly snowfall depth from
+//! AccuWeather is stored per market in `SnowBuckets`; a policy triggers when the
+//! snowfall over any 24 consecutive hours of its coverage window reaches the strike.
+//! Hours without a reading count as no snowfall.
+
+use crate::Millimeters;
+
+/// Length of the snowfall accumulation window in hours
+pub const SNOWFALL_WINDOW_HOURS: u64 = 24;
+
+/// Highest snowfall over any `window_hours` consecutive hours, for readings given
+/// as (hour_index, tenths of mm) in ascending hour order. `None` without readings.
+pub fn max_rolling_sum(readings: &[(u64, Millimeters)], window_hours: u64) -> Option<Millimeters> {
+    let window_hours = window_hours.max(1);
+    let mut max_sum: Option<Millimeters> = None;
+    let mut sum: Millimeters = 0;
+    let mut oldest = 0;
+
+    for (hour, mm) in readings {
+        sum = sum.saturating_add(*mm);
+        // Drop readings that fell out of the window ending at `hour`
+        while readings[oldest].0 + window_hours <= *hour {
+            sum = sum.saturating_sub(readings[oldest].1);
+            oldest += 1;
+        }
+        max_sum = max_sum.max(Some(sum));
+    }
+
+    max_sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_max_rolling_sum() {
+        assert_eq!(max_rolling_sum(&[], SNOWFALL_WINDOW_HOURS), None);
+        assert_eq!(max_rolling_sum(&[(100, 0)], SNOWFALL_WINDOW_HOURS), Some(0));
+
+        // 10cm at hour 100 and 15cm at hour 123 fall in one 24h window
+        let readings = [(100, 1_000), (110, 0), (123, 1_500), (124, 200)];
+        assert_eq!(max_rolling_sum(&readings, SNOWFALL_WINDOW_HOURS), Some(2_500));
+
+        // Hour 124 is 24 hours after hour 100, so they never count together
+        let readings = [(100, 1_000), (124, 1_500)];
+        assert_eq!(max_rolling_sum(&readings, SNOWFALL_WINDOW_HOURS), Some(1_500));
+
+        // A 1-hour window is the largest single reading
+        let readings = [(1, 300), (2, 700), (3, 400)];
+        assert_eq!(max_rolling_sum(&readings, 1), Some(700));
+    }
+}
@@ -0,0 +1,94 @@
+//! # Threshold Trigger Index
+//!
+//! `ThresholdTriggerLogs` is keyed by a global trigger ID. `TriggersByMarket`
+//! lists each market's trigger IDs in ascending order so a market's triggers can
+//! be read without scanning every log.
+//!
+//! - The index keeps the most recent `MAX_TRIGGERS_PER_MARKET` IDs; older ones
+//!   drop out of the index but their logs stay until retention prunes them.
+//! - Retention pruning removes an ID from the index together with its log.
+//! - `PrmxTriggerLogApi` pages through a market's triggers newest first: a page
+//!   returns up to `limit` logs with IDs below `cursor` (all when `None`), and the
+//!   ID to pass as the next cursor if older ones remain.
+
+use alloc::vec::Vec;
+
+/// Most recent trigger IDs kept per market in `TriggersByMarket`
+pub const MAX_TRIGGERS_PER_MARKET: u32 = 512;
+
+/// Largest page `PrmxTriggerLogApi` returns, whatever `limit` asks for
+pub const MAX_TRIGGER_LOG_PAGE: u32 = 100;
+
+/// Append `trigger_id` to an ascending index of at most `max` IDs, dropping the
+/// oldest ones to make room
+pub fn push_bounded(ids: &mut Vec<u64>, trigger_id: u64, max: usize) {
+    if max == 0 {
+        return;
+    }
+    if ids.len() >= max {
+        ids.drain(..=ids.len() - max);
+    }
+    ids.push(trigger_id);
+}
+
+/// Page of an ascending index, newest first: up to `limit` IDs below `cursor` and
+/// the cursor of the next page (the last ID returned) if older IDs remain.
+/// `limit` is clamped to `1..=MAX_TRIGGER_LOG_PAGE`.
+pub fn page(ids: &[u64], cursor: Option<u64>, limit: u32) -> (Vec<u64>, Option<u64>) {
+    let limit = limit.clamp(1, MAX_TRIGGER_LOG_PAGE) as usize;
+    let end = match cursor {
+        Some(cursor) => ids.partition_point(|id| *id < cursor),
+        None => ids.len(),
+    };
+    let start = end.saturating_sub(limit);
+    let page: Vec<u64> = ids[start..end].iter().rev().copied().collect();
+    let next = if start > 0 { page.last().copied() } else { None };
+    (page, next)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_push_bounded_drops_oldest() {
+        let mut ids = vec![1, 2, 3];
+        push_bounded(&mut ids, 4, 4);
+        assert_eq!(ids, vec![1, 2, 3, 4]);
+        push_bounded(&mut ids, 7, 4);
+        assert_eq!(ids, vec![2, 3, 4, 7]);
+
+        // A bound lowered below the current length trims down to it
+        push_bounded(&mut ids, 8, 2);
+        assert_eq!(ids, vec![7, 8]);
+    }
+
+    #[test]
+    fn test_page_walks_newest_first() {
+        let ids: Vec<u64> = vec![2, 5, 9, 14, 20];
+
+        let (first, next) = page(&ids, None, 2);
+        assert_eq!(first, vec![20, 14]);
+        assert_eq!(next, Some(14));
+
+        let (second, next) = page(&ids, next, 2);
+        assert_eq!(second, vec![9, 5]);
+        assert_eq!(next, Some(5));
+
+        let (last, next) = page(&ids, next, 2);
+        assert_eq!(last, vec![2]);
+        assert_eq!(next, None);
+
+        // A cursor between IDs, e.g. one pruned since the previous page
+        assert_eq!(page(&ids, Some(10), 10), (vec![9, 5, 2], None));
+        assert_eq!(page(&ids, Some(2), 10), (vec![], None));
+
+        // Limits are clamped to 1..=MAX_TRIGGER_LOG_PAGE
+        assert_eq!(page(&ids, None, 0), (vec![20], Some(20)));
+        let many: Vec<u64> = (0..150).collect();
+        let (capped, next) = page(&many, None, 500);
+        assert_eq!(capped.len(), MAX_TRIGGER_LOG_PAGE as usize);
+        assert_eq!((capped[0], next), (149, Some(50)));
+    }
+}
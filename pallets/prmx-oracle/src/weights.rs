@@ -0,0 +1,744 @@
+//! Placeholder weights for `pallet_prmx_oracle`, written by hand: the benchmarks
+//! have not been run, so none of these values is measured.
+//!
+//! Storage reads/writes per extrinsic are counted from the dispatch paths in
+//! `lib.rs`; ref-time bases and per-item slopes are unmeasured guesses.
+//! Hourly submissions are counted for the default 24h rolling window, and calls
+//! that settle a policy include the policy pallet's settlement reads/writes.
+//! They must be replaced with weights generated by the benchmarks in
+//! `benchmarking.rs` (see `docs/benchmarking.md`) before production use.
+//!
+//! Components:
+//! - `n`: hourly readings in a submission (at most 24)
+//! - `m`: markets queued by `request_rainfall_fetch_all` (`NextMarketId`)
+//! - `v`: votes already cast on a V2 dispute (`MAX_V2_DISPUTE_VOTES`)
+//! - `b`: legacy rain buckets cleared by `set_market_window`
+//! - `p`: providers assigned to a market (`MAX_PROVIDERS_PER_MARKET`)
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use core::marker::PhantomData;
+use frame_support::{
+    traits::Get,
+    weights::{constants::RocksDbWeight, Weight},
+};
+
+/// Weight functions needed for `pallet_prmx_oracle`.
+pub trait WeightInfo {
+    fn set_market_location_key() -> Weight;
+    fn submit_rainfall() -> Weight;
+    fn add_oracle_provider() -> Weight;
+    fn remove_oracle_provider() -> Weight;
+    fn set_test_rainfall() -> Weight;
+    fn set_accuweather_api_key() -> Weight;
+    fn request_rainfall_fetch() -> Weight;
+    fn complete_rainfall_fetch() -> Weight;
+    fn submit_rainfall_from_ocw() -> Weight;
+    fn request_rainfall_fetch_all(m: u32) -> Weight;
+    fn submit_v2_report() -> Weight;
+    fn add_v2_reporter() -> Weight;
+    fn remove_v2_reporter() -> Weight;
+    fn submit_hourly_rainfall_from_ocw(n: u32) -> Weight;
+    fn submit_provider_hourly_rainfall_from_ocw(n: u32) -> Weight;
+    fn set_provider_agreement() -> Weight;
+    fn dispute_v2_report() -> Weight;
+    fn resolve_v2_dispute() -> Weight;
+    fn vote_v2_dispute(v: u32) -> Weight;
+    fn finalize_v2_report() -> Weight;
+    fn submit_hourly_temperature_from_ocw(n: u32) -> Weight;
+    fn submit_hourly_wind_gust_from_ocw(n: u32) -> Weight;
+    fn bond_as_oracle_provider() -> Weight;
+    fn unbond_oracle_provider() -> Weight;
+    fn withdraw_unbonded_provider_bond() -> Weight;
+    fn set_market_window(b: u32) -> Weight;
+    fn request_backfill() -> Weight;
+    fn submit_backfilled_rainfall_from_ocw(n: u32) -> Weight;
+    fn set_settlement_grace_period() -> Weight;
+    fn force_settle_expired_policy() -> Weight;
+    fn report_heartbeat() -> Weight;
+    fn set_oracle_stale_after_hours() -> Weight;
+    fn poke_settlement() -> Weight;
+    fn submit_hourly_snowfall_from_ocw(n: u32) -> Weight;
+    fn submit_gauge_level_from_ocw() -> Weight;
+    fn set_gauge_source() -> Weight;
+    fn set_provider_assignments(p: u32) -> Weight;
+    fn rotate_provider() -> Weight;
+    fn correct_hourly_rainfall_from_ocw(n: u32) -> Weight;
+    fn report_ocw_health() -> Weight;
+    fn set_data_retention() -> Weight;
+    fn mirror_observations(n: u32) -> Weight;
+    fn set_mirror_quorum() -> Weight;
+    fn submit_listing_feasibility() -> Weight;
+    fn set_market_data_source() -> Weight;
+    fn register_oracle_keys() -> Weight;
+    fn purge_oracle_keys() -> Weight;
+}
+
+/// Weights for `pallet_prmx_oracle` using the runtime's database weights.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    /// Reads: Markets, MarketLocationConfig
+    /// Writes: MarketLocationConfig
+    fn set_market_location_key() -> Weight {
+        Weight::from_parts(30_000_000, 4_000)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Reads: KeyOwners, OracleProviders, ProviderBonds, ProviderAssignments, PausedOperations,
+    /// MarketLocationConfig (2), RainBuckets, RollingState, Timestamp::Now, StaleMarkets,
+    /// RainBucketPruneCursor
+    /// Writes: RainBuckets, RollingState, MarketLastDataAt, StaleMarkets, RainBucketPruneCursor
+    fn submit_rainfall() -> Weight {
+        Weight::from_parts(45_000_000, 4_500)
+            .saturating_add(T::DbWeight::get().reads(12_u64))
+            .saturating_add(T::DbWeight::get().writes(5_u64))
+    }
+    /// Writes: OracleProviders
+    fn add_oracle_provider() -> Weight {
+        Weight::from_parts(12_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Reads: ProviderKeys
+    /// Writes: OracleProviders, ProviderKeys, KeyOwners
+    fn remove_oracle_provider() -> Weight {
+        Weight::from_parts(16_000_000, 2_500)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    /// Reads: Markets, MarketLocationConfig (2), StaleMarkets, RainBucketPruneCursor
+    /// Writes: MarketLocationConfig, RainBuckets, RollingState, MarketLastDataAt, StaleMarkets,
+    /// RainBucketPruneCursor
+    fn set_test_rainfall() -> Weight {
+        Weight::from_parts(40_000_000, 4_000)
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(6_u64))
+    }
+    /// Writes: PendingApiKey, ApiKeyConfiguredAt
+    fn set_accuweather_api_key() -> Weight {
+        Weight::from_parts(12_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Reads: Markets, MarketLocationConfig, PendingFetchRequests
+    /// Writes: PendingFetchRequests
+    fn request_rainfall_fetch() -> Weight {
+        Weight::from_parts(20_000_000, 4_000)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Reads: Timestamp::Now, MarketLocationConfig, RainBuckets, StaleMarkets,
+    /// RainBucketPruneCursor
+    /// Writes: PendingFetchRequests, RainBuckets, RollingState, MarketLastDataAt, StaleMarkets,
+    /// RainBucketPruneCursor
+    fn complete_rainfall_fetch() -> Weight {
+        Weight::from_parts(35_000_000, 4_000)
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(6_u64))
+    }
+    /// Reads: KeyOwners, OracleProviders, ProviderBonds, ProviderAssignments, PausedOperations,
+    /// Markets, Timestamp::Now, MarketLocationConfig, RainBuckets, StaleMarkets,
+    /// PendingFetchRequests, RainBucketPruneCursor
+    /// Writes: RainBuckets, RollingState, MarketLastDataAt, StaleMarkets, PendingFetchRequests,
+    /// RainBucketPruneCursor
+    fn submit_rainfall_from_ocw() -> Weight {
+        Weight::from_parts(45_000_000, 4_500)
+            .saturating_add(T::DbWeight::get().reads(12_u64))
+            .saturating_add(T::DbWeight::get().writes(6_u64))
+    }
+    /// Reads: NextMarketId, Timestamp::Now, plus Markets and PendingFetchRequests per market
+    /// Writes: PendingFetchRequests per market
+    fn request_rainfall_fetch_all(m: u32) -> Weight {
+        Weight::from_parts(15_000_000, 1_500)
+            .saturating_add(Weight::from_parts(6_000_000, 0).saturating_mul(m.into()))
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(m.into())))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(m.into())))
+            .saturating_add(Weight::from_parts(0, 2_500).saturating_mul(m.into()))
+    }
+    /// Reads: AuthorizedV2Reporters, PausedOperations, V2FinalReportByPolicy,
+    /// V2FinalReportHashes, PendingV2Reports, Timestamp::Now, NextV2FinalizationIndex
+    /// Writes: PendingV2Reports, V2FinalizationQueue, NextV2FinalizationIndex
+    fn submit_v2_report() -> Weight {
+        Weight::from_parts(35_000_000, 4_000)
+            .saturating_add(T::DbWeight::get().reads(7_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    /// Writes: AuthorizedV2Reporters
+    fn add_v2_reporter() -> Weight {
+        Weight::from_parts(12_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Writes: AuthorizedV2Reporters
+    fn remove_v2_reporter() -> Weight {
+        Weight::from_parts(12_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Reads: KeyOwners, OracleProviders, ProviderBonds, ProviderAssignments, PausedOperations,
+    /// Markets, Timestamp::Now, MarketLocationConfig, ProviderAgreement, StaleMarkets,
+    /// PendingFetchRequests, HourlyRing, the HourlyBuckets slot and ProviderHourlyReadings
+    /// of the evicted hour, the HourlyBuckets slot of the buffer hour, ProviderStats of the
+    /// submitter and of the evicted hour's providers (5), plus ProviderHourlyReadings,
+    /// HourlyBuckets, HourlyRing, ProviderBonds and ProviderStats (5) per reading
+    /// Writes: RollingState, MarketLastDataAt, StaleMarkets, PendingFetchRequests, HourlyRing,
+    /// the evicted hour's HourlyBuckets slot and ProviderHourlyReadings, ProviderStats of the
+    /// submitter and of the evicted hour's providers (5), plus ProviderHourlyReadings,
+    /// HourlyBuckets and HourlyRing per reading
+    fn submit_hourly_rainfall_from_ocw(n: u32) -> Weight {
+        Weight::from_parts(70_000_000, 6_000)
+            .saturating_add(Weight::from_parts(12_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(21_u64))
+            .saturating_add(T::DbWeight::get().reads((8_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(12_u64))
+            .saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 3_000).saturating_mul(n.into()))
+    }
+    /// As `submit_hourly_rainfall_from_ocw`
+    fn submit_provider_hourly_rainfall_from_ocw(n: u32) -> Weight {
+        Weight::from_parts(70_000_000, 6_000)
+            .saturating_add(Weight::from_parts(12_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(21_u64))
+            .saturating_add(T::DbWeight::get().reads((8_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(12_u64))
+            .saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 3_000).saturating_mul(n.into()))
+    }
+    /// Writes: ProviderAgreement
+    fn set_provider_agreement() -> Weight {
+        Weight::from_parts(12_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Reads: PendingV2Reports, V2Disputes, System::Account
+    /// Writes: V2Disputes, System::Account
+    fn dispute_v2_report() -> Weight {
+        Weight::from_parts(40_000_000, 4_200)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Reads: V2Disputes, PendingV2Reports, System::Account, ProviderBonds,
+    /// ProviderUnbondings, V2FinalReportByPolicy, NextV2FinalReportPruneIndex,
+    /// plus policy settlement (20)
+    /// Writes: V2Disputes, V2DisputeVotes, PendingV2Reports, System::Account (2),
+    /// V2FinalReportByPolicy, V2FinalReportPruneQueue, NextV2FinalReportPruneIndex,
+    /// plus policy settlement (18)
+    ///
+    /// Worst case of both branches: an upheld dispute slashes the reporter, a rejected
+    /// one settles the policy.
+    fn resolve_v2_dispute() -> Weight {
+        Weight::from_parts(190_000_000, 9_000)
+            .saturating_add(T::DbWeight::get().reads(27_u64))
+            .saturating_add(T::DbWeight::get().writes(25_u64))
+    }
+    /// Reads: AuthorizedV2Reporters, V2Disputes, PendingV2Reports, V2DisputeVotes,
+    /// plus `resolve_v2_dispute` when the vote reaches quorum
+    /// Writes: V2DisputeVotes, plus `resolve_v2_dispute` when the vote reaches quorum
+    fn vote_v2_dispute(v: u32) -> Weight {
+        Weight::from_parts(200_000_000, 10_000)
+            .saturating_add(Weight::from_parts(300_000, 0).saturating_mul(v.into()))
+            .saturating_add(T::DbWeight::get().reads(29_u64))
+            .saturating_add(T::DbWeight::get().writes(26_u64))
+            .saturating_add(Weight::from_parts(0, 48).saturating_mul(v.into()))
+    }
+    /// Reads: PendingV2Reports, V2Disputes, NextV2FinalReportPruneIndex,
+    /// plus policy settlement (20)
+    /// Writes: PendingV2Reports, V2FinalReportByPolicy, V2FinalReportPruneQueue,
+    /// NextV2FinalReportPruneIndex, plus policy settlement (18)
+    fn finalize_v2_report() -> Weight {
+        Weight::from_parts(160_000_000, 8_500)
+            .saturating_add(T::DbWeight::get().reads(23_u64))
+            .saturating_add(T::DbWeight::get().writes(22_u64))
+    }
+    /// Reads: KeyOwners, OracleProviders, ProviderBonds, ProviderAssignments, PausedOperations,
+    /// Markets, Timestamp::Now, plus the TempBuckets of the retention window (25)
+    /// Writes: TempBuckets per reading
+    fn submit_hourly_temperature_from_ocw(n: u32) -> Weight {
+        Weight::from_parts(40_000_000, 4_000)
+            .saturating_add(Weight::from_parts(4_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(32_u64))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 2_500).saturating_mul(n.into()))
+    }
+    /// Reads: KeyOwners, OracleProviders, ProviderBonds, ProviderAssignments, PausedOperations,
+    /// Markets, Timestamp::Now, plus the WindBuckets of the retention window (25)
+    /// Writes: WindBuckets per reading
+    fn submit_hourly_wind_gust_from_ocw(n: u32) -> Weight {
+        Weight::from_parts(40_000_000, 4_000)
+            .saturating_add(Weight::from_parts(4_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(32_u64))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 2_500).saturating_mul(n.into()))
+    }
+    /// Reads: OracleProviders, ProviderBonds, System::Account
+    /// Writes: ProviderBonds, System::Account
+    fn bond_as_oracle_provider() -> Weight {
+        Weight::from_parts(38_000_000, 3_600)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Reads: ProviderBonds, ProviderUnbondings
+    /// Writes: ProviderBonds, ProviderUnbondings
+    fn unbond_oracle_provider() -> Weight {
+        Weight::from_parts(28_000_000, 3_600)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Reads: ProviderUnbondings, System::Account
+    /// Writes: ProviderUnbondings, System::Account
+    fn withdraw_unbonded_provider_bond() -> Weight {
+        Weight::from_parts(36_000_000, 3_600)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Reads: MarketLocationConfig, Timestamp::Now, HourlyRing, ProviderAgreement, plus the
+    /// HourlyBuckets slot and ProviderHourlyReadings of each re-slotted hour (at most b)
+    /// Writes: MarketLocationConfig, RollingState, HourlyRing, RainBucketPruneCursor, plus each
+    /// cleared RainBuckets entry and the evicted, drained and reinserted HourlyBuckets of each hour
+    fn set_market_window(b: u32) -> Weight {
+        Weight::from_parts(35_000_000, 4_000)
+            .saturating_add(Weight::from_parts(2_000_000, 0).saturating_mul(b.into()))
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(b.into())))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+            .saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(b.into())))
+    }
+    /// Reads: MarketLocationConfig, Timestamp::Now
+    /// Writes: BackfillRequests
+    fn request_backfill() -> Weight {
+        Weight::from_parts(22_000_000, 4_000)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// As `submit_hourly_rainfall_from_ocw`, plus BackfillRequests (read and write),
+    /// without Markets and PendingFetchRequests
+    fn submit_backfilled_rainfall_from_ocw(n: u32) -> Weight {
+        Weight::from_parts(75_000_000, 6_000)
+            .saturating_add(Weight::from_parts(12_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(20_u64))
+            .saturating_add(T::DbWeight::get().reads((8_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(12_u64))
+            .saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 3_000).saturating_mul(n.into()))
+    }
+    /// Reads: MarketLocationConfig
+    /// Writes: SettlementGracePeriods
+    fn set_settlement_grace_period() -> Weight {
+        Weight::from_parts(18_000_000, 4_000)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Reads: Policies, Timestamp::Now, MarketLocationConfig, the RainBuckets of a
+    /// 7-day coverage window plus its rolling window (192), the HourlyBuckets and
+    /// ProviderHourlyReadings of the coverage window (338), plus policy settlement (20)
+    /// Writes: AwaitingSettlementData, plus policy settlement (17)
+    fn force_settle_expired_policy() -> Weight {
+        Weight::from_parts(520_000_000, 110_000)
+            .saturating_add(T::DbWeight::get().reads(553_u64))
+            .saturating_add(T::DbWeight::get().writes(18_u64))
+    }
+    /// Reads: KeyOwners, OracleProviders
+    /// Writes: ProviderLastHeartbeat
+    fn report_heartbeat() -> Weight {
+        Weight::from_parts(15_000_000, 2_500)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Writes: OracleStaleAfterHours
+    fn set_oracle_stale_after_hours() -> Weight {
+        Weight::from_parts(12_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Reads: PausedOperations, Policies, Timestamp::Now, SettlementGracePeriods,
+    /// MarketLocationConfig, RollingState, Markets, the RainBuckets of a 7-day coverage
+    /// window plus its rolling window (192), the HourlyBuckets and ProviderHourlyReadings
+    /// of the coverage window (338), plus policy settlement (20), System::Account (2)
+    /// Writes: AwaitingSettlementData, plus policy settlement (17), System::Account (2)
+    fn poke_settlement() -> Weight {
+        Weight::from_parts(570_000_000, 112_000)
+            .saturating_add(T::DbWeight::get().reads(559_u64))
+            .saturating_add(T::DbWeight::get().writes(20_u64))
+    }
+    /// Reads: KeyOwners, OracleProviders, ProviderBonds, ProviderAssignments, PausedOperations,
+    /// Markets, Timestamp::Now, plus the SnowBuckets of the retention window (25)
+    /// Writes: SnowBuckets per reading
+    fn submit_hourly_snowfall_from_ocw(n: u32) -> Weight {
+        Weight::from_parts(40_000_000, 4_000)
+            .saturating_add(Weight::from_parts(4_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(32_u64))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 2_500).saturating_mul(n.into()))
+    }
+    /// Reads: KeyOwners, OracleProviders, ProviderBonds, ProviderAssignments, PausedOperations,
+    /// GaugeSources, Timestamp::Now, Markets, MarketLocationConfig, the HourlyBuckets of
+    /// the rolling window (26), plus the GaugeBuckets of the retention window (193)
+    /// Writes: GaugeBuckets, plus the bucket aged out of retention
+    fn submit_gauge_level_from_ocw() -> Weight {
+        Weight::from_parts(95_000_000, 24_000)
+            .saturating_add(T::DbWeight::get().reads(228_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Reads: Markets
+    /// Writes: GaugeSources
+    fn set_gauge_source() -> Weight {
+        Weight::from_parts(16_000_000, 3_500)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Reads: Markets, plus OracleProviders per provider
+    /// Writes: ProviderAssignments
+    fn set_provider_assignments(p: u32) -> Weight {
+        Weight::from_parts(16_000_000, 3_500)
+            .saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(p.into()))
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p.into())))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+            .saturating_add(Weight::from_parts(0, 2_500).saturating_mul(p.into()))
+    }
+    /// Reads: OracleProviders, ProviderAssignments
+    /// Writes: ProviderAssignments
+    fn rotate_provider() -> Weight {
+        Weight::from_parts(20_000_000, 4_100)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// As `submit_hourly_rainfall_from_ocw`, without PendingFetchRequests and the
+    /// submitter's ProviderStats
+    fn correct_hourly_rainfall_from_ocw(n: u32) -> Weight {
+        Weight::from_parts(65_000_000, 6_000)
+            .saturating_add(Weight::from_parts(12_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(19_u64))
+            .saturating_add(T::DbWeight::get().reads((8_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(10_u64))
+            .saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 3_000).saturating_mul(n.into()))
+    }
+    /// Reads: KeyOwners, OracleProviders
+    /// Writes: OcwHealth
+    fn report_ocw_health() -> Weight {
+        Weight::from_parts(18_000_000, 2_600)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Reads: Markets
+    /// Writes: DataRetention
+    fn set_data_retention() -> Weight {
+        Weight::from_parts(16_000_000, 3_600)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Reads: KeyOwners, OracleProviders, ProviderBonds, ProviderAssignments, PausedOperations,
+    /// MirroredDigests, MirrorVotes, MirrorQuorum, plus a full
+    /// `submit_provider_hourly_rainfall_from_ocw` once quorum is reached
+    /// Writes: MirrorVotes, MirroredDigests, plus the submission's writes
+    fn mirror_observations(n: u32) -> Weight {
+        Weight::from_parts(95_000_000, 12_000)
+            .saturating_add(Weight::from_parts(12_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(28_u64))
+            .saturating_add(T::DbWeight::get().reads((8_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(14_u64))
+            .saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 3_000).saturating_mul(n.into()))
+    }
+    /// Writes: MirrorQuorum
+    fn set_mirror_quorum() -> Weight {
+        Weight::from_parts(12_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Reads: KeyOwners, OracleProviders, ProviderBonds, PausedOperations, PendingListingChecks,
+    /// ListingProposals
+    /// Writes: ListingProposals, PendingListingChecks
+    fn submit_listing_feasibility() -> Weight {
+        Weight::from_parts(28_000_000, 4_500)
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Reads: Markets
+    /// Writes: MarketDataSources
+    fn set_market_data_source() -> Weight {
+        Weight::from_parts(17_000_000, 3_500)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Verifies the sr25519 ownership proof
+    /// Reads: OracleProviders (2), KeyOwners, ProviderKeys
+    /// Writes: ProviderKeys, KeyOwners (2)
+    fn register_oracle_keys() -> Weight {
+        Weight::from_parts(62_000_000, 5_000)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    /// Reads: ProviderKeys
+    /// Writes: ProviderKeys, KeyOwners
+    fn purge_oracle_keys() -> Weight {
+        Weight::from_parts(16_000_000, 2_500)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+    fn set_market_location_key() -> Weight {
+        Weight::from_parts(30_000_000, 4_000)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn submit_rainfall() -> Weight {
+        Weight::from_parts(45_000_000, 4_500)
+            .saturating_add(RocksDbWeight::get().reads(12_u64))
+            .saturating_add(RocksDbWeight::get().writes(5_u64))
+    }
+    fn add_oracle_provider() -> Weight {
+        Weight::from_parts(12_000_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn remove_oracle_provider() -> Weight {
+        Weight::from_parts(16_000_000, 2_500)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    fn set_test_rainfall() -> Weight {
+        Weight::from_parts(40_000_000, 4_000)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(6_u64))
+    }
+    fn set_accuweather_api_key() -> Weight {
+        Weight::from_parts(12_000_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    fn request_rainfall_fetch() -> Weight {
+        Weight::from_parts(20_000_000, 4_000)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn complete_rainfall_fetch() -> Weight {
+        Weight::from_parts(35_000_000, 4_000)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(6_u64))
+    }
+    fn submit_rainfall_from_ocw() -> Weight {
+        Weight::from_parts(45_000_000, 4_500)
+            .saturating_add(RocksDbWeight::get().reads(12_u64))
+            .saturating_add(RocksDbWeight::get().writes(6_u64))
+    }
+    fn request_rainfall_fetch_all(m: u32) -> Weight {
+        Weight::from_parts(15_000_000, 1_500)
+            .saturating_add(Weight::from_parts(6_000_000, 0).saturating_mul(m.into()))
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(m.into())))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(m.into())))
+            .saturating_add(Weight::from_parts(0, 2_500).saturating_mul(m.into()))
+    }
+    fn submit_v2_report() -> Weight {
+        Weight::from_parts(35_000_000, 4_000)
+            .saturating_add(RocksDbWeight::get().reads(7_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    fn add_v2_reporter() -> Weight {
+        Weight::from_parts(12_000_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn remove_v2_reporter() -> Weight {
+        Weight::from_parts(12_000_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn submit_hourly_rainfall_from_ocw(n: u32) -> Weight {
+        Weight::from_parts(70_000_000, 6_000)
+            .saturating_add(Weight::from_parts(12_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(21_u64))
+            .saturating_add(RocksDbWeight::get().reads((8_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes(12_u64))
+            .saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 3_000).saturating_mul(n.into()))
+    }
+    fn submit_provider_hourly_rainfall_from_ocw(n: u32) -> Weight {
+        Weight::from_parts(70_000_000, 6_000)
+            .saturating_add(Weight::from_parts(12_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(21_u64))
+            .saturating_add(RocksDbWeight::get().reads((8_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes(12_u64))
+            .saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 3_000).saturating_mul(n.into()))
+    }
+    fn set_provider_agreement() -> Weight {
+        Weight::from_parts(12_000_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn dispute_v2_report() -> Weight {
+        Weight::from_parts(40_000_000, 4_200)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    fn resolve_v2_dispute() -> Weight {
+        Weight::from_parts(190_000_000, 9_000)
+            .saturating_add(RocksDbWeight::get().reads(27_u64))
+            .saturating_add(RocksDbWeight::get().writes(25_u64))
+    }
+    fn vote_v2_dispute(v: u32) -> Weight {
+        Weight::from_parts(200_000_000, 10_000)
+            .saturating_add(Weight::from_parts(300_000, 0).saturating_mul(v.into()))
+            .saturating_add(RocksDbWeight::get().reads(29_u64))
+            .saturating_add(RocksDbWeight::get().writes(26_u64))
+            .saturating_add(Weight::from_parts(0, 48).saturating_mul(v.into()))
+    }
+    fn finalize_v2_report() -> Weight {
+        Weight::from_parts(160_000_000, 8_500)
+            .saturating_add(RocksDbWeight::get().reads(23_u64))
+            .saturating_add(RocksDbWeight::get().writes(22_u64))
+    }
+    fn submit_hourly_temperature_from_ocw(n: u32) -> Weight {
+        Weight::from_parts(40_000_000, 4_000)
+            .saturating_add(Weight::from_parts(4_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(32_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 2_500).saturating_mul(n.into()))
+    }
+    fn submit_hourly_wind_gust_from_ocw(n: u32) -> Weight {
+        Weight::from_parts(40_000_000, 4_000)
+            .saturating_add(Weight::from_parts(4_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(32_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 2_500).saturating_mul(n.into()))
+    }
+    fn bond_as_oracle_provider() -> Weight {
+        Weight::from_parts(38_000_000, 3_600)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    fn unbond_oracle_provider() -> Weight {
+        Weight::from_parts(28_000_000, 3_600)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    fn withdraw_unbonded_provider_bond() -> Weight {
+        Weight::from_parts(36_000_000, 3_600)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    fn set_market_window(b: u32) -> Weight {
+        Weight::from_parts(35_000_000, 4_000)
+            .saturating_add(Weight::from_parts(2_000_000, 0).saturating_mul(b.into()))
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(b.into())))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+            .saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(b.into())))
+    }
+    fn request_backfill() -> Weight {
+        Weight::from_parts(22_000_000, 4_000)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn submit_backfilled_rainfall_from_ocw(n: u32) -> Weight {
+        Weight::from_parts(75_000_000, 6_000)
+            .saturating_add(Weight::from_parts(12_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(20_u64))
+            .saturating_add(RocksDbWeight::get().reads((8_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes(12_u64))
+            .saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 3_000).saturating_mul(n.into()))
+    }
+    fn set_settlement_grace_period() -> Weight {
+        Weight::from_parts(18_000_000, 4_000)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn force_settle_expired_policy() -> Weight {
+        Weight::from_parts(520_000_000, 110_000)
+            .saturating_add(RocksDbWeight::get().reads(553_u64))
+            .saturating_add(RocksDbWeight::get().writes(18_u64))
+    }
+    fn report_heartbeat() -> Weight {
+        Weight::from_parts(15_000_000, 2_500)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn set_oracle_stale_after_hours() -> Weight {
+        Weight::from_parts(12_000_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn poke_settlement() -> Weight {
+        Weight::from_parts(570_000_000, 112_000)
+            .saturating_add(RocksDbWeight::get().reads(559_u64))
+            .saturating_add(RocksDbWeight::get().writes(20_u64))
+    }
+    fn submit_hourly_snowfall_from_ocw(n: u32) -> Weight {
+        Weight::from_parts(40_000_000, 4_000)
+            .saturating_add(Weight::from_parts(4_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(32_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 2_500).saturating_mul(n.into()))
+    }
+    fn submit_gauge_level_from_ocw() -> Weight {
+        Weight::from_parts(95_000_000, 24_000)
+            .saturating_add(RocksDbWeight::get().reads(228_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    fn set_gauge_source() -> Weight {
+        Weight::from_parts(16_000_000, 3_500)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn set_provider_assignments(p: u32) -> Weight {
+        Weight::from_parts(16_000_000, 3_500)
+            .saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(p.into()))
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(p.into())))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+            .saturating_add(Weight::from_parts(0, 2_500).saturating_mul(p.into()))
+    }
+    fn rotate_provider() -> Weight {
+        Weight::from_parts(20_000_000, 4_100)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn correct_hourly_rainfall_from_ocw(n: u32) -> Weight {
+        Weight::from_parts(65_000_000, 6_000)
+            .saturating_add(Weight::from_parts(12_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(19_u64))
+            .saturating_add(RocksDbWeight::get().reads((8_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes(10_u64))
+            .saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 3_000).saturating_mul(n.into()))
+    }
+    fn report_ocw_health() -> Weight {
+        Weight::from_parts(18_000_000, 2_600)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn set_data_retention() -> Weight {
+        Weight::from_parts(16_000_000, 3_600)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn mirror_observations(n: u32) -> Weight {
+        Weight::from_parts(95_000_000, 12_000)
+            .saturating_add(Weight::from_parts(12_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(28_u64))
+            .saturating_add(RocksDbWeight::get().reads((8_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes(14_u64))
+            .saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 3_000).saturating_mul(n.into()))
+    }
+    fn set_mirror_quorum() -> Weight {
+        Weight::from_parts(12_000_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn submit_listing_feasibility() -> Weight {
+        Weight::from_parts(28_000_000, 4_500)
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    fn set_market_data_source() -> Weight {
+        Weight::from_parts(17_000_000, 3_500)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn register_oracle_keys() -> Weight {
+        Weight::from_parts(62_000_000, 5_000)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    fn purge_oracle_keys() -> Weight {
+        Weight::from_parts(16_000_000, 2_500)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+}
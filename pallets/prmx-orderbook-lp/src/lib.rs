@@ -5,9 +5,18 @@
 //! ## Overview
 //!
 //! - Sellers (including DAO) can place ask orders to sell LP tokens.
-//! - Buyers can purchase LP tokens by matching against asks.
-//! - Orders are sorted by price (lowest first for asks).
+//! - Buyers can purchase LP tokens by matching against asks, or place limit
+//!   bids. Payment for the unfilled part of a bid is held by the pallet account
+//!   until the bid fills or is cancelled.
+//! - Incoming bids and asks match resting orders with price-time priority and
+//!   fill partially, trading at the resting order's price (see [`matching`]).
+//! - Orders are sorted by price (lowest first for asks, highest first for bids).
 //! - LP Tokens are **policy-specific** - each policy has its own LP token pool.
+//! - Policy holders can also list shares of their active coverage. Buyers pay the
+//!   seller and the policy pallet records them as beneficiaries of the shares
+//!   they bought. Listings fill partially, share by share.
+//! - Trades settle in the policy's denomination asset (its market's base asset,
+//!   USDT by default); prices are quoted in that asset's units.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -15,9 +24,18 @@ extern crate alloc;
 
 pub use pallet::*;
 
+pub mod matching;
+pub use matching::OrderSide;
+
 use alloc::vec::Vec;
 use frame_support::traits::fungibles;
 use pallet_prmx_holdings::HoldingsApi;
+use prmx_primitives::{OrderBookDepth, OrderBookLevel, PolicyAssetApi};
+use prmx_time::ChainTime;
+use sp_runtime::traits::AccountIdConversion;
+
+/// Pallet ID for the account holding the payment of open bids
+pub const PALLET_ID: frame_support::PalletId = frame_support::PalletId(*b"prmxlpob");
 
 /// Policy ID type - re-exported from primitives
 pub use prmx_primitives::PolicyId;
@@ -33,6 +51,22 @@ pub trait LpOrderbookApi<AccountId, Balance> {
     ) -> Result<(), sp_runtime::DispatchError>;
 }
 
+/// Trait for trading policy shares (implemented by the policy pallet, used by
+/// this pallet for policy listings)
+pub trait PolicyPositionApi<AccountId> {
+    /// Shares of a policy `who` can sell (0 if the policy cannot be traded)
+    fn position_shares(policy_id: PolicyId, who: &AccountId) -> u128;
+
+    /// Move `shares` of a policy from `from` to `to`, who is then paid their
+    /// share of any payout
+    fn transfer_position(
+        policy_id: PolicyId,
+        from: &AccountId,
+        to: &AccountId,
+        shares: u128,
+    ) -> Result<(), sp_runtime::DispatchError>;
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -56,12 +90,39 @@ pub mod pallet {
         pub order_id: OrderId,
         pub policy_id: PolicyId,      // LP tokens are policy-specific
         pub seller: T::AccountId,
-        pub price: T::Balance,        // price per LP Token share in USDT units
+        pub price: T::Balance,        // price per LP Token share in policy asset units
+        pub quantity: u128,           // original quantity
+        pub remaining: u128,          // remaining unfilled quantity
+        pub created_at: u64,
+    }
+
+    /// LP Bid Order structure. The buyer's payment for `remaining` is held by
+    /// the pallet account.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct LpBidOrder<T: Config> {
+        pub order_id: OrderId,
+        pub policy_id: PolicyId,
+        pub buyer: T::AccountId,
+        pub price: T::Balance,        // limit price per LP Token share in policy asset units
         pub quantity: u128,           // original quantity
         pub remaining: u128,          // remaining unfilled quantity
         pub created_at: u64,
     }
 
+    /// Listing of policy shares on the secondary market
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct PolicyListing<T: Config> {
+        pub listing_id: OrderId,
+        pub policy_id: PolicyId,
+        pub seller: T::AccountId,
+        pub price_per_share: T::Balance, // policy asset units per policy share
+        pub shares: u128,                // original quantity
+        pub remaining: u128,             // remaining unsold shares
+        pub created_at: u64,
+    }
+
     // =========================================================================
     //                                  Config
     // =========================================================================
@@ -76,14 +137,17 @@ pub mod pallet {
         /// Asset ID type
         type AssetId: Parameter + Member + Copy + Default + MaxEncodedLen;
 
-        /// Fungibles implementation for USDT transfers
+        /// Fungibles implementation for trade payments
         type Assets: fungibles::Mutate<Self::AccountId, AssetId = Self::AssetId, Balance = Self::Balance>
             + fungibles::Inspect<Self::AccountId>;
 
-        /// USDT asset ID
+        /// USDT asset ID, used for policies without a recorded denomination
         #[pallet::constant]
         type UsdtAssetId: Get<Self::AssetId>;
 
+        /// Denomination asset of each policy
+        type PolicyAssets: PolicyAssetApi<Self::AssetId>;
+
         /// Access to holdings pallet (now policy-based)
         type HoldingsApi: HoldingsApi<Self::AccountId, Balance = Self::Balance>;
 
@@ -102,6 +166,16 @@ pub mod pallet {
         /// Maximum active orders per user per policy
         #[pallet::constant]
         type MaxOrdersPerUser: Get<u32>;
+
+        /// Access to policy shares for policy listings
+        type PolicyPositions: PolicyPositionApi<Self::AccountId>;
+
+        /// Maximum open listings per policy
+        #[pallet::constant]
+        type MaxListingsPerPolicy: Get<u32>;
+
+        /// Source of chain time for order and listing IDs
+        type TimeProvider: ChainTime;
     }
 
     // =========================================================================
@@ -165,6 +239,77 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// Bid orders by ID
+    #[pallet::storage]
+    #[pallet::getter(fn bid_orders)]
+    pub type BidOrders<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        OrderId,
+        LpBidOrder<T>,
+        OptionQuery,
+    >;
+
+    /// Bid book: PolicyId -> Price -> Vec<OrderId>
+    /// Orders at each price level, sorted by time (FIFO)
+    #[pallet::storage]
+    #[pallet::getter(fn bid_book)]
+    pub type BidBook<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        PolicyId,
+        Blake2_128Concat,
+        T::Balance,  // price
+        BoundedVec<OrderId, T::MaxOrdersPerPriceLevel>,
+        ValueQuery,
+    >;
+
+    /// Bid price levels for a policy (sorted descending)
+    #[pallet::storage]
+    #[pallet::getter(fn bid_price_levels)]
+    pub type BidPriceLevels<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        PolicyId,
+        BoundedVec<T::Balance, T::MaxPriceLevels>,
+        ValueQuery,
+    >;
+
+    /// User's active bids per policy
+    #[pallet::storage]
+    #[pallet::getter(fn user_bids)]
+    pub type UserBids<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        PolicyId,
+        BoundedVec<OrderId, T::MaxOrdersPerUser>,
+        ValueQuery,
+    >;
+
+    /// Policy share listings by ID
+    #[pallet::storage]
+    #[pallet::getter(fn policy_listings)]
+    pub type PolicyListings<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        OrderId,
+        PolicyListing<T>,
+        OptionQuery,
+    >;
+
+    /// Open listings per policy, in listing order
+    #[pallet::storage]
+    #[pallet::getter(fn listings_by_policy)]
+    pub type ListingsByPolicy<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        PolicyId,
+        BoundedVec<OrderId, T::MaxListingsPerPolicy>,
+        ValueQuery,
+    >;
+
     // =========================================================================
     //                                  Events
     // =========================================================================
@@ -172,7 +317,8 @@ pub mod pallet {
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
-        /// Ask order placed for policy LP tokens. [order_id, policy_id, seller, price, quantity]
+        /// Ask order placed for policy LP tokens. `quantity` is what rests on the
+        /// book after any immediate fills. [order_id, policy_id, seller, price, quantity]
         AskPlaced {
             order_id: OrderId,
             policy_id: PolicyId,
@@ -194,10 +340,61 @@ pub mod pallet {
             price: T::Balance,
             quantity: u128,
         },
+        /// Bid order placed for policy LP tokens. `quantity` is what rests on the
+        /// book after any immediate fills. [order_id, policy_id, buyer, price, quantity]
+        BidPlaced {
+            order_id: OrderId,
+            policy_id: PolicyId,
+            buyer: T::AccountId,
+            price: T::Balance,
+            quantity: u128,
+        },
+        /// Bid order cancelled and its held payment refunded. [order_id, remaining, refunded]
+        BidCancelled {
+            order_id: OrderId,
+            remaining: u128,
+            refunded: T::Balance,
+        },
+        /// Incoming order filled against a resting order, at the resting order's
+        /// price. Emitted with `TradeExecuted` for each fill.
+        /// [policy_id, maker_order_id, taker_order_id, maker, taker, taker_side, price, quantity]
+        OrderMatched {
+            policy_id: PolicyId,
+            maker_order_id: OrderId,
+            /// None for `buy_lp`, which does not rest on the book
+            taker_order_id: Option<OrderId>,
+            maker: T::AccountId,
+            taker: T::AccountId,
+            taker_side: OrderSide,
+            price: T::Balance,
+            quantity: u128,
+        },
         /// Order fully filled. [order_id]
         OrderFilled {
             order_id: OrderId,
         },
+        /// Policy shares listed for sale. [listing_id, policy_id, seller, price_per_share, shares]
+        PolicyListed {
+            listing_id: OrderId,
+            policy_id: PolicyId,
+            seller: T::AccountId,
+            price_per_share: T::Balance,
+            shares: u128,
+        },
+        /// Policy listing cancelled. [listing_id, remaining]
+        PolicyListingCancelled {
+            listing_id: OrderId,
+            remaining: u128,
+        },
+        /// Policy shares sold. [listing_id, policy_id, buyer, seller, price_per_share, shares]
+        PolicySharesSold {
+            listing_id: OrderId,
+            policy_id: PolicyId,
+            buyer: T::AccountId,
+            seller: T::AccountId,
+            price_per_share: T::Balance,
+            shares: u128,
+        },
     }
 
     // =========================================================================
@@ -230,6 +427,16 @@ pub mod pallet {
         TransferFailed,
         /// Too many orders at price level.
         TooManyOrdersAtPriceLevel,
+        /// Listing not found.
+        ListingNotFound,
+        /// Not the listing owner.
+        NotListingOwner,
+        /// Seller does not hold enough unlisted shares of the policy.
+        InsufficientPolicyShares,
+        /// Policy already has the maximum number of open listings.
+        TooManyListings,
+        /// Cannot buy from your own listing.
+        CannotBuyOwnListing,
     }
 
     // =========================================================================
@@ -361,99 +568,218 @@ pub mod pallet {
 
             ensure!(quantity > 0, Error::<T>::InvalidQuantity);
 
-            // Get price levels for this policy
-            let price_levels = PriceLevels::<T>::get(policy_id);
-            
-            let mut remaining_to_buy = quantity;
-            let mut total_cost_u128: u128 = 0;
+            let remaining_to_buy = Self::match_asks(policy_id, &who, None, max_price, quantity)?;
 
-            // Iterate through price levels from lowest to highest
-            for price in price_levels.iter() {
-                if *price > max_price {
-                    break; // Price too high
-                }
+            // Check if we filled anything
+            let filled = quantity.saturating_sub(remaining_to_buy);
+            ensure!(filled > 0, Error::<T>::NoMatchingOrders);
 
-                if remaining_to_buy == 0 {
-                    break;
-                }
+            Ok(())
+        }
 
-                // Get orders at this price level
-                let order_ids = AskBook::<T>::get(policy_id, price);
+        /// Place a limit bid to buy LP tokens for a specific policy.
+        ///
+        /// The bid first fills against asks at or below `price`, lowest first, at
+        /// each ask's price. Payment for the unfilled rest is held by the pallet
+        /// until the bid fills or is cancelled.
+        ///
+        /// - `policy_id`: The policy whose LP tokens to buy.
+        /// - `price`: Maximum price per LP share in the policy's asset units.
+        /// - `quantity`: Number of LP shares to buy.
+        #[pallet::call_index(7)]
+        #[pallet::weight(100_000)]
+        pub fn place_lp_bid(
+            origin: OriginFor<T>,
+            policy_id: PolicyId,
+            price: T::Balance,
+            quantity: u128,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
 
-                for order_id in order_ids.iter() {
-                    if remaining_to_buy == 0 {
-                        break;
-                    }
+            Self::do_place_lp_bid(policy_id, &who, price, quantity)
+        }
 
-                    if let Some(mut order) = Orders::<T>::get(order_id) {
-                        let fill_qty = core::cmp::min(remaining_to_buy, order.remaining);
-                        
-                        if fill_qty > 0 {
-                            // Calculate cost
-                            let price_u128: u128 = (*price).into();
-                            let cost_u128 = price_u128
-                                .checked_mul(fill_qty)
-                                .ok_or(Error::<T>::ArithmeticOverflow)?;
-                            let cost: T::Balance = cost_u128.into();
-
-                            // Transfer USDT from buyer to seller
-                            T::Assets::transfer(
-                                T::UsdtAssetId::get(),
-                                &who,
-                                &order.seller,
-                                cost,
-                                Preservation::Preserve,
-                            )?;
-
-                            // Transfer locked LP tokens from seller to buyer (policy-specific)
-                            T::HoldingsApi::transfer_locked_lp_tokens(
-                                policy_id,
-                                &order.seller,
-                                &who,
-                                fill_qty,
-                            ).map_err(|_| Error::<T>::TransferFailed)?;
-
-                            // Register buyer as LP holder for this policy
-                            T::HoldingsApi::register_lp_holder(policy_id, &who)
-                                .map_err(|_| Error::<T>::TransferFailed)?;
-
-                            // Update order
-                            order.remaining = order.remaining.saturating_sub(fill_qty);
-                            remaining_to_buy = remaining_to_buy.saturating_sub(fill_qty);
-                            total_cost_u128 = total_cost_u128.saturating_add(cost_u128);
-
-                            Self::deposit_event(Event::TradeExecuted {
-                                order_id: *order_id,
-                                policy_id,
-                                buyer: who.clone(),
-                                seller: order.seller.clone(),
-                                price: *price,
-                                quantity: fill_qty,
-                            });
-
-                            if order.remaining == 0 {
-                                // Order fully filled - remove it
-                                Self::remove_from_ask_book(policy_id, *price, *order_id)?;
-                                UserOrders::<T>::mutate(&order.seller, policy_id, |orders| {
-                                    orders.retain(|&id| id != *order_id);
-                                });
-                                Orders::<T>::remove(order_id);
-
-                                Self::deposit_event(Event::OrderFilled {
-                                    order_id: *order_id,
-                                });
-                            } else {
-                                // Update order with new remaining
-                                Orders::<T>::insert(order_id, order);
-                            }
-                        }
-                    }
-                }
-            }
+        /// Cancel a bid order, refunding the payment held for its unfilled part.
+        ///
+        /// - `order_id`: The bid to cancel.
+        #[pallet::call_index(8)]
+        #[pallet::weight(30_000)]
+        pub fn cancel_lp_bid(
+            origin: OriginFor<T>,
+            order_id: OrderId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
 
-            // Check if we filled anything
-            let filled = quantity.saturating_sub(remaining_to_buy);
-            ensure!(filled > 0, Error::<T>::NoMatchingOrders);
+            let bid = BidOrders::<T>::get(order_id)
+                .ok_or(Error::<T>::OrderNotFound)?;
+            ensure!(bid.buyer == who, Error::<T>::NotOrderOwner);
+
+            let refunded: T::Balance = matching::notional(bid.price.into(), bid.remaining)
+                .ok_or(Error::<T>::ArithmeticOverflow)?
+                .into();
+            T::Assets::transfer(
+                Self::policy_asset(bid.policy_id),
+                &Self::escrow_account(),
+                &who,
+                refunded,
+                Preservation::Expendable,
+            ).map_err(|_| Error::<T>::TransferFailed)?;
+
+            Self::remove_bid(&bid);
+
+            Self::deposit_event(Event::BidCancelled {
+                order_id,
+                remaining: bid.remaining,
+                refunded,
+            });
+
+            Ok(())
+        }
+
+        /// List shares of an active policy for sale.
+        ///
+        /// The seller must hold the shares (as holder or earlier buyer) and the
+        /// policy must still be transferable. Shares stay with the seller until sold.
+        ///
+        /// - `policy_id`: The policy whose shares to sell.
+        /// - `price_per_share`: Price per policy share, in base units of the policy's
+        ///   settlement asset.
+        /// - `shares`: Number of shares to list.
+        #[pallet::call_index(4)]
+        #[pallet::weight(50_000)]
+        pub fn list_policy_position(
+            origin: OriginFor<T>,
+            policy_id: PolicyId,
+            price_per_share: T::Balance,
+            shares: u128,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(shares > 0, Error::<T>::InvalidQuantity);
+            ensure!(price_per_share > T::Balance::zero(), Error::<T>::InvalidPrice);
+
+            // Shares already listed by the seller cannot be listed again
+            let listed = Self::listed_shares(policy_id, &who);
+            let available = T::PolicyPositions::position_shares(policy_id, &who);
+            ensure!(
+                listed.saturating_add(shares) <= available,
+                Error::<T>::InsufficientPolicyShares
+            );
+
+            let now = T::TimeProvider::now_secs();
+            let nonce = AccountNonce::<T>::get(&who);
+            let listing_id = generate_unique_id(b"PLIST", &who, now, nonce);
+            AccountNonce::<T>::insert(&who, nonce + 1);
+
+            ListingsByPolicy::<T>::try_mutate(policy_id, |listings| {
+                listings.try_push(listing_id).map_err(|_| Error::<T>::TooManyListings)
+            })?;
+
+            PolicyListings::<T>::insert(listing_id, PolicyListing::<T> {
+                listing_id,
+                policy_id,
+                seller: who.clone(),
+                price_per_share,
+                shares,
+                remaining: shares,
+                created_at: now,
+            });
+
+            Self::deposit_event(Event::PolicyListed {
+                listing_id,
+                policy_id,
+                seller: who,
+                price_per_share,
+                shares,
+            });
+
+            Ok(())
+        }
+
+        /// Cancel a policy listing.
+        ///
+        /// - `listing_id`: The listing to cancel.
+        #[pallet::call_index(5)]
+        #[pallet::weight(30_000)]
+        pub fn cancel_policy_listing(
+            origin: OriginFor<T>,
+            listing_id: OrderId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let listing = PolicyListings::<T>::get(listing_id)
+                .ok_or(Error::<T>::ListingNotFound)?;
+            ensure!(listing.seller == who, Error::<T>::NotListingOwner);
+
+            Self::remove_listing(listing.policy_id, listing_id);
+
+            Self::deposit_event(Event::PolicyListingCancelled {
+                listing_id,
+                remaining: listing.remaining,
+            });
+
+            Ok(())
+        }
+
+        /// Buy shares from a policy listing. Fills up to `shares`, leaving the rest
+        /// of the listing open.
+        ///
+        /// - `listing_id`: The listing to buy from.
+        /// - `shares`: Number of shares to buy.
+        /// - `max_price`: Maximum price willing to pay per share.
+        #[pallet::call_index(6)]
+        #[pallet::weight(100_000)]
+        pub fn buy_policy_position(
+            origin: OriginFor<T>,
+            listing_id: OrderId,
+            shares: u128,
+            max_price: T::Balance,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(shares > 0, Error::<T>::InvalidQuantity);
+
+            let mut listing = PolicyListings::<T>::get(listing_id)
+                .ok_or(Error::<T>::ListingNotFound)?;
+            ensure!(listing.seller != who, Error::<T>::CannotBuyOwnListing);
+            ensure!(listing.price_per_share <= max_price, Error::<T>::PriceTooHigh);
+
+            let fill = core::cmp::min(shares, listing.remaining);
+            let price_u128: u128 = listing.price_per_share.into();
+            let cost: T::Balance = price_u128
+                .checked_mul(fill)
+                .ok_or(Error::<T>::ArithmeticOverflow)?
+                .into();
+
+            // Pay the seller in the policy's asset
+            T::Assets::transfer(
+                Self::policy_asset(listing.policy_id),
+                &who,
+                &listing.seller,
+                cost,
+                Preservation::Preserve,
+            ).map_err(|_| Error::<T>::InsufficientUsdtBalance)?;
+
+            // Record the buyer as beneficiary of the shares
+            T::PolicyPositions::transfer_position(listing.policy_id, &listing.seller, &who, fill)?;
+
+            listing.remaining = listing.remaining.saturating_sub(fill);
+
+            Self::deposit_event(Event::PolicySharesSold {
+                listing_id,
+                policy_id: listing.policy_id,
+                buyer: who,
+                seller: listing.seller.clone(),
+                price_per_share: listing.price_per_share,
+                shares: fill,
+            });
+
+            if listing.remaining == 0 {
+                Self::remove_listing(listing.policy_id, listing_id);
+                Self::deposit_event(Event::OrderFilled { order_id: listing_id });
+            } else {
+                PolicyListings::<T>::insert(listing_id, listing);
+            }
 
             Ok(())
         }
@@ -464,6 +790,11 @@ pub mod pallet {
     // =========================================================================
 
     impl<T: Config> Pallet<T> {
+        /// Asset trades of a policy's LP tokens and shares are paid in
+        pub fn policy_asset(policy_id: PolicyId) -> T::AssetId {
+            T::PolicyAssets::policy_asset(policy_id).unwrap_or_else(T::UsdtAssetId::get)
+        }
+
         /// Internal function to place an LP ask order for a policy
         pub fn do_place_lp_ask(
             policy_id: PolicyId,
@@ -483,18 +814,24 @@ pub mod pallet {
                 .map_err(|_| Error::<T>::InsufficientLpBalance)?;
 
             // Generate unique order ID
-            let now = Self::current_timestamp();
+            let now = T::TimeProvider::now_secs();
             let nonce = AccountNonce::<T>::get(seller);
             let order_id = generate_unique_id(b"ORDER", seller, now, nonce);
             AccountNonce::<T>::insert(seller, nonce + 1);
-            
+
+            // Fill against resting bids first; only the rest goes on the book
+            let remaining = Self::match_bids(policy_id, seller, order_id, price, quantity)?;
+            if remaining == 0 {
+                return Ok(());
+            }
+
             let order = LpAskOrder::<T> {
                 order_id,
                 policy_id,
                 seller: seller.clone(),
                 price,
                 quantity,
-                remaining: quantity,
+                remaining,
                 created_at: now,
             };
 
@@ -515,12 +852,275 @@ pub mod pallet {
                 policy_id,
                 seller: seller.clone(),
                 price,
+                quantity: remaining,
+            });
+
+            Ok(())
+        }
+
+        /// Internal function to place an LP bid order for a policy
+        pub fn do_place_lp_bid(
+            policy_id: PolicyId,
+            buyer: &T::AccountId,
+            price: T::Balance,
+            quantity: u128,
+        ) -> DispatchResult {
+            ensure!(quantity > 0, Error::<T>::InvalidQuantity);
+            ensure!(price > T::Balance::zero(), Error::<T>::InvalidPrice);
+
+            // Generate unique order ID
+            let now = T::TimeProvider::now_secs();
+            let nonce = AccountNonce::<T>::get(buyer);
+            let order_id = generate_unique_id(b"LPBID", buyer, now, nonce);
+            AccountNonce::<T>::insert(buyer, nonce + 1);
+
+            // Fill against resting asks first; only the rest goes on the book
+            let remaining = Self::match_asks(policy_id, buyer, Some(order_id), price, quantity)?;
+            if remaining == 0 {
+                return Ok(());
+            }
+
+            // Hold payment for the rest at the bid price
+            let held: T::Balance = matching::notional(price.into(), remaining)
+                .ok_or(Error::<T>::ArithmeticOverflow)?
+                .into();
+            T::Assets::transfer(
+                Self::policy_asset(policy_id),
+                buyer,
+                &Self::escrow_account(),
+                held,
+                Preservation::Preserve,
+            ).map_err(|_| Error::<T>::InsufficientUsdtBalance)?;
+
+            BidOrders::<T>::insert(order_id, LpBidOrder::<T> {
+                order_id,
+                policy_id,
+                buyer: buyer.clone(),
+                price,
                 quantity,
+                remaining,
+                created_at: now,
+            });
+
+            Self::add_to_bid_book(policy_id, price, order_id)?;
+
+            UserBids::<T>::try_mutate(buyer, policy_id, |orders| -> DispatchResult {
+                orders.try_push(order_id).map_err(|_| Error::<T>::TooManyOrdersAtPriceLevel)?;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::BidPlaced {
+                order_id,
+                policy_id,
+                buyer: buyer.clone(),
+                price,
+                quantity: remaining,
             });
 
             Ok(())
         }
 
+        /// Account holding the payment of open bids
+        pub fn escrow_account() -> T::AccountId {
+            PALLET_ID.into_account_truncating()
+        }
+
+        /// Buy up to `quantity` LP shares from asks priced at or below `limit`,
+        /// paying each seller from `buyer` at the ask's price. The buyer's own
+        /// asks are skipped. Returns the quantity left unfilled.
+        fn match_asks(
+            policy_id: PolicyId,
+            buyer: &T::AccountId,
+            taker_order_id: Option<OrderId>,
+            limit: T::Balance,
+            quantity: u128,
+        ) -> Result<u128, DispatchError> {
+            let asset = Self::policy_asset(policy_id);
+            let mut remaining = quantity;
+
+            // Iterate through price levels from lowest to highest
+            for price in PriceLevels::<T>::get(policy_id).iter() {
+                if remaining == 0 || !OrderSide::Bid.crosses(&limit, price) {
+                    break;
+                }
+
+                // Orders at this price level, oldest first
+                for order_id in AskBook::<T>::get(policy_id, price).iter() {
+                    if remaining == 0 {
+                        break;
+                    }
+
+                    let Some(mut order) = Orders::<T>::get(order_id) else { continue };
+                    if &order.seller == buyer {
+                        continue;
+                    }
+
+                    let fill_qty = core::cmp::min(remaining, order.remaining);
+                    let cost: T::Balance = matching::notional((*price).into(), fill_qty)
+                        .ok_or(Error::<T>::ArithmeticOverflow)?
+                        .into();
+
+                    // Pay the seller in the policy's asset
+                    T::Assets::transfer(asset, buyer, &order.seller, cost, Preservation::Preserve)?;
+
+                    // Transfer locked LP tokens from seller to buyer (policy-specific)
+                    T::HoldingsApi::transfer_locked_lp_tokens(
+                        policy_id,
+                        &order.seller,
+                        buyer,
+                        fill_qty,
+                    ).map_err(|_| Error::<T>::TransferFailed)?;
+
+                    // Register buyer as LP holder for this policy
+                    T::HoldingsApi::register_lp_holder(policy_id, buyer)
+                        .map_err(|_| Error::<T>::TransferFailed)?;
+
+                    order.remaining = order.remaining.saturating_sub(fill_qty);
+                    remaining = remaining.saturating_sub(fill_qty);
+
+                    Self::deposit_fill(
+                        policy_id,
+                        *order_id,
+                        taker_order_id,
+                        buyer,
+                        &order.seller,
+                        OrderSide::Bid,
+                        *price,
+                        fill_qty,
+                    );
+
+                    if order.remaining == 0 {
+                        // Order fully filled - remove it
+                        Self::remove_from_ask_book(policy_id, *price, *order_id)?;
+                        UserOrders::<T>::mutate(&order.seller, policy_id, |orders| {
+                            orders.retain(|&id| id != *order_id);
+                        });
+                        Orders::<T>::remove(order_id);
+
+                        Self::deposit_event(Event::OrderFilled { order_id: *order_id });
+                    } else {
+                        Orders::<T>::insert(order_id, order);
+                    }
+                }
+            }
+
+            Ok(remaining)
+        }
+
+        /// Sell up to `quantity` locked LP shares of `seller` to bids priced at or
+        /// above `limit`, paying the seller from each bid's held payment at the
+        /// bid's price. The seller's own bids are skipped. Returns the quantity
+        /// left unfilled.
+        fn match_bids(
+            policy_id: PolicyId,
+            seller: &T::AccountId,
+            taker_order_id: OrderId,
+            limit: T::Balance,
+            quantity: u128,
+        ) -> Result<u128, DispatchError> {
+            let asset = Self::policy_asset(policy_id);
+            let escrow = Self::escrow_account();
+            let mut remaining = quantity;
+
+            // Iterate through price levels from highest to lowest
+            for price in BidPriceLevels::<T>::get(policy_id).iter() {
+                if remaining == 0 || !OrderSide::Ask.crosses(&limit, price) {
+                    break;
+                }
+
+                // Orders at this price level, oldest first
+                for order_id in BidBook::<T>::get(policy_id, price).iter() {
+                    if remaining == 0 {
+                        break;
+                    }
+
+                    let Some(mut bid) = BidOrders::<T>::get(order_id) else { continue };
+                    if &bid.buyer == seller {
+                        continue;
+                    }
+
+                    let fill_qty = core::cmp::min(remaining, bid.remaining);
+                    let cost: T::Balance = matching::notional((*price).into(), fill_qty)
+                        .ok_or(Error::<T>::ArithmeticOverflow)?
+                        .into();
+
+                    T::HoldingsApi::transfer_locked_lp_tokens(
+                        policy_id,
+                        seller,
+                        &bid.buyer,
+                        fill_qty,
+                    ).map_err(|_| Error::<T>::TransferFailed)?;
+
+                    T::HoldingsApi::register_lp_holder(policy_id, &bid.buyer)
+                        .map_err(|_| Error::<T>::TransferFailed)?;
+
+                    // Pay the seller from the bid's held payment
+                    T::Assets::transfer(asset, &escrow, seller, cost, Preservation::Expendable)
+                        .map_err(|_| Error::<T>::TransferFailed)?;
+
+                    bid.remaining = bid.remaining.saturating_sub(fill_qty);
+                    remaining = remaining.saturating_sub(fill_qty);
+
+                    Self::deposit_fill(
+                        policy_id,
+                        *order_id,
+                        Some(taker_order_id),
+                        &bid.buyer,
+                        seller,
+                        OrderSide::Ask,
+                        *price,
+                        fill_qty,
+                    );
+
+                    if bid.remaining == 0 {
+                        Self::remove_bid(&bid);
+                        Self::deposit_event(Event::OrderFilled { order_id: *order_id });
+                    } else {
+                        BidOrders::<T>::insert(order_id, bid);
+                    }
+                }
+            }
+
+            Ok(remaining)
+        }
+
+        /// Emit `TradeExecuted` and `OrderMatched` for one fill of a resting order
+        #[allow(clippy::too_many_arguments)]
+        fn deposit_fill(
+            policy_id: PolicyId,
+            maker_order_id: OrderId,
+            taker_order_id: Option<OrderId>,
+            buyer: &T::AccountId,
+            seller: &T::AccountId,
+            taker_side: OrderSide,
+            price: T::Balance,
+            quantity: u128,
+        ) {
+            let (maker, taker) = match taker_side {
+                OrderSide::Bid => (seller, buyer),
+                OrderSide::Ask => (buyer, seller),
+            };
+
+            Self::deposit_event(Event::TradeExecuted {
+                order_id: maker_order_id,
+                policy_id,
+                buyer: buyer.clone(),
+                seller: seller.clone(),
+                price,
+                quantity,
+            });
+            Self::deposit_event(Event::OrderMatched {
+                policy_id,
+                maker_order_id,
+                taker_order_id,
+                maker: maker.clone(),
+                taker: taker.clone(),
+                taker_side,
+                price,
+                quantity,
+            });
+        }
+
         /// Add order to ask book at price level
         fn add_to_ask_book(
             policy_id: PolicyId,
@@ -548,6 +1148,52 @@ pub mod pallet {
             Ok(())
         }
 
+        /// Add order to bid book at price level
+        fn add_to_bid_book(
+            policy_id: PolicyId,
+            price: T::Balance,
+            order_id: OrderId,
+        ) -> DispatchResult {
+            BidBook::<T>::try_mutate(policy_id, price, |orders| {
+                orders.try_push(order_id)
+                    .map_err(|_| Error::<T>::TooManyOrdersAtPriceLevel)
+            })?;
+
+            // Update price levels (maintain descending order)
+            BidPriceLevels::<T>::try_mutate(policy_id, |levels| -> DispatchResult {
+                if let Err(pos) = matching::level_position(levels, OrderSide::Bid, &price) {
+                    levels
+                        .try_insert(pos, price)
+                        .map_err(|_| Error::<T>::TooManyOrdersAtPriceLevel)?;
+                }
+                Ok(())
+            })?;
+
+            Ok(())
+        }
+
+        /// Remove a bid from the bid book, its owner's bids and storage
+        fn remove_bid(bid: &LpBidOrder<T>) {
+            let order_id = bid.order_id;
+            BidBook::<T>::mutate_exists(bid.policy_id, bid.price, |orders| {
+                if let Some(ids) = orders {
+                    ids.retain(|&id| id != order_id);
+                    if ids.is_empty() {
+                        *orders = None;
+                    }
+                }
+            });
+            if !BidBook::<T>::contains_key(bid.policy_id, bid.price) {
+                BidPriceLevels::<T>::mutate(bid.policy_id, |levels| {
+                    levels.retain(|&p| p != bid.price);
+                });
+            }
+            UserBids::<T>::mutate(&bid.buyer, bid.policy_id, |orders| {
+                orders.retain(|&id| id != order_id);
+            });
+            BidOrders::<T>::remove(order_id);
+        }
+
         /// Remove order from ask book
         fn remove_from_ask_book(
             policy_id: PolicyId,
@@ -571,12 +1217,27 @@ pub mod pallet {
             Ok(())
         }
 
-        /// Get current timestamp
-        fn current_timestamp() -> u64 {
-            let block_number: u64 = frame_system::Pallet::<T>::block_number()
-                .try_into()
-                .unwrap_or(0);
-            block_number * 6 // Assume 6 second blocks
+        /// Shares of a policy `seller` has listed and not yet sold
+        pub fn listed_shares(policy_id: PolicyId, seller: &T::AccountId) -> u128 {
+            ListingsByPolicy::<T>::get(policy_id)
+                .iter()
+                .filter_map(PolicyListings::<T>::get)
+                .filter(|listing| &listing.seller == seller)
+                .map(|listing| listing.remaining)
+                .sum()
+        }
+
+        /// Remove a policy listing and its index entry
+        fn remove_listing(policy_id: PolicyId, listing_id: OrderId) {
+            ListingsByPolicy::<T>::mutate_exists(policy_id, |listings| {
+                if let Some(ids) = listings {
+                    ids.retain(|&id| id != listing_id);
+                    if ids.is_empty() {
+                        *listings = None;
+                    }
+                }
+            });
+            PolicyListings::<T>::remove(listing_id);
         }
 
         /// Get best ask price for a policy
@@ -585,6 +1246,53 @@ pub mod pallet {
             levels.first().cloned()
         }
 
+        /// Get best bid price for a policy
+        pub fn best_bid_price(policy_id: PolicyId) -> Option<T::Balance> {
+            BidPriceLevels::<T>::get(policy_id).first().cloned()
+        }
+
+        /// Up to `max_levels` best bid and ask levels of a policy, with the
+        /// unfilled quantity and number of orders at each price
+        pub fn order_book_depth(
+            policy_id: PolicyId,
+            max_levels: u32,
+        ) -> OrderBookDepth<T::Balance> {
+            let asks = PriceLevels::<T>::get(policy_id)
+                .iter()
+                .take(max_levels as usize)
+                .map(|price| {
+                    let ids = AskBook::<T>::get(policy_id, price);
+                    OrderBookLevel {
+                        price: *price,
+                        quantity: ids
+                            .iter()
+                            .filter_map(Orders::<T>::get)
+                            .map(|o| o.remaining)
+                            .sum(),
+                        orders: ids.len() as u32,
+                    }
+                })
+                .collect();
+            let bids = BidPriceLevels::<T>::get(policy_id)
+                .iter()
+                .take(max_levels as usize)
+                .map(|price| {
+                    let ids = BidBook::<T>::get(policy_id, price);
+                    OrderBookLevel {
+                        price: *price,
+                        quantity: ids
+                            .iter()
+                            .filter_map(BidOrders::<T>::get)
+                            .map(|o| o.remaining)
+                            .sum(),
+                        orders: ids.len() as u32,
+                    }
+                })
+                .collect();
+
+            OrderBookDepth { policy_id, bids, asks }
+        }
+
         /// Get all asks for a policy
         pub fn get_asks_for_policy(policy_id: PolicyId) -> Vec<(T::Balance, Vec<OrderId>)> {
             let levels = PriceLevels::<T>::get(policy_id);
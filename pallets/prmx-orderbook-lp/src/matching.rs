@@ -0,0 +1,118 @@
+//! # Order Matching
+//!
+//! Price rules of the LP order book. Incoming orders match resting orders on
+//! the other side with price-time priority: best price first, and within a
+//! price level in the order they were placed. Each fill trades at the resting
+//! (maker) order's price, so a taker never pays more than its limit.
+
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// Side of an order
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum OrderSide {
+    /// Buys LP shares
+    Bid,
+    /// Sells LP shares
+    Ask,
+}
+
+impl OrderSide {
+    /// Side of the resting orders an incoming order of this side matches
+    pub fn opposite(self) -> Self {
+        match self {
+            OrderSide::Bid => OrderSide::Ask,
+            OrderSide::Ask => OrderSide::Bid,
+        }
+    }
+
+    /// Whether an incoming order of this side with price `limit` trades with a
+    /// resting order at `maker_price`
+    pub fn crosses<P: Ord>(self, limit: &P, maker_price: &P) -> bool {
+        match self {
+            OrderSide::Bid => maker_price <= limit,
+            OrderSide::Ask => maker_price >= limit,
+        }
+    }
+
+    /// Whether `a` is a better price than `b` for resting orders of this side
+    /// (higher for bids, lower for asks)
+    pub fn is_better<P: Ord>(self, a: &P, b: &P) -> bool {
+        match self {
+            OrderSide::Bid => a > b,
+            OrderSide::Ask => a < b,
+        }
+    }
+}
+
+/// Position of `price` in price levels of resting orders on `side`, sorted
+/// best price first: `Ok` if the level exists, otherwise `Err` with the
+/// index to insert it at
+pub fn level_position<P: Ord>(levels: &[P], side: OrderSide, price: &P) -> Result<usize, usize> {
+    match levels.iter().position(|level| !side.is_better(level, price)) {
+        Some(pos) if &levels[pos] == price => Ok(pos),
+        Some(pos) => Err(pos),
+        None => Err(levels.len()),
+    }
+}
+
+/// Amount paid for `quantity` LP shares at `price` per share
+pub fn notional(price: u128, quantity: u128) -> Option<u128> {
+    price.checked_mul(quantity)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crossing_trades_at_or_through_the_limit() {
+        // A bid at 100 takes asks at 100 or cheaper
+        assert!(OrderSide::Bid.crosses(&100u128, &90));
+        assert!(OrderSide::Bid.crosses(&100u128, &100));
+        assert!(!OrderSide::Bid.crosses(&100u128, &101));
+
+        // An ask at 100 takes bids at 100 or higher
+        assert!(OrderSide::Ask.crosses(&100u128, &110));
+        assert!(OrderSide::Ask.crosses(&100u128, &100));
+        assert!(!OrderSide::Ask.crosses(&100u128, &99));
+
+        assert_eq!(OrderSide::Bid.opposite(), OrderSide::Ask);
+        assert_eq!(OrderSide::Ask.opposite(), OrderSide::Bid);
+    }
+
+    #[test]
+    fn test_level_position_keeps_best_price_first() {
+        let asks = [10u128, 20, 30];
+        assert_eq!(level_position(&asks, OrderSide::Ask, &20), Ok(1));
+        assert_eq!(level_position(&asks, OrderSide::Ask, &5), Err(0));
+        assert_eq!(level_position(&asks, OrderSide::Ask, &25), Err(2));
+        assert_eq!(level_position(&asks, OrderSide::Ask, &40), Err(3));
+
+        let bids = [30u128, 20, 10];
+        assert_eq!(level_position(&bids, OrderSide::Bid, &20), Ok(1));
+        assert_eq!(level_position(&bids, OrderSide::Bid, &40), Err(0));
+        assert_eq!(level_position(&bids, OrderSide::Bid, &25), Err(1));
+        assert_eq!(level_position(&bids, OrderSide::Bid, &5), Err(3));
+
+        assert_eq!(level_position::<u128>(&[], OrderSide::Bid, &5), Err(0));
+    }
+
+    #[test]
+    fn test_notional_overflow() {
+        assert_eq!(notional(1_500_000, 3), Some(4_500_000));
+        assert_eq!(notional(u128::MAX, 2), None);
+    }
+}
@@ -0,0 +1,141 @@
+//! # PRMX Pause Pallet
+//!
+//! Emergency circuit breaker for the protocol. Governance can pause new quotes,
+//! policy creation, settlements and oracle submissions independently; the quote,
+//! policy and oracle pallets check the flags through `prmx_primitives::PauseApi`
+//! and reject paused operations with `OperationPaused`.
+//!
+//! Pausing settlements only defers them: automatic settlement checks resume on
+//! the next check after the flag is cleared.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+pub use prmx_primitives::{PausableOperation, PauseApi};
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::pallet_prelude::*;
+    use frame_system::pallet_prelude::*;
+
+    // =========================================================================
+    //                                  Config
+    // =========================================================================
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Origin that can pause and resume operations
+        type GovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+    }
+
+    // =========================================================================
+    //                                  Storage
+    // =========================================================================
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// Paused operations (value: block at which the pause started)
+    #[pallet::storage]
+    #[pallet::getter(fn paused_operations)]
+    pub type PausedOperations<T: Config> =
+        StorageMap<_, Blake2_128Concat, PausableOperation, BlockNumberFor<T>, OptionQuery>;
+
+    // =========================================================================
+    //                                  Events
+    // =========================================================================
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Operation paused. [operation]
+        OperationPaused { operation: PausableOperation },
+        /// Operation resumed. [operation, paused_since]
+        OperationResumed {
+            operation: PausableOperation,
+            paused_since: BlockNumberFor<T>,
+        },
+    }
+
+    // =========================================================================
+    //                                  Errors
+    // =========================================================================
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Operation is already paused.
+        AlreadyPaused,
+        /// Operation is not paused.
+        NotPaused,
+    }
+
+    // =========================================================================
+    //                                Extrinsics
+    // =========================================================================
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Pause an operation across the protocol.
+        /// Only callable by GovernanceOrigin.
+        #[pallet::call_index(0)]
+        #[pallet::weight(10_000)]
+        pub fn pause(origin: OriginFor<T>, operation: PausableOperation) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                !PausedOperations::<T>::contains_key(operation),
+                Error::<T>::AlreadyPaused
+            );
+
+            PausedOperations::<T>::insert(operation, frame_system::Pallet::<T>::block_number());
+
+            log::warn!(
+                target: "prmx-pause",
+                "⛔ Operation {:?} paused",
+                operation
+            );
+
+            Self::deposit_event(Event::OperationPaused { operation });
+
+            Ok(())
+        }
+
+        /// Resume a paused operation.
+        /// Only callable by GovernanceOrigin.
+        #[pallet::call_index(1)]
+        #[pallet::weight(10_000)]
+        pub fn resume(origin: OriginFor<T>, operation: PausableOperation) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            let paused_since =
+                PausedOperations::<T>::take(operation).ok_or(Error::<T>::NotPaused)?;
+
+            log::info!(
+                target: "prmx-pause",
+                "✅ Operation {:?} resumed",
+                operation
+            );
+
+            Self::deposit_event(Event::OperationResumed {
+                operation,
+                paused_since,
+            });
+
+            Ok(())
+        }
+    }
+}
+
+// =============================================================================
+//                            PauseApi Implementation
+// =============================================================================
+
+impl<T: Config> PauseApi for Pallet<T> {
+    fn is_paused(operation: PausableOperation) -> bool {
+        PausedOperations::<T>::contains_key(operation)
+    }
+}
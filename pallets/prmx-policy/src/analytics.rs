@@ -0,0 +1,78 @@
+//! # Settlement Analytics
+//!
+//! Every settlement adds to its market's `MarketSettlementStats`: settled and
+//! triggered policy counts, premiums, payouts and the highest observed metric.
+//! Actuarial recalibration and dashboards read them through
+//! `PrmxSettlementAnalyticsApi` instead of replaying settlement events.
+//!
+//! Totals saturate rather than overflow. Policies settled before the runtime
+//! upgrade that introduced them are not counted.
+
+use prmx_primitives::{MarketSettlementStats, Millimeters};
+
+/// Add one settlement to a market's totals
+pub fn record_settlement<Balance>(
+    stats: &mut MarketSettlementStats<Balance>,
+    event_occurred: bool,
+    premium: Balance,
+    payout: Balance,
+    observed_mm: Option<Millimeters>,
+) where
+    Balance: Copy + From<u128> + Into<u128>,
+{
+    stats.settled_policies = stats.settled_policies.saturating_add(1);
+    if event_occurred {
+        stats.triggered_policies = stats.triggered_policies.saturating_add(1);
+    }
+    let total_premiums: u128 = stats.total_premiums.into();
+    stats.total_premiums = total_premiums.saturating_add(premium.into()).into();
+    let total_payouts: u128 = stats.total_payouts.into();
+    stats.total_payouts = total_payouts.saturating_add(payout.into()).into();
+    stats.max_observed_mm = match (stats.max_observed_mm, observed_mm) {
+        (Some(max), Some(observed)) => Some(max.max(observed)),
+        (max, observed) => max.or(observed),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_settlement_accumulates() {
+        let mut stats = MarketSettlementStats::<u128>::default();
+
+        record_settlement(&mut stats, false, 40, 0, Some(1_200));
+        record_settlement(&mut stats, true, 60, 1_000, Some(2_600));
+        // Settled without an observation (e.g. forced by governance)
+        record_settlement(&mut stats, true, 50, 500, None);
+        record_settlement(&mut stats, false, 30, 0, Some(800));
+
+        assert_eq!(
+            stats,
+            MarketSettlementStats {
+                settled_policies: 4,
+                triggered_policies: 2,
+                total_premiums: 180,
+                total_payouts: 1_500,
+                max_observed_mm: Some(2_600),
+            }
+        );
+    }
+
+    #[test]
+    fn test_record_settlement_saturates() {
+        let mut stats = MarketSettlementStats::<u128> {
+            settled_policies: u32::MAX,
+            total_payouts: u128::MAX - 1,
+            ..Default::default()
+        };
+
+        record_settlement(&mut stats, false, 10, 5, None);
+
+        assert_eq!(stats.settled_policies, u32::MAX);
+        assert_eq!(stats.total_premiums, 10);
+        assert_eq!(stats.total_payouts, u128::MAX);
+        assert_eq!(stats.max_observed_mm, None);
+    }
+}
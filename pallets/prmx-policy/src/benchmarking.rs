@@ -0,0 +1,512 @@
+//! Benchmarks for the PRMX policy pallet
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_support::pallet_prelude::*;
+use frame_system::RawOrigin;
+
+/// Create an active policy for `holder` and return its id
+fn active_policy<T: Config>(holder: &T::AccountId, v2: bool) -> PolicyId {
+    let quote_id = T::BenchmarkHelper::ready_quote(holder, v2);
+    Pallet::<T>::apply_coverage_with_quote(RawOrigin::Signed(holder.clone()).into(), quote_id)
+        .expect("quote is ready and funded");
+    *Pallet::<T>::policies_of(holder).last().expect("policy was created")
+}
+
+/// Move the chain clock past a policy's coverage window
+fn end_coverage<T: Config>(policy_id: PolicyId) {
+    let policy = Policies::<T>::get(policy_id).expect("policy exists");
+    T::BenchmarkHelper::set_timestamp(policy.coverage_end.saturating_add(1));
+}
+
+/// Give `b` secondary-market buyers one share each of a policy
+fn sell_positions<T: Config>(policy_id: PolicyId, b: u32) {
+    // Benchmark quotes are small; make sure every buyer can hold a share
+    Policies::<T>::mutate(policy_id, |policy| {
+        let policy = policy.as_mut().expect("policy exists");
+        policy.shares = policy.shares.max(b as u128 + 1);
+    });
+    let beneficiaries: Vec<(T::AccountId, u128)> =
+        (0..b).map(|i| (account("beneficiary", i, 0), 1)).collect();
+    PolicyBeneficiaries::<T>::insert(
+        policy_id,
+        BoundedVec::try_from(beneficiaries).expect("b is bounded by MaxPolicyBeneficiaries"),
+    );
+}
+
+/// Charge the highest protocol fee on the market of a quote
+fn charge_protocol_fee<T: Config>(quote_id: prmx_primitives::QuoteId) {
+    let market_id = T::QuoteApi::get_quote_request(quote_id)
+        .expect("quote exists")
+        .market_id;
+    ProtocolFeeBp::<T>::insert(market_id, fees::MAX_PROTOCOL_FEE_BP);
+}
+
+/// Installment plan with the most installments
+fn longest_plan() -> InstallmentPlan {
+    InstallmentPlan {
+        installments: installments::MAX_INSTALLMENTS,
+        interval_secs: installments::MIN_INSTALLMENT_INTERVAL_SECS,
+    }
+}
+
+/// Trigger a policy created with an installment plan and move the chain clock
+/// to its second installment
+fn scheduled_payout<T: Config>(holder: &T::AccountId) -> PolicyId {
+    let quote_id = T::BenchmarkHelper::ready_quote(holder, false);
+    Pallet::<T>::apply_coverage_with_installments(
+        RawOrigin::Signed(holder.clone()).into(),
+        quote_id,
+        longest_plan(),
+    )
+    .expect("quote is ready and funded");
+    let policy_id = *Pallet::<T>::policies_of(holder).last().expect("policy was created");
+    Pallet::<T>::do_settle_policy(policy_id, true, SettlementReason::GovernanceForced)
+        .expect("policy is active");
+
+    let schedule = PayoutSchedule::<T>::get(policy_id).expect("payout was deferred");
+    T::BenchmarkHelper::set_timestamp(schedule.start.saturating_add(schedule.plan.interval_secs));
+    policy_id
+}
+
+/// Hold the full payout of an active policy for `holder` as if its claim had
+/// not been verified. The pool already holds the max payout.
+fn held_payout<T: Config>(holder: &T::AccountId) -> PolicyId {
+    let policy_id = active_policy::<T>(holder, false);
+    let policy = Policies::<T>::get(policy_id).expect("policy exists");
+    HeldPayouts::<T>::insert(policy_id, HeldPayoutInfo {
+        claimant: holder.clone(),
+        amount: policy.max_payout,
+        held_at: T::TimeProvider::now_secs(),
+    });
+    policy_id
+}
+
+/// Schedule a policy for `holder` ahead of its coverage start and return its id
+fn scheduled_policy<T: Config>(holder: &T::AccountId) -> PolicyId {
+    let quote_id = T::BenchmarkHelper::ready_quote(holder, false);
+    charge_protocol_fee::<T>(quote_id);
+    Pallet::<T>::apply_coverage_scheduled(RawOrigin::Signed(holder.clone()).into(), quote_id)
+        .expect("coverage starts after the minimum lead time");
+    *Pallet::<T>::policies_of(holder).last().expect("policy was created")
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn apply_coverage_with_quote() {
+        let caller: T::AccountId = whitelisted_caller();
+        let quote_id = T::BenchmarkHelper::ready_quote(&caller, false);
+        charge_protocol_fee::<T>(quote_id);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), quote_id);
+
+        assert_eq!(Pallet::<T>::policies_of(&caller).len(), 1);
+    }
+
+    #[benchmark]
+    fn apply_coverage_with_tiers(t: Linear<1, { <MaxPayoutTiers as Get<u32>>::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let quote_id = T::BenchmarkHelper::ready_quote(&caller, false);
+        charge_protocol_fee::<T>(quote_id);
+        let market_id = T::QuoteApi::get_quote_request(quote_id)
+            .expect("quote exists")
+            .market_id;
+        let strike = T::MarketsApi::strike_value(market_id).expect("market exists");
+        let payout_tiers: BoundedVec<PayoutTier, MaxPayoutTiers> = (0..t)
+            .map(|i| PayoutTier {
+                threshold_mm: strike + i * 10,
+                payout_bp: tiers::FULL_PAYOUT_BP * (i + 1) / t,
+            })
+            .collect::<Vec<_>>()
+            .try_into()
+            .expect("t is bounded by MaxPayoutTiers");
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), quote_id, payout_tiers);
+
+        assert_eq!(Pallet::<T>::policies_of(&caller).len(), 1);
+    }
+
+    #[benchmark]
+    fn settle_policy(b: Linear<0, { T::MaxPolicyBeneficiaries::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let policy_id = active_policy::<T>(&caller, false);
+        sell_positions::<T>(policy_id, b);
+        end_coverage::<T>(policy_id);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), policy_id, true);
+
+        assert!(SettlementResults::<T>::contains_key(policy_id));
+    }
+
+    #[benchmark]
+    fn trigger_immediate_settlement(b: Linear<0, { T::MaxPolicyBeneficiaries::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let policy_id = active_policy::<T>(&caller, false);
+        sell_positions::<T>(policy_id, b);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, policy_id);
+
+        assert!(SettlementResults::<T>::contains_key(policy_id));
+    }
+
+    #[benchmark]
+    fn settle_v2_policy(
+        b: Linear<0, { T::MaxPolicyBeneficiaries::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        let origin =
+            T::V2OracleOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let caller: T::AccountId = whitelisted_caller();
+        let policy_id = active_policy::<T>(&caller, true);
+        sell_positions::<T>(policy_id, b);
+        let policy = Policies::<T>::get(policy_id).expect("policy exists");
+        let strike = policy.strike_mm.unwrap_or_default();
+        end_coverage::<T>(policy_id);
+
+        #[extrinsic_call]
+        _(
+            origin as T::RuntimeOrigin,
+            policy_id,
+            prmx_primitives::V2Outcome::Triggered,
+            policy.coverage_end,
+            strike,
+            [7u8; 32],
+        );
+
+        assert!(V2FinalReport::<T>::contains_key(policy_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn transfer_policy() {
+        let caller: T::AccountId = whitelisted_caller();
+        let policy_id = active_policy::<T>(&caller, false);
+        let new_holder: T::AccountId = account("new_holder", 0, 0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), policy_id, new_holder.clone());
+
+        assert_eq!(Policies::<T>::get(policy_id).expect("policy exists").holder, new_holder);
+    }
+
+    #[benchmark]
+    fn cancel_policy() {
+        let caller: T::AccountId = whitelisted_caller();
+        let policy_id = active_policy::<T>(&caller, false);
+        PolicyCancellationTerms::<T>::put(CancellationTerms {
+            refund_bp: 9_000,
+            allow_during_coverage: false,
+        });
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), policy_id);
+
+        assert_eq!(
+            Policies::<T>::get(policy_id).expect("policy exists").status,
+            PolicyStatus::Cancelled
+        );
+    }
+
+    #[benchmark]
+    fn set_cancellation_terms() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let terms = CancellationTerms { refund_bp: 9_000, allow_during_coverage: true };
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, terms);
+
+        assert_eq!(PolicyCancellationTerms::<T>::get(), terms);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_notional_caps() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let cap: T::Balance = (1_000 * PAYOUT_PER_SHARE).into();
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, Some(cap), Some(cap));
+
+        assert_eq!(MaxNotionalPerMarket::<T>::get(), Some(cap));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn apply_coverage_with_installments() {
+        let caller: T::AccountId = whitelisted_caller();
+        let quote_id = T::BenchmarkHelper::ready_quote(&caller, false);
+        charge_protocol_fee::<T>(quote_id);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), quote_id, longest_plan());
+
+        let policy_id = *Pallet::<T>::policies_of(&caller).last().expect("policy was created");
+        assert!(PolicyInstallmentPlans::<T>::contains_key(policy_id));
+    }
+
+    #[benchmark]
+    fn apply_coverage_with_payout_splits(s: Linear<1, { <MaxPayoutSplits as Get<u32>>::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let quote_id = T::BenchmarkHelper::ready_quote(&caller, false);
+        charge_protocol_fee::<T>(quote_id);
+        let share = splits::FULL_SPLIT_BP / s as u16;
+        let payout_splits: BoundedVec<(T::AccountId, u16), MaxPayoutSplits> = (0..s)
+            .map(|i| {
+                // The last beneficiary takes the rounding remainder
+                let bp = if i + 1 == s {
+                    splits::FULL_SPLIT_BP - share * (s as u16 - 1)
+                } else {
+                    share
+                };
+                (account("split", i, 0), bp)
+            })
+            .collect::<Vec<_>>()
+            .try_into()
+            .expect("s is bounded by MaxPayoutSplits");
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), quote_id, payout_splits);
+
+        let policy_id = *Pallet::<T>::policies_of(&caller).last().expect("policy was created");
+        assert!(PolicyPayoutSplits::<T>::contains_key(policy_id));
+    }
+
+    #[benchmark]
+    fn force_settle_batch(
+        n: Linear<1, { <MaxForceSettleBatch as Get<u32>>::get() }>,
+        b: Linear<0, { T::MaxPolicyBeneficiaries::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let policy_ids: BoundedVec<PolicyId, MaxForceSettleBatch> = (0..n)
+            .map(|i| {
+                let policy_id = active_policy::<T>(&account("holder", i, 0), false);
+                sell_positions::<T>(policy_id, b);
+                policy_id
+            })
+            .collect::<Vec<_>>()
+            .try_into()
+            .expect("n is bounded by MaxForceSettleBatch");
+        let last = *policy_ids.last().expect("n is at least 1");
+
+        #[extrinsic_call]
+        _(
+            origin as T::RuntimeOrigin,
+            policy_ids,
+            prmx_primitives::V2Outcome::Triggered,
+            ForceSettleReason::OracleOutage,
+        );
+
+        assert!(ForcedSettlements::<T>::contains_key(last));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn claim_installment() {
+        let caller: T::AccountId = whitelisted_caller();
+        let policy_id = scheduled_payout::<T>(&caller);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), policy_id);
+
+        let schedule = PayoutSchedule::<T>::get(policy_id).expect("installments remain");
+        assert_eq!(schedule.released_installments, 2);
+    }
+
+    #[benchmark]
+    fn release_installment() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let policy_id = scheduled_payout::<T>(&caller);
+        let now = T::TimeProvider::now_secs();
+
+        #[block]
+        {
+            Pallet::<T>::release_installments(policy_id, now)
+                .map_err(|_| BenchmarkError::Stop("installment release failed"))?;
+        }
+
+        let schedule = PayoutSchedule::<T>::get(policy_id).expect("installments remain");
+        assert_eq!(schedule.released_installments, 2);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_protocol_fee() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let market_id: pallet_prmx_markets::MarketId = 0;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, market_id, fees::MAX_PROTOCOL_FEE_BP);
+
+        assert_eq!(ProtocolFeeBp::<T>::get(market_id), fees::MAX_PROTOCOL_FEE_BP);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn withdraw_treasury() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let caller: T::AccountId = whitelisted_caller();
+        let quote_id = T::BenchmarkHelper::ready_quote(&caller, false);
+        charge_protocol_fee::<T>(quote_id);
+        Pallet::<T>::apply_coverage_with_quote(RawOrigin::Signed(caller).into(), quote_id)
+            .expect("quote is ready and funded");
+        let asset = T::UsdtAssetId::get();
+        let amount = T::Assets::balance(asset, &Pallet::<T>::treasury_account());
+        let recipient: T::AccountId = account("recipient", 0, 0);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, asset, recipient.clone(), amount);
+
+        assert_eq!(T::Assets::balance(asset, &recipient), amount);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn extend_policy() {
+        let caller: T::AccountId = whitelisted_caller();
+        // V2 policies cannot be extended
+        let policy_id = active_policy::<T>(&caller, false);
+        let quote_id = T::BenchmarkHelper::extension_quote(&caller, policy_id);
+        charge_protocol_fee::<T>(quote_id);
+        let new_end = T::QuoteApi::get_quote_request(quote_id)
+            .expect("quote exists")
+            .coverage_end;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), policy_id, new_end, quote_id);
+
+        let policy = Policies::<T>::get(policy_id).expect("policy exists");
+        assert_eq!(policy.coverage_end, new_end);
+    }
+
+    #[benchmark]
+    fn set_claim_verification() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let market_id: pallet_prmx_markets::MarketId = 0;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, market_id, true);
+
+        assert!(ClaimVerificationEnabled::<T>::get(market_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn release_held_payout() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let caller: T::AccountId = whitelisted_caller();
+        let policy_id = held_payout::<T>(&caller);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, policy_id);
+
+        assert!(!HeldPayouts::<T>::contains_key(policy_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn deny_held_payout() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let caller: T::AccountId = whitelisted_caller();
+        let policy_id = held_payout::<T>(&caller);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, policy_id);
+
+        assert!(!HeldPayouts::<T>::contains_key(policy_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn settle_group_policy(
+        b: Linear<0, { T::MaxPolicyBeneficiaries::get() }>,
+        m: Linear<
+            { pallet_prmx_quote::group::MIN_GROUP_MEMBERS },
+            { pallet_prmx_quote::group::MAX_GROUP_MEMBERS },
+        >,
+    ) -> Result<(), BenchmarkError> {
+        let origin =
+            T::V2OracleOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let caller: T::AccountId = whitelisted_caller();
+        let quote_id = T::BenchmarkHelper::group_quote(&caller, m);
+        Pallet::<T>::apply_coverage_with_quote(RawOrigin::Signed(caller.clone()).into(), quote_id)
+            .expect("quote is ready and funded");
+        let policy_id = *Pallet::<T>::policies_of(&caller).last().expect("policy was created");
+        sell_positions::<T>(policy_id, b);
+        let policy = Policies::<T>::get(policy_id).expect("policy exists");
+        // Every member reaches the strike
+        let strike = policy.strike_mm.unwrap_or_default();
+        let observations: BoundedVec<u32, pallet_prmx_quote::MaxGroupMembers> = (0..m)
+            .map(|_| strike)
+            .collect::<Vec<_>>()
+            .try_into()
+            .expect("m is bounded by MaxGroupMembers");
+        end_coverage::<T>(policy_id);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, policy_id, observations, policy.coverage_end, [7u8; 32]);
+
+        assert!(V2FinalReport::<T>::contains_key(policy_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn apply_coverage_scheduled() {
+        let caller: T::AccountId = whitelisted_caller();
+        let quote_id = T::BenchmarkHelper::ready_quote(&caller, false);
+        charge_protocol_fee::<T>(quote_id);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), quote_id);
+
+        let policy_id = *Pallet::<T>::policies_of(&caller).last().expect("policy was created");
+        assert!(CapitalReservations::<T>::contains_key(policy_id));
+    }
+
+    #[benchmark]
+    fn cancel_scheduled_policy() {
+        let caller: T::AccountId = whitelisted_caller();
+        let policy_id = scheduled_policy::<T>(&caller);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), policy_id);
+
+        assert_eq!(
+            Policies::<T>::get(policy_id).expect("policy exists").status,
+            PolicyStatus::Cancelled
+        );
+    }
+
+    #[benchmark]
+    fn activate_scheduled_policy() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let policy_id = scheduled_policy::<T>(&caller);
+        let policy = Policies::<T>::get(policy_id).expect("policy exists");
+        T::BenchmarkHelper::set_timestamp(policy.coverage_start);
+
+        #[block]
+        {
+            Pallet::<T>::activate_scheduled_policy(policy_id)
+                .map_err(|_| BenchmarkError::Stop("activation failed"))?;
+        }
+
+        assert_eq!(
+            Policies::<T>::get(policy_id).expect("policy exists").status,
+            PolicyStatus::Active
+        );
+        Ok(())
+    }
+}
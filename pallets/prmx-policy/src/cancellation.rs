@@ -0,0 +1,94 @@
+//! # Policy Cancellation
+//!
+//! Holders may cancel a policy before its coverage starts and receive a
+//! governance-set fraction of the premium back (net of any protocol fee, see
+//! `fees`). If governance allows it, a
+//! policy can also be cancelled during coverage, with the refund prorated by
+//! the share of the coverage window still remaining.
+//!
+//! Whatever the holder is not refunded stays in the policy pool and is
+//! distributed to LP holders, which returns the DAO's capital to the DAO.
+
+use crate::tiers::FULL_PAYOUT_BP;
+
+/// Governance-set cancellation terms
+#[derive(
+    codec::Encode,
+    codec::Decode,
+    codec::DecodeWithMemTracking,
+    codec::MaxEncodedLen,
+    scale_info::TypeInfo,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Debug,
+    Default,
+)]
+pub struct CancellationTerms {
+    /// Share of the premium refunded, in basis points (10_000 = 100%).
+    /// Nothing is refunded until governance sets terms.
+    pub refund_bp: u32,
+    /// Whether policies may be cancelled after coverage has started
+    pub allow_during_coverage: bool,
+}
+
+impl CancellationTerms {
+    pub fn is_valid(&self) -> bool {
+        self.refund_bp <= FULL_PAYOUT_BP
+    }
+}
+
+/// Premium refunded for a cancellation at `now` (unix seconds), or `None` if
+/// the terms do not allow cancelling at that time.
+pub fn refund_amount(
+    premium: u128,
+    terms: &CancellationTerms,
+    coverage_start: u64,
+    coverage_end: u64,
+    now: u64,
+) -> Option<u128> {
+    let refund = premium.saturating_mul(terms.refund_bp as u128) / FULL_PAYOUT_BP as u128;
+
+    if now < coverage_start {
+        return Some(refund);
+    }
+    if !terms.allow_during_coverage || now >= coverage_end {
+        return None;
+    }
+
+    let remaining = (coverage_end - now) as u128;
+    let duration = coverage_end.saturating_sub(coverage_start) as u128;
+    Some(refund.saturating_mul(remaining) / duration)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const START: u64 = 1_000_000;
+    const END: u64 = START + 86_400;
+
+    #[test]
+    fn test_refund_before_coverage() {
+        let terms = CancellationTerms { refund_bp: 9_000, allow_during_coverage: false };
+        assert_eq!(refund_amount(10_000_000, &terms, START, END, START - 1), Some(9_000_000));
+
+        // Not allowed once coverage has started
+        assert_eq!(refund_amount(10_000_000, &terms, START, END, START), None);
+
+        // Default terms refund nothing
+        let default = CancellationTerms::default();
+        assert_eq!(refund_amount(10_000_000, &default, START, END, 0), Some(0));
+        assert!(!CancellationTerms { refund_bp: 10_001, allow_during_coverage: false }.is_valid());
+    }
+
+    #[test]
+    fn test_refund_prorated_during_coverage() {
+        let terms = CancellationTerms { refund_bp: 10_000, allow_during_coverage: true };
+        assert_eq!(refund_amount(10_000_000, &terms, START, END, START), Some(10_000_000));
+        assert_eq!(refund_amount(10_000_000, &terms, START, END, START + 21_600), Some(7_500_000));
+        assert_eq!(refund_amount(10_000_000, &terms, START, END, END - 1), Some(115));
+        assert_eq!(refund_amount(10_000_000, &terms, START, END, END), None);
+    }
+}
@@ -0,0 +1,57 @@
+//! # Notional Exposure Caps
+//!
+//! Governance can cap the total max payout (notional) of open policies per
+//! market and per location. A location is a 0.1° grid cell around the policy's
+//! coordinates, so V2 policies with custom coordinates in the same market are
+//! grouped by where they actually are.
+//!
+//! Utilization is added when a policy is created and released when it is
+//! settled or cancelled.
+
+/// Grid cell size in coordinate units (coordinates are scaled by 1e6, so 0.1°)
+pub const LOCATION_CELL_SIZE: i32 = 100_000;
+
+/// Grid cell a policy location falls in, as (latitude cell, longitude cell)
+pub type LocationCell = (i32, i32);
+
+/// Grid cell for coordinates scaled by 1e6
+pub fn location_cell(latitude: i32, longitude: i32) -> LocationCell {
+    (
+        latitude.div_euclid(LOCATION_CELL_SIZE),
+        longitude.div_euclid(LOCATION_CELL_SIZE),
+    )
+}
+
+/// Whether adding `additional` notional to `current` stays within `cap`
+/// (no cap means unlimited)
+pub fn within_cap(current: u128, additional: u128, cap: Option<u128>) -> bool {
+    match cap {
+        Some(cap) => current
+            .checked_add(additional)
+            .is_some_and(|total| total <= cap),
+        None => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_location_cell() {
+        // Manila (14.5995, 120.9842)
+        assert_eq!(location_cell(14_599_500, 120_984_200), (145, 1_209));
+        assert_eq!(location_cell(14_500_000, 120_999_999), (145, 1_209));
+        // Southern and western coordinates round down, not towards zero
+        assert_eq!(location_cell(-33_868_800, -70_000_001), (-339, -701));
+        assert_ne!(location_cell(-1, 0), location_cell(0, 0));
+    }
+
+    #[test]
+    fn test_within_cap() {
+        assert!(within_cap(u128::MAX, 1, None));
+        assert!(within_cap(600, 400, Some(1_000)));
+        assert!(!within_cap(600, 401, Some(1_000)));
+        assert!(!within_cap(u128::MAX, 1, Some(u128::MAX)));
+    }
+}
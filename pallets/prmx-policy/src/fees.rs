@@ -0,0 +1,67 @@
+//! # Protocol Fees
+//!
+//! Governance can set a protocol fee per market, in basis points of the premium.
+//! At policy creation the fee is taken from the premium and sent to the pallet's
+//! treasury account; the rest of the premium goes to the policy pool and DAO
+//! capital makes up the difference, so the pool still holds the full max payout.
+//!
+//! The fee is not refunded when a policy is cancelled. Governance withdraws
+//! accumulated fees from the treasury with `withdraw_treasury`.
+
+use crate::tiers::apply_bp;
+
+/// Highest protocol fee governance can set (20% of the premium)
+pub const MAX_PROTOCOL_FEE_BP: u32 = 2_000;
+
+/// How a premium is split between the treasury and the policy pool
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PremiumSplit {
+    /// Protocol fee sent to the treasury
+    pub fee: u128,
+    /// Premium left for the policy pool
+    pub net_premium: u128,
+    /// DAO capital needed to bring the pool up to the max payout
+    pub dao_capital: u128,
+}
+
+/// Split `premium` for a policy paying at most `max_payout` under a fee of `fee_bp`
+pub fn split_premium(premium: u128, max_payout: u128, fee_bp: u32) -> PremiumSplit {
+    let fee = apply_bp(premium, fee_bp);
+    let net_premium = premium.saturating_sub(fee);
+
+    PremiumSplit {
+        fee,
+        net_premium,
+        dao_capital: max_payout.saturating_sub(net_premium),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_premium() {
+        // 10 USDT premium on 100 USDT cover, 5% fee
+        let split = split_premium(10_000_000, 100_000_000, 500);
+        assert_eq!(split.fee, 500_000);
+        assert_eq!(split.net_premium, 9_500_000);
+        assert_eq!(split.dao_capital, 90_500_000);
+        assert_eq!(split.net_premium + split.dao_capital, 100_000_000);
+    }
+
+    #[test]
+    fn test_split_premium_without_fee() {
+        let split = split_premium(10_000_000, 100_000_000, 0);
+        assert_eq!(split.fee, 0);
+        assert_eq!(split.net_premium, 10_000_000);
+        assert_eq!(split.dao_capital, 90_000_000);
+    }
+
+    #[test]
+    fn test_split_premium_rounds_fee_down() {
+        let split = split_premium(999, 10_000, 1);
+        assert_eq!(split.fee, 0);
+        assert_eq!(split.net_premium, 999);
+    }
+}
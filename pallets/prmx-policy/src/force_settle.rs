@@ -0,0 +1,74 @@
+//! # Forced Batch Settlement
+//!
+//! Disaster recovery for when oracle infrastructure is down: governance settles
+//! a batch of policies on an outcome it supplies (`force_settle_batch`). Every
+//! forced settlement carries a reason code, recorded in `ForcedSettlements` and
+//! emitted with `PolicyForceSettled`, so forced settlements can be told apart
+//! from oracle-driven ones.
+//!
+//! Each policy settles in its own storage layer. A policy that cannot settle is
+//! rolled back and reported with `PolicyForceSettleFailed`; the rest of the
+//! batch still settles.
+
+/// Why governance forced a settlement
+#[derive(
+    codec::Encode,
+    codec::Decode,
+    codec::DecodeWithMemTracking,
+    codec::MaxEncodedLen,
+    scale_info::TypeInfo,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Debug,
+)]
+pub enum ForceSettleReason {
+    /// The oracle pallets or their off-chain workers are not reporting
+    OracleOutage,
+    /// The weather data providers are unavailable or serving bad data
+    DataProviderOutage,
+    /// Oracle reports were disputed and governance settled on the reviewed data
+    DisputeResolution,
+    /// A chain incident (halt, runtime bug) prevented normal settlement
+    ChainIncident,
+}
+
+/// Result of a forced settlement batch
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct BatchTally {
+    /// Policies settled
+    pub settled: u32,
+    /// Policies that could not be settled and were rolled back
+    pub failed: u32,
+    /// Payout of the settled policies
+    pub total_payout: u128,
+}
+
+impl BatchTally {
+    pub fn record_settled(&mut self, payout: u128) {
+        self.settled = self.settled.saturating_add(1);
+        self.total_payout = self.total_payout.saturating_add(payout);
+    }
+
+    pub fn record_failed(&mut self) {
+        self.failed = self.failed.saturating_add(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_batch_tally() {
+        let mut tally = BatchTally::default();
+        tally.record_settled(1_000);
+        tally.record_failed();
+        tally.record_settled(0);
+        assert_eq!(tally, BatchTally { settled: 2, failed: 1, total_payout: 1_000 });
+
+        tally.record_settled(u128::MAX);
+        assert_eq!(tally.total_payout, u128::MAX);
+    }
+}
@@ -0,0 +1,89 @@
+//! # Group Policies
+//!
+//! A policy created from a group quote (see `pallet_prmx_quote::group`) covers
+//! several locations and coverage windows in one market as a single policy.
+//! Its members are kept in `PolicyGroupMembers`; the policy's own location is
+//! the first member's and its window spans every member's.
+//!
+//! Group policies settle through `settle_group_policy` instead of a single V2
+//! report: the oracle reports one observation per member, measured over that
+//! member's window at its location. The policy triggers when any member
+//! reaches the strike and pays out once, on the highest triggering
+//! observation (which also selects the payout tier, if any).
+
+use alloc::vec::Vec;
+
+pub use pallet_prmx_quote::group::GroupMember;
+use prmx_primitives::Millimeters;
+
+/// Indices of the members whose observation reached `strike_mm`, in member order
+pub fn triggered_members(strike_mm: Millimeters, observations: &[Millimeters]) -> Vec<u32> {
+    observations
+        .iter()
+        .enumerate()
+        .filter(|(_, observed)| **observed >= strike_mm)
+        .map(|(i, _)| i as u32)
+        .collect()
+}
+
+/// Observation the policy settles on: the highest triggering one, or the
+/// highest overall when no member triggered
+pub fn settlement_observation(observations: &[Millimeters], triggered: &[u32]) -> Millimeters {
+    let triggering = triggered
+        .iter()
+        .filter_map(|i| observations.get(*i as usize))
+        .max();
+    triggering
+        .or_else(|| observations.iter().max())
+        .copied()
+        .unwrap_or(0)
+}
+
+/// Whether `observed_at` lies in the coverage window of a triggered member
+pub fn within_triggered_window(
+    members: &[GroupMember],
+    triggered: &[u32],
+    observed_at: u64,
+) -> bool {
+    triggered
+        .iter()
+        .filter_map(|i| members.get(*i as usize))
+        .any(|member| member.covers(observed_at))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn member(coverage_start: u64, coverage_end: u64) -> GroupMember {
+        GroupMember {
+            latitude: 14_599_500,
+            longitude: 120_984_200,
+            coverage_start,
+            coverage_end,
+        }
+    }
+
+    #[test]
+    fn test_triggered_members_reach_the_strike() {
+        assert_eq!(triggered_members(500, &[120, 500, 730]), vec![1, 2]);
+        assert!(triggered_members(500, &[120, 499]).is_empty());
+    }
+
+    #[test]
+    fn test_settlement_observation() {
+        // Highest triggering observation
+        assert_eq!(settlement_observation(&[450, 500, 730], &[1, 2]), 730);
+        // No member triggered: highest overall
+        assert_eq!(settlement_observation(&[120, 499], &[]), 499);
+        assert_eq!(settlement_observation(&[], &[]), 0);
+    }
+
+    #[test]
+    fn test_observed_at_must_be_in_a_triggered_window() {
+        let members = [member(100, 200), member(300, 400)];
+        assert!(within_triggered_window(&members, &[1], 350));
+        assert!(!within_triggered_window(&members, &[1], 150));
+        assert!(!within_triggered_window(&members, &[], 150));
+    }
+}
@@ -0,0 +1,143 @@
+//! # Deferred Payouts (Structured Settlement)
+//!
+//! At policy creation the holder may choose to receive a triggered payout in
+//! `installments` equal parts, one every `interval_secs`, instead of a lump sum.
+//! The first installment is paid at settlement; the rest stay in the policy pool
+//! and are released as they mature, either by the periodic on-chain sweep or by
+//! the beneficiary calling `claim_installment`. The sweep drains
+//! `InstallmentQueue`, which holds each schedule under the hour its next
+//! installment is due (`release_bucket`), hour by hour from `InstallmentCursor`.
+//!
+//! Only the holder's share is deferred. Secondary-market positions and the LP
+//! remainder of a tiered payout are paid at settlement as usual.
+
+/// Fewest installments in a plan (one would be a lump sum)
+pub const MIN_INSTALLMENTS: u32 = 2;
+
+/// Most installments in a plan
+pub const MAX_INSTALLMENTS: u32 = 24;
+
+/// Shortest interval between installments (1 day)
+pub const MIN_INSTALLMENT_INTERVAL_SECS: u64 = 24 * 3600;
+
+/// Longest interval between installments (90 days)
+pub const MAX_INSTALLMENT_INTERVAL_SECS: u64 = 90 * 24 * 3600;
+
+/// Payout schedule chosen at policy creation
+#[derive(
+    codec::Encode,
+    codec::Decode,
+    codec::DecodeWithMemTracking,
+    codec::MaxEncodedLen,
+    scale_info::TypeInfo,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Debug,
+)]
+pub struct InstallmentPlan {
+    /// Number of equal installments
+    pub installments: u32,
+    /// Seconds between installments
+    pub interval_secs: u64,
+}
+
+impl InstallmentPlan {
+    pub fn is_valid(&self) -> bool {
+        (MIN_INSTALLMENTS..=MAX_INSTALLMENTS).contains(&self.installments)
+            && (MIN_INSTALLMENT_INTERVAL_SECS..=MAX_INSTALLMENT_INTERVAL_SECS)
+                .contains(&self.interval_secs)
+    }
+}
+
+/// Installments matured at `now` for a schedule whose first installment was due
+/// at `start`
+pub fn matured_installments(plan: &InstallmentPlan, start: u64, now: u64) -> u32 {
+    if now < start {
+        return 0;
+    }
+    let elapsed = (now - start) / plan.interval_secs.max(1);
+    elapsed.saturating_add(1).min(plan.installments as u64) as u32
+}
+
+/// When the next installment of a schedule that started at `start` is due, after
+/// `released` have been paid; `None` once all have been
+pub fn next_due(plan: &InstallmentPlan, start: u64, released: u32) -> Option<u64> {
+    (released < plan.installments)
+        .then(|| start.saturating_add(plan.interval_secs.saturating_mul(released as u64)))
+}
+
+/// Width of an `InstallmentQueue` bucket (1 hour)
+pub const RELEASE_BUCKET_SECS: u64 = 3600;
+
+/// `InstallmentQueue` bucket of an installment due at `due`
+pub fn release_bucket(due: u64) -> u64 {
+    due / RELEASE_BUCKET_SECS
+}
+
+/// Cumulative amount due after `matured` of `installments` installments of
+/// `total`. Rounding leftovers are paid with the last installment.
+pub fn cumulative_due(total: u128, installments: u32, matured: u32) -> u128 {
+    if installments == 0 || matured >= installments {
+        return total;
+    }
+    total / installments as u128 * matured as u128
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: u64 = 24 * 3600;
+
+    #[test]
+    fn test_plan_is_valid() {
+        assert!(InstallmentPlan { installments: 12, interval_secs: 30 * DAY }.is_valid());
+        assert!(!InstallmentPlan { installments: 1, interval_secs: 30 * DAY }.is_valid());
+        assert!(!InstallmentPlan { installments: 25, interval_secs: 30 * DAY }.is_valid());
+        assert!(!InstallmentPlan { installments: 12, interval_secs: 3600 }.is_valid());
+        assert!(!InstallmentPlan { installments: 12, interval_secs: 91 * DAY }.is_valid());
+    }
+
+    #[test]
+    fn test_matured_installments() {
+        let plan = InstallmentPlan { installments: 4, interval_secs: 7 * DAY };
+        let start = 1_000_000;
+
+        assert_eq!(matured_installments(&plan, start, start - 1), 0);
+        // The first installment is due at settlement
+        assert_eq!(matured_installments(&plan, start, start), 1);
+        assert_eq!(matured_installments(&plan, start, start + 7 * DAY - 1), 1);
+        assert_eq!(matured_installments(&plan, start, start + 7 * DAY), 2);
+        assert_eq!(matured_installments(&plan, start, start + 365 * DAY), 4);
+    }
+
+    #[test]
+    fn test_next_due_follows_matured_installments() {
+        let plan = InstallmentPlan { installments: 3, interval_secs: 7 * DAY };
+        let start = 1_000_000;
+
+        assert_eq!(next_due(&plan, start, 0), Some(start));
+        assert_eq!(next_due(&plan, start, 1), Some(start + 7 * DAY));
+        assert_eq!(next_due(&plan, start, 2), Some(start + 14 * DAY));
+        assert_eq!(next_due(&plan, start, 3), None);
+        // Due exactly when the installment matures
+        for released in 0..plan.installments {
+            let due = next_due(&plan, start, released).unwrap();
+            assert_eq!(matured_installments(&plan, start, due - 1), released);
+            assert_eq!(matured_installments(&plan, start, due), released + 1);
+        }
+        assert_eq!(release_bucket(start + 7 * DAY), (start + 7 * DAY) / RELEASE_BUCKET_SECS);
+    }
+
+    #[test]
+    fn test_cumulative_due() {
+        assert_eq!(cumulative_due(1_000, 3, 0), 0);
+        assert_eq!(cumulative_due(1_000, 3, 1), 333);
+        assert_eq!(cumulative_due(1_000, 3, 2), 666);
+        // The last installment takes the remainder
+        assert_eq!(cumulative_due(1_000, 3, 3), 1_000);
+        assert_eq!(cumulative_due(1_000, 3, 7), 1_000);
+    }
+}
@@ -8,28 +8,119 @@
 //! - Each policy locks capital (user premium + DAO contribution) in a per-policy pool.
 //! - LP tokens are minted to the DAO when policies are created.
 //! - Policies can be settled based on oracle data.
-//! - Capital can be invested in DeFi (Hydration Pool 102) via CapitalApi integration.
+//! - Capital can be invested in DeFi yield strategies via CapitalApi integration.
+//! - Policies can carry a tiered payout table (`apply_coverage_with_tiers`); the
+//!   oracle reports the maximum observed metric and the payout follows the highest
+//!   tier reached (see `tiers`).
+//! - Holders can `transfer_policy` to another account while coverage is running;
+//!   settlement pays whoever holds the policy at that time.
+//! - Holders can `cancel_policy` before coverage starts (or, if governance allows,
+//!   during coverage) for a partial premium refund (see `cancellation`).
+//! - Holders can sell some or all of a policy's shares on the secondary market
+//!   (policy listings in the orderbook pallet); buyers are paid their share of the
+//!   payout at settlement (see `positions`).
+//! - Governance can cap open notional (max payout) per market and per location;
+//!   policies that would breach a cap are rejected (see `exposure`).
+//! - Policies can be created with an installment plan
+//!   (`apply_coverage_with_installments`); a triggered payout is then released to
+//!   the holder in equal installments instead of a lump sum, by a periodic sweep
+//!   or via `claim_installment` (see `installments`).
+//! - Governance can set a protocol fee per market; it is taken from each premium
+//!   into the pallet's treasury account and withdrawn with `withdraw_treasury`
+//!   (see `fees`).
+//! - A holder's immediate payout can be delivered to another chain through
+//!   `PayoutRouter` (pallet_prmx_xcm_capital delivers it via XCM); otherwise it is
+//!   paid to the holder's account here.
+//! - Holders can `extend_policy` before coverage ends with a quote for the added
+//!   window; its premium goes to the pool for LP holders, and the extended window
+//!   is re-validated against the market's duration and lead-time rules.
+//! - Each policy is denominated in its market's base asset, recorded at creation
+//!   in `PolicyAssets`; premium, pool, fees and payouts all move in that asset.
+//!   Policies created before markets had their own asset use USDT.
+//! - Each policy's derived pool account is recorded at creation in
+//!   `PolicyPoolAccounts`, with the reverse lookup in `PoolAccountPolicies`, so
+//!   explorers and the capital pallet can resolve pool balances without
+//!   re-deriving the account (`PrmxPolicyApi::pool_account_of`).
+//! - Policies can be created with payout splits
+//!   (`apply_coverage_with_payout_splits`); the holder's share of a triggered
+//!   payout is then paid to several beneficiaries in basis points (see `splits`).
+//! - Governance can `force_settle_batch` policies on a supplied outcome when oracle
+//!   infrastructure is down, with a mandatory reason code; each policy settles or
+//!   fails on its own and forced settlements are recorded in `ForcedSettlements`
+//!   (see `force_settle`).
+//! - Settlement runs as a per-policy state machine (Pending → LiquidityEnsured →
+//!   PaidOut → Finalized) recorded in `SettlementProgresses`; a settlement the
+//!   oracle's `on_initialize` sweeps leave part way is resumed from its last
+//!   completed stage with its recorded outcome by `on_initialize`, instead of
+//!   unwinding capital or paying out twice, while a failing extrinsic rolls the
+//!   whole settlement back. Settlement cannot be re-entered while one is running
+//!   (see `settlement`).
+//! - Governance can require claim verification per market
+//!   (`set_claim_verification`); the holder's share of a triggered payout is then
+//!   released only if `ClaimVerifier` approves the claim (e.g. KYC or sanctions
+//!   screening). Otherwise it is held in the policy pool in `HeldPayouts` until
+//!   governance calls `release_held_payout` or `deny_held_payout`.
+//! - Group quotes create group policies covering several locations/windows in one
+//!   market (`PolicyGroupMembers`); `settle_group_policy` takes one observation per
+//!   member and triggers the policy once if any member reaches the strike (see
+//!   `group`).
+//! - Policies can be bought well before coverage starts
+//!   (`apply_coverage_scheduled`); the premium is held in escrow and the DAO
+//!   capital is reserved until a periodic sweep activates the policy at coverage
+//!   start. Before then `cancel_scheduled_policy` refunds the premium in full (see
+//!   `schedule`).
+//! - Each settlement adds to its market's totals in `MarketSettlementStats`
+//!   (policies, triggers, premiums, payouts, highest observed metric), served by
+//!   `PrmxSettlementAnalyticsApi` (see `analytics`).
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 extern crate alloc;
 
+pub mod analytics;
+pub mod cancellation;
+pub mod exposure;
+pub mod fees;
+pub mod force_settle;
+pub mod group;
+pub mod installments;
+pub mod positions;
+pub mod schedule;
+pub mod settlement;
+pub mod splits;
+pub mod tiers;
+pub mod weights;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub use cancellation::CancellationTerms;
+pub use exposure::LocationCell;
+pub use force_settle::ForceSettleReason;
+pub use group::GroupMember;
+pub use installments::InstallmentPlan;
 pub use pallet::*;
+pub use settlement::{SettlementProgress, SettlementStage};
+pub use tiers::PayoutTier;
+pub use weights::WeightInfo;
 
 use alloc::vec::Vec;
 use frame_support::traits::fungibles::{Inspect, Mutate};
 use frame_support::traits::tokens::Preservation;
 use frame_support::traits::Get;
-use pallet_prmx_holdings::HoldingsApi;
+use pallet_prmx_holdings::{HoldingsApi, LpRedemptionPriceApi};
 use pallet_prmx_markets::MarketsAccess;
 use pallet_prmx_quote::QuoteAccess;
 use sp_runtime::DispatchError;
 
-use pallet_prmx_orderbook_lp::LpOrderbookApi;
+use pallet_prmx_orderbook_lp::{LpOrderbookApi, PolicyPositionApi};
+pub use prmx_primitives::PolicyAssetApi;
 
 // Re-export PolicyId from primitives
 pub use prmx_primitives::PolicyId;
-use prmx_primitives::generate_unique_id;
+use prmx_primitives::{generate_unique_id, Millimeters};
+use prmx_time::ChainTime;
+pub use pallet_prmx_oracle::{SettlementReason, SettlementRecord};
 
 // =============================================================================
 //                              Traits
@@ -39,19 +130,22 @@ use prmx_primitives::generate_unique_id;
 ///
 /// This is implemented by pallet_prmx_policy to allow other pallets
 /// (like pallet_prmx_xcm_capital) to derive the on-chain account used
-/// for each policy pool.
+/// for each policy pool, and to look up who currently holds a policy.
 pub trait PolicyPoolAccountApi<AccountId> {
     fn policy_pool_account(policy_id: PolicyId) -> AccountId;
+
+    /// Current holder of a policy, if it exists
+    fn policy_holder(policy_id: PolicyId) -> Option<AccountId>;
 }
 
 /// Capital management API used by pallet_prmx_policy.
 ///
 /// This trait abstracts capital management operations. In v1, it is implemented
-/// by pallet_prmx_xcm_capital to manage DeFi investments (Hydration Pool 102).
+/// by pallet_prmx_xcm_capital to manage DeFi investments across its registered strategies.
 pub trait CapitalApi<AccountId> {
     type Balance;
 
-    /// Allocate capital of a policy into the DeFi strategy (Hydration Pool 102).
+    /// Allocate capital of a policy into the policy's DeFi strategy.
     ///
     /// This is called by a DAO-controlled extrinsic, not by users.
     fn allocate_to_defi(
@@ -80,6 +174,13 @@ pub trait CapitalApi<AccountId> {
     /// Notification that a policy is fully settled.
     /// Implementations can use this to perform any final cleanup.
     fn on_policy_settled(policy_id: PolicyId) -> Result<(), DispatchError>;
+
+    /// Pay the shortfall backstop its share of a new policy's `premium`
+    /// (after the protocol fee). Returns the amount paid.
+    fn fund_backstop(
+        policy_id: PolicyId,
+        premium: Self::Balance,
+    ) -> Result<Self::Balance, DispatchError>;
 }
 
 /// No-op implementation of CapitalApi for when yield management is disabled.
@@ -121,6 +222,132 @@ where
         // No-op: nothing to clean up
         Ok(())
     }
+
+    fn fund_backstop(
+        _policy_id: PolicyId,
+        _premium: Self::Balance,
+    ) -> Result<Self::Balance, DispatchError> {
+        // No-op: no backstop without yield pallet
+        Ok(Default::default())
+    }
+}
+
+/// Reinsurance API used by pallet_prmx_policy.
+///
+/// Lets the DAO cede part of each policy's exposure to a reinsurance pool
+/// (implemented by pallet_prmx_reinsurance). The policy pallet calls these
+/// hooks at creation and settlement.
+pub trait ReinsuranceApi<AccountId> {
+    type Balance;
+
+    /// Cede a share of a new policy's `max_payout`. The DAO pays the matching
+    /// share of `premium` to the reinsurance pool. Returns the ceded exposure.
+    fn on_policy_created(
+        policy_id: PolicyId,
+        premium: Self::Balance,
+        max_payout: Self::Balance,
+    ) -> Result<Self::Balance, DispatchError>;
+
+    /// Pay the reinsured share of a triggered policy's `payout` into the
+    /// policy pool before the holder is paid. Returns the amount paid.
+    fn on_policy_payout(
+        policy_id: PolicyId,
+        pool_account: &AccountId,
+        payout: Self::Balance,
+    ) -> Result<Self::Balance, DispatchError>;
+
+    /// Release any exposure still ceded for a settled or cancelled policy.
+    fn on_policy_closed(policy_id: PolicyId);
+}
+
+/// No-op implementation of ReinsuranceApi for when no exposure is ceded.
+pub struct NoOpReinsurance<Balance>(core::marker::PhantomData<Balance>);
+
+impl<AccountId, Balance> ReinsuranceApi<AccountId> for NoOpReinsurance<Balance>
+where
+    Balance: Default,
+{
+    type Balance = Balance;
+
+    fn on_policy_created(
+        _policy_id: PolicyId,
+        _premium: Self::Balance,
+        _max_payout: Self::Balance,
+    ) -> Result<Self::Balance, DispatchError> {
+        Ok(Balance::default())
+    }
+
+    fn on_policy_payout(
+        _policy_id: PolicyId,
+        _pool_account: &AccountId,
+        _payout: Self::Balance,
+    ) -> Result<Self::Balance, DispatchError> {
+        Ok(Balance::default())
+    }
+
+    fn on_policy_closed(_policy_id: PolicyId) {}
+}
+
+/// Payout delivery API used by pallet_prmx_policy.
+///
+/// Lets a holder have their payout delivered to a beneficiary on another chain
+/// (implemented by pallet_prmx_xcm_capital). The policy pallet offers the
+/// holder's share of every triggered payout it pays immediately.
+pub trait PayoutRouter<AccountId> {
+    type Balance;
+
+    /// Take over the payout of `amount` to `holder` from `pool_account` if the
+    /// holder asked for it to be delivered elsewhere. Returns `false` when the
+    /// policy pallet should pay the holder locally.
+    fn route_payout(
+        policy_id: PolicyId,
+        holder: &AccountId,
+        pool_account: &AccountId,
+        amount: Self::Balance,
+    ) -> Result<bool, DispatchError>;
+}
+
+/// PayoutRouter that leaves every payout to be paid locally.
+pub struct LocalPayoutRouter<Balance>(core::marker::PhantomData<Balance>);
+
+impl<AccountId, Balance> PayoutRouter<AccountId> for LocalPayoutRouter<Balance> {
+    type Balance = Balance;
+
+    fn route_payout(
+        _policy_id: PolicyId,
+        _holder: &AccountId,
+        _pool_account: &AccountId,
+        _amount: Self::Balance,
+    ) -> Result<bool, DispatchError> {
+        Ok(false)
+    }
+}
+
+/// Claim verification API used by pallet_prmx_policy.
+///
+/// Lets integrators check a claimant before a triggered payout is released
+/// (e.g. a KYC attestation or a sanctions allowlist) without changing the
+/// policy pallet. Only consulted for markets where governance enabled claim
+/// verification.
+pub trait ClaimVerifier<AccountId> {
+    /// Whether `claimant` may be paid `amount` from `policy_id` in `market_id`.
+    /// A rejected claim is held for governance to release or deny.
+    fn verify_claim(policy_id: PolicyId, market_id: u64, claimant: &AccountId, amount: u128)
+        -> bool;
+}
+
+/// ClaimVerifier that approves every claim.
+pub struct NoOpClaimVerifier;
+
+impl<AccountId> ClaimVerifier<AccountId> for NoOpClaimVerifier {
+    fn verify_claim(
+        _policy_id: PolicyId,
+        _market_id: u64,
+        _claimant: &AccountId,
+        _amount: u128,
+    ) -> bool {
+        true
+    }
 }
 
 /// Stub implementation for when orderbook is not yet implemented
@@ -145,9 +372,10 @@ impl<AccountId, Balance> LpOrderbookApi<AccountId, Balance> for StubLpOrderbook<
 pub mod pallet {
     use super::*;
     use frame_support::pallet_prelude::*;
-    use frame_support::traits::Time;
     use frame_system::pallet_prelude::*;
     use pallet_prmx_markets::MarketId;
+    use pallet_prmx_oracle::OracleStatusApi;
+    use prmx_primitives::{PausableOperation, PauseApi};
     use sp_runtime::traits::{AccountIdConversion, Zero};
 
     // =========================================================================
@@ -165,6 +393,9 @@ pub mod pallet {
         Expired,
         Settled,
         Cancelled,
+        /// Bought ahead of coverage start; premium in escrow and DAO capital
+        /// reserved until activation (see `schedule`)
+        Scheduled,
     }
 
     /// Settlement result for a policy
@@ -173,9 +404,12 @@ pub mod pallet {
     pub struct SettlementResult<T: Config> {
         /// Whether the rainfall event occurred (exceeded strike threshold)
         pub event_occurred: bool,
-        /// Amount paid out to policy holder (0 if no event)
+        /// Amount paid out to policy holder and secondary-market positions (0 if no
+        /// event), including any share deferred into a `PayoutSchedule` or held in
+        /// `HeldPayouts`
         pub payout_to_holder: T::Balance,
-        /// Amount returned to LP holders (0 if event occurred)
+        /// Amount returned to LP holders (0 if the event occurred, unless a tiered
+        /// payout paid less than the pool)
         pub returned_to_lps: T::Balance,
         /// Timestamp of settlement (unix seconds)
         pub settled_at: u64,
@@ -189,6 +423,7 @@ pub mod pallet {
         /// Human-readable label like "manila-1", "tokyo-2", etc.
         pub policy_label: BoundedVec<u8, ConstU32<32>>,
         pub market_id: MarketId,
+        /// Current owner; receives the payout. Changes on `transfer_policy`.
         pub holder: T::AccountId,
         pub coverage_start: u64,    // unix seconds
         pub coverage_end: u64,      // unix seconds
@@ -205,6 +440,66 @@ pub mod pallet {
         pub early_trigger: bool,
         pub oracle_status_v2: Option<prmx_primitives::V2OracleStatus>,
         pub strike_mm: Option<u32>,
+        /// Tiered payout table; empty for all-or-nothing settlement on the strike
+        pub payout_tiers: BoundedVec<PayoutTier, MaxPayoutTiers>,
+    }
+
+    /// Maximum tiers in a policy's payout table
+    pub type MaxPayoutTiers = ConstU32<8>;
+
+    /// Maximum beneficiaries in a policy's payout splits
+    pub type MaxPayoutSplits = ConstU32<8>;
+
+    /// Maximum policies in one `force_settle_batch`
+    pub type MaxForceSettleBatch = ConstU32<32>;
+
+    /// Holder's share of a triggered payout held for governance after the claim
+    /// was not verified
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct HeldPayoutInfo<T: Config> {
+        /// Holder at settlement; receives the payout if it is released
+        pub claimant: T::AccountId,
+        /// Amount held in the policy pool
+        pub amount: T::Balance,
+        /// Settlement time (unix seconds)
+        pub held_at: u64,
+    }
+
+    /// Premium and DAO capital that fund a policy's pool. Kept in
+    /// `CapitalReservations` until a scheduled policy is activated.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct PolicyFunding<T: Config> {
+        /// Denomination asset of the policy
+        pub asset: T::AssetId,
+        /// Premium paid by the holder, protocol fee included
+        pub premium: T::Balance,
+        /// Protocol fee taken from the premium into the treasury
+        pub protocol_fee: T::Balance,
+        /// DAO capital transferred to the pool
+        pub dao_capital: T::Balance,
+        /// DAO capital per share, the price of the DAO LP ask
+        pub dao_capital_per_share: T::Balance,
+        /// Coverage start (unix seconds); a scheduled policy is activated from then
+        pub activates_at: u64,
+    }
+
+    /// Installment payout of a triggered policy (see `installments`)
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct PayoutScheduleInfo<T: Config> {
+        /// Holder at settlement; receives every installment
+        pub beneficiary: T::AccountId,
+        /// Holder's share of the payout, held in the policy pool until released
+        pub total: T::Balance,
+        /// Amount released so far
+        pub released: T::Balance,
+        /// Installments released so far
+        pub released_installments: u32,
+        pub plan: InstallmentPlan,
+        /// Settlement time (unix seconds); the first installment is due then
+        pub start: u64,
     }
 
     // =========================================================================
@@ -220,15 +515,38 @@ pub mod pallet {
     /// Payout per share in smallest units (100 * 10^6 = 100_000_000)
     pub const PAYOUT_PER_SHARE: u128 = PAYOUT_PER_SHARE_USDT * 10u128.pow(USDT_DECIMALS);
 
-    /// Pallet ID for generating derived accounts
-    pub const PALLET_ID: frame_support::PalletId = frame_support::PalletId(*b"prmxplcy");
+    /// Blocks between sweeps releasing matured installments
+    pub const BLOCKS_PER_INSTALLMENT_CHECK: u32 = 100;
+
+    /// Maximum payout schedules released per sweep
+    pub const MAX_INSTALLMENT_RELEASES_PER_BLOCK: u32 = 10;
+
+    /// Maximum `InstallmentQueue` buckets the installment sweep advances over
+    pub const MAX_INSTALLMENT_BUCKETS_PER_BLOCK: u32 = 10;
+
+    /// Blocks between sweeps activating scheduled policies
+    pub const BLOCKS_PER_ACTIVATION_CHECK: u32 = 10;
+
+    /// Maximum scheduled policies activated per sweep
+    pub const MAX_ACTIVATIONS_PER_BLOCK: u32 = 10;
+
+    /// Maximum `ActivationQueue` buckets the activation sweep advances over
+    pub const MAX_ACTIVATION_BUCKETS_PER_BLOCK: u32 = 10;
+
+    /// Blocks between sweeps resuming settlements left part way
+    pub const BLOCKS_PER_SETTLEMENT_RESUME: u32 = 10;
+
+    /// Maximum part-way settlements resumed per sweep
+    pub const MAX_SETTLEMENT_RESUMES_PER_BLOCK: u32 = 5;
+
+    /// Pallet ID for generating derived accounts: frame_support::PalletId = frame_support::PalletId(*b"prmxplcy");
 
     // =========================================================================
     //                                  Config
     // =========================================================================
 
     #[pallet::config]
-    pub trait Config: frame_system::Config + pallet_timestamp::Config {
+    pub trait Config: frame_system::Config {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
         /// Balance type
@@ -237,11 +555,11 @@ pub mod pallet {
         /// Asset ID type
         type AssetId: Parameter + Member + Copy + Default + MaxEncodedLen;
 
-        /// Fungibles implementation for USDT transfers
+        /// Fungibles implementation for premium, pool and payout transfers
         type Assets: Mutate<Self::AccountId, AssetId = Self::AssetId, Balance = Self::Balance>
             + Inspect<Self::AccountId>;
 
-        /// USDT asset ID
+        /// USDT asset ID, the denomination of policies without a `PolicyAssets` entry
         #[pallet::constant]
         type UsdtAssetId: Get<Self::AssetId>;
 
@@ -266,16 +584,71 @@ pub mod pallet {
         #[pallet::constant]
         type MaxPoliciesPerMarket: Get<u32>;
 
-        /// Capital management API for DeFi yield strategy integration (Hydration Pool 102).
+        /// Maximum accounts holding shares of a policy bought on the secondary market
+        #[pallet::constant]
+        type MaxPolicyBeneficiaries: Get<u32>;
+
+        /// Capital management API for DeFi yield strategy integration.
         /// Use NoOpCapitalApi if yield management is not enabled.
         type CapitalApi: CapitalApi<Self::AccountId, Balance = Self::Balance>;
 
+        /// Reinsurance hooks for ceding part of each policy's exposure.
+        /// Use NoOpReinsurance if no reinsurance pool is configured.
+        type Reinsurance: ReinsuranceApi<Self::AccountId, Balance = Self::Balance>;
+
+        /// Delivery of holder payouts to other chains (use LocalPayoutRouter to
+        /// pay every payout on this chain)
+        type PayoutRouter: PayoutRouter<Self::AccountId, Balance = Self::Balance>;
+
+        /// Check of claimants in markets with claim verification enabled
+        /// (use NoOpClaimVerifier to approve every claim)
+        type ClaimVerifier: ClaimVerifier<Self::AccountId>;
+
         /// Access to markets pallet for market name lookup (used for policy labels)
-        type MarketsApi: pallet_prmx_markets::MarketsAccess<Balance = Self::Balance>;
+        /// and each market's denomination asset
+        type MarketsApi: pallet_prmx_markets::MarketsAccess<
+            Balance = Self::Balance,
+            AssetId = Self::AssetId,
+        >;
 
         /// Origin that can submit V2 oracle reports.
         /// Only authorized accounts/origins can settle V2 policies.
         type V2OracleOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Origin that can set policy cancellation terms.
+        type GovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Oracle liveness, used to pause new policies on markets without recent data
+        type OracleStatus: pallet_prmx_oracle::OracleStatusApi;
+
+        /// Emergency pause flags for policy creation and settlement
+        type Pause: PauseApi;
+
+        /// Source of chain time for coverage windows, settlement and payout schedules
+        type TimeProvider: ChainTime;
+
+        /// Weight info for extrinsics
+        type WeightInfo: WeightInfo;
+
+        /// Sets up quotes and balances for benchmarks
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<Self::AccountId>;
+    }
+
+    /// Benchmark setup that needs other pallets
+    #[cfg(feature = "runtime-benchmarks")]
+    pub trait BenchmarkHelper<AccountId> {
+        /// Create a ready quote requested by `requester` (V2 when `v2` is set) and
+        /// fund the requester's premium and the DAO capital for the policy
+        fn ready_quote(requester: &AccountId, v2: bool) -> prmx_primitives::QuoteId;
+        /// Create a ready quote requested by `requester` on the terms of `policy_id`,
+        /// covering two days from its coverage end, and fund the premium
+        fn extension_quote(requester: &AccountId, policy_id: PolicyId) -> prmx_primitives::QuoteId;
+        /// Create a ready group quote of `members` members requested by `requester`
+        /// and fund the premium and the DAO capital for the policy
+        fn group_quote(requester: &AccountId, members: u32) -> prmx_primitives::QuoteId;
+        /// Set the time returned by `TimeProvider`
+        fn set_timestamp(now_secs: u64);
     }
 
     // =========================================================================
@@ -334,6 +707,33 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// Settlement totals per market (see `analytics`)
+    #[pallet::storage]
+    #[pallet::getter(fn market_settlement_stats)]
+    pub type MarketSettlementStats<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        MarketId,
+        prmx_primitives::MarketSettlementStats<T::Balance>,
+        ValueQuery,
+    >;
+
+    /// Why each settled policy settled and the oracle data behind it
+    #[pallet::storage]
+    #[pallet::getter(fn settlement_records)]
+    pub type SettlementRecords<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        PolicyId,
+        SettlementRecord,
+        OptionQuery,
+    >;
+
+    /// Premium refund terms for `cancel_policy`, set by governance.
+    #[pallet::storage]
+    #[pallet::getter(fn cancellation_terms)]
+    pub type PolicyCancellationTerms<T: Config> = StorageValue<_, CancellationTerms, ValueQuery>;
+
     /// Per-market policy counter for generating sequential labels.
     /// Each market has its own counter starting from 0.
     /// Used to generate labels like "manila-1", "tokyo-2", etc.
@@ -358,741 +758,3290 @@ pub mod pallet {
         OptionQuery,
     >;
 
-    // =========================================================================
-    //                                  Events
-    // =========================================================================
+    /// Shares of a policy sold on the secondary market, by account. The holder
+    /// owns the remaining shares.
+    #[pallet::storage]
+    #[pallet::getter(fn policy_beneficiaries)]
+    pub type PolicyBeneficiaries<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        PolicyId,
+        BoundedVec<(T::AccountId, u128), T::MaxPolicyBeneficiaries>,
+        ValueQuery,
+    >;
 
-    #[pallet::event]
-    #[pallet::generate_deposit(pub(super) fn deposit_event)]
-    pub enum Event<T: Config> {
-        /// Policy created. [policy_id, market_id, holder, shares]
-        PolicyCreated {
-            policy_id: PolicyId,
-            market_id: MarketId,
-            holder: T::AccountId,
-            shares: u128,
-        },
-        /// Policy capital locked. [policy_id, user_premium, dao_capital]
-        CapitalLocked {
-            policy_id: PolicyId,
-            user_premium: T::Balance,
-            dao_capital: T::Balance,
-        },
-        /// LP tokens minted to DAO for a specific policy. [policy_id, shares]
-        LpTokensMinted {
-            policy_id: PolicyId,
-            shares: u128,
-        },
-        /// DAO LP ask placed for a specific policy's LP tokens. [policy_id, price_per_share, quantity]
-        DaoLpAskPlaced {
-            policy_id: PolicyId,
-            price_per_share: T::Balance,
-            quantity: u128,
-        },
-        /// Policy settled. [policy_id, payout_to_holder]
-        PolicySettled {
-            policy_id: PolicyId,
-            payout_to_holder: T::Balance,
-        },
-        /// Policy expired (no payout). [policy_id, residual_to_pool]
-        PolicyExpiredNoEvent {
-            policy_id: PolicyId,
-            residual_to_pool: T::Balance,
-        },
-        /// V2 policy created - emitted for off-chain oracle to pick up.
-        V2PolicyCreated {
-            policy_id: PolicyId,
-            market_id: MarketId,
-            coverage_start: u64,
-            coverage_end: u64,
-            strike_mm: u32,
-            latitude: i32,
-            longitude: i32,
-        },
-        /// V2 policy settled by off-chain oracle report.
-        V2PolicySettled {
-            policy_id: PolicyId,
-            outcome: prmx_primitives::V2Outcome,
-            cumulative_mm: u32,
-            evidence_hash: [u8; 32],
-        },
-    }
+    /// Governance cap on open notional (sum of max payouts) per market; `None` is uncapped.
+    #[pallet::storage]
+    #[pallet::getter(fn max_notional_per_market)]
+    pub type MaxNotionalPerMarket<T: Config> = StorageValue<_, T::Balance, OptionQuery>;
 
-    // =========================================================================
-    //                                  Errors
-    // =========================================================================
+    /// Governance cap on open notional per location cell; `None` is uncapped.
+    #[pallet::storage]
+    #[pallet::getter(fn max_notional_per_location)]
+    pub type MaxNotionalPerLocation<T: Config> = StorageValue<_, T::Balance, OptionQuery>;
 
-    #[pallet::error]
-    pub enum Error<T> {
-        /// Policy not found.
-        PolicyNotFound,
-        /// Quote not found.
-        QuoteNotFound,
-        /// Quote not ready.
-        QuoteNotReady,
-        /// Quote expired.
-        QuoteExpired,
-        /// Unauthorized (not the quote requester).
-        Unauthorized,
-        /// Policy already settled.
-        PolicyAlreadySettled,
-        /// Coverage window not ended.
-        CoverageNotEnded,
-        /// Insufficient funds.
-        InsufficientFunds,
-        /// Insufficient DAO capital.
-        InsufficientDaoCapital,
-        /// Arithmetic overflow.
-        ArithmeticOverflow,
-        /// Transfer failed.
-        TransferFailed,
-        /// Not a V2 policy.
-        NotV2Policy,
-        /// V2 report already submitted for this policy.
-        V2ReportAlreadySubmitted,
-        /// Invalid V2 observed_at timestamp.
-        InvalidObservedAt,
-        /// V2 threshold not met for triggered claim.
-        ThresholdNotMet,
-        /// V2 policy not active.
-        V2PolicyNotActive,
-    }
+    /// Open notional per market, counted against `MaxNotionalPerMarket`.
+    #[pallet::storage]
+    #[pallet::getter(fn market_notional)]
+    pub type MarketNotional<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        MarketId,
+        T::Balance,
+        ValueQuery,
+    >;
 
-    // =========================================================================
-    //                                Extrinsics
-    // =========================================================================
+    /// Open notional per location cell, counted against `MaxNotionalPerLocation`.
+    #[pallet::storage]
+    #[pallet::getter(fn location_notional)]
+    pub type LocationNotional<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        LocationCell,
+        T::Balance,
+        ValueQuery,
+    >;
 
-    #[pallet::call]
-    impl<T: Config> Pallet<T> {
-        /// Apply for coverage using a previously obtained quote.
-        /// 
-        /// This will:
-        /// 1. Create a policy from the quote.
-        /// 2. Lock capital (user premium + DAO contribution).
-        /// 3. Mint LP tokens to DAO.
-        /// 4. Place DAO LP ask on orderbook.
-        #[pallet::call_index(0)]
-        #[pallet::weight(100_000)]
-        pub fn apply_coverage_with_quote(
-            origin: OriginFor<T>,
-            quote_id: prmx_primitives::QuoteId,
-        ) -> DispatchResult {
-            let who = ensure_signed(origin)?;
+    /// Installment plans chosen at policy creation, by policy ID. Removed when
+    /// the policy settles (moving to `PayoutSchedule` if it triggered) or is
+    /// cancelled; kept with a held payout until it is released or denied.
+    #[pallet::storage]
+    #[pallet::getter(fn installment_plan)]
+    pub type PolicyInstallmentPlans<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        PolicyId,
+        InstallmentPlan,
+        OptionQuery,
+    >;
 
-            // Load quote request and result
-            let req = T::QuoteApi::get_quote_request(quote_id)
-                .ok_or(Error::<T>::QuoteNotFound)?;
-            let res = T::QuoteApi::get_quote_result(quote_id)
-                .ok_or(Error::<T>::QuoteNotReady)?;
+    /// Payout splits chosen at policy creation (beneficiary, basis points), by
+    /// policy ID. Removed when the policy settles or is cancelled; kept with a
+    /// held payout until it is released or denied.
+    #[pallet::storage]
+    #[pallet::getter(fn payout_splits)]
+    pub type PolicyPayoutSplits<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        PolicyId,
+        BoundedVec<(T::AccountId, u16), MaxPayoutSplits>,
+        OptionQuery,
+    >;
 
-            // Verify the caller is the quote requester
+    /// Reason code of each policy settled by `force_settle_batch`, by policy ID.
+    #[pallet::storage]
+    #[pallet::getter(fn forced_settlement)]
+    pub type ForcedSettlements<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        PolicyId,
+        ForceSettleReason,
+        OptionQuery,
+    >;
+
+    /// Stage reached by each policy's settlement, with the outcome and amounts
+    /// needed to resume it. Removed once the settlement reaches `Finalized`;
+    /// `SettlementResults` and `SettlementRecords` keep the outcome.
+    #[pallet::storage]
+    #[pallet::getter(fn settlement_progress)]
+    pub type SettlementProgresses<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        PolicyId,
+        SettlementProgress<T::Balance, SettlementRecord>,
+        OptionQuery,
+    >;
+
+    /// Last `SettlementProgresses` key the resume sweep visited; it continues
+    /// after it in the next sweep. Empty once the sweep wrapped around.
+    #[pallet::storage]
+    pub type SettlementResumeCursor<T: Config> = StorageValue<_, PolicyId, OptionQuery>;
+
+    /// Policy whose settlement is running, guarding against nested settlement
+    /// from cross-pallet calls. Empty between settlements.
+    #[pallet::storage]
+    pub type ActiveSettlement<T: Config> = StorageValue<_, PolicyId, OptionQuery>;
+
+    /// Outstanding installment payouts of triggered policies, by policy ID.
+    /// Removed when the last installment is released.
+    #[pallet::storage]
+    #[pallet::getter(fn payout_schedule)]
+    pub type PayoutSchedule<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        PolicyId,
+        PayoutScheduleInfo<T>,
+        OptionQuery,
+    >;
+
+    /// Payout schedules by `installments::release_bucket` of their next
+    /// installment's due time, with the due time itself as value. Drained by the
+    /// installment sweep.
+    #[pallet::storage]
+    pub type InstallmentQueue<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        u64,
+        Blake2_128Concat,
+        PolicyId,
+        u64,
+        OptionQuery,
+    >;
+
+    /// Earliest `InstallmentQueue` bucket the installment sweep has not
+    /// finished. Empty until the first payout is deferred.
+    #[pallet::storage]
+    pub type InstallmentCursor<T: Config> = StorageValue<_, u64, OptionQuery>;
+
+    /// Markets whose triggered payouts are released only to claimants approved
+    /// by `ClaimVerifier`, set by governance.
+    #[pallet::storage]
+    #[pallet::getter(fn claim_verification_enabled)]
+    pub type ClaimVerificationEnabled<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        MarketId,
+        bool,
+        ValueQuery,
+    >;
+
+    /// Payouts held in the policy pool after the claim was not verified, by
+    /// policy ID. Removed when governance releases or denies the payout.
+    #[pallet::storage]
+    #[pallet::getter(fn held_payout)]
+    pub type HeldPayouts<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        PolicyId,
+        HeldPayoutInfo<T>,
+        OptionQuery,
+    >;
+
+    /// Protocol fee per market in basis points of the premium, set by governance.
+    #[pallet::storage]
+    #[pallet::getter(fn protocol_fee_bp)]
+    pub type ProtocolFeeBp<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        MarketId,
+        u32,
+        ValueQuery,
+    >;
+
+    /// Protocol fees collected into the treasury per market, over all time.
+    #[pallet::storage]
+    #[pallet::getter(fn collected_fees)]
+    pub type CollectedFees<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        MarketId,
+        T::Balance,
+        ValueQuery,
+    >;
+
+    /// Protocol fee taken from each open policy's premium; not refunded on
+    /// cancellation. Removed when the policy settles or is cancelled.
+    #[pallet::storage]
+    #[pallet::getter(fn policy_protocol_fee)]
+    pub type PolicyProtocolFees<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        PolicyId,
+        T::Balance,
+        ValueQuery,
+    >;
+
+    /// Denomination asset per policy, the market's base asset at creation.
+    /// Policies created before markets had their own asset have no entry and
+    /// are denominated in USDT.
+    #[pallet::storage]
+    #[pallet::getter(fn policy_assets)]
+    pub type PolicyAssets<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        PolicyId,
+        T::AssetId,
+        OptionQuery,
+    >;
+
+    /// Pool account derived for each policy, recorded at creation. Policies
+    /// created before the map have no entry; their account is derived on lookup.
+    #[pallet::storage]
+    #[pallet::getter(fn policy_pool_accounts)]
+    pub type PolicyPoolAccounts<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        PolicyId,
+        T::AccountId,
+        OptionQuery,
+    >;
+
+    /// Policy owning each recorded pool account (reverse of `PolicyPoolAccounts`)
+    #[pallet::storage]
+    #[pallet::getter(fn pool_account_policies)]
+    pub type PoolAccountPolicies<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        PolicyId,
+        OptionQuery,
+    >;
+
+    /// Members of policies created from a group quote (see `group`)
+    #[pallet::storage]
+    #[pallet::getter(fn policy_group_members)]
+    pub type PolicyGroupMembers<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        PolicyId,
+        BoundedVec<GroupMember, pallet_prmx_quote::MaxGroupMembers>,
+        OptionQuery,
+    >;
+
+    /// Reservation ledger of scheduled policies: escrowed premium and reserved
+    /// DAO capital by policy ID (see `schedule`). Removed at activation or
+    /// cancellation.
+    #[pallet::storage]
+    #[pallet::getter(fn capital_reservation)]
+    pub type CapitalReservations<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        PolicyId,
+        PolicyFunding<T>,
+        OptionQuery,
+    >;
+
+    /// Scheduled policies by `schedule::activation_bucket` of their coverage
+    /// start, with the start itself as value. Drained by the activation sweep.
+    #[pallet::storage]
+    pub type ActivationQueue<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        u64,
+        Blake2_128Concat,
+        PolicyId,
+        u64,
+        OptionQuery,
+    >;
+
+    /// Earliest `ActivationQueue` bucket the activation sweep has not finished.
+    /// Empty until the first policy is scheduled.
+    #[pallet::storage]
+    pub type ActivationCursor<T: Config> = StorageValue<_, u64, OptionQuery>;
+
+    /// DAO capital reserved for scheduled policies per asset, the sum of
+    /// `CapitalReservations`; not available to other new policies.
+    #[pallet::storage]
+    #[pallet::getter(fn reserved_dao_capital)]
+    pub type ReservedDaoCapital<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AssetId,
+        T::Balance,
+        ValueQuery,
+    >;
+
+    // =========================================================================
+    //                                  Events
+    // =========================================================================
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Policy created. [policy_id, market_id, holder, shares]
+        PolicyCreated {
+            policy_id: PolicyId,
+            market_id: MarketId,
+            holder: T::AccountId,
+            shares: u128,
+        },
+        /// Policy capital locked. The pool receives `user_premium - protocol_fee`
+        /// plus `dao_capital`. [policy_id, user_premium, protocol_fee, dao_capital]
+        CapitalLocked {
+            policy_id: PolicyId,
+            user_premium: T::Balance,
+            protocol_fee: T::Balance,
+            dao_capital: T::Balance,
+        },
+        /// LP tokens minted to DAO for a specific policy. [policy_id, shares]
+        LpTokensMinted {
+            policy_id: PolicyId,
+            shares: u128,
+        },
+        /// DAO LP ask placed for a specific policy's LP tokens. [policy_id, price_per_share, quantity]
+        DaoLpAskPlaced {
+            policy_id: PolicyId,
+            price_per_share: T::Balance,
+            quantity: u128,
+        },
+        /// Policy settled. [policy_id, payout_to_holder]
+        PolicySettled {
+            policy_id: PolicyId,
+            payout_to_holder: T::Balance,
+        },
+        /// Policy settled, with the reason and the data it settled on (see `SettlementRecords`).
+        /// Emitted for every settlement alongside `PolicySettled` or `PolicyExpiredNoEvent`.
+        PolicySettledV2 {
+            policy_id: PolicyId,
+            event_occurred: bool,
+            payout_to_holder: T::Balance,
+            reason: SettlementReason,
+            observed_mm: Option<Millimeters>,
+            providers: BoundedVec<pallet_prmx_oracle::WeatherProvider, ConstU32<{ pallet_prmx_oracle::MAX_WEATHER_PROVIDERS }>>,
+            evidence_hash: [u8; 32],
+        },
+        /// A policy's settlement completed a stage (see `SettlementProgresses`).
+        /// [policy_id, stage]
+        SettlementStageAdvanced {
+            policy_id: PolicyId,
+            stage: SettlementStage,
+        },
+        /// Policy expired (no payout). [policy_id, residual_to_pool]
+        PolicyExpiredNoEvent {
+            policy_id: PolicyId,
+            residual_to_pool: T::Balance,
+        },
+        /// V2 policy created - emitted for off-chain oracle to pick up.
+        /// `event_type` tells the oracle whether to measure cumulative rainfall, 24-hour
+        /// snowfall or 24-hour rainfall at river flood stage against `strike_mm`.
+        V2PolicyCreated {
+            policy_id: PolicyId,
+            market_id: MarketId,
+            coverage_start: u64,
+            coverage_end: u64,
+            strike_mm: u32,
+            latitude: i32,
+            longitude: i32,
+            event_type: prmx_primitives::EventType,
+        },
+        /// Policy coverage extended with a quote for the added window. The pool receives
+        /// `premium - protocol_fee`. [policy_id, previous_end, new_end, premium, protocol_fee]
+        PolicyExtended {
+            policy_id: PolicyId,
+            previous_end: u64,
+            new_end: u64,
+            premium: T::Balance,
+            protocol_fee: T::Balance,
+        },
+        /// Policy cancelled by its holder. [policy_id, holder, refund, returned_to_lps]
+        PolicyCancelled {
+            policy_id: PolicyId,
+            holder: T::AccountId,
+            refund: T::Balance,
+            returned_to_lps: T::Balance,
+        },
+        /// Cancellation terms updated by governance.
+        CancellationTermsSet {
+            refund_bp: u32,
+            allow_during_coverage: bool,
+        },
+        /// Policy ownership transferred. [policy_id, from, to]
+        PolicyTransferred {
+            policy_id: PolicyId,
+            from: T::AccountId,
+            to: T::AccountId,
+        },
+        /// V2 policy settled by off-chain oracle report.
+        V2PolicySettled {
+            policy_id: PolicyId,
+            outcome: prmx_primitives::V2Outcome,
+            cumulative_mm: u32,
+            evidence_hash: [u8; 32],
+        },
+        /// Policy shares moved to another account. [policy_id, from, to, shares]
+        PositionTransferred {
+            policy_id: PolicyId,
+            from: T::AccountId,
+            to: T::AccountId,
+            shares: u128,
+        },
+        /// Share of a payout paid to a secondary-market position. [policy_id, beneficiary, amount]
+        PositionPaid {
+            policy_id: PolicyId,
+            beneficiary: T::AccountId,
+            amount: T::Balance,
+        },
+        /// Notional caps updated by governance.
+        NotionalCapsSet {
+            max_per_market: Option<T::Balance>,
+            max_per_location: Option<T::Balance>,
+        },
+        /// Holder's share of a triggered payout deferred into installments.
+        /// [policy_id, beneficiary, total, installments, interval_secs]
+        PayoutScheduled {
+            policy_id: PolicyId,
+            beneficiary: T::AccountId,
+            total: T::Balance,
+            installments: u32,
+            interval_secs: u64,
+        },
+        /// Matured installments paid out. [policy_id, beneficiary, amount, remaining]
+        InstallmentReleased {
+            policy_id: PolicyId,
+            beneficiary: T::AccountId,
+            amount: T::Balance,
+            remaining: T::Balance,
+        },
+        /// Protocol fee for a market updated by governance.
+        ProtocolFeeSet {
+            market_id: MarketId,
+            fee_bp: u32,
+        },
+        /// Fees withdrawn from the treasury by governance. [asset, to, amount]
+        TreasuryWithdrawn {
+            asset: T::AssetId,
+            to: T::AccountId,
+            amount: T::Balance,
+        },
+        /// Payout splits recorded for a new policy. [policy_id, splits]
+        PayoutSplitsSet {
+            policy_id: PolicyId,
+            splits: BoundedVec<(T::AccountId, u16), MaxPayoutSplits>,
+        },
+        /// Split of the holder's payout paid to a beneficiary. [policy_id, beneficiary, amount]
+        PayoutSplitPaid {
+            policy_id: PolicyId,
+            beneficiary: T::AccountId,
+            amount: T::Balance,
+        },
+        /// Governance forced a policy's settlement. Emitted after the policy's normal
+        /// settlement events. [policy_id, outcome, reason_code, payout]
+        PolicyForceSettled {
+            policy_id: PolicyId,
+            outcome: prmx_primitives::V2Outcome,
+            reason_code: ForceSettleReason,
+            payout: T::Balance,
+        },
+        /// A policy in a forced batch could not be settled; its changes were rolled
+        /// back. [policy_id, reason_code, error]
+        PolicyForceSettleFailed {
+            policy_id: PolicyId,
+            reason_code: ForceSettleReason,
+            error: DispatchError,
+        },
+        /// Forced settlement batch processed. [reason_code, settled, failed, total_payout]
+        ForceSettleBatchCompleted {
+            reason_code: ForceSettleReason,
+            settled: u32,
+            failed: u32,
+            total_payout: T::Balance,
+        },
+        /// Claim verification for a market switched by governance. [market_id, enabled]
+        ClaimVerificationSet {
+            market_id: MarketId,
+            enabled: bool,
+        },
+        /// Holder's share of a triggered payout held after the claim was not verified.
+        /// [policy_id, claimant, amount]
+        PayoutHeld {
+            policy_id: PolicyId,
+            claimant: T::AccountId,
+            amount: T::Balance,
+        },
+        /// Held payout released by governance to the claimant (or their installment
+        /// plan, splits or payout route). [policy_id, claimant, amount]
+        HeldPayoutReleased {
+            policy_id: PolicyId,
+            claimant: T::AccountId,
+            amount: T::Balance,
+        },
+        /// Held payout denied by governance and returned to the DAO capital account.
+        /// [policy_id, claimant, amount]
+        HeldPayoutDenied {
+            policy_id: PolicyId,
+            claimant: T::AccountId,
+            amount: T::Balance,
+        },
+        /// Group policy created - emitted instead of `V2PolicyCreated` for the
+        /// off-chain oracle to observe each member's location over its own window.
+        GroupPolicyCreated {
+            policy_id: PolicyId,
+            market_id: MarketId,
+            strike_mm: u32,
+            event_type: prmx_primitives::EventType,
+            members: Vec<GroupMember>,
+        },
+        /// Group policy settled on per-member observations. `triggered_members`
+        /// indexes the members that reached the strike.
+        GroupPolicySettled {
+            policy_id: PolicyId,
+            outcome: prmx_primitives::V2Outcome,
+            observations: Vec<u32>,
+            triggered_members: Vec<u32>,
+            evidence_hash: [u8; 32],
+        },
+        /// Policy scheduled: premium held in escrow and DAO capital reserved until
+        /// coverage starts. [policy_id, activates_at, premium, dao_capital]
+        PolicyScheduled {
+            policy_id: PolicyId,
+            activates_at: u64,
+            premium: T::Balance,
+            dao_capital: T::Balance,
+        },
+        /// Scheduled policy activated at coverage start; its capital is now locked
+        /// in the policy pool. [policy_id]
+        ScheduledPolicyActivated {
+            policy_id: PolicyId,
+        },
+        /// Scheduled policy cancelled by the holder before coverage start, with the
+        /// full premium refunded. [policy_id, holder, refund]
+        ScheduledPolicyCancelled {
+            policy_id: PolicyId,
+            holder: T::AccountId,
+            refund: T::Balance,
+        },
+        /// Scheduled policy could not be funded at coverage start and lapsed, with
+        /// the full premium refunded. [policy_id, holder, refund]
+        ScheduledPolicyLapsed {
+            policy_id: PolicyId,
+            holder: T::AccountId,
+            refund: T::Balance,
+        },
+    }
+
+    // =========================================================================
+    //                                  Errors
+    // =========================================================================
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Policy not found.
+        PolicyNotFound,
+        /// Quote not found.
+        QuoteNotFound,
+        /// Quote not ready.
+        QuoteNotReady,
+        /// Quote expired.
+        QuoteExpired,
+        /// Unauthorized (not the quote requester).
+        Unauthorized,
+        /// Policy already settled.
+        PolicyAlreadySettled,
+        /// Coverage window not ended.
+        CoverageNotEnded,
+        /// Insufficient funds.
+        InsufficientFunds,
+        /// Insufficient DAO capital.
+        InsufficientDaoCapital,
+        /// Arithmetic overflow.
+        ArithmeticOverflow,
+        /// Transfer failed.
+        TransferFailed,
+        /// Not a V2 policy.
+        NotV2Policy,
+        /// V2 report already submitted for this policy.
+        V2ReportAlreadySubmitted,
+        /// Invalid V2 observed_at timestamp.
+        InvalidObservedAt,
+        /// V2 threshold not met for triggered claim.
+        ThresholdNotMet,
+        /// V2 policy not active.
+        V2PolicyNotActive,
+        /// Caller does not hold the policy.
+        NotPolicyHolder,
+        /// Policy cannot be transferred to its current holder.
+        TransferToSelf,
+        /// Policy is settled, or its coverage has ended or an oracle report is
+        /// awaiting settlement.
+        PolicyNotTransferable,
+        /// Payout tiers must increase, start at or above the strike and pay at most 100%.
+        InvalidPayoutTiers,
+        /// A triggered report on a tiered V2 policy must reach the top tier or be
+        /// observed at coverage end.
+        TierNotFinal,
+        /// Coverage has started and cancellation terms do not allow cancelling now.
+        CancellationNotAllowed,
+        /// Refund share cannot exceed 10,000 basis points.
+        InvalidCancellationTerms,
+        /// No recent oracle data for the market; new policies are paused.
+        MarketOracleStale,
+        /// Operation is paused by governance.
+        OperationPaused,
+        /// Seller does not hold enough shares of the policy.
+        InsufficientPolicyShares,
+        /// Policy already has the maximum number of beneficiaries.
+        TooManyBeneficiaries,
+        /// Policy shares have been sold to other accounts.
+        PolicySharesSold,
+        /// The policy would take the market's open notional over its cap.
+        MarketNotionalCapExceeded,
+        /// The policy would take its location's open notional over its cap.
+        LocationNotionalCapExceeded,
+        /// Installment count or interval outside the allowed range.
+        InvalidInstallmentPlan,
+        /// The policy has no outstanding installment payout.
+        NoPayoutSchedule,
+        /// No installment has matured since the last release.
+        NoInstallmentDue,
+        /// Protocol fee cannot exceed `fees::MAX_PROTOCOL_FEE_BP`.
+        InvalidProtocolFee,
+        /// The treasury holds less than the requested withdrawal.
+        InsufficientTreasuryBalance,
+        /// Market is not open for new policies.
+        MarketNotOpen,
+        /// The market's denomination asset is unknown or does not exist.
+        MarketAssetUnavailable,
+        /// The quote does not cover the policy's terms from its current end to the
+        /// requested end.
+        InvalidExtensionQuote,
+        /// Payout splits must be distinct accounts with positive shares adding up
+        /// to 10,000 basis points.
+        InvalidPayoutSplits,
+        /// The policy's payout goes to fixed beneficiaries and cannot be transferred.
+        PolicyHasPayoutSplits,
+        /// The quote's terms do not match the parameter hash stored when it was
+        /// priced, or it was priced without one.
+        QuoteParamsMismatch,
+        /// A forced settlement batch must name at least one policy.
+        EmptyForceSettleBatch,
+        /// Another settlement is running; settlement cannot be re-entered.
+        SettlementInProgress,
+        /// The policy has no held payout.
+        NoHeldPayout,
+        /// Not a group policy.
+        NotGroupPolicy,
+        /// Group policies settle through `settle_group_policy` and cannot be extended.
+        IsGroupPolicy,
+        /// V2 policies are reported on by the off-chain oracle for the window they
+        /// were created with and cannot be extended.
+        V2PolicyNotExtendable,
+        /// A group settlement must report one observation per member.
+        GroupObservationMismatch,
+        /// Coverage starts too soon to schedule the policy (see `schedule`).
+        ScheduleTooSoon,
+        /// Not a scheduled policy awaiting activation.
+        NotScheduledPolicy,
+        /// The scheduled policy's coverage has started; it can no longer be cancelled.
+        ScheduledCoverageStarted,
+    }
+
+    // =========================================================================
+    //                                Extrinsics
+    // =========================================================================
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Apply for coverage using a previously obtained quote.
+        /// 
+        /// This will:
+        /// 1. Create a policy from the quote.
+        /// 2. Lock capital (user premium + DAO contribution).
+        /// 3. Mint LP tokens to DAO.
+        /// 4. Place DAO LP ask on orderbook.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::apply_coverage_with_quote())]
+        pub fn apply_coverage_with_quote(
+            origin: OriginFor<T>,
+            quote_id: prmx_primitives::QuoteId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::do_apply_coverage(who, quote_id, BoundedVec::new(), None, None, false)
+        }
+
+        /// Apply for coverage with a tiered payout table instead of the
+        /// all-or-nothing strike (e.g. 50mm → 30%, 80mm → 70%, 120mm → 100%).
+        ///
+        /// - `quote_id`: A ready quote requested by the caller.
+        /// - `payout_tiers`: Strictly increasing thresholds (tenths of mm) and payouts
+        ///   (basis points of max payout). The first tier must be at or above the
+        ///   quoted strike and no tier may pay more than 100%.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::apply_coverage_with_tiers(payout_tiers.len() as u32))]
+        pub fn apply_coverage_with_tiers(
+            origin: OriginFor<T>,
+            quote_id: prmx_primitives::QuoteId,
+            payout_tiers: BoundedVec<PayoutTier, MaxPayoutTiers>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(!payout_tiers.is_empty(), Error::<T>::InvalidPayoutTiers);
+
+            Self::do_apply_coverage(who, quote_id, payout_tiers, None, None, false)
+        }
+
+        /// Settle a policy after coverage window has ended.
+        /// This is permissionless - anyone can call it once conditions are met.
+        /// 
+        /// - `policy_id`: The policy to settle.
+        /// - `event_occurred`: Whether the rainfall event occurred (from oracle).
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::settle_policy(
+            T::MaxPolicyBeneficiaries::get().saturating_add(MaxPayoutSplits::get())
+        ))]
+        pub fn settle_policy(
+            origin: OriginFor<T>,
+            policy_id: PolicyId,
+            event_occurred: bool, // In production, this would come from oracle
+        ) -> DispatchResult {
+            // Permissionless - anyone can settle
+            let _who = ensure_signed(origin)?;
+
+            // Load policy
+            let policy = Policies::<T>::get(policy_id)
+                .ok_or(Error::<T>::PolicyNotFound)?;
+
+            // Ensure policy is active or expired (not already settled)
+            ensure!(
+                policy.status == PolicyStatus::Active || policy.status == PolicyStatus::Expired,
+                Error::<T>::PolicyAlreadySettled
+            );
+
+            // Check coverage window has ended
+            let now = T::TimeProvider::now_secs();
+            log::info!(
+                target: "prmx-policy",
+                "🔍 Settlement check - now: {}, coverage_end: {}, comparison: {}",
+                now,
+                policy.coverage_end,
+                now >= policy.coverage_end
+            );
+            ensure!(
+                now >= policy.coverage_end,
+                Error::<T>::CoverageNotEnded
+            );
+
+            // Call internal settlement function
+            Self::do_settle_policy(policy_id, event_occurred, SettlementReason::Manual)?;
+
+            Ok(())
+        }
+
+        /// Trigger immediate settlement for a policy when threshold is exceeded.
+        /// This is called by the Oracle pallet when automatic settlement is triggered.
+        /// Does NOT require coverage window to have ended.
+        /// 
+        /// - `policy_id`: The policy to settle immediately.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::trigger_immediate_settlement(
+            T::MaxPolicyBeneficiaries::get().saturating_add(MaxPayoutSplits::get())
+        ))]
+        pub fn trigger_immediate_settlement(
+            origin: OriginFor<T>,
+            policy_id: PolicyId,
+        ) -> DispatchResult {
+            // For now, allow root origin (oracle will call via internal function)
+            // In production, this would be restricted to OracleOrigin
+            ensure_root(origin)?;
+
+            // Load policy
+            let policy = Policies::<T>::get(policy_id)
+                .ok_or(Error::<T>::PolicyNotFound)?;
+
+            // Ensure policy is active (not already settled or cancelled)
+            ensure!(
+                policy.status == PolicyStatus::Active,
+                Error::<T>::PolicyAlreadySettled
+            );
+
+            log::info!(
+                target: "prmx-policy",
+                "⚡ Immediate settlement triggered for policy {} (threshold exceeded)",
+                policy_id
+            );
+
+            // Call internal settlement function with event_occurred = true
+            Self::do_settle_policy(policy_id, true, SettlementReason::GovernanceForced)?;
+
+            Ok(())
+        }
+
+        /// Settle a V2 policy based on off-chain oracle report.
+        /// 
+        /// Only authorized V2 oracle reporters can call this.
+        /// V2 policies are completely isolated from V1 settlement.
+        ///
+        /// - `policy_id`: The V2 policy to settle.
+        /// - `outcome`: Triggered or MaturedNoEvent.
+        /// - `observed_at`: Timestamp when the outcome was determined.
+        /// - `cumulative_mm`: Cumulative rainfall (for `Snowfall24h` policies the highest
+        ///   24-hour snowfall, for `FloodComposite` the highest 24-hour rainfall at river
+        ///   flood stage) in tenths of mm.
+        /// - `evidence_hash`: SHA256 hash of off-chain evidence JSON.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::settle_v2_policy(
+            T::MaxPolicyBeneficiaries::get().saturating_add(MaxPayoutSplits::get())
+        ))]
+        pub fn settle_v2_policy(
+            origin: OriginFor<T>,
+            policy_id: PolicyId,
+            outcome: prmx_primitives::V2Outcome,
+            observed_at: u64,
+            cumulative_mm: u32,
+            evidence_hash: [u8; 32],
+        ) -> DispatchResult {
+            // Only V2 oracle origin can call this
+            T::V2OracleOrigin::ensure_origin(origin.clone())?;
+
+            // Load policy
+            let mut policy = Policies::<T>::get(policy_id)
+                .ok_or(Error::<T>::PolicyNotFound)?;
+
+            // Verify this is a V2 policy
+            ensure!(
+                policy.policy_version == prmx_primitives::PolicyVersion::V2,
+                Error::<T>::NotV2Policy
+            );
+            ensure!(
+                !PolicyGroupMembers::<T>::contains_key(policy_id),
+                Error::<T>::IsGroupPolicy
+            );
+
+            // Verify policy is active
+            ensure!(
+                policy.status == PolicyStatus::Active,
+                Error::<T>::V2PolicyNotActive
+            );
+
+            // Verify no report already submitted
+            ensure!(
+                !V2FinalReport::<T>::contains_key(policy_id),
+                Error::<T>::V2ReportAlreadySubmitted
+            );
+
+            // Validate report based on outcome
+            match outcome {
+                prmx_primitives::V2Outcome::Triggered => {
+                    // observed_at must be within coverage window
+                    ensure!(
+                        observed_at >= policy.coverage_start && observed_at <= policy.coverage_end,
+                        Error::<T>::InvalidObservedAt
+                    );
+                    // cumulative_mm must meet or exceed strike
+                    if let Some(strike) = policy.strike_mm {
+                        ensure!(cumulative_mm >= strike, Error::<T>::ThresholdNotMet);
+                    }
+                    // Tiered policies settle once, at the top tier or at coverage end
+                    if let Some(top) = tiers::top_threshold(&policy.payout_tiers) {
+                        ensure!(
+                            cumulative_mm >= top || observed_at >= policy.coverage_end,
+                            Error::<T>::TierNotFinal
+                        );
+                    }
+                }
+                prmx_primitives::V2Outcome::MaturedNoEvent => {
+                    // observed_at must be at or after coverage end
+                    ensure!(
+                        observed_at >= policy.coverage_end,
+                        Error::<T>::CoverageNotEnded
+                    );
+                }
+            }
+
+            // Get reporter account (for the report record)
+            let reporter = match ensure_signed(origin) {
+                Ok(acc) => acc,
+                Err(_) => T::DaoAccountId::get(), // Root origin uses DAO account
+            };
+
+            let now = T::TimeProvider::now_secs();
+
+            // Store the immutable V2 report
+            let report = prmx_primitives::V2Report {
+                outcome: outcome.clone(),
+                observed_at,
+                cumulative_mm,
+                evidence_hash,
+                reporter,
+                submitted_at: now,
+            };
+            V2FinalReport::<T>::insert(policy_id, report);
+
+            // Update policy oracle status
+            policy.oracle_status_v2 = Some(match outcome {
+                prmx_primitives::V2Outcome::Triggered => prmx_primitives::V2OracleStatus::TriggeredReported,
+                prmx_primitives::V2Outcome::MaturedNoEvent => prmx_primitives::V2OracleStatus::MaturedReported,
+            });
+
+            // Perform actual settlement using existing mechanics
+            let event_occurred = matches!(outcome, prmx_primitives::V2Outcome::Triggered);
+            let payout = Self::do_settle_policy_with_observation(
+                policy_id,
+                event_occurred,
+                Self::v2_settlement_record(cumulative_mm, evidence_hash),
+            )?;
+
+            // Update oracle status to Settled
+            if let Some(mut p) = Policies::<T>::get(policy_id) {
+                p.oracle_status_v2 = Some(prmx_primitives::V2OracleStatus::Settled);
+                Policies::<T>::insert(policy_id, p);
+            }
+
+            // Emit V2-specific settlement event
+            Self::deposit_event(Event::V2PolicySettled {
+                policy_id,
+                outcome,
+                cumulative_mm,
+                evidence_hash,
+            });
+
+            log::info!(
+                target: "prmx-policy",
+                "✅ V2 policy {} settled: {:?}, cumulative_mm={}, payout={}",
+                policy_id,
+                outcome,
+                cumulative_mm,
+                payout.into()
+            );
+
+            Ok(())
+        }
+
+        /// Transfer a policy to another account. The new holder receives any payout.
+        ///
+        /// Only the current holder can transfer, and only while the policy is active
+        /// and its coverage window has not ended. V2 policies cannot be transferred
+        /// once the oracle has reported an outcome. Shares sold on the secondary
+        /// market stay with their buyers; if the new holder owns some, they are
+        /// merged into the holder's shares.
+        ///
+        /// - `policy_id`: The policy to transfer.
+        /// - `new_holder`: Account that will own the policy.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::transfer_policy())]
+        pub fn transfer_policy(
+            origin: OriginFor<T>,
+            policy_id: PolicyId,
+            new_holder: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let mut policy = Policies::<T>::get(policy_id)
+                .ok_or(Error::<T>::PolicyNotFound)?;
+
+            ensure!(policy.holder == who, Error::<T>::NotPolicyHolder);
+            ensure!(new_holder != who, Error::<T>::TransferToSelf);
+            ensure!(
+                !PolicyPayoutSplits::<T>::contains_key(policy_id),
+                Error::<T>::PolicyHasPayoutSplits
+            );
+            ensure!(
+                Self::is_transferable(&policy, T::TimeProvider::now_secs()),
+                Error::<T>::PolicyNotTransferable
+            );
+
+            PolicyBeneficiaries::<T>::mutate_exists(policy_id, |beneficiaries| {
+                if let Some(list) = beneficiaries {
+                    list.retain(|(account, _)| *account != new_holder);
+                    if list.is_empty() {
+                        *beneficiaries = None;
+                    }
+                }
+            });
+
+            policy.holder = new_holder.clone();
+            Policies::<T>::insert(policy_id, policy);
+
+            Self::deposit_event(Event::PolicyTransferred {
+                policy_id,
+                from: who,
+                to: new_holder,
+            });
+
+            Ok(())
+        }
+
+        /// Cancel a policy and refund part of its premium.
+        ///
+        /// Before coverage starts the holder receives `refund_bp` of the premium. If
+        /// governance allows, an active policy can also be cancelled during coverage,
+        /// with the refund prorated by the remaining coverage time. The rest of the
+        /// pool (including DAO capital) is returned to LP holders.
+        ///
+        /// - `policy_id`: The policy to cancel.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::cancel_policy())]
+        pub fn cancel_policy(
+            origin: OriginFor<T>,
+            policy_id: PolicyId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let mut policy = Policies::<T>::get(policy_id)
+                .ok_or(Error::<T>::PolicyNotFound)?;
+
+            ensure!(policy.holder == who, Error::<T>::NotPolicyHolder);
+            ensure!(
+                policy.status == PolicyStatus::Active,
+                Error::<T>::PolicyAlreadySettled
+            );
+            ensure!(
+                PolicyBeneficiaries::<T>::get(policy_id).is_empty(),
+                Error::<T>::PolicySharesSold
+            );
+
+            // Same conditions as a transfer: coverage not ended, no V2 report pending
+            // settlement and no settlement left part way, whose pool may already be
+            // paid out. The terms decide whether coverage may have started.
+            let now = T::TimeProvider::now_secs();
+            ensure!(
+                Self::is_transferable(&policy, now),
+                Error::<T>::CancellationNotAllowed
+            );
+
+            // The protocol fee is not refunded
+            let premium: u128 = policy.premium_paid.into();
+            let refundable = premium.saturating_sub(PolicyProtocolFees::<T>::take(policy_id).into());
+            let refund_due = cancellation::refund_amount(
+                refundable,
+                &PolicyCancellationTerms::<T>::get(),
+                policy.coverage_start,
+                policy.coverage_end,
+                now,
+            ).ok_or(Error::<T>::CancellationNotAllowed)?;
+
+            // Unwind any DeFi allocation so the full pool is held locally
+            T::CapitalApi::ensure_local_liquidity(policy_id, policy.max_payout)?;
+
+            let pool_account = Self::policy_pool_account(policy_id);
+            let asset = Self::policy_asset(policy_id);
+            let pool_balance: u128 = T::Assets::balance(asset, &pool_account).into();
+            let refund_u128 = refund_due.min(pool_balance);
+            let refund: T::Balance = refund_u128.into();
+
+            if refund > T::Balance::zero() {
+                T::Assets::transfer(
+                    asset,
+                    &pool_account,
+                    &who,
+                    refund,
+                    frame_support::traits::tokens::Preservation::Expendable,
+                ).map_err(|_| Error::<T>::TransferFailed)?;
+            }
+
+            // Remaining capital goes back to LP holders pro-rata (the DAO until it sells)
+            let returned_to_lps: T::Balance = pool_balance.saturating_sub(refund_u128).into();
+            if returned_to_lps > T::Balance::zero() {
+                T::HoldingsApi::distribute_to_lp_holders(
+                    policy_id,
+                    &pool_account,
+                    returned_to_lps,
+                ).map_err(|_| Error::<T>::TransferFailed)?;
+            }
+
+            T::HoldingsApi::cleanup_policy_lp_tokens(policy_id)
+                .map_err(|_| Error::<T>::TransferFailed)?;
+
+            Self::release_notional(
+                policy.market_id,
+                exposure::location_cell(policy.latitude, policy.longitude),
+                policy.max_payout,
+            );
+
+            PolicyRiskPoolBalance::<T>::insert(policy_id, T::Balance::zero());
+            PolicyInstallmentPlans::<T>::remove(policy_id);
+            PolicyPayoutSplits::<T>::remove(policy_id);
+            T::MarketsApi::record_premium_refund(policy.market_id, refund_u128);
+            policy.status = PolicyStatus::Cancelled;
+            Policies::<T>::insert(policy_id, policy);
+
+            T::CapitalApi::on_policy_settled(policy_id)?;
+            T::Reinsurance::on_policy_closed(policy_id);
+
+            Self::deposit_event(Event::PolicyCancelled {
+                policy_id,
+                holder: who,
+                refund,
+                returned_to_lps,
+            });
+
+            Ok(())
+        }
+
+        /// Set the premium refund terms for policy cancellation.
+        ///
+        /// Only callable by GovernanceOrigin.
+        ///
+        /// - `terms`: Refund share in basis points and whether cancelling during
+        ///   coverage (prorated) is allowed.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::set_cancellation_terms())]
+        pub fn set_cancellation_terms(
+            origin: OriginFor<T>,
+            terms: CancellationTerms,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            ensure!(terms.is_valid(), Error::<T>::InvalidCancellationTerms);
+
+            PolicyCancellationTerms::<T>::put(terms);
+
+            Self::deposit_event(Event::CancellationTermsSet {
+                refund_bp: terms.refund_bp,
+                allow_during_coverage: terms.allow_during_coverage,
+            });
+
+            Ok(())
+        }
+
+        /// Set the caps on open notional (sum of max payouts of active policies).
+        ///
+        /// Only callable by GovernanceOrigin. Lowering a cap below current
+        /// utilization does not affect existing policies; new policies are
+        /// rejected until utilization falls back under the cap.
+        ///
+        /// - `max_per_market`: Cap per market, or `None` for no cap.
+        /// - `max_per_location`: Cap per location cell (see `exposure`), or `None`.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::set_notional_caps())]
+        pub fn set_notional_caps(
+            origin: OriginFor<T>,
+            max_per_market: Option<T::Balance>,
+            max_per_location: Option<T::Balance>,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            MaxNotionalPerMarket::<T>::set(max_per_market);
+            MaxNotionalPerLocation::<T>::set(max_per_location);
+
+            Self::deposit_event(Event::NotionalCapsSet {
+                max_per_market,
+                max_per_location,
+            });
+
+            Ok(())
+        }
+
+        /// Apply for coverage with the payout released in installments instead of
+        /// a lump sum (structured settlement).
+        ///
+        /// If the policy triggers, the first installment is paid at settlement and
+        /// the rest every `interval_secs` after, to whoever holds the policy at
+        /// settlement. Secondary-market positions are still paid in full at
+        /// settlement.
+        ///
+        /// - `quote_id`: A ready quote requested by the caller.
+        /// - `plan`: Number of installments and the interval between them (see
+        ///   `installments` for the allowed ranges).
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::apply_coverage_with_installments())]
+        pub fn apply_coverage_with_installments(
+            origin: OriginFor<T>,
+            quote_id: prmx_primitives::QuoteId,
+            plan: InstallmentPlan,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(plan.is_valid(), Error::<T>::InvalidInstallmentPlan);
+
+            Self::do_apply_coverage(who, quote_id, BoundedVec::new(), Some(plan), None, false)
+        }
+
+        /// Release the matured installments of a triggered policy's payout
+        /// without waiting for the periodic sweep.
+        ///
+        /// Only the beneficiary of the payout schedule can claim.
+        ///
+        /// - `policy_id`: The settled policy with an outstanding payout schedule.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::claim_installment())]
+        pub fn claim_installment(
+            origin: OriginFor<T>,
+            policy_id: PolicyId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let schedule = PayoutSchedule::<T>::get(policy_id)
+                .ok_or(Error::<T>::NoPayoutSchedule)?;
+            ensure!(schedule.beneficiary == who, Error::<T>::NotPolicyHolder);
+            ensure!(
+                !T::Pause::is_paused(PausableOperation::Settlements),
+                Error::<T>::OperationPaused
+            );
+
+            let released = Self::release_installments(policy_id, T::TimeProvider::now_secs())?;
+            ensure!(released > T::Balance::zero(), Error::<T>::NoInstallmentDue);
+
+            Ok(())
+        }
+
+        /// Set the protocol fee taken from premiums of new policies in a market.
+        ///
+        /// Only callable by GovernanceOrigin. Existing policies are not affected.
+        ///
+        /// - `market_id`: The market to configure.
+        /// - `fee_bp`: Fee in basis points of the premium, at most
+        ///   `fees::MAX_PROTOCOL_FEE_BP`; 0 disables the fee.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::set_protocol_fee())]
+        pub fn set_protocol_fee(
+            origin: OriginFor<T>,
+            market_id: MarketId,
+            fee_bp: u32,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            ensure!(fee_bp <= fees::MAX_PROTOCOL_FEE_BP, Error::<T>::InvalidProtocolFee);
+
+            if fee_bp == 0 {
+                ProtocolFeeBp::<T>::remove(market_id);
+            } else {
+                ProtocolFeeBp::<T>::insert(market_id, fee_bp);
+            }
+
+            Self::deposit_event(Event::ProtocolFeeSet { market_id, fee_bp });
+
+            Ok(())
+        }
+
+        /// Withdraw collected protocol fees from the treasury account.
+        ///
+        /// Only callable by GovernanceOrigin.
+        ///
+        /// - `asset`: Asset to withdraw; fees are held in each market's base asset.
+        /// - `to`: Account receiving the fees.
+        /// - `amount`: Amount to withdraw.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::withdraw_treasury())]
+        pub fn withdraw_treasury(
+            origin: OriginFor<T>,
+            asset: T::AssetId,
+            to: T::AccountId,
+            amount: T::Balance,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            let treasury = Self::treasury_account();
+            ensure!(
+                T::Assets::balance(asset, &treasury) >= amount,
+                Error::<T>::InsufficientTreasuryBalance
+            );
+
+            T::Assets::transfer(
+                asset,
+                &treasury,
+                &to,
+                amount,
+                Preservation::Expendable,
+            ).map_err(|_| Error::<T>::TransferFailed)?;
+
+            Self::deposit_event(Event::TreasuryWithdrawn { asset, to, amount });
+
+            Ok(())
+        }
+
+        /// Extend a policy's coverage to `new_end` before it ends. V2 policies cannot
+        /// be extended.
+        ///
+        /// - `quote_id`: A ready quote requested by the holder for the same market,
+        ///   location, shares and terms, covering the current coverage end to `new_end`.
+        ///   Its premium (less the protocol fee) is added to the policy pool.
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::extend_policy())]
+        pub fn extend_policy(
+            origin: OriginFor<T>,
+            policy_id: PolicyId,
+            new_end: u64,
+            quote_id: prmx_primitives::QuoteId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::do_extend_policy(who, policy_id, new_end, quote_id)
+        }
+
+        /// Apply for coverage with the holder's payout split between several
+        /// beneficiaries (e.g. 80% to the farmer, 20% to the lender).
+        ///
+        /// The splits are fixed at creation; the policy cannot be transferred.
+        /// Secondary-market positions are still paid their share first.
+        ///
+        /// - `quote_id`: A ready quote requested by the caller.
+        /// - `splits`: Distinct beneficiaries with their share of the holder's
+        ///   payout in basis points, adding up to 10,000 (see `splits`).
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::apply_coverage_with_payout_splits(splits.len() as u32))]
+        pub fn apply_coverage_with_payout_splits(
+            origin: OriginFor<T>,
+            quote_id: prmx_primitives::QuoteId,
+            splits: BoundedVec<(T::AccountId, u16), MaxPayoutSplits>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(splits::validate_splits(&splits), Error::<T>::InvalidPayoutSplits);
+
+            Self::do_apply_coverage(who, quote_id, BoundedVec::new(), None, Some(splits), false)
+        }
+
+        /// Settle a batch of policies on a governance-supplied outcome, for disaster
+        /// recovery when oracle infrastructure is down.
+        ///
+        /// Only callable by GovernanceOrigin. Coverage does not need to have ended.
+        /// Each policy settles on its own: one that cannot settle is rolled back and
+        /// reported with `PolicyForceSettleFailed`, and the rest still settle.
+        ///
+        /// - `policy_ids`: Active or expired policies to settle.
+        /// - `outcome`: Triggered (full or top-tier payout) or MaturedNoEvent.
+        /// - `reason_code`: Why settlement is forced; recorded per policy in
+        ///   `ForcedSettlements`.
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::force_settle_batch(
+            policy_ids.len() as u32,
+            T::MaxPolicyBeneficiaries::get().saturating_add(MaxPayoutSplits::get())
+        ))]
+        pub fn force_settle_batch(
+            origin: OriginFor<T>,
+            policy_ids: BoundedVec<PolicyId, MaxForceSettleBatch>,
+            outcome: prmx_primitives::V2Outcome,
+            reason_code: ForceSettleReason,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            ensure!(!policy_ids.is_empty(), Error::<T>::EmptyForceSettleBatch);
+
+            let mut tally = force_settle::BatchTally::default();
+            for policy_id in policy_ids {
+                let result = frame_support::storage::with_storage_layer(|| {
+                    Self::do_force_settle_policy(policy_id, outcome, reason_code)
+                });
+
+                match result {
+                    Ok(payout) => {
+                        tally.record_settled(payout.into());
+                        Self::deposit_event(Event::PolicyForceSettled {
+                            policy_id,
+                            outcome,
+                            reason_code,
+                            payout,
+                        });
+                    }
+                    Err(error) => {
+                        tally.record_failed();
+                        Self::deposit_event(Event::PolicyForceSettleFailed {
+                            policy_id,
+                            reason_code,
+                            error,
+                        });
+                    }
+                }
+            }
+
+            log::info!(
+                target: "prmx-policy",
+                "🚨 Forced settlement batch ({:?}): {} settled, {} failed",
+                reason_code,
+                tally.settled,
+                tally.failed
+            );
+
+            Self::deposit_event(Event::ForceSettleBatchCompleted {
+                reason_code,
+                settled: tally.settled,
+                failed: tally.failed,
+                total_payout: tally.total_payout.into(),
+            });
+
+            Ok(())
+        }
+
+        /// Require `ClaimVerifier` approval before triggered payouts in a market
+        /// are released to holders.
+        ///
+        /// Only callable by GovernanceOrigin. Payouts already held stay held.
+        ///
+        /// - `market_id`: The market to configure.
+        /// - `enabled`: Whether claims in the market are verified.
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::set_claim_verification())]
+        pub fn set_claim_verification(
+            origin: OriginFor<T>,
+            market_id: MarketId,
+            enabled: bool,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            if enabled {
+                ClaimVerificationEnabled::<T>::insert(market_id, true);
+            } else {
+                ClaimVerificationEnabled::<T>::remove(market_id);
+            }
+
+            Self::deposit_event(Event::ClaimVerificationSet { market_id, enabled });
+
+            Ok(())
+        }
+
+        /// Release a held payout to its claimant, through the policy's
+        /// installment plan, payout splits or payout route as at settlement.
+        ///
+        /// Only callable by GovernanceOrigin.
+        ///
+        /// - `policy_id`: The settled policy with a held payout.
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::release_held_payout())]
+        pub fn release_held_payout(
+            origin: OriginFor<T>,
+            policy_id: PolicyId,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            let held = HeldPayouts::<T>::take(policy_id).ok_or(Error::<T>::NoHeldPayout)?;
+
+            Self::deliver_holder_payout(
+                policy_id,
+                &held.claimant,
+                held.amount.into(),
+                T::TimeProvider::now_secs(),
+            )?;
+
+            Self::deposit_event(Event::HeldPayoutReleased {
+                policy_id,
+                claimant: held.claimant,
+                amount: held.amount,
+            });
+
+            Ok(())
+        }
+
+        /// Deny a held payout and return it to the DAO capital account.
+        ///
+        /// Only callable by GovernanceOrigin.
+        ///
+        /// - `policy_id`: The settled policy with a held payout.
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::deny_held_payout())]
+        pub fn deny_held_payout(
+            origin: OriginFor<T>,
+            policy_id: PolicyId,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            let held = HeldPayouts::<T>::take(policy_id).ok_or(Error::<T>::NoHeldPayout)?;
+            PolicyInstallmentPlans::<T>::remove(policy_id);
+            PolicyPayoutSplits::<T>::remove(policy_id);
+
+            if held.amount > T::Balance::zero() {
+                T::Assets::transfer(
+                    Self::policy_asset(policy_id),
+                    &Self::policy_pool_account(policy_id),
+                    &T::DaoCapitalAccountId::get(),
+                    held.amount,
+                    Preservation::Expendable,
+                ).map_err(|_| Error::<T>::TransferFailed)?;
+            }
+
+            Self::deposit_event(Event::HeldPayoutDenied {
+                policy_id,
+                claimant: held.claimant,
+                amount: held.amount,
+            });
+
+            Ok(())
+        }
+
+        /// Settle a group policy (see `group`) on one observation per member.
+        ///
+        /// Only authorized V2 oracle reporters can call this. The policy triggers
+        /// once if any member's observation reaches the strike; `observed_at` must then
+        /// lie in the coverage window of a triggering member. Otherwise it must be at
+        /// or after the end of the last member's window.
+        ///
+        /// - `policy_id`: The group policy to settle.
+        /// - `observations`: Each member's observation in tenths of mm, measured over
+        ///   the member's window at its location as `cumulative_mm` is for
+        ///   `settle_v2_policy`, in member order.
+        /// - `observed_at`: Timestamp when the outcome was determined.
+        /// - `evidence_hash`: SHA256 hash of off-chain evidence JSON.
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::WeightInfo::settle_group_policy(
+            T::MaxPolicyBeneficiaries::get().saturating_add(MaxPayoutSplits::get()),
+            observations.len() as u32,
+        ))]
+        pub fn settle_group_policy(
+            origin: OriginFor<T>,
+            policy_id: PolicyId,
+            observations: BoundedVec<u32, pallet_prmx_quote::MaxGroupMembers>,
+            observed_at: u64,
+            evidence_hash: [u8; 32],
+        ) -> DispatchResult {
+            T::V2OracleOrigin::ensure_origin(origin.clone())?;
+
+            let policy = Policies::<T>::get(policy_id)
+                .ok_or(Error::<T>::PolicyNotFound)?;
+            let members = PolicyGroupMembers::<T>::get(policy_id)
+                .ok_or(Error::<T>::NotGroupPolicy)?;
+            ensure!(
+                policy.status == PolicyStatus::Active,
+                Error::<T>::V2PolicyNotActive
+            );
+            ensure!(
+                !V2FinalReport::<T>::contains_key(policy_id),
+                Error::<T>::V2ReportAlreadySubmitted
+            );
+            ensure!(
+                observations.len() == members.len(),
+                Error::<T>::GroupObservationMismatch
+            );
+
+            // Group policies are V2 policies with a strike
+            let strike = policy.strike_mm.ok_or(Error::<T>::NotGroupPolicy)?;
+            let triggered = group::triggered_members(strike, &observations);
+            let settled_mm = group::settlement_observation(&observations, &triggered);
+
+            let outcome = if triggered.is_empty() {
+                ensure!(
+                    observed_at >= policy.coverage_end,
+                    Error::<T>::CoverageNotEnded
+                );
+                prmx_primitives::V2Outcome::MaturedNoEvent
+            } else {
+                ensure!(
+                    group::within_triggered_window(&members, &triggered, observed_at),
+                    Error::<T>::InvalidObservedAt
+                );
+                // Tiered policies settle once, at the top tier or at coverage end
+                if let Some(top) = tiers::top_threshold(&policy.payout_tiers) {
+                    ensure!(
+                        settled_mm >= top || observed_at >= policy.coverage_end,
+                        Error::<T>::TierNotFinal
+                    );
+                }
+                prmx_primitives::V2Outcome::Triggered
+            };
+
+            let reporter = match ensure_signed(origin) {
+                Ok(acc) => acc,
+                Err(_) => T::DaoAccountId::get(), // Root origin uses DAO account
+            };
+
+            V2FinalReport::<T>::insert(policy_id, prmx_primitives::V2Report {
+                outcome: outcome.clone(),
+                observed_at,
+                cumulative_mm: settled_mm,
+                evidence_hash,
+                reporter,
+                submitted_at: T::TimeProvider::now_secs(),
+            });
+
+            let event_occurred = matches!(outcome, prmx_primitives::V2Outcome::Triggered);
+            Self::do_settle_policy_with_observation(
+                policy_id,
+                event_occurred,
+                Self::v2_settlement_record(settled_mm, evidence_hash),
+            )?;
+
+            if let Some(mut p) = Policies::<T>::get(policy_id) {
+                p.oracle_status_v2 = Some(prmx_primitives::V2OracleStatus::Settled);
+                Policies::<T>::insert(policy_id, p);
+            }
+
+            Self::deposit_event(Event::GroupPolicySettled {
+                policy_id,
+                outcome,
+                observations: observations.into_inner(),
+                triggered_members: triggered,
+                evidence_hash,
+            });
+
+            Ok(())
+        }
+
+        /// Buy a policy ahead of its coverage start (see `schedule`).
+        ///
+        /// The premium is held in escrow and the DAO capital reserved; the policy
+        /// is activated and its pool funded when coverage starts. Coverage must
+        /// start at least `schedule::MIN_SCHEDULED_LEAD_SECS` from now.
+        ///
+        /// - `quote_id`: The quote ID from the quote pallet.
+        #[pallet::call_index(20)]
+        #[pallet::weight(T::WeightInfo::apply_coverage_scheduled())]
+        pub fn apply_coverage_scheduled(
+            origin: OriginFor<T>,
+            quote_id: prmx_primitives::QuoteId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_apply_coverage(who, quote_id, BoundedVec::new(), None, None, true)
+        }
+
+        /// Cancel a scheduled policy before its coverage starts and refund the
+        /// escrowed premium in full, protocol fee included.
+        ///
+        /// - `policy_id`: The scheduled policy to cancel.
+        #[pallet::call_index(21)]
+        #[pallet::weight(T::WeightInfo::cancel_scheduled_policy())]
+        pub fn cancel_scheduled_policy(
+            origin: OriginFor<T>,
+            policy_id: PolicyId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let policy = Policies::<T>::get(policy_id)
+                .ok_or(Error::<T>::PolicyNotFound)?;
+            ensure!(policy.holder == who, Error::<T>::NotPolicyHolder);
+            ensure!(
+                policy.status == PolicyStatus::Scheduled,
+                Error::<T>::NotScheduledPolicy
+            );
+            // Once coverage starts the policy is activated by the next sweep
+            ensure!(
+                !schedule::is_due(policy.coverage_start, T::TimeProvider::now_secs()),
+                Error::<T>::ScheduledCoverageStarted
+            );
+
+            let (_, refund) = Self::refund_scheduled_policy(policy_id)?;
+
+            Self::deposit_event(Event::ScheduledPolicyCancelled {
+                policy_id,
+                holder: who,
+                refund,
+            });
+
+            Ok(())
+        }
+    }
+
+    // =========================================================================
+    //                                  Hooks
+    // =========================================================================
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// Activate scheduled policies whose coverage has started (every
+        /// BLOCKS_PER_ACTIVATION_CHECK blocks), resume settlements left part way
+        /// (every BLOCKS_PER_SETTLEMENT_RESUME blocks) and release matured
+        /// installments (every BLOCKS_PER_INSTALLMENT_CHECK blocks)
+        fn on_initialize(block_number: BlockNumberFor<T>) -> Weight {
+            use sp_runtime::traits::UniqueSaturatedInto;
+            let block_num: u32 = block_number.unique_saturated_into();
+            let mut weight = Weight::zero();
+
+            // Not gated on the settlement pause: coverage must not start unfunded
+            if block_num % BLOCKS_PER_ACTIVATION_CHECK == 0 {
+                weight = weight.saturating_add(Self::activate_due_policies());
+            }
+
+            if T::Pause::is_paused(PausableOperation::Settlements) {
+                return weight.saturating_add(T::DbWeight::get().reads(1));
+            }
+
+            if block_num % BLOCKS_PER_SETTLEMENT_RESUME == 0 {
+                weight = weight.saturating_add(Self::resume_partial_settlements());
+            }
+
+            if block_num % BLOCKS_PER_INSTALLMENT_CHECK != 0 {
+                return weight.saturating_add(T::DbWeight::get().reads(1));
+            }
+
+            weight.saturating_add(Self::release_due_installments())
+        }
+    }
+
+    // =========================================================================
+    //                           Helper Functions
+    // =========================================================================
+
+    impl<T: Config> Pallet<T> {
+        /// Get the derived account for a policy's capital pool
+        pub fn policy_pool_account(policy_id: PolicyId) -> T::AccountId {
+            PALLET_ID.into_sub_account_truncating(("policy", policy_id))
+        }
+
+        /// Get the asset a policy is denominated in (USDT if none was recorded)
+        pub fn policy_asset(policy_id: PolicyId) -> T::AssetId {
+            PolicyAssets::<T>::get(policy_id).unwrap_or_else(T::UsdtAssetId::get)
+        }
+
+        /// Get the derived account holding collected protocol fees
+        pub fn treasury_account() -> T::AccountId {
+            PALLET_ID.into_sub_account_truncating("treasury")
+        }
+
+        /// Get the derived account holding the premiums of scheduled policies
+        pub fn escrow_account() -> T::AccountId {
+            PALLET_ID.into_sub_account_truncating("escrow")
+        }
+
+        /// Get the derived account for a market's residual pool
+        pub fn market_residual_account(market_id: MarketId) -> T::AccountId {
+            PALLET_ID.into_sub_account_truncating(("market", market_id))
+        }
+
+        /// Generate a human-readable policy label like "manila-0", "tokyo-1".
+        /// Uses account nonce for consistent numbering per account.
+        /// e.g., nonce=0 -> "manila-0", nonce=3 -> "manila-3"
+        fn generate_policy_label(market_id: MarketId, nonce: u64) -> BoundedVec<u8, ConstU32<32>> {
+            // Get market name from MarketsApi
+            let market_name_bytes = T::MarketsApi::market_name(market_id)
+                .unwrap_or_else(|_| b"unknown".to_vec());
+            
+            // Convert to lowercase string
+            let market_name = core::str::from_utf8(&market_name_bytes)
+                .unwrap_or("unknown");
+            
+            // Use nonce for policy numbering
+            let policy_number = nonce;
+
+            // Generate label: "manila-1", "tokyo-2", etc.
+            let label_string = alloc::format!(
+                "{}-{}",
+                market_name.to_ascii_lowercase(),
+                policy_number
+            );
+
+            // Convert to bounded vec (truncate if too long)
+            label_string
+                .into_bytes()
+                .try_into()
+                .unwrap_or_else(|_| {
+                    // Fallback: just use "policy-{id}" format
+                    let fallback = alloc::format!("policy-{}", policy_number);
+                    fallback.into_bytes().try_into().unwrap_or_default()
+                })
+        }
+
+        /// Create a policy from a ready quote, optionally with a tiered payout table
+        /// or an installment plan
+        fn do_apply_coverage(
+            who: T::AccountId,
+            quote_id: prmx_primitives::QuoteId,
+            payout_tiers: BoundedVec<PayoutTier, MaxPayoutTiers>,
+            installment_plan: Option<InstallmentPlan>,
+            payout_splits: Option<BoundedVec<(T::AccountId, u16), MaxPayoutSplits>>,
+            scheduled: bool,
+        ) -> DispatchResult {
+
+            // Load quote request and result
+            let req = T::QuoteApi::get_quote_request(quote_id)
+                .ok_or(Error::<T>::QuoteNotFound)?;
+            let res = T::QuoteApi::get_quote_result(quote_id)
+                .ok_or(Error::<T>::QuoteNotReady)?;
+
+            // Verify the caller is the quote requester
             ensure!(who == req.requester, Error::<T>::Unauthorized);
 
-            // Check quote is ready
+            // The policy is built from these terms; they must be the ones priced
+            Self::ensure_quote_binding(quote_id, &req, &res)?;
+
+            // Check quote is ready
+            ensure!(
+                T::QuoteApi::is_quote_ready(quote_id),
+                Error::<T>::QuoteExpired
+            );
+
+            ensure!(
+                !T::Pause::is_paused(PausableOperation::PolicyCreation),
+                Error::<T>::OperationPaused
+            );
+
+            // Quotes issued before the market was paused, closed or archived
+            // cannot be used
+            ensure!(
+                T::MarketsApi::is_market_open(req.market_id),
+                Error::<T>::MarketNotOpen
+            );
+
+            // Sales pause while the market's oracle is stale
+            ensure!(
+                !T::OracleStatus::is_market_stale(req.market_id),
+                Error::<T>::MarketOracleStale
+            );
+
+            // The policy is denominated in the market's base asset. It cannot change
+            // once the market is open, so it is the asset the quote was priced in.
+            let asset = T::MarketsApi::base_asset(req.market_id)
+                .map_err(|_| Error::<T>::MarketAssetUnavailable)?;
+            ensure!(T::Assets::asset_exists(asset), Error::<T>::MarketAssetUnavailable);
+
+            // Calculate capital requirements
+            let shares = req.shares;
+            let premium = res.total_premium;
+            let premium_u128: u128 = premium.into();
+
+            // max_payout = shares * PAYOUT_PER_SHARE
+            let max_payout_u128 = shares
+                .checked_mul(PAYOUT_PER_SHARE)
+                .ok_or(Error::<T>::ArithmeticOverflow)?;
+            let max_payout: T::Balance = max_payout_u128.into();
+
+            // Stay within the governance exposure caps
+            let location = exposure::location_cell(req.latitude, req.longitude);
+            ensure!(
+                exposure::within_cap(
+                    MarketNotional::<T>::get(req.market_id).into(),
+                    max_payout_u128,
+                    MaxNotionalPerMarket::<T>::get().map(Into::into),
+                ),
+                Error::<T>::MarketNotionalCapExceeded
+            );
+            ensure!(
+                exposure::within_cap(
+                    LocationNotional::<T>::get(location).into(),
+                    max_payout_u128,
+                    MaxNotionalPerLocation::<T>::get().map(Into::into),
+                ),
+                Error::<T>::LocationNotionalCapExceeded
+            );
+
+            // The protocol fee comes out of the premium; the DAO covers the rest:
+            // required_capital = max_payout - (premium - fee)
+            let fee_bp = ProtocolFeeBp::<T>::get(req.market_id);
+            let split = fees::split_premium(premium_u128, max_payout_u128, fee_bp);
+            let protocol_fee: T::Balance = split.fee.into();
+            let required_capital: T::Balance = split.dao_capital.into();
+
+            // Calculate required capital per share (for orderbook listing)
+            let premium_per_share_u128: u128 = res.premium_per_share.into();
+            let required_capital_per_share_u128 =
+                fees::split_premium(premium_per_share_u128, PAYOUT_PER_SHARE, fee_bp).dao_capital;
+            let required_capital_per_share: T::Balance = required_capital_per_share_u128.into();
+
+            // Create policy with hash-based ID
+            let now = T::TimeProvider::now_secs();
+            let nonce = AccountNonce::<T>::get(&who);
+            let policy_id = generate_unique_id(b"V1V2", &who, now, nonce);
+            AccountNonce::<T>::insert(&who, nonce + 1);
+
+            ensure!(
+                !scheduled || schedule::can_schedule(req.coverage_start, now),
+                Error::<T>::ScheduleTooSoon
+            );
+
+            // Generate policy label using nonce (e.g., "manila-1" for nonce=0)
+            let policy_label = Self::generate_policy_label(req.market_id, nonce);
+
+            // Get strike value for V2 policies:
+            // - Use custom strike from quote if provided
+            // - Otherwise fall back to market's default strike
+            let strike_mm = if req.policy_version == prmx_primitives::PolicyVersion::V2 {
+                match req.strike_mm {
+                    Some(custom_strike) => Some(custom_strike),
+                    None => T::MarketsApi::strike_value(req.market_id).ok(),
+                }
+            } else {
+                None
+            };
+
+            // Tiers may not pay out below the strike the premium was priced for
+            if !payout_tiers.is_empty() {
+                let priced_strike = match strike_mm {
+                    Some(strike) => strike,
+                    None => T::MarketsApi::strike_value(req.market_id)
+                        .map_err(|_| Error::<T>::InvalidPayoutTiers)?,
+                };
+                ensure!(
+                    tiers::validate_tiers(&payout_tiers, priced_strike),
+                    Error::<T>::InvalidPayoutTiers
+                );
+            }
+
+            // Group quotes cover several locations (see `group`)
+            let group_members = T::QuoteApi::group_members(quote_id);
+
+            let policy = PolicyInfo::<T> {
+                policy_id,
+                policy_label,
+                market_id: req.market_id,
+                holder: who.clone(),
+                coverage_start: req.coverage_start,
+                coverage_end: req.coverage_end,
+                shares,
+                latitude: req.latitude,
+                longitude: req.longitude,
+                status: if scheduled { PolicyStatus::Scheduled } else { PolicyStatus::Active },
+                premium_paid: premium,
+                max_payout,
+                created_at: now,
+                // V2 fields from quote
+                policy_version: req.policy_version,
+                event_type: req.event_type,
+                early_trigger: req.early_trigger,
+                oracle_status_v2: if req.policy_version == prmx_primitives::PolicyVersion::V2 {
+                    Some(prmx_primitives::V2OracleStatus::PendingMonitoring)
+                } else {
+                    None
+                },
+                strike_mm,
+                payout_tiers,
+            };
+
+            // Get pool account for this policy
+            let pool_account = Self::policy_pool_account(policy_id);
+
+            let funding = PolicyFunding::<T> {
+                asset,
+                premium,
+                protocol_fee,
+                dao_capital: required_capital,
+                dao_capital_per_share: required_capital_per_share,
+                activates_at: req.coverage_start,
+            };
+
+            // DAO capital reserved for scheduled policies is not available
+            Self::ensure_dao_capital_available(asset, required_capital)?;
+
+            if scheduled {
+                // Hold the full premium in escrow until coverage starts
+                T::Assets::transfer(
+                    asset,
+                    &who,
+                    &Self::escrow_account(),
+                    premium,
+                    Preservation::Expendable,
+                ).map_err(|_| Error::<T>::InsufficientFunds)?;
+            }
+
+            // Store policy
+            Policies::<T>::insert(policy_id, &policy);
+            PolicyAssets::<T>::insert(policy_id, asset);
+            PolicyPoolAccounts::<T>::insert(policy_id, &pool_account);
+            PoolAccountPolicies::<T>::insert(&pool_account, policy_id);
+            if let Some(plan) = installment_plan {
+                PolicyInstallmentPlans::<T>::insert(policy_id, plan);
+            }
+            if let Some(splits) = payout_splits {
+                PolicyPayoutSplits::<T>::insert(policy_id, &splits);
+                Self::deposit_event(Event::PayoutSplitsSet { policy_id, splits });
+            }
+            if !group_members.is_empty() {
+                // Bounded by the quote pallet's own limit
+                PolicyGroupMembers::<T>::insert(
+                    policy_id,
+                    BoundedVec::truncate_from(group_members.clone()),
+                );
+            }
+
+            // Add to market index
+            PoliciesByMarket::<T>::mutate(req.market_id, |policies| {
+                let _ = policies.try_push(policy_id);
+            });
+
+            // Count the policy against the exposure caps (scheduled policies too)
+            Self::add_notional(req.market_id, location, max_payout);
+
+            // Consume the quote
+            T::QuoteApi::consume_quote(quote_id)?;
+
+            // Emit events
+            Self::deposit_event(Event::PolicyCreated {
+                policy_id,
+                market_id: req.market_id,
+                holder: who.clone(),
+                shares,
+            });
+
+            // Emit V2PolicyCreated (or GroupPolicyCreated) for off-chain oracle to pick up
+            if req.policy_version == prmx_primitives::PolicyVersion::V2 {
+                if let Some(strike) = strike_mm {
+                    if !group_members.is_empty() {
+                        Self::deposit_event(Event::GroupPolicyCreated {
+                            policy_id,
+                            market_id: req.market_id,
+                            strike_mm: strike,
+                            event_type: req.event_type,
+                            members: group_members,
+                        });
+                    } else {
+                        Self::deposit_event(Event::V2PolicyCreated {
+                            policy_id,
+                            market_id: req.market_id,
+                            coverage_start: req.coverage_start,
+                            coverage_end: req.coverage_end,
+                            strike_mm: strike,
+                            latitude: req.latitude,
+                            longitude: req.longitude,
+                            event_type: req.event_type,
+                        });
+                    }
+                }
+            }
+
+            if scheduled {
+                // Capital is locked at activation (see `schedule`)
+                ReservedDaoCapital::<T>::mutate(asset, |total| {
+                    let current: u128 = (*total).into();
+                    *total = current.saturating_add(split.dao_capital).into();
+                });
+                CapitalReservations::<T>::insert(policy_id, funding);
+                Self::enqueue_activation(policy_id, req.coverage_start);
+
+                Self::deposit_event(Event::PolicyScheduled {
+                    policy_id,
+                    activates_at: req.coverage_start,
+                    premium,
+                    dao_capital: required_capital,
+                });
+                return Ok(());
+            }
+
+            Self::fund_policy(&policy, &who, &funding)
+        }
+
+        /// Move a new policy's premium from `payer` into its pool (the protocol fee
+        /// into the treasury) together with its DAO capital, then mint the DAO's LP
+        /// tokens and list them on the orderbook
+        fn fund_policy(
+            policy: &PolicyInfo<T>,
+            payer: &T::AccountId,
+            funding: &PolicyFunding<T>,
+        ) -> DispatchResult {
+            let policy_id = policy.policy_id;
+            let market_id = policy.market_id;
+            let asset = funding.asset;
+            let pool_account = Self::policy_pool_account(policy_id);
+            let protocol_fee = funding.protocol_fee;
+            let fee_u128: u128 = protocol_fee.into();
+            let premium_u128: u128 = funding.premium.into();
+            let net_premium: T::Balance = premium_u128.saturating_sub(fee_u128).into();
+
+            // Transfer premium to pool, less the protocol fee
+            T::Assets::transfer(
+                asset,
+                payer,
+                &pool_account,
+                net_premium,
+                Preservation::Expendable,
+            ).map_err(|_| Error::<T>::InsufficientFunds)?;
+
+            // Transfer protocol fee to treasury
+            if protocol_fee > T::Balance::zero() {
+                T::Assets::transfer(
+                    asset,
+                    payer,
+                    &Self::treasury_account(),
+                    protocol_fee,
+                    Preservation::Expendable,
+                ).map_err(|_| Error::<T>::InsufficientFunds)?;
+
+                PolicyProtocolFees::<T>::insert(policy_id, protocol_fee);
+                CollectedFees::<T>::mutate(market_id, |total| {
+                    let current: u128 = (*total).into();
+                    *total = current.saturating_add(fee_u128).into();
+                });
+            }
+
+            // Transfer DAO capital to pool
+            if funding.dao_capital > T::Balance::zero() {
+                T::Assets::transfer(
+                    asset,
+                    &T::DaoCapitalAccountId::get(),
+                    &pool_account,
+                    funding.dao_capital,
+                    Preservation::Expendable,
+                ).map_err(|_| Error::<T>::InsufficientDaoCapital)?;
+            }
+
+            // Set pool balance
+            PolicyRiskPoolBalance::<T>::insert(policy_id, policy.max_payout);
+
+            // Mint LP tokens to DAO for THIS POLICY (policy-specific LP tokens)
+            T::HoldingsApi::mint_lp_tokens(policy_id, &T::DaoAccountId::get(), policy.shares)
+                .map_err(|_| Error::<T>::ArithmeticOverflow)?;
+
+            // Register DAO as LP holder for this policy (for automatic payout distribution)
+            T::HoldingsApi::register_lp_holder(policy_id, &T::DaoAccountId::get())
+                .map_err(|_| Error::<T>::ArithmeticOverflow)?;
+
+            // Place DAO LP ask on orderbook for THIS POLICY's LP tokens
+            T::LpOrderbook::place_dao_lp_ask(
+                policy_id,
+                &T::DaoAccountId::get(),
+                funding.dao_capital_per_share,
+                policy.shares,
+            )?;
+
+            T::MarketsApi::record_premium(market_id, premium_u128);
+
+            Self::deposit_event(Event::CapitalLocked {
+                policy_id,
+                user_premium: funding.premium,
+                protocol_fee,
+                dao_capital: funding.dao_capital,
+            });
+
+            Self::deposit_event(Event::LpTokensMinted {
+                policy_id,
+                shares: policy.shares,
+            });
+
+            Self::deposit_event(Event::DaoLpAskPlaced {
+                policy_id,
+                price_per_share: funding.dao_capital_per_share,
+                quantity: policy.shares,
+            });
+
+            // Assign the policy a DeFi strategy and auto-allocate its capital there
+            // Uses the configured allocation percentage (default 100%)
+            if let Err(e) =
+                T::CapitalApi::auto_allocate_policy_capital(policy_id, policy.max_payout)
+            {
+                log::warn!(
+                    target: "prmx-policy",
+                    "⚠️ Auto-allocation to DeFi failed for policy {}: {:?}",
+                    policy_id,
+                    e
+                );
+                // Don't fail policy creation if auto-allocation fails
+                // The DAO can manually allocate later
+            }
+
+            // Cede part of the exposure to the reinsurance pool, if configured, with
+            // the matching share of the premium after the protocol fee.
+            // Like auto-allocation, this never blocks policy creation.
+            if let Err(e) =
+                T::Reinsurance::on_policy_created(policy_id, net_premium, policy.max_payout)
+            {
+                log::warn!(
+                    target: "prmx-policy",
+                    "⚠️ Reinsurance cession failed for policy {}: {:?}",
+                    policy_id,
+                    e
+                );
+            }
+
+            // Pay the shortfall backstop its share of the premium, likewise
+            // without blocking policy creation.
+            if let Err(e) = T::CapitalApi::fund_backstop(policy_id, net_premium) {
+                log::warn!(
+                    target: "prmx-policy",
+                    "⚠️ Backstop funding failed for policy {}: {:?}",
+                    policy_id,
+                    e
+                );
+            }
+
+            Ok(())
+        }
+
+        /// Ensure the DAO capital account can fund `amount` on top of the capital
+        /// reserved for scheduled policies
+        fn ensure_dao_capital_available(asset: T::AssetId, amount: T::Balance) -> DispatchResult {
+            if amount == T::Balance::zero() {
+                return Ok(());
+            }
+            let balance: u128 = T::Assets::balance(asset, &T::DaoCapitalAccountId::get()).into();
+            let reserved: u128 = ReservedDaoCapital::<T>::get(asset).into();
+            ensure!(
+                schedule::available_capital(balance, reserved) >= amount.into(),
+                Error::<T>::InsufficientDaoCapital
+            );
+            Ok(())
+        }
+
+        /// Recompute the quote's parameter hash from the terms about to be used and
+        /// compare it with the hash stored when the quote was priced
+        fn ensure_quote_binding(
+            quote_id: prmx_primitives::QuoteId,
+            req: &pallet_prmx_quote::QuoteRequestInfo<T::AccountId>,
+            res: &pallet_prmx_quote::QuoteResultInfo<T::Balance>,
+        ) -> DispatchResult {
+            let priced = T::QuoteApi::quote_params_hash(quote_id)
+                .ok_or(Error::<T>::QuoteParamsMismatch)?;
+            ensure!(
+                pallet_prmx_quote::binding::params_hash(req, res) == priced,
+                Error::<T>::QuoteParamsMismatch
+            );
+            Ok(())
+        }
+
+        /// Extend a policy's coverage window with a quote for the added period
+        fn do_extend_policy(
+            who: T::AccountId,
+            policy_id: PolicyId,
+            new_end: u64,
+            quote_id: prmx_primitives::QuoteId,
+        ) -> DispatchResult {
+            let mut policy = Policies::<T>::get(policy_id).ok_or(Error::<T>::PolicyNotFound)?;
+
+            ensure!(policy.holder == who, Error::<T>::NotPolicyHolder);
+            // A group policy's window spans its members' windows
+            ensure!(
+                !PolicyGroupMembers::<T>::contains_key(policy_id),
+                Error::<T>::IsGroupPolicy
+            );
+            // The off-chain V2 reporter evaluates the window the policy was created with
+            ensure!(
+                policy.policy_version != prmx_primitives::PolicyVersion::V2,
+                Error::<T>::V2PolicyNotExtendable
+            );
+            // Positions sold on the secondary market were priced for the current window
+            ensure!(
+                PolicyBeneficiaries::<T>::get(policy_id).is_empty(),
+                Error::<T>::PolicySharesSold
+            );
+
+            // Same conditions as a transfer: active, coverage not ended, no V2 report
+            // pending settlement and no settlement left part way
+            let now = T::TimeProvider::now_secs();
+            ensure!(
+                Self::is_transferable(&policy, now),
+                Error::<T>::PolicyNotTransferable
+            );
+
+            let req = T::QuoteApi::get_quote_request(quote_id)
+                .ok_or(Error::<T>::QuoteNotFound)?;
+            let res = T::QuoteApi::get_quote_result(quote_id)
+                .ok_or(Error::<T>::QuoteNotReady)?;
+            ensure!(who == req.requester, Error::<T>::Unauthorized);
             ensure!(
                 T::QuoteApi::is_quote_ready(quote_id),
                 Error::<T>::QuoteExpired
             );
+            Self::ensure_quote_binding(quote_id, &req, &res)?;
 
-            // Calculate capital requirements
-            let shares = req.shares;
-            let premium = res.total_premium;
-            let premium_u128: u128 = premium.into();
-
-            // max_payout = shares * PAYOUT_PER_SHARE
-            let max_payout_u128 = shares
-                .checked_mul(PAYOUT_PER_SHARE)
-                .ok_or(Error::<T>::ArithmeticOverflow)?;
-            let max_payout: T::Balance = max_payout_u128.into();
-
-            // required_capital = max_payout - premium
-            let required_capital_u128 = max_payout_u128.saturating_sub(premium_u128);
-            let required_capital: T::Balance = required_capital_u128.into();
-
-            // Calculate required capital per share (for orderbook listing)
-            let premium_per_share_u128: u128 = res.premium_per_share.into();
-            let payout_per_share_u128 = PAYOUT_PER_SHARE;
-            let required_capital_per_share_u128 = payout_per_share_u128
-                .saturating_sub(premium_per_share_u128);
-            let required_capital_per_share: T::Balance = required_capital_per_share_u128.into();
-
-            // Create policy with hash-based ID
-            let now = Self::current_timestamp();
-            let nonce = AccountNonce::<T>::get(&who);
-            let policy_id = generate_unique_id(b"V1V2", &who, now, nonce);
-            AccountNonce::<T>::insert(&who, nonce + 1);
-
-            // Generate policy label using nonce (e.g., "manila-1" for nonce=0)
-            let policy_label = Self::generate_policy_label(req.market_id, nonce);
+            // The quote must price exactly the added window on the policy's terms
+            ensure!(
+                req.market_id == policy.market_id
+                    && req.latitude == policy.latitude
+                    && req.longitude == policy.longitude
+                    && req.shares == policy.shares
+                    && req.policy_version == policy.policy_version
+                    && req.event_type == policy.event_type
+                    && req.early_trigger == policy.early_trigger
+                    && req.strike_mm == policy.strike_mm
+                    && req.coverage_start == policy.coverage_end
+                    && req.coverage_end == new_end,
+                Error::<T>::InvalidExtensionQuote
+            );
 
-            // Get strike value for V2 policies:
-            // - Use custom strike from quote if provided
-            // - Otherwise fall back to market's default strike
-            let strike_mm = if req.policy_version == prmx_primitives::PolicyVersion::V2 {
-                match req.strike_mm {
-                    Some(custom_strike) => Some(custom_strike),
-                    None => T::MarketsApi::strike_value(req.market_id).ok(),
-                }
-            } else {
-                None
-            };
+            ensure!(
+                !T::Pause::is_paused(PausableOperation::PolicyCreation),
+                Error::<T>::OperationPaused
+            );
+            ensure!(
+                T::MarketsApi::is_market_open(policy.market_id),
+                Error::<T>::MarketNotOpen
+            );
+            ensure!(
+                !T::OracleStatus::is_market_stale(policy.market_id),
+                Error::<T>::MarketOracleStale
+            );
 
-            let policy = PolicyInfo::<T> {
-                policy_id,
-                policy_label,
-                market_id: req.market_id,
-                holder: who.clone(),
-                coverage_start: req.coverage_start,
-                coverage_end: req.coverage_end,
-                shares,
-                latitude: req.latitude,
-                longitude: req.longitude,
-                status: PolicyStatus::Active,
-                premium_paid: premium,
-                max_payout,
-                created_at: now,
-                // V2 fields from quote
-                policy_version: req.policy_version,
-                event_type: req.event_type,
-                early_trigger: req.early_trigger,
-                oracle_status_v2: if req.policy_version == prmx_primitives::PolicyVersion::V2 {
-                    Some(prmx_primitives::V2OracleStatus::PendingMonitoring)
-                } else {
-                    None
-                },
-                strike_mm,
-            };
+            // Re-validate the extended window against the market's current rules
+            T::MarketsApi::validate_coverage_extension(
+                policy.market_id,
+                policy.coverage_start,
+                policy.coverage_end,
+                new_end,
+                now,
+            )?;
 
-            // Get pool account for this policy
-            let pool_account = Self::policy_pool_account(policy_id);
+            // Max payout is unchanged, so the pool only takes the added premium
+            let asset = Self::policy_asset(policy_id);
+            let premium = res.total_premium;
+            let fee_bp = ProtocolFeeBp::<T>::get(policy.market_id);
+            let split = fees::split_premium(premium.into(), policy.max_payout.into(), fee_bp);
+            let protocol_fee: T::Balance = split.fee.into();
+            let net_premium: T::Balance = split.net_premium.into();
 
-            // Transfer premium from user to pool
             T::Assets::transfer(
-                T::UsdtAssetId::get(),
+                asset,
                 &who,
-                &pool_account,
-                premium,
+                &Self::policy_pool_account(policy_id),
+                net_premium,
                 Preservation::Expendable,
             ).map_err(|_| Error::<T>::InsufficientFunds)?;
 
-            // Transfer DAO capital to pool
-            if required_capital > T::Balance::zero() {
+            if protocol_fee > T::Balance::zero() {
                 T::Assets::transfer(
-                    T::UsdtAssetId::get(),
-                    &T::DaoCapitalAccountId::get(),
-                    &pool_account,
-                    required_capital,
+                    asset,
+                    &who,
+                    &Self::treasury_account(),
+                    protocol_fee,
                     Preservation::Expendable,
-                ).map_err(|_| Error::<T>::InsufficientDaoCapital)?;
+                ).map_err(|_| Error::<T>::InsufficientFunds)?;
+
+                PolicyProtocolFees::<T>::mutate(policy_id, |total| {
+                    *total = (*total).saturating_add(protocol_fee);
+                });
+                CollectedFees::<T>::mutate(policy.market_id, |total| {
+                    let current: u128 = (*total).into();
+                    *total = current.saturating_add(split.fee).into();
+                });
             }
 
-            // Store policy
+            PolicyRiskPoolBalance::<T>::mutate(policy_id, |balance| {
+                *balance = (*balance).saturating_add(net_premium);
+            });
+
+            let previous_end = policy.coverage_end;
+            policy.coverage_end = new_end;
+            policy.premium_paid = policy.premium_paid.saturating_add(premium);
+            T::MarketsApi::record_premium(policy.market_id, premium.into());
             Policies::<T>::insert(policy_id, policy);
 
-            // Add to market index
-            PoliciesByMarket::<T>::mutate(req.market_id, |policies| {
-                let _ = policies.try_push(policy_id);
+            T::QuoteApi::consume_quote(quote_id)?;
+
+            Self::deposit_event(Event::PolicyExtended {
+                policy_id,
+                previous_end,
+                new_end,
+                premium,
+                protocol_fee,
             });
 
-            // Set pool balance
-            PolicyRiskPoolBalance::<T>::insert(policy_id, max_payout);
+            Ok(())
+        }
 
-            // Mint LP tokens to DAO for THIS POLICY (policy-specific LP tokens)
-            T::HoldingsApi::mint_lp_tokens(policy_id, &T::DaoAccountId::get(), shares)
-                .map_err(|_| Error::<T>::ArithmeticOverflow)?;
+        /// Move `shares` of an active policy from `from` to `to`. Used by the
+        /// secondary market once the buyer has paid.
+        pub fn do_transfer_position(
+            policy_id: PolicyId,
+            from: &T::AccountId,
+            to: &T::AccountId,
+            shares: u128,
+        ) -> DispatchResult {
+            let policy = Policies::<T>::get(policy_id)
+                .ok_or(Error::<T>::PolicyNotFound)?;
+            ensure!(
+                Self::is_transferable(&policy, T::TimeProvider::now_secs()),
+                Error::<T>::PolicyNotTransferable
+            );
+            ensure!(from != to, Error::<T>::TransferToSelf);
 
-            // Register DAO as LP holder for this policy (for automatic payout distribution)
-            T::HoldingsApi::register_lp_holder(policy_id, &T::DaoAccountId::get())
-                .map_err(|_| Error::<T>::ArithmeticOverflow)?;
+            PolicyBeneficiaries::<T>::try_mutate(policy_id, |beneficiaries| -> DispatchResult {
+                let mut list = beneficiaries.to_vec();
+                positions::move_shares(&mut list, &policy.holder, policy.shares, from, to, shares)
+                    .ok_or(Error::<T>::InsufficientPolicyShares)?;
+                *beneficiaries = list.try_into().map_err(|_| Error::<T>::TooManyBeneficiaries)?;
+                Ok(())
+            })?;
 
-            // Place DAO LP ask on orderbook for THIS POLICY's LP tokens
-            T::LpOrderbook::place_dao_lp_ask(
+            Self::deposit_event(Event::PositionTransferred {
                 policy_id,
-                &T::DaoAccountId::get(),
-                required_capital_per_share,
+                from: from.clone(),
+                to: to.clone(),
                 shares,
-            )?;
+            });
 
-            // Consume the quote
-            T::QuoteApi::consume_quote(quote_id)?;
+            Ok(())
+        }
 
-            // Emit events
-            Self::deposit_event(Event::PolicyCreated {
+        /// Count a new policy's notional against its market and location
+        fn add_notional(market_id: MarketId, location: LocationCell, notional: T::Balance) {
+            let notional: u128 = notional.into();
+            MarketNotional::<T>::mutate(market_id, |total| {
+                let current: u128 = (*total).into();
+                *total = current.saturating_add(notional).into();
+            });
+            LocationNotional::<T>::mutate(location, |total| {
+                let current: u128 = (*total).into();
+                *total = current.saturating_add(notional).into();
+            });
+        }
+
+        /// Release a closed policy's notional from its market and location
+        fn release_notional(market_id: MarketId, location: LocationCell, notional: T::Balance) {
+            let notional: u128 = notional.into();
+            MarketNotional::<T>::mutate_exists(market_id, |total| {
+                let current: u128 = total.map(Into::into).unwrap_or_default();
+                let remaining = current.saturating_sub(notional);
+                *total = (remaining > 0).then(|| remaining.into());
+            });
+            LocationNotional::<T>::mutate_exists(location, |total| {
+                let current: u128 = total.map(Into::into).unwrap_or_default();
+                let remaining = current.saturating_sub(notional);
+                *total = (remaining > 0).then(|| remaining.into());
+            });
+        }
+
+        /// Get all policies for a market
+        pub fn get_policies_for_market(market_id: MarketId) -> Vec<PolicyId> {
+            PoliciesByMarket::<T>::get(market_id).into_inner()
+        }
+
+        /// Get policy info
+        pub fn get_policy(policy_id: PolicyId) -> Option<PolicyInfo<T>> {
+            Policies::<T>::get(policy_id)
+        }
+
+        /// Whether a policy can change hands: active, coverage not yet ended, (for V2)
+        /// no oracle report pending settlement, and no settlement left part way
+        pub fn is_transferable(policy: &PolicyInfo<T>, now: u64) -> bool {
+            let awaiting_settlement = matches!(
+                policy.oracle_status_v2,
+                Some(prmx_primitives::V2OracleStatus::TriggeredReported)
+                    | Some(prmx_primitives::V2OracleStatus::MaturedReported)
+                    | Some(prmx_primitives::V2OracleStatus::Settled)
+            );
+
+            policy.status == PolicyStatus::Active
+                && now < policy.coverage_end
+                && !awaiting_settlement
+                && !Self::is_settling(policy.policy_id)
+        }
+
+        /// Whether a policy's settlement has started but not reached `Finalized`.
+        /// Its pool may already be unwound or paid out while it is still `Active`.
+        pub fn is_settling(policy_id: PolicyId) -> bool {
+            SettlementProgresses::<T>::get(policy_id)
+                .is_some_and(|progress| progress.stage != SettlementStage::Finalized)
+        }
+
+        /// Check if policy is active
+        pub fn is_policy_active(policy_id: PolicyId) -> bool {
+            Policies::<T>::get(policy_id)
+                .map(|p| p.status == PolicyStatus::Active)
+                .unwrap_or(false)
+        }
+
+        /// Internal settlement function - performs the actual settlement logic
+        /// without oracle observations. Returns the payout amount on success
+        pub fn do_settle_policy(
+            policy_id: PolicyId,
+            event_occurred: bool,
+            reason: SettlementReason,
+        ) -> Result<T::Balance, DispatchError> {
+            Self::do_settle_policy_with_observation(
                 policy_id,
-                market_id: req.market_id,
-                holder: who,
-                shares,
+                event_occurred,
+                SettlementRecord::without_data(reason, None),
+            )
+        }
+
+        /// Settle one policy of a `force_settle_batch` and record its reason code.
+        /// V2 policies are marked settled so the off-chain oracle stops reporting.
+        fn do_force_settle_policy(
+            policy_id: PolicyId,
+            outcome: prmx_primitives::V2Outcome,
+            reason_code: ForceSettleReason,
+        ) -> Result<T::Balance, DispatchError> {
+            let event_occurred = matches!(outcome, prmx_primitives::V2Outcome::Triggered);
+            let payout = Self::do_settle_policy(
+                policy_id,
+                event_occurred,
+                SettlementReason::GovernanceForced,
+            )?;
+
+            Policies::<T>::mutate(policy_id, |policy| {
+                if let Some(policy) = policy {
+                    if policy.policy_version == prmx_primitives::PolicyVersion::V2 {
+                        policy.oracle_status_v2 = Some(prmx_primitives::V2OracleStatus::Settled);
+                    }
+                }
             });
+            ForcedSettlements::<T>::insert(policy_id, reason_code);
 
-            // Emit V2PolicyCreated for off-chain oracle to pick up
-            if req.policy_version == prmx_primitives::PolicyVersion::V2 {
-                if let Some(strike) = strike_mm {
-                    Self::deposit_event(Event::V2PolicyCreated {
+            Ok(payout)
+        }
+
+        /// Settlement record for a V2 oracle report
+        pub fn v2_settlement_record(cumulative_mm: u32, evidence_hash: [u8; 32]) -> SettlementRecord {
+            SettlementRecord {
+                evidence_hash,
+                ..SettlementRecord::without_data(SettlementReason::OracleReport, Some(cumulative_mm))
+            }
+        }
+
+        /// Payout a policy is entitled to when the event occurred. Tiered policies pay
+        /// the highest tier reached by `observed_mm`; without an observation the top
+        /// tier applies.
+        pub fn entitled_payout(policy: &PolicyInfo<T>, observed_mm: Option<Millimeters>) -> T::Balance {
+            if policy.payout_tiers.is_empty() {
+                return policy.max_payout;
+            }
+
+            let bp = match observed_mm {
+                Some(observed) => tiers::payout_bp(&policy.payout_tiers, observed),
+                None => policy.payout_tiers.last().map(|tier| tier.payout_bp).unwrap_or(0),
+            };
+            tiers::apply_bp(policy.max_payout.into(), bp).into()
+        }
+
+        /// Payout `do_settle_policy_with_observation` would make, without changing state:
+        /// (payout, of which to secondary-market positions, returned to LP holders).
+        /// The pool is taken at its risk pool balance, i.e. as restored once DeFi
+        /// capital is unwound; reinsurance contributions are not included.
+        pub fn preview_settlement_payout(
+            policy_id: PolicyId,
+            event_occurred: bool,
+            observed_mm: Option<Millimeters>,
+        ) -> Option<(T::Balance, T::Balance, T::Balance)> {
+            let policy = Policies::<T>::get(policy_id)?;
+            if policy.status != PolicyStatus::Active && policy.status != PolicyStatus::Expired {
+                return None;
+            }
+
+            let pool_balance: u128 = PolicyRiskPoolBalance::<T>::get(policy_id).into();
+            if !event_occurred {
+                return Some((Zero::zero(), Zero::zero(), pool_balance.into()));
+            }
+
+            let payout = Self::entitled_payout(&policy, observed_mm).into().min(pool_balance);
+            let (_, to_holder) = positions::split_payout(
+                payout,
+                policy.shares,
+                &PolicyBeneficiaries::<T>::get(policy_id),
+            );
+
+            Some((
+                payout.into(),
+                payout.saturating_sub(to_holder).into(),
+                pool_balance.saturating_sub(payout).into(),
+            ))
+        }
+
+        /// Settlement with the maximum observed metric in `record.observed_mm`, used for
+        /// tiered payouts. Whatever the holder is not paid goes back to LP holders.
+        /// `record` is stored in `SettlementRecords` and emitted with `PolicySettledV2`.
+        ///
+        /// Runs the settlement stages in order, resuming a settlement an earlier
+        /// attempt left part way with the outcome and record it started with;
+        /// `event_occurred` and `record` then only apply to a fresh settlement.
+        /// Only attempts made outside a transaction (the oracle's `on_initialize`
+        /// sweeps and `resume_partial_settlements`) leave one part way; when the
+        /// caller's storage layer rolls back, its recorded progress goes with it
+        /// (see `settlement`).
+        pub fn do_settle_policy_with_observation(
+            policy_id: PolicyId,
+            event_occurred: bool,
+            record: SettlementRecord,
+        ) -> Result<T::Balance, DispatchError> {
+            // Every settlement path goes through here
+            ensure!(
+                !T::Pause::is_paused(PausableOperation::Settlements),
+                Error::<T>::OperationPaused
+            );
+
+            // Capital, reinsurance and payout routing call into other pallets; none
+            // of them may start another settlement while this one runs
+            ensure!(ActiveSettlement::<T>::get().is_none(), Error::<T>::SettlementInProgress);
+            ActiveSettlement::<T>::put(policy_id);
+            let result = Self::advance_settlement(policy_id, event_occurred, record);
+            ActiveSettlement::<T>::kill();
+
+            result
+        }
+
+        /// Run the remaining settlement stages of a policy, each in its own storage
+        /// layer, recording each completed stage in `SettlementProgresses` until the
+        /// last one removes the entry
+        fn advance_settlement(
+            policy_id: PolicyId,
+            event_occurred: bool,
+            record: SettlementRecord,
+        ) -> Result<T::Balance, DispatchError> {
+            // Load policy
+            let policy = Policies::<T>::get(policy_id)
+                .ok_or(Error::<T>::PolicyNotFound)?;
+
+            // Ensure policy is active or expired (not already settled)
+            ensure!(
+                policy.status == PolicyStatus::Active || policy.status == PolicyStatus::Expired,
+                Error::<T>::PolicyAlreadySettled
+            );
+
+            let now = T::TimeProvider::now_secs();
+
+            // A resumed settlement keeps the outcome and record it started with,
+            // whatever this attempt was asked to settle on
+            let mut progress = match SettlementProgresses::<T>::get(policy_id) {
+                Some(progress) => {
+                    if progress.event_occurred != event_occurred {
+                        log::warn!(
+                            target: "prmx-policy",
+                            "⚠️ Resuming settlement of policy {} with its recorded outcome {} \
+                             (attempted with {})",
+                            policy_id,
+                            progress.event_occurred,
+                            event_occurred
+                        );
+                    }
+                    progress
+                }
+                None => SettlementProgress::new(event_occurred, record, now),
+            };
+
+            progress.advance(
+                |stage, progress| {
+                    frame_support::storage::with_storage_layer(|| match stage {
+                        SettlementStage::Pending => {
+                            Self::ensure_settlement_liquidity(policy_id, &policy, progress)
+                        }
+                        SettlementStage::LiquidityEnsured => {
+                            Self::pay_out_settlement(policy_id, &policy, progress, now)
+                        }
+                        SettlementStage::PaidOut => {
+                            Self::finalize_settlement(policy_id, policy.clone(), progress, now)
+                        }
+                        SettlementStage::Finalized => Ok(()),
+                    })
+                },
+                |progress| {
+                    if progress.stage == SettlementStage::Finalized {
+                        SettlementProgresses::<T>::remove(policy_id);
+                    } else {
+                        SettlementProgresses::<T>::insert(policy_id, progress);
+                    }
+                    Self::deposit_event(Event::SettlementStageAdvanced {
                         policy_id,
-                        market_id: req.market_id,
-                        coverage_start: req.coverage_start,
-                        coverage_end: req.coverage_end,
-                        strike_mm: strike,
-                        latitude: req.latitude,
-                        longitude: req.longitude,
+                        stage: progress.stage,
                     });
-                }
-            }
+                },
+            )?;
 
-            Self::deposit_event(Event::CapitalLocked {
+            Ok(progress.payout_to_holder)
+        }
+
+        /// `Pending` → `LiquidityEnsured`: bring the pool's capital back on chain
+        /// and collect any reinsurance share of the payout
+        fn ensure_settlement_liquidity(
+            policy_id: PolicyId,
+            policy: &PolicyInfo<T>,
+            progress: &SettlementProgress<T::Balance, SettlementRecord>,
+        ) -> DispatchResult {
+            // =========================================================================
+            // DeFi Integration: Ensure local liquidity before settlement
+            // =========================================================================
+            // Before settlement, we need to ensure the policy pool has enough
+            // local USDT to fulfill obligations. This unwinds any LP positions
+            // and has the DAO top up any shortfall from DeFi losses.
+            log::info!(
+                target: "prmx-policy",
+                "💰 Ensuring local liquidity for policy {} (required: {} USDT)",
                 policy_id,
-                user_premium: premium,
-                dao_capital: required_capital,
-            });
+                policy.max_payout.into()
+            );
+
+            T::CapitalApi::ensure_local_liquidity(policy_id, policy.max_payout)?;
+
+            // The reinsurance pool pays its share of a triggered payout into the
+            // policy pool first; any excess over the payout goes to LP holders
+            if progress.event_occurred {
+                let payout_due = Self::entitled_payout(policy, progress.record.observed_mm);
+                let pool_account = Self::policy_pool_account(policy_id);
+                T::Reinsurance::on_policy_payout(policy_id, &pool_account, payout_due)?;
+            }
+
+            Ok(())
+        }
+
+        /// `LiquidityEnsured` → `PaidOut`: pay the holder, positions and splits,
+        /// and return the rest of the pool to LP holders
+        fn pay_out_settlement(
+            policy_id: PolicyId,
+            policy: &PolicyInfo<T>,
+            progress: &mut SettlementProgress<T::Balance, SettlementRecord>,
+            now: u64,
+        ) -> DispatchResult {
+            let pool_account = Self::policy_pool_account(policy_id);
+
+            // After unwinding, get the ACTUAL on-chain pool balance
+            // This may be less than max_payout if DAO couldn't cover full DeFi loss
+            let asset = Self::policy_asset(policy_id);
+            let pool_balance = T::Assets::balance(asset, &pool_account);
+            
+            log::info!(
+                target: "prmx-policy",
+                "📊 Pool balance after DeFi unwind: {} USDT (max_payout was {})",
+                pool_balance.into(),
+                policy.max_payout.into()
+            );
 
-            Self::deposit_event(Event::LpTokensMinted {
-                policy_id,
-                shares,
-            });
+            if !progress.event_occurred {
+                // Event did not occur - distribute pool to LP holders pro-rata
+                PolicyInstallmentPlans::<T>::remove(policy_id);
+                PolicyPayoutSplits::<T>::remove(policy_id);
+                
+                // Distribute directly from policy pool to all LP holders OF THIS POLICY
+                T::HoldingsApi::distribute_to_lp_holders(
+                    policy_id,
+                    &pool_account,
+                    pool_balance,
+                ).map_err(|_| Error::<T>::TransferFailed)?;
 
-            Self::deposit_event(Event::DaoLpAskPlaced {
-                policy_id,
-                price_per_share: required_capital_per_share,
-                quantity: shares,
-            });
+                progress.payout_to_holder = T::Balance::zero();
+                progress.returned_to_lps = pool_balance;
+                return Ok(());
+            }
 
-            // Auto-allocate policy capital to DeFi strategy (Hydration Pool 102)
-            // Uses the configured allocation percentage (default 100%)
-            if let Err(e) = T::CapitalApi::auto_allocate_policy_capital(policy_id, max_payout) {
+            // Event occurred - pay out to policy holder
+            // In case of DAO insolvency, pool may have less than max_payout
+            // Pay out what's available in the pool
+            let entitled = Self::entitled_payout(policy, progress.record.observed_mm);
+            let payout = if pool_balance < entitled {
                 log::warn!(
                     target: "prmx-policy",
-                    "⚠️ Auto-allocation to DeFi failed for policy {}: {:?}",
+                    "⚠️ Pool has {} USDT but payout due is {} USDT - paying out available balance",
+                    pool_balance.into(),
+                    entitled.into()
+                );
+                pool_balance
+            } else {
+                entitled
+            };
+
+            // Pay secondary-market positions their share, then the holder the rest.
+            // `policy.holder` is the current owner, including after `transfer_policy`.
+            let beneficiaries = PolicyBeneficiaries::<T>::get(policy_id);
+            let (position_payouts, to_holder) =
+                positions::split_payout(payout.into(), policy.shares, &beneficiaries);
+            for (beneficiary, amount) in position_payouts {
+                if amount == 0 {
+                    continue;
+                }
+                let amount: T::Balance = amount.into();
+                T::Assets::transfer(
+                    asset,
+                    &pool_account,
+                    &beneficiary,
+                    amount,
+                    frame_support::traits::tokens::Preservation::Expendable,
+                ).map_err(|_| Error::<T>::TransferFailed)?;
+                Self::deposit_event(Event::PositionPaid {
                     policy_id,
-                    e
+                    beneficiary,
+                    amount,
+                });
+            }
+
+            // In markets with claim verification an unverified holder's share stays
+            // in the pool, with any installment plan or splits, until governance
+            // releases or denies it
+            let verified = to_holder == 0
+                || !ClaimVerificationEnabled::<T>::get(policy.market_id)
+                || T::ClaimVerifier::verify_claim(
+                    policy_id,
+                    policy.market_id,
+                    &policy.holder,
+                    to_holder,
                 );
-                // Don't fail policy creation if auto-allocation fails
-                // The DAO can manually allocate later
+            if verified {
+                Self::deliver_holder_payout(policy_id, &policy.holder, to_holder, now)?;
+            } else {
+                HeldPayouts::<T>::insert(policy_id, HeldPayoutInfo {
+                    claimant: policy.holder.clone(),
+                    amount: to_holder.into(),
+                    held_at: now,
+                });
+                Self::deposit_event(Event::PayoutHeld {
+                    policy_id,
+                    claimant: policy.holder.clone(),
+                    amount: to_holder.into(),
+                });
+            }
+
+            // A partial (tiered) payout leaves a remainder for LP holders
+            let returned_to_lps: T::Balance = pool_balance.into().saturating_sub(payout.into()).into();
+            if returned_to_lps > T::Balance::zero() {
+                T::HoldingsApi::distribute_to_lp_holders(
+                    policy_id,
+                    &pool_account,
+                    returned_to_lps,
+                ).map_err(|_| Error::<T>::TransferFailed)?;
             }
 
+            progress.payout_to_holder = payout;
+            progress.returned_to_lps = returned_to_lps;
+
             Ok(())
         }
 
-        /// Settle a policy after coverage window has ended.
-        /// This is permissionless - anyone can call it once conditions are met.
-        /// 
-        /// - `policy_id`: The policy to settle.
-        /// - `event_occurred`: Whether the rainfall event occurred (from oracle).
-        #[pallet::call_index(1)]
-        #[pallet::weight(50_000)]
-        pub fn settle_policy(
-            origin: OriginFor<T>,
+        /// `PaidOut` → `Finalized`: burn the LP tokens, mark the policy settled and
+        /// release it from capital, reinsurance and the exposure caps
+        fn finalize_settlement(
             policy_id: PolicyId,
-            event_occurred: bool, // In production, this would come from oracle
+            mut policy: PolicyInfo<T>,
+            progress: &SettlementProgress<T::Balance, SettlementRecord>,
+            now: u64,
         ) -> DispatchResult {
-            // Permissionless - anyone can settle
-            let _who = ensure_signed(origin)?;
+            let market_id = policy.market_id;
+            let location = exposure::location_cell(policy.latitude, policy.longitude);
+            let notional = policy.max_payout;
+            let premium = policy.premium_paid;
+
+            // Update storage
+            PolicyRiskPoolBalance::<T>::insert(policy_id, T::Balance::zero());
+            policy.status = PolicyStatus::Settled;
+            Policies::<T>::insert(policy_id, policy);
 
-            // Load policy
-            let policy = Policies::<T>::get(policy_id)
-                .ok_or(Error::<T>::PolicyNotFound)?;
+            // Cleanup LP tokens (burn all LP tokens for this policy)
+            T::HoldingsApi::cleanup_policy_lp_tokens(policy_id)
+                .map_err(|_| Error::<T>::TransferFailed)?;
 
-            // Ensure policy is active or expired (not already settled)
-            ensure!(
-                policy.status == PolicyStatus::Active || policy.status == PolicyStatus::Expired,
-                Error::<T>::PolicyAlreadySettled
-            );
+            // Store settlement result
+            SettlementResults::<T>::insert(policy_id, SettlementResult {
+                event_occurred: progress.event_occurred,
+                payout_to_holder: progress.payout_to_holder,
+                returned_to_lps: progress.returned_to_lps,
+                settled_at: now,
+            });
 
-            // Check coverage window has ended
-            let now = Self::current_timestamp();
-            log::info!(
-                target: "prmx-policy",
-                "🔍 Settlement check - now: {}, coverage_end: {}, comparison: {}",
-                now,
-                policy.coverage_end,
-                now >= policy.coverage_end
-            );
-            ensure!(
-                now >= policy.coverage_end,
-                Error::<T>::CoverageNotEnded
-            );
+            if progress.event_occurred {
+                T::MarketsApi::record_payout(market_id, progress.payout_to_holder.into());
+                Self::deposit_event(Event::PolicySettled {
+                    policy_id,
+                    payout_to_holder: progress.payout_to_holder,
+                });
+            } else {
+                Self::deposit_event(Event::PolicyExpiredNoEvent {
+                    policy_id,
+                    residual_to_pool: progress.returned_to_lps,
+                });
+            }
 
-            // Call internal settlement function
-            Self::do_settle_policy(policy_id, event_occurred)?;
+            // =========================================================================
+            // DeFi Integration: Notify CapitalApi of settlement completion
+            // =========================================================================
+            // Perform any final cleanup for the policy's capital management state.
+            T::CapitalApi::on_policy_settled(policy_id)?;
+            T::Reinsurance::on_policy_closed(policy_id);
+
+            Self::release_notional(market_id, location, notional);
+            PolicyProtocolFees::<T>::remove(policy_id);
+            MarketSettlementStats::<T>::mutate(market_id, |stats| {
+                analytics::record_settlement(
+                    stats,
+                    progress.event_occurred,
+                    premium,
+                    progress.payout_to_holder,
+                    progress.record.observed_mm,
+                )
+            });
+
+            Self::deposit_event(Event::PolicySettledV2 {
+                policy_id,
+                event_occurred: progress.event_occurred,
+                payout_to_holder: progress.payout_to_holder,
+                reason: progress.record.reason,
+                observed_mm: progress.record.observed_mm,
+                providers: progress.record.providers.clone(),
+                evidence_hash: progress.record.evidence_hash,
+            });
+            SettlementRecords::<T>::insert(policy_id, progress.record.clone());
 
             Ok(())
         }
 
-        /// Trigger immediate settlement for a policy when threshold is exceeded.
-        /// This is called by the Oracle pallet when automatic settlement is triggered.
-        /// Does NOT require coverage window to have ended.
-        /// 
-        /// - `policy_id`: The policy to settle immediately.
-        #[pallet::call_index(2)]
-        #[pallet::weight(50_000)]
-        pub fn trigger_immediate_settlement(
-            origin: OriginFor<T>,
+        /// Pay the holder's share of a triggered payout from the pool (only if
+        /// there's something to transfer)
+        fn deliver_holder_payout(
             policy_id: PolicyId,
+            holder: &T::AccountId,
+            to_holder: u128,
+            now: u64,
         ) -> DispatchResult {
-            // For now, allow root origin (oracle will call via internal function)
-            // In production, this would be restricted to OracleOrigin
-            ensure_root(origin)?;
-
-            // Load policy
-            let policy = Policies::<T>::get(policy_id)
-                .ok_or(Error::<T>::PolicyNotFound)?;
+            let pool_account = Self::policy_pool_account(policy_id);
+            let asset = Self::policy_asset(policy_id);
+
+            // With an installment plan the holder's share stays in the pool and
+            // is released from now on; with payout splits it goes to the
+            // beneficiaries instead.
+            let installment_plan = PolicyInstallmentPlans::<T>::take(policy_id);
+            match (installment_plan, PolicyPayoutSplits::<T>::take(policy_id)) {
+                (_, Some(splits)) if to_holder > 0 => {
+                    for (beneficiary, amount) in splits::split_amount(to_holder, &splits) {
+                        if amount == 0 {
+                            continue;
+                        }
+                        let amount: T::Balance = amount.into();
+                        T::Assets::transfer(
+                            asset,
+                            &pool_account,
+                            &beneficiary,
+                            amount,
+                            frame_support::traits::tokens::Preservation::Expendable,
+                        ).map_err(|_| Error::<T>::TransferFailed)?;
+                        Self::deposit_event(Event::PayoutSplitPaid {
+                            policy_id,
+                            beneficiary,
+                            amount,
+                        });
+                    }
+                }
+                (Some(plan), _) if to_holder > 0 => {
+                    Self::schedule_payout(policy_id, holder, to_holder.into(), plan, now)?;
+                }
+                _ if to_holder > 0 => {
+                    // The holder may have the payout delivered to another chain
+                    let routed = T::PayoutRouter::route_payout(
+                        policy_id,
+                        holder,
+                        &pool_account,
+                        to_holder.into(),
+                    )?;
+                    if !routed {
+                        T::Assets::transfer(
+                            asset,
+                            &pool_account,
+                            holder,
+                            to_holder.into(),
+                            frame_support::traits::tokens::Preservation::Expendable,
+                        ).map_err(|_| Error::<T>::TransferFailed)?;
+                    }
+                }
+                _ => {}
+            }
 
-            // Ensure policy is active (not already settled or cancelled)
-            ensure!(
-                policy.status == PolicyStatus::Active,
-                Error::<T>::PolicyAlreadySettled
-            );
+            Ok(())
+        }
 
-            log::info!(
-                target: "prmx-policy",
-                "⚡ Immediate settlement triggered for policy {} (threshold exceeded)",
-                policy_id
-            );
+        /// Defer `total` to `beneficiary` under `plan` and pay the first installment
+        fn schedule_payout(
+            policy_id: PolicyId,
+            beneficiary: &T::AccountId,
+            total: T::Balance,
+            plan: InstallmentPlan,
+            now: u64,
+        ) -> DispatchResult {
+            PayoutSchedule::<T>::insert(policy_id, PayoutScheduleInfo {
+                beneficiary: beneficiary.clone(),
+                total,
+                released: T::Balance::zero(),
+                released_installments: 0,
+                plan,
+                start: now,
+            });
 
-            // Call internal settlement function with event_occurred = true
-            Self::do_settle_policy(policy_id, true)?;
+            Self::deposit_event(Event::PayoutScheduled {
+                policy_id,
+                beneficiary: beneficiary.clone(),
+                total,
+                installments: plan.installments,
+                interval_secs: plan.interval_secs,
+            });
 
+            Self::release_installments(policy_id, now)?;
             Ok(())
         }
 
-        /// Settle a V2 policy based on off-chain oracle report.
-        /// 
-        /// Only authorized V2 oracle reporters can call this.
-        /// V2 policies are completely isolated from V1 settlement.
-        ///
-        /// - `policy_id`: The V2 policy to settle.
-        /// - `outcome`: Triggered or MaturedNoEvent.
-        /// - `observed_at`: Timestamp when the outcome was determined.
-        /// - `cumulative_mm`: Cumulative rainfall in tenths of mm.
-        /// - `evidence_hash`: SHA256 hash of off-chain evidence JSON.
-        #[pallet::call_index(3)]
-        #[pallet::weight(100_000)]
-        pub fn settle_v2_policy(
-            origin: OriginFor<T>,
-            policy_id: PolicyId,
-            outcome: prmx_primitives::V2Outcome,
-            observed_at: u64,
-            cumulative_mm: u32,
-            evidence_hash: [u8; 32],
-        ) -> DispatchResult {
-            // Only V2 oracle origin can call this
-            T::V2OracleOrigin::ensure_origin(origin.clone())?;
+        /// Pay out the installments of a schedule matured by `now`. Returns the
+        /// amount released (zero if none matured since the last release).
+        pub fn release_installments(policy_id: PolicyId, now: u64) -> Result<T::Balance, DispatchError> {
+            let mut schedule = PayoutSchedule::<T>::get(policy_id)
+                .ok_or(Error::<T>::NoPayoutSchedule)?;
 
-            // Load policy
-            let mut policy = Policies::<T>::get(policy_id)
-                .ok_or(Error::<T>::PolicyNotFound)?;
+            let matured = installments::matured_installments(&schedule.plan, schedule.start, now);
+            if matured <= schedule.released_installments {
+                return Ok(T::Balance::zero());
+            }
 
-            // Verify this is a V2 policy
-            ensure!(
-                policy.policy_version == prmx_primitives::PolicyVersion::V2,
-                Error::<T>::NotV2Policy
-            );
+            let total: u128 = schedule.total.into();
+            let released: u128 = schedule.released.into();
+            let amount = installments::cumulative_due(total, schedule.plan.installments, matured)
+                .saturating_sub(released);
 
-            // Verify policy is active
-            ensure!(
-                policy.status == PolicyStatus::Active,
-                Error::<T>::V2PolicyNotActive
-            );
+            if amount > 0 {
+                T::Assets::transfer(
+                    Self::policy_asset(policy_id),
+                    &Self::policy_pool_account(policy_id),
+                    &schedule.beneficiary,
+                    amount.into(),
+                    Preservation::Expendable,
+                ).map_err(|_| Error::<T>::TransferFailed)?;
+            }
 
-            // Verify no report already submitted
-            ensure!(
-                !V2FinalReport::<T>::contains_key(policy_id),
-                Error::<T>::V2ReportAlreadySubmitted
+            let released = released.saturating_add(amount);
+            let remaining = total.saturating_sub(released);
+            let queued_due = installments::next_due(
+                &schedule.plan,
+                schedule.start,
+                schedule.released_installments,
             );
+            schedule.released = released.into();
+            schedule.released_installments = matured;
 
-            // Validate report based on outcome
-            match outcome {
-                prmx_primitives::V2Outcome::Triggered => {
-                    // observed_at must be within coverage window
-                    ensure!(
-                        observed_at >= policy.coverage_start && observed_at <= policy.coverage_end,
-                        Error::<T>::InvalidObservedAt
-                    );
-                    // cumulative_mm must meet or exceed strike
-                    if let Some(strike) = policy.strike_mm {
-                        ensure!(cumulative_mm >= strike, Error::<T>::ThresholdNotMet);
-                    }
-                }
-                prmx_primitives::V2Outcome::MaturedNoEvent => {
-                    // observed_at must be at or after coverage end
-                    ensure!(
-                        observed_at >= policy.coverage_end,
-                        Error::<T>::CoverageNotEnded
-                    );
+            if let Some(due) = queued_due {
+                InstallmentQueue::<T>::remove(installments::release_bucket(due), policy_id);
+            }
+            match installments::next_due(&schedule.plan, schedule.start, matured) {
+                Some(due) => {
+                    PayoutSchedule::<T>::insert(policy_id, &schedule);
+                    Self::enqueue_installment(policy_id, due);
                 }
+                None => PayoutSchedule::<T>::remove(policy_id),
             }
 
-            // Get reporter account (for the report record)
-            let reporter = match ensure_signed(origin) {
-                Ok(acc) => acc,
-                Err(_) => T::DaoAccountId::get(), // Root origin uses DAO account
-            };
+            Self::deposit_event(Event::InstallmentReleased {
+                policy_id,
+                beneficiary: schedule.beneficiary,
+                amount: amount.into(),
+                remaining: remaining.into(),
+            });
 
-            let now = Self::current_timestamp();
+            Ok(amount.into())
+        }
 
-            // Store the immutable V2 report
-            let report = prmx_primitives::V2Report {
-                outcome: outcome.clone(),
-                observed_at,
-                cumulative_mm,
-                evidence_hash,
-                reporter,
-                submitted_at: now,
+        /// Resume up to MAX_SETTLEMENT_RESUMES_PER_BLOCK settlements an oracle sweep
+        /// left part way, walking `SettlementProgresses` from `SettlementResumeCursor`.
+        /// Called from on_initialize every BLOCKS_PER_SETTLEMENT_RESUME blocks.
+        pub fn resume_partial_settlements() -> Weight {
+            let mut weight = T::DbWeight::get().reads(1);
+            let max_resumes = MAX_SETTLEMENT_RESUMES_PER_BLOCK as usize;
+            let iter = match SettlementResumeCursor::<T>::get() {
+                Some(last) => SettlementProgresses::<T>::iter_from(
+                    SettlementProgresses::<T>::hashed_key_for(last),
+                ),
+                None => SettlementProgresses::<T>::iter(),
             };
-            V2FinalReport::<T>::insert(policy_id, report);
+            let batch: Vec<_> = iter.take(max_resumes).collect();
 
-            // Update policy oracle status
-            policy.oracle_status_v2 = Some(match outcome {
-                prmx_primitives::V2Outcome::Triggered => prmx_primitives::V2OracleStatus::TriggeredReported,
-                prmx_primitives::V2Outcome::MaturedNoEvent => prmx_primitives::V2OracleStatus::MaturedReported,
-            });
-
-            // Perform actual settlement using existing mechanics
-            let event_occurred = matches!(outcome, prmx_primitives::V2Outcome::Triggered);
-            let payout = Self::do_settle_policy(policy_id, event_occurred)?;
-
-            // Update oracle status to Settled
-            if let Some(mut p) = Policies::<T>::get(policy_id) {
-                p.oracle_status_v2 = Some(prmx_primitives::V2OracleStatus::Settled);
-                Policies::<T>::insert(policy_id, p);
+            match batch.last() {
+                Some((last, _)) if batch.len() == max_resumes => {
+                    SettlementResumeCursor::<T>::put(last)
+                }
+                _ => SettlementResumeCursor::<T>::kill(),
             }
 
-            // Emit V2-specific settlement event
-            Self::deposit_event(Event::V2PolicySettled {
-                policy_id,
-                outcome,
-                cumulative_mm,
-                evidence_hash,
-            });
-
-            log::info!(
-                target: "prmx-policy",
-                "✅ V2 policy {} settled: {:?}, cumulative_mm={}, payout={}",
-                policy_id,
-                outcome,
-                cumulative_mm,
-                payout.into()
-            );
+            for (policy_id, progress) in batch {
+                // Outside a storage layer, so each stage that completes stays recorded
+                let result = Self::do_settle_policy_with_observation(
+                    policy_id,
+                    progress.event_occurred,
+                    progress.record,
+                );
 
-            Ok(())
-        }
-    }
+                if let Err(e) = result {
+                    log::warn!(
+                        target: "prmx-policy",
+                        "❌ Failed to resume settlement of policy {} at {:?}: {:?}",
+                        policy_id,
+                        progress.stage,
+                        e
+                    );
+                }
 
-    // =========================================================================
-    //                           Helper Functions
-    // =========================================================================
+                weight = weight.saturating_add(T::WeightInfo::settle_policy(
+                    T::MaxPolicyBeneficiaries::get().saturating_add(MaxPayoutSplits::get()),
+                ));
+            }
 
-    impl<T: Config> Pallet<T> {
-        /// Get the derived account for a policy's capital pool
-        pub fn policy_pool_account(policy_id: PolicyId) -> T::AccountId {
-            PALLET_ID.into_sub_account_truncating(("policy", policy_id))
+            weight
         }
 
-        /// Get the derived account for a market's residual pool
-        pub fn market_residual_account(market_id: MarketId) -> T::AccountId {
-            PALLET_ID.into_sub_account_truncating(("market", market_id))
+        /// Release matured installments of up to MAX_INSTALLMENT_RELEASES_PER_BLOCK
+        /// schedules. Called from on_initialize every BLOCKS_PER_INSTALLMENT_CHECK blocks;
+        /// drains `InstallmentQueue` bucket by bucket from `InstallmentCursor`.
+        pub fn release_due_installments() -> Weight {
+            let now = T::TimeProvider::now_secs();
+            let mut weight = T::DbWeight::get().reads(2);
+
+            let Some(mut bucket) = InstallmentCursor::<T>::get() else {
+                return weight;
+            };
+            let current = installments::release_bucket(now);
+            let mut remaining = MAX_INSTALLMENT_RELEASES_PER_BLOCK;
+            let mut buckets = 0u32;
+
+            while bucket <= current
+                && remaining > 0
+                && buckets < MAX_INSTALLMENT_BUCKETS_PER_BLOCK
+            {
+                buckets += 1;
+                let queued: Vec<(PolicyId, u64)> = InstallmentQueue::<T>::iter_prefix(bucket)
+                    .take(remaining as usize)
+                    .collect();
+                // The bucket's entries, and whether any are left afterwards
+                weight = weight.saturating_add(T::DbWeight::get().reads(2));
+
+                let mut pending = false;
+                for (policy_id, due) in queued {
+                    remaining -= 1;
+                    // Only the current bucket can hold installments not yet due
+                    if now < due {
+                        pending = true;
+                        weight = weight.saturating_add(T::DbWeight::get().reads(1));
+                        continue;
+                    }
+
+                    InstallmentQueue::<T>::remove(bucket, policy_id);
+                    // Roll back partial writes if the transfer fails
+                    let result = frame_support::storage::with_storage_layer(|| {
+                        Self::release_installments(policy_id, now)
+                    });
+
+                    if let Err(e) = result {
+                        log::warn!(
+                            target: "prmx-policy",
+                            "❌ Failed to release installment for policy {}: {:?}",
+                            policy_id,
+                            e
+                        );
+                        // Retried by a later sweep; the beneficiary can also claim it
+                        if PayoutSchedule::<T>::contains_key(policy_id) {
+                            let retry = current.saturating_add(1);
+                            InstallmentQueue::<T>::insert(retry, policy_id, due);
+                        }
+                    }
+
+                    weight = weight.saturating_add(T::WeightInfo::release_installment());
+                }
+
+                if pending || InstallmentQueue::<T>::iter_prefix(bucket).next().is_some() {
+                    break;
+                }
+                bucket += 1;
+            }
+
+            InstallmentCursor::<T>::put(bucket);
+            weight.saturating_add(T::DbWeight::get().writes(1))
         }
 
-        /// Get current timestamp from pallet_timestamp (in seconds)
-        pub fn current_timestamp() -> u64 {
-            // Get timestamp from pallet_timestamp (returns milliseconds)
-            let now_ms: u64 = pallet_timestamp::Pallet::<T>::now()
-                .try_into()
-                .unwrap_or(0);
-            let now_secs = now_ms / 1000;
-            log::info!(
-                target: "prmx-policy",
-                "🔍 current_timestamp() - raw_ms: {}, seconds: {}",
-                now_ms,
-                now_secs
-            );
-            now_secs
+        /// Queue a payout schedule for release when its next installment is due
+        fn enqueue_installment(policy_id: PolicyId, due: u64) {
+            let bucket = installments::release_bucket(due);
+            InstallmentQueue::<T>::insert(bucket, policy_id, due);
+            InstallmentCursor::<T>::mutate(|cursor| {
+                *cursor = Some(cursor.map_or(bucket, |current| current.min(bucket)));
+            });
         }
 
-        /// Generate a human-readable policy label like "manila-0", "tokyo-1".
-        /// Uses account nonce for consistent numbering per account.
-        /// e.g., nonce=0 -> "manila-0", nonce=3 -> "manila-3"
-        fn generate_policy_label(market_id: MarketId, nonce: u64) -> BoundedVec<u8, ConstU32<32>> {
-            // Get market name from MarketsApi
-            let market_name_bytes = T::MarketsApi::market_name(market_id)
-                .unwrap_or_else(|_| b"unknown".to_vec());
-            
-            // Convert to lowercase string
-            let market_name = core::str::from_utf8(&market_name_bytes)
-                .unwrap_or("unknown");
-            
-            // Use nonce for policy numbering
-            let policy_number = nonce;
+        /// Activate scheduled policies whose coverage has started. Called from
+        /// on_initialize every BLOCKS_PER_ACTIVATION_CHECK blocks; drains
+        /// `ActivationQueue` bucket by bucket from `ActivationCursor`, looking at
+        /// most at MAX_ACTIVATIONS_PER_BLOCK queued policies.
+        pub fn activate_due_policies() -> Weight {
+            let now = T::TimeProvider::now_secs();
+            let mut weight = T::DbWeight::get().reads(2);
 
-            // Generate label: "manila-1", "tokyo-2", etc.
-            let label_string = alloc::format!(
-                "{}-{}",
-                market_name.to_ascii_lowercase(),
-                policy_number
-            );
+            let Some(mut bucket) = ActivationCursor::<T>::get() else {
+                return weight;
+            };
+            let current = schedule::activation_bucket(now);
+            let mut remaining = MAX_ACTIVATIONS_PER_BLOCK;
+            let mut buckets = 0u32;
+
+            while bucket <= current && remaining > 0 && buckets < MAX_ACTIVATION_BUCKETS_PER_BLOCK {
+                buckets += 1;
+                let queued: Vec<(PolicyId, u64)> = ActivationQueue::<T>::iter_prefix(bucket)
+                    .take(remaining as usize)
+                    .collect();
+                // The bucket's entries, and whether any are left afterwards
+                weight = weight.saturating_add(T::DbWeight::get().reads(2));
+
+                let mut pending = false;
+                for (policy_id, activates_at) in queued {
+                    remaining -= 1;
+                    // Only the current bucket can hold policies not yet due
+                    if !schedule::is_due(activates_at, now) {
+                        pending = true;
+                        weight = weight.saturating_add(T::DbWeight::get().reads(1));
+                        continue;
+                    }
 
-            // Convert to bounded vec (truncate if too long)
-            label_string
-                .into_bytes()
-                .try_into()
-                .unwrap_or_else(|_| {
-                    // Fallback: just use "policy-{id}" format
-                    let fallback = alloc::format!("policy-{}", policy_number);
-                    fallback.into_bytes().try_into().unwrap_or_default()
-                })
-        }
+                    ActivationQueue::<T>::remove(bucket, policy_id);
+                    Self::activate_or_lapse(policy_id);
+                    weight = weight.saturating_add(T::WeightInfo::activate_scheduled_policy());
+                }
 
-        /// Get all policies for a market
-        pub fn get_policies_for_market(market_id: MarketId) -> Vec<PolicyId> {
-            PoliciesByMarket::<T>::get(market_id).into_inner()
+                if pending || ActivationQueue::<T>::iter_prefix(bucket).next().is_some() {
+                    break;
+                }
+                bucket += 1;
+            }
+
+            ActivationCursor::<T>::put(bucket);
+            weight.saturating_add(T::DbWeight::get().writes(1))
         }
 
-        /// Get policy info
-        pub fn get_policy(policy_id: PolicyId) -> Option<PolicyInfo<T>> {
-            Policies::<T>::get(policy_id)
+        /// Activate a scheduled policy, or let it lapse with a full refund if it
+        /// cannot be funded
+        fn activate_or_lapse(policy_id: PolicyId) {
+            // Roll back partial writes if funding fails
+            let result = frame_support::storage::with_storage_layer(|| {
+                Self::activate_scheduled_policy(policy_id)
+            });
+
+            if let Err(e) = result {
+                log::warn!(
+                    target: "prmx-policy",
+                    "❌ Failed to activate scheduled policy {}: {:?}",
+                    policy_id,
+                    e
+                );
+                // Coverage cannot start unfunded; the policy lapses with a full refund
+                let lapsed = frame_support::storage::with_storage_layer(|| {
+                    Self::refund_scheduled_policy(policy_id)
+                });
+                match lapsed {
+                    Ok((holder, refund)) => {
+                        Self::deposit_event(Event::ScheduledPolicyLapsed {
+                            policy_id,
+                            holder,
+                            refund,
+                        });
+                    }
+                    Err(e) => log::warn!(
+                        target: "prmx-policy",
+                        "❌ Failed to refund lapsed policy {}: {:?}",
+                        policy_id,
+                        e
+                    ),
+                }
+            }
         }
 
-        /// Check if policy is active
-        pub fn is_policy_active(policy_id: PolicyId) -> bool {
-            Policies::<T>::get(policy_id)
-                .map(|p| p.status == PolicyStatus::Active)
-                .unwrap_or(false)
+        /// Queue a scheduled policy for activation when its coverage starts
+        fn enqueue_activation(policy_id: PolicyId, activates_at: u64) {
+            let bucket = schedule::activation_bucket(activates_at);
+            ActivationQueue::<T>::insert(bucket, policy_id, activates_at);
+            ActivationCursor::<T>::mutate(|cursor| {
+                *cursor = Some(cursor.map_or(bucket, |current| current.min(bucket)));
+            });
         }
 
-        /// Internal settlement function - performs the actual settlement logic
-        /// Returns the payout amount on success
-        pub fn do_settle_policy(policy_id: PolicyId, event_occurred: bool) -> Result<T::Balance, DispatchError> {
-            // Load policy
+        /// Fund a scheduled policy's pool from escrow and the DAO capital account,
+        /// release its reservation and make it active
+        pub fn activate_scheduled_policy(policy_id: PolicyId) -> DispatchResult {
             let mut policy = Policies::<T>::get(policy_id)
                 .ok_or(Error::<T>::PolicyNotFound)?;
-
-            // Ensure policy is active or expired (not already settled)
             ensure!(
-                policy.status == PolicyStatus::Active || policy.status == PolicyStatus::Expired,
-                Error::<T>::PolicyAlreadySettled
+                policy.status == PolicyStatus::Scheduled,
+                Error::<T>::NotScheduledPolicy
             );
+            let funding = CapitalReservations::<T>::take(policy_id)
+                .ok_or(Error::<T>::NotScheduledPolicy)?;
+            Self::release_reservation(&funding);
+            let bucket = schedule::activation_bucket(funding.activates_at);
+            ActivationQueue::<T>::remove(bucket, policy_id);
 
-            let now = Self::current_timestamp();
+            Self::fund_policy(&policy, &Self::escrow_account(), &funding)?;
 
-            // Get pool account
-            let pool_account = Self::policy_pool_account(policy_id);
+            policy.status = PolicyStatus::Active;
+            Policies::<T>::insert(policy_id, policy);
 
-            // =========================================================================
-            // DeFi Integration: Ensure local liquidity before settlement
-            // =========================================================================
-            // Before settlement, we need to ensure the policy pool has enough
-            // local USDT to fulfill obligations. This unwinds any LP positions
-            // and has the DAO top up any shortfall from DeFi losses.
-            log::info!(
-                target: "prmx-policy",
-                "💰 Ensuring local liquidity for policy {} (required: {} USDT)",
-                policy_id,
-                policy.max_payout.into()
-            );
+            Self::deposit_event(Event::ScheduledPolicyActivated { policy_id });
 
-            T::CapitalApi::ensure_local_liquidity(policy_id, policy.max_payout)?;
+            Ok(())
+        }
 
-            // After unwinding, get the ACTUAL on-chain pool balance
-            // This may be less than max_payout if DAO couldn't cover full DeFi loss
-            let pool_balance = T::Assets::balance(T::UsdtAssetId::get(), &pool_account);
-            
-            log::info!(
-                target: "prmx-policy",
-                "📊 Pool balance after DeFi unwind: {} USDT (max_payout was {})",
-                pool_balance.into(),
-                policy.max_payout.into()
+        /// Return a scheduled policy's escrowed premium to its holder in full,
+        /// release its reservation and notional, and mark it cancelled. Returns
+        /// the holder and the refund.
+        fn refund_scheduled_policy(
+            policy_id: PolicyId,
+        ) -> Result<(T::AccountId, T::Balance), DispatchError> {
+            let mut policy = Policies::<T>::get(policy_id)
+                .ok_or(Error::<T>::PolicyNotFound)?;
+            ensure!(
+                policy.status == PolicyStatus::Scheduled,
+                Error::<T>::NotScheduledPolicy
             );
+            let funding = CapitalReservations::<T>::take(policy_id)
+                .ok_or(Error::<T>::NotScheduledPolicy)?;
+            Self::release_reservation(&funding);
+            let bucket = schedule::activation_bucket(funding.activates_at);
+            ActivationQueue::<T>::remove(bucket, policy_id);
 
-            let payout_to_holder: T::Balance;
-
-            if event_occurred {
-                // Event occurred - pay out to policy holder
-                // In case of DAO insolvency, pool may have less than max_payout
-                // Pay out what's available in the pool
-                let payout = if pool_balance < policy.max_payout {
-                    log::warn!(
-                        target: "prmx-policy",
-                        "⚠️ Pool has {} USDT but max_payout is {} USDT - paying out available balance",
-                        pool_balance.into(),
-                        policy.max_payout.into()
-                    );
-                    pool_balance
-                } else {
-                    policy.max_payout
-                };
-                payout_to_holder = payout;
-
-                // Transfer from pool to holder (only if there's something to transfer)
-                if payout > T::Balance::zero() {
+            if funding.premium > T::Balance::zero() {
                 T::Assets::transfer(
-                    T::UsdtAssetId::get(),
-                    &pool_account,
+                    funding.asset,
+                    &Self::escrow_account(),
                     &policy.holder,
-                    payout,
-                    frame_support::traits::tokens::Preservation::Expendable,
-                ).map_err(|_| Error::<T>::TransferFailed)?;
-                }
-
-                // Update storage
-                PolicyRiskPoolBalance::<T>::insert(policy_id, T::Balance::zero());
-                policy.status = PolicyStatus::Settled;
-                Policies::<T>::insert(policy_id, policy);
-
-                // Cleanup LP tokens (burn all LP tokens for this policy)
-                T::HoldingsApi::cleanup_policy_lp_tokens(policy_id)
-                    .map_err(|_| Error::<T>::TransferFailed)?;
-
-                // Store settlement result
-                SettlementResults::<T>::insert(policy_id, SettlementResult {
-                    event_occurred: true,
-                    payout_to_holder: payout,
-                    returned_to_lps: T::Balance::zero(),
-                    settled_at: now,
-                });
-
-                Self::deposit_event(Event::PolicySettled {
-                    policy_id,
-                    payout_to_holder: payout,
-                });
-            } else {
-                // Event did not occur - distribute pool to LP holders pro-rata
-                payout_to_holder = T::Balance::zero();
-                
-                // Distribute directly from policy pool to all LP holders OF THIS POLICY
-                T::HoldingsApi::distribute_to_lp_holders(
-                    policy_id,
-                    &pool_account,
-                    pool_balance,
+                    funding.premium,
+                    Preservation::Expendable,
                 ).map_err(|_| Error::<T>::TransferFailed)?;
+            }
 
-                // Cleanup LP tokens (burn all LP tokens for this policy)
-                T::HoldingsApi::cleanup_policy_lp_tokens(policy_id)
-                    .map_err(|_| Error::<T>::TransferFailed)?;
-
-                PolicyRiskPoolBalance::<T>::insert(policy_id, T::Balance::zero());
-                policy.status = PolicyStatus::Settled;
-                Policies::<T>::insert(policy_id, policy.clone());
-
-                // Store settlement result
-                SettlementResults::<T>::insert(policy_id, SettlementResult {
-                    event_occurred: false,
-                    payout_to_holder: T::Balance::zero(),
-                    returned_to_lps: pool_balance,
-                    settled_at: now,
-                });
+            Self::release_notional(
+                policy.market_id,
+                exposure::location_cell(policy.latitude, policy.longitude),
+                policy.max_payout,
+            );
 
-                Self::deposit_event(Event::PolicyExpiredNoEvent {
-                    policy_id,
-                    residual_to_pool: pool_balance,
-                });
-            }
+            let holder = policy.holder.clone();
+            policy.status = PolicyStatus::Cancelled;
+            Policies::<T>::insert(policy_id, policy);
 
-            // =========================================================================
-            // DeFi Integration: Notify CapitalApi of settlement completion
-            // =========================================================================
-            // Perform any final cleanup for the policy's capital management state.
-            T::CapitalApi::on_policy_settled(policy_id)?;
+            Ok((holder, funding.premium))
+        }
 
-            Ok(payout_to_holder)
+        /// Remove a scheduled policy's DAO capital from `ReservedDaoCapital`
+        fn release_reservation(funding: &PolicyFunding<T>) {
+            let amount: u128 = funding.dao_capital.into();
+            ReservedDaoCapital::<T>::mutate_exists(funding.asset, |total| {
+                let current: u128 = total.map(Into::into).unwrap_or_default();
+                let remaining = current.saturating_sub(amount);
+                *total = (remaining > 0).then(|| remaining.into());
+            });
         }
 
         /// Get all active policies for a market that are currently in their coverage window
@@ -1113,6 +4062,119 @@ pub mod pallet {
                 })
                 .collect()
         }
+
+        // =====================================================================
+        //                       Runtime API Queries
+        // =====================================================================
+
+        /// All policies currently held by `who`, including policies where `who`
+        /// bought shares on the secondary market (see `PrmxPolicyApi::policies_of`)
+        pub fn policies_of(who: &T::AccountId) -> Vec<PolicyId> {
+            Policies::<T>::iter()
+                .filter(|(policy_id, policy)| {
+                    &policy.holder == who
+                        || PolicyBeneficiaries::<T>::get(policy_id)
+                            .iter()
+                            .any(|(account, _)| account == who)
+                })
+                .map(|(policy_id, _)| policy_id)
+                .collect()
+        }
+
+        /// Pool account of a policy (None if the policy does not exist)
+        pub fn pool_account_of(policy_id: PolicyId) -> Option<T::AccountId> {
+            PolicyPoolAccounts::<T>::get(policy_id).or_else(|| {
+                Policies::<T>::contains_key(policy_id)
+                    .then(|| Self::policy_pool_account(policy_id))
+            })
+        }
+
+        /// Policy details with pool balance and settlement result
+        pub fn policy_details(
+            policy_id: PolicyId,
+        ) -> Option<prmx_primitives::PolicyDetails<T::AccountId, T::Balance>> {
+            let policy = Policies::<T>::get(policy_id)?;
+
+            let status = match policy.status {
+                PolicyStatus::Active => prmx_primitives::PolicyState::Active,
+                PolicyStatus::Expired => prmx_primitives::PolicyState::Expired,
+                PolicyStatus::Settled => prmx_primitives::PolicyState::Settled,
+                PolicyStatus::Cancelled => prmx_primitives::PolicyState::Cancelled,
+                PolicyStatus::Scheduled => prmx_primitives::PolicyState::Scheduled,
+            };
+            let settlement = SettlementResults::<T>::get(policy_id).map(|result| {
+                prmx_primitives::PolicySettlementSummary {
+                    event_occurred: result.event_occurred,
+                    payout_to_holder: result.payout_to_holder,
+                    returned_to_lps: result.returned_to_lps,
+                    settled_at: result.settled_at,
+                }
+            });
+            let pool_balance = T::Assets::balance(
+                Self::policy_asset(policy_id),
+                &Self::policy_pool_account(policy_id),
+            );
+
+            Some(prmx_primitives::PolicyDetails {
+                policy_id,
+                label: policy.policy_label.into_inner(),
+                market_id: policy.market_id,
+                holder: policy.holder,
+                status,
+                policy_version: policy.policy_version,
+                coverage_start: policy.coverage_start,
+                coverage_end: policy.coverage_end,
+                shares: policy.shares,
+                premium_paid: policy.premium_paid,
+                max_payout: policy.max_payout,
+                pool_balance,
+                strike_mm: policy.strike_mm,
+                settlement,
+            })
+        }
+
+        /// Open exposure of a market across its active policies
+        pub fn active_exposure(market_id: MarketId) -> prmx_primitives::MarketExposure<T::Balance> {
+            let mut exposure = prmx_primitives::MarketExposure {
+                market_id,
+                active_policies: 0,
+                total_shares: 0,
+                total_max_payout: T::Balance::zero(),
+            };
+
+            for policy_id in PoliciesByMarket::<T>::get(market_id) {
+                let Some(policy) = Policies::<T>::get(policy_id) else {
+                    continue;
+                };
+                if policy.status != PolicyStatus::Active {
+                    continue;
+                }
+
+                let total: u128 = exposure.total_max_payout.into();
+                exposure.active_policies = exposure.active_policies.saturating_add(1);
+                exposure.total_shares = exposure.total_shares.saturating_add(policy.shares);
+                exposure.total_max_payout = total.saturating_add(policy.max_payout.into()).into();
+            }
+
+            exposure
+        }
+
+        /// Open notional and caps for a market and a location in it
+        pub fn notional_utilization(
+            market_id: MarketId,
+            latitude: i32,
+            longitude: i32,
+        ) -> prmx_primitives::NotionalUtilization<T::Balance> {
+            prmx_primitives::NotionalUtilization {
+                market_id,
+                market_notional: MarketNotional::<T>::get(market_id),
+                max_per_market: MaxNotionalPerMarket::<T>::get(),
+                location_notional: LocationNotional::<T>::get(
+                    exposure::location_cell(latitude, longitude),
+                ),
+                max_per_location: MaxNotionalPerLocation::<T>::get(),
+            }
+        }
     }
 }
 
@@ -1121,10 +4183,6 @@ pub mod pallet {
 // =============================================================================
 
 impl<T: Config> pallet_prmx_oracle::PolicySettlement<T::AccountId> for Pallet<T> {
-    fn current_time() -> u64 {
-        pallet::Pallet::<T>::current_timestamp()
-    }
-    
     fn get_active_policies_in_window(market_id: pallet_prmx_markets::MarketId, current_time: u64) -> Vec<pallet_prmx_oracle::PolicyId> {
         pallet::Pallet::<T>::get_active_policies_in_window(market_id, current_time)
     }
@@ -1135,9 +4193,15 @@ impl<T: Config> pallet_prmx_oracle::PolicySettlement<T::AccountId> for Pallet<T>
         })
     }
 
-    fn trigger_immediate_settlement(policy_id: pallet_prmx_oracle::PolicyId) -> Result<u128, sp_runtime::DispatchError> {
+    fn early_trigger_threshold(policy_id: pallet_prmx_oracle::PolicyId, strike_mm: Millimeters) -> Millimeters {
+        pallet::Policies::<T>::get(policy_id)
+            .and_then(|p| tiers::top_threshold(&p.payout_tiers))
+            .unwrap_or(strike_mm)
+    }
+
+    fn trigger_immediate_settlement(policy_id: pallet_prmx_oracle::PolicyId, record: SettlementRecord) -> Result<u128, sp_runtime::DispatchError> {
         // Call internal settlement function with event_occurred = true
-        let payout = pallet::Pallet::<T>::do_settle_policy(policy_id, true)?;
+        let payout = pallet::Pallet::<T>::do_settle_policy_with_observation(policy_id, true, record)?;
         Ok(payout.into())
     }
     
@@ -1153,12 +4217,27 @@ impl<T: Config> pallet_prmx_oracle::PolicySettlement<T::AccountId> for Pallet<T>
             .collect()
     }
     
-    fn settle_expired_policy(policy_id: pallet_prmx_oracle::PolicyId, event_occurred: bool) -> Result<u128, sp_runtime::DispatchError> {
+    fn settle_expired_policy(
+        policy_id: pallet_prmx_oracle::PolicyId,
+        event_occurred: bool,
+        record: SettlementRecord,
+    ) -> Result<u128, sp_runtime::DispatchError> {
         // Call internal settlement function with the determined event outcome
-        let payout = pallet::Pallet::<T>::do_settle_policy(policy_id, event_occurred)?;
+        let payout = pallet::Pallet::<T>::do_settle_policy_with_observation(policy_id, event_occurred, record)?;
         Ok(payout.into())
     }
 
+    fn preview_settlement_payout(
+        policy_id: pallet_prmx_oracle::PolicyId,
+        event_occurred: bool,
+        observed_mm: Option<Millimeters>,
+    ) -> Option<(u128, u128, u128)> {
+        pallet::Pallet::<T>::preview_settlement_payout(policy_id, event_occurred, observed_mm)
+            .map(|(payout, to_positions, to_lps)| {
+                (payout.into(), to_positions.into(), to_lps.into())
+            })
+    }
+
     fn settle_v2_policy(
         policy_id: pallet_prmx_oracle::PolicyId,
         outcome: prmx_primitives::V2Outcome,
@@ -1175,6 +4254,11 @@ impl<T: Config> pallet_prmx_oracle::PolicySettlement<T::AccountId> for Pallet<T>
             return Err(pallet::Error::<T>::NotV2Policy.into());
         }
 
+        // Group policies need one observation per member
+        if pallet::PolicyGroupMembers::<T>::contains_key(policy_id) {
+            return Err(pallet::Error::<T>::IsGroupPolicy.into());
+        }
+
         // Verify policy is active
         if policy.status != pallet::PolicyStatus::Active {
             return Err(pallet::Error::<T>::V2PolicyNotActive.into());
@@ -1198,6 +4282,12 @@ impl<T: Config> pallet_prmx_oracle::PolicySettlement<T::AccountId> for Pallet<T>
                         return Err(pallet::Error::<T>::ThresholdNotMet.into());
                     }
                 }
+                // Tiered policies settle once, at the top tier or at coverage end
+                if let Some(top) = tiers::top_threshold(&policy.payout_tiers) {
+                    if cumulative_mm < top && observed_at < policy.coverage_end {
+                        return Err(pallet::Error::<T>::TierNotFinal.into());
+                    }
+                }
             }
             prmx_primitives::V2Outcome::MaturedNoEvent => {
                 // observed_at must be at or after coverage end
@@ -1207,7 +4297,7 @@ impl<T: Config> pallet_prmx_oracle::PolicySettlement<T::AccountId> for Pallet<T>
             }
         }
 
-        let now = pallet::Pallet::<T>::current_timestamp();
+        let now = T::TimeProvider::now_secs();
 
         // Store the immutable V2 report
         let report = prmx_primitives::V2Report {
@@ -1228,7 +4318,11 @@ impl<T: Config> pallet_prmx_oracle::PolicySettlement<T::AccountId> for Pallet<T>
 
         // Perform actual settlement using existing mechanics
         let event_occurred = matches!(outcome, prmx_primitives::V2Outcome::Triggered);
-        pallet::Pallet::<T>::do_settle_policy(policy_id, event_occurred)?;
+        pallet::Pallet::<T>::do_settle_policy_with_observation(
+            policy_id,
+            event_occurred,
+            pallet::Pallet::<T>::v2_settlement_record(cumulative_mm, evidence_hash),
+        )?;
 
         // Update oracle status to Settled
         if let Some(mut p) = pallet::Policies::<T>::get(policy_id) {
@@ -1254,6 +4348,86 @@ impl<T: Config> pallet_prmx_oracle::PolicySettlement<T::AccountId> for Pallet<T>
 
 impl<T: Config> PolicyPoolAccountApi<T::AccountId> for Pallet<T> {
     fn policy_pool_account(policy_id: PolicyId) -> T::AccountId {
-        pallet::Pallet::<T>::policy_pool_account(policy_id)
+        pallet::PolicyPoolAccounts::<T>::get(policy_id)
+            .unwrap_or_else(|| pallet::Pallet::<T>::policy_pool_account(policy_id))
+    }
+
+    fn policy_holder(policy_id: PolicyId) -> Option<T::AccountId> {
+        pallet::Policies::<T>::get(policy_id).map(|p| p.holder)
+    }
+}
+
+// =============================================================================
+//                       PolicyAssetApi Implementation
+// =============================================================================
+
+impl<T: Config> PolicyAssetApi<T::AssetId> for Pallet<T> {
+    fn policy_asset(policy_id: PolicyId) -> Option<T::AssetId> {
+        pallet::Policies::<T>::contains_key(policy_id)
+            .then(|| pallet::Pallet::<T>::policy_asset(policy_id))
+    }
+}
+
+// =============================================================================
+//                       PolicyPositionApi Implementation
+// =============================================================================
+
+impl<T: Config> PolicyPositionApi<T::AccountId> for Pallet<T> {
+    fn position_shares(policy_id: PolicyId, who: &T::AccountId) -> u128 {
+        let Some(policy) = pallet::Policies::<T>::get(policy_id) else {
+            return 0;
+        };
+        if !pallet::Pallet::<T>::is_transferable(&policy, T::TimeProvider::now_secs()) {
+            return 0;
+        }
+        positions::shares_of(
+            who,
+            &policy.holder,
+            policy.shares,
+            &pallet::PolicyBeneficiaries::<T>::get(policy_id),
+        )
+    }
+
+    fn transfer_position(
+        policy_id: PolicyId,
+        from: &T::AccountId,
+        to: &T::AccountId,
+        shares: u128,
+    ) -> Result<(), DispatchError> {
+        pallet::Pallet::<T>::do_transfer_position(policy_id, from, to, shares)
+    }
+}
+
+// =============================================================================
+//                      LpRedemptionPriceApi Implementation
+// =============================================================================
+
+impl<T: Config> LpRedemptionPriceApi<T::Balance> for Pallet<T> {
+    /// The DAO buys LP shares back at the capital per share it raised for the
+    /// policy (max payout less net premium), while the policy is still transferable.
+    fn redemption_price(policy_id: PolicyId) -> Option<T::Balance> {
+        let policy = pallet::Policies::<T>::get(policy_id)?;
+        if policy.shares == 0
+            || !pallet::Pallet::<T>::is_transferable(&policy, T::TimeProvider::now_secs())
+        {
+            return None;
+        }
+
+        // Capital raised is the max payout less the premium net of the protocol fee
+        let max_payout: u128 = policy.max_payout.into();
+        let premium: u128 = policy.premium_paid.into();
+        let net_premium = premium.saturating_sub(pallet::PolicyProtocolFees::<T>::get(policy_id).into());
+        let capital = max_payout.saturating_sub(net_premium);
+        Some((capital / policy.shares).into())
+    }
+}
+
+// =============================================================================
+//                          OpenNotional Implementation
+// =============================================================================
+
+impl<T: Config> pallet_prmx_quote::OpenNotional<T::Balance> for Pallet<T> {
+    fn open_notional(market_id: pallet_prmx_markets::MarketId) -> T::Balance {
+        pallet::MarketNotional::<T>::get(market_id)
     }
 }
@@ -0,0 +1,136 @@
+//! # Policy Positions
+//!
+//! Shares of a policy can be sold on the secondary market (see the policy
+//! listings in `pallet_prmx_orderbook_lp`). Buyers become beneficiaries of the
+//! shares they bought; the policy holder keeps every share not held by a
+//! beneficiary and still controls the policy (transfer, cancellation).
+//!
+//! At settlement the payout is split pro-rata by shares. Rounding remainders
+//! go to the holder.
+
+use alloc::vec::Vec;
+
+/// Shares held by `who`, given the policy holder and its beneficiaries
+pub fn shares_of<AccountId: PartialEq>(
+    who: &AccountId,
+    holder: &AccountId,
+    total_shares: u128,
+    beneficiaries: &[(AccountId, u128)],
+) -> u128 {
+    if who == holder {
+        let sold: u128 = beneficiaries.iter().map(|(_, shares)| *shares).sum();
+        total_shares.saturating_sub(sold)
+    } else {
+        beneficiaries
+            .iter()
+            .find(|(account, _)| account == who)
+            .map_or(0, |(_, shares)| *shares)
+    }
+}
+
+/// Move `shares` from `from` to `to` in the beneficiary list. The holder's shares
+/// are implicit, so moving from or to the holder only touches the other side.
+/// Returns `None` if `from` does not hold enough shares.
+pub fn move_shares<AccountId: PartialEq + Clone>(
+    beneficiaries: &mut Vec<(AccountId, u128)>,
+    holder: &AccountId,
+    total_shares: u128,
+    from: &AccountId,
+    to: &AccountId,
+    shares: u128,
+) -> Option<()> {
+    if shares == 0 || shares_of(from, holder, total_shares, beneficiaries) < shares {
+        return None;
+    }
+
+    if from != holder {
+        let index = beneficiaries.iter().position(|(account, _)| account == from)?;
+        beneficiaries[index].1 -= shares;
+        if beneficiaries[index].1 == 0 {
+            beneficiaries.remove(index);
+        }
+    }
+
+    if to != holder {
+        match beneficiaries.iter_mut().find(|(account, _)| account == to) {
+            Some((_, held)) => *held = held.saturating_add(shares),
+            None => beneficiaries.push((to.clone(), shares)),
+        }
+    }
+
+    Some(())
+}
+
+/// Split `payout` between beneficiaries pro-rata by shares. Returns each
+/// beneficiary's amount and what is left for the holder.
+pub fn split_payout<AccountId: Clone>(
+    payout: u128,
+    total_shares: u128,
+    beneficiaries: &[(AccountId, u128)],
+) -> (Vec<(AccountId, u128)>, u128) {
+    if total_shares == 0 {
+        return (Vec::new(), payout);
+    }
+
+    let mut remaining = payout;
+    let amounts = beneficiaries
+        .iter()
+        .map(|(account, shares)| {
+            let amount = (payout.saturating_mul(*shares) / total_shares).min(remaining);
+            remaining -= amount;
+            (account.clone(), amount)
+        })
+        .collect();
+
+    (amounts, remaining)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOLDER: u32 = 1;
+
+    #[test]
+    fn test_move_shares() {
+        let mut beneficiaries = Vec::new();
+
+        // Holder sells 3 of 10 shares, then 2 more to the same buyer
+        assert!(move_shares(&mut beneficiaries, &HOLDER, 10, &HOLDER, &2, 3).is_some());
+        assert!(move_shares(&mut beneficiaries, &HOLDER, 10, &HOLDER, &2, 2).is_some());
+        assert_eq!(beneficiaries, vec![(2, 5)]);
+        assert_eq!(shares_of(&HOLDER, &HOLDER, 10, &beneficiaries), 5);
+
+        // Buyer resells part of the position
+        assert!(move_shares(&mut beneficiaries, &HOLDER, 10, &2, &3, 4).is_some());
+        assert_eq!(beneficiaries, vec![(2, 1), (3, 4)]);
+
+        // Nobody can sell more than they hold
+        assert!(move_shares(&mut beneficiaries, &HOLDER, 10, &2, &3, 2).is_none());
+        assert!(move_shares(&mut beneficiaries, &HOLDER, 10, &HOLDER, &3, 6).is_none());
+        assert!(move_shares(&mut beneficiaries, &HOLDER, 10, &4, &3, 1).is_none());
+        assert!(move_shares(&mut beneficiaries, &HOLDER, 10, &HOLDER, &3, 0).is_none());
+
+        // Selling back to the holder removes the position
+        assert!(move_shares(&mut beneficiaries, &HOLDER, 10, &2, &HOLDER, 1).is_some());
+        assert_eq!(beneficiaries, vec![(3, 4)]);
+        assert_eq!(shares_of(&HOLDER, &HOLDER, 10, &beneficiaries), 6);
+    }
+
+    #[test]
+    fn test_split_payout() {
+        let beneficiaries = vec![(2u32, 3), (3u32, 3)];
+        let (amounts, to_holder) = split_payout(1_000, 10, &beneficiaries);
+        assert_eq!(amounts, vec![(2, 300), (3, 300)]);
+        assert_eq!(to_holder, 400);
+
+        // Rounding remainder goes to the holder
+        let (amounts, to_holder) = split_payout(100, 7, &beneficiaries);
+        assert_eq!(amounts, vec![(2, 42), (3, 42)]);
+        assert_eq!(to_holder, 16);
+
+        let (amounts, to_holder) = split_payout(0, 10, &beneficiaries);
+        assert_eq!(amounts, vec![(2, 0), (3, 0)]);
+        assert_eq!(to_holder, 0);
+    }
+}
@@ -0,0 +1,82 @@
+//! # Scheduled Policy Start
+//!
+//! `apply_coverage_scheduled` buys a policy well before its coverage starts
+//! without locking capital for the whole lead time. The holder's premium is held
+//! in the pallet's escrow account, and the DAO capital the policy will need is
+//! recorded in a reservation ledger (`CapitalReservations`, totalled per asset in
+//! `ReservedDaoCapital`) instead of being moved into the policy pool.
+//!
+//! A periodic sweep in `on_initialize` activates each scheduled policy once its
+//! coverage starts. Scheduled policies are queued in `ActivationQueue` by the
+//! minute their coverage starts (`activation_bucket`), and the sweep drains that
+//! queue minute by minute from `ActivationCursor`. On activation the premium
+//! moves from escrow into the pool (less the protocol fee), the DAO capital is
+//! transferred and LP tokens are minted as for any other policy. Until then the
+//! holder can `cancel_scheduled_policy` for a full refund, protocol fee
+//! included. A policy whose DAO capital cannot be funded at activation lapses
+//! with the same full refund.
+//!
+//! Reserved DAO capital is not available to other new policies.
+
+/// Shortest time from purchase to coverage start for a scheduled policy (1 day)
+pub const MIN_SCHEDULED_LEAD_SECS: u64 = 24 * 3600;
+
+/// Whether a policy starting at `coverage_start` can be scheduled at `now`
+pub fn can_schedule(coverage_start: u64, now: u64) -> bool {
+    coverage_start >= now.saturating_add(MIN_SCHEDULED_LEAD_SECS)
+}
+
+/// Whether a scheduled policy activating at `activates_at` is due at `now`
+pub fn is_due(activates_at: u64, now: u64) -> bool {
+    now >= activates_at
+}
+
+/// Width of an `ActivationQueue` bucket (1 minute)
+pub const ACTIVATION_BUCKET_SECS: u64 = 60;
+
+/// `ActivationQueue` bucket of a policy activating at `activates_at`
+pub fn activation_bucket(activates_at: u64) -> u64 {
+    activates_at / ACTIVATION_BUCKET_SECS
+}
+
+/// DAO capital free for new policies: the capital account's balance less
+/// outstanding reservations
+pub fn available_capital(dao_balance: u128, reserved: u128) -> u128 {
+    dao_balance.saturating_sub(reserved)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schedule_needs_the_minimum_lead_time() {
+        let now = 1_000_000;
+        assert!(can_schedule(now + MIN_SCHEDULED_LEAD_SECS, now));
+        assert!(!can_schedule(now + MIN_SCHEDULED_LEAD_SECS - 1, now));
+        assert!(!can_schedule(now, now));
+    }
+
+    #[test]
+    fn test_due_from_coverage_start() {
+        assert!(!is_due(1_000, 999));
+        assert!(is_due(1_000, 1_000));
+        assert!(is_due(1_000, 5_000));
+    }
+
+    #[test]
+    fn test_activation_buckets_follow_coverage_start() {
+        assert_eq!(activation_bucket(0), 0);
+        assert_eq!(activation_bucket(ACTIVATION_BUCKET_SECS - 1), 0);
+        assert_eq!(activation_bucket(ACTIVATION_BUCKET_SECS), 1);
+        assert!(activation_bucket(1_000_000) <= activation_bucket(1_000_060));
+    }
+
+    #[test]
+    fn test_reservations_reduce_available_capital() {
+        assert_eq!(available_capital(1_000, 400), 600);
+        assert_eq!(available_capital(1_000, 0), 1_000);
+        // Capital spent below outstanding reservations
+        assert_eq!(available_capital(300, 400), 0);
+    }
+}
@@ -0,0 +1,190 @@
+//! # Settlement State Machine
+//!
+//! Settling a policy calls across pallets: capital unwinds DeFi positions and
+//! reinsurance pays in (`LiquidityEnsured`), then the holder, positions and LP
+//! holders are paid (`PaidOut`), then LP tokens are burned and capital and
+//! reinsurance release the policy (`Finalized`).
+//!
+//! Each stage runs in its own storage layer and is recorded in
+//! `SettlementProgresses` once it completes; the entry is removed when the
+//! settlement reaches `Finalized`. Only the oracle's `on_initialize`
+//! sweeps (threshold triggers and expired policies) call settlement outside a
+//! transaction, so only there does a failing stage leave the earlier stages in
+//! place. The policy pallet's own `on_initialize` walks `SettlementProgresses`
+//! and resumes such settlements from the recorded stage instead of unwinding or
+//! paying again, and so does any other settlement attempt on the policy. A
+//! resumed settlement keeps the outcome and record it started with, whatever
+//! outcome the resuming caller passes.
+//!
+//! Everywhere else the whole settlement is rolled back with the caller's storage
+//! layer, recorded progress included: a failing extrinsic (`settle_policy`,
+//! `poke_settlement`, `force_settle_expired_policy`), a policy in
+//! `force_settle_batch` or a V2 report finalization starts over from `Pending`
+//! on the next attempt.
+//!
+//! Settlement is not reentrant: while one policy is settling, any nested call
+//! into settlement (e.g. from a capital or reinsurance hook) is rejected.
+
+/// Stage a policy's settlement has completed
+#[derive(
+    codec::Encode,
+    codec::Decode,
+    codec::DecodeWithMemTracking,
+    codec::MaxEncodedLen,
+    scale_info::TypeInfo,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Debug,
+)]
+pub enum SettlementStage {
+    /// Settlement started; nothing has moved yet
+    Pending,
+    /// The pool holds local liquidity and any reinsurance share of the payout
+    LiquidityEnsured,
+    /// The payout and the LP holders' share of the pool have been paid
+    PaidOut,
+    /// LP tokens are burned and the policy is settled
+    Finalized,
+}
+
+impl SettlementStage {
+    /// Stage following this one (`Finalized` is terminal)
+    pub fn next(self) -> Self {
+        match self {
+            SettlementStage::Pending => SettlementStage::LiquidityEnsured,
+            SettlementStage::LiquidityEnsured => SettlementStage::PaidOut,
+            SettlementStage::PaidOut | SettlementStage::Finalized => SettlementStage::Finalized,
+        }
+    }
+}
+
+/// Progress of a policy's settlement, carried between attempts
+#[derive(
+    codec::Encode,
+    codec::Decode,
+    codec::DecodeWithMemTracking,
+    codec::MaxEncodedLen,
+    scale_info::TypeInfo,
+    Clone,
+    PartialEq,
+    Eq,
+    Debug,
+)]
+pub struct SettlementProgress<Balance, Record> {
+    pub stage: SettlementStage,
+    /// Outcome the settlement started with
+    pub event_occurred: bool,
+    /// Record the settlement started with; its observation sets the payout
+    pub record: Record,
+    /// Payout to the holder, positions and splits (set at `PaidOut`)
+    pub payout_to_holder: Balance,
+    /// Pool balance returned to LP holders (set at `PaidOut`)
+    pub returned_to_lps: Balance,
+    /// Unix timestamp of the first attempt
+    pub started_at: u64,
+}
+
+impl<Balance: Default, Record> SettlementProgress<Balance, Record> {
+    pub fn new(event_occurred: bool, record: Record, started_at: u64) -> Self {
+        Self {
+            stage: SettlementStage::Pending,
+            event_occurred,
+            record,
+            payout_to_holder: Balance::default(),
+            returned_to_lps: Balance::default(),
+            started_at,
+        }
+    }
+}
+
+impl<Balance, Record> SettlementProgress<Balance, Record> {
+    /// Run the stages left through `run_stage`, calling `on_completed` after each
+    /// one. A failing stage stops the run with `stage` still pointing at it, so
+    /// the next call retries that stage and skips the completed ones.
+    pub fn advance<E>(
+        &mut self,
+        mut run_stage: impl FnMut(SettlementStage, &mut Self) -> Result<(), E>,
+        mut on_completed: impl FnMut(&Self),
+    ) -> Result<(), E> {
+        while self.stage != SettlementStage::Finalized {
+            let stage = self.stage;
+            run_stage(stage, self)?;
+            self.stage = stage.next();
+            on_completed(self);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stages_advance_in_order() {
+        let mut stage = SettlementStage::Pending;
+        let mut seen = alloc::vec![stage];
+        while stage != SettlementStage::Finalized {
+            stage = stage.next();
+            seen.push(stage);
+        }
+        assert_eq!(
+            seen,
+            alloc::vec![
+                SettlementStage::Pending,
+                SettlementStage::LiquidityEnsured,
+                SettlementStage::PaidOut,
+                SettlementStage::Finalized,
+            ]
+        );
+        assert_eq!(SettlementStage::Finalized.next(), SettlementStage::Finalized);
+        assert!(SettlementStage::LiquidityEnsured < SettlementStage::PaidOut);
+    }
+
+    #[test]
+    fn test_failed_stage_resumes() {
+        let mut progress = SettlementProgress::<u128, &str>::new(true, "trigger", 1_000);
+        let mut stored = None;
+        let mut ran = alloc::vec![];
+
+        // Paying out fails on the first attempt, after liquidity was ensured
+        let result = progress.advance(
+            |stage, progress| {
+                ran.push(stage);
+                match stage {
+                    SettlementStage::LiquidityEnsured => Err("pool short"),
+                    _ => {
+                        progress.payout_to_holder = 500;
+                        Ok(())
+                    }
+                }
+            },
+            |progress| stored = Some(progress.clone()),
+        );
+        assert_eq!(result, Err("pool short"));
+        assert_eq!(ran, alloc::vec![SettlementStage::Pending, SettlementStage::LiquidityEnsured]);
+        let mut resumed = stored.clone().unwrap();
+        assert_eq!(resumed.stage, SettlementStage::LiquidityEnsured);
+
+        // The retry skips the completed stage and keeps the recorded outcome
+        ran.clear();
+        let result: Result<(), &str> = resumed.advance(
+            |stage, _| {
+                ran.push(stage);
+                Ok(())
+            },
+            |progress| stored = Some(progress.clone()),
+        );
+        assert_eq!(result, Ok(()));
+        assert_eq!(ran, alloc::vec![SettlementStage::LiquidityEnsured, SettlementStage::PaidOut]);
+        let finished = stored.unwrap();
+        assert_eq!(finished.stage, SettlementStage::Finalized);
+        assert!(finished.event_occurred);
+        assert_eq!(finished.record, "trigger");
+        assert_eq!(finished.payout_to_holder, 500);
+    }
+}
@@ -0,0 +1,86 @@
+//! # Payout Splits
+//!
+//! At policy creation the holder may direct their share of a triggered payout to
+//! several beneficiaries in basis points (e.g. 80% to the farmer, 20% to the
+//! lender financing the crop). The splits are fixed for the life of the policy,
+//! so a policy with splits cannot be transferred.
+//!
+//! Only the holder's share is split. Secondary-market positions are paid their
+//! share first, as usual.
+
+use alloc::vec::Vec;
+
+/// Basis points the splits of a policy must add up to
+pub const FULL_SPLIT_BP: u16 = 10_000;
+
+/// Splits are valid if there is at least one, each has a positive share, no
+/// account appears twice and the shares add up to `FULL_SPLIT_BP`
+pub fn validate_splits<AccountId: PartialEq>(splits: &[(AccountId, u16)]) -> bool {
+    if splits.is_empty() || splits.iter().any(|(_, bp)| *bp == 0) {
+        return false;
+    }
+
+    let distinct = splits
+        .iter()
+        .enumerate()
+        .all(|(i, (account, _))| !splits[..i].iter().any(|(other, _)| other == account));
+    let total: u32 = splits.iter().map(|(_, bp)| *bp as u32).sum();
+
+    distinct && total == FULL_SPLIT_BP as u32
+}
+
+/// Split `amount` between the beneficiaries by basis points. Rounding dust goes
+/// to the last beneficiary, so the amounts always add up to `amount`.
+pub fn split_amount<AccountId: Clone>(
+    amount: u128,
+    splits: &[(AccountId, u16)],
+) -> Vec<(AccountId, u128)> {
+    let mut remaining = amount;
+    let last = splits.len().saturating_sub(1);
+    splits
+        .iter()
+        .enumerate()
+        .map(|(i, (account, bp))| {
+            let share = if i == last {
+                remaining
+            } else {
+                (amount.saturating_mul(*bp as u128) / FULL_SPLIT_BP as u128).min(remaining)
+            };
+            remaining -= share;
+            (account.clone(), share)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_splits() {
+        assert!(validate_splits(&[(1u32, 8_000), (2, 2_000)]));
+        assert!(validate_splits(&[(1u32, FULL_SPLIT_BP)]));
+
+        // Empty, short, over or zero shares
+        assert!(!validate_splits::<u32>(&[]));
+        assert!(!validate_splits(&[(1u32, 8_000), (2, 1_999)]));
+        assert!(!validate_splits(&[(1u32, 8_000), (2, 2_001)]));
+        assert!(!validate_splits(&[(1u32, FULL_SPLIT_BP), (2, 0)]));
+
+        // Duplicate beneficiary
+        assert!(!validate_splits(&[(1u32, 5_000), (1, 5_000)]));
+    }
+
+    #[test]
+    fn test_split_amount() {
+        let splits = vec![(1u32, 8_000), (2u32, 2_000)];
+        assert_eq!(split_amount(1_000, &splits), vec![(1, 800), (2, 200)]);
+
+        // Rounding dust goes to the last beneficiary
+        let splits = vec![(1u32, 3_333), (2u32, 3_333), (3u32, 3_334)];
+        assert_eq!(split_amount(100, &splits), vec![(1, 33), (2, 33), (3, 34)]);
+        assert_eq!(split_amount(1, &splits), vec![(1, 0), (2, 0), (3, 1)]);
+
+        assert_eq!(split_amount(0, &splits), vec![(1, 0), (2, 0), (3, 0)]);
+    }
+}
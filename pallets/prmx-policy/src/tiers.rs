@@ -0,0 +1,113 @@
+//! # Tiered Payouts
+//!
+//! A policy may replace the all-or-nothing strike with a table of payout tiers,
+//! e.g. 50mm → 30%, 80mm → 70%, 120mm → 100% of max payout. At settlement the
+//! oracle reports the maximum observed metric and the highest tier it reached
+//! determines the payout.
+//!
+//! A valid table has strictly increasing thresholds and payouts, starts at or
+//! above the quoted strike (so the expected payout never exceeds what the
+//! premium priced) and pays at most 100%.
+
+use prmx_primitives::Millimeters;
+
+/// 100% in basis points
+pub const FULL_PAYOUT_BP: u32 = 10_000;
+
+/// One payout tier
+#[derive(
+    codec::Encode,
+    codec::Decode,
+    codec::DecodeWithMemTracking,
+    codec::MaxEncodedLen,
+    scale_info::TypeInfo,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Debug,
+)]
+pub struct PayoutTier {
+    /// Observed metric at or above which this tier pays (tenths of mm)
+    pub threshold_mm: Millimeters,
+    /// Share of max payout in basis points (10_000 = 100%)
+    pub payout_bp: u32,
+}
+
+/// Whether `tiers` is a valid table for a policy with strike `strike_mm`
+pub fn validate_tiers(tiers: &[PayoutTier], strike_mm: Millimeters) -> bool {
+    let Some(first) = tiers.first() else {
+        return false;
+    };
+
+    let increasing = tiers.windows(2).all(|pair| {
+        pair[0].threshold_mm < pair[1].threshold_mm && pair[0].payout_bp < pair[1].payout_bp
+    });
+
+    increasing
+        && first.threshold_mm >= strike_mm
+        && first.payout_bp > 0
+        && tiers.iter().all(|tier| tier.payout_bp <= FULL_PAYOUT_BP)
+}
+
+/// Payout in basis points for an observed metric: the highest tier reached, or 0
+pub fn payout_bp(tiers: &[PayoutTier], observed_mm: Millimeters) -> u32 {
+    tiers
+        .iter()
+        .rev()
+        .find(|tier| observed_mm >= tier.threshold_mm)
+        .map(|tier| tier.payout_bp)
+        .unwrap_or(0)
+}
+
+/// Threshold of the top tier, reached when no further payout is possible
+pub fn top_threshold(tiers: &[PayoutTier]) -> Option<Millimeters> {
+    tiers.last().map(|tier| tier.threshold_mm)
+}
+
+/// Apply a basis-point share to an amount
+pub fn apply_bp(amount: u128, bp: u32) -> u128 {
+    amount.saturating_mul(bp as u128) / FULL_PAYOUT_BP as u128
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tier(threshold_mm: Millimeters, payout_bp: u32) -> PayoutTier {
+        PayoutTier { threshold_mm, payout_bp }
+    }
+
+    fn example() -> [PayoutTier; 3] {
+        [tier(500, 3_000), tier(800, 7_000), tier(1_200, 10_000)]
+    }
+
+    #[test]
+    fn test_payout_from_highest_tier_reached() {
+        let tiers = example();
+        assert_eq!(payout_bp(&tiers, 499), 0);
+        assert_eq!(payout_bp(&tiers, 500), 3_000);
+        assert_eq!(payout_bp(&tiers, 950), 7_000);
+        assert_eq!(payout_bp(&tiers, 5_000), 10_000);
+        assert_eq!(top_threshold(&tiers), Some(1_200));
+
+        assert_eq!(apply_bp(100_000_000, 3_000), 30_000_000);
+        assert_eq!(apply_bp(100_000_000, 10_000), 100_000_000);
+    }
+
+    #[test]
+    fn test_tier_validation() {
+        assert!(validate_tiers(&example(), 500));
+        assert!(validate_tiers(&[tier(600, 10_000)], 500));
+
+        // Below the priced strike
+        assert!(!validate_tiers(&example(), 600));
+        // Not increasing
+        assert!(!validate_tiers(&[tier(500, 3_000), tier(500, 7_000)], 500));
+        assert!(!validate_tiers(&[tier(500, 7_000), tier(800, 3_000)], 500));
+        // Over 100%, zero first tier, or empty
+        assert!(!validate_tiers(&[tier(500, 3_000), tier(800, 10_001)], 500));
+        assert!(!validate_tiers(&[tier(500, 0), tier(800, 5_000)], 500));
+        assert!(!validate_tiers(&[], 500));
+    }
+}
@@ -0,0 +1,440 @@
+//! Placeholder weights for `pallet_prmx_policy`, written by hand: the benchmarks
+//! have not been run, so none of these values is measured.
+//!
+//! Storage reads/writes per extrinsic are counted from the dispatch paths in
+//! `lib.rs`, including the quote, markets, assets, holdings, orderbook, capital
+//! and reinsurance calls they make; ref-time bases are unmeasured guesses.
+//! Settlement and cancellation are counted with the DAO as the only LP holder.
+//! They must be replaced with weights generated by the benchmarks in
+//! `benchmarking.rs` (see `docs/benchmarking.md`) before production use.
+//!
+//! Components:
+//! - `t`: tiers in a payout table (`MaxPayoutTiers`)
+//! - `b`: secondary-market beneficiaries and payout split beneficiaries paid at
+//!   settlement (`MaxPolicyBeneficiaries` + `MaxPayoutSplits`)
+//! - `s`: beneficiaries in a policy's payout splits (`MaxPayoutSplits`)
+//! - `n`: policies in a forced settlement batch (`MaxForceSettleBatch`)
+//! - `m`: members of a group policy (`MaxGroupMembers`)
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use core::marker::PhantomData;
+use frame_support::{
+    traits::Get,
+    weights::{constants::RocksDbWeight, Weight},
+};
+
+/// Weight functions needed for `pallet_prmx_policy`.
+pub trait WeightInfo {
+    fn apply_coverage_with_quote() -> Weight;
+    fn apply_coverage_with_tiers(t: u32) -> Weight;
+    fn settle_policy(b: u32) -> Weight;
+    fn trigger_immediate_settlement(b: u32) -> Weight;
+    fn settle_v2_policy(b: u32) -> Weight;
+    fn transfer_policy() -> Weight;
+    fn cancel_policy() -> Weight;
+    fn set_cancellation_terms() -> Weight;
+    fn set_notional_caps() -> Weight;
+    fn apply_coverage_with_installments() -> Weight;
+    fn claim_installment() -> Weight;
+    fn release_installment() -> Weight;
+    fn set_protocol_fee() -> Weight;
+    fn withdraw_treasury() -> Weight;
+    fn extend_policy() -> Weight;
+    fn apply_coverage_with_payout_splits(s: u32) -> Weight;
+    fn force_settle_batch(n: u32, b: u32) -> Weight;
+    fn set_claim_verification() -> Weight;
+    fn release_held_payout() -> Weight;
+    fn deny_held_payout() -> Weight;
+    fn settle_group_policy(b: u32, m: u32) -> Weight;
+    fn apply_coverage_scheduled() -> Weight;
+    fn cancel_scheduled_policy() -> Weight;
+    fn activate_scheduled_policy() -> Weight;
+}
+
+/// Weights for `pallet_prmx_policy` using the runtime's database weights.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    /// Reads: QuoteRequests, QuoteResults, QuoteParamsHashes, QuoteStatuses, PausedOperations,
+    /// StaleMarkets, GroupQuoteMembers, Markets (3), Timestamp::Now, AccountNonce,
+    /// MaxNotionalPerMarket, MaxNotionalPerLocation, MarketNotional,
+    /// LocationNotional, ProtocolFeeBp, ReservedDaoCapital, CollectedFees, Assets (5),
+    /// holdings (3), orderbook (2), capital allocation (2), reinsurance (2)
+    /// Writes: AccountNonce, Policies, PoliciesByMarket, PolicyGroupMembers,
+    /// PolicyRiskPoolBalance, PolicyPoolAccounts, PoolAccountPolicies, MarketNotional,
+    /// LocationNotional, CollectedFees, PolicyProtocolFees,
+    /// Assets (4), holdings (3), orderbook (2), QuoteStatuses, capital
+    /// allocation (2), reinsurance (2)
+    /// Plus QuoteDeposits and System::Account read and written to release the
+    /// quote deposit
+    fn apply_coverage_with_quote() -> Weight {
+        Weight::from_parts(180_000_000, 9_500)
+            .saturating_add(T::DbWeight::get().reads(37_u64))
+            .saturating_add(T::DbWeight::get().writes(28_u64))
+    }
+    /// As `apply_coverage_with_quote`, validating and storing `t` tiers
+    fn apply_coverage_with_tiers(t: u32) -> Weight {
+        Weight::from_parts(182_000_000, 9_500)
+            .saturating_add(Weight::from_parts(400_000, 0).saturating_mul(t.into()))
+            .saturating_add(Weight::from_parts(0, 8).saturating_mul(t.into()))
+            .saturating_add(T::DbWeight::get().reads(37_u64))
+            .saturating_add(T::DbWeight::get().writes(28_u64))
+    }
+    /// Reads: Policies, Timestamp::Now, PausedOperations, capital allocation (2),
+    /// reinsurance (2), Assets (4), holdings (3), PolicyBeneficiaries,
+    /// PolicyInstallmentPlans, PolicyPayoutSplits, MarketNotional, LocationNotional,
+    /// MarketSettlementStats, InstallmentCursor, payout routing (3),
+    /// beneficiary Assets account (b)
+    /// Writes: Policies, PolicyRiskPoolBalance, SettlementResults, SettlementRecords,
+    /// MarketSettlementStats, PolicyInstallmentPlans, PolicyPayoutSplits,
+    /// PayoutSchedule, InstallmentQueue, InstallmentCursor, PolicyProtocolFees,
+    /// Assets (3),
+    /// holdings (3), capital allocation (2), reinsurance (2), MarketNotional,
+    /// LocationNotional, payout routing (3), beneficiary Assets account (b)
+    fn settle_policy(b: u32) -> Weight {
+        Weight::from_parts(140_000_000, 8_000)
+            .saturating_add(Weight::from_parts(25_000_000, 0).saturating_mul(b.into()))
+            .saturating_add(Weight::from_parts(0, 2_600).saturating_mul(b.into()))
+            .saturating_add(T::DbWeight::get().reads(25_u64))
+            .saturating_add(T::DbWeight::get().writes(26_u64))
+            .saturating_add(T::DbWeight::get().reads(1_u64).saturating_mul(b.into()))
+            .saturating_add(T::DbWeight::get().writes(1_u64).saturating_mul(b.into()))
+    }
+    /// As `settle_policy`, without the coverage-end check
+    fn trigger_immediate_settlement(b: u32) -> Weight {
+        Weight::from_parts(138_000_000, 8_000)
+            .saturating_add(Weight::from_parts(25_000_000, 0).saturating_mul(b.into()))
+            .saturating_add(Weight::from_parts(0, 2_600).saturating_mul(b.into()))
+            .saturating_add(T::DbWeight::get().reads(25_u64))
+            .saturating_add(T::DbWeight::get().writes(26_u64))
+            .saturating_add(T::DbWeight::get().reads(1_u64).saturating_mul(b.into()))
+            .saturating_add(T::DbWeight::get().writes(1_u64).saturating_mul(b.into()))
+    }
+    /// As `settle_policy`, plus PolicyGroupMembers, V2FinalReport and the V2
+    /// oracle status update
+    /// Writes: as `settle_policy`, plus V2FinalReport and Policies
+    fn settle_v2_policy(b: u32) -> Weight {
+        Weight::from_parts(150_000_000, 8_200)
+            .saturating_add(Weight::from_parts(25_000_000, 0).saturating_mul(b.into()))
+            .saturating_add(Weight::from_parts(0, 2_600).saturating_mul(b.into()))
+            .saturating_add(T::DbWeight::get().reads(28_u64))
+            .saturating_add(T::DbWeight::get().writes(28_u64))
+            .saturating_add(T::DbWeight::get().reads(1_u64).saturating_mul(b.into()))
+            .saturating_add(T::DbWeight::get().writes(1_u64).saturating_mul(b.into()))
+    }
+    /// Reads: Policies, PolicyPayoutSplits, Timestamp::Now, V2FinalReport,
+    /// PolicyBeneficiaries
+    /// Writes: Policies, PolicyBeneficiaries
+    fn transfer_policy() -> Weight {
+        Weight::from_parts(28_000_000, 4_500)
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Reads: Policies, Timestamp::Now, V2FinalReport, PolicyBeneficiaries,
+    /// PolicyCancellationTerms, PolicyProtocolFees, capital allocation (2),
+    /// Assets (4), holdings (3), reinsurance (2), MarketNotional, LocationNotional
+    /// Writes: Policies, PolicyRiskPoolBalance, PolicyInstallmentPlans,
+    /// PolicyPayoutSplits, PolicyProtocolFees, Assets (3),
+    /// holdings (3), capital allocation (2), reinsurance (2), MarketNotional,
+    /// LocationNotional
+    fn cancel_policy() -> Weight {
+        Weight::from_parts(130_000_000, 8_000)
+            .saturating_add(T::DbWeight::get().reads(19_u64))
+            .saturating_add(T::DbWeight::get().writes(17_u64))
+    }
+    /// Writes: PolicyCancellationTerms
+    fn set_cancellation_terms() -> Weight {
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Writes: MaxNotionalPerMarket, MaxNotionalPerLocation
+    fn set_notional_caps() -> Weight {
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// As `apply_coverage_with_quote`, plus PolicyInstallmentPlans
+    fn apply_coverage_with_installments() -> Weight {
+        Weight::from_parts(181_000_000, 9_500)
+            .saturating_add(T::DbWeight::get().reads(35_u64))
+            .saturating_add(T::DbWeight::get().writes(27_u64))
+    }
+    /// Reads: PayoutSchedule, PausedOperations, Timestamp::Now, InstallmentCursor, Assets (3)
+    /// Writes: PayoutSchedule, InstallmentQueue (2), InstallmentCursor, Assets (2)
+    fn claim_installment() -> Weight {
+        Weight::from_parts(45_000_000, 4_000)
+            .saturating_add(T::DbWeight::get().reads(7_u64))
+            .saturating_add(T::DbWeight::get().writes(6_u64))
+    }
+    /// One schedule released by the `on_initialize` sweep
+    /// Reads: PayoutSchedule, InstallmentCursor, Assets (3)
+    /// Writes: PayoutSchedule, InstallmentQueue (2), InstallmentCursor, Assets (2)
+    fn release_installment() -> Weight {
+        Weight::from_parts(40_000_000, 4_000)
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(6_u64))
+    }
+    /// Writes: ProtocolFeeBp
+    fn set_protocol_fee() -> Weight {
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Reads: Assets (3)
+    /// Writes: Assets (2)
+    fn withdraw_treasury() -> Weight {
+        Weight::from_parts(35_000_000, 3_600)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Reads: Policies, PolicyGroupMembers, PolicyBeneficiaries, Timestamp::Now,
+    /// QuoteRequests, QuoteResults, QuoteParamsHashes, QuoteStatuses, PausedOperations,
+    /// StaleMarkets, Markets (3), PolicyAssets, ProtocolFeeBp, Assets (4)
+    /// Writes: Policies, PolicyRiskPoolBalance, PolicyProtocolFees, CollectedFees,
+    /// Assets (3), QuoteStatuses
+    /// Plus QuoteDeposits and System::Account read and written to release the
+    /// quote deposit
+    fn extend_policy() -> Weight {
+        Weight::from_parts(95_000_000, 6_000)
+            .saturating_add(T::DbWeight::get().reads(21_u64))
+            .saturating_add(T::DbWeight::get().writes(10_u64))
+    }
+    /// As `apply_coverage_with_quote`, validating and storing `s` payout splits
+    fn apply_coverage_with_payout_splits(s: u32) -> Weight {
+        Weight::from_parts(182_000_000, 9_500)
+            .saturating_add(Weight::from_parts(300_000, 0).saturating_mul(s.into()))
+            .saturating_add(Weight::from_parts(0, 34).saturating_mul(s.into()))
+            .saturating_add(T::DbWeight::get().reads(37_u64))
+            .saturating_add(T::DbWeight::get().writes(29_u64))
+    }
+    /// Per policy, as `settle_policy` with `b` beneficiaries
+    /// Writes: per policy, as `settle_policy`, plus ForcedSettlements
+    fn force_settle_batch(n: u32, b: u32) -> Weight {
+        let nb = u64::from(n.saturating_mul(b));
+        Weight::from_parts(15_000_000, 0)
+            .saturating_add(Weight::from_parts(140_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(Weight::from_parts(0, 8_000).saturating_mul(n.into()))
+            .saturating_add(Weight::from_parts(25_000_000, 0).saturating_mul(nb))
+            .saturating_add(Weight::from_parts(0, 2_600).saturating_mul(nb))
+            .saturating_add(T::DbWeight::get().reads(24_u64).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().writes(25_u64).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(1_u64).saturating_mul(nb))
+            .saturating_add(T::DbWeight::get().writes(1_u64).saturating_mul(nb))
+    }
+    /// Writes: ClaimVerificationEnabled
+    fn set_claim_verification() -> Weight {
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Released as a lump sum to the claimant
+    /// Reads: HeldPayouts, PolicyInstallmentPlans, PolicyPayoutSplits, PolicyAssets,
+    /// Timestamp::Now, payout route, Assets (3)
+    /// Writes: HeldPayouts, PolicyInstallmentPlans, PolicyPayoutSplits, Assets (2)
+    fn release_held_payout() -> Weight {
+        Weight::from_parts(50_000_000, 4_000)
+            .saturating_add(T::DbWeight::get().reads(9_u64))
+            .saturating_add(T::DbWeight::get().writes(5_u64))
+    }
+    /// Reads: HeldPayouts, PolicyAssets, Assets (3)
+    /// Writes: HeldPayouts, PolicyInstallmentPlans, PolicyPayoutSplits, Assets (2)
+    fn deny_held_payout() -> Weight {
+        Weight::from_parts(40_000_000, 4_000)
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(5_u64))
+    }
+    /// As `settle_v2_policy`, checking `m` member observations
+    fn settle_group_policy(b: u32, m: u32) -> Weight {
+        Weight::from_parts(152_000_000, 8_200)
+            .saturating_add(Weight::from_parts(25_000_000, 0).saturating_mul(b.into()))
+            .saturating_add(Weight::from_parts(0, 2_600).saturating_mul(b.into()))
+            .saturating_add(Weight::from_parts(150_000, 0).saturating_mul(m.into()))
+            .saturating_add(Weight::from_parts(0, 24).saturating_mul(m.into()))
+            .saturating_add(T::DbWeight::get().reads(27_u64))
+            .saturating_add(T::DbWeight::get().writes(26_u64))
+            .saturating_add(T::DbWeight::get().reads(1_u64).saturating_mul(b.into()))
+            .saturating_add(T::DbWeight::get().writes(1_u64).saturating_mul(b.into()))
+    }
+    /// Reads: QuoteRequests, QuoteResults, QuoteParamsHashes, QuoteStatuses, PausedOperations,
+    /// StaleMarkets, GroupQuoteMembers, Markets (3), Timestamp::Now, AccountNonce,
+    /// MaxNotionalPerMarket, MaxNotionalPerLocation, MarketNotional,
+    /// LocationNotional, ProtocolFeeBp, ReservedDaoCapital, ActivationCursor, Assets (4)
+    /// Writes: AccountNonce, Policies, PoliciesByMarket, PolicyGroupMembers, PolicyAssets,
+    /// PolicyPoolAccounts, PoolAccountPolicies, MarketNotional, LocationNotional,
+    /// CapitalReservations, ReservedDaoCapital, ActivationQueue, ActivationCursor,
+    /// QuoteStatuses, Assets (2)
+    /// Plus QuoteDeposits and System::Account read and written to release the
+    /// quote deposit
+    fn apply_coverage_scheduled() -> Weight {
+        Weight::from_parts(120_000_000, 7_000)
+            .saturating_add(T::DbWeight::get().reads(25_u64))
+            .saturating_add(T::DbWeight::get().writes(18_u64))
+    }
+    /// Reads: Policies, Timestamp::Now, CapitalReservations, ReservedDaoCapital,
+    /// Assets (3), MarketNotional, LocationNotional
+    /// Writes: Policies, CapitalReservations, ReservedDaoCapital, ActivationQueue,
+    /// Assets (2), MarketNotional, LocationNotional
+    fn cancel_scheduled_policy() -> Weight {
+        Weight::from_parts(55_000_000, 4_500)
+            .saturating_add(T::DbWeight::get().reads(9_u64))
+            .saturating_add(T::DbWeight::get().writes(8_u64))
+    }
+    /// Reads: Policies, CapitalReservations, ReservedDaoCapital, CollectedFees,
+    /// Markets, Assets (5), holdings (3), orderbook (2), capital allocation (2),
+    /// reinsurance (2)
+    /// Writes: Policies, CapitalReservations, ReservedDaoCapital, ActivationQueue,
+    /// PolicyRiskPoolBalance, PolicyProtocolFees, CollectedFees, Markets, Assets (4),
+    /// holdings (3), orderbook (2), capital allocation (2), reinsurance (2)
+    fn activate_scheduled_policy() -> Weight {
+        Weight::from_parts(110_000_000, 8_000)
+            .saturating_add(T::DbWeight::get().reads(19_u64))
+            .saturating_add(T::DbWeight::get().writes(21_u64))
+    }
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+    fn apply_coverage_with_quote() -> Weight {
+        Weight::from_parts(180_000_000, 9_500)
+            .saturating_add(RocksDbWeight::get().reads(37_u64))
+            .saturating_add(RocksDbWeight::get().writes(28_u64))
+    }
+    fn apply_coverage_with_tiers(t: u32) -> Weight {
+        Weight::from_parts(182_000_000, 9_500)
+            .saturating_add(Weight::from_parts(400_000, 0).saturating_mul(t.into()))
+            .saturating_add(Weight::from_parts(0, 8).saturating_mul(t.into()))
+            .saturating_add(RocksDbWeight::get().reads(37_u64))
+            .saturating_add(RocksDbWeight::get().writes(28_u64))
+    }
+    fn settle_policy(b: u32) -> Weight {
+        Weight::from_parts(140_000_000, 8_000)
+            .saturating_add(Weight::from_parts(25_000_000, 0).saturating_mul(b.into()))
+            .saturating_add(Weight::from_parts(0, 2_600).saturating_mul(b.into()))
+            .saturating_add(RocksDbWeight::get().reads(25_u64))
+            .saturating_add(RocksDbWeight::get().writes(26_u64))
+            .saturating_add(RocksDbWeight::get().reads(1_u64).saturating_mul(b.into()))
+            .saturating_add(RocksDbWeight::get().writes(1_u64).saturating_mul(b.into()))
+    }
+    fn trigger_immediate_settlement(b: u32) -> Weight {
+        Weight::from_parts(138_000_000, 8_000)
+            .saturating_add(Weight::from_parts(25_000_000, 0).saturating_mul(b.into()))
+            .saturating_add(Weight::from_parts(0, 2_600).saturating_mul(b.into()))
+            .saturating_add(RocksDbWeight::get().reads(25_u64))
+            .saturating_add(RocksDbWeight::get().writes(26_u64))
+            .saturating_add(RocksDbWeight::get().reads(1_u64).saturating_mul(b.into()))
+            .saturating_add(RocksDbWeight::get().writes(1_u64).saturating_mul(b.into()))
+    }
+    fn settle_v2_policy(b: u32) -> Weight {
+        Weight::from_parts(150_000_000, 8_200)
+            .saturating_add(Weight::from_parts(25_000_000, 0).saturating_mul(b.into()))
+            .saturating_add(Weight::from_parts(0, 2_600).saturating_mul(b.into()))
+            .saturating_add(RocksDbWeight::get().reads(28_u64))
+            .saturating_add(RocksDbWeight::get().writes(28_u64))
+            .saturating_add(RocksDbWeight::get().reads(1_u64).saturating_mul(b.into()))
+            .saturating_add(RocksDbWeight::get().writes(1_u64).saturating_mul(b.into()))
+    }
+    fn transfer_policy() -> Weight {
+        Weight::from_parts(28_000_000, 4_500)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    fn cancel_policy() -> Weight {
+        Weight::from_parts(130_000_000, 8_000)
+            .saturating_add(RocksDbWeight::get().reads(19_u64))
+            .saturating_add(RocksDbWeight::get().writes(17_u64))
+    }
+    fn set_cancellation_terms() -> Weight {
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn set_notional_caps() -> Weight {
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    fn apply_coverage_with_installments() -> Weight {
+        Weight::from_parts(181_000_000, 9_500)
+            .saturating_add(RocksDbWeight::get().reads(35_u64))
+            .saturating_add(RocksDbWeight::get().writes(27_u64))
+    }
+    fn claim_installment() -> Weight {
+        Weight::from_parts(45_000_000, 4_000)
+            .saturating_add(RocksDbWeight::get().reads(7_u64))
+            .saturating_add(RocksDbWeight::get().writes(6_u64))
+    }
+    fn release_installment() -> Weight {
+        Weight::from_parts(40_000_000, 4_000)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(6_u64))
+    }
+    fn set_protocol_fee() -> Weight {
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn withdraw_treasury() -> Weight {
+        Weight::from_parts(35_000_000, 3_600)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    fn extend_policy() -> Weight {
+        Weight::from_parts(95_000_000, 6_000)
+            .saturating_add(RocksDbWeight::get().reads(21_u64))
+            .saturating_add(RocksDbWeight::get().writes(10_u64))
+    }
+    fn apply_coverage_with_payout_splits(s: u32) -> Weight {
+        Weight::from_parts(182_000_000, 9_500)
+            .saturating_add(Weight::from_parts(300_000, 0).saturating_mul(s.into()))
+            .saturating_add(Weight::from_parts(0, 34).saturating_mul(s.into()))
+            .saturating_add(RocksDbWeight::get().reads(37_u64))
+            .saturating_add(RocksDbWeight::get().writes(29_u64))
+    }
+    fn force_settle_batch(n: u32, b: u32) -> Weight {
+        let nb = u64::from(n.saturating_mul(b));
+        Weight::from_parts(15_000_000, 0)
+            .saturating_add(Weight::from_parts(140_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(Weight::from_parts(0, 8_000).saturating_mul(n.into()))
+            .saturating_add(Weight::from_parts(25_000_000, 0).saturating_mul(nb))
+            .saturating_add(Weight::from_parts(0, 2_600).saturating_mul(nb))
+            .saturating_add(RocksDbWeight::get().reads(24_u64).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().writes(25_u64).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(1_u64).saturating_mul(nb))
+            .saturating_add(RocksDbWeight::get().writes(1_u64).saturating_mul(nb))
+    }
+    fn set_claim_verification() -> Weight {
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn release_held_payout() -> Weight {
+        Weight::from_parts(50_000_000, 4_000)
+            .saturating_add(RocksDbWeight::get().reads(9_u64))
+            .saturating_add(RocksDbWeight::get().writes(5_u64))
+    }
+    fn deny_held_payout() -> Weight {
+        Weight::from_parts(40_000_000, 4_000)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(5_u64))
+    }
+    fn settle_group_policy(b: u32, m: u32) -> Weight {
+        Weight::from_parts(152_000_000, 8_200)
+            .saturating_add(Weight::from_parts(25_000_000, 0).saturating_mul(b.into()))
+            .saturating_add(Weight::from_parts(0, 2_600).saturating_mul(b.into()))
+            .saturating_add(Weight::from_parts(150_000, 0).saturating_mul(m.into()))
+            .saturating_add(Weight::from_parts(0, 24).saturating_mul(m.into()))
+            .saturating_add(RocksDbWeight::get().reads(27_u64))
+            .saturating_add(RocksDbWeight::get().writes(26_u64))
+            .saturating_add(RocksDbWeight::get().reads(1_u64).saturating_mul(b.into()))
+            .saturating_add(RocksDbWeight::get().writes(1_u64).saturating_mul(b.into()))
+    }
+    fn apply_coverage_scheduled() -> Weight {
+        Weight::from_parts(120_000_000, 7_000)
+            .saturating_add(RocksDbWeight::get().reads(25_u64))
+            .saturating_add(RocksDbWeight::get().writes(18_u64))
+    }
+    fn cancel_scheduled_policy() -> Weight {
+        Weight::from_parts(55_000_000, 4_500)
+            .saturating_add(RocksDbWeight::get().reads(9_u64))
+            .saturating_add(RocksDbWeight::get().writes(8_u64))
+    }
+    fn activate_scheduled_policy() -> Weight {
+        Weight::from_parts(110_000_000, 8_000)
+            .saturating_add(RocksDbWeight::get().reads(19_u64))
+            .saturating_add(RocksDbWeight::get().writes(21_u64))
+    }
+}
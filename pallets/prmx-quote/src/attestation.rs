@@ -0,0 +1,178 @@
+//! # Pricing Service Attestations
+//!
+//! Keeps a compromised offchain worker from fabricating R model probabilities,
+//! in the spirit of ERC-3668: the data comes from off-chain, but the chain only
+//! accepts it with a signature it can check itself.
+//!
+//! - The OCW sends the R pricing service a `request_hash` parameter: blake2_256
+//!   of `b"prmx_pricing_request:" || SCALE(PriceCacheKey) || SCALE(coverage)`,
+//!   with coverage in whole units as sent in the `coverage` parameter.
+//! - The service recomputes the hash from the parameters it priced and returns
+//!   an `attestation` object (`coverage`, `avg_cost_micros`, `signature`): an
+//!   ed25519 signature over `b"prmx_pricing_attestation:" || request_hash ||
+//!   SCALE(avg_cost_micros as u64)`.
+//! - Once governance registers the service's public key (`PricingServiceKey`),
+//!   OCW results for R-priced quotes must carry the attestation. The chain
+//!   recomputes the request hash from the quote request, checks the signature
+//!   and requires the submitted probability to equal `probability_ppm`.
+//!
+//! The attestation is part of the raw response body, so quotes served from the
+//! price cache carry the attestation of the call that filled it. The cache key
+//! ignores coverage, which is why the signed coverage travels with it.
+
+use alloc::{string::String, vec::Vec};
+use codec::Encode;
+
+use crate::{price_cache::PriceCacheKey, PricingAttestation};
+
+/// Domain separator of the request hash
+pub const REQUEST_HASH_CONTEXT: &[u8] = b"prmx_pricing_request:";
+
+/// Domain separator of the message signed by the pricing service
+pub const ATTESTATION_CONTEXT: &[u8] = b"prmx_pricing_attestation:";
+
+/// Hash identifying an R model request
+pub fn request_hash(key: &PriceCacheKey, coverage: u64) -> [u8; 32] {
+    let mut data = REQUEST_HASH_CONTEXT.to_vec();
+    data.extend_from_slice(&key.encode());
+    data.extend_from_slice(&coverage.encode());
+    sp_io::hashing::blake2_256(&data)
+}
+
+/// `request_hash=0x…` query parameter for the R API
+pub fn request_hash_param(hash: &[u8; 32]) -> String {
+    let mut param = String::from("request_hash=0x");
+    for byte in hash {
+        param.push_str(&alloc::format!("{:02x}", byte));
+    }
+    param
+}
+
+/// Message the pricing service signs
+pub fn signed_message(request_hash: &[u8; 32], avg_cost_micros: u64) -> Vec<u8> {
+    let mut message = ATTESTATION_CONTEXT.to_vec();
+    message.extend_from_slice(request_hash);
+    message.extend_from_slice(&avg_cost_micros.encode());
+    message
+}
+
+/// Probability of an attested cost, rounded half up and capped at 100%.
+/// `None` for zero coverage.
+pub fn probability_ppm(avg_cost_micros: u64, coverage: u64) -> Option<u32> {
+    if coverage == 0 {
+        return None;
+    }
+    let coverage = coverage as u128;
+    let ppm = (avg_cost_micros as u128 + coverage / 2) / coverage;
+    Some(ppm.min(1_000_000) as u32)
+}
+
+/// Whether `attestation` is signed by `service_key` for `request_hash`
+pub fn verify(
+    service_key: &[u8; 32],
+    request_hash: &[u8; 32],
+    attestation: &PricingAttestation,
+) -> bool {
+    sp_io::crypto::ed25519_verify(
+        &sp_core::ed25519::Signature::from_raw(attestation.signature),
+        &signed_message(request_hash, attestation.avg_cost_micros),
+        &sp_core::ed25519::Public::from_raw(*service_key),
+    )
+}
+
+/// Attestation in a raw R API response body, if it has a well-formed one
+pub fn from_response(body: &[u8]) -> Option<PricingAttestation> {
+    let attestation = prmx_json::RPricingResponse::from_json(body).ok()?.attestation?;
+    Some(PricingAttestation {
+        coverage: attestation.coverage,
+        avg_cost_micros: attestation.avg_cost_micros,
+        signature: decode_signature(&attestation.signature)?,
+    })
+}
+
+/// 64-byte signature from hex, with or without `0x`
+fn decode_signature(hex: &str) -> Option<[u8; 64]> {
+    let hex = hex.strip_prefix("0x").unwrap_or(hex).as_bytes();
+    if hex.len() != 128 {
+        return None;
+    }
+    let nibble = |c: u8| (c as char).to_digit(16).map(|d| d as u8);
+    let mut signature = [0u8; 64];
+    for (byte, pair) in signature.iter_mut().zip(hex.chunks(2)) {
+        *byte = (nibble(pair[0])? << 4) | nibble(pair[1])?;
+    }
+    Some(signature)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sp_core::Pair;
+
+    fn manila_key() -> PriceCacheKey {
+        PriceCacheKey::new(0, 14_599_500, 120_984_200, 24, 500)
+    }
+
+    #[test]
+    fn test_request_hash_binds_inputs_and_coverage() {
+        let hash = request_hash(&manila_key(), 1_000);
+        assert_eq!(hash, request_hash(&manila_key(), 1_000));
+        assert_ne!(hash, request_hash(&manila_key(), 2_000));
+        let tokyo = PriceCacheKey::new(2, 35_676_200, 139_650_300, 24, 500);
+        assert_ne!(hash, request_hash(&tokyo, 1_000));
+        assert_eq!(request_hash_param(&[0xab; 32]).len(), "request_hash=0x".len() + 64);
+        assert!(request_hash_param(&[0x0f; 32]).ends_with("0f0f"));
+    }
+
+    #[test]
+    fn test_probability_ppm_from_attested_cost() {
+        // 0.902 on 100 of coverage is 0.902%
+        assert_eq!(probability_ppm(902_000, 100), Some(9_020));
+        // Rounds half up
+        assert_eq!(probability_ppm(15, 10), Some(2));
+        assert_eq!(probability_ppm(14, 10), Some(1));
+        assert_eq!(probability_ppm(u64::MAX, 1), Some(1_000_000));
+        assert_eq!(probability_ppm(902_000, 0), None);
+    }
+
+    #[test]
+    fn test_verify_signed_attestation() {
+        let service = sp_core::ed25519::Pair::from_seed(&[7u8; 32]);
+        let service_key = service.public().0;
+        let hash = request_hash(&manila_key(), 100);
+        let attestation = PricingAttestation {
+            coverage: 100,
+            avg_cost_micros: 902_000,
+            signature: service.sign(&signed_message(&hash, 902_000)).0,
+        };
+        assert!(verify(&service_key, &hash, &attestation));
+
+        let inflated = PricingAttestation { avg_cost_micros: 5_000_000, ..attestation.clone() };
+        assert!(!verify(&service_key, &hash, &inflated));
+        assert!(!verify(&service_key, &request_hash(&manila_key(), 200), &attestation));
+        assert!(!verify(&[9u8; 32], &hash, &attestation));
+    }
+
+    #[test]
+    fn test_from_response() {
+        let signature = "ab".repeat(64);
+        let body = alloc::format!(
+            r#"{{"avg_cost":[0.902],"attestation":{{"coverage":[100],
+            "avg_cost_micros":[902000],"signature":["0x{}"]}}}}"#,
+            signature
+        );
+        assert_eq!(
+            from_response(body.as_bytes()),
+            Some(PricingAttestation {
+                coverage: 100,
+                avg_cost_micros: 902_000,
+                signature: [0xab; 64],
+            })
+        );
+
+        assert_eq!(from_response(br#"{"avg_cost":[0.902]}"#), None);
+        let short = br#"{"avg_cost":1,"attestation":{"coverage":1,"avg_cost_micros":1,
+            "signature":"abcd"}}"#;
+        assert_eq!(from_response(short), None);
+    }
+}
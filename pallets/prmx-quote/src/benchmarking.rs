@@ -0,0 +1,606 @@
+//! Benchmarks for the PRMX quote pallet
+
+use super::*;
+use alloc::vec;
+use frame_benchmarking::v2::*;
+use frame_support::{pallet_prelude::*, traits::Currency};
+use frame_system::RawOrigin;
+use crate::bonding_curve::BondingCurve;
+use pallet_prmx_markets::{MarketId, PartsPerMillion};
+use prmx_primitives::{EventSpecV3, EventType, EventTypeV3, PerilAggregation, ThresholdV3, UnitV3};
+use sp_runtime::traits::{Bounded, IdentifyAccount};
+
+/// Coverage window valid under the default window rules at timestamp zero
+const COVERAGE_START: u64 = 86_400;
+const COVERAGE_END: u64 = COVERAGE_START + 2 * 86_400;
+const DURATION_DAYS: u8 = 2;
+
+fn quote_params(market_id: MarketId) -> QuoteParams {
+    QuoteParams {
+        market_id,
+        coverage_start: COVERAGE_START,
+        coverage_end: COVERAGE_END,
+        latitude: 14_599_500,
+        longitude: 120_984_200,
+        shares: 10,
+    }
+}
+
+/// Pricing evidence with a maximum-length evidence URL
+fn max_evidence() -> Option<PricingEvidence> {
+    Some(PricingEvidence {
+        inputs_hash: [1; 32],
+        response_hash: [2; 32],
+        evidence_url: Some(
+            vec![b'e'; pricing_evidence::MAX_EVIDENCE_URL_LEN as usize]
+                .try_into()
+                .expect("exactly the maximum length"),
+        ),
+    })
+}
+
+/// Generate and register a pricing service key
+fn pricing_service<T: Config>() -> sp_core::ed25519::Public {
+    let service = sp_io::crypto::ed25519_generate(KEY_TYPE, None);
+    PricingServiceKey::<T>::put(service.0);
+    service
+}
+
+/// Attestation by `service` of a quote's R model call at `probability_ppm`
+fn attest<T: Config>(
+    service: &sp_core::ed25519::Public,
+    quote_id: QuoteId,
+    probability_ppm: PartsPerMillion,
+) -> PricingAttestation {
+    const COVERAGE: u64 = 1_000;
+
+    let req = QuoteRequests::<T>::get(quote_id).expect("quote was requested");
+    let key = Pallet::<T>::price_cache_key(&req, None).expect("market exists");
+    let request_hash = attestation::request_hash(&key, COVERAGE);
+    let avg_cost_micros = probability_ppm as u64 * COVERAGE;
+    let message = attestation::signed_message(&request_hash, avg_cost_micros);
+    let signature = sp_io::crypto::ed25519_sign(KEY_TYPE, service, &message)
+        .expect("key was just generated");
+
+    PricingAttestation { coverage: COVERAGE, avg_cost_micros, signature: signature.0 }
+}
+
+/// Bonding curve with room for any benchmark quote
+fn benchmark_curve<T: Config>() -> BondingCurve<T::Balance> {
+    BondingCurve {
+        base_probability_ppm: 20_000,
+        strike_exponent: bonding_curve::MAX_STRIKE_EXPONENT,
+        utilization_slope_ppm: 500_000,
+        capacity: u128::MAX.into(),
+        max_probability_ppm: 500_000,
+    }
+}
+
+fn wind_perils(p: u32) -> BoundedVec<EventSpecV3, MaxQuotePerils> {
+    let perils: Vec<EventSpecV3> = (0..p)
+        .map(|i| EventSpecV3 {
+            event_type: EventTypeV3::WindGustMaxGte,
+            threshold: ThresholdV3 {
+                value: 20_000 + i as i64,
+                unit: UnitV3::MpsX1000,
+            },
+            early_trigger: false,
+        })
+        .collect();
+    perils.try_into().expect("p is bounded by MaxQuotePerils")
+}
+
+/// `m` group members a little apart, all on the benchmark coverage window
+fn group_members(m: u32) -> BoundedVec<group::GroupMember, MaxGroupMembers> {
+    let members: Vec<group::GroupMember> = (0..m)
+        .map(|i| group::GroupMember {
+            latitude: 14_599_500 + i as i32 * 10_000,
+            longitude: 120_984_200,
+            coverage_start: COVERAGE_START,
+            coverage_end: COVERAGE_END,
+        })
+        .collect();
+    members.try_into().expect("m is bounded by MaxGroupMembers")
+}
+
+/// Request a group quote of `m` members from `caller` and return its id
+fn pending_group_quote<T: Config>(caller: &T::AccountId, market_id: MarketId, m: u32) -> QuoteId {
+    Pallet::<T>::request_group_quote(
+        RawOrigin::Signed(caller.clone()).into(),
+        market_id,
+        group_members(m),
+        10,
+        EventType::CumulativeRainfallWindow,
+        500,
+    )
+    .expect("market is open and V2-enabled");
+    *PendingQuotes::<T>::get().last().expect("quote was queued")
+}
+
+/// Whitelisted caller able to reserve the deposits of the quotes it requests
+fn funded_caller<T: Config>() -> T::AccountId {
+    let caller: T::AccountId = whitelisted_caller();
+    T::DepositCurrency::make_free_balance_be(
+        &caller,
+        DepositBalanceOf::<T>::max_value() / 2u32.into(),
+    );
+    caller
+}
+
+/// Request a V1 quote from `caller` and return its id
+fn pending_quote<T: Config>(caller: &T::AccountId, market_id: MarketId) -> QuoteId {
+    let params = quote_params(market_id);
+    Pallet::<T>::request_policy_quote(
+        RawOrigin::Signed(caller.clone()).into(),
+        params.market_id,
+        params.coverage_start,
+        params.coverage_end,
+        params.latitude,
+        params.longitude,
+        params.shares,
+    )
+    .expect("market is open");
+    *PendingQuotes::<T>::get().last().expect("quote was queued")
+}
+
+/// Generate a quote authority key and register its account as a quote provider
+fn quote_authority<T: Config>() -> (sp_core::sr25519::Public, T::Public)
+where
+    T::Public: From<sp_core::sr25519::Public>,
+{
+    let key = sp_io::crypto::sr25519_generate(KEY_TYPE, None);
+    let public: T::Public = key.into();
+    QuoteProviders::<T>::insert(public.clone().into_account(), true);
+    (key, public)
+}
+
+/// Sign an OCW payload with a key from `quote_authority`
+fn sign<T: Config>(key: &sp_core::sr25519::Public, payload: &impl Encode) -> T::Signature
+where
+    T::Signature: From<sp_core::sr25519::Signature>,
+{
+    sp_io::crypto::sr25519_sign(KEY_TYPE, key, &payload.encode())
+        .expect("key was just generated")
+        .into()
+}
+
+#[benchmarks(
+    where
+        T::Public: From<sp_core::sr25519::Public>,
+        T::Signature: From<sp_core::sr25519::Signature>,
+)]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn request_policy_quote() {
+        let market_id = T::BenchmarkHelper::open_market();
+        let caller = funded_caller::<T>();
+        let params = quote_params(market_id);
+        // Worst case: priced on-chain in the request
+        MarketBondingCurves::<T>::insert(market_id, benchmark_curve::<T>());
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller),
+            params.market_id,
+            params.coverage_start,
+            params.coverage_end,
+            params.latitude,
+            params.longitude,
+            params.shares,
+        );
+
+        assert_eq!(QuoteResults::<T>::iter().count(), 1);
+    }
+
+    #[benchmark]
+    fn submit_quote() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let market_id = T::BenchmarkHelper::open_market();
+        let caller = funded_caller::<T>();
+        let quote_id = pending_quote::<T>(&caller, market_id);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, quote_id, 50_000);
+
+        assert_eq!(QuoteStatuses::<T>::get(quote_id), QuoteStatus::Ready);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn submit_quote_from_ocw() {
+        let market_id = T::BenchmarkHelper::open_market();
+        let caller = funded_caller::<T>();
+        let quote_id = pending_quote::<T>(&caller, market_id);
+        let (key, public) = quote_authority::<T>();
+        // Worst case: the result carries an attestation that is checked
+        let service = pricing_service::<T>();
+        let payload = QuoteResultPayload {
+            block_number: frame_system::Pallet::<T>::block_number(),
+            public,
+            quote_id,
+            probability_ppm: 50_000,
+            evidence: max_evidence(),
+            attestation: Some(attest::<T>(&service, quote_id, 50_000)),
+        };
+        let signature = sign::<T>(&key, &payload);
+
+        #[extrinsic_call]
+        _(RawOrigin::None, payload, signature);
+
+        assert!(QuotePricingEvidence::<T>::contains_key(quote_id));
+    }
+
+    #[benchmark]
+    fn set_pricing_service_key() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, Some([7u8; 32]));
+
+        assert_eq!(PricingServiceKey::<T>::get(), Some([7u8; 32]));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_pricing_api_key() {
+        #[extrinsic_call]
+        _(RawOrigin::Root, vec![b'k'; 64]);
+    }
+
+    #[benchmark]
+    fn set_pricing_api_url() {
+        #[extrinsic_call]
+        _(RawOrigin::Root, vec![b'u'; 128]);
+    }
+
+    #[benchmark]
+    fn add_quote_provider() {
+        let provider: T::AccountId = account("provider", 0, 0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, provider.clone());
+
+        assert!(QuoteProviders::<T>::get(&provider));
+    }
+
+    #[benchmark]
+    fn remove_quote_provider() {
+        let provider = quote_provider::<T>();
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, provider.clone());
+
+        assert!(!QuoteProviders::<T>::get(&provider));
+    }
+
+    #[benchmark]
+    fn request_policy_quote_v2(p: Linear<0, { prmx_primitives::MAX_ADDITIONAL_PERILS }>) {
+        let market_id = T::BenchmarkHelper::open_market();
+        let caller = funded_caller::<T>();
+        let params = quote_params(market_id);
+        // Quotes without perils are priced on-chain in the request
+        MarketBondingCurves::<T>::insert(market_id, benchmark_curve::<T>());
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller),
+            params.market_id,
+            params.coverage_start,
+            params.coverage_end,
+            params.latitude,
+            params.longitude,
+            params.shares,
+            DURATION_DAYS,
+            EventType::CumulativeRainfallWindow,
+            500,
+            wind_perils(p),
+            PerilAggregation::Any,
+        );
+
+        assert_eq!(QuoteRequests::<T>::iter().count(), 1);
+    }
+
+    #[benchmark]
+    fn cancel_quote() {
+        let market_id = T::BenchmarkHelper::open_market();
+        let caller = funded_caller::<T>();
+        let quote_id = pending_quote::<T>(&caller, market_id);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), quote_id);
+
+        assert_eq!(QuoteStatuses::<T>::get(quote_id), QuoteStatus::Cancelled);
+    }
+
+    #[benchmark]
+    fn refresh_quote() {
+        let market_id = T::BenchmarkHelper::open_market();
+        let caller = funded_caller::<T>();
+        let quote_id = pending_quote::<T>(&caller, market_id);
+        QuoteStatuses::<T>::insert(quote_id, QuoteStatus::Expired);
+        MarketBondingCurves::<T>::insert(market_id, benchmark_curve::<T>());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), quote_id);
+
+        let new_quote_id = RefreshedQuotes::<T>::get(quote_id).expect("quote was refreshed");
+        assert_eq!(QuoteStatuses::<T>::get(new_quote_id), QuoteStatus::Ready);
+    }
+
+    #[benchmark]
+    fn request_policy_quotes_batch(n: Linear<1, { T::MaxQuotesPerBatch::get() }>) {
+        let market_id = T::BenchmarkHelper::open_market();
+        let caller = funded_caller::<T>();
+        let params: BoundedVec<QuoteParams, T::MaxQuotesPerBatch> = (0..n)
+            .map(|_| quote_params(market_id))
+            .collect::<Vec<_>>()
+            .try_into()
+            .expect("n is bounded by MaxQuotesPerBatch");
+        MarketBondingCurves::<T>::insert(market_id, benchmark_curve::<T>());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), params);
+
+        assert_eq!(QuoteResults::<T>::iter().count(), n as usize);
+    }
+
+    #[benchmark]
+    fn submit_quote_batch_from_ocw(n: Linear<1, { T::MaxQuotesPerBatch::get() }>) {
+        let market_id = T::BenchmarkHelper::open_market();
+        let caller = funded_caller::<T>();
+        let params: BoundedVec<QuoteParams, T::MaxQuotesPerBatch> = (0..n)
+            .map(|_| quote_params(market_id))
+            .collect::<Vec<_>>()
+            .try_into()
+            .expect("n is bounded by MaxQuotesPerBatch");
+        Pallet::<T>::request_policy_quotes_batch(RawOrigin::Signed(caller).into(), params)
+            .expect("market is open");
+        let batch_id = NextQuoteBatchId::<T>::get() - 1;
+        let service = pricing_service::<T>();
+        let results: QuoteBatchResults<T> = QuoteBatches::<T>::get(batch_id)
+            .expect("batch was stored")
+            .quote_ids
+            .iter()
+            .map(|quote_id| {
+                let attestation = attest::<T>(&service, *quote_id, 50_000);
+                (*quote_id, 50_000, max_evidence(), Some(attestation))
+            })
+            .collect::<Vec<_>>()
+            .try_into()
+            .expect("same length as the batch");
+        let (key, public) = quote_authority::<T>();
+        let payload = QuoteBatchResultPayload {
+            block_number: frame_system::Pallet::<T>::block_number(),
+            public,
+            batch_id,
+            results,
+        };
+        let signature = sign::<T>(&key, &payload);
+
+        #[extrinsic_call]
+        _(RawOrigin::None, payload, signature);
+
+        assert!(QuoteBatches::<T>::get(batch_id).expect("batch exists").resolved);
+    }
+
+    #[benchmark]
+    fn set_market_probability_override(
+        t: Linear<0, { <MaxProbabilityTiers as Get<u32>>::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let market_id = T::BenchmarkHelper::open_market();
+        let tiers: BoundedVec<ProbabilityTier, MaxProbabilityTiers> = (0..t)
+            .map(|i| ProbabilityTier {
+                max_duration_hours: 24 * (i as u64 + 1),
+                min_strike_mm: 100,
+                probability_ppm: 50_000,
+            })
+            .collect::<Vec<_>>()
+            .try_into()
+            .expect("t is bounded by MaxProbabilityTiers");
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, market_id, 50_000, tiers);
+
+        assert!(MarketProbabilityOverrides::<T>::contains_key(market_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn clear_market_probability_override() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let market_id = T::BenchmarkHelper::open_market();
+        MarketProbabilityOverrides::<T>::insert(
+            market_id,
+            ProbabilityOverride { default_ppm: 50_000, tiers: BoundedVec::new() },
+        );
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, market_id);
+
+        assert!(!MarketProbabilityOverrides::<T>::contains_key(market_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn submit_peril_quote_from_ocw(p: Linear<0, { prmx_primitives::MAX_ADDITIONAL_PERILS }>) {
+        let market_id = T::BenchmarkHelper::open_market();
+        let caller = funded_caller::<T>();
+        let params = quote_params(market_id);
+        Pallet::<T>::request_policy_quote_v2(
+            RawOrigin::Signed(caller).into(),
+            params.market_id,
+            params.coverage_start,
+            params.coverage_end,
+            params.latitude,
+            params.longitude,
+            params.shares,
+            DURATION_DAYS,
+            EventType::CumulativeRainfallWindow,
+            500,
+            wind_perils(p),
+            PerilAggregation::Any,
+        )
+        .expect("market is open and V2-enabled");
+        let quote_id = *PendingQuotes::<T>::get().last().expect("quote was queued");
+        let probabilities: BoundedVec<PartsPerMillion, MaxPerilProbabilities> =
+            vec![50_000; p as usize + 1].try_into().expect("one more than the perils");
+        let (key, public) = quote_authority::<T>();
+        let payload = PerilQuoteResultPayload {
+            block_number: frame_system::Pallet::<T>::block_number(),
+            public,
+            quote_id,
+            peril_probabilities: probabilities,
+            evidence: max_evidence(),
+        };
+        let signature = sign::<T>(&key, &payload);
+
+        #[extrinsic_call]
+        _(RawOrigin::None, payload, signature);
+
+        assert_eq!(QuoteStatuses::<T>::get(quote_id), QuoteStatus::Ready);
+    }
+
+    #[benchmark]
+    fn set_pricing_evidence_url() {
+        #[extrinsic_call]
+        _(RawOrigin::Root, vec![b'u'; 128]);
+    }
+
+    #[benchmark]
+    fn set_market_bonding_curve() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let market_id = T::BenchmarkHelper::open_market();
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, market_id, benchmark_curve::<T>());
+
+        assert!(MarketBondingCurves::<T>::contains_key(market_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn clear_market_bonding_curve() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let market_id = T::BenchmarkHelper::open_market();
+        MarketBondingCurves::<T>::insert(market_id, benchmark_curve::<T>());
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, market_id);
+
+        assert!(!MarketBondingCurves::<T>::contains_key(market_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn request_quote_ladder(n: Linear<1, { T::MaxQuotesPerBatch::get() }>) {
+        let market_id = T::BenchmarkHelper::open_market();
+        let caller = funded_caller::<T>();
+        let params = quote_params(market_id);
+        let strikes: BoundedVec<u32, T::MaxQuotesPerBatch> = (0..n)
+            .map(|i| 100 + i * 10)
+            .collect::<Vec<_>>()
+            .try_into()
+            .expect("n is bounded by MaxQuotesPerBatch");
+        // Every strike is priced on-chain and the ladder resolves in the request
+        MarketBondingCurves::<T>::insert(market_id, benchmark_curve::<T>());
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller),
+            params.market_id,
+            params.coverage_start,
+            params.coverage_end,
+            params.latitude,
+            params.longitude,
+            params.shares,
+            DURATION_DAYS,
+            EventType::CumulativeRainfallWindow,
+            strikes,
+        );
+
+        let batch_id = NextQuoteBatchId::<T>::get() - 1;
+        assert!(QuoteLadders::<T>::contains_key(batch_id));
+        assert!(QuoteBatches::<T>::get(batch_id).expect("batch exists").resolved);
+    }
+
+    #[benchmark]
+    fn request_group_quote(m: Linear<{ group::MIN_GROUP_MEMBERS }, { group::MAX_GROUP_MEMBERS }>) {
+        let market_id = T::BenchmarkHelper::open_market();
+        let caller = funded_caller::<T>();
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller),
+            market_id,
+            group_members(m),
+            10,
+            EventType::CumulativeRainfallWindow,
+            500,
+        );
+
+        assert_eq!(GroupQuoteMembers::<T>::iter().count(), 1);
+    }
+
+    #[benchmark]
+    fn submit_group_quote_from_ocw(
+        m: Linear<{ group::MIN_GROUP_MEMBERS }, { group::MAX_GROUP_MEMBERS }>,
+    ) {
+        let market_id = T::BenchmarkHelper::open_market();
+        let caller = funded_caller::<T>();
+        let quote_id = pending_group_quote::<T>(&caller, market_id, m);
+        let probabilities: BoundedVec<PartsPerMillion, MaxGroupMembers> =
+            vec![50_000; m as usize].try_into().expect("one per member");
+        let (key, public) = quote_authority::<T>();
+        let payload = GroupQuoteResultPayload {
+            block_number: frame_system::Pallet::<T>::block_number(),
+            public,
+            quote_id,
+            member_probabilities: probabilities,
+            correlation_discount_bp: group::MAX_CORRELATION_DISCOUNT_BP,
+            evidence: max_evidence(),
+        };
+        let signature = sign::<T>(&key, &payload);
+
+        #[extrinsic_call]
+        _(RawOrigin::None, payload, signature);
+
+        assert!(GroupQuotePricing::<T>::contains_key(quote_id));
+    }
+
+    #[benchmark]
+    fn release_quote_deposit() {
+        let market_id = T::BenchmarkHelper::open_market();
+        let caller = funded_caller::<T>();
+        let quote_id = pending_quote::<T>(&caller, market_id);
+        QuoteStatuses::<T>::insert(quote_id, QuoteStatus::Expired);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), quote_id);
+
+        assert!(!QuoteDeposits::<T>::contains_key(quote_id));
+    }
+
+    #[benchmark]
+    fn slash_quote_deposit() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let market_id = T::BenchmarkHelper::open_market();
+        let caller = funded_caller::<T>();
+        let quote_id = pending_quote::<T>(&caller, market_id);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, quote_id);
+
+        assert!(!QuoteDeposits::<T>::contains_key(quote_id));
+        Ok(())
+    }
+}
@@ -0,0 +1,129 @@
+//! # Quote → Policy Binding
+//!
+//! When a quote is priced, the pallet stores a hash of the terms it was priced
+//! for (`QuoteParamsHashes`): the quote's coverage, location, shares, event and
+//! perils, and the resulting premium. The policy pallet recomputes the hash from
+//! the terms it is about to write into the policy and rejects the policy if the
+//! two differ, so a policy can never be created on terms that were not priced.
+//!
+//! The hash is blake2_256 of the SCALE encoding of [`PricedTerms`], prefixed
+//! with `PARAMS_HASH_DOMAIN`. Changing what is hashed must change the domain.
+
+use codec::Encode;
+
+use crate::{QuoteRequestInfo, QuoteResultInfo};
+
+/// Domain tag of the quote parameter hash
+pub const PARAMS_HASH_DOMAIN: &[u8] = b"prmx-quote/params/v1";
+
+/// Terms a quote was priced for, as hashed
+#[derive(Encode)]
+pub struct PricedTerms<'a, AccountId, Balance> {
+    pub quote_id: &'a prmx_primitives::QuoteId,
+    pub market_id: u64,
+    pub requester: &'a AccountId,
+    pub coverage_start: u64,
+    pub coverage_end: u64,
+    pub latitude: i32,
+    pub longitude: i32,
+    pub shares: u128,
+    pub policy_version: &'a prmx_primitives::PolicyVersion,
+    pub event_type: &'a prmx_primitives::EventType,
+    pub early_trigger: bool,
+    pub duration_days: u8,
+    pub strike_mm: Option<u32>,
+    pub perils: &'a [prmx_primitives::EventSpecV3],
+    pub peril_aggregation: &'a prmx_primitives::PerilAggregation,
+    pub premium_per_share: &'a Balance,
+    pub total_premium: &'a Balance,
+}
+
+/// Parameter hash of a quote request and its priced result
+pub fn params_hash<AccountId: Encode, Balance: Encode>(
+    req: &QuoteRequestInfo<AccountId>,
+    res: &QuoteResultInfo<Balance>,
+) -> [u8; 32] {
+    let terms = PricedTerms {
+        quote_id: &req.quote_id,
+        market_id: req.market_id,
+        requester: &req.requester,
+        coverage_start: req.coverage_start,
+        coverage_end: req.coverage_end,
+        latitude: req.latitude,
+        longitude: req.longitude,
+        shares: req.shares,
+        policy_version: &req.policy_version,
+        event_type: &req.event_type,
+        early_trigger: req.early_trigger,
+        duration_days: req.duration_days,
+        strike_mm: req.strike_mm,
+        perils: &req.perils,
+        peril_aggregation: &req.peril_aggregation,
+        premium_per_share: &res.premium_per_share,
+        total_premium: &res.total_premium,
+    };
+    sp_io::hashing::blake2_256(&(PARAMS_HASH_DOMAIN, terms).encode())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    fn request() -> QuoteRequestInfo<u64> {
+        QuoteRequestInfo {
+            quote_id: prmx_primitives::QuoteId::from([1u8; 16]),
+            market_id: 0,
+            requester: 7,
+            coverage_start: 1_700_000_000,
+            coverage_end: 1_700_172_800,
+            latitude: 14_599_500,
+            longitude: 120_984_200,
+            shares: 10,
+            requested_at: 1_699_999_000,
+            policy_version: prmx_primitives::PolicyVersion::V2,
+            event_type: prmx_primitives::EventType::CumulativeRainfallWindow,
+            early_trigger: true,
+            duration_days: 2,
+            strike_mm: Some(500),
+            perils: Vec::new(),
+            peril_aggregation: prmx_primitives::PerilAggregation::Any,
+        }
+    }
+
+    fn result() -> QuoteResultInfo<u128> {
+        QuoteResultInfo {
+            probability_ppm: 50_000,
+            peril_probabilities: vec![50_000],
+            premium_per_share: 6_000_000,
+            total_premium: 60_000_000,
+            calculated_at: 1_700_000_000,
+        }
+    }
+
+    #[test]
+    fn test_params_hash_binds_priced_terms() {
+        let hash = params_hash(&request(), &result());
+        assert_eq!(hash, params_hash(&request(), &result()));
+
+        // Request time, probabilities and pricing time are not policy terms
+        let mut req = request();
+        req.requested_at += 60;
+        let mut res = result();
+        res.calculated_at += 60;
+        res.probability_ppm += 1;
+        assert_eq!(hash, params_hash(&req, &res));
+
+        let mut req = request();
+        req.shares += 1;
+        assert_ne!(hash, params_hash(&req, &result()));
+
+        let mut req = request();
+        req.strike_mm = Some(600);
+        assert_ne!(hash, params_hash(&req, &result()));
+
+        let mut res = result();
+        res.total_premium -= 1;
+        assert_ne!(hash, params_hash(&request(), &res));
+    }
+}
@@ -0,0 +1,169 @@
+//! # Bonding-Curve Pricing
+//!
+//! On-chain alternative to the R model that governance can select per market
+//! (`set_market_bonding_curve`). Quotes on such markets are priced in the
+//! request transaction, without the offchain worker, so quoting keeps working
+//! while the R pricing service is down.
+//!
+//! The curve gives the trigger probability the premium is calculated from:
+//!
+//! p = base × (market_strike / strike)^strike_exponent × (1 + slope × u / (1 - u))
+//!
+//! - The strike term makes quotes with a custom strike above the market strike
+//!   cheaper and below it dearer.
+//! - u is the market's open notional over the curve's `capacity`, taken at the
+//!   midpoint of the purchase, so one large quote pays what the same cover
+//!   bought in small pieces would on average. The loading reaches
+//!   1 + slope at 50% utilization and grows without bound towards 100%.
+//!
+//! p is capped at `max_probability_ppm`. Quotes that would take utilization to
+//! 100% or beyond are refused.
+
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+
+/// One in parts per million
+const PPM: u128 = 1_000_000;
+
+/// Highest allowed `strike_exponent`
+pub const MAX_STRIKE_EXPONENT: u8 = 3;
+
+/// Governance parameters of a market's bonding curve
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    PartialEq,
+    Eq,
+    Debug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct BondingCurve<Balance> {
+    /// Probability at the market strike with no open notional
+    pub base_probability_ppm: u32,
+    /// Power of the strike ratio; 0 ignores the strike
+    pub strike_exponent: u8,
+    /// Loading at 50% utilization, in ppm of the strike-adjusted probability
+    pub utilization_slope_ppm: u32,
+    /// Open notional (sum of max payouts) at which the curve is exhausted
+    pub capacity: Balance,
+    /// Upper bound on the quoted probability
+    pub max_probability_ppm: u32,
+}
+
+impl<Balance: Copy + Into<u128>> BondingCurve<Balance> {
+    pub fn is_valid(&self) -> bool {
+        self.base_probability_ppm <= self.max_probability_ppm
+            && self.max_probability_ppm as u128 <= PPM
+            && self.strike_exponent <= MAX_STRIKE_EXPONENT
+            && self.capacity.into() > 0
+    }
+
+    /// Probability for a quote of `quote_notional` on a market with
+    /// `market_notional` open, at `strike` against the market's `market_strike`
+    /// (both mm * 10). `None` if the quote would exhaust the curve.
+    pub fn probability_ppm(
+        &self,
+        market_notional: u128,
+        quote_notional: u128,
+        market_strike: u32,
+        strike: u32,
+    ) -> Option<u32> {
+        let capacity: u128 = self.capacity.into();
+        if market_notional.checked_add(quote_notional)? >= capacity {
+            return None;
+        }
+
+        // Below PPM: the midpoint is under the notional after the quote
+        let utilization = ratio_ppm(market_notional + quote_notional / 2, capacity).min(PPM - 1);
+        let loading = PPM + self.utilization_slope_ppm as u128 * utilization / (PPM - utilization);
+
+        let probability = (self.base_probability_ppm as u128)
+            .saturating_mul(self.strike_factor_ppm(market_strike, strike))
+            / PPM;
+        let probability = probability.saturating_mul(loading) / PPM;
+        Some(probability.min(self.max_probability_ppm as u128) as u32)
+    }
+
+    /// (market_strike / strike)^strike_exponent in ppm
+    fn strike_factor_ppm(&self, market_strike: u32, strike: u32) -> u128 {
+        if strike == 0 {
+            return PPM;
+        }
+        let ratio = ratio_ppm(market_strike as u128, strike as u128);
+        (0..self.strike_exponent).fold(PPM, |factor, _| factor.saturating_mul(ratio) / PPM)
+    }
+}
+
+/// `numerator / denominator` in ppm, for a non-zero denominator
+fn ratio_ppm(numerator: u128, denominator: u128) -> u128 {
+    match numerator.checked_mul(PPM) {
+        Some(scaled) => scaled / denominator,
+        None => numerator / (denominator / PPM).max(1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn curve() -> BondingCurve<u128> {
+        BondingCurve {
+            base_probability_ppm: 20_000,
+            strike_exponent: 1,
+            utilization_slope_ppm: 1_000_000,
+            capacity: 1_000_000,
+            max_probability_ppm: 500_000,
+        }
+    }
+
+    #[test]
+    fn test_base_probability_at_market_strike_and_no_utilization() {
+        assert_eq!(curve().probability_ppm(0, 0, 500, 500), Some(20_000));
+        assert_eq!(curve().probability_ppm(0, 1, 500, 500), Some(20_000));
+    }
+
+    #[test]
+    fn test_utilization_loading_uses_purchase_midpoint() {
+        // Midpoint at 50% utilization: loading 1 + 1.0 × 0.5 / 0.5 = 2
+        assert_eq!(curve().probability_ppm(400_000, 200_000, 500, 500), Some(40_000));
+        // Same midpoint reached with a larger purchase costs the same probability
+        assert_eq!(curve().probability_ppm(300_000, 400_000, 500, 500), Some(40_000));
+        // Loading rises steeply near capacity: 90% → 1 + 9 = 10
+        assert_eq!(curve().probability_ppm(900_000, 0, 500, 500), Some(200_000));
+        // Capped at max_probability_ppm
+        assert_eq!(curve().probability_ppm(990_000, 0, 500, 500), Some(500_000));
+    }
+
+    #[test]
+    fn test_capacity_is_never_reached() {
+        assert_eq!(curve().probability_ppm(600_000, 400_000, 500, 500), None);
+        assert_eq!(curve().probability_ppm(1_000_000, 0, 500, 500), None);
+        assert_eq!(curve().probability_ppm(u128::MAX, 1, 500, 500), None);
+    }
+
+    #[test]
+    fn test_strike_distance() {
+        // Double the market strike halves the probability with exponent 1 ...
+        assert_eq!(curve().probability_ppm(0, 0, 500, 1_000), Some(10_000));
+        // ... and quarters it with exponent 2
+        let steep = BondingCurve { strike_exponent: 2, ..curve() };
+        assert_eq!(steep.probability_ppm(0, 0, 500, 1_000), Some(5_000));
+        // A lower strike is dearer
+        assert_eq!(curve().probability_ppm(0, 0, 500, 250), Some(40_000));
+        // Exponent 0 ignores the strike
+        let flat = BondingCurve { strike_exponent: 0, ..curve() };
+        assert_eq!(flat.probability_ppm(0, 0, 500, 1_000), Some(20_000));
+    }
+
+    #[test]
+    fn test_is_valid() {
+        assert!(curve().is_valid());
+        assert!(!BondingCurve { capacity: 0, ..curve() }.is_valid());
+        assert!(!BondingCurve { strike_exponent: 4, ..curve() }.is_valid());
+        assert!(!BondingCurve { base_probability_ppm: 600_000, ..curve() }.is_valid());
+        assert!(!BondingCurve { max_probability_ppm: 1_000_001, ..curve() }.is_valid());
+    }
+}
@@ -0,0 +1,176 @@
+//! # Group Quotes
+//!
+//! A group quote prices a bundle of locations and coverage windows in one
+//! market as a single V2 policy (`request_group_quote`). The policy triggers
+//! once, when any member's observation over its own window reaches the strike.
+//!
+//! Each member is priced like a single V2 quote. The probability that at least
+//! one member triggers is at most the sum of the member probabilities, and the
+//! more correlated the locations are, the further below it lies. The R model's
+//! group endpoint (see `pricing_params`) returns this as a correlation discount
+//! on the summed member probabilities:
+//!
+//! p = max(Σ pᵢ × (1 - discount), max pᵢ), capped at 100%
+//!
+//! The floor keeps a group at least as expensive as its riskiest member.
+
+use alloc::{format, string::String, vec::Vec};
+
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+
+/// One in parts per million
+const PPM: u128 = 1_000_000;
+
+/// Basis points in 100%
+const FULL_BP: u128 = 10_000;
+
+/// Fewest members in a group quote; a single location is an ordinary quote
+pub const MIN_GROUP_MEMBERS: u32 = 2;
+
+/// Most members in a group quote
+pub const MAX_GROUP_MEMBERS: u32 = 8;
+
+/// Highest correlation discount accepted from the R model (50%)
+pub const MAX_CORRELATION_DISCOUNT_BP: u16 = 5_000;
+
+/// One location and coverage window of a group quote
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    PartialEq,
+    Eq,
+    Debug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct GroupMember {
+    pub latitude: i32,      // scaled by 1e6
+    pub longitude: i32,     // scaled by 1e6
+    pub coverage_start: u64,
+    pub coverage_end: u64,
+}
+
+impl GroupMember {
+    /// Coverage duration in whole days, as V2 duration rules expect
+    pub fn duration_days(&self) -> u8 {
+        let days = self.coverage_end.saturating_sub(self.coverage_start) / 86_400;
+        days.min(u8::MAX as u64) as u8
+    }
+
+    /// Whether `timestamp` lies within the member's coverage window
+    pub fn covers(&self, timestamp: u64) -> bool {
+        timestamp >= self.coverage_start && timestamp <= self.coverage_end
+    }
+}
+
+/// Earliest start and latest end over all members; `None` without members
+pub fn coverage_bounds(members: &[GroupMember]) -> Option<(u64, u64)> {
+    let start = members.iter().map(|m| m.coverage_start).min()?;
+    let end = members.iter().map(|m| m.coverage_end).max()?;
+    Some((start, end))
+}
+
+/// Probability that at least one member triggers, in parts per million, from
+/// the member probabilities and the correlation discount (see module docs)
+pub fn group_probability(member_probabilities: &[u32], correlation_discount_bp: u16) -> u32 {
+    let clamped = member_probabilities.iter().map(|p| (*p as u128).min(PPM));
+    let highest = clamped.clone().max().unwrap_or(0);
+    let sum: u128 = clamped.sum();
+
+    let discount_bp = (correlation_discount_bp as u128).min(FULL_BP);
+    let discounted = sum * (FULL_BP - discount_bp) / FULL_BP;
+    discounted.max(highest).min(PPM) as u32
+}
+
+/// Correlation discount in basis points from the R model's fraction, capped at
+/// `MAX_CORRELATION_DISCOUNT_BP`. `None` for a negative or non-finite fraction.
+pub fn discount_bp_from_fraction(fraction: f64) -> Option<u16> {
+    if !fraction.is_finite() || fraction < 0.0 {
+        return None;
+    }
+    // Manual rounding: add 0.5 and truncate (f64::round not available in no_std)
+    let bp = (fraction.min(1.0) * FULL_BP as f64 + 0.5) as u32;
+    Some(bp.min(MAX_CORRELATION_DISCOUNT_BP as u32) as u16)
+}
+
+/// Member query parameter of the R model's group endpoint:
+/// `group=lat:lon:startdate:duration_in_hours,...` in member order
+pub fn pricing_params(members: &[GroupMember]) -> String {
+    let members: Vec<String> = members
+        .iter()
+        .map(|m| {
+            format!(
+                "{}:{}:{}:{}",
+                m.latitude as f64 / 1_000_000.0,
+                m.longitude as f64 / 1_000_000.0,
+                m.coverage_start,
+                m.coverage_end.saturating_sub(m.coverage_start) / 3600
+            )
+        })
+        .collect();
+    format!("group={}", members.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn member(latitude: i32, coverage_start: u64, days: u64) -> GroupMember {
+        GroupMember {
+            latitude,
+            longitude: 120_984_200,
+            coverage_start,
+            coverage_end: coverage_start + days * 86_400,
+        }
+    }
+
+    #[test]
+    fn test_group_probability_discounts_the_sum() {
+        // 30,000 summed, less 20%
+        assert_eq!(group_probability(&[10_000, 20_000], 2_000), 24_000);
+        assert_eq!(group_probability(&[10_000, 20_000], 0), 30_000);
+    }
+
+    #[test]
+    fn test_group_probability_is_floored_at_the_riskiest_member() {
+        assert_eq!(group_probability(&[10_000, 40_000], 5_000), 40_000);
+    }
+
+    #[test]
+    fn test_group_probability_is_capped_at_certainty() {
+        assert_eq!(group_probability(&[700_000, 800_000], 0), 1_000_000);
+        assert_eq!(group_probability(&[2_000_000, 0], 0), 1_000_000);
+        assert_eq!(group_probability(&[], 1_000), 0);
+    }
+
+    #[test]
+    fn test_discount_from_fraction() {
+        assert_eq!(discount_bp_from_fraction(0.18), Some(1_800));
+        assert_eq!(discount_bp_from_fraction(0.0), Some(0));
+        assert_eq!(discount_bp_from_fraction(0.9), Some(MAX_CORRELATION_DISCOUNT_BP));
+        assert_eq!(discount_bp_from_fraction(-0.1), None);
+        assert_eq!(discount_bp_from_fraction(f64::NAN), None);
+    }
+
+    #[test]
+    fn test_coverage_bounds_and_duration() {
+        let members = [member(14_599_500, 86_400, 2), member(14_650_000, 3 * 86_400, 3)];
+        assert_eq!(coverage_bounds(&members), Some((86_400, 6 * 86_400)));
+        assert_eq!(members[1].duration_days(), 3);
+        assert!(members[0].covers(2 * 86_400));
+        assert!(!members[0].covers(4 * 86_400));
+        assert_eq!(coverage_bounds(&[]), None);
+    }
+
+    #[test]
+    fn test_pricing_params_lists_members_in_order() {
+        let members = [member(14_599_500, 86_400, 2), member(14_650_000, 3 * 86_400, 1)];
+        assert_eq!(
+            pricing_params(&members),
+            "group=14.5995:120.9842:86400:48,14.65:120.9842:259200:24"
+        );
+    }
+}
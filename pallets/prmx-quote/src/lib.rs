@@ -8,14 +8,63 @@
 //!
 //! - Users call `request_policy_quote` with coverage details.
 //! - Offchain worker fetches probability from R model API.
-//! - `submit_quote` stores the calculated premium.
+//! - `submit_quote_from_ocw` stores the calculated premium. OCW results are unsigned
+//!   transactions whose payload is signed by a registered quote provider's `quot`
+//!   key; governance can set a result manually with `submit_quote`.
 //! - Users can then apply for coverage using the quote.
+//! - Requesters can `cancel_quote` a pending/ready quote or `refresh_quote` an
+//!   expired one, which re-enqueues the same parameters under a new quote ID.
+//! - Brokers can price a portfolio with `request_policy_quotes_batch`; the OCW prices
+//!   all members in one run and `QuoteBatchReady` fires once every member resolves.
+//! - `request_quote_ladder` requests V2 quotes for several strikes on the same
+//!   coverage as one batch; `QuoteLadderReady` lists each strike's premium.
+//! - `request_group_quote` prices a bundle of locations/windows in one market as a
+//!   single V2 policy; the OCW prices each member and applies the R model's
+//!   correlation discount to their sum (see `group`).
+//! - The OCW caches R model probabilities per location bucket and terms (see
+//!   `price_cache`), caps R API calls per block and backs off after failures.
+//! - Markets without an actuarial model are priced from
+//!   `MarketProbabilityOverrides` (set by governance), falling back to
+//!   `FIXED_PROBABILITY_PPM`.
+//! - V2 quotes can add wind/temperature perils to the rainfall strike with an
+//!   ANY/ALL rule (see `perils`); each peril is priced separately and the
+//!   per-peril probabilities are kept in `QuoteResult`.
+//! - OCW-priced quotes store hashes of the exact R API requests and raw responses
+//!   in `QuotePricingEvidence`, with an optional evidence URL; the raw payload
+//!   stays in the node's offchain storage for audits (see `pricing_evidence`).
+//! - Once governance registers the R pricing service's key
+//!   (`set_pricing_service_key`), R-priced OCW results must carry the service's
+//!   signed attestation of the cost behind them (see `attestation`).
+//! - Governance can switch a market to an on-chain bonding curve
+//!   (`set_market_bonding_curve`, see `bonding_curve`); quotes without
+//!   additional perils on such markets are priced instantly from the market's
+//!   utilization and strike distance, without the R API.
+//! - Every priced quote stores a hash of the terms it was priced for in
+//!   `QuoteParamsHashes`; the policy pallet recomputes it before creating a
+//!   policy from the quote (see `binding`).
+//! - Each requested quote reserves a refundable deposit from its requester, and
+//!   accounts may only request so many quotes per block window (see
+//!   `request_limits`).
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 extern crate alloc;
 
+pub mod attestation;
+pub mod binding;
+pub mod bonding_curve;
+pub mod group;
+pub mod perils;
+pub mod price_cache;
+pub mod pricing_evidence;
+pub mod request_limits;
+pub mod weights;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
 pub use pallet::*;
+pub use weights::WeightInfo;
 
 use alloc::vec::Vec;
 
@@ -86,7 +135,8 @@ pub const TEST_R_PRICING_API_URL: &[u8] = b"http://34.51.195.144:19090/pricing";
 /// Fixed probability for markets without actuarial model support.
 /// 1% probability = 10,000 ppm (parts per million).
 /// This is a temporary benchmark for markets like Amsterdam and Tokyo
-/// until proper actuarial models are developed.
+/// until proper actuarial models are developed. Governance can replace it
+/// per market via `set_market_probability_override`.
 pub const FIXED_PROBABILITY_PPM: u32 = 10_000;
 
 /// Quote ID type - re-exported from primitives
@@ -105,6 +155,24 @@ pub trait QuoteAccess<AccountId, Balance> {
     
     /// Check if a quote is valid and ready to use
     fn is_quote_ready(quote_id: QuoteId) -> bool;
+
+    /// Hash of the terms the quote was priced for (see `binding`)
+    fn quote_params_hash(quote_id: QuoteId) -> Option<[u8; 32]>;
+
+    /// Members of a group quote (see `group`); empty for single-location quotes
+    fn group_members(quote_id: QuoteId) -> Vec<group::GroupMember>;
+}
+
+/// Open notional (sum of max payouts of active policies) per market, the
+/// utilization input of bonding-curve pricing
+pub trait OpenNotional<Balance> {
+    fn open_notional(market_id: u64) -> Balance;
+}
+
+impl<Balance: Default> OpenNotional<Balance> for () {
+    fn open_notional(_market_id: u64) -> Balance {
+        Balance::default()
+    }
 }
 
 /// Quote request info (generic version for trait)
@@ -129,12 +197,18 @@ pub struct QuoteRequestInfo<AccountId> {
     pub duration_days: u8,
     /// Custom strike threshold in mm * 10 (V2 only, e.g., 500 = 50mm)
     pub strike_mm: Option<u32>,
+    /// Perils covered besides the rainfall strike (V2 multi-peril only)
+    pub perils: Vec<prmx_primitives::EventSpecV3>,
+    /// How the rainfall strike and `perils` combine
+    pub peril_aggregation: prmx_primitives::PerilAggregation,
 }
 
 /// Quote result info (generic version for trait)
 #[derive(codec::Encode, codec::Decode, Clone, PartialEq, Eq, Debug, scale_info::TypeInfo)]
 pub struct QuoteResultInfo<Balance> {
     pub probability_ppm: u32,
+    /// Rainfall strike probability first, then each additional peril in request order
+    pub peril_probabilities: Vec<u32>,
     pub premium_per_share: Balance,
     pub total_premium: Balance,
     pub calculated_at: u64,
@@ -144,17 +218,37 @@ pub struct QuoteResultInfo<Balance> {
 pub mod pallet {
     use super::*;
     use frame_support::pallet_prelude::*;
-    use frame_support::traits::Time;
+    use frame_support::traits::{Currency, ReservableCurrency};
+    use codec::DecodeWithMemTracking;
     use frame_system::pallet_prelude::*;
     use pallet_prmx_markets::{MarketId, MarketsAccess, PartsPerMillion};
+    use prmx_time::ChainTime;
+    use bonding_curve::BondingCurve;
+    use pricing_evidence::PricingCall;
     use sp_runtime::offchain::{http, Duration};
+    use sp_runtime::traits::{Saturating, Zero};
 
     // =========================================================================
     //                                  Types
     // =========================================================================
 
     pub use prmx_primitives::QuoteId;
-    use prmx_primitives::generate_unique_id;
+    use prmx_primitives::{
+        generate_unique_id, EventSpecV3, PausableOperation, PauseApi, PerilAggregation,
+    };
+    use group::{GroupMember, MAX_CORRELATION_DISCOUNT_BP};
+    use request_limits::RequestWindow;
+
+    /// Balance of the currency quote deposits are reserved in
+    pub type DepositBalanceOf<T> = <<T as Config>::DepositCurrency as Currency<
+        <T as frame_system::Config>::AccountId,
+    >>::Balance;
+
+    /// Maximum additional perils on a quote
+    pub type MaxQuotePerils = ConstU32<{ prmx_primitives::MAX_ADDITIONAL_PERILS }>;
+
+    /// Maximum per-peril probabilities on a quote result (rainfall strike + perils)
+    pub type MaxPerilProbabilities = ConstU32<{ prmx_primitives::MAX_ADDITIONAL_PERILS + 1 }>;
 
     /// Quote request from a user
     #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
@@ -180,17 +274,257 @@ pub mod pallet {
         /// Custom strike threshold in mm * 10 (V2 only, e.g., 500 = 50mm)
         /// If None, uses market's default strike value
         pub strike_mm: Option<u32>,
+        /// Perils priced alongside the rainfall strike (V2 multi-peril only)
+        pub perils: BoundedVec<EventSpecV3, MaxQuotePerils>,
+        /// How the rainfall strike and `perils` combine into a trigger
+        pub peril_aggregation: PerilAggregation,
     }
 
     /// Quote result from the offchain worker
     #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
     pub struct QuoteResult<Balance> {
+        /// Combined probability of the quote's trigger
         pub probability_ppm: PartsPerMillion,
+        /// Rainfall strike probability first, then each additional peril in request order
+        pub peril_probabilities: BoundedVec<PartsPerMillion, MaxPerilProbabilities>,
         pub premium_per_share: Balance,
         pub total_premium: Balance,
         pub calculated_at: u64,
     }
 
+    /// Maximum length of a pricing evidence URL
+    pub type MaxEvidenceUrlLength = ConstU32<{ pricing_evidence::MAX_EVIDENCE_URL_LEN }>;
+
+    /// Audit trail of the R model calls that priced a quote
+    #[derive(
+        Encode,
+        Decode,
+        DecodeWithMemTracking,
+        Clone,
+        PartialEq,
+        Eq,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+    )]
+    pub struct PricingEvidence {
+        /// blake2_256 of the SCALE-encoded request URLs, in pricing order
+        pub inputs_hash: [u8; 32],
+        /// blake2_256 of the SCALE-encoded raw response bodies, in pricing order
+        pub response_hash: [u8; 32],
+        /// Where the raw payload is published, if the node operator does so
+        pub evidence_url: Option<BoundedVec<u8, MaxEvidenceUrlLength>>,
+    }
+
+    /// R pricing service signature over a request and its cost (see `attestation`)
+    #[derive(
+        Encode,
+        Decode,
+        DecodeWithMemTracking,
+        Clone,
+        PartialEq,
+        Eq,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+    )]
+    pub struct PricingAttestation {
+        /// Coverage the request was priced for, in whole units
+        pub coverage: u64,
+        /// Attested `avg_cost` in millionths of a unit
+        pub avg_cost_micros: u64,
+        /// ed25519 signature by the registered pricing service key
+        pub signature: [u8; 64],
+    }
+
+    /// Quote result signed by a quote authority key and submitted unsigned by the OCW
+    #[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct QuoteResultPayload<Public, BlockNumber> {
+        /// Block at which the OCW priced the quote
+        pub block_number: BlockNumber,
+        /// Quote authority key that signed the result
+        pub public: Public,
+        pub quote_id: QuoteId,
+        pub probability_ppm: PartsPerMillion,
+        /// Hashes of the R API calls behind `probability_ppm`, if any
+        pub evidence: Option<PricingEvidence>,
+        /// Pricing service attestation of the R model cost, if the service signed it
+        pub attestation: Option<PricingAttestation>,
+    }
+
+    impl<T: frame_system::offchain::SigningTypes> frame_system::offchain::SignedPayload<T>
+        for QuoteResultPayload<T::Public, BlockNumberFor<T>>
+    {
+        fn public(&self) -> T::Public {
+            self.public.clone()
+        }
+    }
+
+    /// Per-peril quote result signed by a quote authority key and submitted unsigned
+    /// by the OCW
+    #[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct PerilQuoteResultPayload<Public, BlockNumber> {
+        /// Block at which the OCW priced the quote
+        pub block_number: BlockNumber,
+        /// Quote authority key that signed the result
+        pub public: Public,
+        pub quote_id: QuoteId,
+        /// Rainfall strike probability first, then each additional peril in request order
+        pub peril_probabilities: BoundedVec<PartsPerMillion, MaxPerilProbabilities>,
+        /// Hashes of the R API calls behind the probabilities, if any
+        pub evidence: Option<PricingEvidence>,
+    }
+
+    impl<T: frame_system::offchain::SigningTypes> frame_system::offchain::SignedPayload<T>
+        for PerilQuoteResultPayload<T::Public, BlockNumberFor<T>>
+    {
+        fn public(&self) -> T::Public {
+            self.public.clone()
+        }
+    }
+
+    /// Maximum members in a group quote
+    pub type MaxGroupMembers = ConstU32<{ group::MAX_GROUP_MEMBERS }>;
+
+    /// How the offchain worker priced a group quote
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct GroupPricing {
+        /// Probability of each member, in member order
+        pub member_probabilities: BoundedVec<PartsPerMillion, MaxGroupMembers>,
+        /// Discount on the summed member probabilities for correlation between members
+        pub correlation_discount_bp: u16,
+    }
+
+    /// Group quote result signed by a quote authority key and submitted unsigned by
+    /// the OCW
+    #[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct GroupQuoteResultPayload<Public, BlockNumber> {
+        /// Block at which the OCW priced the quote
+        pub block_number: BlockNumber,
+        /// Quote authority key that signed the result
+        pub public: Public,
+        pub quote_id: QuoteId,
+        /// Probability of each member, in member order
+        pub member_probabilities: BoundedVec<PartsPerMillion, MaxGroupMembers>,
+        /// Correlation discount from the R model's group endpoint
+        pub correlation_discount_bp: u16,
+        /// Hashes of the R API calls behind the result, if any
+        pub evidence: Option<PricingEvidence>,
+    }
+
+    impl<T: frame_system::offchain::SigningTypes> frame_system::offchain::SignedPayload<T>
+        for GroupQuoteResultPayload<T::Public, BlockNumberFor<T>>
+    {
+        fn public(&self) -> T::Public {
+            self.public.clone()
+        }
+    }
+
+    /// Probability, pricing evidence and attestation of each priced member of a
+    /// quote batch
+    pub type QuoteBatchResults<T> = BoundedVec<
+        (QuoteId, PartsPerMillion, Option<PricingEvidence>, Option<PricingAttestation>),
+        <T as Config>::MaxQuotesPerBatch,
+    >;
+
+    /// Quote batch results signed by a quote authority key and submitted unsigned by
+    /// the OCW; `Results` is `QuoteBatchResults<T>`
+    #[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct QuoteBatchResultPayload<Public, BlockNumber, Results> {
+        /// Block at which the OCW priced the batch
+        pub block_number: BlockNumber,
+        /// Quote authority key that signed the results
+        pub public: Public,
+        pub batch_id: QuoteBatchId,
+        pub results: Results,
+    }
+
+    impl<T: Config> frame_system::offchain::SignedPayload<T>
+        for QuoteBatchResultPayload<T::Public, BlockNumberFor<T>, QuoteBatchResults<T>>
+    {
+        fn public(&self) -> T::Public {
+            self.public.clone()
+        }
+    }
+
+    /// Quote batch ID (auto-increment)
+    pub type QuoteBatchId = u64;
+
+    /// Parameters of one V1 quote in a batch request
+    #[derive(
+        Encode,
+        Decode,
+        DecodeWithMemTracking,
+        Clone,
+        PartialEq,
+        Eq,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+    )]
+    pub struct QuoteParams {
+        pub market_id: MarketId,
+        pub coverage_start: u64,
+        pub coverage_end: u64,
+        pub latitude: i32,      // scaled by 1e6
+        pub longitude: i32,     // scaled by 1e6
+        pub shares: u128,
+    }
+
+    /// Quotes requested together via `request_policy_quotes_batch`
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct QuoteBatch<T: Config> {
+        pub requester: T::AccountId,
+        pub quote_ids: BoundedVec<QuoteId, T::MaxQuotesPerBatch>,
+        pub requested_at: u64,
+        /// Set once every member has left the Pending state
+        pub resolved: bool,
+    }
+
+    /// Maximum tiers in a market probability override
+    pub type MaxProbabilityTiers = ConstU32<16>;
+
+    /// Fallback probability for quotes up to a duration and from a strike upwards
+    #[derive(
+        Encode,
+        Decode,
+        DecodeWithMemTracking,
+        Clone,
+        PartialEq,
+        Eq,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+    )]
+    pub struct ProbabilityTier {
+        /// Matches quotes with coverage duration <= this many hours
+        pub max_duration_hours: u64,
+        /// Matches quotes with strike >= this value (mm * 10)
+        pub min_strike_mm: u32,
+        pub probability_ppm: PartsPerMillion,
+    }
+
+    /// Governance-set fallback probabilities for a market without an actuarial model.
+    /// The first matching tier wins; quotes matching no tier use `default_ppm`.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct ProbabilityOverride {
+        pub default_ppm: PartsPerMillion,
+        pub tiers: BoundedVec<ProbabilityTier, MaxProbabilityTiers>,
+    }
+
+    impl ProbabilityOverride {
+        pub fn probability_for(&self, duration_hours: u64, strike_mm: u32) -> PartsPerMillion {
+            self.tiers
+                .iter()
+                .find(|tier| {
+                    duration_hours <= tier.max_duration_hours && strike_mm >= tier.min_strike_mm
+                })
+                .map(|tier| tier.probability_ppm)
+                .unwrap_or(self.default_ppm)
+        }
+    }
+
     /// Quote status
     #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
     pub enum QuoteStatus {
@@ -199,6 +533,8 @@ pub mod pallet {
         Ready,
         Consumed,
         Expired,
+        /// Withdrawn by the requester before use
+        Cancelled,
     }
 
     // =========================================================================
@@ -208,8 +544,8 @@ pub mod pallet {
     #[pallet::config]
     pub trait Config: 
         frame_system::Config 
-        + pallet_timestamp::Config 
         + frame_system::offchain::CreateSignedTransaction<Call<Self>>
+        + frame_system::offchain::CreateBare<Call<Self>>
     {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
@@ -219,6 +555,9 @@ pub mod pallet {
         /// Access to markets pallet
         type MarketsApi: MarketsAccess<Balance = Self::Balance>;
 
+        /// Source of chain time for quote IDs, coverage windows and quote validity
+        type TimeProvider: ChainTime;
+
         /// Quote validity duration in seconds (how long a quote is valid after calculation)
         #[pallet::constant]
         type QuoteValiditySeconds: Get<u64>;
@@ -231,8 +570,53 @@ pub mod pallet {
         #[pallet::constant]
         type MaxPendingQuotes: Get<u32>;
 
+        /// Maximum quotes in a single batch request
+        #[pallet::constant]
+        type MaxQuotesPerBatch: Get<u32>;
+
+        /// Who can set per-market fallback probabilities and bonding curves
+        /// (typically DAO/Root)
+        type GovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Open notional per market, for bonding-curve utilization
+        type OpenNotional: OpenNotional<Self::Balance>;
+
         /// Quote authority ID for signing offchain worker transactions
         type AuthorityId: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>;
+
+        /// Emergency pause flags; new quotes and quote results are rejected while
+        /// `PausableOperation::Quotes` is paused
+        type Pause: PauseApi;
+
+        /// Currency quote deposits are reserved in
+        type DepositCurrency: ReservableCurrency<Self::AccountId>;
+
+        /// Deposit reserved from the requester of each quote (see `request_limits`)
+        #[pallet::constant]
+        type QuoteDeposit: Get<DepositBalanceOf<Self>>;
+
+        /// Most quotes an account may request per `QuoteRateLimitWindow`
+        #[pallet::constant]
+        type MaxQuoteRequestsPerWindow: Get<u32>;
+
+        /// Length in blocks of the quote request rate limit window
+        #[pallet::constant]
+        type QuoteRateLimitWindow: Get<BlockNumberFor<Self>>;
+
+        /// Weight info for extrinsics
+        type WeightInfo: WeightInfo;
+
+        /// Sets up markets for benchmarks
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper;
+    }
+
+    /// Benchmark setup that needs other pallets
+    #[cfg(feature = "runtime-benchmarks")]
+    pub trait BenchmarkHelper {
+        /// Ensure a V2-enabled open market with default window rules exists and
+        /// return its id
+        fn open_market() -> MarketId;
     }
 
     // =========================================================================
@@ -269,6 +653,28 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// Hash of the terms each quote result was priced for (see `binding`)
+    #[pallet::storage]
+    #[pallet::getter(fn params_hash)]
+    pub type QuoteParamsHashes<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        QuoteId,
+        [u8; 32],
+        OptionQuery,
+    >;
+
+    /// Pricing evidence of quote results calculated from the R model
+    #[pallet::storage]
+    #[pallet::getter(fn quote_pricing_evidence)]
+    pub type QuotePricingEvidence<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        QuoteId,
+        PricingEvidence,
+        OptionQuery,
+    >;
+
     /// Quote status by ID
     #[pallet::storage]
     #[pallet::getter(fn quote_status)]
@@ -285,6 +691,101 @@ pub mod pallet {
     #[pallet::getter(fn pending_quotes)]
     pub type PendingQuotes<T: Config> = StorageValue<_, BoundedVec<QuoteId, T::MaxPendingQuotes>, ValueQuery>;
 
+    /// Link from an expired quote to the quote that re-priced it via `refresh_quote`
+    #[pallet::storage]
+    #[pallet::getter(fn refreshed_quote)]
+    pub type RefreshedQuotes<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        QuoteId,
+        QuoteId,
+        OptionQuery,
+    >;
+
+    /// Next quote batch ID
+    #[pallet::storage]
+    #[pallet::getter(fn next_quote_batch_id)]
+    pub type NextQuoteBatchId<T: Config> = StorageValue<_, QuoteBatchId, ValueQuery>;
+
+    /// Quote batches by ID
+    #[pallet::storage]
+    #[pallet::getter(fn quote_batches)]
+    pub type QuoteBatches<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        QuoteBatchId,
+        QuoteBatch<T>,
+        OptionQuery,
+    >;
+
+    /// Batch membership of quotes requested via `request_policy_quotes_batch`
+    #[pallet::storage]
+    #[pallet::getter(fn quote_batch_of)]
+    pub type QuoteBatchOf<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        QuoteId,
+        QuoteBatchId,
+        OptionQuery,
+    >;
+
+    /// Strikes of quote batches requested via `request_quote_ladder`, in the
+    /// order of the batch's `quote_ids`
+    #[pallet::storage]
+    #[pallet::getter(fn quote_ladder)]
+    pub type QuoteLadders<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        QuoteBatchId,
+        BoundedVec<u32, T::MaxQuotesPerBatch>,
+        OptionQuery,
+    >;
+
+    /// Members of quotes requested via `request_group_quote`
+    #[pallet::storage]
+    #[pallet::getter(fn group_quote_members)]
+    pub type GroupQuoteMembers<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        QuoteId,
+        BoundedVec<GroupMember, MaxGroupMembers>,
+        OptionQuery,
+    >;
+
+    /// Member probabilities and correlation discount of OCW-priced group quotes
+    #[pallet::storage]
+    #[pallet::getter(fn group_quote_pricing)]
+    pub type GroupQuotePricing<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        QuoteId,
+        GroupPricing,
+        OptionQuery,
+    >;
+
+    /// Fallback probabilities for markets without an actuarial model.
+    /// Markets without an entry use `FIXED_PROBABILITY_PPM`.
+    #[pallet::storage]
+    #[pallet::getter(fn market_probability_override)]
+    pub type MarketProbabilityOverrides<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        MarketId,
+        ProbabilityOverride,
+        OptionQuery,
+    >;
+
+    /// Markets priced on-chain from a bonding curve instead of the offchain worker
+    #[pallet::storage]
+    #[pallet::getter(fn market_bonding_curve)]
+    pub type MarketBondingCurves<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        MarketId,
+        BondingCurve<T::Balance>,
+        OptionQuery,
+    >;
+
     /// Quote providers (accounts authorized to submit quote results)
     #[pallet::storage]
     #[pallet::getter(fn quote_providers)]
@@ -296,6 +797,34 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// ed25519 public key of the R pricing service. While set, OCW results for
+    /// R-priced quotes must carry an attestation signed by it (see `attestation`).
+    #[pallet::storage]
+    #[pallet::getter(fn pricing_service_key)]
+    pub type PricingServiceKey<T: Config> = StorageValue<_, [u8; 32], OptionQuery>;
+
+    /// Deposits reserved from the requesters of unreleased quotes
+    #[pallet::storage]
+    #[pallet::getter(fn quote_deposit)]
+    pub type QuoteDeposits<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        QuoteId,
+        DepositBalanceOf<T>,
+        OptionQuery,
+    >;
+
+    /// Quote requests of each account in its current rate limit window
+    #[pallet::storage]
+    #[pallet::getter(fn quote_request_window)]
+    pub type QuoteRequestWindows<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        RequestWindow<BlockNumberFor<T>>,
+        ValueQuery,
+    >;
+
     // =========================================================================
     //                           Genesis Configuration
     // =========================================================================
@@ -378,6 +907,83 @@ pub mod pallet {
         QuoteProviderRemoved {
             account: T::AccountId,
         },
+        /// Quote cancelled by its requester. [quote_id]
+        QuoteCancelled {
+            quote_id: QuoteId,
+        },
+        /// Expired quote re-enqueued under a new ID. [old_quote_id, new_quote_id]
+        QuoteRefreshed {
+            old_quote_id: QuoteId,
+            new_quote_id: QuoteId,
+        },
+        /// Batch of quotes requested. [batch_id, requester, quote_ids]
+        QuoteBatchRequested {
+            batch_id: QuoteBatchId,
+            requester: T::AccountId,
+            quote_ids: Vec<QuoteId>,
+        },
+        /// Every quote in a batch has resolved. [batch_id, ready, total_premium]
+        /// `total_premium` sums the members that became Ready.
+        QuoteBatchReady {
+            batch_id: QuoteBatchId,
+            ready: u32,
+            total_premium: T::Balance,
+        },
+        /// Every quote of a strike ladder has resolved. [batch_id, market_id, premiums]
+        /// `premiums` lists `(quote_id, strike_mm, total_premium)` for the members that
+        /// became Ready, in strike order of the request.
+        QuoteLadderReady {
+            batch_id: QuoteBatchId,
+            market_id: MarketId,
+            premiums: Vec<(QuoteId, u32, T::Balance)>,
+        },
+        /// Fallback probability override set for a market. [market_id, default_ppm, tiers]
+        MarketProbabilityOverrideSet {
+            market_id: MarketId,
+            default_ppm: PartsPerMillion,
+            tiers: u32,
+        },
+        /// Fallback probability override removed; the market uses the fixed default again.
+        MarketProbabilityOverrideCleared {
+            market_id: MarketId,
+        },
+        /// Market switched to (or updated) bonding-curve pricing. [market_id]
+        MarketBondingCurveSet {
+            market_id: MarketId,
+        },
+        /// Bonding curve removed; the market is priced by the offchain worker again.
+        MarketBondingCurveCleared {
+            market_id: MarketId,
+        },
+        /// Group quote requested. [quote_id, market_id, requester, members]
+        GroupQuoteRequested {
+            quote_id: QuoteId,
+            market_id: MarketId,
+            requester: T::AccountId,
+            members: u32,
+        },
+        /// Group quote priced from its members. [quote_id, probability_ppm, discount]
+        GroupQuotePriced {
+            quote_id: QuoteId,
+            probability_ppm: PartsPerMillion,
+            correlation_discount_bp: u16,
+        },
+        /// R pricing service key registered, or removed when `None`. [key]
+        PricingServiceKeySet {
+            key: Option<[u8; 32]>,
+        },
+        /// Quote deposit returned to the requester. [quote_id, requester, amount]
+        QuoteDepositReleased {
+            quote_id: QuoteId,
+            requester: T::AccountId,
+            amount: DepositBalanceOf<T>,
+        },
+        /// Quote deposit slashed by governance. [quote_id, requester, amount]
+        QuoteDepositSlashed {
+            quote_id: QuoteId,
+            requester: T::AccountId,
+            amount: DepositBalanceOf<T>,
+        },
     }
 
     // =========================================================================
@@ -418,37 +1024,127 @@ pub mod pallet {
         V2NotAllowed,
         /// Invalid strike threshold (must be 10-3000, i.e., 1mm-300mm).
         InvalidStrike,
+        /// Caller is not the account that requested the quote.
+        NotQuoteRequester,
+        /// Only pending or ready quotes can be cancelled.
+        QuoteNotCancellable,
+        /// Only expired quotes can be refreshed.
+        QuoteNotExpired,
+        /// Quote batch must contain at least one quote.
+        EmptyQuoteBatch,
+        /// Quote batch not found.
+        QuoteBatchNotFound,
+        /// Not enough room in the pending quote queue for the whole batch.
+        TooManyPendingQuotes,
+        /// Submitted results do not match the batch's pending quotes.
+        QuoteBatchMismatch,
+        /// Probability must not exceed 1,000,000 ppm (100%).
+        InvalidProbability,
+        /// Market has no probability override.
+        NoProbabilityOverride,
+        /// Peril must be a supported event type with a matching, valid threshold.
+        InvalidPeril,
+        /// Submitted probabilities must cover the rainfall strike and every peril.
+        PerilCountMismatch,
+        /// Quoting is paused by governance.
+        OperationPaused,
+        /// Event type is not supported for V2 quotes.
+        InvalidEventType,
+        /// Bonding curve parameters are out of range.
+        InvalidBondingCurve,
+        /// Market has no bonding curve.
+        NoBondingCurve,
+        /// The quote would take the market's bonding curve to full capacity.
+        BondingCurveCapacityExceeded,
+        /// A strike ladder lists the same strike more than once.
+        DuplicateLadderStrike,
+        /// A group quote needs at least `group::MIN_GROUP_MEMBERS` members.
+        InvalidGroupSize,
+        /// Quote is not a group quote.
+        NotGroupQuote,
+        /// Submitted probabilities must cover every member of the group quote.
+        GroupMemberCountMismatch,
+        /// Correlation discount exceeds `group::MAX_CORRELATION_DISCOUNT_BP`.
+        InvalidCorrelationDiscount,
+        /// R-priced quote result without a pricing service attestation.
+        PricingAttestationMissing,
+        /// Attestation is not signed by the pricing service key for this quote's request.
+        InvalidPricingAttestation,
+        /// Submitted probability differs from the one implied by the attested cost.
+        AttestedProbabilityMismatch,
+        /// Account has requested `MaxQuoteRequestsPerWindow` quotes in the current window.
+        QuoteRequestRateLimited,
+        /// Quote holds no deposit, e.g. it was already released or slashed.
+        NoQuoteDeposit,
     }
 
     // =========================================================================
     //                              Validate Unsigned
     // =========================================================================
 
+    /// OCW quote results are unsigned transactions carrying a payload signed by a
+    /// quote authority key, so only registered quote providers can price quotes and
+    /// they pay no fees to do so
+    ///
+    /// Custom error codes: 1 = quote or batch not found, 2 = quote not pending,
+    /// 3 = probability above 100%, 4 = correlation discount above the cap
     #[pallet::validate_unsigned]
     impl<T: Config> ValidateUnsigned for Pallet<T> {
         type Call = Call<T>;
 
         fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
             match call {
-                Call::submit_quote { quote_id, probability_ppm } => {
-                    // Validate that the quote exists and is pending
-                    if !QuoteRequests::<T>::contains_key(quote_id) {
-                        return InvalidTransaction::Custom(1).into();
+                Call::submit_quote_from_ocw { payload, signature } => {
+                    Self::validate_ocw_payload(payload, signature, payload.block_number)?;
+                    Self::validate_pending_quote(payload.quote_id)?;
+
+                    // Validate probability is reasonable (0-100%)
+                    if payload.probability_ppm > 1_000_000 {
+                        return InvalidTransaction::Custom(3).into();
                     }
 
-                    if QuoteStatuses::<T>::get(quote_id) != QuoteStatus::Pending {
-                        return InvalidTransaction::Custom(2).into();
+                    Self::quote_result_validity(payload.quote_id)
+                }
+                Call::submit_peril_quote_from_ocw { payload, signature } => {
+                    Self::validate_ocw_payload(payload, signature, payload.block_number)?;
+                    Self::validate_pending_quote(payload.quote_id)?;
+
+                    if payload.peril_probabilities.iter().any(|ppm| *ppm > 1_000_000) {
+                        return InvalidTransaction::Custom(3).into();
                     }
 
-                    // Validate probability is reasonable (0-100%)
-                    if *probability_ppm > 1_000_000 {
+                    Self::quote_result_validity(payload.quote_id)
+                }
+                Call::submit_group_quote_from_ocw { payload, signature } => {
+                    Self::validate_ocw_payload(payload, signature, payload.block_number)?;
+                    Self::validate_pending_quote(payload.quote_id)?;
+
+                    if payload.member_probabilities.iter().any(|ppm| *ppm > 1_000_000) {
+                        return InvalidTransaction::Custom(3).into();
+                    }
+                    if payload.correlation_discount_bp > MAX_CORRELATION_DISCOUNT_BP {
+                        return InvalidTransaction::Custom(4).into();
+                    }
+
+                    Self::quote_result_validity(payload.quote_id)
+                }
+                Call::submit_quote_batch_from_ocw { payload, signature } => {
+                    Self::validate_ocw_payload(payload, signature, payload.block_number)?;
+
+                    let Some(batch) = QuoteBatches::<T>::get(payload.batch_id) else {
+                        return InvalidTransaction::Custom(1).into();
+                    };
+                    if batch.resolved {
+                        return InvalidTransaction::Stale.into();
+                    }
+                    if payload.results.iter().any(|(_, ppm, _, _)| *ppm > 1_000_000) {
                         return InvalidTransaction::Custom(3).into();
                     }
 
-                    ValidTransaction::with_tag_prefix("prmx-quote")
-                        .priority(100)
+                    ValidTransaction::with_tag_prefix("PrmxQuoteBatchResult")
+                        .priority(TransactionPriority::MAX / 2)
+                        .and_provides(payload.batch_id)
                         .longevity(5)
-                        .and_provides([&(quote_id, probability_ppm)])
                         .propagate(true)
                         .build()
                 }
@@ -472,7 +1168,7 @@ pub mod pallet {
         /// - `longitude`: Longitude scaled by 1e6.
         /// - `shares`: Number of shares (1 share = 100 USDT coverage).
         #[pallet::call_index(0)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::request_policy_quote())]
         pub fn request_policy_quote(
             origin: OriginFor<T>,
             market_id: MarketId,
@@ -483,6 +1179,7 @@ pub mod pallet {
             shares: u128,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
+            Self::ensure_quotes_not_paused()?;
 
             // Validate shares
             ensure!(shares > 0, Error::<T>::InvalidShares);
@@ -494,7 +1191,7 @@ pub mod pallet {
             );
 
             // Get current timestamp
-            let now = Self::current_timestamp();
+            let now = T::TimeProvider::now_secs();
 
             // Validate coverage window
             T::MarketsApi::validate_coverage_window(
@@ -505,9 +1202,7 @@ pub mod pallet {
             ).map_err(|_| Error::<T>::InvalidCoverageWindow)?;
 
             // Generate unique quote ID
-            let nonce = AccountNonce::<T>::get(&who);
-            let quote_id = generate_unique_id(b"QUOTE", &who, now, nonce);
-            AccountNonce::<T>::insert(&who, nonce + 1);
+            let quote_id = Self::next_quote_id(&who, now);
             
             // Create quote request (V1 defaults)
             let quote_request = QuoteRequest::<T> {
@@ -526,16 +1221,12 @@ pub mod pallet {
                 early_trigger: false,
                 duration_days: 0, // Not used for V1
                 strike_mm: None,  // V1 uses market's default strike
+                perils: BoundedVec::new(),
+                peril_aggregation: PerilAggregation::Any,
             };
 
-            // Store quote request
-            QuoteRequests::<T>::insert(quote_id, quote_request);
-            QuoteStatuses::<T>::insert(quote_id, QuoteStatus::Pending);
-
-            // Add to pending quotes for offchain worker
-            PendingQuotes::<T>::mutate(|pending| {
-                let _ = pending.try_push(quote_id);
-            });
+            // Store quote request and queue it for the offchain worker
+            Self::enqueue_quote(quote_request)?;
 
             Self::deposit_event(Event::QuoteRequested {
                 quote_id,
@@ -543,57 +1234,70 @@ pub mod pallet {
                 requester: who,
             });
 
-            Ok(())
+            Self::price_from_bonding_curve(quote_id)
         }
 
-        /// Submit a quote result (called by offchain worker or authorized provider).
-        /// 
+        /// Manually set the result of a pending single-peril quote, e.g. while the
+        /// R model API is unavailable. Only callable by GovernanceOrigin; quote
+        /// providers submit results through the offchain worker.
+        ///
         /// - `quote_id`: The quote ID.
         /// - `probability_ppm`: Probability in parts per million (e.g., 5% = 50,000 ppm).
         #[pallet::call_index(1)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::submit_quote())]
         pub fn submit_quote(
             origin: OriginFor<T>,
             quote_id: QuoteId,
             probability_ppm: PartsPerMillion,
         ) -> DispatchResult {
-            // Allow manual submission for testing (simulate offchain worker)
-            let _ = ensure_signed(origin)?;
+            T::GovernanceOrigin::ensure_origin(origin)?;
 
-            Self::do_submit_quote(quote_id, probability_ppm)
+            Self::do_submit_quote(quote_id, probability_ppm, None)
         }
 
-        /// Submit a quote result from offchain worker (signed transaction).
-        /// Only authorized quote providers can call this.
+        /// Submit a quote result from the offchain worker. Submitted unsigned; the
+        /// payload must be signed by the quote authority key of a quote provider,
+        /// which is checked in `validate_unsigned`. While a pricing service key is
+        /// registered, R-priced results must carry a valid attestation.
         #[pallet::call_index(2)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::submit_quote_from_ocw())]
         pub fn submit_quote_from_ocw(
             origin: OriginFor<T>,
-            quote_id: QuoteId,
-            probability_ppm: PartsPerMillion,
+            payload: QuoteResultPayload<T::Public, BlockNumberFor<T>>,
+            _signature: T::Signature,
         ) -> DispatchResult {
-            let who = ensure_signed(origin)?;
+            use sp_runtime::traits::IdentifyAccount;
 
-            // Verify signer is an authorized quote provider
+            ensure_none(origin)?;
+
+            let QuoteResultPayload {
+                public,
+                quote_id,
+                probability_ppm,
+                evidence,
+                attestation,
+                ..
+            } = payload;
             ensure!(
-                QuoteProviders::<T>::get(&who),
+                QuoteProviders::<T>::get(&public.into_account()),
                 Error::<T>::NotQuoteProvider
             );
+            Self::ensure_attested(quote_id, probability_ppm, attestation.as_ref())?;
 
             log::info!(
                 target: "prmx-quote",
-                "🤖 OCW signed tx: submitting quote {} with probability {} ppm",
+                "🤖 OCW unsigned tx: submitting quote {} with probability {} ppm",
                 quote_id,
                 probability_ppm
             );
 
-            Self::do_submit_quote(quote_id, probability_ppm)
+            Self::do_submit_quote(quote_id, probability_ppm, evidence)
         }
 
         /// Store R pricing API key in offchain storage.
         /// Only callable by Root/Sudo.
         #[pallet::call_index(3)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::set_pricing_api_key())]
         pub fn set_pricing_api_key(
             origin: OriginFor<T>,
             api_key: Vec<u8>,
@@ -614,7 +1318,7 @@ pub mod pallet {
         /// Store R pricing API URL in offchain storage.
         /// Only callable by Root/Sudo.
         #[pallet::call_index(4)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::set_pricing_api_url())]
         pub fn set_pricing_api_url(
             origin: OriginFor<T>,
             api_url: Vec<u8>,
@@ -634,7 +1338,7 @@ pub mod pallet {
         /// Add a quote provider account.
         /// Only callable by Root/Sudo.
         #[pallet::call_index(5)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::add_quote_provider())]
         pub fn add_quote_provider(
             origin: OriginFor<T>,
             account: T::AccountId,
@@ -651,7 +1355,7 @@ pub mod pallet {
         /// Remove a quote provider account.
         /// Only callable by Root/Sudo.
         #[pallet::call_index(6)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::remove_quote_provider())]
         pub fn remove_quote_provider(
             origin: OriginFor<T>,
             account: T::AccountId,
@@ -667,7 +1371,8 @@ pub mod pallet {
 
         /// Request a V2 quote for policy coverage.
         /// 
-        /// V2 policies use cumulative rainfall over the coverage window with early trigger.
+        /// V2 policies use cumulative rainfall (or the highest 24-hour snowfall) over the
+        /// coverage window with early trigger.
         /// Currently only Manila market is supported with 2-7 day durations.
         ///
         /// - `market_id`: The market (must be Manila for V2).
@@ -677,9 +1382,15 @@ pub mod pallet {
         /// - `longitude`: Longitude scaled by 1e6.
         /// - `shares`: Number of shares (1 share = 100 USDT coverage).
         /// - `duration_days`: Coverage duration in days (2-7 for V2).
+        /// - `event_type`: `CumulativeRainfallWindow`, `Snowfall24h` or `FloodComposite`.
         /// - `strike_mm`: Custom strike threshold in mm * 10 (e.g., 500 = 50mm). Range: 10-3000 (1mm-300mm).
+        ///   For `Snowfall24h` this is the snowfall depth over any 24 hours of coverage;
+        ///   for `FloodComposite` the 24h rainfall while the river gauge is at flood stage.
+        /// - `perils`: Additional perils (e.g. wind gust, max temperature) covered with the
+        ///   strike. Empty for a single-peril quote.
+        /// - `peril_aggregation`: Whether any (`Any`) or every (`All`) peril must trigger.
         #[pallet::call_index(7)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::request_policy_quote_v2(perils.len() as u32))]
         pub fn request_policy_quote_v2(
             origin: OriginFor<T>,
             market_id: MarketId,
@@ -689,16 +1400,37 @@ pub mod pallet {
             longitude: i32,
             shares: u128,
             duration_days: u8,
+            event_type: prmx_primitives::EventType,
             strike_mm: u32,
+            perils: BoundedVec<EventSpecV3, MaxQuotePerils>,
+            peril_aggregation: PerilAggregation,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
+            Self::ensure_quotes_not_paused()?;
 
             // Validate shares
             ensure!(shares > 0, Error::<T>::InvalidShares);
 
+            // V2 measures cumulative rainfall, 24-hour snowfall or rainfall at river
+            // flood stage; the rolling rainfall sum is V1 only
+            ensure!(
+                matches!(
+                    event_type,
+                    prmx_primitives::EventType::CumulativeRainfallWindow
+                        | prmx_primitives::EventType::Snowfall24h
+                        | prmx_primitives::EventType::FloodComposite
+                ),
+                Error::<T>::InvalidEventType
+            );
+
             // Validate strike range: 10-3000 (1mm-300mm when scaled by 10)
             ensure!(strike_mm >= 10 && strike_mm <= 3000, Error::<T>::InvalidStrike);
 
+            ensure!(
+                perils.iter().all(perils::is_valid_additional_peril),
+                Error::<T>::InvalidPeril
+            );
+
             // Check market is open
             ensure!(
                 T::MarketsApi::is_market_open(market_id),
@@ -710,7 +1442,7 @@ pub mod pallet {
                 .map_err(|_| Error::<T>::V2NotAllowed)?;
 
             // Get current timestamp
-            let now = Self::current_timestamp();
+            let now = T::TimeProvider::now_secs();
 
             // Validate coverage window
             T::MarketsApi::validate_coverage_window(
@@ -721,9 +1453,7 @@ pub mod pallet {
             ).map_err(|_| Error::<T>::InvalidCoverageWindow)?;
 
             // Generate unique quote ID
-            let nonce = AccountNonce::<T>::get(&who);
-            let quote_id = generate_unique_id(b"QUOTE", &who, now, nonce);
-            AccountNonce::<T>::insert(&who, nonce + 1);
+            let quote_id = Self::next_quote_id(&who, now);
             
             // Create V2 quote request with custom strike
             let quote_request = QuoteRequest::<T> {
@@ -738,20 +1468,16 @@ pub mod pallet {
                 requested_at: now,
                 // V2 specifics
                 policy_version: prmx_primitives::PolicyVersion::V2,
-                event_type: prmx_primitives::EventType::CumulativeRainfallWindow,
+                event_type,
                 early_trigger: true, // V2 default
                 duration_days,
                 strike_mm: Some(strike_mm), // Custom strike for V2
+                perils,
+                peril_aggregation,
             };
 
-            // Store quote request
-            QuoteRequests::<T>::insert(quote_id, quote_request);
-            QuoteStatuses::<T>::insert(quote_id, QuoteStatus::Pending);
-
-            // Add to pending quotes for offchain worker
-            PendingQuotes::<T>::mutate(|pending| {
-                let _ = pending.try_push(quote_id);
-            });
+            // Store quote request and queue it for the offchain worker
+            Self::enqueue_quote(quote_request)?;
 
             Self::deposit_event(Event::QuoteRequested {
                 quote_id,
@@ -759,485 +1485,2238 @@ pub mod pallet {
                 market_id,
             });
 
+            Self::price_from_bonding_curve(quote_id)
+        }
+
+        /// Cancel a quote that has not been used yet.
+        /// Only the requester can cancel, while the quote is Pending or Ready.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::cancel_quote())]
+        pub fn cancel_quote(
+            origin: OriginFor<T>,
+            quote_id: QuoteId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let req = QuoteRequests::<T>::get(quote_id)
+                .ok_or(Error::<T>::QuoteNotFound)?;
+            ensure!(req.requester == who, Error::<T>::NotQuoteRequester);
+            ensure!(
+                matches!(
+                    QuoteStatuses::<T>::get(quote_id),
+                    QuoteStatus::Pending | QuoteStatus::Ready
+                ),
+                Error::<T>::QuoteNotCancellable
+            );
+
+            QuoteStatuses::<T>::insert(quote_id, QuoteStatus::Cancelled);
+            PendingQuotes::<T>::mutate(|pending| {
+                pending.retain(|&id| id != quote_id);
+            });
+            Self::release_deposit(quote_id);
+
+            Self::deposit_event(Event::QuoteCancelled { quote_id });
+            Self::check_quote_batch_ready(quote_id);
+
+            Ok(())
+        }
+
+        /// Re-price an expired quote.
+        /// The quote's parameters are re-enqueued under a new quote ID, linked from the
+        /// old one in `RefreshedQuotes`. The coverage window must still be valid.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::refresh_quote())]
+        pub fn refresh_quote(
+            origin: OriginFor<T>,
+            quote_id: QuoteId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_quotes_not_paused()?;
+
+            let req = QuoteRequests::<T>::get(quote_id)
+                .ok_or(Error::<T>::QuoteNotFound)?;
+            ensure!(req.requester == who, Error::<T>::NotQuoteRequester);
+            ensure!(Self::is_quote_expired(quote_id), Error::<T>::QuoteNotExpired);
+
+            let now = T::TimeProvider::now_secs();
+
+            // The original window may have started since the quote was issued
+            T::MarketsApi::validate_coverage_window(
+                req.market_id,
+                req.coverage_start,
+                req.coverage_end,
+                now,
+            ).map_err(|_| Error::<T>::InvalidCoverageWindow)?;
+
+            if QuoteStatuses::<T>::get(quote_id) != QuoteStatus::Expired {
+                QuoteStatuses::<T>::insert(quote_id, QuoteStatus::Expired);
+                Self::deposit_event(Event::QuoteExpired { quote_id });
+            }
+            // The new quote reserves a deposit of its own
+            Self::release_deposit(quote_id);
+
+            let new_quote_id = Self::next_quote_id(&who, now);
+            let market_id = req.market_id;
+            Self::enqueue_quote(QuoteRequest::<T> {
+                quote_id: new_quote_id,
+                requested_at: now,
+                ..req
+            })?;
+            RefreshedQuotes::<T>::insert(quote_id, new_quote_id);
+            if let Some(members) = GroupQuoteMembers::<T>::get(quote_id) {
+                GroupQuoteMembers::<T>::insert(new_quote_id, members);
+            }
+
+            Self::deposit_event(Event::QuoteRequested {
+                quote_id: new_quote_id,
+                market_id,
+                requester: who,
+            });
+            Self::deposit_event(Event::QuoteRefreshed {
+                old_quote_id: quote_id,
+                new_quote_id,
+            });
+
+            Self::price_from_bonding_curve(new_quote_id)
+        }
+
+        /// Request V1 quotes for several locations/durations in one transaction.
+        /// Either every quote is created or none is.
+        ///
+        /// The offchain worker prices all members of the batch in the same run and
+        /// submits them together; `QuoteBatchReady` is emitted once all have resolved.
+        /// Members on bonding-curve markets are priced in this transaction.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::request_policy_quotes_batch(params.len() as u32))]
+        pub fn request_policy_quotes_batch(
+            origin: OriginFor<T>,
+            params: BoundedVec<QuoteParams, T::MaxQuotesPerBatch>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_quotes_not_paused()?;
+
+            ensure!(!params.is_empty(), Error::<T>::EmptyQuoteBatch);
+            ensure!(
+                PendingQuotes::<T>::decode_len().unwrap_or(0) + params.len()
+                    <= T::MaxPendingQuotes::get() as usize,
+                Error::<T>::TooManyPendingQuotes
+            );
+
+            let now = T::TimeProvider::now_secs();
+
+            // Validate every member before storing anything
+            for p in params.iter() {
+                ensure!(p.shares > 0, Error::<T>::InvalidShares);
+                ensure!(
+                    T::MarketsApi::is_market_open(p.market_id),
+                    Error::<T>::MarketNotOpen
+                );
+                T::MarketsApi::validate_coverage_window(
+                    p.market_id,
+                    p.coverage_start,
+                    p.coverage_end,
+                    now,
+                ).map_err(|_| Error::<T>::InvalidCoverageWindow)?;
+            }
+
+            let batch_id = NextQuoteBatchId::<T>::get();
+            NextQuoteBatchId::<T>::put(batch_id + 1);
+
+            let mut quote_ids: BoundedVec<QuoteId, T::MaxQuotesPerBatch> = BoundedVec::new();
+            for p in params.into_iter() {
+                let quote_id = Self::next_quote_id(&who, now);
+
+                // V1 defaults, as in `request_policy_quote`
+                Self::enqueue_quote(QuoteRequest::<T> {
+                    quote_id,
+                    market_id: p.market_id,
+                    requester: who.clone(),
+                    coverage_start: p.coverage_start,
+                    coverage_end: p.coverage_end,
+                    latitude: p.latitude,
+                    longitude: p.longitude,
+                    shares: p.shares,
+                    requested_at: now,
+                    policy_version: prmx_primitives::PolicyVersion::V1,
+                    event_type: prmx_primitives::EventType::Rainfall24hRolling,
+                    early_trigger: false,
+                    duration_days: 0,
+                    strike_mm: None,
+                    perils: BoundedVec::new(),
+                    peril_aggregation: PerilAggregation::Any,
+                })?;
+                QuoteBatchOf::<T>::insert(quote_id, batch_id);
+                // Bounded by the input length
+                let _ = quote_ids.try_push(quote_id);
+
+                Self::deposit_event(Event::QuoteRequested {
+                    quote_id,
+                    market_id: p.market_id,
+                    requester: who.clone(),
+                });
+            }
+
+            QuoteBatches::<T>::insert(
+                batch_id,
+                QuoteBatch {
+                    requester: who.clone(),
+                    quote_ids: quote_ids.clone(),
+                    requested_at: now,
+                    resolved: false,
+                },
+            );
+
+            Self::deposit_event(Event::QuoteBatchRequested {
+                batch_id,
+                requester: who,
+                quote_ids: quote_ids.clone().into_inner(),
+            });
+
+            for quote_id in quote_ids {
+                Self::price_from_bonding_curve(quote_id)?;
+            }
+
+            Ok(())
+        }
+
+        /// Submit results for all pending quotes of a batch from the offchain worker.
+        /// Submitted unsigned; the payload must be signed by the quote authority key
+        /// of a quote provider, which is checked in `validate_unsigned`. Attestations
+        /// are checked per member as in `submit_quote_from_ocw`.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::submit_quote_batch_from_ocw(payload.results.len() as u32))]
+        pub fn submit_quote_batch_from_ocw(
+            origin: OriginFor<T>,
+            payload: QuoteBatchResultPayload<T::Public, BlockNumberFor<T>, QuoteBatchResults<T>>,
+            _signature: T::Signature,
+        ) -> DispatchResult {
+            use sp_runtime::traits::IdentifyAccount;
+
+            ensure_none(origin)?;
+
+            let QuoteBatchResultPayload { public, batch_id, results, .. } = payload;
+            ensure!(
+                QuoteProviders::<T>::get(&public.into_account()),
+                Error::<T>::NotQuoteProvider
+            );
+
+            let batch = QuoteBatches::<T>::get(batch_id)
+                .ok_or(Error::<T>::QuoteBatchNotFound)?;
+            ensure!(
+                results
+                    .iter()
+                    .all(|(quote_id, _, _, _)| batch.quote_ids.contains(quote_id)),
+                Error::<T>::QuoteBatchMismatch
+            );
+
+            log::info!(
+                target: "prmx-quote",
+                "🤖 OCW unsigned tx: submitting {} quotes for batch {}",
+                results.len(),
+                batch_id
+            );
+
+            for (quote_id, probability_ppm, evidence, attestation) in results.into_iter() {
+                // Members cancelled since the OCW priced them are skipped
+                if QuoteStatuses::<T>::get(quote_id) != QuoteStatus::Pending {
+                    continue;
+                }
+                Self::ensure_attested(quote_id, probability_ppm, attestation.as_ref())?;
+                Self::do_submit_quote(quote_id, probability_ppm, evidence)?;
+            }
+
+            Ok(())
+        }
+
+        /// Set the fallback probability for a market without an actuarial model,
+        /// optionally tiered by coverage duration and strike.
+        /// Only callable by GovernanceOrigin.
+        ///
+        /// - `default_ppm`: Probability for quotes matching no tier.
+        /// - `tiers`: Checked in order; the first tier whose `max_duration_hours`
+        ///   and `min_strike_mm` match the quote applies.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::set_market_probability_override(tiers.len() as u32))]
+        pub fn set_market_probability_override(
+            origin: OriginFor<T>,
+            market_id: MarketId,
+            default_ppm: PartsPerMillion,
+            tiers: BoundedVec<ProbabilityTier, MaxProbabilityTiers>,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            T::MarketsApi::dao_margin_bp(market_id).map_err(|_| Error::<T>::MarketNotFound)?;
+            ensure!(
+                default_ppm <= 1_000_000
+                    && tiers.iter().all(|tier| tier.probability_ppm <= 1_000_000),
+                Error::<T>::InvalidProbability
+            );
+
+            let tier_count = tiers.len() as u32;
+            MarketProbabilityOverrides::<T>::insert(
+                market_id,
+                ProbabilityOverride { default_ppm, tiers },
+            );
+
+            Self::deposit_event(Event::MarketProbabilityOverrideSet {
+                market_id,
+                default_ppm,
+                tiers: tier_count,
+            });
+
+            Ok(())
+        }
+
+        /// Remove a market's fallback probability override.
+        /// Only callable by GovernanceOrigin.
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::clear_market_probability_override())]
+        pub fn clear_market_probability_override(
+            origin: OriginFor<T>,
+            market_id: MarketId,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                MarketProbabilityOverrides::<T>::contains_key(market_id),
+                Error::<T>::NoProbabilityOverride
+            );
+            MarketProbabilityOverrides::<T>::remove(market_id);
+
+            Self::deposit_event(Event::MarketProbabilityOverrideCleared { market_id });
+
+            Ok(())
+        }
+
+        /// Submit per-peril probabilities for a multi-peril quote from the offchain worker.
+        /// Submitted unsigned; the payload must be signed by the quote authority key
+        /// of a quote provider, which is checked in `validate_unsigned`.
+        #[pallet::call_index(14)]
+        #[pallet::weight(
+            T::WeightInfo::submit_peril_quote_from_ocw(payload.peril_probabilities.len() as u32)
+        )]
+        pub fn submit_peril_quote_from_ocw(
+            origin: OriginFor<T>,
+            payload: PerilQuoteResultPayload<T::Public, BlockNumberFor<T>>,
+            _signature: T::Signature,
+        ) -> DispatchResult {
+            use sp_runtime::traits::IdentifyAccount;
+
+            ensure_none(origin)?;
+
+            let PerilQuoteResultPayload { public, quote_id, peril_probabilities, evidence, .. } =
+                payload;
+            ensure!(
+                QuoteProviders::<T>::get(&public.into_account()),
+                Error::<T>::NotQuoteProvider
+            );
+
+            log::info!(
+                target: "prmx-quote",
+                "🤖 OCW unsigned tx: submitting quote {} with peril probabilities {:?}",
+                quote_id,
+                peril_probabilities
+            );
+
+            Self::do_submit_peril_quote(quote_id, peril_probabilities, evidence)
+        }
+
+        /// Store the base URL under which the OCW's raw pricing payloads are
+        /// published; quote evidence then points at `{base}/{quote_id}`.
+        /// An empty URL stops attaching evidence URLs.
+        /// Only callable by Root/Sudo.
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::set_pricing_evidence_url())]
+        pub fn set_pricing_evidence_url(
+            origin: OriginFor<T>,
+            evidence_url: Vec<u8>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            sp_io::offchain_index::set(
+                pricing_evidence::PRICING_EVIDENCE_URL_STORAGE,
+                &evidence_url,
+            );
+
+            log::info!(
+                target: "prmx-quote",
+                "🧾 Pricing evidence URL stored"
+            );
+
+            Ok(())
+        }
+
+        /// Price a market's quotes on-chain from a bonding curve (see `bonding_curve`)
+        /// instead of the offchain worker, or update its curve. Quotes already
+        /// pending stay with the offchain worker.
+        /// Only callable by GovernanceOrigin.
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::set_market_bonding_curve())]
+        pub fn set_market_bonding_curve(
+            origin: OriginFor<T>,
+            market_id: MarketId,
+            curve: BondingCurve<T::Balance>,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            T::MarketsApi::dao_margin_bp(market_id).map_err(|_| Error::<T>::MarketNotFound)?;
+            ensure!(curve.is_valid(), Error::<T>::InvalidBondingCurve);
+
+            MarketBondingCurves::<T>::insert(market_id, curve);
+
+            Self::deposit_event(Event::MarketBondingCurveSet { market_id });
+
+            Ok(())
+        }
+
+        /// Remove a market's bonding curve; its quotes go back to the offchain worker.
+        /// Only callable by GovernanceOrigin.
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::clear_market_bonding_curve())]
+        pub fn clear_market_bonding_curve(
+            origin: OriginFor<T>,
+            market_id: MarketId,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                MarketBondingCurves::<T>::contains_key(market_id),
+                Error::<T>::NoBondingCurve
+            );
+            MarketBondingCurves::<T>::remove(market_id);
+
+            Self::deposit_event(Event::MarketBondingCurveCleared { market_id });
+
+            Ok(())
+        }
+
+        /// Request V2 quotes for the same coverage at several strikes in one transaction.
+        /// Either every quote is created or none is.
+        ///
+        /// The quotes form a quote batch, so the offchain worker prices every strike in
+        /// the same run (one R API call per strike, subject to the price cache) and
+        /// submits them together. Once all have resolved, `QuoteLadderReady` lists the
+        /// premium of each strike alongside `QuoteBatchReady`.
+        ///
+        /// - `strikes`: Strike thresholds in mm * 10, each 10-3000 and distinct.
+        /// - Other parameters as in `request_policy_quote_v2`, without additional perils.
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::request_quote_ladder(strikes.len() as u32))]
+        pub fn request_quote_ladder(
+            origin: OriginFor<T>,
+            market_id: MarketId,
+            coverage_start: u64,
+            coverage_end: u64,
+            latitude: i32,
+            longitude: i32,
+            shares: u128,
+            duration_days: u8,
+            event_type: prmx_primitives::EventType,
+            strikes: BoundedVec<u32, T::MaxQuotesPerBatch>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_quotes_not_paused()?;
+
+            ensure!(!strikes.is_empty(), Error::<T>::EmptyQuoteBatch);
+            ensure!(shares > 0, Error::<T>::InvalidShares);
+            ensure!(
+                matches!(
+                    event_type,
+                    prmx_primitives::EventType::CumulativeRainfallWindow
+                        | prmx_primitives::EventType::Snowfall24h
+                        | prmx_primitives::EventType::FloodComposite
+                ),
+                Error::<T>::InvalidEventType
+            );
+            for (i, strike_mm) in strikes.iter().enumerate() {
+                ensure!(*strike_mm >= 10 && *strike_mm <= 3000, Error::<T>::InvalidStrike);
+                ensure!(
+                    !strikes[..i].contains(strike_mm),
+                    Error::<T>::DuplicateLadderStrike
+                );
+            }
+            ensure!(
+                PendingQuotes::<T>::decode_len().unwrap_or(0) + strikes.len()
+                    <= T::MaxPendingQuotes::get() as usize,
+                Error::<T>::TooManyPendingQuotes
+            );
+
+            ensure!(
+                T::MarketsApi::is_market_open(market_id),
+                Error::<T>::MarketNotOpen
+            );
+            T::MarketsApi::ensure_v2_allowed(market_id, duration_days)
+                .map_err(|_| Error::<T>::V2NotAllowed)?;
+
+            let now = T::TimeProvider::now_secs();
+            T::MarketsApi::validate_coverage_window(
+                market_id,
+                coverage_start,
+                coverage_end,
+                now,
+            ).map_err(|_| Error::<T>::InvalidCoverageWindow)?;
+
+            let batch_id = NextQuoteBatchId::<T>::get();
+            NextQuoteBatchId::<T>::put(batch_id + 1);
+
+            let mut quote_ids: BoundedVec<QuoteId, T::MaxQuotesPerBatch> = BoundedVec::new();
+            for strike_mm in strikes.iter() {
+                let quote_id = Self::next_quote_id(&who, now);
+
+                // V2 terms, as in `request_policy_quote_v2`
+                Self::enqueue_quote(QuoteRequest::<T> {
+                    quote_id,
+                    market_id,
+                    requester: who.clone(),
+                    coverage_start,
+                    coverage_end,
+                    latitude,
+                    longitude,
+                    shares,
+                    requested_at: now,
+                    policy_version: prmx_primitives::PolicyVersion::V2,
+                    event_type,
+                    early_trigger: true,
+                    duration_days,
+                    strike_mm: Some(*strike_mm),
+                    perils: BoundedVec::new(),
+                    peril_aggregation: PerilAggregation::Any,
+                })?;
+                QuoteBatchOf::<T>::insert(quote_id, batch_id);
+                // Bounded by the input length
+                let _ = quote_ids.try_push(quote_id);
+
+                Self::deposit_event(Event::QuoteRequested {
+                    quote_id,
+                    market_id,
+                    requester: who.clone(),
+                });
+            }
+
+            QuoteLadders::<T>::insert(batch_id, strikes);
+            QuoteBatches::<T>::insert(
+                batch_id,
+                QuoteBatch {
+                    requester: who.clone(),
+                    quote_ids: quote_ids.clone(),
+                    requested_at: now,
+                    resolved: false,
+                },
+            );
+
+            Self::deposit_event(Event::QuoteBatchRequested {
+                batch_id,
+                requester: who,
+                quote_ids: quote_ids.clone().into_inner(),
+            });
+
+            for quote_id in quote_ids {
+                Self::price_from_bonding_curve(quote_id)?;
+            }
+
+            Ok(())
+        }
+
+        /// Request a V2 quote for a bundle of locations and coverage windows in one
+        /// market, covered by a single policy (see `group`).
+        ///
+        /// The policy triggers once, when any member's observation over its own window
+        /// reaches the strike. The offchain worker prices every member and applies the
+        /// R model's correlation discount to their sum; group quotes are never priced
+        /// from a bonding curve.
+        ///
+        /// - `members`: Locations and coverage windows, at least two. Each window must
+        ///   be valid for the market and have a V2-allowed duration.
+        /// - Other parameters as in `request_policy_quote_v2`, without additional perils.
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::WeightInfo::request_group_quote(members.len() as u32))]
+        pub fn request_group_quote(
+            origin: OriginFor<T>,
+            market_id: MarketId,
+            members: BoundedVec<GroupMember, MaxGroupMembers>,
+            shares: u128,
+            event_type: prmx_primitives::EventType,
+            strike_mm: u32,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_quotes_not_paused()?;
+
+            ensure!(
+                members.len() as u32 >= group::MIN_GROUP_MEMBERS,
+                Error::<T>::InvalidGroupSize
+            );
+            ensure!(shares > 0, Error::<T>::InvalidShares);
+            ensure!(
+                matches!(
+                    event_type,
+                    prmx_primitives::EventType::CumulativeRainfallWindow
+                        | prmx_primitives::EventType::Snowfall24h
+                        | prmx_primitives::EventType::FloodComposite
+                ),
+                Error::<T>::InvalidEventType
+            );
+            ensure!(strike_mm >= 10 && strike_mm <= 3000, Error::<T>::InvalidStrike);
+            ensure!(
+                T::MarketsApi::is_market_open(market_id),
+                Error::<T>::MarketNotOpen
+            );
+
+            let now = T::TimeProvider::now_secs();
+            for member in members.iter() {
+                T::MarketsApi::ensure_v2_allowed(market_id, member.duration_days())
+                    .map_err(|_| Error::<T>::V2NotAllowed)?;
+                T::MarketsApi::validate_coverage_window(
+                    market_id,
+                    member.coverage_start,
+                    member.coverage_end,
+                    now,
+                ).map_err(|_| Error::<T>::InvalidCoverageWindow)?;
+            }
+            let (coverage_start, coverage_end) =
+                group::coverage_bounds(&members).ok_or(Error::<T>::InvalidGroupSize)?;
+
+            let quote_id = Self::next_quote_id(&who, now);
+
+            // The quote spans every member's window at the first member's location;
+            // settlement evaluates each member (see `GroupQuoteMembers`)
+            Self::enqueue_quote(QuoteRequest::<T> {
+                quote_id,
+                market_id,
+                requester: who.clone(),
+                coverage_start,
+                coverage_end,
+                latitude: members[0].latitude,
+                longitude: members[0].longitude,
+                shares,
+                requested_at: now,
+                policy_version: prmx_primitives::PolicyVersion::V2,
+                event_type,
+                early_trigger: true,
+                duration_days: ((coverage_end - coverage_start) / 86_400).min(u8::MAX as u64) as u8,
+                strike_mm: Some(strike_mm),
+                perils: BoundedVec::new(),
+                peril_aggregation: PerilAggregation::Any,
+            })?;
+            let member_count = members.len() as u32;
+            GroupQuoteMembers::<T>::insert(quote_id, members);
+
+            Self::deposit_event(Event::QuoteRequested {
+                quote_id,
+                market_id,
+                requester: who.clone(),
+            });
+            Self::deposit_event(Event::GroupQuoteRequested {
+                quote_id,
+                market_id,
+                requester: who,
+                members: member_count,
+            });
+
+            Ok(())
+        }
+
+        /// Submit member probabilities and the correlation discount of a group quote
+        /// from the offchain worker. Submitted unsigned; the payload must be signed by
+        /// the quote authority key of a quote provider, which is checked in
+        /// `validate_unsigned`.
+        #[pallet::call_index(20)]
+        #[pallet::weight(
+            T::WeightInfo::submit_group_quote_from_ocw(payload.member_probabilities.len() as u32)
+        )]
+        pub fn submit_group_quote_from_ocw(
+            origin: OriginFor<T>,
+            payload: GroupQuoteResultPayload<T::Public, BlockNumberFor<T>>,
+            _signature: T::Signature,
+        ) -> DispatchResult {
+            use sp_runtime::traits::IdentifyAccount;
+
+            ensure_none(origin)?;
+
+            let GroupQuoteResultPayload {
+                public,
+                quote_id,
+                member_probabilities,
+                correlation_discount_bp,
+                evidence,
+                ..
+            } = payload;
+            ensure!(
+                QuoteProviders::<T>::get(&public.into_account()),
+                Error::<T>::NotQuoteProvider
+            );
+
+            log::info!(
+                target: "prmx-quote",
+                "🤖 OCW unsigned tx: submitting group quote {} with members {:?}, discount {} bp",
+                quote_id,
+                member_probabilities,
+                correlation_discount_bp
+            );
+
+            Self::do_submit_group_quote(
+                quote_id,
+                member_probabilities,
+                correlation_discount_bp,
+                evidence,
+            )
+        }
+
+        /// Register the R pricing service's ed25519 public key, or remove it with
+        /// `None`. While a key is registered, OCW results for R-priced quotes are
+        /// rejected unless the service attested the cost behind them.
+        /// Only callable by GovernanceOrigin.
+        #[pallet::call_index(21)]
+        #[pallet::weight(T::WeightInfo::set_pricing_service_key())]
+        pub fn set_pricing_service_key(
+            origin: OriginFor<T>,
+            key: Option<[u8; 32]>,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            PricingServiceKey::<T>::set(key);
+
+            Self::deposit_event(Event::PricingServiceKeySet { key });
+
+            Ok(())
+        }
+
+        /// Return the deposit of a quote that expired without being used to its
+        /// requester. Callable by anyone, so deposits of abandoned quotes do not
+        /// stay reserved.
+        #[pallet::call_index(22)]
+        #[pallet::weight(T::WeightInfo::release_quote_deposit())]
+        pub fn release_quote_deposit(
+            origin: OriginFor<T>,
+            quote_id: QuoteId,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            ensure!(QuoteDeposits::<T>::contains_key(quote_id), Error::<T>::NoQuoteDeposit);
+            ensure!(Self::is_quote_expired(quote_id), Error::<T>::QuoteNotExpired);
+
+            if QuoteStatuses::<T>::get(quote_id) != QuoteStatus::Expired {
+                QuoteStatuses::<T>::insert(quote_id, QuoteStatus::Expired);
+                Self::deposit_event(Event::QuoteExpired { quote_id });
+            }
+            Self::release_deposit(quote_id);
+
+            Ok(())
+        }
+
+        /// Slash the deposit of an abusive quote that has not been released yet.
+        /// Only callable by GovernanceOrigin.
+        #[pallet::call_index(23)]
+        #[pallet::weight(T::WeightInfo::slash_quote_deposit())]
+        pub fn slash_quote_deposit(
+            origin: OriginFor<T>,
+            quote_id: QuoteId,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            let req = QuoteRequests::<T>::get(quote_id)
+                .ok_or(Error::<T>::QuoteNotFound)?;
+            let deposit = QuoteDeposits::<T>::take(quote_id)
+                .ok_or(Error::<T>::NoQuoteDeposit)?;
+
+            let (_imbalance, unslashed) =
+                T::DepositCurrency::slash_reserved(&req.requester, deposit);
+
+            Self::deposit_event(Event::QuoteDepositSlashed {
+                quote_id,
+                requester: req.requester,
+                amount: deposit.saturating_sub(unslashed),
+            });
+
+            Ok(())
+        }
+    }
+
+    // =========================================================================
+    //                           Offchain Worker
+    // =========================================================================
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn offchain_worker(block_number: BlockNumberFor<T>) {
+            use sp_runtime::traits::UniqueSaturatedInto;
+            let block_num: u32 = block_number.unique_saturated_into();
+
+            // Process pending quotes
+            let pending = PendingQuotes::<T>::get();
+            
+            if pending.is_empty() {
+                return;
+            }
+
+            log::info!(
+                target: "prmx-quote",
+                "📊 Offchain worker at block {}: {} pending quotes",
+                block_num,
+                pending.len()
+            );
+
+            // Get API key from offchain storage
+            let api_key = match Self::get_pricing_api_key() {
+                Some(key) => key,
+                None => {
+                    log::warn!(
+                        target: "prmx-quote",
+                        "⚠️ R pricing API key not configured. Skipping quote processing."
+                    );
+                    return;
+                }
+            };
+
+            // Get API URL from offchain storage or use default
+            let api_url = Self::get_pricing_api_url()
+                .unwrap_or_else(|| DEFAULT_R_PRICING_API_URL.as_bytes().to_vec());
+
+            // R API calls left for this run
+            let mut api_budget = price_cache::MAX_R_API_CALLS_PER_BLOCK;
+
+            // Batch members are priced together below
+            let mut batches: Vec<QuoteBatchId> = Vec::new();
+
+            for quote_id in pending.iter() {
+                if let Some(batch_id) = QuoteBatchOf::<T>::get(quote_id) {
+                    if !batches.contains(&batch_id) {
+                        batches.push(batch_id);
+                    }
+                    continue;
+                }
+
+                if let Some(req) = QuoteRequests::<T>::get(quote_id) {
+                    // Only process pending quotes
+                    if QuoteStatuses::<T>::get(quote_id) != QuoteStatus::Pending {
+                        continue;
+                    }
+
+                    log::info!(
+                        target: "prmx-quote",
+                        "🔄 Processing quote {} for market {}",
+                        quote_id,
+                        req.market_id
+                    );
+
+                    if let Some(members) = GroupQuoteMembers::<T>::get(quote_id) {
+                        Self::process_group_quote(
+                            &req,
+                            &members,
+                            &api_key,
+                            &api_url,
+                            &mut api_budget,
+                        );
+                        continue;
+                    }
+
+                    if !req.perils.is_empty() {
+                        Self::process_peril_quote(&req, &api_key, &api_url, &mut api_budget);
+                        continue;
+                    }
+
+                    match Self::quote_probability(&req, &api_key, &api_url, &mut api_budget) {
+                        Ok((probability_ppm, call)) => {
+                            log::info!(
+                                target: "prmx-quote",
+                                "✅ Got probability {} ppm for quote {}",
+                                probability_ppm,
+                                quote_id
+                            );
+
+                            let attestation = call
+                                .as_ref()
+                                .and_then(|call| attestation::from_response(&call.response));
+                            let evidence = Self::record_pricing_evidence(
+                                *quote_id,
+                                call.into_iter().collect(),
+                            );
+
+                            // Submit unsigned transaction to update on-chain
+                            if let Err(e) = Self::submit_quote_unsigned_tx(
+                                *quote_id,
+                                probability_ppm,
+                                evidence,
+                                attestation,
+                            ) {
+                                log::warn!(
+                                    target: "prmx-quote",
+                                    "❌ Failed to submit quote {}: {}",
+                                    quote_id,
+                                    e
+                                );
+                            }
+                        }
+                        Err(e) => {
+                            log::warn!(
+                                target: "prmx-quote",
+                                "❌ Failed to fetch probability for quote {}: {}",
+                                quote_id,
+                                e
+                            );
+                        }
+                    }
+                }
+            }
+
+            for batch_id in batches {
+                Self::process_quote_batch(batch_id, &api_key, &api_url, &mut api_budget);
+            }
+        }
+    }
+
+    // =========================================================================
+    //                           Helper Functions
+    // =========================================================================
+
+    impl<T: Config> Pallet<T> {
+        /// Generate a unique quote ID for `who` and bump their nonce
+        fn next_quote_id(who: &T::AccountId, now: u64) -> QuoteId {
+            let nonce = AccountNonce::<T>::get(who);
+            AccountNonce::<T>::insert(who, nonce + 1);
+            generate_unique_id(b"QUOTE", who, now, nonce)
+        }
+
+        /// Store a new quote request as Pending and add it to `PendingQuotes`.
+        /// The request counts against its requester's rate limit and reserves the
+        /// quote deposit (see `request_limits`).
+        fn enqueue_quote(quote_request: QuoteRequest<T>) -> DispatchResult {
+            let quote_id = quote_request.quote_id;
+
+            QuoteRequestWindows::<T>::try_mutate(&quote_request.requester, |window| {
+                let recorded = window.try_record(
+                    frame_system::Pallet::<T>::block_number(),
+                    T::QuoteRateLimitWindow::get(),
+                    T::MaxQuoteRequestsPerWindow::get(),
+                    1,
+                );
+                ensure!(recorded, Error::<T>::QuoteRequestRateLimited);
+                Ok::<_, DispatchError>(())
+            })?;
+
+            let deposit = T::QuoteDeposit::get();
+            if !deposit.is_zero() {
+                T::DepositCurrency::reserve(&quote_request.requester, deposit)?;
+                QuoteDeposits::<T>::insert(quote_id, deposit);
+            }
+
+            QuoteRequests::<T>::insert(quote_id, quote_request);
+            QuoteStatuses::<T>::insert(quote_id, QuoteStatus::Pending);
+
+            // Add to pending quotes for offchain worker
+            PendingQuotes::<T>::mutate(|pending| {
+                let _ = pending.try_push(quote_id);
+            });
+
+            Ok(())
+        }
+
+        /// Return a quote's deposit to its requester, if it still holds one
+        fn release_deposit(quote_id: QuoteId) {
+            let Some(amount) = QuoteDeposits::<T>::take(quote_id) else {
+                return;
+            };
+            let Some(req) = QuoteRequests::<T>::get(quote_id) else {
+                return;
+            };
+
+            T::DepositCurrency::unreserve(&req.requester, amount);
+
+            Self::deposit_event(Event::QuoteDepositReleased {
+                quote_id,
+                requester: req.requester,
+                amount,
+            });
+        }
+
+        /// Price a just-requested quote from its market's bonding curve. Quotes on
+        /// other markets, and quotes with additional perils or group members, stay
+        /// pending for the offchain worker.
+        fn price_from_bonding_curve(quote_id: QuoteId) -> DispatchResult {
+            let Some(req) = QuoteRequests::<T>::get(quote_id) else {
+                return Ok(());
+            };
+            if !req.perils.is_empty() || GroupQuoteMembers::<T>::contains_key(quote_id) {
+                return Ok(());
+            }
+            let Some(curve) = MarketBondingCurves::<T>::get(req.market_id) else {
+                return Ok(());
+            };
+
+            let payout_per_share: u128 = T::MarketsApi::payout_per_share(req.market_id)
+                .map_err(|_| Error::<T>::MarketNotFound)?
+                .into();
+            let market_strike = T::MarketsApi::strike_value(req.market_id)
+                .map_err(|_| Error::<T>::MarketNotFound)?;
+            let probability_ppm = curve
+                .probability_ppm(
+                    T::OpenNotional::open_notional(req.market_id).into(),
+                    payout_per_share.saturating_mul(req.shares),
+                    market_strike,
+                    req.strike_mm.unwrap_or(market_strike),
+                )
+                .ok_or(Error::<T>::BondingCurveCapacityExceeded)?;
+
+            log::info!(
+                target: "prmx-quote",
+                "📈 Bonding curve priced quote {} at {} ppm",
+                quote_id,
+                probability_ppm
+            );
+
+            Self::do_submit_quote(quote_id, probability_ppm, None)
+        }
+
+        /// Emit `QuoteBatchReady` once no member of the quote's batch is still Pending
+        fn check_quote_batch_ready(quote_id: QuoteId) {
+            let Some(batch_id) = QuoteBatchOf::<T>::get(quote_id) else {
+                return;
+            };
+
+            QuoteBatches::<T>::mutate(batch_id, |maybe_batch| {
+                let Some(batch) = maybe_batch else {
+                    return;
+                };
+                if batch.resolved
+                    || batch
+                        .quote_ids
+                        .iter()
+                        .any(|id| QuoteStatuses::<T>::get(id) == QuoteStatus::Pending)
+                {
+                    return;
+                }
+
+                let mut ready = 0u32;
+                let mut total_premium: u128 = 0;
+                for id in batch.quote_ids.iter() {
+                    if QuoteStatuses::<T>::get(id) == QuoteStatus::Ready {
+                        if let Some(result) = QuoteResults::<T>::get(id) {
+                            ready += 1;
+                            total_premium = total_premium.saturating_add(result.total_premium.into());
+                        }
+                    }
+                }
+                batch.resolved = true;
+
+                Self::deposit_event(Event::QuoteBatchReady {
+                    batch_id,
+                    ready,
+                    total_premium: total_premium.into(),
+                });
+                Self::deposit_ladder_ready(batch_id, &batch.quote_ids);
+            });
+        }
+
+        /// Emit `QuoteLadderReady` with each strike's premium if the batch is a ladder
+        fn deposit_ladder_ready(batch_id: QuoteBatchId, quote_ids: &[QuoteId]) {
+            let Some(strikes) = QuoteLadders::<T>::get(batch_id) else {
+                return;
+            };
+
+            let mut market_id = None;
+            let mut premiums = Vec::new();
+            for (quote_id, strike_mm) in quote_ids.iter().zip(strikes.iter()) {
+                if QuoteStatuses::<T>::get(quote_id) != QuoteStatus::Ready {
+                    continue;
+                }
+                let (Some(req), Some(result)) =
+                    (QuoteRequests::<T>::get(quote_id), QuoteResults::<T>::get(quote_id))
+                else {
+                    continue;
+                };
+                market_id = Some(req.market_id);
+                premiums.push((*quote_id, *strike_mm, result.total_premium));
+            }
+
+            // Every member was cancelled; `QuoteBatchReady` already reports that
+            let Some(market_id) = market_id else {
+                return;
+            };
+
+            Self::deposit_event(Event::QuoteLadderReady {
+                batch_id,
+                market_id,
+                premiums,
+            });
+        }
+
+        /// Whether a quote is expired: marked Expired, or Ready past its validity period
+        fn is_quote_expired(quote_id: QuoteId) -> bool {
+            match QuoteStatuses::<T>::get(quote_id) {
+                QuoteStatus::Expired => true,
+                QuoteStatus::Ready => QuoteResults::<T>::get(quote_id)
+                    .map(|result| {
+                        let validity = T::QuoteValiditySeconds::get();
+                        T::TimeProvider::now_secs() > result.calculated_at.saturating_add(validity)
+                    })
+                    .unwrap_or(false),
+                _ => false,
+            }
+        }
+
+        /// Check if a market has actuarial model support.
+        /// Currently only Manila (market_id = 0) has R model support.
+        /// Other markets (Amsterdam = 1, Tokyo = 2) use fixed probability.
+        fn has_actuarial_model(market_id: u64) -> bool {
+            // Only Manila (market_id = 0) has R actuarial model
+            market_id == 0
+        }
+
+        /// Check the pricing service attestation of an OCW result. Only R-priced
+        /// quotes are attested, and only while a pricing service key is registered.
+        fn ensure_attested(
+            quote_id: QuoteId,
+            probability_ppm: PartsPerMillion,
+            attestation: Option<&PricingAttestation>,
+        ) -> DispatchResult {
+            let Some(service_key) = PricingServiceKey::<T>::get() else {
+                return Ok(());
+            };
+            let req = QuoteRequests::<T>::get(quote_id).ok_or(Error::<T>::QuoteNotFound)?;
+            if !Self::has_actuarial_model(req.market_id) {
+                return Ok(());
+            }
+
+            let attestation = attestation.ok_or(Error::<T>::PricingAttestationMissing)?;
+            let key = Self::price_cache_key(&req, None).map_err(|_| Error::<T>::MarketNotFound)?;
+            let request_hash = attestation::request_hash(&key, attestation.coverage);
+            ensure!(
+                attestation::verify(&service_key, &request_hash, attestation),
+                Error::<T>::InvalidPricingAttestation
+            );
+            ensure!(
+                attestation::probability_ppm(attestation.avg_cost_micros, attestation.coverage)
+                    == Some(probability_ppm),
+                Error::<T>::AttestedProbabilityMismatch
+            );
+
+            Ok(())
+        }
+
+        /// Reject quote operations while governance has paused quoting
+        fn ensure_quotes_not_paused() -> DispatchResult {
+            ensure!(
+                !T::Pause::is_paused(PausableOperation::Quotes),
+                Error::<T>::OperationPaused
+            );
+            Ok(())
+        }
+
+        /// Quote provider that signed an OCW payload, if the signature is valid and the
+        /// payload is not from a future block
+        fn validate_ocw_payload<P: frame_system::offchain::SignedPayload<T>>(
+            payload: &P,
+            signature: &T::Signature,
+            block_number: BlockNumberFor<T>,
+        ) -> Result<T::AccountId, InvalidTransaction> {
+            use sp_runtime::traits::IdentifyAccount;
+
+            if !payload.verify::<T::AuthorityId>(signature.clone()) {
+                return Err(InvalidTransaction::BadProof);
+            }
+
+            let provider = payload.public().into_account();
+            if !QuoteProviders::<T>::get(&provider) {
+                return Err(InvalidTransaction::BadSigner);
+            }
+
+            if block_number > frame_system::Pallet::<T>::block_number() {
+                return Err(InvalidTransaction::Future);
+            }
+
+            Ok(provider)
+        }
+
+        /// Check that an OCW result targets an existing quote that is still pending
+        fn validate_pending_quote(quote_id: QuoteId) -> Result<(), InvalidTransaction> {
+            if !QuoteRequests::<T>::contains_key(quote_id) {
+                return Err(InvalidTransaction::Custom(1));
+            }
+
+            if QuoteStatuses::<T>::get(quote_id) != QuoteStatus::Pending {
+                return Err(InvalidTransaction::Custom(2));
+            }
+
+            Ok(())
+        }
+
+        /// Pool validity of an OCW quote result; one result per quote is accepted
+        fn quote_result_validity(quote_id: QuoteId) -> TransactionValidity {
+            ValidTransaction::with_tag_prefix("PrmxQuoteResult")
+                .priority(TransactionPriority::MAX / 2)
+                .and_provides(quote_id)
+                .longevity(5)
+                .propagate(true)
+                .build()
+        }
+
+        /// Internal function to submit the result of a single-peril quote
+        fn do_submit_quote(
+            quote_id: QuoteId,
+            probability_ppm: PartsPerMillion,
+            evidence: Option<PricingEvidence>,
+        ) -> DispatchResult {
+            let mut peril_probabilities = BoundedVec::new();
+            // Capacity is at least one
+            let _ = peril_probabilities.try_push(probability_ppm);
+            Self::do_submit_peril_quote(quote_id, peril_probabilities, evidence)
+        }
+
+        /// Internal function to submit the result of a group quote from its member
+        /// probabilities and correlation discount
+        fn do_submit_group_quote(
+            quote_id: QuoteId,
+            member_probabilities: BoundedVec<PartsPerMillion, MaxGroupMembers>,
+            correlation_discount_bp: u16,
+            evidence: Option<PricingEvidence>,
+        ) -> DispatchResult {
+            let members = GroupQuoteMembers::<T>::get(quote_id)
+                .ok_or(Error::<T>::NotGroupQuote)?;
+            ensure!(
+                member_probabilities.len() == members.len(),
+                Error::<T>::GroupMemberCountMismatch
+            );
+            ensure!(
+                member_probabilities.iter().all(|p| *p <= 1_000_000),
+                Error::<T>::InvalidProbability
+            );
+            ensure!(
+                correlation_discount_bp <= MAX_CORRELATION_DISCOUNT_BP,
+                Error::<T>::InvalidCorrelationDiscount
+            );
+
+            let probability_ppm =
+                group::group_probability(&member_probabilities, correlation_discount_bp);
+            Self::do_submit_quote(quote_id, probability_ppm, evidence)?;
+
+            GroupQuotePricing::<T>::insert(
+                quote_id,
+                GroupPricing { member_probabilities, correlation_discount_bp },
+            );
+            Self::deposit_event(Event::GroupQuotePriced {
+                quote_id,
+                probability_ppm,
+                correlation_discount_bp,
+            });
+
+            Ok(())
+        }
+
+        /// Internal function to submit quote result from per-peril probabilities
+        fn do_submit_peril_quote(
+            quote_id: QuoteId,
+            peril_probabilities: BoundedVec<PartsPerMillion, MaxPerilProbabilities>,
+            evidence: Option<PricingEvidence>,
+        ) -> DispatchResult {
+            Self::ensure_quotes_not_paused()?;
+
+            // Load quote request
+            let req = QuoteRequests::<T>::get(quote_id)
+                .ok_or(Error::<T>::QuoteNotFound)?;
+
+            // Ensure quote is pending
+            ensure!(
+                QuoteStatuses::<T>::get(quote_id) == QuoteStatus::Pending,
+                Error::<T>::QuoteAlreadyConsumed
+            );
+
+            // One probability for the rainfall strike plus one per peril
+            ensure!(
+                peril_probabilities.len() == req.perils.len() + 1,
+                Error::<T>::PerilCountMismatch
+            );
+            ensure!(
+                peril_probabilities.iter().all(|p| *p <= 1_000_000),
+                Error::<T>::InvalidProbability
+            );
+            let probability_ppm =
+                perils::combine_probabilities(&peril_probabilities, req.peril_aggregation);
+
+            // Get market data
+            let dao_margin_bp = T::MarketsApi::dao_margin_bp(req.market_id)
+                .map_err(|_| Error::<T>::MarketNotFound)?;
+            let payout_per_share = T::MarketsApi::payout_per_share(req.market_id)
+                .map_err(|_| Error::<T>::MarketNotFound)?;
+
+            // Calculate premium
+            let payout_u128: u128 = payout_per_share.into();
+            
+            // Fair premium = payout * probability
+            let fair_premium_u128 = payout_u128
+                .saturating_mul(probability_ppm as u128)
+                / 1_000_000u128;
+
+            // Apply DAO margin: premium = fair_premium * (1 + margin)
+            // margin_factor = 10000 + dao_margin_bp (in basis points)
+            let margin_factor_bp: u128 = 10_000u128 + dao_margin_bp as u128;
+            let premium_per_share_u128 = fair_premium_u128
+                .saturating_mul(margin_factor_bp)
+                / 10_000u128;
+
+            let premium_per_share: T::Balance = premium_per_share_u128.into();
+            let total_premium_u128 = premium_per_share_u128.saturating_mul(req.shares);
+            let total_premium: T::Balance = total_premium_u128.into();
+
+            // Store quote result
+            let now = T::TimeProvider::now_secs();
+            let quote_result = QuoteResult {
+                probability_ppm,
+                peril_probabilities,
+                premium_per_share,
+                total_premium,
+                calculated_at: now,
+            };
+
+            // Bind the quote to the terms it was priced for
+            let params_hash = binding::params_hash(
+                &Self::request_info(req),
+                &Self::result_info(quote_result.clone()),
+            );
+            QuoteResults::<T>::insert(quote_id, quote_result);
+            QuoteParamsHashes::<T>::insert(quote_id, params_hash);
+            if let Some(evidence) = evidence {
+                QuotePricingEvidence::<T>::insert(quote_id, evidence);
+            }
+            QuoteStatuses::<T>::insert(quote_id, QuoteStatus::Ready);
+
+            // Remove from pending quotes
+            PendingQuotes::<T>::mutate(|pending| {
+                pending.retain(|&id| id != quote_id);
+            });
+
+            Self::deposit_event(Event::QuoteReady {
+                quote_id,
+                total_premium,
+            });
+            Self::check_quote_batch_ready(quote_id);
+
             Ok(())
         }
-    }
 
-    // =========================================================================
-    //                           Offchain Worker
-    // =========================================================================
+        /// Get R pricing API key from offchain storage or test fallback
+        fn get_pricing_api_key() -> Option<Vec<u8>> {
+            // Try offchain local storage first
+            let storage = sp_io::offchain::local_storage_get(
+                sp_core::offchain::StorageKind::PERSISTENT,
+                R_PRICING_API_KEY_STORAGE,
+            );
+
+            if let Some(key) = storage {
+                if !key.is_empty() {
+                    return Some(key);
+                }
+            }
+
+            // Fallback: Use test API key in dev mode
+            #[cfg(feature = "dev-mode")]
+            {
+                log::info!(
+                    target: "prmx-quote",
+                    "Using test R pricing API key (dev-mode)"
+                );
+                return Some(TEST_R_PRICING_API_KEY.to_vec());
+            }
+
+            #[cfg(not(feature = "dev-mode"))]
+            None
+        }
+
+        /// Get R pricing API URL from offchain storage or test fallback
+        fn get_pricing_api_url() -> Option<Vec<u8>> {
+            // Try offchain local storage first
+            let storage = sp_io::offchain::local_storage_get(
+                sp_core::offchain::StorageKind::PERSISTENT,
+                R_PRICING_API_URL_STORAGE,
+            );
 
-    #[pallet::hooks]
-    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-        fn offchain_worker(block_number: BlockNumberFor<T>) {
-            use sp_runtime::traits::UniqueSaturatedInto;
-            let block_num: u32 = block_number.unique_saturated_into();
+            if let Some(url) = storage {
+                if !url.is_empty() {
+                    return Some(url);
+                }
+            }
 
-            // Process pending quotes
-            let pending = PendingQuotes::<T>::get();
+            // Fallback: Use test API URL in dev mode
+            #[cfg(feature = "dev-mode")]
+            {
+                log::info!(
+                    target: "prmx-quote",
+                    "Using test R pricing API URL (dev-mode)"
+                );
+                return Some(TEST_R_PRICING_API_URL.to_vec());
+            }
+
+            #[cfg(not(feature = "dev-mode"))]
+            None
+        }
+
+        /// Fetch probability from R pricing API
+        /// 
+        /// API parameters per pricing-model.md:
+        /// - lat, lon: Geographic location
+        /// - startdate: Coverage start as Unix timestamp
+        /// - duration_in_hours: 24 (fixed for v1)
+        /// - threshold: Strike value in mm
+        /// - coverage: payout_per_share × shares
+        /// - number_of_simulations: 100000
+        /// - ROC: 0.08
+        ///
+        /// With `peril`, the peril's `event_type` and threshold replace the rainfall strike.
+        /// Returns the request URL and raw response alongside the probability.
+        fn fetch_probability_from_r_api(
+            req: &QuoteRequest<T>,
+            peril: Option<&EventSpecV3>,
+            api_key: &[u8],
+            api_url: &[u8],
+        ) -> Result<(PartsPerMillion, PricingCall), &'static str> {
+            // Get market data
+            let payout_per_share = T::MarketsApi::payout_per_share(req.market_id)
+                .map_err(|_| "Market not found")?;
             
-            if pending.is_empty() {
-                return;
+            // Get strike value: use custom strike for V2, market default for V1
+            let strike_mm = match req.strike_mm {
+                Some(custom_strike) => custom_strike,
+                None => T::MarketsApi::strike_value(req.market_id)
+                    .map_err(|_| "Market not found")?,
+            };
+
+            // Convert lat/lon to floats (stored as scaled by 1e6)
+            let lat = req.latitude as f64 / 1_000_000.0;
+            let lon = req.longitude as f64 / 1_000_000.0;
+
+            // Calculate coverage amount in whole units (API expects dollars, not micro-dollars)
+            // Balance has 6 decimal places, so divide by 1_000_000
+            let payout_u128: u128 = payout_per_share.into();
+            let coverage_raw = payout_u128.saturating_mul(req.shares);
+            let coverage = coverage_raw / 1_000_000; // Convert to whole dollars
+
+            // Convert strike_mm (stored as mm * 10 for oracle) to actual mm
+            // The R API expects threshold in mm
+            let threshold_mm = strike_mm as f64 / 10.0;
+
+            // Calculate duration in hours from coverage period
+            let duration_in_hours = Self::duration_in_hours(req);
+
+            // Build request URL with query parameters
+            // The R API uses GET with query params, not POST with JSON body
+            let api_url_str = core::str::from_utf8(api_url)
+                .map_err(|_| "Invalid API URL encoding")?;
+            let api_key_str = core::str::from_utf8(api_key)
+                .map_err(|_| "Invalid API key encoding")?;
+
+            // The pricing service signs its cost against this hash (see `attestation`)
+            let request_hash = attestation::request_hash(
+                &Self::price_cache_key(req, peril)?,
+                u64::try_from(coverage).map_err(|_| "Coverage too large")?,
+            );
+
+            // Build full URL with query parameters
+            let threshold_params = Self::threshold_params(req, peril, threshold_mm);
+            let full_url = alloc::format!(
+                "{}?lat={}&lon={}&startdate={}&duration_in_hours={}&{}&coverage={}&number_of_simulations={}&ROC={}&{}",
+                api_url_str,
+                lat,
+                lon,
+                req.coverage_start,
+                duration_in_hours,
+                threshold_params,
+                coverage,
+                DEFAULT_NUMBER_OF_SIMULATIONS,
+                DEFAULT_ROC,
+                attestation::request_hash_param(&request_hash)
+            );
+
+            let response_body = Self::get_from_r_api(&full_url, api_key_str)?;
+
+            // Parse response and calculate probability
+            // Pass coverage (in whole dollars, same units as sent to API) for probability calculation
+            let probability_ppm = Self::parse_r_api_response(&response_body, coverage)?;
+            let call = PricingCall { request: full_url.into_bytes(), response: response_body };
+            Ok((probability_ppm, call))
+        }
+
+        /// Query parameters of the priced threshold: the peril's, or the quote's
+        /// strike (`threshold_mm`) with its event type
+        fn threshold_params(
+            req: &QuoteRequest<T>,
+            peril: Option<&EventSpecV3>,
+            threshold_mm: f64,
+        ) -> alloc::string::String {
+            match peril {
+                Some(spec) => perils::pricing_params(spec),
+                None if req.event_type == prmx_primitives::EventType::Snowfall24h => {
+                    alloc::format!("event_type=snowfall_24h&threshold={}", threshold_mm)
+                }
+                None if req.event_type == prmx_primitives::EventType::FloodComposite => {
+                    alloc::format!("event_type=flood_composite&threshold={}", threshold_mm)
+                }
+                None => alloc::format!("threshold={}", threshold_mm),
             }
+        }
 
+        /// GET `full_url` from the R API and return the raw response body
+        fn get_from_r_api(full_url: &str, api_key: &str) -> Result<Vec<u8>, &'static str> {
             log::info!(
                 target: "prmx-quote",
-                "📊 Offchain worker at block {}: {} pending quotes",
-                block_num,
-                pending.len()
+                "📤 Calling R API: {}",
+                full_url
             );
 
-            // Get API key from offchain storage
-            let api_key = match Self::get_pricing_api_key() {
-                Some(key) => key,
-                None => {
-                    log::warn!(
-                        target: "prmx-quote",
-                        "⚠️ R pricing API key not configured. Skipping quote processing."
-                    );
-                    return;
-                }
+            // Make HTTP GET request
+            let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(30_000));
+
+            let request = http::Request::get(full_url)
+                .add_header("X-API-Key", api_key);
+
+            let pending = request
+                .deadline(deadline)
+                .send()
+                .map_err(|_| "Failed to send HTTP request")?;
+
+            let response = pending
+                .try_wait(deadline)
+                .map_err(|_| "HTTP request timeout")?
+                .map_err(|_| "HTTP request failed")?;
+
+            if response.code != 200 {
+                log::warn!(
+                    target: "prmx-quote",
+                    "R API returned status code {}",
+                    response.code
+                );
+                return Err("R API returned error");
+            }
+
+            let response_body = response.body().collect::<Vec<u8>>();
+
+            log::info!(
+                target: "prmx-quote",
+                "📥 R API response: {}",
+                core::str::from_utf8(&response_body).unwrap_or("invalid utf8")
+            );
+
+            Ok(response_body)
+        }
+
+        /// Fetch the correlation discount between the members of a group quote from
+        /// the R model's group endpoint: the pricing endpoint with a `group` parameter
+        /// listing every member (see `group::pricing_params`) in place of lat, lon,
+        /// startdate and duration_in_hours. Counts against the per-block R API budget.
+        fn fetch_correlation_discount(
+            req: &QuoteRequest<T>,
+            members: &[GroupMember],
+            api_key: &[u8],
+            api_url: &[u8],
+            api_budget: &mut u32,
+        ) -> Result<(u16, PricingCall), &'static str> {
+            if *api_budget == 0 {
+                return Err("R API call budget exhausted for this block");
+            }
+            *api_budget -= 1;
+
+            let payout_per_share = T::MarketsApi::payout_per_share(req.market_id)
+                .map_err(|_| "Market not found")?;
+            let strike_mm = match req.strike_mm {
+                Some(custom_strike) => custom_strike,
+                None => T::MarketsApi::strike_value(req.market_id)
+                    .map_err(|_| "Market not found")?,
             };
+            let payout_u128: u128 = payout_per_share.into();
+            let coverage = payout_u128.saturating_mul(req.shares) / 1_000_000;
 
-            // Get API URL from offchain storage or use default
-            let api_url = Self::get_pricing_api_url()
-                .unwrap_or_else(|| DEFAULT_R_PRICING_API_URL.as_bytes().to_vec());
+            let api_url_str = core::str::from_utf8(api_url)
+                .map_err(|_| "Invalid API URL encoding")?;
+            let api_key_str = core::str::from_utf8(api_key)
+                .map_err(|_| "Invalid API key encoding")?;
 
-            for quote_id in pending.iter() {
-                if let Some(req) = QuoteRequests::<T>::get(quote_id) {
-                    // Only process pending quotes
-                    if QuoteStatuses::<T>::get(quote_id) != QuoteStatus::Pending {
-                        continue;
-                    }
+            let full_url = alloc::format!(
+                "{}?{}&{}&coverage={}&number_of_simulations={}&ROC={}",
+                api_url_str,
+                group::pricing_params(members),
+                Self::threshold_params(req, None, strike_mm as f64 / 10.0),
+                coverage,
+                DEFAULT_NUMBER_OF_SIMULATIONS,
+                DEFAULT_ROC
+            );
 
-                    log::info!(
-                        target: "prmx-quote",
-                        "🔄 Processing quote {} for market {}",
-                        quote_id,
-                        req.market_id
-                    );
+            let response_body = Self::get_from_r_api(&full_url, api_key_str)?;
+            let fraction = prmx_json::RPricingResponse::from_json(&response_body)?
+                .correlation_discount
+                .ok_or("R group response missing correlation_discount")?;
+            let discount_bp = group::discount_bp_from_fraction(fraction)
+                .ok_or("Invalid correlation discount")?;
 
-                    // Check if market has actuarial model support
-                    let probability_result = if Self::has_actuarial_model(req.market_id) {
-                        // Call R API for markets with model support (Manila = market_id 0)
-                        Self::fetch_probability_from_r_api(&req, &api_key, &api_url)
-                    } else {
-                        // Use fixed 1% probability for markets without model
-                        // 1% = 10,000 ppm (parts per million)
-                        // This is a temporary benchmark for Amsterdam, Tokyo, etc.
-                        log::info!(
-                            target: "prmx-quote",
-                            "📊 Using fixed 1% probability for market {} (no actuarial model)",
-                            req.market_id
-                        );
-                        Ok(FIXED_PROBABILITY_PPM)
-                    };
+            let call = PricingCall { request: full_url.into_bytes(), response: response_body };
+            Ok((discount_bp, call))
+        }
 
-                    match probability_result {
-                        Ok(probability_ppm) => {
-                            log::info!(
-                                target: "prmx-quote",
-                                "✅ Got probability {} ppm for quote {}",
-                                probability_ppm,
-                                quote_id
-                            );
+        /// Parse R API response and calculate probability
+        /// 
+        /// Expected response format:
+        /// {
+        ///   "avg_cost": 5.25,
+        ///   "recommended_premium": 6.3,
+        ///   "closest_point": {...},
+        ///   "dist_closest_point_km": 12.5
+        /// }
+        /// 
+        /// Probability calculation per pricing-model.md:
+        /// p = avg_cost / coverage
+        /// probability_ppm = p * 1_000_000
+        fn parse_r_api_response(json: &[u8], coverage: u128) -> Result<PartsPerMillion, &'static str> {
+            let json_str = core::str::from_utf8(json)
+                .map_err(|_| "Invalid JSON encoding")?;
+
+            log::debug!(
+                target: "prmx-quote",
+                "📥 R API response: {}",
+                json_str
+            );
+
+            // Handles both plain and double-encoded (["{\"avg_cost\":0.902,...}"]) responses
+            let response = prmx_json::RPricingResponse::from_json(json)?;
+            let avg_cost = response.avg_cost;
+
+            log::info!(
+                target: "prmx-quote",
+                "📊 avg_cost = {}, coverage = {}",
+                avg_cost,
+                coverage
+            );
+
+            if coverage == 0 {
+                return Err("Coverage cannot be zero");
+            }
+
+            // An attested cost is priced exactly as `ensure_attested` recomputes it
+            let attested_ppm = response
+                .attestation
+                .filter(|attestation| attestation.coverage as u128 == coverage)
+                .and_then(|attestation| {
+                    attestation::probability_ppm(attestation.avg_cost_micros, attestation.coverage)
+                });
+            if let Some(probability_ppm) = attested_ppm {
+                return Ok(probability_ppm);
+            }
+
+            // Calculate probability: p = avg_cost / coverage
+            // Then convert to parts per million
+            // Note: avg_cost is in the same units as coverage (e.g., USDT with 6 decimals)
+            let probability = avg_cost / (coverage as f64);
+            // Manual rounding: add 0.5 and truncate (f64::round not available in no_std)
+            let probability_ppm = (probability * 1_000_000.0 + 0.5) as u32;
 
-                            // Submit signed transaction to update on-chain
-                            if let Err(e) = Self::submit_quote_signed_tx(*quote_id, probability_ppm) {
-                                log::warn!(
-                                    target: "prmx-quote",
-                                    "❌ Failed to submit quote {}: {}",
-                                    quote_id,
-                                    e
-                                );
-                            }
-                        }
-                        Err(e) => {
-                            log::warn!(
-                                target: "prmx-quote",
-                                "❌ Failed to fetch probability for quote {}: {}",
-                                quote_id,
-                                e
-                            );
-                        }
-                    }
-                }
+            // Sanity check: probability should be between 0% and 100%
+            if probability_ppm > 1_000_000u32 {
+                log::warn!(
+                    target: "prmx-quote",
+                    "⚠️ Calculated probability {} ppm exceeds 100%, capping at 1,000,000",
+                    probability_ppm
+                );
+                return Ok(1_000_000);
             }
-        }
-    }
 
-    // =========================================================================
-    //                           Helper Functions
-    // =========================================================================
+            log::info!(
+                target: "prmx-quote",
+                "✅ Calculated probability: {}% ({} ppm)",
+                probability * 100.0,
+                probability_ppm
+            );
 
-    impl<T: Config> Pallet<T> {
-        /// Get current timestamp (simplified - in production use pallet-timestamp)
-        fn current_timestamp() -> u64 {
-            // Get timestamp from pallet_timestamp (returns milliseconds)
-            let now_ms: u64 = pallet_timestamp::Pallet::<T>::now()
-                .try_into()
-                .unwrap_or(0);
-            // Convert to seconds
-            now_ms / 1000
+            Ok(probability_ppm)
         }
 
-        /// Check if a market has actuarial model support.
-        /// Currently only Manila (market_id = 0) has R model support.
-        /// Other markets (Amsterdam = 1, Tokyo = 2) use fixed probability.
-        fn has_actuarial_model(market_id: u64) -> bool {
-            // Only Manila (market_id = 0) has R actuarial model
-            market_id == 0
+        /// Probability for a quote: R model for markets with actuarial support,
+        /// fixed benchmark otherwise. The R API call is returned as pricing evidence.
+        fn quote_probability(
+            req: &QuoteRequest<T>,
+            api_key: &[u8],
+            api_url: &[u8],
+            api_budget: &mut u32,
+        ) -> Result<(PartsPerMillion, Option<PricingCall>), &'static str> {
+            // Check if market has actuarial model support
+            if Self::has_actuarial_model(req.market_id) {
+                // Call R API for markets with model support (Manila = market_id 0)
+                let (probability_ppm, call) =
+                    Self::cached_probability_from_r_api(req, None, api_key, api_url, api_budget)?;
+                Ok((probability_ppm, Some(call)))
+            } else {
+                Ok((Self::fallback_probability(req)?, None))
+            }
         }
 
-        /// Internal function to submit quote result
-        fn do_submit_quote(quote_id: QuoteId, probability_ppm: PartsPerMillion) -> DispatchResult {
-            // Load quote request
-            let req = QuoteRequests::<T>::get(quote_id)
-                .ok_or(Error::<T>::QuoteNotFound)?;
-
-            // Ensure quote is pending
-            ensure!(
-                QuoteStatuses::<T>::get(quote_id) == QuoteStatus::Pending,
-                Error::<T>::QuoteAlreadyConsumed
-            );
+        /// Keep the raw R API calls behind a quote in persistent offchain storage
+        /// and build the evidence submitted with its result. `None` without calls.
+        fn record_pricing_evidence(
+            quote_id: QuoteId,
+            calls: Vec<PricingCall>,
+        ) -> Option<PricingEvidence> {
+            use sp_core::offchain::StorageKind;
 
-            // Get market data
-            let dao_margin_bp = T::MarketsApi::dao_margin_bp(req.market_id)
-                .map_err(|_| Error::<T>::MarketNotFound)?;
-            let payout_per_share = T::MarketsApi::payout_per_share(req.market_id)
-                .map_err(|_| Error::<T>::MarketNotFound)?;
+            if calls.is_empty() {
+                return None;
+            }
 
-            // Calculate premium
-            let payout_u128: u128 = payout_per_share.into();
-            
-            // Fair premium = payout * probability
-            let fair_premium_u128 = payout_u128
-                .saturating_mul(probability_ppm as u128)
-                / 1_000_000u128;
+            sp_io::offchain::local_storage_set(
+                StorageKind::PERSISTENT,
+                &pricing_evidence::payload_storage_key(&quote_id),
+                &calls.encode(),
+            );
 
-            // Apply DAO margin: premium = fair_premium * (1 + margin)
-            // margin_factor = 10000 + dao_margin_bp (in basis points)
-            let margin_factor_bp: u128 = 10_000u128 + dao_margin_bp as u128;
-            let premium_per_share_u128 = fair_premium_u128
-                .saturating_mul(margin_factor_bp)
-                / 10_000u128;
+            let (inputs_hash, response_hash) = pricing_evidence::evidence_hashes(&calls);
+            let evidence_url = sp_io::offchain::local_storage_get(
+                StorageKind::PERSISTENT,
+                pricing_evidence::PRICING_EVIDENCE_URL_STORAGE,
+            )
+            .and_then(|base| pricing_evidence::evidence_url(&base, &quote_id))
+            .and_then(|url| BoundedVec::try_from(url).ok());
 
-            let premium_per_share: T::Balance = premium_per_share_u128.into();
-            let total_premium_u128 = premium_per_share_u128.saturating_mul(req.shares);
-            let total_premium: T::Balance = total_premium_u128.into();
+            Some(PricingEvidence { inputs_hash, response_hash, evidence_url })
+        }
 
-            // Store quote result
-            let now = Self::current_timestamp();
-            let quote_result = QuoteResult {
-                probability_ppm,
-                premium_per_share,
-                total_premium,
-                calculated_at: now,
+        /// Probability for markets without an actuarial model: the governance
+        /// override for the quote's duration and strike, else the fixed 1% benchmark
+        fn fallback_probability(req: &QuoteRequest<T>) -> Result<PartsPerMillion, &'static str> {
+            let Some(probability_override) = MarketProbabilityOverrides::<T>::get(req.market_id)
+            else {
+                // Use fixed 1% probability for markets without model
+                // 1% = 10,000 ppm (parts per million)
+                // This is a temporary benchmark for Amsterdam, Tokyo, etc.
+                log::info!(
+                    target: "prmx-quote",
+                    "📊 Using fixed 1% probability for market {} (no actuarial model)",
+                    req.market_id
+                );
+                return Ok(FIXED_PROBABILITY_PPM);
             };
 
-            QuoteResults::<T>::insert(quote_id, quote_result);
-            QuoteStatuses::<T>::insert(quote_id, QuoteStatus::Ready);
-
-            // Remove from pending quotes
-            PendingQuotes::<T>::mutate(|pending| {
-                pending.retain(|&id| id != quote_id);
-            });
-
-            Self::deposit_event(Event::QuoteReady {
-                quote_id,
-                total_premium,
-            });
+            let strike_mm = match req.strike_mm {
+                Some(custom_strike) => custom_strike,
+                None => T::MarketsApi::strike_value(req.market_id)
+                    .map_err(|_| "Market not found")?,
+            };
+            let probability_ppm =
+                probability_override.probability_for(Self::duration_in_hours(req), strike_mm);
 
-            Ok(())
+            log::info!(
+                target: "prmx-quote",
+                "📊 Using governance probability {} ppm for market {} (no actuarial model)",
+                probability_ppm,
+                req.market_id
+            );
+            Ok(probability_ppm)
         }
 
-        /// Get R pricing API key from offchain storage or test fallback
-        fn get_pricing_api_key() -> Option<Vec<u8>> {
-            // Try offchain local storage first
-            let storage = sp_io::offchain::local_storage_get(
-                sp_core::offchain::StorageKind::PERSISTENT,
-                R_PRICING_API_KEY_STORAGE,
+        /// Price every pending member of a batch and submit them in one transaction.
+        /// If any member fails, nothing is submitted and the batch is retried next block.
+        fn process_quote_batch(
+            batch_id: QuoteBatchId,
+            api_key: &[u8],
+            api_url: &[u8],
+            api_budget: &mut u32,
+        ) {
+            let batch = match QuoteBatches::<T>::get(batch_id) {
+                Some(batch) => batch,
+                None => return,
+            };
+
+            log::info!(
+                target: "prmx-quote",
+                "🔄 Processing quote batch {} ({} quotes)",
+                batch_id,
+                batch.quote_ids.len()
             );
 
-            if let Some(key) = storage {
-                if !key.is_empty() {
-                    return Some(key);
+            let mut results = Vec::new();
+            for quote_id in batch.quote_ids.iter() {
+                if QuoteStatuses::<T>::get(quote_id) != QuoteStatus::Pending {
+                    continue;
+                }
+                let Some(req) = QuoteRequests::<T>::get(quote_id) else {
+                    continue;
+                };
+
+                match Self::quote_probability(&req, api_key, api_url, api_budget) {
+                    Ok((probability_ppm, call)) => {
+                        let attestation = call
+                            .as_ref()
+                            .and_then(|call| attestation::from_response(&call.response));
+                        let evidence =
+                            Self::record_pricing_evidence(*quote_id, call.into_iter().collect());
+                        results.push((*quote_id, probability_ppm, evidence, attestation));
+                    }
+                    Err(e) => {
+                        log::warn!(
+                            target: "prmx-quote",
+                            "❌ Failed to fetch probability for quote {} in batch {}: {}",
+                            quote_id,
+                            batch_id,
+                            e
+                        );
+                        return;
+                    }
                 }
             }
 
-            // Fallback: Use test API key in dev mode
-            #[cfg(feature = "dev-mode")]
-            {
-                log::info!(
+            if results.is_empty() {
+                return;
+            }
+
+            if let Err(e) = Self::submit_quote_batch_unsigned_tx(batch_id, results) {
+                log::warn!(
                     target: "prmx-quote",
-                    "Using test R pricing API key (dev-mode)"
+                    "❌ Failed to submit quote batch {}: {}",
+                    batch_id,
+                    e
                 );
-                return Some(TEST_R_PRICING_API_KEY.to_vec());
             }
-
-            #[cfg(not(feature = "dev-mode"))]
-            None
         }
 
-        /// Get R pricing API URL from offchain storage or test fallback
-        fn get_pricing_api_url() -> Option<Vec<u8>> {
-            // Try offchain local storage first
-            let storage = sp_io::offchain::local_storage_get(
-                sp_core::offchain::StorageKind::PERSISTENT,
-                R_PRICING_API_URL_STORAGE,
-            );
+        /// R model probability of the rainfall strike or one `peril`, served from the
+        /// offchain price cache when a fresh entry exists. Otherwise calls the R API,
+        /// subject to the per-block budget and the failure backoff. Either way the
+        /// R API call that produced the probability is returned with it.
+        fn cached_probability_from_r_api(
+            req: &QuoteRequest<T>,
+            peril: Option<&EventSpecV3>,
+            api_key: &[u8],
+            api_url: &[u8],
+            api_budget: &mut u32,
+        ) -> Result<(PartsPerMillion, PricingCall), &'static str> {
+            use price_cache::{Backoff, CachedPrice};
+            use sp_core::offchain::StorageKind;
 
-            if let Some(url) = storage {
-                if !url.is_empty() {
-                    return Some(url);
-                }
-            }
+            let key = Self::price_cache_key(req, peril)?.storage_key();
 
-            // Fallback: Use test API URL in dev mode
-            #[cfg(feature = "dev-mode")]
-            {
+            let now_ms = sp_io::offchain::timestamp().unix_millis();
+
+            let cached = sp_io::offchain::local_storage_get(StorageKind::PERSISTENT, &key)
+                .and_then(|bytes| CachedPrice::decode(&mut &bytes[..]).ok());
+            if let Some(cached) = cached.filter(|cached| cached.is_fresh(now_ms)) {
                 log::info!(
                     target: "prmx-quote",
-                    "Using test R pricing API URL (dev-mode)"
+                    "💾 Using cached probability {} ppm for quote {}",
+                    cached.probability_ppm,
+                    req.quote_id
                 );
-                return Some(TEST_R_PRICING_API_URL.to_vec());
+                return Ok((cached.probability_ppm, cached.call));
             }
 
-            #[cfg(not(feature = "dev-mode"))]
-            None
+            let backoff = sp_io::offchain::local_storage_get(
+                StorageKind::PERSISTENT,
+                price_cache::R_API_BACKOFF_STORAGE,
+            )
+            .and_then(|bytes| Backoff::decode(&mut &bytes[..]).ok())
+            .unwrap_or_default();
+            if backoff.is_active(now_ms) {
+                return Err("R API backing off after failures");
+            }
+
+            if *api_budget == 0 {
+                return Err("R API call budget exhausted for this block");
+            }
+            *api_budget -= 1;
+
+            match Self::fetch_probability_from_r_api(req, peril, api_key, api_url) {
+                Ok((probability_ppm, call)) => {
+                    let entry = CachedPrice { probability_ppm, cached_at_ms: now_ms, call };
+                    sp_io::offchain::local_storage_set(
+                        StorageKind::PERSISTENT,
+                        &key,
+                        &entry.encode(),
+                    );
+                    if backoff.consecutive_failures > 0 {
+                        sp_io::offchain::local_storage_clear(
+                            StorageKind::PERSISTENT,
+                            price_cache::R_API_BACKOFF_STORAGE,
+                        );
+                    }
+                    Ok((probability_ppm, entry.call))
+                }
+                Err(e) => {
+                    let backoff = backoff.record_failure(now_ms);
+                    log::warn!(
+                        target: "prmx-quote",
+                        "⏳ R API failure #{}, next call in {} ms",
+                        backoff.consecutive_failures,
+                        backoff.retry_after_ms.saturating_sub(now_ms)
+                    );
+                    sp_io::offchain::local_storage_set(
+                        StorageKind::PERSISTENT,
+                        price_cache::R_API_BACKOFF_STORAGE,
+                        &backoff.encode(),
+                    );
+                    Err(e)
+                }
+            }
         }
 
-        /// Fetch probability from R pricing API
-        /// 
-        /// API parameters per pricing-model.md:
-        /// - lat, lon: Geographic location
-        /// - startdate: Coverage start as Unix timestamp
-        /// - duration_in_hours: 24 (fixed for v1)
-        /// - threshold: Strike value in mm
-        /// - coverage: payout_per_share × shares
-        /// - number_of_simulations: 100000
-        /// - ROC: 0.08
-        fn fetch_probability_from_r_api(
+        /// Pricing inputs of the rainfall strike or one `peril` of a quote, used as
+        /// the price cache key and in the attested request hash
+        pub(crate) fn price_cache_key(
             req: &QuoteRequest<T>,
-            api_key: &[u8],
-            api_url: &[u8],
-        ) -> Result<PartsPerMillion, &'static str> {
-            // Get market data
-            let payout_per_share = T::MarketsApi::payout_per_share(req.market_id)
-                .map_err(|_| "Market not found")?;
-            
-            // Get strike value: use custom strike for V2, market default for V1
+            peril: Option<&EventSpecV3>,
+        ) -> Result<price_cache::PriceCacheKey, &'static str> {
             let strike_mm = match req.strike_mm {
                 Some(custom_strike) => custom_strike,
                 None => T::MarketsApi::strike_value(req.market_id)
                     .map_err(|_| "Market not found")?,
             };
+            let key = price_cache::PriceCacheKey::new(
+                req.market_id,
+                req.latitude,
+                req.longitude,
+                Self::duration_in_hours(req),
+                strike_mm,
+            )
+            .for_event_type(req.event_type);
+            Ok(match peril {
+                Some(spec) => key.for_peril(spec),
+                None => key,
+            })
+        }
 
-            // Convert lat/lon to floats (stored as scaled by 1e6)
-            let lat = req.latitude as f64 / 1_000_000.0;
-            let lon = req.longitude as f64 / 1_000_000.0;
+        /// Coverage duration in hours, 24 if the window is invalid
+        fn duration_in_hours(req: &QuoteRequest<T>) -> u64 {
+            if req.coverage_end > req.coverage_start {
+                (req.coverage_end - req.coverage_start) / 3600 // Convert seconds to hours
+            } else {
+                24 // Default to 24 hours if invalid range
+            }
+        }
 
-            // Calculate coverage amount in whole units (API expects dollars, not micro-dollars)
-            // Balance has 6 decimal places, so divide by 1_000_000
-            let payout_u128: u128 = payout_per_share.into();
-            let coverage_raw = payout_u128.saturating_mul(req.shares);
-            let coverage = coverage_raw / 1_000_000; // Convert to whole dollars
+        /// Submit the results of a quote batch as an unsigned transaction signed by each
+        /// local quote authority key
+        fn submit_quote_batch_unsigned_tx(
+            batch_id: QuoteBatchId,
+            results: Vec<(
+                QuoteId,
+                PartsPerMillion,
+                Option<PricingEvidence>,
+                Option<PricingAttestation>,
+            )>,
+        ) -> Result<(), &'static str> {
+            use frame_system::offchain::{SendUnsignedTransaction, Signer};
 
-            // Convert strike_mm (stored as mm * 10 for oracle) to actual mm
-            // The R API expects threshold in mm
-            let threshold_mm = strike_mm as f64 / 10.0;
+            let signer = Signer::<T, T::AuthorityId>::all_accounts();
+
+            if !signer.can_sign() {
+                return Err("No quote authority keys in keystore");
+            }
+
+            let results: QuoteBatchResults<T> =
+                results.try_into().map_err(|_| "Too many quotes in batch")?;
+            let block_number = frame_system::Pallet::<T>::block_number();
+
+            let tx_results = signer.send_unsigned_transaction(
+                |account| QuoteBatchResultPayload {
+                    block_number,
+                    public: account.public.clone(),
+                    batch_id,
+                    results: results.clone(),
+                },
+                |payload, signature| Call::submit_quote_batch_from_ocw { payload, signature },
+            );
+
+            for (acc, result) in &tx_results {
+                match result {
+                    Ok(()) => {
+                        log::info!(
+                            target: "prmx-quote",
+                            "✅ Unsigned tx sent from account {:?} for quote batch {}",
+                            acc.id,
+                            batch_id
+                        );
+                        return Ok(());
+                    }
+                    Err(e) => {
+                        log::warn!(
+                            target: "prmx-quote",
+                            "❌ Unsigned tx from account {:?} failed: {:?}",
+                            acc.id,
+                            e
+                        );
+                    }
+                }
+            }
 
-            // Calculate duration in hours from coverage period
-            let duration_in_hours = if req.coverage_end > req.coverage_start {
-                (req.coverage_end - req.coverage_start) / 3600 // Convert seconds to hours
-            } else {
-                24 // Default to 24 hours if invalid range
-            };
+            Err("All unsigned transactions failed")
+        }
 
-            // Build request URL with query parameters
-            // The R API uses GET with query params, not POST with JSON body
-            let api_url_str = core::str::from_utf8(api_url)
-                .map_err(|_| "Invalid API URL encoding")?;
-            let api_key_str = core::str::from_utf8(api_key)
-                .map_err(|_| "Invalid API key encoding")?;
+        /// Price the rainfall strike and every additional peril of a multi-peril
+        /// quote, then submit them in one transaction. Nothing is submitted unless
+        /// every peril was priced; the quote is retried next block.
+        fn process_peril_quote(
+            req: &QuoteRequest<T>,
+            api_key: &[u8],
+            api_url: &[u8],
+            api_budget: &mut u32,
+        ) {
+            let mut peril_probabilities: Vec<PartsPerMillion> = Vec::new();
+            let mut calls: Vec<PricingCall> = Vec::new();
+
+            let rainfall = Self::quote_probability(req, api_key, api_url, api_budget);
+            let peril_results = req.perils.iter().map(|spec| {
+                if Self::has_actuarial_model(req.market_id) {
+                    Self::cached_probability_from_r_api(req, Some(spec), api_key, api_url, api_budget)
+                        .map(|(probability_ppm, call)| (probability_ppm, Some(call)))
+                } else {
+                    Ok((FIXED_PROBABILITY_PPM, None))
+                }
+            });
 
-            // Build full URL with query parameters
-            let full_url = alloc::format!(
-                "{}?lat={}&lon={}&startdate={}&duration_in_hours={}&threshold={}&coverage={}&number_of_simulations={}&ROC={}",
-                api_url_str,
-                lat,
-                lon,
-                req.coverage_start,
-                duration_in_hours,
-                threshold_mm,
-                coverage,
-                DEFAULT_NUMBER_OF_SIMULATIONS,
-                DEFAULT_ROC
-            );
+            for result in core::iter::once(rainfall).chain(peril_results) {
+                match result {
+                    Ok((probability_ppm, call)) => {
+                        peril_probabilities.push(probability_ppm);
+                        calls.extend(call);
+                    }
+                    Err(e) => {
+                        log::warn!(
+                            target: "prmx-quote",
+                            "❌ Failed to fetch peril probability for quote {}: {}",
+                            req.quote_id,
+                            e
+                        );
+                        return;
+                    }
+                }
+            }
 
             log::info!(
                 target: "prmx-quote",
-                "📤 Calling R API: {}",
-                full_url
+                "✅ Got peril probabilities {:?} ppm for quote {}",
+                peril_probabilities,
+                req.quote_id
             );
 
-            // Make HTTP GET request
-            let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(30_000));
-
-            let request = http::Request::get(&full_url)
-                .add_header("X-API-Key", api_key_str);
-
-            let pending = request
-                .deadline(deadline)
-                .send()
-                .map_err(|_| "Failed to send HTTP request")?;
-
-            let response = pending
-                .try_wait(deadline)
-                .map_err(|_| "HTTP request timeout")?
-                .map_err(|_| "HTTP request failed")?;
-
-            if response.code != 200 {
+            let evidence = Self::record_pricing_evidence(req.quote_id, calls);
+            if let Err(e) =
+                Self::submit_peril_quote_unsigned_tx(req.quote_id, peril_probabilities, evidence)
+            {
                 log::warn!(
                     target: "prmx-quote",
-                    "R API returned status code {}",
-                    response.code
+                    "❌ Failed to submit quote {}: {}",
+                    req.quote_id,
+                    e
                 );
-                return Err("R API returned error");
             }
-
-            let response_body = response.body().collect::<Vec<u8>>();
-            
-            log::info!(
-                target: "prmx-quote",
-                "📥 R API response: {}",
-                core::str::from_utf8(&response_body).unwrap_or("invalid utf8")
-            );
-            
-            // Parse response and calculate probability
-            // Pass coverage (in whole dollars, same units as sent to API) for probability calculation
-            Self::parse_r_api_response(&response_body, coverage)
         }
 
-        /// Parse R API response and calculate probability
-        /// 
-        /// Expected response format:
-        /// {
-        ///   "avg_cost": 5.25,
-        ///   "recommended_premium": 6.3,
-        ///   "closest_point": {...},
-        ///   "dist_closest_point_km": 12.5
-        /// }
-        /// 
-        /// Probability calculation per pricing-model.md:
-        /// p = avg_cost / coverage
-        /// probability_ppm = p * 1_000_000
-        fn parse_r_api_response(json: &[u8], coverage: u128) -> Result<PartsPerMillion, &'static str> {
-            let json_str = core::str::from_utf8(json)
-                .map_err(|_| "Invalid JSON encoding")?;
+        /// Submit the per-peril probabilities of a quote as an unsigned transaction
+        /// signed by each local quote authority key
+        fn submit_peril_quote_unsigned_tx(
+            quote_id: QuoteId,
+            peril_probabilities: Vec<PartsPerMillion>,
+            evidence: Option<PricingEvidence>,
+        ) -> Result<(), &'static str> {
+            use frame_system::offchain::{SendUnsignedTransaction, Signer};
 
-            log::debug!(
-                target: "prmx-quote",
-                "📥 R API response: {}",
-                json_str
-            );
+            let signer = Signer::<T, T::AuthorityId>::all_accounts();
 
-            // Extract avg_cost value from JSON
-            // Look for "avg_cost": followed by a number
-            let avg_cost = Self::extract_json_number(json_str, "avg_cost")
-                .ok_or("Could not find avg_cost in response")?;
+            if !signer.can_sign() {
+                return Err("No quote authority keys in keystore");
+            }
 
-            log::info!(
-                target: "prmx-quote",
-                "📊 avg_cost = {}, coverage = {}",
-                avg_cost,
-                coverage
+            let peril_probabilities: BoundedVec<PartsPerMillion, MaxPerilProbabilities> =
+                peril_probabilities.try_into().map_err(|_| "Too many perils in quote")?;
+            let block_number = frame_system::Pallet::<T>::block_number();
+
+            let results = signer.send_unsigned_transaction(
+                |account| PerilQuoteResultPayload {
+                    block_number,
+                    public: account.public.clone(),
+                    quote_id,
+                    peril_probabilities: peril_probabilities.clone(),
+                    evidence: evidence.clone(),
+                },
+                |payload, signature| Call::submit_peril_quote_from_ocw { payload, signature },
             );
 
-            if coverage == 0 {
-                return Err("Coverage cannot be zero");
+            for (acc, result) in &results {
+                match result {
+                    Ok(()) => {
+                        log::info!(
+                            target: "prmx-quote",
+                            "✅ Unsigned tx sent from account {:?} for quote {}",
+                            acc.id,
+                            quote_id
+                        );
+                        return Ok(());
+                    }
+                    Err(e) => {
+                        log::warn!(
+                            target: "prmx-quote",
+                            "❌ Unsigned tx from account {:?} failed: {:?}",
+                            acc.id,
+                            e
+                        );
+                    }
+                }
             }
 
-            // Calculate probability: p = avg_cost / coverage
-            // Then convert to parts per million
-            // Note: avg_cost is in the same units as coverage (e.g., USDT with 6 decimals)
-            let probability = avg_cost / (coverage as f64);
-            // Manual rounding: add 0.5 and truncate (f64::round not available in no_std)
-            let probability_ppm = (probability * 1_000_000.0 + 0.5) as u32;
+            Err("All unsigned transactions failed")
+        }
 
-            // Sanity check: probability should be between 0% and 100%
-            if probability_ppm > 1_000_000u32 {
-                log::warn!(
-                    target: "prmx-quote",
-                    "⚠️ Calculated probability {} ppm exceeds 100%, capping at 1,000,000",
-                    probability_ppm
-                );
-                return Ok(1_000_000);
+        /// Price every member of a group quote like a single V2 quote, fetch the
+        /// correlation discount between them, then submit them in one transaction.
+        /// Nothing is submitted unless every call succeeded; the quote is retried
+        /// next block. Markets without an actuarial model get no discount.
+        fn process_group_quote(
+            req: &QuoteRequest<T>,
+            members: &[GroupMember],
+            api_key: &[u8],
+            api_url: &[u8],
+            api_budget: &mut u32,
+        ) {
+            let mut member_probabilities: Vec<PartsPerMillion> = Vec::new();
+            let mut calls: Vec<PricingCall> = Vec::new();
+
+            for member in members {
+                let member_req = QuoteRequest::<T> {
+                    quote_id: req.quote_id,
+                    market_id: req.market_id,
+                    requester: req.requester.clone(),
+                    coverage_start: member.coverage_start,
+                    coverage_end: member.coverage_end,
+                    latitude: member.latitude,
+                    longitude: member.longitude,
+                    shares: req.shares,
+                    requested_at: req.requested_at,
+                    policy_version: req.policy_version,
+                    event_type: req.event_type,
+                    early_trigger: req.early_trigger,
+                    duration_days: member.duration_days(),
+                    strike_mm: req.strike_mm,
+                    perils: BoundedVec::new(),
+                    peril_aggregation: PerilAggregation::Any,
+                };
+                match Self::quote_probability(&member_req, api_key, api_url, api_budget) {
+                    Ok((probability_ppm, call)) => {
+                        member_probabilities.push(probability_ppm);
+                        calls.extend(call);
+                    }
+                    Err(e) => {
+                        log::warn!(
+                            target: "prmx-quote",
+                            "❌ Failed to fetch member probability for group quote {}: {}",
+                            req.quote_id,
+                            e
+                        );
+                        return;
+                    }
+                }
             }
 
+            let correlation_discount_bp = if Self::has_actuarial_model(req.market_id) {
+                match Self::fetch_correlation_discount(req, members, api_key, api_url, api_budget)
+                {
+                    Ok((discount_bp, call)) => {
+                        calls.push(call);
+                        discount_bp
+                    }
+                    Err(e) => {
+                        log::warn!(
+                            target: "prmx-quote",
+                            "❌ Failed to fetch correlation discount for group quote {}: {}",
+                            req.quote_id,
+                            e
+                        );
+                        return;
+                    }
+                }
+            } else {
+                0
+            };
+
             log::info!(
                 target: "prmx-quote",
-                "✅ Calculated probability: {}% ({} ppm)",
-                probability * 100.0,
-                probability_ppm
+                "✅ Got member probabilities {:?} ppm, discount {} bp for group quote {}",
+                member_probabilities,
+                correlation_discount_bp,
+                req.quote_id
             );
 
-            Ok(probability_ppm)
+            let evidence = Self::record_pricing_evidence(req.quote_id, calls);
+            if let Err(e) = Self::submit_group_quote_unsigned_tx(
+                req.quote_id,
+                member_probabilities,
+                correlation_discount_bp,
+                evidence,
+            ) {
+                log::warn!(
+                    target: "prmx-quote",
+                    "❌ Failed to submit group quote {}: {}",
+                    req.quote_id,
+                    e
+                );
+            }
         }
 
-        /// Extract a numeric value from JSON by key name
-        fn extract_json_number(json: &str, key: &str) -> Option<f64> {
-            // Try both regular JSON format ("key":) and escaped format (\"key\":)
-            // The R API returns double-encoded JSON: ["{\"avg_cost\":0.902,...}"]
-            
-            // First try escaped format: \"key\":
-            let escaped_pattern = alloc::format!("\\\"{}\\\"", key);
-            if let Some(key_start) = json.find(&escaped_pattern) {
-                let after_key = &json[key_start + escaped_pattern.len()..];
-                if let Some(colon_pos) = after_key.find(':') {
-                    let value_part = &after_key[colon_pos + 1..];
-                    let value_trimmed = value_part.trim_start();
-                    // For escaped JSON, values end at \, or \" or }
-                    let end_pos = value_trimmed
-                        .find(|c: char| c == ',' || c == '\\' || c == '}' || c == ']')
-                        .unwrap_or(value_trimmed.len());
-                    let value_str = value_trimmed[..end_pos].trim();
-                    if let Ok(val) = value_str.parse::<f64>() {
-                        return Some(val);
+        /// Submit the member probabilities and correlation discount of a group quote
+        /// as an unsigned transaction signed by each local quote authority key
+        fn submit_group_quote_unsigned_tx(
+            quote_id: QuoteId,
+            member_probabilities: Vec<PartsPerMillion>,
+            correlation_discount_bp: u16,
+            evidence: Option<PricingEvidence>,
+        ) -> Result<(), &'static str> {
+            use frame_system::offchain::{SendUnsignedTransaction, Signer};
+
+            let signer = Signer::<T, T::AuthorityId>::all_accounts();
+
+            if !signer.can_sign() {
+                return Err("No quote authority keys in keystore");
+            }
+
+            let member_probabilities: BoundedVec<PartsPerMillion, MaxGroupMembers> =
+                member_probabilities.try_into().map_err(|_| "Too many members in group quote")?;
+            let block_number = frame_system::Pallet::<T>::block_number();
+
+            let results = signer.send_unsigned_transaction(
+                |account| GroupQuoteResultPayload {
+                    block_number,
+                    public: account.public.clone(),
+                    quote_id,
+                    member_probabilities: member_probabilities.clone(),
+                    correlation_discount_bp,
+                    evidence: evidence.clone(),
+                },
+                |payload, signature| Call::submit_group_quote_from_ocw { payload, signature },
+            );
+
+            for (acc, result) in &results {
+                match result {
+                    Ok(()) => {
+                        log::info!(
+                            target: "prmx-quote",
+                            "✅ Unsigned tx sent from account {:?} for group quote {}",
+                            acc.id,
+                            quote_id
+                        );
+                        return Ok(());
+                    }
+                    Err(e) => {
+                        log::warn!(
+                            target: "prmx-quote",
+                            "❌ Unsigned tx from account {:?} failed: {:?}",
+                            acc.id,
+                            e
+                        );
                     }
                 }
             }
-            
-            // Fallback to regular format: "key":
-            let pattern = alloc::format!("\"{}\"", key);
-            let key_start = json.find(&pattern)?;
-            
-            // Find the colon after the key
-            let after_key = &json[key_start + pattern.len()..];
-            let colon_pos = after_key.find(':')?;
-            
-            // Get the value part (after the colon)
-            let value_part = &after_key[colon_pos + 1..];
-            
-            // Skip whitespace
-            let value_trimmed = value_part.trim_start();
-            
-            // Find the end of the number (comma, }, or whitespace)
-            let end_pos = value_trimmed
-                .find(|c: char| c == ',' || c == '}' || c == ']' || c == '\n')
-                .unwrap_or(value_trimmed.len());
-            
-            let value_str = value_trimmed[..end_pos].trim();
-            
-            value_str.parse::<f64>().ok()
+
+            Err("All unsigned transactions failed")
         }
 
-        /// Submit a signed transaction to update on-chain quote result
-        fn submit_quote_signed_tx(
+        /// Submit a quote result as an unsigned transaction signed by each local quote
+        /// authority key
+        fn submit_quote_unsigned_tx(
             quote_id: QuoteId,
             probability_ppm: PartsPerMillion,
+            evidence: Option<PricingEvidence>,
+            attestation: Option<PricingAttestation>,
         ) -> Result<(), &'static str> {
-            use frame_system::offchain::{SendSignedTransaction, Signer};
+            use frame_system::offchain::{SendUnsignedTransaction, Signer};
 
             // Get signer from keystore
             let signer = Signer::<T, T::AuthorityId>::all_accounts();
@@ -1245,26 +3724,32 @@ pub mod pallet {
             if !signer.can_sign() {
                 log::warn!(
                     target: "prmx-quote",
-                    "⚠️ No quote authority keys found in keystore. Cannot submit signed tx."
+                    "⚠️ No quote authority keys found in keystore. Cannot submit quote result."
                 );
                 return Err("No quote authority keys in keystore");
             }
 
-            // Create the call
-            let call = Call::<T>::submit_quote_from_ocw {
-                quote_id,
-                probability_ppm,
-            };
-
-            // Send signed transaction
-            let results = signer.send_signed_transaction(|_account| call.clone());
+            let block_number = frame_system::Pallet::<T>::block_number();
+
+            // Send unsigned transaction with a signed payload
+            let results = signer.send_unsigned_transaction(
+                |account| QuoteResultPayload {
+                    block_number,
+                    public: account.public.clone(),
+                    quote_id,
+                    probability_ppm,
+                    evidence: evidence.clone(),
+                    attestation: attestation.clone(),
+                },
+                |payload, signature| Call::submit_quote_from_ocw { payload, signature },
+            );
 
             for (acc, result) in &results {
                 match result {
                     Ok(()) => {
                         log::info!(
                             target: "prmx-quote",
-                            "✅ Signed tx sent from account {:?} for quote {}",
+                            "✅ Unsigned tx sent from account {:?} for quote {}",
                             acc.id,
                             quote_id
                         );
@@ -1273,7 +3758,7 @@ pub mod pallet {
                     Err(e) => {
                         log::warn!(
                             target: "prmx-quote",
-                            "❌ Signed tx from account {:?} failed: {:?}",
+                            "❌ Unsigned tx from account {:?} failed: {:?}",
                             acc.id,
                             e
                         );
@@ -1281,7 +3766,7 @@ pub mod pallet {
                 }
             }
 
-            Err("All signed transactions failed")
+            Err("All unsigned transactions failed")
         }
 
         /// Mark a quote as consumed
@@ -1292,12 +3777,46 @@ pub mod pallet {
             );
 
             QuoteStatuses::<T>::insert(quote_id, QuoteStatus::Consumed);
+            Self::release_deposit(quote_id);
 
             Self::deposit_event(Event::QuoteConsumed { quote_id });
 
             Ok(())
         }
 
+        /// Quote request as exposed through `QuoteAccess`
+        fn request_info(req: QuoteRequest<T>) -> QuoteRequestInfo<T::AccountId> {
+            QuoteRequestInfo {
+                quote_id: req.quote_id,
+                market_id: req.market_id,
+                requester: req.requester,
+                coverage_start: req.coverage_start,
+                coverage_end: req.coverage_end,
+                latitude: req.latitude,
+                longitude: req.longitude,
+                shares: req.shares,
+                requested_at: req.requested_at,
+                policy_version: req.policy_version,
+                event_type: req.event_type,
+                early_trigger: req.early_trigger,
+                duration_days: req.duration_days,
+                strike_mm: req.strike_mm,
+                perils: req.perils.into_inner(),
+                peril_aggregation: req.peril_aggregation,
+            }
+        }
+
+        /// Quote result as exposed through `QuoteAccess`
+        fn result_info(res: QuoteResult<T::Balance>) -> QuoteResultInfo<T::Balance> {
+            QuoteResultInfo {
+                probability_ppm: res.probability_ppm,
+                peril_probabilities: res.peril_probabilities.into_inner(),
+                premium_per_share: res.premium_per_share,
+                total_premium: res.total_premium,
+                calculated_at: res.calculated_at,
+            }
+        }
+
         /// Check if quote is ready and valid
         pub fn is_quote_ready_and_valid(quote_id: QuoteId) -> bool {
             if QuoteStatuses::<T>::get(quote_id) != QuoteStatus::Ready {
@@ -1305,7 +3824,7 @@ pub mod pallet {
             }
 
             if let Some(result) = QuoteResults::<T>::get(quote_id) {
-                let now = Self::current_timestamp();
+                let now = T::TimeProvider::now_secs();
                 let validity = T::QuoteValiditySeconds::get();
                 return now <= result.calculated_at.saturating_add(validity);
             }
@@ -1320,31 +3839,11 @@ pub mod pallet {
 
     impl<T: Config> QuoteAccess<T::AccountId, T::Balance> for Pallet<T> {
         fn get_quote_request(quote_id: QuoteId) -> Option<QuoteRequestInfo<T::AccountId>> {
-            QuoteRequests::<T>::get(quote_id).map(|req| QuoteRequestInfo {
-                quote_id: req.quote_id,
-                market_id: req.market_id,
-                requester: req.requester,
-                coverage_start: req.coverage_start,
-                coverage_end: req.coverage_end,
-                latitude: req.latitude,
-                longitude: req.longitude,
-                shares: req.shares,
-                requested_at: req.requested_at,
-                policy_version: req.policy_version,
-                event_type: req.event_type,
-                early_trigger: req.early_trigger,
-                duration_days: req.duration_days,
-                strike_mm: req.strike_mm,
-            })
+            QuoteRequests::<T>::get(quote_id).map(Pallet::<T>::request_info)
         }
 
         fn get_quote_result(quote_id: QuoteId) -> Option<QuoteResultInfo<T::Balance>> {
-            QuoteResults::<T>::get(quote_id).map(|res| QuoteResultInfo {
-                probability_ppm: res.probability_ppm,
-                premium_per_share: res.premium_per_share,
-                total_premium: res.total_premium,
-                calculated_at: res.calculated_at,
-            })
+            QuoteResults::<T>::get(quote_id).map(Pallet::<T>::result_info)
         }
 
         fn consume_quote(quote_id: QuoteId) -> Result<(), sp_runtime::DispatchError> {
@@ -1354,5 +3853,15 @@ pub mod pallet {
         fn is_quote_ready(quote_id: QuoteId) -> bool {
             Pallet::<T>::is_quote_ready_and_valid(quote_id)
         }
+
+        fn quote_params_hash(quote_id: QuoteId) -> Option<[u8; 32]> {
+            QuoteParamsHashes::<T>::get(quote_id)
+        }
+
+        fn group_members(quote_id: QuoteId) -> Vec<GroupMember> {
+            GroupQuoteMembers::<T>::get(quote_id)
+                .map(BoundedVec::into_inner)
+                .unwrap_or_default()
+        }
     }
 }
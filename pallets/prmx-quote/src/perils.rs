@@ -0,0 +1,138 @@
+//! # Multi-Peril Quoting
+//!
+//! Helpers for V2 quotes that cover additional perils (wind gust, temperature)
+//! alongside the rainfall strike.
+//!
+//! Each peril is priced separately by the R model. The quote's probability is
+//! then combined from the per-peril probabilities, treating perils as
+//! independent:
+//!
+//! - `Any`: 1 - Π(1 - pᵢ)
+//! - `All`: Π pᵢ
+
+use alloc::{format, string::String};
+
+use prmx_primitives::{EventSpecV3, EventTypeV3, PerilAggregation, UnitV3};
+
+/// One in parts per million
+const PPM: u128 = 1_000_000;
+
+/// Combined trigger probability of independent perils, in parts per million.
+/// Inputs above 1,000,000 ppm are treated as certain.
+pub fn combine_probabilities(probabilities: &[u32], aggregation: PerilAggregation) -> u32 {
+    if probabilities.is_empty() {
+        return 0;
+    }
+
+    let clamped = probabilities.iter().map(|p| (*p as u128).min(PPM));
+    let combined = match aggregation {
+        PerilAggregation::Any => {
+            let none_triggered = clamped.fold(PPM, |acc, p| acc * (PPM - p) / PPM);
+            PPM - none_triggered
+        }
+        PerilAggregation::All => clamped.fold(PPM, |acc, p| acc * p / PPM),
+    };
+    combined as u32
+}
+
+/// Threshold unit expected for an event type
+pub fn unit_for(event_type: EventTypeV3) -> UnitV3 {
+    match event_type {
+        EventTypeV3::PrecipSumGte | EventTypeV3::Precip1hGte => UnitV3::MmX1000,
+        EventTypeV3::TempMaxGte | EventTypeV3::TempMinLte => UnitV3::CelsiusX1000,
+        EventTypeV3::WindGustMaxGte => UnitV3::MpsX1000,
+        EventTypeV3::PrecipTypeOccurred => UnitV3::PrecipTypeMask,
+        EventTypeV3::DryStreakGte => UnitV3::Hours,
+        EventTypeV3::CycloneWithinGte => UnitV3::CycloneCategoryWithinKm,
+    }
+}
+
+/// Whether a spec can be quoted as an additional peril. Cumulative rainfall is
+/// already covered by the quote's strike, and precipitation type, dry
+/// streaks and cyclones have no probability model.
+pub fn is_valid_additional_peril(spec: &EventSpecV3) -> bool {
+    let supported = !matches!(
+        spec.event_type,
+        EventTypeV3::PrecipSumGte
+            | EventTypeV3::PrecipTypeOccurred
+            | EventTypeV3::DryStreakGte
+            | EventTypeV3::CycloneWithinGte
+    );
+    // Temperatures may be negative; amounts and speeds may not
+    let threshold_ok = match spec.event_type {
+        EventTypeV3::TempMaxGte | EventTypeV3::TempMinLte => true,
+        _ => spec.threshold.value > 0,
+    };
+
+    supported && threshold_ok && spec.threshold.unit == unit_for(spec.event_type)
+}
+
+/// R API `event_type` parameter for an event type
+pub fn event_type_param(event_type: EventTypeV3) -> &'static str {
+    match event_type {
+        EventTypeV3::PrecipSumGte => "precip_sum_gte",
+        EventTypeV3::Precip1hGte => "precip_1h_gte",
+        EventTypeV3::TempMaxGte => "temp_max_gte",
+        EventTypeV3::TempMinLte => "temp_min_lte",
+        EventTypeV3::WindGustMaxGte => "wind_gust_max_gte",
+        EventTypeV3::PrecipTypeOccurred => "precip_type_occurred",
+        EventTypeV3::DryStreakGte => "dry_streak_gte",
+        EventTypeV3::CycloneWithinGte => "cyclone_within_gte",
+    }
+}
+
+/// R API query parameters for pricing one peril (threshold in natural units)
+pub fn pricing_params(spec: &EventSpecV3) -> String {
+    format!(
+        "event_type={}&threshold={}",
+        event_type_param(spec.event_type),
+        spec.threshold.value as f64 / 1000.0
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prmx_primitives::ThresholdV3;
+
+    fn spec(event_type: EventTypeV3, value: i64, unit: UnitV3) -> EventSpecV3 {
+        EventSpecV3 { event_type, threshold: ThresholdV3 { value, unit }, early_trigger: true }
+    }
+
+    #[test]
+    fn test_combine_probabilities() {
+        // 10% rain, 20% wind
+        let p = [100_000, 200_000];
+        assert_eq!(combine_probabilities(&p, PerilAggregation::Any), 280_000);
+        assert_eq!(combine_probabilities(&p, PerilAggregation::All), 20_000);
+
+        // A single peril is unchanged either way
+        assert_eq!(combine_probabilities(&[12_345], PerilAggregation::Any), 12_345);
+        assert_eq!(combine_probabilities(&[12_345], PerilAggregation::All), 12_345);
+
+        assert_eq!(combine_probabilities(&[], PerilAggregation::Any), 0);
+        assert_eq!(combine_probabilities(&[2_000_000, 0], PerilAggregation::Any), 1_000_000);
+    }
+
+    #[test]
+    fn test_additional_peril_validation() {
+        assert!(is_valid_additional_peril(&spec(EventTypeV3::WindGustMaxGte, 25_000, UnitV3::MpsX1000)));
+        assert!(is_valid_additional_peril(&spec(EventTypeV3::TempMinLte, -5_000, UnitV3::CelsiusX1000)));
+        assert!(is_valid_additional_peril(&spec(EventTypeV3::Precip1hGte, 30_000, UnitV3::MmX1000)));
+
+        // Wrong unit, non-positive amount, or rainfall already covered by the strike
+        assert!(!is_valid_additional_peril(&spec(EventTypeV3::WindGustMaxGte, 25_000, UnitV3::MmX1000)));
+        assert!(!is_valid_additional_peril(&spec(EventTypeV3::WindGustMaxGte, 0, UnitV3::MpsX1000)));
+        assert!(!is_valid_additional_peril(&spec(EventTypeV3::PrecipSumGte, 50_000, UnitV3::MmX1000)));
+        assert!(!is_valid_additional_peril(&spec(EventTypeV3::PrecipTypeOccurred, 2, UnitV3::PrecipTypeMask)));
+        assert!(!is_valid_additional_peril(&spec(EventTypeV3::DryStreakGte, 240, UnitV3::Hours)));
+        let cyclone = spec(EventTypeV3::CycloneWithinGte, 1_003, UnitV3::CycloneCategoryWithinKm);
+        assert!(!is_valid_additional_peril(&cyclone));
+    }
+
+    #[test]
+    fn test_pricing_params() {
+        let wind = spec(EventTypeV3::WindGustMaxGte, 25_500, UnitV3::MpsX1000);
+        assert_eq!(pricing_params(&wind), "event_type=wind_gust_max_gte&threshold=25.5");
+    }
+}
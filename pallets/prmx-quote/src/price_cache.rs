@@ -0,0 +1,215 @@
+//! # Quote Price Cache
+//!
+//! Offchain bookkeeping that keeps the quote OCW from spending R API quota on
+//! identical requests.
+//!
+//! - Probabilities are cached per (market, lat/lon bucket, duration, strike, event
+//!   type) for `PRICE_CACHE_TTL_MS`, so pending quotes for the same location and
+//!   terms reuse one API call.
+//! - Each OCW run may make at most `MAX_R_API_CALLS_PER_BLOCK` calls.
+//! - Consecutive API failures back off exponentially, from `BACKOFF_BASE_MS` up
+//!   to `BACKOFF_MAX_MS`.
+
+use alloc::vec::Vec;
+use codec::{Decode, Encode};
+
+use pallet_prmx_markets::MarketId;
+use prmx_primitives::{EventSpecV3, EventType, EventTypeV3};
+
+use crate::pricing_evidence::PricingCall;
+
+/// Offchain storage prefix for cached probabilities
+pub const PRICE_CACHE_PREFIX: &[u8] = b"prmx-quote::price-cache::";
+
+/// Offchain storage key for the R API backoff state
+pub const R_API_BACKOFF_STORAGE: &[u8] = b"prmx-quote::r-api-backoff";
+
+/// How long a cached probability stays usable (15 minutes)
+pub const PRICE_CACHE_TTL_MS: u64 = 15 * 60 * 1000;
+
+/// Coordinate bucket size, scaled by 1e6 (0.01° ≈ 1.1 km)
+pub const COORDINATE_BUCKET: i32 = 10_000;
+
+/// Maximum R API calls per offchain worker run
+pub const MAX_R_API_CALLS_PER_BLOCK: u32 = 5;
+
+/// Delay after the first failed R API call (30 seconds)
+pub const BACKOFF_BASE_MS: u64 = 30_000;
+
+/// Upper bound on the backoff delay (30 minutes)
+pub const BACKOFF_MAX_MS: u64 = 30 * 60 * 1000;
+
+/// Pricing inputs that determine the R model probability
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PriceCacheKey {
+    pub market_id: MarketId,
+    /// Latitude bucket index (`latitude / COORDINATE_BUCKET`, rounded down)
+    pub lat_bucket: i32,
+    /// Longitude bucket index (`longitude / COORDINATE_BUCKET`, rounded down)
+    pub lon_bucket: i32,
+    pub duration_hours: u64,
+    /// Strike threshold in mm * 10
+    pub strike_mm: u32,
+    /// What the strike measures (rainfall or snowfall)
+    pub event_type: EventType,
+    /// Additional peril priced instead of the rainfall strike (event type, threshold)
+    pub peril: Option<(EventTypeV3, i64)>,
+}
+
+impl PriceCacheKey {
+    /// Key for a quote; coordinates are scaled by 1e6
+    pub fn new(
+        market_id: MarketId,
+        latitude: i32,
+        longitude: i32,
+        duration_hours: u64,
+        strike_mm: u32,
+    ) -> Self {
+        Self {
+            market_id,
+            lat_bucket: coordinate_bucket(latitude),
+            lon_bucket: coordinate_bucket(longitude),
+            duration_hours,
+            strike_mm,
+            event_type: EventType::default(),
+            peril: None,
+        }
+    }
+
+    /// Key for a quote whose strike measures `event_type`
+    pub fn for_event_type(self, event_type: EventType) -> Self {
+        Self { event_type, ..self }
+    }
+
+    /// Key for one of the quote's additional perils
+    pub fn for_peril(self, spec: &EventSpecV3) -> Self {
+        Self { peril: Some((spec.event_type, spec.threshold.value)), ..self }
+    }
+
+    /// Offchain storage key for this entry
+    pub fn storage_key(&self) -> Vec<u8> {
+        let mut key = PRICE_CACHE_PREFIX.to_vec();
+        key.extend_from_slice(&self.encode());
+        key
+    }
+}
+
+/// Bucket index of a coordinate scaled by 1e6. Rounds towards negative infinity
+/// so buckets on either side of zero have the same width.
+pub fn coordinate_bucket(coordinate: i32) -> i32 {
+    coordinate.div_euclid(COORDINATE_BUCKET)
+}
+
+/// Cached probability, when it was fetched and the R API call that produced it
+/// (kept so quotes served from the cache still carry pricing evidence)
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub struct CachedPrice {
+    pub probability_ppm: u32,
+    /// Offchain timestamp in milliseconds
+    pub cached_at_ms: u64,
+    pub call: PricingCall,
+}
+
+impl CachedPrice {
+    pub fn is_fresh(&self, now_ms: u64) -> bool {
+        now_ms.saturating_sub(self.cached_at_ms) < PRICE_CACHE_TTL_MS
+    }
+}
+
+/// Backoff state after consecutive R API failures
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Backoff {
+    pub consecutive_failures: u32,
+    /// No calls are made before this offchain timestamp (milliseconds)
+    pub retry_after_ms: u64,
+}
+
+impl Backoff {
+    pub fn is_active(&self, now_ms: u64) -> bool {
+        now_ms < self.retry_after_ms
+    }
+
+    /// State after another failure at `now_ms`
+    pub fn record_failure(&self, now_ms: u64) -> Self {
+        let consecutive_failures = self.consecutive_failures.saturating_add(1);
+        Self {
+            consecutive_failures,
+            retry_after_ms: now_ms.saturating_add(backoff_delay(consecutive_failures)),
+        }
+    }
+}
+
+/// Delay after `failures` consecutive failures: base * 2^(failures - 1), capped
+pub fn backoff_delay(failures: u32) -> u64 {
+    if failures == 0 {
+        return 0;
+    }
+    let exponent = (failures - 1).min(63);
+    BACKOFF_BASE_MS
+        .saturating_mul(1u64 << exponent)
+        .min(BACKOFF_MAX_MS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nearby_coordinates_share_a_bucket() {
+        // Manila, a few hundred metres apart
+        let a = PriceCacheKey::new(0, 14_599_500, 120_984_200, 24, 500);
+        let b = PriceCacheKey::new(0, 14_595_100, 120_981_000, 24, 500);
+        assert_eq!(a, b);
+        assert_eq!(a.storage_key(), b.storage_key());
+
+        // Different terms or a different cell are separate entries
+        assert_ne!(a, PriceCacheKey::new(0, 14_599_500, 120_984_200, 48, 500));
+        assert_ne!(a, PriceCacheKey::new(0, 14_599_500, 120_984_200, 24, 600));
+        assert_ne!(a, PriceCacheKey::new(0, 14_609_500, 120_984_200, 24, 500));
+        assert_ne!(a.storage_key(), a.for_event_type(EventType::Snowfall24h).storage_key());
+
+        let wind = EventSpecV3 {
+            event_type: EventTypeV3::WindGustMaxGte,
+            threshold: prmx_primitives::ThresholdV3 {
+                value: 25_000,
+                unit: prmx_primitives::UnitV3::MpsX1000,
+            },
+            early_trigger: true,
+        };
+        assert_ne!(a.storage_key(), a.for_peril(&wind).storage_key());
+        assert_eq!(a.for_peril(&wind), b.for_peril(&wind));
+
+        // Buckets straddling zero are not merged
+        assert_eq!(coordinate_bucket(5_000), 0);
+        assert_eq!(coordinate_bucket(-5_000), -1);
+        assert_eq!(coordinate_bucket(-10_000), -1);
+    }
+
+    #[test]
+    fn test_cache_ttl() {
+        let cached = CachedPrice {
+            probability_ppm: 12_000,
+            cached_at_ms: 1_000_000,
+            call: PricingCall { request: b"http://r/pricing".to_vec(), response: Vec::new() },
+        };
+        assert!(cached.is_fresh(1_000_000));
+        assert!(cached.is_fresh(1_000_000 + PRICE_CACHE_TTL_MS - 1));
+        assert!(!cached.is_fresh(1_000_000 + PRICE_CACHE_TTL_MS));
+    }
+
+    #[test]
+    fn test_backoff_doubles_and_caps() {
+        assert_eq!(backoff_delay(0), 0);
+        assert_eq!(backoff_delay(1), BACKOFF_BASE_MS);
+        assert_eq!(backoff_delay(2), 2 * BACKOFF_BASE_MS);
+        assert_eq!(backoff_delay(4), 8 * BACKOFF_BASE_MS);
+        assert_eq!(backoff_delay(10), BACKOFF_MAX_MS);
+        assert_eq!(backoff_delay(u32::MAX), BACKOFF_MAX_MS);
+
+        let now = 5_000;
+        let backoff = Backoff::default().record_failure(now).record_failure(now);
+        assert_eq!(backoff.consecutive_failures, 2);
+        assert!(backoff.is_active(now + 2 * BACKOFF_BASE_MS - 1));
+        assert!(!backoff.is_active(now + 2 * BACKOFF_BASE_MS));
+    }
+}
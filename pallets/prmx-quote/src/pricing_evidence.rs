@@ -0,0 +1,127 @@
+//! # Quote Pricing Evidence
+//!
+//! Lets premiums be audited and disputed after the fact. For every R model call
+//! made while pricing a quote, the OCW keeps the exact request (the full query
+//! URL; the API key travels in a header and is never part of it) and the raw
+//! response body. The quote result is submitted with:
+//!
+//! - `inputs_hash`: blake2_256 of the SCALE-encoded list of requests
+//! - `response_hash`: blake2_256 of the SCALE-encoded list of raw responses
+//! - an optional evidence URL (`{base}/{quote_id}`, base set by governance)
+//!
+//! Calls are listed in pricing order: the rainfall strike first, then each
+//! additional peril. The OCW writes the SCALE-encoded `Vec<PricingCall>` to the
+//! node's persistent offchain storage under `payload_storage_key`, from which
+//! both hashes can be recomputed. Quotes priced from governance fallback
+//! probabilities make no R API call and carry no evidence.
+
+use alloc::vec::Vec;
+use codec::{Decode, Encode};
+
+use prmx_primitives::QuoteId;
+
+/// Offchain storage prefix for raw pricing payloads, followed by the quote ID
+pub const PRICING_PAYLOAD_PREFIX: &[u8] = b"prmx-quote::pricing-payload::";
+
+/// Offchain storage key for the evidence URL base (set via `set_pricing_evidence_url`)
+pub const PRICING_EVIDENCE_URL_STORAGE: &[u8] = b"prmx-quote::pricing-evidence-url";
+
+/// Maximum length of an evidence URL, in bytes
+pub const MAX_EVIDENCE_URL_LEN: u32 = 256;
+
+/// One R model call: what was asked and what came back
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub struct PricingCall {
+    /// Full request URL including query parameters
+    pub request: Vec<u8>,
+    /// Raw response body
+    pub response: Vec<u8>,
+}
+
+/// `(inputs_hash, response_hash)` of the calls that priced a quote
+pub fn evidence_hashes(calls: &[PricingCall]) -> ([u8; 32], [u8; 32]) {
+    let requests: Vec<&[u8]> = calls.iter().map(|call| &call.request[..]).collect();
+    let responses: Vec<&[u8]> = calls.iter().map(|call| &call.response[..]).collect();
+    (
+        sp_io::hashing::blake2_256(&requests.encode()),
+        sp_io::hashing::blake2_256(&responses.encode()),
+    )
+}
+
+/// Offchain storage key of a quote's raw pricing payload
+pub fn payload_storage_key(quote_id: &QuoteId) -> Vec<u8> {
+    let mut key = PRICING_PAYLOAD_PREFIX.to_vec();
+    key.extend_from_slice(&quote_id.encode());
+    key
+}
+
+/// Evidence URL of a quote: `base` without trailing slashes, then `/{quote_id}`.
+/// `None` if the base is empty or the result exceeds `MAX_EVIDENCE_URL_LEN`.
+pub fn evidence_url(base: &[u8], quote_id: &QuoteId) -> Option<Vec<u8>> {
+    let end = base.iter().rposition(|b| *b != b'/').map_or(0, |i| i + 1);
+    if end == 0 {
+        return None;
+    }
+    let mut url = base[..end].to_vec();
+    url.extend_from_slice(alloc::format!("/{}", quote_id).as_bytes());
+    (url.len() <= MAX_EVIDENCE_URL_LEN as usize).then_some(url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn call(request: &[u8], response: &[u8]) -> PricingCall {
+        PricingCall { request: request.to_vec(), response: response.to_vec() }
+    }
+
+    #[test]
+    fn test_evidence_hashes_bind_order_and_content() {
+        let rain = call(b"http://r/pricing?threshold=50", br#"{"avg_cost":5.25}"#);
+        let wind = call(b"http://r/pricing?event_type=wind_gust", br#"{"avg_cost":1.1}"#);
+
+        let (inputs, responses) = evidence_hashes(&[rain.clone(), wind.clone()]);
+        assert_eq!(
+            inputs,
+            sp_io::hashing::blake2_256(&vec![rain.request.clone(), wind.request.clone()].encode())
+        );
+
+        // Swapping the calls changes both hashes
+        let (swapped_inputs, swapped_responses) = evidence_hashes(&[wind, rain.clone()]);
+        assert_ne!(inputs, swapped_inputs);
+        assert_ne!(responses, swapped_responses);
+
+        // A different response leaves the inputs hash alone
+        let tampered = call(&rain.request, br#"{"avg_cost":0.5}"#);
+        let (tampered_inputs, tampered_responses) = evidence_hashes(&[tampered]);
+        let (rain_inputs, rain_responses) = evidence_hashes(&[rain]);
+        assert_eq!(tampered_inputs, rain_inputs);
+        assert_ne!(tampered_responses, rain_responses);
+    }
+
+    #[test]
+    fn test_evidence_url() {
+        let quote_id = QuoteId::from([0xab; 16]);
+        let expected = alloc::format!("https://audit.prmx.io/quotes/{}", quote_id);
+
+        assert_eq!(
+            evidence_url(b"https://audit.prmx.io/quotes", &quote_id),
+            Some(expected.clone().into_bytes())
+        );
+        assert_eq!(
+            evidence_url(b"https://audit.prmx.io/quotes//", &quote_id),
+            Some(expected.into_bytes())
+        );
+        assert_eq!(evidence_url(b"", &quote_id), None);
+        assert_eq!(evidence_url(b"/", &quote_id), None);
+        assert_eq!(evidence_url(&[b'a'; 250], &quote_id), None);
+    }
+
+    #[test]
+    fn test_payload_storage_key_is_per_quote() {
+        let a = payload_storage_key(&QuoteId::from([1; 16]));
+        let b = payload_storage_key(&QuoteId::from([2; 16]));
+        assert!(a.starts_with(PRICING_PAYLOAD_PREFIX));
+        assert_ne!(a, b);
+    }
+}
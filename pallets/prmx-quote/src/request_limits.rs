@@ -0,0 +1,98 @@
+//! # Quote Request Deposits and Rate Limits
+//!
+//! Every pending quote costs the offchain worker an R API call, so requesting
+//! quotes is not free:
+//!
+//! - Each quote reserves `QuoteDeposit` from its requester, recorded in
+//!   `QuoteDeposits`. The deposit is returned when a policy consumes the quote,
+//!   when the requester cancels or refreshes it, or through
+//!   `release_quote_deposit` once it has expired unused. Governance can slash the
+//!   deposit of an abusive quote that has not been released yet.
+//! - Each account may request at most `MaxQuoteRequestsPerWindow` quotes per
+//!   `QuoteRateLimitWindow` blocks. Every member of a batch or ladder counts; a
+//!   group quote or a refresh counts once.
+//!
+//! Quotes requested before deposits were introduced carry none.
+
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::traits::Saturating;
+
+/// Quote requests of one account in its current rate limit window
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+    Debug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct RequestWindow<BlockNumber> {
+    /// Block the window started at
+    pub started_at: BlockNumber,
+    /// Quotes requested since `started_at`
+    pub requests: u32,
+}
+
+impl<BlockNumber: Copy + PartialOrd + Saturating> RequestWindow<BlockNumber> {
+    /// Count `count` more requests at block `now`, starting a new window if the
+    /// current one is `window` blocks old. Returns false, leaving the window
+    /// unchanged, if that would take it over `max` requests.
+    pub fn try_record(
+        &mut self,
+        now: BlockNumber,
+        window: BlockNumber,
+        max: u32,
+        count: u32,
+    ) -> bool {
+        let mut next = *self;
+        if now >= self.started_at.saturating_add(window) {
+            next = Self { started_at: now, requests: 0 };
+        }
+        match next.requests.checked_add(count) {
+            Some(requests) if requests <= max => {
+                *self = Self { requests, ..next };
+                true
+            },
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_requests_limited_per_window() {
+        let mut window = RequestWindow::<u32>::default();
+
+        // 5 requests per 10 blocks
+        assert!(window.try_record(3, 10, 5, 3));
+        assert!(window.try_record(5, 10, 5, 2));
+        assert!(!window.try_record(8, 10, 5, 1));
+        assert_eq!(window, RequestWindow { started_at: 0, requests: 5 });
+
+        // The first window ends at block 10; the next starts at the request
+        assert!(window.try_record(13, 10, 5, 1));
+        assert_eq!(window, RequestWindow { started_at: 13, requests: 1 });
+    }
+
+    #[test]
+    fn test_rejected_request_leaves_window() {
+        let mut window = RequestWindow { started_at: 20, requests: 4 };
+
+        // A batch larger than the limit is rejected even in a fresh window
+        assert!(!window.try_record(40, 10, 5, 6));
+        assert_eq!(window, RequestWindow { started_at: 20, requests: 4 });
+
+        assert!(!window.try_record(25, 10, 5, u32::MAX));
+        assert!(window.try_record(25, 10, 5, 1));
+        assert_eq!(window, RequestWindow { started_at: 20, requests: 5 });
+    }
+}
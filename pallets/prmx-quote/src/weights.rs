@@ -0,0 +1,404 @@
+//! Placeholder weights for `pallet_prmx_quote`, written by hand: the benchmarks
+//! have not been run, so none of these values is measured.
+//!
+//! Storage reads/writes per extrinsic are counted from the dispatch paths in
+//! `lib.rs`; ref-time bases and per-item slopes are unmeasured guesses. They must
+//! be replaced with weights generated by the benchmarks in `benchmarking.rs`
+//! (see `docs/benchmarking.md`) before production use.
+//!
+//! Components:
+//! - `p`: additional perils on a V2 quote (`MaxQuotePerils`)
+//! - `n`: quotes in a batch or strikes in a ladder (`MaxQuotesPerBatch`)
+//! - `t`: probability tiers in a market override (`MaxProbabilityTiers`)
+//! - `m`: members of a group quote (`MaxGroupMembers`)
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use core::marker::PhantomData;
+use frame_support::{
+    traits::Get,
+    weights::{constants::RocksDbWeight, Weight},
+};
+
+/// Weight functions needed for `pallet_prmx_quote`.
+pub trait WeightInfo {
+    fn request_policy_quote() -> Weight;
+    fn submit_quote() -> Weight;
+    fn submit_quote_from_ocw() -> Weight;
+    fn set_pricing_api_key() -> Weight;
+    fn set_pricing_api_url() -> Weight;
+    fn add_quote_provider() -> Weight;
+    fn remove_quote_provider() -> Weight;
+    fn request_policy_quote_v2(p: u32) -> Weight;
+    fn cancel_quote() -> Weight;
+    fn refresh_quote() -> Weight;
+    fn request_policy_quotes_batch(n: u32) -> Weight;
+    fn submit_quote_batch_from_ocw(n: u32) -> Weight;
+    fn set_market_probability_override(t: u32) -> Weight;
+    fn clear_market_probability_override() -> Weight;
+    fn submit_peril_quote_from_ocw(p: u32) -> Weight;
+    fn set_pricing_evidence_url() -> Weight;
+    fn set_market_bonding_curve() -> Weight;
+    fn clear_market_bonding_curve() -> Weight;
+    fn request_quote_ladder(n: u32) -> Weight;
+    fn request_group_quote(m: u32) -> Weight;
+    fn submit_group_quote_from_ocw(m: u32) -> Weight;
+    fn set_pricing_service_key() -> Weight;
+    fn release_quote_deposit() -> Weight;
+    fn slash_quote_deposit() -> Weight;
+}
+
+/// Weights for `pallet_prmx_quote` using the runtime's database weights.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    /// Reads: PausedOperations, Markets (2), Timestamp::Now, AccountNonce, PendingQuotes,
+    /// QuoteRequestWindows, System::Account
+    /// Writes: AccountNonce, QuoteRequests, QuoteStatuses, PendingQuotes,
+    /// QuoteRequestWindows, System::Account, QuoteDeposits
+    /// On bonding-curve markets, plus QuoteRequests, MarketBondingCurves, Markets (2),
+    /// MarketNotional and the reads/writes of `submit_quote`
+    fn request_policy_quote() -> Weight {
+        Weight::from_parts(80_000_000, 6_500)
+            .saturating_add(T::DbWeight::get().reads(21_u64))
+            .saturating_add(T::DbWeight::get().writes(11_u64))
+    }
+    /// Reads: PausedOperations, QuoteRequests, QuoteStatuses, Markets (2),
+    /// Timestamp::Now, PendingQuotes, QuoteBatchOf
+    /// Writes: QuoteResults, QuoteParamsHashes, QuoteStatuses, PendingQuotes
+    fn submit_quote() -> Weight {
+        Weight::from_parts(40_000_000, 4_200)
+            .saturating_add(T::DbWeight::get().reads(8_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    /// As `submit_quote`, plus QuoteProviders and PricingServiceKey, and an ed25519
+    /// attestation check
+    /// Writes: as `submit_quote`, plus QuotePricingEvidence
+    fn submit_quote_from_ocw() -> Weight {
+        Weight::from_parts(92_000_000, 4_200)
+            .saturating_add(T::DbWeight::get().reads(10_u64))
+            .saturating_add(T::DbWeight::get().writes(5_u64))
+    }
+    /// Offchain index write only
+    fn set_pricing_api_key() -> Weight {
+        Weight::from_parts(8_000_000, 0)
+    }
+    /// Offchain index write only
+    fn set_pricing_api_url() -> Weight {
+        Weight::from_parts(8_000_000, 0)
+    }
+    /// Writes: QuoteProviders
+    fn add_quote_provider() -> Weight {
+        Weight::from_parts(12_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Writes: QuoteProviders
+    fn remove_quote_provider() -> Weight {
+        Weight::from_parts(12_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Reads: PausedOperations, Markets (3), V2EnabledMarkets, V2DurationRules,
+    /// Timestamp::Now, AccountNonce, PendingQuotes, QuoteRequestWindows, System::Account
+    /// Writes: AccountNonce, QuoteRequests, QuoteStatuses, PendingQuotes,
+    /// QuoteRequestWindows, System::Account, QuoteDeposits
+    /// On bonding-curve markets, plus QuoteRequests, MarketBondingCurves, Markets (2),
+    /// MarketNotional and the reads/writes of `submit_quote`
+    fn request_policy_quote_v2(p: u32) -> Weight {
+        Weight::from_parts(87_000_000, 6_700)
+            .saturating_add(Weight::from_parts(1_500_000, 0).saturating_mul(p.into()))
+            .saturating_add(Weight::from_parts(0, 40).saturating_mul(p.into()))
+            .saturating_add(T::DbWeight::get().reads(24_u64))
+            .saturating_add(T::DbWeight::get().writes(11_u64))
+    }
+    /// Reads: QuoteRequests, QuoteStatuses, PendingQuotes, QuoteBatchOf, QuoteDeposits,
+    /// System::Account
+    /// Writes: QuoteStatuses, PendingQuotes, QuoteDeposits, System::Account
+    fn cancel_quote() -> Weight {
+        Weight::from_parts(30_000_000, 4_200)
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    /// Reads: PausedOperations, QuoteRequests, QuoteStatuses, QuoteResults,
+    /// Timestamp::Now, Markets, AccountNonce, PendingQuotes, QuoteDeposits,
+    /// System::Account, QuoteRequestWindows
+    /// Writes: QuoteStatuses (2), AccountNonce, QuoteRequests, PendingQuotes, RefreshedQuotes,
+    /// QuoteDeposits (2), System::Account, QuoteRequestWindows
+    /// Group quotes, plus GroupQuoteMembers read and written
+    /// On bonding-curve markets, plus QuoteRequests, MarketBondingCurves, Markets (2),
+    /// MarketNotional and the reads/writes of `submit_quote`
+    fn refresh_quote() -> Weight {
+        Weight::from_parts(85_000_000, 6_800)
+            .saturating_add(T::DbWeight::get().reads(25_u64))
+            .saturating_add(T::DbWeight::get().writes(15_u64))
+    }
+    /// Reads: PausedOperations, PendingQuotes, Timestamp::Now, NextQuoteBatchId,
+    /// plus Markets (2), AccountNonce, QuoteRequestWindows and System::Account per quote
+    /// Writes: NextQuoteBatchId, QuoteBatches, PendingQuotes, plus AccountNonce,
+    /// QuoteRequests, QuoteStatuses, QuoteBatchOf, QuoteRequestWindows, System::Account
+    /// and QuoteDeposits per quote
+    /// On bonding-curve markets, plus per quote QuoteRequests, MarketBondingCurves,
+    /// Markets (2), MarketNotional, the reads/writes of `submit_quote` and QuoteBatches
+    fn request_policy_quotes_batch(n: u32) -> Weight {
+        Weight::from_parts(30_000_000, 3_900)
+            .saturating_add(Weight::from_parts(60_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().reads((19_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+            .saturating_add(T::DbWeight::get().writes((11_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 5_300).saturating_mul(n.into()))
+    }
+    /// Reads: QuoteProviders, QuoteBatches, PricingServiceKey, PausedOperations,
+    /// Timestamp::Now, PendingQuotes, plus QuoteRequests, QuoteStatuses, Markets (2)
+    /// and QuoteBatchOf and an ed25519 attestation check per quote
+    /// Writes: PendingQuotes, QuoteBatches, plus QuoteResults, QuoteParamsHashes,
+    /// QuoteStatuses and QuotePricingEvidence per quote
+    fn submit_quote_batch_from_ocw(n: u32) -> Weight {
+        Weight::from_parts(35_000_000, 4_500)
+            .saturating_add(Weight::from_parts(80_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+            .saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 2_700).saturating_mul(n.into()))
+    }
+    /// Reads: Markets
+    /// Writes: MarketProbabilityOverrides
+    fn set_market_probability_override(t: u32) -> Weight {
+        Weight::from_parts(20_000_000, 3_700)
+            .saturating_add(Weight::from_parts(200_000, 0).saturating_mul(t.into()))
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Reads: MarketProbabilityOverrides
+    /// Writes: MarketProbabilityOverrides
+    fn clear_market_probability_override() -> Weight {
+        Weight::from_parts(18_000_000, 3_700)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// As `submit_quote_from_ocw` without the attestation check, with one
+    /// probability per additional peril
+    fn submit_peril_quote_from_ocw(p: u32) -> Weight {
+        Weight::from_parts(42_000_000, 4_200)
+            .saturating_add(Weight::from_parts(1_000_000, 0).saturating_mul(p.into()))
+            .saturating_add(Weight::from_parts(0, 40).saturating_mul(p.into()))
+            .saturating_add(T::DbWeight::get().reads(9_u64))
+            .saturating_add(T::DbWeight::get().writes(5_u64))
+    }
+    /// Offchain index write only
+    fn set_pricing_evidence_url() -> Weight {
+        Weight::from_parts(8_000_000, 0)
+    }
+    /// Reads: Markets
+    /// Writes: MarketBondingCurves
+    fn set_market_bonding_curve() -> Weight {
+        Weight::from_parts(20_000_000, 3_700)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Reads: MarketBondingCurves
+    /// Writes: MarketBondingCurves
+    fn clear_market_bonding_curve() -> Weight {
+        Weight::from_parts(18_000_000, 3_600)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Reads: PausedOperations, PendingQuotes, Markets (3), Timestamp::Now,
+    /// NextQuoteBatchId, plus AccountNonce, QuoteRequestWindows and System::Account
+    /// per strike
+    /// Writes: NextQuoteBatchId, QuoteLadders, QuoteBatches, PendingQuotes, plus
+    /// AccountNonce, QuoteRequests, QuoteStatuses, QuoteBatchOf, QuoteRequestWindows,
+    /// System::Account and QuoteDeposits per strike
+    /// On bonding-curve markets, plus per strike QuoteRequests, MarketBondingCurves,
+    /// Markets (2), MarketNotional, the reads/writes of `submit_quote`, QuoteBatches
+    /// and QuoteLadders
+    fn request_quote_ladder(n: u32) -> Weight {
+        Weight::from_parts(40_000_000, 4_500)
+            .saturating_add(Weight::from_parts(60_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(7_u64))
+            .saturating_add(T::DbWeight::get().reads((17_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+            .saturating_add(T::DbWeight::get().writes((11_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 5_300).saturating_mul(n.into()))
+    }
+    /// Reads: PausedOperations, Markets (3), Timestamp::Now, AccountNonce,
+    /// PendingQuotes, QuoteRequestWindows, System::Account, plus V2EnabledMarkets,
+    /// V2DurationRules and Markets per member
+    /// Writes: AccountNonce, QuoteRequests, QuoteStatuses, PendingQuotes,
+    /// GroupQuoteMembers, QuoteRequestWindows, System::Account, QuoteDeposits
+    fn request_group_quote(m: u32) -> Weight {
+        Weight::from_parts(60_000_000, 4_800)
+            .saturating_add(Weight::from_parts(4_000_000, 0).saturating_mul(m.into()))
+            .saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+            .saturating_add(T::DbWeight::get().reads(9_u64))
+            .saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(m.into())))
+            .saturating_add(T::DbWeight::get().writes(8_u64))
+    }
+    /// As `submit_quote_from_ocw` without the attestation check, plus
+    /// GroupQuoteMembers read and GroupQuotePricing written, with one probability
+    /// per member
+    fn submit_group_quote_from_ocw(m: u32) -> Weight {
+        Weight::from_parts(45_000_000, 4_500)
+            .saturating_add(Weight::from_parts(500_000, 0).saturating_mul(m.into()))
+            .saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+            .saturating_add(T::DbWeight::get().reads(10_u64))
+            .saturating_add(T::DbWeight::get().writes(6_u64))
+    }
+    /// Writes: PricingServiceKey
+    fn set_pricing_service_key() -> Weight {
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Reads: QuoteDeposits, QuoteStatuses, QuoteResults, Timestamp::Now, QuoteRequests,
+    /// System::Account
+    /// Writes: QuoteStatuses, QuoteDeposits, System::Account
+    fn release_quote_deposit() -> Weight {
+        Weight::from_parts(30_000_000, 3_900)
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    /// Reads: QuoteRequests, QuoteDeposits, System::Account, Balances::TotalIssuance
+    /// Writes: QuoteDeposits, System::Account, Balances::TotalIssuance
+    fn slash_quote_deposit() -> Weight {
+        Weight::from_parts(32_000_000, 3_900)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+    fn request_policy_quote() -> Weight {
+        Weight::from_parts(80_000_000, 6_500)
+            .saturating_add(RocksDbWeight::get().reads(21_u64))
+            .saturating_add(RocksDbWeight::get().writes(11_u64))
+    }
+    fn submit_quote() -> Weight {
+        Weight::from_parts(40_000_000, 4_200)
+            .saturating_add(RocksDbWeight::get().reads(8_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+    fn submit_quote_from_ocw() -> Weight {
+        Weight::from_parts(92_000_000, 4_200)
+            .saturating_add(RocksDbWeight::get().reads(10_u64))
+            .saturating_add(RocksDbWeight::get().writes(5_u64))
+    }
+    fn set_pricing_api_key() -> Weight {
+        Weight::from_parts(8_000_000, 0)
+    }
+    fn set_pricing_api_url() -> Weight {
+        Weight::from_parts(8_000_000, 0)
+    }
+    fn add_quote_provider() -> Weight {
+        Weight::from_parts(12_000_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn remove_quote_provider() -> Weight {
+        Weight::from_parts(12_000_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn request_policy_quote_v2(p: u32) -> Weight {
+        Weight::from_parts(87_000_000, 6_700)
+            .saturating_add(Weight::from_parts(1_500_000, 0).saturating_mul(p.into()))
+            .saturating_add(Weight::from_parts(0, 40).saturating_mul(p.into()))
+            .saturating_add(RocksDbWeight::get().reads(24_u64))
+            .saturating_add(RocksDbWeight::get().writes(11_u64))
+    }
+    fn cancel_quote() -> Weight {
+        Weight::from_parts(30_000_000, 4_200)
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+    fn refresh_quote() -> Weight {
+        Weight::from_parts(85_000_000, 6_800)
+            .saturating_add(RocksDbWeight::get().reads(25_u64))
+            .saturating_add(RocksDbWeight::get().writes(15_u64))
+    }
+    fn request_policy_quotes_batch(n: u32) -> Weight {
+        Weight::from_parts(30_000_000, 3_900)
+            .saturating_add(Weight::from_parts(60_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().reads((19_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+            .saturating_add(RocksDbWeight::get().writes((11_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 5_300).saturating_mul(n.into()))
+    }
+    fn submit_quote_batch_from_ocw(n: u32) -> Weight {
+        Weight::from_parts(35_000_000, 4_500)
+            .saturating_add(Weight::from_parts(80_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+            .saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 2_700).saturating_mul(n.into()))
+    }
+    fn set_market_probability_override(t: u32) -> Weight {
+        Weight::from_parts(20_000_000, 3_700)
+            .saturating_add(Weight::from_parts(200_000, 0).saturating_mul(t.into()))
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn clear_market_probability_override() -> Weight {
+        Weight::from_parts(18_000_000, 3_700)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn submit_peril_quote_from_ocw(p: u32) -> Weight {
+        Weight::from_parts(42_000_000, 4_200)
+            .saturating_add(Weight::from_parts(1_000_000, 0).saturating_mul(p.into()))
+            .saturating_add(Weight::from_parts(0, 40).saturating_mul(p.into()))
+            .saturating_add(RocksDbWeight::get().reads(9_u64))
+            .saturating_add(RocksDbWeight::get().writes(5_u64))
+    }
+    fn set_pricing_evidence_url() -> Weight {
+        Weight::from_parts(8_000_000, 0)
+    }
+    fn set_market_bonding_curve() -> Weight {
+        Weight::from_parts(20_000_000, 3_700)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn clear_market_bonding_curve() -> Weight {
+        Weight::from_parts(18_000_000, 3_600)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn request_quote_ladder(n: u32) -> Weight {
+        Weight::from_parts(40_000_000, 4_500)
+            .saturating_add(Weight::from_parts(60_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(7_u64))
+            .saturating_add(RocksDbWeight::get().reads((17_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+            .saturating_add(RocksDbWeight::get().writes((11_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 5_300).saturating_mul(n.into()))
+    }
+    fn request_group_quote(m: u32) -> Weight {
+        Weight::from_parts(60_000_000, 4_800)
+            .saturating_add(Weight::from_parts(4_000_000, 0).saturating_mul(m.into()))
+            .saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+            .saturating_add(RocksDbWeight::get().reads(9_u64))
+            .saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(m.into())))
+            .saturating_add(RocksDbWeight::get().writes(8_u64))
+    }
+    fn submit_group_quote_from_ocw(m: u32) -> Weight {
+        Weight::from_parts(45_000_000, 4_500)
+            .saturating_add(Weight::from_parts(500_000, 0).saturating_mul(m.into()))
+            .saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+            .saturating_add(RocksDbWeight::get().reads(10_u64))
+            .saturating_add(RocksDbWeight::get().writes(6_u64))
+    }
+    fn set_pricing_service_key() -> Weight {
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn release_quote_deposit() -> Weight {
+        Weight::from_parts(30_000_000, 3_900)
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    fn slash_quote_deposit() -> Weight {
+        Weight::from_parts(32_000_000, 3_900)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+}
@@ -0,0 +1,443 @@
+//! # PRMX Reinsurance Pallet
+//!
+//! A capital pool funded by external reinsurers that takes a share of each
+//! policy's exposure off the DAO.
+//!
+//! ## Overview
+//!
+//! - Reinsurers `deposit_capital` into the pool and receive pool shares; they can
+//!   `withdraw_capital` as long as the pool still covers all ceded exposure.
+//! - Governance sets the cession rate. At policy creation the DAO cedes that share
+//!   of `max_payout` to the pool and pays the same share of the premium into it.
+//! - When a policy triggers, the pool pays its share of the payout into the policy
+//!   pool before the holder is paid. Otherwise the exposure is released and the
+//!   ceded premium stays with reinsurers.
+//! - The pool holds USDT, so only USDT-denominated policies are ceded; policies
+//!   of markets in other assets stay fully with the DAO.
+//!
+//! The policy pallet drives this through `pallet_prmx_policy::ReinsuranceApi`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod shares;
+
+pub use pallet::*;
+
+use frame_support::traits::fungibles::{Inspect, Mutate};
+use frame_support::traits::tokens::Preservation;
+use frame_support::traits::Get;
+use sp_runtime::traits::{AccountIdConversion, Zero};
+use sp_runtime::DispatchError;
+use prmx_primitives::PolicyAssetApi;
+
+pub use pallet_prmx_policy::{PolicyId, ReinsuranceApi};
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::pallet_prelude::*;
+    use frame_system::pallet_prelude::*;
+    use pallet_prmx_markets::PartsPerMillion;
+
+    // =========================================================================
+    //                                  Types
+    // =========================================================================
+
+    /// Exposure ceded for one policy
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct PolicyCession<T: Config> {
+        /// Policy max payout at creation
+        pub max_payout: T::Balance,
+        /// Part of max payout carried by the reinsurance pool
+        pub ceded_exposure: T::Balance,
+        /// Premium the DAO paid into the pool for this cession
+        pub ceded_premium: T::Balance,
+    }
+
+    // =========================================================================
+    //                                Constants
+    // =========================================================================
+
+    /// Pallet ID for deriving the reinsurance pool account
+    pub const PALLET_ID: frame_support::PalletId = frame_support::PalletId(*b"prmxrein");
+
+    // =========================================================================
+    //                                  Config
+    // =========================================================================
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Balance type
+        type Balance: Parameter + Member + From<u128> + Into<u128> + Copy + Default + MaxEncodedLen + Zero + Ord;
+
+        /// Asset ID type
+        type AssetId: Parameter + Member + Copy + Default + MaxEncodedLen;
+
+        /// Fungibles implementation for USDT transfers
+        type Assets: Mutate<Self::AccountId, AssetId = Self::AssetId, Balance = Self::Balance>
+            + Inspect<Self::AccountId>;
+
+        /// USDT asset ID
+        #[pallet::constant]
+        type UsdtAssetId: Get<Self::AssetId>;
+
+        /// DAO account that pays ceded premiums
+        #[pallet::constant]
+        type DaoAccountId: Get<Self::AccountId>;
+
+        /// Denomination asset of each policy; only USDT policies are ceded
+        type PolicyAssets: PolicyAssetApi<Self::AssetId>;
+
+        /// Origin that can set the cession rate
+        type GovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+    }
+
+    // =========================================================================
+    //                                  Storage
+    // =========================================================================
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// Share of each new policy's max payout ceded to the pool (ppm, 0 = disabled)
+    #[pallet::storage]
+    #[pallet::getter(fn cession_rate_ppm)]
+    pub type CessionRatePpm<T: Config> = StorageValue<_, PartsPerMillion, ValueQuery>;
+
+    /// Pool shares per reinsurer
+    #[pallet::storage]
+    #[pallet::getter(fn reinsurer_shares)]
+    pub type ReinsurerShares<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u128, ValueQuery>;
+
+    /// Total pool shares outstanding
+    #[pallet::storage]
+    #[pallet::getter(fn total_shares)]
+    pub type TotalShares<T: Config> = StorageValue<_, u128, ValueQuery>;
+
+    /// Ceded exposure per policy
+    #[pallet::storage]
+    #[pallet::getter(fn policy_cessions)]
+    pub type PolicyCessions<T: Config> =
+        StorageMap<_, Blake2_128Concat, PolicyId, PolicyCession<T>, OptionQuery>;
+
+    /// Sum of exposure ceded for open policies; this much pool capital is locked
+    #[pallet::storage]
+    #[pallet::getter(fn total_ceded_exposure)]
+    pub type TotalCededExposure<T: Config> = StorageValue<_, T::Balance, ValueQuery>;
+
+    // =========================================================================
+    //                                  Events
+    // =========================================================================
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Reinsurer deposited capital. [who, amount, shares]
+        CapitalDeposited {
+            who: T::AccountId,
+            amount: T::Balance,
+            shares: u128,
+        },
+        /// Reinsurer withdrew capital. [who, amount, shares]
+        CapitalWithdrawn {
+            who: T::AccountId,
+            amount: T::Balance,
+            shares: u128,
+        },
+        /// Cession rate updated. [rate_ppm]
+        CessionRateSet {
+            rate_ppm: PartsPerMillion,
+        },
+        /// Exposure ceded for a new policy. [policy_id, ceded_exposure, ceded_premium]
+        ExposureCeded {
+            policy_id: PolicyId,
+            ceded_exposure: T::Balance,
+            ceded_premium: T::Balance,
+        },
+        /// Pool paid its share of a triggered policy. [policy_id, amount]
+        ReinsurancePaid {
+            policy_id: PolicyId,
+            amount: T::Balance,
+        },
+        /// Exposure released after settlement or cancellation. [policy_id, released]
+        ExposureReleased {
+            policy_id: PolicyId,
+            released: T::Balance,
+        },
+    }
+
+    // =========================================================================
+    //                                  Errors
+    // =========================================================================
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Amount must be greater than zero.
+        ZeroAmount,
+        /// Reinsurer does not hold that many shares.
+        InsufficientShares,
+        /// Withdrawal would leave ceded exposure uncovered.
+        CapitalLocked,
+        /// Cession rate cannot exceed 1,000,000 ppm.
+        InvalidCessionRate,
+        /// Pool does not have free capital for this cession.
+        InsufficientCapacity,
+        /// USDT transfer failed.
+        TransferFailed,
+    }
+
+    // =========================================================================
+    //                                Extrinsics
+    // =========================================================================
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Deposit USDT into the reinsurance pool in exchange for pool shares.
+        ///
+        /// - `amount`: USDT to deposit.
+        #[pallet::call_index(0)]
+        #[pallet::weight(50_000)]
+        pub fn deposit_capital(
+            origin: OriginFor<T>,
+            amount: T::Balance,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(amount > T::Balance::zero(), Error::<T>::ZeroAmount);
+
+            let shares = shares::shares_for_deposit(
+                amount.into(),
+                TotalShares::<T>::get(),
+                Self::pool_balance().into(),
+            );
+
+            T::Assets::transfer(
+                T::UsdtAssetId::get(),
+                &who,
+                &Self::pool_account(),
+                amount,
+                Preservation::Preserve,
+            ).map_err(|_| Error::<T>::TransferFailed)?;
+
+            ReinsurerShares::<T>::mutate(&who, |s| *s = s.saturating_add(shares));
+            TotalShares::<T>::mutate(|t| *t = t.saturating_add(shares));
+
+            Self::deposit_event(Event::CapitalDeposited { who, amount, shares });
+
+            Ok(())
+        }
+
+        /// Redeem pool shares for USDT. Only capital not backing ceded exposure
+        /// can be withdrawn.
+        ///
+        /// - `shares`: Pool shares to redeem.
+        #[pallet::call_index(1)]
+        #[pallet::weight(50_000)]
+        pub fn withdraw_capital(
+            origin: OriginFor<T>,
+            shares: u128,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(shares > 0, Error::<T>::ZeroAmount);
+            ensure!(
+                ReinsurerShares::<T>::get(&who) >= shares,
+                Error::<T>::InsufficientShares
+            );
+
+            let amount_u128 = shares::amount_for_shares(
+                shares,
+                TotalShares::<T>::get(),
+                Self::pool_balance().into(),
+            );
+            ensure!(
+                amount_u128 <= Self::free_capital().into(),
+                Error::<T>::CapitalLocked
+            );
+            let amount: T::Balance = amount_u128.into();
+
+            T::Assets::transfer(
+                T::UsdtAssetId::get(),
+                &Self::pool_account(),
+                &who,
+                amount,
+                Preservation::Expendable,
+            ).map_err(|_| Error::<T>::TransferFailed)?;
+
+            ReinsurerShares::<T>::mutate(&who, |s| *s = s.saturating_sub(shares));
+            TotalShares::<T>::mutate(|t| *t = t.saturating_sub(shares));
+
+            Self::deposit_event(Event::CapitalWithdrawn { who, amount, shares });
+
+            Ok(())
+        }
+
+        /// Set the share of each new policy's exposure ceded to the pool.
+        ///
+        /// Only callable by GovernanceOrigin. Existing cessions are unaffected.
+        ///
+        /// - `rate_ppm`: Cession rate in ppm (0 disables reinsurance).
+        #[pallet::call_index(2)]
+        #[pallet::weight(10_000)]
+        pub fn set_cession_rate(
+            origin: OriginFor<T>,
+            rate_ppm: PartsPerMillion,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+            ensure!(rate_ppm <= 1_000_000, Error::<T>::InvalidCessionRate);
+
+            CessionRatePpm::<T>::put(rate_ppm);
+
+            Self::deposit_event(Event::CessionRateSet { rate_ppm });
+
+            Ok(())
+        }
+    }
+
+    // =========================================================================
+    //                           Helper Functions
+    // =========================================================================
+
+    impl<T: Config> Pallet<T> {
+        /// Account holding reinsurer capital
+        pub fn pool_account() -> T::AccountId {
+            PALLET_ID.into_account_truncating()
+        }
+
+        /// USDT held by the pool
+        pub fn pool_balance() -> T::Balance {
+            T::Assets::balance(T::UsdtAssetId::get(), &Self::pool_account())
+        }
+
+        /// Pool capital not backing ceded exposure
+        pub fn free_capital() -> T::Balance {
+            let balance: u128 = Self::pool_balance().into();
+            let locked: u128 = TotalCededExposure::<T>::get().into();
+            balance.saturating_sub(locked).into()
+        }
+
+        fn release_exposure(amount: T::Balance) {
+            TotalCededExposure::<T>::mutate(|total| {
+                let remaining: u128 = (*total).into();
+                *total = remaining.saturating_sub(amount.into()).into();
+            });
+        }
+    }
+}
+
+// =============================================================================
+//                       ReinsuranceApi Implementation
+// =============================================================================
+
+impl<T: Config> ReinsuranceApi<T::AccountId> for Pallet<T> {
+    type Balance = T::Balance;
+
+    fn on_policy_created(
+        policy_id: PolicyId,
+        premium: T::Balance,
+        max_payout: T::Balance,
+    ) -> Result<T::Balance, DispatchError> {
+        // The pool's capital is USDT; it cannot back exposure in another asset
+        let asset = T::PolicyAssets::policy_asset(policy_id).unwrap_or_else(T::UsdtAssetId::get);
+        if asset != T::UsdtAssetId::get() {
+            return Ok(T::Balance::zero());
+        }
+
+        let rate = CessionRatePpm::<T>::get();
+        let ceded_exposure: T::Balance = shares::ceded_amount(max_payout.into(), rate).into();
+        if ceded_exposure.is_zero() {
+            return Ok(T::Balance::zero());
+        }
+
+        if Pallet::<T>::free_capital() < ceded_exposure {
+            return Err(Error::<T>::InsufficientCapacity.into());
+        }
+
+        // The DAO buys the cover with the same share of the premium
+        let ceded_premium: T::Balance = shares::ceded_amount(premium.into(), rate).into();
+        if ceded_premium > T::Balance::zero() {
+            T::Assets::transfer(
+                T::UsdtAssetId::get(),
+                &T::DaoAccountId::get(),
+                &Pallet::<T>::pool_account(),
+                ceded_premium,
+                Preservation::Expendable,
+            ).map_err(|_| Error::<T>::TransferFailed)?;
+        }
+
+        TotalCededExposure::<T>::mutate(|total| {
+            let current: u128 = (*total).into();
+            *total = current.saturating_add(ceded_exposure.into()).into();
+        });
+        PolicyCessions::<T>::insert(policy_id, PolicyCession {
+            max_payout,
+            ceded_exposure,
+            ceded_premium,
+        });
+
+        log::info!(
+            target: "prmx-reinsurance",
+            "🛡️ Ceded {} of policy {} exposure (premium {})",
+            ceded_exposure.into(),
+            policy_id,
+            ceded_premium.into()
+        );
+
+        Pallet::<T>::deposit_event(Event::ExposureCeded {
+            policy_id,
+            ceded_exposure,
+            ceded_premium,
+        });
+
+        Ok(ceded_exposure)
+    }
+
+    fn on_policy_payout(
+        policy_id: PolicyId,
+        pool_account: &T::AccountId,
+        payout: T::Balance,
+    ) -> Result<T::Balance, DispatchError> {
+        let Some(cession) = PolicyCessions::<T>::take(policy_id) else {
+            return Ok(T::Balance::zero());
+        };
+
+        let amount: T::Balance = shares::reinsured_payout(
+            payout.into(),
+            cession.ceded_exposure.into(),
+            cession.max_payout.into(),
+        ).into();
+
+        if amount > T::Balance::zero() {
+            T::Assets::transfer(
+                T::UsdtAssetId::get(),
+                &Pallet::<T>::pool_account(),
+                pool_account,
+                amount,
+                Preservation::Expendable,
+            ).map_err(|_| Error::<T>::TransferFailed)?;
+        }
+
+        Pallet::<T>::release_exposure(cession.ceded_exposure);
+
+        Pallet::<T>::deposit_event(Event::ReinsurancePaid { policy_id, amount });
+
+        Ok(amount)
+    }
+
+    fn on_policy_closed(policy_id: PolicyId) {
+        // Already released if the pool paid out
+        if let Some(cession) = PolicyCessions::<T>::take(policy_id) {
+            Pallet::<T>::release_exposure(cession.ceded_exposure);
+
+            Pallet::<T>::deposit_event(Event::ExposureReleased {
+                policy_id,
+                released: cession.ceded_exposure,
+            });
+        }
+    }
+}
+
@@ -0,0 +1,69 @@
+//! # Reinsurance Pool Accounting
+//!
+//! Reinsurers own the pool pro-rata through shares. Deposits mint shares at the
+//! current pool value, so premiums earned and payouts made are shared by every
+//! reinsurer in proportion to their stake.
+
+use pallet_prmx_markets::PartsPerMillion;
+
+/// One in parts per million
+const PPM: u128 = 1_000_000;
+
+/// Share of `amount` at `ppm`
+pub fn ceded_amount(amount: u128, ppm: PartsPerMillion) -> u128 {
+    amount.saturating_mul(ppm as u128) / PPM
+}
+
+/// Shares minted for a deposit of `amount` into a pool worth `pool_value`
+pub fn shares_for_deposit(amount: u128, total_shares: u128, pool_value: u128) -> u128 {
+    if total_shares == 0 || pool_value == 0 {
+        return amount;
+    }
+    amount.saturating_mul(total_shares) / pool_value
+}
+
+/// Pool value redeemed by `shares`
+pub fn amount_for_shares(shares: u128, total_shares: u128, pool_value: u128) -> u128 {
+    if total_shares == 0 {
+        return 0;
+    }
+    shares.saturating_mul(pool_value) / total_shares
+}
+
+/// Reinsurer's part of a payout: the ceded share of `payout`, never more than the
+/// exposure ceded for the policy
+pub fn reinsured_payout(payout: u128, ceded_exposure: u128, max_payout: u128) -> u128 {
+    if max_payout == 0 {
+        return 0;
+    }
+    (payout.saturating_mul(ceded_exposure) / max_payout).min(ceded_exposure)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shares_track_pool_value() {
+        // First deposit mints 1:1
+        assert_eq!(shares_for_deposit(1_000, 0, 0), 1_000);
+
+        // Pool earned premiums and is now worth 1_200 for 1_000 shares
+        assert_eq!(shares_for_deposit(600, 1_000, 1_200), 500);
+        assert_eq!(amount_for_shares(500, 1_500, 1_800), 600);
+
+        assert_eq!(amount_for_shares(10, 0, 1_000), 0);
+    }
+
+    #[test]
+    fn test_cession_and_payout() {
+        // 30% of a 100 USDT max payout
+        assert_eq!(ceded_amount(100_000_000, 300_000), 30_000_000);
+
+        // Full payout recovers the whole ceded exposure; a 30% tier recovers 30% of it
+        assert_eq!(reinsured_payout(100_000_000, 30_000_000, 100_000_000), 30_000_000);
+        assert_eq!(reinsured_payout(30_000_000, 30_000_000, 100_000_000), 9_000_000);
+        assert_eq!(reinsured_payout(200_000_000, 30_000_000, 100_000_000), 30_000_000);
+        assert_eq!(reinsured_payout(1, 1, 0), 0);
+    }
+}
@@ -0,0 +1,147 @@
+//! # Capital Shortfall Backstop
+//!
+//! A protocol-level USDT pool that covers settlement shortfalls before the DAO.
+//!
+//! - Funding: when a policy is created the DAO pays `premium_share_ppm` of its
+//!   premium (after the protocol fee) into the backstop account, the same way it
+//!   pays the reinsurance pool its share. Anyone can add to it with
+//!   `replenish_backstop`.
+//! - Draws: when unwinding a policy's DeFi position realises less than settlement
+//!   needs, the backstop pays the shortfall, at most `max_draw_ppm` of its balance
+//!   per policy. The DAO covers what remains and LP holders absorb what the DAO
+//!   cannot.
+//! - `BackstopTotals` keeps running totals of contributions, replenishments and
+//!   draws; `PolicyBackstopDraws` records what each policy drew.
+//!
+//! Both shares start at zero, so the backstop is neither funded nor drawn until
+//! governance configures it with `set_backstop_config`.
+
+use sp_runtime::traits::Saturating;
+
+/// One in parts per million
+const PPM: u128 = 1_000_000;
+
+/// Largest share of premiums governance can direct to the backstop (20%)
+pub const MAX_BACKSTOP_PREMIUM_SHARE_PPM: u32 = 200_000;
+
+/// Governance-set funding and utilization of the backstop, in parts per million
+#[derive(
+    codec::Encode,
+    codec::Decode,
+    codec::DecodeWithMemTracking,
+    codec::MaxEncodedLen,
+    scale_info::TypeInfo,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+    Debug,
+)]
+pub struct BackstopConfig {
+    /// Share of each new policy's premium paid into the backstop
+    pub premium_share_ppm: u32,
+    /// Largest share of the backstop balance one policy's shortfall may draw
+    pub max_draw_ppm: u32,
+}
+
+impl BackstopConfig {
+    /// Premium share at most `MAX_BACKSTOP_PREMIUM_SHARE_PPM`, draw cap at most 100%
+    pub fn is_valid(&self) -> bool {
+        self.premium_share_ppm <= MAX_BACKSTOP_PREMIUM_SHARE_PPM
+            && self.max_draw_ppm as u128 <= PPM
+    }
+
+    /// Backstop contribution from `premium`, rounded down
+    pub fn contribution(&self, premium: u128) -> u128 {
+        premium.saturating_mul(self.premium_share_ppm as u128) / PPM
+    }
+
+    /// Amount drawn for `shortfall` from a backstop holding `balance`
+    pub fn draw(&self, shortfall: u128, balance: u128) -> u128 {
+        let cap = balance.saturating_mul(self.max_draw_ppm as u128) / PPM;
+        shortfall.min(cap)
+    }
+}
+
+/// Running totals of the backstop's inflows and draws
+#[derive(
+    codec::Encode,
+    codec::Decode,
+    codec::DecodeWithMemTracking,
+    codec::MaxEncodedLen,
+    scale_info::TypeInfo,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+    Debug,
+)]
+pub struct BackstopTotals<Balance> {
+    /// Premium shares paid in by the DAO
+    pub contributed: Balance,
+    /// Paid in through `replenish_backstop`
+    pub replenished: Balance,
+    /// Paid out to cover shortfalls
+    pub drawn: Balance,
+    /// Shortfalls the backstop paid towards
+    pub draws: u32,
+}
+
+impl<Balance: Saturating + Copy> BackstopTotals<Balance> {
+    pub fn record_contribution(&mut self, amount: Balance) {
+        self.contributed = self.contributed.saturating_add(amount);
+    }
+
+    pub fn record_replenishment(&mut self, amount: Balance) {
+        self.replenished = self.replenished.saturating_add(amount);
+    }
+
+    pub fn record_draw(&mut self, amount: Balance) {
+        self.drawn = self.drawn.saturating_add(amount);
+        self.draws = self.draws.saturating_add(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_neither_funds_nor_draws() {
+        let config = BackstopConfig::default();
+        assert!(config.is_valid());
+        assert_eq!(config.contribution(10_000_000), 0);
+        assert_eq!(config.draw(5_000_000, 100_000_000), 0);
+    }
+
+    #[test]
+    fn test_contribution_and_capped_draw() {
+        // 2% of premiums, at most a quarter of the balance per policy
+        let config = BackstopConfig { premium_share_ppm: 20_000, max_draw_ppm: 250_000 };
+        assert!(config.is_valid());
+        assert_eq!(config.contribution(10_000_000), 200_000);
+        assert_eq!(config.contribution(49), 0);
+
+        assert_eq!(config.draw(5_000_000, 100_000_000), 5_000_000);
+        assert_eq!(config.draw(40_000_000, 100_000_000), 25_000_000);
+        assert_eq!(config.draw(5_000_000, 0), 0);
+
+        assert!(!BackstopConfig { premium_share_ppm: 200_001, max_draw_ppm: 0 }.is_valid());
+        assert!(!BackstopConfig { premium_share_ppm: 0, max_draw_ppm: 1_000_001 }.is_valid());
+    }
+
+    #[test]
+    fn test_totals() {
+        let mut totals = BackstopTotals::<u128>::default();
+        totals.record_contribution(200);
+        totals.record_replenishment(1_000);
+        totals.record_draw(300);
+        totals.record_draw(u128::MAX);
+        assert_eq!(
+            totals,
+            BackstopTotals { contributed: 200, replenished: 1_000, drawn: u128::MAX, draws: 2 }
+        );
+    }
+}
@@ -4,11 +4,20 @@
 //!
 //! ## Overview
 //!
-//! In v1, all LP positions are held under a single DAO account on Hydration (Pool 102: USDT/USDC).
+//! All LP positions are held under a single DAO account on the strategies' chains.
 //! Per-policy allocation is tracked only in PRMX storage. DeFi profit or loss is
 //! fully borne by the DAO. Policy holders and LPs see only the deterministic insurance logic.
 //!
-//! ## Target: Hydration Stableswap Pool 102
+//! ## Strategy Registry
+//!
+//! Governance registers yield strategies (stableswap pool, target parachain,
+//! maximum share of allocated capital) with `register_strategy` and adjusts or
+//! retires them with `update_strategy`. Each policy is assigned a strategy when
+//! it is created (see `strategy`), and `rebalance_policies` moves invested
+//! positions between strategies. `StrategyAccounts` tracks principal and LP
+//! shares per strategy.
+//!
+//! Every chain starts with one strategy, Hydration Stableswap Pool 102:
 //!
 //! - Pool ID: 102
 //! - Assets: USDT (ID 10) + USDC (ID 22)
@@ -27,6 +36,26 @@
 //!
 //! By default, `MockXcmStrategyInterface` is used which simulates DeFi operations locally.
 //!
+//! ## Cross-Chain Payouts
+//!
+//! Policy holders can `set_payout_destination` to have a triggered payout delivered
+//! to an account on another parachain, such as Asset Hub, over the same XCM route.
+//! Failed deliveries are queued for retry and can be claimed locally with
+//! `claim_failed_payout` (see `payouts`).
+//!
+//! ## Shortfall Backstop
+//!
+//! A slice of each policy's premium funds a backstop pool. When unwinding a
+//! position realises less than settlement needs, the backstop pays the shortfall
+//! within its governance-set utilization cap before the DAO is asked (see
+//! `backstop`).
+//!
+//! ## Policy Denomination
+//!
+//! Strategies and the XCM payout route carry USDT only. Policies of markets
+//! denominated in another asset keep their capital in the policy pool, are
+//! never allocated, and are always paid out locally.
+//!
 //! **EXPERIMENTAL**: To enable real XCM cross-chain operations, enable the `live-xcm` feature.
 //! This requires HRMP channels to Asset Hub and Hydration to be properly configured.
 //! ```toml
@@ -39,6 +68,24 @@ extern crate alloc;
 
 pub use pallet::*;
 
+// Split of surplus DeFi yield between DAO, LPs and policy holder.
+pub mod yield_split;
+pub use yield_split::YieldSplit;
+
+// Governance-registered yield strategies and how new capital is placed among them.
+pub mod strategy;
+pub use strategy::{CapitalStrategy, StrategyId};
+
+// Delivery of settlement payouts to beneficiaries on other chains.
+pub mod payouts;
+pub use payouts::PayoutDestination;
+
+// Protocol-level pool that covers settlement shortfalls before the DAO.
+pub mod backstop;
+pub use backstop::{BackstopConfig, BackstopTotals};
+
+pub mod migrations;
+
 // XCM configuration constants for Hydration Pool 102 integration.
 // These are always available for reference, even when live-xcm is disabled.
 pub mod xcm_config;
@@ -51,13 +98,15 @@ pub mod xcm_strategy;
 pub use xcm_strategy::LiveXcmStrategyInterface;
 
 use frame_support::traits::fungibles::{Inspect, Mutate};
-use frame_support::traits::tokens::Preservation;
+use frame_support::traits::tokens::{Fortitude, Precision, Preservation};
 use frame_support::traits::Get;
 use sp_runtime::DispatchError;
 use sp_runtime::traits::Zero;
 
 // Import traits from policy pallet
-pub use pallet_prmx_policy::{CapitalApi, PolicyPoolAccountApi, PolicyId};
+pub use pallet_prmx_policy::{
+    CapitalApi, PayoutRouter, PolicyAssetApi, PolicyPoolAccountApi, PolicyId,
+};
 // Import holdings API for LP ownership checks
 pub use pallet_prmx_holdings::HoldingsApi;
 
@@ -93,33 +142,51 @@ pub const USDC_ASSET_HUB_ID: u128 = 1337;
 //                              Traits
 // =============================================================================
 
-/// Trait for interacting with the XCM-based DeFi strategy on Hydration.
+/// Trait for interacting with XCM-based DeFi strategies.
 ///
-/// This trait encapsulates all XCM and Hydration Stableswap logic. In v1,
-/// we use a mock implementation. In future versions, this will use
-/// XCM Transact to call Hydration's stableswap.add_liquidity/remove_liquidity.
+/// This trait encapsulates all XCM and stableswap logic for the registered
+/// strategies, and the delivery of settlement payouts to other chains. In v1, we use a mock implementation. In future versions, this will
+/// use XCM Transact to call the target chain's stableswap.add_liquidity/remove_liquidity.
 pub trait XcmStrategyInterface {
     type Balance;
     type AccountId;
 
-    /// Enter the DeFi strategy with the given USDT principal.
+    /// Enter `strategy` with the given USDT principal.
     ///
     /// Implementation responsibilities:
-    /// - Move principal USDT from PRMX via Asset Hub into Hydration DAO account
-    /// - Call stableswap.add_liquidity on Pool 102
+    /// - Move principal USDT from PRMX via Asset Hub into the DAO account on the
+    ///   strategy's chain
+    /// - Call stableswap.add_liquidity on the strategy's pool
     /// - Return the number of LP share units minted for this principal
-    fn enter_strategy(principal: Self::Balance) -> Result<u128, DispatchError>;
+    fn enter_strategy(
+        strategy: &CapitalStrategy,
+        principal: Self::Balance,
+    ) -> Result<u128, DispatchError>;
 
-    /// Exit the DeFi strategy by redeeming the given number of LP share units.
+    /// Exit `strategy` by redeeming the given number of LP share units.
     ///
     /// Implementation responsibilities:
-    /// - Call stableswap.remove_liquidity_one_asset on Pool 102
-    /// - Move all resulting USDT back to PRMX via Asset Hub into the policy pool account
+    /// - Call stableswap.remove_liquidity_one_asset on the strategy's pool
+    /// - Move all resulting USDT back to PRMX via Asset Hub into `destination`
+    ///   (the policy pool account, or the DAO account when rebalancing)
     /// - Return the actual USDT amount realised for these shares
     fn exit_strategy(
+        strategy: &CapitalStrategy,
         shares: u128,
-        policy_pool_account: &Self::AccountId,
+        destination: &Self::AccountId,
     ) -> Result<Self::Balance, DispatchError>;
+
+    /// Deliver a settlement payout held by `source` to a beneficiary on another chain.
+    ///
+    /// Implementation responsibilities:
+    /// - Withdraw `amount` USDT from `source` and move it via Asset Hub to
+    ///   `destination.account` on `destination.para_id`
+    /// - Fail without moving funds if the transfer cannot be sent
+    fn deliver_payout(
+        destination: &PayoutDestination,
+        amount: Self::Balance,
+        source: &Self::AccountId,
+    ) -> Result<(), DispatchError>;
 }
 
 #[frame_support::pallet]
@@ -127,8 +194,9 @@ pub mod pallet {
     use super::*;
     use alloc::vec::Vec;
     use frame_support::pallet_prelude::*;
+    use frame_support::PalletId;
     use frame_system::pallet_prelude::*;
-    use sp_runtime::traits::{Saturating, Zero};
+    use sp_runtime::traits::{AccountIdConversion, Saturating, Zero};
 
     // =========================================================================
     //                                  Types
@@ -150,7 +218,7 @@ pub mod pallet {
         Failed,
     }
 
-    /// LP position information for a policy (Hydration Stableswap Pool 102)
+    /// LP position information for a policy, held in the strategy in `PolicyStrategy`
     #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
     #[scale_info(skip_type_params(T))]
     pub struct PolicyLpPosition<T: Config> {
@@ -162,6 +230,85 @@ pub mod pallet {
         pub principal_usdt: T::Balance,
     }
 
+    /// Per-policy DeFi accounting: principal invested and what unwinding realised
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct PolicyYieldRecord<T: Config> {
+        /// Total USDT invested on behalf of the policy
+        pub invested_principal: T::Balance,
+        /// USDT returned by the strategy when the position was unwound
+        pub realised: T::Balance,
+        /// Surplus over the policy's obligations paid to the DAO
+        pub dao_share: T::Balance,
+        /// Surplus distributed to the policy's LP holders
+        pub lp_share: T::Balance,
+        /// Surplus rebated to the policy holder
+        pub holder_share: T::Balance,
+    }
+
+    impl<T: Config> Default for PolicyYieldRecord<T> {
+        fn default() -> Self {
+            Self {
+                invested_principal: Zero::zero(),
+                realised: Zero::zero(),
+                dao_share: Zero::zero(),
+                lp_share: Zero::zero(),
+                holder_share: Zero::zero(),
+            }
+        }
+    }
+
+    /// Capital currently placed in one strategy
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct StrategyAccount<T: Config> {
+        /// Principal of the policy positions held in the strategy
+        pub principal: T::Balance,
+        /// LP share units held in the strategy
+        pub lp_shares: u128,
+    }
+
+    impl<T: Config> Default for StrategyAccount<T> {
+        fn default() -> Self {
+            Self { principal: Zero::zero(), lp_shares: 0 }
+        }
+    }
+
+    /// Cross-chain payout destination set by a policy's holder
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct PolicyPayoutDestination<T: Config> {
+        /// Holder who set the destination; it lapses if the policy changes hands
+        pub holder: T::AccountId,
+        /// Beneficiary on the destination chain
+        pub destination: PayoutDestination,
+    }
+
+    /// Payout held in the payout escrow account after a failed delivery
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct FailedPayout<T: Config> {
+        /// Holder the payout belongs to; the only account that can claim it
+        pub holder: T::AccountId,
+        /// Beneficiary on the destination chain
+        pub destination: PayoutDestination,
+        /// USDT held in escrow for the holder
+        pub amount: T::Balance,
+        /// Delivery attempts so far
+        pub attempts: u32,
+        /// Block of the next automatic retry; `None` once retries are exhausted
+        pub next_retry: Option<BlockNumberFor<T>>,
+    }
+
+    /// Most policies `rebalance_policies` moves in one call
+    pub const MAX_REBALANCE_BATCH: u32 = 32;
+
+    /// Pallet ID deriving the escrow account that holds payouts in cross-chain delivery
+    pub const PAYOUT_ESCROW_PALLET_ID: PalletId = PalletId(*b"prmx/xpo");
+
+    /// Pallet ID deriving the shortfall backstop account
+    pub const BACKSTOP_PALLET_ID: PalletId = PalletId(*b"prmx/bsp");
+
     // =========================================================================
     //                                  Config
     // =========================================================================
@@ -209,16 +356,23 @@ pub mod pallet {
 
         /// Policy pool account derivation API
         type PolicyPoolAccount: PolicyPoolAccountApi<Self::AccountId>;
+
+        /// Denomination asset of each policy; only USDT policies are allocated
+        type PolicyAssets: PolicyAssetApi<Self::AssetId>;
         
-        /// Holdings API for LP token ownership checks
-        type HoldingsApi: pallet_prmx_holdings::HoldingsApi<Self::AccountId>;
+        /// Holdings API for LP token ownership checks and surplus distribution
+        type HoldingsApi: pallet_prmx_holdings::HoldingsApi<Self::AccountId, Balance = Self::Balance>;
     }
 
     // =========================================================================
     //                                  Storage
     // =========================================================================
 
+    /// In-code storage version
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     /// LP share allocation and principal per policy (Pool 102)
@@ -266,10 +420,128 @@ pub mod pallet {
     #[pallet::getter(fn total_allocated_capital)]
     pub type TotalAllocatedCapital<T: Config> = StorageValue<_, T::Balance, ValueQuery>;
 
+    /// Invested principal, realised value and surplus split per policy.
+    /// Kept after settlement for reporting.
+    #[pallet::storage]
+    #[pallet::getter(fn policy_yield_records)]
+    pub type PolicyYieldRecords<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        PolicyId,
+        PolicyYieldRecord<T>,
+        OptionQuery,
+    >;
+
+    /// Split of surplus yield between DAO, LPs and policy holder
+    #[pallet::storage]
+    #[pallet::getter(fn yield_split)]
+    pub type SurplusYieldSplit<T: Config> = StorageValue<_, YieldSplit, ValueQuery>;
+
     /// Genesis initialization flag
     #[pallet::storage]
     pub type Initialized<T: Config> = StorageValue<_, bool, ValueQuery>;
 
+    /// Registered yield strategies
+    #[pallet::storage]
+    #[pallet::getter(fn strategies)]
+    pub type Strategies<T: Config> =
+        StorageMap<_, Blake2_128Concat, StrategyId, CapitalStrategy, OptionQuery>;
+
+    /// ID given to the next registered strategy
+    #[pallet::storage]
+    #[pallet::getter(fn next_strategy_id)]
+    pub type NextStrategyId<T: Config> = StorageValue<_, StrategyId, ValueQuery>;
+
+    /// Principal and LP shares currently held per strategy
+    #[pallet::storage]
+    #[pallet::getter(fn strategy_accounts)]
+    pub type StrategyAccounts<T: Config> =
+        StorageMap<_, Blake2_128Concat, StrategyId, StrategyAccount<T>, ValueQuery>;
+
+    /// Strategy each policy's capital is allocated to. Kept after settlement for reporting.
+    #[pallet::storage]
+    #[pallet::getter(fn policy_strategy)]
+    pub type PolicyStrategy<T: Config> =
+        StorageMap<_, Blake2_128Concat, PolicyId, StrategyId, OptionQuery>;
+
+    /// Cross-chain beneficiary of each policy's payout, set by its holder.
+    /// Removed when the payout is routed.
+    #[pallet::storage]
+    #[pallet::getter(fn payout_destinations)]
+    pub type PayoutDestinations<T: Config> =
+        StorageMap<_, Blake2_128Concat, PolicyId, PolicyPayoutDestination<T>, OptionQuery>;
+
+    /// Payouts whose cross-chain delivery failed, held in escrow until a retry
+    /// succeeds or the holder claims them locally
+    #[pallet::storage]
+    #[pallet::getter(fn failed_payouts)]
+    pub type FailedPayouts<T: Config> =
+        StorageMap<_, Blake2_128Concat, PolicyId, FailedPayout<T>, OptionQuery>;
+
+    /// Make mock payout deliveries fail (for testing the retry queue)
+    #[pallet::storage]
+    #[pallet::getter(fn mock_payout_failure)]
+    pub type MockPayoutFailure<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+    /// Premium share and utilization cap of the shortfall backstop
+    #[pallet::storage]
+    #[pallet::getter(fn backstop_config)]
+    pub type BackstopParameters<T: Config> = StorageValue<_, BackstopConfig, ValueQuery>;
+
+    /// Running totals of backstop contributions, replenishments and draws
+    #[pallet::storage]
+    #[pallet::getter(fn backstop_totals)]
+    pub type BackstopLedger<T: Config> = StorageValue<_, BackstopTotals<T::Balance>, ValueQuery>;
+
+    /// Amount the backstop paid towards each policy's settlement shortfall
+    #[pallet::storage]
+    #[pallet::getter(fn policy_backstop_draws)]
+    pub type PolicyBackstopDraws<T: Config> =
+        StorageMap<_, Blake2_128Concat, PolicyId, T::Balance, OptionQuery>;
+
+    // =========================================================================
+    //                                 Genesis
+    // =========================================================================
+
+    #[pallet::genesis_config]
+    pub struct GenesisConfig<T: Config> {
+        /// Strategies registered at genesis as (pool_id, para_id, max_allocation_ppm)
+        pub strategies: Vec<(u32, u32, u32)>,
+        #[serde(skip)]
+        pub _phantom: core::marker::PhantomData<T>,
+    }
+
+    impl<T: Config> Default for GenesisConfig<T> {
+        /// Hydration Stableswap Pool 102 with no cap
+        fn default() -> Self {
+            let hydration = CapitalStrategy::hydration_pool_102();
+            Self {
+                strategies: alloc::vec![(
+                    hydration.pool_id,
+                    hydration.para_id,
+                    hydration.max_allocation_ppm,
+                )],
+                _phantom: Default::default(),
+            }
+        }
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+        fn build(&self) {
+            for (pool_id, para_id, max_allocation_ppm) in self.strategies.iter() {
+                let strategy = CapitalStrategy {
+                    pool_id: *pool_id,
+                    para_id: *para_id,
+                    max_allocation_ppm: *max_allocation_ppm,
+                    active: true,
+                };
+                assert!(strategy.is_valid(), "Invalid genesis capital strategy");
+                Pallet::<T>::insert_strategy(strategy);
+            }
+        }
+    }
+
     // =========================================================================
     //                                  Events
     // =========================================================================
@@ -326,6 +598,19 @@ pub mod pallet {
             dao_balance: T::Balance,
             max_potential_loss: T::Balance,
         },
+        /// Surplus yield split updated. [dao_ppm, lp_ppm, holder_ppm]
+        YieldSplitUpdated {
+            dao_ppm: u32,
+            lp_ppm: u32,
+            holder_ppm: u32,
+        },
+        /// Surplus yield distributed on unwind. [policy_id, to_dao, to_lps, to_holder]
+        SurplusYieldDistributed {
+            policy_id: PolicyId,
+            to_dao: T::Balance,
+            to_lps: T::Balance,
+            to_holder: T::Balance,
+        },
         /// Loss absorption by LPs/holders due to DAO insolvency.
         /// [policy_id, shortfall_amount, covered_by_dao, absorbed_by_lps]
         LossAbsorbedByLps {
@@ -334,6 +619,87 @@ pub mod pallet {
             covered_by_dao: T::Balance,
             absorbed_by_lps: T::Balance,
         },
+        /// Yield strategy registered. [strategy_id, pool_id, para_id, max_allocation_ppm]
+        StrategyRegistered {
+            strategy_id: StrategyId,
+            pool_id: u32,
+            para_id: u32,
+            max_allocation_ppm: u32,
+        },
+        /// Yield strategy cap or status changed. [strategy_id, max_allocation_ppm, active]
+        StrategyUpdated {
+            strategy_id: StrategyId,
+            max_allocation_ppm: u32,
+            active: bool,
+        },
+        /// Policy assigned to a strategy. [policy_id, strategy_id]
+        PolicyStrategyAssigned {
+            policy_id: PolicyId,
+            strategy_id: StrategyId,
+        },
+        /// Policy position moved between strategies.
+        /// [policy_id, from_strategy, to_strategy, realised_amount, shares]
+        PolicyRebalanced {
+            policy_id: PolicyId,
+            from_strategy: StrategyId,
+            to_strategy: StrategyId,
+            realised_amount: T::Balance,
+            shares: u128,
+        },
+        /// Cross-chain payout destination set or cleared. [policy_id, destination]
+        PayoutDestinationSet {
+            policy_id: PolicyId,
+            destination: Option<PayoutDestination>,
+        },
+        /// Payout sent to a beneficiary on another chain.
+        /// [policy_id, para_id, beneficiary, amount]
+        CrossChainPayoutSent {
+            policy_id: PolicyId,
+            para_id: u32,
+            beneficiary: [u8; 32],
+            amount: T::Balance,
+        },
+        /// Cross-chain payout delivery failed; the payout stays in escrow.
+        /// [policy_id, amount, attempts, next_retry]
+        CrossChainPayoutFailed {
+            policy_id: PolicyId,
+            amount: T::Balance,
+            attempts: u32,
+            next_retry: Option<BlockNumberFor<T>>,
+        },
+        /// Failed cross-chain payout paid to the holder's local account. [policy_id, holder, amount]
+        FailedPayoutClaimed {
+            policy_id: PolicyId,
+            holder: T::AccountId,
+            amount: T::Balance,
+        },
+        /// Mock payout failure toggled. [fail]
+        MockPayoutFailureUpdated {
+            fail: bool,
+        },
+        /// Backstop premium share and utilization cap updated.
+        /// [premium_share_ppm, max_draw_ppm]
+        BackstopConfigUpdated {
+            premium_share_ppm: u32,
+            max_draw_ppm: u32,
+        },
+        /// DAO paid the backstop its share of a new policy's premium. [policy_id, amount]
+        BackstopFunded {
+            policy_id: PolicyId,
+            amount: T::Balance,
+        },
+        /// Backstop replenished directly. [who, amount]
+        BackstopReplenished {
+            who: T::AccountId,
+            amount: T::Balance,
+        },
+        /// Backstop paid towards a policy's settlement shortfall.
+        /// [policy_id, shortfall_amount, drawn]
+        BackstopDrawn {
+            policy_id: PolicyId,
+            shortfall_amount: T::Balance,
+            drawn: T::Balance,
+        },
     }
 
     // =========================================================================
@@ -364,6 +730,32 @@ pub mod pallet {
         PositionUnwinding,
         /// LP holder does not own >=51% of LP tokens
         NotEnoughLpOwnership,
+        /// Yield split shares must add up to 1,000,000 ppm.
+        InvalidYieldSplit,
+        /// No strategy registered under this ID.
+        UnknownStrategy,
+        /// Strategy cap must be above zero and at most 1,000,000 ppm.
+        InvalidStrategy,
+        /// Strategy takes no new capital.
+        StrategyInactive,
+        /// Allocation would take the strategy above its maximum share of capital.
+        StrategyCapExceeded,
+        /// No active strategy can take the allocation within its cap.
+        NoStrategyAvailable,
+        /// Policy capital is already in the target strategy.
+        AlreadyInStrategy,
+        /// Caller does not hold the policy.
+        NotPolicyHolder,
+        /// Payout destination needs a parachain ID and a non-zero account.
+        InvalidPayoutDestination,
+        /// No failed payout is held for the policy.
+        NoFailedPayout,
+        /// Strategies and cross-chain payouts only take USDT-denominated policies.
+        UnsupportedPolicyAsset,
+        /// Backstop premium share above 200,000 ppm or draw cap above 1,000,000 ppm.
+        InvalidBackstopConfig,
+        /// Replenishment amount must be above zero.
+        ZeroReplenishment,
     }
 
     // =========================================================================
@@ -497,6 +889,265 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// Set how surplus DeFi yield is split when a policy's position is unwound.
+        ///
+        /// - `split`: DAO, LP and holder shares in ppm; must add up to 1_000_000.
+        ///   The default sends everything to the DAO.
+        #[pallet::call_index(4)]
+        #[pallet::weight(10_000)]
+        pub fn set_yield_split(
+            origin: OriginFor<T>,
+            split: YieldSplit,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            ensure!(split.is_valid(), Error::<T>::InvalidYieldSplit);
+
+            SurplusYieldSplit::<T>::put(split);
+
+            Self::deposit_event(Event::YieldSplitUpdated {
+                dao_ppm: split.dao_ppm,
+                lp_ppm: split.lp_ppm,
+                holder_ppm: split.holder_ppm,
+            });
+
+            Ok(())
+        }
+
+        /// Register a yield strategy that new policy capital can be allocated to.
+        ///
+        /// - `pool_id`: Stableswap pool on the target chain
+        /// - `para_id`: Parachain the pool lives on
+        /// - `max_allocation_ppm`: Largest share of total allocated capital the
+        ///   strategy may hold (1_000_000 = 100%)
+        #[pallet::call_index(5)]
+        #[pallet::weight(10_000)]
+        pub fn register_strategy(
+            origin: OriginFor<T>,
+            pool_id: u32,
+            para_id: u32,
+            max_allocation_ppm: u32,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let strategy = CapitalStrategy { pool_id, para_id, max_allocation_ppm, active: true };
+            ensure!(strategy.is_valid(), Error::<T>::InvalidStrategy);
+
+            Self::insert_strategy(strategy);
+
+            Ok(())
+        }
+
+        /// Change a strategy's cap or stop it from taking new capital.
+        /// Positions already in an inactive strategy stay until rebalanced or settled.
+        #[pallet::call_index(6)]
+        #[pallet::weight(10_000)]
+        pub fn update_strategy(
+            origin: OriginFor<T>,
+            strategy_id: StrategyId,
+            max_allocation_ppm: u32,
+            active: bool,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            Strategies::<T>::try_mutate(strategy_id, |maybe_strategy| -> DispatchResult {
+                let strategy = maybe_strategy.as_mut().ok_or(Error::<T>::UnknownStrategy)?;
+                let updated = CapitalStrategy { max_allocation_ppm, active, ..*strategy };
+                ensure!(updated.is_valid(), Error::<T>::InvalidStrategy);
+                *strategy = updated;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::StrategyUpdated { strategy_id, max_allocation_ppm, active });
+
+            Ok(())
+        }
+
+        /// Move invested policy positions into another strategy.
+        ///
+        /// Each position is exited to the DAO account and its realised value entered
+        /// into `to_strategy`, so DeFi profit or loss so far travels with the position.
+        /// The target strategy must be active and stay within its cap.
+        ///
+        /// - `to_strategy`: Strategy to move the positions into
+        /// - `policy_ids`: Invested policies to move
+        #[pallet::call_index(7)]
+        #[pallet::weight(100_000u64.saturating_mul(policy_ids.len() as u64))]
+        pub fn rebalance_policies(
+            origin: OriginFor<T>,
+            to_strategy: StrategyId,
+            policy_ids: BoundedVec<PolicyId, ConstU32<MAX_REBALANCE_BATCH>>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            for policy_id in policy_ids {
+                Self::do_rebalance_policy(policy_id, to_strategy)?;
+            }
+
+            Ok(())
+        }
+
+        /// Have the policy's payout delivered to an account on another chain, or
+        /// paid locally again with `None`.
+        ///
+        /// Only the current policy holder can call this. The destination lapses if
+        /// the policy is transferred. Installment payouts and secondary-market
+        /// positions are always paid locally.
+        ///
+        /// - `policy_id`: The policy whose payout to route
+        /// - `destination`: Parachain and 32-byte account receiving the payout
+        #[pallet::call_index(8)]
+        #[pallet::weight(10_000)]
+        pub fn set_payout_destination(
+            origin: OriginFor<T>,
+            policy_id: PolicyId,
+            destination: Option<PayoutDestination>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(
+                T::PolicyPoolAccount::policy_holder(policy_id).as_ref() == Some(&who),
+                Error::<T>::NotPolicyHolder
+            );
+
+            match destination {
+                Some(destination) => {
+                    ensure!(destination.is_valid(), Error::<T>::InvalidPayoutDestination);
+                    ensure!(Self::is_usdt_policy(policy_id), Error::<T>::UnsupportedPolicyAsset);
+                    PayoutDestinations::<T>::insert(
+                        policy_id,
+                        PolicyPayoutDestination { holder: who, destination },
+                    );
+                }
+                None => PayoutDestinations::<T>::remove(policy_id),
+            }
+
+            Self::deposit_event(Event::PayoutDestinationSet { policy_id, destination });
+
+            Ok(())
+        }
+
+        /// Take a payout whose cross-chain delivery failed on this chain instead.
+        ///
+        /// Only the holder the payout belongs to can claim, at any time before a
+        /// retry succeeds.
+        ///
+        /// - `policy_id`: The policy whose failed payout to claim
+        #[pallet::call_index(9)]
+        #[pallet::weight(100_000)]
+        pub fn claim_failed_payout(
+            origin: OriginFor<T>,
+            policy_id: PolicyId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let payout = FailedPayouts::<T>::get(policy_id).ok_or(Error::<T>::NoFailedPayout)?;
+            ensure!(payout.holder == who, Error::<T>::NotPolicyHolder);
+
+            T::Assets::transfer(
+                T::UsdtAssetId::get(),
+                &Self::payout_escrow_account(),
+                &who,
+                payout.amount,
+                Preservation::Expendable,
+            ).map_err(|_| Error::<T>::TransferFailed)?;
+            FailedPayouts::<T>::remove(policy_id);
+
+            Self::deposit_event(Event::FailedPayoutClaimed {
+                policy_id,
+                holder: who,
+                amount: payout.amount,
+            });
+
+            Ok(())
+        }
+
+        /// Make mock payout deliveries fail, to exercise the retry queue.
+        #[pallet::call_index(10)]
+        #[pallet::weight(10_000)]
+        pub fn set_mock_payout_failure(
+            origin: OriginFor<T>,
+            fail: bool,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            MockPayoutFailure::<T>::put(fail);
+
+            Self::deposit_event(Event::MockPayoutFailureUpdated { fail });
+
+            Ok(())
+        }
+
+        /// Configure the shortfall backstop (see `backstop`).
+        ///
+        /// - `premium_share_ppm`: Share of each new USDT policy's premium the DAO pays
+        ///   into the backstop, at most 200_000 (20%)
+        /// - `max_draw_ppm`: Largest share of the backstop balance one policy's
+        ///   shortfall may draw (1_000_000 = 100%, 0 = never draw)
+        #[pallet::call_index(11)]
+        #[pallet::weight(10_000)]
+        pub fn set_backstop_config(
+            origin: OriginFor<T>,
+            premium_share_ppm: u32,
+            max_draw_ppm: u32,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let config = BackstopConfig { premium_share_ppm, max_draw_ppm };
+            ensure!(config.is_valid(), Error::<T>::InvalidBackstopConfig);
+
+            BackstopParameters::<T>::put(config);
+
+            Self::deposit_event(Event::BackstopConfigUpdated { premium_share_ppm, max_draw_ppm });
+
+            Ok(())
+        }
+
+        /// Pay USDT into the shortfall backstop, e.g. to restore it after draws.
+        ///
+        /// - `amount`: Amount of USDT to add
+        #[pallet::call_index(12)]
+        #[pallet::weight(100_000)]
+        pub fn replenish_backstop(
+            origin: OriginFor<T>,
+            amount: T::Balance,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(!amount.is_zero(), Error::<T>::ZeroReplenishment);
+
+            T::Assets::transfer(
+                T::UsdtAssetId::get(),
+                &who,
+                &Self::backstop_account(),
+                amount,
+                Preservation::Preserve,
+            ).map_err(|_| Error::<T>::TransferFailed)?;
+            BackstopLedger::<T>::mutate(|totals| totals.record_replenishment(amount));
+
+            Self::deposit_event(Event::BackstopReplenished { who, amount });
+
+            Ok(())
+        }
+    }
+
+    // =========================================================================
+    //                                  Hooks
+    // =========================================================================
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// Retry failed cross-chain payouts (every BLOCKS_PER_PAYOUT_RETRY_CHECK blocks)
+        fn on_initialize(block_number: BlockNumberFor<T>) -> Weight {
+            use sp_runtime::traits::UniqueSaturatedInto;
+            let block_num: u32 = block_number.unique_saturated_into();
+
+            if block_num % payouts::BLOCKS_PER_PAYOUT_RETRY_CHECK != 0 {
+                return Weight::zero();
+            }
+
+            Self::retry_failed_payouts(block_number)
+        }
     }
 
     // =========================================================================
@@ -514,11 +1165,175 @@ pub mod pallet {
             }
         }
 
+        /// Whether a policy is denominated in USDT, the only asset strategies and
+        /// the XCM payout route carry
+        pub fn is_usdt_policy(policy_id: PolicyId) -> bool {
+            T::PolicyAssets::policy_asset(policy_id)
+                .map_or(true, |asset| asset == T::UsdtAssetId::get())
+        }
+
+        /// Register `strategy` under the next strategy ID
+        pub fn insert_strategy(strategy: CapitalStrategy) -> StrategyId {
+            let strategy_id = NextStrategyId::<T>::get();
+            Strategies::<T>::insert(strategy_id, strategy);
+            NextStrategyId::<T>::put(strategy_id.saturating_add(1));
+
+            Self::deposit_event(Event::StrategyRegistered {
+                strategy_id,
+                pool_id: strategy.pool_id,
+                para_id: strategy.para_id,
+                max_allocation_ppm: strategy.max_allocation_ppm,
+            });
+
+            strategy_id
+        }
+
+        /// Strategy the policy is assigned to
+        pub fn assigned_strategy(
+            policy_id: PolicyId,
+        ) -> Result<(StrategyId, CapitalStrategy), DispatchError> {
+            let strategy_id =
+                PolicyStrategy::<T>::get(policy_id).ok_or(Error::<T>::UnknownStrategy)?;
+            let strategy = Strategies::<T>::get(strategy_id).ok_or(Error::<T>::UnknownStrategy)?;
+            Ok((strategy_id, strategy))
+        }
+
+        /// Strategy to allocate `amount` of the policy's capital to. A policy without
+        /// one is assigned the strategy `strategy::select_strategy` picks; an assigned
+        /// strategy must be active and stay within its cap.
+        pub fn ensure_policy_strategy(
+            policy_id: PolicyId,
+            amount: T::Balance,
+        ) -> Result<(StrategyId, CapitalStrategy), DispatchError> {
+            let total: u128 = TotalAllocatedCapital::<T>::get().into();
+            let amount_u128: u128 = amount.into();
+
+            if PolicyStrategy::<T>::contains_key(policy_id) {
+                let (strategy_id, strategy) = Self::assigned_strategy(policy_id)?;
+                if amount_u128 > 0 {
+                    ensure!(strategy.active, Error::<T>::StrategyInactive);
+                    let principal: u128 = StrategyAccounts::<T>::get(strategy_id).principal.into();
+                    ensure!(
+                        strategy::within_cap(
+                            principal.saturating_add(amount_u128),
+                            total.saturating_add(amount_u128),
+                            strategy.max_allocation_ppm,
+                        ),
+                        Error::<T>::StrategyCapExceeded
+                    );
+                }
+                return Ok((strategy_id, strategy));
+            }
+
+            let mut candidates: Vec<(StrategyId, u128, u32)> = Strategies::<T>::iter()
+                .filter(|(_, strategy)| strategy.active)
+                .map(|(strategy_id, strategy)| {
+                    let principal: u128 = StrategyAccounts::<T>::get(strategy_id).principal.into();
+                    (strategy_id, principal, strategy.max_allocation_ppm)
+                })
+                .collect();
+            candidates.sort_by_key(|(strategy_id, _, _)| *strategy_id);
+
+            let strategy_id = strategy::select_strategy(candidates, total, amount_u128)
+                .ok_or(Error::<T>::NoStrategyAvailable)?;
+            let strategy = Strategies::<T>::get(strategy_id).ok_or(Error::<T>::UnknownStrategy)?;
+
+            PolicyStrategy::<T>::insert(policy_id, strategy_id);
+            Self::deposit_event(Event::PolicyStrategyAssigned { policy_id, strategy_id });
+
+            Ok((strategy_id, strategy))
+        }
+
+        /// Add a position's principal and shares to its strategy's account
+        fn credit_strategy(strategy_id: StrategyId, principal: T::Balance, lp_shares: u128) {
+            StrategyAccounts::<T>::mutate(strategy_id, |account| {
+                account.principal = account.principal.saturating_add(principal);
+                account.lp_shares = account.lp_shares.saturating_add(lp_shares);
+            });
+        }
+
+        /// Remove a position's principal and shares from its strategy's account
+        fn debit_strategy(strategy_id: StrategyId, principal: T::Balance, lp_shares: u128) {
+            StrategyAccounts::<T>::mutate(strategy_id, |account| {
+                account.principal = account.principal.saturating_sub(principal);
+                account.lp_shares = account.lp_shares.saturating_sub(lp_shares);
+            });
+        }
+
+        /// Move one invested policy position into `to_strategy`
+        pub fn do_rebalance_policy(
+            policy_id: PolicyId,
+            to_strategy: StrategyId,
+        ) -> Result<(), DispatchError> {
+            ensure!(
+                PolicyInvestmentStatus::<T>::get(policy_id) == InvestmentStatus::Invested,
+                Error::<T>::NoPositionToUnwind
+            );
+            let mut pos = PolicyLpPositions::<T>::get(policy_id)
+                .ok_or(Error::<T>::NoPositionToUnwind)?;
+
+            let (from_strategy, from) = Self::assigned_strategy(policy_id)?;
+            ensure!(from_strategy != to_strategy, Error::<T>::AlreadyInStrategy);
+            let to = Strategies::<T>::get(to_strategy).ok_or(Error::<T>::UnknownStrategy)?;
+            ensure!(to.active, Error::<T>::StrategyInactive);
+
+            // Total allocated capital is unchanged by the move
+            let total: u128 = TotalAllocatedCapital::<T>::get().into();
+            let to_principal: u128 = StrategyAccounts::<T>::get(to_strategy).principal.into();
+            ensure!(
+                strategy::within_cap(
+                    to_principal.saturating_add(pos.principal_usdt.into()),
+                    total,
+                    to.max_allocation_ppm,
+                ),
+                Error::<T>::StrategyCapExceeded
+            );
+
+            let realised = T::XcmStrategyInterface::exit_strategy(
+                &from,
+                pos.lp_shares,
+                &T::DaoAccountId::get(),
+            ).map_err(|_| Error::<T>::StrategyExitFailed)?;
+            let minted_shares = T::XcmStrategyInterface::enter_strategy(&to, realised)
+                .map_err(|_| Error::<T>::StrategyEntryFailed)?;
+
+            Self::debit_strategy(from_strategy, pos.principal_usdt, pos.lp_shares);
+            Self::credit_strategy(to_strategy, pos.principal_usdt, minted_shares);
+            TotalLpShares::<T>::mutate(|total| {
+                *total = total.saturating_sub(pos.lp_shares).saturating_add(minted_shares);
+            });
+
+            pos.lp_shares = minted_shares;
+            PolicyLpPositions::<T>::insert(policy_id, pos);
+            PolicyStrategy::<T>::insert(policy_id, to_strategy);
+
+            log::info!(
+                target: "prmx-xcm-capital",
+                "🔀 Rebalanced policy {} from strategy {} to {}: realised {} USDT, {} LP shares",
+                policy_id,
+                from_strategy,
+                to_strategy,
+                realised.into(),
+                minted_shares
+            );
+
+            Self::deposit_event(Event::PolicyRebalanced {
+                policy_id,
+                from_strategy,
+                to_strategy,
+                realised_amount: realised,
+                shares: minted_shares,
+            });
+
+            Ok(())
+        }
+
         /// Internal implementation of allocate_to_defi (uses configured PolicyPoolAccount)
         pub fn do_allocate_to_defi(
             policy_id: PolicyId,
             amount: T::Balance,
         ) -> Result<(), DispatchError> {
+            ensure!(Self::is_usdt_policy(policy_id), Error::<T>::UnsupportedPolicyAsset);
             let pool_account = T::PolicyPoolAccount::policy_pool_account(policy_id);
             Self::do_allocate_to_defi_with_account(policy_id, amount, pool_account)
         }
@@ -545,6 +1360,8 @@ pub mod pallet {
             let pool_balance = T::Assets::balance(T::UsdtAssetId::get(), &pool_account);
             ensure!(pool_balance >= amount, Error::<T>::InsufficientPoolFunds);
 
+            let (strategy_id, strategy) = Self::ensure_policy_strategy(policy_id, amount)?;
+
             // =================================================================
             // DAO SOLVENCY CHECK
             // =================================================================
@@ -648,8 +1465,8 @@ pub mod pallet {
                 Preservation::Expendable,
             ).map_err(|_| Error::<T>::TransferFailed)?;
 
-            // Enter DeFi strategy (Hydration Stableswap Pool 102)
-            let minted_shares = T::XcmStrategyInterface::enter_strategy(amount)
+            // Enter the policy's DeFi strategy
+            let minted_shares = T::XcmStrategyInterface::enter_strategy(&strategy, amount)
                 .map_err(|_| Error::<T>::StrategyEntryFailed)?;
 
             // Store or update position (supports incremental allocation)
@@ -685,8 +1502,10 @@ pub mod pallet {
                 
                 log::info!(
                     target: "prmx-xcm-capital",
-                    "📈 Allocated {} USDT to DeFi (Pool 102) for policy {}, received {} LP shares",
+                    "📈 Allocated {} USDT to DeFi strategy {} (pool {}) for policy {}, received {} LP shares",
                     amount.into(),
+                    strategy_id,
+                    strategy.pool_id,
                     policy_id,
                     minted_shares
                 );
@@ -701,6 +1520,13 @@ pub mod pallet {
             TotalAllocatedCapital::<T>::mutate(|total| {
                 *total = total.saturating_add(amount);
             });
+            Self::credit_strategy(strategy_id, amount, minted_shares);
+
+            // Track invested principal for yield accounting
+            PolicyYieldRecords::<T>::mutate(policy_id, |record| {
+                let record = record.get_or_insert_with(Default::default);
+                record.invested_principal = record.invested_principal.saturating_add(amount);
+            });
 
             Self::deposit_event(Event::CapitalAllocated {
                 policy_id,
@@ -718,19 +1544,24 @@ pub mod pallet {
             required_local: T::Balance,
         ) -> Result<(), DispatchError> {
             let pool_account = T::PolicyPoolAccount::policy_pool_account(policy_id);
-            Self::do_ensure_local_liquidity_with_account(policy_id, required_local, pool_account)
+            let holder = T::PolicyPoolAccount::policy_holder(policy_id);
+            Self::do_ensure_local_liquidity_with_account(policy_id, required_local, pool_account, holder)
         }
 
         /// Ensure local liquidity with explicit pool account.
         /// This is used by V3 which has its own policy pool derivation.
         ///
         /// This is called before settlement to ensure the policy pool has enough
-        /// USDT to fulfill obligations. If DAO cannot cover the full shortfall,
-        /// it covers what it can and LPs absorb the remaining loss.
+        /// USDT to fulfill obligations. The backstop covers a shortfall first,
+        /// within its utilization cap. If DAO cannot cover the rest, it covers
+        /// what it can and LPs absorb the remaining loss. Any surplus is
+        /// split per `SurplusYieldSplit`; without a `holder` the rebate share goes
+        /// to the DAO.
         pub fn do_ensure_local_liquidity_with_account(
             policy_id: PolicyId,
             required_local: T::Balance,
             pool_account: T::AccountId,
+            holder: Option<T::AccountId>,
         ) -> Result<(), DispatchError> {
             let status = PolicyInvestmentStatus::<T>::get(policy_id);
 
@@ -748,12 +1579,14 @@ pub mod pallet {
             // Get the position
             let pos = PolicyLpPositions::<T>::get(policy_id)
                 .ok_or(Error::<T>::NoPositionToUnwind)?;
+            let (strategy_id, strategy) = Self::assigned_strategy(policy_id)?;
 
             // Mark as unwinding
             PolicyInvestmentStatus::<T>::insert(policy_id, InvestmentStatus::Unwinding);
 
             // Exit strategy - get all LP shares back
             let realised = T::XcmStrategyInterface::exit_strategy(
+                &strategy,
                 pos.lp_shares,
                 &pool_account,
             ).map_err(|_| Error::<T>::StrategyExitFailed)?;
@@ -777,10 +1610,25 @@ pub mod pallet {
             TotalAllocatedCapital::<T>::mutate(|total| {
                 *total = total.saturating_sub(pos.principal_usdt);
             });
+            Self::debit_strategy(strategy_id, pos.principal_usdt, pos.lp_shares);
 
-            // Check if we need DAO to top up
-            let local_balance = T::Assets::balance(T::UsdtAssetId::get(), &pool_account);
+            PolicyYieldRecords::<T>::mutate(policy_id, |record| {
+                let record = record.get_or_insert_with(Default::default);
+                record.realised = realised;
+            });
+
+            // Let the backstop cover what it can of any shortfall first
+            let mut local_balance = T::Assets::balance(T::UsdtAssetId::get(), &pool_account);
+            if local_balance < required_local {
+                let drawn = Self::draw_from_backstop(
+                    policy_id,
+                    &pool_account,
+                    required_local.saturating_sub(local_balance),
+                )?;
+                local_balance = local_balance.saturating_add(drawn);
+            }
 
+            // Check if we need DAO to top up
             if local_balance < required_local {
                 let shortfall = required_local.saturating_sub(local_balance);
                 let dao_balance = T::Assets::balance(T::UsdtAssetId::get(), &T::DaoAccountId::get());
@@ -875,38 +1723,93 @@ pub mod pallet {
                     });
                 }
             } else if local_balance > required_local {
-                // DAO receives the profit
+                // Surplus is split between DAO, LPs and holder
                 let profit = local_balance.saturating_sub(required_local);
+                Self::distribute_surplus(policy_id, &pool_account, profit, holder)?;
+            }
+
+            // Update total shares
+            TotalLpShares::<T>::mutate(|total| {
+                *total = total.saturating_sub(pos.lp_shares);
+            });
+
+            // Clean up position
+            PolicyLpPositions::<T>::remove(policy_id);
+            PolicyInvestmentStatus::<T>::insert(policy_id, InvestmentStatus::Settled);
+
+            Ok(())
+        }
 
+        /// Split surplus yield from an unwound position per `SurplusYieldSplit`.
+        /// The LP share goes to the DAO if the policy has no LP holders in
+        /// `HoldingsApi`, and the holder share if no holder is given.
+        fn distribute_surplus(
+            policy_id: PolicyId,
+            pool_account: &T::AccountId,
+            surplus: T::Balance,
+            holder: Option<T::AccountId>,
+        ) -> Result<(), DispatchError> {
+            let (mut to_dao, mut to_lps, mut to_holder) =
+                SurplusYieldSplit::<T>::get().apply(surplus.into());
+
+            if to_lps > 0 && T::HoldingsApi::total_lp_shares(policy_id) == 0 {
+                to_dao = to_dao.saturating_add(to_lps);
+                to_lps = 0;
+            }
+            if holder.is_none() {
+                to_dao = to_dao.saturating_add(to_holder);
+                to_holder = 0;
+            }
+
+            if to_dao > 0 {
                 log::info!(
                     target: "prmx-xcm-capital",
                     "🎉 DAO receiving profit of {} USDT from policy {}",
-                    profit.into(),
+                    to_dao,
                     policy_id
                 );
 
                 T::Assets::transfer(
                     T::UsdtAssetId::get(),
-                    &pool_account,
+                    pool_account,
                     &T::DaoAccountId::get(),
-                    profit,
+                    to_dao.into(),
                     Preservation::Preserve,
                 ).map_err(|_| Error::<T>::TransferFailed)?;
 
                 Self::deposit_event(Event::DaoReceivedProfit {
                     policy_id,
-                    profit_amount: profit,
+                    profit_amount: to_dao.into(),
                 });
             }
 
-            // Update total shares
-            TotalLpShares::<T>::mutate(|total| {
-                *total = total.saturating_sub(pos.lp_shares);
+            if to_lps > 0 {
+                T::HoldingsApi::distribute_to_lp_holders(policy_id, pool_account, to_lps.into())?;
+            }
+
+            if let (Some(holder), true) = (holder, to_holder > 0) {
+                T::Assets::transfer(
+                    T::UsdtAssetId::get(),
+                    pool_account,
+                    &holder,
+                    to_holder.into(),
+                    Preservation::Preserve,
+                ).map_err(|_| Error::<T>::TransferFailed)?;
+            }
+
+            PolicyYieldRecords::<T>::mutate(policy_id, |record| {
+                let record = record.get_or_insert_with(Default::default);
+                record.dao_share = to_dao.into();
+                record.lp_share = to_lps.into();
+                record.holder_share = to_holder.into();
             });
 
-            // Clean up position
-            PolicyLpPositions::<T>::remove(policy_id);
-            PolicyInvestmentStatus::<T>::insert(policy_id, InvestmentStatus::Settled);
+            Self::deposit_event(Event::SurplusYieldDistributed {
+                policy_id,
+                to_dao: to_dao.into(),
+                to_lps: to_lps.into(),
+                to_holder: to_holder.into(),
+            });
 
             Ok(())
         }
@@ -914,8 +1817,10 @@ pub mod pallet {
         /// Called when a policy is fully settled to perform any final cleanup.
         pub fn do_on_policy_settled(policy_id: PolicyId) -> Result<(), DispatchError> {
             // Ensure no lingering position
-            if PolicyLpPositions::<T>::contains_key(policy_id) {
-                PolicyLpPositions::<T>::remove(policy_id);
+            if let Some(pos) = PolicyLpPositions::<T>::take(policy_id) {
+                if let Some(strategy_id) = PolicyStrategy::<T>::get(policy_id) {
+                    Self::debit_strategy(strategy_id, pos.principal_usdt, pos.lp_shares);
+                }
             }
 
             // Mark as settled if not already
@@ -932,6 +1837,160 @@ pub mod pallet {
             Ok(())
         }
 
+        /// Account holding payouts while they are delivered to other chains
+        pub fn payout_escrow_account() -> T::AccountId {
+            PAYOUT_ESCROW_PALLET_ID.into_account_truncating()
+        }
+
+        /// Account holding the shortfall backstop
+        pub fn backstop_account() -> T::AccountId {
+            BACKSTOP_PALLET_ID.into_account_truncating()
+        }
+
+        /// Have the DAO pay the backstop its share of a new policy's premium.
+        /// Only USDT policies contribute. Returns the amount paid.
+        pub fn do_fund_backstop(
+            policy_id: PolicyId,
+            premium: T::Balance,
+        ) -> Result<T::Balance, DispatchError> {
+            if !Self::is_usdt_policy(policy_id) {
+                return Ok(T::Balance::zero());
+            }
+            let amount: T::Balance =
+                BackstopParameters::<T>::get().contribution(premium.into()).into();
+            if amount.is_zero() {
+                return Ok(amount);
+            }
+
+            T::Assets::transfer(
+                T::UsdtAssetId::get(),
+                &T::DaoAccountId::get(),
+                &Self::backstop_account(),
+                amount,
+                Preservation::Preserve,
+            ).map_err(|_| Error::<T>::TransferFailed)?;
+            BackstopLedger::<T>::mutate(|totals| totals.record_contribution(amount));
+
+            Self::deposit_event(Event::BackstopFunded { policy_id, amount });
+
+            Ok(amount)
+        }
+
+        /// Pay what the backstop's utilization cap allows towards a policy's
+        /// `shortfall` into its pool. Returns the amount drawn.
+        fn draw_from_backstop(
+            policy_id: PolicyId,
+            pool_account: &T::AccountId,
+            shortfall: T::Balance,
+        ) -> Result<T::Balance, DispatchError> {
+            let backstop = Self::backstop_account();
+            let balance = T::Assets::reducible_balance(
+                T::UsdtAssetId::get(),
+                &backstop,
+                Preservation::Expendable,
+                Fortitude::Polite,
+            );
+            let drawn: T::Balance =
+                BackstopParameters::<T>::get().draw(shortfall.into(), balance.into()).into();
+            if drawn.is_zero() {
+                return Ok(drawn);
+            }
+
+            T::Assets::transfer(
+                T::UsdtAssetId::get(),
+                &backstop,
+                pool_account,
+                drawn,
+                Preservation::Expendable,
+            ).map_err(|_| Error::<T>::TransferFailed)?;
+            BackstopLedger::<T>::mutate(|totals| totals.record_draw(drawn));
+            PolicyBackstopDraws::<T>::insert(policy_id, drawn);
+
+            Self::deposit_event(Event::BackstopDrawn {
+                policy_id,
+                shortfall_amount: shortfall,
+                drawn,
+            });
+
+            Ok(drawn)
+        }
+
+        /// Try to deliver an escrowed payout. On failure the payout is queued in
+        /// `FailedPayouts` with its next retry, if any attempts are left.
+        pub(crate) fn attempt_payout_delivery(policy_id: PolicyId, mut payout: FailedPayout<T>) {
+            payout.attempts = payout.attempts.saturating_add(1);
+
+            // Roll back anything a failed delivery changed
+            let delivered = frame_support::storage::with_storage_layer(|| {
+                T::XcmStrategyInterface::deliver_payout(
+                    &payout.destination,
+                    payout.amount,
+                    &Self::payout_escrow_account(),
+                )
+            });
+
+            match delivered {
+                Ok(()) => {
+                    FailedPayouts::<T>::remove(policy_id);
+
+                    log::info!(
+                        target: "prmx-xcm-capital",
+                        "📤 Payout of {} USDT for policy {} sent to para {}",
+                        payout.amount.into(),
+                        policy_id,
+                        payout.destination.para_id
+                    );
+
+                    Self::deposit_event(Event::CrossChainPayoutSent {
+                        policy_id,
+                        para_id: payout.destination.para_id,
+                        beneficiary: payout.destination.account,
+                        amount: payout.amount,
+                    });
+                }
+                Err(e) => {
+                    let now = frame_system::Pallet::<T>::block_number();
+                    payout.next_retry = payouts::retry_delay(payout.attempts)
+                        .map(|delay| now.saturating_add(delay.into()));
+
+                    log::warn!(
+                        target: "prmx-xcm-capital",
+                        "⚠️ Payout delivery for policy {} failed (attempt {}): {:?}",
+                        policy_id,
+                        payout.attempts,
+                        e
+                    );
+
+                    Self::deposit_event(Event::CrossChainPayoutFailed {
+                        policy_id,
+                        amount: payout.amount,
+                        attempts: payout.attempts,
+                        next_retry: payout.next_retry,
+                    });
+                    FailedPayouts::<T>::insert(policy_id, payout);
+                }
+            }
+        }
+
+        /// Retry up to MAX_PAYOUT_RETRIES_PER_BLOCK failed payouts whose retry is due
+        pub fn retry_failed_payouts(now: BlockNumberFor<T>) -> Weight {
+            let due: Vec<(PolicyId, FailedPayout<T>)> = FailedPayouts::<T>::iter()
+                .filter(|(_, payout)| payout.next_retry.is_some_and(|at| at <= now))
+                .take(payouts::MAX_PAYOUT_RETRIES_PER_BLOCK as usize)
+                .collect();
+
+            let mut weight = T::DbWeight::get().reads(1);
+            for (policy_id, payout) in due {
+                Self::attempt_payout_delivery(policy_id, payout);
+                // Payout entry, MockPayoutFailure and escrow balance
+                weight = weight
+                    .saturating_add(Weight::from_parts(50_000_000, 0))
+                    .saturating_add(T::DbWeight::get().reads_writes(3, 2));
+            }
+
+            weight
+        }
+
         /// Get all invested policies (for diagnostics)
         pub fn get_invested_policies() -> Vec<PolicyId> {
             PolicyLpPositions::<T>::iter_keys().collect()
@@ -957,36 +2016,49 @@ impl<T: Config> CapitalApi<T::AccountId> for Pallet<T> {
         policy_id: PolicyId,
         pool_balance: Self::Balance,
     ) -> Result<(), DispatchError> {
-        // Get allocation percentage (in ppm)
-        let allocation_ppm = pallet::Pallet::<T>::get_allocation_percentage_ppm();
-        
-        if allocation_ppm == 0 {
+        // Capital of policies in other assets stays in the policy pool
+        if !pallet::Pallet::<T>::is_usdt_policy(policy_id) {
             log::info!(
                 target: "prmx-xcm-capital",
-                "📊 Auto-allocation disabled (0%) for policy {}",
+                "📊 Policy {} is not denominated in USDT; no DeFi allocation",
                 policy_id
             );
             return Ok(());
         }
 
+        // Get allocation percentage (in ppm)
+        let allocation_ppm = pallet::Pallet::<T>::get_allocation_percentage_ppm();
+
         // Calculate allocation amount: pool_balance * allocation_ppm / 1_000_000
         let pool_u128: u128 = pool_balance.into();
         let allocation_u128 = pool_u128
             .saturating_mul(allocation_ppm as u128)
             / 1_000_000u128;
-        
-        if allocation_u128 == 0 {
+        let allocation: T::Balance = allocation_u128.into();
+
+        // Policies are assigned a strategy at creation, even without an allocation
+        let (strategy_id, _) = pallet::Pallet::<T>::ensure_policy_strategy(policy_id, allocation)?;
+
+        if allocation_ppm == 0 {
+            log::info!(
+                target: "prmx-xcm-capital",
+                "📊 Auto-allocation disabled (0%) for policy {}",
+                policy_id
+            );
             return Ok(());
         }
 
-        let allocation: T::Balance = allocation_u128.into();
+        if allocation_u128 == 0 {
+            return Ok(());
+        }
 
         log::info!(
             target: "prmx-xcm-capital",
-            "🔄 Auto-allocating {}% of policy {} capital ({} USDT) to DeFi (Pool 102)",
+            "🔄 Auto-allocating {}% of policy {} capital ({} USDT) to DeFi strategy {}",
             allocation_ppm as f64 / 10_000.0,
             policy_id,
-            allocation_u128
+            allocation_u128,
+            strategy_id
         );
 
         pallet::Pallet::<T>::do_allocate_to_defi(policy_id, allocation)
@@ -999,18 +2071,75 @@ impl<T: Config> CapitalApi<T::AccountId> for Pallet<T> {
         pallet::Pallet::<T>::do_ensure_local_liquidity(policy_id, required_local)
     }
 
+    fn fund_backstop(
+        policy_id: PolicyId,
+        premium: Self::Balance,
+    ) -> Result<Self::Balance, DispatchError> {
+        pallet::Pallet::<T>::do_fund_backstop(policy_id, premium)
+    }
+
     fn on_policy_settled(policy_id: PolicyId) -> Result<(), DispatchError> {
         pallet::Pallet::<T>::do_on_policy_settled(policy_id)
     }
 }
 
+// =============================================================================
+//                       PayoutRouter Implementation
+// =============================================================================
+
+impl<T: Config> PayoutRouter<T::AccountId> for Pallet<T> {
+    type Balance = T::Balance;
+
+    /// Move the payout into escrow and deliver it if the holder set a destination
+    fn route_payout(
+        policy_id: PolicyId,
+        holder: &T::AccountId,
+        pool_account: &T::AccountId,
+        amount: Self::Balance,
+    ) -> Result<bool, DispatchError> {
+        // Only USDT travels over the XCM route
+        if !pallet::Pallet::<T>::is_usdt_policy(policy_id) {
+            return Ok(false);
+        }
+
+        // A destination set by a previous holder lapses
+        let Some(entry) = pallet::PayoutDestinations::<T>::take(policy_id) else {
+            return Ok(false);
+        };
+        if entry.holder != *holder {
+            return Ok(false);
+        }
+
+        T::Assets::transfer(
+            T::UsdtAssetId::get(),
+            pool_account,
+            &pallet::Pallet::<T>::payout_escrow_account(),
+            amount,
+            Preservation::Expendable,
+        ).map_err(|_| pallet::Error::<T>::TransferFailed)?;
+
+        pallet::Pallet::<T>::attempt_payout_delivery(
+            policy_id,
+            pallet::FailedPayout {
+                holder: entry.holder,
+                destination: entry.destination,
+                amount,
+                attempts: 0,
+                next_retry: None,
+            },
+        );
+
+        Ok(true)
+    }
+}
+
 // =============================================================================
 //                       Mock XCM Strategy Interface
 // =============================================================================
 
 /// Mock implementation of XcmStrategyInterface for testing.
 ///
-/// This mock simulates Hydration Stableswap Pool 102 entry/exit without actual XCM calls.
+/// This mock simulates stableswap pool entry/exit without actual XCM calls.
 /// The yield/loss can be configured via the MockYieldRatePpm storage.
 pub struct MockXcmStrategyInterface<T>(core::marker::PhantomData<T>);
 
@@ -1019,13 +2148,17 @@ impl<T: Config> XcmStrategyInterface for MockXcmStrategyInterface<T> {
     type AccountId = T::AccountId;
 
     /// Enter strategy: LP shares = principal (1:1 mapping for simplicity)
-    fn enter_strategy(principal: Self::Balance) -> Result<u128, DispatchError> {
+    fn enter_strategy(
+        strategy: &CapitalStrategy,
+        principal: Self::Balance,
+    ) -> Result<u128, DispatchError> {
         // In mock, LP shares are 1:1 with principal
         let principal_u128: u128 = principal.into();
         
         log::info!(
             target: "prmx-xcm-capital",
-            "🔧 [MOCK] Entering DeFi strategy (Pool 102) with {} principal, minting {} LP shares",
+            "🔧 [MOCK] Entering DeFi strategy (pool {}) with {} principal, minting {} LP shares",
+            strategy.pool_id,
             principal_u128,
             principal_u128
         );
@@ -1035,6 +2168,7 @@ impl<T: Config> XcmStrategyInterface for MockXcmStrategyInterface<T> {
 
     /// Exit strategy: apply mock yield rate to determine realised amount
     fn exit_strategy(
+        strategy: &CapitalStrategy,
         shares: u128,
         pool_account: &Self::AccountId,
     ) -> Result<Self::Balance, DispatchError> {
@@ -1059,7 +2193,8 @@ impl<T: Config> XcmStrategyInterface for MockXcmStrategyInterface<T> {
 
         log::info!(
             target: "prmx-xcm-capital",
-            "🔧 [MOCK] Exiting DeFi strategy (Pool 102) with {} LP shares, yield_rate={}ppm, realised={}",
+            "🔧 [MOCK] Exiting DeFi strategy (pool {}) with {} LP shares, yield_rate={}ppm, realised={}",
+            strategy.pool_id,
             shares,
             yield_rate_ppm,
             realised_u128
@@ -1086,7 +2221,8 @@ impl<T: Config> XcmStrategyInterface for MockXcmStrategyInterface<T> {
             realised
         };
 
-        if actual_transfer > Zero::zero() {
+        // Rebalancing exits to the DAO account itself
+        if actual_transfer > Zero::zero() && *pool_account != T::DaoAccountId::get() {
             T::Assets::transfer(
                 T::UsdtAssetId::get(),
                 &T::DaoAccountId::get(),
@@ -1099,4 +2235,34 @@ impl<T: Config> XcmStrategyInterface for MockXcmStrategyInterface<T> {
         // Return actual amount transferred (may be less than expected due to insolvency)
         Ok(actual_transfer)
     }
+
+    /// Deliver payout: burn it from `source`, as the reserve withdraw to Asset Hub
+    /// would, or fail while MockPayoutFailure is set
+    fn deliver_payout(
+        destination: &PayoutDestination,
+        amount: Self::Balance,
+        source: &Self::AccountId,
+    ) -> Result<(), DispatchError> {
+        if pallet::MockPayoutFailure::<T>::get() {
+            return Err(DispatchError::Other("Mock payout delivery failed"));
+        }
+
+        T::Assets::burn_from(
+            T::UsdtAssetId::get(),
+            source,
+            amount,
+            Preservation::Expendable,
+            Precision::Exact,
+            Fortitude::Polite,
+        ).map_err(|_| DispatchError::Other("Mock payout burn failed"))?;
+
+        log::info!(
+            target: "prmx-xcm-capital",
+            "🔧 [MOCK] Delivered {} USDT payout to para {}",
+            amount.into(),
+            destination.para_id
+        );
+
+        Ok(())
+    }
 }
@@ -0,0 +1,104 @@
+//! # Storage Migrations
+//!
+//! Versioned migrations for `pallet_prmx_xcm_capital`, run from the runtime's
+//! `SingleBlockMigrations`. Each one only runs when the on-chain storage version
+//! matches its source version, so it is a no-op on chains started from a
+//! genesis that already has the new layout.
+//!
+//! - `MigrateV0ToV1`: introduce the strategy registry. Before version 1 every
+//!   position was implicitly in Hydration Stableswap Pool 102, so that pool is
+//!   registered as strategy 0 and holds every existing position.
+
+use crate::{
+    CapitalStrategy, Config, Pallet, PolicyLpPositions, PolicyStrategy, StrategyAccount,
+    StrategyAccounts, Strategies,
+};
+use frame_support::{
+    migrations::VersionedMigration,
+    traits::{Get, UncheckedOnRuntimeUpgrade},
+    weights::Weight,
+};
+use sp_runtime::traits::Saturating;
+
+#[cfg(feature = "try-runtime")]
+use alloc::vec::Vec;
+#[cfg(feature = "try-runtime")]
+use codec::{Decode, Encode};
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+/// Register Hydration Stableswap Pool 102 as the first strategy and assign it
+/// every existing LP position. Use through `MigrateV0ToV1`, which checks and
+/// bumps the storage version.
+pub struct UncheckedMigrateV0ToV1<T>(core::marker::PhantomData<T>);
+
+impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV0ToV1<T> {
+    fn on_runtime_upgrade() -> Weight {
+        if Strategies::<T>::iter_keys().next().is_some() {
+            // Registry already populated
+            return T::DbWeight::get().reads(1);
+        }
+
+        let strategy_id = Pallet::<T>::insert_strategy(CapitalStrategy::hydration_pool_102());
+        let mut reads = 2u64;
+        let mut writes = 2u64;
+
+        let mut account = StrategyAccount::<T>::default();
+        let mut assigned = 0u64;
+        for (policy_id, pos) in PolicyLpPositions::<T>::iter() {
+            PolicyStrategy::<T>::insert(policy_id, strategy_id);
+            account.principal = account.principal.saturating_add(pos.principal_usdt);
+            account.lp_shares = account.lp_shares.saturating_add(pos.lp_shares);
+            assigned += 1;
+        }
+        reads += assigned;
+        writes += assigned;
+
+        StrategyAccounts::<T>::insert(strategy_id, account);
+        writes += 1;
+
+        log::info!(
+            target: "prmx-xcm-capital",
+            "Registered Hydration Pool 102 as strategy {}; assigned {} existing positions",
+            strategy_id,
+            assigned
+        );
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+        Ok((PolicyLpPositions::<T>::iter_keys().count() as u64).encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+        let positions = u64::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+        frame_support::ensure!(
+            Strategies::<T>::iter_keys().next().is_some(),
+            "no strategy registered"
+        );
+        for policy_id in PolicyLpPositions::<T>::iter_keys() {
+            frame_support::ensure!(
+                PolicyStrategy::<T>::contains_key(policy_id),
+                "position without a strategy"
+            );
+        }
+        frame_support::ensure!(
+            PolicyLpPositions::<T>::iter_keys().count() as u64 == positions,
+            "positions changed by migration"
+        );
+        Ok(())
+    }
+}
+
+/// Strategy registry introduced with Hydration Pool 102 as strategy 0,
+/// storage version 0 to 1
+pub type MigrateV0ToV1<T> = VersionedMigration<
+    0,
+    1,
+    UncheckedMigrateV0ToV1<T>,
+    Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+>;
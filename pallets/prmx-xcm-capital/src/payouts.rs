@@ -0,0 +1,101 @@
+//! # Cross-Chain Payout Delivery
+//!
+//! A policy holder can have the holder's share of a triggered payout delivered to
+//! a beneficiary account on another parachain, such as Asset Hub, instead of
+//! their PRMX account. At settlement the payout moves from the policy pool into
+//! the payout escrow account and is sent from there through
+//! `XcmStrategyInterface::deliver_payout`, the same Asset Hub reserve route the
+//! yield strategies use.
+//!
+//! A delivery that fails stays in escrow and is queued in `FailedPayouts`. It is
+//! retried with exponential backoff, at most `MAX_PAYOUT_ATTEMPTS` times in
+//! total; until it succeeds the holder can `claim_failed_payout` to be paid on
+//! PRMX instead.
+//!
+//! A destination only applies while the account that set it still holds the
+//! policy, so a transferred policy pays its new holder locally until they set
+//! their own.
+
+/// Delivery attempts, including the one at settlement, before automatic retries stop
+pub const MAX_PAYOUT_ATTEMPTS: u32 = 5;
+
+/// Blocks before the first retry (~10 minutes); each later retry waits twice as long
+pub const PAYOUT_RETRY_BASE_BLOCKS: u32 = 100;
+
+/// Blocks between sweeps of the failed payout queue
+pub const BLOCKS_PER_PAYOUT_RETRY_CHECK: u32 = 10;
+
+/// Most failed payouts retried per sweep
+pub const MAX_PAYOUT_RETRIES_PER_BLOCK: u32 = 8;
+
+/// Beneficiary of a policy's payout on another chain
+#[derive(
+    codec::Encode,
+    codec::Decode,
+    codec::DecodeWithMemTracking,
+    codec::MaxEncodedLen,
+    scale_info::TypeInfo,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Debug,
+)]
+pub struct PayoutDestination {
+    /// Parachain the beneficiary account lives on (1000 for Asset Hub)
+    pub para_id: u32,
+    /// 32-byte beneficiary account on that chain
+    pub account: [u8; 32],
+}
+
+impl PayoutDestination {
+    /// Whether the destination names a parachain and a non-zero account
+    pub fn is_valid(&self) -> bool {
+        self.para_id > 0 && self.account != [0u8; 32]
+    }
+}
+
+/// Blocks to wait before retrying a delivery that has failed `attempts` times,
+/// or `None` once automatic retries are exhausted
+pub fn retry_delay(attempts: u32) -> Option<u32> {
+    if attempts == 0 || attempts >= MAX_PAYOUT_ATTEMPTS {
+        return None;
+    }
+    Some(PAYOUT_RETRY_BASE_BLOCKS.saturating_mul(1u32 << (attempts - 1)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_destination_validity() {
+        let asset_hub = PayoutDestination {
+            para_id: 1000,
+            account: [7u8; 32],
+        };
+        assert!(asset_hub.is_valid());
+        assert!(!PayoutDestination {
+            para_id: 0,
+            ..asset_hub
+        }
+        .is_valid());
+        assert!(!PayoutDestination {
+            account: [0u8; 32],
+            ..asset_hub
+        }
+        .is_valid());
+    }
+
+    #[test]
+    fn test_retry_delay_doubles_until_attempts_run_out() {
+        assert_eq!(retry_delay(0), None);
+        assert_eq!(retry_delay(1), Some(PAYOUT_RETRY_BASE_BLOCKS));
+        assert_eq!(retry_delay(2), Some(2 * PAYOUT_RETRY_BASE_BLOCKS));
+        assert_eq!(
+            retry_delay(MAX_PAYOUT_ATTEMPTS - 1),
+            Some(8 * PAYOUT_RETRY_BASE_BLOCKS)
+        );
+        assert_eq!(retry_delay(MAX_PAYOUT_ATTEMPTS), None);
+    }
+}
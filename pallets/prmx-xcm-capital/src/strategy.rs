@@ -0,0 +1,169 @@
+//! # Capital Strategy Registry
+//!
+//! Governance registers yield strategies (a stableswap pool on a target
+//! parachain) with a cap on the share of all allocated capital each may hold.
+//! Every policy is assigned one strategy when its capital is first allocated;
+//! its position stays there until governance rebalances it to another.
+//!
+//! New capital goes to the active strategy that stays within its cap and uses
+//! the smallest fraction of that cap, so capital spreads across strategies in
+//! proportion to their caps. Caps are only checked when capital flows in:
+//! unwinding other strategies can leave a strategy above its cap, after which
+//! it receives nothing new until its share falls again.
+
+use crate::xcm_config::{HYDRATION_PARA_ID, STABLESWAP_POOL_ID};
+
+/// One in parts per million
+const PPM: u128 = 1_000_000;
+
+/// Strategy identifier, assigned sequentially from 0
+pub type StrategyId = u32;
+
+/// Yield strategy registered by governance
+#[derive(
+    codec::Encode,
+    codec::Decode,
+    codec::DecodeWithMemTracking,
+    codec::MaxEncodedLen,
+    scale_info::TypeInfo,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Debug,
+)]
+pub struct CapitalStrategy {
+    /// Stableswap pool on the target chain
+    pub pool_id: u32,
+    /// Parachain the pool lives on
+    pub para_id: u32,
+    /// Largest share of total allocated capital the strategy may hold, in ppm
+    pub max_allocation_ppm: u32,
+    /// Inactive strategies take no new capital
+    pub active: bool,
+}
+
+impl CapitalStrategy {
+    /// Hydration Stableswap Pool 102 with no cap, the strategy every chain
+    /// starts with
+    pub fn hydration_pool_102() -> Self {
+        Self {
+            pool_id: STABLESWAP_POOL_ID,
+            para_id: HYDRATION_PARA_ID,
+            max_allocation_ppm: PPM as u32,
+            active: true,
+        }
+    }
+
+    /// Cap must be above zero and at most 100%
+    pub fn is_valid(&self) -> bool {
+        self.max_allocation_ppm > 0 && self.max_allocation_ppm as u128 <= PPM
+    }
+}
+
+/// Whether a strategy holding `principal` of `total` allocated capital is
+/// within `max_allocation_ppm`
+pub fn within_cap(principal: u128, total: u128, max_allocation_ppm: u32) -> bool {
+    principal.saturating_mul(PPM) <= total.saturating_mul(max_allocation_ppm as u128)
+}
+
+/// Strategy to place `amount` of new capital in, given `(id, principal,
+/// max_allocation_ppm)` of every active strategy and the `total` allocated
+/// before the new capital. Picks the strategy using the smallest fraction of
+/// its cap afterwards (lowest id on ties); `None` if every strategy would
+/// exceed its cap.
+pub fn select_strategy(
+    candidates: impl IntoIterator<Item = (StrategyId, u128, u32)>,
+    total: u128,
+    amount: u128,
+) -> Option<StrategyId> {
+    let total_after = total.saturating_add(amount);
+    candidates
+        .into_iter()
+        .map(|(id, principal, max_ppm)| (id, principal.saturating_add(amount), max_ppm))
+        .filter(|(_, principal_after, max_ppm)| {
+            *max_ppm > 0 && within_cap(*principal_after, total_after, *max_ppm)
+        })
+        .fold(
+            None,
+            |best: Option<(StrategyId, u128, u32)>, candidate| match best {
+                // a / max_a < b / max_b  <=>  a * max_b < b * max_a
+                Some(best)
+                    if best.1.saturating_mul(candidate.2 as u128)
+                        <= candidate.1.saturating_mul(best.2 as u128) =>
+                {
+                    Some(best)
+                }
+                _ => Some(candidate),
+            },
+        )
+        .map(|(id, _, _)| id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_valid() {
+        assert!(CapitalStrategy::hydration_pool_102().is_valid());
+        let strategy = CapitalStrategy::hydration_pool_102();
+        assert!(!CapitalStrategy {
+            max_allocation_ppm: 0,
+            ..strategy
+        }
+        .is_valid());
+        assert!(!CapitalStrategy {
+            max_allocation_ppm: 1_000_001,
+            ..strategy
+        }
+        .is_valid());
+    }
+
+    #[test]
+    fn test_within_cap() {
+        assert!(within_cap(600, 1_000, 600_000));
+        assert!(!within_cap(601, 1_000, 600_000));
+        assert!(within_cap(0, 0, 1));
+        assert!(!within_cap(1, 1, 999_999));
+    }
+
+    #[test]
+    fn test_first_capital_needs_an_uncapped_strategy() {
+        // With nothing allocated yet, any capped strategy would hold 100%
+        assert_eq!(
+            select_strategy([(0, 0, 600_000), (1, 0, 400_000)], 0, 100),
+            None
+        );
+        assert_eq!(
+            select_strategy([(0, 0, 1_000_000), (1, 0, 400_000)], 0, 100),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn test_capital_spreads_in_proportion_to_caps() {
+        // 0 holds 700 of 1_000 (cap 100%), 1 holds 300 (cap 50%):
+        // after +100, 0 would use 800/1.0 and 1 would use 400/0.5 = 800; tie → lowest id
+        assert_eq!(
+            select_strategy([(0, 700, 1_000_000), (1, 300, 500_000)], 1_000, 100),
+            Some(0)
+        );
+        // 1 further below its cap takes the capital
+        assert_eq!(
+            select_strategy([(0, 800, 1_000_000), (1, 200, 500_000)], 1_000, 100),
+            Some(1)
+        );
+        // ... unless it would exceed the cap: 460 + 100 > 50% of 1_100
+        assert_eq!(
+            select_strategy([(0, 540, 1_000_000), (1, 460, 500_000)], 1_000, 100),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn test_no_candidates() {
+        assert_eq!(select_strategy([], 1_000, 100), None);
+        assert_eq!(select_strategy([(0, 0, 0)], 0, 0), None);
+    }
+}
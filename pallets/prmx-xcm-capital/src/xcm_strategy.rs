@@ -4,6 +4,8 @@
 //!
 //! This module implements the `XcmStrategyInterface` trait using real XCM
 //! for cross-chain capital management with Hydration Pool 102 via Asset Hub.
+//! The pool and target parachain come from the policy's registered
+//! `CapitalStrategy`; the flows below describe the default Hydration strategy.
 //!
 //! ## Prerequisites
 //!
@@ -27,12 +29,19 @@
 //! 2. InitiateReserveWithdraw to Asset Hub
 //! 3. DepositReserveAsset to PRMX
 //!
+//! ### Payout (deliver_payout):
+//! PRMX -> Asset Hub (-> destination parachain)
+//! 1. WithdrawAsset(USDT) from the payout escrow account
+//! 2. InitiateReserveWithdraw to Asset Hub
+//! 3. DepositAsset to the beneficiary on Asset Hub, or DepositReserveAsset to
+//!    the destination parachain and DepositAsset there
+//!
 //! ## Current Status
 //!
 //! XCM messages are constructed but NOT actually sent (TODO). This module
 //! returns expected values and logs intent for testing purposes only.
 
-use crate::{Config, XcmStrategyInterface};
+use crate::{CapitalStrategy, Config, PayoutDestination, XcmStrategyInterface};
 use alloc::vec;
 use alloc::vec::Vec;
 use codec::Encode;
@@ -98,7 +107,12 @@ pub fn asset_hub_location() -> Location {
 
 /// Hydration location
 pub fn hydration_location() -> Location {
-    Location::new(1, [Parachain(HYDRATION_PARA_ID)])
+    sibling_location(HYDRATION_PARA_ID)
+}
+
+/// Location of a sibling parachain hosting a strategy pool
+pub fn sibling_location(para_id: u32) -> Location {
+    Location::new(1, [Parachain(para_id)])
 }
 
 /// PRMX location (from Asset Hub perspective)
@@ -172,13 +186,15 @@ pub fn encode_remove_liquidity(
 //                       XCM Message Builders
 // =============================================================================
 
-/// Build XCM message for depositing USDT into Hydration Pool 102
+/// Build XCM message for depositing USDT into a stableswap pool
 ///
 /// This creates a multi-hop XCM that:
 /// 1. Transfers USDT to Asset Hub
-/// 2. Forwards USDT to Hydration
-/// 3. Executes stableswap.add_liquidity on Hydration
+/// 2. Forwards USDT to the pool's chain (`para_id`)
+/// 3. Executes stableswap.add_liquidity on `pool_id`
 pub fn build_deposit_xcm(
+    pool_id: u32,
+    para_id: u32,
     usdt_amount: u128,
     min_lp_shares: u128,
     dao_account_on_hydration: [u8; 32],
@@ -203,7 +219,7 @@ pub fn build_deposit_xcm(
     
     // Encoded stableswap.add_liquidity call
     let add_liquidity_call = encode_add_liquidity(
-        STABLESWAP_POOL_ID,
+        pool_id,
         usdt_amount,
         min_lp_shares,
     );
@@ -227,7 +243,7 @@ pub fn build_deposit_xcm(
                 // Forward to Hydration with deposit + transact
                 DepositReserveAsset {
                     assets: All.into(),
-                    dest: hydration_location(),
+                    dest: sibling_location(para_id),
                     xcm: Xcm(vec![
                         // On Hydration: Buy execution
                         BuyExecution {
@@ -266,12 +282,13 @@ pub fn build_deposit_xcm(
     ])
 }
 
-/// Build XCM message for withdrawing from Hydration Pool 102 back to PRMX
+/// Build XCM message for withdrawing from a stableswap pool back to PRMX
 ///
-/// This creates a multi-hop XCM that:
-/// 1. Executes stableswap.remove_liquidity_one_asset on Hydration
+/// This creates a multi-hop XCM, sent to the pool's chain, that:
+/// 1. Executes stableswap.remove_liquidity_one_asset on `pool_id`
 /// 2. Transfers resulting USDT via Asset Hub back to PRMX
 pub fn build_withdraw_xcm(
+    pool_id: u32,
     lp_shares: u128,
     min_usdt_out: u128,
     destination_account: [u8; 32],
@@ -280,7 +297,7 @@ pub fn build_withdraw_xcm(
     
     // Encoded stableswap.remove_liquidity_one_asset call
     let remove_liquidity_call = encode_remove_liquidity(
-        STABLESWAP_POOL_ID,
+        pool_id,
         USDT_HYDRATION_ID,
         lp_shares,
         min_usdt_out,
@@ -341,6 +358,76 @@ pub fn build_withdraw_xcm(
     ])
 }
 
+/// Build XCM message for delivering a USDT payout to a beneficiary
+///
+/// This creates an XCM, executed locally, that:
+/// 1. Withdraws the payout from the escrow account and sends it to Asset Hub
+/// 2. Deposits it to `beneficiary` on Asset Hub, or forwards it to `para_id`
+///    and deposits it there
+pub fn build_payout_xcm(para_id: u32, usdt_amount: u128, beneficiary: [u8; 32]) -> Xcm<()> {
+    let weight_limit = WeightLimit::Unlimited;
+
+    let usdt_asset = Asset {
+        id: AssetId(usdt_asset_hub_location()),
+        fun: Fungible(usdt_amount),
+    };
+
+    let beneficiary = Location::new(
+        0,
+        [AccountId32 {
+            network: None,
+            id: beneficiary,
+        }],
+    );
+
+    // Asset Hub is the reserve, so the payout either stays there or moves on
+    let on_asset_hub = if para_id == ASSET_HUB_PARA_ID {
+        vec![
+            BuyExecution {
+                fees: usdt_asset.clone(),
+                weight_limit: weight_limit.clone(),
+            },
+            DepositAsset {
+                assets: All.into(),
+                beneficiary,
+            },
+        ]
+    } else {
+        vec![
+            BuyExecution {
+                fees: usdt_asset.clone(),
+                weight_limit: weight_limit.clone(),
+            },
+            DepositReserveAsset {
+                assets: All.into(),
+                dest: sibling_location(para_id),
+                xcm: Xcm(vec![
+                    BuyExecution {
+                        fees: Asset {
+                            id: AssetId(usdt_asset_hub_location()),
+                            fun: Fungible(usdt_amount / 100), // Use 1% for fees
+                        },
+                        weight_limit,
+                    },
+                    DepositAsset {
+                        assets: All.into(),
+                        beneficiary,
+                    },
+                ]),
+            },
+        ]
+    };
+
+    Xcm(vec![
+        WithdrawAsset(usdt_asset.into()),
+        InitiateReserveWithdraw {
+            assets: All.into(),
+            reserve: asset_hub_location(),
+            xcm: Xcm(on_asset_hub),
+        },
+    ])
+}
+
 // =============================================================================
 //                       Live XCM Strategy Interface
 // =============================================================================
@@ -358,10 +445,13 @@ where
     type Balance = T::Balance;
     type AccountId = T::AccountId;
 
-    /// Enter the DeFi strategy by sending USDT to Hydration Pool 102.
+    /// Enter the DeFi strategy by sending USDT to the strategy's pool.
     ///
     /// Returns the expected number of LP shares (in v1, we estimate 1:1 for stableswap).
-    fn enter_strategy(principal: Self::Balance) -> Result<u128, DispatchError> {
+    fn enter_strategy(
+        strategy: &CapitalStrategy,
+        principal: Self::Balance,
+    ) -> Result<u128, DispatchError> {
         let principal_u128: u128 = principal.into();
         
         // For stableswap with stablecoins, LP shares are roughly 1:1 with deposit
@@ -373,12 +463,20 @@ where
         let dao_account: [u8; 32] = T::DaoAccountId::get().into();
         
         // Build the XCM message
-        let xcm = build_deposit_xcm(principal_u128, min_shares, dao_account);
+        let xcm = build_deposit_xcm(
+            strategy.pool_id,
+            strategy.para_id,
+            principal_u128,
+            min_shares,
+            dao_account,
+        );
         
         log::info!(
             target: "prmx-xcm-capital",
-            "📤 [LIVE XCM] Sending deposit XCM for {} USDT, expecting {} LP shares",
+            "📤 [LIVE XCM] Sending deposit XCM for {} USDT to pool {} on para {}, expecting {} LP shares",
             principal_u128,
+            strategy.pool_id,
+            strategy.para_id,
             expected_shares
         );
         
@@ -397,10 +495,11 @@ where
         Ok(expected_shares)
     }
 
-    /// Exit the DeFi strategy by withdrawing from Hydration Pool 102.
+    /// Exit the DeFi strategy by withdrawing from the strategy's pool.
     ///
     /// Returns the actual USDT amount realized (estimated in v1).
     fn exit_strategy(
+        strategy: &CapitalStrategy,
         shares: u128,
         _policy_pool_account: &Self::AccountId,
     ) -> Result<Self::Balance, DispatchError> {
@@ -412,12 +511,14 @@ where
         let destination: [u8; 32] = _policy_pool_account.clone().into();
         
         // Build the XCM message
-        let xcm = build_withdraw_xcm(shares, min_usdt, destination);
+        let xcm = build_withdraw_xcm(strategy.pool_id, shares, min_usdt, destination);
         
         log::info!(
             target: "prmx-xcm-capital",
-            "📤 [LIVE XCM] Sending withdraw XCM for {} LP shares, expecting {} USDT",
+            "📤 [LIVE XCM] Sending withdraw XCM for {} LP shares to pool {} on para {}, expecting {} USDT",
             shares,
+            strategy.pool_id,
+            strategy.para_id,
             expected_usdt
         );
         
@@ -427,11 +528,41 @@ where
             xcm.0.len()
         );
         
-        // TODO: Actually send the XCM to Hydration
+        // TODO: Actually send the XCM to sibling_location(strategy.para_id)
         // For Chopsticks testing, we'll verify the XCM construction is correct
         
         Ok(expected_usdt.into())
     }
+
+    /// Deliver a payout from the escrow account via Asset Hub.
+    fn deliver_payout(
+        destination: &PayoutDestination,
+        amount: Self::Balance,
+        _source: &Self::AccountId,
+    ) -> Result<(), DispatchError> {
+        let amount_u128: u128 = amount.into();
+
+        // Build the XCM message
+        let xcm = build_payout_xcm(destination.para_id, amount_u128, destination.account);
+
+        log::info!(
+            target: "prmx-xcm-capital",
+            "📤 [LIVE XCM] Sending payout XCM for {} USDT to para {}",
+            amount_u128,
+            destination.para_id
+        );
+
+        log::info!(
+            target: "prmx-xcm-capital",
+            "📤 [LIVE XCM] XCM message prepared with {} instructions",
+            xcm.0.len()
+        );
+
+        // TODO: Execute the XCM from `_source` using pallet_xcm::execute
+        // For Chopsticks testing, we'll verify the XCM construction is correct
+
+        Ok(())
+    }
 }
 
 // =============================================================================
@@ -0,0 +1,72 @@
+//! # Yield Surplus Split
+//!
+//! When a policy's DeFi position is unwound for more than the policy needs, the
+//! surplus is shared between the DAO, the policy's LP holders and (optionally)
+//! the policy holder as a premium rebate. The default keeps the original
+//! behaviour: the DAO receives all of it.
+
+/// One in parts per million
+const PPM: u128 = 1_000_000;
+
+/// Governance-set split of surplus yield, in parts per million
+#[derive(
+    codec::Encode,
+    codec::Decode,
+    codec::DecodeWithMemTracking,
+    codec::MaxEncodedLen,
+    scale_info::TypeInfo,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Debug,
+)]
+pub struct YieldSplit {
+    pub dao_ppm: u32,
+    pub lp_ppm: u32,
+    pub holder_ppm: u32,
+}
+
+impl Default for YieldSplit {
+    fn default() -> Self {
+        Self { dao_ppm: PPM as u32, lp_ppm: 0, holder_ppm: 0 }
+    }
+}
+
+impl YieldSplit {
+    /// Shares must add up to exactly 100%
+    pub fn is_valid(&self) -> bool {
+        self.dao_ppm as u128 + self.lp_ppm as u128 + self.holder_ppm as u128 == PPM
+    }
+
+    /// Split `surplus` into (dao, lp, holder) amounts. Rounding dust goes to the DAO.
+    pub fn apply(&self, surplus: u128) -> (u128, u128, u128) {
+        let lp = surplus.saturating_mul(self.lp_ppm as u128) / PPM;
+        let holder = surplus.saturating_mul(self.holder_ppm as u128) / PPM;
+        let dao = surplus.saturating_sub(lp).saturating_sub(holder);
+        (dao, lp, holder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_sends_everything_to_dao() {
+        let split = YieldSplit::default();
+        assert!(split.is_valid());
+        assert_eq!(split.apply(1_234_567), (1_234_567, 0, 0));
+    }
+
+    #[test]
+    fn test_split_with_dust_to_dao() {
+        let split = YieldSplit { dao_ppm: 500_000, lp_ppm: 400_000, holder_ppm: 100_000 };
+        assert!(split.is_valid());
+        assert_eq!(split.apply(1_000_000), (500_000, 400_000, 100_000));
+        assert_eq!(split.apply(7), (5, 2, 0));
+
+        assert!(!YieldSplit { dao_ppm: 500_000, lp_ppm: 400_000, holder_ppm: 0 }.is_valid());
+        assert!(!YieldSplit { dao_ppm: u32::MAX, lp_ppm: 1, holder_ppm: 0 }.is_valid());
+    }
+}
@@ -4,6 +4,9 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
+use alloc::vec::Vec;
 use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 
@@ -159,7 +162,7 @@ pub enum PolicyVersion {
     V2,
 }
 
-/// Event type for policy - determines how rainfall is measured/evaluated.
+/// Event type for policy - determines how rainfall (or snowfall) is measured/evaluated.
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Encode, Decode, DecodeWithMemTracking, TypeInfo, MaxEncodedLen, Default)]
 pub enum EventType {
     /// 24-hour rolling rainfall sum (V1 default)
@@ -167,6 +170,11 @@ pub enum EventType {
     Rainfall24hRolling,
     /// Cumulative rainfall over the entire coverage window (V2)
     CumulativeRainfallWindow,
+    /// Highest 24-hour snowfall depth during the coverage window, in tenths of mm (V2)
+    Snowfall24h,
+    /// 24-hour rainfall reaching the strike while the market's river gauge is at or
+    /// above its flood stage (V2)
+    FloodComposite,
 }
 
 /// V2 Oracle status - tracks the lifecycle of a V2 policy in the off-chain oracle.
@@ -223,6 +231,24 @@ pub const V2_MAX_DURATION_DAYS: u8 = 7;
 /// Manila market ID (the only market supporting V2 initially)
 pub const MANILA_MARKET_ID: MarketId = 0;
 
+// ============================================================================
+// Multi-Peril Quote Types
+// ============================================================================
+
+/// Maximum perils a V2 quote can carry besides its rainfall strike
+/// (e.g. rain + wind gust + max temperature)
+pub const MAX_ADDITIONAL_PERILS: u32 = 2;
+
+/// How the rainfall strike and additional perils of a multi-peril quote combine.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Encode, Decode, DecodeWithMemTracking, TypeInfo, MaxEncodedLen, Default)]
+pub enum PerilAggregation {
+    /// Triggers when any peril's threshold is met
+    #[default]
+    Any,
+    /// Triggers only when every peril's threshold is met
+    All,
+}
+
 // ============================================================================
 // V3 Types - P2P Climate Risk Market
 // ============================================================================
@@ -243,6 +269,13 @@ pub enum EventTypeV3 {
     WindGustMaxGte,
     /// Specific precipitation type occurred (rain, snow, ice, etc.)
     PrecipTypeOccurred,
+    /// Longest run of consecutive dry hours >= threshold (hours). An hour is dry
+    /// when its precipitation is below `V3_DRY_HOUR_MAX_PRECIP_MM_X1000`.
+    DryStreakGte,
+    /// A tropical cyclone of at least the threshold category passed within the
+    /// threshold radius of the location (see `CycloneThresholdV3`). Driven by
+    /// storm-track advisories rather than station observations.
+    CycloneWithinGte,
 }
 
 impl Default for EventTypeV3 {
@@ -263,6 +296,10 @@ pub enum UnitV3 {
     MpsX1000,
     /// Precipitation type bitmask (rain=1, snow=2, ice=4, etc.)
     PrecipTypeMask,
+    /// Whole hours (not scaled)
+    Hours,
+    /// Saffir-Simpson category and radius packed as `radius_km * 10 + category`
+    CycloneCategoryWithinKm,
 }
 
 impl Default for UnitV3 {
@@ -280,6 +317,46 @@ pub struct ThresholdV3 {
     pub unit: UnitV3,
 }
 
+/// Largest cover radius of a `CycloneWithinGte` threshold (km)
+pub const V3_MAX_CYCLONE_RADIUS_KM: u32 = 500;
+
+/// Threshold of a `CycloneWithinGte` event: a storm of at least `category`
+/// passing within `radius_km` of the location.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct CycloneThresholdV3 {
+    /// Minimum Saffir-Simpson category (1-5)
+    pub category: u8,
+    /// Cover radius around the location (km)
+    pub radius_km: u32,
+}
+
+impl CycloneThresholdV3 {
+    /// Threshold value packing this threshold (`radius_km * 10 + category`)
+    pub fn to_value(&self) -> i64 {
+        self.radius_km as i64 * 10 + self.category as i64
+    }
+
+    /// Unpack a threshold value, `None` unless the category is 1-5 and the
+    /// radius is 1 to `V3_MAX_CYCLONE_RADIUS_KM`
+    pub fn from_value(value: i64) -> Option<Self> {
+        let category = (value % 10) as u8;
+        let radius_km = value / 10;
+        let radius_ok = (1..=V3_MAX_CYCLONE_RADIUS_KM as i64).contains(&radius_km);
+        ((1..=5).contains(&category) && radius_ok)
+            .then_some(Self { category, radius_km: radius_km as u32 })
+    }
+}
+
+/// Saffir-Simpson category of a storm's maximum sustained wind (m/s * 1000);
+/// 0 below hurricane strength
+pub fn saffir_simpson_category(max_wind_mps_x1000: i64) -> u8 {
+    const CATEGORY_MIN_MPS_X1000: [i64; 5] = [33_000, 43_000, 50_000, 58_000, 70_000];
+    CATEGORY_MIN_MPS_X1000
+        .iter()
+        .filter(|min| max_wind_mps_x1000 >= **min)
+        .count() as u8
+}
+
 /// Event specification for a V3 policy.
 /// Defines what weather event triggers a payout.
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Encode, Decode, DecodeWithMemTracking, TypeInfo, MaxEncodedLen, Default)]
@@ -292,6 +369,68 @@ pub struct EventSpecV3 {
     pub early_trigger: bool,
 }
 
+impl EventSpecV3 {
+    /// Whether the threshold is well-formed for the event type. Only packed
+    /// cyclone thresholds are checked; other values are priced as given.
+    pub fn has_valid_threshold(&self) -> bool {
+        match self.event_type {
+            EventTypeV3::CycloneWithinGte => {
+                self.threshold.unit == UnitV3::CycloneCategoryWithinKm
+                    && CycloneThresholdV3::from_value(self.threshold.value).is_some()
+            }
+            _ => true,
+        }
+    }
+}
+
+/// Most locations an area (multi-location) V3 policy can reference
+pub const V3_MAX_AREA_LOCATIONS: u32 = 8;
+
+/// How an area policy combines the aggregated values of its locations.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Encode, Decode, DecodeWithMemTracking, TypeInfo, MaxEncodedLen, Default)]
+pub enum AreaAggregationV3 {
+    /// Mean of the locations' values
+    #[default]
+    Average,
+    /// Value of the location closest to triggering: the highest, or the lowest for
+    /// `TempMinLte`. Precipitation types occurring anywhere count.
+    Max,
+}
+
+/// Locations of an area (corridor/regional) V3 policy.
+/// The first location is the policy's primary `location_id`.
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, DecodeWithMemTracking, TypeInfo, MaxEncodedLen)]
+pub struct AreaSpecV3 {
+    /// Location IDs from the V3 location registry
+    pub locations: sp_core::bounded::BoundedVec<u64, sp_core::ConstU32<V3_MAX_AREA_LOCATIONS>>,
+    /// How the locations' aggregated values combine
+    pub aggregation: AreaAggregationV3,
+}
+
+impl AreaSpecV3 {
+    /// At least two distinct locations, and an aggregation the event type supports
+    /// (precipitation type masks cannot be averaged; cyclone covers are a radius
+    /// around a single location)
+    pub fn is_valid_for(&self, event_type: EventTypeV3) -> bool {
+        let distinct = self
+            .locations
+            .iter()
+            .enumerate()
+            .all(|(i, id)| !self.locations[..i].contains(id));
+
+        self.locations.len() >= 2
+            && distinct
+            && event_type != EventTypeV3::CycloneWithinGte
+            && !(event_type == EventTypeV3::PrecipTypeOccurred
+                && self.aggregation == AreaAggregationV3::Average)
+    }
+
+    /// Primary location (the first one)
+    pub fn primary(&self) -> Option<u64> {
+        self.locations.first().copied()
+    }
+}
+
 /// Aggregation state variants for on-chain oracle state.
 /// Each variant matches an EventTypeV3 and holds the current aggregated value.
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Encode, Decode, DecodeWithMemTracking, TypeInfo, MaxEncodedLen)]
@@ -308,6 +447,11 @@ pub enum AggStateV3 {
     WindGustMax { max_mps_x1000: i64 },
     /// Bitmask of precipitation types that occurred
     PrecipTypeOccurred { mask: u8 },
+    /// Dry hours in the current run and the longest run seen so far
+    ConsecutiveDryHours { current_hours: u32, longest_hours: u32 },
+    /// Closest approach of any storm fix (km, `u32::MAX` before the first fix)
+    /// and the highest category reached within the cover radius
+    CycloneApproach { closest_km: u32, peak_category: u8 },
 }
 
 impl Default for AggStateV3 {
@@ -326,6 +470,10 @@ impl AggStateV3 {
             EventTypeV3::TempMinLte => Self::TempMin { min_c_x1000: i64::MAX },
             EventTypeV3::WindGustMaxGte => Self::WindGustMax { max_mps_x1000: 0 },
             EventTypeV3::PrecipTypeOccurred => Self::PrecipTypeOccurred { mask: 0 },
+            EventTypeV3::DryStreakGte => Self::ConsecutiveDryHours { current_hours: 0, longest_hours: 0 },
+            EventTypeV3::CycloneWithinGte => {
+                Self::CycloneApproach { closest_km: u32::MAX, peak_category: 0 }
+            }
         }
     }
 }
@@ -396,6 +544,9 @@ pub const V3_MIN_DURATION_DAYS: u8 = 1;
 /// Maximum duration for V3 policies (in days)
 pub const V3_MAX_DURATION_DAYS: u8 = 30;
 
+/// Precipitation below which an hour counts as dry for `DryStreakGte` (0.1mm)
+pub const V3_DRY_HOUR_MAX_PRECIP_MM_X1000: i64 = 100;
+
 /// Fixed payout per share in smallest units (100 USDT * 10^6 = 100_000_000)
 pub const V3_PAYOUT_PER_SHARE: u128 = 100_000_000;
 
@@ -417,3 +568,544 @@ pub const V3_OBSERVATIONS_TTL_SECS: u64 = 30 * 24 * 3600;
 /// Snapshots TTL in seconds (90 days)
 pub const V3_SNAPSHOTS_TTL_SECS: u64 = 90 * 24 * 3600;
 
+// ============================================================================
+// Emergency Pause
+// ============================================================================
+
+/// Protocol operations governance can pause independently
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Encode, Decode, DecodeWithMemTracking, TypeInfo, MaxEncodedLen)]
+pub enum PausableOperation {
+    /// Requesting and pricing new quotes
+    Quotes,
+    /// Creating new policies from quotes
+    PolicyCreation,
+    /// Settling policies (automatic, oracle-reported and manual)
+    Settlements,
+    /// Weather data and report submissions from oracle OCWs and reporters
+    OracleSubmissions,
+}
+
+/// Pause flags checked by the quote, policy and oracle pallets
+pub trait PauseApi {
+    /// Whether governance has paused `operation`
+    fn is_paused(operation: PausableOperation) -> bool;
+}
+
+/// No-op implementation for runtimes without an emergency pause
+impl PauseApi for () {
+    fn is_paused(_operation: PausableOperation) -> bool {
+        false
+    }
+}
+
+// ============================================================================
+// Policy Denomination
+// ============================================================================
+
+/// Asset each policy's premium, pool and payout are denominated in (the base
+/// asset of its market at creation). Implemented by the policy pallet and used
+/// by the orderbook, holdings, reinsurance and capital pallets.
+pub trait PolicyAssetApi<AssetId> {
+    /// Denomination asset of a policy, `None` if the policy is unknown
+    fn policy_asset(policy_id: PolicyId) -> Option<AssetId>;
+}
+
+/// No-op implementation: every policy falls back to the caller's USDT asset
+impl<AssetId> PolicyAssetApi<AssetId> for () {
+    fn policy_asset(_policy_id: PolicyId) -> Option<AssetId> {
+        None
+    }
+}
+
+// ============================================================================
+// OCW Errors
+// ============================================================================
+
+/// Why an offchain worker step failed, shared by the V1/V2 and V3 oracle OCWs
+#[derive(
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Debug,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum OcwErrorKind {
+    /// Endpoint answered with a non-200 status
+    Http(u16),
+    /// Request could not be sent, timed out or the connection failed
+    Timeout,
+    /// Response or stored data could not be decoded, or lacked a required field
+    Parse,
+    /// The fetch time budget or request quota is used up
+    Budget,
+    /// No local signing key, or signing failed
+    Keystore,
+    /// Required offchain configuration (API key, endpoint) is missing or invalid
+    Config,
+    /// The transaction pool rejected a submission
+    Submission,
+}
+
+impl OcwErrorKind {
+    /// Whether the same request may succeed on retry (timeouts, rate limiting
+    /// and server errors)
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Timeout => true,
+            Self::Http(code) => *code == 429 || *code >= 500,
+            _ => false,
+        }
+    }
+}
+
+/// OCW errors counted per `OcwErrorKind`, kept in offchain storage for
+/// diagnostics
+#[derive(
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Debug,
+    Default,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct OcwErrorCounts {
+    pub http: u32,
+    pub timeout: u32,
+    pub parse: u32,
+    pub budget: u32,
+    pub keystore: u32,
+    pub config: u32,
+    pub submission: u32,
+}
+
+impl OcwErrorCounts {
+    /// Count one error
+    pub fn record(&mut self, kind: OcwErrorKind) {
+        let count = match kind {
+            OcwErrorKind::Http(_) => &mut self.http,
+            OcwErrorKind::Timeout => &mut self.timeout,
+            OcwErrorKind::Parse => &mut self.parse,
+            OcwErrorKind::Budget => &mut self.budget,
+            OcwErrorKind::Keystore => &mut self.keystore,
+            OcwErrorKind::Config => &mut self.config,
+            OcwErrorKind::Submission => &mut self.submission,
+        };
+        *count = count.saturating_add(1);
+    }
+
+    /// Errors of every kind
+    pub fn total(&self) -> u32 {
+        self.http
+            .saturating_add(self.timeout)
+            .saturating_add(self.parse)
+            .saturating_add(self.budget)
+            .saturating_add(self.keystore)
+            .saturating_add(self.config)
+            .saturating_add(self.submission)
+    }
+}
+
+// ============================================================================
+// Oracle Runtime API
+// ============================================================================
+
+/// Hourly rainfall bucket as exposed by `PrmxOracleApi`
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo)]
+pub struct HourlyRainfall {
+    /// Hour index (unix_timestamp / 3600)
+    pub hour_index: u64,
+    /// Rainfall in tenths of mm
+    pub mm: Millimeters,
+    /// Unix timestamp when the bucket was written
+    pub fetched_at: u64,
+    /// Data source: 0 = current conditions, 1 = single provider, 2 = multi-provider aggregate
+    pub source: u8,
+}
+
+/// Current 24h rolling sum of a market compared to its strike
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo)]
+pub struct ThresholdStatus {
+    /// Market ID
+    pub market_id: MarketId,
+    /// Current 24h rolling sum in tenths of mm
+    pub rolling_sum_mm: Millimeters,
+    /// Market strike threshold in tenths of mm
+    pub strike_mm: Millimeters,
+    /// Whether the rolling sum has reached the strike
+    pub exceeded: bool,
+    /// Most recent bucket index included in the rolling sum
+    pub last_bucket_index: u64,
+}
+
+/// Data quality of an oracle provider account as exposed by `PrmxOracleProviderApi`
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo)]
+pub struct ProviderQuality {
+    /// Hourly readings submitted (first reading per market and hour)
+    pub submissions: u32,
+    /// Readings that arrived more than 3 hours after their hour ended
+    pub late_submissions: u32,
+    /// Readings compared with a final consensus median
+    pub scored_readings: u32,
+    /// Scored readings beyond the agreement tolerance from the median
+    pub outlier_readings: u32,
+    /// Mean distance from the consensus median in tenths of mm
+    pub mean_deviation_mm: Millimeters,
+    /// Unix timestamp of the latest submission
+    pub last_submission_at: u64,
+    /// Score used to weight the provider's readings, in parts per million
+    pub score_ppm: u32,
+}
+
+sp_api::decl_runtime_apis! {
+    /// Read-only access to V1/V2 oracle rainfall state for front-ends and indexers.
+    pub trait PrmxOracleApi {
+        /// 24h rolling rainfall sum ending at `timestamp` (None if the market does not exist)
+        fn rolling_sum_at(market_id: MarketId, timestamp: u64) -> Option<Millimeters>;
+
+        /// Hourly buckets with `from <= hour start <= to` (unix timestamps), ordered by hour
+        fn hourly_buckets(market_id: MarketId, from: u64, to: u64) -> Vec<HourlyRainfall>;
+
+        /// Current rolling sum versus strike (None if the market or its rainfall state is missing)
+        fn threshold_status(market_id: MarketId) -> Option<ThresholdStatus>;
+    }
+
+    /// Oracle provider data quality for governance tooling deciding on slashing
+    /// or removal.
+    pub trait PrmxOracleProviderApi<AccountId>
+    where
+        AccountId: codec::Codec,
+    {
+        /// Submission statistics and score of `account` (None if it never submitted)
+        fn provider_quality(account: AccountId) -> Option<ProviderQuality>;
+    }
+
+    /// Location lookups in the V3 oracle registry for front-ends creating requests.
+    pub trait PrmxOracleV3Api {
+        /// Nearest active V3 location to `latitude`/`longitude` (scaled by 1e6),
+        /// searched in the surrounding geohash cells (about 5 km)
+        fn find_nearest_location(latitude: i32, longitude: i32) -> Option<u64>;
+    }
+}
+
+// ============================================================================
+// Policy Runtime API
+// ============================================================================
+
+/// Policy lifecycle status as exposed by `PrmxPolicyApi`
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Encode, Decode, TypeInfo)]
+pub enum PolicyState {
+    Active,
+    Expired,
+    Settled,
+    Cancelled,
+    /// Bought ahead of coverage start and awaiting activation
+    Scheduled,
+}
+
+/// Settlement outcome of a policy
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo)]
+pub struct PolicySettlementSummary<Balance> {
+    /// Whether the insured event occurred
+    pub event_occurred: bool,
+    /// Amount paid to the policy holder
+    pub payout_to_holder: Balance,
+    /// Amount distributed to LP holders
+    pub returned_to_lps: Balance,
+    /// Unix timestamp of settlement
+    pub settled_at: u64,
+}
+
+/// Everything a wallet needs to show one policy
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo)]
+pub struct PolicyDetails<AccountId, Balance> {
+    pub policy_id: PolicyId,
+    /// Human-readable label, e.g. "manila-1"
+    pub label: Vec<u8>,
+    pub market_id: MarketId,
+    pub holder: AccountId,
+    pub status: PolicyState,
+    pub policy_version: PolicyVersion,
+    /// Coverage window (unix seconds)
+    pub coverage_start: u64,
+    pub coverage_end: u64,
+    pub shares: u128,
+    pub premium_paid: Balance,
+    pub max_payout: Balance,
+    /// USDT currently held in the policy pool (excludes capital allocated to DeFi)
+    pub pool_balance: Balance,
+    /// Strike in tenths of mm (V2 only)
+    pub strike_mm: Option<Millimeters>,
+    pub settlement: Option<PolicySettlementSummary<Balance>>,
+}
+
+/// Open exposure of a market across its active policies
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo)]
+pub struct MarketExposure<Balance> {
+    pub market_id: MarketId,
+    /// Number of active policies
+    pub active_policies: u32,
+    /// Total shares across active policies
+    pub total_shares: u128,
+    /// Sum of max payouts across active policies
+    pub total_max_payout: Balance,
+}
+
+/// Open notional against the governance exposure caps for a market and a
+/// location in it
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo)]
+pub struct NotionalUtilization<Balance> {
+    pub market_id: MarketId,
+    /// Sum of max payouts of open policies in the market
+    pub market_notional: Balance,
+    /// Market cap (None if uncapped)
+    pub max_per_market: Option<Balance>,
+    /// Sum of max payouts of open policies in the location's grid cell
+    pub location_notional: Balance,
+    /// Location cap (None if uncapped)
+    pub max_per_location: Option<Balance>,
+}
+
+/// Settlement path a policy would take, as reported by `PrmxSettlementApi`
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Encode, Decode, TypeInfo)]
+pub enum SimulatedSettlementPath {
+    /// The rolling sum reached the policy's trigger threshold during coverage
+    ThresholdTriggered,
+    /// Coverage and grace period have ended with final-hour data; settled at expiry
+    CoverageExpired,
+    /// Still in coverage and the trigger threshold has not been reached
+    NotDue,
+    /// Coverage has ended but the settlement grace period has not
+    GracePeriod,
+    /// The grace period has ended without data for the final hour of coverage
+    AwaitingData,
+    /// The policy is already settled or cancelled
+    Closed,
+}
+
+/// Would-be settlement of a policy at a given time, computed without changing state
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo)]
+pub struct SettlementSimulation<Balance> {
+    pub path: SimulatedSettlementPath,
+    /// Whether the insured event occurred (false unless `path` settles the policy)
+    pub event_occurred: bool,
+    /// Rolling sum behind the outcome in tenths of mm: the sum at the trigger,
+    /// otherwise the maximum sampled during coverage up to the simulated time
+    pub observed_mm: Millimeters,
+    /// Market strike in tenths of mm
+    pub strike_mm: Millimeters,
+    /// Rolling sum that settles the policy before coverage ends (the top tier for
+    /// tiered policies)
+    pub trigger_threshold_mm: Millimeters,
+    /// Unix timestamp of the sample at which the trigger threshold was reached
+    pub triggered_at: Option<u64>,
+    /// Payout for the event, including secondary-market positions' share
+    pub payout: Balance,
+    /// Part of `payout` paid to secondary-market positions
+    pub paid_to_positions: Balance,
+    /// Amount distributed to LP holders
+    pub returned_to_lps: Balance,
+}
+
+/// Running totals over a market's settled V1/V2 policies, updated at each
+/// settlement. Counts settlements from the runtime upgrade that introduced them.
+#[derive(
+    Clone, PartialEq, Eq, Debug, Default, Encode, Decode, DecodeWithMemTracking, TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct MarketSettlementStats<Balance> {
+    /// Settled policies
+    pub settled_policies: u32,
+    /// Settled policies whose event occurred
+    pub triggered_policies: u32,
+    /// Premiums paid for the settled policies, protocol fees included
+    pub total_premiums: Balance,
+    /// Payouts of the settled policies
+    pub total_payouts: Balance,
+    /// Highest observed metric any settlement was based on, in tenths of mm
+    /// (None until a settlement records one)
+    pub max_observed_mm: Option<Millimeters>,
+}
+
+sp_api::decl_runtime_apis! {
+    /// Read-only access to policies for wallets and the DAO dashboard.
+    pub trait PrmxPolicyApi<AccountId, Balance>
+    where
+        AccountId: codec::Codec,
+        Balance: codec::Codec,
+    {
+        /// IDs of all policies currently held by `account`
+        fn policies_of(account: AccountId) -> Vec<PolicyId>;
+
+        /// Details of a policy, including its settlement result once settled
+        fn policy_details(policy_id: PolicyId) -> Option<PolicyDetails<AccountId, Balance>>;
+
+        /// Active policies and total max payout for a market
+        fn active_exposure(market_id: MarketId) -> MarketExposure<Balance>;
+
+        /// Open notional and caps for a market and the location at
+        /// `latitude`/`longitude` (scaled by 1e6)
+        fn notional_utilization(
+            market_id: MarketId,
+            latitude: i32,
+            longitude: i32,
+        ) -> NotionalUtilization<Balance>;
+
+        /// Pool account of a V1/V2 or V3 policy (None if the policy does not exist)
+        fn pool_account_of(policy_id: PolicyId) -> Option<AccountId>;
+    }
+
+    /// Settlement dry runs for front-ends and the DAO, to preview settlements and
+    /// debug disagreements about an outcome.
+    pub trait PrmxSettlementApi<Balance>
+    where
+        Balance: codec::Codec,
+    {
+        /// Outcome and payout the automatic settlement would produce for a policy at
+        /// `at_timestamp` (unix seconds) from the oracle data currently on chain
+        /// (None if the policy or its market does not exist)
+        fn simulate_settlement(
+            policy_id: PolicyId,
+            at_timestamp: u64,
+        ) -> Option<SettlementSimulation<Balance>>;
+    }
+
+    /// Historical settlement totals per market for actuarial recalibration and
+    /// dashboards, without an external indexer.
+    pub trait PrmxSettlementAnalyticsApi<Balance>
+    where
+        Balance: codec::Codec,
+    {
+        /// Settlement totals of a market (all zero if nothing settled in it)
+        fn market_settlement_stats(market_id: MarketId) -> MarketSettlementStats<Balance>;
+    }
+}
+
+// ============================================================================
+// Trigger Log Runtime API
+// ============================================================================
+
+/// Threshold trigger of a V1 policy as recorded by the oracle
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo)]
+pub struct TriggerLogEntry<AccountId> {
+    pub trigger_id: u64,
+    pub market_id: MarketId,
+    pub policy_id: PolicyId,
+    /// Unix timestamp of the trigger
+    pub triggered_at: u64,
+    pub block_number: u32,
+    /// Rolling sum at the trigger in tenths of mm
+    pub rolling_sum_mm: Millimeters,
+    /// Strike that was reached in tenths of mm
+    pub strike_threshold: Millimeters,
+    pub holder: AccountId,
+    pub payout_amount: u128,
+    /// Merkle root of the hourly observations in the rolling window
+    pub observation_root: [u8; 32],
+    pub observation_count: u32,
+}
+
+/// One page of a market's trigger logs, newest first
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo)]
+pub struct TriggerLogPage<AccountId> {
+    pub logs: Vec<TriggerLogEntry<AccountId>>,
+    /// Cursor for the next (older) page; None on the last page
+    pub next_cursor: Option<u64>,
+}
+
+sp_api::decl_runtime_apis! {
+    /// Threshold trigger history per market for the UI, without scanning every log.
+    pub trait PrmxTriggerLogApi<AccountId>
+    where
+        AccountId: codec::Codec,
+    {
+        /// Up to `limit` (at most 100) of a market's retained trigger logs with IDs
+        /// below `cursor`, newest first. Pass None for the first page, then the
+        /// returned `next_cursor`.
+        fn trigger_logs(
+            market_id: MarketId,
+            cursor: Option<u64>,
+            limit: u32,
+        ) -> TriggerLogPage<AccountId>;
+    }
+}
+
+// ============================================================================
+// LP Orderbook Runtime API
+// ============================================================================
+
+/// Open orders at one price of an LP order book
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo)]
+pub struct OrderBookLevel<Balance> {
+    /// Price per LP share in the policy's asset units
+    pub price: Balance,
+    /// Unfilled LP shares across the orders at this price
+    pub quantity: u128,
+    /// Number of open orders at this price
+    pub orders: u32,
+}
+
+/// Aggregated bids and asks of a policy's LP order book
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo)]
+pub struct OrderBookDepth<Balance> {
+    pub policy_id: PolicyId,
+    /// Bid levels, best (highest) price first
+    pub bids: Vec<OrderBookLevel<Balance>>,
+    /// Ask levels, best (lowest) price first
+    pub asks: Vec<OrderBookLevel<Balance>>,
+}
+
+sp_api::decl_runtime_apis! {
+    /// Order book depth of policy LP tokens for trading front-ends.
+    pub trait PrmxLpOrderbookApi<Balance>
+    where
+        Balance: codec::Codec,
+    {
+        /// Up to `max_levels` best bid and ask levels of a policy's LP order book
+        fn order_book_depth(policy_id: PolicyId, max_levels: u32) -> OrderBookDepth<Balance>;
+    }
+}
+
+// ============================================================================
+// Health Runtime API
+// ============================================================================
+
+/// Monitoring counters as exposed by `PrmxHealthApi`
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo)]
+pub struct PrmxHealth {
+    /// Unix timestamp the counters were taken at
+    pub timestamp: u64,
+    /// Active V1/V2 and V3 policies
+    pub active_policies: u32,
+    /// Active V3 policies whose oracle snapshots are overdue
+    pub policies_overdue_for_snapshots: u32,
+    /// Markets without rainfall data for `stale_after_hours`
+    pub stale_markets: u32,
+    /// Hours without rainfall data after which a market is stale
+    pub stale_after_hours: u32,
+    /// Quotes waiting to be priced for longer than `pending_quote_max_age_secs`
+    pub stale_pending_quotes: u32,
+    /// Age in seconds after which a pending quote counts as stale
+    pub pending_quote_max_age_secs: u64,
+    /// Active V1/V2 and V3 policies past coverage end that are not settled yet
+    pub queued_expirations: u32,
+    /// Expired V1/V2 policies held back by missing data for their final hour
+    pub awaiting_settlement_data: u32,
+}
+
+sp_api::decl_runtime_apis! {
+    /// Chain health in one call, for node operators to scrape into monitoring.
+    pub trait PrmxHealthApi {
+        /// Current health counters
+        fn prmx_health() -> PrmxHealth;
+    }
+}
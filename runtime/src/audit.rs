@@ -0,0 +1,153 @@
+//! Settlement dossiers served by `prmx_audit::PrmxAuditApi`, assembled from the
+//! V1/V2 policy and oracle pallets or the V3 policy and oracle pallets.
+
+use super::*;
+use pallet_prmx_oracle::SettlementReason;
+use prmx_audit::{
+    FinalReport, OracleSnapshot, PayoutBreakdown, PolicyTerms, SettlementDossier, SettlementPath,
+    TriggerLogRow, UnderwriterPayout, DOSSIER_VERSION,
+};
+use prmx_primitives::OracleReportKindV3;
+
+/// Dossier of a V1/V2 or V3 policy
+pub fn settlement_dossier(policy_id: PolicyId) -> Option<SettlementDossier<AccountId, Balance>> {
+    market_dossier(policy_id).or_else(|| v3_dossier(policy_id))
+}
+
+fn market_dossier(policy_id: PolicyId) -> Option<SettlementDossier<AccountId, Balance>> {
+    let policy = PrmxPolicy::policies(policy_id)?;
+
+    let trigger_log = PrmxOracle::trigger_log_of(policy_id).map(|log| TriggerLogRow {
+        trigger_id: log.trigger_id,
+        triggered_at: log.triggered_at,
+        block_number: log.block_number,
+        rolling_sum_mm: log.rolling_sum_mm,
+        strike_threshold: log.strike_threshold,
+        holder: log.holder,
+        payout_amount: log.payout_amount,
+        observation_root: log.observation_root,
+        observation_count: log.observation_count,
+    });
+
+    // Results and records are stored together when a policy settles
+    let payout = PrmxPolicy::settlement_results(policy_id)
+        .zip(PrmxPolicy::settlement_records(policy_id))
+        .map(|(result, record)| PayoutBreakdown {
+            path: match record.reason {
+                SettlementReason::ThresholdTriggered => SettlementPath::ThresholdTriggered,
+                SettlementReason::CoverageExpired => SettlementPath::CoverageExpired,
+                SettlementReason::GovernanceForced => SettlementPath::GovernanceForced,
+                SettlementReason::OracleReport => SettlementPath::OracleReport,
+                SettlementReason::Manual => SettlementPath::Manual,
+            },
+            event_occurred: result.event_occurred,
+            payout_to_holder: result.payout_to_holder,
+            returned_to_lps: result.returned_to_lps,
+            observed_mm: record.observed_mm,
+            evidence_hash: record.evidence_hash,
+            settled_at: Some(result.settled_at),
+            settled_at_block: None,
+            underwriters: Vec::new(),
+        });
+
+    Some(SettlementDossier {
+        version: DOSSIER_VERSION,
+        policy_id,
+        holder: policy.holder,
+        terms: PolicyTerms::Market {
+            market_id: policy.market_id,
+            policy_version: policy.policy_version,
+            event_type: policy.event_type,
+            strike_mm: policy.strike_mm,
+            early_trigger: policy.early_trigger,
+            latitude: policy.latitude,
+            longitude: policy.longitude,
+        },
+        coverage_start: policy.coverage_start,
+        coverage_end: policy.coverage_end,
+        shares: policy.shares,
+        premium_paid: policy.premium_paid,
+        max_payout: policy.max_payout,
+        rainfall: PrmxOracle::hourly_buckets_in_range(
+            policy.market_id,
+            policy.coverage_start,
+            policy.coverage_end,
+        ),
+        oracle_snapshot: None,
+        final_report: None,
+        trigger_log,
+        payout,
+    })
+}
+
+fn v3_dossier(policy_id: PolicyId) -> Option<SettlementDossier<AccountId, Balance>> {
+    let policy = PrmxPolicyV3::policies(policy_id)?;
+
+    let oracle_snapshot = PrmxOracleV3::oracle_states(policy_id).map(|state| OracleSnapshot {
+        observed_until: state.observed_until,
+        agg_state: state.agg_state,
+        commitment: state.commitment,
+        snapshot_block: state.last_snapshot_block,
+        status: state.status,
+    });
+
+    let final_report = PrmxOracleV3::final_reports(policy_id).map(|report| FinalReport {
+        report_hash: pallet_oracle_v3::commitment::compute_final_report_hash(
+            policy_id,
+            report.kind,
+            report.observed_until,
+            &report.agg_state,
+            &report.commitment,
+        ),
+        kind: report.kind,
+        observed_until: report.observed_until,
+        agg_state: report.agg_state,
+        commitment: report.commitment,
+    });
+
+    let payout = PrmxPolicyV3::settlement_records(policy_id).map(|record| PayoutBreakdown {
+        path: match final_report.as_ref().map(|report| report.kind) {
+            Some(OracleReportKindV3::Maturity) => SettlementPath::V3Maturity,
+            Some(OracleReportKindV3::Trigger) => SettlementPath::V3Trigger,
+            None if record.triggered => SettlementPath::V3Trigger,
+            None => SettlementPath::V3Maturity,
+        },
+        event_occurred: record.triggered,
+        payout_to_holder: record.payout_to_holder,
+        returned_to_lps: record.distributed_to_lps,
+        observed_mm: None,
+        evidence_hash: [0u8; 32],
+        settled_at: None,
+        settled_at_block: Some(record.settled_at),
+        underwriters: PrmxPolicyV3::underwriter_breakdown(&policy, record.payout_to_holder)
+            .into_iter()
+            .map(|(underwriter, shares, premium, payout_obligation)| UnderwriterPayout {
+                underwriter,
+                shares,
+                premium,
+                payout_obligation,
+            })
+            .collect(),
+    });
+
+    Some(SettlementDossier {
+        version: DOSSIER_VERSION,
+        policy_id,
+        holder: policy.holder,
+        terms: PolicyTerms::V3 {
+            location_id: policy.location_id,
+            event_spec: policy.event_spec,
+            area: PrmxOracleV3::policy_metadata(policy_id).and_then(|meta| meta.area),
+        },
+        coverage_start: policy.coverage_start,
+        coverage_end: policy.coverage_end,
+        shares: policy.total_shares,
+        premium_paid: policy.premium_per_share.saturating_mul(policy.total_shares),
+        max_payout: policy.payout_per_share.saturating_mul(policy.total_shares),
+        rainfall: Vec::new(),
+        oracle_snapshot,
+        final_report,
+        trigger_log: None,
+        payout,
+    })
+}
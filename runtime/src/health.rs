@@ -0,0 +1,57 @@
+//! Monitoring counters served by `prmx_primitives::PrmxHealthApi`, gathered from
+//! the policy, oracle and quote pallets of both product lines.
+
+use super::*;
+use prmx_primitives::{PolicyStatusV3, PrmxHealth};
+
+/// Pending quotes older than this are reported as stale. The pricing OCW runs
+/// every block, so a quote waiting ten minutes points at a stuck worker.
+pub const PENDING_QUOTE_MAX_AGE_SECS: u64 = 10 * 60;
+
+/// Health counters at the current block
+pub fn prmx_health() -> PrmxHealth {
+    let now = Timestamp::now() / 1000;
+
+    let mut active_policies = 0u32;
+    let mut queued_expirations = 0u32;
+    for (_, policy) in pallet_prmx_policy::Policies::<Runtime>::iter() {
+        if policy.status == pallet_prmx_policy::PolicyStatus::Active {
+            active_policies = active_policies.saturating_add(1);
+            if now > policy.coverage_end {
+                queued_expirations = queued_expirations.saturating_add(1);
+            }
+        }
+    }
+    for (_, policy) in pallet_policy_v3::Policies::<Runtime>::iter() {
+        if policy.status == PolicyStatusV3::Active {
+            active_policies = active_policies.saturating_add(1);
+            if now > policy.coverage_end {
+                queued_expirations = queued_expirations.saturating_add(1);
+            }
+        }
+    }
+
+    let stale_pending_quotes = PrmxQuote::pending_quotes()
+        .iter()
+        .filter_map(|quote_id| PrmxQuote::quote_requests(quote_id))
+        .filter(|request| now.saturating_sub(request.requested_at) > PENDING_QUOTE_MAX_AGE_SECS)
+        .count() as u32;
+
+    let policies_overdue_for_snapshots =
+        pallet_oracle_v3::OverdueSnapshots::<Runtime>::iter_keys().count() as u32;
+    let awaiting_settlement_data =
+        pallet_prmx_oracle::AwaitingSettlementData::<Runtime>::iter_keys().count() as u32;
+
+    PrmxHealth {
+        timestamp: now,
+        active_policies,
+        policies_overdue_for_snapshots,
+        stale_markets: pallet_prmx_oracle::StaleMarkets::<Runtime>::iter_keys().count() as u32,
+        stale_after_hours: PrmxOracle::oracle_stale_after_hours()
+            .unwrap_or(pallet_prmx_oracle::DEFAULT_ORACLE_STALE_HOURS),
+        stale_pending_quotes,
+        pending_quote_max_age_secs: PENDING_QUOTE_MAX_AGE_SECS,
+        queued_expirations,
+        awaiting_settlement_data,
+    }
+}
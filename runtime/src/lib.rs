@@ -40,7 +40,7 @@ use frame_support::{
     weights::{constants::WEIGHT_REF_TIME_PER_SECOND, Weight},
 };
 use frame_system::EnsureRoot;
-use prmx_primitives::{EventSpecV3, PolicyId};
+use prmx_primitives::{AreaSpecV3, EventSpecV3, PolicyId};
 use pallet_prmx_holdings::HoldingsApi;
 use pallet_prmx_xcm_capital::CapitalApi;
 
@@ -77,6 +77,9 @@ pub type AssetId = u32;
 /// USDT Asset ID (constant)
 pub const USDT_ASSET_ID: AssetId = 1;
 
+mod audit;
+mod health;
+
 /// Opaque types block types.
 pub mod opaque {
     use super::*;
@@ -101,7 +104,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_name: alloc::borrow::Cow::Borrowed("prmx"),
     impl_name: alloc::borrow::Cow::Borrowed("prmx-node"),
     authoring_version: 1,
-    spec_version: 100,
+    spec_version: 103,
     impl_version: 1,
     apis: RUNTIME_API_VERSIONS,
     transaction_version: 1,
@@ -177,7 +180,7 @@ impl frame_system::Config for Runtime {
     type OnSetCode = ();
     type MaxConsumers = ConstU32<16>;
     type RuntimeTask = ();
-    type SingleBlockMigrations = ();
+    type SingleBlockMigrations = Migrations;
     type MultiBlockMigrator = ();
     type PreInherents = ();
     type PostInherents = ();
@@ -225,6 +228,21 @@ impl pallet_timestamp::Config for Runtime {
     type WeightInfo = ();
 }
 
+/// Chain time for every PRMX pallet: the timestamp inherent, counted in this
+/// solochain's own 6 second blocks until the first one is set. A parachain build
+/// passes `TimingMode::Parachain` with the relay parent number instead.
+pub struct PrmxTime;
+
+impl prmx_time::ChainTime for PrmxTime {
+    fn now_millis() -> u64 {
+        prmx_time::resolve_millis(
+            pallet_timestamp::Now::<Runtime>::get(),
+            System::block_number().into(),
+            prmx_time::TimingMode::Solochain { block_time_ms: 2 * MinimumPeriod::get() },
+        )
+    }
+}
+
 // =============================================================================
 //                          Balances Pallet (PRMX Token)
 // =============================================================================
@@ -316,6 +334,13 @@ impl pallet_assets::Config for Runtime {
 //                          PRMX Markets Pallet
 // =============================================================================
 
+parameter_types! {
+    /// Deposit bonded with a market listing proposal: 50 PRMX (18 decimals)
+    pub const ListingDeposit: Balance = 50_000_000_000_000_000_000;
+    /// Loss ratio margin rules run weekly (6s blocks)
+    pub const MarginEpochBlocks: BlockNumber = 7 * 24 * 600;
+}
+
 impl pallet_prmx_markets::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type Balance = Balance;
@@ -323,6 +348,10 @@ impl pallet_prmx_markets::Config for Runtime {
     type NewMarketNotifier = PrmxOracle;
     /// DAO operations require Root (Sudo) origin
     type DaoOrigin = EnsureRoot<AccountId>;
+    type TimeProvider = PrmxTime;
+    type DepositCurrency = Balances;
+    type ListingDeposit = ListingDeposit;
+    type MarginEpochBlocks = MarginEpochBlocks;
 }
 
 // =============================================================================
@@ -333,6 +362,9 @@ parameter_types! {
     /// Maximum LP holders per policy for automatic distribution
     /// LP tokens are now policy-specific, not market-specific
     pub const MaxLpHoldersPerPolicy: u32 = 100;
+    /// Redemptions are processed hourly (600 blocks at 6s)
+    pub const RedemptionEpochBlocks: BlockNumber = 600;
+    pub const MaxRedemptionRequests: u32 = 256;
 }
 
 impl pallet_prmx_holdings::Config for Runtime {
@@ -341,6 +373,15 @@ impl pallet_prmx_holdings::Config for Runtime {
     type Assets = Assets;
     type UsdtAssetId = ConstU32<USDT_ASSET_ID>;
     type MaxLpHoldersPerPolicy = MaxLpHoldersPerPolicy;
+    /// The DAO receives LP payouts and buys back redeemed LP shares
+    type DaoAccountId = DaoAccountId;
+    /// Redemptions are priced at the policy's capital per share
+    type RedemptionPrice = PrmxPolicy;
+    /// LP payouts are made in each policy's market base asset
+    type PolicyAssets = PrmxPolicy;
+    type RedemptionEpochBlocks = RedemptionEpochBlocks;
+    type MaxRedemptionRequests = MaxRedemptionRequests;
+    type WeightInfo = pallet_prmx_holdings::weights::SubstrateWeight<Runtime>;
 }
 
 // =============================================================================
@@ -354,17 +395,40 @@ parameter_types! {
     pub const ProbabilityApiUrl: &'static str = "http://34.51.195.144:19090/pricing";
     /// Maximum pending quotes
     pub const MaxPendingQuotes: u32 = 100;
+    /// Maximum quotes in one batch request
+    pub const MaxQuotesPerBatch: u32 = 20;
+    /// Refundable deposit reserved per quote request: 1 PRMX (18 decimals)
+    pub const QuoteDeposit: Balance = 1_000_000_000_000_000_000;
+    /// Quote requests allowed per account per window, room for a few full batches
+    pub const MaxQuoteRequestsPerWindow: u32 = 60;
+    /// Quote request rate limit window: 1 hour (6s blocks)
+    pub const QuoteRateLimitWindow: BlockNumber = 600;
 }
 
 impl pallet_prmx_quote::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type Balance = Balance;
     type MarketsApi = PrmxMarkets;
+    type TimeProvider = PrmxTime;
     type QuoteValiditySeconds = QuoteValiditySeconds;
     type ProbabilityApiUrl = ProbabilityApiUrl;
     type MaxPendingQuotes = MaxPendingQuotes;
+    type MaxQuotesPerBatch = MaxQuotesPerBatch;
+    /// Governance can set per-market fallback probabilities and bonding curves
+    type GovernanceOrigin = EnsureRoot<AccountId>;
+    /// Bonding-curve utilization is the policy pallet's open notional per market
+    type OpenNotional = PrmxPolicy;
     /// Quote authority ID for signing offchain worker transactions
     type AuthorityId = pallet_prmx_quote::crypto::QuoteAuthId;
+    /// Quoting can be paused through the emergency pause pallet
+    type Pause = PrmxPause;
+    type DepositCurrency = Balances;
+    type QuoteDeposit = QuoteDeposit;
+    type MaxQuoteRequestsPerWindow = MaxQuoteRequestsPerWindow;
+    type QuoteRateLimitWindow = QuoteRateLimitWindow;
+    type WeightInfo = pallet_prmx_quote::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = PrmxBenchmarkHelper;
 }
 
 // =============================================================================
@@ -375,6 +439,7 @@ parameter_types! {
     pub const MaxOrdersPerPriceLevel: u32 = 100;
     pub const MaxPriceLevels: u32 = 1000;
     pub const MaxOrdersPerUser: u32 = 50;
+    pub const MaxListingsPerPolicy: u32 = 50;
 }
 
 impl pallet_prmx_orderbook_lp::Config for Runtime {
@@ -388,6 +453,12 @@ impl pallet_prmx_orderbook_lp::Config for Runtime {
     type MaxOrdersPerPriceLevel = MaxOrdersPerPriceLevel;
     type MaxPriceLevels = MaxPriceLevels;
     type MaxOrdersPerUser = MaxOrdersPerUser;
+    /// Policy listings move shares through the policy pallet
+    type PolicyPositions = PrmxPolicy;
+    type MaxListingsPerPolicy = MaxListingsPerPolicy;
+    type TimeProvider = PrmxTime;
+    /// Trades are paid in each policy's market base asset
+    type PolicyAssets = PrmxPolicy;
 }
 
 // =============================================================================
@@ -415,6 +486,8 @@ parameter_types! {
     pub const UsdtAssetId: AssetId = USDT_ASSET_ID;
     /// Max policies per market
     pub const MaxPoliciesPerMarket: u32 = 10000;
+    /// Max secondary-market buyers per policy
+    pub const MaxPolicyBeneficiaries: u32 = 16;
 }
 
 impl pallet_prmx_policy::Config for Runtime {
@@ -429,12 +502,30 @@ impl pallet_prmx_policy::Config for Runtime {
     type DaoAccountId = DaoAccountId;
     type DaoCapitalAccountId = DaoCapitalAccountId;
     type MaxPoliciesPerMarket = MaxPoliciesPerMarket;
-    /// Capital management via XCM-based DeFi strategy (Hydration Pool 102)
+    type MaxPolicyBeneficiaries = MaxPolicyBeneficiaries;
+    /// Capital management via XCM-based DeFi strategies (governance strategy registry)
     type CapitalApi = PrmxXcmCapital;
-    /// Access to markets pallet for policy label generation
+    /// Reinsurance pool takes a governance-set share of each policy's exposure
+    type Reinsurance = PrmxReinsurance;
+    /// Holders can have payouts delivered to other chains via XCM
+    type PayoutRouter = PrmxXcmCapital;
+    /// No claim attestation provider yet; markets with claim verification enabled
+    /// release every claim
+    type ClaimVerifier = pallet_prmx_policy::NoOpClaimVerifier;
+    /// Access to markets pallet for policy labels and denomination assets
     type MarketsApi = PrmxMarkets;
     /// V2 oracle origin - only root/sudo can settle V2 policies
     type V2OracleOrigin = EnsureRoot<AccountId>;
+    /// Cancellation terms are set by root/sudo
+    type GovernanceOrigin = EnsureRoot<AccountId>;
+    /// New policies pause on markets whose oracle data is stale
+    type OracleStatus = PrmxOracle;
+    /// Policy creation and settlement can be paused through the emergency pause pallet
+    type Pause = PrmxPause;
+    type TimeProvider = PrmxTime;
+    type WeightInfo = pallet_prmx_policy::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = PrmxBenchmarkHelper;
 }
 
 // =============================================================================
@@ -444,6 +535,22 @@ impl pallet_prmx_policy::Config for Runtime {
 parameter_types! {
     /// Maximum length of AccuWeather location key
     pub const MaxLocationKeyLength: u32 = 64;
+    /// V2 reports can be disputed for ~1 hour (600 blocks at 6s) before settlement
+    pub const V2ChallengePeriod: BlockNumber = 600;
+    /// Bond to dispute a V2 report: 10 PRMX (18 decimals)
+    pub const V2DisputeBond: Balance = 10_000_000_000_000_000_000;
+    /// Matching V2 reporter votes needed to resolve a dispute without governance
+    pub const V2DisputeQuorum: u32 = 2;
+    /// Minimum oracle provider bond: 100 PRMX (18 decimals)
+    pub const OracleProviderBond: Balance = 100_000_000_000_000_000_000;
+    /// Unbonded provider funds stay slashable for ~7 days (6s blocks)
+    pub const ProviderUnbondingPeriod: BlockNumber = 7 * 24 * 600;
+    /// 10% of a provider's stake is slashed per offence
+    pub const ProviderSlashFraction: sp_runtime::Perbill = sp_runtime::Perbill::from_percent(10);
+    /// Readings more than 10mm from the quorum median are slashed
+    pub const ProviderSlashTolerance: u32 = 100;
+    /// Reward for settling an expired policy with `poke_settlement`: 1 PRMX (18 decimals)
+    pub const KeeperReward: Balance = 1_000_000_000_000_000_000;
 }
 
 /// Implements frame_system::offchain::SigningTypes for signed transaction submission
@@ -521,7 +628,24 @@ impl pallet_prmx_oracle::Config for Runtime {
     type MaxLocationKeyLength = MaxLocationKeyLength;
     /// Oracle authority ID for signing offchain worker transactions
     type AuthorityId = pallet_prmx_oracle::crypto::OracleAuthId;
-    type WeightInfo = ();
+    /// V2 dispute bonds are reserved in the native PRMX token
+    type Currency = Balances;
+    type V2ChallengePeriod = V2ChallengePeriod;
+    type V2DisputeBond = V2DisputeBond;
+    type V2DisputeQuorum = V2DisputeQuorum;
+    /// Providers bond in PRMX; genesis providers are bonded automatically
+    type OracleProviderBond = OracleProviderBond;
+    type ProviderUnbondingPeriod = ProviderUnbondingPeriod;
+    type ProviderSlashFraction = ProviderSlashFraction;
+    type ProviderSlashTolerance = ProviderSlashTolerance;
+    /// Oracle submissions and automatic settlement can be paused
+    type Pause = PrmxPause;
+    /// Keepers settling expired policies are paid in PRMX by the DAO
+    type KeeperReward = KeeperReward;
+    type KeeperRewardAccount = DaoAccountId;
+    type WeightInfo = pallet_prmx_oracle::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = PrmxBenchmarkHelper;
 }
 
 // =============================================================================
@@ -541,10 +665,40 @@ impl pallet_prmx_xcm_capital::Config for Runtime {
     type XcmStrategyInterface = pallet_prmx_xcm_capital::MockXcmStrategyInterface<Runtime>;
     /// Policy pool account derivation from policy pallet
     type PolicyPoolAccount = PrmxPolicy;
+    /// Only USDT-denominated policies are allocated or paid out via XCM
+    type PolicyAssets = PrmxPolicy;
     /// Holdings API for LP token ownership checks
     type HoldingsApi = PrmxHoldings;
 }
 
+// =============================================================================
+//                          PRMX Reinsurance Pallet
+// =============================================================================
+
+impl pallet_prmx_reinsurance::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type AssetId = AssetId;
+    type Assets = Assets;
+    type UsdtAssetId = ConstU32<USDT_ASSET_ID>;
+    /// DAO pays ceded premiums
+    type DaoAccountId = DaoAccountId;
+    /// Only USDT-denominated policies are ceded
+    type PolicyAssets = PrmxPolicy;
+    /// Cession rate is set by root/sudo
+    type GovernanceOrigin = EnsureRoot<AccountId>;
+}
+
+// =============================================================================
+//                          PRMX Pause Pallet
+// =============================================================================
+
+impl pallet_prmx_pause::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    /// Emergency pauses are set by root/sudo
+    type GovernanceOrigin = EnsureRoot<AccountId>;
+}
+
 // =============================================================================
 //                          PRMX V3 Pallets (P2P Climate Risk Market)
 // =============================================================================
@@ -554,6 +708,33 @@ parameter_types! {
     pub const MaxLocationKeyLengthV3: u32 = 64;
     /// Maximum LP holders per V3 policy
     pub const MaxLpHoldersPerPolicyV3: u32 = 200;
+    /// Matching oracle member attestations required to settle a V3 final report
+    pub const FinalReportQuorumV3: u32 = 1;
+    /// New V3 locations within 1 km of an active one are rejected as duplicates
+    pub const LocationDedupRadiusV3: u32 = 1_000;
+    /// V3 policies without a snapshot for 12 hours (two missed intervals) are overdue
+    pub const SnapshotOverdueBlocksV3: BlockNumber = 12 * 600;
+    /// Maturity reports wait 6 hours after a snapshot gap so the OCW can backfill
+    pub const OverdueGraceExtensionV3: BlockNumber = 6 * 600;
+    /// Oracle states checked per V3 snapshot gap sweep
+    pub const MaxSnapshotGapChecksV3: u32 = 64;
+    /// Settled V3 policies keep their oracle storage for 90 days before it can be purged
+    pub const SettledRetentionBlocksV3: BlockNumber = 90 * 24 * 600;
+    /// Maximum open underwriter offers per V3 RFQ
+    pub const MaxOffersPerRfqV3: u32 = 16;
+    /// Maximum distinct underwriters filling a V3 request / policy
+    pub const MaxUnderwritersPerPolicyV3: u32 = 64;
+    /// V3 fills must be fully collateralized, so a trigger can always draw the
+    /// full payout from the underwriters' collateral
+    pub const InitialMarginV3: sp_runtime::Permill = sp_runtime::Permill::from_percent(100);
+    /// V3 collateral accounts below 60% of their obligations get a margin call
+    pub const MaintenanceMarginV3: sp_runtime::Permill = sp_runtime::Permill::from_percent(60);
+    /// One day to top up before positions are reduced (6s blocks)
+    pub const MarginCallPeriodV3: BlockNumber = 24 * 600;
+    /// Collateral accounts checked per V3 margin sweep
+    pub const MaxMarginChecksPerBlockV3: u32 = 32;
+    /// Maximum open positions per V3 collateral account
+    pub const MaxPositionsPerUnderwriterV3: u32 = 128;
 }
 
 /// Implement CreateBare for any call type to enable unsigned transactions
@@ -581,6 +762,14 @@ impl pallet_oracle_v3::RequestExpiryApiV3 for RequestExpiryApiV3Adapter {
     fn expire_request(request_id: prmx_primitives::PolicyId) -> frame_support::dispatch::DispatchResult {
         pallet_market_v3::Pallet::<Runtime>::do_expire_request(request_id)
     }
+
+    fn get_expired_rfqs(current_time: u64) -> Vec<prmx_primitives::PolicyId> {
+        pallet_market_v3::Pallet::<Runtime>::get_expired_rfqs_internal(current_time)
+    }
+
+    fn expire_rfq(rfq_id: prmx_primitives::PolicyId) -> frame_support::dispatch::DispatchResult {
+        pallet_market_v3::Pallet::<Runtime>::do_expire_rfq(rfq_id)
+    }
 }
 
 /// V3 Oracle Pallet Configuration
@@ -595,7 +784,18 @@ impl pallet_oracle_v3::Config for Runtime {
     /// Request expiry API for detecting expired requests
     type RequestExpiryApi = RequestExpiryApiV3Adapter;
     type MaxLocationKeyLength = MaxLocationKeyLengthV3;
-    type WeightInfo = ();
+    /// Single-member quorum for dev; raise once multiple oracle operators are onboarded
+    type FinalReportQuorum = FinalReportQuorumV3;
+    type LocationDedupRadius = LocationDedupRadiusV3;
+    type SnapshotOverdueBlocks = SnapshotOverdueBlocksV3;
+    type OverdueGraceExtension = OverdueGraceExtensionV3;
+    type MaxSnapshotGapChecks = MaxSnapshotGapChecksV3;
+    type SettledRetentionBlocks = SettledRetentionBlocksV3;
+    /// OCW key for signed unsigned-transaction payloads (account must be an oracle member)
+    type AuthorityId = pallet_oracle_v3::crypto::OracleV3AuthId;
+    type WeightInfo = pallet_oracle_v3::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = PrmxBenchmarkHelper;
 }
 
 /// V3 Holdings API implementation using existing holdings pallet
@@ -612,6 +812,14 @@ impl pallet_policy_v3::HoldingsApiV3<AccountId> for HoldingsApiV3Adapter {
         pallet_prmx_holdings::Pallet::<Runtime>::mint_lp_tokens(policy_id, to, amount)
     }
 
+    fn burn_lp_tokens(
+        policy_id: prmx_primitives::PolicyId,
+        from: &AccountId,
+        amount: u128,
+    ) -> Result<(), sp_runtime::DispatchError> {
+        pallet_prmx_holdings::Pallet::<Runtime>::burn_lp_tokens(policy_id, from, amount)
+    }
+
     fn register_lp_holder(
         policy_id: prmx_primitives::PolicyId,
         holder: &AccountId,
@@ -652,9 +860,10 @@ impl pallet_policy_v3::CapitalApiV3<AccountId> for CapitalApiV3Adapter {
         policy_id: prmx_primitives::PolicyId,
         amount: Balance,
     ) -> Result<(), sp_runtime::DispatchError> {
-        // V3 uses its own policy pool account derivation (pallet_policy_v3)
+        // V3 pool accounts are recorded by pallet_policy_v3
         // Use the new method that accepts the pool account directly
-        let pool_account = pallet_policy_v3::Pallet::<Runtime>::policy_pool_account(policy_id);
+        let pool_account =
+            <PrmxPolicyV3 as pallet_policy_v3::PolicyPoolAccountApi<AccountId>>::policy_pool_account(policy_id);
         pallet_prmx_xcm_capital::Pallet::<Runtime>::do_allocate_to_defi_with_account(
             policy_id,
             amount,
@@ -673,12 +882,15 @@ impl pallet_policy_v3::CapitalApiV3<AccountId> for CapitalApiV3Adapter {
         policy_id: prmx_primitives::PolicyId,
         required_local: Balance,
     ) -> Result<(), sp_runtime::DispatchError> {
-        // V3 uses its own policy pool account derivation (pallet_policy_v3)
-        let pool_account = pallet_policy_v3::Pallet::<Runtime>::policy_pool_account(policy_id);
+        // V3 pool accounts are recorded by pallet_policy_v3.
+        // V3 LPs are not tracked in PrmxHoldings, so surplus yield goes to the DAO.
+        let pool_account =
+            <PrmxPolicyV3 as pallet_policy_v3::PolicyPoolAccountApi<AccountId>>::policy_pool_account(policy_id);
         pallet_prmx_xcm_capital::Pallet::<Runtime>::do_ensure_local_liquidity_with_account(
             policy_id,
             required_local,
             pool_account,
+            None,
         )
     }
 
@@ -689,6 +901,26 @@ impl pallet_policy_v3::CapitalApiV3<AccountId> for CapitalApiV3Adapter {
     }
 }
 
+/// V3 Collateral API implementation using the market pallet's collateral accounts
+pub struct CollateralApiV3Adapter;
+
+impl pallet_policy_v3::CollateralApiV3<AccountId> for CollateralApiV3Adapter {
+    type Balance = Balance;
+
+    fn draw_obligations(
+        policy_id: prmx_primitives::PolicyId,
+        to: &AccountId,
+    ) -> Result<Balance, sp_runtime::DispatchError> {
+        pallet_market_v3::Pallet::<Runtime>::draw_obligations(policy_id, to)
+    }
+
+    fn release_obligations(
+        policy_id: prmx_primitives::PolicyId,
+    ) -> Result<(), sp_runtime::DispatchError> {
+        pallet_market_v3::Pallet::<Runtime>::release_obligations(policy_id)
+    }
+}
+
 /// V3 Policy Pallet Configuration
 impl pallet_policy_v3::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
@@ -698,7 +930,10 @@ impl pallet_policy_v3::Config for Runtime {
     type UsdtAssetId = ConstU32<USDT_ASSET_ID>;
     type HoldingsApi = HoldingsApiV3Adapter;
     type CapitalApi = CapitalApiV3Adapter;
+    type CollateralApi = CollateralApiV3Adapter;
     type MaxLpHoldersPerPolicy = MaxLpHoldersPerPolicyV3;
+    type MaxUnderwritersPerPolicy = MaxUnderwritersPerPolicyV3;
+    type TimeProvider = PrmxTime;
     type WeightInfo = ();
 }
 
@@ -709,6 +944,10 @@ impl pallet_market_v3::LocationRegistryApiV3 for LocationRegistryV3Adapter {
     fn is_location_active(location_id: pallet_market_v3::LocationId) -> bool {
         pallet_oracle_v3::Pallet::<Runtime>::is_location_active(location_id)
     }
+
+    fn find_nearest_location(latitude: i32, longitude: i32) -> Option<pallet_market_v3::LocationId> {
+        pallet_oracle_v3::Pallet::<Runtime>::find_nearest_location(latitude, longitude)
+    }
 }
 
 /// V3 Policy API Adapter
@@ -718,22 +957,26 @@ impl pallet_market_v3::PolicyApiV3<AccountId, Balance> for PolicyApiV3Adapter {
     fn create_policy(
         policy_id: PolicyId,
         holder: AccountId,
+        underwriter: AccountId,
         location_id: pallet_market_v3::LocationId,
         event_spec: EventSpecV3,
         initial_shares: u128,
         premium_per_share: Balance,
         coverage_start: u64,
         coverage_end: u64,
+        area: Option<AreaSpecV3>,
     ) -> Result<(), sp_runtime::DispatchError> {
         pallet_policy_v3::Pallet::<Runtime>::create_policy(
             policy_id,
             holder,
+            underwriter,
             location_id,
             event_spec,
             initial_shares,
             premium_per_share,
             coverage_start,
             coverage_end,
+            area,
         )
     }
 
@@ -745,6 +988,14 @@ impl pallet_market_v3::PolicyApiV3<AccountId, Balance> for PolicyApiV3Adapter {
         pallet_policy_v3::Pallet::<Runtime>::add_shares_to_policy(policy_id, underwriter, shares)
     }
 
+    fn remove_shares_from_policy(
+        policy_id: PolicyId,
+        underwriter: AccountId,
+        shares: u128,
+    ) -> Result<AccountId, sp_runtime::DispatchError> {
+        pallet_policy_v3::Pallet::<Runtime>::remove_shares_from_policy(policy_id, underwriter, shares)
+    }
+
     fn allocate_to_defi(policy_id: PolicyId, amount: Balance) -> Result<(), sp_runtime::DispatchError> {
         pallet_policy_v3::Pallet::<Runtime>::allocate_to_defi(policy_id, amount)
     }
@@ -790,6 +1041,14 @@ impl pallet_market_v3::Config for Runtime {
     type HoldingsApi = HoldingsApiV3MarketAdapter;
     /// Only root/oracle can trigger request expiry
     type ExpiryOrigin = EnsureRoot<AccountId>;
+    type MaxOffersPerRfq = MaxOffersPerRfqV3;
+    type MaxUnderwritersPerRequest = MaxUnderwritersPerPolicyV3;
+    type InitialMargin = InitialMarginV3;
+    type MaintenanceMargin = MaintenanceMarginV3;
+    type MarginCallPeriod = MarginCallPeriodV3;
+    type MaxMarginChecksPerBlock = MaxMarginChecksPerBlockV3;
+    type MaxPositionsPerUnderwriter = MaxPositionsPerUnderwriterV3;
+    type TimeProvider = PrmxTime;
     type WeightInfo = ();
 }
 
@@ -799,6 +1058,273 @@ impl pallet_market_v3::Config for Runtime {
 
 use frame_support::traits::ConstBool;
 
+// =============================================================================
+//                          Benchmark Helpers
+// =============================================================================
+
+/// Cross-pallet setup for the PRMX pallet benchmarks
+#[cfg(feature = "runtime-benchmarks")]
+pub struct PrmxBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl PrmxBenchmarkHelper {
+    const LATITUDE: i32 = 14_599_500;
+    const LONGITUDE: i32 = 120_984_200;
+    /// Coverage window of benchmark quotes (secs)
+    const COVERAGE_WINDOW: (u64, u64) = (86_400, 3 * 86_400);
+
+    /// Create the USDT asset if needed and mint `amount` to `who`
+    fn fund_usdt(who: &AccountId, amount: Balance) {
+        use frame_support::traits::fungibles::{Create, Inspect, Mutate};
+
+        if !<Assets as Inspect<AccountId>>::asset_exists(USDT_ASSET_ID) {
+            <Assets as Create<AccountId>>::create(USDT_ASSET_ID, DaoAccountId::get(), true, 1)
+                .expect("USDT asset can be created");
+        }
+        <Assets as Mutate<AccountId>>::mint_into(USDT_ASSET_ID, who, amount)
+            .expect("USDT can be minted");
+    }
+
+    /// Create an open, V2-enabled market around Manila and return its id
+    fn create_market() -> pallet_prmx_markets::MarketId {
+        PrmxMarkets::dao_create_market(
+            RuntimeOrigin::root(),
+            b"Benchmark".to_vec(),
+            Self::LATITUDE,
+            Self::LONGITUDE,
+            8,
+            500,
+            USDT_ASSET_ID,
+            100_000_000,
+            pallet_prmx_markets::RiskParameters { dao_margin_bp: 2_000 },
+            pallet_prmx_markets::WindowRules::default(),
+        )
+        .expect("market parameters are valid");
+        let market_id = pallet_prmx_markets::NextMarketId::<Runtime>::get() - 1;
+        pallet_prmx_markets::V2EnabledMarkets::<Runtime>::insert(market_id, true);
+        market_id
+    }
+
+    /// Request and price a quote on `market_id` for `window`, funding the quote
+    /// deposit, premium and DAO capital
+    fn ready_quote_on(
+        requester: &AccountId,
+        market_id: pallet_prmx_markets::MarketId,
+        v2: bool,
+        window: (u64, u64),
+    ) -> prmx_primitives::QuoteId {
+        use frame_support::traits::Currency;
+        Balances::make_free_balance_be(requester, 10 * QuoteDeposit::get());
+
+        let origin = RuntimeOrigin::signed(requester.clone());
+        let (coverage_start, coverage_end) = window;
+        if v2 {
+            PrmxQuote::request_policy_quote_v2(
+                origin,
+                market_id,
+                coverage_start,
+                coverage_end,
+                Self::LATITUDE,
+                Self::LONGITUDE,
+                10,
+                2,
+                prmx_primitives::EventType::CumulativeRainfallWindow,
+                500,
+                Default::default(),
+                prmx_primitives::PerilAggregation::Any,
+            )
+        } else {
+            PrmxQuote::request_policy_quote(
+                origin,
+                market_id,
+                coverage_start,
+                coverage_end,
+                Self::LATITUDE,
+                Self::LONGITUDE,
+                10,
+            )
+        }
+        .expect("market is open");
+        let quote_id = *pallet_prmx_quote::PendingQuotes::<Runtime>::get()
+            .last()
+            .expect("quote was queued");
+        PrmxQuote::submit_quote(RuntimeOrigin::root(), quote_id, 50_000).expect("quote is pending");
+
+        Self::fund_usdt(requester, 1_000_000_000_000);
+        Self::fund_usdt(&DaoCapitalAccountId::get(), 1_000_000_000_000);
+        quote_id
+    }
+
+    /// Create a policy for `holder` on `market_id` and return its id
+    fn policy_on(
+        holder: &AccountId,
+        market_id: pallet_prmx_markets::MarketId,
+        v2: bool,
+    ) -> PolicyId {
+        let quote_id = Self::ready_quote_on(holder, market_id, v2, Self::COVERAGE_WINDOW);
+        PrmxPolicy::apply_coverage_with_quote(RuntimeOrigin::signed(holder.clone()), quote_id)
+            .expect("quote is ready and funded");
+        *PrmxPolicy::policies_of(holder).last().expect("policy was created")
+    }
+
+    fn rain_spec_v3() -> EventSpecV3 {
+        EventSpecV3 {
+            event_type: prmx_primitives::EventTypeV3::PrecipSumGte,
+            threshold: prmx_primitives::ThresholdV3 {
+                value: 50_000,
+                unit: prmx_primitives::UnitV3::MmX1000,
+            },
+            early_trigger: true,
+        }
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_prmx_quote::BenchmarkHelper for PrmxBenchmarkHelper {
+    fn open_market() -> pallet_prmx_markets::MarketId {
+        Self::create_market()
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_prmx_policy::BenchmarkHelper<AccountId> for PrmxBenchmarkHelper {
+    fn ready_quote(requester: &AccountId, v2: bool) -> prmx_primitives::QuoteId {
+        Self::ready_quote_on(requester, Self::create_market(), v2, Self::COVERAGE_WINDOW)
+    }
+
+    fn extension_quote(requester: &AccountId, policy_id: PolicyId) -> prmx_primitives::QuoteId {
+        let policy = PrmxPolicy::policies(policy_id).expect("policy exists");
+        let v2 = policy.policy_version == prmx_primitives::PolicyVersion::V2;
+        let window = (policy.coverage_end, policy.coverage_end + 2 * 86_400);
+        Self::ready_quote_on(requester, policy.market_id, v2, window)
+    }
+
+    fn group_quote(requester: &AccountId, members: u32) -> prmx_primitives::QuoteId {
+        let (coverage_start, coverage_end) = Self::COVERAGE_WINDOW;
+        let members: Vec<pallet_prmx_quote::group::GroupMember> = (0..members as i32)
+            .map(|i| pallet_prmx_quote::group::GroupMember {
+                latitude: Self::LATITUDE + i * 10_000,
+                longitude: Self::LONGITUDE,
+                coverage_start,
+                coverage_end,
+            })
+            .collect();
+        PrmxQuote::request_group_quote(
+            RuntimeOrigin::signed(requester.clone()),
+            Self::create_market(),
+            members.try_into().expect("members are bounded by MaxGroupMembers"),
+            10,
+            prmx_primitives::EventType::CumulativeRainfallWindow,
+            500,
+        )
+        .expect("market is open");
+        let quote_id = *pallet_prmx_quote::PendingQuotes::<Runtime>::get()
+            .last()
+            .expect("quote was queued");
+        PrmxQuote::submit_quote(RuntimeOrigin::root(), quote_id, 50_000).expect("quote is pending");
+
+        Self::fund_usdt(requester, 1_000_000_000_000);
+        Self::fund_usdt(&DaoCapitalAccountId::get(), 1_000_000_000_000);
+        quote_id
+    }
+
+    fn set_timestamp(now_secs: u64) {
+        pallet_timestamp::Now::<Runtime>::put(now_secs.saturating_mul(1000));
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_prmx_oracle::BenchmarkHelper for PrmxBenchmarkHelper {
+    fn set_timestamp(now_secs: u64) {
+        pallet_timestamp::Now::<Runtime>::put(now_secs.saturating_mul(1000));
+    }
+
+    fn v2_policy() -> PolicyId {
+        let holder: AccountId = frame_benchmarking::account("holder", 0, 0);
+        Self::policy_on(&holder, Self::create_market(), true)
+    }
+
+    fn expired_policy(market_id: pallet_prmx_markets::MarketId) -> PolicyId {
+        let holder: AccountId = frame_benchmarking::account("holder", 1, 0);
+        let policy_id = Self::policy_on(&holder, market_id, false);
+        let policy = PrmxPolicy::policies(policy_id).expect("policy was created");
+        Self::set_timestamp(policy.coverage_end + 1);
+        policy_id
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_oracle_v3::BenchmarkHelper for PrmxBenchmarkHelper {
+    fn active_policy(location_id: pallet_oracle_v3::LocationId, event_spec: EventSpecV3) -> PolicyId {
+        let holder: AccountId = frame_benchmarking::account("holder", 0, 0);
+        let policy_id = prmx_primitives::generate_unique_id(b"V3", &holder, 0, location_id);
+        PrmxPolicyV3::create_policy(
+            policy_id,
+            holder,
+            frame_benchmarking::account("underwriter", 0, 0),
+            location_id,
+            event_spec,
+            1,
+            1_000_000,
+            86_400,
+            8 * 86_400,
+            None,
+        )
+        .expect("location is active");
+        Self::fund_usdt(
+            &PrmxPolicyV3::policy_pool_account(policy_id),
+            prmx_primitives::V3_PAYOUT_PER_SHARE,
+        );
+        policy_id
+    }
+
+    fn expirable_request(location_id: pallet_oracle_v3::LocationId) -> PolicyId {
+        let requester: AccountId = frame_benchmarking::account("requester", 0, 0);
+        Self::fund_usdt(&requester, 1_000_000_000_000);
+        let now = pallet_timestamp::Now::<Runtime>::get() / 1000;
+        let nonce = pallet_market_v3::AccountNonce::<Runtime>::get(&requester);
+        PrmxMarketV3::create_underwrite_request(
+            RuntimeOrigin::signed(requester.clone()),
+            location_id,
+            Self::rain_spec_v3(),
+            10,
+            1_000_000,
+            now + 86_400,
+            now + 2 * 86_400,
+            now + 3_600,
+        )
+        .expect("location is active and the premium is funded");
+        prmx_primitives::generate_unique_id(b"V3", &requester, now, nonce)
+    }
+
+    fn expirable_rfq(location_id: pallet_oracle_v3::LocationId) -> PolicyId {
+        let requester: AccountId = frame_benchmarking::account("requester", 1, 0);
+        let now = pallet_timestamp::Now::<Runtime>::get() / 1000;
+        let nonce = pallet_market_v3::AccountNonce::<Runtime>::get(&requester);
+        PrmxMarketV3::create_rfq(
+            RuntimeOrigin::signed(requester.clone()),
+            location_id,
+            Self::rain_spec_v3(),
+            10,
+            now + 86_400,
+            now + 2 * 86_400,
+            now + 3_600,
+        )
+        .expect("location is active");
+        let rfq_id = prmx_primitives::generate_unique_id(b"V3RFQ", &requester, now, nonce);
+        for i in 0..MaxOffersPerRfqV3::get() {
+            let underwriter: AccountId = frame_benchmarking::account("underwriter", i, 0);
+            Self::fund_usdt(&underwriter, 1_000_000_000_000);
+            PrmxMarketV3::submit_rfq_offer(RuntimeOrigin::signed(underwriter), rfq_id, 1_000_000)
+                .expect("offer is funded and before the deadline");
+        }
+        pallet_timestamp::Now::<Runtime>::put(
+            (now + 3_600 + pallet_market_v3::V3_RFQ_ACCEPTANCE_WINDOW_SECS) * 1000,
+        );
+        rfq_id
+    }
+}
+
 // =============================================================================
 //                          Construct Runtime
 // =============================================================================
@@ -834,6 +1360,12 @@ construct_runtime!(
         PrmxOracleV3: pallet_oracle_v3,
         PrmxPolicyV3: pallet_policy_v3,
         PrmxMarketV3: pallet_market_v3,
+
+        // PRMX Reinsurance (appended to keep existing pallet indices)
+        PrmxReinsurance: pallet_prmx_reinsurance,
+
+        // PRMX Emergency Pause
+        PrmxPause: pallet_prmx_pause,
     }
 );
 
@@ -861,6 +1393,19 @@ pub type SignedExtra = (
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic =
     generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
+/// Storage migrations run on the next runtime upgrade
+pub type Migrations = (
+    pallet_oracle_v3::migrations::MigrateV0ToV1<Runtime>,
+    pallet_oracle_v3::migrations::MigrateV1ToV2<Runtime>,
+    pallet_oracle_v3::migrations::MigrateV2ToV3<Runtime>,
+    pallet_prmx_oracle::migrations::MigrateV0ToV1<Runtime>,
+    pallet_prmx_oracle::migrations::MigrateV1ToV2<Runtime>,
+    pallet_prmx_oracle::migrations::MigrateV2ToV3<Runtime>,
+    pallet_prmx_oracle::migrations::MigrateV3ToV4<Runtime>,
+    pallet_prmx_oracle::migrations::MigrateV4ToV5<Runtime>,
+    pallet_prmx_xcm_capital::migrations::MigrateV0ToV1<Runtime>,
+);
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
     Runtime,
@@ -870,6 +1415,20 @@ pub type Executive = frame_executive::Executive<
     AllPalletsWithSystem,
 >;
 
+// =============================================================================
+//                          Benchmarks
+// =============================================================================
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benches {
+    frame_benchmarking::define_benchmarks!(
+        [pallet_prmx_quote, PrmxQuote]
+        [pallet_prmx_policy, PrmxPolicy]
+        [pallet_prmx_oracle, PrmxOracle]
+        [pallet_oracle_v3, PrmxOracleV3]
+    );
+}
+
 // =============================================================================
 //                          Runtime APIs
 // =============================================================================
@@ -1019,6 +1578,163 @@ impl_runtime_apis! {
         }
     }
 
+    impl prmx_primitives::PrmxOracleApi<Block> for Runtime {
+        fn rolling_sum_at(market_id: u64, timestamp: u64) -> Option<u32> {
+            PrmxOracle::rolling_sum_at(market_id, timestamp)
+        }
+
+        fn hourly_buckets(market_id: u64, from: u64, to: u64) -> Vec<prmx_primitives::HourlyRainfall> {
+            PrmxOracle::hourly_buckets_in_range(market_id, from, to)
+        }
+
+        fn threshold_status(market_id: u64) -> Option<prmx_primitives::ThresholdStatus> {
+            PrmxOracle::threshold_status(market_id)
+        }
+    }
+
+    impl prmx_primitives::PrmxOracleProviderApi<Block, AccountId> for Runtime {
+        fn provider_quality(account: AccountId) -> Option<prmx_primitives::ProviderQuality> {
+            PrmxOracle::provider_quality(&account)
+        }
+    }
+
+    impl prmx_primitives::PrmxOracleV3Api<Block> for Runtime {
+        fn find_nearest_location(latitude: i32, longitude: i32) -> Option<u64> {
+            PrmxOracleV3::find_nearest_location(latitude, longitude)
+        }
+    }
+
+    impl prmx_primitives::PrmxPolicyApi<Block, AccountId, Balance> for Runtime {
+        fn policies_of(account: AccountId) -> Vec<prmx_primitives::PolicyId> {
+            PrmxPolicy::policies_of(&account)
+        }
+
+        fn policy_details(
+            policy_id: prmx_primitives::PolicyId,
+        ) -> Option<prmx_primitives::PolicyDetails<AccountId, Balance>> {
+            PrmxPolicy::policy_details(policy_id)
+        }
+
+        fn active_exposure(market_id: u64) -> prmx_primitives::MarketExposure<Balance> {
+            PrmxPolicy::active_exposure(market_id)
+        }
+
+        fn notional_utilization(
+            market_id: u64,
+            latitude: i32,
+            longitude: i32,
+        ) -> prmx_primitives::NotionalUtilization<Balance> {
+            PrmxPolicy::notional_utilization(market_id, latitude, longitude)
+        }
+
+        fn pool_account_of(policy_id: prmx_primitives::PolicyId) -> Option<AccountId> {
+            PrmxPolicy::pool_account_of(policy_id)
+                .or_else(|| PrmxPolicyV3::pool_account_of(policy_id))
+        }
+    }
+
+    impl prmx_audit::PrmxAuditApi<Block, AccountId, Balance> for Runtime {
+        fn settlement_dossier(
+            policy_id: prmx_primitives::PolicyId,
+        ) -> Option<prmx_audit::SettlementDossier<AccountId, Balance>> {
+            audit::settlement_dossier(policy_id)
+        }
+    }
+
+    impl prmx_primitives::PrmxSettlementApi<Block, Balance> for Runtime {
+        fn simulate_settlement(
+            policy_id: prmx_primitives::PolicyId,
+            at_timestamp: u64,
+        ) -> Option<prmx_primitives::SettlementSimulation<Balance>> {
+            PrmxOracle::simulate_settlement(policy_id, at_timestamp)
+        }
+    }
+
+    impl prmx_primitives::PrmxTriggerLogApi<Block, AccountId> for Runtime {
+        fn trigger_logs(
+            market_id: u64,
+            cursor: Option<u64>,
+            limit: u32,
+        ) -> prmx_primitives::TriggerLogPage<AccountId> {
+            let (logs, next_cursor) = PrmxOracle::trigger_logs_of_market(market_id, cursor, limit);
+            prmx_primitives::TriggerLogPage {
+                logs: logs
+                    .into_iter()
+                    .map(|log| prmx_primitives::TriggerLogEntry {
+                        trigger_id: log.trigger_id,
+                        market_id: log.market_id,
+                        policy_id: log.policy_id,
+                        triggered_at: log.triggered_at,
+                        block_number: log.block_number,
+                        rolling_sum_mm: log.rolling_sum_mm,
+                        strike_threshold: log.strike_threshold,
+                        holder: log.holder,
+                        payout_amount: log.payout_amount,
+                        observation_root: log.observation_root,
+                        observation_count: log.observation_count,
+                    })
+                    .collect(),
+                next_cursor,
+            }
+        }
+    }
+
+    impl prmx_primitives::PrmxSettlementAnalyticsApi<Block, Balance> for Runtime {
+        fn market_settlement_stats(
+            market_id: u64,
+        ) -> prmx_primitives::MarketSettlementStats<Balance> {
+            PrmxPolicy::market_settlement_stats(market_id)
+        }
+    }
+
+    impl prmx_primitives::PrmxHealthApi<Block> for Runtime {
+        fn prmx_health() -> prmx_primitives::PrmxHealth {
+            health::prmx_health()
+        }
+    }
+
+    impl prmx_primitives::PrmxLpOrderbookApi<Block, Balance> for Runtime {
+        fn order_book_depth(
+            policy_id: prmx_primitives::PolicyId,
+            max_levels: u32,
+        ) -> prmx_primitives::OrderBookDepth<Balance> {
+            PrmxOrderbookLp::order_book_depth(policy_id, max_levels)
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    impl frame_benchmarking::Benchmark<Block> for Runtime {
+        fn benchmark_metadata(extra: bool) -> (
+            Vec<frame_benchmarking::BenchmarkList>,
+            Vec<frame_support::traits::StorageInfo>,
+        ) {
+            use frame_benchmarking::BenchmarkList;
+            use frame_support::traits::StorageInfoTrait;
+
+            let mut list = Vec::<BenchmarkList>::new();
+            list_benchmarks!(list, extra);
+
+            let storage_info = AllPalletsWithSystem::storage_info();
+            (list, storage_info)
+        }
+
+        fn dispatch_benchmark(
+            config: frame_benchmarking::BenchmarkConfig,
+        ) -> Result<Vec<frame_benchmarking::BenchmarkBatch>, alloc::string::String> {
+            use frame_benchmarking::BenchmarkBatch;
+            use frame_support::traits::WhitelistedStorageKeys;
+            use sp_storage::TrackedStorageKey;
+
+            let whitelist: Vec<TrackedStorageKey> =
+                AllPalletsWithSystem::whitelisted_storage_keys();
+            let mut batches = Vec::<BenchmarkBatch>::new();
+            let params = (&config, &whitelist);
+            add_benchmarks!(params, batches);
+
+            Ok(batches)
+        }
+    }
+
     impl sp_genesis_builder::GenesisBuilder<Block> for Runtime {
         fn build_state(config: Vec<u8>) -> sp_genesis_builder::Result {
             build_state::<RuntimeGenesisConfig>(config)
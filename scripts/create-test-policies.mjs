@@ -102,7 +102,7 @@ async function main() {
   }
 
   // Submit quote (fallback probability)
-  await sendTx(api, api.tx.prmxQuote.submitQuote(quoteId, FALLBACK_PROBABILITY_PPM), alice, 'Submit quote fallback');
+  await sendTx(api, api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(quoteId, FALLBACK_PROBABILITY_PPM)), alice, 'Submit quote fallback');
 
   // Apply for coverage
   const policyEvents = await sendTx(api, api.tx.prmxPolicy.applyCoverageWithQuote(quoteId), bob, 'Apply for V1 coverage');
@@ -122,7 +122,7 @@ async function main() {
   const v2End = chainNow + 86400; // Ends in 1 day
   const durationDays = 2;
   
-  const v2QuoteTx = api.tx.prmxQuote.requestPolicyQuoteV2(MANILA_MARKET_ID, v2Start, v2End, MANILA_LAT, MANILA_LON, 3, durationDays, STRIKE_VALUE);
+  const v2QuoteTx = api.tx.prmxQuote.requestPolicyQuoteV2(MANILA_MARKET_ID, v2Start, v2End, MANILA_LAT, MANILA_LON, 3, durationDays, STRIKE_VALUE, [], 'Any');
   const v2QuoteEvents = await sendTx(api, v2QuoteTx, bob, 'Request V2 quote');
   
   let v2QuoteId;
@@ -140,7 +140,7 @@ async function main() {
     return;
   }
 
-  await sendTx(api, api.tx.prmxQuote.submitQuote(v2QuoteId, FALLBACK_PROBABILITY_PPM), alice, 'Submit V2 quote fallback');
+  await sendTx(api, api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(v2QuoteId, FALLBACK_PROBABILITY_PPM)), alice, 'Submit V2 quote fallback');
 
   const v2PolicyEvents = await sendTx(api, api.tx.prmxPolicy.applyCoverageWithQuote(v2QuoteId), bob, 'Apply for V2 coverage');
   
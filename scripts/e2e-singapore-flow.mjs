@@ -245,7 +245,7 @@ async function main() {
   // Submit quote if pending
   const status = await api.query.prmxQuote.quoteStatuses(expectedQuoteId);
   if (status.toString() === 'Pending') {
-    await signAndWait(api.tx.prmxQuote.submitQuote(expectedQuoteId, PROBABILITY_PPM), alice, 'submitQuote');
+    await signAndWait(api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(expectedQuoteId, PROBABILITY_PPM)), alice, 'submitQuote');
   } else {
     console.log(`   Quote status is ${status.toString()}, skipping submitQuote`);
   }
@@ -94,7 +94,7 @@ async function main() {
     const status = await api.query.prmxQuote.quoteStatuses(expectedQuoteId);
     if (status.toString() === 'Pending') {
         await signAndWait(
-            api.tx.prmxQuote.submitQuote(expectedQuoteId, 50_000), // 5% probability
+            api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(expectedQuoteId, 50_000)), // 5% probability
             alice,
             'submitQuote'
         );
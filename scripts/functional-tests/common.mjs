@@ -117,9 +117,10 @@ export async function requestQuote(api, user, marketId, coverageStart, coverageE
     return quoteId;
 }
 
-export async function submitQuote(api, oracle, quoteId, probabilityPpm = 50_000) {
-    const submitQuoteTx = api.tx.prmxQuote.submitQuote(quoteId, probabilityPpm);
-    await sendTx(submitQuoteTx, oracle);
+// submitQuote is governance-only; `sudoKey` must be the sudo account (Alice on dev chains)
+export async function submitQuote(api, sudoKey, quoteId, probabilityPpm = 50_000) {
+    const submitQuoteTx = api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(quoteId, probabilityPpm));
+    await sendTx(submitQuoteTx, sudoKey);
     
     const quoteResult = await api.query.prmxQuote.quoteResults(quoteId);
     return quoteResult.isSome ? BigInt(quoteResult.unwrap().totalPremium.toString()) : 0n;
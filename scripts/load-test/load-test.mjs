@@ -164,7 +164,7 @@ async function requestQuote(api, user, coverageStart, coverageEnd) {
 
 // Submit quote result (simulating off-chain worker)
 async function submitQuote(api, quoteId, probabilityPpm = 100000) { // 10%
-  const submitQuoteTx = api.tx.prmxQuote.submitQuote(quoteId, probabilityPpm);
+  const submitQuoteTx = api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(quoteId, probabilityPpm));
   await sendTx(api, submitQuoteTx, accounts.alice);
 }
 
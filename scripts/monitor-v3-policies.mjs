@@ -112,6 +112,16 @@ function parseAggState(aggState, policyId = null) {
             const val = parseInt(String(stateValue?.mask || stateValue || 0).replace(/,/g, ''));
             return { type: 'PrecipType', value: `mask: ${val}` };
         }
+        case 'ConsecutiveDryHours': {
+            const val = parseInt(String(stateValue?.longest_hours || stateValue?.longestHours || 0).replace(/,/g, ''));
+            return { type: 'DryStreak', value: `${val} h` };
+        }
+        case 'CycloneApproach': {
+            const closest = parseInt(String(stateValue?.closest_km ?? stateValue?.closestKm ?? 0).replace(/,/g, ''));
+            const peak = parseInt(String(stateValue?.peak_category ?? stateValue?.peakCategory ?? 0).replace(/,/g, ''));
+            const distance = closest === 4294967295 ? 'no storm' : `${closest} km`;
+            return { type: 'Cyclone', value: `closest ${distance}, peak cat ${peak}` };
+        }
         default:
             return { type: stateType, value: JSON.stringify(stateValue) };
     }
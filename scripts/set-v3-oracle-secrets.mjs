@@ -12,6 +12,14 @@
  *   --hmac-secret <secret>     HMAC secret for Ingest API auth (or V3_INGEST_HMAC_SECRET env var)
  *   --accuweather-key <key>    AccuWeather API key (or ACCUWEATHER_API_KEY env var)
  *   --ingest-url <url>         Ingest API base URL (or V3_INGEST_API_URL env var)
+ *   --accuweather-mirrors <urls>  Comma-separated AccuWeather mirror base URLs tried after
+ *                              the public host (or ACCUWEATHER_MIRRORS env var)
+ *   --storm-track-url <url>    Storm-track API base URL for cyclone policies
+ *                              (or V3_STORM_TRACK_API_URL env var)
+ *   --settlement-webhooks <urls>  Comma-separated URLs notified after each final report
+ *                              (or V3_SETTLEMENT_WEBHOOKS env var)
+ *   --fetch-budget <n>         AccuWeather calls per OCW run, default 20 (or V3_FETCH_BUDGET env var)
+ *   --daily-budget <n>         AccuWeather calls per UTC day, default 2000 (or V3_DAILY_BUDGET env var)
  *   --ws-url <url>             WebSocket URL of the node (default: ws://127.0.0.1:9944)
  *   
  * Examples:
@@ -26,13 +34,18 @@
  */
 
 import { ApiPromise, WsProvider } from '@polkadot/api';
-import { u8aToHex, stringToU8a, compactAddLength } from '@polkadot/util';
+import { u8aToHex, stringToU8a, compactAddLength, u8aConcat } from '@polkadot/util';
 
 // Storage key prefixes (must match OCW constants)
 const STORAGE_PREFIX = 'ocw:v3:';
 const INGEST_HMAC_SECRET_KEY = STORAGE_PREFIX + 'ingest_hmac_secret';
 const ACCUWEATHER_API_KEY = STORAGE_PREFIX + 'accuweather_api_key';
 const INGEST_API_URL_KEY = STORAGE_PREFIX + 'ingest_api_url';
+const ACCUWEATHER_MIRRORS_KEY = STORAGE_PREFIX + 'accuweather_mirrors';
+const SETTLEMENT_WEBHOOKS_KEY = STORAGE_PREFIX + 'settlement_webhooks';
+const STORM_TRACK_API_URL_KEY = STORAGE_PREFIX + 'storm_track_api_url';
+const FETCH_BUDGET_PER_RUN_KEY = STORAGE_PREFIX + 'fetch_budget_per_run';
+const DAILY_FETCH_BUDGET_KEY = STORAGE_PREFIX + 'daily_fetch_budget';
 
 function parseArgs() {
     const args = process.argv.slice(2);
@@ -40,6 +53,11 @@ function parseArgs() {
         hmacSecret: process.env.V3_INGEST_HMAC_SECRET,
         accuweatherKey: process.env.ACCUWEATHER_API_KEY,
         ingestUrl: process.env.V3_INGEST_API_URL || 'http://localhost:3001',
+        accuweatherMirrors: process.env.ACCUWEATHER_MIRRORS,
+        settlementWebhooks: process.env.V3_SETTLEMENT_WEBHOOKS,
+        stormTrackUrl: process.env.V3_STORM_TRACK_API_URL,
+        fetchBudget: process.env.V3_FETCH_BUDGET,
+        dailyBudget: process.env.V3_DAILY_BUDGET,
         wsUrl: 'ws://127.0.0.1:9944',
     };
     
@@ -54,6 +72,21 @@ function parseArgs() {
             case '--ingest-url':
                 config.ingestUrl = args[++i];
                 break;
+            case '--accuweather-mirrors':
+                config.accuweatherMirrors = args[++i];
+                break;
+            case '--settlement-webhooks':
+                config.settlementWebhooks = args[++i];
+                break;
+            case '--storm-track-url':
+                config.stormTrackUrl = args[++i];
+                break;
+            case '--fetch-budget':
+                config.fetchBudget = args[++i];
+                break;
+            case '--daily-budget':
+                config.dailyBudget = args[++i];
+                break;
             case '--ws-url':
                 config.wsUrl = args[++i];
                 break;
@@ -69,12 +102,22 @@ Options:
   --hmac-secret <secret>     HMAC secret for Ingest API auth
   --accuweather-key <key>    AccuWeather API key  
   --ingest-url <url>         Ingest API base URL (default: http://localhost:3001)
+  --accuweather-mirrors <urls>  Comma-separated AccuWeather mirror base URLs
+  --settlement-webhooks <urls>  Comma-separated settlement webhook URLs
+  --storm-track-url <url>    Storm-track API base URL for cyclone policies
+  --fetch-budget <n>         AccuWeather calls per OCW run (default: 20)
+  --daily-budget <n>         AccuWeather calls per UTC day (default: 2000)
   --ws-url <url>             WebSocket URL (default: ws://127.0.0.1:9944)
 
 Environment Variables:
   V3_INGEST_HMAC_SECRET      HMAC secret
   ACCUWEATHER_API_KEY        AccuWeather API key
   V3_INGEST_API_URL          Ingest API URL
+  ACCUWEATHER_MIRRORS        AccuWeather mirror base URLs
+  V3_SETTLEMENT_WEBHOOKS     Settlement webhook URLs
+  V3_STORM_TRACK_API_URL     Storm-track API base URL
+  V3_FETCH_BUDGET            AccuWeather calls per OCW run
+  V3_DAILY_BUDGET            AccuWeather calls per UTC day
                 `);
                 process.exit(0);
         }
@@ -145,6 +188,55 @@ async function main() {
         await api.rpc.offchain.localStorageSet('PERSISTENT', urlKey, urlValue);
         console.log('  ✅ Ingest API URL stored');
 
+        // AccuWeather mirrors (SCALE Vec<Vec<u8>>), optional
+        if (config.accuweatherMirrors) {
+            const mirrors = config.accuweatherMirrors.split(',').map((m) => m.trim()).filter(Boolean);
+            const mirrorsKey = u8aToHex(stringToU8a(ACCUWEATHER_MIRRORS_KEY));
+            // Compact item count followed by each length-prefixed URL
+            const mirrorsValue = u8aToHex(u8aConcat(
+                api.createType('Compact<u32>', mirrors.length).toU8a(),
+                ...mirrors.map((m) => compactAddLength(stringToU8a(m)))
+            ));
+            await api.rpc.offchain.localStorageSet('PERSISTENT', mirrorsKey, mirrorsValue);
+            console.log(`  ✅ ${mirrors.length} AccuWeather mirror(s) stored`);
+        }
+
+        // Settlement webhooks (SCALE Vec<Vec<u8>>), optional
+        if (config.settlementWebhooks) {
+            const webhooks = config.settlementWebhooks.split(',').map((w) => w.trim()).filter(Boolean);
+            const webhooksKey = u8aToHex(stringToU8a(SETTLEMENT_WEBHOOKS_KEY));
+            const webhooksValue = u8aToHex(u8aConcat(
+                api.createType('Compact<u32>', webhooks.length).toU8a(),
+                ...webhooks.map((w) => compactAddLength(stringToU8a(w)))
+            ));
+            await api.rpc.offchain.localStorageSet('PERSISTENT', webhooksKey, webhooksValue);
+            console.log(`  ✅ ${webhooks.length} settlement webhook(s) stored`);
+        }
+
+        // Storm-track API URL, optional (only cyclone policies use it)
+        if (config.stormTrackUrl) {
+            const stormKey = u8aToHex(stringToU8a(STORM_TRACK_API_URL_KEY));
+            const stormValue = scaleEncodeBytes(config.stormTrackUrl);
+            await api.rpc.offchain.localStorageSet('PERSISTENT', stormKey, stormValue);
+            console.log('  ✅ Storm-track API URL stored');
+        }
+
+        // Per-run AccuWeather fetch budget (SCALE u32), optional
+        if (config.fetchBudget) {
+            const budgetKey = u8aToHex(stringToU8a(FETCH_BUDGET_PER_RUN_KEY));
+            const budgetValue = api.createType('u32', Number(config.fetchBudget)).toHex();
+            await api.rpc.offchain.localStorageSet('PERSISTENT', budgetKey, budgetValue);
+            console.log(`  ✅ Fetch budget of ${config.fetchBudget} call(s) per run stored`);
+        }
+
+        // Daily AccuWeather fetch budget (SCALE u32), optional
+        if (config.dailyBudget) {
+            const dailyKey = u8aToHex(stringToU8a(DAILY_FETCH_BUDGET_KEY));
+            const dailyValue = api.createType('u32', Number(config.dailyBudget)).toHex();
+            await api.rpc.offchain.localStorageSet('PERSISTENT', dailyKey, dailyValue);
+            console.log(`  ✅ Daily budget of ${config.dailyBudget} call(s) stored`);
+        }
+
         console.log('');
         console.log('🎉 V3 oracle secrets successfully configured!');
         console.log('');
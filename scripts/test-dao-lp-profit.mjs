@@ -152,7 +152,7 @@ async function main() {
     });
 
     await new Promise((resolve) => {
-        api.tx.prmxQuote.submitQuote(quoteId, 50_000).signAndSend(alice, ({ status }) => {
+        api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(quoteId, 50_000)).signAndSend(alice, ({ status }) => {
             if (status.isInBlock) resolve();
         });
     });
@@ -220,7 +220,7 @@ async function main() {
     console.log('─'.repeat(70));
 
     const probabilityPpm = 50_000; // 5%
-    const submitQuoteTx = api.tx.prmxQuote.submitQuote(quoteId, probabilityPpm);
+    const submitQuoteTx = api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(quoteId, probabilityPpm));
     
     await new Promise((resolve) => {
         submitQuoteTx.signAndSend(alice, ({ status }) => {
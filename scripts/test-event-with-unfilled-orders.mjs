@@ -190,7 +190,7 @@ async function main() {
     });
     console.log(`✅ Quote requested: ID ${quoteId}`);
 
-    const submitQuoteTx = api.tx.prmxQuote.submitQuote(quoteId, 50_000); // 5%
+    const submitQuoteTx = api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(quoteId, 50_000)); // 5%
     await new Promise((resolve) => {
         submitQuoteTx.signAndSend(alice, ({ status }) => {
             if (status.isInBlock) resolve();
@@ -316,7 +316,7 @@ async function main() {
     console.log(`   Event probability: ${probabilityPpm / 10000}%`);
     console.log(`   DAO margin: 20%`);
 
-    const submitQuoteTx = api.tx.prmxQuote.submitQuote(quoteId, probabilityPpm);
+    const submitQuoteTx = api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(quoteId, probabilityPpm));
     
     await new Promise((resolve, reject) => {
         submitQuoteTx.signAndSend(alice, ({ status, events, dispatchError }) => {
@@ -156,7 +156,7 @@ async function main() {
 
     // Submit quote
     await new Promise((resolve) => {
-        api.tx.prmxQuote.submitQuote(quoteId0, 50_000).signAndSend(alice, ({ status }) => {
+        api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(quoteId0, 50_000)).signAndSend(alice, ({ status }) => {
             if (status.isInBlock) resolve();
         });
     });
@@ -208,7 +208,7 @@ async function main() {
     });
 
     await new Promise((resolve) => {
-        api.tx.prmxQuote.submitQuote(quoteId1, 50_000).signAndSend(alice, ({ status }) => {
+        api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(quoteId1, 50_000)).signAndSend(alice, ({ status }) => {
             if (status.isInBlock) resolve();
         });
     });
@@ -186,13 +186,13 @@ async function main() {
   // Total for 10 shares: 60 USDT
   console.log(`   Expected premium: ~60 USDT (5% * 100 USDT * 1.2 margin * 10 shares)`);
   
-  const submitQuoteTx = api.tx.prmxQuote.submitQuote(
+  const submitQuoteTx = api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(
     0,              // quoteId
     probabilityPpm  // probability in parts per million
-  );
+  ));
   
-  // Note: In production, this would be an unsigned transaction from offchain worker
-  // For testing, we submit it as a signed transaction from Alice (simulating oracle)
+  // Note: In production, the offchain worker submits this as an unsigned transaction
+  // with a signed payload. For testing, Alice sets it through the sudo override.
   await submitAndWait(api, submitQuoteTx, alice, 'Submit Quote (Simulated Oracle)');
   
   // Check quote result
@@ -214,7 +214,7 @@ async function main() {
     console.log(`✅ Quote requested (ID: ${quoteId})`);
 
     // Submit quote
-    const submitQuoteTx = api.tx.prmxQuote.submitQuote(quoteId, 50_000); // 5% probability
+    const submitQuoteTx = api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(quoteId, 50_000)); // 5% probability
     await new Promise((resolve) => {
         submitQuoteTx.signAndSend(alice, ({ status }) => {
             if (status.isInBlock) resolve();
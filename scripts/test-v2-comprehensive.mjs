@@ -290,7 +290,7 @@ async function requestV1Quote(api, bob, coverageStart, coverageEnd, shares) {
 
 async function requestV2Quote(api, bob, coverageStart, coverageEnd, shares, durationDays, strikeMm = STRIKE_VALUE) {
     const tx = api.tx.prmxQuote.requestPolicyQuoteV2(
-        MANILA_MARKET_ID, coverageStart, coverageEnd, MANILA_LAT, MANILA_LON, shares, durationDays, strikeMm
+        MANILA_MARKET_ID, coverageStart, coverageEnd, MANILA_LAT, MANILA_LON, shares, durationDays, strikeMm, [], 'Any'
     );
     const events = await sendTx(api, tx, bob);
     for (const { event } of events) {
@@ -302,7 +302,7 @@ async function requestV2Quote(api, bob, coverageStart, coverageEnd, shares, dura
 }
 
 async function submitQuoteFallback(api, alice, quoteId) {
-    await sendTx(api, api.tx.prmxQuote.submitQuote(quoteId, FALLBACK_PROBABILITY_PPM), alice);
+    await sendTx(api, api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(quoteId, FALLBACK_PROBABILITY_PPM)), alice);
     const result = await api.query.prmxQuote.quoteResults(quoteId);
     return result.isSome ? BigInt(result.unwrap().totalPremium.toString()) : 0n;
 }
@@ -1198,7 +1198,7 @@ async function testV2DurationValidation(api, accounts) {
         
         try {
             const tx = api.tx.prmxQuote.requestPolicyQuoteV2(
-                MANILA_MARKET_ID, coverageStart, coverageEnd, MANILA_LAT, MANILA_LON, 1, days, STRIKE_VALUE
+                MANILA_MARKET_ID, coverageStart, coverageEnd, MANILA_LAT, MANILA_LON, 1, days, STRIKE_VALUE, [], 'Any'
             );
             await sendTx(api, tx, bob);
             
@@ -118,7 +118,7 @@ async function main() {
     }
     if (status1.toString() === 'Pending' && i === 4) {
       try {
-        await signAndWait(api.tx.prmxQuote.submitQuote(quoteId1, 50000), alice, 'submitQuote');
+        await signAndWait(api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(quoteId1, 50000)), alice, 'submitQuote');
         quote1Ready = true;
       } catch (e) {
         console.log('   (Waiting for OCW...)');
@@ -222,7 +222,7 @@ async function main() {
     if (status2.toString() === 'Pending' && i === 3) {
       // Last attempt - try to submit manually
       try {
-        await signAndWait(api.tx.prmxQuote.submitQuote(quoteId2, 30000), alice, 'submitQuote');
+        await signAndWait(api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(quoteId2, 30000)), alice, 'submitQuote');
         quoteReady = true;
       } catch (e) {
         console.log(`   (OCW submitted quote concurrently: ${e.message})`);
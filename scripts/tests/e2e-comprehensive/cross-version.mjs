@@ -96,8 +96,8 @@ async function testSimultaneousActivePolices(api, accounts, results) {
             const quoteId = findEventAndExtractId(quoteEvents, 'prmxQuote', 'QuoteRequested', 0);
             
             await signAndSend(
-                api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM),
-                oracle,
+                api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM)),
+                getKeyring().alice,
                 api
             );
             
@@ -130,7 +130,9 @@ async function testSimultaneousActivePolices(api, accounts, results) {
                     MANILA_LON,
                     2 + i,
                     3,
-                    400 + (i * 50)
+                    400 + (i * 50),
+                    [],
+                    'Any'
                 ),
                 charlie,
                 api
@@ -139,8 +141,8 @@ async function testSimultaneousActivePolices(api, accounts, results) {
             const quoteId = findEventAndExtractId(quoteEvents, 'prmxQuote', 'QuoteRequested', 0);
             
             await signAndSend(
-                api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM),
-                oracle,
+                api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM)),
+                getKeyring().alice,
                 api
             );
             
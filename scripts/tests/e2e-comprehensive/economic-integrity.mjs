@@ -102,8 +102,8 @@ async function testTotalFundConservation(api, accounts, results) {
         const quoteId = findEventAndExtractId(quoteEvents, 'prmxQuote', 'QuoteRequested', 0);
         
         await signAndSend(
-            api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM),
-            oracle,
+            api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM)),
+            getKeyring().alice,
             api
         );
         
@@ -392,8 +392,8 @@ async function testSettlementFundDistribution(api, accounts, results) {
         const quoteId = findEventAndExtractId(quoteEvents, 'prmxQuote', 'QuoteRequested', 0);
         
         await signAndSend(
-            api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM),
-            oracle,
+            api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM)),
+            getKeyring().alice,
             api
         );
         
@@ -78,8 +78,8 @@ async function testDelayedDataAllVersions(api, accounts, results) {
         const quoteId = findEventAndExtractId(quoteEvents, 'prmxQuote', 'QuoteRequested', 0);
         
         await signAndSend(
-            api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM),
-            oracle,
+            api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM)),
+            getKeyring().alice,
             api
         );
         
@@ -75,8 +75,8 @@ async function testDelayedOracleData(api, bob, oracle, results) {
         const quoteId = findEventAndExtractId(quoteEvents, 'prmxQuote', 'QuoteRequested', 0);
         
         await signAndSend(
-            api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM),
-            oracle,
+            api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM)),
+            getKeyring().alice,
             api
         );
         
@@ -278,8 +278,8 @@ async function testDoubleSettlement(api, bob, oracle, results) {
         const quoteId = findEventAndExtractId(quoteEvents, 'prmxQuote', 'QuoteRequested', 0);
         
         await signAndSend(
-            api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM),
-            oracle,
+            api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM)),
+            getKeyring().alice,
             api
         );
         
@@ -443,8 +443,8 @@ async function testSettlementBeforeCoverageEnd(api, bob, oracle, results) {
         const quoteId = findEventAndExtractId(quoteEvents, 'prmxQuote', 'QuoteRequested', 0);
         
         await signAndSend(
-            api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM),
-            oracle,
+            api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM)),
+            getKeyring().alice,
             api
         );
         
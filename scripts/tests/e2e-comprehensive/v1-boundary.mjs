@@ -82,8 +82,8 @@ async function testExact24hWindowEdge(api, bob, oracle, results) {
         results.log('Quote created', quoteId !== null, `QuoteId: ${quoteId?.substring(0, 18)}...`);
         
         await signAndSend(
-            api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM),
-            oracle,
+            api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM)),
+            getKeyring().alice,
             api
         );
         
@@ -174,8 +174,8 @@ async function testThresholdExact50mm(api, bob, oracle, results) {
         const quoteId = findEventAndExtractId(quoteEvents, 'prmxQuote', 'QuoteRequested', 0);
         
         await signAndSend(
-            api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM),
-            oracle,
+            api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM)),
+            getKeyring().alice,
             api
         );
         
@@ -425,8 +425,8 @@ async function testMidPolicyLpTransfer(api, bob, charlie, oracle, results) {
         const quoteId = findEventAndExtractId(quoteEvents, 'prmxQuote', 'QuoteRequested', 0);
         
         await signAndSend(
-            api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM),
-            oracle,
+            api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM)),
+            getKeyring().alice,
             api
         );
         
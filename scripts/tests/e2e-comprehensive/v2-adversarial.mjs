@@ -79,7 +79,9 @@ async function testHappyPathMultiDayLifecycle(api, bob, oracle, results) {
                 MANILA_LON,
                 shares,
                 V2_DURATION_DAYS,
-                V2_STRIKE_MM
+                V2_STRIKE_MM,
+                [],
+                'Any'
             ),
             bob,
             api
@@ -91,8 +93,8 @@ async function testHappyPathMultiDayLifecycle(api, bob, oracle, results) {
         
         // Submit quote
         await signAndSend(
-            api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM),
-            oracle,
+            api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM)),
+            getKeyring().alice,
             api
         );
         results.log('V2 Quote submitted', true);
@@ -260,7 +262,9 @@ async function testNoEventFalseNegative(api, bob, oracle, results) {
                 MANILA_LON,
                 3,
                 V2_DURATION_DAYS,
-                strike
+                strike,
+                [],
+                'Any'
             ),
             bob,
             api
@@ -269,8 +273,8 @@ async function testNoEventFalseNegative(api, bob, oracle, results) {
         const quoteId = findEventAndExtractId(quoteEvents, 'prmxQuote', 'QuoteRequested', 0);
         
         await signAndSend(
-            api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM),
-            oracle,
+            api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM)),
+            getKeyring().alice,
             api
         );
         
@@ -352,7 +356,9 @@ async function testNoEventCorrectPath(api, bob, oracle, results) {
                 MANILA_LON,
                 2,
                 V2_DURATION_DAYS,
-                highStrike
+                highStrike,
+                [],
+                'Any'
             ),
             bob,
             api
@@ -361,8 +367,8 @@ async function testNoEventCorrectPath(api, bob, oracle, results) {
         const quoteId = findEventAndExtractId(quoteEvents, 'prmxQuote', 'QuoteRequested', 0);
         
         await signAndSend(
-            api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM),
-            oracle,
+            api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM)),
+            getKeyring().alice,
             api
         );
         
@@ -70,7 +70,9 @@ async function createV2Policy(api, bob, oracle, strikeMm = V2_STRIKE_MM, duratio
             MANILA_LON,
             shares,
             durationDays,
-            strikeMm
+            strikeMm,
+            [],
+            'Any'
         ),
         bob,
         api
@@ -79,8 +81,8 @@ async function createV2Policy(api, bob, oracle, strikeMm = V2_STRIKE_MM, duratio
     const quoteId = findEventAndExtractId(quoteEvents, 'prmxQuote', 'QuoteRequested', 0);
     
     await signAndSend(
-        api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM),
-        oracle,
+        api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM)),
+        getKeyring().alice,
         api
     );
     
@@ -361,7 +363,9 @@ async function testDurationMinimum(api, bob, oracle, results) {
                 MANILA_LON,
                 2,
                 0,  // 0 days - should be rejected
-                V2_STRIKE_MM
+                V2_STRIKE_MM,
+                [],
+                'Any'
             ),
             bob,
             api
@@ -384,7 +388,9 @@ async function testDurationMinimum(api, bob, oracle, results) {
                 MANILA_LON,
                 2,
                 1,  // 1 day
-                V2_STRIKE_MM
+                V2_STRIKE_MM,
+                [],
+                'Any'
             ),
             bob,
             api
@@ -427,7 +433,9 @@ async function testDurationMaximum(api, bob, oracle, results) {
                 MANILA_LON,
                 2,
                 365,  // 365 days - very long
-                V2_STRIKE_MM
+                V2_STRIKE_MM,
+                [],
+                'Any'
             ),
             bob,
             api
@@ -99,8 +99,8 @@ async function testVersionCoexistenceNoCollision(api, accounts, results) {
         console.log(`      V1 QuoteId: ${createdIds.v1.quoteId?.substring(0, 24)}...`);
         
         await signAndSend(
-            api.tx.prmxQuote.submitQuote(createdIds.v1.quoteId, DEFAULT_PROBABILITY_PPM),
-            oracle,
+            api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(createdIds.v1.quoteId, DEFAULT_PROBABILITY_PPM)),
+            getKeyring().alice,
             api
         );
         
@@ -129,7 +129,9 @@ async function testVersionCoexistenceNoCollision(api, accounts, results) {
                 MANILA_LON,
                 3,
                 3,   // 3 days
-                400  // 40mm
+                400,  // 40mm
+                [],
+                'Any'
             ),
             charlie,
             api
@@ -139,8 +141,8 @@ async function testVersionCoexistenceNoCollision(api, accounts, results) {
         console.log(`      V2 QuoteId: ${createdIds.v2.quoteId?.substring(0, 24)}...`);
         
         await signAndSend(
-            api.tx.prmxQuote.submitQuote(createdIds.v2.quoteId, DEFAULT_PROBABILITY_PPM),
-            oracle,
+            api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(createdIds.v2.quoteId, DEFAULT_PROBABILITY_PPM)),
+            getKeyring().alice,
             api
         );
         
@@ -95,8 +95,8 @@ async function createV1Policy(api, bob, oracle, results) {
     
     // Submit quote
     await signAndSend(
-        api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM),
-        oracle,
+        api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM)),
+        getKeyring().alice,
         api
     );
     
@@ -148,7 +148,9 @@ async function createV2Policy(api, charlie, oracle, results) {
             MANILA_LON,
             shares,
             durationDays,
-            strikeMm
+            strikeMm,
+            [],
+            'Any'
         ),
         charlie,
         api
@@ -162,8 +164,8 @@ async function createV2Policy(api, charlie, oracle, results) {
     
     // Submit quote
     await signAndSend(
-        api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM),
-        oracle,
+        api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM)),
+        getKeyring().alice,
         api
     );
     
@@ -72,8 +72,8 @@ async function createTestPolicy(api, bob, oracle, results) {
     
     // Submit quote
     await signAndSend(
-        api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM),
-        oracle,
+        api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM)),
+        getKeyring().alice,
         api
     );
     
@@ -329,8 +329,8 @@ async function testMultiplePolicyQueries(api, bob, oracle, results) {
             const quoteId = findEventAndExtractId(quoteEvents, 'prmxQuote', 'QuoteRequested', 0);
             
             await signAndSend(
-                api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM),
-                oracle,
+                api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM)),
+                getKeyring().alice,
                 api
             );
             
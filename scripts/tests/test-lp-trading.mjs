@@ -77,8 +77,8 @@ async function createPolicyForTrading(api, bob, oracle, results) {
     
     // Submit quote
     await signAndSend(
-        api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM),
-        oracle,
+        api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM)),
+        getKeyring().alice,
         api
     );
     
@@ -81,8 +81,8 @@ async function testMultiplePolicyholders(api, accounts, results) {
             
             // Submit quote
             await signAndSend(
-                api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM),
-                oracle,
+                api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM)),
+                getKeyring().alice,
                 api
             );
             
@@ -361,8 +361,8 @@ async function testSettlementWithMultipleStakeholders(api, accounts, results) {
         const quoteId = findEventAndExtractId(quoteEvents, 'prmxQuote', 'QuoteRequested', 0);
         
         await signAndSend(
-            api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM),
-            oracle,
+            api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM)),
+            getKeyring().alice,
             api
         );
         
@@ -78,8 +78,8 @@ async function createPolicyForThresholdTest(api, bob, oracle, results) {
     
     // Submit quote
     await signAndSend(
-        api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM),
-        oracle,
+        api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM)),
+        getKeyring().alice,
         api
     );
     
@@ -279,8 +279,8 @@ async function testNoEventSettlement(api, bob, oracle, results) {
         
         // Submit quote
         await signAndSend(
-            api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM),
-            oracle,
+            api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM)),
+            getKeyring().alice,
             api
         );
         
@@ -101,8 +101,8 @@ async function testSubmitQuote(api, oracle, quoteId, results) {
     console.log(`   Submitting quote for ${quoteId.substring(0, 18)}...`);
     
     const { events } = await signAndSend(
-        api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM),
-        oracle,
+        api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM)),
+        getKeyring().alice,
         api
     );
     
@@ -79,7 +79,9 @@ async function testRequestV2Quote(api, bob, results) {
             MANILA_LON,
             shares,
             V2_DURATION_DAYS,
-            V2_STRIKE_MM
+            V2_STRIKE_MM,
+            [],
+            'Any'
         ),
         bob,
         api
@@ -111,8 +113,8 @@ async function testSubmitV2Quote(api, oracle, quoteId, results) {
     console.log(`   Submitting V2 quote for ${quoteId.substring(0, 18)}...`);
     
     const { events } = await signAndSend(
-        api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM),
-        oracle,
+        api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM)),
+        getKeyring().alice,
         api
     );
     
@@ -334,7 +336,9 @@ async function testV2NoEventScenario(api, bob, oracle, results) {
             MANILA_LON,
             shares,
             V2_DURATION_DAYS,
-            V2_STRIKE_MM
+            V2_STRIKE_MM,
+            [],
+            'Any'
         ),
         bob,
         api
@@ -344,8 +348,8 @@ async function testV2NoEventScenario(api, bob, oracle, results) {
     
     // Submit quote
     await signAndSend(
-        api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM),
-        oracle,
+        api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(quoteId, DEFAULT_PROBABILITY_PPM)),
+        getKeyring().alice,
         api
     );
     
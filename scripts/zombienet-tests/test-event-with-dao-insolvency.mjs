@@ -133,7 +133,7 @@ async function main() {
         
         // Submit quote (5% probability)
         await submitAndWait(api,
-            api.tx.prmxQuote.submitQuote(quoteId, 50000),
+            api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(quoteId, 50000)),
             alice, 'Submit quote'
         );
         
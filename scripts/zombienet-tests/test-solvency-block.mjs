@@ -158,7 +158,7 @@ async function main() {
         
         // Submit quote
         await submitAndWait(api,
-            api.tx.prmxQuote.submitQuote(quoteId, 50000),
+            api.tx.sudo.sudo(api.tx.prmxQuote.submitQuote(quoteId, 50000)),
             alice, 'Submit quote'
         );
         
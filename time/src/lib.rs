@@ -0,0 +1,95 @@
+//! PRMX Time
+//!
+//! One definition of "now" for every PRMX pallet. Pallets take a
+//! `TimeProvider: ChainTime` config item instead of reading `pallet_timestamp`
+//! or deriving time from the block number themselves, and the runtime provides
+//! the single implementation.
+//!
+//! ## Modes
+//!
+//! - Solochain: the block author sets the timestamp inherent for its Aura slot,
+//!   and blocks follow the runtime's own block time.
+//! - Parachain: the collator sets the timestamp inherent and the relay chain
+//!   checks it against the relay parent's slot, so chain time follows relay-chain
+//!   time. Before the first timestamp, the relay parent number and the relay
+//!   block time drive the estimate (see [`TimingMode`]).
+//!
+//! In both modes the timestamp inherent is applied after `on_initialize`, so the
+//! first block has no timestamp until it is; [`resolve_millis`] estimates the
+//! time from the block number until then. Afterwards hooks see the previous
+//! block's timestamp, which is at most one block behind.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Chain time in Unix time
+pub trait ChainTime {
+    /// Current chain time in Unix milliseconds
+    fn now_millis() -> u64;
+
+    /// Current chain time in Unix seconds
+    fn now_secs() -> u64 {
+        Self::now_millis() / 1000
+    }
+}
+
+/// Which block clock stands in for the timestamp before one is set
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TimingMode {
+    /// Own block number, one block every `block_time_ms`
+    Solochain { block_time_ms: u64 },
+    /// Relay parent block number, one relay block every `relay_block_time_ms`
+    Parachain { relay_block_time_ms: u64 },
+}
+
+impl TimingMode {
+    /// Milliseconds per block of the clock this mode counts
+    pub fn block_time_ms(&self) -> u64 {
+        match self {
+            TimingMode::Solochain { block_time_ms } => *block_time_ms,
+            TimingMode::Parachain { relay_block_time_ms } => *relay_block_time_ms,
+        }
+    }
+}
+
+/// Chain time from the timestamp inherent (`timestamp_ms`). While none has been
+/// set (zero) it is estimated as `block_number` blocks of `mode`'s clock, where
+/// `block_number` is the own block number on a solochain and the relay parent
+/// number on a parachain.
+pub fn resolve_millis(timestamp_ms: u64, block_number: u64, mode: TimingMode) -> u64 {
+    if timestamp_ms > 0 {
+        return timestamp_ms;
+    }
+    block_number.saturating_mul(mode.block_time_ms())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FixedTime;
+    impl ChainTime for FixedTime {
+        fn now_millis() -> u64 {
+            1_704_067_200_999
+        }
+    }
+
+    #[test]
+    fn test_now_secs_truncates_millis() {
+        assert_eq!(FixedTime::now_secs(), 1_704_067_200);
+    }
+
+    #[test]
+    fn test_resolve_millis_prefers_timestamp() {
+        let solo = TimingMode::Solochain { block_time_ms: 6_000 };
+        let para = TimingMode::Parachain { relay_block_time_ms: 6_000 };
+
+        assert_eq!(resolve_millis(1_704_067_200_000, 10, solo), 1_704_067_200_000);
+        assert_eq!(resolve_millis(1_704_067_200_000, 10, para), 1_704_067_200_000);
+
+        // No timestamp yet: estimated from the mode's block clock
+        assert_eq!(resolve_millis(0, 10, solo), 60_000);
+        let slow_relay = TimingMode::Parachain { relay_block_time_ms: 12_000 };
+        assert_eq!(resolve_millis(0, 3, slow_relay), 36_000);
+        assert_eq!(resolve_millis(0, u64::MAX, solo), u64::MAX);
+    }
+}